@@ -3,14 +3,16 @@
 //! HTTP API application bootstrap for ELF.
 
 pub mod routes;
+pub mod shadow;
 pub mod state;
+pub mod warmup;
 
 use std::{net::SocketAddr, path::PathBuf};
 
+use axum::Router;
 use clap::Parser;
 use color_eyre::{Result, eyre};
 use tokio::net::TcpListener;
-use tracing_subscriber::EnvFilter;
 
 use crate::state::AppState;
 use elf_config::Config;
@@ -26,16 +28,41 @@ pub struct Args {
 	/// Path to the ELF configuration file.
 	#[arg(long, short = 'c', value_name = "FILE")]
 	pub config: PathBuf,
+	/// Validate and lint the configuration, print the findings, and exit.
+	#[arg(long)]
+	pub check_config: bool,
 }
 
 /// Starts the public and admin HTTP servers.
 pub async fn run(args: Args) -> Result<()> {
-	let config = elf_config::load(&args.config)?;
+	let (config, lints) = elf_config::load_with_lints(&args.config)?;
+
+	if args.check_config {
+		elf_runtime::telemetry::print_lints(&lints);
+
+		return Ok(());
+	}
+
+	let _telemetry = elf_runtime::telemetry::init(&config.service, "elf-api")?;
+
+	elf_runtime::telemetry::log_lints(&lints);
+
+	let (http_addr, admin_addr) = listen_addrs(&config)?;
+	let state = AppState::new(config).await?;
+
+	warmup::spawn_warmup(state.service.clone(), state.readiness.clone());
+
+	let app = routes::router(state.clone());
+	let admin_app = routes::admin_router(state);
+
+	serve(app, admin_app, http_addr, admin_addr).await
+}
+
+/// Parses the public and admin bind addresses and enforces the loopback and auth-mode rules.
+pub fn listen_addrs(config: &Config) -> Result<(SocketAddr, SocketAddr)> {
 	let http_addr: SocketAddr = config.service.http_bind.parse()?;
 	let admin_addr: SocketAddr = config.service.admin_bind.parse()?;
 
-	init_tracing(&config)?;
-
 	if config.security.bind_localhost_only && !http_addr.ip().is_loopback() {
 		return Err(eyre::eyre!(
 			"http_bind must be a loopback address when bind_localhost_only is true."
@@ -51,9 +78,11 @@ pub async fn run(args: Args) -> Result<()> {
 					"security.auth_mode=off is only allowed when http_bind is a loopback address."
 				));
 			},
-			"static_keys" => {},
+			"static_keys" | "jwt" => {},
 			_ => {
-				return Err(eyre::eyre!("security.auth_mode must be one of off or static_keys."));
+				return Err(eyre::eyre!(
+					"security.auth_mode must be one of off, static_keys, or jwt."
+				));
 			},
 		}
 	}
@@ -61,9 +90,16 @@ pub async fn run(args: Args) -> Result<()> {
 		return Err(eyre::eyre!("admin_bind must be a loopback address."));
 	}
 
-	let state = AppState::new(config).await?;
-	let app = routes::router(state.clone());
-	let admin_app = routes::admin_router(state);
+	Ok((http_addr, admin_addr))
+}
+
+/// Serves the public and admin routers until either server stops.
+pub async fn serve(
+	app: Router,
+	admin_app: Router,
+	http_addr: SocketAddr,
+	admin_addr: SocketAddr,
+) -> Result<()> {
 	let http_listener = TcpListener::bind(http_addr).await?;
 
 	tracing::info!(%http_addr, "HTTP server listening.");
@@ -79,12 +115,3 @@ pub async fn run(args: Args) -> Result<()> {
 
 	Ok(())
 }
-
-fn init_tracing(config: &Config) -> Result<()> {
-	let filter =
-		EnvFilter::try_new(&config.service.log_level).unwrap_or_else(|_| EnvFilter::new("info"));
-
-	tracing_subscriber::fmt().with_env_filter(filter).init();
-
-	Ok(())
-}
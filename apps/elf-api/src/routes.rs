@@ -13,10 +13,14 @@ mod health;
 mod ingestion_profiles;
 mod knowledge;
 mod notes;
+mod org_stats;
 mod recall;
 mod route_builder;
 mod search;
+mod search_profiles;
+mod sessions;
 mod sharing;
+mod standing_queries;
 mod support;
 mod trace;
 mod types;
@@ -43,7 +47,7 @@ use axum::{
 	middleware::Next,
 	response::{IntoResponse, Response},
 };
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use serde_json::{Map, Value};
 use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 use uuid::Uuid;
@@ -60,71 +64,125 @@ use elf_domain::{
 	writegate::WritePolicy,
 };
 use elf_service::{
-	AddEventRequest, AddEventResponse, AddNoteInput, AddNoteRequest, AddNoteResponse,
+	AccessSimulateRequest, AccessSimulateResponse, AddEventRequest, AddEventResponse, AddNoteInput,
+	AddNoteRequest, AddNoteResponse, AdminGrantPutRequest, AdminGrantResponse,
+	AdminGrantRevokeRequest, AdminGrantsListRequest, AdminGrantsListResponse,
+	AdminGraphEntitiesMergeRequest, AdminGraphEntitiesMergeResponse,
+	AdminGraphEntityKindPromoteRequest, AdminGraphEntityKindResponse,
+	AdminGraphEntityKindsListRequest, AdminGraphEntityKindsListResponse,
+	AdminGraphEntitySplitRequest, AdminGraphEntitySplitResponse,
 	AdminGraphPredicateAliasAddRequest, AdminGraphPredicateAliasesListRequest,
 	AdminGraphPredicateAliasesResponse, AdminGraphPredicatePatchRequest,
-	AdminGraphPredicateResponse, AdminGraphPredicatesListRequest, AdminGraphPredicatesListResponse,
+	AdminGraphPredicatePromoteRequest, AdminGraphPredicateResponse,
+	AdminGraphPredicatesListRequest, AdminGraphPredicatesListResponse,
 	AdminIngestionProfileCreateRequest, AdminIngestionProfileDefaultGetRequest,
 	AdminIngestionProfileDefaultResponse, AdminIngestionProfileDefaultSetRequest,
 	AdminIngestionProfileGetRequest, AdminIngestionProfileListRequest,
 	AdminIngestionProfileResponse, AdminIngestionProfileVersionsListRequest,
 	AdminIngestionProfileVersionsListResponse, AdminIngestionProfilesListResponse,
-	ConsolidationProposalGetRequest, ConsolidationProposalInput, ConsolidationProposalResponse,
-	ConsolidationProposalReviewRequest, ConsolidationProposalsListRequest,
-	ConsolidationProposalsListResponse, ConsolidationRunCreateRequest,
-	ConsolidationRunCreateResponse, ConsolidationRunGetRequest, ConsolidationRunResponse,
-	ConsolidationRunsListRequest, ConsolidationRunsListResponse, CoreBlockAttachRequest,
-	CoreBlockAttachResponse, CoreBlockDetachRequest, CoreBlockDetachResponse,
-	CoreBlockUpsertRequest, CoreBlockUpsertResponse, CoreBlocksGetRequest, CoreBlocksResponse,
-	DeleteRequest, DeleteResponse, DocType, DocsDeleteRequest, DocsDeleteResponse,
-	DocsExcerptResponse, DocsExcerptsGetRequest, DocsGetRequest, DocsGetResponse, DocsPutRequest,
-	DocsPutResponse, DocsSearchL0Request, DocsSearchL0Response, DreamingReviewQueueRequest,
+	AdminOutboxDeadLetterListRequest, AdminOutboxDeadLetterListResponse, AdminOutboxReplayRequest,
+	AdminOutboxReplayResponse, AdminReembedActivateRequest, AdminReembedActivateResponse,
+	AdminReembedRunItem, AdminReembedRunRequest, AdminReembedRunsListRequest,
+	AdminReembedRunsResponse, AdminWriteIncidentsListRequest, AdminWriteIncidentsListResponse,
+	AuditLogListRequest, AuditLogListResponse, AuditTransport, BulkImportRequest,
+	BulkImportResponse, BulkImporter, ConsolidationProposalGetRequest, ConsolidationProposalInput,
+	ConsolidationProposalResponse, ConsolidationProposalReviewRequest,
+	ConsolidationProposalsListRequest, ConsolidationProposalsListResponse,
+	ConsolidationRunCreateRequest, ConsolidationRunCreateResponse, ConsolidationRunGetRequest,
+	ConsolidationRunResponse, ConsolidationRunsListRequest, ConsolidationRunsListResponse,
+	CoreBlockAttachRequest, CoreBlockAttachResponse, CoreBlockDetachRequest,
+	CoreBlockDetachResponse, CoreBlockUpsertRequest, CoreBlockUpsertResponse, CoreBlocksGetRequest,
+	CoreBlocksResponse, DeleteBatchRequest, DeleteRequest, DeleteResponse, DocType,
+	DocsDeleteRequest, DocsDeleteResponse, DocsExcerptResponse, DocsExcerptsGetRequest,
+	DocsGetRequest, DocsGetResponse, DocsPutRequest, DocsPutResponse, DocsSearchL0Request,
+	DocsSearchL0Response, DocsSearchRequest, DocsSearchResponse, DreamingReviewQueueRequest,
 	DreamingReviewQueueResponse, EntityMemoryViewRequest, EntityMemoryViewResponse, Error,
-	EventMessage, GranteeKind, GraphQueryEntityRef, GraphQueryPredicateRef, GraphQueryRequest,
-	GraphQueryResponse, GraphReportRequest, GraphReportResponse, IngestionProfileSelector,
+	EventMessage, GranteeKind, GraphFactsAsOfRequest, GraphFactsAsOfResponse,
+	GraphNeighborhoodRequest, GraphNeighborhoodResponse, GraphQueryEntityRef,
+	GraphQueryPredicateRef, GraphQueryRequest, GraphQueryResponse, GraphReportRequest,
+	GraphReportResponse, IndexVerifyReport, IndexVerifyRequest, IngestionProfileSelector,
 	KnowledgePageChangedSource, KnowledgePageGetRequest, KnowledgePageLintRequest,
 	KnowledgePageLintResponse, KnowledgePageRebuildRequest, KnowledgePageRebuildResponse,
 	KnowledgePageResponse, KnowledgePageSearchRequest, KnowledgePageSearchResponse,
 	KnowledgePageWatchRebuildRequest, KnowledgePageWatchRebuildResponse, KnowledgePagesListRequest,
-	KnowledgePagesListResponse, ListRequest, ListResponse, MemoryCorrectionAction,
-	MemoryCorrectionRequest, MemoryCorrectionResponse, MemoryHistoryGetRequest,
-	MemoryHistoryResponse, NoteFetchRequest, NoteFetchResponse, NoteProvenanceBundleResponse,
-	NoteProvenanceGetRequest, PayloadLevel, PublishNoteRequest, QueryPlan, RankingRequestOverride,
-	RebuildReport, RecallDebugPanelRequest, RecallDebugPanelResponse, SearchDetailsRequest,
-	SearchDetailsResult, SearchExplainRequest, SearchExplainResponse, SearchIndexItem,
-	SearchRequest, SearchResponse, SearchSessionGetRequest, SearchTimelineGroup,
-	SearchTimelineRequest, SearchTrajectoryResponse, SearchTrajectorySummary, ShareScope,
+	KnowledgePagesListResponse, ListRequest, ListResponse, ListTrashedRequest, ListTrashedResponse,
+	MAX_SEARCH_BATCH_QUERIES, MemoryCorrectionAction, MemoryCorrectionRequest,
+	MemoryCorrectionResponse, MemoryHistoryGetRequest, MemoryHistoryResponse, MemoryStatsRequest,
+	MemoryStatsResponse, MemoryTimelineBucket, MemoryTimelineRequest, MemoryTimelineResponse,
+	NoteBatchResponse, NoteEventsRequest, NoteEventsResponse, NoteFetchRequest, NoteFetchResponse,
+	NoteHistoryRequest, NoteHistoryResponse, NoteMergeStrategy, NoteProvenanceBundleResponse,
+	NoteProvenanceGetRequest, NoteRelationCreateRequest, NoteRelationCreateResponse,
+	NoteRelationDeleteRequest, NoteRelationDeleteResponse, NoteRelationKind,
+	NoteRelationsListRequest, NoteRelationsListResponse, NotesCiteRequest, NotesCiteResponse,
+	NotesMergeRequest, NotesMergeResponse, OrgMemoryStatsRequest, OrgMemoryStatsResponse,
+	PayloadLevel, PinNoteRequest, PinNoteResponse, ProviderHealthResponse, PublishNoteRequest,
+	QdrantAuditReport, QdrantAuditRequest, QdrantMaintenanceRunRequest,
+	QdrantMaintenanceRunsListRequest, QdrantMaintenanceRunsResponse, QueryPlan, QuotaUsageRequest,
+	QuotaUsageResponse, RankDocument, RankDocumentsRequest, RankDocumentsResponse,
+	RankingRequestOverride, RebuildQdrantRequest, RebuildReport, RecallContextRequest,
+	RecallContextResponse, RecallContextTurn, RecallDebugPanelRequest, RecallDebugPanelResponse,
+	SearchAnswerRequest, SearchAnswerResponse, SearchBatchRequest, SearchBatchResponse,
+	SearchDetailsRequest, SearchDetailsResult, SearchExplainRequest, SearchExplainResponse,
+	SearchFeedbackKind, SearchFeedbackRequest, SearchFeedbackResponse, SearchIndexItem,
+	SearchProfileDeleteResponse, SearchProfileGetRequest, SearchProfileRef, SearchProfileResponse,
+	SearchProfileTemplate, SearchProfileUpsertRequest, SearchProfilesListRequest,
+	SearchProfilesListResponse, SearchRequest, SearchResponse, SearchScopedRequest,
+	SearchScopedResponse, SearchSessionGetRequest, SearchShadowReportRequest,
+	SearchShadowReportResponse, SearchTimelineGroup, SearchTimelineRequest,
+	SearchTrajectoryResponse, SearchTrajectorySummary, SearchV2Delivery, SearchV2Mode,
+	SearchV2Request, SearchWarning, SearchWithProfileRequest, SessionAppendRequest,
+	SessionAppendResponse, SessionGetRequest, SessionGetResponse, SessionMessageInput,
+	SessionSummarizeRequest, SessionSummarizeResponse, ShareScope, SnapshotRestoreRequest,
+	SnapshotRestoreResponse, SnapshotRestorer, SourceRefsResolveRequest, SourceRefsResolveResponse,
 	SpaceGrantRevokeRequest, SpaceGrantRevokeResponse, SpaceGrantUpsertRequest,
-	SpaceGrantsListRequest, TextPositionSelector, TextQuoteSelector, TraceBundleGetRequest,
-	TraceBundleResponse, TraceGetRequest, TraceGetResponse, TraceRecentListRequest,
-	TraceRecentListResponse, TraceTrajectoryGetRequest, UnpublishNoteRequest, UpdateRequest,
-	UpdateResponse, WorkJournalEntryCreateRequest, WorkJournalEntryCreateResponse,
-	WorkJournalEntryFamily, WorkJournalEntryGetRequest, WorkJournalEntryResponse,
-	WorkJournalSessionReadbackRequest, WorkJournalSessionReadbackResponse, search::TraceBundleMode,
+	SpaceGrantsListRequest, StandingQueriesListRequest, StandingQueriesListResponse,
+	StandingQueryCreateRequest, StandingQueryDeleteResponse, StandingQueryFilter,
+	StandingQueryGetRequest, StandingQueryMatchesRequest, StandingQueryMatchesResponse,
+	StandingQueryResponse, StorageReportResponse, TenantExportRequest, TextPositionSelector,
+	TextQuoteSelector, TraceArtifactGetRequest, TraceBundleGetRequest, TraceBundleResponse,
+	TraceGetRequest, TraceGetResponse, TraceRecentListRequest, TraceRecentListResponse,
+	TraceTrajectoryGetRequest, UndeleteRequest, UndeleteResponse, UnpublishNoteRequest,
+	UpdateBatchItem, UpdateBatchRequest, UpdateRequest, UpdateResponse,
+	WorkJournalEntryCreateRequest, WorkJournalEntryCreateResponse, WorkJournalEntryFamily,
+	WorkJournalEntryGetRequest, WorkJournalEntryResponse, WorkJournalSessionReadbackRequest,
+	WorkJournalSessionReadbackResponse, search::TraceBundleMode, with_audit_transport,
 };
 use support::{
-	ApiError, EntityMemoryQuery, RequestContext, SearchMode, effective_token_id, empty_json_object,
+	ApiError, EntityMemoryQuery, RequestContext, effective_token_id, empty_json_object,
 	format_scope, format_space, json_error, parse_optional_rfc3339, parse_space,
-	require_admin_for_org_shared_writes, required_read_profile,
+	require_admin_for_org_shared_writes, require_writer_role, required_read_profile,
 };
 #[cfg(test)]
 use support::{
-	apply_auth_key_context, inject_request_id_into_json_body, parse_request_id_from_headers,
-	resolve_auth_key, sanitize_trusted_token_header,
+	apply_auth_key_context, audit_transport, inject_request_id_into_json_body,
+	parse_request_id_from_headers, resolve_auth_key, sanitize_trusted_token_header,
 };
 use types::{
-	AdminGraphPredicateAliasAddBody, AdminGraphPredicatePatchBody, AdminGraphPredicatesListQuery,
-	AdminIngestionProfileCreateBody, AdminIngestionProfileDefaultResponseV2,
-	AdminIngestionProfileDefaultSetBody, AdminIngestionProfileGetQuery, AdminNoteCorrectionBody,
-	ConsolidationProposalReviewBody, ConsolidationProposalsListQuery, ConsolidationRunCreateBody,
-	ConsolidationRunsListQuery, CoreBlockAttachBody, CoreBlockUpsertBody, DocsExcerptsGetBody,
-	DocsPutBody, DocsSearchL0Body, DreamingReviewQueueQuery, ErrorBody, EventsIngestRequest,
-	GraphQueryBody, GraphReportBody, KnowledgePageRebuildBody, KnowledgePageWatchRebuildBody,
-	KnowledgePagesListQuery, KnowledgePagesSearchBody, NotePatchRequest, NotesIngestRequest,
-	NotesListQuery, PublishResponseV2, RecallDebugPanelBody, SearchCreateRequest,
-	SearchCreateResponseV2, SearchDetailsBody, SearchDetailsResponseV2, SearchIndexResponseV2,
-	SearchSessionGetQuery, SearchTimelineQuery, SearchTimelineResponseV2, ShareScopeBody,
-	SpaceGrantItemV2, SpaceGrantUpsertBody, SpaceGrantUpsertResponseV2, SpaceGrantsListResponseV2,
+	AdminAccessSimulateBody, AdminAuditLogListQuery, AdminGrantPutBody, AdminGrantRevokeBody,
+	AdminGrantsListQuery, AdminGraphEntitiesMergeBody, AdminGraphEntityKindsListQuery,
+	AdminGraphEntitySplitBody, AdminGraphPredicateAliasAddBody, AdminGraphPredicatePatchBody,
+	AdminGraphPredicatesListQuery, AdminIngestionProfileCreateBody,
+	AdminIngestionProfileDefaultResponseV2, AdminIngestionProfileDefaultSetBody,
+	AdminIngestionProfileGetQuery, AdminNoteCorrectionBody, AdminOutboxDeadLetterListQuery,
+	AdminOutboxReplayBody, AdminReembedRunBody, AdminReembedRunsListQuery,
+	AdminWriteIncidentsListQuery, ConsolidationProposalReviewBody, ConsolidationProposalsListQuery,
+	ConsolidationRunCreateBody, ConsolidationRunsListQuery, CoreBlockAttachBody,
+	CoreBlockUpsertBody, DocsExcerptsGetBody, DocsPutBody, DocsSearchBody, DocsSearchL0Body,
+	DreamingReviewQueueQuery, ErrorBody, EventsIngestRequest, GraphFactsAsOfBody,
+	GraphNeighborhoodBody, GraphQueryBody, GraphReportBody, IndexVerifyBody,
+	KnowledgePageRebuildBody, KnowledgePageWatchRebuildBody, KnowledgePagesListQuery,
+	KnowledgePagesSearchBody, MemoryStatsQuery, MemoryTimelineQuery, NotePatchRequest,
+	NotesBulkImportQuery, NotesCiteBody, NotesDeleteBatchBody, NotesGetQuery, NotesIngestRequest,
+	NotesListQuery, NotesMergeBody, NotesRelationCreateBody, NotesSourceRefsResolveBody,
+	NotesSubscribeQuery, NotesUpdateBatchBody, OrgMemoryStatsQuery, PublishResponseV2,
+	QdrantAuditBody, QdrantMaintenanceRunBody, QdrantMaintenanceRunsListQuery, RankDocumentsBody,
+	RebuildQdrantBody, RecallContextBody, RecallDebugPanelBody, SearchBatchBody,
+	SearchCreateRequest, SearchCreateResponseV2, SearchDetailsBody, SearchDetailsResponseV2,
+	SearchFeedbackBody, SearchIndexResponseV2, SearchProfilePutBody, SearchScopedBody,
+	SearchSessionGetQuery, SearchShadowReportQuery, SearchTimelineQuery, SearchTimelineResponseV2,
+	SearchWithProfileBody, SearchWithProfileResponseV2, SessionAppendBody, SessionSummarizeBody,
+	ShareScopeBody, SpaceGrantItemV2, SpaceGrantUpsertBody, SpaceGrantUpsertResponseV2,
+	SpaceGrantsListResponseV2, StandingQueryCreateBody, StandingQueryMatchesQuery,
 	TraceBundleGetQuery, TraceRecentListQuery, WorkJournalEntryCreateBody,
 	WorkJournalSessionReadbackBody,
 };
@@ -141,6 +199,8 @@ const MAX_CONTEXT_HEADER_CHARS: usize = 128;
 const MAX_REQUEST_BYTES: usize = 1_048_576;
 const MAX_DOC_REQUEST_BYTES: usize = 4 * 1_024 * 1_024;
 const MAX_NOTES_PER_INGEST: usize = 256;
+const MAX_BULK_IMPORT_BYTES: usize = 64 * 1_024 * 1_024;
+const MAX_RESTORE_BYTES: u64 = 4 * 1_024 * 1_024 * 1_024;
 const MAX_MESSAGES_PER_EVENT: usize = 256;
 const MAX_MESSAGE_CHARS: usize = 16_384;
 const MAX_QUERY_CHARS: usize = 2_048;
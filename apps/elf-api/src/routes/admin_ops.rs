@@ -1,11 +1,46 @@
-use crate::routes::{ApiError, AppState, ErrorBody, Json, RebuildReport, State};
+use std::{future, io, pin::Pin};
+
+use axum::{
+	body::{Body, Bytes, HttpBody},
+	http::header::CONTENT_TYPE,
+	response::{IntoResponse, Response},
+};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::routes::{
+	self, AccessSimulateRequest, AccessSimulateResponse, AdminAccessSimulateBody,
+	AdminAuditLogListQuery, AdminGrantPutBody, AdminGrantPutRequest, AdminGrantResponse,
+	AdminGrantRevokeBody, AdminGrantRevokeRequest, AdminGrantsListQuery, AdminGrantsListRequest,
+	AdminGrantsListResponse, AdminOutboxDeadLetterListQuery, AdminOutboxDeadLetterListRequest,
+	AdminOutboxDeadLetterListResponse, AdminOutboxReplayBody, AdminOutboxReplayRequest,
+	AdminOutboxReplayResponse, AdminReembedActivateRequest, AdminReembedActivateResponse,
+	AdminReembedRunBody, AdminReembedRunItem, AdminReembedRunRequest, AdminReembedRunsListQuery,
+	AdminReembedRunsListRequest, AdminReembedRunsResponse, AdminWriteIncidentsListQuery,
+	AdminWriteIncidentsListRequest, AdminWriteIncidentsListResponse, ApiError, AppState,
+	AuditLogListRequest, AuditLogListResponse, ErrorBody, HeaderMap, IndexVerifyBody,
+	IndexVerifyReport, IndexVerifyRequest, Json, JsonRejection, MAX_RESTORE_BYTES,
+	MemoryStatsQuery, MemoryStatsRequest, MemoryStatsResponse, Path, ProviderHealthResponse,
+	QdrantAuditBody, QdrantAuditReport, QdrantAuditRequest, QdrantMaintenanceRunBody,
+	QdrantMaintenanceRunRequest, QdrantMaintenanceRunsListQuery, QdrantMaintenanceRunsListRequest,
+	QdrantMaintenanceRunsResponse, Query, QueryRejection, QuotaUsageRequest, QuotaUsageResponse,
+	RebuildQdrantBody, RebuildQdrantRequest, RebuildReport, RequestContext, SnapshotRestoreRequest,
+	SnapshotRestoreResponse, SnapshotRestorer, State, StatusCode, StorageReportResponse,
+	TenantExportRequest, Uuid,
+};
+use elf_service::TenantExportLine;
+
+/// Export batches buffered between the database cursor and the response body.
+const EXPORT_CHANNEL_BATCHES: usize = 4;
 
 #[utoipa::path(
 	post,
 	path = "/v2/admin/qdrant/rebuild",
 	tag = "admin",
+	request_body(content = Value, description = "Optional tenant, project, scope, note type, and updated_after filters."),
 	responses(
 		(status = 200, description = "Qdrant rebuild report.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
 		(status = 401, description = "Authentication required.", body = ErrorBody),
 		(status = 403, description = "Admin access required.", body = ErrorBody),
 		(status = 500, description = "Internal error.", body = ErrorBody),
@@ -13,8 +48,881 @@ use crate::routes::{ApiError, AppState, ErrorBody, Json, RebuildReport, State};
 )]
 pub(super) async fn rebuild_qdrant(
 	State(state): State<AppState>,
+	body: Bytes,
 ) -> Result<Json<RebuildReport>, ApiError> {
-	let response = state.service.rebuild_qdrant().await?;
+	// The body is optional so existing callers that post nothing keep rebuilding everything.
+	let payload: RebuildQdrantBody = if body.is_empty() {
+		RebuildQdrantBody::default()
+	} else {
+		serde_json::from_slice(&body).map_err(|err| {
+			tracing::warn!(error = %err, "Invalid request payload.");
+
+			routes::json_error(
+				StatusCode::BAD_REQUEST,
+				"INVALID_REQUEST",
+				"Invalid request payload.",
+				None,
+			)
+		})?
+	};
+	let response = state
+		.service
+		.rebuild_qdrant(RebuildQdrantRequest {
+			tenant_id: payload.tenant_id,
+			project_id: payload.project_id,
+			scope: payload.scope,
+			note_type: payload.note_type,
+			updated_after: payload.updated_after,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/qdrant/audit",
+	tag = "admin",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Qdrant payload isolation audit report.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn qdrant_audit(
+	State(state): State<AppState>,
+	payload: Result<Json<QdrantAuditBody>, JsonRejection>,
+) -> Result<Json<QdrantAuditReport>, ApiError> {
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_qdrant_audit(QdrantAuditRequest {
+			quarantine: payload.quarantine,
+			max_findings: payload.max_findings,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/index/verify",
+	tag = "admin",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Postgres and Qdrant index drift report.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_verify_index(
+	State(state): State<AppState>,
+	payload: Result<Json<IndexVerifyBody>, JsonRejection>,
+) -> Result<Json<IndexVerifyReport>, ApiError> {
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_verify_index(IndexVerifyRequest {
+			repair: payload.repair,
+			max_findings: payload.max_findings,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/qdrant/maintenance/runs",
+	tag = "admin",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Recorded maintenance runs, one per collection.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn qdrant_maintenance_run(
+	State(state): State<AppState>,
+	payload: Result<Json<QdrantMaintenanceRunBody>, JsonRejection>,
+) -> Result<Json<QdrantMaintenanceRunsResponse>, ApiError> {
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_qdrant_maintenance_run(QdrantMaintenanceRunRequest {
+			operation: payload.operation,
+			collection: payload.collection,
+			snapshot_name: payload.snapshot_name,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/qdrant/maintenance/runs",
+	tag = "admin",
+	params(
+		("operation" = Option<String>, Query, description = "Optional operation filter."),
+		("limit" = Option<u32>, Query, description = "Maximum runs to return."),
+	),
+	responses(
+		(status = 200, description = "Scheduled and manual maintenance runs, newest first.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn qdrant_maintenance_runs_list(
+	State(state): State<AppState>,
+	query: Result<Query<QdrantMaintenanceRunsListQuery>, QueryRejection>,
+) -> Result<Json<QdrantMaintenanceRunsResponse>, ApiError> {
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_qdrant_maintenance_runs_list(QdrantMaintenanceRunsListRequest {
+			operation: query.operation,
+			limit: query.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/storage/report",
+	tag = "admin",
+	responses(
+		(status = 200, description = "Storage usage report with suggested maintenance.", body = Value),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn storage_report(
+	State(state): State<AppState>,
+) -> Result<Json<StorageReportResponse>, ApiError> {
+	let response = state.service.storage_report().await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/memory/stats",
+	tag = "admin",
+	params(
+		("expiring_within_days" = Option<u32>, Query, description = "Window in days for notes nearing TTL expiry."),
+		("top_hits_limit" = Option<u32>, Query, description = "Maximum most-hit notes to return."),
+	),
+	responses(
+		(status = 200, description = "Memory usage summary for the caller's tenant and project.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn memory_stats(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<MemoryStatsQuery>, QueryRejection>,
+) -> Result<Json<MemoryStatsResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.memory_stats(MemoryStatsRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			expiring_within_days: query.expiring_within_days,
+			top_hits_limit: query.top_hits_limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/providers/health",
+	tag = "admin",
+	responses(
+		(status = 200, description = "Circuit-breaker health of the configured providers.", body = Value),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+	)
+)]
+pub(super) async fn provider_health(State(state): State<AppState>) -> Json<ProviderHealthResponse> {
+	Json(state.service.provider_health())
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/quota/usage",
+	tag = "admin",
+	responses(
+		(status = 200, description = "Usage of every per-tenant quota for the caller's tenant.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn quota_usage(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+) -> Result<Json<QuotaUsageResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response =
+		state.service.quota_usage(QuotaUsageRequest { tenant_id: ctx.tenant_id }).await?;
 
 	Ok(Json(response))
 }
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/reembed/runs",
+	tag = "admin",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Re-embed run after processing the requested batches.", body = Value),
+		(status = 400, description = "Invalid request or reembed is not configured.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_reembed_run(
+	State(state): State<AppState>,
+	payload: Result<Json<AdminReembedRunBody>, JsonRejection>,
+) -> Result<Json<AdminReembedRunItem>, ApiError> {
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_reembed_run(AdminReembedRunRequest { max_batches: payload.max_batches })
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/reembed/runs",
+	tag = "admin",
+	params(("limit" = Option<u32>, Query, description = "Maximum runs to return.")),
+	responses(
+		(status = 200, description = "Re-embed runs, newest first.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_reembed_runs_list(
+	State(state): State<AppState>,
+	query: Result<Query<AdminReembedRunsListQuery>, QueryRejection>,
+) -> Result<Json<AdminReembedRunsResponse>, ApiError> {
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_reembed_runs_list(AdminReembedRunsListRequest { limit: query.limit })
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/reembed/runs/{run_id}/activate",
+	tag = "admin",
+	params(("run_id" = Uuid, Path, description = "Re-embed run ID.")),
+	responses(
+		(status = 200, description = "Target embedding version is now active.", body = Value),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Re-embed run was not found.", body = ErrorBody),
+		(status = 409, description = "Run is not completed or notes still lack target vectors.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_reembed_activate(
+	State(state): State<AppState>,
+	Path(run_id): Path<Uuid>,
+) -> Result<Json<AdminReembedActivateResponse>, ApiError> {
+	let response =
+		state.service.admin_reembed_activate(AdminReembedActivateRequest { run_id }).await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/outbox/dead-letters",
+	tag = "admin",
+	params(("limit" = Option<u32>, Query, description = "Maximum dead-lettered jobs to return.")),
+	responses(
+		(status = 200, description = "Dead-lettered note-indexing jobs of the caller's tenant, most recent first.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_outbox_dead_letter_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<AdminOutboxDeadLetterListQuery>, QueryRejection>,
+) -> Result<Json<AdminOutboxDeadLetterListResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_outbox_dead_letter_list(AdminOutboxDeadLetterListRequest {
+			tenant_id: ctx.tenant_id,
+			limit: query.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/outbox/dead-letters/replay",
+	tag = "admin",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Requeued jobs and requested ids that are not dead-lettered.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_outbox_replay(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<AdminOutboxReplayBody>, JsonRejection>,
+) -> Result<Json<AdminOutboxReplayResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_outbox_replay(AdminOutboxReplayRequest {
+			tenant_id: ctx.tenant_id,
+			outbox_ids: payload.outbox_ids,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	put,
+	path = "/v2/admin/grants",
+	tag = "admin",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Active shared-read grant and whether it was created.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required or scope is not writable.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_grants_put(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<AdminGrantPutBody>, JsonRejection>,
+) -> Result<Json<AdminGrantResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.grants_put(AdminGrantPutRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: payload.project_id.unwrap_or(ctx.project_id),
+			actor_agent_id: ctx.agent_id,
+			scope: payload.scope,
+			space_owner_agent_id: payload.space_owner_agent_id,
+			grantee_kind: payload.grantee_kind,
+			grantee_agent_id: payload.grantee_agent_id,
+			reason: payload.reason,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/grants",
+	tag = "admin",
+	params(
+		("project_id" = Option<String>, Query, description = "Optional project filter."),
+		("scope" = Option<String>, Query, description = "Optional scope filter."),
+		("space_owner_agent_id" = Option<String>, Query, description = "Optional space owner filter."),
+		("include_revoked" = Option<bool>, Query, description = "Include revoked grants."),
+		("limit" = Option<u32>, Query, description = "Maximum grants to return."),
+	),
+	responses(
+		(status = 200, description = "Shared-read grants in the caller's tenant, newest first.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_grants_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<AdminGrantsListQuery>, QueryRejection>,
+) -> Result<Json<AdminGrantsListResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.grants_list(AdminGrantsListRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: query.project_id,
+			scope: query.scope,
+			space_owner_agent_id: query.space_owner_agent_id,
+			include_revoked: query.include_revoked,
+			limit: query.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/grants/{grant_id}/revoke",
+	tag = "admin",
+	params(("grant_id" = Uuid, Path, description = "Grant ID.")),
+	request_body = Value,
+	responses(
+		(status = 200, description = "Revoked shared-read grant and whether it changed.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Grant was not found in the caller's tenant.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_grants_revoke(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(grant_id): Path<Uuid>,
+	payload: Result<Json<AdminGrantRevokeBody>, JsonRejection>,
+) -> Result<Json<AdminGrantResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.grants_revoke(AdminGrantRevokeRequest {
+			tenant_id: ctx.tenant_id,
+			actor_agent_id: ctx.agent_id,
+			grant_id,
+			reason: payload.reason,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/audit-log",
+	tag = "admin",
+	params(
+		("operation" = Option<String>, Query, description = "Optional operation filter."),
+		("token_id" = Option<String>, Query, description = "Optional token filter."),
+		("actor" = Option<String>, Query, description = "Optional actor filter."),
+		("before" = Option<String>, Query, description = "Cursor returned as next_before by the previous page."),
+		("limit" = Option<u32>, Query, description = "Maximum entries to return."),
+	),
+	responses(
+		(status = 200, description = "Audit-log entries for the caller's tenant, newest first.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_audit_log_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<AdminAuditLogListQuery>, QueryRejection>,
+) -> Result<Json<AuditLogListResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.audit_log_list(AuditLogListRequest {
+			tenant_id: ctx.tenant_id,
+			operation: query.operation,
+			token_id: query.token_id,
+			actor: query.actor,
+			before: query.before,
+			limit: query.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/write-incidents",
+	tag = "admin",
+	params(
+		("agent_id" = Option<String>, Query, description = "Optional writing-agent filter."),
+		("limit" = Option<u32>, Query, description = "Maximum incidents to return."),
+	),
+	responses(
+		(status = 200, description = "Runaway write-loop incidents, newest activity first.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_write_incidents_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<AdminWriteIncidentsListQuery>, QueryRejection>,
+) -> Result<Json<AdminWriteIncidentsListResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_write_incidents_list(AdminWriteIncidentsListRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: query.agent_id,
+			limit: query.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/access/simulate",
+	tag = "admin",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Read access decision trace for one note.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_access_simulate(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<AdminAccessSimulateBody>, JsonRejection>,
+) -> Result<Json<AccessSimulateResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.access_simulate(AccessSimulateRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: payload.agent_id,
+			read_profile: payload.read_profile,
+			note_id: payload.note_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/export",
+	tag = "admin",
+	responses(
+		(status = 200, description = "Tenant export archive as newline-delimited JSON.", content_type = "application/x-ndjson", body = String),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_tenant_export(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+) -> Result<Response, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let mut exporter =
+		state.service.tenant_export(TenantExportRequest { tenant_id: ctx.tenant_id }).await?;
+	let (sender, receiver) = mpsc::channel::<Result<Bytes, io::Error>>(EXPORT_CHANNEL_BATCHES);
+
+	tokio::spawn(async move {
+		loop {
+			let chunk = match exporter.next_batch().await {
+				Ok(Some(lines)) => encode_export_lines(&lines),
+				Ok(None) => return,
+				Err(err) => {
+					tracing::error!(error = %err, "Tenant export failed.");
+
+					Err(io::Error::other(err.to_string()))
+				},
+			};
+			let failed = chunk.is_err();
+
+			if sender.send(chunk).await.is_err() || failed {
+				return;
+			}
+		}
+	});
+
+	Ok(([(CONTENT_TYPE, "application/x-ndjson")], Body::from_stream(ReceiverStream::new(receiver)))
+		.into_response())
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/restore",
+	tag = "admin",
+	request_body(content = String, content_type = "application/x-ndjson", description = "Tenant export archive from GET /v2/admin/export."),
+	responses(
+		(status = 200, description = "Archive restored and indexing enqueued.", body = Value),
+		(status = 400, description = "Invalid or incomplete archive.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 413, description = "Request body too large.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn admin_snapshot_restore(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	body: Body,
+) -> Result<Json<SnapshotRestoreResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let mut restorer =
+		state.service.restore_snapshot(SnapshotRestoreRequest { tenant_id: ctx.tenant_id }).await?;
+	let mut body = body;
+	let mut buffer: Vec<u8> = Vec::new();
+	let mut total_bytes = 0_u64;
+
+	while let Some(frame) = future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+		let frame = frame.map_err(|err| {
+			tracing::warn!(error = %err, "Failed to read restore body.");
+
+			routes::json_error(
+				StatusCode::BAD_REQUEST,
+				"INVALID_REQUEST",
+				"Failed to read request body.",
+				None,
+			)
+		})?;
+		let Ok(data) = frame.into_data() else {
+			continue;
+		};
+
+		total_bytes += data.len() as u64;
+
+		if total_bytes > MAX_RESTORE_BYTES {
+			return Err(routes::json_error(
+				StatusCode::PAYLOAD_TOO_LARGE,
+				"INVALID_REQUEST",
+				"Restore archive is too large.",
+				None,
+			));
+		}
+
+		buffer.extend_from_slice(&data);
+
+		while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+			let line: Vec<u8> = buffer.drain(..=end).collect();
+
+			push_restore_line(&mut restorer, &line).await?;
+		}
+	}
+
+	push_restore_line(&mut restorer, &buffer).await?;
+
+	let response = restorer.finish().await?;
+
+	Ok(Json(response))
+}
+
+fn encode_export_lines(lines: &[TenantExportLine]) -> Result<Bytes, io::Error> {
+	let mut buffer = Vec::new();
+
+	for line in lines {
+		serde_json::to_writer(&mut buffer, line).map_err(io::Error::other)?;
+		buffer.push(b'\n');
+	}
+
+	Ok(Bytes::from(buffer))
+}
+
+async fn push_restore_line(restorer: &mut SnapshotRestorer, line: &[u8]) -> Result<(), ApiError> {
+	let line = std::str::from_utf8(line).map_err(|_| {
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Restore archive must be UTF-8.",
+			None,
+		)
+	})?;
+
+	restorer.push_line(line).await?;
+
+	Ok(())
+}
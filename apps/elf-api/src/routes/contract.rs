@@ -12,7 +12,16 @@ use crate::routes::{
 		__path_admin_note_correction_apply, __path_admin_note_history_get,
 		__path_admin_note_provenance_get,
 	},
-	admin_ops::__path_rebuild_qdrant,
+	admin_ops::{
+		__path_admin_access_simulate, __path_admin_audit_log_list, __path_admin_grants_list,
+		__path_admin_grants_put, __path_admin_grants_revoke, __path_admin_outbox_dead_letter_list,
+		__path_admin_outbox_replay, __path_admin_reembed_activate, __path_admin_reembed_run,
+		__path_admin_reembed_runs_list, __path_admin_snapshot_restore, __path_admin_tenant_export,
+		__path_admin_verify_index, __path_admin_write_incidents_list, __path_memory_stats,
+		__path_provider_health, __path_qdrant_audit, __path_qdrant_maintenance_run,
+		__path_qdrant_maintenance_runs_list, __path_quota_usage, __path_rebuild_qdrant,
+		__path_storage_report,
+	},
 	consolidation::{
 		__path_consolidation_proposal_get, __path_consolidation_proposal_review,
 		__path_consolidation_proposals_list, __path_consolidation_run_create,
@@ -23,18 +32,21 @@ use crate::routes::{
 		__path_admin_core_block_upsert, __path_core_blocks_get, __path_entity_memory_get,
 	},
 	docs::{
-		__path_admin_docs_excerpts_get, __path_admin_docs_get, __path_admin_docs_search_l0,
-		__path_docs_delete, __path_docs_excerpts_get, __path_docs_get, __path_docs_put,
-		__path_docs_search_l0,
+		__path_admin_docs_excerpts_get, __path_admin_docs_get, __path_admin_docs_search,
+		__path_admin_docs_search_l0, __path_docs_delete, __path_docs_excerpts_get, __path_docs_get,
+		__path_docs_put, __path_docs_search, __path_docs_search_l0,
 	},
 	dreaming::__path_dreaming_review_queue,
 	events::__path_events_ingest,
 	graph::{
+		__path_admin_graph_entities_merge, __path_admin_graph_entity_kind_promote,
+		__path_admin_graph_entity_kinds_list, __path_admin_graph_entity_split,
 		__path_admin_graph_predicate_alias_add, __path_admin_graph_predicate_aliases_list,
-		__path_admin_graph_predicate_patch, __path_admin_graph_predicates_list, __path_graph_query,
-		__path_graph_report,
+		__path_admin_graph_predicate_patch, __path_admin_graph_predicate_promote,
+		__path_admin_graph_predicates_list, __path_graph_facts_as_of, __path_graph_neighborhood,
+		__path_graph_query, __path_graph_report,
 	},
-	health::__path_health,
+	health::{__path_health, __path_ready},
 	ingestion_profiles::{
 		__path_admin_ingestion_profile_create, __path_admin_ingestion_profile_default_get,
 		__path_admin_ingestion_profile_default_set, __path_admin_ingestion_profile_get,
@@ -46,18 +58,35 @@ use crate::routes::{
 		__path_knowledge_pages_watch_rebuild,
 	},
 	notes::{
-		__path_notes_delete, __path_notes_get, __path_notes_ingest, __path_notes_list,
-		__path_notes_patch, __path_notes_publish, __path_notes_unpublish,
+		__path_notes_bulk_import, __path_notes_cite, __path_notes_delete,
+		__path_notes_delete_batch, __path_notes_get, __path_notes_history, __path_notes_ingest,
+		__path_notes_list, __path_notes_merge, __path_notes_patch, __path_notes_pin,
+		__path_notes_publish, __path_notes_relation_create, __path_notes_relation_delete,
+		__path_notes_relations_list, __path_notes_source_refs_resolve, __path_notes_subscribe,
+		__path_notes_trash_list, __path_notes_undelete, __path_notes_unpin, __path_notes_unpublish,
+		__path_notes_update_batch,
 	},
-	recall::__path_recall_debug_panel,
+	org_stats::{__path_memory_timeline, __path_org_memory_stats},
+	recall::{__path_recall_context, __path_recall_debug_panel},
 	search::{
-		__path_searches_create, __path_searches_get, __path_searches_notes, __path_searches_raw,
-		__path_searches_timeline,
+		__path_admin_search_shadow_report, __path_rank_documents, __path_searches_answer,
+		__path_searches_batch, __path_searches_create, __path_searches_feedback,
+		__path_searches_get, __path_searches_notes, __path_searches_raw, __path_searches_scoped,
+		__path_searches_stream, __path_searches_timeline, __path_searches_with_profile,
+	},
+	search_profiles::{
+		__path_admin_search_profile_delete, __path_admin_search_profile_get,
+		__path_admin_search_profile_put, __path_admin_search_profiles_list,
 	},
+	sessions::{__path_session_append, __path_session_get, __path_session_summarize},
 	sharing::{__path_space_grant_revoke, __path_space_grant_upsert, __path_space_grants_list},
+	standing_queries::{
+		__path_standing_queries_list, __path_standing_query_create, __path_standing_query_delete,
+		__path_standing_query_get, __path_standing_query_matches,
+	},
 	trace::{
-		__path_trace_bundle_get, __path_trace_get, __path_trace_item_get, __path_trace_recent_list,
-		__path_trace_trajectory_get,
+		__path_trace_artifact_get, __path_trace_bundle_get, __path_trace_get,
+		__path_trace_item_get, __path_trace_recent_list, __path_trace_trajectory_get,
 	},
 	types::{
 		AdminIngestionProfileDefaultResponseV2, AdminIngestionProfileDefaultSetBody, ErrorBody,
@@ -83,11 +112,14 @@ pub const SCALAR_DOCS_PATH: &str = "/docs";
 	),
 	paths(
 		health,
+		ready,
 		notes_ingest,
+		notes_bulk_import,
 		events_ingest,
 		docs_put,
 		docs_get,
 		docs_delete,
+		docs_search,
 		docs_search_l0,
 		docs_excerpts_get,
 		core_blocks_get,
@@ -96,23 +128,57 @@ pub const SCALAR_DOCS_PATH: &str = "/docs";
 		admin_core_block_attach,
 		admin_core_block_detach,
 		admin_docs_get,
+		admin_docs_search,
 		admin_docs_search_l0,
 		admin_docs_excerpts_get,
 		graph_query,
 		graph_report,
+		graph_neighborhood,
+		graph_facts_as_of,
+		org_memory_stats,
+		memory_timeline,
 		searches_create,
+		searches_answer,
+		searches_batch,
+		searches_scoped,
+		searches_stream,
+		searches_with_profile,
 		searches_get,
 		searches_timeline,
 		searches_notes,
+		searches_feedback,
+		rank_documents,
 		notes_list,
 		notes_get,
+		notes_history,
+		notes_cite,
+		notes_source_refs_resolve,
+		notes_subscribe,
 		notes_patch,
 		notes_delete,
+		notes_update_batch,
+		notes_delete_batch,
+		notes_trash_list,
+		notes_undelete,
+		notes_merge,
+		notes_relations_list,
+		notes_relation_create,
+		notes_relation_delete,
+		notes_pin,
+		notes_unpin,
 		notes_publish,
 		notes_unpublish,
 		work_journal_entry_create,
 		work_journal_entry_get,
 		work_journal_session_readback,
+		session_append,
+		session_get,
+		session_summarize,
+		standing_query_create,
+		standing_queries_list,
+		standing_query_get,
+		standing_query_delete,
+		standing_query_matches,
 		space_grants_list,
 		space_grant_upsert,
 		space_grant_revoke,
@@ -122,6 +188,10 @@ pub const SCALAR_DOCS_PATH: &str = "/docs";
 		admin_ingestion_profile_versions_list,
 		admin_ingestion_profile_default_get,
 		admin_ingestion_profile_default_set,
+		admin_search_profiles_list,
+		admin_search_profile_get,
+		admin_search_profile_put,
+		admin_search_profile_delete,
 		consolidation_run_create,
 		consolidation_runs_list,
 		consolidation_run_get,
@@ -129,6 +199,7 @@ pub const SCALAR_DOCS_PATH: &str = "/docs";
 		consolidation_proposal_get,
 		consolidation_proposal_review,
 		dreaming_review_queue,
+		recall_context,
 		recall_debug_panel,
 		knowledge_page_rebuild,
 		knowledge_pages_watch_rebuild,
@@ -137,16 +208,44 @@ pub const SCALAR_DOCS_PATH: &str = "/docs";
 		knowledge_page_get,
 		knowledge_page_lint,
 		rebuild_qdrant,
+		qdrant_audit,
+		admin_verify_index,
+		qdrant_maintenance_run,
+		qdrant_maintenance_runs_list,
+		storage_report,
+		memory_stats,
+		provider_health,
+		quota_usage,
+		admin_grants_put,
+		admin_grants_list,
+		admin_grants_revoke,
+		admin_reembed_run,
+		admin_reembed_runs_list,
+		admin_reembed_activate,
+		admin_outbox_dead_letter_list,
+		admin_outbox_replay,
+		admin_tenant_export,
+		admin_snapshot_restore,
+		admin_write_incidents_list,
+		admin_audit_log_list,
+		admin_access_simulate,
 		searches_raw,
+		admin_search_shadow_report,
 		trace_recent_list,
 		trace_get,
 		trace_bundle_get,
+		trace_artifact_get,
 		trace_trajectory_get,
 		trace_item_get,
 		admin_graph_predicates_list,
 		admin_graph_predicate_patch,
 		admin_graph_predicate_alias_add,
 		admin_graph_predicate_aliases_list,
+		admin_graph_predicate_promote,
+		admin_graph_entities_merge,
+		admin_graph_entity_split,
+		admin_graph_entity_kinds_list,
+		admin_graph_entity_kind_promote,
 		admin_note_provenance_get,
 		admin_note_history_get,
 		admin_note_correction_apply,
@@ -157,7 +256,7 @@ pub const SCALAR_DOCS_PATH: &str = "/docs";
 		ErrorBody,
 	)),
 	tags(
-		(name = "health", description = "Health and process liveness."),
+		(name = "health", description = "Health, process liveness, and startup readiness."),
 		(name = "notes", description = "Memory note ingestion, listing, mutation, and sharing."),
 		(name = "events", description = "Event ingestion through the extractor pipeline."),
 		(name = "docs", description = "Document extension ingestion, search, and excerpt retrieval."),
@@ -168,6 +267,8 @@ pub const SCALAR_DOCS_PATH: &str = "/docs";
 		(name = "recall", description = "Cross-layer recall and debug readback."),
 		(name = "knowledge", description = "Derived knowledge page rebuild and lint readback."),
 		(name = "work_journal", description = "Source-adjacent Work Journal capture and session readback."),
+		(name = "sessions", description = "Short-term session memory and promotion into notes."),
+		(name = "standing_queries", description = "Standing queries evaluated against newly indexed notes."),
 		(name = "admin", description = "Local admin and operator inspection routes."),
 	)
 )]
@@ -1,5 +1,6 @@
 mod excerpts;
 mod read;
+mod search;
 mod search_l0;
 mod write;
 
@@ -9,6 +10,7 @@ pub(super) use self::{
 		docs_excerpts_get,
 	},
 	read::{__path_admin_docs_get, __path_docs_get, admin_docs_get, docs_get},
+	search::{__path_admin_docs_search, __path_docs_search, admin_docs_search, docs_search},
 	search_l0::{
 		__path_admin_docs_search_l0, __path_docs_search_l0, admin_docs_search_l0, docs_search_l0,
 	},
@@ -0,0 +1,83 @@
+use crate::routes::{
+	self, ApiError, AppState, DocsSearchBody, DocsSearchRequest, DocsSearchResponse, ErrorBody,
+	HeaderMap, Json, JsonRejection, State, StatusCode, docs::search_l0,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/docs/search",
+	tag = "docs",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Cross-document search results with bounded excerpts.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn docs_search(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<DocsSearchBody>, JsonRejection>,
+) -> Result<Json<DocsSearchResponse>, ApiError> {
+	docs_search_inner(state, headers, payload).await
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/docs/search",
+	tag = "admin",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Cross-document search results through the admin mirror.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_docs_search(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<DocsSearchBody>, JsonRejection>,
+) -> Result<Json<DocsSearchResponse>, ApiError> {
+	docs_search_inner(state, headers, payload).await
+}
+
+async fn docs_search_inner(
+	state: AppState,
+	headers: HeaderMap,
+	payload: Result<Json<DocsSearchBody>, JsonRejection>,
+) -> Result<Json<DocsSearchResponse>, ApiError> {
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let level = payload.level.as_deref().map(str::trim).filter(|level| !level.is_empty());
+
+	if let Some(level) = level
+		&& !matches!(level, "L0" | "L1" | "L2")
+	{
+		return Err(routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"level must be one of: L0|L1|L2.",
+			Some(vec!["$.level".to_string()]),
+		));
+	}
+
+	let level = level.map(ToString::to_string);
+	let search = search_l0::docs_search_l0_request(&headers, payload.search)?;
+	let response = state.service.docs_search(DocsSearchRequest { search, level }).await?;
+
+	Ok(Json(response))
+}
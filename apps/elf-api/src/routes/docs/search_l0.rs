@@ -48,14 +48,12 @@ pub(in crate::routes) async fn admin_docs_search_l0(
 	docs_search_l0_inner(state, headers, payload).await
 }
 
-pub(super) async fn docs_search_l0_inner(
+async fn docs_search_l0_inner(
 	state: AppState,
 	headers: HeaderMap,
 	payload: Result<Json<DocsSearchL0Body>, JsonRejection>,
 ) -> Result<Json<DocsSearchL0Response>, ApiError> {
-	let ctx = RequestContext::from_headers(&headers)?;
-	let read_profile = routes::required_read_profile(&headers)?;
-	let Json(mut payload) = payload.map_err(|err| {
+	let Json(payload) = payload.map_err(|err| {
 		tracing::warn!(error = %err, "Invalid request payload.");
 
 		routes::json_error(
@@ -65,6 +63,18 @@ pub(super) async fn docs_search_l0_inner(
 			None,
 		)
 	})?;
+	let response = state.service.docs_search_l0(docs_search_l0_request(&headers, payload)?).await?;
+
+	Ok(Json(response))
+}
+
+/// Validates a docs search body and binds it to the caller's request context.
+pub(super) fn docs_search_l0_request(
+	headers: &HeaderMap,
+	mut payload: DocsSearchL0Body,
+) -> Result<DocsSearchL0Request, ApiError> {
+	let ctx = RequestContext::from_headers(headers)?;
+	let read_profile = routes::required_read_profile(headers)?;
 	let status = payload.status.as_deref().map(str::trim).filter(|status| !status.is_empty());
 
 	if let Some(status) = status {
@@ -119,31 +129,26 @@ pub(super) async fn docs_search_l0_inner(
 		));
 	}
 
-	let response = state
-		.service
-		.docs_search_l0(DocsSearchL0Request {
-			tenant_id: ctx.tenant_id,
-			project_id: ctx.project_id,
-			caller_agent_id: ctx.agent_id,
-			read_profile,
-			query: payload.query,
-			scope: payload.scope,
-			status: payload.status,
-			doc_type: payload.doc_type.map(|doc_type| doc_type.as_str().to_string()),
-			sparse_mode: payload.sparse_mode,
-			domain: payload.domain,
-			repo: payload.repo,
-			agent_id: payload.agent_id,
-			thread_id: payload.thread_id,
-			updated_after: payload.updated_after,
-			updated_before: payload.updated_before,
-			ts_gte: payload.ts_gte,
-			ts_lte: payload.ts_lte,
-			top_k: payload.top_k,
-			candidate_k: payload.candidate_k,
-			explain: payload.explain,
-		})
-		.await?;
-
-	Ok(Json(response))
+	Ok(DocsSearchL0Request {
+		tenant_id: ctx.tenant_id,
+		project_id: ctx.project_id,
+		caller_agent_id: ctx.agent_id,
+		read_profile,
+		query: payload.query,
+		scope: payload.scope,
+		status: payload.status,
+		doc_type: payload.doc_type.map(|doc_type| doc_type.as_str().to_string()),
+		sparse_mode: payload.sparse_mode,
+		domain: payload.domain,
+		repo: payload.repo,
+		agent_id: payload.agent_id,
+		thread_id: payload.thread_id,
+		updated_after: payload.updated_after,
+		updated_before: payload.updated_before,
+		ts_gte: payload.ts_gte,
+		ts_lte: payload.ts_lte,
+		top_k: payload.top_k,
+		candidate_k: payload.candidate_k,
+		explain: payload.explain,
+	})
 }
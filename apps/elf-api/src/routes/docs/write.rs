@@ -37,6 +37,8 @@ pub(in crate::routes) async fn docs_put(
 	})?;
 	let role = role.map(|Extension(role)| role);
 
+	routes::require_writer_role(role)?;
+
 	if payload.scope.trim() == "org_shared" {
 		routes::require_admin_for_org_shared_writes(
 			state.service.cfg.security.auth_mode.as_str(),
@@ -79,9 +81,13 @@ pub(in crate::routes) async fn docs_put(
 pub(in crate::routes) async fn docs_delete(
 	State(state): State<AppState>,
 	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
 	Path(doc_id): Path<Uuid>,
 ) -> Result<Json<DocsDeleteResponse>, ApiError> {
 	let ctx = RequestContext::from_headers(&headers)?;
+
+	routes::require_writer_role(role.map(|Extension(role)| role))?;
+
 	let response = state
 		.service
 		.docs_delete(DocsDeleteRequest {
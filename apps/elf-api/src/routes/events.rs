@@ -69,9 +69,11 @@ pub(super) async fn events_ingest(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			role,
 			scope: payload.scope,
 			dry_run: payload.dry_run,
 			ingestion_profile: payload.ingestion_profile,
+			locale: payload.locale,
 			messages: payload.messages,
 		})
 		.await?;
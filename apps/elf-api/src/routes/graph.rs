@@ -1,12 +1,26 @@
+mod entities;
+mod entity_kinds;
 mod predicates;
 mod query;
 
 pub(super) use self::{
+	entities::{
+		__path_admin_graph_entities_merge, __path_admin_graph_entity_split,
+		admin_graph_entities_merge, admin_graph_entity_split,
+	},
+	entity_kinds::{
+		__path_admin_graph_entity_kind_promote, __path_admin_graph_entity_kinds_list,
+		admin_graph_entity_kind_promote, admin_graph_entity_kinds_list,
+	},
 	predicates::{
 		__path_admin_graph_predicate_alias_add, __path_admin_graph_predicate_aliases_list,
-		__path_admin_graph_predicate_patch, __path_admin_graph_predicates_list,
-		admin_graph_predicate_alias_add, admin_graph_predicate_aliases_list,
-		admin_graph_predicate_patch, admin_graph_predicates_list,
+		__path_admin_graph_predicate_patch, __path_admin_graph_predicate_promote,
+		__path_admin_graph_predicates_list, admin_graph_predicate_alias_add,
+		admin_graph_predicate_aliases_list, admin_graph_predicate_patch,
+		admin_graph_predicate_promote, admin_graph_predicates_list,
+	},
+	query::{
+		__path_graph_facts_as_of, __path_graph_neighborhood, __path_graph_query,
+		__path_graph_report, graph_facts_as_of, graph_neighborhood, graph_query, graph_report,
 	},
-	query::{__path_graph_query, __path_graph_report, graph_query, graph_report},
 };
@@ -0,0 +1,102 @@
+use crate::routes::{
+	self, AdminGraphEntitiesMergeBody, AdminGraphEntitiesMergeRequest,
+	AdminGraphEntitiesMergeResponse, AdminGraphEntitySplitBody, AdminGraphEntitySplitRequest,
+	AdminGraphEntitySplitResponse, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection,
+	Path, RequestContext, State, StatusCode, Uuid,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/graph/entities/merge",
+	tag = "graph",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Graph entities were merged into the winner.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Entity was not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_graph_entities_merge(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<AdminGraphEntitiesMergeBody>, JsonRejection>,
+) -> Result<Json<AdminGraphEntitiesMergeResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_graph_entities_merge(AdminGraphEntitiesMergeRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			winner_entity_id: payload.winner_entity_id,
+			loser_entity_ids: payload.loser_entity_ids,
+			reason: payload.reason,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/graph/entities/{entity_id}/split",
+	tag = "graph",
+	params(("entity_id" = Uuid, Path, description = "Source entity ID.")),
+	request_body = Value,
+	responses(
+		(status = 200, description = "Aliases and facts were moved to a new graph entity.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Entity was not found.", body = ErrorBody),
+		(status = 409, description = "New canonical surface already exists.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_graph_entity_split(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(entity_id): Path<Uuid>,
+	payload: Result<Json<AdminGraphEntitySplitBody>, JsonRejection>,
+) -> Result<Json<AdminGraphEntitySplitResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_graph_entity_split(AdminGraphEntitySplitRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			entity_id,
+			canonical: payload.canonical,
+			kind: payload.kind,
+			aliases: payload.aliases.unwrap_or_default(),
+			fact_ids: payload.fact_ids.unwrap_or_default(),
+			reason: payload.reason,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
@@ -0,0 +1,82 @@
+use crate::routes::{
+	self, AdminGraphEntityKindPromoteRequest, AdminGraphEntityKindResponse,
+	AdminGraphEntityKindsListQuery, AdminGraphEntityKindsListRequest,
+	AdminGraphEntityKindsListResponse, ApiError, AppState, ErrorBody, HeaderMap, Json, Path, Query,
+	QueryRejection, RequestContext, State, StatusCode, Uuid,
+};
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/graph/entity-kinds",
+	tag = "graph",
+	params(("scope" = Option<String>, Query, description = "Entity kind scope filter.")),
+	responses(
+		(status = 200, description = "Graph entity kinds.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_graph_entity_kinds_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<AdminGraphEntityKindsListQuery>, QueryRejection>,
+) -> Result<Json<AdminGraphEntityKindsListResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.admin_graph_entity_kinds_list(AdminGraphEntityKindsListRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			scope: query.scope,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/graph/entity-kinds/{kind_id}/promote",
+	tag = "graph",
+	params(("kind_id" = Uuid, Path, description = "Entity kind ID.")),
+	responses(
+		(status = 200, description = "Graph entity kind was promoted to tenant scope.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Entity kind was not found.", body = ErrorBody),
+		(status = 409, description = "Entity kind promotion conflicted.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_graph_entity_kind_promote(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(kind_id): Path<Uuid>,
+) -> Result<Json<AdminGraphEntityKindResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.admin_graph_entity_kind_promote(AdminGraphEntityKindPromoteRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			kind_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
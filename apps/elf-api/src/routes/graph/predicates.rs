@@ -1,10 +1,11 @@
 use crate::routes::{
 	self, AdminGraphPredicateAliasAddBody, AdminGraphPredicateAliasAddRequest,
 	AdminGraphPredicateAliasesListRequest, AdminGraphPredicateAliasesResponse,
-	AdminGraphPredicatePatchBody, AdminGraphPredicatePatchRequest, AdminGraphPredicateResponse,
-	AdminGraphPredicatesListQuery, AdminGraphPredicatesListRequest,
-	AdminGraphPredicatesListResponse, ApiError, AppState, ErrorBody, HeaderMap, Json,
-	JsonRejection, Path, Query, QueryRejection, RequestContext, State, StatusCode, Uuid,
+	AdminGraphPredicatePatchBody, AdminGraphPredicatePatchRequest,
+	AdminGraphPredicatePromoteRequest, AdminGraphPredicateResponse, AdminGraphPredicatesListQuery,
+	AdminGraphPredicatesListRequest, AdminGraphPredicatesListResponse, ApiError, AppState,
+	ErrorBody, HeaderMap, Json, JsonRejection, Path, Query, QueryRejection, RequestContext, State,
+	StatusCode, Uuid,
 };
 
 #[utoipa::path(
@@ -182,3 +183,37 @@ pub(in crate::routes) async fn admin_graph_predicate_aliases_list(
 
 	Ok(Json(response))
 }
+
+#[utoipa::path(
+	post,
+	path = "/v2/admin/graph/predicates/{predicate_id}/promote",
+	tag = "graph",
+	params(("predicate_id" = Uuid, Path, description = "Predicate ID.")),
+	responses(
+		(status = 200, description = "Graph predicate was promoted to tenant scope.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Predicate was not found.", body = ErrorBody),
+		(status = 409, description = "Predicate promotion conflicted.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_graph_predicate_promote(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(predicate_id): Path<Uuid>,
+) -> Result<Json<AdminGraphPredicateResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.admin_graph_predicate_promote(AdminGraphPredicatePromoteRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			predicate_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
@@ -1,5 +1,7 @@
 use crate::routes::{
-	self, ApiError, AppState, ErrorBody, GraphQueryBody, GraphQueryRequest, GraphQueryResponse,
+	self, ApiError, AppState, ErrorBody, GraphFactsAsOfBody, GraphFactsAsOfRequest,
+	GraphFactsAsOfResponse, GraphNeighborhoodBody, GraphNeighborhoodRequest,
+	GraphNeighborhoodResponse, GraphQueryBody, GraphQueryRequest, GraphQueryResponse,
 	GraphReportBody, GraphReportRequest, GraphReportResponse, HeaderMap, Json, JsonRejection,
 	RequestContext, State, StatusCode,
 };
@@ -105,3 +107,112 @@ pub(in crate::routes) async fn graph_report(
 
 	Ok(Json(response))
 }
+
+#[utoipa::path(
+	post,
+	path = "/v2/graph/neighborhood",
+	tag = "graph",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Bounded entity neighborhood as subject/predicate/object triples.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 404, description = "Entity or predicate not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn graph_neighborhood(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<GraphNeighborhoodBody>, JsonRejection>,
+) -> Result<Json<GraphNeighborhoodResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let as_of = routes::parse_optional_rfc3339(payload.as_of.as_ref(), "$.as_of")?;
+	let response = state
+		.service
+		.graph_neighborhood(GraphNeighborhoodRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			read_profile,
+			entity: payload.entity,
+			depth: payload.depth,
+			predicates: payload.predicates,
+			scopes: payload.scopes,
+			as_of,
+			limit: payload.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/graph/facts/as-of",
+	tag = "graph",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Graph facts about an entity as they stood at a point in time, including superseded facts.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 404, description = "Entity or predicate not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn graph_facts_as_of(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<GraphFactsAsOfBody>, JsonRejection>,
+) -> Result<Json<GraphFactsAsOfResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let Some(as_of) = routes::parse_optional_rfc3339(payload.as_of.as_ref(), "$.as_of")? else {
+		return Err(routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"$.as_of is required.",
+			Some(vec!["$.as_of".to_string()]),
+		));
+	};
+	let response = state
+		.service
+		.graph_facts_as_of(GraphFactsAsOfRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			read_profile,
+			entity: payload.entity,
+			as_of,
+			predicate: payload.predicate,
+			scopes: payload.scopes,
+			include_superseded: payload.include_superseded,
+			limit: payload.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
@@ -1,4 +1,8 @@
-use crate::routes::StatusCode;
+use crate::{
+	routes::{IntoResponse, Json, Response, State, StatusCode},
+	state::AppState,
+	warmup::Readiness,
+};
 
 #[utoipa::path(
 	get,
@@ -9,3 +13,22 @@ use crate::routes::StatusCode;
 pub(super) async fn health() -> StatusCode {
 	StatusCode::OK
 }
+
+#[utoipa::path(
+	get,
+	path = "/ready",
+	tag = "health",
+	responses(
+		(status = 200, description = "Startup warm-up finished or is disabled."),
+		(status = 503, description = "Startup warm-up is still running or failed.")
+	)
+)]
+pub(super) async fn ready(State(state): State<AppState>) -> Response {
+	let readiness = state.readiness.get();
+	let status = match readiness {
+		Readiness::Ready { .. } => StatusCode::OK,
+		Readiness::Warming | Readiness::Failed { .. } => StatusCode::SERVICE_UNAVAILABLE,
+	};
+
+	(status, Json(readiness)).into_response()
+}
@@ -1,11 +1,32 @@
+mod bulk_import;
 mod ingest;
+mod pin;
 mod publish;
 mod read;
+mod relations;
+mod subscribe;
+mod trash;
 mod write;
 
 pub(super) use self::{
+	bulk_import::{__path_notes_bulk_import, notes_bulk_import},
 	ingest::{__path_notes_ingest, notes_ingest},
+	pin::{__path_notes_pin, __path_notes_unpin, notes_pin, notes_unpin},
 	publish::{__path_notes_publish, __path_notes_unpublish, notes_publish, notes_unpublish},
-	read::{__path_notes_get, __path_notes_list, notes_get, notes_list},
-	write::{__path_notes_delete, __path_notes_patch, notes_delete, notes_patch},
+	read::{
+		__path_notes_cite, __path_notes_get, __path_notes_history, __path_notes_list,
+		__path_notes_source_refs_resolve, notes_cite, notes_get, notes_history, notes_list,
+		notes_source_refs_resolve,
+	},
+	relations::{
+		__path_notes_relation_create, __path_notes_relation_delete, __path_notes_relations_list,
+		notes_relation_create, notes_relation_delete, notes_relations_list,
+	},
+	subscribe::{__path_notes_subscribe, notes_subscribe},
+	trash::{__path_notes_trash_list, __path_notes_undelete, notes_trash_list, notes_undelete},
+	write::{
+		__path_notes_delete, __path_notes_delete_batch, __path_notes_merge, __path_notes_patch,
+		__path_notes_update_batch, notes_delete, notes_delete_batch, notes_merge, notes_patch,
+		notes_update_batch,
+	},
 };
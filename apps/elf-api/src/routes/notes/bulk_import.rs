@@ -0,0 +1,124 @@
+use std::{future, pin::Pin};
+
+use axum::body::HttpBody;
+
+use crate::routes::{
+	self, ApiError, AppState, Body, BulkImportRequest, BulkImportResponse, BulkImporter, ErrorBody,
+	Extension, HeaderMap, Json, MAX_BULK_IMPORT_BYTES, NotesBulkImportQuery, Query, QueryRejection,
+	RequestContext, SecurityAuthRole, State, StatusCode,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/bulk-import",
+	tag = "notes",
+	params(
+		("scope" = String, Query, description = "Scope applied to every imported note."),
+	),
+	request_body(content = String, content_type = "application/x-ndjson", description = "One note object per line, as in POST /v2/notes/ingest."),
+	responses(
+		(status = 200, description = "Import finished; per-line results are returned.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 413, description = "Request body too large.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_bulk_import(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	query: Result<Query<NotesBulkImportQuery>, QueryRejection>,
+	body: Body,
+) -> Result<Json<BulkImportResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.",
+			None,
+		)
+	})?;
+	let role = role.map(|Extension(role)| role);
+
+	if query.scope.trim() == "org_shared" {
+		routes::require_admin_for_org_shared_writes(
+			state.service.cfg.security.auth_mode.as_str(),
+			role,
+		)?;
+	}
+
+	let mut importer = state
+		.service
+		.bulk_import(BulkImportRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role,
+			scope: query.scope,
+		})
+		.await?;
+	let mut body = body;
+	let mut buffer: Vec<u8> = Vec::new();
+	let mut total_bytes = 0_usize;
+
+	while let Some(frame) = future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+		let frame = frame.map_err(|err| {
+			tracing::warn!(error = %err, "Failed to read bulk import body.");
+
+			routes::json_error(
+				StatusCode::BAD_REQUEST,
+				"INVALID_REQUEST",
+				"Failed to read request body.",
+				None,
+			)
+		})?;
+		let Ok(data) = frame.into_data() else {
+			continue;
+		};
+
+		total_bytes += data.len();
+
+		if total_bytes > MAX_BULK_IMPORT_BYTES {
+			return Err(routes::json_error(
+				StatusCode::PAYLOAD_TOO_LARGE,
+				"INVALID_REQUEST",
+				"Bulk import body is too large.",
+				None,
+			));
+		}
+
+		buffer.extend_from_slice(&data);
+
+		while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+			let line: Vec<u8> = buffer.drain(..=end).collect();
+
+			push_line(&mut importer, &line).await?;
+		}
+	}
+
+	push_line(&mut importer, &buffer).await?;
+
+	let response = importer.finish().await?;
+
+	Ok(Json(response))
+}
+
+async fn push_line(importer: &mut BulkImporter<'_>, line: &[u8]) -> Result<(), ApiError> {
+	let line = std::str::from_utf8(line).map_err(|_| {
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Bulk import body must be UTF-8.",
+			None,
+		)
+	})?;
+
+	importer.push_line(line).await?;
+
+	Ok(())
+}
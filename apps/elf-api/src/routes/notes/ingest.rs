@@ -43,6 +43,7 @@ pub(in crate::routes) async fn notes_ingest(
 			role,
 		)?;
 	}
+
 	if payload.notes.len() > MAX_NOTES_PER_INGEST {
 		return Err(routes::json_error(
 			StatusCode::BAD_REQUEST,
@@ -58,6 +59,7 @@ pub(in crate::routes) async fn notes_ingest(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			role,
 			scope: payload.scope,
 			notes: payload.notes,
 		})
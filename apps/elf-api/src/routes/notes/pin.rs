@@ -0,0 +1,69 @@
+use crate::routes::{
+	ApiError, AppState, ErrorBody, Extension, HeaderMap, Json, Path, PinNoteRequest,
+	PinNoteResponse, RequestContext, SecurityAuthRole, State, Uuid,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/{note_id}/pin",
+	tag = "notes",
+	params(("note_id" = Uuid, Path, description = "Note ID.")),
+	responses(
+		(status = 200, description = "Note is pinned.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 409, description = "Note is not active.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_pin(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	Path(note_id): Path<Uuid>,
+) -> Result<Json<PinNoteResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state.service.pin_note(pin_request(ctx, role, note_id)).await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/{note_id}/unpin",
+	tag = "notes",
+	params(("note_id" = Uuid, Path, description = "Note ID.")),
+	responses(
+		(status = 200, description = "Note is unpinned.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_unpin(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	Path(note_id): Path<Uuid>,
+) -> Result<Json<PinNoteResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state.service.unpin_note(pin_request(ctx, role, note_id)).await?;
+
+	Ok(Json(response))
+}
+
+fn pin_request(
+	ctx: RequestContext,
+	role: Option<Extension<SecurityAuthRole>>,
+	note_id: Uuid,
+) -> PinNoteRequest {
+	PinNoteRequest {
+		tenant_id: ctx.tenant_id,
+		project_id: ctx.project_id,
+		agent_id: ctx.agent_id,
+		role: role.map(|Extension(role)| role),
+		note_id,
+	}
+}
@@ -91,6 +91,7 @@ pub(super) async fn notes_publish_inner(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			role,
 			note_id,
 			scope,
 		})
@@ -135,6 +136,7 @@ pub(super) async fn notes_unpublish_inner(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			role,
 			note_id,
 		})
 		.await?;
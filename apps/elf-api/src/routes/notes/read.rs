@@ -1,7 +1,9 @@
 use crate::routes::{
-	self, ApiError, AppState, ErrorBody, HeaderMap, Json, ListRequest, ListResponse,
-	NoteFetchRequest, NoteFetchResponse, NotesListQuery, Path, Query, QueryRejection,
-	RequestContext, State, StatusCode, Uuid,
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, ListRequest, ListResponse,
+	NoteFetchRequest, NoteFetchResponse, NoteHistoryRequest, NoteHistoryResponse, NotesCiteBody,
+	NotesCiteRequest, NotesCiteResponse, NotesGetQuery, NotesListQuery, NotesSourceRefsResolveBody,
+	Path, Query, QueryRejection, RequestContext, SourceRefsResolveRequest,
+	SourceRefsResolveResponse, State, StatusCode, Uuid,
 };
 
 #[utoipa::path(
@@ -56,7 +58,14 @@ pub(in crate::routes) async fn notes_list(
 	get,
 	path = "/v2/notes/{note_id}",
 	tag = "notes",
-	params(("note_id" = Uuid, Path, description = "Note ID.")),
+	params(
+		("note_id" = Uuid, Path, description = "Note ID."),
+		(
+			"include_access_stats" = Option<bool>,
+			Query,
+			description = "Attach retrieval analytics for the note."
+		),
+	),
 	responses(
 		(status = 200, description = "Note details.", body = Value),
 		(status = 400, description = "Invalid request.", body = ErrorBody),
@@ -70,15 +79,147 @@ pub(in crate::routes) async fn notes_get(
 	State(state): State<AppState>,
 	headers: HeaderMap,
 	Path(note_id): Path<Uuid>,
+	query: Result<Query<NotesGetQuery>, QueryRejection>,
 ) -> Result<Json<NoteFetchResponse>, ApiError> {
 	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
 	let response = state
 		.service
 		.get_note(NoteFetchRequest {
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
 			note_id,
+			include_access_stats: query.include_access_stats.unwrap_or(false),
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/notes/{note_id}/history",
+	tag = "notes",
+	params(("note_id" = Uuid, Path, description = "Note ID.")),
+	responses(
+		(status = 200, description = "Note versions with text diffs, oldest first.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_history(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(note_id): Path<Uuid>,
+) -> Result<Json<NoteHistoryResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.note_history(NoteHistoryRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			note_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/cite",
+	tag = "notes",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Citation blocks for the readable notes.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_cite(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<NotesCiteBody>, JsonRejection>,
+) -> Result<Json<NotesCiteResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.notes_cite(NotesCiteRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			note_ids: payload.note_ids,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/source-refs/resolve",
+	tag = "notes",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Doc excerpts behind the readable notes' source_refs.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_source_refs_resolve(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<NotesSourceRefsResolveBody>, JsonRejection>,
+) -> Result<Json<SourceRefsResolveResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.resolve_source_refs(SourceRefsResolveRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			read_profile,
+			note_ids: payload.note_ids,
+			level: payload.level,
 		})
 		.await?;
 
@@ -0,0 +1,128 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, Extension, HeaderMap, Json, JsonRejection,
+	NoteRelationCreateRequest, NoteRelationCreateResponse, NoteRelationDeleteRequest,
+	NoteRelationDeleteResponse, NoteRelationsListRequest, NoteRelationsListResponse,
+	NotesRelationCreateBody, Path, RequestContext, SecurityAuthRole, State, StatusCode, Uuid,
+};
+
+#[utoipa::path(
+	get,
+	path = "/v2/notes/{note_id}/relations",
+	tag = "notes",
+	params(("note_id" = Uuid, Path, description = "Note ID.")),
+	responses(
+		(status = 200, description = "Outgoing and incoming relations of the note.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_relations_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(note_id): Path<Uuid>,
+) -> Result<Json<NoteRelationsListResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.note_relations_list(NoteRelationsListRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			note_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/{note_id}/relations",
+	tag = "notes",
+	params(("note_id" = Uuid, Path, description = "Source note ID.")),
+	request_body = Value,
+	responses(
+		(status = 200, description = "Relation was created or already existed.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 404, description = "Note was not found.", body = ErrorBody),
+		(status = 409, description = "Relation conflicts with an existing one.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_relation_create(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	Path(note_id): Path<Uuid>,
+	payload: Result<Json<NotesRelationCreateBody>, JsonRejection>,
+) -> Result<Json<NoteRelationCreateResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+
+	let response = state
+		.service
+		.note_relation_create(NoteRelationCreateRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
+			from_note_id: note_id,
+			to_note_id: payload.to_note_id,
+			relation: payload.relation,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	delete,
+	path = "/v2/notes/{note_id}/relations/{relation_id}",
+	tag = "notes",
+	params(
+		("note_id" = Uuid, Path, description = "Source note ID."),
+		("relation_id" = Uuid, Path, description = "Relation ID."),
+	),
+	responses(
+		(status = 200, description = "Relation was removed.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 404, description = "Relation was not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_relation_delete(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	Path((note_id, relation_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<NoteRelationDeleteResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+
+	let response = state
+		.service
+		.note_relation_delete(NoteRelationDeleteRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
+			from_note_id: note_id,
+			relation_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
@@ -0,0 +1,61 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, NoteEventsRequest, NoteEventsResponse,
+	NotesSubscribeQuery, Query, QueryRejection, RequestContext, State, StatusCode,
+};
+
+#[utoipa::path(
+	get,
+	path = "/v2/notes/events",
+	tag = "notes",
+	params(
+		(
+			"after" = Option<String>,
+			Query,
+			description = "Cursor from a previous page, or latest. Omit to read from the oldest retained event."
+		),
+		("limit" = Option<u32>, Query, description = "Maximum events to return (1-500, default 100)."),
+		(
+			"wait_ms" = Option<u64>,
+			Query,
+			description = "Long-poll wait for new events, in milliseconds (max 30000, default 0)."
+		),
+	),
+	responses(
+		(status = 200, description = "Note writes after the cursor that the caller can read.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_subscribe(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<NotesSubscribeQuery>, QueryRejection>,
+) -> Result<Json<NoteEventsResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.note_events(NoteEventsRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			read_profile,
+			after: query.after,
+			limit: query.limit,
+			wait_ms: query.wait_ms,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
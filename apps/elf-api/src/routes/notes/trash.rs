@@ -0,0 +1,69 @@
+use crate::routes::{
+	ApiError, AppState, ErrorBody, Extension, HeaderMap, Json, ListTrashedRequest,
+	ListTrashedResponse, Path, RequestContext, SecurityAuthRole, State, UndeleteRequest,
+	UndeleteResponse, Uuid,
+};
+
+#[utoipa::path(
+	get,
+	path = "/v2/notes/trash",
+	tag = "notes",
+	responses(
+		(status = 200, description = "Trashed notes owned by the caller.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_trash_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+) -> Result<Json<ListTrashedResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.list_trashed(ListTrashedRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/{note_id}/undelete",
+	tag = "notes",
+	params(("note_id" = Uuid, Path, description = "Trashed note ID.")),
+	responses(
+		(status = 200, description = "Note was restored from the trash.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 409, description = "Note is not in the trash or its key is taken.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_undelete(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	Path(note_id): Path<Uuid>,
+) -> Result<Json<UndeleteResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+
+	let response = state
+		.service
+		.undelete(UndeleteRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
+			note_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
@@ -1,7 +1,9 @@
 use crate::routes::{
-	self, ApiError, AppState, DeleteRequest, DeleteResponse, ErrorBody, HeaderMap, Json,
-	JsonRejection, NotePatchRequest, Path, RequestContext, State, StatusCode, UpdateRequest,
-	UpdateResponse, Uuid,
+	self, ApiError, AppState, DeleteBatchRequest, DeleteRequest, DeleteResponse, ErrorBody,
+	Extension, HeaderMap, Json, JsonRejection, NoteBatchResponse, NotePatchRequest,
+	NotesDeleteBatchBody, NotesMergeBody, NotesMergeRequest, NotesMergeResponse,
+	NotesUpdateBatchBody, Path, RequestContext, SecurityAuthRole, State, StatusCode,
+	UpdateBatchRequest, UpdateRequest, UpdateResponse, Uuid,
 };
 
 #[utoipa::path(
@@ -23,6 +25,7 @@ use crate::routes::{
 pub(in crate::routes) async fn notes_patch(
 	State(state): State<AppState>,
 	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
 	Path(note_id): Path<Uuid>,
 	payload: Result<Json<NotePatchRequest>, JsonRejection>,
 ) -> Result<Json<UpdateResponse>, ApiError> {
@@ -37,12 +40,14 @@ pub(in crate::routes) async fn notes_patch(
 			None,
 		)
 	})?;
+
 	let response = state
 		.service
 		.update(UpdateRequest {
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
 			note_id,
 			text: payload.text,
 			importance: payload.importance,
@@ -60,7 +65,7 @@ pub(in crate::routes) async fn notes_patch(
 	tag = "notes",
 	params(("note_id" = Uuid, Path, description = "Note ID.")),
 	responses(
-		(status = 200, description = "Note was deleted.", body = Value),
+		(status = 200, description = "Note was moved to the trash.", body = Value),
 		(status = 400, description = "Invalid request.", body = ErrorBody),
 		(status = 401, description = "Authentication required.", body = ErrorBody),
 		(status = 403, description = "Scope denied.", body = ErrorBody),
@@ -71,18 +76,156 @@ pub(in crate::routes) async fn notes_patch(
 pub(in crate::routes) async fn notes_delete(
 	State(state): State<AppState>,
 	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
 	Path(note_id): Path<Uuid>,
 ) -> Result<Json<DeleteResponse>, ApiError> {
 	let ctx = RequestContext::from_headers(&headers)?;
+
 	let response = state
 		.service
 		.delete(DeleteRequest {
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
 			note_id,
 		})
 		.await?;
 
 	Ok(Json(response))
 }
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/batch/update",
+	tag = "notes",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Per-note update results, in request order.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_update_batch(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	payload: Result<Json<NotesUpdateBatchBody>, JsonRejection>,
+) -> Result<Json<NoteBatchResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.update_batch(UpdateBatchRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
+			items: payload.items,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/batch/delete",
+	tag = "notes",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Per-note delete results, in request order.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_delete_batch(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	payload: Result<Json<NotesDeleteBatchBody>, JsonRejection>,
+) -> Result<Json<NoteBatchResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.delete_batch(DeleteBatchRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
+			note_ids: payload.note_ids,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/notes/{note_id}/merge",
+	tag = "notes",
+	params(("note_id" = Uuid, Path, description = "Primary note ID that survives the merge.")),
+	request_body = Value,
+	responses(
+		(status = 200, description = "Secondary note was merged into the primary note.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 404, description = "Note was not found.", body = ErrorBody),
+		(status = 409, description = "Notes changed during the merge.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn notes_merge(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	Path(note_id): Path<Uuid>,
+	payload: Result<Json<NotesMergeBody>, JsonRejection>,
+) -> Result<Json<NotesMergeResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.notes_merge(NotesMergeRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
+			primary_note_id: note_id,
+			secondary_note_id: payload.secondary_note_id,
+			strategy: payload.strategy,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
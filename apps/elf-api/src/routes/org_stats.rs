@@ -0,0 +1,110 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, MemoryTimelineQuery,
+	MemoryTimelineRequest, MemoryTimelineResponse, OrgMemoryStatsQuery, OrgMemoryStatsRequest,
+	OrgMemoryStatsResponse, Query, QueryRejection, RequestContext, State, StatusCode,
+};
+
+#[utoipa::path(
+	get,
+	path = "/v2/org-stats",
+	tag = "notes",
+	params(
+		("min_count" = Option<u32>, Query, description = "Optional note-count threshold; cannot go below the server minimum."),
+		("limit" = Option<u32>, Query, description = "Maximum topics and entities to return."),
+	),
+	responses(
+		(status = 200, description = "Thresholded topic and entity frequencies over shared scopes.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn org_memory_stats(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<OrgMemoryStatsQuery>, QueryRejection>,
+) -> Result<Json<OrgMemoryStatsResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.org_memory_stats(OrgMemoryStatsRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			read_profile,
+			min_count: query.min_count,
+			limit: query.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/memory-timeline",
+	tag = "notes",
+	params(
+		("bucket" = Option<String>, Query, description = "Bucket unit: day (default) or week, in UTC."),
+		("since" = Option<String>, Query, description = "Inclusive RFC3339 lower bound on note creation time."),
+		("until" = Option<String>, Query, description = "Exclusive RFC3339 upper bound on note creation time."),
+		("max_buckets" = Option<u32>, Query, description = "Maximum most recent buckets to return."),
+		("representatives_per_bucket" = Option<u32>, Query, description = "Maximum highest-importance notes per bucket."),
+		("top_entities_per_bucket" = Option<u32>, Query, description = "Maximum graph entities per bucket."),
+	),
+	responses(
+		(status = 200, description = "Per-bucket note counts, top entities, and representative notes.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn memory_timeline(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<MemoryTimelineQuery>, QueryRejection>,
+) -> Result<Json<MemoryTimelineResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let since = routes::parse_optional_rfc3339(query.since.as_ref(), "$.since")?;
+	let until = routes::parse_optional_rfc3339(query.until.as_ref(), "$.until")?;
+	let response = state
+		.service
+		.memory_timeline(MemoryTimelineRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			read_profile,
+			bucket: query.bucket,
+			since,
+			until,
+			max_buckets: query.max_buckets,
+			representatives_per_bucket: query.representatives_per_bucket,
+			top_entities_per_bucket: query.top_entities_per_bucket,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
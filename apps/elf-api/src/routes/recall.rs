@@ -1,8 +1,62 @@
 use crate::routes::{
-	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RecallDebugPanelBody,
-	RecallDebugPanelRequest, RecallDebugPanelResponse, RequestContext, State, StatusCode,
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RecallContextBody,
+	RecallContextRequest, RecallContextResponse, RecallDebugPanelBody, RecallDebugPanelRequest,
+	RecallDebugPanelResponse, RequestContext, State, StatusCode,
 };
 
+#[utoipa::path(
+	post,
+	path = "/v2/recall/context",
+	tag = "recall",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Derived queries and a cited context block.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(super) async fn recall_context(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<RecallContextBody>, JsonRejection>,
+) -> Result<Json<RecallContextResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.recall_for_context(RecallContextRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
+			read_profile,
+			turns: payload.turns,
+			max_queries: payload.max_queries,
+			top_k: payload.top_k,
+			max_context_chars: payload.max_context_chars,
+			mode: payload.mode,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
 #[utoipa::path(
 	post,
 	path = "/v2/recall-debug/panel",
@@ -56,6 +110,10 @@ pub(super) async fn recall_debug_panel_inner(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
 			read_profile,
 			trace_id: payload.trace_id,
 			query: payload.query,
@@ -16,15 +16,25 @@ pub fn router(state: AppState) -> Router {
 		.merge(routes::contract_router())
 		.merge(
 			public::public_api_router()
+				.merge(public::search_api_router(state.clone()))
+				.route_layer(middleware::from_fn_with_state(
+					state.clone(),
+					routes::support::audit_transport_middleware,
+				))
 				.with_state(state.clone())
 				.layer(DefaultBodyLimit::max(MAX_REQUEST_BYTES)),
 		)
 		.merge(
 			public::docs_api_router()
+				.route_layer(middleware::from_fn_with_state(
+					state.clone(),
+					routes::support::audit_transport_middleware,
+				))
 				.with_state(state)
 				.layer(DefaultBodyLimit::max(MAX_DOC_REQUEST_BYTES)),
 		)
 		.layer(middleware::from_fn_with_state(auth_state, routes::support::api_auth_middleware))
+		.layer(middleware::from_fn(routes::support::trace_context_middleware))
 }
 
 /// Builds the authenticated admin API router.
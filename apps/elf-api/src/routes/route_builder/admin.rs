@@ -7,17 +7,23 @@ use crate::{
 
 pub(super) fn admin_router(state: AppState) -> Router {
 	let auth_state = state.clone();
+	let audit_state = state.clone();
 	let protected_router = Router::new()
 		.merge(admin_search_routes())
 		.merge(admin_core_routes())
 		.merge(admin_docs_routes())
 		.merge(admin_notes_routes())
 		.merge(admin_ingestion_profile_routes())
+		.merge(admin_search_profile_routes())
 		.merge(admin_consolidation_routes())
 		.merge(admin_knowledge_routes())
 		.merge(admin_trace_routes())
 		.merge(admin_graph_routes())
 		.merge(admin_ops_routes())
+		.route_layer(middleware::from_fn_with_state(
+			audit_state,
+			routes::support::audit_transport_middleware,
+		))
 		.with_state(state)
 		.layer(DefaultBodyLimit::max(MAX_REQUEST_BYTES))
 		.layer(middleware::from_fn_with_state(auth_state, routes::support::admin_auth_middleware));
@@ -26,6 +32,7 @@ pub(super) fn admin_router(state: AppState) -> Router {
 		.route(ADMIN_VIEWER_PATH, routing::get(routes::viewer::admin_viewer))
 		.route("/", routing::get(routes::viewer::admin_viewer))
 		.merge(protected_router)
+		.layer(middleware::from_fn(routes::support::trace_context_middleware))
 }
 
 fn admin_search_routes() -> Router<AppState> {
@@ -41,6 +48,7 @@ fn admin_search_routes() -> Router<AppState> {
 			"/v2/admin/searches/{search_id}/notes",
 			routing::post(routes::search::searches_notes),
 		)
+		.route("/v2/admin/shadow/report", routing::get(routes::search::admin_search_shadow_report))
 }
 
 fn admin_core_routes() -> Router<AppState> {
@@ -62,6 +70,7 @@ fn admin_core_routes() -> Router<AppState> {
 
 fn admin_docs_routes() -> Router<AppState> {
 	Router::new()
+		.route("/v2/admin/docs/search", routing::post(routes::docs::admin_docs_search))
 		.route("/v2/admin/docs/search/l0", routing::post(routes::docs::admin_docs_search_l0))
 		.route("/v2/admin/docs/excerpts", routing::post(routes::docs::admin_docs_excerpts_get))
 		.route("/v2/admin/docs/{doc_id}", routing::get(routes::docs::admin_docs_get))
@@ -85,6 +94,20 @@ fn admin_notes_routes() -> Router<AppState> {
 		)
 }
 
+fn admin_search_profile_routes() -> Router<AppState> {
+	Router::new()
+		.route(
+			"/v2/admin/search-profiles",
+			routing::get(routes::search_profiles::admin_search_profiles_list),
+		)
+		.route(
+			"/v2/admin/search-profiles/{name}",
+			routing::get(routes::search_profiles::admin_search_profile_get)
+				.put(routes::search_profiles::admin_search_profile_put)
+				.delete(routes::search_profiles::admin_search_profile_delete),
+		)
+}
+
 fn admin_ingestion_profile_routes() -> Router<AppState> {
 	Router::new()
 		.route(
@@ -166,6 +189,10 @@ fn admin_trace_routes() -> Router<AppState> {
 		.route("/v2/admin/traces/recent", routing::get(routes::trace::trace_recent_list))
 		.route("/v2/admin/traces/{trace_id}", routing::get(routes::trace::trace_get))
 		.route("/v2/admin/traces/{trace_id}/bundle", routing::get(routes::trace::trace_bundle_get))
+		.route(
+			"/v2/admin/traces/{trace_id}/artifact",
+			routing::get(routes::trace::trace_artifact_get),
+		)
 		.route(
 			"/v2/admin/trajectories/{trace_id}",
 			routing::get(routes::trace::trace_trajectory_get),
@@ -188,9 +215,74 @@ fn admin_graph_routes() -> Router<AppState> {
 			routing::post(routes::graph::admin_graph_predicate_alias_add)
 				.get(routes::graph::admin_graph_predicate_aliases_list),
 		)
+		.route(
+			"/v2/admin/graph/predicates/{predicate_id}/promote",
+			routing::post(routes::graph::admin_graph_predicate_promote),
+		)
+		.route(
+			"/v2/admin/graph/entities/merge",
+			routing::post(routes::graph::admin_graph_entities_merge),
+		)
+		.route(
+			"/v2/admin/graph/entities/{entity_id}/split",
+			routing::post(routes::graph::admin_graph_entity_split),
+		)
+		.route(
+			"/v2/admin/graph/entity-kinds",
+			routing::get(routes::graph::admin_graph_entity_kinds_list),
+		)
+		.route(
+			"/v2/admin/graph/entity-kinds/{kind_id}/promote",
+			routing::post(routes::graph::admin_graph_entity_kind_promote),
+		)
 }
 
 fn admin_ops_routes() -> Router<AppState> {
 	Router::new()
 		.route("/v2/admin/qdrant/rebuild", routing::post(routes::admin_ops::rebuild_qdrant))
+		.route("/v2/admin/qdrant/audit", routing::post(routes::admin_ops::qdrant_audit))
+		.route("/v2/admin/index/verify", routing::post(routes::admin_ops::admin_verify_index))
+		.route(
+			"/v2/admin/qdrant/maintenance/runs",
+			routing::get(routes::admin_ops::qdrant_maintenance_runs_list)
+				.post(routes::admin_ops::qdrant_maintenance_run),
+		)
+		.route("/v2/admin/storage/report", routing::get(routes::admin_ops::storage_report))
+		.route("/v2/admin/memory/stats", routing::get(routes::admin_ops::memory_stats))
+		.route("/v2/admin/providers/health", routing::get(routes::admin_ops::provider_health))
+		.route("/v2/admin/quota/usage", routing::get(routes::admin_ops::quota_usage))
+		.route(
+			"/v2/admin/grants",
+			routing::get(routes::admin_ops::admin_grants_list)
+				.put(routes::admin_ops::admin_grants_put),
+		)
+		.route(
+			"/v2/admin/grants/{grant_id}/revoke",
+			routing::post(routes::admin_ops::admin_grants_revoke),
+		)
+		.route(
+			"/v2/admin/reembed/runs",
+			routing::get(routes::admin_ops::admin_reembed_runs_list)
+				.post(routes::admin_ops::admin_reembed_run),
+		)
+		.route(
+			"/v2/admin/reembed/runs/{run_id}/activate",
+			routing::post(routes::admin_ops::admin_reembed_activate),
+		)
+		.route("/v2/admin/export", routing::get(routes::admin_ops::admin_tenant_export))
+		.route("/v2/admin/restore", routing::post(routes::admin_ops::admin_snapshot_restore))
+		.route(
+			"/v2/admin/outbox/dead-letters",
+			routing::get(routes::admin_ops::admin_outbox_dead_letter_list),
+		)
+		.route(
+			"/v2/admin/outbox/dead-letters/replay",
+			routing::post(routes::admin_ops::admin_outbox_replay),
+		)
+		.route(
+			"/v2/admin/write-incidents",
+			routing::get(routes::admin_ops::admin_write_incidents_list),
+		)
+		.route("/v2/admin/audit-log", routing::get(routes::admin_ops::admin_audit_log_list))
+		.route("/v2/admin/access/simulate", routing::post(routes::admin_ops::admin_access_simulate))
 }
@@ -1,28 +1,58 @@
-use axum::{Router, routing};
+use axum::{Router, middleware, routing};
 
 use crate::{routes, state::AppState};
 
 pub(super) fn public_api_router() -> Router<AppState> {
 	Router::new()
 		.route("/health", routing::get(routes::health::health))
+		.route("/ready", routing::get(routes::health::ready))
 		.route("/v2/notes/ingest", routing::post(routes::notes::notes_ingest))
+		.route("/v2/notes/bulk-import", routing::post(routes::notes::notes_bulk_import))
 		.route("/v2/events/ingest", routing::post(routes::events::events_ingest))
 		.route("/v2/core-blocks", routing::get(routes::core_memory::core_blocks_get))
 		.route("/v2/entity-memory", routing::get(routes::core_memory::entity_memory_get))
 		.route("/v2/recall-debug/panel", routing::post(routes::recall::recall_debug_panel))
-		.route("/v2/searches", routing::post(routes::search::searches_create))
 		.route("/v2/searches/{search_id}", routing::get(routes::search::searches_get))
 		.route("/v2/searches/{search_id}/timeline", routing::get(routes::search::searches_timeline))
 		.route("/v2/searches/{search_id}/notes", routing::post(routes::search::searches_notes))
+		.route("/v2/searches/feedback", routing::post(routes::search::searches_feedback))
+		.route("/v2/rank", routing::post(routes::search::rank_documents))
 		.route("/v2/graph/query", routing::post(routes::graph::graph_query))
 		.route("/v2/graph/report", routing::post(routes::graph::graph_report))
+		.route("/v2/graph/neighborhood", routing::post(routes::graph::graph_neighborhood))
+		.route("/v2/graph/facts/as-of", routing::post(routes::graph::graph_facts_as_of))
+		.route("/v2/org-stats", routing::get(routes::org_stats::org_memory_stats))
+		.route("/v2/memory-timeline", routing::get(routes::org_stats::memory_timeline))
 		.route("/v2/notes", routing::get(routes::notes::notes_list))
+		.route("/v2/notes/cite", routing::post(routes::notes::notes_cite))
+		.route(
+			"/v2/notes/source-refs/resolve",
+			routing::post(routes::notes::notes_source_refs_resolve),
+		)
+		.route("/v2/notes/events", routing::get(routes::notes::notes_subscribe))
+		.route("/v2/notes/trash", routing::get(routes::notes::notes_trash_list))
+		.route("/v2/notes/batch/update", routing::post(routes::notes::notes_update_batch))
+		.route("/v2/notes/batch/delete", routing::post(routes::notes::notes_delete_batch))
 		.route(
 			"/v2/notes/{note_id}",
 			routing::get(routes::notes::notes_get)
 				.patch(routes::notes::notes_patch)
 				.delete(routes::notes::notes_delete),
 		)
+		.route(
+			"/v2/notes/{note_id}/relations",
+			routing::get(routes::notes::notes_relations_list)
+				.post(routes::notes::notes_relation_create),
+		)
+		.route(
+			"/v2/notes/{note_id}/relations/{relation_id}",
+			routing::delete(routes::notes::notes_relation_delete),
+		)
+		.route("/v2/notes/{note_id}/history", routing::get(routes::notes::notes_history))
+		.route("/v2/notes/{note_id}/merge", routing::post(routes::notes::notes_merge))
+		.route("/v2/notes/{note_id}/undelete", routing::post(routes::notes::notes_undelete))
+		.route("/v2/notes/{note_id}/pin", routing::post(routes::notes::notes_pin))
+		.route("/v2/notes/{note_id}/unpin", routing::post(routes::notes::notes_unpin))
 		.route("/v2/notes/{note_id}/publish", routing::post(routes::notes::notes_publish))
 		.route("/v2/notes/{note_id}/unpublish", routing::post(routes::notes::notes_unpublish))
 		.route(
@@ -37,6 +67,29 @@ pub(super) fn public_api_router() -> Router<AppState> {
 			"/v2/work-journal/readback",
 			routing::post(routes::work_journal::work_journal_session_readback),
 		)
+		.route("/v2/sessions/{session_id}", routing::get(routes::sessions::session_get))
+		.route(
+			"/v2/sessions/{session_id}/messages",
+			routing::post(routes::sessions::session_append),
+		)
+		.route(
+			"/v2/sessions/{session_id}/summarize",
+			routing::post(routes::sessions::session_summarize),
+		)
+		.route(
+			"/v2/standing-queries",
+			routing::get(routes::standing_queries::standing_queries_list)
+				.post(routes::standing_queries::standing_query_create),
+		)
+		.route(
+			"/v2/standing-queries/{standing_query_id}",
+			routing::get(routes::standing_queries::standing_query_get)
+				.delete(routes::standing_queries::standing_query_delete),
+		)
+		.route(
+			"/v2/standing-queries/{standing_query_id}/matches",
+			routing::get(routes::standing_queries::standing_query_matches),
+		)
 		.route(
 			"/v2/spaces/{space}/grants",
 			routing::get(routes::sharing::space_grants_list)
@@ -48,6 +101,22 @@ pub(super) fn public_api_router() -> Router<AppState> {
 		)
 }
 
+/// Routes that start a search and count against `security.quotas.max_search_qps`.
+pub(super) fn search_api_router(state: AppState) -> Router<AppState> {
+	Router::new()
+		.route("/v2/searches", routing::post(routes::search::searches_create))
+		.route("/v2/searches/answer", routing::post(routes::search::searches_answer))
+		.route("/v2/searches/batch", routing::post(routes::search::searches_batch))
+		.route("/v2/searches/scoped", routing::post(routes::search::searches_scoped))
+		.route("/v2/searches/stream", routing::post(routes::search::searches_stream))
+		.route("/v2/searches/profiles/{name}", routing::post(routes::search::searches_with_profile))
+		.route("/v2/recall/context", routing::post(routes::recall::recall_context))
+		.route_layer(middleware::from_fn_with_state(
+			state,
+			routes::support::search_quota_middleware,
+		))
+}
+
 pub(super) fn docs_api_router() -> Router<AppState> {
 	Router::new()
 		.route("/v2/docs", routing::post(routes::docs::docs_put))
@@ -55,6 +124,7 @@ pub(super) fn docs_api_router() -> Router<AppState> {
 			"/v2/docs/{doc_id}",
 			routing::get(routes::docs::docs_get).delete(routes::docs::docs_delete),
 		)
+		.route("/v2/docs/search", routing::post(routes::docs::docs_search))
 		.route("/v2/docs/search/l0", routing::post(routes::docs::docs_search_l0))
 		.route("/v2/docs/excerpts", routing::post(routes::docs::docs_excerpts_get))
 }
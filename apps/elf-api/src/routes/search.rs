@@ -1,12 +1,28 @@
+mod answer;
+mod batch;
 mod create;
 mod details;
+mod feedback;
+mod profile;
+mod rank;
 mod raw;
 mod read;
+mod scoped;
+mod shadow;
+mod stream;
 mod validation;
 
 pub(super) use self::{
+	answer::{__path_searches_answer, searches_answer},
+	batch::{__path_searches_batch, searches_batch},
 	create::{__path_searches_create, searches_create},
 	details::{__path_searches_notes, searches_notes},
+	feedback::{__path_searches_feedback, searches_feedback},
+	profile::{__path_searches_with_profile, searches_with_profile},
+	rank::{__path_rank_documents, rank_documents},
 	raw::{__path_searches_raw, searches_raw},
 	read::{__path_searches_get, __path_searches_timeline, searches_get, searches_timeline},
+	scoped::{__path_searches_scoped, searches_scoped},
+	shadow::{__path_admin_search_shadow_report, admin_search_shadow_report},
+	stream::{__path_searches_stream, searches_stream},
 };
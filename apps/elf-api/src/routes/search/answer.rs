@@ -0,0 +1,66 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RequestContext,
+	SearchAnswerRequest, SearchAnswerResponse, SearchCreateRequest, SearchRequest, State,
+	search::validation,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/searches/answer",
+	tag = "search",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Grounded answer or refusal.", body = Value),
+		(status = 400, description = "Invalid request or answer synthesis disabled.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn searches_answer(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<SearchCreateRequest>, JsonRejection>,
+) -> Result<Json<SearchAnswerResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(validation::invalid_json_payload)?;
+
+	validation::validate_search_create_payload(
+		&payload,
+		state.service.cfg.memory.top_k,
+		state.service.cfg.memory.candidate_k,
+	)?;
+
+	let request = SearchAnswerRequest {
+		search: SearchRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
+			read_profile,
+			query: payload.query,
+			top_k: payload.top_k,
+			candidate_k: payload.candidate_k,
+			filter: payload.filter,
+			types: payload.types,
+			updated_after: payload.updated_after,
+			updated_before: payload.updated_before,
+			min_importance: payload.min_importance,
+			max_result_tokens: payload.max_result_tokens,
+			payload_level: payload.payload_level.unwrap_or_default(),
+			record_hits: Some(false),
+			ranking: None,
+			session_id: None,
+			session_mode: None,
+		},
+		mode: payload.mode,
+	};
+	let response = state.service.search_answer(request).await?;
+
+	Ok(Json(response))
+}
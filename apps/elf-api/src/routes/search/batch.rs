@@ -0,0 +1,67 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RequestContext,
+	SearchBatchBody, SearchBatchRequest, SearchBatchResponse, SearchRequest, State,
+	search::validation,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/searches/batch",
+	tag = "search",
+	request_body = Value,
+	responses(
+		(status = 200, description = "One search session per query, in request order.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn searches_batch(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<SearchBatchBody>, JsonRejection>,
+) -> Result<Json<SearchBatchResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(validation::invalid_json_payload)?;
+
+	validation::validate_search_batch_payload(
+		&payload,
+		state.service.cfg.memory.top_k,
+		state.service.cfg.memory.candidate_k,
+	)?;
+
+	let token_id =
+		routes::effective_token_id(state.service.cfg.security.auth_mode.as_str(), &headers);
+	let searches = payload
+		.searches
+		.into_iter()
+		.map(|search| SearchRequest {
+			tenant_id: ctx.tenant_id.clone(),
+			project_id: ctx.project_id.clone(),
+			agent_id: ctx.agent_id.clone(),
+			token_id: token_id.clone(),
+			read_profile: read_profile.clone(),
+			query: search.query,
+			top_k: search.top_k,
+			candidate_k: search.candidate_k,
+			filter: search.filter,
+			types: search.types,
+			updated_after: search.updated_after,
+			updated_before: search.updated_before,
+			min_importance: search.min_importance,
+			max_result_tokens: search.max_result_tokens,
+			payload_level: search.payload_level.unwrap_or_default(),
+			record_hits: Some(false),
+			ranking: None,
+			session_id: search.session_id,
+			session_mode: search.session_mode,
+		})
+		.collect();
+	let response =
+		state.service.search_batch(SearchBatchRequest { searches, mode: payload.mode }).await?;
+
+	Ok(Json(response))
+}
@@ -1,7 +1,13 @@
-use crate::routes::{
-	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RequestContext,
-	SearchCreateRequest, SearchCreateResponseV2, SearchMode, SearchRequest, State,
-	search::validation,
+use std::time::Instant;
+
+use crate::{
+	routes::{
+		self, ApiError, AppState, ErrorBody, HEADER_AGENT_ID, HEADER_PROJECT_ID,
+		HEADER_READ_PROFILE, HEADER_TENANT_ID, HeaderMap, Json, JsonRejection, RequestContext,
+		SearchCreateRequest, SearchCreateResponseV2, SearchRequest, SearchV2Delivery,
+		SearchV2Request, State, StatusCode, search::validation,
+	},
+	shadow::SearchShadowProbe,
 };
 
 #[utoipa::path(
@@ -34,50 +40,90 @@ pub(in crate::routes) async fn searches_create(
 	)?;
 
 	let mode = payload.mode;
+	// The shadow replays the validated request as received, so it applies every filter the
+	// primary did.
+	let probe_body = match state.shadow {
+		Some(_) => serde_json::to_value(&payload)
+			.inspect_err(|err| {
+				tracing::warn!(error = %err, "Failed to encode the search shadow probe body.");
+			})
+			.ok(),
+		None => None,
+	};
+	let probe_context = (ctx.tenant_id.clone(), ctx.project_id.clone(), ctx.agent_id.clone());
+	let probe_read_profile = read_profile.clone();
+	let started = Instant::now();
 	let token_id =
 		routes::effective_token_id(state.service.cfg.security.auth_mode.as_str(), &headers);
-	let build_request = || SearchRequest {
-		tenant_id: ctx.tenant_id,
-		project_id: ctx.project_id,
-		agent_id: ctx.agent_id,
-		token_id: token_id.clone(),
-		read_profile,
-		query: payload.query.clone(),
-		top_k: payload.top_k,
-		candidate_k: payload.candidate_k,
-		filter: payload.filter.clone(),
-		payload_level: payload.payload_level.unwrap_or_default(),
-		record_hits: Some(false),
-		ranking: None,
+	let request = SearchV2Request {
+		search: SearchRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			token_id,
+			read_profile,
+			query: payload.query.clone(),
+			top_k: payload.top_k,
+			candidate_k: payload.candidate_k,
+			filter: payload.filter.clone(),
+			types: payload.types,
+			updated_after: payload.updated_after,
+			updated_before: payload.updated_before,
+			min_importance: payload.min_importance,
+			max_result_tokens: payload.max_result_tokens,
+			payload_level: payload.payload_level.unwrap_or_default(),
+			record_hits: Some(false),
+			ranking: None,
+			session_id: payload.session_id.clone(),
+			session_mode: payload.session_mode.clone(),
+		},
+		mode,
+		delivery: SearchV2Delivery::Session,
+	};
+	let response = state.service.search_v2(request).await?;
+	let session = response.session.ok_or_else(|| {
+		routes::json_error(
+			StatusCode::INTERNAL_SERVER_ERROR,
+			"INTERNAL_ERROR",
+			"Search session was not created.",
+			None,
+		)
+	})?;
+	let response = SearchCreateResponseV2 {
+		mode,
+		trace_id: response.trace_id,
+		search_id: session.search_session_id,
+		expires_at: session.expires_at,
+		items: session.items,
+		trajectory_summary: response.trajectory_summary,
+		query_plan: response.query_plan,
+		warnings: response.warnings,
 	};
-	let response = match mode {
-		SearchMode::QuickFind => {
-			let response = state.service.search_quick(build_request()).await?;
 
-			SearchCreateResponseV2 {
-				mode,
-				trace_id: response.trace_id,
-				search_id: response.search_session_id,
-				expires_at: response.expires_at,
-				items: response.items,
-				trajectory_summary: response.trajectory_summary,
-				query_plan: None,
-			}
-		},
-		SearchMode::PlannedSearch => {
-			let response = state.service.search_planned(build_request()).await?;
+	if let Some(body) = probe_body
+		&& let Some(shadow) = state.shadow.as_ref().filter(|shadow| shadow.sampled())
+	{
+		let (tenant_id, project_id, agent_id) = probe_context;
+		let probe = SearchShadowProbe {
+			headers: vec![
+				(HEADER_TENANT_ID, tenant_id.clone()),
+				(HEADER_PROJECT_ID, project_id.clone()),
+				(HEADER_AGENT_ID, agent_id.clone()),
+				(HEADER_READ_PROFILE, probe_read_profile),
+			],
+			tenant_id,
+			project_id,
+			agent_id,
+			mode: mode.as_str().to_string(),
+			query: payload.query.clone(),
+			body,
+			primary_trace_id: response.trace_id,
+			primary_note_ids: response.items.iter().map(|item| item.note_id).collect(),
+			primary_latency_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+		};
 
-			SearchCreateResponseV2 {
-				mode,
-				trace_id: response.trace_id,
-				search_id: response.search_session_id,
-				expires_at: response.expires_at,
-				items: response.items,
-				trajectory_summary: response.trajectory_summary,
-				query_plan: Some(response.query_plan),
-			}
-		},
-	};
+		shadow.dispatch(state.service.clone(), probe);
+	}
 
 	Ok(Json(response))
 }
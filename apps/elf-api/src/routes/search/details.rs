@@ -40,6 +40,7 @@ pub(in crate::routes) async fn searches_notes(
 			payload_level: payload.payload_level.unwrap_or_default(),
 			note_ids: payload.note_ids,
 			record_hits: payload.record_hits,
+			session_id: payload.session_id,
 		})
 		.await?;
 
@@ -0,0 +1,41 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RequestContext,
+	SearchFeedbackBody, SearchFeedbackRequest, SearchFeedbackResponse, State, search::validation,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/searches/feedback",
+	tag = "search",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Recorded feedback for one search result.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn searches_feedback(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<SearchFeedbackBody>, JsonRejection>,
+) -> Result<Json<SearchFeedbackResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(validation::invalid_json_payload)?;
+	let response = state
+		.service
+		.record_feedback(SearchFeedbackRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
+			result_handle: payload.result_handle,
+			feedback: payload.feedback,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
@@ -0,0 +1,77 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, Path, RequestContext,
+	SearchWithProfileBody, SearchWithProfileRequest, SearchWithProfileResponseV2, State,
+	StatusCode, search::validation,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/searches/profiles/{name}",
+	tag = "search",
+	params(("name" = String, Path, description = "Search profile name.")),
+	request_body = Value,
+	responses(
+		(status = 200, description = "Search session was created from the profile.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 404, description = "Search profile not found.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn searches_with_profile(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(name): Path<String>,
+	payload: Result<Json<SearchWithProfileBody>, JsonRejection>,
+) -> Result<Json<SearchWithProfileResponseV2>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(validation::invalid_json_payload)?;
+
+	validation::validate_search_with_profile_payload(&payload)?;
+
+	let response = state
+		.service
+		.search_with_profile(SearchWithProfileRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
+			read_profile,
+			profile: name,
+			query: payload.query,
+			top_k: payload.top_k,
+			payload_level: payload.payload_level,
+			updated_after: payload.updated_after,
+			updated_before: payload.updated_before,
+			session_id: payload.session_id,
+			session_mode: payload.session_mode,
+		})
+		.await?;
+	let search = response.search;
+	let session = search.session.ok_or_else(|| {
+		routes::json_error(
+			StatusCode::INTERNAL_SERVER_ERROR,
+			"INTERNAL_ERROR",
+			"Search session was not created.",
+			None,
+		)
+	})?;
+
+	Ok(Json(SearchWithProfileResponseV2 {
+		profile: response.profile,
+		mode: search.mode,
+		trace_id: search.trace_id,
+		search_id: session.search_session_id,
+		expires_at: session.expires_at,
+		items: session.items,
+		trajectory_summary: search.trajectory_summary,
+		query_plan: search.query_plan,
+		warnings: search.warnings,
+	}))
+}
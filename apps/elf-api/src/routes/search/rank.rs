@@ -0,0 +1,43 @@
+use crate::routes::{
+	ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RankDocumentsBody,
+	RankDocumentsRequest, RankDocumentsResponse, RequestContext, State, search::validation,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/rank",
+	tag = "search",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Caller-provided documents ranked with explain terms.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn rank_documents(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<RankDocumentsBody>, JsonRejection>,
+) -> Result<Json<RankDocumentsResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(validation::invalid_json_payload)?;
+
+	validation::validate_rank_documents_payload(&payload)?;
+
+	let response = state
+		.service
+		.rank_documents(RankDocumentsRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			query: payload.query,
+			docs: payload.docs,
+			top_k: payload.top_k,
+			ranking: None,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
@@ -1,6 +1,7 @@
 use crate::routes::{
 	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RequestContext,
-	SearchCreateRequest, SearchMode, SearchRequest, SearchResponse, State, search::validation,
+	SearchCreateRequest, SearchRequest, SearchResponse, SearchV2Delivery, SearchV2Request, State,
+	search::validation,
 };
 
 #[utoipa::path(
@@ -32,35 +33,40 @@ pub(in crate::routes) async fn searches_raw(
 		state.service.cfg.memory.candidate_k,
 	)?;
 
-	let request = SearchRequest {
-		tenant_id: ctx.tenant_id,
-		project_id: ctx.project_id,
-		agent_id: ctx.agent_id,
-		token_id: routes::effective_token_id(
-			state.service.cfg.security.auth_mode.as_str(),
-			&headers,
-		),
-		read_profile,
-		query: payload.query,
-		filter: payload.filter,
-		payload_level: payload.payload_level.unwrap_or_default(),
-		top_k: payload.top_k,
-		candidate_k: payload.candidate_k,
-		record_hits: Some(false),
-		ranking: payload.ranking,
-	};
-	let response = match payload.mode {
-		SearchMode::QuickFind => state.service.search_raw_quick(request).await?,
-		SearchMode::PlannedSearch => {
-			let response = state.service.search_raw_planned(request).await?;
-
-			SearchResponse {
-				trace_id: response.trace_id,
-				items: response.items,
-				trajectory_summary: response.trajectory_summary,
-			}
+	let request = SearchV2Request {
+		search: SearchRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
+			read_profile,
+			query: payload.query,
+			filter: payload.filter,
+			types: payload.types,
+			updated_after: payload.updated_after,
+			updated_before: payload.updated_before,
+			min_importance: payload.min_importance,
+			max_result_tokens: payload.max_result_tokens,
+			payload_level: payload.payload_level.unwrap_or_default(),
+			top_k: payload.top_k,
+			candidate_k: payload.candidate_k,
+			record_hits: Some(false),
+			ranking: payload.ranking,
+			session_id: None,
+			session_mode: None,
 		},
+		mode: payload.mode,
+		delivery: SearchV2Delivery::Raw,
 	};
+	let response = state.service.search_v2(request).await?;
 
-	Ok(Json(response))
+	Ok(Json(SearchResponse {
+		trace_id: response.trace_id,
+		items: response.items,
+		trajectory_summary: response.trajectory_summary,
+		warnings: response.warnings,
+	}))
 }
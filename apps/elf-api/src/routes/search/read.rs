@@ -1,8 +1,7 @@
 use crate::routes::{
 	ApiError, AppState, ErrorBody, HeaderMap, Json, Path, Query, QueryRejection, RequestContext,
-	SearchIndexResponseV2, SearchMode, SearchSessionGetQuery, SearchSessionGetRequest,
-	SearchTimelineQuery, SearchTimelineRequest, SearchTimelineResponseV2, State, Uuid,
-	search::validation,
+	SearchIndexResponseV2, SearchSessionGetQuery, SearchSessionGetRequest, SearchTimelineQuery,
+	SearchTimelineRequest, SearchTimelineResponseV2, SearchV2Mode, State, Uuid, search::validation,
 };
 
 #[utoipa::path(
@@ -45,9 +44,9 @@ pub(in crate::routes) async fn searches_get(
 		})
 		.await?;
 	let mode = if response.query_plan.is_some() {
-		SearchMode::PlannedSearch
+		SearchV2Mode::PlannedSearch
 	} else {
-		SearchMode::QuickFind
+		SearchV2Mode::QuickFind
 	};
 
 	Ok(Json(SearchIndexResponseV2 {
@@ -68,7 +67,12 @@ pub(in crate::routes) async fn searches_get(
 	params(
 		("search_id" = Uuid, Path, description = "Search session ID."),
 		("payload_level" = Option<String>, Query, description = "Optional payload level."),
-		("group_by" = Option<String>, Query, description = "Timeline grouping mode."),
+		(
+			"group_by" = Option<String>,
+			Query,
+			description = "Timeline grouping mode: day, week, month, type, scope, or none."
+		),
+		("group_limit" = Option<u32>, Query, description = "Maximum items per group (1-100)."),
 	),
 	responses(
 		(status = 200, description = "Search session timeline.", body = Value),
@@ -96,6 +100,7 @@ pub(in crate::routes) async fn searches_timeline(
 			search_session_id: search_id,
 			payload_level: query.payload_level.unwrap_or_default(),
 			group_by: query.group_by,
+			group_limit: query.group_limit,
 		})
 		.await?;
 
@@ -0,0 +1,51 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, Query, QueryRejection, RequestContext,
+	SearchShadowReportQuery, SearchShadowReportRequest, SearchShadowReportResponse, State,
+	StatusCode,
+};
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/shadow/report",
+	tag = "admin",
+	params(
+		("since" = Option<String>, Query, description = "Inclusive RFC3339 lower bound on comparison time."),
+		("limit" = Option<u32>, Query, description = "Maximum number of recent comparisons to return."),
+	),
+	responses(
+		(status = 200, description = "Search shadow comparison report.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_search_shadow_report(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	query: Result<Query<SearchShadowReportQuery>, QueryRejection>,
+) -> Result<Json<SearchShadowReportResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let since = routes::parse_optional_rfc3339(query.since.as_ref(), "$.since")?;
+	let response = state
+		.service
+		.shadow_report(SearchShadowReportRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			since,
+			limit: query.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
@@ -0,0 +1,137 @@
+use std::convert::Infallible;
+
+use axum::response::{
+	IntoResponse, Response,
+	sse::{Event, KeepAlive, Sse},
+};
+use serde::Serialize;
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, RequestContext,
+	SearchCreateRequest, SearchCreateResponseV2, SearchRequest, SearchV2Delivery, SearchV2Request,
+	State, StatusCode, search::validation,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/searches/stream",
+	tag = "search",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Server-sent events: one `stage` event per completed pipeline stage, then one `result` or `error` event.", content_type = "text/event-stream", body = String),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn searches_stream(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<SearchCreateRequest>, JsonRejection>,
+) -> Result<Response, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(validation::invalid_json_payload)?;
+
+	validation::validate_search_create_payload(
+		&payload,
+		state.service.cfg.memory.top_k,
+		state.service.cfg.memory.candidate_k,
+	)?;
+
+	let mode = payload.mode;
+	let request = SearchV2Request {
+		search: SearchRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
+			read_profile,
+			query: payload.query,
+			top_k: payload.top_k,
+			candidate_k: payload.candidate_k,
+			filter: payload.filter,
+			types: payload.types,
+			updated_after: payload.updated_after,
+			updated_before: payload.updated_before,
+			min_importance: payload.min_importance,
+			max_result_tokens: payload.max_result_tokens,
+			payload_level: payload.payload_level.unwrap_or_default(),
+			// Matches `searches_create`: hits are recorded when session notes are read, and ranking
+			// overrides are only accepted by the admin raw search.
+			record_hits: Some(false),
+			ranking: None,
+			session_id: payload.session_id,
+			session_mode: payload.session_mode,
+		},
+		mode,
+		delivery: SearchV2Delivery::Session,
+	};
+	let (stage_sender, mut stage_receiver) = mpsc::unbounded_channel();
+	let (event_sender, event_receiver) = mpsc::unbounded_channel();
+	let service = state.service.clone();
+
+	tokio::spawn(async move {
+		let search = service.search_v2_streaming(request, stage_sender);
+		let forward_stages = async {
+			while let Some(stage) = stage_receiver.recv().await {
+				send_event(&event_sender, "stage", &stage);
+			}
+		};
+		let (result, ()) = tokio::join!(search, forward_stages);
+		let session_result = result.map_err(ApiError::from).and_then(|response| {
+			let session = response.session.ok_or_else(|| {
+				routes::json_error(
+					StatusCode::INTERNAL_SERVER_ERROR,
+					"INTERNAL_ERROR",
+					"Search session was not created.",
+					None,
+				)
+			})?;
+
+			Ok(SearchCreateResponseV2 {
+				mode,
+				trace_id: response.trace_id,
+				search_id: session.search_session_id,
+				expires_at: session.expires_at,
+				items: session.items,
+				trajectory_summary: response.trajectory_summary,
+				query_plan: response.query_plan,
+				warnings: response.warnings,
+			})
+		});
+
+		match session_result {
+			Ok(response) => send_event(&event_sender, "result", &response),
+			Err(err) => send_event(
+				&event_sender,
+				"error",
+				&ErrorBody { error_code: err.error_code, message: err.message, fields: err.fields },
+			),
+		}
+	});
+
+	Ok(Sse::new(UnboundedReceiverStream::new(event_receiver))
+		.keep_alive(KeepAlive::default())
+		.into_response())
+}
+
+fn send_event<T>(sender: &UnboundedSender<Result<Event, Infallible>>, name: &str, data: &T)
+where
+	T: Serialize,
+{
+	match Event::default().event(name).json_data(data) {
+		Ok(event) => {
+			let _ = sender.send(Ok(event));
+		},
+		Err(err) =>
+			tracing::error!(error = %err, event = name, "Failed to encode search stream event."),
+	}
+}
@@ -1,6 +1,7 @@
 use crate::routes::{
 	self, ApiError, JsonRejection, MAX_CANDIDATE_K, MAX_NOTE_IDS_PER_DETAILS, MAX_QUERY_CHARS,
-	MAX_TOP_K, QueryRejection, SearchCreateRequest, SearchDetailsBody, StatusCode,
+	MAX_SEARCH_BATCH_QUERIES, MAX_TOP_K, QueryRejection, RankDocumentsBody, SearchBatchBody,
+	SearchCreateRequest, SearchDetailsBody, SearchScopedBody, SearchWithProfileBody, StatusCode,
 };
 
 pub(super) fn invalid_json_payload(err: JsonRejection) -> ApiError {
@@ -59,6 +60,67 @@ pub(super) fn validate_search_raw_payload(
 	)
 }
 
+pub(super) fn validate_search_scoped_payload(
+	payload: &SearchScopedBody,
+	default_top_k: u32,
+	default_candidate_k: u32,
+) -> Result<(), ApiError> {
+	validate_search_limits(
+		payload.query.as_str(),
+		payload.top_k,
+		payload.candidate_k,
+		default_top_k,
+		default_candidate_k,
+	)?;
+
+	for (scope, top_k) in &payload.scope_top_k {
+		if *top_k > MAX_TOP_K {
+			return Err(routes::json_error(
+				StatusCode::BAD_REQUEST,
+				"INVALID_REQUEST",
+				"scope_top_k is too large.",
+				Some(vec![format!("$.scope_top_k.{scope}")]),
+			));
+		}
+	}
+
+	Ok(())
+}
+
+pub(super) fn validate_search_with_profile_payload(
+	payload: &SearchWithProfileBody,
+) -> Result<(), ApiError> {
+	// Profile limits were validated when the profile was stored; only caller overrides are checked.
+	validate_search_limits(payload.query.as_str(), payload.top_k, None, 0, 0)
+}
+
+pub(super) fn validate_search_batch_payload(
+	payload: &SearchBatchBody,
+	default_top_k: u32,
+	default_candidate_k: u32,
+) -> Result<(), ApiError> {
+	if payload.searches.is_empty() || payload.searches.len() > MAX_SEARCH_BATCH_QUERIES {
+		return Err(routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			format!("searches must contain 1 to {MAX_SEARCH_BATCH_QUERIES} searches."),
+			Some(vec!["$.searches".to_string()]),
+		));
+	}
+
+	for search in &payload.searches {
+		validate_search_limits(
+			search.query.as_str(),
+			search.top_k,
+			search.candidate_k,
+			default_top_k,
+			default_candidate_k,
+		)?;
+	}
+
+	Ok(())
+}
+
 pub(super) fn validate_search_details_payload(payload: &SearchDetailsBody) -> Result<(), ApiError> {
 	if payload.note_ids.len() > MAX_NOTE_IDS_PER_DETAILS {
 		return Err(routes::json_error(
@@ -72,6 +134,19 @@ pub(super) fn validate_search_details_payload(payload: &SearchDetailsBody) -> Re
 	Ok(())
 }
 
+pub(super) fn validate_rank_documents_payload(payload: &RankDocumentsBody) -> Result<(), ApiError> {
+	if payload.query.chars().count() > MAX_QUERY_CHARS {
+		return Err(routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Query is too long.",
+			Some(vec!["$.query".to_string()]),
+		));
+	}
+
+	Ok(())
+}
+
 fn validate_search_limits(
 	query: &str,
 	top_k: Option<u32>,
@@ -0,0 +1,145 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, Path, RequestContext,
+	SearchProfileDeleteResponse, SearchProfileGetRequest, SearchProfilePutBody,
+	SearchProfileResponse, SearchProfileUpsertRequest, SearchProfilesListRequest,
+	SearchProfilesListResponse, State, StatusCode,
+};
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/search-profiles",
+	tag = "admin",
+	responses(
+		(status = 200, description = "Search profiles of the project.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_search_profiles_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+) -> Result<Json<SearchProfilesListResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.search_profiles_list(SearchProfilesListRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/search-profiles/{name}",
+	tag = "admin",
+	params(("name" = String, Path, description = "Search profile name.")),
+	responses(
+		(status = 200, description = "Search profile.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Search profile not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_search_profile_get(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(name): Path<String>,
+) -> Result<Json<SearchProfileResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.search_profile_get(SearchProfileGetRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			name,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	put,
+	path = "/v2/admin/search-profiles/{name}",
+	tag = "admin",
+	params(("name" = String, Path, description = "Search profile name.")),
+	request_body = Value,
+	responses(
+		(status = 200, description = "Search profile was stored.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_search_profile_put(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(name): Path<String>,
+	payload: Result<Json<SearchProfilePutBody>, JsonRejection>,
+) -> Result<Json<SearchProfileResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.search_profile_upsert(SearchProfileUpsertRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			name,
+			description: payload.description,
+			template: payload.template,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	delete,
+	path = "/v2/admin/search-profiles/{name}",
+	tag = "admin",
+	params(("name" = String, Path, description = "Search profile name.")),
+	responses(
+		(status = 200, description = "Search profile was deleted.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Search profile not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn admin_search_profile_delete(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(name): Path<String>,
+) -> Result<Json<SearchProfileDeleteResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.search_profile_delete(SearchProfileGetRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			name,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
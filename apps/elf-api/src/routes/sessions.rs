@@ -0,0 +1,146 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, Extension, HeaderMap, Json, JsonRejection, Path,
+	RequestContext, SecurityAuthRole, SessionAppendBody, SessionAppendRequest,
+	SessionAppendResponse, SessionGetRequest, SessionGetResponse, SessionSummarizeBody,
+	SessionSummarizeRequest, SessionSummarizeResponse, State, StatusCode,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/sessions/{session_id}/messages",
+	tag = "sessions",
+	params(("session_id" = String, Path, description = "Caller-chosen session ID.")),
+	request_body = Value,
+	responses(
+		(status = 200, description = "Messages were appended to the session.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn session_append(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	Path(session_id): Path<String>,
+	payload: Result<Json<SessionAppendBody>, JsonRejection>,
+) -> Result<Json<SessionAppendResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.session_append(SessionAppendRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role: role.map(|Extension(role)| role),
+			session_id,
+			ttl_seconds: payload.ttl_seconds,
+			messages: payload.messages,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/sessions/{session_id}",
+	tag = "sessions",
+	params(("session_id" = String, Path, description = "Caller-chosen session ID.")),
+	responses(
+		(status = 200, description = "Unexpired session messages.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 404, description = "Session not found or expired.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn session_get(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(session_id): Path<String>,
+) -> Result<Json<SessionGetResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.session_get(SessionGetRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			session_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	post,
+	path = "/v2/sessions/{session_id}/summarize",
+	tag = "sessions",
+	params(("session_id" = String, Path, description = "Caller-chosen session ID.")),
+	request_body = Value,
+	responses(
+		(status = 200, description = "Session messages were summarized into notes.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Scope denied.", body = ErrorBody),
+		(status = 404, description = "Session not found or expired.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn session_summarize(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	role: Option<Extension<SecurityAuthRole>>,
+	Path(session_id): Path<String>,
+	payload: Result<Json<SessionSummarizeBody>, JsonRejection>,
+) -> Result<Json<SessionSummarizeResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let role = role.map(|Extension(role)| role);
+
+	if payload.scope.as_deref().map(str::trim) == Some("org_shared") {
+		routes::require_admin_for_org_shared_writes(
+			state.service.cfg.security.auth_mode.as_str(),
+			role,
+		)?;
+	}
+
+	let response = state
+		.service
+		.session_summarize_to_notes(SessionSummarizeRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			role,
+			session_id,
+			scope: payload.scope,
+			dry_run: payload.dry_run,
+			locale: payload.locale,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
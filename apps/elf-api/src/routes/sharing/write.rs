@@ -40,6 +40,8 @@ pub(in crate::routes) async fn space_grant_upsert(
 	let scope = routes::parse_space(space.as_str())?;
 	let role = role.map(|Extension(role)| role);
 
+	routes::require_writer_role(role)?;
+
 	if matches!(scope, ShareScope::OrgShared) {
 		routes::require_admin_for_org_shared_writes(
 			state.service.cfg.security.auth_mode.as_str(),
@@ -102,6 +104,8 @@ pub(in crate::routes) async fn space_grant_revoke(
 	let scope = routes::parse_space(space.as_str())?;
 	let role = role.map(|Extension(role)| role);
 
+	routes::require_writer_role(role)?;
+
 	if matches!(scope, ShareScope::OrgShared) {
 		routes::require_admin_for_org_shared_writes(
 			state.service.cfg.security.auth_mode.as_str(),
@@ -0,0 +1,195 @@
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, JsonRejection, Path, Query,
+	QueryRejection, RequestContext, StandingQueriesListRequest, StandingQueriesListResponse,
+	StandingQueryCreateBody, StandingQueryCreateRequest, StandingQueryDeleteResponse,
+	StandingQueryGetRequest, StandingQueryMatchesQuery, StandingQueryMatchesRequest,
+	StandingQueryMatchesResponse, StandingQueryResponse, State, StatusCode, Uuid,
+};
+
+#[utoipa::path(
+	post,
+	path = "/v2/standing-queries",
+	tag = "standing_queries",
+	request_body = Value,
+	responses(
+		(status = 200, description = "Standing query was registered.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 422, description = "Non-English input rejected.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn standing_query_create(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	payload: Result<Json<StandingQueryCreateBody>, JsonRejection>,
+) -> Result<Json<StandingQueryResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let read_profile = routes::required_read_profile(&headers)?;
+	let Json(payload) = payload.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid request payload.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid request payload.",
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.standing_query_create(StandingQueryCreateRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			read_profile,
+			query: payload.query,
+			top_k: payload.top_k,
+			filter: payload.filter,
+			webhook_url: payload.webhook_url,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/standing-queries",
+	tag = "standing_queries",
+	responses(
+		(status = 200, description = "Standing queries registered by the caller.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn standing_queries_list(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+) -> Result<Json<StandingQueriesListResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.standing_queries_list(StandingQueriesListRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/standing-queries/{standing_query_id}",
+	tag = "standing_queries",
+	params(("standing_query_id" = Uuid, Path, description = "Standing query ID.")),
+	responses(
+		(status = 200, description = "Standing query registered by the caller.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 404, description = "Standing query not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn standing_query_get(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(standing_query_id): Path<Uuid>,
+) -> Result<Json<StandingQueryResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.standing_query_get(StandingQueryGetRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			standing_query_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	delete,
+	path = "/v2/standing-queries/{standing_query_id}",
+	tag = "standing_queries",
+	params(("standing_query_id" = Uuid, Path, description = "Standing query ID.")),
+	responses(
+		(status = 200, description = "Standing query was deleted.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 404, description = "Standing query not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn standing_query_delete(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(standing_query_id): Path<Uuid>,
+) -> Result<Json<StandingQueryDeleteResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let response = state
+		.service
+		.standing_query_delete(StandingQueryGetRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			standing_query_id,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
+
+#[utoipa::path(
+	get,
+	path = "/v2/standing-queries/{standing_query_id}/matches",
+	tag = "standing_queries",
+	params(
+		("standing_query_id" = Uuid, Path, description = "Standing query ID."),
+		("after_seq" = Option<i64>, Query, description = "Return matches after this change-feed position."),
+		("limit" = Option<u32>, Query, description = "Maximum number of matches to return."),
+	),
+	responses(
+		(status = 200, description = "Standing query match change feed.", body = Value),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 404, description = "Standing query not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn standing_query_matches(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(standing_query_id): Path<Uuid>,
+	query: Result<Query<StandingQueryMatchesQuery>, QueryRejection>,
+) -> Result<Json<StandingQueryMatchesResponse>, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let Query(query) = query.map_err(|err| {
+		tracing::warn!(error = %err, "Invalid query parameters.");
+
+		routes::json_error(
+			StatusCode::BAD_REQUEST,
+			"INVALID_REQUEST",
+			"Invalid query parameters.".to_string(),
+			None,
+		)
+	})?;
+	let response = state
+		.service
+		.standing_query_matches(StandingQueryMatchesRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			standing_query_id,
+			after_seq: query.after_seq,
+			limit: query.limit,
+		})
+		.await?;
+
+	Ok(Json(response))
+}
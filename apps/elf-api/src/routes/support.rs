@@ -1,24 +1,31 @@
+mod audit;
 mod auth;
 mod errors;
 mod headers;
+mod quota;
 mod request_id;
 mod scope;
 mod support_types;
+mod telemetry;
 mod time;
 
+#[cfg(test)]
+pub(super) use self::{
+	audit::audit_transport,
+	auth::{apply_auth_key_context, resolve_auth_key, sanitize_trusted_token_header},
+	request_id::{inject_request_id_into_json_body, parse_request_id_from_headers},
+};
 pub(super) use self::{
+	audit::audit_transport_middleware,
 	auth::{
 		admin_auth_middleware, api_auth_middleware, effective_token_id,
-		require_admin_for_org_shared_writes,
+		require_admin_for_org_shared_writes, require_writer_role,
 	},
 	errors::{ApiError, json_error},
 	headers::{RequestContext, required_read_profile},
+	quota::search_quota_middleware,
 	scope::{format_scope, format_space, parse_space},
-	support_types::{EntityMemoryQuery, SearchMode, empty_json_object},
+	support_types::{EntityMemoryQuery, empty_json_object},
+	telemetry::trace_context_middleware,
 	time::parse_optional_rfc3339,
 };
-#[cfg(test)]
-pub(super) use self::{
-	auth::{apply_auth_key_context, resolve_auth_key, sanitize_trusted_token_header},
-	request_id::{inject_request_id_into_json_body, parse_request_id_from_headers},
-};
@@ -0,0 +1,51 @@
+use axum::extract::MatchedPath;
+
+use crate::routes::{
+	AppState, AuditTransport, Body, HEADER_AGENT_ID, HEADER_PROJECT_ID, HEADER_TENANT_ID,
+	HeaderMap, Next, Request, Response, State, support::auth, with_audit_transport,
+};
+
+/// Attaches the caller and route of the request to the audit entries its service calls record.
+///
+/// Runs as a route layer inside authentication, so the tenant, agent, and trusted token headers
+/// are the ones bound to the caller. The service decides which operations are audited and records
+/// their targets and outcomes; this layer only supplies transport metadata.
+pub(in crate::routes) async fn audit_transport_middleware(
+	State(state): State<AppState>,
+	req: Request<Body>,
+	next: Next,
+) -> Response {
+	let transport = audit_transport(
+		state.service.cfg.security.auth_mode.as_str(),
+		req.method().as_str(),
+		req.extensions().get::<MatchedPath>().map(MatchedPath::as_str),
+		req.headers(),
+	);
+
+	with_audit_transport(transport, next.run(req)).await
+}
+
+pub(in crate::routes) fn audit_transport(
+	auth_mode: &str,
+	method: &str,
+	template: Option<&str>,
+	headers: &HeaderMap,
+) -> AuditTransport {
+	AuditTransport {
+		tenant_id: header_value(headers, HEADER_TENANT_ID),
+		project_id: header_value(headers, HEADER_PROJECT_ID),
+		actor: header_value(headers, HEADER_AGENT_ID),
+		token_id: auth::effective_token_id(auth_mode, headers),
+		method: Some(method.to_string()),
+		path: template.map(str::to_string),
+	}
+}
+
+fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
+	headers
+		.get(name)
+		.and_then(|value| value.to_str().ok())
+		.map(str::trim)
+		.filter(|value| !value.is_empty())
+		.map(str::to_string)
+}
@@ -7,6 +7,7 @@ use crate::routes::{
 		request_id,
 	},
 };
+use elf_runtime::jwt::{JwtError, JwtIdentity};
 
 pub(in super::super) fn trusted_token_id(headers: &HeaderMap) -> Option<String> {
 	let raw = headers.get(HEADER_TRUSTED_TOKEN_ID)?;
@@ -21,7 +22,7 @@ pub(in super::super) fn sanitize_trusted_token_header(headers: &mut HeaderMap) {
 
 pub(in super::super) fn effective_token_id(auth_mode: &str, headers: &HeaderMap) -> Option<String> {
 	match auth_mode.trim() {
-		"static_keys" => trusted_token_id(headers),
+		"static_keys" | "jwt" => trusted_token_id(headers),
 		_ => None,
 	}
 }
@@ -99,11 +100,80 @@ pub(in super::super) fn apply_auth_key_context(
 	Ok(())
 }
 
+pub(in super::super) async fn resolve_jwt_identity(
+	state: &AppState,
+	headers: &HeaderMap,
+) -> Result<JwtIdentity, ApiError> {
+	let unauthorized = || {
+		errors::json_error(
+			StatusCode::UNAUTHORIZED,
+			"UNAUTHORIZED",
+			"Authentication required.",
+			None,
+		)
+	};
+	let token = bearer_token(headers).ok_or_else(unauthorized)?;
+	let verifier = state.jwt.as_ref().ok_or_else(|| {
+		errors::json_error(
+			StatusCode::INTERNAL_SERVER_ERROR,
+			"INTERNAL_ERROR",
+			"Invalid security.jwt configuration.",
+			None,
+		)
+	})?;
+
+	verifier.verify(token.as_str()).await.map_err(|err| match err {
+		JwtError::InvalidToken(reason) => {
+			tracing::debug!(reason, "Rejected bearer JWT.");
+
+			unauthorized()
+		},
+		err => {
+			tracing::error!(error = %err, "Failed to verify bearer JWT.");
+
+			errors::json_error(
+				StatusCode::SERVICE_UNAVAILABLE,
+				"AUTH_UNAVAILABLE",
+				"Token verification is unavailable.",
+				None,
+			)
+		},
+	})
+}
+
+pub(in super::super) fn apply_jwt_context(
+	headers: &mut HeaderMap,
+	identity: &JwtIdentity,
+) -> Result<(), ApiError> {
+	set_context_header(headers, HEADER_TENANT_ID, identity.tenant_id.as_str())?;
+	set_context_header(headers, HEADER_PROJECT_ID, identity.project_id.as_str())?;
+	set_context_header(headers, HEADER_AGENT_ID, identity.agent_id.as_str())?;
+	set_context_header(headers, HEADER_READ_PROFILE, identity.read_profile.as_str())?;
+	set_context_header(headers, HEADER_TRUSTED_TOKEN_ID, identity.token_id().as_str())?;
+
+	Ok(())
+}
+
+pub(in super::super) fn require_writer_role(
+	role: Option<SecurityAuthRole>,
+) -> Result<(), ApiError> {
+	if role == Some(SecurityAuthRole::Reader) {
+		return Err(errors::json_error(
+			StatusCode::FORBIDDEN,
+			"FORBIDDEN",
+			"Reader tokens cannot write.",
+			None,
+		));
+	}
+
+	Ok(())
+}
+
 pub(in super::super) fn require_admin_for_org_shared_writes(
 	auth_mode: &str,
 	role: Option<SecurityAuthRole>,
 ) -> Result<(), ApiError> {
-	if auth_mode.trim() != "static_keys" {
+	if !matches!(auth_mode.trim(), "static_keys" | "jwt") {
 		return Ok(());
 	}
 	if matches!(role, Some(SecurityAuthRole::Admin | SecurityAuthRole::SuperAdmin)) {
@@ -118,99 +188,102 @@ pub(in super::super) async fn api_auth_middleware(
 	req: Request<Body>,
 	next: Next,
 ) -> Response {
-	let security = &state.service.cfg.security;
-	let request_id = match request_id::parse_request_id_from_headers(req.headers()) {
-		Ok(request_id) => request_id,
-		Err(err) => return request_id::with_request_id(err.into_response(), Uuid::new_v4()).await,
-	};
-	let mut req = req;
+	run_authenticated(&state, req, next, false).await
+}
 
-	sanitize_trusted_token_header(req.headers_mut());
+pub(in super::super) async fn admin_auth_middleware(
+	State(state): State<AppState>,
+	req: Request<Body>,
+	next: Next,
+) -> Response {
+	run_authenticated(&state, req, next, true).await
+}
 
-	let response = match security.auth_mode.trim() {
-		"off" => next.run(req).await,
-		"static_keys" => {
-			let key = match resolve_auth_key(req.headers(), &security.auth_keys) {
-				Ok(key) => key,
-				Err(err) => {
-					return request_id::with_request_id(err.into_response(), request_id).await;
-				},
-			};
+/// Bearer credential resolved under `security.auth_mode`.
+enum Credential<'a> {
+	StaticKey(&'a SecurityAuthKey),
+	Jwt(JwtIdentity),
+}
+impl Credential<'_> {
+	fn role(&self) -> SecurityAuthRole {
+		match self {
+			Self::StaticKey(key) => key.role,
+			Self::Jwt(identity) => identity.role,
+		}
+	}
 
-			req.extensions_mut().insert(key.role);
+	fn apply_context(&self, headers: &mut HeaderMap) -> Result<(), ApiError> {
+		match self {
+			Self::StaticKey(key) => apply_auth_key_context(headers, key),
+			Self::Jwt(identity) => apply_jwt_context(headers, identity),
+		}
+	}
+}
 
-			if let Err(err) = apply_auth_key_context(req.headers_mut(), key) {
-				return request_id::with_request_id(err.into_response(), request_id).await;
-			}
+/// Resolves the request credential, or `None` when `security.auth_mode` is `off`.
+async fn resolve_credential<'a>(
+	state: &'a AppState,
+	headers: &HeaderMap,
+) -> Result<Option<Credential<'a>>, ApiError> {
+	let security = &state.service.cfg.security;
 
-			next.run(req).await
-		},
-		_ => errors::json_error(
+	match security.auth_mode.trim() {
+		"off" => Ok(None),
+		"static_keys" => resolve_auth_key(headers, &security.auth_keys)
+			.map(|key| Some(Credential::StaticKey(key))),
+		"jwt" => resolve_jwt_identity(state, headers).await.map(|id| Some(Credential::Jwt(id))),
+		_ => Err(errors::json_error(
 			StatusCode::INTERNAL_SERVER_ERROR,
 			"INTERNAL_ERROR",
 			"Invalid security.auth_mode configuration.",
 			None,
-		)
-		.into_response(),
-	};
-
-	request_id::with_request_id(response, request_id).await
+		)),
+	}
 }
 
-pub(in super::super) async fn admin_auth_middleware(
-	State(state): State<AppState>,
-	req: Request<Body>,
+async fn run_authenticated(
+	state: &AppState,
+	mut req: Request<Body>,
 	next: Next,
+	admin_only: bool,
 ) -> Response {
-	let security = &state.service.cfg.security;
 	let request_id = match request_id::parse_request_id_from_headers(req.headers()) {
 		Ok(request_id) => request_id,
 		Err(err) => return request_id::with_request_id(err.into_response(), Uuid::new_v4()).await,
 	};
-	let mut req = req;
 
 	sanitize_trusted_token_header(req.headers_mut());
 
-	let response = match security.auth_mode.trim() {
-		"off" => next.run(req).await,
-		"static_keys" => {
-			let key = match resolve_auth_key(req.headers(), &security.auth_keys) {
-				Ok(key) => key,
-				Err(err) => {
-					return request_id::with_request_id(err.into_response(), request_id).await;
-				},
-			};
-
-			req.extensions_mut().insert(key.role);
-
-			if !matches!(key.role, SecurityAuthRole::Admin | SecurityAuthRole::SuperAdmin) {
-				return request_id::with_request_id(
-					errors::json_error(
-						StatusCode::FORBIDDEN,
-						"FORBIDDEN",
-						"Admin token required.",
-						None,
-					)
-					.into_response(),
-					request_id,
-				)
-				.await;
-			}
-
-			if let Err(err) = apply_auth_key_context(req.headers_mut(), key) {
-				return request_id::with_request_id(err.into_response(), request_id).await;
-			}
-
-			next.run(req).await
-		},
-		_ => errors::json_error(
-			StatusCode::INTERNAL_SERVER_ERROR,
-			"INTERNAL_ERROR",
-			"Invalid security.auth_mode configuration.",
-			None,
-		)
-		.into_response(),
+	let response = match authenticate(state, &mut req, admin_only).await {
+		Ok(()) => next.run(req).await,
+		Err(err) => err.into_response(),
 	};
 
 	request_id::with_request_id(response, request_id).await
 }
+
+/// Records the caller's role on the request and replaces its context headers with the
+/// credential's context.
+async fn authenticate(
+	state: &AppState,
+	req: &mut Request<Body>,
+	admin_only: bool,
+) -> Result<(), ApiError> {
+	let Some(credential) = resolve_credential(state, req.headers()).await? else {
+		return Ok(());
+	};
+	let role = credential.role();
+
+	req.extensions_mut().insert(role);
+
+	if admin_only && !matches!(role, SecurityAuthRole::Admin | SecurityAuthRole::SuperAdmin) {
+		return Err(errors::json_error(
+			StatusCode::FORBIDDEN,
+			"FORBIDDEN",
+			"Admin token required.",
+			None,
+		));
+	}
+
+	credential.apply_context(req.headers_mut())
+}
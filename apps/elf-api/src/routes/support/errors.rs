@@ -37,6 +37,8 @@ impl From<Error> for ApiError {
 				json_error(StatusCode::NOT_FOUND, "NOT_FOUND", message, None),
 			Error::Conflict { message } =>
 				json_error(StatusCode::CONFLICT, "CONFLICT", message, None),
+			Error::QuotaExceeded { message } =>
+				json_error(StatusCode::TOO_MANY_REQUESTS, "QUOTA_EXCEEDED", message, None),
 			Error::Provider { message } => {
 				let sanitized = sanitize_log_text(message.as_str());
 
@@ -0,0 +1,29 @@
+use axum::{
+	http::{HeaderValue, header::RETRY_AFTER},
+	response::IntoResponse,
+};
+
+use crate::routes::{ApiError, AppState, Body, HEADER_TENANT_ID, Next, Request, Response, State};
+
+/// Rejects searches beyond the tenant's `security.quotas.max_search_qps` with HTTP 429.
+///
+/// Runs after authentication, so the tenant header is the one bound to the caller's key.
+pub(in crate::routes) async fn search_quota_middleware(
+	State(state): State<AppState>,
+	req: Request<Body>,
+	next: Next,
+) -> Response {
+	let tenant_id = req.headers().get(HEADER_TENANT_ID).and_then(|value| value.to_str().ok());
+
+	if let Some(tenant_id) = tenant_id
+		&& let Err(err) = state.service.check_search_quota(tenant_id.trim())
+	{
+		let mut response = ApiError::from(err).into_response();
+
+		response.headers_mut().insert(RETRY_AFTER, HeaderValue::from_static("1"));
+
+		return response;
+	}
+
+	next.run(req).await
+}
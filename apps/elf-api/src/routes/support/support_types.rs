@@ -1,11 +1,4 @@
-use crate::routes::{Deserialize, Map, Serialize, Uuid, Value};
-
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "snake_case")]
-pub(in super::super) enum SearchMode {
-	QuickFind,
-	PlannedSearch,
-}
+use crate::routes::{Deserialize, Map, Uuid, Value};
 
 #[derive(Clone, Debug, Deserialize)]
 pub(in super::super) struct EntityMemoryQuery {
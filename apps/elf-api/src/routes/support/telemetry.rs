@@ -0,0 +1,47 @@
+use axum::{
+	extract::{MatchedPath, Request},
+	middleware::Next,
+};
+use opentelemetry::{global, propagation::Extractor};
+use tracing::{Instrument, field};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::routes::{HeaderMap, Response};
+
+struct HeaderCarrier<'a>(&'a HeaderMap);
+impl Extractor for HeaderCarrier<'_> {
+	fn get(&self, key: &str) -> Option<&str> {
+		self.0.get(key).and_then(|value| value.to_str().ok())
+	}
+
+	fn keys(&self) -> Vec<&str> {
+		self.0.keys().map(|key| key.as_str()).collect()
+	}
+}
+
+/// Wraps each request in an `http.request` span parented by the caller's W3C `traceparent`.
+pub(in crate::routes) async fn trace_context_middleware(req: Request, next: Next) -> Response {
+	let route = req
+		.extensions()
+		.get::<MatchedPath>()
+		.map(|path| path.as_str().to_string())
+		.unwrap_or_else(|| req.uri().path().to_string());
+	let span = tracing::info_span!(
+		"http.request",
+		http.method = %req.method(),
+		http.route = %route,
+		http.status_code = field::Empty,
+	);
+	let parent = global::get_text_map_propagator(|propagator| {
+		propagator.extract(&HeaderCarrier(req.headers()))
+	});
+
+	// Without an OpenTelemetry layer installed there is no context to attach, which is fine.
+	let _ = span.set_parent(parent);
+
+	let response = next.run(req).instrument(span.clone()).await;
+
+	span.record("http.status_code", response.status().as_u16());
+
+	response
+}
@@ -1,5 +1,7 @@
 mod admin_auth;
 mod admin_viewer;
+mod audit;
 mod auth_key_context;
 mod auth_key_resolution;
 mod request_id;
+mod search_shadow_body;
@@ -0,0 +1,21 @@
+use axum::http::{HeaderMap, HeaderValue};
+
+use crate::routes::{self, HEADER_AGENT_ID, HEADER_PROJECT_ID, HEADER_TENANT_ID};
+
+#[test]
+fn audit_transport_carries_caller_headers_and_route_template() {
+	let mut headers = HeaderMap::new();
+
+	headers.insert(HEADER_TENANT_ID, HeaderValue::from_static("t1"));
+	headers.insert(HEADER_PROJECT_ID, HeaderValue::from_static(" p1 "));
+	headers.insert(HEADER_AGENT_ID, HeaderValue::from_static(""));
+
+	let transport = routes::audit_transport("off", "POST", Some("/v2/searches/feedback"), &headers);
+
+	assert_eq!(transport.tenant_id.as_deref(), Some("t1"));
+	assert_eq!(transport.project_id.as_deref(), Some("p1"));
+	assert_eq!(transport.actor, None);
+	assert_eq!(transport.token_id, None);
+	assert_eq!(transport.method.as_deref(), Some("POST"));
+	assert_eq!(transport.path.as_deref(), Some("/v2/searches/feedback"));
+}
@@ -0,0 +1,28 @@
+use serde_json::json;
+
+use crate::routes::SearchCreateRequest;
+
+#[test]
+fn search_shadow_body_replays_every_request_field() {
+	let request = json!({
+		"mode": "quick_find",
+		"query": "deploy cadence",
+		"top_k": 5,
+		"candidate_k": 40,
+		"filter": { "op": "eq", "field": "type", "value": "fact" },
+		"types": ["fact"],
+		"updated_after": "2026-01-01T00:00:00Z",
+		"updated_before": "2026-02-01T00:00:00Z",
+		"min_importance": 0.5,
+		"max_result_tokens": 800,
+		"payload_level": "l1",
+		"ranking": null,
+		"session_id": "session-1",
+		"session_mode": "boost",
+	});
+	let payload: SearchCreateRequest =
+		serde_json::from_value(request.clone()).expect("Expected a valid search request.");
+	let body = serde_json::to_value(&payload).expect("Expected an encoded shadow body.");
+
+	assert_eq!(body, request);
+}
@@ -1,7 +1,9 @@
+mod artifact;
 mod explain;
 mod read;
 
 pub(super) use self::{
+	artifact::{__path_trace_artifact_get, trace_artifact_get},
 	explain::{
 		__path_trace_item_get, __path_trace_trajectory_get, trace_item_get, trace_trajectory_get,
 	},
@@ -0,0 +1,58 @@
+use axum::http::{
+	HeaderValue,
+	header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE},
+};
+
+use crate::routes::{
+	self, ApiError, AppState, ErrorBody, HeaderMap, IntoResponse, Path, RequestContext, Response,
+	State, TraceArtifactGetRequest, Uuid,
+};
+use elf_service::search;
+
+#[utoipa::path(
+	get,
+	path = "/v2/admin/traces/{trace_id}/artifact",
+	tag = "admin",
+	params(("trace_id" = Uuid, Path, description = "Search trace ID.")),
+	responses(
+		(status = 200, description = "Gzip-compressed JSON trace artifact with a hashed manifest.", content_type = "application/gzip", body = Vec<u8>),
+		(status = 400, description = "Invalid request.", body = ErrorBody),
+		(status = 401, description = "Authentication required.", body = ErrorBody),
+		(status = 403, description = "Admin access required.", body = ErrorBody),
+		(status = 404, description = "Trace was not found.", body = ErrorBody),
+		(status = 500, description = "Internal error.", body = ErrorBody),
+	)
+)]
+pub(in crate::routes) async fn trace_artifact_get(
+	State(state): State<AppState>,
+	headers: HeaderMap,
+	Path(trace_id): Path<Uuid>,
+) -> Result<Response, ApiError> {
+	let ctx = RequestContext::from_headers(&headers)?;
+	let artifact = state
+		.service
+		.trace_artifact_get(TraceArtifactGetRequest {
+			tenant_id: ctx.tenant_id,
+			project_id: ctx.project_id,
+			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
+			trace_id,
+		})
+		.await?;
+	let bytes = search::encode_trace_artifact(&artifact)?;
+	let disposition = format!("attachment; filename=\"elf-trace-{trace_id}.json.gz\"");
+	let mut response = bytes.into_response();
+	let response_headers = response.headers_mut();
+
+	response_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/gzip"));
+	response_headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+
+	if let Ok(value) = HeaderValue::from_str(&disposition) {
+		response_headers.insert(CONTENT_DISPOSITION, value);
+	}
+
+	Ok(response)
+}
@@ -1,6 +1,7 @@
 use crate::routes::{
-	ApiError, AppState, ErrorBody, HeaderMap, Json, Path, RequestContext, SearchExplainRequest,
-	SearchExplainResponse, SearchTrajectoryResponse, State, TraceTrajectoryGetRequest, Uuid,
+	self, ApiError, AppState, ErrorBody, HeaderMap, Json, Path, RequestContext,
+	SearchExplainRequest, SearchExplainResponse, SearchTrajectoryResponse, State,
+	TraceTrajectoryGetRequest, Uuid,
 };
 
 #[utoipa::path(
@@ -29,6 +30,10 @@ pub(in crate::routes) async fn trace_trajectory_get(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
 			trace_id,
 		})
 		.await?;
@@ -62,6 +67,10 @@ pub(in crate::routes) async fn trace_item_get(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
 			result_handle: item_id,
 		})
 		.await?;
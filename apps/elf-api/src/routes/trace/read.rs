@@ -31,6 +31,10 @@ pub(in crate::routes) async fn trace_get(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
 			trace_id,
 		})
 		.await?;
@@ -98,6 +102,10 @@ pub(in crate::routes) async fn trace_recent_list(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
 			limit: query.limit,
 			cursor_created_at,
 			cursor_trace_id,
@@ -153,6 +161,10 @@ pub(in crate::routes) async fn trace_bundle_get(
 			tenant_id: ctx.tenant_id,
 			project_id: ctx.project_id,
 			agent_id: ctx.agent_id,
+			token_id: routes::effective_token_id(
+				state.service.cfg.security.auth_mode.as_str(),
+				&headers,
+			),
 			trace_id,
 			mode: query.mode.unwrap_or_default(),
 			stage_items_limit: query.stage_items_limit,
@@ -1,3 +1,4 @@
+mod admin_ops;
 mod consolidation;
 mod core_memory;
 mod docs;
@@ -9,22 +10,34 @@ mod knowledge;
 mod notes;
 mod recall;
 mod search;
+mod search_profiles;
+mod sessions;
 mod sharing;
+mod standing_queries;
 mod trace;
 mod work_journal;
 
 pub(in crate::routes) use self::{
+	admin_ops::{
+		AdminAccessSimulateBody, AdminAuditLogListQuery, AdminGrantPutBody, AdminGrantRevokeBody,
+		AdminGrantsListQuery, AdminOutboxDeadLetterListQuery, AdminOutboxReplayBody,
+		AdminReembedRunBody, AdminReembedRunsListQuery, AdminWriteIncidentsListQuery,
+		IndexVerifyBody, MemoryStatsQuery, QdrantAuditBody, QdrantMaintenanceRunBody,
+		QdrantMaintenanceRunsListQuery, RebuildQdrantBody,
+	},
 	consolidation::{
 		ConsolidationProposalReviewBody, ConsolidationProposalsListQuery,
 		ConsolidationRunCreateBody, ConsolidationRunsListQuery, DreamingReviewQueueQuery,
 	},
 	core_memory::{CoreBlockAttachBody, CoreBlockUpsertBody},
-	docs::{DocsExcerptsGetBody, DocsPutBody, DocsSearchL0Body},
+	docs::{DocsExcerptsGetBody, DocsPutBody, DocsSearchBody, DocsSearchL0Body},
 	errors::ErrorBody,
 	events::EventsIngestRequest,
 	graph::{
+		AdminGraphEntitiesMergeBody, AdminGraphEntityKindsListQuery, AdminGraphEntitySplitBody,
 		AdminGraphPredicateAliasAddBody, AdminGraphPredicatePatchBody,
-		AdminGraphPredicatesListQuery, GraphQueryBody, GraphReportBody,
+		AdminGraphPredicatesListQuery, GraphFactsAsOfBody, GraphNeighborhoodBody, GraphQueryBody,
+		GraphReportBody,
 	},
 	ingestion_profiles::{
 		AdminIngestionProfileCreateBody, AdminIngestionProfileDefaultResponseV2,
@@ -35,19 +48,25 @@ pub(in crate::routes) use self::{
 		KnowledgePagesSearchBody,
 	},
 	notes::{
-		AdminNoteCorrectionBody, NotePatchRequest, NotesIngestRequest, NotesListQuery,
-		PublishResponseV2,
+		AdminNoteCorrectionBody, NotePatchRequest, NotesBulkImportQuery, NotesCiteBody,
+		NotesDeleteBatchBody, NotesGetQuery, NotesIngestRequest, NotesListQuery, NotesMergeBody,
+		NotesRelationCreateBody, NotesSourceRefsResolveBody, NotesSubscribeQuery,
+		NotesUpdateBatchBody, PublishResponseV2,
 	},
-	recall::RecallDebugPanelBody,
+	recall::{RecallContextBody, RecallDebugPanelBody},
 	search::{
-		SearchCreateRequest, SearchCreateResponseV2, SearchDetailsBody, SearchDetailsResponseV2,
-		SearchIndexResponseV2, SearchSessionGetQuery, SearchTimelineQuery,
+		RankDocumentsBody, SearchBatchBody, SearchCreateRequest, SearchCreateResponseV2,
+		SearchDetailsBody, SearchDetailsResponseV2, SearchFeedbackBody, SearchIndexResponseV2,
+		SearchScopedBody, SearchSessionGetQuery, SearchShadowReportQuery, SearchTimelineQuery,
 		SearchTimelineResponseV2,
 	},
+	search_profiles::{SearchProfilePutBody, SearchWithProfileBody, SearchWithProfileResponseV2},
+	sessions::{SessionAppendBody, SessionSummarizeBody},
 	sharing::{
-		ShareScopeBody, SpaceGrantItemV2, SpaceGrantUpsertBody, SpaceGrantUpsertResponseV2,
-		SpaceGrantsListResponseV2,
+		MemoryTimelineQuery, OrgMemoryStatsQuery, ShareScopeBody, SpaceGrantItemV2,
+		SpaceGrantUpsertBody, SpaceGrantUpsertResponseV2, SpaceGrantsListResponseV2,
 	},
+	standing_queries::{StandingQueryCreateBody, StandingQueryMatchesQuery},
 	trace::{TraceBundleGetQuery, TraceRecentListQuery},
 	work_journal::{WorkJournalEntryCreateBody, WorkJournalSessionReadbackBody},
 };
@@ -62,8 +81,11 @@ use crate::routes::{
 	AddNoteInput, ConsolidationInputRef, ConsolidationLineage, ConsolidationProposalInput,
 	ConsolidationReviewAction, ConsolidationReviewState, DocType, EventMessage, GranteeKind,
 	GraphQueryEntityRef, GraphQueryPredicateRef, IngestionProfileSelector, KnowledgePageKind,
-	KnowledgeSourceKind, MemoryCorrectionAction, PayloadLevel, QueryPlan, RankingRequestOverride,
-	SearchDetailsResult, SearchIndexItem, SearchMode, SearchTimelineGroup, SearchTrajectorySummary,
-	TextPositionSelector, TextQuoteSelector, TraceBundleMode, WorkJournalEntryFamily, WritePolicy,
+	KnowledgeSourceKind, MemoryCorrectionAction, MemoryTimelineBucket, NoteMergeStrategy,
+	NoteRelationKind, PayloadLevel, QueryPlan, RankDocument, RankingRequestOverride,
+	RecallContextTurn, SearchDetailsResult, SearchFeedbackKind, SearchIndexItem, SearchProfileRef,
+	SearchProfileTemplate, SearchTimelineGroup, SearchTrajectorySummary, SearchV2Mode,
+	SearchWarning, SessionMessageInput, StandingQueryFilter, TextPositionSelector,
+	TextQuoteSelector, TraceBundleMode, UpdateBatchItem, WorkJournalEntryFamily, WritePolicy,
 	empty_json_object,
 };
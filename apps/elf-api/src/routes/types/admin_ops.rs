@@ -0,0 +1,110 @@
+use crate::routes::types::{Deserialize, GranteeKind, Uuid};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct QdrantAuditBody {
+	#[serde(default)]
+	pub(in crate::routes) quarantine: bool,
+	pub(in crate::routes) max_findings: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct RebuildQdrantBody {
+	pub(in crate::routes) tenant_id: Option<String>,
+	pub(in crate::routes) project_id: Option<String>,
+	pub(in crate::routes) scope: Option<String>,
+	pub(in crate::routes) note_type: Option<String>,
+	pub(in crate::routes) updated_after: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct IndexVerifyBody {
+	#[serde(default)]
+	pub(in crate::routes) repair: bool,
+	pub(in crate::routes) max_findings: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct AdminAuditLogListQuery {
+	pub(in crate::routes) operation: Option<String>,
+	pub(in crate::routes) token_id: Option<String>,
+	pub(in crate::routes) actor: Option<String>,
+	pub(in crate::routes) before: Option<String>,
+	pub(in crate::routes) limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct AdminWriteIncidentsListQuery {
+	pub(in crate::routes) agent_id: Option<String>,
+	pub(in crate::routes) limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct AdminOutboxDeadLetterListQuery {
+	pub(in crate::routes) limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct MemoryStatsQuery {
+	pub(in crate::routes) expiring_within_days: Option<u32>,
+	pub(in crate::routes) top_hits_limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct AdminOutboxReplayBody {
+	pub(in crate::routes) outbox_ids: Vec<Uuid>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct AdminAccessSimulateBody {
+	pub(in crate::routes) agent_id: String,
+	pub(in crate::routes) read_profile: String,
+	pub(in crate::routes) note_id: Uuid,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct QdrantMaintenanceRunBody {
+	pub(in crate::routes) operation: String,
+	pub(in crate::routes) collection: Option<String>,
+	pub(in crate::routes) snapshot_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct QdrantMaintenanceRunsListQuery {
+	pub(in crate::routes) operation: Option<String>,
+	pub(in crate::routes) limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct AdminReembedRunBody {
+	pub(in crate::routes) max_batches: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct AdminReembedRunsListQuery {
+	pub(in crate::routes) limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct AdminGrantPutBody {
+	pub(in crate::routes) project_id: Option<String>,
+	pub(in crate::routes) scope: String,
+	pub(in crate::routes) space_owner_agent_id: String,
+	pub(in crate::routes) grantee_kind: GranteeKind,
+	pub(in crate::routes) grantee_agent_id: Option<String>,
+	pub(in crate::routes) reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct AdminGrantsListQuery {
+	pub(in crate::routes) project_id: Option<String>,
+	pub(in crate::routes) scope: Option<String>,
+	pub(in crate::routes) space_owner_agent_id: Option<String>,
+	#[serde(default)]
+	pub(in crate::routes) include_revoked: bool,
+	pub(in crate::routes) limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(in crate::routes) struct AdminGrantRevokeBody {
+	pub(in crate::routes) reason: Option<String>,
+}
@@ -34,6 +34,13 @@ pub(in crate::routes) struct DocsSearchL0Body {
 	pub(in crate::routes) explain: Option<bool>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct DocsSearchBody {
+	#[serde(flatten)]
+	pub(in crate::routes) search: DocsSearchL0Body,
+	pub(in crate::routes) level: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct DocsExcerptsGetBody {
 	pub(in crate::routes) doc_id: Uuid,
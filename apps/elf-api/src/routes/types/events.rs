@@ -5,5 +5,6 @@ pub(in crate::routes) struct EventsIngestRequest {
 	pub(in crate::routes) scope: Option<String>,
 	pub(in crate::routes) dry_run: Option<bool>,
 	pub(in crate::routes) ingestion_profile: Option<IngestionProfileSelector>,
+	pub(in crate::routes) locale: Option<String>,
 	pub(in crate::routes) messages: Vec<EventMessage>,
 }
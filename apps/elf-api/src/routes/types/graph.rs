@@ -1,4 +1,4 @@
-use crate::routes::types::{Deserialize, GraphQueryEntityRef, GraphQueryPredicateRef};
+use crate::routes::types::{Deserialize, GraphQueryEntityRef, GraphQueryPredicateRef, Uuid};
 
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct GraphQueryBody {
@@ -20,11 +20,52 @@ pub(in crate::routes) struct GraphReportBody {
 	pub(in crate::routes) explain: Option<bool>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct GraphNeighborhoodBody {
+	pub(in crate::routes) entity: GraphQueryEntityRef,
+	pub(in crate::routes) depth: Option<u32>,
+	pub(in crate::routes) predicates: Option<Vec<GraphQueryPredicateRef>>,
+	pub(in crate::routes) scopes: Option<Vec<String>>,
+	pub(in crate::routes) as_of: Option<String>,
+	pub(in crate::routes) limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct GraphFactsAsOfBody {
+	pub(in crate::routes) entity: GraphQueryEntityRef,
+	pub(in crate::routes) as_of: Option<String>,
+	pub(in crate::routes) predicate: Option<GraphQueryPredicateRef>,
+	pub(in crate::routes) scopes: Option<Vec<String>>,
+	pub(in crate::routes) include_superseded: Option<bool>,
+	pub(in crate::routes) limit: Option<u32>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct AdminGraphPredicatesListQuery {
 	pub(in crate::routes) scope: Option<String>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct AdminGraphEntitiesMergeBody {
+	pub(in crate::routes) winner_entity_id: Uuid,
+	pub(in crate::routes) loser_entity_ids: Vec<Uuid>,
+	pub(in crate::routes) reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct AdminGraphEntitySplitBody {
+	pub(in crate::routes) canonical: String,
+	pub(in crate::routes) kind: Option<String>,
+	pub(in crate::routes) aliases: Option<Vec<String>>,
+	pub(in crate::routes) fact_ids: Option<Vec<Uuid>>,
+	pub(in crate::routes) reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct AdminGraphEntityKindsListQuery {
+	pub(in crate::routes) scope: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct AdminGraphPredicatePatchBody {
 	pub(in crate::routes) status: Option<String>,
@@ -1,5 +1,6 @@
 use crate::routes::types::{
-	AddNoteInput, Deserialize, MemoryCorrectionAction, Serialize, Uuid, Value,
+	AddNoteInput, Deserialize, MemoryCorrectionAction, NoteMergeStrategy, NoteRelationKind,
+	Serialize, UpdateBatchItem, Uuid, Value,
 };
 
 #[derive(Clone, Debug, Deserialize)]
@@ -15,6 +16,23 @@ pub(in crate::routes) struct NotesListQuery {
 	pub(in crate::routes) r#type: Option<String>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesSubscribeQuery {
+	pub(in crate::routes) after: Option<String>,
+	pub(in crate::routes) limit: Option<u32>,
+	pub(in crate::routes) wait_ms: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesBulkImportQuery {
+	pub(in crate::routes) scope: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesGetQuery {
+	pub(in crate::routes) include_access_stats: Option<bool>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct NotePatchRequest {
 	pub(in crate::routes) text: Option<String>,
@@ -23,6 +41,40 @@ pub(in crate::routes) struct NotePatchRequest {
 	pub(in crate::routes) ttl_days: Option<i64>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesUpdateBatchBody {
+	pub(in crate::routes) items: Vec<UpdateBatchItem>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesDeleteBatchBody {
+	pub(in crate::routes) note_ids: Vec<Uuid>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesRelationCreateBody {
+	pub(in crate::routes) to_note_id: Uuid,
+	pub(in crate::routes) relation: NoteRelationKind,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesCiteBody {
+	pub(in crate::routes) note_ids: Vec<Uuid>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesSourceRefsResolveBody {
+	pub(in crate::routes) note_ids: Vec<Uuid>,
+	pub(in crate::routes) level: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct NotesMergeBody {
+	pub(in crate::routes) secondary_note_id: Uuid,
+	#[serde(default)]
+	pub(in crate::routes) strategy: NoteMergeStrategy,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct AdminNoteCorrectionBody {
 	pub(in crate::routes) action: MemoryCorrectionAction,
@@ -1,4 +1,6 @@
-use crate::routes::types::{Deserialize, GraphQueryEntityRef, GraphQueryPredicateRef, Uuid};
+use crate::routes::types::{
+	Deserialize, GraphQueryEntityRef, GraphQueryPredicateRef, RecallContextTurn, SearchV2Mode, Uuid,
+};
 
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct RecallDebugPanelBody {
@@ -11,3 +13,13 @@ pub(in crate::routes) struct RecallDebugPanelBody {
 	pub(in crate::routes) include_dreaming: Option<bool>,
 	pub(in crate::routes) limit: Option<u32>,
 }
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct RecallContextBody {
+	pub(in crate::routes) turns: Vec<RecallContextTurn>,
+	pub(in crate::routes) max_queries: Option<u32>,
+	pub(in crate::routes) top_k: Option<u32>,
+	pub(in crate::routes) max_context_chars: Option<u32>,
+	#[serde(default)]
+	pub(in crate::routes) mode: SearchV2Mode,
+}
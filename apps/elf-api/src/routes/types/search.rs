@@ -1,24 +1,83 @@
+use std::collections::HashMap;
+
 use crate::routes::types::{
-	Deserialize, OffsetDateTime, PayloadLevel, QueryPlan, RankingRequestOverride,
-	SearchDetailsResult, SearchIndexItem, SearchMode, SearchTimelineGroup, SearchTrajectorySummary,
-	Serialize, Uuid, Value,
+	Deserialize, OffsetDateTime, PayloadLevel, QueryPlan, RankDocument, RankingRequestOverride,
+	SearchDetailsResult, SearchFeedbackKind, SearchIndexItem, SearchTimelineGroup,
+	SearchTrajectorySummary, SearchV2Mode, SearchWarning, Serialize, Uuid, Value,
 };
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub(in crate::routes) struct SearchCreateRequest {
-	pub(in crate::routes) mode: SearchMode,
+	pub(in crate::routes) mode: SearchV2Mode,
 	pub(in crate::routes) query: String,
 	pub(in crate::routes) top_k: Option<u32>,
 	pub(in crate::routes) candidate_k: Option<u32>,
 
 	pub(in crate::routes) filter: Option<Value>,
+	#[serde(default)]
+	pub(in crate::routes) types: Vec<String>,
+	pub(in crate::routes) updated_after: Option<String>,
+	pub(in crate::routes) updated_before: Option<String>,
+	pub(in crate::routes) min_importance: Option<f32>,
+	pub(in crate::routes) max_result_tokens: Option<u32>,
 	pub(in crate::routes) payload_level: Option<PayloadLevel>,
 	pub(in crate::routes) ranking: Option<RankingRequestOverride>,
+	pub(in crate::routes) session_id: Option<String>,
+	pub(in crate::routes) session_mode: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SearchBatchBody {
+	pub(in crate::routes) mode: SearchV2Mode,
+	pub(in crate::routes) searches: Vec<SearchBatchItemBody>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SearchBatchItemBody {
+	pub(in crate::routes) query: String,
+	pub(in crate::routes) top_k: Option<u32>,
+	pub(in crate::routes) candidate_k: Option<u32>,
+
+	pub(in crate::routes) filter: Option<Value>,
+	#[serde(default)]
+	pub(in crate::routes) types: Vec<String>,
+	pub(in crate::routes) updated_after: Option<String>,
+	pub(in crate::routes) updated_before: Option<String>,
+	pub(in crate::routes) min_importance: Option<f32>,
+	pub(in crate::routes) max_result_tokens: Option<u32>,
+	pub(in crate::routes) payload_level: Option<PayloadLevel>,
+	pub(in crate::routes) session_id: Option<String>,
+	pub(in crate::routes) session_mode: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SearchScopedBody {
+	pub(in crate::routes) mode: SearchV2Mode,
+	pub(in crate::routes) query: String,
+	pub(in crate::routes) top_k: Option<u32>,
+	pub(in crate::routes) candidate_k: Option<u32>,
+
+	pub(in crate::routes) filter: Option<Value>,
+	#[serde(default)]
+	pub(in crate::routes) types: Vec<String>,
+	pub(in crate::routes) updated_after: Option<String>,
+	pub(in crate::routes) updated_before: Option<String>,
+	pub(in crate::routes) min_importance: Option<f32>,
+	pub(in crate::routes) max_result_tokens: Option<u32>,
+	pub(in crate::routes) payload_level: Option<PayloadLevel>,
+	#[serde(default)]
+	pub(in crate::routes) scope_top_k: HashMap<String, u32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SearchFeedbackBody {
+	pub(in crate::routes) result_handle: Uuid,
+	pub(in crate::routes) feedback: SearchFeedbackKind,
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub(in crate::routes) struct SearchIndexResponseV2 {
-	pub(in crate::routes) mode: SearchMode,
+	pub(in crate::routes) mode: SearchV2Mode,
 	pub(in crate::routes) trace_id: Uuid,
 	pub(in crate::routes) search_id: Uuid,
 	#[serde(with = "elf_service::time_serde")]
@@ -32,7 +91,7 @@ pub(in crate::routes) struct SearchIndexResponseV2 {
 
 #[derive(Clone, Debug, Serialize)]
 pub(in crate::routes) struct SearchCreateResponseV2 {
-	pub(in crate::routes) mode: SearchMode,
+	pub(in crate::routes) mode: SearchV2Mode,
 	pub(in crate::routes) trace_id: Uuid,
 	pub(in crate::routes) search_id: Uuid,
 	#[serde(with = "elf_service::time_serde")]
@@ -42,6 +101,7 @@ pub(in crate::routes) struct SearchCreateResponseV2 {
 	pub(in crate::routes) trajectory_summary: Option<SearchTrajectorySummary>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub(in crate::routes) query_plan: Option<QueryPlan>,
+	pub(in crate::routes) warnings: Vec<SearchWarning>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -51,10 +111,17 @@ pub(in crate::routes) struct SearchSessionGetQuery {
 	pub(in crate::routes) touch: Option<bool>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SearchShadowReportQuery {
+	pub(in crate::routes) since: Option<String>,
+	pub(in crate::routes) limit: Option<u32>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct SearchTimelineQuery {
 	pub(in crate::routes) payload_level: Option<PayloadLevel>,
 	pub(in crate::routes) group_by: Option<String>,
+	pub(in crate::routes) group_limit: Option<u32>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -70,6 +137,7 @@ pub(in crate::routes) struct SearchDetailsBody {
 	pub(in crate::routes) note_ids: Vec<Uuid>,
 	pub(in crate::routes) payload_level: Option<PayloadLevel>,
 	pub(in crate::routes) record_hits: Option<bool>,
+	pub(in crate::routes) session_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -79,3 +147,10 @@ pub(in crate::routes) struct SearchDetailsResponseV2 {
 	pub(in crate::routes) expires_at: OffsetDateTime,
 	pub(in crate::routes) results: Vec<SearchDetailsResult>,
 }
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct RankDocumentsBody {
+	pub(in crate::routes) query: String,
+	pub(in crate::routes) docs: Vec<RankDocument>,
+	pub(in crate::routes) top_k: Option<u32>,
+}
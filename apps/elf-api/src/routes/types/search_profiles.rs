@@ -0,0 +1,37 @@
+use crate::routes::types::{
+	Deserialize, OffsetDateTime, PayloadLevel, QueryPlan, SearchIndexItem, SearchProfileRef,
+	SearchProfileTemplate, SearchTrajectorySummary, SearchV2Mode, SearchWarning, Serialize, Uuid,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SearchProfilePutBody {
+	pub(in crate::routes) description: Option<String>,
+	pub(in crate::routes) template: SearchProfileTemplate,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SearchWithProfileBody {
+	pub(in crate::routes) query: String,
+	pub(in crate::routes) top_k: Option<u32>,
+	pub(in crate::routes) payload_level: Option<PayloadLevel>,
+	pub(in crate::routes) updated_after: Option<String>,
+	pub(in crate::routes) updated_before: Option<String>,
+	pub(in crate::routes) session_id: Option<String>,
+	pub(in crate::routes) session_mode: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub(in crate::routes) struct SearchWithProfileResponseV2 {
+	pub(in crate::routes) profile: SearchProfileRef,
+	pub(in crate::routes) mode: SearchV2Mode,
+	pub(in crate::routes) trace_id: Uuid,
+	pub(in crate::routes) search_id: Uuid,
+	#[serde(with = "elf_service::time_serde")]
+	pub(in crate::routes) expires_at: OffsetDateTime,
+	pub(in crate::routes) items: Vec<SearchIndexItem>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(in crate::routes) trajectory_summary: Option<SearchTrajectorySummary>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(in crate::routes) query_plan: Option<QueryPlan>,
+	pub(in crate::routes) warnings: Vec<SearchWarning>,
+}
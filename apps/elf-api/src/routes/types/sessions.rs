@@ -0,0 +1,14 @@
+use crate::routes::types::{Deserialize, SessionMessageInput};
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SessionAppendBody {
+	pub(in crate::routes) ttl_seconds: Option<i64>,
+	pub(in crate::routes) messages: Vec<SessionMessageInput>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct SessionSummarizeBody {
+	pub(in crate::routes) scope: Option<String>,
+	pub(in crate::routes) dry_run: Option<bool>,
+	pub(in crate::routes) locale: Option<String>,
+}
@@ -1,4 +1,6 @@
-use crate::routes::types::{Deserialize, GranteeKind, OffsetDateTime, Serialize};
+use crate::routes::types::{
+	Deserialize, GranteeKind, MemoryTimelineBucket, OffsetDateTime, Serialize,
+};
 
 #[derive(Clone, Debug, Deserialize)]
 pub(in crate::routes) struct ShareScopeBody {
@@ -32,3 +34,20 @@ pub(in crate::routes) struct SpaceGrantItemV2 {
 pub(in crate::routes) struct SpaceGrantsListResponseV2 {
 	pub(in crate::routes) grants: Vec<SpaceGrantItemV2>,
 }
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct OrgMemoryStatsQuery {
+	pub(in crate::routes) min_count: Option<u32>,
+	pub(in crate::routes) limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct MemoryTimelineQuery {
+	#[serde(default)]
+	pub(in crate::routes) bucket: MemoryTimelineBucket,
+	pub(in crate::routes) since: Option<String>,
+	pub(in crate::routes) until: Option<String>,
+	pub(in crate::routes) max_buckets: Option<u32>,
+	pub(in crate::routes) representatives_per_bucket: Option<u32>,
+	pub(in crate::routes) top_entities_per_bucket: Option<u32>,
+}
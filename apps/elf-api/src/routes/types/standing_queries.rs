@@ -0,0 +1,16 @@
+use crate::routes::types::{Deserialize, StandingQueryFilter};
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct StandingQueryCreateBody {
+	pub(in crate::routes) query: String,
+	pub(in crate::routes) top_k: Option<u32>,
+	#[serde(default)]
+	pub(in crate::routes) filter: StandingQueryFilter,
+	pub(in crate::routes) webhook_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(in crate::routes) struct StandingQueryMatchesQuery {
+	pub(in crate::routes) after_seq: Option<i64>,
+	pub(in crate::routes) limit: Option<u32>,
+}
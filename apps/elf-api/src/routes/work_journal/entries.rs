@@ -38,6 +38,8 @@ pub(in crate::routes) async fn work_journal_entry_create(
 	})?;
 	let role = role.map(|Extension(role)| role);
 
+	routes::require_writer_role(role)?;
+
 	if payload.scope.trim() == "org_shared" {
 		routes::require_admin_for_org_shared_writes(
 			state.service.cfg.security.auth_mode.as_str(),
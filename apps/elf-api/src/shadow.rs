@@ -0,0 +1,151 @@
+//! Search traffic shadowing to a second ELF deployment.
+
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+use color_eyre::Result;
+use reqwest::Client;
+use serde_json::Value;
+use uuid::Uuid;
+
+use elf_config::Config;
+use elf_service::{ElfService, SearchShadowComparisonInput};
+
+const SEARCH_PATH: &str = "/v2/searches";
+
+/// Mirrors sampled search requests to a shadow deployment and records the comparison.
+pub struct SearchShadow {
+	client: Client,
+	endpoint: String,
+	sample_rate: f32,
+	auth_token: Option<String>,
+}
+impl SearchShadow {
+	/// Builds the shadow client when `[shadow]` is configured and enabled.
+	pub fn from_config(config: &Config) -> Result<Option<Self>> {
+		let Some(shadow) = config.shadow.as_ref().filter(|shadow| shadow.enabled) else {
+			return Ok(None);
+		};
+		let client = Client::builder().timeout(Duration::from_millis(shadow.timeout_ms)).build()?;
+		let endpoint = format!("{}{SEARCH_PATH}", shadow.base_url.trim_end_matches('/'));
+
+		Ok(Some(Self {
+			client,
+			endpoint,
+			sample_rate: shadow.sample_rate,
+			auth_token: shadow.auth_token.clone(),
+		}))
+	}
+
+	/// Returns whether the current request falls inside the configured sample.
+	pub fn sampled(&self) -> bool {
+		let draw = (Uuid::new_v4().as_u128() >> 64) as f64 / u64::MAX as f64;
+
+		draw < f64::from(self.sample_rate)
+	}
+
+	/// Sends the probe in the background; failures are recorded, never surfaced to the caller.
+	pub fn dispatch(self: &Arc<Self>, service: Arc<ElfService>, probe: SearchShadowProbe) {
+		let shadow = Arc::clone(self);
+
+		tokio::spawn(async move {
+			let input = shadow.run(probe).await;
+
+			if let Err(err) = service.shadow_comparison_record(input).await {
+				tracing::warn!(error = %err, "Failed to record search shadow comparison.");
+			}
+		});
+	}
+
+	async fn run(&self, probe: SearchShadowProbe) -> SearchShadowComparisonInput {
+		let mut request = self.client.post(&self.endpoint).json(&probe.body);
+
+		for (name, value) in &probe.headers {
+			request = request.header(*name, value);
+		}
+		if let Some(token) = self.auth_token.as_deref() {
+			request = request.bearer_auth(token);
+		}
+
+		let started = Instant::now();
+		let outcome = request.send().await;
+		let mut input = SearchShadowComparisonInput {
+			tenant_id: probe.tenant_id,
+			project_id: probe.project_id,
+			agent_id: probe.agent_id,
+			mode: probe.mode,
+			query: probe.query,
+			primary_trace_id: Some(probe.primary_trace_id),
+			shadow_trace_id: None,
+			primary_note_ids: probe.primary_note_ids,
+			shadow_note_ids: Vec::new(),
+			primary_latency_ms: probe.primary_latency_ms,
+			shadow_latency_ms: None,
+			shadow_status: None,
+			error: None,
+		};
+		let response = match outcome {
+			Ok(response) => response,
+			Err(err) => {
+				input.error = Some(err.to_string());
+
+				return input;
+			},
+		};
+		let status = response.status();
+
+		input.shadow_status = Some(status.as_u16());
+
+		let body = response.json::<Value>().await;
+
+		input.shadow_latency_ms =
+			Some(u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX));
+
+		match body {
+			Ok(body) if status.is_success() => {
+				input.shadow_trace_id = parse_uuid(body.get("trace_id"));
+				input.shadow_note_ids = body
+					.get("items")
+					.and_then(Value::as_array)
+					.map(|items| {
+						items.iter().filter_map(|item| parse_uuid(item.get("note_id"))).collect()
+					})
+					.unwrap_or_default();
+			},
+			Ok(_) => input.error = Some(format!("Shadow deployment returned HTTP {status}.")),
+			Err(err) => input.error = Some(err.to_string()),
+		}
+
+		input
+	}
+}
+
+/// One primary search outcome plus the request needed to replay it on the shadow.
+pub struct SearchShadowProbe {
+	/// Tenant that issued the search.
+	pub tenant_id: String,
+	/// Project that issued the search.
+	pub project_id: String,
+	/// Agent that issued the search.
+	pub agent_id: String,
+	/// Search mode label.
+	pub mode: String,
+	/// Query text.
+	pub query: String,
+	/// JSON body replayed against the shadow deployment.
+	pub body: Value,
+	/// Context headers replayed against the shadow deployment.
+	pub headers: Vec<(&'static str, String)>,
+	/// Trace identifier of the primary search.
+	pub primary_trace_id: Uuid,
+	/// Ranked note identifiers returned by the primary search.
+	pub primary_note_ids: Vec<Uuid>,
+	/// Primary search latency in milliseconds.
+	pub primary_latency_ms: u64,
+}
+
+fn parse_uuid(value: Option<&Value>) -> Option<Uuid> {
+	value.and_then(Value::as_str).and_then(|raw| Uuid::parse_str(raw).ok())
+}
@@ -4,11 +4,13 @@ use std::sync::Arc;
 
 use color_eyre::Result;
 
+use crate::{shadow::SearchShadow, warmup::ReadinessState};
 use elf_config::Config;
+use elf_runtime::jwt::JwtVerifier;
 use elf_service::ElfService;
 use elf_storage::{
 	db::Db,
-	qdrant::{DOCS_SEARCH_FILTER_INDEXES, QdrantStore},
+	qdrant::{DOCS_SEARCH_FILTER_INDEXES, NOTES_SEARCH_FILTER_INDEXES, QdrantStore},
 };
 
 /// Shared state for API handlers.
@@ -16,6 +18,12 @@ use elf_storage::{
 pub struct AppState {
 	/// The service instance serving API requests.
 	pub service: Arc<ElfService>,
+	/// Search shadowing client, present when `[shadow]` is enabled.
+	pub shadow: Option<Arc<SearchShadow>>,
+	/// Startup warm-up readiness reported by `GET /ready`.
+	pub readiness: Arc<ReadinessState>,
+	/// Bearer JWT verifier, present when `security.auth_mode` is `jwt`.
+	pub jwt: Option<Arc<JwtVerifier>>,
 }
 impl AppState {
 	/// Builds application state and ensures storage backends are ready.
@@ -24,9 +32,15 @@ impl AppState {
 
 		db.ensure_schema(config.storage.qdrant.vector_dim).await?;
 
+		Self::from_db(config, db).await
+	}
+
+	/// Builds application state on an existing, schema-ready Postgres handle.
+	pub async fn from_db(config: Config, db: Db) -> Result<Self> {
 		let qdrant = QdrantStore::new(&config.storage.qdrant)?;
 
 		qdrant.ensure_collection().await?;
+		qdrant.ensure_payload_indexes(&NOTES_SEARCH_FILTER_INDEXES).await?;
 
 		let docs_qdrant = QdrantStore::new_with_collection(
 			&config.storage.qdrant,
@@ -36,8 +50,14 @@ impl AppState {
 		docs_qdrant.ensure_collection().await?;
 		docs_qdrant.ensure_payload_indexes(&DOCS_SEARCH_FILTER_INDEXES).await?;
 
+		let shadow = SearchShadow::from_config(&config)?.map(Arc::new);
+		let readiness = Arc::new(ReadinessState::from_config(&config));
+		let jwt = match (config.security.auth_mode.trim(), config.security.jwt.as_ref()) {
+			("jwt", Some(jwt)) => Some(Arc::new(JwtVerifier::new(jwt)?)),
+			_ => None,
+		};
 		let service = ElfService::new(config, db, qdrant);
 
-		Ok(Self { service: Arc::new(service) })
+		Ok(Self { service: Arc::new(service), shadow, readiness, jwt })
 	}
 }
@@ -0,0 +1,85 @@
+//! Startup warm-up and the readiness state it gates.
+
+use std::sync::{Arc, RwLock};
+
+use serde::Serialize;
+
+use elf_config::Config;
+use elf_service::{ElfService, WarmupReport};
+
+/// Readiness reported by `GET /ready`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Readiness {
+	/// The warm-up task is still running.
+	Warming,
+	/// The service is warm; `warmup` is absent when `[warmup]` is disabled.
+	Ready {
+		/// Report from the completed warm-up run.
+		warmup: Option<WarmupReport>,
+	},
+	/// The warm-up task failed, so the service keeps reporting not ready.
+	Failed {
+		/// Error returned by the failed warm-up step.
+		error: String,
+	},
+}
+
+/// Shared readiness updated by the warm-up task.
+pub struct ReadinessState {
+	inner: RwLock<Readiness>,
+}
+impl ReadinessState {
+	/// Starts in `Warming` when `[warmup]` is enabled and `Ready` otherwise.
+	pub fn from_config(config: &Config) -> Self {
+		let readiness = if config.warmup.as_ref().is_some_and(|warmup| warmup.enabled) {
+			Readiness::Warming
+		} else {
+			Readiness::Ready { warmup: None }
+		};
+
+		Self { inner: RwLock::new(readiness) }
+	}
+
+	/// Returns the current readiness snapshot.
+	pub fn get(&self) -> Readiness {
+		self.inner.read().unwrap_or_else(|err| err.into_inner()).clone()
+	}
+
+	fn set(&self, readiness: Readiness) {
+		*self.inner.write().unwrap_or_else(|err| err.into_inner()) = readiness;
+	}
+}
+
+/// Runs the configured warm-up in the background and records the outcome.
+pub fn spawn_warmup(service: Arc<ElfService>, readiness: Arc<ReadinessState>) {
+	let Some(warmup) = service.cfg.warmup.as_ref().filter(|warmup| warmup.enabled) else {
+		return;
+	};
+	let query_count = warmup.queries.len();
+
+	tokio::spawn(async move {
+		let Some(warmup) = service.cfg.warmup.as_ref() else { return };
+
+		tracing::info!(queries = query_count, "Startup warm-up started.");
+
+		match service.warm_up(warmup).await {
+			Ok(report) => {
+				tracing::info!(
+					elapsed_ms = report.elapsed_ms,
+					embedding_latency_ms = report.embedding_latency_ms,
+					rerank_latency_ms = report.rerank_latency_ms,
+					cache_rows_loaded = report.cache_rows_loaded,
+					"Startup warm-up finished."
+				);
+
+				readiness.set(Readiness::Ready { warmup: Some(report) });
+			},
+			Err(err) => {
+				tracing::error!(error = %err, "Startup warm-up failed.");
+
+				readiness.set(Readiness::Failed { error: err.to_string() });
+			},
+		}
+	});
+}
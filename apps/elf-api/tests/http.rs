@@ -7,4 +7,5 @@
 #[path = "http/auth_admin.rs"] mod auth_admin;
 #[path = "http/contract.rs"] mod contract;
 #[path = "http/request_validation.rs"] mod request_validation;
+#[path = "http/search_stream.rs"] mod search_stream;
 #[path = "http/sharing.rs"] mod sharing;
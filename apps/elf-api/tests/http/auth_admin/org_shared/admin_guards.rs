@@ -2,3 +2,4 @@ mod admin_guards_events;
 mod admin_guards_grants;
 mod admin_guards_notes;
 mod admin_guards_publish;
+mod admin_guards_reader;
@@ -0,0 +1,172 @@
+use axum::{
+	body::Body,
+	http::{Request, StatusCode},
+};
+use tower::util::ServiceExt as _;
+
+use crate::helpers;
+use elf_api::{routes, state::AppState};
+use elf_config::{SecurityAuthKey, SecurityAuthRole};
+
+fn reader_and_user_keys() -> Vec<SecurityAuthKey> {
+	vec![
+		SecurityAuthKey {
+			token_id: "reader".to_string(),
+			token: "reader-token".to_string(),
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: Some("a".to_string()),
+			read_profile: "private_plus_project".to_string(),
+			role: SecurityAuthRole::Reader,
+		},
+		SecurityAuthKey {
+			token_id: "user".to_string(),
+			token: "user-token".to_string(),
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: Some("a".to_string()),
+			read_profile: "private_plus_project".to_string(),
+			role: SecurityAuthRole::User,
+		},
+	]
+}
+
+fn post(uri: &str, token: &str, payload: &serde_json::Value) -> Request<Body> {
+	Request::builder()
+		.method("POST")
+		.uri(uri)
+		.header("Authorization", format!("Bearer {token}"))
+		.header("content-type", "application/json")
+		.body(Body::from(payload.to_string()))
+		.expect("Failed to build request.")
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_GRPC_URL (or ELF_QDRANT_URL) to run."]
+async fn static_keys_reader_tokens_cannot_ingest_notes() {
+	let Some((test_db, qdrant_url, collection)) = helpers::test_env().await else { return };
+	let mut config = helpers::test_config(test_db.dsn().to_string(), qdrant_url, collection);
+
+	config.security.auth_mode = "static_keys".to_string();
+	config.security.auth_keys = reader_and_user_keys();
+
+	let state = AppState::new(config).await.expect("Failed to initialize app state.");
+	let app = routes::router(state);
+	let payload = serde_json::json!({
+		"scope": "agent_private",
+		"notes": [{
+			"type": "fact",
+			"key": null,
+			"text": "你好",
+			"importance": 0.5,
+			"confidence": 0.9,
+			"ttl_days": null,
+			"source_ref": {}
+		}]
+	});
+	let response_reader = app
+		.clone()
+		.oneshot(
+			Request::builder()
+				.method("POST")
+				.uri("/v2/notes/ingest")
+				.header("Authorization", "Bearer reader-token")
+				.header("content-type", "application/json")
+				.body(Body::from(payload.to_string()))
+				.expect("Failed to build request."),
+		)
+		.await
+		.expect("Failed to call notes ingest (reader).");
+
+	assert_eq!(response_reader.status(), StatusCode::FORBIDDEN);
+
+	let response_user = app
+		.oneshot(
+			Request::builder()
+				.method("POST")
+				.uri("/v2/notes/ingest")
+				.header("Authorization", "Bearer user-token")
+				.header("content-type", "application/json")
+				.body(Body::from(payload.to_string()))
+				.expect("Failed to build request."),
+		)
+		.await
+		.expect("Failed to call notes ingest (user).");
+
+	assert_eq!(response_user.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_GRPC_URL (or ELF_QDRANT_URL) to run."]
+async fn static_keys_reader_tokens_cannot_pin_or_append_sessions() {
+	let Some((test_db, qdrant_url, collection)) = helpers::test_env().await else { return };
+	let mut config = helpers::test_config(test_db.dsn().to_string(), qdrant_url, collection);
+
+	config.security.auth_mode = "static_keys".to_string();
+	config.security.auth_keys = reader_and_user_keys();
+
+	let state = AppState::new(config).await.expect("Failed to initialize app state.");
+	let app = routes::router(state);
+	let ingest = serde_json::json!({
+		"scope": "agent_private",
+		"notes": [{
+			"type": "fact",
+			"key": "deploy_day",
+			"text": "Fact: Deploys run on Tuesdays.",
+			"importance": 0.5,
+			"confidence": 0.9,
+			"ttl_days": null,
+			"source_ref": {}
+		}]
+	});
+	let response = app
+		.clone()
+		.oneshot(post("/v2/notes/ingest", "user-token", &ingest))
+		.await
+		.expect("Failed to call notes ingest.");
+
+	assert_eq!(response.status(), StatusCode::OK);
+
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.expect("Failed to read ingest response.");
+	let body: serde_json::Value =
+		serde_json::from_slice(&body).expect("Failed to parse ingest response.");
+	let note_id = body["results"][0]["note_id"].as_str().expect("Expected note_id.");
+
+	for action in ["pin", "unpin"] {
+		let response = app
+			.clone()
+			.oneshot(post(
+				format!("/v2/notes/{note_id}/{action}").as_str(),
+				"reader-token",
+				&serde_json::json!({}),
+			))
+			.await
+			.expect("Failed to call note pin.");
+
+		assert_eq!(response.status(), StatusCode::FORBIDDEN, "{action}");
+	}
+
+	let append = serde_json::json!({
+		"messages": [{ "role": "user", "content": "Deploys run on Tuesdays." }]
+	});
+	let response = app
+		.clone()
+		.oneshot(post("/v2/sessions/reader-session/messages", "reader-token", &append))
+		.await
+		.expect("Failed to call session append (reader).");
+
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+	let response = app
+		.oneshot(post("/v2/sessions/user-session/messages", "user-token", &append))
+		.await
+		.expect("Failed to call session append (user).");
+
+	assert_eq!(response.status(), StatusCode::OK);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -15,14 +15,53 @@ async fn openapi_json_route_serves_generated_contract() {
 	assert!(spec.get("request_id").is_none());
 
 	helpers::assert_openapi_method(&spec, "/health", "get");
+	helpers::assert_openapi_method(&spec, "/ready", "get");
 	helpers::assert_openapi_method(&spec, "/v2/notes/ingest", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/cite", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/source-refs/resolve", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/events", "get");
+	helpers::assert_openapi_method(&spec, "/v2/notes/trash", "get");
+	helpers::assert_openapi_method(&spec, "/v2/notes/batch/update", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/batch/delete", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/{note_id}/history", "get");
+	helpers::assert_openapi_method(&spec, "/v2/notes/{note_id}/relations", "get");
+	helpers::assert_openapi_method(&spec, "/v2/notes/{note_id}/relations", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/{note_id}/relations/{relation_id}", "delete");
+	helpers::assert_openapi_method(&spec, "/v2/notes/{note_id}/undelete", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/{note_id}/pin", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/{note_id}/unpin", "post");
+	helpers::assert_openapi_method(&spec, "/v2/searches/answer", "post");
+	helpers::assert_openapi_method(&spec, "/v2/notes/bulk-import", "post");
+	helpers::assert_openapi_method(&spec, "/v2/searches/batch", "post");
+	helpers::assert_openapi_method(&spec, "/v2/searches/scoped", "post");
+	helpers::assert_openapi_method(&spec, "/v2/searches/stream", "post");
+	helpers::assert_openapi_method(&spec, "/v2/searches/profiles/{name}", "post");
+	helpers::assert_openapi_method(&spec, "/v2/recall/context", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/search-profiles", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/search-profiles/{name}", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/search-profiles/{name}", "put");
+	helpers::assert_openapi_method(&spec, "/v2/admin/search-profiles/{name}", "delete");
+	helpers::assert_openapi_method(&spec, "/v2/searches/feedback", "post");
 	helpers::assert_openapi_method(&spec, "/v2/events/ingest", "post");
 	helpers::assert_openapi_method(&spec, "/v2/core-blocks", "get");
 	helpers::assert_openapi_method(&spec, "/v2/entity-memory", "get");
+	helpers::assert_openapi_method(&spec, "/v2/docs/search", "post");
 	helpers::assert_openapi_method(&spec, "/v2/docs/search/l0", "post");
 	helpers::assert_openapi_method(&spec, "/v2/work-journal/entries", "post");
 	helpers::assert_openapi_method(&spec, "/v2/work-journal/entries/{entry_id}", "get");
 	helpers::assert_openapi_method(&spec, "/v2/work-journal/readback", "post");
+	helpers::assert_openapi_method(&spec, "/v2/sessions/{session_id}", "get");
+	helpers::assert_openapi_method(&spec, "/v2/sessions/{session_id}/messages", "post");
+	helpers::assert_openapi_method(&spec, "/v2/sessions/{session_id}/summarize", "post");
+	helpers::assert_openapi_method(&spec, "/v2/standing-queries", "post");
+	helpers::assert_openapi_method(&spec, "/v2/standing-queries", "get");
+	helpers::assert_openapi_method(&spec, "/v2/standing-queries/{standing_query_id}", "get");
+	helpers::assert_openapi_method(&spec, "/v2/standing-queries/{standing_query_id}", "delete");
+	helpers::assert_openapi_method(
+		&spec,
+		"/v2/standing-queries/{standing_query_id}/matches",
+		"get",
+	);
 	helpers::assert_openapi_method(&spec, "/v2/searches/{search_id}/notes", "post");
 	helpers::assert_openapi_method(&spec, "/v2/admin/core-blocks", "post");
 	helpers::assert_openapi_method(&spec, "/v2/admin/core-blocks/{block_id}/attachments", "post");
@@ -32,10 +71,45 @@ async fn openapi_json_route_serves_generated_contract() {
 		"delete",
 	);
 	helpers::assert_openapi_method(&spec, "/v2/admin/docs/{doc_id}", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/docs/search", "post");
 	helpers::assert_openapi_method(&spec, "/v2/admin/docs/search/l0", "post");
 	helpers::assert_openapi_method(&spec, "/v2/admin/docs/excerpts", "post");
 	helpers::assert_openapi_method(&spec, "/v2/graph/report", "post");
+	helpers::assert_openapi_method(&spec, "/v2/graph/neighborhood", "post");
+	helpers::assert_openapi_method(&spec, "/v2/graph/facts/as-of", "post");
+	helpers::assert_openapi_method(
+		&spec,
+		"/v2/admin/graph/predicates/{predicate_id}/promote",
+		"post",
+	);
+	helpers::assert_openapi_method(&spec, "/v2/admin/graph/entities/merge", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/graph/entities/{entity_id}/split", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/graph/entity-kinds", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/graph/entity-kinds/{kind_id}/promote", "post");
+	helpers::assert_openapi_method(&spec, "/v2/org-stats", "get");
+	helpers::assert_openapi_method(&spec, "/v2/memory-timeline", "get");
+	helpers::assert_openapi_method(&spec, "/v2/rank", "post");
 	helpers::assert_openapi_method(&spec, "/v2/admin/searches/raw", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/qdrant/audit", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/index/verify", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/storage/report", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/memory/stats", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/providers/health", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/quota/usage", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/grants", "put");
+	helpers::assert_openapi_method(&spec, "/v2/admin/grants", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/grants/{grant_id}/revoke", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/reembed/runs", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/reembed/runs", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/reembed/runs/{run_id}/activate", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/export", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/restore", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/write-incidents", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/outbox/dead-letters", "get");
+	helpers::assert_openapi_method(&spec, "/v2/admin/outbox/dead-letters/replay", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/access/simulate", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/qdrant/maintenance/runs", "post");
+	helpers::assert_openapi_method(&spec, "/v2/admin/qdrant/maintenance/runs", "get");
 	helpers::assert_openapi_method(&spec, "/v2/admin/events/ingestion-profiles/default", "get");
 	helpers::assert_openapi_method(&spec, "/v2/admin/events/ingestion-profiles/default", "put");
 	helpers::assert_openapi_method(&spec, "/v2/admin/consolidation/runs", "post");
@@ -36,6 +36,9 @@ pub(crate) fn test_ranking() -> Ranking {
 				last_hit_tau_days: 14.0,
 			},
 			decay: RankingDeterministicDecay { enabled: false, weight: 0.05, tau_days: 30.0 },
+			evidence: None,
+			session: None,
+			feedback: None,
 		},
 		blend: RankingBlend {
 			enabled: true,
@@ -52,6 +55,7 @@ pub(crate) fn test_ranking() -> Ranking {
 			sim_threshold: 0.88,
 			mmr_lambda: 0.7,
 			max_skips: 64,
+			scope_balance: None,
 		},
 		retrieval_sources: RankingRetrievalSources {
 			fusion_weight: 1.0,
@@ -69,6 +73,8 @@ pub(crate) fn test_config(dsn: String, qdrant_url: String, collection: String) -
 			mcp_bind: "127.0.0.1:0".to_string(),
 			admin_bind: "127.0.0.1:0".to_string(),
 			log_level: "info".to_string(),
+			mode: None,
+			otel: None,
 		},
 		storage: Storage {
 			postgres: Postgres { dsn, pool_max_conns: 4 },
@@ -81,6 +87,9 @@ pub(crate) fn test_config(dsn: String, qdrant_url: String, collection: String) -
 		},
 		providers: Providers {
 			embedding: dummy_embedding_provider(),
+			embeddings: None,
+			embedding_scopes: None,
+			embedding_types: None,
 			rerank: dummy_provider(),
 			llm_extractor: dummy_llm_provider(),
 		},
@@ -116,6 +125,8 @@ pub(crate) fn test_config(dsn: String, qdrant_url: String, collection: String) -
 			update_sim_threshold: 0.85,
 			candidate_k: 60,
 			top_k: 12,
+			version_coalesce_window_ms: None,
+			write_anomaly: None,
 			policy: MemoryPolicy { rules: vec![] },
 		},
 		search: test_search(),
@@ -131,6 +142,10 @@ pub(crate) fn test_config(dsn: String, qdrant_url: String, collection: String) -
 			},
 			purge_deleted_after_days: 30,
 			purge_deprecated_after_days: 180,
+			purge_trashed_after_days: None,
+			consolidation: None,
+			schedule: None,
+			importance_rescore: None,
 		},
 		security: Security {
 			bind_localhost_only: true,
@@ -141,15 +156,29 @@ pub(crate) fn test_config(dsn: String, qdrant_url: String, collection: String) -
 			evidence_max_quote_chars: 320,
 			auth_mode: "off".to_string(),
 			auth_keys: vec![],
+			jwt: None,
+			permissions: None,
+			pii: None,
+			secret_patterns: None,
+			egress: None,
+			quotas: None,
 		},
 		chunking: Chunking {
 			enabled: true,
 			max_tokens: 512,
 			overlap_tokens: 128,
 			tokenizer_repo: "gpt2".to_string(),
+			per_type: None,
 		},
 		context: None,
 		mcp: None,
+		shadow: None,
+		embedding_projection: None,
+		url_snapshots: None,
+		warmup: None,
+		qdrant_maintenance: None,
+		reembed: None,
+		worker: None,
 	}
 }
 
@@ -167,6 +196,7 @@ pub(crate) fn test_search() -> Search {
 			expansion_ttl_days: 7,
 			rerank_ttl_days: 7,
 			max_payload_bytes: Some(262_144),
+			results: None,
 		},
 		explain: SearchExplain {
 			retention_days: 7,
@@ -180,12 +210,16 @@ pub(crate) fn test_search() -> Search {
 			max_children_per_node: 4,
 			max_nodes_per_scope: 32,
 			max_total_nodes: 256,
+			max_elapsed_ms: None,
 		},
 		graph_context: SearchGraphContext {
 			enabled: false,
 			max_facts_per_item: 16,
 			max_evidence_notes_per_fact: 16,
 		},
+		snippet: None,
+		answer: None,
+		degraded: None,
 	}
 }
 
@@ -199,6 +233,10 @@ pub(crate) fn dummy_embedding_provider() -> EmbeddingProviderConfig {
 		dimensions: 4_096,
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		query_input: None,
+		model_dir: None,
+		resilience: None,
+		cache: None,
 	}
 }
 
@@ -211,6 +249,8 @@ pub(crate) fn dummy_provider() -> ProviderConfig {
 		model: "local-token-overlap".to_string(),
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		resilience: None,
+		max_docs_per_call: None,
 	}
 }
 
@@ -224,5 +264,6 @@ pub(crate) fn dummy_llm_provider() -> LlmProviderConfig {
 		temperature: 0.1,
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		resilience: None,
 	}
 }
@@ -31,3 +31,24 @@ async fn health_ok() {
 
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_GRPC_URL (or ELF_QDRANT_URL) to run."]
+async fn ready_ok_without_warmup() {
+	let Some((test_db, qdrant_url, collection)) = helpers::test_env().await else {
+		return;
+	};
+	let config = helpers::test_config(test_db.dsn().to_string(), qdrant_url, collection);
+	let state = AppState::new(config).await.expect("Failed to initialize app state.");
+	let app = routes::router(state);
+	let response = app
+		.oneshot(
+			Request::builder().uri("/ready").body(Body::empty()).expect("Failed to build request."),
+		)
+		.await
+		.expect("Failed to call /ready.");
+
+	assert_eq!(response.status(), StatusCode::OK);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -0,0 +1,136 @@
+use axum::{
+	Router,
+	body::{self, Body},
+	http::{Request, StatusCode},
+};
+use serde_json::Value;
+use tower::util::ServiceExt as _;
+
+use crate::helpers::{self, TEST_AGENT_A, TEST_PROJECT_ID, TEST_TENANT_ID};
+use elf_api::{routes, state::AppState};
+
+/// Posts `payload` to the streaming search route and returns each `(event, data)` pair in order.
+async fn stream_search(app: &Router, payload: Value) -> Vec<(String, Value)> {
+	let response = app
+		.clone()
+		.oneshot(
+			Request::builder()
+				.method("POST")
+				.uri("/v2/searches/stream")
+				.header("X-ELF-Tenant-Id", TEST_TENANT_ID)
+				.header("X-ELF-Project-Id", TEST_PROJECT_ID)
+				.header("X-ELF-Agent-Id", TEST_AGENT_A)
+				.header("X-ELF-Read-Profile", "private_only")
+				.header("content-type", "application/json")
+				.body(Body::from(payload.to_string()))
+				.expect("Failed to build stream request."),
+		)
+		.await
+		.expect("Failed to call search stream.");
+
+	assert_eq!(response.status(), StatusCode::OK);
+	assert_eq!(
+		response.headers().get("content-type").and_then(|value| value.to_str().ok()),
+		Some("text/event-stream")
+	);
+
+	let body = body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.expect("Failed to read stream body.");
+	let text = String::from_utf8(body.to_vec()).expect("Stream body should be UTF-8.");
+
+	text.split("\n\n")
+		.filter_map(|frame| {
+			let event = frame.lines().find_map(|line| line.strip_prefix("event: "))?;
+			let data = frame.lines().find_map(|line| line.strip_prefix("data: "))?;
+
+			Some((
+				event.to_string(),
+				serde_json::from_str(data).expect("Stream event data should be JSON."),
+			))
+		})
+		.collect()
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_GRPC_URL (or ELF_QDRANT_URL) to run."]
+async fn search_stream_sends_completed_stages_then_one_result() {
+	let Some((test_db, qdrant_url, collection)) = helpers::test_env().await else {
+		return;
+	};
+	let config = helpers::test_config(test_db.dsn().to_string(), qdrant_url, collection);
+	let state = AppState::new(config).await.expect("Failed to initialize app state.");
+	let app = routes::router(state.clone());
+	let note_id = helpers::create_note_for_payload_level_tests(
+		&app,
+		&state,
+		"Stream contract note about the staging deploy checklist.",
+		serde_json::json!({}),
+	)
+	.await;
+	let events = stream_search(
+		&app,
+		serde_json::json!({
+			"mode": "quick_find",
+			"query": "staging deploy checklist",
+			"top_k": 5,
+			"candidate_k": 10,
+		}),
+	)
+	.await;
+	let (terminal, stages) = events.split_last().expect("Expected at least one stream event.");
+	let stage_names = stages
+		.iter()
+		.map(|(event, data)| {
+			assert_eq!(event, "stage");
+
+			data["stage_name"].as_str().expect("Stage event should carry a name.")
+		})
+		.collect::<Vec<_>>();
+
+	// Quick searches do not rerank, so no `rerank.score` stage is sent.
+	assert_eq!(
+		stage_names,
+		vec!["rewrite.expansion", "recall.candidates", "fusion.merge", "selection.final"]
+	);
+	assert_eq!(terminal.0, "result");
+	assert_eq!(terminal.1["mode"], "quick_find");
+	assert!(terminal.1["search_id"].is_string());
+	assert!(
+		terminal.1["items"]
+			.as_array()
+			.expect("Result should carry items.")
+			.iter()
+			.any(|item| item["note_id"] == note_id.to_string())
+	);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_GRPC_URL (or ELF_QDRANT_URL) to run."]
+async fn search_stream_ends_with_one_error_event_when_the_search_fails() {
+	let Some((test_db, qdrant_url, collection)) = helpers::test_env().await else {
+		return;
+	};
+	let config = helpers::test_config(test_db.dsn().to_string(), qdrant_url, collection);
+	let state = AppState::new(config).await.expect("Failed to initialize app state.");
+	let app = routes::router(state);
+	let events = stream_search(
+		&app,
+		serde_json::json!({
+			"mode": "quick_find",
+			"query": "안녕하세요",
+			"top_k": 5,
+			"candidate_k": 10,
+		}),
+	)
+	.await;
+	let (terminal, stages) = events.split_last().expect("Expected at least one stream event.");
+
+	assert!(stages.iter().all(|(event, _)| event == "stage"));
+	assert_eq!(terminal.0, "error");
+	assert_eq!(terminal.1["error_code"], "NON_ENGLISH_INPUT");
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -0,0 +1,152 @@
+mod cli;
+mod outbox;
+mod provider_stub;
+mod stats;
+mod types;
+mod workload;
+
+pub use cli::Args;
+
+use std::{fs, sync::Arc, time::Duration};
+
+use color_eyre::{Result, eyre};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use tracing_subscriber::EnvFilter;
+use uuid::Uuid;
+
+use elf_storage::db::Db;
+use outbox::{OutboxSampler, OutboxScope};
+use types::{BenchReport, ELF_BENCH_REPORT_SCHEMA_V1, PhasesReport, TargetReport, WorkloadReport};
+use workload::Target;
+
+const MAX_NOTES_PER_REQUEST: usize = 256;
+
+pub async fn run(args: Args) -> Result<()> {
+	validate_args(&args)?;
+
+	let config = elf_config::load(&args.config)?;
+	let filter = EnvFilter::new(config.service.log_level.clone());
+
+	// The report may go to stdout, so logs stay on stderr.
+	tracing_subscriber::fmt().with_env_filter(filter).with_writer(std::io::stderr).init();
+
+	let provider_calls = match args.provider_stub_bind.as_deref() {
+		Some(bind) => Some(provider_stub::spawn(bind, config.storage.qdrant.vector_dim).await?),
+		None => None,
+	};
+	let db = Db::connect(&config.storage.postgres).await?;
+	let api_base =
+		args.api_base.clone().unwrap_or_else(|| format!("http://{}", config.service.http_bind));
+	let target = Arc::new(Target {
+		client: reqwest::Client::builder()
+			.timeout(Duration::from_secs(args.request_timeout_secs))
+			.build()?,
+		api_base: api_base.clone(),
+		token: args.token.clone(),
+		tenant_id: args.tenant_id.clone(),
+		project_id: args.project_id.clone(),
+		agent_id: args.agent_id.clone(),
+		read_profile: args.read_profile.clone(),
+	});
+	let run_id = Uuid::new_v4();
+	let run_tag = run_id.simple().to_string()[..8].to_string();
+	let started_at = OffsetDateTime::now_utc();
+	let interval = Duration::from_millis(args.sample_interval_ms);
+	let scope = OutboxScope {
+		pool: db.pool.clone(),
+		tenant_id: args.tenant_id.clone(),
+		project_id: args.project_id.clone(),
+		since: started_at,
+	};
+	let sampler = OutboxSampler::spawn(scope.clone(), interval);
+
+	tracing::info!(%run_id, api_base, "Starting write-path benchmark.");
+
+	let add_note = workload::run_phase(
+		&target,
+		"/v2/notes/ingest",
+		workload::add_note_bodies(&args, &run_tag),
+		args.concurrency,
+	)
+	.await;
+	let add_event = workload::run_phase(
+		&target,
+		"/v2/events/ingest",
+		workload::add_event_bodies(&args),
+		args.concurrency,
+	)
+	.await;
+	let (drain_ms, drained) =
+		outbox::wait_for_drain(&scope, interval, Duration::from_secs(args.drain_timeout_secs))
+			.await?;
+
+	if !drained {
+		tracing::warn!(drain_ms, "Note outbox did not drain before the timeout.");
+	}
+
+	let search = workload::run_phase(
+		&target,
+		"/v2/searches",
+		workload::search_bodies(&args),
+		args.concurrency,
+	)
+	.await;
+	let samples = sampler.finish().await;
+	let outbox = outbox::build_report(&scope, samples, drain_ms, drained).await?;
+	let report = BenchReport {
+		schema: ELF_BENCH_REPORT_SCHEMA_V1,
+		run_id,
+		started_at: started_at.format(&Rfc3339)?,
+		finished_at: OffsetDateTime::now_utc().format(&Rfc3339)?,
+		target: TargetReport {
+			api_base,
+			tenant_id: args.tenant_id.clone(),
+			project_id: args.project_id.clone(),
+			agent_id: args.agent_id.clone(),
+			scope: args.scope.clone(),
+		},
+		workload: WorkloadReport {
+			corpus_size: args.corpus_size,
+			notes_per_request: args.notes_per_request,
+			events: args.events,
+			searches: args.searches,
+			concurrency: args.concurrency,
+			search_mode: args.search_mode,
+		},
+		phases: PhasesReport { add_note, add_event, search },
+		outbox,
+		provider_calls: provider_calls.map(|counts| counts.report()),
+	};
+	let json = serde_json::to_string_pretty(&report)?;
+
+	match args.out.as_ref() {
+		Some(path) => {
+			if let Some(parent) = path.parent() {
+				fs::create_dir_all(parent)?;
+			}
+
+			fs::write(path, format!("{json}\n"))?;
+		},
+		None => println!("{json}"),
+	}
+
+	Ok(())
+}
+
+fn validate_args(args: &Args) -> Result<()> {
+	if args.concurrency == 0 {
+		return Err(eyre::eyre!("--concurrency must be greater than zero."));
+	}
+	if !(1..=MAX_NOTES_PER_REQUEST).contains(&args.notes_per_request) {
+		return Err(eyre::eyre!(
+			"--notes-per-request must be between 1 and {MAX_NOTES_PER_REQUEST}."
+		));
+	}
+	if args.sample_interval_ms == 0 {
+		return Err(eyre::eyre!("--sample-interval-ms must be greater than zero."));
+	}
+
+	Ok(())
+}
+
+#[cfg(test)] mod tests;
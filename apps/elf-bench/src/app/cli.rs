@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+
+#[derive(Debug, Parser)]
+#[command(
+	version = elf_cli::VERSION,
+	rename_all = "kebab",
+	styles = elf_cli::styles(),
+)]
+pub struct Args {
+	/// ELF config of the target deployment; supplies the API bind address and Postgres DSN.
+	#[arg(long, short = 'c', value_name = "FILE")]
+	pub config: PathBuf,
+	/// Public API base URL. Defaults to `http://<service.http_bind>`.
+	#[arg(long, value_name = "URL")]
+	pub api_base: Option<String>,
+	/// Bearer token sent with every request when the deployment uses static keys.
+	#[arg(long, env = "ELF_BENCH_TOKEN", value_name = "TOKEN", hide_env_values = true)]
+	pub token: Option<String>,
+	#[arg(long, value_name = "ID", default_value = "elf-bench")]
+	pub tenant_id: String,
+	#[arg(long, value_name = "ID", default_value = "elf-bench")]
+	pub project_id: String,
+	#[arg(long, value_name = "ID", default_value = "elf-bench-agent")]
+	pub agent_id: String,
+	#[arg(long, value_name = "SCOPE", default_value = "agent_private")]
+	pub scope: String,
+	#[arg(long, value_name = "PROFILE", default_value = "private_plus_project")]
+	pub read_profile: String,
+	/// Number of synthetic notes written through `POST /v2/notes/ingest`.
+	#[arg(long, value_name = "N", default_value_t = 200)]
+	pub corpus_size: usize,
+	/// Notes sent per add_note request.
+	#[arg(long, value_name = "N", default_value_t = 1)]
+	pub notes_per_request: usize,
+	/// Number of `POST /v2/events/ingest` requests.
+	#[arg(long, value_name = "N", default_value_t = 20)]
+	pub events: usize,
+	/// Number of `POST /v2/searches` requests issued after the write phases.
+	#[arg(long, value_name = "N", default_value_t = 100)]
+	pub searches: usize,
+	/// Maximum in-flight requests per phase.
+	#[arg(long, value_name = "N", default_value_t = 8)]
+	pub concurrency: usize,
+	#[arg(long, value_enum, default_value_t = SearchMode::QuickFind)]
+	pub search_mode: SearchMode,
+	/// Per-request HTTP timeout.
+	#[arg(long, value_name = "SECONDS", default_value_t = 60)]
+	pub request_timeout_secs: u64,
+	/// Longest wait for the note outbox to drain after the write phases.
+	#[arg(long, value_name = "SECONDS", default_value_t = 120)]
+	pub drain_timeout_secs: u64,
+	/// Interval between outbox backlog samples.
+	#[arg(long, value_name = "MS", default_value_t = 250)]
+	pub sample_interval_ms: u64,
+	/// Serve a deterministic embedding, rerank, and extractor stub on this address and count
+	/// calls.
+	///
+	/// Point the deployment's provider `api_base` values at the stub to attribute provider calls.
+	#[arg(long, value_name = "ADDR")]
+	pub provider_stub_bind: Option<String>,
+	/// Write the JSON report to this file instead of stdout.
+	#[arg(long, value_name = "FILE")]
+	pub out: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+	#[value(name = "quick_find")]
+	QuickFind,
+	#[value(name = "planned_search")]
+	PlannedSearch,
+}
+impl SearchMode {
+	pub(super) fn as_str(self) -> &'static str {
+		match self {
+			Self::QuickFind => "quick_find",
+			Self::PlannedSearch => "planned_search",
+		}
+	}
+}
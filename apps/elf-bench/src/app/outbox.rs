@@ -0,0 +1,149 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::{Duration, Instant},
+};
+
+use color_eyre::Result;
+use sqlx::{FromRow, PgPool};
+use time::OffsetDateTime;
+use tokio::task::JoinHandle;
+
+use crate::app::{stats, types::OutboxReport};
+
+/// Restricts outbox queries to jobs for notes written by this run's tenant and project.
+#[derive(Clone)]
+pub(super) struct OutboxScope {
+	pub(super) pool: PgPool,
+	pub(super) tenant_id: String,
+	pub(super) project_id: String,
+	pub(super) since: OffsetDateTime,
+}
+
+#[derive(Debug, FromRow)]
+struct BacklogRow {
+	pending: i64,
+	failed: i64,
+	oldest_pending_ms: f64,
+}
+
+/// Background task recording the bench note backlog at a fixed interval.
+pub(super) struct OutboxSampler {
+	stop: Arc<AtomicBool>,
+	handle: JoinHandle<Vec<(i64, f64)>>,
+}
+impl OutboxSampler {
+	pub(super) fn spawn(scope: OutboxScope, interval: Duration) -> Self {
+		let stop = Arc::new(AtomicBool::new(false));
+		let stop_flag = Arc::clone(&stop);
+		let handle = tokio::spawn(async move {
+			let mut samples = Vec::new();
+
+			while !stop_flag.load(Ordering::Relaxed) {
+				match fetch_backlog(&scope).await {
+					Ok(row) => samples.push((row.pending, row.oldest_pending_ms)),
+					Err(err) => tracing::warn!(error = %err, "Outbox backlog sample failed."),
+				}
+
+				tokio::time::sleep(interval).await;
+			}
+
+			samples
+		});
+
+		Self { stop, handle }
+	}
+
+	pub(super) async fn finish(self) -> Vec<(i64, f64)> {
+		self.stop.store(true, Ordering::Relaxed);
+
+		self.handle.await.unwrap_or_default()
+	}
+}
+
+/// Waits until no bench note job is pending and returns the wait in milliseconds.
+pub(super) async fn wait_for_drain(
+	scope: &OutboxScope,
+	interval: Duration,
+	timeout: Duration,
+) -> Result<(f64, bool)> {
+	let started = Instant::now();
+
+	loop {
+		let backlog = fetch_backlog(scope).await?;
+		let elapsed_ms = started.elapsed().as_secs_f64() * 1_000.0;
+
+		if backlog.pending == 0 {
+			return Ok((elapsed_ms, true));
+		}
+		if started.elapsed() >= timeout {
+			return Ok((elapsed_ms, false));
+		}
+
+		tokio::time::sleep(interval).await;
+	}
+}
+
+pub(super) async fn build_report(
+	scope: &OutboxScope,
+	samples: Vec<(i64, f64)>,
+	drain_ms: f64,
+	drained: bool,
+) -> Result<OutboxReport> {
+	let backlog = fetch_backlog(scope).await?;
+	let lags = fetch_done_lags(scope).await?;
+
+	Ok(OutboxReport {
+		samples: samples.len(),
+		max_pending: samples.iter().map(|(pending, _)| *pending).max().unwrap_or_default(),
+		max_oldest_pending_ms: stats::round_ms(
+			samples.iter().map(|(_, oldest)| *oldest).fold(0.0, f64::max),
+		),
+		drain_ms: stats::round_ms(drain_ms),
+		drained,
+		failed_jobs: backlog.failed,
+		lag_ms: stats::summarize_latencies(&lags),
+	})
+}
+
+async fn fetch_backlog(scope: &OutboxScope) -> Result<BacklogRow> {
+	let row = sqlx::query_as::<_, BacklogRow>(
+		"\
+SELECT
+	COUNT(*) FILTER (WHERE o.status <> 'DONE') AS pending,
+	COUNT(*) FILTER (WHERE o.status = 'FAILED') AS failed,
+	COALESCE(
+		EXTRACT(EPOCH FROM (now() - MIN(o.created_at) FILTER (WHERE o.status <> 'DONE'))) * 1000.0,
+		0
+	)::float8 AS oldest_pending_ms
+FROM indexing_outbox o
+JOIN memory_notes n ON n.note_id = o.note_id
+WHERE n.tenant_id = $1 AND n.project_id = $2 AND o.created_at >= $3",
+	)
+	.bind(scope.tenant_id.as_str())
+	.bind(scope.project_id.as_str())
+	.bind(scope.since)
+	.fetch_one(&scope.pool)
+	.await?;
+
+	Ok(row)
+}
+
+async fn fetch_done_lags(scope: &OutboxScope) -> Result<Vec<f64>> {
+	let lags = sqlx::query_scalar::<_, f64>(
+		"\
+SELECT (EXTRACT(EPOCH FROM (o.updated_at - o.created_at)) * 1000.0)::float8
+FROM indexing_outbox o
+JOIN memory_notes n ON n.note_id = o.note_id
+WHERE n.tenant_id = $1 AND n.project_id = $2 AND o.created_at >= $3 AND o.status = 'DONE'",
+	)
+	.bind(scope.tenant_id.as_str())
+	.bind(scope.project_id.as_str())
+	.bind(scope.since)
+	.fetch_all(&scope.pool)
+	.await?;
+
+	Ok(lags)
+}
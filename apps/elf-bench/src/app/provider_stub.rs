@@ -0,0 +1,140 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicU64, Ordering},
+};
+
+use axum::{Json, Router, extract::State};
+use color_eyre::Result;
+use serde_json::Value;
+use tokio::net::TcpListener;
+
+use crate::app::types::ProviderCallReport;
+
+/// Per-kind call counters shared with the stub's request handler.
+#[derive(Debug, Default)]
+pub(super) struct ProviderCallCounts {
+	embedding: AtomicU64,
+	embedding_inputs: AtomicU64,
+	rerank: AtomicU64,
+	extractor: AtomicU64,
+	unrecognized: AtomicU64,
+}
+impl ProviderCallCounts {
+	pub(super) fn report(&self) -> ProviderCallReport {
+		ProviderCallReport {
+			embedding: self.embedding.load(Ordering::Relaxed),
+			embedding_inputs: self.embedding_inputs.load(Ordering::Relaxed),
+			rerank: self.rerank.load(Ordering::Relaxed),
+			extractor: self.extractor.load(Ordering::Relaxed),
+			unrecognized: self.unrecognized.load(Ordering::Relaxed),
+		}
+	}
+}
+
+#[derive(Clone)]
+struct StubState {
+	counts: Arc<ProviderCallCounts>,
+	vector_dim: u32,
+}
+
+/// Serves a deterministic provider stub on `bind` until the process exits.
+///
+/// Requests are classified by body shape rather than path so any configured provider `path`
+/// works: `input` is an embedding call, `query` with `documents` is a rerank call, and `messages`
+/// is an extractor call.
+pub(super) async fn spawn(bind: &str, vector_dim: u32) -> Result<Arc<ProviderCallCounts>> {
+	let counts = Arc::new(ProviderCallCounts::default());
+	let state = StubState { counts: Arc::clone(&counts), vector_dim };
+	let listener = TcpListener::bind(bind).await?;
+	let app = Router::new().fallback(handle).with_state(state);
+
+	tracing::info!(bind, "Provider stub listening.");
+	tokio::spawn(async move {
+		if let Err(err) = axum::serve(listener, app).await {
+			tracing::error!(error = %err, "Provider stub stopped.");
+		}
+	});
+
+	Ok(counts)
+}
+
+async fn handle(State(state): State<StubState>, Json(body): Json<Value>) -> Json<Value> {
+	let counts = &state.counts;
+
+	if let Some(inputs) = body.get("input").and_then(Value::as_array) {
+		let dim = body
+			.get("dimensions")
+			.and_then(Value::as_u64)
+			.map_or(state.vector_dim, |dim| dim as u32);
+		let data = inputs
+			.iter()
+			.enumerate()
+			.map(|(index, input)| {
+				serde_json::json!({
+					"index": index,
+					"embedding": stub_embedding(input.as_str().unwrap_or_default(), dim),
+				})
+			})
+			.collect::<Vec<_>>();
+
+		counts.embedding.fetch_add(1, Ordering::Relaxed);
+		counts.embedding_inputs.fetch_add(inputs.len() as u64, Ordering::Relaxed);
+
+		return Json(serde_json::json!({ "data": data }));
+	}
+	if let Some(documents) = body.get("documents").and_then(Value::as_array) {
+		let query = body.get("query").and_then(Value::as_str).unwrap_or_default().to_lowercase();
+		let results = documents
+			.iter()
+			.enumerate()
+			.map(|(index, doc)| {
+				let doc = doc.as_str().unwrap_or_default().to_lowercase();
+				let hits = query.split_whitespace().filter(|term| doc.contains(term)).count();
+				let terms = query.split_whitespace().count().max(1);
+
+				serde_json::json!({ "index": index, "relevance_score": hits as f64 / terms as f64 })
+			})
+			.collect::<Vec<_>>();
+
+		counts.rerank.fetch_add(1, Ordering::Relaxed);
+
+		return Json(serde_json::json!({ "results": results }));
+	}
+	if body.get("messages").is_some() {
+		counts.extractor.fetch_add(1, Ordering::Relaxed);
+
+		return Json(serde_json::json!({
+			"choices": [{ "message": { "content": "{\"notes\":[]}" } }],
+		}));
+	}
+
+	counts.unrecognized.fetch_add(1, Ordering::Relaxed);
+
+	Json(serde_json::json!({}))
+}
+
+// Hashes each lowercase token into one signed bucket and L2-normalizes the result.
+fn stub_embedding(text: &str, dim: u32) -> Vec<f32> {
+	let dim = dim.max(1) as usize;
+	let mut vector = vec![0.0_f32; dim];
+
+	for token in
+		text.split(|ch: char| !ch.is_ascii_alphanumeric()).filter(|token| !token.is_empty())
+	{
+		let hash = blake3::hash(token.to_ascii_lowercase().as_bytes());
+		let bytes = hash.as_bytes();
+		let index = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize % dim;
+
+		vector[index] += if bytes[4] & 1 == 0 { 1.0 } else { -1.0 };
+	}
+
+	let norm = vector.iter().map(|value| value * value).sum::<f32>().sqrt();
+
+	if norm > 0.0 {
+		vector.iter_mut().for_each(|value| *value /= norm);
+	} else {
+		vector[0] = 1.0;
+	}
+
+	vector
+}
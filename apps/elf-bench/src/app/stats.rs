@@ -0,0 +1,34 @@
+use crate::app::types::LatencySummary;
+
+pub(super) fn summarize_latencies(samples: &[f64]) -> LatencySummary {
+	if samples.is_empty() {
+		return LatencySummary::default();
+	}
+
+	let mut sorted = samples.to_vec();
+
+	sorted.sort_by(f64::total_cmp);
+
+	let sum: f64 = sorted.iter().sum();
+
+	LatencySummary {
+		count: sorted.len(),
+		mean: round_ms(sum / sorted.len() as f64),
+		p50: percentile(&sorted, 0.50),
+		p90: percentile(&sorted, 0.90),
+		p95: percentile(&sorted, 0.95),
+		p99: percentile(&sorted, 0.99),
+		max: round_ms(sorted[sorted.len() - 1]),
+	}
+}
+
+pub(super) fn round_ms(value: f64) -> f64 {
+	(value * 1_000.0).round() / 1_000.0
+}
+
+// Nearest-rank percentile over an ascending, non-empty slice.
+fn percentile(sorted: &[f64], quantile: f64) -> f64 {
+	let rank = (quantile * sorted.len() as f64).ceil() as usize;
+
+	round_ms(sorted[rank.clamp(1, sorted.len()) - 1])
+}
@@ -0,0 +1,32 @@
+use crate::app::{stats, types::LatencySummary, workload};
+
+#[test]
+fn summarize_latencies_uses_nearest_rank_percentiles() {
+	let samples = (1..=100).rev().map(f64::from).collect::<Vec<_>>();
+	let summary = stats::summarize_latencies(&samples);
+
+	assert_eq!(
+		summary,
+		LatencySummary {
+			count: 100,
+			mean: 50.5,
+			p50: 50.0,
+			p90: 90.0,
+			p95: 95.0,
+			p99: 99.0,
+			max: 100.0,
+		}
+	);
+	assert_eq!(stats::summarize_latencies(&[]), LatencySummary::default());
+}
+
+#[test]
+fn synthetic_corpus_is_distinct_and_english() {
+	let first = workload::synthetic_fact(0);
+	let second = workload::synthetic_fact(1);
+
+	assert_ne!(first, second);
+	assert!(first.starts_with("The billing service "));
+	assert!(first.is_ascii());
+	assert_eq!(workload::synthetic_query(0), "How many times does the billing service use?");
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::app::cli::SearchMode;
+
+pub(super) const ELF_BENCH_REPORT_SCHEMA_V1: &str = "elf.bench_report/v1";
+
+#[derive(Debug, Serialize)]
+pub(super) struct BenchReport {
+	pub(super) schema: &'static str,
+	pub(super) run_id: Uuid,
+	pub(super) started_at: String,
+	pub(super) finished_at: String,
+	pub(super) target: TargetReport,
+	pub(super) workload: WorkloadReport,
+	pub(super) phases: PhasesReport,
+	pub(super) outbox: OutboxReport,
+	/// `None` unless the provider stub served this run.
+	pub(super) provider_calls: Option<ProviderCallReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct TargetReport {
+	pub(super) api_base: String,
+	pub(super) tenant_id: String,
+	pub(super) project_id: String,
+	pub(super) agent_id: String,
+	pub(super) scope: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct WorkloadReport {
+	pub(super) corpus_size: usize,
+	pub(super) notes_per_request: usize,
+	pub(super) events: usize,
+	pub(super) searches: usize,
+	pub(super) concurrency: usize,
+	pub(super) search_mode: SearchMode,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct PhasesReport {
+	pub(super) add_note: PhaseReport,
+	pub(super) add_event: PhaseReport,
+	pub(super) search: PhaseReport,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(super) struct PhaseReport {
+	pub(super) requests: usize,
+	pub(super) succeeded: usize,
+	pub(super) failed: usize,
+	pub(super) duration_ms: f64,
+	pub(super) throughput_rps: f64,
+	pub(super) latency_ms: LatencySummary,
+	/// Count of `results[].op` values returned by write endpoints.
+	pub(super) ops: BTreeMap<String, u64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(super) first_error: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub(super) struct LatencySummary {
+	pub(super) count: usize,
+	pub(super) mean: f64,
+	pub(super) p50: f64,
+	pub(super) p90: f64,
+	pub(super) p95: f64,
+	pub(super) p99: f64,
+	pub(super) max: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct OutboxReport {
+	pub(super) samples: usize,
+	pub(super) max_pending: i64,
+	pub(super) max_oldest_pending_ms: f64,
+	/// Time from the end of the write phases until no bench note job was pending.
+	pub(super) drain_ms: f64,
+	pub(super) drained: bool,
+	pub(super) failed_jobs: i64,
+	/// Enqueue-to-done latency of completed bench note jobs.
+	pub(super) lag_ms: LatencySummary,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct ProviderCallReport {
+	pub(super) embedding: u64,
+	pub(super) embedding_inputs: u64,
+	pub(super) rerank: u64,
+	pub(super) extractor: u64,
+	pub(super) unrecognized: u64,
+}
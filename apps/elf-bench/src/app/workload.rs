@@ -0,0 +1,231 @@
+use std::{
+	collections::BTreeMap,
+	sync::{
+		Arc,
+		atomic::{AtomicUsize, Ordering},
+	},
+	time::Instant,
+};
+
+use color_eyre::{Result, eyre};
+use reqwest::Client;
+use serde_json::Value;
+
+use crate::app::{Args, stats, types::PhaseReport};
+
+const SUBJECTS: [&str; 8] = [
+	"billing service",
+	"search cluster",
+	"deploy pipeline",
+	"auth gateway",
+	"metrics exporter",
+	"backup job",
+	"cache layer",
+	"ingest worker",
+];
+const FACTS: [(&str, &str); 6] = [
+	("retries failed calls up to", "times"),
+	("keeps request logs for", "days"),
+	("spreads its data across", "nodes"),
+	("caps each batch at", "items"),
+	("times out slow requests after", "seconds"),
+	("runs its cleanup task every", "hours"),
+];
+
+/// Connection details shared by every benchmark request.
+pub(super) struct Target {
+	pub(super) client: Client,
+	pub(super) api_base: String,
+	pub(super) token: Option<String>,
+	pub(super) tenant_id: String,
+	pub(super) project_id: String,
+	pub(super) agent_id: String,
+	pub(super) read_profile: String,
+}
+impl Target {
+	async fn post(&self, path: &str, body: &Value) -> Result<Value> {
+		let url =
+			format!("{}/{}", self.api_base.trim_end_matches('/'), path.trim_start_matches('/'));
+		let mut request = self
+			.client
+			.post(url)
+			.header("X-ELF-Tenant-Id", &self.tenant_id)
+			.header("X-ELF-Project-Id", &self.project_id)
+			.header("X-ELF-Agent-Id", &self.agent_id)
+			.header("X-ELF-Read-Profile", &self.read_profile)
+			.json(body);
+
+		if let Some(token) = self.token.as_deref() {
+			request = request.bearer_auth(token);
+		}
+
+		let response = request.send().await?;
+		let status = response.status();
+		let text = response.text().await?;
+
+		if !status.is_success() {
+			return Err(eyre::eyre!("{path} returned {status}: {text}"));
+		}
+
+		Ok(serde_json::from_str(&text)?)
+	}
+}
+
+struct Sample {
+	latency_ms: f64,
+	outcome: Result<Value>,
+}
+
+/// Returns the synthetic English fact recorded for corpus item `index`.
+pub(super) fn synthetic_fact(index: usize) -> String {
+	let subject = SUBJECTS[index % SUBJECTS.len()];
+	let (verb, unit) = FACTS[(index / SUBJECTS.len()) % FACTS.len()];
+	let amount = 2 + (index * 7) % 97;
+
+	format!("The {subject} {verb} {amount} {unit} according to benchmark record {index}.")
+}
+
+/// Returns the synthetic question asked by search request `index`.
+pub(super) fn synthetic_query(index: usize) -> String {
+	let subject = SUBJECTS[index % SUBJECTS.len()];
+	let (_, unit) = FACTS[(index / SUBJECTS.len()) % FACTS.len()];
+
+	format!("How many {unit} does the {subject} use?")
+}
+
+pub(super) fn add_note_bodies(args: &Args, run_tag: &str) -> Vec<Value> {
+	let indices = (0..args.corpus_size).collect::<Vec<_>>();
+
+	indices
+		.chunks(args.notes_per_request)
+		.map(|chunk| {
+			let notes = chunk
+				.iter()
+				.map(|index| {
+					serde_json::json!({
+						"type": "fact",
+						"key": format!("bench-{run_tag}-{index}"),
+						"text": synthetic_fact(*index),
+						"importance": 0.5,
+						"confidence": 0.9,
+						"ttl_days": null,
+					})
+				})
+				.collect::<Vec<_>>();
+
+			serde_json::json!({ "scope": args.scope, "notes": notes })
+		})
+		.collect()
+}
+
+pub(super) fn add_event_bodies(args: &Args) -> Vec<Value> {
+	(0..args.events)
+		.map(|index| {
+			let fact = synthetic_fact(args.corpus_size + index);
+
+			serde_json::json!({
+				"scope": args.scope,
+				"messages": [{ "role": "user", "content": format!("Please remember this. {fact}") }],
+			})
+		})
+		.collect()
+}
+
+pub(super) fn search_bodies(args: &Args) -> Vec<Value> {
+	(0..args.searches)
+		.map(|index| {
+			serde_json::json!({
+				"mode": args.search_mode.as_str(),
+				"query": synthetic_query(index),
+			})
+		})
+		.collect()
+}
+
+/// Sends `bodies` to `path` with at most `concurrency` requests in flight.
+pub(super) async fn run_phase(
+	target: &Arc<Target>,
+	path: &'static str,
+	bodies: Vec<Value>,
+	concurrency: usize,
+) -> PhaseReport {
+	let requests = bodies.len();
+	let bodies = Arc::new(bodies);
+	let next = Arc::new(AtomicUsize::new(0));
+	let started = Instant::now();
+	let mut workers = Vec::with_capacity(concurrency);
+
+	for _ in 0..concurrency.min(requests) {
+		let target = Arc::clone(target);
+		let bodies = Arc::clone(&bodies);
+		let next = Arc::clone(&next);
+
+		workers.push(tokio::spawn(async move {
+			let mut samples = Vec::new();
+
+			loop {
+				let index = next.fetch_add(1, Ordering::Relaxed);
+				let Some(body) = bodies.get(index) else { break };
+				let sent = Instant::now();
+				let outcome = target.post(path, body).await;
+
+				samples
+					.push(Sample { latency_ms: sent.elapsed().as_secs_f64() * 1_000.0, outcome });
+			}
+
+			samples
+		}));
+	}
+
+	let mut samples = Vec::with_capacity(requests);
+
+	for worker in workers {
+		match worker.await {
+			Ok(worker_samples) => samples.extend(worker_samples),
+			Err(err) => tracing::error!(error = %err, path, "Benchmark worker task failed."),
+		}
+	}
+
+	summarize_phase(samples, started.elapsed().as_secs_f64() * 1_000.0)
+}
+
+fn summarize_phase(samples: Vec<Sample>, duration_ms: f64) -> PhaseReport {
+	let latencies = samples.iter().map(|sample| sample.latency_ms).collect::<Vec<_>>();
+	let mut report = PhaseReport {
+		requests: samples.len(),
+		duration_ms: stats::round_ms(duration_ms),
+		latency_ms: stats::summarize_latencies(&latencies),
+		..PhaseReport::default()
+	};
+	let mut ops = BTreeMap::new();
+
+	for sample in samples {
+		match sample.outcome {
+			Ok(body) => {
+				report.succeeded += 1;
+
+				for result in body.get("results").and_then(Value::as_array).into_iter().flatten() {
+					if let Some(op) = result.get("op").and_then(Value::as_str) {
+						*ops.entry(op.to_string()).or_insert(0) += 1;
+					}
+				}
+			},
+			Err(err) => {
+				report.failed += 1;
+
+				if report.first_error.is_none() {
+					report.first_error = Some(err.to_string());
+				}
+			},
+		}
+	}
+
+	report.ops = ops;
+	report.throughput_rps = if duration_ms > 0.0 {
+		stats::round_ms(report.succeeded as f64 * 1_000.0 / duration_ms)
+	} else {
+		0.0
+	};
+
+	report
+}
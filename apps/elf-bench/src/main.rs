@@ -0,0 +1,17 @@
+//! CLI entrypoint for the ELF write-path benchmark.
+
+mod app;
+
+use clap::Parser;
+use color_eyre::Result;
+
+use app::Args;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+	color_eyre::install()?;
+
+	let args = Args::parse();
+
+	app::run(args).await
+}
@@ -5,15 +5,17 @@ pub(in crate::args) mod constants;
 pub(in crate::args) mod diagnostics;
 pub(in crate::args) mod memory;
 pub(in crate::args) mod search;
+pub(in crate::args) mod serve;
 
 pub(crate) use self::{
 	benchmark::{BenchmarkArgs, BenchmarkCommand, BenchmarkReportArgs, BenchmarkRunArgs},
 	commands::{Cli, Commands},
 	common::{AdminEndpointArgs, ContextArgs, OutputArgs, PublicEndpointArgs, ReadContextArgs},
 	diagnostics::{
-		AdminPostArgs, DiagnosticsArgs, DiagnosticsCommand, NoteProvenanceArgs, RecentTracesArgs,
-		TraceBundleArgs,
+		DiagnosticsArgs, DiagnosticsCommand, IndexVerifyArgs, NoteProvenanceArgs,
+		QdrantRebuildArgs, RecentTracesArgs, TraceBundleArgs,
 	},
-	memory::{AddNoteArgs, BackfillArgs, StatusArgs},
+	memory::{AddNoteArgs, BackfillArgs, ExportArgs, RestoreArgs, StatusArgs},
 	search::{AdminSearchArgs, PayloadLevel, SearchArgs, SearchMode},
+	serve::ServeArgs,
 };
@@ -1,7 +1,8 @@
 use clap::{Parser, Subcommand};
 
 use crate::args::{
-	AddNoteArgs, BackfillArgs, BenchmarkArgs, DiagnosticsArgs, SearchArgs, StatusArgs,
+	AddNoteArgs, BackfillArgs, BenchmarkArgs, DiagnosticsArgs, ExportArgs, RestoreArgs, SearchArgs,
+	ServeArgs, StatusArgs,
 };
 
 #[derive(Debug, Parser)]
@@ -9,7 +10,7 @@ use crate::args::{
 	version = elf_cli::VERSION,
 	rename_all = "kebab",
 	styles = elf_cli::styles(),
-	about = "Local ELF workflow wrappers over the HTTP API and repo benchmark tasks."
+	about = "Local ELF workflow wrappers over the HTTP API and repo benchmark tasks, plus the all-in-one server."
 )]
 pub(crate) struct Cli {
 	#[command(subcommand)]
@@ -23,6 +24,10 @@ pub(crate) enum Commands {
 	AddNote(AddNoteArgs),
 	/// Create a search session through POST /v2/searches.
 	Search(SearchArgs),
+	/// Download a snapshot of all tenant memory through GET /v2/admin/export.
+	Export(ExportArgs),
+	/// Restore an export archive into the context tenant through POST /v2/admin/restore.
+	Restore(RestoreArgs),
 	/// Check local API process health.
 	Status(StatusArgs),
 	/// Run the checked-in resumable backfill benchmark workflow.
@@ -31,4 +36,6 @@ pub(crate) enum Commands {
 	Benchmark(BenchmarkArgs),
 	/// Read production diagnostics through admin HTTP endpoints.
 	Diagnostics(DiagnosticsArgs),
+	/// Run api, worker, and mcp in one process when `service.mode = "all_in_one"`.
+	Serve(ServeArgs),
 }
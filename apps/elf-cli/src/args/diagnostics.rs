@@ -9,13 +9,44 @@ pub(crate) struct DiagnosticsArgs {
 }
 
 #[derive(Debug, Args)]
-pub(crate) struct AdminPostArgs {
+pub(crate) struct QdrantRebuildArgs {
 	#[command(flatten)]
 	pub(crate) endpoint: AdminEndpointArgs,
 	#[command(flatten)]
 	pub(crate) context: ContextArgs,
 	#[command(flatten)]
 	pub(crate) output: OutputArgs,
+	/// Rebuild only notes of this tenant.
+	#[arg(long = "filter-tenant")]
+	pub(crate) filter_tenant: Option<String>,
+	/// Rebuild only notes of this project; requires --filter-tenant.
+	#[arg(long = "filter-project", requires = "filter_tenant")]
+	pub(crate) filter_project: Option<String>,
+	/// Rebuild only notes in this scope.
+	#[arg(long = "filter-scope")]
+	pub(crate) filter_scope: Option<String>,
+	/// Rebuild only notes of this type.
+	#[arg(long = "filter-type")]
+	pub(crate) filter_type: Option<String>,
+	/// Rebuild only notes updated after this RFC 3339 timestamp.
+	#[arg(long)]
+	pub(crate) updated_after: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct IndexVerifyArgs {
+	#[command(flatten)]
+	pub(crate) endpoint: AdminEndpointArgs,
+	#[command(flatten)]
+	pub(crate) context: ContextArgs,
+	#[command(flatten)]
+	pub(crate) output: OutputArgs,
+	/// Enqueue outbox rows that repair the reported drift.
+	#[arg(long)]
+	pub(crate) repair: bool,
+	/// Maximum findings to include in the report.
+	#[arg(long)]
+	pub(crate) max_findings: Option<u32>,
 }
 
 #[derive(Debug, Args)]
@@ -69,8 +100,11 @@ pub(crate) struct NoteProvenanceArgs {
 #[derive(Debug, Subcommand)]
 #[command(rename_all = "kebab")]
 pub(crate) enum DiagnosticsCommand {
-	/// Rebuild Qdrant from Postgres vectors through the admin API.
-	QdrantRebuild(AdminPostArgs),
+	/// Rebuild Qdrant from Postgres vectors through the admin API, optionally for a subset of
+	/// notes.
+	QdrantRebuild(QdrantRebuildArgs),
+	/// Compare Postgres chunks with Qdrant points and report drift.
+	IndexVerify(IndexVerifyArgs),
 	/// Run raw admin search and include trace/result/source_ref data.
 	RawSearch(AdminSearchArgs),
 	/// List recent persisted search traces.
@@ -1,6 +1,8 @@
+use std::path::PathBuf;
+
 use clap::Args;
 
-use crate::args::{ContextArgs, OutputArgs, PublicEndpointArgs};
+use crate::args::{AdminEndpointArgs, ContextArgs, OutputArgs, PublicEndpointArgs};
 
 #[derive(Debug, Args)]
 pub(crate) struct AddNoteArgs {
@@ -47,6 +49,32 @@ pub(crate) struct StatusArgs {
 	pub(crate) output: OutputArgs,
 }
 
+#[derive(Debug, Args)]
+pub(crate) struct ExportArgs {
+	#[command(flatten)]
+	pub(crate) endpoint: AdminEndpointArgs,
+	#[command(flatten)]
+	pub(crate) context: ContextArgs,
+	#[command(flatten)]
+	pub(crate) output: OutputArgs,
+	/// File that receives the JSONL archive.
+	#[arg(long)]
+	pub(crate) file: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct RestoreArgs {
+	#[command(flatten)]
+	pub(crate) endpoint: AdminEndpointArgs,
+	#[command(flatten)]
+	pub(crate) context: ContextArgs,
+	#[command(flatten)]
+	pub(crate) output: OutputArgs,
+	/// JSONL archive written by `elf export`.
+	#[arg(long)]
+	pub(crate) file: PathBuf,
+}
+
 #[derive(Debug, Args)]
 pub(crate) struct BackfillArgs {
 	#[command(flatten)]
@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub(crate) struct ServeArgs {
+	/// Path to the ELF configuration file; `service.mode` must be `all_in_one`.
+	#[arg(long, short = 'c', value_name = "FILE")]
+	pub(crate) config: PathBuf,
+	/// Validate and lint the configuration, print the findings, and exit.
+	#[arg(long)]
+	pub(crate) check_config: bool,
+}
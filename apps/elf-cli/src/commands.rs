@@ -1,9 +1,14 @@
+use std::{
+	fs::{self, File},
+	io::{BufWriter, Write},
+};
+
 use color_eyre::{Result, eyre};
 use reqwest::{Client, Method, StatusCode};
 use serde_json::Value;
 
 use crate::{
-	args::{AddNoteArgs, SearchArgs, StatusArgs},
+	args::{AddNoteArgs, ExportArgs, RestoreArgs, SearchArgs, StatusArgs},
 	http::{self, JsonRequest, redact_url},
 	json::{self},
 };
@@ -93,6 +98,88 @@ pub(crate) async fn run_search(client: &Client, args: SearchArgs) -> Result<()>
 	json::write_json(&output, args.output.pretty)
 }
 
+pub(crate) async fn run_export(client: &Client, args: ExportArgs) -> Result<()> {
+	let mut response = http::request_stream(
+		client,
+		&args.endpoint.admin_url,
+		"/v2/admin/export",
+		args.endpoint.admin_token.as_deref(),
+		&args.context,
+	)
+	.await?;
+	let request_id = http::header_string(response.headers(), "x-elf-request-id");
+	let mut writer = BufWriter::new(File::create(&args.file)?);
+	let mut bytes = 0_u64;
+	let mut lines = 0_u64;
+	let mut partial = Vec::new();
+	let mut last_line = Vec::new();
+
+	while let Some(chunk) = response.chunk().await? {
+		writer.write_all(&chunk)?;
+
+		bytes += chunk.len() as u64;
+
+		for segment in chunk.split_inclusive(|byte| *byte == b'\n') {
+			partial.extend_from_slice(segment);
+
+			if partial.ends_with(b"\n") {
+				lines += 1;
+				last_line = std::mem::take(&mut partial);
+			}
+		}
+	}
+
+	writer.flush()?;
+
+	let summary: Value = serde_json::from_slice(&last_line).unwrap_or(Value::Null);
+
+	if !partial.is_empty() || summary.get("kind").and_then(Value::as_str) != Some("summary") {
+		return Err(eyre::eyre!(
+			"ELF export to {} ended without a summary line; the archive is incomplete.",
+			args.file.display()
+		));
+	}
+
+	let output = serde_json::json!({
+		"schema": "elf.cli.export/v1",
+		"request": {
+			"admin_url": redact_url(&args.endpoint.admin_url),
+			"tenant_id": args.context.tenant_id,
+		},
+		"request_id": request_id,
+		"file": args.file.display().to_string(),
+		"bytes": bytes,
+		"lines": lines,
+		"counts": summary.get("counts").cloned().unwrap_or(Value::Null),
+	});
+
+	json::write_json(&output, args.output.pretty)
+}
+
+pub(crate) async fn run_restore(client: &Client, args: RestoreArgs) -> Result<()> {
+	let archive = fs::read(&args.file)?;
+	let response = http::request_ndjson(
+		client,
+		&args.endpoint.admin_url,
+		"/v2/admin/restore",
+		args.endpoint.admin_token.as_deref(),
+		&args.context,
+		archive,
+	)
+	.await?;
+	let output = serde_json::json!({
+		"schema": "elf.cli.restore/v1",
+		"request": {
+			"admin_url": redact_url(&args.endpoint.admin_url),
+			"tenant_id": args.context.tenant_id,
+		},
+		"file": args.file.display().to_string(),
+		"response": response,
+	});
+
+	json::write_json(&output, args.output.pretty)
+}
+
 pub(crate) async fn run_status(client: &Client, args: StatusArgs) -> Result<()> {
 	let url = http::join_url(&args.endpoint.api_url, "/health");
 	let mut request = client.get(&url);
@@ -4,8 +4,8 @@ use serde_json::Value;
 
 use crate::{
 	args::{
-		AdminPostArgs, AdminSearchArgs, DiagnosticsArgs, DiagnosticsCommand, NoteProvenanceArgs,
-		RecentTracesArgs, TraceBundleArgs,
+		AdminSearchArgs, DiagnosticsArgs, DiagnosticsCommand, IndexVerifyArgs, NoteProvenanceArgs,
+		QdrantRebuildArgs, RecentTracesArgs, TraceBundleArgs,
 	},
 	http::{self, JsonRequest, redact_url},
 	json::{self},
@@ -14,6 +14,7 @@ use crate::{
 pub(crate) async fn run_diagnostics(client: &Client, args: DiagnosticsArgs) -> Result<()> {
 	match args.command {
 		DiagnosticsCommand::QdrantRebuild(args) => run_qdrant_rebuild(client, args).await,
+		DiagnosticsCommand::IndexVerify(args) => run_index_verify(client, args).await,
 		DiagnosticsCommand::RawSearch(args) => run_raw_search(client, args).await,
 		DiagnosticsCommand::RecentTraces(args) => run_recent_traces(client, args).await,
 		DiagnosticsCommand::TraceBundle(args) => run_trace_bundle(client, args).await,
@@ -21,7 +22,14 @@ pub(crate) async fn run_diagnostics(client: &Client, args: DiagnosticsArgs) -> R
 	}
 }
 
-async fn run_qdrant_rebuild(client: &Client, args: AdminPostArgs) -> Result<()> {
+async fn run_qdrant_rebuild(client: &Client, args: QdrantRebuildArgs) -> Result<()> {
+	let body = serde_json::json!({
+		"tenant_id": args.filter_tenant,
+		"project_id": args.filter_project,
+		"scope": args.filter_scope,
+		"note_type": args.filter_type,
+		"updated_after": args.updated_after,
+	});
 	let response = http::request_json(
 		client,
 		JsonRequest {
@@ -31,13 +39,42 @@ async fn run_qdrant_rebuild(client: &Client, args: AdminPostArgs) -> Result<()>
 			token: args.endpoint.admin_token.as_deref(),
 			context: Some(&args.context),
 			read_profile: None,
-			body: None,
+			body: Some(&body),
 		},
 	)
 	.await?;
 	let output = serde_json::json!({
 		"schema": "elf.cli.diagnostics.qdrant_rebuild/v1",
 		"admin_url": redact_url(&args.endpoint.admin_url),
+		"filter": body,
+		"response": response,
+	});
+
+	json::write_json(&output, args.output.pretty)
+}
+
+async fn run_index_verify(client: &Client, args: IndexVerifyArgs) -> Result<()> {
+	let body = serde_json::json!({
+		"repair": args.repair,
+		"max_findings": args.max_findings,
+	});
+	let response = http::request_json(
+		client,
+		JsonRequest {
+			method: Method::POST,
+			base_url: &args.endpoint.admin_url,
+			path: "/v2/admin/index/verify",
+			token: args.endpoint.admin_token.as_deref(),
+			context: Some(&args.context),
+			read_profile: None,
+			body: Some(&body),
+		},
+	)
+	.await?;
+	let output = serde_json::json!({
+		"schema": "elf.cli.diagnostics.index_verify/v1",
+		"admin_url": redact_url(&args.endpoint.admin_url),
+		"repair": args.repair,
 		"response": response,
 	});
 
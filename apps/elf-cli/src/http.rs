@@ -64,6 +64,59 @@ pub(crate) async fn request_json_query(
 	parse_json_response(request.send().await?).await
 }
 
+pub(crate) async fn request_ndjson(
+	client: &Client,
+	base_url: &str,
+	path: &str,
+	token: Option<&str>,
+	context: &ContextArgs,
+	body: Vec<u8>,
+) -> Result<Value> {
+	let mut request = client
+		.post(join_url(base_url, path))
+		.header("Content-Type", "application/x-ndjson")
+		.body(body);
+
+	if let Some(token) = token {
+		request = request.bearer_auth(token);
+	}
+
+	request = add_context_headers(request, context);
+
+	parse_json_response(request.send().await?).await
+}
+
+pub(crate) async fn request_stream(
+	client: &Client,
+	base_url: &str,
+	path: &str,
+	token: Option<&str>,
+	context: &ContextArgs,
+) -> Result<Response> {
+	let mut request = client.get(join_url(base_url, path));
+
+	if let Some(token) = token {
+		request = request.bearer_auth(token);
+	}
+
+	request = add_context_headers(request, context);
+
+	let response = request.send().await?;
+	let status = response.status();
+
+	if !status.is_success() {
+		let request_id = header_string(response.headers(), "x-elf-request-id");
+		let text = response.text().await?;
+
+		return Err(eyre::eyre!(
+			"ELF request failed with HTTP status {status} and request_id {}: {text}",
+			request_id.as_deref().unwrap_or("unknown")
+		));
+	}
+
+	Ok(response)
+}
+
 fn add_context_headers(request: RequestBuilder, context: &ContextArgs) -> RequestBuilder {
 	request
 		.header("X-ELF-Tenant-Id", &context.tenant_id)
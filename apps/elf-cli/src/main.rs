@@ -5,6 +5,7 @@ mod commands;
 mod diagnostics;
 mod http;
 mod json;
+mod serve;
 mod tasks;
 
 use clap::Parser;
@@ -26,9 +27,12 @@ async fn run(cli: Cli) -> Result<()> {
 	match cli.command {
 		Commands::AddNote(args) => commands::run_add_note(&client, args).await,
 		Commands::Search(args) => commands::run_search(&client, args).await,
+		Commands::Export(args) => commands::run_export(&client, args).await,
+		Commands::Restore(args) => commands::run_restore(&client, args).await,
 		Commands::Status(args) => commands::run_status(&client, args).await,
 		Commands::Backfill(args) => tasks::run_backfill(args),
 		Commands::Benchmark(args) => tasks::run_benchmark(args),
 		Commands::Diagnostics(args) => diagnostics::run_diagnostics(&client, args).await,
+		Commands::Serve(args) => serve::run_serve(args).await,
 	}
 }
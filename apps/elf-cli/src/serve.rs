@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use axum::{
+	Router,
+	extract::Request,
+	http::Method,
+	middleware::{self, Next},
+	response::Response,
+};
+use color_eyre::{Result, eyre};
+use tokio::sync::Notify;
+
+use crate::args::ServeArgs;
+use elf_api::{routes, state::AppState, warmup};
+use elf_storage::db::Db;
+
+/// Runs the API, worker, and MCP server in one process on a shared Postgres pool.
+///
+/// Successful non-GET API requests wake the worker through an in-process channel, so outbox jobs
+/// are picked up without waiting out the worker poll interval.
+pub(crate) async fn run_serve(args: ServeArgs) -> Result<()> {
+	let (config, lints) = elf_config::load_with_lints(&args.config)?;
+
+	if args.check_config {
+		elf_runtime::telemetry::print_lints(&lints);
+
+		return Ok(());
+	}
+	if !config.service.is_all_in_one() {
+		return Err(eyre::eyre!(
+			"elf serve requires service.mode = \"all_in_one\"; run elf-api, elf-worker, and elf-mcp for split deployments."
+		));
+	}
+
+	let _telemetry = elf_runtime::telemetry::init(&config.service, "elf")?;
+
+	elf_runtime::telemetry::log_lints(&lints);
+
+	let (http_addr, admin_addr) = elf_api::listen_addrs(&config)?;
+	let db = Db::connect(&config.storage.postgres).await?;
+
+	db.ensure_schema(config.storage.qdrant.vector_dim).await?;
+
+	let worker_state = elf_worker::build_state(&config, Db { pool: db.pool.clone() }).await?;
+	let state = AppState::from_db(config, db).await?;
+	let service = state.service.clone();
+	let wakeup = Arc::new(Notify::new());
+
+	warmup::spawn_warmup(state.service.clone(), state.readiness.clone());
+
+	let app = with_outbox_wakeup(routes::router(state.clone()), wakeup.clone());
+	let admin_app = with_outbox_wakeup(routes::admin_router(state), wakeup.clone());
+	let api = elf_api::serve(app, admin_app, http_addr, admin_addr);
+	let worker = async {
+		elf_worker::worker::run_worker_with_wakeup(worker_state, Some(wakeup)).await?;
+
+		Ok(())
+	};
+	let mcp = async {
+		if service.cfg.mcp.is_none() {
+			tracing::info!("No [mcp] section is configured; the MCP server is disabled.");
+
+			return Ok(());
+		}
+
+		elf_mcp::serve(&service.cfg).await
+	};
+
+	tracing::info!("Running api, worker, and mcp in all_in_one mode.");
+	tokio::try_join!(api, worker, mcp)?;
+
+	Ok(())
+}
+
+fn with_outbox_wakeup(router: Router, wakeup: Arc<Notify>) -> Router {
+	router.layer(middleware::from_fn(move |request: Request, next: Next| {
+		let wakeup = wakeup.clone();
+
+		async move { notify_after_write(request, next, &wakeup).await }
+	}))
+}
+
+async fn notify_after_write(request: Request, next: Next, wakeup: &Notify) -> Response {
+	let write = !matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+	let response = next.run(request).await;
+
+	if write && response.status().is_success() {
+		wakeup.notify_one();
+	}
+
+	response
+}
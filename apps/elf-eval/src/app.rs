@@ -1,5 +1,6 @@
 mod cli;
 mod compare;
+mod continuous;
 mod dataset;
 mod eval;
 mod metrics;
@@ -19,19 +20,32 @@ pub async fn run(args: Args) -> Result<()> {
 
 	tracing_subscriber::fmt().with_env_filter(filter).init();
 
-	if !args.trace_id.is_empty() {
+	if args.continuous {
+		return continuous::run_continuous(args.config_a.as_path(), config_a, &args).await;
+	}
+	if !args.trace_id.is_empty() || !args.trace_artifact.is_empty() {
 		let Some(config_b_path) = &args.config_b else {
 			return Err(eyre::eyre!("Trace compare mode requires --config-b."));
 		};
 		let config_b = elf_config::load(config_b_path)?;
-		let output = trace_compare::trace_compare(
-			args.config_a.as_path(),
-			config_a,
-			config_b_path.as_path(),
-			config_b,
-			&args,
-		)
-		.await?;
+		let output = if args.trace_artifact.is_empty() {
+			trace_compare::trace_compare(
+				args.config_a.as_path(),
+				config_a,
+				config_b_path.as_path(),
+				config_b,
+				&args,
+			)
+			.await?
+		} else {
+			trace_compare::trace_compare_artifacts(
+				args.config_a.as_path(),
+				config_a,
+				config_b_path.as_path(),
+				config_b,
+				&args,
+			)?
+		};
 		let json = serde_json::to_string_pretty(&output)?;
 
 		println!("{json}");
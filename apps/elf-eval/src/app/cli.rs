@@ -15,7 +15,12 @@ pub struct Args {
 	pub config_a: PathBuf,
 	#[arg(long = "config-b", value_name = "FILE")]
 	pub config_b: Option<PathBuf>,
-	#[arg(long, short = 'd', value_name = "FILE", required_unless_present = "trace_id")]
+	#[arg(
+		long,
+		short = 'd',
+		value_name = "FILE",
+		required_unless_present_any = ["trace_id", "trace_artifact", "continuous"]
+	)]
 	pub dataset: Option<PathBuf>,
 	#[arg(long, value_name = "N")]
 	pub top_k: Option<u32>,
@@ -29,6 +34,44 @@ pub struct Args {
 	pub search_mode_b: Option<SearchMode>,
 	#[arg(long = "trace-id", value_name = "UUID", num_args = 1..)]
 	pub trace_id: Vec<Uuid>,
+	#[arg(long = "trace-artifact", value_name = "FILE", num_args = 1.., conflicts_with = "trace_id")]
+	pub trace_artifact: Vec<PathBuf>,
+	/// Sample recent production traces on a schedule instead of evaluating a dataset.
+	#[arg(long, conflicts_with_all = ["dataset", "trace_id", "trace_artifact"])]
+	pub continuous: bool,
+	/// Run a single continuous evaluation cycle and exit.
+	#[arg(long, requires = "continuous")]
+	pub once: bool,
+	/// Hours between continuous evaluation cycles.
+	#[arg(long, value_name = "HOURS", default_value_t = 24)]
+	pub interval_hours: u32,
+	/// Hours of trace history sampled by each cycle.
+	#[arg(long, value_name = "HOURS", default_value_t = 24)]
+	pub sample_window_hours: u32,
+	/// Maximum number of traces sampled by each cycle.
+	#[arg(long, value_name = "N", default_value_t = 500)]
+	pub sample_size: u32,
+	/// Minutes after a search during which a recorded hit counts as a relevance label.
+	#[arg(long, value_name = "MINUTES", default_value_t = 30)]
+	pub label_window_minutes: u32,
+	/// Minimum labeled traces required before quality thresholds are enforced.
+	#[arg(long, value_name = "N", default_value_t = 20)]
+	pub min_labeled_traces: u32,
+	/// Alert when the mean reciprocal rank over labeled traces drops below this value.
+	#[arg(long, value_name = "RATIO")]
+	pub min_mean_rr: Option<f64>,
+	/// Alert when the mean nDCG over labeled traces drops below this value.
+	#[arg(long, value_name = "RATIO")]
+	pub min_mean_ndcg: Option<f64>,
+	/// Alert when the share of sampled traces with zero results exceeds this value.
+	#[arg(long, value_name = "RATIO")]
+	pub max_empty_result_rate: Option<f64>,
+	/// Alert when the set churn between repeated identical queries exceeds this value.
+	#[arg(long, value_name = "RATIO")]
+	pub max_set_churn_at_k: Option<f64>,
+	/// Webhook URL that receives a JSON payload when a threshold is breached.
+	#[arg(long, value_name = "URL")]
+	pub alert_webhook: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Serialize, ValueEnum)]
@@ -0,0 +1,230 @@
+mod analysis;
+mod types;
+
+use std::{path::Path, time::Duration as StdDuration};
+
+use color_eyre::{Result, eyre};
+use time::{Duration, OffsetDateTime, format_description::well_known::Rfc3339};
+use uuid::Uuid;
+
+use crate::app::Args;
+use elf_config::Config;
+use elf_storage::db::Db;
+use types::{
+	ContinuousRunReport, ContinuousThresholds, SampledTrace, SampledTraceRow, TraceItemRow,
+	TraceLabelRow,
+};
+
+const ALERT_WEBHOOK_TIMEOUT_SECS: u64 = 10;
+
+/// Samples recent production traces on a schedule, records quality and stability metrics, and
+/// alerts on threshold breaches.
+///
+/// Runs until interrupted unless `--once` is set. A failed cycle is logged and retried on the next
+/// interval so a transient database error does not stop the daemon.
+pub(super) async fn run_continuous(config_path: &Path, config: Config, args: &Args) -> Result<()> {
+	let db = Db::connect(&config.storage.postgres).await?;
+
+	db.ensure_schema(config.storage.qdrant.vector_dim).await?;
+
+	let client = reqwest::Client::builder()
+		.timeout(StdDuration::from_secs(ALERT_WEBHOOK_TIMEOUT_SECS))
+		.build()?;
+	let interval = StdDuration::from_secs(u64::from(args.interval_hours.max(1)) * 3_600);
+
+	loop {
+		match run_cycle(&db, &client, config_path, args, OffsetDateTime::now_utc()).await {
+			Ok(report) => {
+				let json = serde_json::to_string_pretty(&report)?;
+
+				println!("{json}");
+			},
+			Err(err) if !args.once => {
+				tracing::error!(error = %err, "Continuous evaluation cycle failed.");
+			},
+			Err(err) => return Err(err),
+		}
+
+		if args.once {
+			return Ok(());
+		}
+
+		tokio::time::sleep(interval).await;
+	}
+}
+
+fn thresholds_from_args(args: &Args) -> ContinuousThresholds {
+	ContinuousThresholds {
+		min_labeled_traces: args.min_labeled_traces as usize,
+		min_mean_rr: args.min_mean_rr,
+		min_mean_ndcg: args.min_mean_ndcg,
+		max_empty_result_rate: args.max_empty_result_rate,
+		max_set_churn_at_k: args.max_set_churn_at_k,
+	}
+}
+
+async fn run_cycle(
+	db: &Db,
+	client: &reqwest::Client,
+	config_path: &Path,
+	args: &Args,
+	now: OffsetDateTime,
+) -> Result<ContinuousRunReport> {
+	// Traces newer than the label window may still receive hits, so they are left for the next
+	// cycle.
+	let label_window = Duration::minutes(i64::from(args.label_window_minutes));
+	let window_end = now - label_window;
+	let window_start = window_end - Duration::hours(i64::from(args.sample_window_hours.max(1)));
+	let traces =
+		load_sampled_traces(db, window_start, window_end, args.sample_size, label_window).await?;
+	let metrics = analysis::summarize(&traces);
+	let breaches = analysis::evaluate_thresholds(&metrics, &thresholds_from_args(args));
+	let run_id = Uuid::new_v4();
+	let config_path = config_path.display().to_string();
+	let mut report = ContinuousRunReport {
+		run_id,
+		config_path,
+		window_start: window_start.format(&Rfc3339)?,
+		window_end: window_end.format(&Rfc3339)?,
+		metrics,
+		breaches,
+		alert_status: "none",
+	};
+
+	if !report.breaches.is_empty() {
+		report.alert_status = match args.alert_webhook.as_deref() {
+			Some(url) => match send_alert(client, url, &report).await {
+				Ok(()) => "sent",
+				Err(err) => {
+					tracing::warn!(error = %err, run_id = %run_id, "Continuous eval alert failed.");
+
+					"failed"
+				},
+			},
+			None => "not_configured",
+		};
+	}
+
+	insert_run(db, &report, window_start, window_end, now).await?;
+
+	Ok(report)
+}
+
+async fn load_sampled_traces(
+	db: &Db,
+	window_start: OffsetDateTime,
+	window_end: OffsetDateTime,
+	sample_size: u32,
+	label_window: Duration,
+) -> Result<Vec<SampledTrace>> {
+	let rows = sqlx::query_as::<_, SampledTraceRow>(
+		"\
+SELECT
+	trace_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	read_profile,
+	query,
+	top_k,
+	created_at
+FROM search_traces
+WHERE created_at > $1 AND created_at <= $2
+ORDER BY random()
+LIMIT $3",
+	)
+	.bind(window_start)
+	.bind(window_end)
+	.bind(i64::from(sample_size.max(1)))
+	.fetch_all(&db.pool)
+	.await?;
+	let trace_ids: Vec<Uuid> = rows.iter().map(|row| row.trace_id).collect();
+	let items = sqlx::query_as::<_, TraceItemRow>(
+		"\
+SELECT trace_id, note_id
+FROM search_trace_items
+WHERE trace_id = ANY($1)
+ORDER BY trace_id, rank ASC",
+	)
+	.bind(&trace_ids)
+	.fetch_all(&db.pool)
+	.await?;
+	// Hits recorded during the search itself share the trace timestamp, so only later hits (for
+	// example, detail reads) count as relevance labels.
+	let labels = sqlx::query_as::<_, TraceLabelRow>(
+		"\
+SELECT DISTINCT i.trace_id, i.note_id
+FROM search_trace_items i
+JOIN search_traces t ON t.trace_id = i.trace_id
+JOIN memory_hits h ON h.note_id = i.note_id
+WHERE i.trace_id = ANY($1)
+	AND h.ts > t.created_at
+	AND h.ts <= t.created_at + ($2::bigint * interval '1 second')",
+	)
+	.bind(&trace_ids)
+	.bind(label_window.whole_seconds())
+	.fetch_all(&db.pool)
+	.await?;
+
+	Ok(analysis::build_sampled_traces(rows, items, labels))
+}
+
+async fn send_alert(
+	client: &reqwest::Client,
+	url: &str,
+	report: &ContinuousRunReport,
+) -> Result<()> {
+	let body = serde_json::json!({
+		"event": "elf_eval.continuous.threshold_breach",
+		"run": report,
+	});
+	let response = client.post(url).json(&body).send().await?;
+	let status = response.status();
+
+	if !status.is_success() {
+		return Err(eyre::eyre!("Alert webhook returned HTTP {status}."));
+	}
+
+	Ok(())
+}
+
+async fn insert_run(
+	db: &Db,
+	report: &ContinuousRunReport,
+	window_start: OffsetDateTime,
+	window_end: OffsetDateTime,
+	now: OffsetDateTime,
+) -> Result<()> {
+	sqlx::query(
+		"\
+INSERT INTO eval_continuous_runs (
+	run_id,
+	config_path,
+	window_start,
+	window_end,
+	sampled_traces,
+	labeled_traces,
+	metrics,
+	breaches,
+	alert_status,
+	created_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+	)
+	.bind(report.run_id)
+	.bind(report.config_path.as_str())
+	.bind(window_start)
+	.bind(window_end)
+	.bind(i32::try_from(report.metrics.sampled_traces)?)
+	.bind(i32::try_from(report.metrics.labeled_traces)?)
+	.bind(serde_json::to_value(&report.metrics)?)
+	.bind(serde_json::to_value(&report.breaches)?)
+	.bind(report.alert_status)
+	.bind(now)
+	.execute(&db.pool)
+	.await?;
+
+	Ok(())
+}
+
+#[cfg(test)] mod tests;
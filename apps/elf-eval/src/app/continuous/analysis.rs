@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::app::{
+	continuous::types::{
+		ContinuousMetrics, ContinuousThresholds, SampledTrace, SampledTraceRow, ThresholdBreach,
+		TraceItemRow, TraceLabelRow,
+	},
+	metrics,
+};
+
+/// Joins sampled trace rows with their ranked items and weak labels.
+///
+/// Items must be ordered by rank within each trace; duplicate notes keep their first rank.
+pub(super) fn build_sampled_traces(
+	rows: Vec<SampledTraceRow>,
+	items: Vec<TraceItemRow>,
+	labels: Vec<TraceLabelRow>,
+) -> Vec<SampledTrace> {
+	let mut items_by_trace: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+	let mut labels_by_trace: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+
+	for item in items {
+		let note_ids = items_by_trace.entry(item.trace_id).or_default();
+
+		if !note_ids.contains(&item.note_id) {
+			note_ids.push(item.note_id);
+		}
+	}
+	for label in labels {
+		let note_ids = labels_by_trace.entry(label.trace_id).or_default();
+
+		if !note_ids.contains(&label.note_id) {
+			note_ids.push(label.note_id);
+		}
+	}
+
+	rows.into_iter()
+		.map(|row| SampledTrace {
+			context_key: format!(
+				"{}\u{1f}{}\u{1f}{}\u{1f}{}\u{1f}{}",
+				row.tenant_id, row.project_id, row.agent_id, row.read_profile, row.query
+			),
+			top_k: usize::try_from(row.top_k).unwrap_or(0).max(1),
+			created_at: row.created_at,
+			retrieved_note_ids: items_by_trace.remove(&row.trace_id).unwrap_or_default(),
+			labeled_note_ids: labels_by_trace.remove(&row.trace_id).unwrap_or_default(),
+		})
+		.collect()
+}
+
+/// Computes weak-label retrieval quality and repeated-query stability for sampled traces.
+///
+/// Quality metrics cover only traces with at least one label. Stability compares each repeated
+/// search (same tenant, project, agent, read profile, and query text) with its previous run.
+pub(super) fn summarize(traces: &[SampledTrace]) -> ContinuousMetrics {
+	let sampled_traces = traces.len();
+
+	if sampled_traces == 0 {
+		return ContinuousMetrics::default();
+	}
+
+	let empty = traces.iter().filter(|trace| trace.retrieved_note_ids.is_empty()).count();
+	let mut rr = Vec::new();
+	let mut ndcg = Vec::new();
+	let mut precision = Vec::new();
+
+	for trace in traces.iter().filter(|trace| !trace.labeled_note_ids.is_empty()) {
+		let expected: HashSet<Uuid> = trace.labeled_note_ids.iter().copied().collect();
+		let metrics = metrics::compute_metrics(&trace.retrieved_note_ids, &expected);
+
+		rr.push(metrics.rr);
+		ndcg.push(metrics.ndcg);
+		precision.push(metrics.precision_at_k);
+	}
+
+	let (positional_churn, set_churn) = repeated_query_churn(traces);
+
+	ContinuousMetrics {
+		sampled_traces,
+		labeled_traces: rr.len(),
+		label_coverage: rr.len() as f64 / sampled_traces as f64,
+		empty_result_rate: empty as f64 / sampled_traces as f64,
+		mean_rr: mean(&rr),
+		mean_ndcg: mean(&ndcg),
+		avg_precision_at_k: mean(&precision),
+		repeated_query_pairs: set_churn.len(),
+		avg_positional_churn_at_k: mean(&positional_churn),
+		avg_set_churn_at_k: mean(&set_churn),
+	}
+}
+
+/// Returns every configured threshold the metrics violate.
+///
+/// Quality thresholds are skipped until `min_labeled_traces` labeled traces were sampled, so a
+/// quiet window does not page anyone.
+pub(super) fn evaluate_thresholds(
+	metrics: &ContinuousMetrics,
+	thresholds: &ContinuousThresholds,
+) -> Vec<ThresholdBreach> {
+	let mut breaches = Vec::new();
+	let enough_labels =
+		metrics.labeled_traces > 0 && metrics.labeled_traces >= thresholds.min_labeled_traces;
+
+	if enough_labels {
+		push_below(&mut breaches, "mean_rr", metrics.mean_rr, thresholds.min_mean_rr);
+		push_below(&mut breaches, "mean_ndcg", metrics.mean_ndcg, thresholds.min_mean_ndcg);
+	}
+	if metrics.sampled_traces > 0 {
+		push_above(
+			&mut breaches,
+			"empty_result_rate",
+			Some(metrics.empty_result_rate),
+			thresholds.max_empty_result_rate,
+		);
+	}
+
+	push_above(
+		&mut breaches,
+		"avg_set_churn_at_k",
+		metrics.avg_set_churn_at_k,
+		thresholds.max_set_churn_at_k,
+	);
+
+	breaches
+}
+
+fn repeated_query_churn(traces: &[SampledTrace]) -> (Vec<f64>, Vec<f64>) {
+	let mut groups: HashMap<&str, Vec<&SampledTrace>> = HashMap::new();
+	let mut positional = Vec::new();
+	let mut set = Vec::new();
+
+	for trace in traces {
+		groups.entry(trace.context_key.as_str()).or_default().push(trace);
+	}
+	for group in groups.values_mut() {
+		group.sort_by_key(|trace| trace.created_at);
+
+		for pair in group.windows(2) {
+			let (previous, current) = (pair[0], pair[1]);
+			let returned = previous.retrieved_note_ids.len().max(current.retrieved_note_ids.len());
+
+			if returned == 0 {
+				continue;
+			}
+
+			// Compare only the positions either run filled so short result lists are not
+			// reported as churn.
+			let k = previous.top_k.min(current.top_k).min(returned);
+			let (positional_churn, set_churn) = metrics::churn_against_baseline_at_k(
+				&previous.retrieved_note_ids,
+				&current.retrieved_note_ids,
+				k,
+			);
+
+			positional.push(positional_churn);
+			set.push(set_churn);
+		}
+	}
+
+	(positional, set)
+}
+
+fn push_below(
+	breaches: &mut Vec<ThresholdBreach>,
+	metric: &'static str,
+	value: Option<f64>,
+	threshold: Option<f64>,
+) {
+	if let (Some(value), Some(threshold)) = (value, threshold)
+		&& value < threshold
+	{
+		breaches.push(ThresholdBreach { metric, value, threshold });
+	}
+}
+
+fn push_above(
+	breaches: &mut Vec<ThresholdBreach>,
+	metric: &'static str,
+	value: Option<f64>,
+	threshold: Option<f64>,
+) {
+	if let (Some(value), Some(threshold)) = (value, threshold)
+		&& value > threshold
+	{
+		breaches.push(ThresholdBreach { metric, value, threshold });
+	}
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+	if values.is_empty() {
+		return None;
+	}
+
+	Some(values.iter().sum::<f64>() / values.len() as f64)
+}
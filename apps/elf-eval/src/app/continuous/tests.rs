@@ -0,0 +1,101 @@
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::app::continuous::{
+	analysis,
+	types::{ContinuousThresholds, SampledTrace, SampledTraceRow, TraceItemRow, TraceLabelRow},
+};
+
+fn trace(query: &str, minutes: i64, retrieved: &[Uuid], labeled: &[Uuid]) -> SampledTrace {
+	SampledTrace {
+		context_key: format!("t\u{1f}p\u{1f}a\u{1f}private_plus_project\u{1f}{query}"),
+		top_k: 3,
+		created_at: OffsetDateTime::UNIX_EPOCH + Duration::minutes(minutes),
+		retrieved_note_ids: retrieved.to_vec(),
+		labeled_note_ids: labeled.to_vec(),
+	}
+}
+
+#[test]
+fn build_sampled_traces_orders_items_and_attaches_labels() {
+	let trace_id = Uuid::new_v4();
+	let note_a = Uuid::new_v4();
+	let note_b = Uuid::new_v4();
+	let rows = vec![SampledTraceRow {
+		trace_id,
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		read_profile: "private_only".to_string(),
+		query: "deploy steps".to_string(),
+		top_k: 5,
+		created_at: OffsetDateTime::UNIX_EPOCH,
+	}];
+	let items = vec![
+		TraceItemRow { trace_id, note_id: note_a },
+		TraceItemRow { trace_id, note_id: note_b },
+		TraceItemRow { trace_id, note_id: note_a },
+	];
+	let labels = vec![TraceLabelRow { trace_id, note_id: note_b }];
+	let traces = analysis::build_sampled_traces(rows, items, labels);
+
+	assert_eq!(traces.len(), 1);
+	assert_eq!(traces[0].retrieved_note_ids, vec![note_a, note_b]);
+	assert_eq!(traces[0].labeled_note_ids, vec![note_b]);
+	assert_eq!(traces[0].top_k, 5);
+}
+
+#[test]
+fn summarize_scores_labeled_traces_and_repeated_query_churn() {
+	let [a, b, c, d] = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+	let traces = vec![
+		trace("deploy steps", 0, &[a, b, c], &[b]),
+		trace("deploy steps", 10, &[a, b, d], &[]),
+		trace("rollback plan", 5, &[c], &[c]),
+		trace("unknown topic", 6, &[], &[]),
+	];
+	let metrics = analysis::summarize(&traces);
+
+	assert_eq!(metrics.sampled_traces, 4);
+	assert_eq!(metrics.labeled_traces, 2);
+	assert!((metrics.label_coverage - 0.5).abs() < 1e-12);
+	assert!((metrics.empty_result_rate - 0.25).abs() < 1e-12);
+	assert!((metrics.mean_rr.expect("labeled traces") - 0.75).abs() < 1e-12);
+	assert_eq!(metrics.repeated_query_pairs, 1);
+	assert!((metrics.avg_positional_churn_at_k.expect("pair") - (1.0 / 3.0)).abs() < 1e-12);
+	assert!((metrics.avg_set_churn_at_k.expect("pair") - (1.0 / 3.0)).abs() < 1e-12);
+}
+
+#[test]
+fn summarize_ignores_short_result_lists_when_measuring_churn() {
+	let a = Uuid::new_v4();
+	let traces = vec![trace("deploy steps", 0, &[a], &[]), trace("deploy steps", 1, &[a], &[])];
+	let metrics = analysis::summarize(&traces);
+
+	assert_eq!(metrics.avg_set_churn_at_k, Some(0.0));
+	assert_eq!(metrics.avg_positional_churn_at_k, Some(0.0));
+	assert_eq!(metrics.mean_rr, None);
+}
+
+#[test]
+fn evaluate_thresholds_skips_quality_checks_until_enough_labels() {
+	let a = Uuid::new_v4();
+	let b = Uuid::new_v4();
+	let metrics = analysis::summarize(&[trace("deploy steps", 0, &[a, b], &[b])]);
+	let mut thresholds = ContinuousThresholds {
+		min_labeled_traces: 2,
+		min_mean_rr: Some(0.9),
+		max_empty_result_rate: Some(0.0),
+		..Default::default()
+	};
+
+	assert!(analysis::evaluate_thresholds(&metrics, &thresholds).is_empty());
+
+	thresholds.min_labeled_traces = 1;
+
+	let breaches = analysis::evaluate_thresholds(&metrics, &thresholds);
+
+	assert_eq!(breaches.len(), 1);
+	assert_eq!(breaches[0].metric, "mean_rr");
+	assert!((breaches[0].value - 0.5).abs() < 1e-12);
+}
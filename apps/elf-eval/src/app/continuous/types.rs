@@ -0,0 +1,85 @@
+use serde::Serialize;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow)]
+pub(super) struct SampledTraceRow {
+	pub(super) trace_id: Uuid,
+	pub(super) tenant_id: String,
+	pub(super) project_id: String,
+	pub(super) agent_id: String,
+	pub(super) read_profile: String,
+	pub(super) query: String,
+	pub(super) top_k: i32,
+	pub(super) created_at: OffsetDateTime,
+}
+
+#[derive(Debug, FromRow)]
+pub(super) struct TraceItemRow {
+	pub(super) trace_id: Uuid,
+	pub(super) note_id: Uuid,
+}
+
+#[derive(Debug, FromRow)]
+pub(super) struct TraceLabelRow {
+	pub(super) trace_id: Uuid,
+	pub(super) note_id: Uuid,
+}
+
+/// One sampled production search with its returned notes and weak relevance labels.
+#[derive(Debug)]
+pub(super) struct SampledTrace {
+	pub(super) context_key: String,
+	pub(super) top_k: usize,
+	pub(super) created_at: OffsetDateTime,
+	pub(super) retrieved_note_ids: Vec<Uuid>,
+	pub(super) labeled_note_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub(super) struct ContinuousMetrics {
+	pub(super) sampled_traces: usize,
+	pub(super) labeled_traces: usize,
+	pub(super) label_coverage: f64,
+	pub(super) empty_result_rate: f64,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(super) mean_rr: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(super) mean_ndcg: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(super) avg_precision_at_k: Option<f64>,
+	pub(super) repeated_query_pairs: usize,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(super) avg_positional_churn_at_k: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(super) avg_set_churn_at_k: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(super) struct ThresholdBreach {
+	pub(super) metric: &'static str,
+	pub(super) value: f64,
+	pub(super) threshold: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct ContinuousRunReport {
+	pub(super) run_id: Uuid,
+	pub(super) config_path: String,
+	pub(super) window_start: String,
+	pub(super) window_end: String,
+	pub(super) metrics: ContinuousMetrics,
+	pub(super) breaches: Vec<ThresholdBreach>,
+	pub(super) alert_status: &'static str,
+}
+
+/// Alert thresholds applied to each continuous evaluation cycle.
+#[derive(Clone, Debug, Default)]
+pub(super) struct ContinuousThresholds {
+	pub(super) min_labeled_traces: usize,
+	pub(super) min_mean_rr: Option<f64>,
+	pub(super) min_mean_ndcg: Option<f64>,
+	pub(super) max_empty_result_rate: Option<f64>,
+	pub(super) max_set_churn_at_k: Option<f64>,
+}
@@ -77,8 +77,15 @@ pub(super) fn merge_query(
 			top_k: Some(top_k),
 			candidate_k: Some(candidate_k),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking,
+			session_id: None,
+			session_mode: None,
 		},
 	})
 }
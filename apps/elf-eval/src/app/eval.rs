@@ -199,6 +199,7 @@ async fn search_with_mode(
 				expires_at: response.expires_at,
 				items: response.items,
 				trajectory_summary: response.trajectory_summary,
+				warnings: response.warnings,
 			})
 		},
 	}
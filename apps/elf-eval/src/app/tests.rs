@@ -63,6 +63,9 @@ fn retrieval_top_rank_retention_counts_unique_notes_and_retained_notes() {
 			note_updated_at: now,
 			note_hit_count: 0,
 			note_last_hit_at: None,
+			note_evidence_coverage: None,
+			note_session_adjustment: None,
+			note_feedback_adjustment: None,
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -71,6 +74,7 @@ fn retrieval_top_rank_retention_counts_unique_notes_and_retained_notes() {
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		},
 		elf_service::search::TraceReplayCandidate {
 			note_id: note_a,
@@ -85,6 +89,9 @@ fn retrieval_top_rank_retention_counts_unique_notes_and_retained_notes() {
 			note_updated_at: now,
 			note_hit_count: 0,
 			note_last_hit_at: None,
+			note_evidence_coverage: None,
+			note_session_adjustment: None,
+			note_feedback_adjustment: None,
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -93,6 +100,7 @@ fn retrieval_top_rank_retention_counts_unique_notes_and_retained_notes() {
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		},
 		elf_service::search::TraceReplayCandidate {
 			note_id: note_b,
@@ -107,6 +115,9 @@ fn retrieval_top_rank_retention_counts_unique_notes_and_retained_notes() {
 			note_updated_at: now,
 			note_hit_count: 0,
 			note_last_hit_at: None,
+			note_evidence_coverage: None,
+			note_session_adjustment: None,
+			note_feedback_adjustment: None,
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -115,6 +126,7 @@ fn retrieval_top_rank_retention_counts_unique_notes_and_retained_notes() {
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		},
 		elf_service::search::TraceReplayCandidate {
 			note_id: note_c,
@@ -129,6 +141,9 @@ fn retrieval_top_rank_retention_counts_unique_notes_and_retained_notes() {
 			note_updated_at: now,
 			note_hit_count: 0,
 			note_last_hit_at: None,
+			note_evidence_coverage: None,
+			note_session_adjustment: None,
+			note_feedback_adjustment: None,
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -137,6 +152,7 @@ fn retrieval_top_rank_retention_counts_unique_notes_and_retained_notes() {
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		},
 	];
 	let note_ids = vec![note_a, note_c];
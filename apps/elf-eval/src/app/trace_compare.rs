@@ -1,4 +1,5 @@
 mod analysis;
+mod artifact;
 mod types;
 
 use std::path::Path;
@@ -15,9 +16,9 @@ use elf_config::Config;
 use elf_service::search::{self, TraceReplayContext};
 use elf_storage::db::Db;
 use types::{
-	TraceCompareCandidateRow, TraceCompareChurn, TraceCompareGuardrails, TraceCompareOutput,
-	TraceComparePolicies, TraceComparePolicy, TraceCompareStageRow, TraceCompareSummary,
-	TraceCompareTrace, TraceCompareTraceRow, TraceCompareVariant,
+	TraceCompareCandidateRow, TraceCompareChurn, TraceCompareGuardrails, TraceCompareInputs,
+	TraceCompareOutput, TraceComparePolicies, TraceComparePolicy, TraceCompareStageRow,
+	TraceCompareSummary, TraceCompareTrace, TraceCompareTraceRow, TraceCompareVariant,
 };
 
 pub(super) async fn trace_compare(
@@ -27,31 +28,62 @@ pub(super) async fn trace_compare(
 	config_b: Config,
 	args: &Args,
 ) -> Result<TraceCompareOutput> {
-	let policy_id_a =
-		search::ranking_policy_id(&config_a, None).map_err(|err| eyre::eyre!("{err}"))?;
-	let policy_id_b =
-		search::ranking_policy_id(&config_b, None).map_err(|err| eyre::eyre!("{err}"))?;
 	let db = Db::connect(&config_a.storage.postgres).await?;
 
 	db.ensure_schema(config_a.storage.qdrant.vector_dim).await?;
 
-	let mut traces = Vec::with_capacity(args.trace_id.len());
+	let mut inputs = Vec::with_capacity(args.trace_id.len());
+
+	for trace_id in &args.trace_id {
+		inputs.push(load_trace_compare_inputs(&db, trace_id).await?);
+	}
+
+	build_trace_compare_output(config_a_path, &config_a, config_b_path, &config_b, inputs, args)
+}
+
+pub(super) fn trace_compare_artifacts(
+	config_a_path: &Path,
+	config_a: Config,
+	config_b_path: &Path,
+	config_b: Config,
+	args: &Args,
+) -> Result<TraceCompareOutput> {
+	let mut inputs = Vec::with_capacity(args.trace_artifact.len());
+
+	for path in &args.trace_artifact {
+		inputs.push(artifact::load_trace_compare_inputs(path.as_path())?);
+	}
+
+	build_trace_compare_output(config_a_path, &config_a, config_b_path, &config_b, inputs, args)
+}
+
+fn build_trace_compare_output(
+	config_a_path: &Path,
+	config_a: &Config,
+	config_b_path: &Path,
+	config_b: &Config,
+	inputs: Vec<TraceCompareInputs>,
+	args: &Args,
+) -> Result<TraceCompareOutput> {
+	let policy_id_a =
+		search::ranking_policy_id(config_a, None).map_err(|err| eyre::eyre!("{err}"))?;
+	let policy_id_b =
+		search::ranking_policy_id(config_b, None).map_err(|err| eyre::eyre!("{err}"))?;
+	let mut traces = Vec::with_capacity(inputs.len());
 	let mut positional_sum = 0.0_f64;
 	let mut set_sum = 0.0_f64;
 	let mut top3_retention_a_sum = 0.0_f64;
 	let mut top3_retention_b_sum = 0.0_f64;
 
-	for trace_id in &args.trace_id {
-		let trace = compare_trace_id(
-			&db,
-			&config_a,
-			&config_b,
+	for input in inputs {
+		let trace = compare_trace(
+			config_a,
+			config_b,
 			policy_id_a.as_str(),
 			policy_id_b.as_str(),
-			trace_id,
+			input,
 			args,
-		)
-		.await?;
+		)?;
 
 		positional_sum += trace.churn.positional_churn_at_k;
 		set_sum += trace.churn.set_churn_at_k;
@@ -87,30 +119,35 @@ pub(super) async fn trace_compare(
 	})
 }
 
-async fn compare_trace_id(
-	db: &Db,
-	config_a: &Config,
-	config_b: &Config,
-	policy_id_a: &str,
-	policy_id_b: &str,
-	trace_id: &Uuid,
-	args: &Args,
-) -> Result<TraceCompareTrace> {
+async fn load_trace_compare_inputs(db: &Db, trace_id: &Uuid) -> Result<TraceCompareInputs> {
 	let trace_row = fetch_trace_compare_trace_row(db, trace_id).await?;
 	let candidate_rows = fetch_trace_compare_candidate_rows(db, trace_id).await?;
 	let stage_rows = fetch_trace_compare_stage_rows(db, trace_id).await?;
 	let context = TraceReplayContext {
 		trace_id: trace_row.trace_id,
-		query: trace_row.query.clone(),
+		query: trace_row.query,
 		candidate_count: u32::try_from(trace_row.candidate_count).unwrap_or(0),
 		top_k: u32::try_from(trace_row.top_k).unwrap_or(0),
 		created_at: trace_row.created_at,
 	};
+	let candidates = analysis::decode_trace_replay_candidates(candidate_rows);
+
+	Ok(TraceCompareInputs { context, candidates, stage_rows })
+}
+
+fn compare_trace(
+	config_a: &Config,
+	config_b: &Config,
+	policy_id_a: &str,
+	policy_id_b: &str,
+	input: TraceCompareInputs,
+	args: &Args,
+) -> Result<TraceCompareTrace> {
+	let TraceCompareInputs { context, candidates, stage_rows } = input;
 	let created_at = context
 		.created_at
 		.format(&Rfc3339)
 		.map_err(|err| eyre::eyre!("Failed to format trace created_at: {err}"))?;
-	let candidates = analysis::decode_trace_replay_candidates(candidate_rows);
 	let top_k = args.top_k.unwrap_or(context.top_k).max(1);
 	let items_a =
 		search::replay_ranking_from_candidates(config_a, &context, None, &candidates, top_k)
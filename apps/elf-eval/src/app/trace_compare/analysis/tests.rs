@@ -155,6 +155,9 @@ fn decode_candidates_falls_back_when_valid_snapshot_has_nil_ids() {
 		note_updated_at: now,
 		note_hit_count: 1,
 		note_last_hit_at: None,
+		note_evidence_coverage: None,
+		note_session_adjustment: None,
+		note_feedback_adjustment: None,
 		diversity_selected: None,
 		diversity_selected_rank: None,
 		diversity_selected_reason: None,
@@ -163,6 +166,7 @@ fn decode_candidates_falls_back_when_valid_snapshot_has_nil_ids() {
 		diversity_similarity: None,
 		diversity_mmr_score: None,
 		diversity_missing_embedding: None,
+		diversity_balance_penalty: None,
 	};
 	let rows = vec![TraceCompareCandidateRow {
 		candidate_snapshot: serde_json::to_value(snapshot).expect("Snapshot serializes."),
@@ -0,0 +1,37 @@
+use std::{fs, path::Path};
+
+use color_eyre::{Result, eyre};
+
+use crate::app::trace_compare::types::{TraceCompareInputs, TraceCompareStageRow};
+use elf_service::search::{self, TraceArtifact, TraceReplayContext};
+
+pub(super) fn load_trace_compare_inputs(path: &Path) -> Result<TraceCompareInputs> {
+	let bytes = fs::read(path)?;
+	let artifact = search::decode_trace_artifact(&bytes)
+		.map_err(|err| eyre::eyre!("Failed to load trace artifact {}: {err}", path.display()))?;
+
+	Ok(trace_compare_inputs(artifact))
+}
+
+fn trace_compare_inputs(artifact: TraceArtifact) -> TraceCompareInputs {
+	let bundle = artifact.bundle;
+	let context = TraceReplayContext {
+		trace_id: bundle.trace.trace_id,
+		query: bundle.trace.query,
+		candidate_count: bundle.trace.candidate_count,
+		top_k: bundle.trace.top_k,
+		created_at: bundle.trace.created_at,
+	};
+	let stage_rows = bundle
+		.stages
+		.into_iter()
+		.map(|stage| TraceCompareStageRow {
+			stage_order: i32::try_from(stage.stage_order).unwrap_or(i32::MAX),
+			stage_name: stage.stage_name,
+			stage_payload: stage.stage_payload,
+			item_count: stage.items.len() as i64,
+		})
+		.collect();
+
+	TraceCompareInputs { context, candidates: bundle.candidates.unwrap_or_default(), stage_rows }
+}
@@ -4,7 +4,7 @@ use sqlx::FromRow;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use elf_service::search::TraceReplayItem;
+use elf_service::search::{TraceReplayCandidate, TraceReplayContext, TraceReplayItem};
 
 #[derive(Debug, Serialize)]
 pub(in crate::app) struct TraceCompareOutput {
@@ -90,6 +90,12 @@ pub(super) struct TraceCompareRegressionAttribution {
 	pub(super) evidence: String,
 }
 
+pub(super) struct TraceCompareInputs {
+	pub(super) context: TraceReplayContext,
+	pub(super) candidates: Vec<TraceReplayCandidate>,
+	pub(super) stage_rows: Vec<TraceCompareStageRow>,
+}
+
 #[derive(FromRow)]
 pub(super) struct TraceCompareTraceRow {
 	pub(super) trace_id: Uuid,
@@ -150,6 +150,7 @@ async fn run_backfill_attempt(
 				tenant_id: TENANT_ID.to_string(),
 				project_id: PROJECT_ID.to_string(),
 				agent_id: AGENT_ID.to_string(),
+				role: None,
 				scope: SCOPE.to_string(),
 				notes: batch.iter().map(|note| notes::note_input(note)).collect(),
 			})
@@ -65,6 +65,7 @@ async fn run_update_replacement_check(
 			tenant_id: TENANT_ID.to_string(),
 			project_id: PROJECT_ID.to_string(),
 			agent_id: AGENT_ID.to_string(),
+			role: None,
 			note_id: update_note_id,
 			text: Some(update_text.clone()),
 			importance: None,
@@ -122,6 +123,7 @@ async fn run_delete_suppression_check(
 			tenant_id: TENANT_ID.to_string(),
 			project_id: PROJECT_ID.to_string(),
 			agent_id: AGENT_ID.to_string(),
+			role: None,
 			note_id: delete_note_id,
 		})
 		.await?;
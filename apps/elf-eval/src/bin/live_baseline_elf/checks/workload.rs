@@ -25,6 +25,7 @@ pub(crate) fn concurrent_add_request(index: usize) -> AddNoteRequest {
 		tenant_id: TENANT_ID.to_string(),
 		project_id: PROJECT_ID.to_string(),
 		agent_id: AGENT_ID.to_string(),
+		role: None,
 		scope: SCOPE.to_string(),
 		notes: vec![AddNoteInput {
 			r#type: "fact".to_string(),
@@ -83,6 +84,7 @@ pub(crate) fn soak_add_request(index: usize) -> AddNoteRequest {
 		tenant_id: TENANT_ID.to_string(),
 		project_id: PROJECT_ID.to_string(),
 		agent_id: AGENT_ID.to_string(),
+		role: None,
 		scope: SCOPE.to_string(),
 		notes: vec![AddNoteInput {
 			r#type: "fact".to_string(),
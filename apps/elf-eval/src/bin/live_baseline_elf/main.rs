@@ -36,8 +36,8 @@ use elf_chunking::ChunkingConfig;
 use elf_config::{Config, EmbeddingProviderConfig, LlmProviderConfig, ProviderConfig};
 use elf_service::{
 	AddNoteInput, AddNoteRequest, BoxFuture, DeleteRequest, ElfService, EmbeddingProvider,
-	ExtractorProvider, NoteOp, PayloadLevel, Providers, RerankProvider, SearchRequest,
-	UpdateRequest,
+	ExtractorProvider, NoteOp, PayloadLevel, Providers, RebuildQdrantRequest, RerankProvider,
+	SearchRequest, UpdateRequest,
 };
 use elf_storage::{db::Db, qdrant::QdrantStore};
 use elf_testkit::TestDatabase;
@@ -116,7 +116,7 @@ async fn run(args: Args) -> Result<ElfBaselineReport> {
 	let note_ids = backfill.note_ids;
 	let initial_worker =
 		runtime::run_worker_until_indexed(&runtime, &service, &note_ids, "corpus_upsert").await?;
-	let rebuild = service.rebuild_qdrant().await?;
+	let rebuild = service.rebuild_qdrant(RebuildQdrantRequest::default()).await?;
 	let query_manifest = corpus::load_queries(&args.queries)?;
 	let query_results = runtime::run_queries(&service, query_manifest.queries).await?;
 	let pass_count = query_results.iter().filter(|result| result.matched).count();
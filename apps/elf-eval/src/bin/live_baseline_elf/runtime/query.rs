@@ -36,8 +36,15 @@ pub(crate) async fn run_single_query(service: &ElfService, case: QueryCase) -> R
 			top_k: Some(top_k),
 			candidate_k: Some(top_k.max(20).saturating_mul(4)),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await?;
 	let latency_ms = started_at.elapsed().as_secs_f64() * 1_000.0;
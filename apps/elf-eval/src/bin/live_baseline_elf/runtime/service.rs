@@ -49,8 +49,19 @@ pub(super) async fn build_worker_state(runtime: &BaselineRuntime) -> Result<Work
 		db,
 		qdrant,
 		docs_qdrant,
-		embedding: cfg.providers.embedding,
+		embedding: cfg.providers.embedding.clone(),
+		embedding_scopes: cfg.providers.embedding_scope_providers(),
+		embedding_types: cfg.providers.embedding_type_providers(),
 		chunking,
+		chunking_per_type: cfg.chunking.per_type.unwrap_or_default(),
 		tokenizer,
+		url_snapshots: None,
+		egress: None,
+		qdrant_maintenance: None,
+		trash_retention_days: None,
+		note_consolidation: None,
+		lifecycle: None,
+		importance_rescore: None,
+		indexing_concurrency: 1,
 	})
 }
@@ -98,6 +98,7 @@ async fn update_stale_trap_sources(
 				tenant_id: TENANT_ID.to_string(),
 				project_id: project_id.to_string(),
 				agent_id: AGENT_ID.to_string(),
+				role: None,
 				scope: SCOPE.to_string(),
 				notes: vec![AddNoteInput {
 					r#type: "fact".to_string(),
@@ -21,8 +21,15 @@ pub(super) async fn search_elf_job(
 			top_k: Some(5),
 			candidate_k: Some(20),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.map_err(|err| eyre::eyre!("ELF search_raw failed for {}: {err}", loaded.job.job_id))?;
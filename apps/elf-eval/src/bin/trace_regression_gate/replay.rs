@@ -28,6 +28,9 @@ pub(super) fn decode_trace_replay_candidates(
 				note_updated_at: row.note_updated_at,
 				note_hit_count: row.note_hit_count,
 				note_last_hit_at: row.note_last_hit_at,
+				note_evidence_coverage: None,
+				note_session_adjustment: None,
+				note_feedback_adjustment: None,
 				diversity_selected: None,
 				diversity_selected_rank: None,
 				diversity_selected_reason: None,
@@ -36,6 +39,7 @@ pub(super) fn decode_trace_replay_candidates(
 				diversity_similarity: None,
 				diversity_mmr_score: None,
 				diversity_missing_embedding: None,
+				diversity_balance_penalty: None,
 			})
 		})
 		.collect()
@@ -1,12 +1,14 @@
 mod server;
 
-use std::{net::SocketAddr, path::PathBuf};
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
 
 use clap::Parser;
 use color_eyre::{Result, eyre};
 
-use elf_config::{McpContext, Security};
+use elf_config::{Config, McpContext, Security};
+use elf_runtime::jwt::JwtVerifier;
 
+/// CLI arguments for launching the ELF MCP server.
 #[derive(Debug, Parser)]
 #[command(
 	version = elf_cli::VERSION,
@@ -14,18 +16,45 @@ use elf_config::{McpContext, Security};
 	styles = elf_cli::styles(),
 )]
 pub struct Args {
+	/// Path to the ELF configuration file.
 	#[arg(long, short = 'c', value_name = "FILE")]
 	pub config: PathBuf,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub enum McpAuthState {
 	Off,
-	StaticKeys { bearer_token: String },
+	StaticKeys {
+		bearer_token: String,
+	},
+	/// Callers present their own bearer JWT, which is verified here and forwarded to elf-api.
+	Jwt {
+		verifier: Arc<JwtVerifier>,
+	},
 }
+impl PartialEq for McpAuthState {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Off, Self::Off) => true,
+			(Self::StaticKeys { bearer_token: left }, Self::StaticKeys { bearer_token: right }) =>
+				left == right,
+			(Self::Jwt { verifier: left }, Self::Jwt { verifier: right }) =>
+				Arc::ptr_eq(left, right),
+			_ => false,
+		}
+	}
+}
+impl Eq for McpAuthState {}
 
+/// Loads the configuration file and serves MCP until the server stops.
 pub async fn run(args: Args) -> Result<()> {
 	let config = elf_config::load(&args.config)?;
+
+	serve(&config).await
+}
+
+/// Builds the MCP auth state from `config` and serves MCP on the configured bind address.
+pub async fn serve(config: &Config) -> Result<()> {
 	let mcp =
 		config.mcp.as_ref().ok_or_else(|| eyre::eyre!("mcp section is required for elf-mcp."))?;
 	let auth_state = build_auth_state(&config.security, &config.service.mcp_bind, mcp)?;
@@ -48,8 +77,16 @@ fn build_auth_state(security: &Security, mcp_bind: &str, mcp: &McpContext) -> Re
 			Ok(McpAuthState::Off)
 		},
 		"static_keys" => select_static_key(security, mcp),
+		"jwt" => {
+			let jwt = security
+				.jwt
+				.as_ref()
+				.ok_or_else(|| eyre::eyre!("security.jwt is required when auth_mode is jwt."))?;
+
+			Ok(McpAuthState::Jwt { verifier: Arc::new(JwtVerifier::new(jwt)?) })
+		},
 		other => Err(eyre::eyre!(
-			"security.auth_mode must be one of off or static_keys for elf-mcp, got {other}."
+			"security.auth_mode must be one of off, static_keys, or jwt for elf-mcp, got {other}."
 		)),
 	}
 }
@@ -106,6 +143,12 @@ mod tests {
 			evidence_max_quote_chars: 400,
 			auth_mode: auth_mode.to_string(),
 			auth_keys,
+			jwt: None,
+			permissions: None,
+			pii: None,
+			secret_patterns: None,
+			egress: None,
+			quotas: None,
 		}
 	}
 
@@ -162,4 +205,14 @@ mod tests {
 
 		assert!(err.to_string().contains("Found multiple"), "unexpected error: {err}");
 	}
+
+	#[test]
+	fn jwt_mode_requires_jwt_section() {
+		let security = sample_security("jwt", vec![]);
+		let mcp = sample_mcp();
+		let err =
+			app::build_auth_state(&security, "0.0.0.0:9090", &mcp).expect_err("expected error");
+
+		assert!(err.to_string().contains("security.jwt is required"), "unexpected error: {err}");
+	}
 }
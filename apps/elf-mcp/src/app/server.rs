@@ -1,3 +1,4 @@
+mod resources;
 mod runtime;
 mod schemas;
 mod state;
@@ -10,15 +11,16 @@ use rmcp::handler::server::router::tool::ToolRouter;
 
 #[cfg(test)]
 use schemas::{
-	docs_excerpts_get_schema, docs_put_schema, docs_search_l0_schema, notes_ingest_schema,
-	recall_debug_panel_schema, searches_create_schema, searches_get_schema, searches_notes_schema,
-	searches_timeline_schema, work_journal_entry_create_schema,
-	work_journal_session_readback_schema,
+	docs_excerpts_get_schema, docs_put_schema, docs_search_l0_schema, docs_search_schema,
+	notes_get_schema, notes_ingest_schema, recall_debug_panel_schema, searches_create_schema,
+	searches_get_schema, searches_notes_schema, searches_scoped_schema, searches_timeline_schema,
+	work_journal_entry_create_schema, work_journal_session_readback_schema,
 };
 use state::{ElfContextHeaders, ElfMcp, HttpMethod};
 #[cfg(test)] use support::is_authorized;
 use support::{
 	handle_response, is_admin_path, mcp_auth_middleware, normalize_api_base, params_to_query,
+	read_bearer_token, read_response_json,
 };
 
 const HEADER_TENANT_ID: &str = "X-ELF-Tenant-Id";
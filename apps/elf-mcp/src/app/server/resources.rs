@@ -0,0 +1,100 @@
+use reqwest::StatusCode;
+use rmcp::{
+	ErrorData,
+	model::{ReadResourceResult, ResourceContents, ResourceTemplate},
+};
+use uuid::Uuid;
+
+use crate::app::server::ElfMcp;
+
+const NOTE_URI_PREFIX: &str = "memory://note/";
+const TRACE_URI_PREFIX: &str = "memory://trace/";
+const JSON_MIME_TYPE: &str = "application/json";
+
+/// A memory item addressable as an MCP resource.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum MemoryResource {
+	Note(Uuid),
+	Trace(Uuid),
+}
+impl MemoryResource {
+	pub(super) fn parse(uri: &str) -> Result<Self, ErrorData> {
+		let (id, build): (&str, fn(Uuid) -> Self) = if let Some(id) =
+			uri.strip_prefix(NOTE_URI_PREFIX)
+		{
+			(id, Self::Note)
+		} else if let Some(id) = uri.strip_prefix(TRACE_URI_PREFIX) {
+			(id, Self::Trace)
+		} else {
+			return Err(ErrorData::resource_not_found(
+				format!(
+					"Unknown resource URI {uri}; expected {NOTE_URI_PREFIX}{{note_id}} or {TRACE_URI_PREFIX}{{trace_id}}."
+				),
+				None,
+			));
+		};
+		let id = Uuid::parse_str(id).map_err(|_| {
+			ErrorData::invalid_params(format!("Resource URI {uri} must end with a UUID."), None)
+		})?;
+
+		Ok(build(id))
+	}
+
+	/// HTTP API path that serves the resource. Notes are read on the public bind under the
+	/// configured read profile; traces are read on the admin bind.
+	pub(super) fn api_path(self) -> String {
+		match self {
+			Self::Note(note_id) => format!("/v2/notes/{note_id}"),
+			Self::Trace(trace_id) => format!("/v2/admin/traces/{trace_id}"),
+		}
+	}
+}
+
+pub(super) fn resource_templates() -> Vec<ResourceTemplate> {
+	vec![
+		ResourceTemplate::new(format!("{NOTE_URI_PREFIX}{{note_id}}"), "memory_note")
+			.with_title("Memory note")
+			.with_description(
+				"One memory note as returned by GET /v2/notes/{note_id}, readable only when the configured read_profile allows it.",
+			)
+			.with_mime_type(JSON_MIME_TYPE),
+		ResourceTemplate::new(format!("{TRACE_URI_PREFIX}{{trace_id}}"), "memory_trace")
+			.with_title("Search trace")
+			.with_description(
+				"One search trace as returned by GET /v2/admin/traces/{trace_id}, without full stage internals.",
+			)
+			.with_mime_type(JSON_MIME_TYPE),
+	]
+}
+
+impl ElfMcp {
+	/// Reads a `memory://` resource through the HTTP API and returns the JSON body as text.
+	pub(super) async fn read_memory_resource(
+		&self,
+		uri: &str,
+	) -> Result<ReadResourceResult, ErrorData> {
+		let resource = MemoryResource::parse(uri)?;
+		let (status, body) = self.fetch_json(resource.api_path().as_str()).await?;
+
+		if status == StatusCode::NOT_FOUND {
+			return Err(ErrorData::resource_not_found(
+				format!("Resource {uri} was not found."),
+				Some(body),
+			));
+		}
+		if !status.is_success() {
+			return Err(ErrorData::invalid_request(
+				format!("ELF API rejected resource {uri} with status {status}."),
+				Some(body),
+			));
+		}
+
+		let text = serde_json::to_string_pretty(&body).map_err(|err| {
+			ErrorData::internal_error(format!("Failed to render resource {uri}: {err}"), None)
+		})?;
+
+		Ok(ReadResourceResult::new(vec![
+			ResourceContents::text(text, uri).with_mime_type(JSON_MIME_TYPE),
+		]))
+	}
+}
@@ -1,10 +1,15 @@
 use std::{net::SocketAddr, sync::Arc};
 
-use axum::{Router, middleware};
+use axum::{Router, http::request::Parts, middleware};
 use color_eyre::Result;
 use rmcp::{
-	ServerHandler,
-	model::{ServerCapabilities, ServerInfo},
+	ErrorData, RoleServer, ServerHandler,
+	handler::server::tool::ToolCallContext,
+	model::{
+		CallToolRequestParams, CallToolResult, ListResourceTemplatesResult, PaginatedRequestParams,
+		ReadResourceRequestParams, ReadResourceResult, ServerCapabilities, ServerInfo,
+	},
+	service::RequestContext,
 	transport::streamable_http_server::{
 		StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
 	},
@@ -13,15 +18,52 @@ use tokio::net::TcpListener;
 
 use crate::app::{
 	McpAuthState,
-	server::{self, ElfContextHeaders, ElfMcp},
+	server::{self, ElfContextHeaders, ElfMcp, resources},
 };
 use elf_config::McpContext;
 
 #[rmcp::tool_handler(router = self.tool_router)]
 impl ServerHandler for ElfMcp {
 	fn get_info(&self) -> ServerInfo {
-		ServerInfo::new(ServerCapabilities::builder().enable_tools().build())
-			.with_instructions("ELF MCP adapter that forwards tool calls to the ELF HTTP API.")
+		ServerInfo::new(ServerCapabilities::builder().enable_tools().enable_resources().build())
+			.with_instructions(
+				"ELF MCP adapter that forwards tool calls to the ELF HTTP API. Notes and traces are readable as memory://note/{note_id} and memory://trace/{trace_id} resources.",
+			)
+	}
+
+	async fn list_resource_templates(
+		&self,
+		_request: Option<PaginatedRequestParams>,
+		_context: RequestContext<RoleServer>,
+	) -> Result<ListResourceTemplatesResult, ErrorData> {
+		Ok(ListResourceTemplatesResult::with_all_items(resources::resource_templates()))
+	}
+
+	async fn read_resource(
+		&self,
+		request: ReadResourceRequestParams,
+		context: RequestContext<RoleServer>,
+	) -> Result<ReadResourceResult, ErrorData> {
+		let caller = match context.extensions.get::<Parts>() {
+			Some(parts) => self.for_caller(&parts.headers),
+			None => self.clone(),
+		};
+
+		caller.read_memory_resource(request.uri.as_str()).await
+	}
+
+	async fn call_tool(
+		&self,
+		request: CallToolRequestParams,
+		context: RequestContext<RoleServer>,
+	) -> Result<CallToolResult, ErrorData> {
+		let caller = match context.extensions.get::<Parts>() {
+			Some(parts) => self.for_caller(&parts.headers),
+			None => self.clone(),
+		};
+		let tcc = ToolCallContext::new(&caller, request, context);
+
+		self.tool_router.call(tcc).await
 	}
 }
 
@@ -5,7 +5,9 @@ mod graph;
 mod memory;
 mod notes;
 mod search;
+mod sessions;
 mod sharing;
+mod standing_queries;
 mod work_journal;
 
 pub(in crate::app::server) use self::{
@@ -17,22 +19,38 @@ pub(in crate::app::server) use self::{
 		admin_trace_bundle_get_schema, admin_trace_get_schema, admin_trace_item_get_schema,
 		admin_traces_recent_list_schema, admin_trajectory_get_schema,
 	},
-	docs::{docs_excerpts_get_schema, docs_get_schema, docs_put_schema, docs_search_l0_schema},
+	docs::{
+		docs_excerpts_get_schema, docs_get_schema, docs_put_schema, docs_search_l0_schema,
+		docs_search_schema,
+	},
 	events::events_ingest_schema,
-	graph::{graph_query_schema, graph_report_schema},
+	graph::{
+		graph_facts_as_of_schema, graph_neighborhood_schema, graph_query_schema,
+		graph_report_schema,
+	},
 	memory::{
 		core_blocks_get_schema, dreaming_review_queue_schema, entity_memory_get_schema,
+		memory_recall_for_context_schema, memory_timeline_schema, org_memory_stats_schema,
 		recall_debug_panel_schema,
 	},
 	notes::{
-		notes_get_schema, notes_ingest_schema, notes_list_schema, notes_patch_schema,
-		notes_publish_schema, notes_unpublish_schema,
+		notes_cite_schema, notes_delete_schema, notes_events_schema, notes_get_schema,
+		notes_history_schema, notes_ingest_schema, notes_list_schema, notes_merge_schema,
+		notes_patch_schema, notes_pin_schema, notes_publish_schema,
+		notes_source_refs_resolve_schema, notes_trash_list_schema, notes_undelete_schema,
+		notes_unpin_schema, notes_unpublish_schema,
 	},
 	search::{
-		searches_create_schema, searches_get_schema, searches_notes_schema,
-		searches_timeline_schema,
+		rank_documents_schema, search_with_profile_schema, searches_batch_schema,
+		searches_create_schema, searches_feedback_schema, searches_get_schema,
+		searches_notes_schema, searches_scoped_schema, searches_timeline_schema,
 	},
+	sessions::{session_append_schema, session_get_schema, session_summarize_schema},
 	sharing::{space_grant_revoke_schema, space_grant_upsert_schema, space_grants_list_schema},
+	standing_queries::{
+		standing_queries_list_schema, standing_query_create_schema, standing_query_delete_schema,
+		standing_query_matches_schema,
+	},
 	work_journal::{
 		work_journal_entry_create_schema, work_journal_entry_get_schema,
 		work_journal_session_readback_schema,
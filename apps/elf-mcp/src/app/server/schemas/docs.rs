@@ -148,6 +148,40 @@ pub(in crate::app::server) fn docs_search_l0_schema() -> Arc<JsonObject> {
 	}))
 }
 
+pub(in crate::app::server) fn docs_search_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["query"],
+		"properties": {
+			"query": { "type": "string" },
+			"scope": { "type": ["string", "null"], "enum": ["agent_private", "project_shared", "org_shared", null] },
+			"status": { "type": ["string", "null"], "enum": ["active", "deleted", null] },
+			"doc_type": {
+				"type": ["string", "null"],
+				"enum": ["knowledge", "chat", "search", "dev", null]
+			},
+			"agent_id": { "type": ["string", "null"] },
+			"thread_id": { "type": ["string", "null"] },
+			"updated_after": { "type": ["string", "null"], "format": "date-time" },
+			"updated_before": { "type": ["string", "null"], "format": "date-time" },
+			"ts_gte": { "type": ["string", "null"], "format": "date-time" },
+			"ts_lte": { "type": ["string", "null"], "format": "date-time" },
+			"top_k": { "type": ["integer", "null"] },
+			"candidate_k": { "type": ["integer", "null"] },
+			"sparse_mode": {
+				"type": ["string", "null"],
+				"enum": ["auto", "on", "off", null]
+			},
+			"domain": { "type": ["string", "null"] },
+			"repo": { "type": ["string", "null"] },
+			"level": { "type": ["string", "null"], "enum": ["L0", "L1", "L2", null] },
+			"explain": { "type": ["boolean", "null"] },
+			"read_profile": { "type": ["string", "null"] }
+		}
+	}))
+}
+
 pub(in crate::app::server) fn docs_excerpts_get_schema() -> Arc<JsonObject> {
 	Arc::new(rmcp::object!({
 		"type": "object",
@@ -10,6 +10,7 @@ pub(in crate::app::server) fn events_ingest_schema() -> Arc<JsonObject> {
 		"properties": {
 			"scope": { "type": ["string", "null"] },
 			"dry_run": { "type": ["boolean", "null"] },
+			"locale": { "type": ["string", "null"] },
 			"ingestion_profile": {
 				"type": "object",
 				"additionalProperties": true,
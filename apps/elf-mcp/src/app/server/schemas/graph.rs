@@ -71,3 +71,143 @@ pub(in crate::app::server) fn graph_query_schema() -> Arc<JsonObject> {
 pub(in crate::app::server) fn graph_report_schema() -> Arc<JsonObject> {
 	graph_query_schema()
 }
+
+pub(in crate::app::server) fn graph_neighborhood_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["entity"],
+		"properties": {
+			"entity": {
+				"oneOf": [
+					{
+						"type": "object",
+						"required": ["entity_id"],
+						"properties": {
+							"entity_id": {
+								"type": "string",
+								"format": "uuid"
+							}
+						}
+					},
+					{
+						"type": "object",
+						"required": ["surface"],
+						"properties": {
+							"surface": { "type": "string" }
+						}
+					}
+				]
+			},
+			"depth": {
+				"type": ["integer", "null"],
+				"minimum": 1,
+				"maximum": 3
+			},
+			"predicates": {
+				"type": ["array", "null"],
+				"maxItems": 16,
+				"items": {
+					"oneOf": [
+						{
+							"type": "object",
+							"required": ["predicate_id"],
+							"properties": {
+								"predicate_id": {
+									"type": "string",
+									"format": "uuid"
+								}
+							}
+						},
+						{
+							"type": "object",
+							"required": ["surface"],
+							"properties": {
+								"surface": { "type": "string" }
+							}
+						}
+					]
+				}
+			},
+			"scopes": {
+				"type": ["array", "null"],
+				"items": { "type": "string" }
+			},
+			"as_of": {
+				"type": ["string", "null"],
+				"format": "date-time"
+			},
+			"limit": {
+				"type": ["integer", "null"],
+				"minimum": 1,
+				"maximum": 200
+			}
+		}
+	}))
+}
+
+pub(in crate::app::server) fn graph_facts_as_of_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["entity", "as_of"],
+		"properties": {
+			"entity": {
+				"oneOf": [
+					{
+						"type": "object",
+						"required": ["entity_id"],
+						"properties": {
+							"entity_id": {
+								"type": "string",
+								"format": "uuid"
+							}
+						}
+					},
+					{
+						"type": "object",
+						"required": ["surface"],
+						"properties": {
+							"surface": { "type": "string" }
+						}
+					}
+				]
+			},
+			"as_of": {
+				"type": "string",
+				"format": "date-time"
+			},
+			"predicate": {
+				"oneOf": [
+					{
+						"type": "object",
+						"required": ["predicate_id"],
+						"properties": {
+							"predicate_id": {
+								"type": "string",
+								"format": "uuid"
+							}
+						}
+					},
+					{
+						"type": "object",
+						"required": ["surface"],
+						"properties": {
+							"surface": { "type": "string" }
+						}
+					}
+				]
+			},
+			"scopes": {
+				"type": ["array", "null"],
+				"items": { "type": "string" }
+			},
+			"include_superseded": { "type": ["boolean", "null"] },
+			"limit": {
+				"type": ["integer", "null"],
+				"minimum": 1,
+				"maximum": 200
+			}
+		}
+	}))
+}
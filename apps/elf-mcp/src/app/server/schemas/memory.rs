@@ -24,6 +24,50 @@ pub(in crate::app::server) fn entity_memory_get_schema() -> Arc<JsonObject> {
 	}))
 }
 
+pub(in crate::app::server) fn org_memory_stats_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"properties": {
+			"min_count": { "type": ["integer", "null"], "minimum": 0 },
+			"limit": {
+				"type": ["integer", "null"],
+				"minimum": 1,
+				"maximum": 200
+			},
+			"read_profile": { "type": ["string", "null"] }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn memory_timeline_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"properties": {
+			"bucket": { "type": ["string", "null"], "enum": ["day", "week", null] },
+			"since": { "type": ["string", "null"], "format": "date-time" },
+			"until": { "type": ["string", "null"], "format": "date-time" },
+			"max_buckets": {
+				"type": ["integer", "null"],
+				"minimum": 1,
+				"maximum": 366
+			},
+			"representatives_per_bucket": {
+				"type": ["integer", "null"],
+				"minimum": 0,
+				"maximum": 10
+			},
+			"top_entities_per_bucket": {
+				"type": ["integer", "null"],
+				"minimum": 0,
+				"maximum": 20
+			},
+			"read_profile": { "type": ["string", "null"] }
+		}
+	}))
+}
+
 pub(in crate::app::server) fn dreaming_review_queue_schema() -> Arc<JsonObject> {
 	Arc::new(rmcp::object!({
 		"type": "object",
@@ -43,6 +87,35 @@ pub(in crate::app::server) fn dreaming_review_queue_schema() -> Arc<JsonObject>
 	}))
 }
 
+pub(in crate::app::server) fn memory_recall_for_context_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["turns"],
+		"properties": {
+			"read_profile": { "type": ["string", "null"] },
+			"turns": {
+				"type": "array",
+				"minItems": 1,
+				"maxItems": 20,
+				"items": {
+					"type": "object",
+					"additionalProperties": false,
+					"required": ["role", "content"],
+					"properties": {
+						"role": { "type": "string" },
+						"content": { "type": "string" }
+					}
+				}
+			},
+			"max_queries": { "type": ["integer", "null"], "minimum": 1, "maximum": 3 },
+			"top_k": { "type": ["integer", "null"], "minimum": 1, "maximum": 20 },
+			"max_context_chars": { "type": ["integer", "null"], "minimum": 200, "maximum": 8000 },
+			"mode": { "type": ["string", "null"], "enum": ["quick_find", "planned_search", null] }
+		}
+	}))
+}
+
 pub(in crate::app::server) fn recall_debug_panel_schema() -> Arc<JsonObject> {
 	Arc::new(rmcp::object!({
 		"type": "object",
@@ -48,7 +48,116 @@ pub(in crate::app::server) fn notes_list_schema() -> Arc<JsonObject> {
 	}))
 }
 
+pub(in crate::app::server) fn notes_events_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"properties": {
+			"after": { "type": ["string", "null"] },
+			"limit": { "type": ["integer", "null"], "minimum": 1, "maximum": 500 },
+			"wait_ms": { "type": ["integer", "null"], "minimum": 0, "maximum": 30000 }
+		}
+	}))
+}
+
 pub(in crate::app::server) fn notes_get_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["note_id"],
+		"properties": {
+			"note_id": { "type": "string" },
+			"include_access_stats": { "type": ["boolean", "null"] }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn notes_history_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["note_id"],
+		"properties": {
+			"note_id": { "type": "string" }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn notes_cite_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["note_ids"],
+		"properties": {
+			"note_ids": {
+				"type": "array",
+				"items": { "type": "string" },
+				"minItems": 1,
+				"maxItems": 50
+			}
+		}
+	}))
+}
+
+pub(in crate::app::server) fn notes_source_refs_resolve_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["note_ids"],
+		"properties": {
+			"note_ids": {
+				"type": "array",
+				"items": { "type": "string" },
+				"minItems": 1,
+				"maxItems": 50
+			},
+			"level": { "type": ["string", "null"], "enum": ["L0", "L1", "L2", null] }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn notes_delete_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["note_id"],
+		"properties": {
+			"note_id": { "type": "string" }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn notes_trash_list_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"properties": {}
+	}))
+}
+
+pub(in crate::app::server) fn notes_undelete_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["note_id"],
+		"properties": {
+			"note_id": { "type": "string" }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn notes_pin_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["note_id"],
+		"properties": {
+			"note_id": { "type": "string" }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn notes_unpin_schema() -> Arc<JsonObject> {
 	Arc::new(rmcp::object!({
 		"type": "object",
 		"additionalProperties": true,
@@ -74,6 +183,19 @@ pub(in crate::app::server) fn notes_patch_schema() -> Arc<JsonObject> {
 	}))
 }
 
+pub(in crate::app::server) fn notes_merge_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["note_id", "secondary_note_id"],
+		"properties": {
+			"note_id": { "type": "string" },
+			"secondary_note_id": { "type": "string" },
+			"strategy": { "type": "string", "enum": ["concatenate", "extractor"] }
+		}
+	}))
+}
+
 pub(in crate::app::server) fn notes_publish_schema() -> Arc<JsonObject> {
 	Arc::new(rmcp::object!({
 		"type": "object",
@@ -145,6 +267,8 @@ fn notes_structured_schema() -> Value {
 				"type": ["array", "null"],
 				"items": { "type": "string" }
 			},
+			"question": { "type": ["string", "null"] },
+			"answer": { "type": ["string", "null"] },
 			"entities": {
 				"type": ["array", "null"],
 				"items": notes_structured_entity_schema()
@@ -3,22 +3,103 @@ use std::sync::Arc;
 use rmcp::model::JsonObject;
 
 pub(in crate::app::server) fn searches_create_schema() -> Arc<JsonObject> {
-	let filter_schema = rmcp::object!({
+	Arc::new(rmcp::object!({
 		"type": "object",
-		"required": ["schema", "expr"],
+		"additionalProperties": true,
+		"required": ["query", "mode"],
 		"properties": {
-			"schema": {
-				"type": "string",
-				"const": "search_filter_expr/v1",
+			"query": { "type": "string" },
+			"mode": { "type": "string", "enum": ["quick_find", "planned_search"] },
+			"payload_level": {
+				"type": ["string", "null"],
+				"enum": ["l0", "l1", "l2", null]
 			},
-			"expr": {
-				"type": "object",
-				"additionalProperties": true,
+			"top_k": { "type": ["integer", "null"] },
+			"candidate_k": { "type": ["integer", "null"] },
+			"filter": search_filter_schema(),
+			"types": { "type": ["array", "null"], "items": { "type": "string" } },
+			"updated_after": { "type": ["string", "null"], "format": "date-time" },
+			"updated_before": { "type": ["string", "null"], "format": "date-time" },
+			"min_importance": { "type": ["number", "null"], "minimum": 0, "maximum": 1 },
+			"max_result_tokens": { "type": ["integer", "null"], "minimum": 1 },
+			"read_profile": { "type": ["string", "null"] },
+			"session_id": { "type": ["string", "null"] },
+			"session_mode": {
+				"type": ["string", "null"],
+				"enum": ["boost", "penalty", "off", null]
+			}
+		}
+	}))
+}
+
+pub(in crate::app::server) fn search_with_profile_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["profile", "query"],
+		"properties": {
+			"profile": { "type": "string" },
+			"query": { "type": "string" },
+			"payload_level": {
+				"type": ["string", "null"],
+				"enum": ["l0", "l1", "l2", null]
 			},
-		},
+			"top_k": { "type": ["integer", "null"] },
+			"updated_after": { "type": ["string", "null"], "format": "date-time" },
+			"updated_before": { "type": ["string", "null"], "format": "date-time" },
+			"read_profile": { "type": ["string", "null"] },
+			"session_id": { "type": ["string", "null"] },
+			"session_mode": {
+				"type": ["string", "null"],
+				"enum": ["boost", "penalty", "off", null]
+			}
+		}
+	}))
+}
+
+pub(in crate::app::server) fn searches_batch_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
 		"additionalProperties": true,
-	});
+		"required": ["searches", "mode"],
+		"properties": {
+			"mode": { "type": "string", "enum": ["quick_find", "planned_search"] },
+			"read_profile": { "type": ["string", "null"] },
+			"searches": {
+				"type": "array",
+				"minItems": 1,
+				"maxItems": 8,
+				"items": {
+					"type": "object",
+					"additionalProperties": true,
+					"required": ["query"],
+					"properties": {
+						"query": { "type": "string" },
+						"payload_level": {
+							"type": ["string", "null"],
+							"enum": ["l0", "l1", "l2", null]
+						},
+						"top_k": { "type": ["integer", "null"] },
+						"candidate_k": { "type": ["integer", "null"] },
+						"filter": search_filter_schema(),
+						"types": { "type": ["array", "null"], "items": { "type": "string" } },
+						"updated_after": { "type": ["string", "null"], "format": "date-time" },
+						"updated_before": { "type": ["string", "null"], "format": "date-time" },
+						"min_importance": { "type": ["number", "null"], "minimum": 0, "maximum": 1 },
+						"max_result_tokens": { "type": ["integer", "null"], "minimum": 1 },
+						"session_id": { "type": ["string", "null"] },
+						"session_mode": {
+							"type": ["string", "null"],
+							"enum": ["boost", "penalty", "off", null]
+						}
+					}
+				}
+			}
+		}
+	}))
+}
 
+pub(in crate::app::server) fn searches_scoped_schema() -> Arc<JsonObject> {
 	Arc::new(rmcp::object!({
 		"type": "object",
 		"additionalProperties": true,
@@ -32,12 +113,39 @@ pub(in crate::app::server) fn searches_create_schema() -> Arc<JsonObject> {
 			},
 			"top_k": { "type": ["integer", "null"] },
 			"candidate_k": { "type": ["integer", "null"] },
-			"filter": filter_schema,
-			"read_profile": { "type": ["string", "null"] }
+			"filter": search_filter_schema(),
+			"types": { "type": ["array", "null"], "items": { "type": "string" } },
+			"updated_after": { "type": ["string", "null"], "format": "date-time" },
+			"updated_before": { "type": ["string", "null"], "format": "date-time" },
+			"min_importance": { "type": ["number", "null"], "minimum": 0, "maximum": 1 },
+			"max_result_tokens": { "type": ["integer", "null"], "minimum": 1 },
+			"read_profile": { "type": ["string", "null"] },
+			"scope_top_k": {
+				"type": "object",
+				"additionalProperties": { "type": "integer", "minimum": 1 }
+			}
 		}
 	}))
 }
 
+fn search_filter_schema() -> JsonObject {
+	rmcp::object!({
+		"type": "object",
+		"required": ["schema", "expr"],
+		"properties": {
+			"schema": {
+				"type": "string",
+				"const": "search_filter_expr/v1",
+			},
+			"expr": {
+				"type": "object",
+				"additionalProperties": true,
+			},
+		},
+		"additionalProperties": true,
+	})
+}
+
 pub(in crate::app::server) fn searches_get_schema() -> Arc<JsonObject> {
 	Arc::new(rmcp::object!({
 		"type": "object",
@@ -66,7 +174,11 @@ pub(in crate::app::server) fn searches_timeline_schema() -> Arc<JsonObject> {
 				"type": ["string", "null"],
 				"enum": ["l0", "l1", "l2", null]
 			},
-			"group_by": { "type": ["string", "null"] }
+			"group_by": {
+				"type": ["string", "null"],
+				"enum": ["day", "week", "month", "type", "scope", "none", null]
+			},
+			"group_limit": { "type": ["integer", "null"], "minimum": 1, "maximum": 100 }
 		}
 	}))
 }
@@ -83,7 +195,48 @@ pub(in crate::app::server) fn searches_notes_schema() -> Arc<JsonObject> {
 				"enum": ["l0", "l1", "l2", null]
 			},
 			"note_ids": { "type": "array", "items": { "type": "string" } },
-			"record_hits": { "type": ["boolean", "null"] }
+			"record_hits": { "type": ["boolean", "null"] },
+			"session_id": { "type": ["string", "null"] }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn searches_feedback_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": false,
+		"required": ["result_handle", "feedback"],
+		"properties": {
+			"result_handle": { "type": "string" },
+			"feedback": { "type": "string", "enum": ["useful", "not_useful", "wrong"] }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn rank_documents_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": false,
+		"required": ["query", "docs"],
+		"properties": {
+			"query": { "type": "string" },
+			"docs": {
+				"type": "array",
+				"minItems": 1,
+				"maxItems": 256,
+				"items": {
+					"type": "object",
+					"additionalProperties": false,
+					"required": ["text"],
+					"properties": {
+						"id": { "type": ["string", "null"] },
+						"text": { "type": "string" },
+						"importance": { "type": ["number", "null"], "minimum": 0, "maximum": 1 },
+						"updated_at": { "type": ["string", "null"], "format": "date-time" }
+					}
+				}
+			},
+			"top_k": { "type": ["integer", "null"], "minimum": 1 }
 		}
 	}))
 }
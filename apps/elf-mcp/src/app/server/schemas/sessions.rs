@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use rmcp::model::JsonObject;
+
+pub(in crate::app::server) fn session_append_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["session_id", "messages"],
+		"properties": {
+			"session_id": { "type": "string" },
+			"ttl_seconds": { "type": ["integer", "null"], "minimum": 60, "maximum": 604800 },
+			"messages": {
+				"type": "array",
+				"items": {
+					"type": "object",
+					"additionalProperties": true,
+					"required": ["role", "content"],
+					"properties": {
+						"role": { "type": "string" },
+						"content": { "type": "string" },
+						"ts": { "type": ["string", "null"] },
+						"msg_id": { "type": ["string", "null"] }
+					}
+				}
+			}
+		}
+	}))
+}
+
+pub(in crate::app::server) fn session_get_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["session_id"],
+		"properties": {
+			"session_id": { "type": "string" }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn session_summarize_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["session_id"],
+		"properties": {
+			"session_id": { "type": "string" },
+			"scope": { "type": ["string", "null"], "enum": ["agent_private", "project_shared", "org_shared", null] },
+			"dry_run": { "type": ["boolean", "null"] },
+			"locale": { "type": ["string", "null"] }
+		}
+	}))
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use rmcp::model::JsonObject;
+
+pub(in crate::app::server) fn standing_query_create_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["query"],
+		"properties": {
+			"query": { "type": "string" },
+			"top_k": { "type": ["integer", "null"], "minimum": 1, "maximum": 100 },
+			"filter": {
+				"type": ["object", "null"],
+				"additionalProperties": false,
+				"properties": {
+					"types": {
+						"type": "array",
+						"items": {
+							"type": "string",
+							"enum": ["preference", "constraint", "decision", "profile", "fact", "plan", "qa"]
+						}
+					},
+					"min_importance": { "type": ["number", "null"], "minimum": 0, "maximum": 1 },
+					"min_confidence": { "type": ["number", "null"], "minimum": 0, "maximum": 1 }
+				}
+			},
+			"webhook_url": { "type": ["string", "null"] }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn standing_queries_list_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"properties": {}
+	}))
+}
+
+pub(in crate::app::server) fn standing_query_delete_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["standing_query_id"],
+		"properties": {
+			"standing_query_id": { "type": "string" }
+		}
+	}))
+}
+
+pub(in crate::app::server) fn standing_query_matches_schema() -> Arc<JsonObject> {
+	Arc::new(rmcp::object!({
+		"type": "object",
+		"additionalProperties": true,
+		"required": ["standing_query_id"],
+		"properties": {
+			"standing_query_id": { "type": "string" },
+			"after_seq": { "type": ["integer", "null"], "minimum": 0 },
+			"limit": { "type": ["integer", "null"], "minimum": 1, "maximum": 500 }
+		}
+	}))
+}
@@ -1,3 +1,4 @@
+use axum::http::HeaderMap;
 use color_eyre::Result;
 use reqwest::{Client, RequestBuilder};
 use rmcp::{
@@ -51,6 +52,7 @@ pub(super) struct ElfMcp {
 	client: Client,
 	context: ElfContextHeaders,
 	auth_state: McpAuthState,
+	caller_token: Option<String>,
 	pub(super) tool_router: ToolRouter<Self>,
 }
 impl ElfMcp {
@@ -66,10 +68,22 @@ impl ElfMcp {
 			client: Client::new(),
 			context,
 			auth_state,
+			caller_token: None,
 			tool_router: Self::tool_router(),
 		}
 	}
 
+	/// Returns a handler that forwards the calling client's bearer token in `jwt` auth mode.
+	pub(super) fn for_caller(&self, headers: &HeaderMap) -> Self {
+		let mut mcp = self.clone();
+
+		if matches!(self.auth_state, McpAuthState::Jwt { .. }) {
+			mcp.caller_token = server::read_bearer_token(headers).map(ToString::to_string);
+		}
+
+		mcp
+	}
+
 	pub(super) fn api_base_for_path(&self, path: &str) -> &str {
 		if server::is_admin_path(path) { &self.admin_api_base } else { &self.http_api_base }
 	}
@@ -92,6 +106,10 @@ impl ElfMcp {
 			McpAuthState::Off => builder,
 			McpAuthState::StaticKeys { bearer_token } =>
 				builder.header(HEADER_AUTHORIZATION, format!("Bearer {bearer_token}")),
+			McpAuthState::Jwt { .. } => match self.caller_token.as_deref() {
+				Some(token) => builder.header(HEADER_AUTHORIZATION, format!("Bearer {token}")),
+				None => builder,
+			},
 		}
 	}
 
@@ -206,6 +224,23 @@ impl ElfMcp {
 		server::handle_response(response).await
 	}
 
+	/// Sends a GET with the configured context headers and returns the status and JSON body.
+	pub(super) async fn fetch_json(
+		&self,
+		path: &str,
+	) -> Result<(reqwest::StatusCode, Value), ErrorData> {
+		let url = format!("{}{}", self.api_base_for_path(path), path);
+		let response = self
+			.apply_context_headers(self.client.get(url), None, Uuid::new_v4())
+			.send()
+			.await
+			.map_err(|err| {
+				ErrorData::internal_error(format!("ELF API request failed: {err}"), None)
+			})?;
+
+		server::read_response_json(response).await
+	}
+
 	pub(super) async fn forward(
 		&self,
 		method: HttpMethod,
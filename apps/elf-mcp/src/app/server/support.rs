@@ -18,11 +18,15 @@ pub(super) fn is_admin_path(path: &str) -> bool {
 	path.starts_with("/v2/admin/")
 }
 
-pub(super) fn is_authorized(headers: &HeaderMap, auth_state: &McpAuthState) -> bool {
+pub(super) async fn is_authorized(headers: &HeaderMap, auth_state: &McpAuthState) -> bool {
 	match auth_state {
 		McpAuthState::Off => true,
 		McpAuthState::StaticKeys { bearer_token } =>
 			read_bearer_token(headers).is_some_and(|token| token == bearer_token),
+		McpAuthState::Jwt { verifier } => match read_bearer_token(headers) {
+			Some(token) => verifier.verify(token).await.is_ok(),
+			None => false,
+		},
 	}
 }
 
@@ -119,6 +123,19 @@ pub(super) fn reject_context_override_params(params: &JsonObject) -> Result<(),
 pub(super) async fn handle_response(
 	response: reqwest::Response,
 ) -> Result<CallToolResult, ErrorData> {
+	let (status, parsed) = read_response_json(response).await?;
+
+	if status.is_success() {
+		Ok(CallToolResult::structured(parsed))
+	} else {
+		Ok(CallToolResult::structured_error(parsed))
+	}
+}
+
+/// Reads the response body as JSON, wrapping non-JSON bodies as `{ "raw": ... }`.
+pub(super) async fn read_response_json(
+	response: reqwest::Response,
+) -> Result<(reqwest::StatusCode, Value), ErrorData> {
 	let status = response.status();
 	let bytes = response
 		.bytes()
@@ -130,11 +147,7 @@ pub(super) async fn handle_response(
 		serde_json::json!({ "raw": raw })
 	});
 
-	if status.is_success() {
-		Ok(CallToolResult::structured(parsed))
-	} else {
-		Ok(CallToolResult::structured_error(parsed))
-	}
+	Ok((status, parsed))
 }
 
 pub(super) async fn mcp_auth_middleware(
@@ -142,11 +155,8 @@ pub(super) async fn mcp_auth_middleware(
 	req: Request<Body>,
 	next: Next,
 ) -> axum::response::Response {
-	if !is_authorized(req.headers(), &auth_state) {
-		return (
-			StatusCode::UNAUTHORIZED,
-			"Authentication required for security.auth_mode=static_keys with a Bearer token.",
-		)
+	if !is_authorized(req.headers(), &auth_state).await {
+		return (StatusCode::UNAUTHORIZED, "Authentication required with a Bearer token.")
 			.into_response();
 	}
 
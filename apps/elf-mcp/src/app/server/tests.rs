@@ -1,4 +1,5 @@
 mod forwarding;
+mod resources;
 mod schemas;
 mod tool_definitions;
 
@@ -32,33 +33,39 @@ fn admin_paths_use_admin_api_base() {
 	assert_eq!(mcp.api_base_for_path("/v2/recall-debug/panel"), "http://127.0.0.1:9000");
 }
 
-#[test]
-fn off_mode_allows_requests_without_auth_header() {
+#[tokio::test]
+async fn off_mode_allows_requests_without_auth_header() {
 	let headers = HeaderMap::new();
 
-	assert!(super::is_authorized(&headers, &McpAuthState::Off));
+	assert!(super::is_authorized(&headers, &McpAuthState::Off).await);
 }
 
-#[test]
-fn static_keys_mode_requires_authorization_bearer_header() {
+#[tokio::test]
+async fn static_keys_mode_requires_authorization_bearer_header() {
 	let mut headers = HeaderMap::new();
 
 	headers.insert(HEADER_AUTHORIZATION, "Bearer token-a".parse().expect("valid header"));
 
-	assert!(super::is_authorized(
-		&headers,
-		&McpAuthState::StaticKeys { bearer_token: "token-a".to_string() }
-	));
+	assert!(
+		super::is_authorized(
+			&headers,
+			&McpAuthState::StaticKeys { bearer_token: "token-a".to_string() }
+		)
+		.await
+	);
 }
 
-#[test]
-fn static_keys_mode_rejects_non_bearer_schemes() {
+#[tokio::test]
+async fn static_keys_mode_rejects_non_bearer_schemes() {
 	let mut headers = HeaderMap::new();
 
 	headers.insert(HEADER_AUTHORIZATION, "bearer token-a".parse().expect("valid header"));
 
-	assert!(!super::is_authorized(
-		&headers,
-		&McpAuthState::StaticKeys { bearer_token: "token-a".to_string() }
-	));
+	assert!(
+		!super::is_authorized(
+			&headers,
+			&McpAuthState::StaticKeys { bearer_token: "token-a".to_string() }
+		)
+		.await
+	);
 }
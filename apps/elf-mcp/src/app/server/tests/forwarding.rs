@@ -57,7 +57,8 @@ async fn recall_debug_panel_rejects_context_override_params() {
 
 #[tokio::test]
 async fn default_ingestion_profile_set_uses_put_admin_default_path() {
-	let (admin_base, received) = spawn_recording_admin_server().await;
+	let (admin_base, received) =
+		spawn_recording_server("/v2/admin/events/ingestion-profiles/default").await;
 	let context = McpContext {
 		tenant_id: "tenant-a".to_string(),
 		project_id: "project-a".to_string(),
@@ -86,23 +87,93 @@ async fn default_ingestion_profile_set_uses_put_admin_default_path() {
 	assert_eq!(request.body.get("version").and_then(Value::as_i64), Some(2));
 }
 
-async fn spawn_recording_admin_server() -> (String, Receiver<RecordedRequest>) {
+#[tokio::test]
+async fn search_with_profile_moves_profile_into_the_path() {
+	let (api_base, received) = spawn_recording_server("/v2/searches/profiles/{name}").await;
+	let context = McpContext {
+		tenant_id: "tenant-a".to_string(),
+		project_id: "project-a".to_string(),
+		agent_id: "agent-a".to_string(),
+		read_profile: "private_plus_project".to_string(),
+	};
+	let mcp = ElfMcp::new(
+		api_base,
+		"http://127.0.0.1:9001".to_string(),
+		ElfContextHeaders::new(&context),
+		McpAuthState::Off,
+	);
+	let params = Map::from_iter([
+		("profile".to_string(), Value::String("decisions".to_string())),
+		("query".to_string(), Value::String("What did we decide about caching?".to_string())),
+		("read_profile".to_string(), Value::String("all_scopes".to_string())),
+	]);
+	let result = mcp.elf_search_with_profile(params).await;
+
+	assert!(result.is_ok(), "profile search should forward successfully: {result:?}");
+
+	let request = receive_recorded_request(received).await;
+
+	assert_eq!(request.method, Method::POST);
+	assert_eq!(request.path, "/v2/searches/profiles/decisions");
+	assert!(request.body.get("profile").is_none());
+	assert!(request.body.get("read_profile").is_none());
+	assert_eq!(
+		request.body.get("query").and_then(Value::as_str),
+		Some("What did we decide about caching?")
+	);
+}
+
+#[tokio::test]
+async fn memory_recall_for_context_forwards_turns_without_read_profile() {
+	let (api_base, received) = spawn_recording_server("/v2/recall/context").await;
+	let context = McpContext {
+		tenant_id: "tenant-a".to_string(),
+		project_id: "project-a".to_string(),
+		agent_id: "agent-a".to_string(),
+		read_profile: "private_plus_project".to_string(),
+	};
+	let mcp = ElfMcp::new(
+		api_base,
+		"http://127.0.0.1:9001".to_string(),
+		ElfContextHeaders::new(&context),
+		McpAuthState::Off,
+	);
+	let turns = serde_json::json!([
+		{ "role": "user", "content": "Can we ship the cache change on Friday?" }
+	]);
+	let params = Map::from_iter([
+		("turns".to_string(), turns.clone()),
+		("read_profile".to_string(), Value::String("all_scopes".to_string())),
+	]);
+	let result = mcp.elf_memory_recall_for_context(params).await;
+
+	assert!(result.is_ok(), "recall for context should forward successfully: {result:?}");
+
+	let request = receive_recorded_request(received).await;
+
+	assert_eq!(request.method, Method::POST);
+	assert_eq!(request.path, "/v2/recall/context");
+	assert!(request.body.get("read_profile").is_none());
+	assert_eq!(request.body.get("turns"), Some(&turns));
+}
+
+async fn spawn_recording_server(path: &str) -> (String, Receiver<RecordedRequest>) {
 	let (tx, rx) = oneshot::channel();
 	let app = Router::new()
-		.route("/v2/admin/events/ingestion-profiles/default", routing::any(record_request))
+		.route(path, routing::any(record_request))
 		.with_state(Arc::new(Mutex::new(Some(tx))));
 	let listener = match TcpListener::bind("127.0.0.1:0").await {
 		Ok(listener) => listener,
-		Err(err) => panic!("Failed to bind MCP recording admin server: {err}."),
+		Err(err) => panic!("Failed to bind MCP recording server: {err}."),
 	};
 	let addr = match listener.local_addr() {
 		Ok(addr) => addr,
-		Err(err) => panic!("Failed to read MCP recording admin server address: {err}."),
+		Err(err) => panic!("Failed to read MCP recording server address: {err}."),
 	};
 
 	tokio::spawn(async move {
 		if let Err(err) = axum::serve(listener, app).await {
-			panic!("MCP recording admin server failed: {err}.");
+			panic!("MCP recording server failed: {err}.");
 		}
 	});
 
@@ -117,7 +188,7 @@ async fn record_request(
 ) -> Json<Value> {
 	let mut sender = match recorder.lock() {
 		Ok(sender) => sender,
-		Err(err) => panic!("MCP recording admin server mutex was poisoned: {err}."),
+		Err(err) => panic!("MCP recording server mutex was poisoned: {err}."),
 	};
 
 	if let Some(tx) = sender.take() {
@@ -130,7 +201,7 @@ async fn record_request(
 async fn receive_recorded_request(received: Receiver<RecordedRequest>) -> RecordedRequest {
 	match time::timeout(Duration::from_secs(3), received).await {
 		Ok(Ok(request)) => request,
-		Ok(Err(err)) => panic!("MCP recording admin server closed before recording: {err}."),
-		Err(err) => panic!("Timed out waiting for MCP recording admin server: {err}."),
+		Ok(Err(err)) => panic!("MCP recording server closed before recording: {err}."),
+		Err(err) => panic!("Timed out waiting for MCP recording server: {err}."),
 	}
 }
@@ -0,0 +1,117 @@
+use axum::{
+	Json, Router,
+	http::{HeaderMap, StatusCode, Uri},
+	routing,
+};
+use rmcp::model::{ErrorCode, ResourceContents};
+use serde_json::Value;
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+use crate::app::{
+	McpAuthState,
+	server::{
+		ElfContextHeaders, ElfMcp, HEADER_READ_PROFILE,
+		resources::{self, MemoryResource},
+	},
+};
+use elf_config::McpContext;
+
+const KNOWN_NOTE_ID: Uuid = Uuid::from_u128(7);
+
+#[test]
+fn memory_resource_uris_map_to_api_paths() {
+	let note_id = Uuid::from_u128(1);
+	let trace_id = Uuid::from_u128(2);
+	let note = MemoryResource::parse(format!("memory://note/{note_id}").as_str())
+		.expect("Note URI should parse.");
+	let trace = MemoryResource::parse(format!("memory://trace/{trace_id}").as_str())
+		.expect("Trace URI should parse.");
+
+	assert_eq!(note.api_path(), format!("/v2/notes/{note_id}"));
+	assert_eq!(trace.api_path(), format!("/v2/admin/traces/{trace_id}"));
+
+	let unknown = MemoryResource::parse("memory://doc/1").expect_err("Unknown kind is rejected.");
+	let malformed = MemoryResource::parse("memory://note/abc").expect_err("Bad id is rejected.");
+
+	assert_eq!(unknown.code, ErrorCode::RESOURCE_NOT_FOUND);
+	assert_eq!(malformed.code, ErrorCode::INVALID_PARAMS);
+}
+
+#[test]
+fn resource_templates_cover_notes_and_traces() {
+	let templates = resources::resource_templates()
+		.into_iter()
+		.map(|template| template.uri_template.clone())
+		.collect::<Vec<_>>();
+
+	assert_eq!(templates, vec!["memory://note/{note_id}", "memory://trace/{trace_id}"]);
+}
+
+#[tokio::test]
+async fn read_memory_resource_forwards_with_the_configured_read_profile() {
+	let api_base = spawn_note_server().await;
+	let context = McpContext {
+		tenant_id: "tenant-a".to_string(),
+		project_id: "project-a".to_string(),
+		agent_id: "agent-a".to_string(),
+		read_profile: "private_plus_project".to_string(),
+	};
+	let mcp = ElfMcp::new(
+		api_base,
+		"http://127.0.0.1:9001".to_string(),
+		ElfContextHeaders::new(&context),
+		McpAuthState::Off,
+	);
+	let uri = format!("memory://note/{KNOWN_NOTE_ID}");
+	let result = mcp.read_memory_resource(uri.as_str()).await.expect("Note should be readable.");
+	let Some(ResourceContents::TextResourceContents { uri: content_uri, mime_type, text, .. }) =
+		result.contents.first()
+	else {
+		panic!("Expected one text resource.");
+	};
+	let body: Value = serde_json::from_str(text).expect("Resource text should be JSON.");
+
+	assert_eq!(content_uri, &uri);
+	assert_eq!(mime_type.as_deref(), Some("application/json"));
+	assert_eq!(body["read_profile"], "private_plus_project");
+	assert_eq!(body["path"], format!("/v2/notes/{KNOWN_NOTE_ID}"));
+
+	let missing = mcp
+		.read_memory_resource(format!("memory://note/{}", Uuid::from_u128(8)).as_str())
+		.await
+		.expect_err("Unknown note should fail.");
+
+	assert_eq!(missing.code, ErrorCode::RESOURCE_NOT_FOUND);
+}
+
+async fn spawn_note_server() -> String {
+	let app = Router::new().route("/v2/notes/{note_id}", routing::get(serve_note));
+	let listener = match TcpListener::bind("127.0.0.1:0").await {
+		Ok(listener) => listener,
+		Err(err) => panic!("Failed to bind MCP resource server: {err}."),
+	};
+	let addr = match listener.local_addr() {
+		Ok(addr) => addr,
+		Err(err) => panic!("Failed to read MCP resource server address: {err}."),
+	};
+
+	tokio::spawn(async move {
+		if let Err(err) = axum::serve(listener, app).await {
+			panic!("MCP resource server failed: {err}.");
+		}
+	});
+
+	format!("http://{addr}")
+}
+
+async fn serve_note(headers: HeaderMap, uri: Uri) -> (StatusCode, Json<Value>) {
+	if uri.path() != format!("/v2/notes/{KNOWN_NOTE_ID}") {
+		return (StatusCode::NOT_FOUND, Json(serde_json::json!({ "error_code": "NOT_FOUND" })));
+	}
+
+	let read_profile =
+		headers.get(HEADER_READ_PROFILE).and_then(|value| value.to_str().ok()).unwrap_or_default();
+
+	(StatusCode::OK, Json(serde_json::json!({ "path": uri.path(), "read_profile": read_profile })))
+}
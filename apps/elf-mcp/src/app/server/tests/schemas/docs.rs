@@ -61,6 +61,32 @@ fn docs_search_l0_schema_includes_filter_fields() {
 		])
 	);
 }
+
+#[test]
+fn docs_search_schema_includes_level_and_filter_fields() {
+	let schema = server::docs_search_schema();
+	let properties = schema
+		.get("properties")
+		.and_then(Value::as_object)
+		.expect("docs_search schema is missing properties.");
+
+	for field in ["query", "scope", "doc_type", "updated_after", "sparse_mode", "level"] {
+		assert!(properties.contains_key(field), "Missing schema field: {field}.");
+	}
+
+	assert_eq!(
+		properties.get("level").and_then(Value::as_object).and_then(|field| {
+			field.get("enum").and_then(Value::as_array).map(|vals| vals.to_vec())
+		}),
+		Some(vec![
+			Value::String("L0".to_string()),
+			Value::String("L1".to_string()),
+			Value::String("L2".to_string()),
+			Value::Null,
+		])
+	);
+}
+
 #[test]
 fn docs_put_schema_includes_required_fields_and_write_policy() {
 	let schema = server::docs_put_schema();
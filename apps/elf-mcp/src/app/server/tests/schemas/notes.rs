@@ -72,3 +72,15 @@ fn notes_ingest_schema_includes_structured_entities_relations() {
 		})
 	}));
 }
+
+#[test]
+fn notes_get_schema_exposes_access_stats_flag() {
+	let schema = server::notes_get_schema();
+	let properties = schema
+		.get("properties")
+		.and_then(Value::as_object)
+		.expect("notes get schema is missing properties.");
+
+	assert!(properties.contains_key("note_id"));
+	assert!(properties.contains_key("include_access_stats"));
+}
@@ -9,6 +9,7 @@ fn payload_level_schema_for_search_tools_is_l0_l1_l2() {
 		server::searches_get_schema(),
 		server::searches_timeline_schema(),
 		server::searches_notes_schema(),
+		server::searches_scoped_schema(),
 	] {
 		let properties = schema
 			.get("properties")
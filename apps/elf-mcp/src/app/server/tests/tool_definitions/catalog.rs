@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::app::server::HttpMethod;
 
-const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
+const ALL_TOOL_DEFINITIONS: [ToolDefinition; 64] = [
 	ToolDefinition::new(
 		"elf_notes_ingest",
 		HttpMethod::Post,
@@ -21,6 +21,18 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"/v2/graph/report",
 		"Build a source-backed graph topic map with current, historical, future, inferred, ambiguous, stale, and superseded fact markers.",
 	),
+	ToolDefinition::new(
+		"elf_graph_neighborhood",
+		HttpMethod::Post,
+		"/v2/graph/neighborhood",
+		"Traverse up to three hops of graph facts around an entity and return bounded subject/predicate/object triples with evidence note ids.",
+	),
+	ToolDefinition::new(
+		"elf_graph_facts_as_of",
+		HttpMethod::Post,
+		"/v2/graph/facts/as-of",
+		"Read the graph facts about an entity that were valid at a point in time, including facts later superseded, with their replacement fact ids and evidence note ids.",
+	),
 	ToolDefinition::new(
 		"elf_events_ingest",
 		HttpMethod::Post,
@@ -33,6 +45,30 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"/v2/searches",
 		"Create a search session using quick-find or planned-search mode. Response includes optional trajectory_summary.",
 	),
+	ToolDefinition::new(
+		"elf_searches_answer",
+		HttpMethod::Post,
+		"/v2/searches/answer",
+		"Retrieve notes for a query and synthesize a short answer whose every sentence cites verbatim note quotes with offsets. Refuses when grounding is low; requires search.answer.enabled.",
+	),
+	ToolDefinition::new(
+		"elf_searches_batch",
+		HttpMethod::Post,
+		"/v2/searches/batch",
+		"Run up to 8 related searches in one request with shared embedding calls. Returns one search session per query, in request order.",
+	),
+	ToolDefinition::new(
+		"elf_searches_scoped",
+		HttpMethod::Post,
+		"/v2/searches/scoped",
+		"Search every scope the read profile allows concurrently and return one ranked section per scope (agent_private, project_shared, org_shared). scope_top_k overrides the per-section limit.",
+	),
+	ToolDefinition::new(
+		"elf_search_with_profile",
+		HttpMethod::Post,
+		"/v2/searches/profiles/{profile}",
+		"Create a search session from a named search profile. The profile supplies mode, filters, ranking overrides, and limits; pass the query and optionally override top_k, payload_level, or the updated_at window.",
+	),
 	ToolDefinition::new(
 		"elf_core_blocks_get",
 		HttpMethod::Get,
@@ -45,6 +81,18 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"/v2/entity-memory",
 		"Fetch an entity-scoped memory view across attached core blocks and graph-linked archival notes.",
 	),
+	ToolDefinition::new(
+		"elf_org_memory_stats",
+		HttpMethod::Get,
+		"/v2/org-stats",
+		"Summarize topic and entity frequencies over shared scopes only. Items below the minimum note and agent counts are withheld so private or small-population memory cannot be inferred.",
+	),
+	ToolDefinition::new(
+		"elf_memory_timeline",
+		HttpMethod::Get,
+		"/v2/memory-timeline",
+		"Summarize readable memory by day or week: per-bucket note counts by type, top graph entities, and the highest-importance representative notes, without fetching every note.",
+	),
 	ToolDefinition::new(
 		"elf_dreaming_review_queue",
 		HttpMethod::Get,
@@ -57,6 +105,12 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"/v2/recall-debug/panel",
 		"Build an agent-facing cross-layer recall/debug panel and deterministic recall_trace over memory traces, source documents, knowledge pages, graph facts, and Dreaming proposals.",
 	),
+	ToolDefinition::new(
+		"elf_memory_recall_for_context",
+		HttpMethod::Post,
+		"/v2/recall/context",
+		"Derive 1 to 3 retrieval queries from recent conversation turns with the extractor, run them as one batch search, and return a compact context block whose [n] markers cite note ids. Falls back to the last user turn when no query can be derived.",
+	),
 	ToolDefinition::new(
 		"elf_work_journal_entry_create",
 		HttpMethod::Post,
@@ -75,6 +129,48 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"/v2/work-journal/readback",
 		"Read newest Work Journal entries for a session and return a where_stopped projection with journal evidence.",
 	),
+	ToolDefinition::new(
+		"elf_session_append",
+		HttpMethod::Post,
+		"/v2/sessions/{session_id}/messages",
+		"Append messages to a short-term working-memory session.",
+	),
+	ToolDefinition::new(
+		"elf_session_get",
+		HttpMethod::Get,
+		"/v2/sessions/{session_id}",
+		"Read the unexpired messages of one working-memory session.",
+	),
+	ToolDefinition::new(
+		"elf_session_summarize_to_notes",
+		HttpMethod::Post,
+		"/v2/sessions/{session_id}/summarize",
+		"Extract long-term notes from a working-memory session.",
+	),
+	ToolDefinition::new(
+		"elf_standing_query_create",
+		HttpMethod::Post,
+		"/v2/standing-queries",
+		"Register a standing query that the worker evaluates against newly indexed notes.",
+	),
+	ToolDefinition::new(
+		"elf_standing_queries_list",
+		HttpMethod::Get,
+		"/v2/standing-queries",
+		"List active standing queries registered by the configured agent.",
+	),
+	ToolDefinition::new(
+		"elf_standing_query_delete",
+		HttpMethod::Delete,
+		"/v2/standing-queries/{standing_query_id}",
+		"Delete a standing query by standing_query_id.",
+	),
+	ToolDefinition::new(
+		"elf_standing_query_matches",
+		HttpMethod::Get,
+		"/v2/standing-queries/{standing_query_id}/matches",
+		"Read the match change feed of one standing query.",
+	),
 	ToolDefinition::new(
 		"elf_searches_get",
 		HttpMethod::Get,
@@ -93,6 +189,18 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"/v2/searches/{search_id}/notes",
 		"Fetch note details for selected note_ids from a search session. l0/l1 strip evidence/source_ref/structured; l2 returns full detail.",
 	),
+	ToolDefinition::new(
+		"elf_searches_feedback",
+		HttpMethod::Post,
+		"/v2/searches/feedback",
+		"Record useful, not_useful, or wrong feedback on one search result by result_handle. Repeating the call replaces earlier feedback; aggregated feedback can adjust future ranking.",
+	),
+	ToolDefinition::new(
+		"elf_rank_documents",
+		HttpMethod::Post,
+		"/v2/rank",
+		"Rank caller-provided candidate documents against a query with the search rerank, blend, and deterministic signal pipeline, without retrieval. Returns scored results with ranking explain terms.",
+	),
 	ToolDefinition::new(
 		"elf_notes_list",
 		HttpMethod::Get,
@@ -105,6 +213,30 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"/v2/notes/{note_id}",
 		"Fetch a single note by note_id.",
 	),
+	ToolDefinition::new(
+		"elf_notes_history",
+		HttpMethod::Get,
+		"/v2/notes/{note_id}/history",
+		"Read a note's version chain, oldest first, with the op, reason, and actor of each change, a word-level text diff against the previous version, and the changed fields.",
+	),
+	ToolDefinition::new(
+		"elf_notes_cite",
+		HttpMethod::Post,
+		"/v2/notes/cite",
+		"Build citation blocks for note_ids: key evidence quote, source locator, scope, and created date, formatted for direct inclusion in answers.",
+	),
+	ToolDefinition::new(
+		"elf_notes_resolve_source_refs",
+		HttpMethod::Post,
+		"/v2/notes/source-refs/resolve",
+		"Fetch the doc excerpts behind note_ids in one call: each elf_doc_ext/v1 source_ref is dereferenced into a verified excerpt with doc_id, offsets, and text, after note and doc read checks.",
+	),
+	ToolDefinition::new(
+		"elf_notes_events",
+		HttpMethod::Get,
+		"/v2/notes/events",
+		"Read note writes after a cursor that the caller can read, in commit order. Set wait_ms to long-poll; pass next_cursor back as after to resume.",
+	),
 	ToolDefinition::new(
 		"elf_notes_patch",
 		HttpMethod::Patch,
@@ -115,7 +247,37 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"elf_notes_delete",
 		HttpMethod::Delete,
 		"/v2/notes/{note_id}",
-		"Delete a note by note_id.",
+		"Move a note to the trash by note_id. Trashed notes can be restored until the retention window purges them.",
+	),
+	ToolDefinition::new(
+		"elf_notes_trash_list",
+		HttpMethod::Get,
+		"/v2/notes/trash",
+		"List the caller's trashed notes with the time each becomes eligible for purge.",
+	),
+	ToolDefinition::new(
+		"elf_notes_undelete",
+		HttpMethod::Post,
+		"/v2/notes/{note_id}/undelete",
+		"Restore a trashed note by note_id.",
+	),
+	ToolDefinition::new(
+		"elf_notes_merge",
+		HttpMethod::Post,
+		"/v2/notes/{note_id}/merge",
+		"Merge secondary_note_id into note_id. Unions source refs, structured fields, and graph evidence, then supersedes the secondary note.",
+	),
+	ToolDefinition::new(
+		"elf_notes_pin",
+		HttpMethod::Post,
+		"/v2/notes/{note_id}/pin",
+		"Pin a note by note_id so search always includes it when the caller can read it.",
+	),
+	ToolDefinition::new(
+		"elf_notes_unpin",
+		HttpMethod::Post,
+		"/v2/notes/{note_id}/unpin",
+		"Unpin a note by note_id so search ranks it normally again.",
 	),
 	ToolDefinition::new(
 		"elf_notes_publish",
@@ -133,19 +295,19 @@ const ALL_TOOL_DEFINITIONS: [ToolDefinition; 37] = [
 		"elf_space_grants_list",
 		HttpMethod::Get,
 		"/v2/spaces/{space}/grants",
-		"List sharing grants for a space (team_shared or org_shared).",
+		"List read grants for a shared space (team_shared or org_shared), including agent grantees.",
 	),
 	ToolDefinition::new(
 		"elf_space_grant_upsert",
 		HttpMethod::Post,
 		"/v2/spaces/{space}/grants",
-		"Upsert a sharing grant for a space (team_shared or org_shared).",
+		"Grant read access to a shared space (team_shared or org_shared) to the whole project or to one agent (grantee_kind=agent, grantee_agent_id). Denied unless the space is writable for the caller.",
 	),
 	ToolDefinition::new(
 		"elf_space_grant_revoke",
 		HttpMethod::Post,
 		"/v2/spaces/{space}/grants/revoke",
-		"Revoke a sharing grant for a space (team_shared or org_shared).",
+		"Revoke a read grant for a shared space (team_shared or org_shared) from the project or one agent.",
 	),
 	ToolDefinition::new(
 		"elf_admin_traces_recent_list",
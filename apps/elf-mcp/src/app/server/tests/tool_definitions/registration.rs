@@ -7,17 +7,36 @@ fn registers_all_tools() {
 		"elf_notes_ingest",
 		"elf_graph_query",
 		"elf_graph_report",
+		"elf_graph_neighborhood",
+		"elf_graph_facts_as_of",
 		"elf_events_ingest",
 		"elf_core_blocks_get",
 		"elf_entity_memory_get",
+		"elf_org_memory_stats",
+		"elf_memory_timeline",
 		"elf_searches_create",
+		"elf_searches_answer",
+		"elf_searches_batch",
+		"elf_searches_scoped",
+		"elf_search_with_profile",
 		"elf_searches_get",
 		"elf_searches_timeline",
 		"elf_searches_notes",
+		"elf_searches_feedback",
+		"elf_rank_documents",
 		"elf_notes_list",
 		"elf_notes_get",
+		"elf_notes_history",
+		"elf_notes_cite",
+		"elf_notes_resolve_source_refs",
+		"elf_notes_events",
 		"elf_notes_patch",
 		"elf_notes_delete",
+		"elf_notes_trash_list",
+		"elf_notes_undelete",
+		"elf_notes_merge",
+		"elf_notes_pin",
+		"elf_notes_unpin",
 		"elf_notes_publish",
 		"elf_notes_unpublish",
 		"elf_space_grants_list",
@@ -26,9 +45,17 @@ fn registers_all_tools() {
 		"elf_admin_traces_recent_list",
 		"elf_dreaming_review_queue",
 		"elf_recall_debug_panel",
+		"elf_memory_recall_for_context",
 		"elf_work_journal_entry_create",
 		"elf_work_journal_entry_get",
 		"elf_work_journal_session_readback",
+		"elf_session_append",
+		"elf_session_get",
+		"elf_session_summarize_to_notes",
+		"elf_standing_query_create",
+		"elf_standing_queries_list",
+		"elf_standing_query_delete",
+		"elf_standing_query_matches",
 		"elf_admin_trace_get",
 		"elf_admin_trajectory_get",
 		"elf_admin_trace_item_get",
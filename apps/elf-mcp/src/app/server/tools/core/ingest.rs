@@ -6,7 +6,10 @@ use rmcp::{
 
 use crate::app::server::{
 	ElfMcp, HttpMethod,
-	schemas::{events_ingest_schema, graph_query_schema, graph_report_schema, notes_ingest_schema},
+	schemas::{
+		events_ingest_schema, graph_facts_as_of_schema, graph_neighborhood_schema,
+		graph_query_schema, graph_report_schema, notes_ingest_schema,
+	},
 };
 
 #[rmcp::tool_router(router = core_ingest_tool_router, vis = "pub(in crate::app::server)")]
@@ -38,6 +41,27 @@ impl ElfMcp {
 		self.forward(HttpMethod::Post, "/v2/graph/report", params, None).await
 	}
 
+	#[rmcp::tool(
+		name = "elf_graph_neighborhood",
+		description = "Traverse up to three hops of graph facts around an entity and return bounded subject/predicate/object triples with evidence note ids.",
+		input_schema = graph_neighborhood_schema()
+	)]
+	async fn elf_graph_neighborhood(
+		&self,
+		params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Post, "/v2/graph/neighborhood", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_graph_facts_as_of",
+		description = "Read the graph facts about an entity that were valid at a point in time, including facts later superseded, with their replacement fact ids and evidence note ids.",
+		input_schema = graph_facts_as_of_schema()
+	)]
+	async fn elf_graph_facts_as_of(&self, params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Post, "/v2/graph/facts/as-of", params, None).await
+	}
+
 	#[rmcp::tool(
 		name = "elf_events_ingest",
 		description = "Ingest an event by extracting evidence-bound notes using the configured LLM extractor.",
@@ -8,7 +8,11 @@ use crate::app::server::{
 	ElfMcp, HttpMethod,
 	schemas::{
 		core_blocks_get_schema, dreaming_review_queue_schema, entity_memory_get_schema,
-		recall_debug_panel_schema, work_journal_entry_create_schema, work_journal_entry_get_schema,
+		memory_recall_for_context_schema, memory_timeline_schema, org_memory_stats_schema,
+		recall_debug_panel_schema, session_append_schema, session_get_schema,
+		session_summarize_schema, standing_queries_list_schema, standing_query_create_schema,
+		standing_query_delete_schema, standing_query_matches_schema,
+		work_journal_entry_create_schema, work_journal_entry_get_schema,
 		work_journal_session_readback_schema,
 	},
 	support,
@@ -88,6 +92,36 @@ impl ElfMcp {
 		self.forward(HttpMethod::Get, "/v2/entity-memory", params, None).await
 	}
 
+	#[rmcp::tool(
+		name = "elf_org_memory_stats",
+		description = "Summarize topic and entity frequencies over shared scopes only. Items below the minimum note and agent counts are withheld so private or small-population memory cannot be inferred.",
+		input_schema = org_memory_stats_schema()
+	)]
+	async fn elf_org_memory_stats(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+
+		self.forward(HttpMethod::Get, "/v2/org-stats", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_memory_timeline",
+		description = "Summarize readable memory by day or week: per-bucket note counts by type, top graph entities, and the highest-importance representative notes, without fetching every note.",
+		input_schema = memory_timeline_schema()
+	)]
+	async fn elf_memory_timeline(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+
+		self.forward(HttpMethod::Get, "/v2/memory-timeline", params, None).await
+	}
+
 	#[rmcp::tool(
 		name = "elf_dreaming_review_queue",
 		description = "List source-backed Dreaming review queue proposals with variants, affected refs, lint flags, policy gates, and review audit.",
@@ -113,4 +147,118 @@ impl ElfMcp {
 
 		self.forward(HttpMethod::Post, "/v2/recall-debug/panel", params, None).await
 	}
+
+	#[rmcp::tool(
+		name = "elf_memory_recall_for_context",
+		description = "Derive 1 to 3 retrieval queries from recent conversation turns with the extractor, run them as one batch search, and return a compact context block whose [n] markers cite note ids. Falls back to the last user turn when no query can be derived.",
+		input_schema = memory_recall_for_context_schema()
+	)]
+	pub(in crate::app::server) async fn elf_memory_recall_for_context(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+
+		self.forward(HttpMethod::Post, "/v2/recall/context", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_standing_query_create",
+		description = "Register a standing query that the worker evaluates against newly indexed notes, recording a match when a note enters the query's top-k and optionally notifying a webhook.",
+		input_schema = standing_query_create_schema()
+	)]
+	async fn elf_standing_query_create(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+
+		self.forward(HttpMethod::Post, "/v2/standing-queries", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_standing_queries_list",
+		description = "List active standing queries registered by the configured agent.",
+		input_schema = standing_queries_list_schema()
+	)]
+	async fn elf_standing_queries_list(
+		&self,
+		params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Get, "/v2/standing-queries", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_standing_query_delete",
+		description = "Delete a standing query by standing_query_id and cancel its pending webhook deliveries.",
+		input_schema = standing_query_delete_schema()
+	)]
+	async fn elf_standing_query_delete(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		let standing_query_id = support::take_required_string(&mut params, "standing_query_id")?;
+		let path = format!("/v2/standing-queries/{standing_query_id}");
+
+		self.forward(HttpMethod::Delete, &path, JsonObject::new(), None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_standing_query_matches",
+		description = "Read the match change feed of one standing query after an optional after_seq position.",
+		input_schema = standing_query_matches_schema()
+	)]
+	async fn elf_standing_query_matches(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		let standing_query_id = support::take_required_string(&mut params, "standing_query_id")?;
+		let path = format!("/v2/standing-queries/{standing_query_id}/matches");
+
+		self.forward(HttpMethod::Get, &path, params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_session_append",
+		description = "Append messages to a short-term working-memory session. Session messages are not notes, are never searched, and expire after ttl_seconds (default six hours) unless summarized into notes.",
+		input_schema = session_append_schema()
+	)]
+	async fn elf_session_append(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		let session_id = support::take_required_string(&mut params, "session_id")?;
+		let path = format!("/v2/sessions/{session_id}/messages");
+
+		self.forward(HttpMethod::Post, &path, params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_session_get",
+		description = "Read the unexpired messages of one working-memory session owned by the configured agent.",
+		input_schema = session_get_schema()
+	)]
+	async fn elf_session_get(&self, mut params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		let session_id = support::take_required_string(&mut params, "session_id")?;
+		let path = format!("/v2/sessions/{session_id}");
+
+		self.forward(HttpMethod::Get, &path, JsonObject::new(), None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_session_summarize_to_notes",
+		description = "Extract long-term notes from a working-memory session. Messages go through event ingestion, so the write gate and evidence rules decide which notes persist.",
+		input_schema = session_summarize_schema()
+	)]
+	async fn elf_session_summarize_to_notes(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		let session_id = support::take_required_string(&mut params, "session_id")?;
+		let path = format!("/v2/sessions/{session_id}/summarize");
+
+		self.forward(HttpMethod::Post, &path, params, None).await
+	}
 }
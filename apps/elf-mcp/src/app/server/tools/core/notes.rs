@@ -7,8 +7,10 @@ use rmcp::{
 use crate::app::server::{
 	ElfMcp, HttpMethod,
 	schemas::{
-		notes_get_schema, notes_list_schema, notes_patch_schema, notes_publish_schema,
-		notes_unpublish_schema,
+		notes_cite_schema, notes_delete_schema, notes_events_schema, notes_get_schema,
+		notes_history_schema, notes_list_schema, notes_merge_schema, notes_patch_schema,
+		notes_pin_schema, notes_publish_schema, notes_source_refs_resolve_schema,
+		notes_trash_list_schema, notes_undelete_schema, notes_unpin_schema, notes_unpublish_schema,
 	},
 	support,
 };
@@ -26,14 +28,56 @@ impl ElfMcp {
 
 	#[rmcp::tool(
 		name = "elf_notes_get",
-		description = "Fetch a single note by note_id.",
+		description = "Fetch a single note by note_id. Set include_access_stats to attach retrieval analytics.",
 		input_schema = notes_get_schema()
 	)]
 	async fn elf_notes_get(&self, mut params: JsonObject) -> Result<CallToolResult, ErrorData> {
 		let note_id = support::take_required_string(&mut params, "note_id")?;
 		let path = format!("/v2/notes/{note_id}");
 
-		self.forward(HttpMethod::Get, &path, JsonObject::new(), None).await
+		self.forward(HttpMethod::Get, &path, params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_notes_history",
+		description = "Read a note's version chain, oldest first, with the op, reason, and actor of each change, a word-level text diff against the previous version, and the changed fields.",
+		input_schema = notes_history_schema()
+	)]
+	async fn elf_notes_history(&self, mut params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		let note_id = support::take_required_string(&mut params, "note_id")?;
+		let path = format!("/v2/notes/{note_id}/history");
+
+		self.forward(HttpMethod::Get, &path, params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_notes_cite",
+		description = "Build citation blocks for note_ids: key evidence quote, source locator, scope, and created date, formatted for direct inclusion in answers.",
+		input_schema = notes_cite_schema()
+	)]
+	async fn elf_notes_cite(&self, params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Post, "/v2/notes/cite", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_notes_resolve_source_refs",
+		description = "Fetch the doc excerpts behind note_ids in one call: each elf_doc_ext/v1 source_ref is dereferenced into a verified excerpt with doc_id, offsets, and text, after note and doc read checks.",
+		input_schema = notes_source_refs_resolve_schema()
+	)]
+	async fn elf_notes_resolve_source_refs(
+		&self,
+		params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Post, "/v2/notes/source-refs/resolve", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_notes_events",
+		description = "Read note writes after a cursor that the caller can read, in commit order. Set wait_ms to long-poll; pass next_cursor back as after to resume.",
+		input_schema = notes_events_schema()
+	)]
+	async fn elf_notes_events(&self, params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Get, "/v2/notes/events", params, None).await
 	}
 
 	#[rmcp::tool(
@@ -50,8 +94,8 @@ impl ElfMcp {
 
 	#[rmcp::tool(
 		name = "elf_notes_delete",
-		description = "Delete a note by note_id.",
-		input_schema = notes_get_schema()
+		description = "Move a note to the trash by note_id. Trashed notes can be restored until the retention window purges them.",
+		input_schema = notes_delete_schema()
 	)]
 	async fn elf_notes_delete(&self, mut params: JsonObject) -> Result<CallToolResult, ErrorData> {
 		let note_id = support::take_required_string(&mut params, "note_id")?;
@@ -60,6 +104,66 @@ impl ElfMcp {
 		self.forward(HttpMethod::Delete, &path, JsonObject::new(), None).await
 	}
 
+	#[rmcp::tool(
+		name = "elf_notes_trash_list",
+		description = "List the caller's trashed notes with the time each becomes eligible for purge.",
+		input_schema = notes_trash_list_schema()
+	)]
+	async fn elf_notes_trash_list(&self, params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Get, "/v2/notes/trash", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_notes_undelete",
+		description = "Restore a trashed note by note_id.",
+		input_schema = notes_undelete_schema()
+	)]
+	async fn elf_notes_undelete(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		let note_id = support::take_required_string(&mut params, "note_id")?;
+		let path = format!("/v2/notes/{note_id}/undelete");
+
+		self.forward(HttpMethod::Post, &path, JsonObject::new(), None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_notes_merge",
+		description = "Merge secondary_note_id into note_id. Unions source refs, structured fields, and graph evidence, then supersedes the secondary note.",
+		input_schema = notes_merge_schema()
+	)]
+	async fn elf_notes_merge(&self, mut params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		let note_id = support::take_required_string(&mut params, "note_id")?;
+		let path = format!("/v2/notes/{note_id}/merge");
+
+		self.forward(HttpMethod::Post, &path, params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_notes_pin",
+		description = "Pin a note by note_id so search always includes it when the caller can read it.",
+		input_schema = notes_pin_schema()
+	)]
+	async fn elf_notes_pin(&self, mut params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		let note_id = support::take_required_string(&mut params, "note_id")?;
+		let path = format!("/v2/notes/{note_id}/pin");
+
+		self.forward(HttpMethod::Post, &path, JsonObject::new(), None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_notes_unpin",
+		description = "Unpin a note by note_id so search ranks it normally again.",
+		input_schema = notes_unpin_schema()
+	)]
+	async fn elf_notes_unpin(&self, mut params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		let note_id = support::take_required_string(&mut params, "note_id")?;
+		let path = format!("/v2/notes/{note_id}/unpin");
+
+		self.forward(HttpMethod::Post, &path, JsonObject::new(), None).await
+	}
+
 	#[rmcp::tool(
 		name = "elf_notes_publish",
 		description = "Publish a note from agent_private into a shared space (team_shared or org_shared).",
@@ -7,8 +7,9 @@ use rmcp::{
 use crate::app::server::{
 	ElfMcp, HttpMethod,
 	schemas::{
-		searches_create_schema, searches_get_schema, searches_notes_schema,
-		searches_timeline_schema,
+		rank_documents_schema, search_with_profile_schema, searches_batch_schema,
+		searches_create_schema, searches_feedback_schema, searches_get_schema,
+		searches_notes_schema, searches_scoped_schema, searches_timeline_schema,
 	},
 	support,
 };
@@ -30,6 +31,68 @@ impl ElfMcp {
 		self.forward(HttpMethod::Post, "/v2/searches", params, None).await
 	}
 
+	#[rmcp::tool(
+		name = "elf_searches_answer",
+		description = "Retrieve notes for a query and synthesize a short answer whose every sentence cites verbatim note quotes with offsets. Refuses when grounding is low; requires search.answer.enabled.",
+		input_schema = searches_create_schema()
+	)]
+	async fn elf_searches_answer(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+
+		self.forward(HttpMethod::Post, "/v2/searches/answer", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_searches_batch",
+		description = "Run up to 8 related searches in one request with shared embedding calls. Returns one search session per query, in request order.",
+		input_schema = searches_batch_schema()
+	)]
+	async fn elf_searches_batch(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+
+		self.forward(HttpMethod::Post, "/v2/searches/batch", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_searches_scoped",
+		description = "Search every scope the read profile allows concurrently and return one ranked section per scope (agent_private, project_shared, org_shared). scope_top_k overrides the per-section limit.",
+		input_schema = searches_scoped_schema()
+	)]
+	async fn elf_searches_scoped(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+
+		self.forward(HttpMethod::Post, "/v2/searches/scoped", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_search_with_profile",
+		description = "Create a search session from a named search profile. The profile supplies mode, filters, ranking overrides, and limits; pass the query and optionally override top_k, payload_level, or the updated_at window.",
+		input_schema = search_with_profile_schema()
+	)]
+	pub(in crate::app::server) async fn elf_search_with_profile(
+		&self,
+		mut params: JsonObject,
+	) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+		let profile = support::take_required_string(&mut params, "profile")?;
+		let path = format!("/v2/searches/profiles/{profile}");
+
+		self.forward(HttpMethod::Post, &path, params, None).await
+	}
+
 	#[rmcp::tool(
 		name = "elf_searches_get",
 		description = "Fetch a search session index view by search_id, including optional trajectory_summary.",
@@ -71,4 +134,22 @@ impl ElfMcp {
 
 		self.forward(HttpMethod::Post, &path, params, None).await
 	}
+
+	#[rmcp::tool(
+		name = "elf_searches_feedback",
+		description = "Record useful, not_useful, or wrong feedback on one search result by result_handle. Repeating the call replaces earlier feedback; aggregated feedback can adjust future ranking.",
+		input_schema = searches_feedback_schema()
+	)]
+	async fn elf_searches_feedback(&self, params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Post, "/v2/searches/feedback", params, None).await
+	}
+
+	#[rmcp::tool(
+		name = "elf_rank_documents",
+		description = "Rank caller-provided candidate documents against a query with the search rerank, blend, and deterministic signal pipeline, without retrieval. Returns scored results with ranking explain terms.",
+		input_schema = rank_documents_schema()
+	)]
+	async fn elf_rank_documents(&self, params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		self.forward(HttpMethod::Post, "/v2/rank", params, None).await
+	}
 }
@@ -14,7 +14,7 @@ use crate::app::server::{
 impl ElfMcp {
 	#[rmcp::tool(
 		name = "elf_space_grants_list",
-		description = "List sharing grants for a space (team_shared or org_shared).",
+		description = "List read grants for a shared space (team_shared or org_shared), including agent grantees.",
 		input_schema = space_grants_list_schema()
 	)]
 	async fn elf_space_grants_list(
@@ -29,7 +29,7 @@ impl ElfMcp {
 
 	#[rmcp::tool(
 		name = "elf_space_grant_upsert",
-		description = "Upsert a sharing grant for a space (team_shared or org_shared).",
+		description = "Grant read access to a shared space (team_shared or org_shared) to the whole project or to one agent (grantee_kind=agent, grantee_agent_id). Denied unless the space is writable for the caller.",
 		input_schema = space_grant_upsert_schema()
 	)]
 	async fn elf_space_grant_upsert(
@@ -44,7 +44,7 @@ impl ElfMcp {
 
 	#[rmcp::tool(
 		name = "elf_space_grant_revoke",
-		description = "Revoke a sharing grant for a space (team_shared or org_shared).",
+		description = "Revoke a read grant for a shared space (team_shared or org_shared) from the project or one agent.",
 		input_schema = space_grant_revoke_schema()
 	)]
 	async fn elf_space_grant_revoke(
@@ -6,7 +6,10 @@ use rmcp::{
 
 use crate::app::server::{
 	ElfMcp, HttpMethod,
-	schemas::{docs_excerpts_get_schema, docs_get_schema, docs_put_schema, docs_search_l0_schema},
+	schemas::{
+		docs_excerpts_get_schema, docs_get_schema, docs_put_schema, docs_search_l0_schema,
+		docs_search_schema,
+	},
 	support,
 };
 
@@ -60,6 +63,18 @@ impl ElfMcp {
 		self.forward(HttpMethod::Post, "/v2/docs/search/l0", params, None).await
 	}
 
+	#[rmcp::tool(
+		name = "elf_docs_search",
+		description = "Search across documents: chunk-level hits with doc title, pointer, and a bounded excerpt (L0, L1, or L2; default L1).",
+		input_schema = docs_search_schema()
+	)]
+	async fn elf_docs_search(&self, mut params: JsonObject) -> Result<CallToolResult, ErrorData> {
+		// read_profile is part of the MCP server configuration and is not client-controlled.
+		let _ = support::take_optional_string(&mut params, "read_profile")?;
+
+		self.forward(HttpMethod::Post, "/v2/docs/search", params, None).await
+	}
+
 	#[rmcp::tool(
 		name = "elf_docs_excerpts_get",
 		description = "Hydrate a verifiable excerpt (L1 or L2) from a stored document.",
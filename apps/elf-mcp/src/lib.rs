@@ -0,0 +1,8 @@
+#![recursion_limit = "512"]
+#![cfg_attr(test, allow(unused_crate_dependencies))]
+
+//! MCP server application for ELF.
+
+mod app;
+
+pub use app::{Args, run, serve};
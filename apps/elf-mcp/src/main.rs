@@ -1,13 +1,11 @@
-//! Binary entrypoint for the ELF MCP app.
-
-#![recursion_limit = "512"]
+#![allow(unused_crate_dependencies)]
 
-mod app;
+//! Binary entrypoint for the ELF MCP app.
 
 use clap::Parser;
 use color_eyre::Result;
 
-use app::Args;
+use elf_mcp::Args;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -15,5 +13,5 @@ async fn main() -> Result<()> {
 
 	let args = Args::parse();
 
-	app::run(args).await
+	elf_mcp::run(args).await
 }
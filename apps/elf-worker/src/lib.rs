@@ -11,14 +11,14 @@ pub use error::{Error, Result};
 use std::path::PathBuf;
 
 use clap::Parser;
-use tracing_subscriber::EnvFilter;
 
 use elf_chunking::ChunkingConfig;
+use elf_config::{Config, CronSchedule};
 use elf_storage::{
 	db::Db,
-	qdrant::{DOCS_SEARCH_FILTER_INDEXES, QdrantStore},
+	qdrant::{DOCS_SEARCH_FILTER_INDEXES, NOTES_SEARCH_FILTER_INDEXES, QdrantStore},
 };
-use worker::WorkerState;
+use worker::{LifecycleRunner, NoteConsolidation, WorkerState};
 
 /// CLI arguments for the worker binary.
 #[derive(Debug, Parser)]
@@ -31,22 +31,43 @@ pub struct Args {
 	#[arg(long, short = 'c', value_name = "FILE")]
 	/// Path to the worker configuration file.
 	pub config: PathBuf,
+	#[arg(long)]
+	/// Validate and lint the configuration, print the findings, and exit.
+	pub check_config: bool,
 }
 
 /// Loads configuration, initializes storage handles, and starts the worker loop.
 pub async fn run(args: Args) -> Result<()> {
-	let config = elf_config::load(&args.config).map_err(|err| Error::Message(err.to_string()))?;
-	let filter = EnvFilter::new(config.service.log_level.clone());
+	let (config, lints) =
+		elf_config::load_with_lints(&args.config).map_err(|err| Error::Message(err.to_string()))?;
 
-	tracing_subscriber::fmt().with_env_filter(filter).init();
+	if args.check_config {
+		elf_runtime::telemetry::print_lints(&lints);
+
+		return Ok(());
+	}
+
+	let _telemetry = elf_runtime::telemetry::init(&config.service, "elf-worker")
+		.map_err(|err| Error::Message(err.to_string()))?;
+
+	elf_runtime::telemetry::log_lints(&lints);
 
 	let db = Db::connect(&config.storage.postgres).await?;
 
 	db.ensure_schema(config.storage.qdrant.vector_dim).await?;
 
+	let state = build_state(&config, db).await?;
+
+	worker::run_worker(state).await
+}
+
+/// Builds worker state on an existing, schema-ready Postgres handle and ensures the Qdrant
+/// collections exist.
+pub async fn build_state(config: &Config, db: Db) -> Result<WorkerState> {
 	let qdrant = QdrantStore::new(&config.storage.qdrant)?;
 
 	qdrant.ensure_collection().await?;
+	qdrant.ensure_payload_indexes(&NOTES_SEARCH_FILTER_INDEXES).await?;
 
 	let docs_qdrant = QdrantStore::new_with_collection(
 		&config.storage.qdrant,
@@ -56,20 +77,53 @@ pub async fn run(args: Args) -> Result<()> {
 	docs_qdrant.ensure_collection().await?;
 	docs_qdrant.ensure_payload_indexes(&DOCS_SEARCH_FILTER_INDEXES).await?;
 
-	let tokenizer_repo = config.chunking.tokenizer_repo.clone();
-	let tokenizer = elf_chunking::load_tokenizer(&tokenizer_repo)?;
+	let tokenizer = elf_chunking::load_tokenizer(&config.chunking.tokenizer_repo)?;
 	let chunking = ChunkingConfig {
 		max_tokens: config.chunking.max_tokens,
 		overlap_tokens: config.chunking.overlap_tokens,
 	};
-	let state = WorkerState {
+
+	Ok(WorkerState {
 		db,
 		qdrant,
 		docs_qdrant,
-		embedding: config.providers.embedding,
+		embedding: config.providers.embedding.clone(),
+		embedding_scopes: config.providers.embedding_scope_providers(),
+		embedding_types: config.providers.embedding_type_providers(),
 		chunking,
+		chunking_per_type: config.chunking.per_type.clone().unwrap_or_default(),
 		tokenizer,
-	};
+		url_snapshots: config.url_snapshots.clone(),
+		egress: config.security.egress.clone(),
+		qdrant_maintenance: config.qdrant_maintenance.clone(),
+		trash_retention_days: Some(config.lifecycle.trash_retention_days()),
+		note_consolidation: config.lifecycle.consolidation.clone().filter(|cfg| cfg.enabled).map(
+			|schedule| NoteConsolidation {
+				schedule,
+				llm_extractor: config.providers.llm_extractor.clone(),
+				max_note_chars: config.memory.max_note_chars,
+			},
+		),
+		lifecycle: lifecycle_runner(config)?,
+		importance_rescore: config.lifecycle.importance_rescore.clone().filter(|cfg| cfg.enabled),
+		indexing_concurrency: config
+			.worker
+			.as_ref()
+			.map_or(1, |worker| worker.concurrency.max(1) as usize),
+	})
+}
 
-	worker::run_worker(state).await
+fn lifecycle_runner(config: &Config) -> Result<Option<LifecycleRunner>> {
+	let Some(schedule) = config.lifecycle.schedule.as_ref().filter(|schedule| schedule.enabled)
+	else {
+		return Ok(None);
+	};
+	let cron = CronSchedule::parse(&schedule.cron).map_err(Error::Validation)?;
+
+	Ok(Some(LifecycleRunner {
+		schedule: cron,
+		batch_size: schedule.batch_size,
+		purge_deleted_after_days: config.lifecycle.purge_deleted_after_days,
+		purge_deprecated_after_days: config.lifecycle.purge_deprecated_after_days,
+	}))
 }
@@ -2,19 +2,30 @@
 
 mod consolidation_jobs;
 mod doc_indexing;
+mod egress;
 mod helpers;
+mod importance_rescore_jobs;
+mod lifecycle_jobs;
+mod note_consolidation_jobs;
 mod note_indexing;
 mod outbox_jobs;
+mod qdrant_maintenance_jobs;
 mod runtime;
+mod standing_query_jobs;
 mod trace_jobs;
 mod types;
+mod url_snapshot_jobs;
 
 pub use self::{
-	runtime::{process_once, run_worker},
-	types::WorkerState,
+	runtime::{process_once, run_worker, run_worker_with_wakeup},
+	types::{LifecycleRunner, NoteConsolidation, WorkerState},
 };
 
-use std::{collections::HashMap, slice, string::ToString};
+use std::{
+	collections::{HashMap, HashSet},
+	slice,
+	string::ToString,
+};
 
 use qdrant_client::{
 	Payload, QdrantError,
@@ -31,8 +42,12 @@ use uuid::Uuid;
 use crate::{Error, Result};
 use consolidation_jobs::handle_consolidation_job;
 use doc_indexing::{handle_doc_delete, handle_doc_upsert};
+use egress::{EgressClient, EgressError};
 use elf_chunking::{Chunk, ChunkingConfig, Tokenizer};
-use elf_config::EmbeddingProviderConfig;
+use elf_config::{
+	ChunkingTypeOverride, CronSchedule, EmbeddingProviderConfig, LifecycleConsolidation,
+	LifecycleImportanceRescore, LlmProviderConfig, QdrantMaintenance, SecurityEgress, UrlSnapshots,
+};
 use elf_domain::consolidation::{
 	CONSOLIDATION_CONTRACT_SCHEMA_V1, ConsolidationJobPayload, ConsolidationProposalContract,
 	ConsolidationReviewState, ConsolidationRunState, ConsolidationValidationError,
@@ -48,29 +63,51 @@ use elf_storage::{
 	},
 	outbox,
 	qdrant::{BM25_MODEL, BM25_VECTOR_NAME, DENSE_VECTOR_NAME, QdrantStore},
-	queries,
+	queries, standing_queries,
 };
 use helpers::{
-	backoff_for_attempt, build_chunk_records, encode_json, format_timestamp, format_vector_text,
-	is_not_found_error, mean_pool, note_is_active, project_doc_ref_fields, sanitize_outbox_error,
-	to_std_duration, validate_vector_dim,
+	backoff_for_attempt, build_chunk_records, chunk_note_text, chunking_snapshot, encode_json,
+	format_timestamp, format_vector_text, is_not_found_error, mean_pool, note_is_active,
+	parse_vector_text, project_doc_ref_fields, resolve_note_chunking, resolve_semantic_threshold,
+	sanitize_outbox_error, to_std_duration, validate_vector_dim,
 };
+#[cfg(test)] use importance_rescore_jobs::rescored_importance;
+use importance_rescore_jobs::run_importance_rescore;
+use lifecycle_jobs::{next_cron_run, prune_expired, run_lifecycle};
+#[cfg(test)] use note_consolidation_jobs::merged_text_allowed;
+use note_consolidation_jobs::{insert_version, note_snapshot, run_note_consolidation};
 use note_indexing::{handle_delete, handle_upsert};
 use outbox_jobs::{
 	process_consolidation_run_job_once, process_doc_indexing_outbox_once,
-	process_indexing_outbox_once, process_trace_outbox_once,
+	process_indexing_outbox_batch, process_indexing_outbox_once, process_trace_outbox_once,
 };
+use qdrant_maintenance_jobs::run_due_qdrant_maintenance;
+#[cfg(test)] use qdrant_maintenance_jobs::{is_due, scheduled_interval};
+use standing_query_jobs::{evaluate_standing_queries, process_standing_query_notifications_once};
 use trace_jobs::{
 	handle_trace_job, purge_expired_cache, purge_expired_search_sessions,
-	purge_expired_trace_candidates, purge_expired_traces,
+	purge_expired_session_hits, purge_expired_session_messages, purge_expired_trace_candidates,
+	purge_expired_traces,
 };
 use types::{
 	BASE_BACKOFF_MS, CLAIM_LEASE_SECONDS, CONSOLIDATION_JOB_LEASE_SECONDS, ChunkRecord,
-	DocChunkIndexRow, MAX_BACKOFF_MS, MAX_OUTBOX_ERROR_CHARS, NoteFieldRow, POLL_INTERVAL_MS,
-	ProjectDocRefFields, TRACE_CLEANUP_INTERVAL_SECONDS, TRACE_OUTBOX_LEASE_SECONDS,
+	DocChunkIndexRow, ELF_LIFECYCLE_RUN_SCHEMA_V1, ELF_QDRANT_MAINTENANCE_ALERT_SCHEMA_V1,
+	ELF_STANDING_QUERY_NOTIFICATION_SCHEMA_V1, IMPORTANCE_RESCORE_LOCK_ID,
+	IMPORTANCE_RESCORE_REASON, ImportanceSignals, IndexingOutboxStats, LIFECYCLE_EXPIRE_REASON,
+	LIFECYCLE_LOCK_ID, LifecycleRunReport, MAX_BACKOFF_MS, MAX_OUTBOX_ERROR_CHARS,
+	MAX_ROBOTS_TXT_BYTES, NOTE_CONSOLIDATION_LOCK_ID, NOTE_CONSOLIDATION_REASON, NearDuplicatePair,
+	NoteFieldRow, ORG_PROJECT_ID, OUTBOX_MAX_ATTEMPTS, OUTBOX_THROUGHPUT_LOG_INTERVAL_SECONDS,
+	POLL_INTERVAL_MS, ProjectDocRefFields, QDRANT_MAINTENANCE_ALERT_TIMEOUT_MS,
+	QDRANT_MAINTENANCE_CHECK_INTERVAL_SECONDS, QDRANT_MAINTENANCE_LOCK_ID,
+	STANDING_QUERY_NOTIFY_BATCH, STANDING_QUERY_NOTIFY_LEASE_SECONDS,
+	STANDING_QUERY_NOTIFY_MAX_ATTEMPTS, STANDING_QUERY_WEBHOOK_TIMEOUT_MS,
+	TRACE_CLEANUP_BATCH_SIZE, TRACE_CLEANUP_INTERVAL_SECONDS, TRACE_OUTBOX_LEASE_SECONDS,
 	TraceCandidateInsert, TraceCandidateRecord, TraceItemInsert, TraceItemRecord, TracePayload,
 	TraceRecord, TraceStageInsert, TraceStageItemInsert, TraceTrajectoryStageRecord,
+	URL_SNAPSHOT_DOC_TYPE, URL_SNAPSHOT_LEASE_SECONDS, WORKER_VERSION_ACTOR,
 };
+use url_snapshot_jobs::process_url_snapshot_once;
+#[cfg(test)] use url_snapshot_jobs::{html_to_text, robots_allows};
 
 #[cfg(test)]
 #[path = "worker/tests.rs"]
@@ -0,0 +1,154 @@
+use std::{
+	fmt::{self, Display, Formatter},
+	net::{IpAddr, SocketAddr},
+	time::Duration as StdDuration,
+};
+
+use reqwest::{Client, Response, Url, header::LOCATION, redirect::Policy};
+use serde_json::Value;
+
+use elf_config::SecurityEgress;
+use elf_domain::egress;
+
+/// Redirect hops followed by [`EgressClient::get`] before giving up.
+pub(super) const MAX_EGRESS_REDIRECTS: usize = 5;
+
+/// Failure of an outbound request to a caller-supplied URL.
+#[derive(Debug)]
+pub(super) enum EgressError {
+	/// The target is refused by the egress policy; retrying cannot succeed.
+	Refused(String),
+	/// The target could not be reached; the request may succeed later.
+	Unreachable(String),
+}
+impl Display for EgressError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Refused(message) | Self::Unreachable(message) => f.write_str(message),
+		}
+	}
+}
+
+/// HTTP client for caller-supplied URLs that only connects to vetted public addresses.
+///
+/// Every request resolves the host, refuses it unless every address is public and the host passes
+/// `security.egress`, and pins the connection to the vetted addresses so a second DNS answer
+/// cannot redirect it. Redirects are followed by hand so each hop is vetted the same way.
+pub(super) struct EgressClient {
+	timeout: StdDuration,
+	user_agent: Option<String>,
+	policy: Option<SecurityEgress>,
+}
+impl EgressClient {
+	pub(super) fn new(
+		timeout: StdDuration,
+		user_agent: Option<&str>,
+		policy: Option<&SecurityEgress>,
+	) -> Self {
+		Self { timeout, user_agent: user_agent.map(str::to_string), policy: policy.cloned() }
+	}
+
+	/// Sends a GET request, following up to [`MAX_EGRESS_REDIRECTS`] vetted redirects.
+	pub(super) async fn get(&self, url: Url) -> Result<Response, EgressError> {
+		let mut url = url;
+
+		for _ in 0..=MAX_EGRESS_REDIRECTS {
+			let client = self.pinned_client(&url).await?;
+			let response = client
+				.get(url.clone())
+				.send()
+				.await
+				.map_err(|err| EgressError::Unreachable(format!("Request failed: {err}.")))?;
+
+			if !response.status().is_redirection() {
+				return Ok(response);
+			}
+
+			let Some(location) =
+				response.headers().get(LOCATION).and_then(|value| value.to_str().ok())
+			else {
+				return Ok(response);
+			};
+
+			url = url
+				.join(location)
+				.map_err(|err| EgressError::Refused(format!("Invalid redirect target: {err}.")))?;
+		}
+
+		Err(EgressError::Refused(format!("More than {MAX_EGRESS_REDIRECTS} redirects.")))
+	}
+
+	/// Sends a JSON POST request. Redirects are not followed.
+	pub(super) async fn post_json(&self, url: &str, body: &Value) -> Result<Response, EgressError> {
+		let url =
+			Url::parse(url).map_err(|err| EgressError::Refused(format!("Invalid URL: {err}.")))?;
+
+		self.pinned_client(&url)
+			.await?
+			.post(url)
+			.json(body)
+			.send()
+			.await
+			.map_err(|err| EgressError::Unreachable(format!("Request failed: {err}.")))
+	}
+
+	/// Vets `url` and builds a client whose connections to its host go to the vetted addresses.
+	async fn pinned_client(&self, url: &Url) -> Result<Client, EgressError> {
+		let addrs = self.vet(url).await?;
+		let mut builder =
+			Client::builder().timeout(self.timeout).redirect(Policy::none()).no_proxy();
+
+		if let Some(user_agent) = self.user_agent.as_deref() {
+			builder = builder.user_agent(user_agent);
+		}
+		if let Some(domain) = url.domain() {
+			builder = builder.resolve_to_addrs(domain, &addrs);
+		}
+
+		builder.build().map_err(|err| EgressError::Unreachable(err.to_string()))
+	}
+
+	/// Returns the addresses `url` may be fetched from, or why it is refused.
+	async fn vet(&self, url: &Url) -> Result<Vec<SocketAddr>, EgressError> {
+		if !matches!(url.scheme(), "http" | "https") {
+			return Err(EgressError::Refused(format!("Unsupported URL scheme {}.", url.scheme())));
+		}
+
+		let host =
+			url.host_str().ok_or_else(|| EgressError::Refused("URL has no host.".to_string()))?;
+		let port = url
+			.port_or_known_default()
+			.ok_or_else(|| EgressError::Refused("URL has no port.".to_string()))?;
+
+		egress::check_host(host, self.policy.as_ref()).map_err(|denial| {
+			EgressError::Refused(format!("Refused {host}: {}.", denial.message()))
+		})?;
+
+		let addrs: Vec<SocketAddr> = match url.domain() {
+			Some(domain) => tokio::net::lookup_host((domain, port))
+				.await
+				.map_err(|err| {
+					EgressError::Unreachable(format!("Failed to resolve {domain}: {err}."))
+				})?
+				.collect(),
+			None => host
+				.trim_start_matches('[')
+				.trim_end_matches(']')
+				.parse::<IpAddr>()
+				.map(|ip| vec![SocketAddr::new(ip, port)])
+				.map_err(|_| EgressError::Refused(format!("Invalid host {host}.")))?,
+		};
+
+		if addrs.is_empty() {
+			return Err(EgressError::Unreachable(format!("{host} resolved to no addresses.")));
+		}
+		if let Some(addr) = addrs.iter().find(|addr| !egress::is_public_ip(addr.ip())) {
+			return Err(EgressError::Refused(format!(
+				"Refused {host}: resolves to non-public address {}.",
+				addr.ip()
+			)));
+		}
+
+		Ok(addrs)
+	}
+}
@@ -1,7 +1,7 @@
 use crate::worker::{
-	BASE_BACKOFF_MS, Chunk, ChunkRecord, Error, MAX_BACKOFF_MS, MAX_OUTBOX_ERROR_CHARS, MemoryNote,
-	OffsetDateTime, ProjectDocRefFields, QdrantError, Result, Rfc3339, Serialize, ToString, Uuid,
-	Value,
+	BASE_BACKOFF_MS, Chunk, ChunkRecord, ChunkingConfig, ChunkingTypeOverride, Error, HashMap,
+	MAX_BACKOFF_MS, MAX_OUTBOX_ERROR_CHARS, MemoryNote, OffsetDateTime, ProjectDocRefFields,
+	QdrantError, Result, Rfc3339, Serialize, ToString, Tokenizer, Uuid, Value,
 };
 
 pub(super) fn is_not_found_error(err: &QdrantError) -> bool {
@@ -27,6 +27,83 @@ pub(super) fn note_is_active(note: &MemoryNote, now: OffsetDateTime) -> bool {
 	true
 }
 
+/// Applies the per-type override for `note_type` on top of the global chunking window.
+///
+/// Returns `None` when notes of this type are indexed as one whole-text chunk.
+pub(super) fn resolve_note_chunking(
+	base: &ChunkingConfig,
+	per_type: &HashMap<String, ChunkingTypeOverride>,
+	note_type: &str,
+) -> Option<ChunkingConfig> {
+	let Some(rule) = per_type.get(note_type) else {
+		return Some(base.clone());
+	};
+
+	if rule.enabled == Some(false) {
+		return None;
+	}
+
+	Some(ChunkingConfig {
+		max_tokens: rule.max_tokens.unwrap_or(base.max_tokens),
+		overlap_tokens: rule.overlap_tokens.unwrap_or(base.overlap_tokens),
+	})
+}
+
+/// Returns the adjacent-sentence similarity threshold when notes of `note_type` use the `semantic`
+/// chunking strategy.
+pub(super) fn resolve_semantic_threshold(
+	per_type: &HashMap<String, ChunkingTypeOverride>,
+	note_type: &str,
+) -> Option<f32> {
+	let rule = per_type.get(note_type)?;
+
+	if rule.enabled == Some(false) || rule.strategy.as_deref().map(str::trim) != Some("semantic") {
+		return None;
+	}
+
+	rule.similarity_threshold
+}
+
+/// Splits note text with the resolved window, or returns the whole text as a single chunk.
+pub(super) fn chunk_note_text(
+	text: &str,
+	chunking: Option<&ChunkingConfig>,
+	tokenizer: &Tokenizer,
+) -> Vec<Chunk> {
+	match chunking {
+		Some(cfg) => elf_chunking::split_text(text, cfg, tokenizer),
+		None if text.is_empty() => Vec::new(),
+		None => vec![Chunk {
+			chunk_index: 0,
+			start_offset: 0,
+			end_offset: text.len(),
+			text: text.to_string(),
+		}],
+	}
+}
+
+/// Describes the chunking settings recorded on each chunk row.
+pub(super) fn chunking_snapshot(
+	chunking: Option<&ChunkingConfig>,
+	semantic_threshold: Option<f32>,
+) -> Value {
+	match (chunking, semantic_threshold) {
+		(Some(cfg), Some(threshold)) => serde_json::json!({
+			"enabled": true,
+			"strategy": "semantic",
+			"similarity_threshold": threshold,
+			"max_tokens": cfg.max_tokens,
+			"overlap_tokens": cfg.overlap_tokens,
+		}),
+		(Some(cfg), None) => serde_json::json!({
+			"enabled": true,
+			"max_tokens": cfg.max_tokens,
+			"overlap_tokens": cfg.overlap_tokens,
+		}),
+		(None, _) => serde_json::json!({ "enabled": false }),
+	}
+}
+
 pub(super) fn build_chunk_records(note_id: Uuid, chunks: &[Chunk]) -> Result<Vec<ChunkRecord>> {
 	let mut records = Vec::with_capacity(chunks.len());
 
@@ -40,6 +117,7 @@ pub(super) fn build_chunk_records(note_id: Uuid, chunks: &[Chunk]) -> Result<Vec
 			start_offset,
 			end_offset,
 			text: chunk.text.clone(),
+			content_hash: blake3::hash(chunk.text.as_bytes()).to_hex().to_string(),
 		});
 	}
 
@@ -110,6 +188,27 @@ pub(super) fn format_vector_text(vec: &[f32]) -> String {
 	out
 }
 
+pub(super) fn parse_vector_text(text: &str) -> Result<Vec<f32>> {
+	let inner = text
+		.trim()
+		.strip_prefix('[')
+		.and_then(|rest| rest.strip_suffix(']'))
+		.ok_or_else(|| Error::Validation("Vector text is not bracketed.".to_string()))?;
+
+	if inner.trim().is_empty() {
+		return Ok(Vec::new());
+	}
+
+	inner
+		.split(',')
+		.map(|part| {
+			part.trim().parse::<f32>().map_err(|_| {
+				Error::Validation("Vector text contains a non-numeric value.".to_string())
+			})
+		})
+		.collect()
+}
+
 pub(super) fn encode_json<T>(value: &T, label: &str) -> Result<Value>
 where
 	T: Serialize,
@@ -0,0 +1,162 @@
+use crate::worker::{
+	self, HashMap, IMPORTANCE_RESCORE_LOCK_ID, IMPORTANCE_RESCORE_REASON, ImportanceSignals,
+	LifecycleImportanceRescore, MemoryNote, OffsetDateTime, Result, Uuid, WorkerState, outbox,
+};
+
+/// Recomputes the importance of active notes from hits, feedback, and age.
+///
+/// Notes are scanned in `note_id` order, one batch per transaction. A note whose importance moves
+/// by at least `min_delta` gets an `UPDATE` version and an outbox `UPSERT` so the Qdrant payload
+/// follows; `updated_at` is left alone so re-scoring does not reset recency decay.
+///
+/// A transaction-scoped advisory lock keeps concurrent workers from running overlapping passes.
+pub(super) async fn run_importance_rescore(state: &WorkerState) -> Result<()> {
+	let Some(cfg) = state.importance_rescore.as_ref().filter(|cfg| cfg.enabled) else {
+		return Ok(());
+	};
+	let mut lock_tx = state.db.pool.begin().await?;
+	let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+		.bind(IMPORTANCE_RESCORE_LOCK_ID)
+		.fetch_one(&mut *lock_tx)
+		.await?;
+
+	if !locked {
+		return Ok(());
+	}
+
+	let now = OffsetDateTime::now_utc();
+	let batch_size = i64::from(cfg.batch_size);
+	let mut after = Uuid::nil();
+	let mut scanned = 0_u64;
+	let mut rescored = 0_u64;
+
+	loop {
+		let mut tx = state.db.pool.begin().await?;
+		let notes = sqlx::query_as::<_, MemoryNote>(
+			"\
+SELECT *
+FROM memory_notes
+WHERE status = 'active'
+	AND (expires_at IS NULL OR expires_at > $1)
+	AND note_id > $2
+ORDER BY note_id
+LIMIT $3
+FOR UPDATE SKIP LOCKED",
+		)
+		.bind(now)
+		.bind(after)
+		.bind(batch_size)
+		.fetch_all(&mut *tx)
+		.await?;
+		let Some(last) = notes.last() else { break };
+
+		after = last.note_id;
+
+		let fetched = notes.len() as i64;
+		let note_ids: Vec<Uuid> = notes.iter().map(|note| note.note_id).collect();
+		let feedback: HashMap<Uuid, (i64, i64, i64)> = sqlx::query_as::<_, (Uuid, i64, i64, i64)>(
+			"\
+SELECT
+	note_id,
+	COUNT(*) FILTER (WHERE feedback = 'useful'),
+	COUNT(*) FILTER (WHERE feedback = 'not_useful'),
+	COUNT(*) FILTER (WHERE feedback = 'wrong')
+FROM memory_feedback
+WHERE note_id = ANY($1::uuid[])
+GROUP BY note_id",
+		)
+		.bind(&note_ids)
+		.fetch_all(&mut *tx)
+		.await?
+		.into_iter()
+		.map(|(note_id, useful, not_useful, wrong)| (note_id, (useful, not_useful, wrong)))
+		.collect();
+
+		for mut note in notes {
+			let (useful, not_useful, wrong) =
+				feedback.get(&note.note_id).copied().unwrap_or_default();
+			let signals = ImportanceSignals {
+				hit_count: note.hit_count,
+				last_hit_at: note.last_hit_at,
+				created_at: note.created_at,
+				useful,
+				not_useful,
+				wrong,
+			};
+			let importance = rescored_importance(cfg, note.importance, signals, now);
+
+			scanned += 1;
+
+			if (importance - note.importance).abs() < cfg.min_delta.max(f32::EPSILON) {
+				continue;
+			}
+
+			let prev = worker::note_snapshot(&note);
+
+			note.importance = importance;
+
+			sqlx::query("UPDATE memory_notes SET importance = $1 WHERE note_id = $2")
+				.bind(importance)
+				.bind(note.note_id)
+				.execute(&mut *tx)
+				.await?;
+			worker::insert_version(
+				&mut tx,
+				note.note_id,
+				"UPDATE",
+				prev,
+				worker::note_snapshot(&note),
+				IMPORTANCE_RESCORE_REASON,
+				now,
+			)
+			.await?;
+			outbox::enqueue_outbox(&mut *tx, note.note_id, "UPSERT", &note.embedding_version)
+				.await?;
+
+			rescored += 1;
+		}
+
+		tx.commit().await?;
+
+		if fetched < batch_size {
+			break;
+		}
+	}
+
+	lock_tx.commit().await?;
+
+	if rescored > 0 {
+		tracing::info!(scanned, rescored, "Re-scored note importance.");
+	}
+
+	Ok(())
+}
+
+/// Blends the current importance with usage signals, clamped to `[0.0, 1.0]`.
+///
+/// The hit signal saturates with hit count and decays with last-hit age; a note never hit scores
+/// zero. The feedback signal matches the ranking feedback term: `wrong` counts twice as much as
+/// `not_useful`, and the signal shrinks toward zero for notes with little feedback. Freshness
+/// decays with note age.
+pub(super) fn rescored_importance(
+	cfg: &LifecycleImportanceRescore,
+	importance: f32,
+	signals: ImportanceSignals,
+	now: OffsetDateTime,
+) -> f32 {
+	let age_days = |ts: OffsetDateTime| ((now - ts).as_seconds_f32() / 86_400.0).max(0.0);
+	let hit_count = signals.hit_count.max(0) as f32;
+	let saturation = hit_count / (hit_count + cfg.hits_half_saturation);
+	let recency =
+		signals.last_hit_at.map_or(1.0, |ts| (-age_days(ts) / cfg.last_hit_tau_days).exp());
+	let useful = signals.useful as f32;
+	let negative = signals.not_useful as f32 + 2.0 * signals.wrong as f32;
+	let feedback = (useful - negative) / (useful + negative + 1.0);
+	let freshness = (-age_days(signals.created_at) / cfg.age_tau_days).exp();
+
+	(cfg.prior_weight * importance
+		+ cfg.hits_weight * saturation * recency
+		+ cfg.feedback_weight * feedback
+		+ cfg.age_weight * freshness)
+		.clamp(0.0, 1.0)
+}
@@ -0,0 +1,277 @@
+use time::{Duration, Time};
+
+use crate::worker::{
+	self, CronSchedule, ELF_LIFECYCLE_RUN_SCHEMA_V1, LIFECYCLE_EXPIRE_REASON, LIFECYCLE_LOCK_ID,
+	LifecycleRunReport, LifecycleRunner, MemoryNote, OffsetDateTime, Result, WorkerState, outbox,
+};
+
+/// Days searched for the next matching minute before a schedule is treated as never firing.
+const MAX_CRON_LOOKAHEAD_DAYS: i64 = 366 * 5;
+
+/// Returns the first minute strictly after `after` that `schedule` fires on, in UTC.
+pub(super) fn next_cron_run(
+	schedule: &CronSchedule,
+	after: OffsetDateTime,
+) -> Option<OffsetDateTime> {
+	let after = after.to_offset(time::UtcOffset::UTC);
+	let start = after.replace_second(0).ok()?.replace_nanosecond(0).ok()? + Duration::minutes(1);
+	let mut date = start.date();
+
+	for _ in 0..MAX_CRON_LOOKAHEAD_DAYS {
+		if schedule.matches_date(
+			u8::from(date.month()),
+			date.day(),
+			date.weekday().number_days_from_sunday(),
+		) {
+			let from = if date == start.date() { start.time() } else { Time::MIDNIGHT };
+
+			if let Some(time) = first_matching_time(schedule, from) {
+				return Some(date.with_time(time).assume_utc());
+			}
+		}
+
+		date = date.next_day()?;
+	}
+
+	None
+}
+
+/// Runs the lifecycle runner once: expires notes past their TTL, purges deleted and deprecated
+/// notes past their retention windows, runs [`prune_expired`], and logs an `elf.lifecycle_run/v1`
+/// summary.
+///
+/// A transaction-scoped advisory lock keeps concurrent workers from running overlapping passes. A
+/// failing step is recorded in the summary and does not stop the remaining steps.
+pub(super) async fn run_lifecycle(state: &WorkerState, cfg: &LifecycleRunner) -> Result<()> {
+	let mut lock_tx = state.db.pool.begin().await?;
+	let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+		.bind(LIFECYCLE_LOCK_ID)
+		.fetch_one(&mut *lock_tx)
+		.await?;
+
+	if !locked {
+		return Ok(());
+	}
+
+	let now = OffsetDateTime::now_utc();
+	let batch_size = i64::from(cfg.batch_size);
+	let mut report = LifecycleRunReport {
+		schema: ELF_LIFECYCLE_RUN_SCHEMA_V1,
+		started_at: worker::format_timestamp(now)?,
+		..Default::default()
+	};
+
+	report.notes_expired =
+		record(&mut report.errors, "notes_expired", expire_notes(state, now, batch_size).await);
+	report.deleted_notes_purged = record(
+		&mut report.errors,
+		"deleted_notes_purged",
+		purge_notes(
+			state,
+			"deleted",
+			now - Duration::days(cfg.purge_deleted_after_days),
+			batch_size,
+		)
+		.await,
+	);
+	report.deprecated_notes_purged = record(
+		&mut report.errors,
+		"deprecated_notes_purged",
+		purge_notes(
+			state,
+			"deprecated",
+			now - Duration::days(cfg.purge_deprecated_after_days),
+			batch_size,
+		)
+		.await,
+	);
+
+	prune_expired(state, now, batch_size, &mut report).await;
+
+	report.duration_ms = (OffsetDateTime::now_utc() - now).whole_milliseconds() as i64;
+
+	lock_tx.commit().await?;
+
+	let summary = serde_json::to_string(&report)?;
+
+	if report.errors.is_empty() {
+		tracing::info!(report = %summary, "Lifecycle run finished.");
+	} else {
+		tracing::warn!(report = %summary, "Lifecycle run finished with errors.");
+	}
+
+	Ok(())
+}
+
+/// Purges notes trashed longer than the trash retention window and prunes expired traces, caches,
+/// and session rows, recording counts and failed steps in `report`.
+///
+/// The lifecycle runner calls this on its schedule. Workers without a scheduled runner call it on
+/// the trace cleanup interval instead, so trash and traces still expire.
+pub(super) async fn prune_expired(
+	state: &WorkerState,
+	now: OffsetDateTime,
+	batch_size: i64,
+	report: &mut LifecycleRunReport,
+) {
+	if let Some(retention_days) = state.trash_retention_days {
+		report.trashed_notes_purged = record(
+			&mut report.errors,
+			"trashed_notes_purged",
+			purge_notes(state, "trashed", now - Duration::days(retention_days), batch_size).await,
+		);
+	}
+
+	report.trace_candidates_pruned = record(
+		&mut report.errors,
+		"trace_candidates_pruned",
+		worker::purge_expired_trace_candidates(&state.db, now).await,
+	);
+	report.traces_pruned = record(
+		&mut report.errors,
+		"traces_pruned",
+		worker::purge_expired_traces(&state.db, now).await,
+	);
+	report.llm_cache_pruned = record(
+		&mut report.errors,
+		"llm_cache_pruned",
+		worker::purge_expired_cache(&state.db, now).await,
+	);
+	report.search_sessions_pruned = record(
+		&mut report.errors,
+		"search_sessions_pruned",
+		worker::purge_expired_search_sessions(&state.db, now).await,
+	);
+	report.session_hits_pruned = record(
+		&mut report.errors,
+		"session_hits_pruned",
+		worker::purge_expired_session_hits(&state.db, now).await,
+	);
+	report.session_messages_pruned = record(
+		&mut report.errors,
+		"session_messages_pruned",
+		worker::purge_expired_session_messages(&state.db, now).await,
+	);
+}
+
+/// Sets active notes whose `expires_at` has passed to `deleted`, one batch per transaction.
+///
+/// Each note gets a `DELETE` version row and an outbox `DELETE` job, matching a manual delete.
+async fn expire_notes(state: &WorkerState, now: OffsetDateTime, batch_size: i64) -> Result<u64> {
+	let mut expired = 0;
+
+	loop {
+		let mut tx = state.db.pool.begin().await?;
+		let notes = sqlx::query_as::<_, MemoryNote>(
+			"\
+SELECT *
+FROM memory_notes
+WHERE status = 'active' AND expires_at IS NOT NULL AND expires_at <= $1
+ORDER BY expires_at ASC
+LIMIT $2
+FOR UPDATE SKIP LOCKED",
+		)
+		.bind(now)
+		.bind(batch_size)
+		.fetch_all(&mut *tx)
+		.await?;
+		let fetched = notes.len() as i64;
+
+		for mut note in notes {
+			let prev = worker::note_snapshot(&note);
+
+			note.status = "deleted".to_string();
+			note.updated_at = now;
+
+			sqlx::query("UPDATE memory_notes SET status = $1, updated_at = $2 WHERE note_id = $3")
+				.bind(&note.status)
+				.bind(now)
+				.bind(note.note_id)
+				.execute(&mut *tx)
+				.await?;
+			worker::insert_version(
+				&mut tx,
+				note.note_id,
+				"DELETE",
+				prev,
+				worker::note_snapshot(&note),
+				LIFECYCLE_EXPIRE_REASON,
+				now,
+			)
+			.await?;
+			outbox::enqueue_outbox(&mut *tx, note.note_id, "DELETE", &note.embedding_version)
+				.await?;
+		}
+
+		tx.commit().await?;
+
+		expired += fetched as u64;
+
+		if fetched < batch_size {
+			return Ok(expired);
+		}
+	}
+}
+
+/// Hard-deletes notes in `status` that have not changed or been hit since `cutoff`, one batch at a
+/// time.
+///
+/// Dependent rows cascade from `memory_notes`; version history is kept. Qdrant points were already
+/// removed by the DELETE outbox job queued when the note left the active set.
+async fn purge_notes(
+	state: &WorkerState,
+	status: &str,
+	cutoff: OffsetDateTime,
+	batch_size: i64,
+) -> Result<u64> {
+	let mut purged = 0;
+
+	loop {
+		let result = sqlx::query(
+			"\
+DELETE FROM memory_notes
+WHERE note_id IN (
+	SELECT note_id
+	FROM memory_notes
+	WHERE status = $1
+		AND updated_at <= $2
+		AND (last_hit_at IS NULL OR last_hit_at <= $2)
+	LIMIT $3
+	FOR UPDATE SKIP LOCKED
+)",
+		)
+		.bind(status)
+		.bind(cutoff)
+		.bind(batch_size)
+		.execute(&state.db.pool)
+		.await?;
+
+		purged += result.rows_affected();
+
+		if (result.rows_affected() as i64) < batch_size {
+			return Ok(purged);
+		}
+	}
+}
+
+fn record(errors: &mut Vec<String>, step: &str, result: Result<u64>) -> u64 {
+	result.unwrap_or_else(|err| {
+		errors.push(format!("{step}: {err}"));
+
+		0
+	})
+}
+
+fn first_matching_time(schedule: &CronSchedule, from: Time) -> Option<Time> {
+	for hour in from.hour()..24 {
+		let first_minute = if hour == from.hour() { from.minute() } else { 0 };
+
+		for minute in first_minute..60 {
+			if schedule.matches_time(hour, minute) {
+				return Time::from_hms(hour, minute, 0).ok();
+			}
+		}
+	}
+
+	None
+}
@@ -0,0 +1,381 @@
+use serde_json::Value;
+use sqlx::{PgConnection, Postgres, Transaction};
+
+use crate::worker::{
+	self, Error, HashSet, MemoryNote, NOTE_CONSOLIDATION_LOCK_ID, NOTE_CONSOLIDATION_REASON,
+	NearDuplicatePair, NoteConsolidation, OffsetDateTime, Result, Uuid, WORKER_VERSION_ACTOR,
+	WorkerState, outbox,
+};
+use elf_domain::{english_gate, note_merge, writegate};
+use elf_providers::extractor;
+use elf_storage::search_watermarks;
+
+/// Merges near-duplicate active notes through the LLM extractor.
+///
+/// Candidate pairs share tenant, project, agent, scope, type, and embedding version, and their
+/// pooled note embeddings reach the configured cosine similarity. The older note of each pair
+/// survives with the rewritten text and the unioned source references; the newer note is
+/// deprecated with a version row. Both notes are queued for reindexing. A note merged away in
+/// this pass is not considered again, so larger clusters collapse over consecutive passes.
+///
+/// A transaction-scoped advisory lock keeps concurrent workers from running overlapping passes.
+pub(super) async fn run_note_consolidation(state: &WorkerState) -> Result<()> {
+	let Some(cfg) = state.note_consolidation.as_ref().filter(|cfg| cfg.schedule.enabled) else {
+		return Ok(());
+	};
+	let mut lock_tx = state.db.pool.begin().await?;
+	let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+		.bind(NOTE_CONSOLIDATION_LOCK_ID)
+		.fetch_one(&mut *lock_tx)
+		.await?;
+
+	if !locked {
+		return Ok(());
+	}
+
+	let max_merges = cfg.schedule.max_merges_per_run as usize;
+	let pairs = find_near_duplicate_pairs(
+		&mut lock_tx,
+		cfg.schedule.similarity_threshold,
+		i64::from(cfg.schedule.max_merges_per_run).saturating_mul(4),
+		OffsetDateTime::now_utc(),
+	)
+	.await?;
+	let mut merged_away = HashSet::new();
+
+	for pair in pairs {
+		if merged_away.len() >= max_merges {
+			break;
+		}
+		if merged_away.contains(&pair.primary_note_id)
+			|| merged_away.contains(&pair.secondary_note_id)
+		{
+			continue;
+		}
+
+		match merge_pair(state, cfg, &pair).await {
+			Ok(true) => {
+				merged_away.insert(pair.secondary_note_id);
+			},
+			Ok(false) => {},
+			Err(err) => {
+				tracing::warn!(
+					error = %err,
+					primary_note_id = %pair.primary_note_id,
+					secondary_note_id = %pair.secondary_note_id,
+					"Automatic note consolidation failed for a pair."
+				);
+			},
+		}
+	}
+
+	lock_tx.commit().await?;
+
+	if !merged_away.is_empty() {
+		tracing::info!(count = merged_away.len(), "Consolidated near-duplicate notes.");
+	}
+
+	Ok(())
+}
+
+async fn find_near_duplicate_pairs(
+	conn: &mut PgConnection,
+	similarity_threshold: f32,
+	limit: i64,
+	now: OffsetDateTime,
+) -> Result<Vec<NearDuplicatePair>> {
+	let pairs = sqlx::query_as::<_, NearDuplicatePair>(
+		"\
+SELECT
+	a.note_id AS primary_note_id,
+	b.note_id AS secondary_note_id,
+	(1 - (ea.vec <=> eb.vec))::real AS similarity
+FROM memory_notes a
+JOIN note_embeddings ea
+	ON ea.note_id = a.note_id AND ea.embedding_version = a.embedding_version
+JOIN memory_notes b
+	ON b.tenant_id = a.tenant_id
+	AND b.project_id = a.project_id
+	AND b.agent_id = a.agent_id
+	AND b.scope = a.scope
+	AND b.type = a.type
+	AND b.embedding_version = a.embedding_version
+	AND (b.created_at, b.note_id) > (a.created_at, a.note_id)
+JOIN note_embeddings eb
+	ON eb.note_id = b.note_id AND eb.embedding_version = b.embedding_version
+WHERE a.status = 'active'
+	AND b.status = 'active'
+	AND (a.expires_at IS NULL OR a.expires_at > $1)
+	AND (b.expires_at IS NULL OR b.expires_at > $1)
+	AND 1 - (ea.vec <=> eb.vec) >= $2
+ORDER BY similarity DESC, a.note_id, b.note_id
+LIMIT $3",
+	)
+	.bind(now)
+	.bind(f64::from(similarity_threshold))
+	.bind(limit)
+	.fetch_all(conn)
+	.await?;
+
+	Ok(pairs)
+}
+
+/// Merges one candidate pair, returning `false` when it was skipped.
+async fn merge_pair(
+	state: &WorkerState,
+	cfg: &NoteConsolidation,
+	pair: &NearDuplicatePair,
+) -> Result<bool> {
+	let now = OffsetDateTime::now_utc();
+	let Some((primary, secondary)) =
+		fetch_pair(&mut *state.db.pool.acquire().await?, pair, false).await?
+	else {
+		return Ok(false);
+	};
+
+	if !worker::note_is_active(&primary, now) || !worker::note_is_active(&secondary, now) {
+		return Ok(false);
+	}
+
+	let messages = note_merge::build_merge_messages(primary.text.as_str(), secondary.text.as_str());
+	let raw = extractor::extract(&cfg.llm_extractor, &messages)
+		.await
+		.map_err(|err| Error::Message(err.to_string()))?;
+	let text = note_merge::merged_text(&raw).ok_or_else(|| {
+		Error::Validation("Merge extractor response is missing a non-empty text field.".to_string())
+	})?;
+
+	if !merged_text_allowed(text.as_str(), cfg.max_note_chars) {
+		tracing::info!(
+			primary_note_id = %primary.note_id,
+			secondary_note_id = %secondary.note_id,
+			similarity = pair.similarity,
+			"Skipped consolidation because the merged text failed the write gate."
+		);
+
+		return Ok(false);
+	}
+
+	let mut tx = state.db.pool.begin().await?;
+	let Some((mut locked_primary, mut locked_secondary)) = fetch_pair(&mut tx, pair, true).await?
+	else {
+		return Ok(false);
+	};
+
+	if locked_primary.updated_at != primary.updated_at
+		|| locked_secondary.updated_at != secondary.updated_at
+	{
+		return Ok(false);
+	}
+
+	apply_merge(&mut tx, &mut locked_primary, &mut locked_secondary, text, now).await?;
+	tx.commit().await?;
+
+	Ok(true)
+}
+
+pub(super) fn merged_text_allowed(text: &str, max_note_chars: u32) -> bool {
+	english_gate::is_english_natural_language(text)
+		&& text.chars().count() as u32 <= max_note_chars
+		&& !writegate::contains_secrets(text)
+}
+
+async fn fetch_pair(
+	conn: &mut PgConnection,
+	pair: &NearDuplicatePair,
+	for_update: bool,
+) -> Result<Option<(MemoryNote, MemoryNote)>> {
+	let ids = vec![pair.primary_note_id, pair.secondary_note_id];
+	let notes = if for_update {
+		sqlx::query_as::<_, MemoryNote>(
+			"SELECT * FROM memory_notes WHERE note_id = ANY($1::uuid[]) ORDER BY note_id FOR UPDATE",
+		)
+		.bind(ids)
+		.fetch_all(conn)
+		.await?
+	} else {
+		sqlx::query_as::<_, MemoryNote>(
+			"SELECT * FROM memory_notes WHERE note_id = ANY($1::uuid[])",
+		)
+		.bind(ids)
+		.fetch_all(conn)
+		.await?
+	};
+	let mut primary = None;
+	let mut secondary = None;
+
+	for note in notes {
+		if note.note_id == pair.primary_note_id {
+			primary = Some(note);
+		} else {
+			secondary = Some(note);
+		}
+	}
+
+	Ok(primary.zip(secondary))
+}
+
+async fn apply_merge(
+	tx: &mut Transaction<'_, Postgres>,
+	primary: &mut MemoryNote,
+	secondary: &mut MemoryNote,
+	text: String,
+	now: OffsetDateTime,
+) -> Result<()> {
+	let primary_prev = note_snapshot(primary);
+
+	primary.text = text;
+	primary.source_ref = note_merge::merge_source_refs(
+		&primary.source_ref,
+		&secondary.source_ref,
+		secondary.note_id,
+	);
+	primary.importance = primary.importance.max(secondary.importance);
+	primary.confidence = primary.confidence.max(secondary.confidence);
+	primary.expires_at = match (primary.expires_at, secondary.expires_at) {
+		(Some(primary_expiry), Some(secondary_expiry)) =>
+			Some(primary_expiry.max(secondary_expiry)),
+		_ => None,
+	};
+	primary.updated_at = now;
+
+	update_note(tx, primary).await?;
+	insert_version(
+		tx,
+		primary.note_id,
+		"UPDATE",
+		primary_prev,
+		note_snapshot(primary),
+		NOTE_CONSOLIDATION_REASON,
+		now,
+	)
+	.await?;
+
+	let secondary_prev = note_snapshot(secondary);
+
+	secondary.status = "deprecated".to_string();
+	secondary.source_ref = note_merge::mark_merged_into(&secondary.source_ref, primary.note_id);
+	secondary.updated_at = now;
+
+	update_note(tx, secondary).await?;
+	insert_version(
+		tx,
+		secondary.note_id,
+		"DEPRECATE",
+		secondary_prev,
+		note_snapshot(secondary),
+		NOTE_CONSOLIDATION_REASON,
+		now,
+	)
+	.await?;
+	outbox::enqueue_outbox(&mut **tx, primary.note_id, "UPSERT", &primary.embedding_version)
+		.await?;
+	outbox::enqueue_outbox(&mut **tx, secondary.note_id, "DELETE", &secondary.embedding_version)
+		.await?;
+
+	Ok(())
+}
+
+async fn update_note(tx: &mut Transaction<'_, Postgres>, note: &MemoryNote) -> Result<()> {
+	sqlx::query(
+		"\
+UPDATE memory_notes
+SET
+	text = $1,
+	importance = $2,
+	confidence = $3,
+	status = $4,
+	source_ref = $5,
+	expires_at = $6,
+	updated_at = $7
+WHERE note_id = $8",
+	)
+	.bind(note.text.as_str())
+	.bind(note.importance)
+	.bind(note.confidence)
+	.bind(note.status.as_str())
+	.bind(&note.source_ref)
+	.bind(note.expires_at)
+	.bind(note.updated_at)
+	.bind(note.note_id)
+	.execute(&mut **tx)
+	.await?;
+
+	Ok(())
+}
+
+/// Writes a worker-authored version row and its note event, and advances the search result
+/// watermark of the spaces the snapshots name.
+pub(super) async fn insert_version(
+	tx: &mut Transaction<'_, Postgres>,
+	note_id: Uuid,
+	op: &str,
+	prev_snapshot: Value,
+	new_snapshot: Value,
+	reason: &str,
+	now: OffsetDateTime,
+) -> Result<()> {
+	sqlx::query(
+		"\
+WITH version AS (
+	INSERT INTO memory_note_versions (
+		version_id,
+		note_id,
+		op,
+		prev_snapshot,
+		new_snapshot,
+		reason,
+		actor,
+		ts
+	)
+	VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
+)
+INSERT INTO memory_note_events (version_id, note_id, tenant_id, project_id, agent_id, scope, op, ts)
+SELECT
+	$1,
+	$2,
+	$5::jsonb->>'tenant_id',
+	$5::jsonb->>'project_id',
+	$5::jsonb->>'agent_id',
+	$5::jsonb->>'scope',
+	$3,
+	$8",
+	)
+	.bind(Uuid::new_v4())
+	.bind(note_id)
+	.bind(op)
+	.bind(&prev_snapshot)
+	.bind(&new_snapshot)
+	.bind(reason)
+	.bind(WORKER_VERSION_ACTOR)
+	.bind(now)
+	.execute(&mut **tx)
+	.await?;
+
+	search_watermarks::bump_for_snapshots(&mut **tx, &[&prev_snapshot, &new_snapshot], now).await?;
+
+	Ok(())
+}
+
+pub(super) fn note_snapshot(note: &MemoryNote) -> Value {
+	serde_json::json!({
+		"note_id": note.note_id,
+		"tenant_id": note.tenant_id,
+		"project_id": note.project_id,
+		"agent_id": note.agent_id,
+		"scope": note.scope,
+		"type": note.r#type,
+		"key": note.key,
+		"text": note.text,
+		"importance": note.importance,
+		"confidence": note.confidence,
+		"status": note.status,
+		"created_at": note.created_at,
+		"updated_at": note.updated_at,
+		"expires_at": note.expires_at,
+		"embedding_version": note.embedding_version,
+		"source_ref": note.source_ref,
+		"hit_count": note.hit_count,
+		"last_hit_at": note.last_hit_at,
+	})
+}
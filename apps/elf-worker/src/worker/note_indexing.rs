@@ -1,9 +1,11 @@
 mod qdrant_points;
+mod shared_embeddings;
 
 use crate::worker::{
-	self, Db, Error, IndexingOutboxEntry, MemoryNote, NoteFieldRow, OffsetDateTime, PgExecutor,
-	Result, Uuid, WorkerState, embedding, queries,
+	self, Chunk, ChunkRecord, ChunkingConfig, Db, Error, IndexingOutboxEntry, MemoryNote,
+	NoteFieldRow, OffsetDateTime, PgExecutor, Result, Uuid, WorkerState, embedding, queries,
 };
+use elf_storage::chunk_dedup;
 
 pub(super) async fn handle_upsert(state: &WorkerState, job: &IndexingOutboxEntry) -> Result<()> {
 	let note = fetch_note(&state.db, job.note_id).await?;
@@ -29,105 +31,224 @@ pub(super) async fn handle_upsert(state: &WorkerState, job: &IndexingOutboxEntry
 	}
 
 	let fields = fetch_note_fields(&state.db, note.note_id).await?;
-	let chunks = elf_chunking::split_text(&note.text, &state.chunking, &state.tokenizer);
+	let chunking = state.chunking_for_type(&note.r#type);
+	let chunks = match (chunking.as_ref(), state.semantic_threshold_for_type(&note.r#type)) {
+		(Some(cfg), Some(threshold)) => semantic_chunks(state, &note, cfg, threshold).await?,
+		_ => worker::chunk_note_text(&note.text, chunking.as_ref(), &state.tokenizer),
+	};
 
 	if chunks.is_empty() {
 		return Err(Error::Validation("Chunking produced no chunks.".to_string()));
 	}
 
 	let records = worker::build_chunk_records(note.note_id, &chunks)?;
-	let chunk_texts: Vec<String> = records.iter().map(|record| record.text.clone()).collect();
+	let plan =
+		shared_embeddings::plan_chunk_embeddings(&state.db, &records, &job.embedding_version)
+			.await?;
+	let reused_chunks = plan.reused(&records);
 	let field_texts: Vec<String> = fields.iter().map(|field| field.text.clone()).collect();
-	let mut embed_inputs = Vec::with_capacity(chunk_texts.len() + field_texts.len());
+	let mut embed_inputs = plan.missing_texts();
 
-	embed_inputs.extend(chunk_texts);
 	embed_inputs.extend(field_texts);
 
-	let vectors = embedding::embed(&state.embedding, &embed_inputs)
-		.await
-		.map_err(|err| Error::Message(err.to_string()))?;
+	// Fully deduplicated notes without structured fields need no provider call at all.
+	let vectors = if embed_inputs.is_empty() {
+		Vec::new()
+	} else {
+		embedding::embed(state.embedding_for_note(&note.scope, &note.r#type), &embed_inputs)
+			.await
+			.map_err(|err| Error::Message(err.to_string()))?
+	};
 
-	if vectors.len() != records.len() + fields.len() {
+	if vectors.len() != plan.missing_len() + fields.len() {
 		return Err(Error::Validation(format!(
 			"Embedding provider returned {} vectors for {} items.",
 			vectors.len(),
-			records.len() + fields.len()
+			plan.missing_len() + fields.len()
 		)));
 	}
 
-	let (chunk_vectors, field_vectors) = vectors.split_at(records.len());
+	let (embedded_chunk_vectors, field_vectors) = vectors.split_at(plan.missing_len());
+	let chunk_vectors = plan.resolve(&records, embedded_chunk_vectors)?;
 
 	for vector in chunk_vectors.iter().chain(field_vectors.iter()) {
 		worker::validate_vector_dim(vector, state.qdrant.vector_dim)?;
 	}
 
+	persist_note_index(
+		state,
+		&note,
+		&job.embedding_version,
+		&records,
+		&chunk_vectors,
+		&fields,
+		field_vectors,
+	)
+	.await?;
+
+	if reused_chunks > 0 {
+		tracing::debug!(
+			note_id = %note.note_id,
+			reused_chunks,
+			total_chunks = records.len(),
+			"Reused shared chunk embeddings."
+		);
+	}
+
+	// Shared embeddings only save provider calls; every chunk keeps its own point because the
+	// point payload carries this note's scope and ownership filters.
+	qdrant_points::replace_chunks(state, &note, &job.embedding_version, &records, &chunk_vectors)
+		.await?;
+
+	if let Err(err) = worker::evaluate_standing_queries(state, &note, &job.embedding_version).await
 	{
-		let mut tx = state.db.pool.begin().await?;
-
-		queries::delete_note_chunks(&mut *tx, note.note_id).await?;
-
-		for record in &records {
-			queries::insert_note_chunk(
-				&mut *tx,
-				record.chunk_id,
-				note.note_id,
-				record.chunk_index,
-				record.start_offset,
-				record.end_offset,
-				record.text.as_str(),
-				&job.embedding_version,
-			)
-			.await?;
-		}
-		for (record, vector) in records.iter().zip(chunk_vectors.iter()) {
-			let vec_text = worker::format_vector_text(vector);
-
-			queries::insert_note_chunk_embedding(
-				&mut *tx,
-				record.chunk_id,
-				&job.embedding_version,
-				vector.len() as i32,
-				vec_text.as_str(),
-			)
+		tracing::warn!(
+			error = %err,
+			note_id = %note.note_id,
+			"Standing query evaluation failed for indexed note."
+		);
+	}
+
+	Ok(())
+}
+
+pub(super) async fn handle_delete(state: &WorkerState, job: &IndexingOutboxEntry) -> Result<()> {
+	let mut tx = state.db.pool.begin().await?;
+	let released =
+		chunk_dedup::release_note_chunk_refs(&mut *tx, job.note_id, OffsetDateTime::now_utc())
 			.await?;
-		}
 
-		let pooled = worker::mean_pool(chunk_vectors)
-			.ok_or_else(|| Error::Message("Cannot pool empty chunk vectors.".to_string()))?;
+	chunk_dedup::prune_chunk_content_embeddings(&mut *tx, &released).await?;
+	tx.commit().await?;
+	qdrant_points::delete_note_points(state, job.note_id).await?;
 
-		worker::validate_vector_dim(&pooled, state.qdrant.vector_dim)?;
+	Ok(())
+}
+
+/// Embeds each sentence and splits the note where neighbouring sentences drift apart.
+async fn semantic_chunks(
+	state: &WorkerState,
+	note: &MemoryNote,
+	cfg: &ChunkingConfig,
+	threshold: f32,
+) -> Result<Vec<Chunk>> {
+	let spans = elf_chunking::sentence_spans(&note.text);
+
+	if spans.len() < 2 {
+		return Ok(elf_chunking::split_text(&note.text, cfg, &state.tokenizer));
+	}
 
-		insert_embedding_tx(
+	let sentences: Vec<String> =
+		spans.iter().map(|(start, end)| note.text[*start..*end].trim().to_string()).collect();
+	let vectors = embedding::embed(state.embedding_for_note(&note.scope, &note.r#type), &sentences)
+		.await
+		.map_err(|err| Error::Message(err.to_string()))?;
+
+	if vectors.len() != sentences.len() {
+		return Err(Error::Validation(format!(
+			"Embedding provider returned {} vectors for {} sentences.",
+			vectors.len(),
+			sentences.len()
+		)));
+	}
+
+	Ok(elf_chunking::split_text_semantic(
+		&note.text,
+		&spans,
+		&vectors,
+		threshold,
+		cfg,
+		&state.tokenizer,
+	))
+}
+
+async fn persist_note_index(
+	state: &WorkerState,
+	note: &MemoryNote,
+	embedding_version: &str,
+	records: &[ChunkRecord],
+	chunk_vectors: &[Vec<f32>],
+	fields: &[NoteFieldRow],
+	field_vectors: &[Vec<f32>],
+) -> Result<()> {
+	let now = OffsetDateTime::now_utc();
+	let chunking = worker::chunking_snapshot(
+		state.chunking_for_type(&note.r#type).as_ref(),
+		state.semantic_threshold_for_type(&note.r#type),
+	);
+	let mut tx = state.db.pool.begin().await?;
+	// Release the previous chunk set before replacing it so shared embeddings stay counted once
+	// per live chunk row.
+	let released = chunk_dedup::release_note_chunk_refs(&mut *tx, note.note_id, now).await?;
+
+	queries::delete_note_chunks(&mut *tx, note.note_id).await?;
+
+	for record in records {
+		queries::insert_note_chunk(
 			&mut *tx,
+			record.chunk_id,
 			note.note_id,
-			&job.embedding_version,
-			pooled.len() as i32,
-			&pooled,
+			record.chunk_index,
+			record.start_offset,
+			record.end_offset,
+			record.text.as_str(),
+			record.content_hash.as_str(),
+			embedding_version,
+			&chunking,
 		)
 		.await?;
+	}
+	for (record, vector) in records.iter().zip(chunk_vectors.iter()) {
+		let vec_text = worker::format_vector_text(vector);
 
-		for (field, vector) in fields.iter().zip(field_vectors.iter()) {
-			insert_note_field_embedding_tx(
-				&mut *tx,
-				field.field_id,
-				&job.embedding_version,
-				vector.len() as i32,
-				vector,
-			)
-			.await?;
-		}
+		queries::insert_note_chunk_embedding(
+			&mut *tx,
+			record.chunk_id,
+			embedding_version,
+			vector.len() as i32,
+			vec_text.as_str(),
+		)
+		.await?;
+	}
+	for (content_hash, (refs, vector)) in
+		shared_embeddings::reference_counts(records, chunk_vectors)
+	{
+		let vec_text = worker::format_vector_text(vector);
 
-		tx.commit().await?;
+		chunk_dedup::acquire_chunk_content_embedding(
+			&mut *tx,
+			content_hash,
+			embedding_version,
+			vector.len() as i32,
+			vec_text.as_str(),
+			refs,
+			now,
+		)
+		.await?;
 	}
 
-	qdrant_points::replace_chunks(state, &note, &job.embedding_version, &records, chunk_vectors)
+	chunk_dedup::prune_chunk_content_embeddings(&mut *tx, &released).await?;
+
+	let pooled = worker::mean_pool(chunk_vectors)
+		.ok_or_else(|| Error::Message("Cannot pool empty chunk vectors.".to_string()))?;
+
+	worker::validate_vector_dim(&pooled, state.qdrant.vector_dim)?;
+
+	insert_embedding_tx(&mut *tx, note.note_id, embedding_version, pooled.len() as i32, &pooled)
 		.await?;
 
-	Ok(())
-}
+	for (field, vector) in fields.iter().zip(field_vectors.iter()) {
+		insert_note_field_embedding_tx(
+			&mut *tx,
+			field.field_id,
+			embedding_version,
+			vector.len() as i32,
+			vector,
+		)
+		.await?;
+	}
 
-pub(super) async fn handle_delete(state: &WorkerState, job: &IndexingOutboxEntry) -> Result<()> {
-	qdrant_points::delete_note_points(state, job.note_id).await?;
+	tx.commit().await?;
 
 	Ok(())
 }
@@ -141,12 +262,16 @@ pub(super) async fn fetch_note(db: &Db, note_id: Uuid) -> Result<Option<MemoryNo
 	Ok(note)
 }
 
+/// Loads the structured fields to embed for a note.
+///
+/// Answers of `qa` notes are returned as snippets but never embedded, so field retrieval matches on
+/// the question side only.
 pub(super) async fn fetch_note_fields(db: &Db, note_id: Uuid) -> Result<Vec<NoteFieldRow>> {
 	let rows = sqlx::query_as::<_, NoteFieldRow>(
 		"\
 SELECT field_id, text
 FROM memory_note_fields
-WHERE note_id = $1
+WHERE note_id = $1 AND field_kind <> 'answer'
 ORDER BY field_kind ASC, item_index ASC",
 	)
 	.bind(note_id)
@@ -145,6 +145,7 @@ mod tests {
 			start_offset: 10,
 			end_offset: 39,
 			text: "Important deployment note.".to_string(),
+			content_hash: blake3::hash(b"Important deployment note.").to_hex().to_string(),
 		}
 	}
 
@@ -0,0 +1,162 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::worker::{self, ChunkRecord, Db, Error, HashMap, Result};
+use elf_storage::chunk_dedup;
+
+/// Chunk vectors resolved from shared embeddings plus the texts that still need embedding.
+pub(super) struct ChunkEmbeddingPlan {
+	cached: HashMap<String, Vec<f32>>,
+	missing: Vec<(String, String)>,
+}
+impl ChunkEmbeddingPlan {
+	pub(super) fn new(records: &[ChunkRecord], cached: HashMap<String, Vec<f32>>) -> Self {
+		let mut missing = Vec::new();
+		let mut queued = HashSet::new();
+
+		for record in records {
+			if cached.contains_key(&record.content_hash)
+				|| !queued.insert(record.content_hash.as_str())
+			{
+				continue;
+			}
+
+			missing.push((record.content_hash.clone(), record.text.clone()));
+		}
+
+		Self { cached, missing }
+	}
+
+	/// Number of chunk records served by a previously stored shared embedding.
+	pub(super) fn reused(&self, records: &[ChunkRecord]) -> usize {
+		records.iter().filter(|record| self.cached.contains_key(&record.content_hash)).count()
+	}
+
+	/// Distinct chunk texts that must be sent to the embedding provider, in first-seen order.
+	pub(super) fn missing_texts(&self) -> Vec<String> {
+		self.missing.iter().map(|(_, text)| text.clone()).collect()
+	}
+
+	pub(super) fn missing_len(&self) -> usize {
+		self.missing.len()
+	}
+
+	/// Returns one vector per record, combining cached vectors with freshly embedded ones.
+	pub(super) fn resolve(
+		mut self,
+		records: &[ChunkRecord],
+		embedded: &[Vec<f32>],
+	) -> Result<Vec<Vec<f32>>> {
+		for ((hash, _), vector) in self.missing.into_iter().zip(embedded.iter()) {
+			self.cached.insert(hash, vector.clone());
+		}
+
+		records
+			.iter()
+			.map(|record| {
+				self.cached.get(&record.content_hash).cloned().ok_or_else(|| {
+					Error::Validation(format!(
+						"Missing embedding for chunk {} content hash.",
+						record.chunk_id
+					))
+				})
+			})
+			.collect()
+	}
+}
+
+/// Loads stored shared embeddings for the records and plans which chunk texts to embed.
+pub(super) async fn plan_chunk_embeddings(
+	db: &Db,
+	records: &[ChunkRecord],
+	embedding_version: &str,
+) -> Result<ChunkEmbeddingPlan> {
+	let mut hashes = records.iter().map(|record| record.content_hash.clone()).collect::<Vec<_>>();
+
+	hashes.sort();
+	hashes.dedup();
+
+	let rows =
+		chunk_dedup::fetch_chunk_content_embeddings(&db.pool, embedding_version, &hashes).await?;
+	let mut cached = HashMap::with_capacity(rows.len());
+
+	for row in rows {
+		cached.insert(row.content_hash, worker::parse_vector_text(row.vec_text.as_str())?);
+	}
+
+	Ok(ChunkEmbeddingPlan::new(records, cached))
+}
+
+/// Groups records by content hash, returning the reference count and vector for each hash.
+pub(super) fn reference_counts<'a>(
+	records: &'a [ChunkRecord],
+	vectors: &'a [Vec<f32>],
+) -> BTreeMap<&'a str, (i32, &'a [f32])> {
+	let mut counts = BTreeMap::new();
+
+	for (record, vector) in records.iter().zip(vectors.iter()) {
+		counts
+			.entry(record.content_hash.as_str())
+			.and_modify(|(refs, _)| *refs += 1)
+			.or_insert((1, vector.as_slice()));
+	}
+
+	counts
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::worker::{
+		ChunkRecord, HashMap, Uuid, format_vector_text, note_indexing::shared_embeddings,
+		parse_vector_text,
+	};
+
+	fn record(chunk_index: i32, text: &str) -> ChunkRecord {
+		ChunkRecord {
+			chunk_id: Uuid::new_v4(),
+			chunk_index,
+			start_offset: 0,
+			end_offset: text.len() as i32,
+			text: text.to_string(),
+			content_hash: blake3::hash(text.as_bytes()).to_hex().to_string(),
+		}
+	}
+
+	#[test]
+	fn plan_embeds_each_new_text_once_and_reuses_stored_vectors() {
+		let records = vec![
+			record(0, "Shared boilerplate footer."),
+			record(1, "Unique deployment detail."),
+			record(2, "Shared boilerplate footer."),
+			record(3, "Stored license header."),
+		];
+		let cached = HashMap::from([(records[3].content_hash.clone(), vec![0.5, 0.5])]);
+		let plan = shared_embeddings::ChunkEmbeddingPlan::new(&records, cached);
+
+		assert_eq!(plan.reused(&records), 1);
+		assert_eq!(
+			plan.missing_texts(),
+			vec!["Shared boilerplate footer.".to_string(), "Unique deployment detail.".to_string()]
+		);
+
+		let vectors = plan
+			.resolve(&records, &[vec![1.0, 0.0], vec![0.0, 1.0]])
+			.expect("all records should resolve");
+
+		assert_eq!(vectors, vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 0.0], vec![0.5, 0.5]]);
+
+		let counts = shared_embeddings::reference_counts(&records, &vectors);
+
+		assert_eq!(counts.len(), 3);
+		assert_eq!(counts[records[0].content_hash.as_str()].0, 2);
+		assert_eq!(counts[records[1].content_hash.as_str()].0, 1);
+	}
+
+	#[test]
+	fn vector_text_round_trips() {
+		let vector = vec![0.25_f32, -1.5, 3.0];
+		let parsed = parse_vector_text(&format_vector_text(&vector)).expect("vector should parse");
+
+		assert_eq!(parsed, vector);
+		assert!(parse_vector_text("0.1,0.2").is_err());
+	}
+}
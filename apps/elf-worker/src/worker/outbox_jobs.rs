@@ -1,22 +1,71 @@
+use futures::future;
+use tracing::Instrument;
+
 use crate::worker::{
-	self, CLAIM_LEASE_SECONDS, CONSOLIDATION_JOB_LEASE_SECONDS, Db, Error, OffsetDateTime, Result,
-	TRACE_OUTBOX_LEASE_SECONDS, ToString, Uuid, WorkerState, consolidation, doc_outbox, outbox,
+	self, CLAIM_LEASE_SECONDS, CONSOLIDATION_JOB_LEASE_SECONDS, Db, Error, IndexingOutboxEntry,
+	IndexingOutboxStats, OUTBOX_MAX_ATTEMPTS, OffsetDateTime, Result, TRACE_OUTBOX_LEASE_SECONDS,
+	ToString, Uuid, WorkerState, consolidation, doc_outbox, outbox,
 };
 
 pub(super) async fn process_indexing_outbox_once(state: &WorkerState) -> Result<()> {
+	process_indexing_outbox_batch(state, 1).await.map(|_| ())
+}
+
+/// Claims up to `limit` due note-indexing jobs and runs them concurrently.
+///
+/// The claim query hands out only the oldest open job of each note, so jobs in one batch always
+/// belong to different notes and per-note order is kept.
+pub(super) async fn process_indexing_outbox_batch(
+	state: &WorkerState,
+	limit: usize,
+) -> Result<IndexingOutboxStats> {
 	let now = OffsetDateTime::now_utc();
-	let job = outbox::claim_next_indexing_outbox_job(&state.db, now, CLAIM_LEASE_SECONDS).await?;
-	let Some(job) = job else { return Ok(()) };
-	let result = match job.op.as_str() {
-		"UPSERT" => worker::handle_upsert(state, &job).await,
-		"DELETE" => worker::handle_delete(state, &job).await,
-		other => Err(Error::Validation(format!("Unsupported outbox op: {other}."))),
-	};
+	let mut jobs = Vec::new();
+
+	while jobs.len() < limit.max(1) {
+		let Some(job) =
+			outbox::claim_next_indexing_outbox_job(&state.db, now, CLAIM_LEASE_SECONDS).await?
+		else {
+			break;
+		};
+
+		jobs.push(job);
+	}
+
+	let results = future::join_all(jobs.iter().map(|job| run_indexing_job(state, job))).await;
+	let mut stats = IndexingOutboxStats::default();
+
+	for (job, result) in jobs.iter().zip(results) {
+		stats.record(job.attempts, result?);
+	}
+
+	Ok(stats)
+}
+
+/// Runs one claimed note-indexing job and records its outcome; returns whether it succeeded.
+async fn run_indexing_job(state: &WorkerState, job: &IndexingOutboxEntry) -> Result<bool> {
+	let span = tracing::info_span!(
+		"worker.indexing_outbox",
+		outbox_id = %job.outbox_id,
+		note_id = %job.note_id,
+		op = %job.op,
+	);
+	let result = async {
+		match job.op.as_str() {
+			"UPSERT" => worker::handle_upsert(state, job).await,
+			"DELETE" => worker::handle_delete(state, job).await,
+			other => Err(Error::Validation(format!("Unsupported outbox op: {other}."))),
+		}
+	}
+	.instrument(span)
+	.await;
 
 	match result {
 		Ok(()) => {
 			outbox::mark_indexing_outbox_done(&state.db, job.outbox_id, OffsetDateTime::now_utc())
 				.await?;
+
+			Ok(true)
 		},
 		Err(err) => {
 			tracing::error!(
@@ -27,10 +76,10 @@ pub(super) async fn process_indexing_outbox_once(state: &WorkerState) -> Result<
 			);
 
 			mark_failed(&state.db, job.outbox_id, job.attempts, &err).await?;
+
+			Ok(false)
 		},
 	}
-
-	Ok(())
 }
 
 pub(super) async fn process_doc_indexing_outbox_once(state: &WorkerState) -> Result<()> {
@@ -38,11 +87,22 @@ pub(super) async fn process_doc_indexing_outbox_once(state: &WorkerState) -> Res
 	let job =
 		doc_outbox::claim_next_doc_indexing_outbox_job(&state.db, now, CLAIM_LEASE_SECONDS).await?;
 	let Some(job) = job else { return Ok(()) };
-	let result = match job.op.as_str() {
-		"UPSERT" => worker::handle_doc_upsert(state, &job).await,
-		"DELETE" => worker::handle_doc_delete(state, &job).await,
-		other => Err(Error::Validation(format!("Unsupported doc outbox op: {other}."))),
-	};
+	let span = tracing::info_span!(
+		"worker.doc_indexing_outbox",
+		outbox_id = %job.outbox_id,
+		doc_id = %job.doc_id,
+		chunk_id = %job.chunk_id,
+		op = %job.op,
+	);
+	let result = async {
+		match job.op.as_str() {
+			"UPSERT" => worker::handle_doc_upsert(state, &job).await,
+			"DELETE" => worker::handle_doc_delete(state, &job).await,
+			other => Err(Error::Validation(format!("Unsupported doc outbox op: {other}."))),
+		}
+	}
+	.instrument(span)
+	.await;
 
 	match result {
 		Ok(()) => {
@@ -74,7 +134,12 @@ pub(super) async fn process_trace_outbox_once(state: &WorkerState) -> Result<()>
 	let job =
 		outbox::claim_next_trace_outbox_job(&state.db, now, TRACE_OUTBOX_LEASE_SECONDS).await?;
 	let Some(job) = job else { return Ok(()) };
-	let result = worker::handle_trace_job(&state.db, &job).await;
+	let span = tracing::info_span!(
+		"worker.trace_outbox",
+		outbox_id = %job.outbox_id,
+		trace_id = %job.trace_id,
+	);
+	let result = worker::handle_trace_job(&state.db, &job).instrument(span).await;
 
 	match result {
 		Ok(()) => {
@@ -124,6 +189,7 @@ pub(super) async fn process_consolidation_run_job_once(state: &WorkerState) -> R
 	Ok(())
 }
 
+/// Schedules a retry for a failed note-indexing job, or dead-letters it once retries run out.
 pub(super) async fn mark_failed(
 	db: &Db,
 	outbox_id: Uuid,
@@ -136,6 +202,25 @@ pub(super) async fn mark_failed(
 	let available_at = now + backoff;
 	let error_text = worker::sanitize_outbox_error(&err.to_string());
 
+	if next_attempts >= OUTBOX_MAX_ATTEMPTS {
+		tracing::warn!(
+			outbox_id = %outbox_id,
+			attempts = next_attempts,
+			"Outbox job exhausted its retries and was dead-lettered."
+		);
+
+		outbox::mark_indexing_outbox_dead_letter(
+			db,
+			outbox_id,
+			next_attempts,
+			error_text.as_str(),
+			now,
+		)
+		.await?;
+
+		return Ok(());
+	}
+
 	outbox::mark_indexing_outbox_failed(
 		db,
 		outbox_id,
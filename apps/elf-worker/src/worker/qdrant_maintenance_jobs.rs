@@ -0,0 +1,160 @@
+use time::Duration;
+
+use crate::worker::{
+	self, ELF_QDRANT_MAINTENANCE_ALERT_SCHEMA_V1, Error, OffsetDateTime,
+	QDRANT_MAINTENANCE_ALERT_TIMEOUT_MS, QDRANT_MAINTENANCE_LOCK_ID, Result, WorkerState,
+	format_timestamp,
+};
+use elf_config::QdrantMaintenance;
+use elf_storage::{
+	models::QdrantMaintenanceRun,
+	qdrant_maintenance::{
+		self, COLLECTION_DOCS, COLLECTION_NOTES, QdrantMaintenanceOperation,
+		QdrantMaintenanceRunInsert,
+	},
+};
+
+/// Runs every scheduled Qdrant maintenance operation whose interval has elapsed.
+///
+/// A transaction-scoped advisory lock keeps concurrent workers from running the same schedule
+/// twice; a worker that cannot take the lock skips the pass.
+pub(super) async fn run_due_qdrant_maintenance(state: &WorkerState) -> Result<()> {
+	let Some(cfg) = state.qdrant_maintenance.as_ref().filter(|cfg| cfg.enabled) else {
+		return Ok(());
+	};
+	let mut tx = state.db.pool.begin().await?;
+	let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+		.bind(QDRANT_MAINTENANCE_LOCK_ID)
+		.fetch_one(&mut *tx)
+		.await?;
+
+	if !locked {
+		return Ok(());
+	}
+
+	for operation in QdrantMaintenanceOperation::SCHEDULED {
+		let Some(interval_seconds) = scheduled_interval(cfg, operation) else { continue };
+		let last_started_at =
+			qdrant_maintenance::latest_scheduled_run_started_at(&mut *tx, operation).await?;
+
+		if !is_due(last_started_at, interval_seconds, OffsetDateTime::now_utc()) {
+			continue;
+		}
+
+		for (collection, store) in
+			[(COLLECTION_NOTES, &state.qdrant), (COLLECTION_DOCS, &state.docs_qdrant)]
+		{
+			let started_at = OffsetDateTime::now_utc();
+			let (detail, error) = qdrant_maintenance::run_qdrant_maintenance(
+				&state.db.pool,
+				store,
+				collection,
+				operation,
+				cfg,
+				started_at,
+			)
+			.await;
+			let finished_at = OffsetDateTime::now_utc();
+			let mut run = QdrantMaintenanceRunInsert {
+				operation,
+				collection,
+				trigger: "scheduled",
+				detail: &detail,
+				error: error.as_deref(),
+				alert_status: "none",
+				started_at,
+				finished_at,
+			};
+
+			if let Some(error) = error.as_deref() {
+				tracing::warn!(
+					error,
+					operation = operation.as_str(),
+					collection,
+					"Scheduled Qdrant maintenance failed."
+				);
+
+				run.alert_status = match cfg.alert_webhook.as_deref() {
+					Some(url) => match send_alert(url, &run).await {
+						Ok(()) => "sent",
+						Err(err) => {
+							tracing::warn!(error = %err, "Qdrant maintenance alert failed.");
+
+							"failed"
+						},
+					},
+					None => "not_configured",
+				};
+			}
+
+			let recorded: QdrantMaintenanceRun =
+				qdrant_maintenance::insert_qdrant_maintenance_run(&mut *tx, &run).await?;
+
+			tracing::info!(
+				run_id = %recorded.run_id,
+				operation = operation.as_str(),
+				collection,
+				status = recorded.status.as_str(),
+				"Scheduled Qdrant maintenance finished."
+			);
+		}
+	}
+
+	tx.commit().await?;
+
+	Ok(())
+}
+
+pub(super) fn scheduled_interval(
+	cfg: &QdrantMaintenance,
+	operation: QdrantMaintenanceOperation,
+) -> Option<u64> {
+	match operation {
+		QdrantMaintenanceOperation::Optimize => cfg.optimize_interval_seconds,
+		QdrantMaintenanceOperation::Snapshot => cfg.snapshot_interval_seconds,
+		QdrantMaintenanceOperation::VerifyCounts => cfg.verify_interval_seconds,
+		QdrantMaintenanceOperation::RestoreSnapshot => None,
+	}
+}
+
+pub(super) fn is_due(
+	last_started_at: Option<OffsetDateTime>,
+	interval_seconds: u64,
+	now: OffsetDateTime,
+) -> bool {
+	let Some(last_started_at) = last_started_at else { return true };
+	let interval = Duration::seconds(i64::try_from(interval_seconds).unwrap_or(i64::MAX));
+
+	now - last_started_at >= interval
+}
+
+async fn send_alert(url: &str, run: &QdrantMaintenanceRunInsert<'_>) -> Result<()> {
+	let client = reqwest::Client::builder()
+		.timeout(worker::to_std_duration(Duration::milliseconds(
+			QDRANT_MAINTENANCE_ALERT_TIMEOUT_MS,
+		)))
+		.build()
+		.map_err(|err| Error::Message(err.to_string()))?;
+	let body = serde_json::json!({
+		"schema": ELF_QDRANT_MAINTENANCE_ALERT_SCHEMA_V1,
+		"operation": run.operation.as_str(),
+		"collection": run.collection,
+		"error": run.error,
+		"detail": run.detail,
+		"started_at": format_timestamp(run.started_at)?,
+		"finished_at": format_timestamp(run.finished_at)?,
+	});
+	let response = client
+		.post(url)
+		.json(&body)
+		.send()
+		.await
+		.map_err(|err| Error::Message(format!("Alert request failed: {err}.")))?;
+	let status = response.status();
+
+	if !status.is_success() {
+		return Err(Error::Message(format!("Alert webhook responded with status {status}.")));
+	}
+
+	Ok(())
+}
@@ -1,17 +1,56 @@
+use std::sync::Arc;
+
 use time::Duration;
+use tokio::sync::Notify;
 
 use crate::worker::{
-	self, OffsetDateTime, POLL_INTERVAL_MS, Result, TRACE_CLEANUP_INTERVAL_SECONDS, WorkerState,
+	self, IndexingOutboxStats, LifecycleRunReport, OUTBOX_THROUGHPUT_LOG_INTERVAL_SECONDS,
+	OffsetDateTime, POLL_INTERVAL_MS, QDRANT_MAINTENANCE_CHECK_INTERVAL_SECONDS, Result,
+	TRACE_CLEANUP_BATCH_SIZE, TRACE_CLEANUP_INTERVAL_SECONDS, WorkerState,
 };
 
-/// Runs the worker polling loop for note, document, and trace outboxes.
+/// Runs the worker polling loop for note, document, and trace outboxes, standing-query
+/// notifications, external URL snapshots, scheduled Qdrant maintenance, automatic note
+/// consolidation, importance re-scoring, and the cron-scheduled lifecycle runner.
 pub async fn run_worker(state: WorkerState) -> Result<()> {
+	run_worker_with_wakeup(state, None).await
+}
+
+/// Runs the worker polling loop and, when `wakeup` is set, starts the next pass as soon as it is
+/// notified instead of waiting out the poll interval.
+///
+/// In-process deployments notify `wakeup` after writes that enqueue outbox jobs; the poll interval
+/// still covers jobs enqueued by other processes and retries that come due later.
+///
+/// Each pass runs up to `state.indexing_concurrency` note-indexing jobs at once. While passes keep
+/// filling that limit, the next pass starts without waiting so a backlog drains continuously.
+pub async fn run_worker_with_wakeup(state: WorkerState, wakeup: Option<Arc<Notify>>) -> Result<()> {
 	let mut last_trace_cleanup = OffsetDateTime::now_utc();
+	let mut last_maintenance_check: Option<OffsetDateTime> = None;
+	let mut last_note_consolidation = OffsetDateTime::now_utc();
+	let mut last_importance_rescore = OffsetDateTime::now_utc();
+	let mut next_lifecycle_run = state
+		.lifecycle
+		.as_ref()
+		.and_then(|cfg| worker::next_cron_run(&cfg.schedule, OffsetDateTime::now_utc()));
+	let mut throughput = IndexingOutboxStats::default();
+	let mut throughput_since = OffsetDateTime::now_utc();
 
 	loop {
-		if let Err(err) = worker::process_indexing_outbox_once(&state).await {
-			tracing::error!(error = %err, "Indexing outbox processing failed.");
-		}
+		let indexing_backlogged =
+			match worker::process_indexing_outbox_batch(&state, state.indexing_concurrency).await {
+				Ok(stats) => {
+					throughput.merge(stats);
+
+					stats.processed as usize >= state.indexing_concurrency
+				},
+				Err(err) => {
+					tracing::error!(error = %err, "Indexing outbox processing failed.");
+
+					false
+				},
+			};
+
 		if let Err(err) = worker::process_doc_indexing_outbox_once(&state).await {
 			tracing::error!(error = %err, "Doc indexing outbox processing failed.");
 		}
@@ -21,28 +60,108 @@ pub async fn run_worker(state: WorkerState) -> Result<()> {
 		if let Err(err) = worker::process_consolidation_run_job_once(&state).await {
 			tracing::error!(error = %err, "Consolidation run job processing failed.");
 		}
+		if let Err(err) = worker::process_standing_query_notifications_once(&state).await {
+			tracing::error!(error = %err, "Standing query notification processing failed.");
+		}
+		if let Err(err) = worker::process_url_snapshot_once(&state).await {
+			tracing::error!(error = %err, "External URL snapshot processing failed.");
+		}
 
 		let now = OffsetDateTime::now_utc();
 
-		if now - last_trace_cleanup >= Duration::seconds(TRACE_CLEANUP_INTERVAL_SECONDS) {
-			if let Err(err) = worker::purge_expired_trace_candidates(&state.db, now).await {
-				tracing::error!(error = %err, "Search trace candidate cleanup failed.");
+		if now - throughput_since >= Duration::seconds(OUTBOX_THROUGHPUT_LOG_INTERVAL_SECONDS) {
+			log_indexing_throughput(throughput, now - throughput_since, state.indexing_concurrency);
+
+			throughput = IndexingOutboxStats::default();
+			throughput_since = now;
+		}
+		// A scheduled lifecycle runner prunes the same rows, so the interval pass only runs without
+		// one.
+		if state.lifecycle.is_none()
+			&& now - last_trace_cleanup >= Duration::seconds(TRACE_CLEANUP_INTERVAL_SECONDS)
+		{
+			let mut report = LifecycleRunReport::default();
+
+			worker::prune_expired(&state, now, TRACE_CLEANUP_BATCH_SIZE, &mut report).await;
+
+			for error in &report.errors {
+				tracing::error!(error, "Expired row cleanup failed.");
+			}
+
+			last_trace_cleanup = now;
+		}
+		if last_maintenance_check.is_none_or(|last| {
+			now - last >= Duration::seconds(QDRANT_MAINTENANCE_CHECK_INTERVAL_SECONDS)
+		}) {
+			if let Err(err) = worker::run_due_qdrant_maintenance(&state).await {
+				tracing::error!(error = %err, "Scheduled Qdrant maintenance failed.");
 			}
-			if let Err(err) = worker::purge_expired_traces(&state.db, now).await {
-				tracing::error!(error = %err, "Search trace cleanup failed.");
-			} else {
-				last_trace_cleanup = now;
+
+			last_maintenance_check = Some(now);
+		}
+		if let Some(cfg) = state.note_consolidation.as_ref().filter(|cfg| cfg.schedule.enabled)
+			&& now - last_note_consolidation
+				>= Duration::seconds(cfg.schedule.interval_seconds as i64)
+		{
+			if let Err(err) = worker::run_note_consolidation(&state).await {
+				tracing::error!(error = %err, "Automatic note consolidation failed.");
 			}
-			if let Err(err) = worker::purge_expired_cache(&state.db, now).await {
-				tracing::error!(error = %err, "LLM cache cleanup failed.");
+
+			last_note_consolidation = now;
+		}
+		if let Some(cfg) = state.importance_rescore.as_ref().filter(|cfg| cfg.enabled)
+			&& now - last_importance_rescore >= Duration::seconds(cfg.interval_seconds as i64)
+		{
+			if let Err(err) = worker::run_importance_rescore(&state).await {
+				tracing::error!(error = %err, "Importance re-scoring failed.");
 			}
-			if let Err(err) = worker::purge_expired_search_sessions(&state.db, now).await {
-				tracing::error!(error = %err, "Search session cleanup failed.");
+
+			last_importance_rescore = now;
+		}
+		if let Some(cfg) = state.lifecycle.as_ref()
+			&& next_lifecycle_run.is_some_and(|at| now >= at)
+		{
+			if let Err(err) = worker::run_lifecycle(&state, cfg).await {
+				tracing::error!(error = %err, "Scheduled lifecycle run failed.");
 			}
+
+			next_lifecycle_run = worker::next_cron_run(&cfg.schedule, now);
+		}
+
+		if indexing_backlogged {
+			continue;
+		}
+
+		let poll =
+			tokio::time::sleep(worker::to_std_duration(Duration::milliseconds(POLL_INTERVAL_MS)));
+
+		match wakeup.as_deref() {
+			Some(wakeup) => {
+				tokio::select! {
+					_ = poll => {},
+					_ = wakeup.notified() => {},
+				}
+			},
+			None => poll.await,
 		}
+	}
+}
 
-		tokio::time::sleep(worker::to_std_duration(Duration::milliseconds(POLL_INTERVAL_MS))).await;
+fn log_indexing_throughput(stats: IndexingOutboxStats, elapsed: Duration, concurrency: usize) {
+	if stats.processed == 0 {
+		return;
 	}
+
+	let jobs_per_second = f64::from(stats.processed) / elapsed.as_seconds_f64().max(1.0);
+
+	tracing::info!(
+		processed = stats.processed,
+		failed = stats.failed,
+		retried = stats.retried,
+		jobs_per_second,
+		concurrency,
+		"Indexing outbox throughput."
+	);
 }
 
 /// Processes at most one due job from each worker-owned queue.
@@ -51,6 +170,8 @@ pub async fn process_once(state: &WorkerState) -> Result<()> {
 	worker::process_doc_indexing_outbox_once(state).await?;
 	worker::process_trace_outbox_once(state).await?;
 	worker::process_consolidation_run_job_once(state).await?;
+	worker::process_standing_query_notifications_once(state).await?;
+	worker::process_url_snapshot_once(state).await?;
 
 	Ok(())
 }
@@ -0,0 +1,221 @@
+use std::slice;
+
+use time::Duration;
+
+use crate::worker::{
+	self, ELF_STANDING_QUERY_NOTIFICATION_SCHEMA_V1, EgressClient, EgressError, Error,
+	MAX_OUTBOX_ERROR_CHARS, MemoryNote, ORG_PROJECT_ID, OffsetDateTime, Result,
+	STANDING_QUERY_NOTIFY_BATCH, STANDING_QUERY_NOTIFY_LEASE_SECONDS,
+	STANDING_QUERY_NOTIFY_MAX_ATTEMPTS, STANDING_QUERY_WEBHOOK_TIMEOUT_MS, WorkerState, embedding,
+	format_timestamp, sanitize_outbox_error, standing_queries,
+};
+use elf_storage::{
+	models::{StandingQuery, StandingQueryNotification},
+	standing_queries::StandingQueryMatchInsert,
+};
+
+/// Records matches for every standing query whose top-k the newly indexed note enters.
+pub(super) async fn evaluate_standing_queries(
+	state: &WorkerState,
+	note: &MemoryNote,
+	embedding_version: &str,
+) -> Result<()> {
+	let queries = standing_queries::list_active_standing_queries_for_note(
+		&state.db.pool,
+		note.tenant_id.as_str(),
+		note.project_id.as_str(),
+		ORG_PROJECT_ID,
+		note.scope.as_str(),
+	)
+	.await?;
+
+	for query in queries {
+		if let Err(err) = evaluate_standing_query(state, &query, note, embedding_version).await {
+			tracing::warn!(
+				error = %err,
+				standing_query_id = %query.standing_query_id,
+				note_id = %note.note_id,
+				"Standing query evaluation failed."
+			);
+		}
+	}
+
+	Ok(())
+}
+
+async fn evaluate_standing_query(
+	state: &WorkerState,
+	query: &StandingQuery,
+	note: &MemoryNote,
+	embedding_version: &str,
+) -> Result<()> {
+	ensure_query_embedding(state, query, note, embedding_version).await?;
+
+	let now = OffsetDateTime::now_utc();
+	let Some(rank) = standing_queries::rank_note_for_standing_query(
+		&state.db.pool,
+		query,
+		note.note_id,
+		embedding_version,
+		ORG_PROJECT_ID,
+		now,
+	)
+	.await?
+	else {
+		return Ok(());
+	};
+
+	if rank.rank > query.top_k {
+		return Ok(());
+	}
+
+	let recorded = standing_queries::insert_standing_query_match(
+		&state.db.pool,
+		&StandingQueryMatchInsert {
+			standing_query_id: query.standing_query_id,
+			note_id: note.note_id,
+			embedding_version,
+			rank,
+			notify: query.webhook_url.is_some(),
+			now,
+		},
+	)
+	.await?;
+
+	if recorded {
+		tracing::info!(
+			standing_query_id = %query.standing_query_id,
+			note_id = %note.note_id,
+			rank = rank.rank,
+			"Standing query matched a newly indexed note."
+		);
+	}
+
+	Ok(())
+}
+
+async fn ensure_query_embedding(
+	state: &WorkerState,
+	query: &StandingQuery,
+	note: &MemoryNote,
+	embedding_version: &str,
+) -> Result<()> {
+	if standing_queries::has_standing_query_embedding(
+		&state.db.pool,
+		query.standing_query_id,
+		embedding_version,
+	)
+	.await?
+	{
+		return Ok(());
+	}
+
+	// Embed with the provider that produced `embedding_version`, so the query and note vectors
+	// share one space.
+	let provider = state.embedding_for_note(note.scope.as_str(), note.r#type.as_str());
+	let vectors = embedding::embed(provider, slice::from_ref(&query.query))
+		.await
+		.map_err(|err| Error::Message(err.to_string()))?;
+	let vector = vectors.into_iter().next().ok_or_else(|| {
+		Error::Validation("Embedding provider returned no vector for standing query.".to_string())
+	})?;
+
+	worker::validate_vector_dim(&vector, state.qdrant.vector_dim)?;
+	standing_queries::upsert_standing_query_embedding(
+		&state.db.pool,
+		query.standing_query_id,
+		embedding_version,
+		vector.len() as i32,
+		worker::format_vector_text(&vector).as_str(),
+	)
+	.await?;
+
+	Ok(())
+}
+
+pub(super) async fn process_standing_query_notifications_once(state: &WorkerState) -> Result<()> {
+	let now = OffsetDateTime::now_utc();
+	let lease_until = now + Duration::seconds(STANDING_QUERY_NOTIFY_LEASE_SECONDS);
+	let notifications = standing_queries::claim_standing_query_notifications(
+		&state.db.pool,
+		now,
+		lease_until,
+		STANDING_QUERY_NOTIFY_BATCH,
+	)
+	.await?;
+
+	if notifications.is_empty() {
+		return Ok(());
+	}
+
+	let client = EgressClient::new(
+		worker::to_std_duration(Duration::milliseconds(STANDING_QUERY_WEBHOOK_TIMEOUT_MS)),
+		None,
+		state.egress.as_ref(),
+	);
+
+	for notification in notifications {
+		match deliver_notification(&client, &notification).await {
+			Ok(()) => {
+				standing_queries::mark_standing_query_notification_delivered(
+					&state.db.pool,
+					notification.match_id,
+					OffsetDateTime::now_utc(),
+				)
+				.await?;
+			},
+			Err(err) => {
+				let attempts = notification.notify_attempts.saturating_add(1);
+				let retryable = matches!(err, EgressError::Unreachable(_));
+				let retry_at = (retryable && attempts < STANDING_QUERY_NOTIFY_MAX_ATTEMPTS)
+					.then(|| OffsetDateTime::now_utc() + worker::backoff_for_attempt(attempts));
+				let error = sanitize_outbox_error(&err.to_string())
+					.chars()
+					.take(MAX_OUTBOX_ERROR_CHARS)
+					.collect::<String>();
+
+				tracing::warn!(
+					error = %error,
+					match_id = %notification.match_id,
+					attempts,
+					"Standing query webhook delivery failed."
+				);
+				standing_queries::mark_standing_query_notification_failed(
+					&state.db.pool,
+					notification.match_id,
+					error.as_str(),
+					retry_at,
+				)
+				.await?;
+			},
+		}
+	}
+
+	Ok(())
+}
+
+/// Posts one match to its webhook. A refused target is final; other failures may be retried.
+async fn deliver_notification(
+	client: &EgressClient,
+	notification: &StandingQueryNotification,
+) -> std::result::Result<(), EgressError> {
+	let matched_at = format_timestamp(notification.created_at)
+		.map_err(|err| EgressError::Refused(err.to_string()))?;
+	let body = serde_json::json!({
+		"schema": ELF_STANDING_QUERY_NOTIFICATION_SCHEMA_V1,
+		"standing_query_id": notification.standing_query_id,
+		"match_id": notification.match_id,
+		"note_id": notification.note_id,
+		"rank": notification.rank,
+		"score": notification.score,
+		"matched_at": matched_at,
+	});
+	let response = client.post_json(notification.webhook_url.as_str(), &body).await?;
+	let status = response.status();
+
+	if !status.is_success() {
+		return Err(EgressError::Unreachable(format!("Webhook responded with status {status}.")));
+	}
+
+	Ok(())
+}
@@ -1,7 +1,15 @@
+use std::collections::HashMap;
+
 use serde_json;
-use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use time::{Duration, OffsetDateTime, format_description::well_known::Rfc3339};
 
 use crate::worker::{self};
+use elf_chunking::ChunkingConfig;
+use elf_config::{
+	ChunkingTypeOverride, CronSchedule, LifecycleImportanceRescore, QdrantMaintenance,
+	SecurityEgress,
+};
+use elf_storage::qdrant_maintenance::QdrantMaintenanceOperation;
 
 #[test]
 fn pooled_vector_is_mean_of_chunks() {
@@ -111,3 +119,278 @@ fn project_doc_ref_fields_gates_optional_ref_fields_by_doc_type() {
 	assert!(dev_projection.2.is_none());
 	assert_eq!(dev_projection.3.as_deref(), Some("org/repo"));
 }
+
+#[test]
+fn robots_allows_prefers_longest_match_for_matching_agent_group() {
+	let robots_txt = "\
+User-agent: *
+Disallow: /
+
+User-agent: elf-snapshot
+Disallow: /private
+Allow: /private/public$
+";
+
+	assert!(worker::robots_allows(robots_txt, "elf-snapshot/1.0", "/docs/page"));
+	assert!(!worker::robots_allows(robots_txt, "elf-snapshot/1.0", "/private/notes"));
+	assert!(worker::robots_allows(robots_txt, "elf-snapshot/1.0", "/private/public"));
+	assert!(!worker::robots_allows(robots_txt, "elf-snapshot/1.0", "/private/public/more"));
+	assert!(!worker::robots_allows(robots_txt, "other-bot/2.0", "/docs/page"));
+}
+
+#[test]
+fn robots_allows_supports_wildcards_and_missing_groups() {
+	let robots_txt = "User-agent: *\nDisallow: /*.pdf$\nDisallow:\n";
+
+	assert!(!worker::robots_allows(robots_txt, "elf", "/files/report.pdf"));
+	assert!(worker::robots_allows(robots_txt, "elf", "/files/report.pdf.html"));
+	assert!(worker::robots_allows("User-agent: other\nDisallow: /\n", "elf", "/anything"));
+	assert!(worker::robots_allows("", "elf", "/anything"));
+}
+
+#[test]
+fn html_to_text_extracts_title_and_visible_text() {
+	let html = "\
+<html><head><title>Release &amp; Notes</title><style>p { color: red; }</style></head>
+<body><!-- hidden --><h1>Deploy guide</h1><script>var x = '<p>';</script>
+<p>Run the <b>migration</b> before&nbsp;deploying.</p><p>Then restart the worker.</p></body></html>";
+	let (title, text) = worker::html_to_text(html);
+
+	assert_eq!(title.as_deref(), Some("Release & Notes"));
+	assert_eq!(text, "Deploy guide\nRun the migration before deploying.\nThen restart the worker.");
+}
+
+#[test]
+fn note_chunking_resolves_per_type_overrides() {
+	let base = ChunkingConfig { max_tokens: 512, overlap_tokens: 128 };
+	let per_type = HashMap::from([
+		(
+			"profile".to_string(),
+			ChunkingTypeOverride { enabled: Some(false), ..Default::default() },
+		),
+		(
+			"decision".to_string(),
+			ChunkingTypeOverride { overlap_tokens: Some(256), ..Default::default() },
+		),
+	]);
+	let fact = worker::resolve_note_chunking(&base, &per_type, "fact").expect("fact is chunked");
+	let decision =
+		worker::resolve_note_chunking(&base, &per_type, "decision").expect("decision is chunked");
+	let profile = worker::resolve_note_chunking(&base, &per_type, "profile");
+
+	assert_eq!((fact.max_tokens, fact.overlap_tokens), (512, 128));
+	assert_eq!((decision.max_tokens, decision.overlap_tokens), (512, 256));
+	assert!(profile.is_none());
+	assert_eq!(
+		worker::chunking_snapshot(profile.as_ref(), None),
+		serde_json::json!({ "enabled": false })
+	);
+	assert_eq!(
+		worker::chunking_snapshot(Some(&decision), None),
+		serde_json::json!({ "enabled": true, "max_tokens": 512, "overlap_tokens": 256 })
+	);
+}
+
+#[test]
+fn semantic_threshold_resolves_only_for_enabled_semantic_types() {
+	let base = ChunkingConfig { max_tokens: 512, overlap_tokens: 128 };
+	let per_type = HashMap::from([
+		(
+			"plan".to_string(),
+			ChunkingTypeOverride {
+				strategy: Some("semantic".to_string()),
+				similarity_threshold: Some(0.7),
+				..Default::default()
+			},
+		),
+		(
+			"profile".to_string(),
+			ChunkingTypeOverride {
+				enabled: Some(false),
+				strategy: Some("semantic".to_string()),
+				similarity_threshold: Some(0.7),
+				..Default::default()
+			},
+		),
+	]);
+
+	assert_eq!(worker::resolve_semantic_threshold(&per_type, "plan"), Some(0.7));
+	assert_eq!(worker::resolve_semantic_threshold(&per_type, "profile"), None);
+	assert_eq!(worker::resolve_semantic_threshold(&per_type, "fact"), None);
+	assert_eq!(
+		worker::chunking_snapshot(Some(&base), Some(0.5)),
+		serde_json::json!({
+			"enabled": true,
+			"strategy": "semantic",
+			"similarity_threshold": 0.5,
+			"max_tokens": 512,
+			"overlap_tokens": 128,
+		})
+	);
+}
+
+#[test]
+fn qdrant_maintenance_runs_when_interval_elapsed_and_never_schedules_restores() {
+	let cfg = QdrantMaintenance {
+		enabled: true,
+		optimize_interval_seconds: Some(3_600),
+		snapshot_interval_seconds: None,
+		verify_interval_seconds: Some(600),
+		snapshot_retention: 3,
+		max_count_drift: 0,
+		rest_url: None,
+		alert_webhook: None,
+	};
+	let now = OffsetDateTime::UNIX_EPOCH + Duration::days(1);
+
+	assert_eq!(worker::scheduled_interval(&cfg, QdrantMaintenanceOperation::Optimize), Some(3_600));
+	assert_eq!(worker::scheduled_interval(&cfg, QdrantMaintenanceOperation::Snapshot), None);
+	assert_eq!(worker::scheduled_interval(&cfg, QdrantMaintenanceOperation::RestoreSnapshot), None);
+	assert!(worker::is_due(None, 600, now));
+	assert!(!worker::is_due(Some(now - Duration::seconds(599)), 600, now));
+	assert!(worker::is_due(Some(now - Duration::seconds(600)), 600, now));
+}
+
+#[test]
+fn rescored_importance_rewards_recent_hits_and_useful_feedback() {
+	let cfg = LifecycleImportanceRescore {
+		enabled: true,
+		interval_seconds: 3_600,
+		batch_size: 500,
+		min_delta: 0.01,
+		prior_weight: 0.5,
+		hits_weight: 0.4,
+		hits_half_saturation: 4.0,
+		last_hit_tau_days: 30.0,
+		feedback_weight: 0.2,
+		age_weight: 0.1,
+		age_tau_days: 90.0,
+	};
+	let now = OffsetDateTime::UNIX_EPOCH + Duration::days(365);
+	let quiet = worker::ImportanceSignals {
+		hit_count: 0,
+		last_hit_at: None,
+		created_at: now,
+		useful: 0,
+		not_useful: 0,
+		wrong: 0,
+	};
+	let used =
+		worker::ImportanceSignals { hit_count: 4, last_hit_at: Some(now), useful: 3, ..quiet };
+	let wrong =
+		worker::ImportanceSignals { created_at: now - Duration::days(900), wrong: 5, ..quiet };
+
+	// 0.5 * 0.6 + 0.1 freshness for a brand-new note without signals.
+	assert!((worker::rescored_importance(&cfg, 0.6, quiet, now) - 0.4).abs() < 1e-6);
+	// Adds 0.4 * 0.5 for hits at half saturation and 0.2 * 0.75 for useful feedback.
+	assert!((worker::rescored_importance(&cfg, 0.6, used, now) - 0.75).abs() < 1e-6);
+	assert_eq!(worker::rescored_importance(&cfg, 0.0, wrong, now), 0.0);
+	assert_eq!(
+		worker::rescored_importance(
+			&LifecycleImportanceRescore { prior_weight: 1.0, ..cfg },
+			0.95,
+			used,
+			now
+		),
+		1.0
+	);
+}
+
+#[test]
+fn next_cron_run_finds_the_next_matching_minute() {
+	let at = |ts: &str| OffsetDateTime::parse(ts, &Rfc3339).expect("Failed to parse timestamp.");
+	let daily = CronSchedule::parse("0 3 * * *").expect("Expected a valid expression.");
+
+	assert_eq!(
+		worker::next_cron_run(&daily, at("2026-03-01T02:59:30Z")),
+		Some(at("2026-03-01T03:00:00Z"))
+	);
+	assert_eq!(
+		worker::next_cron_run(&daily, at("2026-03-01T03:00:00Z")),
+		Some(at("2026-03-02T03:00:00Z"))
+	);
+
+	let weekdays = CronSchedule::parse("30 9 * * 1-5").expect("Expected a valid expression.");
+
+	// 2026-03-06 is a Friday, so the next run skips the weekend.
+	assert_eq!(
+		worker::next_cron_run(&weekdays, at("2026-03-06T10:00:00Z")),
+		Some(at("2026-03-09T09:30:00Z"))
+	);
+
+	let leap_day = CronSchedule::parse("0 0 29 2 *").expect("Expected a valid expression.");
+
+	assert_eq!(
+		worker::next_cron_run(&leap_day, at("2026-03-01T00:00:00Z")),
+		Some(at("2028-02-29T00:00:00Z"))
+	);
+}
+
+#[test]
+fn consolidated_text_must_pass_the_write_gate() {
+	let text = "Deploys run on Fridays after the release review is approved.";
+
+	assert!(worker::merged_text_allowed(text, 200));
+	assert!(!worker::merged_text_allowed(text, 20));
+	assert!(!worker::merged_text_allowed("部署总是在周五审核之后进行。", 200));
+	assert!(!worker::merged_text_allowed(
+		"Deploys use the token sk-abcdefghijklmnopqrstuvwxyz0123456789 for access.",
+		200
+	));
+}
+
+#[test]
+fn indexing_outbox_stats_count_failures_and_retries() {
+	let mut stats = worker::IndexingOutboxStats::default();
+
+	stats.record(0, true);
+	stats.record(2, false);
+
+	let mut total = worker::IndexingOutboxStats::default();
+
+	total.merge(stats);
+	total.merge(stats);
+
+	assert_eq!(total, worker::IndexingOutboxStats { processed: 4, failed: 2, retried: 2 });
+}
+
+#[tokio::test]
+async fn egress_client_refuses_internal_targets() {
+	let client = worker::EgressClient::new(std::time::Duration::from_secs(1), None, None);
+
+	for url in [
+		"http://127.0.0.1:6333/collections",
+		"http://169.254.169.254/latest/meta-data/",
+		"http://10.0.0.5/",
+		"http://localhost:8080/",
+		"http://[::1]/",
+		"http://[::ffff:127.0.0.1]/",
+		"file:///etc/passwd",
+	] {
+		let url = reqwest::Url::parse(url).expect("Expected a parseable URL.");
+		let err = client.get(url.clone()).await.expect_err("Expected the target to be refused.");
+
+		assert!(
+			matches!(err, worker::EgressError::Refused(_)),
+			"Unexpected error for {url}: {err}"
+		);
+	}
+
+	let err = client
+		.post_json("http://192.168.0.10/hook", &serde_json::json!({}))
+		.await
+		.expect_err("Expected the webhook target to be refused.");
+
+	assert!(matches!(err, worker::EgressError::Refused(_)), "Unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn egress_client_applies_host_lists_before_resolving() {
+	let policy =
+		SecurityEgress { allowed_hosts: vec!["example.com".to_string()], denied_hosts: Vec::new() };
+	let client = worker::EgressClient::new(std::time::Duration::from_secs(1), None, Some(&policy));
+	let url = reqwest::Url::parse("https://unlisted.invalid/").expect("Expected a parseable URL.");
+	let err = client.get(url).await.expect_err("Expected an unlisted host to be refused.");
+
+	assert!(matches!(err, worker::EgressError::Refused(_)), "Unexpected error: {err}");
+}
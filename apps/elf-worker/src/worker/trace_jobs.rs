@@ -2,8 +2,8 @@ mod cleanup;
 mod persistence;
 
 pub(super) use cleanup::{
-	purge_expired_cache, purge_expired_search_sessions, purge_expired_trace_candidates,
-	purge_expired_traces,
+	purge_expired_cache, purge_expired_search_sessions, purge_expired_session_hits,
+	purge_expired_session_messages, purge_expired_trace_candidates, purge_expired_traces,
 };
 
 use crate::worker::{self, Db, Result, TraceOutboxJob, TracePayload};
@@ -3,7 +3,7 @@ use crate::worker::{Db, OffsetDateTime, Result};
 pub(in crate::worker) async fn purge_expired_trace_candidates(
 	db: &Db,
 	now: OffsetDateTime,
-) -> Result<()> {
+) -> Result<u64> {
 	let result = sqlx::query("DELETE FROM search_trace_candidates WHERE expires_at <= $1")
 		.bind(now)
 		.execute(&db.pool)
@@ -13,10 +13,10 @@ pub(in crate::worker) async fn purge_expired_trace_candidates(
 		tracing::info!(count = result.rows_affected(), "Purged expired search trace candidates.");
 	}
 
-	Ok(())
+	Ok(result.rows_affected())
 }
 
-pub(in crate::worker) async fn purge_expired_traces(db: &Db, now: OffsetDateTime) -> Result<()> {
+pub(in crate::worker) async fn purge_expired_traces(db: &Db, now: OffsetDateTime) -> Result<u64> {
 	let result = sqlx::query("DELETE FROM search_traces WHERE expires_at <= $1")
 		.bind(now)
 		.execute(&db.pool)
@@ -26,10 +26,10 @@ pub(in crate::worker) async fn purge_expired_traces(db: &Db, now: OffsetDateTime
 		tracing::info!(count = result.rows_affected(), "Purged expired search traces.");
 	}
 
-	Ok(())
+	Ok(result.rows_affected())
 }
 
-pub(in crate::worker) async fn purge_expired_cache(db: &Db, now: OffsetDateTime) -> Result<()> {
+pub(in crate::worker) async fn purge_expired_cache(db: &Db, now: OffsetDateTime) -> Result<u64> {
 	let result = sqlx::query("DELETE FROM llm_cache WHERE expires_at <= $1")
 		.bind(now)
 		.execute(&db.pool)
@@ -39,13 +39,13 @@ pub(in crate::worker) async fn purge_expired_cache(db: &Db, now: OffsetDateTime)
 		tracing::info!(count = result.rows_affected(), "Purged expired LLM cache entries.");
 	}
 
-	Ok(())
+	Ok(result.rows_affected())
 }
 
 pub(in crate::worker) async fn purge_expired_search_sessions(
 	db: &Db,
 	now: OffsetDateTime,
-) -> Result<()> {
+) -> Result<u64> {
 	let result = sqlx::query("DELETE FROM search_sessions WHERE expires_at <= $1")
 		.bind(now)
 		.execute(&db.pool)
@@ -55,5 +55,37 @@ pub(in crate::worker) async fn purge_expired_search_sessions(
 		tracing::info!(count = result.rows_affected(), "Purged expired search sessions.");
 	}
 
-	Ok(())
+	Ok(result.rows_affected())
+}
+
+pub(in crate::worker) async fn purge_expired_session_hits(
+	db: &Db,
+	now: OffsetDateTime,
+) -> Result<u64> {
+	let result = sqlx::query("DELETE FROM memory_session_hits WHERE expires_at <= $1")
+		.bind(now)
+		.execute(&db.pool)
+		.await?;
+
+	if result.rows_affected() > 0 {
+		tracing::info!(count = result.rows_affected(), "Purged expired memory session hits.");
+	}
+
+	Ok(result.rows_affected())
+}
+
+pub(in crate::worker) async fn purge_expired_session_messages(
+	db: &Db,
+	now: OffsetDateTime,
+) -> Result<u64> {
+	let result = sqlx::query("DELETE FROM memory_session_messages WHERE expires_at <= $1")
+		.bind(now)
+		.execute(&db.pool)
+		.await?;
+
+	if result.rows_affected() > 0 {
+		tracing::info!(count = result.rows_affected(), "Purged expired session messages.");
+	}
+
+	Ok(result.rows_affected())
 }
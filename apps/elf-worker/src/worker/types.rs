@@ -1,6 +1,8 @@
 use crate::worker::{
-	ChunkingConfig, Db, Deserialize, EmbeddingProviderConfig, FromRow, OffsetDateTime, QdrantStore,
-	Tokenizer, Uuid, Value,
+	self, ChunkingConfig, ChunkingTypeOverride, CronSchedule, Db, Deserialize,
+	EmbeddingProviderConfig, FromRow, HashMap, LifecycleConsolidation, LifecycleImportanceRescore,
+	LlmProviderConfig, OffsetDateTime, QdrantMaintenance, QdrantStore, SecurityEgress, Serialize,
+	Tokenizer, UrlSnapshots, Uuid, Value,
 };
 
 pub(super) type ProjectDocRefFields = (String, Option<String>, Option<String>, Option<String>);
@@ -10,9 +12,34 @@ pub(super) const CLAIM_LEASE_SECONDS: i64 = 30;
 pub(super) const BASE_BACKOFF_MS: i64 = 500;
 pub(super) const MAX_BACKOFF_MS: i64 = 30_000;
 pub(super) const TRACE_CLEANUP_INTERVAL_SECONDS: i64 = 900;
+pub(super) const TRACE_CLEANUP_BATCH_SIZE: i64 = 1_000;
 pub(super) const TRACE_OUTBOX_LEASE_SECONDS: i64 = 30;
 pub(super) const CONSOLIDATION_JOB_LEASE_SECONDS: i64 = 30;
 pub(super) const MAX_OUTBOX_ERROR_CHARS: usize = 1_024;
+pub(super) const OUTBOX_MAX_ATTEMPTS: i32 = 10;
+pub(super) const OUTBOX_THROUGHPUT_LOG_INTERVAL_SECONDS: i64 = 60;
+pub(super) const ORG_PROJECT_ID: &str = "__org__";
+pub(super) const ELF_STANDING_QUERY_NOTIFICATION_SCHEMA_V1: &str =
+	"elf.standing_query_notification/v1";
+pub(super) const STANDING_QUERY_NOTIFY_BATCH: i64 = 16;
+pub(super) const STANDING_QUERY_NOTIFY_LEASE_SECONDS: i64 = 30;
+pub(super) const STANDING_QUERY_NOTIFY_MAX_ATTEMPTS: i32 = 5;
+pub(super) const STANDING_QUERY_WEBHOOK_TIMEOUT_MS: i64 = 5_000;
+pub(super) const URL_SNAPSHOT_LEASE_SECONDS: i64 = 60;
+pub(super) const URL_SNAPSHOT_DOC_TYPE: &str = "knowledge";
+pub(super) const MAX_ROBOTS_TXT_BYTES: usize = 512 * 1_024;
+pub(super) const QDRANT_MAINTENANCE_CHECK_INTERVAL_SECONDS: i64 = 60;
+pub(super) const QDRANT_MAINTENANCE_LOCK_ID: i64 = 7_120_115;
+pub(super) const QDRANT_MAINTENANCE_ALERT_TIMEOUT_MS: i64 = 5_000;
+pub(super) const ELF_QDRANT_MAINTENANCE_ALERT_SCHEMA_V1: &str = "elf.qdrant_maintenance_alert/v1";
+pub(super) const NOTE_CONSOLIDATION_LOCK_ID: i64 = 7_120_116;
+pub(super) const NOTE_CONSOLIDATION_REASON: &str = "consolidation:auto";
+pub(super) const WORKER_VERSION_ACTOR: &str = "elf-worker";
+pub(super) const LIFECYCLE_LOCK_ID: i64 = 7_120_117;
+pub(super) const LIFECYCLE_EXPIRE_REASON: &str = "lifecycle:expired";
+pub(super) const ELF_LIFECYCLE_RUN_SCHEMA_V1: &str = "elf.lifecycle_run/v1";
+pub(super) const IMPORTANCE_RESCORE_LOCK_ID: i64 = 7_120_118;
+pub(super) const IMPORTANCE_RESCORE_REASON: &str = "importance:rescore";
 
 /// Shared runtime state used by the worker loop.
 pub struct WorkerState {
@@ -24,10 +51,147 @@ pub struct WorkerState {
 	pub docs_qdrant: QdrantStore,
 	/// Embedding provider configuration.
 	pub embedding: EmbeddingProviderConfig,
+	/// Embedding providers routed per scope, resolved from the config and keyed by scope label.
+	pub embedding_scopes: HashMap<String, EmbeddingProviderConfig>,
+	/// Embedding providers routed per note type, resolved from the config and keyed by note type.
+	pub embedding_types: HashMap<String, EmbeddingProviderConfig>,
 	/// Chunking configuration for notes and docs.
 	pub chunking: ChunkingConfig,
+	/// Per-note-type chunking overrides keyed by note type.
+	pub chunking_per_type: HashMap<String, ChunkingTypeOverride>,
 	/// Tokenizer used for chunking operations.
 	pub tokenizer: Tokenizer,
+	/// External URL snapshot settings; `None` disables the snapshot task.
+	pub url_snapshots: Option<UrlSnapshots>,
+	/// Host lists applied to URL snapshot fetches and webhooks; `None` allows every public host.
+	pub egress: Option<SecurityEgress>,
+	/// Scheduled Qdrant maintenance settings; `None` disables the schedule.
+	pub qdrant_maintenance: Option<QdrantMaintenance>,
+	/// Days trashed notes are kept before purge; `None` disables the trash purge.
+	pub trash_retention_days: Option<i64>,
+	/// Automatic near-duplicate note consolidation; `None` disables the task.
+	pub note_consolidation: Option<NoteConsolidation>,
+	/// Cron-scheduled note expiry, purge, and trace pruning; `None` disables the runner.
+	pub lifecycle: Option<LifecycleRunner>,
+	/// Usage-based note importance re-scoring; `None` disables the task.
+	pub importance_rescore: Option<LifecycleImportanceRescore>,
+	/// Note-indexing outbox jobs claimed and run concurrently per pass; at least 1.
+	pub indexing_concurrency: usize,
+}
+impl WorkerState {
+	/// Returns the embedding provider configured for notes in `scope`.
+	pub fn embedding_for_scope(&self, scope: &str) -> &EmbeddingProviderConfig {
+		self.embedding_scopes.get(scope).unwrap_or(&self.embedding)
+	}
+
+	/// Returns the embedding provider configured for a note of `note_type` in `scope`.
+	///
+	/// A note type override takes precedence over the scope override.
+	pub fn embedding_for_note(&self, scope: &str, note_type: &str) -> &EmbeddingProviderConfig {
+		self.embedding_types.get(note_type).unwrap_or_else(|| self.embedding_for_scope(scope))
+	}
+
+	/// Returns the chunking window for notes of `note_type`, or `None` when such notes are indexed
+	/// as one whole-text chunk.
+	pub fn chunking_for_type(&self, note_type: &str) -> Option<ChunkingConfig> {
+		worker::resolve_note_chunking(&self.chunking, &self.chunking_per_type, note_type)
+	}
+
+	/// Returns the adjacent-sentence similarity threshold when notes of `note_type` are chunked
+	/// semantically, or `None` for fixed token windows.
+	pub fn semantic_threshold_for_type(&self, note_type: &str) -> Option<f32> {
+		worker::resolve_semantic_threshold(&self.chunking_per_type, note_type)
+	}
+}
+
+/// Settings for the automatic near-duplicate note consolidation task.
+#[derive(Clone, Debug)]
+pub struct NoteConsolidation {
+	/// Schedule, similarity threshold, and per-pass merge budget.
+	pub schedule: LifecycleConsolidation,
+	/// Extractor provider that rewrites each merged pair as one note.
+	pub llm_extractor: LlmProviderConfig,
+	/// Longest merged note text accepted, in characters.
+	pub max_note_chars: u32,
+}
+
+/// Settings for the cron-scheduled lifecycle runner.
+#[derive(Clone, Debug)]
+pub struct LifecycleRunner {
+	/// UTC schedule the runner fires on.
+	pub schedule: CronSchedule,
+	/// Largest number of notes expired or purged per batch.
+	pub batch_size: u32,
+	/// Days deleted notes are kept before purge.
+	pub purge_deleted_after_days: i64,
+	/// Days deprecated notes are kept without hits before purge.
+	pub purge_deprecated_after_days: i64,
+}
+
+/// Summary of one lifecycle run, logged as `elf.lifecycle_run/v1`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub(super) struct LifecycleRunReport {
+	pub(super) schema: &'static str,
+	pub(super) started_at: String,
+	pub(super) duration_ms: i64,
+	pub(super) notes_expired: u64,
+	pub(super) deleted_notes_purged: u64,
+	pub(super) deprecated_notes_purged: u64,
+	pub(super) trashed_notes_purged: u64,
+	pub(super) trace_candidates_pruned: u64,
+	pub(super) traces_pruned: u64,
+	pub(super) llm_cache_pruned: u64,
+	pub(super) search_sessions_pruned: u64,
+	pub(super) session_hits_pruned: u64,
+	pub(super) session_messages_pruned: u64,
+	pub(super) errors: Vec<String>,
+}
+
+/// Usage signals of one note that importance re-scoring reads.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct ImportanceSignals {
+	pub(super) hit_count: i64,
+	pub(super) last_hit_at: Option<OffsetDateTime>,
+	pub(super) created_at: OffsetDateTime,
+	pub(super) useful: i64,
+	pub(super) not_useful: i64,
+	pub(super) wrong: i64,
+}
+
+/// Outcome counts of note-indexing outbox jobs, accumulated for throughput logging.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct IndexingOutboxStats {
+	/// Jobs that ran, whether they succeeded or failed.
+	pub(super) processed: u32,
+	/// Jobs that failed, whether rescheduled with backoff or dead-lettered.
+	pub(super) failed: u32,
+	/// Jobs that were retries of an earlier failed attempt.
+	pub(super) retried: u32,
+}
+impl IndexingOutboxStats {
+	pub(super) fn record(&mut self, attempts: i32, succeeded: bool) {
+		self.processed += 1;
+
+		if !succeeded {
+			self.failed += 1;
+		}
+		if attempts > 0 {
+			self.retried += 1;
+		}
+	}
+
+	pub(super) fn merge(&mut self, other: Self) {
+		self.processed += other.processed;
+		self.failed += other.failed;
+		self.retried += other.retried;
+	}
+}
+
+#[derive(Debug, FromRow)]
+pub(super) struct NearDuplicatePair {
+	pub(super) primary_note_id: Uuid,
+	pub(super) secondary_note_id: Uuid,
+	pub(super) similarity: f32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -162,6 +326,7 @@ pub(super) struct ChunkRecord {
 	pub(super) start_offset: i32,
 	pub(super) end_offset: i32,
 	pub(super) text: String,
+	pub(super) content_hash: String,
 }
 
 #[derive(Debug, FromRow)]
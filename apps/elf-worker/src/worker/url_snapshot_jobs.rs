@@ -0,0 +1,364 @@
+mod extract;
+
+pub(super) use extract::{html_to_text, robots_allows};
+
+use time::Duration;
+
+use crate::worker::{
+	self, Chunk, EgressClient, EgressError, MAX_OUTBOX_ERROR_CHARS, MAX_ROBOTS_TXT_BYTES,
+	OffsetDateTime, Result, URL_SNAPSHOT_DOC_TYPE, URL_SNAPSHOT_LEASE_SECONDS, Uuid, WorkerState,
+	doc_outbox, docs, sanitize_outbox_error,
+};
+use elf_config::UrlSnapshots;
+use elf_domain::{english_gate, writegate};
+use elf_storage::{
+	models::{DocChunk, DocDocument, SourceUrlSnapshot},
+	url_snapshots,
+};
+
+enum FetchOutcome {
+	Page(FetchedPage),
+	Blocked,
+}
+
+struct FetchedPage {
+	final_url: String,
+	http_status: i32,
+	title: Option<String>,
+	text: String,
+	truncated: bool,
+}
+
+struct SnapshotFailure {
+	message: String,
+	http_status: Option<i32>,
+	retryable: bool,
+}
+impl SnapshotFailure {
+	fn retryable(message: String, http_status: Option<i32>) -> Self {
+		Self { message, http_status, retryable: true }
+	}
+
+	fn permanent(message: String, http_status: Option<i32>) -> Self {
+		Self { message, http_status, retryable: false }
+	}
+}
+
+/// Fetches at most one due external URL and stores it as a snapshot document.
+pub(super) async fn process_url_snapshot_once(state: &WorkerState) -> Result<()> {
+	let Some(settings) = state.url_snapshots.as_ref().filter(|settings| settings.enabled) else {
+		return Ok(());
+	};
+	let now = OffsetDateTime::now_utc();
+	let lease_until = now + Duration::seconds(URL_SNAPSHOT_LEASE_SECONDS);
+	let Some(job) =
+		url_snapshots::claim_next_source_url_snapshot(&state.db.pool, now, lease_until).await?
+	else {
+		return Ok(());
+	};
+	let client = EgressClient::new(
+		worker::to_std_duration(Duration::milliseconds(settings.timeout_ms as i64)),
+		Some(settings.user_agent.as_str()),
+		state.egress.as_ref(),
+	);
+
+	match fetch_snapshot(&client, settings, &job).await {
+		Ok(FetchOutcome::Page(page)) => store_snapshot(state, &job, page).await,
+		Ok(FetchOutcome::Blocked) => {
+			tracing::info!(
+				snapshot_id = %job.snapshot_id,
+				note_id = %job.note_id,
+				"External URL snapshot disallowed by robots.txt."
+			);
+			url_snapshots::mark_source_url_snapshot_blocked(
+				&state.db.pool,
+				job.snapshot_id,
+				OffsetDateTime::now_utc(),
+			)
+			.await?;
+
+			Ok(())
+		},
+		Err(failure) => record_failure(state, settings, &job, failure).await,
+	}
+}
+
+async fn fetch_snapshot(
+	client: &EgressClient,
+	settings: &UrlSnapshots,
+	job: &SourceUrlSnapshot,
+) -> Result<FetchOutcome, SnapshotFailure> {
+	let url = reqwest::Url::parse(job.url.as_str())
+		.map_err(|err| SnapshotFailure::permanent(format!("Invalid URL: {err}."), None))?;
+
+	if !robots_allow_url(client, settings, &url).await? {
+		return Ok(FetchOutcome::Blocked);
+	}
+
+	let response = client.get(url).await.map_err(|err| snapshot_failure("Page", err))?;
+	let status = response.status();
+	let http_status = Some(i32::from(status.as_u16()));
+
+	if !status.is_success() {
+		let message = format!("Page responded with status {status}.");
+
+		return Err(if status.is_server_error() || status.as_u16() == 429 {
+			SnapshotFailure::retryable(message, http_status)
+		} else {
+			SnapshotFailure::permanent(message, http_status)
+		});
+	}
+
+	let final_url = response.url().to_string();
+	let content_type = response
+		.headers()
+		.get(reqwest::header::CONTENT_TYPE)
+		.and_then(|value| value.to_str().ok())
+		.unwrap_or("text/html")
+		.to_ascii_lowercase();
+	let is_html =
+		content_type.starts_with("text/html") || content_type.starts_with("application/xhtml+xml");
+
+	if !is_html && !content_type.starts_with("text/plain") {
+		return Err(SnapshotFailure::permanent(
+			format!("Unsupported content type {content_type}."),
+			http_status,
+		));
+	}
+
+	let (body, truncated) =
+		read_limited(response, settings.max_bytes as usize).await.map_err(|err| {
+			SnapshotFailure::retryable(format!("Page read failed: {err}."), http_status)
+		})?;
+	let body = String::from_utf8_lossy(&body);
+	let (title, text) =
+		if is_html { html_to_text(body.as_ref()) } else { (None, body.trim().to_string()) };
+
+	if text.is_empty() {
+		return Err(SnapshotFailure::permanent(
+			"Page has no text content.".to_string(),
+			http_status,
+		));
+	}
+	if writegate::contains_secrets(text.as_str()) {
+		return Err(SnapshotFailure::permanent(
+			"Page content contains secrets.".to_string(),
+			http_status,
+		));
+	}
+	if !english_gate::is_english_natural_language(text.as_str()) {
+		return Err(SnapshotFailure::permanent(
+			"Page content is not English.".to_string(),
+			http_status,
+		));
+	}
+
+	let title = title.filter(|title| english_gate::is_english_natural_language(title));
+
+	Ok(FetchOutcome::Page(FetchedPage {
+		final_url,
+		http_status: i32::from(status.as_u16()),
+		title,
+		text,
+		truncated,
+	}))
+}
+
+async fn robots_allow_url(
+	client: &EgressClient,
+	settings: &UrlSnapshots,
+	url: &reqwest::Url,
+) -> Result<bool, SnapshotFailure> {
+	let robots_url = url
+		.join("/robots.txt")
+		.map_err(|err| SnapshotFailure::permanent(format!("Invalid URL: {err}."), None))?;
+	let response =
+		client.get(robots_url).await.map_err(|err| snapshot_failure("robots.txt", err))?;
+	let status = response.status();
+
+	// A missing robots.txt allows everything; an unreachable one is retried rather than ignored.
+	if status.is_client_error() {
+		return Ok(true);
+	}
+	if !status.is_success() {
+		return Err(SnapshotFailure::retryable(
+			format!("robots.txt responded with status {status}."),
+			None,
+		));
+	}
+
+	let (body, _) = read_limited(response, MAX_ROBOTS_TXT_BYTES).await.map_err(|err| {
+		SnapshotFailure::retryable(format!("robots.txt read failed: {err}."), None)
+	})?;
+	let path = match url.query() {
+		Some(query) => format!("{}?{query}", url.path()),
+		None => url.path().to_string(),
+	};
+
+	Ok(robots_allows(String::from_utf8_lossy(&body).as_ref(), settings.user_agent.as_str(), &path))
+}
+
+fn snapshot_failure(target: &str, err: EgressError) -> SnapshotFailure {
+	match err {
+		EgressError::Refused(message) =>
+			SnapshotFailure::permanent(format!("{target} request refused: {message}"), None),
+		EgressError::Unreachable(message) =>
+			SnapshotFailure::retryable(format!("{target} request failed: {message}"), None),
+	}
+}
+
+async fn read_limited(
+	mut response: reqwest::Response,
+	max_bytes: usize,
+) -> reqwest::Result<(Vec<u8>, bool)> {
+	let mut body = Vec::new();
+
+	while let Some(chunk) = response.chunk().await? {
+		let remaining = max_bytes.saturating_sub(body.len());
+
+		if chunk.len() > remaining {
+			body.extend_from_slice(&chunk[..remaining]);
+
+			return Ok((body, true));
+		}
+
+		body.extend_from_slice(&chunk);
+	}
+
+	Ok((body, false))
+}
+
+async fn store_snapshot(
+	state: &WorkerState,
+	job: &SourceUrlSnapshot,
+	page: FetchedPage,
+) -> Result<()> {
+	let now = OffsetDateTime::now_utc();
+	let fetched_at = worker::format_timestamp(now)?;
+	let doc_id = Uuid::new_v4();
+	let content_hash = blake3::hash(page.text.as_bytes()).to_hex().to_string();
+	let domain = reqwest::Url::parse(job.url.as_str())
+		.ok()
+		.and_then(|url| url.host_str().map(str::to_string))
+		.unwrap_or_default();
+	let source_ref = serde_json::json!({
+		"schema": "doc_source_ref/v1",
+		"doc_type": URL_SNAPSHOT_DOC_TYPE,
+		"ts": fetched_at,
+		"source_kind": "web_page",
+		"canonical_uri": job.url,
+		"captured_at": fetched_at,
+		"trust_label": "public_web",
+		"url": job.url,
+		"final_url": page.final_url,
+		"domain": domain,
+		"http_status": page.http_status,
+		"truncated": page.truncated,
+		"note_id": job.note_id,
+	});
+	let chunks = elf_chunking::split_text(page.text.as_str(), &state.chunking, &state.tokenizer);
+	let doc = DocDocument {
+		doc_id,
+		tenant_id: job.tenant_id.clone(),
+		project_id: job.project_id.clone(),
+		agent_id: job.agent_id.clone(),
+		scope: job.scope.clone(),
+		doc_type: URL_SNAPSHOT_DOC_TYPE.to_string(),
+		status: "active".to_string(),
+		title: page.title,
+		source_ref,
+		content_bytes: page.text.len() as i32,
+		content: page.text,
+		content_hash: content_hash.clone(),
+		created_at: now,
+		updated_at: now,
+	};
+	let mut tx = state.db.pool.begin().await?;
+
+	docs::insert_doc_document(&mut *tx, &doc).await?;
+
+	for chunk in chunks {
+		let chunk_row = doc_chunk_row(doc_id, chunk, now);
+
+		docs::insert_doc_chunk(&mut *tx, &chunk_row).await?;
+		doc_outbox::enqueue_doc_outbox(
+			&mut *tx,
+			doc_id,
+			chunk_row.chunk_id,
+			"UPSERT",
+			job.embedding_version.as_str(),
+		)
+		.await?;
+	}
+
+	url_snapshots::mark_source_url_snapshot_done(
+		&mut *tx,
+		job.snapshot_id,
+		doc_id,
+		content_hash.as_str(),
+		page.http_status,
+		now,
+	)
+	.await?;
+	url_snapshots::attach_source_url_snapshot(&mut *tx, job.note_id, job.url.as_str()).await?;
+
+	tx.commit().await?;
+
+	tracing::info!(
+		snapshot_id = %job.snapshot_id,
+		note_id = %job.note_id,
+		doc_id = %doc_id,
+		truncated = page.truncated,
+		"External URL snapshot stored."
+	);
+
+	Ok(())
+}
+
+fn doc_chunk_row(doc_id: Uuid, chunk: Chunk, now: OffsetDateTime) -> DocChunk {
+	DocChunk {
+		chunk_id: Uuid::new_v4(),
+		doc_id,
+		chunk_index: chunk.chunk_index,
+		start_offset: chunk.start_offset as i32,
+		end_offset: chunk.end_offset as i32,
+		chunk_hash: blake3::hash(chunk.text.as_bytes()).to_hex().to_string(),
+		chunk_text: chunk.text,
+		created_at: now,
+	}
+}
+
+async fn record_failure(
+	state: &WorkerState,
+	settings: &UrlSnapshots,
+	job: &SourceUrlSnapshot,
+	failure: SnapshotFailure,
+) -> Result<()> {
+	let now = OffsetDateTime::now_utc();
+	let attempts = job.attempts.saturating_add(1);
+	let retry_at = (failure.retryable && attempts < settings.max_attempts as i32)
+		.then(|| now + worker::backoff_for_attempt(attempts));
+	let error = sanitize_outbox_error(&failure.message)
+		.chars()
+		.take(MAX_OUTBOX_ERROR_CHARS)
+		.collect::<String>();
+
+	tracing::warn!(
+		error = %error,
+		snapshot_id = %job.snapshot_id,
+		note_id = %job.note_id,
+		attempts,
+		"External URL snapshot failed."
+	);
+	url_snapshots::mark_source_url_snapshot_failed(
+		&state.db.pool,
+		job.snapshot_id,
+		error.as_str(),
+		failure.http_status,
+		retry_at,
+		now,
+	)
+	.await?;
+
+	Ok(())
+}
@@ -0,0 +1,261 @@
+const SKIPPED_ELEMENTS: [&str; 6] = ["script", "style", "noscript", "template", "svg", "head"];
+const INLINE_ELEMENTS: [&str; 16] = [
+	"a", "abbr", "b", "bdi", "cite", "code", "em", "i", "kbd", "mark", "q", "s", "small", "span",
+	"strong", "u",
+];
+
+/// Returns whether `robots_txt` allows `user_agent` to fetch `path`.
+///
+/// Uses the group whose product token matches the user agent, falling back to `*`. Within that
+/// group the longest matching rule wins and ties resolve to allow, per RFC 9309.
+pub(in crate::worker) fn robots_allows(robots_txt: &str, user_agent: &str, path: &str) -> bool {
+	let product = user_agent
+		.split(|ch: char| ch == '/' || ch.is_whitespace())
+		.next()
+		.unwrap_or_default()
+		.to_ascii_lowercase();
+	let groups = parse_robots_groups(robots_txt);
+	let matched = groups
+		.iter()
+		.find(|group| {
+			!product.is_empty() && group.agents.iter().any(|agent| agent.as_str() == product)
+		})
+		.or_else(|| groups.iter().find(|group| group.agents.iter().any(|agent| agent == "*")));
+	let Some(group) = matched else {
+		return true;
+	};
+	let mut best: Option<(usize, bool)> = None;
+
+	for (pattern, allow) in &group.rules {
+		if !robots_pattern_matches(pattern, path) {
+			continue;
+		}
+
+		let better = match best {
+			None => true,
+			Some((len, best_allow)) =>
+				pattern.len() > len || (pattern.len() == len && *allow && !best_allow),
+		};
+
+		if better {
+			best = Some((pattern.len(), *allow));
+		}
+	}
+
+	best.is_none_or(|(_, allow)| allow)
+}
+
+/// Converts an HTML document into its title and readable text.
+pub(in crate::worker) fn html_to_text(html: &str) -> (Option<String>, String) {
+	let title = extract_title(html);
+	let mut out = String::with_capacity(html.len() / 2);
+	let mut skip_until: Option<String> = None;
+	let mut rest = html;
+
+	while let Some(start) = rest.find('<') {
+		if skip_until.is_none() {
+			out.push_str(&decode_entities(&rest[..start]));
+		}
+
+		rest = &rest[start..];
+
+		if rest.starts_with("<!--") {
+			rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+
+			continue;
+		}
+
+		let Some(end) = rest.find('>') else {
+			rest = "";
+
+			break;
+		};
+		let tag = &rest[1..end];
+
+		rest = &rest[end + 1..];
+
+		let closing = tag.starts_with('/');
+		let name = tag
+			.trim_start_matches('/')
+			.split(|ch: char| ch.is_whitespace() || ch == '/')
+			.next()
+			.unwrap_or_default()
+			.to_ascii_lowercase();
+
+		if let Some(skipped) = skip_until.as_deref() {
+			if closing && name == skipped {
+				skip_until = None;
+			}
+
+			continue;
+		}
+		if !closing && !tag.ends_with('/') && SKIPPED_ELEMENTS.contains(&name.as_str()) {
+			skip_until = Some(name);
+
+			continue;
+		}
+		if INLINE_ELEMENTS.contains(&name.as_str()) {
+			continue;
+		}
+
+		out.push('\n');
+	}
+
+	if skip_until.is_none() {
+		out.push_str(&decode_entities(rest));
+	}
+
+	(title, normalize_whitespace(&out))
+}
+
+struct RobotsGroup {
+	agents: Vec<String>,
+	rules: Vec<(String, bool)>,
+}
+
+fn parse_robots_groups(robots_txt: &str) -> Vec<RobotsGroup> {
+	let mut groups: Vec<RobotsGroup> = Vec::new();
+	let mut in_agent_run = false;
+
+	for line in robots_txt.lines() {
+		let line = line.split('#').next().unwrap_or_default().trim();
+		let Some((key, value)) = line.split_once(':') else {
+			continue;
+		};
+		let key = key.trim().to_ascii_lowercase();
+		let value = value.trim();
+
+		match key.as_str() {
+			"user-agent" => {
+				if !in_agent_run || groups.is_empty() {
+					groups.push(RobotsGroup { agents: Vec::new(), rules: Vec::new() });
+				}
+				if let Some(group) = groups.last_mut() {
+					group.agents.push(value.to_ascii_lowercase());
+				}
+
+				in_agent_run = true;
+			},
+			"allow" | "disallow" => {
+				in_agent_run = false;
+
+				// An empty disallow matches nothing, so it never restricts the group.
+				if value.is_empty() {
+					continue;
+				}
+				if let Some(group) = groups.last_mut() {
+					group.rules.push((value.to_string(), key == "allow"));
+				}
+			},
+			_ => {},
+		}
+	}
+
+	groups
+}
+
+fn robots_pattern_matches(pattern: &str, path: &str) -> bool {
+	let (pattern, anchored) = match pattern.strip_suffix('$') {
+		Some(pattern) => (pattern, true),
+		None => (pattern, false),
+	};
+	let mut parts = pattern.split('*');
+	let first = parts.next().unwrap_or_default();
+
+	if !path.starts_with(first) {
+		return false;
+	}
+
+	let mut cursor = first.len();
+	let parts = parts.collect::<Vec<_>>();
+
+	for (idx, part) in parts.iter().enumerate() {
+		let is_last = idx + 1 == parts.len();
+
+		if is_last && anchored {
+			return path.len() >= cursor + part.len() && path.ends_with(part);
+		}
+
+		match path[cursor..].find(part) {
+			Some(found) => cursor += found + part.len(),
+			None => return false,
+		}
+	}
+
+	!anchored || cursor == path.len()
+}
+
+fn extract_title(html: &str) -> Option<String> {
+	let lower = html.to_ascii_lowercase();
+	let open = lower.find("<title")?;
+	let start = open + lower[open..].find('>')? + 1;
+	let end = start + lower[start..].find("</title")?;
+	let title = normalize_whitespace(&decode_entities(&html[start..end]));
+
+	(!title.is_empty()).then_some(title)
+}
+
+fn decode_entities(text: &str) -> String {
+	if !text.contains('&') {
+		return text.to_string();
+	}
+
+	let mut out = String::with_capacity(text.len());
+	let mut rest = text;
+
+	while let Some(start) = rest.find('&') {
+		out.push_str(&rest[..start]);
+
+		rest = &rest[start..];
+
+		let decoded = rest
+			.find(';')
+			.filter(|end| *end <= 10)
+			.and_then(|end| decode_entity(&rest[1..end]).map(|ch| (ch, end + 1)));
+
+		match decoded {
+			Some((ch, consumed)) => {
+				out.push(ch);
+
+				rest = &rest[consumed..];
+			},
+			None => {
+				out.push('&');
+
+				rest = &rest[1..];
+			},
+		}
+	}
+
+	out.push_str(rest);
+
+	out
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+	match entity {
+		"amp" => Some('&'),
+		"lt" => Some('<'),
+		"gt" => Some('>'),
+		"quot" => Some('"'),
+		"apos" => Some('\''),
+		"nbsp" => Some(' '),
+		_ => {
+			let code = entity.strip_prefix('#')?;
+			let value = match code.strip_prefix(['x', 'X']) {
+				Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+				None => code.parse().ok()?,
+			};
+
+			char::from_u32(value)
+		},
+	}
+}
+
+fn normalize_whitespace(text: &str) -> String {
+	text.lines()
+		.map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+		.filter(|line| !line.is_empty())
+		.collect::<Vec<_>>()
+		.join("\n")
+}
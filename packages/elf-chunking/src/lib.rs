@@ -28,7 +28,28 @@ pub struct Chunk {
 	pub text: String,
 }
 
+/// Character and token caps applied to one snippet; unset caps are not enforced.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SnippetLimit {
+	/// Maximum characters kept, including any truncation marker the caller appends.
+	pub max_chars: Option<usize>,
+	/// Maximum tokens kept, counted with the chunking tokenizer.
+	pub max_tokens: Option<usize>,
+}
+
+/// Position at which a snippet was cut to satisfy a [`SnippetLimit`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SnippetCut {
+	/// Byte offset in the original text where the kept prefix ends.
+	pub end: usize,
+	/// Whether the kept prefix ends on a sentence boundary.
+	pub sentence_boundary: bool,
+}
+
 /// Loads a tokenizer from a local JSON file path or Hugging Face repository identifier.
+///
+/// Repository identifiers require the `tokenizer-download` feature; without it only local
+/// paths load.
 pub fn load_tokenizer(repo: &str) -> Result<Tokenizer, Error> {
 	let path = Path::new(repo);
 
@@ -36,7 +57,15 @@ pub fn load_tokenizer(repo: &str) -> Result<Tokenizer, Error> {
 		return Tokenizer::from_file(path);
 	}
 
-	Tokenizer::from_pretrained(repo, None)
+	#[cfg(feature = "tokenizer-download")]
+	{
+		Tokenizer::from_pretrained(repo, None)
+	}
+	#[cfg(not(feature = "tokenizer-download"))]
+	{
+		Err(format!("tokenizer {repo} is not a local file and tokenizer-download is disabled")
+			.into())
+	}
 }
 
 /// Splits text into sentence-aware chunks that honor the configured token window.
@@ -97,6 +126,168 @@ pub fn split_text(text: &str, cfg: &ChunkingConfig, tokenizer: &Tokenizer) -> Ve
 	chunks
 }
 
+/// Returns byte spans of the sentences in `text`, folding whitespace-only segments into the
+/// preceding sentence so every span carries text worth embedding.
+pub fn sentence_spans(text: &str) -> Vec<(usize, usize)> {
+	let mut spans: Vec<(usize, usize)> = Vec::new();
+
+	for (idx, sentence) in text.split_sentence_bound_indices() {
+		let end = idx + sentence.len();
+
+		match spans.last_mut() {
+			Some(last) if sentence.trim().is_empty() => last.1 = end,
+			_ => spans.push((idx, end)),
+		}
+	}
+
+	spans
+}
+
+/// Splits text where adjacent sentences drift apart semantically.
+///
+/// `spans` comes from [`sentence_spans`] and `sentence_vectors` holds one embedding per span. A new
+/// chunk starts wherever the cosine similarity between neighbouring sentences drops below
+/// `similarity_threshold`. Segments still longer than `cfg.max_tokens` are split further with
+/// [`split_text`], so the token window stays a hard cap. Offsets refer to the original `text`.
+pub fn split_text_semantic(
+	text: &str,
+	spans: &[(usize, usize)],
+	sentence_vectors: &[Vec<f32>],
+	similarity_threshold: f32,
+	cfg: &ChunkingConfig,
+	tokenizer: &Tokenizer,
+) -> Vec<Chunk> {
+	if spans.is_empty() || spans.len() != sentence_vectors.len() {
+		return split_text(text, cfg, tokenizer);
+	}
+
+	let mut segments = Vec::new();
+	let mut segment_start = spans[0].0;
+
+	for (idx, pair) in sentence_vectors.windows(2).enumerate() {
+		if cosine_similarity(&pair[0], &pair[1]) < similarity_threshold {
+			segments.push((segment_start, spans[idx].1));
+
+			segment_start = spans[idx + 1].0;
+		}
+	}
+
+	segments.push((segment_start, spans[spans.len() - 1].1));
+
+	let mut chunks = Vec::new();
+
+	for (start, end) in segments {
+		for chunk in split_text(&text[start..end], cfg, tokenizer) {
+			chunks.push(Chunk {
+				chunk_index: chunks.len() as i32,
+				start_offset: start + chunk.start_offset,
+				end_offset: start + chunk.end_offset,
+				text: chunk.text,
+			});
+		}
+	}
+
+	chunks
+}
+
+/// Finds where to cut `text` so the kept prefix fits `limit`, preferring sentence boundaries.
+///
+/// `marker_chars` characters are reserved out of `max_chars` for a truncation marker. Token caps
+/// are only enforced when a tokenizer is supplied. Returns `None` when the whole text fits.
+pub fn truncate_snippet(
+	text: &str,
+	limit: SnippetLimit,
+	marker_chars: usize,
+	tokenizer: Option<&Tokenizer>,
+) -> Option<SnippetCut> {
+	let char_budget = limit
+		.max_chars
+		.filter(|max_chars| text.chars().count() > *max_chars)
+		.map(|max_chars| byte_offset_of_char(text, max_chars.saturating_sub(marker_chars)));
+	let token_budget = match (limit.max_tokens, tokenizer) {
+		(Some(max_tokens), Some(tokenizer)) => token_budget_end(text, max_tokens, tokenizer),
+		_ => None,
+	};
+	let budget = match (char_budget, token_budget) {
+		(Some(chars), Some(tokens)) => chars.min(tokens),
+		(Some(budget), None) | (None, Some(budget)) => budget,
+		(None, None) => return None,
+	};
+	let sentence_end = text
+		.split_sentence_bound_indices()
+		.map(|(idx, sentence)| idx + sentence.len())
+		.take_while(|end| *end <= budget)
+		.last()
+		.map(|end| text[..end].trim_end().len())
+		.filter(|end| *end > 0);
+
+	if let Some(end) = sentence_end {
+		return Some(SnippetCut { end, sentence_boundary: true });
+	}
+
+	let at_word_end = text[budget..].chars().next().is_none_or(char::is_whitespace);
+	let word_end = if at_word_end {
+		text[..budget].trim_end().len()
+	} else {
+		text[..budget]
+			.rfind(char::is_whitespace)
+			.map(|end| text[..end].trim_end().len())
+			.filter(|end| *end > 0)
+			.unwrap_or(budget)
+	};
+
+	Some(SnippetCut { end: word_end, sentence_boundary: false })
+}
+
+/// Counts the tokens `tokenizer` produces for `text`, or `None` when encoding fails.
+pub fn count_tokens(text: &str, tokenizer: &Tokenizer) -> Option<usize> {
+	match tokenizer.encode(text, false) {
+		Ok(encoding) => Some(encoding.len()),
+		Err(err) => {
+			tracing::error!(error = %err, "Tokenizer failed to encode text for counting.");
+
+			None
+		},
+	}
+}
+
+fn cosine_similarity(left: &[f32], right: &[f32]) -> f32 {
+	let dot: f32 = left.iter().zip(right).map(|(a, b)| a * b).sum();
+	let left_norm = left.iter().map(|value| value * value).sum::<f32>().sqrt();
+	let right_norm = right.iter().map(|value| value * value).sum::<f32>().sqrt();
+
+	if left_norm == 0.0 || right_norm == 0.0 {
+		return 0.0;
+	}
+
+	dot / (left_norm * right_norm)
+}
+
+fn byte_offset_of_char(text: &str, char_index: usize) -> usize {
+	text.char_indices().nth(char_index).map(|(idx, _)| idx).unwrap_or(text.len())
+}
+
+fn token_budget_end(text: &str, max_tokens: usize, tokenizer: &Tokenizer) -> Option<usize> {
+	let encoding = match tokenizer.encode(text, false) {
+		Ok(encoding) => encoding,
+		Err(err) => {
+			tracing::error!(error = %err, "Tokenizer failed to encode snippet.");
+
+			return None;
+		},
+	};
+	let offsets = encoding.get_offsets();
+
+	if offsets.len() <= max_tokens {
+		return None;
+	}
+	if max_tokens == 0 {
+		return Some(0);
+	}
+
+	offsets.get(max_tokens - 1).map(|(_, end)| (*end).min(text.len()))
+}
+
 fn overlap_tail(text: &str, overlap_tokens: u32, tokenizer: &Tokenizer) -> String {
 	if overlap_tokens == 0 {
 		return String::new();
@@ -145,6 +336,50 @@ mod tests {
 		assert!(chunks[0].text.contains("local note"));
 	}
 
+	#[test]
+	fn truncate_snippet_prefers_sentence_boundaries() {
+		let text = "First sentence here. Second sentence runs longer than the cap.";
+		let limit = crate::SnippetLimit { max_chars: Some(40), max_tokens: None };
+		let cut = crate::truncate_snippet(text, limit, 3, None).expect("Snippet should be cut.");
+
+		assert_eq!(&text[..cut.end], "First sentence here.");
+		assert!(cut.sentence_boundary);
+		assert_eq!(crate::truncate_snippet(text, crate::SnippetLimit::default(), 3, None), None);
+	}
+
+	#[test]
+	fn truncate_snippet_falls_back_to_word_boundary_and_counts_tokens() {
+		let path = local_dev_tokenizer_path();
+		let tokenizer = crate::load_tokenizer(path.to_str().expect("Path must be valid UTF-8"))
+			.expect("Local dev tokenizer must load.");
+		let text = "one local note another local note";
+		let limit = crate::SnippetLimit { max_chars: None, max_tokens: Some(3) };
+		let cut = crate::truncate_snippet(text, limit, 3, Some(&tokenizer))
+			.expect("Snippet should be cut.");
+
+		assert_eq!(&text[..cut.end], "one local note");
+		assert!(!cut.sentence_boundary);
+		assert_eq!(crate::count_tokens(text, &tokenizer), Some(6));
+	}
+
+	#[test]
+	fn semantic_split_breaks_where_neighbouring_sentences_drift() {
+		let path = local_dev_tokenizer_path();
+		let tokenizer = crate::load_tokenizer(path.to_str().expect("Path must be valid UTF-8"))
+			.expect("Local dev tokenizer must load.");
+		let cfg = ChunkingConfig { max_tokens: 64, overlap_tokens: 0 };
+		let text = "Deploys run on Fridays. Deploys need approval. Lunch is at noon.";
+		let spans = crate::sentence_spans(text);
+		let vectors = vec![vec![1.0, 0.0], vec![0.9, 0.1], vec![0.0, 1.0]];
+		let chunks = crate::split_text_semantic(text, &spans, &vectors, 0.8, &cfg, &tokenizer);
+
+		assert_eq!(spans.len(), 3);
+		assert_eq!(chunks.len(), 2);
+		assert_eq!(chunks[0].text, "Deploys run on Fridays. Deploys need approval. ");
+		assert_eq!(chunks[1].chunk_index, 1);
+		assert_eq!(&text[chunks[1].start_offset..chunks[1].end_offset], "Lunch is at noon.");
+	}
+
 	#[test]
 	fn splits_into_chunks_with_overlap() {
 		let cfg = ChunkingConfig { max_tokens: 2, overlap_tokens: 1 };
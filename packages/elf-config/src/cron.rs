@@ -0,0 +1,140 @@
+//! Five-field cron expressions for worker schedules.
+
+const FIELD_NAMES: [&str; 5] = ["minute", "hour", "day-of-month", "month", "day-of-week"];
+const FIELD_RANGES: [(u32, u32); 5] = [(0, 59), (0, 23), (1, 31), (1, 12), (0, 7)];
+const MONTH_MAX_DAYS: [u32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Parsed `minute hour day-of-month month day-of-week` expression, evaluated in UTC.
+///
+/// Each field accepts `*`, a value, a range `a-b`, a step `*/n` or `a-b/n`, or a comma-separated
+/// list of those. Day-of-week runs from 0 (Sunday) to 6; 7 is also Sunday. As in classic cron,
+/// when both day fields are restricted a day matches if either field does.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CronSchedule {
+	minutes: u64,
+	hours: u64,
+	days_of_month: u64,
+	months: u64,
+	days_of_week: u64,
+	day_of_month_any: bool,
+	day_of_week_any: bool,
+}
+impl CronSchedule {
+	/// Parses `expr`, rejecting malformed fields and schedules that can never fire.
+	pub fn parse(expr: &str) -> Result<Self, String> {
+		let fields: Vec<&str> = expr.split_whitespace().collect();
+
+		if fields.len() != FIELD_NAMES.len() {
+			return Err(format!(
+				"cron expression must have 5 fields (minute hour day-of-month month day-of-week); got {}.",
+				fields.len()
+			));
+		}
+
+		let mut masks = [0_u64; 5];
+
+		for (idx, field) in fields.iter().enumerate() {
+			masks[idx] = parse_field(field, FIELD_RANGES[idx])
+				.map_err(|message| format!("cron {} field {message}", FIELD_NAMES[idx]))?;
+		}
+
+		// Day-of-week 7 is an alias for Sunday.
+		if masks[4] & (1 << 7) != 0 {
+			masks[4] = (masks[4] | 1) & !(1 << 7);
+		}
+
+		let schedule = Self {
+			minutes: masks[0],
+			hours: masks[1],
+			days_of_month: masks[2],
+			months: masks[3],
+			days_of_week: masks[4],
+			day_of_month_any: fields[2] == "*",
+			day_of_week_any: fields[4] == "*",
+		};
+
+		if schedule.day_of_week_any && !schedule.has_reachable_day_of_month() {
+			return Err("cron expression never matches a calendar date.".to_string());
+		}
+
+		Ok(schedule)
+	}
+
+	/// Returns whether the minute and hour fields match.
+	pub fn matches_time(&self, hour: u8, minute: u8) -> bool {
+		bit(self.hours, u32::from(hour)) && bit(self.minutes, u32::from(minute))
+	}
+
+	/// Returns whether the month and day fields match; `weekday` counts from 0 for Sunday.
+	pub fn matches_date(&self, month: u8, day: u8, weekday: u8) -> bool {
+		if !bit(self.months, u32::from(month)) {
+			return false;
+		}
+
+		let dom = bit(self.days_of_month, u32::from(day));
+		let dow = bit(self.days_of_week, u32::from(weekday));
+
+		match (self.day_of_month_any, self.day_of_week_any) {
+			(true, true) => true,
+			(true, false) => dow,
+			(false, true) => dom,
+			(false, false) => dom || dow,
+		}
+	}
+
+	fn has_reachable_day_of_month(&self) -> bool {
+		(1..=12_u32).any(|month| {
+			bit(self.months, month)
+				&& (1..=MONTH_MAX_DAYS[month as usize - 1]).any(|day| bit(self.days_of_month, day))
+		})
+	}
+}
+
+fn parse_field(field: &str, (min, max): (u32, u32)) -> Result<u64, String> {
+	let mut mask = 0_u64;
+
+	for part in field.split(',') {
+		let (range, step) = match part.split_once('/') {
+			Some((range, step)) => {
+				let step = step
+					.parse::<u32>()
+					.ok()
+					.filter(|step| *step > 0)
+					.ok_or_else(|| format!("has an invalid step in {part:?}."))?;
+
+				(range, step)
+			},
+			None => (part, 1),
+		};
+		let (start, end) = match range {
+			"*" => (min, max),
+			_ => match range.split_once('-') {
+				Some((start, end)) => (parse_value(start, part)?, parse_value(end, part)?),
+				None => {
+					let value = parse_value(range, part)?;
+
+					// `n/step` runs from n to the end of the field.
+					(value, if step > 1 { max } else { value })
+				},
+			},
+		};
+
+		if start < min || end > max || start > end {
+			return Err(format!("value {part:?} must be within {min}-{max}."));
+		}
+
+		for value in (start..=end).step_by(step as usize) {
+			mask |= 1 << value;
+		}
+	}
+
+	Ok(mask)
+}
+
+fn parse_value(value: &str, part: &str) -> Result<u32, String> {
+	value.parse::<u32>().map_err(|_| format!("has an invalid value in {part:?}."))
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+	value < 64 && mask & (1 << value) != 0
+}
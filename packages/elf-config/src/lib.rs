@@ -1,22 +1,34 @@
 //! ELF configuration loading and validation.
 
+mod cron;
 mod error;
+mod lint;
 mod loader;
 mod types;
 mod validation;
 
 pub use self::{
+	cron::CronSchedule,
 	error::{Error, Result},
-	loader::load,
+	lint::{ConfigLint, ConfigLintKind, DEPRECATED_KEYS, lint, migrate_deprecated_keys},
+	loader::{load, load_with_lints},
 	types::{
-		Chunking, Config, Context, EmbeddingProviderConfig, Lifecycle, LlmProviderConfig,
-		McpContext, Memory, MemoryPolicy, MemoryPolicyRule, Postgres, ProviderConfig, Providers,
-		Qdrant, Ranking, RankingBlend, RankingBlendSegment, RankingDeterministic,
-		RankingDeterministicDecay, RankingDeterministicHits, RankingDeterministicLexical,
-		RankingDiversity, RankingRetrievalSources, ReadProfiles, ScopePrecedence,
-		ScopeWriteAllowed, Scopes, Search, SearchCache, SearchDynamic, SearchExpansion,
-		SearchExplain, SearchGraphContext, SearchPrefilter, SearchRecursive, Security,
-		SecurityAuthKey, SecurityAuthRole, Service, Storage, TtlDays,
+		Chunking, ChunkingTypeOverride, Config, Context, DEFAULT_PURGE_TRASHED_AFTER_DAYS,
+		EmbeddingCache, EmbeddingProjection, EmbeddingProviderConfig, EmbeddingQueryInput,
+		Lifecycle, LifecycleConsolidation, LifecycleImportanceRescore, LifecycleSchedule,
+		LlmProviderConfig, McpContext, Memory, MemoryPolicy, MemoryPolicyRule, MemoryWriteAnomaly,
+		Postgres, ProviderConfig, ProviderResilience, Providers, Qdrant, QdrantMaintenance,
+		Ranking, RankingBlend, RankingBlendSegment, RankingDeterministic,
+		RankingDeterministicDecay, RankingDeterministicEvidence, RankingDeterministicFeedback,
+		RankingDeterministicHits, RankingDeterministicLexical, RankingDeterministicSession,
+		RankingDiversity, RankingDiversityScopeBalance, RankingRetrievalSources, ReadProfiles,
+		Reembed, ScopePrecedence, ScopeWriteAllowed, Scopes, Search, SearchAnswer, SearchCache,
+		SearchDegraded, SearchDynamic, SearchExpansion, SearchExplain, SearchGraphContext,
+		SearchPrefilter, SearchRecursive, SearchResultCache, SearchSnippet, SearchSnippetLimit,
+		Security, SecurityAuthKey, SecurityAuthRole, SecurityEgress, SecurityJwt,
+		SecurityJwtClaims, SecurityPii, SecurityPiiAction, SecurityQuotas, SecurityRolePermissions,
+		SecuritySecretPattern, SecuritySecretPatterns, Service, ServiceOtel, Shadow, Storage,
+		TtlDays, UrlSnapshots, Warmup, Worker,
 	},
 	validation::validate,
 };
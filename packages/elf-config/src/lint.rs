@@ -0,0 +1,237 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use toml::{Table, Value};
+
+use crate::Config;
+
+/// Deprecated dotted key paths and the keys that replace them.
+///
+/// Deprecated keys are migrated in place before deserialization so older config files keep
+/// loading, and every migration is reported as a lint. Add an entry here when a released key is
+/// renamed; no key has been renamed yet.
+pub const DEPRECATED_KEYS: &[(&str, &str)] = &[];
+
+/// Category of a non-fatal config lint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConfigLintKind {
+	/// A deprecated key was found and migrated to its replacement.
+	DeprecatedKey,
+	/// A key is not part of the config schema and was ignored.
+	UnknownKey,
+	/// A section is present but has no runtime effect.
+	UnusedSection,
+	/// A setting differs from the recommended value.
+	NotRecommended,
+}
+impl ConfigLintKind {
+	/// Returns the stable snake_case label for this lint kind.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::DeprecatedKey => "deprecated_key",
+			Self::UnknownKey => "unknown_key",
+			Self::UnusedSection => "unused_section",
+			Self::NotRecommended => "not_recommended",
+		}
+	}
+}
+
+/// One non-fatal config warning with an actionable hint.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigLint {
+	/// Lint category.
+	pub kind: ConfigLintKind,
+	/// Dotted config key the lint refers to.
+	pub key: String,
+	/// Human-readable description of the problem.
+	pub message: String,
+	/// Suggested change that resolves the lint.
+	pub hint: String,
+}
+impl ConfigLint {
+	fn new(
+		kind: ConfigLintKind,
+		key: impl Into<String>,
+		message: impl Into<String>,
+		hint: impl Into<String>,
+	) -> Self {
+		Self { kind, key: key.into(), message: message.into(), hint: hint.into() }
+	}
+
+	pub(crate) fn unknown_key(key: String) -> Self {
+		Self::new(
+			ConfigLintKind::UnknownKey,
+			key.clone(),
+			format!("{key} is not a recognized config key and is ignored."),
+			"Remove the key or check its spelling against the config spec.",
+		)
+	}
+}
+impl Display for ConfigLint {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "[{}] {} Hint: {}", self.kind.as_str(), self.message, self.hint)
+	}
+}
+
+/// Returns semantic lints for a deserialized config.
+///
+/// Lints never reject a config; they flag sections without effect and settings that drift
+/// from recommended values.
+pub fn lint(cfg: &Config) -> Vec<ConfigLint> {
+	let mut lints = Vec::new();
+
+	lint_unused_sections(cfg, &mut lints);
+	lint_not_recommended(cfg, &mut lints);
+
+	lints
+}
+
+/// Moves each deprecated key in `deprecated` to its replacement and reports one lint per key.
+///
+/// The loader passes [`DEPRECATED_KEYS`]; the table is a parameter so the mechanism can be
+/// exercised while that list is empty.
+pub fn migrate_deprecated_keys(root: &mut Table, deprecated: &[(&str, &str)]) -> Vec<ConfigLint> {
+	let mut lints = Vec::new();
+
+	for (old_key, new_key) in deprecated {
+		let (parent_path, old_leaf) = split_key(old_key);
+		let (_, new_leaf) = split_key(new_key);
+		let Some(parent) = table_at_mut(root, parent_path) else { continue };
+		let Some(value) = parent.remove(old_leaf) else { continue };
+
+		if parent.contains_key(new_leaf) {
+			lints.push(ConfigLint::new(
+				ConfigLintKind::DeprecatedKey,
+				*old_key,
+				format!("{old_key} is deprecated and ignored because {new_key} is also set."),
+				format!("Remove {old_key}."),
+			));
+		} else {
+			parent.insert(new_leaf.to_string(), value);
+			lints.push(ConfigLint::new(
+				ConfigLintKind::DeprecatedKey,
+				*old_key,
+				format!("{old_key} is deprecated and was migrated to {new_key}."),
+				format!("Rename {old_key} to {new_key}."),
+			));
+		}
+	}
+
+	lints
+}
+
+fn split_key(key: &str) -> (&str, &str) {
+	key.rsplit_once('.').unwrap_or(("", key))
+}
+
+fn table_at_mut<'a>(root: &'a mut Table, path: &str) -> Option<&'a mut Table> {
+	let mut table = root;
+
+	for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+		table = table.get_mut(segment).and_then(Value::as_table_mut)?;
+	}
+
+	Some(table)
+}
+
+fn lint_unused_sections(cfg: &Config, lints: &mut Vec<ConfigLint>) {
+	if cfg.shadow.as_ref().is_some_and(|shadow| !shadow.enabled) {
+		lints.push(disabled_section("shadow"));
+	}
+	if cfg.embedding_projection.as_ref().is_some_and(|projection| !projection.enabled) {
+		lints.push(disabled_section("embedding_projection"));
+	}
+	if cfg.url_snapshots.as_ref().is_some_and(|snapshots| !snapshots.enabled) {
+		lints.push(disabled_section("url_snapshots"));
+	}
+	if cfg.warmup.as_ref().is_some_and(|warmup| !warmup.enabled) {
+		lints.push(disabled_section("warmup"));
+	}
+	if cfg.context.as_ref().is_some_and(|context| {
+		context.project_descriptions.is_none()
+			&& context.scope_descriptions.is_none()
+			&& context.scope_boost_weight.is_none()
+	}) {
+		lints.push(ConfigLint::new(
+			ConfigLintKind::UnusedSection,
+			"context",
+			"[context] is present but sets no keys.",
+			"Remove the empty [context] section.",
+		));
+	}
+
+	let deterministic = &cfg.ranking.deterministic;
+
+	if deterministic.enabled {
+		return;
+	}
+
+	for (key, enabled) in [
+		("ranking.deterministic.lexical", deterministic.lexical.enabled),
+		("ranking.deterministic.hits", deterministic.hits.enabled),
+		("ranking.deterministic.decay", deterministic.decay.enabled),
+		(
+			"ranking.deterministic.evidence",
+			deterministic.evidence.as_ref().is_some_and(|evidence| evidence.enabled),
+		),
+		(
+			"ranking.deterministic.session",
+			deterministic.session.as_ref().is_some_and(|session| session.enabled),
+		),
+		(
+			"ranking.deterministic.feedback",
+			deterministic.feedback.as_ref().is_some_and(|feedback| feedback.enabled),
+		),
+	] {
+		if enabled {
+			lints.push(ConfigLint::new(
+				ConfigLintKind::UnusedSection,
+				key,
+				format!("{key}.enabled is true but ranking.deterministic.enabled is false."),
+				"Set ranking.deterministic.enabled = true or disable the term.",
+			));
+		}
+	}
+}
+
+fn disabled_section(section: &str) -> ConfigLint {
+	ConfigLint::new(
+		ConfigLintKind::UnusedSection,
+		section,
+		format!("[{section}] is present but {section}.enabled is false, so it has no effect."),
+		format!("Remove the [{section}] section or set {section}.enabled = true."),
+	)
+}
+
+fn lint_not_recommended(cfg: &Config, lints: &mut Vec<ConfigLint>) {
+	for (key, value, reason) in [
+		(
+			"security.redact_secrets_on_write",
+			cfg.security.redact_secrets_on_write,
+			"Secrets in note text are stored verbatim.",
+		),
+		(
+			"search.cache.enabled",
+			cfg.search.cache.enabled,
+			"Repeated queries re-run expansion and rerank.",
+		),
+		(
+			"search.expansion.include_original",
+			cfg.search.expansion.include_original,
+			"Expanded searches drop the caller's original query.",
+		),
+		(
+			"ranking.diversity.enabled",
+			cfg.ranking.diversity.enabled,
+			"Near-duplicate notes can crowd out the result set.",
+		),
+	] {
+		if !value {
+			lints.push(ConfigLint::new(
+				ConfigLintKind::NotRecommended,
+				key,
+				format!("{key} is false; the recommended value is true. {reason}"),
+				format!("Set {key} = true unless this is intentional."),
+			));
+		}
+	}
+}
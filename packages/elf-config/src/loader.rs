@@ -1,15 +1,34 @@
 use std::{fs, path::Path};
 
-use crate::{Config, Error, Result, validation};
+use toml::{Table, Value};
+
+use crate::{Config, ConfigLint, Error, Result, lint, validation};
 
 /// Loads, deserializes, and validates an ELF TOML configuration file.
 pub fn load(path: &Path) -> Result<Config> {
+	load_with_lints(path).map(|(cfg, _)| cfg)
+}
+
+/// Loads an ELF TOML configuration file and returns it with its non-fatal lints.
+///
+/// Deprecated keys are migrated before deserialization, unknown keys are reported instead of
+/// silently dropped, and semantic lints run after validation succeeds.
+pub fn load_with_lints(path: &Path) -> Result<(Config, Vec<ConfigLint>)> {
 	let raw = fs::read_to_string(path)
 		.map_err(|err| Error::ReadConfig { path: path.to_path_buf(), source: err })?;
-	let cfg: Config = toml::from_str(&raw)
+	let mut root: Table = toml::from_str(&raw)
 		.map_err(|err| Error::ParseConfig { path: path.to_path_buf(), source: err })?;
+	let mut lints = lint::migrate_deprecated_keys(&mut root, lint::DEPRECATED_KEYS);
+	let mut unknown_keys = Vec::new();
+	let cfg: Config = serde_ignored::deserialize(Value::Table(root), |key| {
+		unknown_keys.push(key.to_string());
+	})
+	.map_err(|err| Error::ParseConfig { path: path.to_path_buf(), source: err })?;
 
 	validation::validate(&cfg)?;
 
-	Ok(cfg)
+	lints.extend(unknown_keys.into_iter().map(ConfigLint::unknown_key));
+	lints.extend(lint::lint(&cfg));
+
+	Ok((cfg, lints))
 }
@@ -1,34 +1,60 @@
 mod chunking;
 mod context;
+mod embedding_projection;
 mod lifecycle;
 mod memory;
 mod providers;
+mod qdrant_maintenance;
 mod ranking;
+mod reembed;
 mod scopes;
 mod search;
 mod security;
 mod service;
+mod shadow;
 mod storage;
+mod url_snapshots;
+mod warmup;
+mod worker;
 
 pub use self::{
-	chunking::Chunking,
+	chunking::{Chunking, ChunkingTypeOverride},
 	context::{Context, McpContext},
-	lifecycle::{Lifecycle, TtlDays},
-	memory::{Memory, MemoryPolicy, MemoryPolicyRule},
-	providers::{EmbeddingProviderConfig, LlmProviderConfig, ProviderConfig, Providers},
+	embedding_projection::EmbeddingProjection,
+	lifecycle::{
+		DEFAULT_PURGE_TRASHED_AFTER_DAYS, Lifecycle, LifecycleConsolidation,
+		LifecycleImportanceRescore, LifecycleSchedule, TtlDays,
+	},
+	memory::{Memory, MemoryPolicy, MemoryPolicyRule, MemoryWriteAnomaly},
+	providers::{
+		EmbeddingCache, EmbeddingProviderConfig, EmbeddingQueryInput, LlmProviderConfig,
+		ProviderConfig, ProviderResilience, Providers,
+	},
+	qdrant_maintenance::QdrantMaintenance,
 	ranking::{
 		Ranking, RankingBlend, RankingBlendSegment, RankingDeterministic,
-		RankingDeterministicDecay, RankingDeterministicHits, RankingDeterministicLexical,
-		RankingDiversity, RankingRetrievalSources,
+		RankingDeterministicDecay, RankingDeterministicEvidence, RankingDeterministicFeedback,
+		RankingDeterministicHits, RankingDeterministicLexical, RankingDeterministicSession,
+		RankingDiversity, RankingDiversityScopeBalance, RankingRetrievalSources,
 	},
+	reembed::Reembed,
 	scopes::{ReadProfiles, ScopePrecedence, ScopeWriteAllowed, Scopes},
 	search::{
-		Search, SearchCache, SearchDynamic, SearchExpansion, SearchExplain, SearchGraphContext,
-		SearchPrefilter, SearchRecursive,
+		Search, SearchAnswer, SearchCache, SearchDegraded, SearchDynamic, SearchExpansion,
+		SearchExplain, SearchGraphContext, SearchPrefilter, SearchRecursive, SearchResultCache,
+		SearchSnippet, SearchSnippetLimit,
+	},
+	security::{
+		Security, SecurityAuthKey, SecurityAuthRole, SecurityEgress, SecurityJwt,
+		SecurityJwtClaims, SecurityPii, SecurityPiiAction, SecurityQuotas, SecurityRolePermissions,
+		SecuritySecretPattern, SecuritySecretPatterns,
 	},
-	security::{Security, SecurityAuthKey, SecurityAuthRole},
-	service::Service,
+	service::{Service, ServiceOtel},
+	shadow::Shadow,
 	storage::{Postgres, Qdrant, Storage},
+	url_snapshots::UrlSnapshots,
+	warmup::Warmup,
+	worker::Worker,
 };
 
 use serde::Deserialize;
@@ -60,4 +86,18 @@ pub struct Config {
 	pub context: Option<Context>,
 	/// Optional MCP forwarding context used by `elf-mcp`.
 	pub mcp: Option<McpContext>,
+	/// Optional search traffic shadowing to a second deployment.
+	pub shadow: Option<Shadow>,
+	/// Optional legacy-embedding projection used during vector dimension migrations.
+	pub embedding_projection: Option<EmbeddingProjection>,
+	/// Optional worker snapshotting of external URLs referenced by note evidence.
+	pub url_snapshots: Option<UrlSnapshots>,
+	/// Optional elf-api startup warm-up that gates readiness.
+	pub warmup: Option<Warmup>,
+	/// Optional elf-worker schedule for Qdrant optimizer, snapshot, and point-count maintenance.
+	pub qdrant_maintenance: Option<QdrantMaintenance>,
+	/// Optional target of admin re-embed runs that migrate notes to a new embedding model.
+	pub reembed: Option<Reembed>,
+	/// Optional elf-worker outbox concurrency; omitted runs one note-indexing job at a time.
+	pub worker: Option<Worker>,
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 /// Sentence-aware token chunking settings.
@@ -11,4 +13,23 @@ pub struct Chunking {
 	pub overlap_tokens: u32,
 	/// Hugging Face tokenizer repo used for token counting.
 	pub tokenizer_repo: String,
+	/// Per-note-type overrides keyed by note type; unset fields inherit the global settings.
+	#[serde(default)]
+	pub per_type: Option<HashMap<String, ChunkingTypeOverride>>,
+}
+
+/// Chunking override applied to one note type at indexing time.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ChunkingTypeOverride {
+	/// Whether notes of this type are split; `false` indexes each note as one whole-text chunk.
+	pub enabled: Option<bool>,
+	/// Maximum tokens allowed in one chunk for this note type.
+	pub max_tokens: Option<u32>,
+	/// Number of tail tokens overlapped into the next chunk for this note type.
+	pub overlap_tokens: Option<u32>,
+	/// Split strategy: `token_window` (default) or `semantic`, which also breaks chunks where
+	/// adjacent sentence embeddings drift apart.
+	pub strategy: Option<String>,
+	/// Cosine similarity between adjacent sentences below which a `semantic` chunk ends.
+	pub similarity_threshold: Option<f32>,
 }
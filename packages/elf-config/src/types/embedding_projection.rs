@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+/// Projection of legacy higher-dimension embeddings into the configured vector dimension.
+///
+/// Used while migrating to a lower-dimension embedding model so notes that have not been
+/// re-embedded yet stay searchable at reduced fidelity.
+#[derive(Debug, Deserialize)]
+pub struct EmbeddingProjection {
+	/// Whether legacy vectors may be projected during Qdrant rebuilds.
+	pub enabled: bool,
+	/// Projection method. Only `truncate` is supported.
+	pub mode: String,
+	/// Dimension of the legacy vectors being projected.
+	pub source_dim: u32,
+}
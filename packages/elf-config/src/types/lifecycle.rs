@@ -1,5 +1,8 @@
 use serde::Deserialize;
 
+/// Days a trashed note is kept when `purge_trashed_after_days` is unset.
+pub const DEFAULT_PURGE_TRASHED_AFTER_DAYS: i64 = 30;
+
 /// Lifecycle retention and purge settings.
 #[derive(Debug, Deserialize)]
 pub struct Lifecycle {
@@ -9,6 +12,80 @@ pub struct Lifecycle {
 	pub purge_deleted_after_days: i64,
 	/// Days to retain deprecated notes before purge.
 	pub purge_deprecated_after_days: i64,
+	/// Days a trashed note stays restorable before the worker purges it. Defaults to
+	/// [`DEFAULT_PURGE_TRASHED_AFTER_DAYS`].
+	#[serde(default)]
+	pub purge_trashed_after_days: Option<i64>,
+	/// Optional worker schedule that merges near-duplicate notes; unset disables it.
+	#[serde(default)]
+	pub consolidation: Option<LifecycleConsolidation>,
+	/// Optional worker schedule that expires and purges notes and prunes stale traces; unset
+	/// disables it.
+	#[serde(default)]
+	pub schedule: Option<LifecycleSchedule>,
+	/// Optional worker task that recomputes note importance from usage signals; unset disables it.
+	#[serde(default)]
+	pub importance_rescore: Option<LifecycleImportanceRescore>,
+}
+impl Lifecycle {
+	/// Returns the trash retention window in days.
+	pub fn trash_retention_days(&self) -> i64 {
+		self.purge_trashed_after_days.unwrap_or(DEFAULT_PURGE_TRASHED_AFTER_DAYS)
+	}
+}
+
+/// Worker schedule for merging near-duplicate notes through the LLM extractor.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LifecycleConsolidation {
+	/// Whether elf-worker runs automatic consolidation.
+	pub enabled: bool,
+	/// Seconds between consolidation passes.
+	pub interval_seconds: u64,
+	/// Minimum cosine similarity between two note embeddings for them to be merged.
+	pub similarity_threshold: f32,
+	/// Largest number of note merges applied in one pass.
+	pub max_merges_per_run: u32,
+}
+
+/// Worker schedule for the lifecycle runner.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LifecycleSchedule {
+	/// Whether elf-worker runs the lifecycle runner.
+	pub enabled: bool,
+	/// Five-field UTC cron expression, for example `"0 3 * * *"` for daily at 03:00.
+	pub cron: String,
+	/// Largest number of notes expired or purged per batch.
+	pub batch_size: u32,
+}
+
+/// Worker task that recomputes note importance from hits, feedback, and age.
+///
+/// Each pass sets `importance = clamp(prior_weight * importance + hits_weight * hits +
+/// feedback_weight * feedback + age_weight * freshness, 0, 1)` for active notes.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LifecycleImportanceRescore {
+	/// Whether elf-worker runs importance re-scoring.
+	pub enabled: bool,
+	/// Seconds between re-scoring passes.
+	pub interval_seconds: u64,
+	/// Largest number of notes loaded per batch.
+	pub batch_size: u32,
+	/// Smallest importance change that is written; smaller changes are skipped.
+	pub min_delta: f32,
+	/// Weight kept from the current importance.
+	pub prior_weight: f32,
+	/// Weight of the hit signal: saturating hit count decayed by last-hit age.
+	pub hits_weight: f32,
+	/// Hit count at which the hit signal reaches half of its maximum.
+	pub hits_half_saturation: f32,
+	/// Decay window in days for the last-hit age.
+	pub last_hit_tau_days: f32,
+	/// Weight of the net feedback signal in [-1, 1].
+	pub feedback_weight: f32,
+	/// Weight of the freshness signal, which decays with note age.
+	pub age_weight: f32,
+	/// Decay window in days for note age.
+	pub age_tau_days: f32,
 }
 
 /// TTL values in days for each note type.
@@ -15,10 +15,28 @@ pub struct Memory {
 	pub candidate_k: u32,
 	/// Final top-k size for note retrieval.
 	pub top_k: u32,
+	/// Optional window in milliseconds within which successive updates by the same actor share
+	/// one version row and one pending outbox job.
+	pub version_coalesce_window_ms: Option<u64>,
+	/// Optional detector for agents writing near-duplicate notes at a high rate.
+	pub write_anomaly: Option<MemoryWriteAnomaly>,
 	/// Optional downgrade rules applied after base memory decisions.
 	pub policy: MemoryPolicy,
 }
 
+/// Sliding-window similarity and frequency limits applied to each writing agent.
+#[derive(Debug, Deserialize)]
+pub struct MemoryWriteAnomaly {
+	/// Sliding window in seconds over which the agent's recent notes are compared.
+	pub window_seconds: u64,
+	/// Number of similar notes within the window at which further writes are throttled.
+	pub max_similar_writes: u32,
+	/// Token-set similarity at or above which two notes count as similar.
+	pub similarity_threshold: f32,
+	/// `reject` refuses the write with `RATE_ANOMALY`; `report` only records an incident.
+	pub action: String,
+}
+
 /// Collection of memory-policy downgrade rules.
 #[derive(Debug, Deserialize)]
 pub struct MemoryPolicy {
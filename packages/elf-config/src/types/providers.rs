@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 use serde_json::{Map, Value};
 
@@ -6,14 +8,82 @@ use serde_json::{Map, Value};
 pub struct Providers {
 	/// Embedding provider used for vector generation.
 	pub embedding: EmbeddingProviderConfig,
+	#[serde(default)]
+	/// Optional named embedding providers that scope and note type routes select by name.
+	pub embeddings: Option<HashMap<String, EmbeddingProviderConfig>>,
+	/// Optional per-scope embedding provider routes from scope label to provider name.
+	///
+	/// Scopes without a route use `embedding`.
+	pub embedding_scopes: Option<HashMap<String, String>>,
+	/// Optional per-note-type embedding provider routes from note type to provider name.
+	///
+	/// A note type route takes precedence over the note's scope route.
+	#[serde(default)]
+	pub embedding_types: Option<HashMap<String, String>>,
 	/// Rerank provider used for late-stage scoring.
 	pub rerank: ProviderConfig,
 	/// LLM provider used by extraction flows such as `add_event`.
 	pub llm_extractor: LlmProviderConfig,
 }
+impl Providers {
+	/// Route name that selects `embedding` instead of a named provider.
+	pub const DEFAULT_EMBEDDING: &str = "default";
+
+	/// Returns the embedding provider a route `name` selects, or `None` for an unknown name.
+	pub fn embedding_named(&self, name: &str) -> Option<&EmbeddingProviderConfig> {
+		if name == Self::DEFAULT_EMBEDDING {
+			return Some(&self.embedding);
+		}
+
+		self.embeddings.as_ref().and_then(|embeddings| embeddings.get(name))
+	}
+
+	/// Returns the embedding provider routed for notes in `scope`.
+	pub fn embedding_for_scope(&self, scope: &str) -> &EmbeddingProviderConfig {
+		self.embedding_scopes
+			.as_ref()
+			.and_then(|routes| routes.get(scope))
+			.and_then(|name| self.embedding_named(name))
+			.unwrap_or(&self.embedding)
+	}
+
+	/// Returns the embedding provider routed for a note of `note_type` in `scope`.
+	///
+	/// A note type route takes precedence over the scope route.
+	pub fn embedding_for_note(&self, scope: &str, note_type: &str) -> &EmbeddingProviderConfig {
+		self.embedding_types
+			.as_ref()
+			.and_then(|routes| routes.get(note_type))
+			.and_then(|name| self.embedding_named(name))
+			.unwrap_or_else(|| self.embedding_for_scope(scope))
+	}
+
+	/// Returns the provider each routed scope resolves to, keyed by scope label.
+	pub fn embedding_scope_providers(&self) -> HashMap<String, EmbeddingProviderConfig> {
+		self.resolve_embedding_routes(self.embedding_scopes.as_ref())
+	}
+
+	/// Returns the provider each routed note type resolves to, keyed by note type.
+	pub fn embedding_type_providers(&self) -> HashMap<String, EmbeddingProviderConfig> {
+		self.resolve_embedding_routes(self.embedding_types.as_ref())
+	}
+
+	fn resolve_embedding_routes(
+		&self,
+		routes: Option<&HashMap<String, String>>,
+	) -> HashMap<String, EmbeddingProviderConfig> {
+		routes
+			.into_iter()
+			.flatten()
+			.filter_map(|(key, name)| {
+				self.embedding_named(name).map(|provider| (key.clone(), provider.clone()))
+			})
+			.collect()
+	}
+}
 
 /// Embedding-provider settings.
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct EmbeddingProviderConfig {
 	/// Provider implementation identifier.
 	pub provider_id: String,
@@ -31,6 +101,46 @@ pub struct EmbeddingProviderConfig {
 	pub timeout_ms: u64,
 	/// Extra HTTP headers sent with embedding requests.
 	pub default_headers: Map<String, Value>,
+	#[serde(default)]
+	/// Optional query-side input construction. Defaults to the `context_suffix` strategy.
+	pub query_input: Option<EmbeddingQueryInput>,
+	#[serde(default)]
+	/// Model directory for `provider_id = "local_model"`, holding `config.json`, `tokenizer.json`,
+	/// and `model.safetensors`.
+	pub model_dir: Option<String>,
+	#[serde(default)]
+	/// Optional retry and circuit-breaker policy for remote embedding calls.
+	pub resilience: Option<ProviderResilience>,
+	#[serde(default)]
+	/// Optional in-process vector cache and request coalescing for this provider.
+	pub cache: Option<EmbeddingCache>,
+}
+
+/// In-process embedding cache and request coalescing for one embedding provider.
+///
+/// Vectors are cached by provider, model, dimensions, and text hash, so repeated texts skip the
+/// provider call until they are evicted.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EmbeddingCache {
+	/// Maximum cached vectors; the least recently used vector is evicted first.
+	pub max_entries: u32,
+	#[serde(default)]
+	/// Milliseconds to wait for concurrent embed calls to join one provider request; `0` disables
+	/// coalescing.
+	pub batch_window_ms: u64,
+	#[serde(default)]
+	/// Maximum texts in one coalesced provider request; a full batch is sent early. Defaults to
+	/// 256.
+	pub max_batch_texts: Option<u32>,
+}
+
+/// Query-side embedding input construction for one embedding model.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EmbeddingQueryInput {
+	/// Strategy: `query_only`, `context_suffix`, `context_prefix`, or `template`.
+	pub strategy: String,
+	/// Template with `{query}` and optional `{context}` placeholders, for the `template` strategy.
+	pub template: Option<String>,
 }
 
 /// Generic provider settings shared by non-embedding APIs such as rerank.
@@ -50,10 +160,18 @@ pub struct ProviderConfig {
 	pub timeout_ms: u64,
 	/// Extra HTTP headers sent with provider requests.
 	pub default_headers: Map<String, Value>,
+	#[serde(default)]
+	/// Optional retry and circuit-breaker policy for remote provider calls.
+	pub resilience: Option<ProviderResilience>,
+	#[serde(default)]
+	/// Optional upper bound on documents sent per rerank request.
+	///
+	/// Larger candidate sets are split into batches that run a few at a time.
+	pub max_docs_per_call: Option<u32>,
 }
 
 /// LLM extractor provider settings.
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct LlmProviderConfig {
 	/// Provider implementation identifier.
 	pub provider_id: String,
@@ -71,4 +189,28 @@ pub struct LlmProviderConfig {
 	pub timeout_ms: u64,
 	/// Extra HTTP headers sent with extraction requests.
 	pub default_headers: Map<String, Value>,
+	#[serde(default)]
+	/// Optional retry and circuit-breaker policy for remote extraction calls.
+	pub resilience: Option<ProviderResilience>,
+}
+
+/// Retry, backoff, and circuit-breaker settings for one remote provider.
+///
+/// Only transient failures (timeouts, connection errors, HTTP 429, and HTTP 5xx) are retried.
+/// Every failed call, after its retries, counts toward the breaker.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProviderResilience {
+	/// Retries after the first failed attempt.
+	pub max_retries: u32,
+	/// Delay before the first retry in milliseconds; doubles on each further retry.
+	pub initial_backoff_ms: u64,
+	/// Upper bound on one retry delay in milliseconds.
+	pub max_backoff_ms: u64,
+	#[serde(default)]
+	/// Optional wall-clock budget in milliseconds for one call, covering every attempt and delay.
+	pub call_timeout_ms: Option<u64>,
+	/// Consecutive failed calls that open the breaker; `0` disables the breaker.
+	pub failure_threshold: u32,
+	/// Milliseconds the breaker stays open before one trial call is let through.
+	pub open_ms: u64,
 }
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+/// Optional worker schedule for Qdrant optimizer runs, collection snapshots, and point-count
+/// checks.
+#[derive(Clone, Debug, Deserialize)]
+pub struct QdrantMaintenance {
+	/// Whether elf-worker runs the scheduled operations.
+	pub enabled: bool,
+	/// Seconds between optimizer runs on both collections; unset disables the schedule.
+	pub optimize_interval_seconds: Option<u64>,
+	/// Seconds between collection snapshots; unset disables the schedule.
+	pub snapshot_interval_seconds: Option<u64>,
+	/// Seconds between Qdrant point-count checks against Postgres; unset disables the schedule.
+	pub verify_interval_seconds: Option<u64>,
+	/// Number of most recent snapshots kept per collection; older snapshots are deleted.
+	pub snapshot_retention: u32,
+	/// Largest point-count difference between Qdrant and Postgres that still passes verification.
+	pub max_count_drift: u64,
+	/// Qdrant REST base URL, required to restore snapshots.
+	pub rest_url: Option<String>,
+	/// Webhook notified when a scheduled operation fails.
+	pub alert_webhook: Option<String>,
+}
@@ -28,6 +28,15 @@ pub struct RankingDeterministic {
 	pub hits: RankingDeterministicHits,
 	/// Decay term settings.
 	pub decay: RankingDeterministicDecay,
+	/// Optional evidence-coverage term settings.
+	#[serde(default)]
+	pub evidence: Option<RankingDeterministicEvidence>,
+	/// Optional within-session term settings.
+	#[serde(default)]
+	pub session: Option<RankingDeterministicSession>,
+	/// Optional recorded-feedback term settings.
+	#[serde(default)]
+	pub feedback: Option<RankingDeterministicFeedback>,
 }
 
 /// Lexical-overlap deterministic term.
@@ -69,6 +78,39 @@ pub struct RankingDeterministicDecay {
 	pub tau_days: f32,
 }
 
+/// Evidence-coverage deterministic term.
+#[derive(Debug, Deserialize)]
+pub struct RankingDeterministicEvidence {
+	/// Whether the evidence term is enabled.
+	pub enabled: bool,
+	/// Weight assigned to the evidence term.
+	pub weight: f32,
+	/// Best candidate coverage required before weaker candidates are penalized.
+	pub strong_coverage: f32,
+}
+
+/// Within-session deterministic term for notes a session already retrieved.
+#[derive(Debug, Deserialize)]
+pub struct RankingDeterministicSession {
+	/// Whether the session term is enabled.
+	pub enabled: bool,
+	/// Default mode: `boost` favors continuity and `penalty` favors novelty.
+	pub mode: String,
+	/// Weight assigned to the session term.
+	pub weight: f32,
+	/// Seconds a session remembers a retrieved note after its last recorded hit.
+	pub ttl_seconds: i64,
+}
+
+/// Deterministic term derived from aggregated result feedback.
+#[derive(Debug, Deserialize)]
+pub struct RankingDeterministicFeedback {
+	/// Whether the feedback term is enabled.
+	pub enabled: bool,
+	/// Largest boost or penalty the feedback term can contribute.
+	pub weight: f32,
+}
+
 /// Retrieval/rerank blending configuration.
 #[derive(Debug, Deserialize)]
 pub struct RankingBlend {
@@ -102,6 +144,20 @@ pub struct RankingDiversity {
 	pub mmr_lambda: f32,
 	/// Maximum number of skipped candidates before backfilling.
 	pub max_skips: u32,
+	/// Optional penalty on scopes or note types that already dominate the selection.
+	#[serde(default)]
+	pub scope_balance: Option<RankingDiversityScopeBalance>,
+}
+
+/// MMR penalty on over-represented scopes or note types.
+#[derive(Debug, Deserialize)]
+pub struct RankingDiversityScopeBalance {
+	/// Whether scope balancing is enabled.
+	pub enabled: bool,
+	/// Note attribute to balance: `scope` or `type`.
+	pub by: String,
+	/// Penalty scaled by the share of selected items that share the candidate's attribute.
+	pub weight: f32,
 }
 
 /// Source weighting and priority between fusion and structured-field retrieval.
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+use crate::EmbeddingProviderConfig;
+
+/// Target of an admin re-embed run that migrates notes to a new embedding model or dimension.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Reembed {
+	/// Qdrant collection that receives the re-embedded note points.
+	pub collection: String,
+	/// Notes re-embedded per provider call and progress checkpoint.
+	pub batch_size: u32,
+	/// Target embedding provider. Its `dimensions` is the target vector dimension.
+	pub provider: EmbeddingProviderConfig,
+}
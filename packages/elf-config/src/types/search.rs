@@ -17,6 +17,15 @@ pub struct Search {
 	pub recursive: SearchRecursive,
 	/// Graph-context enrichment settings.
 	pub graph_context: SearchGraphContext,
+	/// Optional per-payload-level snippet caps.
+	#[serde(default)]
+	pub snippet: Option<SearchSnippet>,
+	/// Optional grounded answer synthesis served by `search_answer`.
+	#[serde(default)]
+	pub answer: Option<SearchAnswer>,
+	/// Optional fallback used when the embedding provider fails at query time.
+	#[serde(default)]
+	pub degraded: Option<SearchDegraded>,
 }
 
 /// Query expansion settings.
@@ -57,6 +66,18 @@ pub struct SearchCache {
 	pub rerank_ttl_days: i64,
 	/// Optional upper bound on cached payload size in bytes.
 	pub max_payload_bytes: Option<u64>,
+	/// Optional whole-result cache for repeated identical searches.
+	#[serde(default)]
+	pub results: Option<SearchResultCache>,
+}
+
+/// Whole-result search cache keyed by the normalized request and a scope freshness watermark.
+#[derive(Debug, Deserialize)]
+pub struct SearchResultCache {
+	/// Whether finished search results are cached.
+	pub enabled: bool,
+	/// TTL in seconds for cached search results.
+	pub ttl_seconds: i64,
 }
 
 /// Search explainability retention and write-path settings.
@@ -85,6 +106,8 @@ pub struct SearchRecursive {
 	pub max_nodes_per_scope: u32,
 	/// Maximum nodes retained across the whole traversal.
 	pub max_total_nodes: u32,
+	/// Optional wall-clock budget in milliseconds for the whole recursive stage.
+	pub max_elapsed_ms: Option<u64>,
 }
 
 /// Graph-context enrichment limits applied to search responses.
@@ -97,3 +120,43 @@ pub struct SearchGraphContext {
 	/// Maximum evidence notes attached to one fact.
 	pub max_evidence_notes_per_fact: u32,
 }
+
+/// Snippet caps keyed by payload level; unset levels keep snippets as stitched from chunks.
+#[derive(Debug, Deserialize)]
+pub struct SearchSnippet {
+	/// Caps applied to `l0` responses.
+	pub l0: Option<SearchSnippetLimit>,
+	/// Caps applied to `l1` responses.
+	pub l1: Option<SearchSnippetLimit>,
+	/// Caps applied to `l2` responses.
+	pub l2: Option<SearchSnippetLimit>,
+}
+
+/// Character and token caps for snippets at one payload level.
+#[derive(Debug, Deserialize)]
+pub struct SearchSnippetLimit {
+	/// Maximum snippet characters, including the truncation marker.
+	pub max_chars: Option<u32>,
+	/// Maximum snippet tokens, counted with the chunking tokenizer.
+	pub max_tokens: Option<u32>,
+}
+
+/// Grounded answer synthesis settings; the endpoint is refused unless `enabled` is true.
+#[derive(Debug, Deserialize)]
+pub struct SearchAnswer {
+	/// Enables the `search_answer` endpoint.
+	pub enabled: bool,
+	/// Maximum retrieved notes handed to the extractor as sources.
+	pub max_sources: u32,
+	/// Maximum sentences kept in a synthesized answer.
+	pub max_sentences: u32,
+	/// Minimum grounding score, in 0.0-1.0, below which the answer is refused.
+	pub min_grounding: f32,
+}
+
+/// Degraded-mode fallback settings for query-time embedding failures.
+#[derive(Debug, Deserialize)]
+pub struct SearchDegraded {
+	/// Serves BM25-only retrieval without rerank instead of failing when query embedding fails.
+	pub bm25_fallback: bool,
+}
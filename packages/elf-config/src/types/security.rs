@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 /// Request security, evidence, and auth settings.
@@ -15,10 +17,156 @@ pub struct Security {
 	pub evidence_max_quotes: u32,
 	/// Maximum characters allowed in one evidence quote.
 	pub evidence_max_quote_chars: u32,
-	/// Authentication mode such as `off` or `static_keys`.
+	/// Authentication mode: `off`, `static_keys`, or `jwt`.
 	pub auth_mode: String,
 	/// Static bearer-token entries used when `auth_mode` is `static_keys`.
 	pub auth_keys: Vec<SecurityAuthKey>,
+	/// Bearer JWT validation used when `auth_mode` is `jwt`.
+	pub jwt: Option<SecurityJwt>,
+	/// Optional per-tenant write and search quotas.
+	pub quotas: Option<SecurityQuotas>,
+	/// Optional per-role write permissions; roles without an entry keep the default matrix.
+	pub permissions: Option<HashMap<SecurityAuthRole, SecurityRolePermissions>>,
+	/// Optional write-time PII detection policy; omitted means PII is stored unchanged.
+	pub pii: Option<SecurityPii>,
+	/// Optional secret-detection rules for the note writegate; omitted means the built-in rules.
+	pub secret_patterns: Option<SecuritySecretPatterns>,
+	/// Optional host lists for worker fetches and webhooks; non-public addresses are always
+	/// refused.
+	pub egress: Option<SecurityEgress>,
+}
+
+/// Hosts the worker may contact for URL snapshots and standing query webhooks.
+///
+/// Each entry matches the host and its subdomains.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SecurityEgress {
+	/// Hosts that may be contacted; empty allows every public host.
+	#[serde(default)]
+	pub allowed_hosts: Vec<String>,
+	/// Hosts that are never contacted, checked before `allowed_hosts`.
+	#[serde(default)]
+	pub denied_hosts: Vec<String>,
+}
+
+/// Secret-detection rules behind `REJECT_SECRET`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SecuritySecretPatterns {
+	/// Whether the built-in rules (private keys, provider keys, `password=` and similar) are off.
+	#[serde(default)]
+	pub disable_builtin: bool,
+	/// Deployment-specific rules checked alongside the built-in ones.
+	#[serde(default)]
+	pub custom: Vec<SecuritySecretPattern>,
+}
+
+/// One deployment-specific secret rule.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SecuritySecretPattern {
+	/// Stable label reported as `pattern_class` in reject feedback.
+	pub name: String,
+	/// Regular expression matched against note text.
+	pub pattern: String,
+	/// Minimum Shannon entropy, in bits per character, a match needs to count as a secret.
+	pub min_entropy: Option<f32>,
+}
+
+/// Per-category actions for PII detected in note text at write time.
+///
+/// A category left out of the table is allowed.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SecurityPii {
+	/// Action for email addresses.
+	#[serde(default)]
+	pub email: SecurityPiiAction,
+	/// Action for phone numbers.
+	#[serde(default)]
+	pub phone: SecurityPiiAction,
+	/// Action for payment card numbers that pass the Luhn check.
+	#[serde(default)]
+	pub credit_card: SecurityPiiAction,
+	/// Action for national identifiers such as US Social Security numbers.
+	#[serde(default)]
+	pub national_id: SecurityPiiAction,
+}
+
+/// What the write path does with one detected PII category.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityPiiAction {
+	/// Reject the note with `REJECT_PII`.
+	Reject,
+	/// Replace each match with a category placeholder before persistence.
+	Redact,
+	/// Store the text unchanged.
+	#[default]
+	Allow,
+}
+
+/// Scopes each write operation may target for one role.
+///
+/// An operation left out of the table may not target any scope.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SecurityRolePermissions {
+	/// Scopes writable through notes ingest and bulk import.
+	#[serde(default)]
+	pub add_note: Vec<String>,
+	/// Scopes writable through events ingest.
+	#[serde(default)]
+	pub add_event: Vec<String>,
+	/// Scopes whose notes may be patched, merged, published, or unpublished.
+	#[serde(default)]
+	pub update: Vec<String>,
+	/// Scopes whose notes may be trashed or restored.
+	#[serde(default)]
+	pub delete: Vec<String>,
+}
+
+/// Bearer JWT validation against an OIDC provider's JWKS.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SecurityJwt {
+	/// http(s) URL of the JSON Web Key Set that signs accepted tokens.
+	pub jwks_url: String,
+	/// Required `iss` claim.
+	pub issuer: String,
+	/// Required `aud` claim.
+	pub audience: String,
+	/// Accepted signing algorithms, such as `RS256` or `ES256`.
+	pub algorithms: Vec<String>,
+	/// Seconds a fetched key set is reused before it is fetched again.
+	pub jwks_cache_seconds: u64,
+	/// Clock skew allowed when checking `exp` and `nbf`.
+	pub leeway_seconds: u64,
+	/// Claim names that carry the request context.
+	pub claims: SecurityJwtClaims,
+}
+
+/// Names of the JWT claims mapped onto the request context.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SecurityJwtClaims {
+	/// Claim holding the tenant identifier.
+	pub tenant_id: String,
+	/// Claim holding the project identifier.
+	pub project_id: String,
+	/// Claim holding the agent identifier.
+	pub agent_id: String,
+	/// Claim holding the read profile.
+	pub read_profile: String,
+	/// Optional claim holding `reader`, `user`, or `admin`; tokens without it are users.
+	pub role: Option<String>,
+}
+
+/// Per-tenant limits that keep one tenant from exhausting shared capacity.
+///
+/// Every limit is optional; an unset limit is not enforced.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SecurityQuotas {
+	/// Maximum notes a tenant may create per UTC day.
+	pub max_notes_per_day: Option<u32>,
+	/// Maximum searches a tenant may start per second on one API process.
+	pub max_search_qps: Option<u32>,
+	/// Maximum notes a tenant may hold, counting trashed notes that can still be restored.
+	pub max_total_notes: Option<u64>,
 }
 
 /// A single static bearer-token entry.
@@ -42,9 +190,11 @@ pub struct SecurityAuthKey {
 }
 
 /// Role values accepted by static auth keys.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SecurityAuthRole {
+	/// Read-only token that can search but never write.
+	Reader,
 	/// Standard user token.
 	User,
 	/// Admin token with elevated write privileges.
@@ -52,3 +202,14 @@ pub enum SecurityAuthRole {
 	/// Super-admin token for global admin operations.
 	SuperAdmin,
 }
+impl SecurityAuthRole {
+	/// Returns the configured role name.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Reader => "reader",
+			Self::User => "user",
+			Self::Admin => "admin",
+			Self::SuperAdmin => "super_admin",
+		}
+	}
+}
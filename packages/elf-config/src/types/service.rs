@@ -11,4 +11,32 @@ pub struct Service {
 	pub admin_bind: String,
 	/// Default service log level.
 	pub log_level: String,
+	/// Deployment topology: `split` (default) runs elf-api, elf-worker, and elf-mcp as separate
+	/// processes; `all_in_one` runs all three inside the `elf` binary.
+	#[serde(default)]
+	pub mode: Option<String>,
+	/// Optional OpenTelemetry trace export.
+	#[serde(default)]
+	pub otel: Option<ServiceOtel>,
+}
+impl Service {
+	/// Returns whether the config selects the single-process `all_in_one` topology.
+	pub fn is_all_in_one(&self) -> bool {
+		self.mode.as_deref().is_some_and(|mode| mode.trim() == "all_in_one")
+	}
+}
+
+/// OpenTelemetry trace export over OTLP/HTTP.
+#[derive(Debug, Deserialize)]
+pub struct ServiceOtel {
+	/// Whether spans are exported.
+	pub enabled: bool,
+	/// OTLP/HTTP traces endpoint, such as `http://127.0.0.1:4318/v1/traces`.
+	pub endpoint: String,
+	/// Fraction of new root traces that are sampled, in 0.0-1.0.
+	///
+	/// Requests that carry a W3C `traceparent` follow the caller's sampling decision.
+	pub sample_ratio: f64,
+	/// Export request timeout in milliseconds.
+	pub timeout_ms: u64,
 }
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+/// Optional mirroring of read-only search traffic to a second ELF deployment.
+#[derive(Debug, Deserialize)]
+pub struct Shadow {
+	/// Whether search shadowing is enabled.
+	pub enabled: bool,
+	/// Base URL of the shadow deployment's public HTTP API.
+	pub base_url: String,
+	/// Fraction of search requests mirrored to the shadow deployment, in the range 0.0-1.0.
+	pub sample_rate: f32,
+	/// Timeout for each shadow request in milliseconds.
+	pub timeout_ms: u64,
+	/// Optional bearer token presented to the shadow deployment.
+	pub auth_token: Option<String>,
+}
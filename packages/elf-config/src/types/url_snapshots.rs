@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+/// Optional worker task that snapshots external URLs referenced by note `source_ref` values.
+#[derive(Clone, Debug, Deserialize)]
+pub struct UrlSnapshots {
+	/// Whether new `external_url/v1` source refs are queued for snapshotting.
+	pub enabled: bool,
+	/// Maximum response body bytes read from one URL.
+	pub max_bytes: u64,
+	/// Timeout for each robots.txt and page request in milliseconds.
+	pub timeout_ms: u64,
+	/// User-Agent sent with fetches and matched against robots.txt groups.
+	pub user_agent: String,
+	/// Maximum fetch attempts before a snapshot is marked failed.
+	pub max_attempts: u32,
+}
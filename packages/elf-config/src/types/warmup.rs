@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+/// Optional elf-api startup task that warms providers, Qdrant, and caches before reporting ready.
+#[derive(Debug, Deserialize)]
+pub struct Warmup {
+	/// Whether elf-api runs the warm-up task and gates readiness on it.
+	pub enabled: bool,
+	/// Representative English queries embedded and run against Qdrant to prime the collection.
+	pub queries: Vec<String>,
+	/// Number of most recently accessed search cache rows loaded during warm-up.
+	pub cache_rows: u32,
+	/// Maximum embedding or rerank provider latency in milliseconds accepted as warm.
+	pub max_provider_latency_ms: u64,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+/// Optional elf-worker outbox processing settings.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Worker {
+	/// Note-indexing outbox jobs processed concurrently per pass.
+	///
+	/// Jobs of the same note still run one at a time in enqueue order.
+	pub concurrency: u32,
+}
@@ -1,13 +1,20 @@
 mod chunking;
 mod context;
+mod embedding_projection;
 mod mcp;
 mod memory;
 mod providers;
+mod qdrant_maintenance;
 mod ranking;
+mod reembed;
 mod search;
 mod security;
 mod service;
+mod shadow;
 mod storage;
+mod url_snapshots;
+mod warmup;
+mod worker;
 
 use crate::{Config, Result};
 
@@ -23,6 +30,13 @@ pub fn validate(cfg: &Config) -> Result<()> {
 	chunking::validate(cfg)?;
 	context::validate(cfg)?;
 	mcp::validate(cfg)?;
+	shadow::validate(cfg)?;
+	embedding_projection::validate(cfg)?;
+	url_snapshots::validate(cfg)?;
+	warmup::validate(cfg)?;
+	qdrant_maintenance::validate(cfg)?;
+	reembed::validate(cfg)?;
+	worker::validate(cfg)?;
 	search::validate_graph_context(cfg)?;
 
 	Ok(())
@@ -1,4 +1,4 @@
-use crate::{Config, Error, Result};
+use crate::{ChunkingTypeOverride, Config, Error, Result};
 
 pub(super) fn validate(cfg: &Config) -> Result<()> {
 	if !cfg.chunking.enabled {
@@ -20,5 +20,86 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 		});
 	}
 
+	validate_per_type(cfg)
+}
+
+fn validate_per_type(cfg: &Config) -> Result<()> {
+	let Some(per_type) = cfg.chunking.per_type.as_ref() else {
+		return Ok(());
+	};
+
+	for (note_type, rule) in per_type {
+		let path = format!("chunking.per_type.{note_type}");
+
+		if !matches!(
+			note_type.as_str(),
+			"preference" | "constraint" | "decision" | "profile" | "fact" | "plan" | "qa"
+		) {
+			return Err(Error::Validation {
+				message: format!(
+					"{path} must name one of preference, constraint, decision, profile, fact, plan, or qa."
+				),
+			});
+		}
+		if rule.enabled == Some(false) {
+			continue;
+		}
+
+		validate_strategy(&path, rule)?;
+
+		let max_tokens = rule.max_tokens.unwrap_or(cfg.chunking.max_tokens);
+		let overlap_tokens = rule.overlap_tokens.unwrap_or(cfg.chunking.overlap_tokens);
+
+		if max_tokens == 0 {
+			return Err(Error::Validation {
+				message: format!("{path}.max_tokens must be greater than zero."),
+			});
+		}
+		if overlap_tokens >= max_tokens {
+			return Err(Error::Validation {
+				message: format!(
+					"{path}.overlap_tokens must be less than the resolved max_tokens."
+				),
+			});
+		}
+	}
+
+	Ok(())
+}
+
+fn validate_strategy(path: &str, rule: &ChunkingTypeOverride) -> Result<()> {
+	match rule.strategy.as_deref().map(str::trim) {
+		None | Some("token_window") =>
+			if rule.similarity_threshold.is_some() {
+				return Err(Error::Validation {
+					message: format!(
+						"{path}.similarity_threshold is only allowed when strategy is semantic."
+					),
+				});
+			},
+		Some("semantic") => {
+			let threshold = rule.similarity_threshold.ok_or_else(|| Error::Validation {
+				message: format!(
+					"{path}.similarity_threshold is required when strategy is semantic."
+				),
+			})?;
+
+			if !threshold.is_finite() || threshold <= 0.0 || threshold >= 1.0 {
+				return Err(Error::Validation {
+					message: format!(
+						"{path}.similarity_threshold must be between 0.0 and 1.0 exclusive."
+					),
+				});
+			}
+		},
+		Some(other) => {
+			return Err(Error::Validation {
+				message: format!(
+					"{path}.strategy must be one of: token_window, semantic. Got {other}."
+				),
+			});
+		},
+	}
+
 	Ok(())
 }
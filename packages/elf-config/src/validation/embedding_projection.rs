@@ -0,0 +1,23 @@
+use crate::{Config, Error, Result};
+
+pub(super) fn validate(cfg: &Config) -> Result<()> {
+	let Some(projection) = cfg.embedding_projection.as_ref() else { return Ok(()) };
+
+	if !projection.enabled {
+		return Ok(());
+	}
+	if projection.mode.trim() != "truncate" {
+		return Err(Error::Validation {
+			message: "embedding_projection.mode must be truncate.".to_string(),
+		});
+	}
+	if projection.source_dim <= cfg.storage.qdrant.vector_dim {
+		return Err(Error::Validation {
+			message:
+				"embedding_projection.source_dim must be greater than storage.qdrant.vector_dim."
+					.to_string(),
+		});
+	}
+
+	Ok(())
+}
@@ -1,8 +1,42 @@
 use std::collections::HashSet;
 
-use crate::{Config, Error, Result};
+use crate::{
+	Config, CronSchedule, Error, LifecycleConsolidation, LifecycleImportanceRescore,
+	LifecycleSchedule, MemoryWriteAnomaly, Result,
+};
 
 pub(super) fn validate(cfg: &Config) -> Result<()> {
+	if cfg.lifecycle.purge_trashed_after_days.is_some_and(|days| days <= 0) {
+		return Err(Error::Validation {
+			message: "lifecycle.purge_trashed_after_days must be greater than zero.".to_string(),
+		});
+	}
+	if let Some(consolidation) = cfg.lifecycle.consolidation.as_ref() {
+		validate_lifecycle_consolidation(consolidation)?;
+	}
+	if let Some(schedule) = cfg.lifecycle.schedule.as_ref() {
+		validate_lifecycle_schedule(schedule)?;
+	}
+	if let Some(rescore) = cfg.lifecycle.importance_rescore.as_ref() {
+		validate_lifecycle_importance_rescore(rescore)?;
+	}
+	if let Some(window_ms) = cfg.memory.version_coalesce_window_ms {
+		if window_ms == 0 {
+			return Err(Error::Validation {
+				message: "memory.version_coalesce_window_ms must be greater than zero.".to_string(),
+			});
+		}
+		if window_ms > 3_600_000 {
+			return Err(Error::Validation {
+				message: "memory.version_coalesce_window_ms must be 3_600_000 or less.".to_string(),
+			});
+		}
+	}
+
+	if let Some(anomaly) = cfg.memory.write_anomaly.as_ref() {
+		validate_write_anomaly(anomaly)?;
+	}
+
 	let mut seen_rules = HashSet::new();
 
 	for (idx, rule) in cfg.memory.policy.rules.iter().enumerate() {
@@ -16,11 +50,11 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 			}
 			if !matches!(
 				note_type.as_str(),
-				"preference" | "constraint" | "decision" | "profile" | "fact" | "plan"
+				"preference" | "constraint" | "decision" | "profile" | "fact" | "plan" | "qa"
 			) {
 				return Err(Error::Validation {
 					message: format!(
-						"{path}.note_type must be one of preference, constraint, decision, profile, fact, or plan."
+						"{path}.note_type must be one of preference, constraint, decision, profile, fact, plan, or qa."
 					),
 				});
 			}
@@ -73,3 +107,125 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 
 	Ok(())
 }
+
+fn validate_write_anomaly(anomaly: &MemoryWriteAnomaly) -> Result<()> {
+	if !(1..=86_400).contains(&anomaly.window_seconds) {
+		return Err(Error::Validation {
+			message: "memory.write_anomaly.window_seconds must be between 1 and 86_400."
+				.to_string(),
+		});
+	}
+	if anomaly.max_similar_writes < 2 {
+		return Err(Error::Validation {
+			message: "memory.write_anomaly.max_similar_writes must be at least 2.".to_string(),
+		});
+	}
+	if !anomaly.similarity_threshold.is_finite()
+		|| anomaly.similarity_threshold <= 0.0
+		|| anomaly.similarity_threshold > 1.0
+	{
+		return Err(Error::Validation {
+			message: "memory.write_anomaly.similarity_threshold must be in (0.0, 1.0].".to_string(),
+		});
+	}
+	if !matches!(anomaly.action.as_str(), "reject" | "report") {
+		return Err(Error::Validation {
+			message: "memory.write_anomaly.action must be one of reject or report.".to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+fn validate_lifecycle_consolidation(consolidation: &LifecycleConsolidation) -> Result<()> {
+	if consolidation.interval_seconds < 60 {
+		return Err(Error::Validation {
+			message: "lifecycle.consolidation.interval_seconds must be at least 60 seconds."
+				.to_string(),
+		});
+	}
+	if !consolidation.similarity_threshold.is_finite()
+		|| consolidation.similarity_threshold <= 0.0
+		|| consolidation.similarity_threshold > 1.0
+	{
+		return Err(Error::Validation {
+			message: "lifecycle.consolidation.similarity_threshold must be in (0.0, 1.0]."
+				.to_string(),
+		});
+	}
+	if consolidation.max_merges_per_run == 0 {
+		return Err(Error::Validation {
+			message: "lifecycle.consolidation.max_merges_per_run must be greater than zero."
+				.to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+fn validate_lifecycle_schedule(schedule: &LifecycleSchedule) -> Result<()> {
+	if let Err(message) = CronSchedule::parse(&schedule.cron) {
+		return Err(Error::Validation {
+			message: format!("lifecycle.schedule.cron is invalid: {message}"),
+		});
+	}
+	if schedule.batch_size == 0 || schedule.batch_size > 100_000 {
+		return Err(Error::Validation {
+			message: "lifecycle.schedule.batch_size must be between 1 and 100000.".to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+fn validate_lifecycle_importance_rescore(rescore: &LifecycleImportanceRescore) -> Result<()> {
+	if rescore.interval_seconds < 60 {
+		return Err(Error::Validation {
+			message: "lifecycle.importance_rescore.interval_seconds must be at least 60 seconds."
+				.to_string(),
+		});
+	}
+	if rescore.batch_size == 0 || rescore.batch_size > 100_000 {
+		return Err(Error::Validation {
+			message: "lifecycle.importance_rescore.batch_size must be between 1 and 100000."
+				.to_string(),
+		});
+	}
+	if !rescore.min_delta.is_finite() || !(0.0..1.0).contains(&rescore.min_delta) {
+		return Err(Error::Validation {
+			message: "lifecycle.importance_rescore.min_delta must be in [0.0, 1.0).".to_string(),
+		});
+	}
+	if !rescore.prior_weight.is_finite() || !(0.0..=1.0).contains(&rescore.prior_weight) {
+		return Err(Error::Validation {
+			message: "lifecycle.importance_rescore.prior_weight must be in [0.0, 1.0].".to_string(),
+		});
+	}
+
+	for (name, weight) in [
+		("hits_weight", rescore.hits_weight),
+		("feedback_weight", rescore.feedback_weight),
+		("age_weight", rescore.age_weight),
+	] {
+		if !weight.is_finite() || weight < 0.0 {
+			return Err(Error::Validation {
+				message: format!(
+					"lifecycle.importance_rescore.{name} must be a finite number >= 0.0."
+				),
+			});
+		}
+	}
+	for (name, value) in [
+		("hits_half_saturation", rescore.hits_half_saturation),
+		("last_hit_tau_days", rescore.last_hit_tau_days),
+		("age_tau_days", rescore.age_tau_days),
+	] {
+		if !value.is_finite() || value <= 0.0 {
+			return Err(Error::Validation {
+				message: format!("lifecycle.importance_rescore.{name} must be greater than zero."),
+			});
+		}
+	}
+
+	Ok(())
+}
@@ -1,4 +1,11 @@
-use crate::{Config, Error, Result};
+use std::collections::HashMap;
+
+use crate::{
+	Config, EmbeddingCache, EmbeddingProviderConfig, EmbeddingQueryInput, Error,
+	ProviderResilience, Providers, Result,
+};
+
+const MAX_EMBEDDING_BATCH_WINDOW_MS: u64 = 1_000;
 
 pub(super) fn validate(cfg: &Config) -> Result<()> {
 	if cfg.providers.embedding.dimensions == 0 {
@@ -13,6 +20,41 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 		});
 	}
 
+	if let Some(query_input) = cfg.providers.embedding.query_input.as_ref() {
+		validate_query_input("providers.embedding", query_input)?;
+	}
+
+	if let Some(cache) = cfg.providers.embedding.cache.as_ref() {
+		validate_cache("providers.embedding", cache)?;
+	}
+
+	validate_model_dir("providers.embedding", &cfg.providers.embedding)?;
+
+	if let Some(embeddings) = cfg.providers.embeddings.as_ref() {
+		validate_embeddings(cfg, embeddings)?;
+	}
+	if let Some(embedding_scopes) = cfg.providers.embedding_scopes.as_ref() {
+		validate_embedding_scopes(cfg, embedding_scopes)?;
+	}
+	if let Some(embedding_types) = cfg.providers.embedding_types.as_ref() {
+		validate_embedding_types(cfg, embedding_types)?;
+	}
+
+	for (path, resilience) in [
+		("providers.embedding", cfg.providers.embedding.resilience.as_ref()),
+		("providers.rerank", cfg.providers.rerank.resilience.as_ref()),
+		("providers.llm_extractor", cfg.providers.llm_extractor.resilience.as_ref()),
+	] {
+		if let Some(resilience) = resilience {
+			validate_resilience(path, resilience)?;
+		}
+	}
+	if cfg.providers.rerank.max_docs_per_call == Some(0) {
+		return Err(Error::Validation {
+			message: "providers.rerank.max_docs_per_call must be greater than zero.".to_string(),
+		});
+	}
+
 	for (label, key) in [
 		("embedding", &cfg.providers.embedding.api_key),
 		("rerank", &cfg.providers.rerank.api_key),
@@ -27,3 +69,198 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 
 	Ok(())
 }
+
+fn validate_embeddings(
+	cfg: &Config,
+	embeddings: &HashMap<String, EmbeddingProviderConfig>,
+) -> Result<()> {
+	for (name, provider) in embeddings {
+		if name == Providers::DEFAULT_EMBEDDING {
+			return Err(Error::Validation {
+				message: format!(
+					"providers.embeddings.{name} is reserved for providers.embedding; choose another name."
+				),
+			});
+		}
+
+		let path = format!("providers.embeddings.{name}");
+
+		// Every named provider writes into the one notes collection, so it must produce vectors
+		// of the collection dimension.
+		if provider.dimensions != cfg.storage.qdrant.vector_dim {
+			return Err(Error::Validation {
+				message: format!("{path}.dimensions must match storage.qdrant.vector_dim."),
+			});
+		}
+
+		validate_embedding_provider(&path, provider)?;
+	}
+
+	Ok(())
+}
+
+fn validate_embedding_scopes(
+	cfg: &Config,
+	embedding_scopes: &HashMap<String, String>,
+) -> Result<()> {
+	for (scope, name) in embedding_scopes {
+		if !cfg.scopes.allowed.iter().any(|allowed| allowed == scope) {
+			return Err(Error::Validation {
+				message: format!(
+					"providers.embedding_scopes.{scope} must name a scope listed in scopes.allowed."
+				),
+			});
+		}
+
+		validate_embedding_route(cfg, &format!("providers.embedding_scopes.{scope}"), name)?;
+	}
+
+	Ok(())
+}
+
+fn validate_embedding_types(cfg: &Config, embedding_types: &HashMap<String, String>) -> Result<()> {
+	for (note_type, name) in embedding_types {
+		if !matches!(
+			note_type.as_str(),
+			"preference" | "constraint" | "decision" | "profile" | "fact" | "plan" | "qa"
+		) {
+			return Err(Error::Validation {
+				message: format!(
+					"providers.embedding_types.{note_type} must name a note type: preference, constraint, decision, profile, fact, plan, or qa."
+				),
+			});
+		}
+
+		validate_embedding_route(cfg, &format!("providers.embedding_types.{note_type}"), name)?;
+	}
+
+	Ok(())
+}
+
+fn validate_embedding_route(cfg: &Config, path: &str, name: &str) -> Result<()> {
+	if cfg.providers.embedding_named(name).is_none() {
+		return Err(Error::Validation {
+			message: format!(
+				"{path} must name a provider in providers.embeddings or {}, got {name}.",
+				Providers::DEFAULT_EMBEDDING
+			),
+		});
+	}
+
+	Ok(())
+}
+
+/// Validates the settings every secondary embedding provider table must satisfy.
+pub(super) fn validate_embedding_provider(
+	path: &str,
+	provider: &EmbeddingProviderConfig,
+) -> Result<()> {
+	if provider.api_key.trim().is_empty() {
+		return Err(Error::Validation { message: format!("{path}.api_key must be non-empty.") });
+	}
+	if let Some(query_input) = provider.query_input.as_ref() {
+		validate_query_input(path, query_input)?;
+	}
+	if let Some(resilience) = provider.resilience.as_ref() {
+		validate_resilience(path, resilience)?;
+	}
+	if let Some(cache) = provider.cache.as_ref() {
+		validate_cache(path, cache)?;
+	}
+
+	validate_model_dir(path, provider)
+}
+
+fn validate_model_dir(path: &str, provider: &EmbeddingProviderConfig) -> Result<()> {
+	let has_model_dir = provider.model_dir.as_deref().is_some_and(|dir| !dir.trim().is_empty());
+
+	match (provider.provider_id == "local_model", has_model_dir) {
+		(true, false) => Err(Error::Validation {
+			message: format!("{path}.model_dir is required when provider_id is local_model."),
+		}),
+		(false, true) => Err(Error::Validation {
+			message: format!("{path}.model_dir is only valid when provider_id is local_model."),
+		}),
+		_ => Ok(()),
+	}
+}
+
+fn validate_query_input(path: &str, query_input: &EmbeddingQueryInput) -> Result<()> {
+	let is_template = match query_input.strategy.as_str() {
+		"query_only" | "context_suffix" | "context_prefix" => false,
+		"template" => true,
+		_ => {
+			return Err(Error::Validation {
+				message: format!(
+					"{path}.query_input.strategy must be one of query_only, context_suffix, context_prefix, or template."
+				),
+			});
+		},
+	};
+
+	match (is_template, query_input.template.as_deref()) {
+		(true, Some(template)) if template.contains("{query}") => Ok(()),
+		(true, _) => Err(Error::Validation {
+			message: format!(
+				"{path}.query_input.template must contain {{query}} when strategy is template."
+			),
+		}),
+		(false, Some(_)) => Err(Error::Validation {
+			message: format!(
+				"{path}.query_input.template is only valid when strategy is template."
+			),
+		}),
+		(false, None) => Ok(()),
+	}
+}
+
+fn validate_cache(path: &str, cache: &EmbeddingCache) -> Result<()> {
+	if cache.max_entries == 0 {
+		return Err(Error::Validation {
+			message: format!("{path}.cache.max_entries must be greater than zero."),
+		});
+	}
+	if cache.batch_window_ms > MAX_EMBEDDING_BATCH_WINDOW_MS {
+		return Err(Error::Validation {
+			message: format!(
+				"{path}.cache.batch_window_ms must be {MAX_EMBEDDING_BATCH_WINDOW_MS} or less."
+			),
+		});
+	}
+	if cache.max_batch_texts == Some(0) {
+		return Err(Error::Validation {
+			message: format!("{path}.cache.max_batch_texts must be greater than zero."),
+		});
+	}
+
+	Ok(())
+}
+
+fn validate_resilience(path: &str, resilience: &ProviderResilience) -> Result<()> {
+	if resilience.max_retries > 10 {
+		return Err(Error::Validation {
+			message: format!("{path}.resilience.max_retries must be 10 or less."),
+		});
+	}
+	if resilience.max_backoff_ms < resilience.initial_backoff_ms {
+		return Err(Error::Validation {
+			message: format!(
+				"{path}.resilience.max_backoff_ms must be greater than or equal to initial_backoff_ms."
+			),
+		});
+	}
+	if resilience.call_timeout_ms == Some(0) {
+		return Err(Error::Validation {
+			message: format!("{path}.resilience.call_timeout_ms must be greater than zero."),
+		});
+	}
+	if resilience.failure_threshold > 0 && resilience.open_ms == 0 {
+		return Err(Error::Validation {
+			message: format!(
+				"{path}.resilience.open_ms must be greater than zero when failure_threshold is set."
+			),
+		});
+	}
+
+	Ok(())
+}
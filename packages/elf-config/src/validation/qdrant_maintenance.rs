@@ -0,0 +1,56 @@
+use crate::{Config, Error, Result};
+
+const MIN_INTERVAL_SECONDS: u64 = 60;
+const MAX_SNAPSHOT_RETENTION: u32 = 100;
+
+pub(super) fn validate(cfg: &Config) -> Result<()> {
+	let Some(maintenance) = cfg.qdrant_maintenance.as_ref() else { return Ok(()) };
+
+	for (name, value) in [
+		("optimize_interval_seconds", maintenance.optimize_interval_seconds),
+		("snapshot_interval_seconds", maintenance.snapshot_interval_seconds),
+		("verify_interval_seconds", maintenance.verify_interval_seconds),
+	] {
+		if value.is_some_and(|seconds| seconds < MIN_INTERVAL_SECONDS) {
+			return Err(Error::Validation {
+				message: format!(
+					"qdrant_maintenance.{name} must be at least {MIN_INTERVAL_SECONDS} seconds."
+				),
+			});
+		}
+	}
+
+	if maintenance.snapshot_retention == 0
+		|| maintenance.snapshot_retention > MAX_SNAPSHOT_RETENTION
+	{
+		return Err(Error::Validation {
+			message: format!(
+				"qdrant_maintenance.snapshot_retention must be between 1 and {MAX_SNAPSHOT_RETENTION}."
+			),
+		});
+	}
+
+	for (name, value) in
+		[("rest_url", &maintenance.rest_url), ("alert_webhook", &maintenance.alert_webhook)]
+	{
+		let Some(url) = value.as_deref().map(str::trim) else { continue };
+
+		if !(url.starts_with("http://") || url.starts_with("https://")) {
+			return Err(Error::Validation {
+				message: format!("qdrant_maintenance.{name} must be an http or https URL."),
+			});
+		}
+	}
+
+	if maintenance.enabled
+		&& maintenance.optimize_interval_seconds.is_none()
+		&& maintenance.snapshot_interval_seconds.is_none()
+		&& maintenance.verify_interval_seconds.is_none()
+	{
+		return Err(Error::Validation {
+			message: "qdrant_maintenance requires at least one interval when enabled.".to_string(),
+		});
+	}
+
+	Ok(())
+}
@@ -1,5 +1,9 @@
 use crate::{Config, Error, Result};
 
+const SESSION_MODES: [&str; 2] = ["boost", "penalty"];
+const MIN_SESSION_TTL_SECONDS: i64 = 60;
+const MAX_SESSION_TTL_SECONDS: i64 = 7 * 86_400;
+
 pub(super) fn validate(cfg: &Config) -> Result<()> {
 	validate_core(cfg)?;
 	validate_blend(cfg)?;
@@ -92,6 +96,20 @@ fn validate_diversity(cfg: &Config) -> Result<()> {
 			message: "ranking.diversity.mmr_lambda must be in the range 0.0-1.0.".to_string(),
 		});
 	}
+	if let Some(scope_balance) = diversity.scope_balance.as_ref() {
+		if !matches!(scope_balance.by.as_str(), "scope" | "type") {
+			return Err(Error::Validation {
+				message: "ranking.diversity.scope_balance.by must be one of scope or type."
+					.to_string(),
+			});
+		}
+		if !(0.0..=1.0).contains(&scope_balance.weight) {
+			return Err(Error::Validation {
+				message: "ranking.diversity.scope_balance.weight must be in the range 0.0-1.0."
+					.to_string(),
+			});
+		}
+	}
 
 	Ok(())
 }
@@ -125,14 +143,29 @@ fn validate_retrieval_sources(cfg: &Config) -> Result<()> {
 
 fn validate_deterministic(cfg: &Config) -> Result<()> {
 	let det = &cfg.ranking.deterministic;
-	let det_lex = &det.lexical;
-	let det_hits = &det.hits;
-	let det_decay = &det.decay;
+
+	validate_deterministic_weights(cfg)?;
+
+	if det.enabled && det.lexical.enabled {
+		validate_deterministic_lexical(cfg)?;
+	}
+	if det.enabled && det.hits.enabled {
+		validate_deterministic_hits(cfg)?;
+	}
+	if det.enabled && det.decay.enabled {
+		validate_deterministic_decay(cfg)?;
+	}
+
+	validate_deterministic_signals(cfg)
+}
+
+fn validate_deterministic_weights(cfg: &Config) -> Result<()> {
+	let det = &cfg.ranking.deterministic;
 
 	for (path, weight) in [
-		("ranking.deterministic.lexical", det_lex.weight),
-		("ranking.deterministic.hits", det_hits.weight),
-		("ranking.deterministic.decay", det_decay.weight),
+		("ranking.deterministic.lexical", det.lexical.weight),
+		("ranking.deterministic.hits", det.hits.weight),
+		("ranking.deterministic.decay", det.decay.weight),
 	] {
 		if weight < 0.0 {
 			return Err(Error::Validation {
@@ -146,71 +179,136 @@ fn validate_deterministic(cfg: &Config) -> Result<()> {
 		}
 	}
 
-	if det.enabled && det_lex.enabled {
-		if !det_lex.min_ratio.is_finite() {
-			return Err(Error::Validation {
-				message: "ranking.deterministic.lexical.min_ratio must be a finite number."
-					.to_string(),
-			});
-		}
-		if !(0.0..=1.0).contains(&det_lex.min_ratio) {
-			return Err(Error::Validation {
-				message: "ranking.deterministic.lexical.min_ratio must be in the range 0.0-1.0."
-					.to_string(),
-			});
-		}
-		if det_lex.max_query_terms == 0 {
+	Ok(())
+}
+
+fn validate_deterministic_lexical(cfg: &Config) -> Result<()> {
+	let det_lex = &cfg.ranking.deterministic.lexical;
+
+	if !det_lex.min_ratio.is_finite() {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.lexical.min_ratio must be a finite number.".to_string(),
+		});
+	}
+	if !(0.0..=1.0).contains(&det_lex.min_ratio) {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.lexical.min_ratio must be in the range 0.0-1.0."
+				.to_string(),
+		});
+	}
+	if det_lex.max_query_terms == 0 {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.lexical.max_query_terms must be greater than zero."
+				.to_string(),
+		});
+	}
+	if det_lex.max_text_terms == 0 {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.lexical.max_text_terms must be greater than zero."
+				.to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+fn validate_deterministic_hits(cfg: &Config) -> Result<()> {
+	let det_hits = &cfg.ranking.deterministic.hits;
+
+	if !det_hits.half_saturation.is_finite() {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.hits.half_saturation must be a finite number."
+				.to_string(),
+		});
+	}
+	if det_hits.half_saturation <= 0.0 {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.hits.half_saturation must be greater than zero."
+				.to_string(),
+		});
+	}
+	if !det_hits.last_hit_tau_days.is_finite() {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.hits.last_hit_tau_days must be a finite number."
+				.to_string(),
+		});
+	}
+	if det_hits.last_hit_tau_days < 0.0 {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.hits.last_hit_tau_days must be zero or greater."
+				.to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+fn validate_deterministic_decay(cfg: &Config) -> Result<()> {
+	let det_decay = &cfg.ranking.deterministic.decay;
+
+	if !det_decay.tau_days.is_finite() {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.decay.tau_days must be a finite number.".to_string(),
+		});
+	}
+	if det_decay.tau_days <= 0.0 {
+		return Err(Error::Validation {
+			message: "ranking.deterministic.decay.tau_days must be greater than zero.".to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+fn validate_deterministic_signals(cfg: &Config) -> Result<()> {
+	let det = &cfg.ranking.deterministic;
+
+	if let Some(evidence) = det.evidence.as_ref() {
+		if evidence.weight < 0.0 || !evidence.weight.is_finite() {
 			return Err(Error::Validation {
-				message: "ranking.deterministic.lexical.max_query_terms must be greater than zero."
-					.to_string(),
+				message:
+					"ranking.deterministic.evidence.weight must be a finite number zero or greater."
+						.to_string(),
 			});
 		}
-		if det_lex.max_text_terms == 0 {
+		if !(0.0..=1.0).contains(&evidence.strong_coverage) {
 			return Err(Error::Validation {
-				message: "ranking.deterministic.lexical.max_text_terms must be greater than zero."
-					.to_string(),
+				message:
+					"ranking.deterministic.evidence.strong_coverage must be in the range 0.0-1.0."
+						.to_string(),
 			});
 		}
 	}
-	if det.enabled && det_hits.enabled {
-		if !det_hits.half_saturation.is_finite() {
+	if let Some(session) = det.session.as_ref() {
+		if !SESSION_MODES.contains(&session.mode.as_str()) {
 			return Err(Error::Validation {
-				message: "ranking.deterministic.hits.half_saturation must be a finite number."
+				message: "ranking.deterministic.session.mode must be one of boost or penalty."
 					.to_string(),
 			});
 		}
-		if det_hits.half_saturation <= 0.0 {
+		if session.weight < 0.0 || !session.weight.is_finite() {
 			return Err(Error::Validation {
-				message: "ranking.deterministic.hits.half_saturation must be greater than zero."
-					.to_string(),
+				message:
+					"ranking.deterministic.session.weight must be a finite number zero or greater."
+						.to_string(),
 			});
 		}
-		if !det_hits.last_hit_tau_days.is_finite() {
+		if !(MIN_SESSION_TTL_SECONDS..=MAX_SESSION_TTL_SECONDS).contains(&session.ttl_seconds) {
 			return Err(Error::Validation {
-				message: "ranking.deterministic.hits.last_hit_tau_days must be a finite number."
-					.to_string(),
-			});
-		}
-		if det_hits.last_hit_tau_days < 0.0 {
-			return Err(Error::Validation {
-				message: "ranking.deterministic.hits.last_hit_tau_days must be zero or greater."
-					.to_string(),
+				message: format!(
+					"ranking.deterministic.session.ttl_seconds must be between {MIN_SESSION_TTL_SECONDS} and {MAX_SESSION_TTL_SECONDS}."
+				),
 			});
 		}
 	}
-	if det.enabled && det_decay.enabled {
-		if !det_decay.tau_days.is_finite() {
-			return Err(Error::Validation {
-				message: "ranking.deterministic.decay.tau_days must be a finite number."
-					.to_string(),
-			});
-		}
-		if det_decay.tau_days <= 0.0 {
-			return Err(Error::Validation {
-				message: "ranking.deterministic.decay.tau_days must be greater than zero."
+	if let Some(feedback) = det.feedback.as_ref()
+		&& (feedback.weight < 0.0 || !feedback.weight.is_finite())
+	{
+		return Err(Error::Validation {
+			message:
+				"ranking.deterministic.feedback.weight must be a finite number zero or greater."
 					.to_string(),
-			});
-		}
+		});
 	}
 
 	Ok(())
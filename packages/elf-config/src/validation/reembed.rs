@@ -0,0 +1,55 @@
+use crate::{Config, Error, Result, validation::providers};
+
+const MAX_BATCH_SIZE: u32 = 1_000;
+
+pub(super) fn validate(cfg: &Config) -> Result<()> {
+	let Some(reembed) = cfg.reembed.as_ref() else { return Ok(()) };
+	let collection = reembed.collection.trim();
+
+	if collection.is_empty() {
+		return Err(Error::Validation {
+			message: "reembed.collection must be non-empty.".to_string(),
+		});
+	}
+	if collection == cfg.storage.qdrant.collection
+		|| collection == cfg.storage.qdrant.docs_collection
+	{
+		return Err(Error::Validation {
+			message:
+				"reembed.collection must differ from storage.qdrant.collection and docs_collection."
+					.to_string(),
+		});
+	}
+	if reembed.batch_size == 0 || reembed.batch_size > MAX_BATCH_SIZE {
+		return Err(Error::Validation {
+			message: format!("reembed.batch_size must be between 1 and {MAX_BATCH_SIZE}."),
+		});
+	}
+	if reembed.provider.dimensions == 0 {
+		return Err(Error::Validation {
+			message: "reembed.provider.dimensions must be greater than zero.".to_string(),
+		});
+	}
+	if reembed.provider.provider_id == cfg.providers.embedding.provider_id
+		&& reembed.provider.model == cfg.providers.embedding.model
+		&& reembed.provider.dimensions == cfg.storage.qdrant.vector_dim
+	{
+		return Err(Error::Validation {
+			message:
+				"reembed.provider must change the embedding provider_id, model, or dimensions."
+					.to_string(),
+		});
+	}
+	// Activation moves every note to one embedding version, which per-scope and per-type
+	// overrides would contradict.
+	if cfg.providers.embedding_scopes.as_ref().is_some_and(|overrides| !overrides.is_empty())
+		|| cfg.providers.embedding_types.as_ref().is_some_and(|overrides| !overrides.is_empty())
+	{
+		return Err(Error::Validation {
+			message: "reembed requires providers.embedding_scopes and providers.embedding_types to be unset."
+				.to_string(),
+		});
+	}
+
+	providers::validate_embedding_provider("reembed.provider", &reembed.provider)
+}
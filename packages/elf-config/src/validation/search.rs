@@ -1,5 +1,7 @@
 use crate::{Config, Error, Result};
 
+const MAX_RESULT_CACHE_TTL_SECONDS: i64 = 3_600;
+
 pub(super) fn validate(cfg: &Config) -> Result<()> {
 	validate_expansion(cfg)?;
 	validate_dynamic(cfg)?;
@@ -7,6 +9,8 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 	validate_explain(cfg)?;
 	validate_explain_write_mode(cfg)?;
 	validate_recursive(cfg)?;
+	validate_snippet(cfg)?;
+	validate_answer(cfg)?;
 
 	Ok(())
 }
@@ -97,6 +101,16 @@ fn validate_cache(cfg: &Config) -> Result<()> {
 		});
 	}
 
+	if let Some(results) = cfg.search.cache.results.as_ref()
+		&& !(1..=MAX_RESULT_CACHE_TTL_SECONDS).contains(&results.ttl_seconds)
+	{
+		return Err(Error::Validation {
+			message: format!(
+				"search.cache.results.ttl_seconds must be between 1 and {MAX_RESULT_CACHE_TTL_SECONDS}."
+			),
+		});
+	}
+
 	Ok(())
 }
 
@@ -186,6 +200,72 @@ fn validate_recursive(cfg: &Config) -> Result<()> {
 					.to_string(),
 		});
 	}
+	if let Some(max_elapsed_ms) = cfg.search.recursive.max_elapsed_ms {
+		if max_elapsed_ms == 0 {
+			return Err(Error::Validation {
+				message: "search.recursive.max_elapsed_ms must be greater than zero.".to_string(),
+			});
+		}
+		if max_elapsed_ms > 60_000 {
+			return Err(Error::Validation {
+				message: "search.recursive.max_elapsed_ms must be 60_000 or less.".to_string(),
+			});
+		}
+	}
+
+	Ok(())
+}
+
+fn validate_snippet(cfg: &Config) -> Result<()> {
+	let Some(snippet) = cfg.search.snippet.as_ref() else {
+		return Ok(());
+	};
+
+	for (level, limit) in [("l0", &snippet.l0), ("l1", &snippet.l1), ("l2", &snippet.l2)] {
+		let Some(limit) = limit else {
+			continue;
+		};
+
+		if limit.max_chars.is_none() && limit.max_tokens.is_none() {
+			return Err(Error::Validation {
+				message: format!("search.snippet.{level} must set max_chars or max_tokens."),
+			});
+		}
+		if limit.max_chars.is_some_and(|max_chars| max_chars < 16) {
+			return Err(Error::Validation {
+				message: format!("search.snippet.{level}.max_chars must be at least 16."),
+			});
+		}
+		if limit.max_tokens == Some(0) {
+			return Err(Error::Validation {
+				message: format!("search.snippet.{level}.max_tokens must be greater than zero."),
+			});
+		}
+	}
+
+	Ok(())
+}
+
+fn validate_answer(cfg: &Config) -> Result<()> {
+	let Some(answer) = cfg.search.answer.as_ref() else {
+		return Ok(());
+	};
+
+	if !(1..=20).contains(&answer.max_sources) {
+		return Err(Error::Validation {
+			message: "search.answer.max_sources must be between 1 and 20.".to_string(),
+		});
+	}
+	if !(1..=10).contains(&answer.max_sentences) {
+		return Err(Error::Validation {
+			message: "search.answer.max_sentences must be between 1 and 10.".to_string(),
+		});
+	}
+	if !answer.min_grounding.is_finite() || !(0.0..=1.0).contains(&answer.min_grounding) {
+		return Err(Error::Validation {
+			message: "search.answer.min_grounding must be between 0.0 and 1.0.".to_string(),
+		});
+	}
 
 	Ok(())
 }
@@ -1,6 +1,15 @@
 use std::collections::HashSet;
 
-use crate::{Config, Error, Result};
+use regex::Regex;
+
+use crate::{
+	Config, Error, Result, SecurityAuthRole, SecurityEgress, SecurityJwt, SecurityQuotas,
+	SecurityRolePermissions, SecuritySecretPatterns,
+};
+
+/// Asymmetric algorithms accepted for JWKS-verified tokens.
+const JWT_ALGORITHMS: &[&str] =
+	&["RS256", "RS384", "RS512", "PS256", "PS384", "PS512", "ES256", "ES384", "EdDSA"];
 
 pub(super) fn validate(cfg: &Config) -> Result<()> {
 	if !cfg.security.reject_non_english {
@@ -9,13 +18,44 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 		});
 	}
 
+	if let Some(quotas) = cfg.security.quotas.as_ref() {
+		validate_quotas(quotas)?;
+	}
+	if let Some(permissions) = cfg.security.permissions.as_ref() {
+		for (role, permissions) in permissions {
+			validate_role_permissions(cfg, *role, permissions)?;
+		}
+	}
+	if let Some(patterns) = cfg.security.secret_patterns.as_ref() {
+		validate_secret_patterns(patterns)?;
+	}
+	if let Some(egress) = cfg.security.egress.as_ref() {
+		validate_egress(egress)?;
+	}
+
 	let auth_mode = cfg.security.auth_mode.trim();
 
-	if !matches!(auth_mode, "off" | "static_keys") {
+	if !matches!(auth_mode, "off" | "static_keys" | "jwt") {
 		return Err(Error::Validation {
-			message: "security.auth_mode must be one of off or static_keys.".to_string(),
+			message: "security.auth_mode must be one of off, static_keys, or jwt.".to_string(),
 		});
 	}
+	if auth_mode == "jwt" {
+		if !cfg.security.auth_keys.is_empty() {
+			return Err(Error::Validation {
+				message: "security.auth_keys must be empty when security.auth_mode is jwt."
+					.to_string(),
+			});
+		}
+
+		let Some(jwt) = cfg.security.jwt.as_ref() else {
+			return Err(Error::Validation {
+				message: "security.jwt is required when security.auth_mode is jwt.".to_string(),
+			});
+		};
+
+		return validate_jwt(jwt);
+	}
 	if auth_mode == "off" {
 		if !cfg.security.auth_keys.is_empty() {
 			return Err(Error::Validation {
@@ -102,3 +142,186 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 
 	Ok(())
 }
+
+fn validate_jwt(jwt: &SecurityJwt) -> Result<()> {
+	let jwks_url = jwt.jwks_url.trim();
+
+	if !(jwks_url.starts_with("https://") || jwks_url.starts_with("http://")) {
+		return Err(Error::Validation {
+			message: "security.jwt.jwks_url must be an http(s) URL.".to_string(),
+		});
+	}
+
+	for (path, value) in [
+		("security.jwt.issuer", &jwt.issuer),
+		("security.jwt.audience", &jwt.audience),
+		("security.jwt.claims.tenant_id", &jwt.claims.tenant_id),
+		("security.jwt.claims.project_id", &jwt.claims.project_id),
+		("security.jwt.claims.agent_id", &jwt.claims.agent_id),
+		("security.jwt.claims.read_profile", &jwt.claims.read_profile),
+	] {
+		if value.trim().is_empty() {
+			return Err(Error::Validation { message: format!("{path} must be non-empty.") });
+		}
+	}
+
+	if jwt.claims.role.as_ref().is_some_and(|role| role.trim().is_empty()) {
+		return Err(Error::Validation {
+			message: "security.jwt.claims.role must be non-empty when set.".to_string(),
+		});
+	}
+	if jwt.algorithms.is_empty() {
+		return Err(Error::Validation {
+			message: "security.jwt.algorithms must be non-empty.".to_string(),
+		});
+	}
+	if let Some(algorithm) =
+		jwt.algorithms.iter().find(|algorithm| !JWT_ALGORITHMS.contains(&algorithm.as_str()))
+	{
+		return Err(Error::Validation {
+			message: format!(
+				"security.jwt.algorithms contains unsupported algorithm {algorithm}. Use one of {}.",
+				JWT_ALGORITHMS.join(", ")
+			),
+		});
+	}
+	if jwt.jwks_cache_seconds == 0 {
+		return Err(Error::Validation {
+			message: "security.jwt.jwks_cache_seconds must be greater than zero.".to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+fn validate_quotas(quotas: &SecurityQuotas) -> Result<()> {
+	for (path, value) in [
+		("security.quotas.max_notes_per_day", quotas.max_notes_per_day.map(u64::from)),
+		("security.quotas.max_search_qps", quotas.max_search_qps.map(u64::from)),
+		("security.quotas.max_total_notes", quotas.max_total_notes),
+	] {
+		if value == Some(0) {
+			return Err(Error::Validation {
+				message: format!("{path} must be greater than zero when set."),
+			});
+		}
+	}
+
+	Ok(())
+}
+
+fn validate_secret_patterns(patterns: &SecuritySecretPatterns) -> Result<()> {
+	if patterns.disable_builtin && patterns.custom.is_empty() {
+		return Err(Error::Validation {
+			message:
+				"security.secret_patterns.custom must be non-empty when disable_builtin is true."
+					.to_string(),
+		});
+	}
+
+	let mut names = HashSet::new();
+
+	for (idx, custom) in patterns.custom.iter().enumerate() {
+		let path = format!("security.secret_patterns.custom[{idx}]");
+
+		if custom.name.is_empty()
+			|| !custom
+				.name
+				.chars()
+				.all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_')
+		{
+			return Err(Error::Validation {
+				message: format!("{path}.name must be non-empty lowercase snake_case."),
+			});
+		}
+		if !names.insert(custom.name.as_str()) {
+			return Err(Error::Validation {
+				message: format!("{path}.name must be unique; duplicate {}.", custom.name),
+			});
+		}
+
+		let regex = Regex::new(custom.pattern.as_str()).map_err(|err| Error::Validation {
+			message: format!("{path}.pattern must be a valid regular expression: {err}."),
+		})?;
+
+		if regex.is_match("") {
+			return Err(Error::Validation {
+				message: format!("{path}.pattern must not match empty text."),
+			});
+		}
+		if let Some(min_entropy) = custom.min_entropy
+			&& (!min_entropy.is_finite() || min_entropy <= 0.0 || min_entropy > 8.0)
+		{
+			return Err(Error::Validation {
+				message: format!("{path}.min_entropy must be in the range (0.0, 8.0]."),
+			});
+		}
+	}
+
+	Ok(())
+}
+
+fn validate_role_permissions(
+	cfg: &Config,
+	role: SecurityAuthRole,
+	permissions: &SecurityRolePermissions,
+) -> Result<()> {
+	for (operation, scopes) in [
+		("add_note", &permissions.add_note),
+		("add_event", &permissions.add_event),
+		("update", &permissions.update),
+		("delete", &permissions.delete),
+	] {
+		let path = format!("security.permissions.{}.{operation}", role.as_str());
+
+		if role == SecurityAuthRole::Reader && !scopes.is_empty() {
+			return Err(Error::Validation {
+				message: format!("{path} must be empty; reader tokens cannot write."),
+			});
+		}
+
+		for scope in scopes {
+			if scope == "org_shared"
+				&& !matches!(role, SecurityAuthRole::Admin | SecurityAuthRole::SuperAdmin)
+			{
+				return Err(Error::Validation {
+					message: format!(
+						"{path} cannot include org_shared; only admin roles write it."
+					),
+				});
+			}
+			if !cfg.scopes.allowed.iter().any(|allowed| allowed == scope) {
+				return Err(Error::Validation {
+					message: format!("{path} contains scope {scope} missing from scopes.allowed."),
+				});
+			}
+		}
+	}
+
+	Ok(())
+}
+
+fn validate_egress(egress: &SecurityEgress) -> Result<()> {
+	for (field, hosts) in
+		[("allowed_hosts", &egress.allowed_hosts), ("denied_hosts", &egress.denied_hosts)]
+	{
+		for (idx, host) in hosts.iter().enumerate() {
+			let valid = !host.is_empty()
+				&& !host.starts_with('.')
+				&& !host.ends_with('.')
+				&& host.chars().all(|ch| {
+					ch.is_ascii_lowercase() || ch.is_ascii_digit() || matches!(ch, '-' | '.')
+				});
+
+			if !valid {
+				return Err(Error::Validation {
+					message: format!(
+						"security.egress.{field}[{idx}] must be a lowercase host name without scheme, port, or path."
+					),
+				});
+			}
+		}
+	}
+
+	Ok(())
+}
@@ -6,6 +6,30 @@ pub(super) fn validate(cfg: &Config) -> Result<()> {
 			message: "service.mcp_bind must be non-empty.".to_string(),
 		});
 	}
+	if let Some(mode) = cfg.service.mode.as_deref()
+		&& !matches!(mode.trim(), "split" | "all_in_one")
+	{
+		return Err(Error::Validation {
+			message: format!("service.mode must be one of: split, all_in_one. Got {mode}."),
+		});
+	}
+	if let Some(otel) = cfg.service.otel.as_ref().filter(|otel| otel.enabled) {
+		if otel.endpoint.trim().is_empty() {
+			return Err(Error::Validation {
+				message: "service.otel.endpoint must be non-empty when enabled.".to_string(),
+			});
+		}
+		if !otel.sample_ratio.is_finite() || !(0.0..=1.0).contains(&otel.sample_ratio) {
+			return Err(Error::Validation {
+				message: "service.otel.sample_ratio must be between 0.0 and 1.0.".to_string(),
+			});
+		}
+		if otel.timeout_ms == 0 {
+			return Err(Error::Validation {
+				message: "service.otel.timeout_ms must be greater than zero.".to_string(),
+			});
+		}
+	}
 
 	Ok(())
 }
@@ -0,0 +1,34 @@
+use crate::{Config, Error, Result};
+
+pub(super) fn validate(cfg: &Config) -> Result<()> {
+	let Some(shadow) = cfg.shadow.as_ref() else { return Ok(()) };
+
+	if !shadow.enabled {
+		return Ok(());
+	}
+
+	let base_url = shadow.base_url.trim();
+
+	if !(base_url.starts_with("http://") || base_url.starts_with("https://")) {
+		return Err(Error::Validation {
+			message: "shadow.base_url must be an http:// or https:// URL.".to_string(),
+		});
+	}
+	if !shadow.sample_rate.is_finite() || shadow.sample_rate <= 0.0 || shadow.sample_rate > 1.0 {
+		return Err(Error::Validation {
+			message: "shadow.sample_rate must be greater than zero and at most 1.0.".to_string(),
+		});
+	}
+	if shadow.timeout_ms == 0 {
+		return Err(Error::Validation {
+			message: "shadow.timeout_ms must be greater than zero.".to_string(),
+		});
+	}
+	if shadow.auth_token.as_deref().is_some_and(|token| token.trim().is_empty()) {
+		return Err(Error::Validation {
+			message: "shadow.auth_token must be non-empty when set.".to_string(),
+		});
+	}
+
+	Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::{Config, Error, Result};
+
+const MAX_SNAPSHOT_BYTES: u64 = 4 * 1_024 * 1_024;
+
+pub(super) fn validate(cfg: &Config) -> Result<()> {
+	let Some(snapshots) = cfg.url_snapshots.as_ref() else { return Ok(()) };
+
+	if !snapshots.enabled {
+		return Ok(());
+	}
+	if snapshots.max_bytes == 0 || snapshots.max_bytes > MAX_SNAPSHOT_BYTES {
+		return Err(Error::Validation {
+			message: format!(
+				"url_snapshots.max_bytes must be greater than zero and at most {MAX_SNAPSHOT_BYTES}."
+			),
+		});
+	}
+	if snapshots.timeout_ms == 0 {
+		return Err(Error::Validation {
+			message: "url_snapshots.timeout_ms must be greater than zero.".to_string(),
+		});
+	}
+
+	let user_agent = snapshots.user_agent.trim();
+
+	if user_agent.is_empty() || !user_agent.is_ascii() {
+		return Err(Error::Validation {
+			message: "url_snapshots.user_agent must be a non-empty ASCII string.".to_string(),
+		});
+	}
+	if snapshots.max_attempts == 0 {
+		return Err(Error::Validation {
+			message: "url_snapshots.max_attempts must be greater than zero.".to_string(),
+		});
+	}
+
+	Ok(())
+}
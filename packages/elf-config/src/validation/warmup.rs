@@ -0,0 +1,36 @@
+use crate::{Config, Error, Result};
+
+const MAX_WARMUP_QUERIES: usize = 32;
+const MAX_WARMUP_CACHE_ROWS: u32 = 10_000;
+
+pub(super) fn validate(cfg: &Config) -> Result<()> {
+	let Some(warmup) = cfg.warmup.as_ref() else { return Ok(()) };
+
+	if !warmup.enabled {
+		return Ok(());
+	}
+	if warmup.queries.is_empty() || warmup.queries.len() > MAX_WARMUP_QUERIES {
+		return Err(Error::Validation {
+			message: format!(
+				"warmup.queries must contain between 1 and {MAX_WARMUP_QUERIES} queries."
+			),
+		});
+	}
+	if warmup.queries.iter().any(|query| query.trim().is_empty()) {
+		return Err(Error::Validation {
+			message: "warmup.queries must not contain empty queries.".to_string(),
+		});
+	}
+	if warmup.cache_rows > MAX_WARMUP_CACHE_ROWS {
+		return Err(Error::Validation {
+			message: format!("warmup.cache_rows must be at most {MAX_WARMUP_CACHE_ROWS}."),
+		});
+	}
+	if warmup.max_provider_latency_ms == 0 {
+		return Err(Error::Validation {
+			message: "warmup.max_provider_latency_ms must be greater than zero.".to_string(),
+		});
+	}
+
+	Ok(())
+}
@@ -0,0 +1,15 @@
+use crate::{Config, Error, Result};
+
+const MAX_WORKER_CONCURRENCY: u32 = 64;
+
+pub(super) fn validate(cfg: &Config) -> Result<()> {
+	let Some(worker) = cfg.worker.as_ref() else { return Ok(()) };
+
+	if worker.concurrency == 0 || worker.concurrency > MAX_WORKER_CONCURRENCY {
+		return Err(Error::Validation {
+			message: format!("worker.concurrency must be between 1 and {MAX_WORKER_CONCURRENCY}."),
+		});
+	}
+
+	Ok(())
+}
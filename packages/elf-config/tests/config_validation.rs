@@ -5,8 +5,19 @@
 #[path = "config_validation/chunking.rs"] mod chunking;
 #[path = "config_validation/context.rs"] mod context;
 #[path = "config_validation/core.rs"] mod core;
+#[path = "config_validation/embedding_projection.rs"] mod embedding_projection;
+#[path = "config_validation/embedding_scopes.rs"] mod embedding_scopes;
 #[path = "config_validation/helpers.rs"] mod helpers;
+#[path = "config_validation/lifecycle_schedule.rs"] mod lifecycle_schedule;
+#[path = "config_validation/lint.rs"] mod lint;
 #[path = "config_validation/memory_policy.rs"] mod memory_policy;
+#[path = "config_validation/otel.rs"] mod otel;
+#[path = "config_validation/provider_resilience.rs"] mod provider_resilience;
+#[path = "config_validation/qdrant_maintenance.rs"] mod qdrant_maintenance;
 #[path = "config_validation/ranking.rs"] mod ranking;
+#[path = "config_validation/reembed.rs"] mod reembed;
 #[path = "config_validation/search.rs"] mod search;
 #[path = "config_validation/security.rs"] mod security;
+#[path = "config_validation/shadow.rs"] mod shadow;
+#[path = "config_validation/url_snapshots.rs"] mod url_snapshots;
+#[path = "config_validation/warmup.rs"] mod warmup;
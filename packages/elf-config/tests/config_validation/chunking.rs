@@ -53,3 +53,76 @@ fn chunking_tokenizer_repo_is_required() {
 		"Unexpected error: {message}"
 	);
 }
+
+#[test]
+fn chunking_per_type_overrides_are_validated_against_resolved_bounds() {
+	let mut payload = helpers::sample_toml(true);
+
+	payload = payload.replace(
+		"tokenizer_repo = \"REPLACE_ME\"\n",
+		"tokenizer_repo = \"REPLACE_ME\"\n\n[chunking.per_type.profile]\nenabled = false\n\n[chunking.per_type.decision]\noverlap_tokens = 256\n",
+	);
+
+	let path = helpers::write_temp_config(payload.clone());
+	let cfg = elf_config::load(&path).expect("Expected per-type overrides to load.");
+
+	fs::remove_file(&path).expect("Failed to remove test config.");
+
+	let per_type = cfg.chunking.per_type.expect("Expected per-type overrides.");
+
+	assert_eq!(per_type["profile"].enabled, Some(false));
+	assert_eq!(per_type["decision"].overlap_tokens, Some(256));
+
+	let path = helpers::write_temp_config(
+		payload.replace("overlap_tokens = 256", "max_tokens = 128\noverlap_tokens = 128"),
+	);
+	let err = elf_config::load(&path).expect_err("Expected resolved overlap validation error.");
+
+	fs::remove_file(&path).expect("Failed to remove test config.");
+
+	assert!(err.to_string().contains(
+		"chunking.per_type.decision.overlap_tokens must be less than the resolved max_tokens."
+	));
+
+	let path = helpers::write_temp_config(
+		payload.replace("[chunking.per_type.decision]", "[chunking.per_type.journal]"),
+	);
+	let err = elf_config::load(&path).expect_err("Expected unknown note type error.");
+
+	fs::remove_file(&path).expect("Failed to remove test config.");
+
+	assert!(err.to_string().contains("chunking.per_type.journal must name one of"));
+}
+
+#[test]
+fn chunking_semantic_strategy_requires_a_similarity_threshold() {
+	let payload = helpers::sample_toml(true).replace(
+		"tokenizer_repo = \"REPLACE_ME\"\n",
+		"tokenizer_repo = \"REPLACE_ME\"\n\n[chunking.per_type.plan]\nstrategy = \"semantic\"\nsimilarity_threshold = 0.72\n",
+	);
+	let path = helpers::write_temp_config(payload.clone());
+	let cfg = elf_config::load(&path).expect("Expected semantic chunking to load.");
+
+	fs::remove_file(&path).expect("Failed to remove test config.");
+
+	let per_type = cfg.chunking.per_type.expect("Expected per-type overrides.");
+
+	assert_eq!(per_type["plan"].strategy.as_deref(), Some("semantic"));
+	assert_eq!(per_type["plan"].similarity_threshold, Some(0.72));
+
+	let path = helpers::write_temp_config(payload.replace("similarity_threshold = 0.72\n", ""));
+	let err = elf_config::load(&path).expect_err("Expected missing threshold error.");
+
+	fs::remove_file(&path).expect("Failed to remove test config.");
+
+	assert!(err.to_string().contains(
+		"chunking.per_type.plan.similarity_threshold is required when strategy is semantic."
+	));
+
+	let path = helpers::write_temp_config(payload.replace("\"semantic\"", "\"paragraph\""));
+	let err = elf_config::load(&path).expect_err("Expected unknown strategy error.");
+
+	fs::remove_file(&path).expect("Failed to remove test config.");
+
+	assert!(err.to_string().contains("chunking.per_type.plan.strategy must be one of"));
+}
@@ -1,6 +1,7 @@
 use std::{env, fs, path::PathBuf};
 
 use crate::helpers::{self, TRACE_GATE_CONFIG_TOML};
+use elf_config::Worker;
 
 #[test]
 fn required_config_fields_must_be_explicit() {
@@ -72,3 +73,58 @@ fn trace_gate_fixture_toml_is_valid() {
 	elf_config::load(&path).expect("Expected trace gate fixture config to be valid.");
 	fs::remove_file(&path).expect("Failed to remove test config.");
 }
+
+#[test]
+fn service_mode_must_be_split_or_all_in_one() {
+	let mut cfg = helpers::base_config();
+
+	cfg.service.mode = Some("all_in_one".to_string());
+
+	assert!(elf_config::validate(&cfg).is_ok());
+	assert!(cfg.service.is_all_in_one());
+
+	cfg.service.mode = Some("sidecar".to_string());
+
+	let err = elf_config::validate(&cfg).expect_err("Expected service.mode error.");
+
+	assert!(
+		err.to_string().contains("service.mode must be one of: split, all_in_one."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn rerank_max_docs_per_call_must_be_positive() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.rerank.max_docs_per_call = Some(32);
+
+	assert!(elf_config::validate(&cfg).is_ok());
+
+	cfg.providers.rerank.max_docs_per_call = Some(0);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected max_docs_per_call error.");
+
+	assert!(
+		err.to_string().contains("providers.rerank.max_docs_per_call must be greater than zero."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn worker_concurrency_must_be_bounded() {
+	let mut cfg = helpers::base_config();
+
+	cfg.worker = Some(Worker { concurrency: 8 });
+
+	assert!(elf_config::validate(&cfg).is_ok());
+
+	cfg.worker = Some(Worker { concurrency: 0 });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected worker.concurrency error.");
+
+	assert!(
+		err.to_string().contains("worker.concurrency must be between 1 and 64."),
+		"Unexpected error: {err}"
+	);
+}
@@ -0,0 +1,57 @@
+use crate::helpers;
+use elf_config::EmbeddingProjection;
+
+fn projection(source_dim: u32) -> EmbeddingProjection {
+	EmbeddingProjection { enabled: true, mode: "truncate".to_string(), source_dim }
+}
+
+#[test]
+fn embedding_projection_accepts_larger_source_dim() {
+	let mut cfg = helpers::base_config();
+
+	cfg.embedding_projection = Some(projection(cfg.storage.qdrant.vector_dim * 2));
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn embedding_projection_requires_larger_source_dim() {
+	let mut cfg = helpers::base_config();
+
+	cfg.embedding_projection = Some(projection(cfg.storage.qdrant.vector_dim));
+
+	let err = elf_config::validate(&cfg).expect_err("Expected projection validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"embedding_projection.source_dim must be greater than storage.qdrant.vector_dim."
+		),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn embedding_projection_rejects_unknown_mode() {
+	let mut cfg = helpers::base_config();
+	let mut projection = projection(cfg.storage.qdrant.vector_dim * 2);
+
+	projection.mode = "pca".to_string();
+	cfg.embedding_projection = Some(projection);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected projection validation error.");
+
+	assert!(
+		err.to_string().contains("embedding_projection.mode must be truncate."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn disabled_embedding_projection_is_not_validated() {
+	let mut cfg = helpers::base_config();
+
+	cfg.embedding_projection =
+		Some(EmbeddingProjection { enabled: false, mode: String::new(), source_dim: 0 });
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
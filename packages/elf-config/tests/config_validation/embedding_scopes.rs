@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+
+use serde_json::Map;
+
+use crate::helpers;
+use elf_config::{Config, EmbeddingCache, EmbeddingProviderConfig, EmbeddingQueryInput};
+
+fn override_provider(dimensions: u32) -> EmbeddingProviderConfig {
+	EmbeddingProviderConfig {
+		provider_id: "embed-large".to_string(),
+		api_base: "http://localhost".to_string(),
+		api_key: "key".to_string(),
+		path: "/embeddings".to_string(),
+		model: "large-model".to_string(),
+		dimensions,
+		timeout_ms: 1_000,
+		default_headers: Map::new(),
+		query_input: None,
+		model_dir: None,
+		resilience: None,
+		cache: None,
+	}
+}
+
+fn config_with_route(scope: &str, provider: EmbeddingProviderConfig) -> Config {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.embeddings = Some(HashMap::from([("large".to_string(), provider)]));
+	cfg.providers.embedding_scopes =
+		Some(HashMap::from([(scope.to_string(), "large".to_string())]));
+
+	cfg
+}
+
+#[test]
+fn embedding_scopes_accept_allowed_scope_with_matching_dimensions() {
+	let cfg = helpers::base_config();
+	let dim = cfg.storage.qdrant.vector_dim;
+	let cfg = config_with_route("agent_private", override_provider(dim));
+
+	assert!(elf_config::validate(&cfg).is_ok());
+	assert_eq!(cfg.providers.embedding_for_scope("agent_private").model, "large-model");
+	assert_eq!(
+		cfg.providers.embedding_for_scope("project_shared").model,
+		cfg.providers.embedding.model
+	);
+}
+
+#[test]
+fn embedding_routes_require_registered_provider_name() {
+	let dim = helpers::base_config().storage.qdrant.vector_dim;
+	let mut cfg = config_with_route("agent_private", override_provider(dim));
+
+	cfg.providers.embedding_types =
+		Some(HashMap::from([("profile".to_string(), "local".to_string())]));
+
+	let err = elf_config::validate(&cfg).expect_err("Expected embedding route validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"providers.embedding_types.profile must name a provider in providers.embeddings or default, got local."
+		),
+		"Unexpected error: {err}"
+	);
+
+	cfg.providers.embedding_types =
+		Some(HashMap::from([("profile".to_string(), "default".to_string())]));
+
+	assert!(elf_config::validate(&cfg).is_ok());
+	assert_eq!(
+		cfg.providers.embedding_for_note("agent_private", "profile").model,
+		cfg.providers.embedding.model
+	);
+}
+
+#[test]
+fn embeddings_reserve_default_name() {
+	let mut cfg = helpers::base_config();
+	let dim = cfg.storage.qdrant.vector_dim;
+
+	cfg.providers.embeddings =
+		Some(HashMap::from([("default".to_string(), override_provider(dim))]));
+
+	let err = elf_config::validate(&cfg).expect_err("Expected embedding name validation error.");
+
+	assert!(
+		err.to_string().contains("providers.embeddings.default is reserved"),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn embedding_scopes_require_allowed_scope() {
+	let dim = helpers::base_config().storage.qdrant.vector_dim;
+	let cfg = config_with_route("org_shared", override_provider(dim));
+	let err = elf_config::validate(&cfg).expect_err("Expected embedding scope validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"providers.embedding_scopes.org_shared must name a scope listed in scopes.allowed."
+		),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn embeddings_require_collection_dimensions() {
+	let dim = helpers::base_config().storage.qdrant.vector_dim;
+	let cfg = config_with_route("agent_private", override_provider(dim * 2));
+	let err =
+		elf_config::validate(&cfg).expect_err("Expected embedding provider validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"providers.embeddings.large.dimensions must match storage.qdrant.vector_dim."
+		),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn embeddings_require_api_key() {
+	let dim = helpers::base_config().storage.qdrant.vector_dim;
+	let mut provider = override_provider(dim);
+
+	provider.api_key = " ".to_string();
+
+	let cfg = config_with_route("agent_private", provider);
+	let err =
+		elf_config::validate(&cfg).expect_err("Expected embedding provider validation error.");
+
+	assert!(
+		err.to_string().contains("providers.embeddings.large.api_key must be non-empty."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn embedding_types_take_precedence_over_scope_routes() {
+	let dim = helpers::base_config().storage.qdrant.vector_dim;
+	let mut cfg = config_with_route("agent_private", override_provider(dim));
+	let mut local = override_provider(dim);
+
+	local.model = "local-model".to_string();
+	cfg.providers.embeddings.get_or_insert_default().insert("local".to_string(), local);
+	cfg.providers.embedding_types =
+		Some(HashMap::from([("profile".to_string(), "local".to_string())]));
+
+	assert!(elf_config::validate(&cfg).is_ok());
+	assert_eq!(cfg.providers.embedding_for_note("agent_private", "profile").model, "local-model");
+	assert_eq!(cfg.providers.embedding_for_note("agent_private", "decision").model, "large-model");
+	assert_eq!(cfg.providers.embedding_type_providers()["profile"].model, "local-model");
+}
+
+#[test]
+fn embedding_types_require_known_note_type() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.embedding_types =
+		Some(HashMap::from([("journal".to_string(), "default".to_string())]));
+
+	let err = elf_config::validate(&cfg).expect_err("Expected embedding type validation error.");
+
+	assert!(
+		err.to_string().contains("providers.embedding_types.journal must name a note type"),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn embedding_query_input_accepts_template_with_query_placeholder() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.embedding.query_input = Some(EmbeddingQueryInput {
+		strategy: "template".to_string(),
+		template: Some("query: {query}".to_string()),
+	});
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn embedding_query_input_rejects_unknown_strategy() {
+	let dim = helpers::base_config().storage.qdrant.vector_dim;
+	let mut provider = override_provider(dim);
+
+	provider.query_input =
+		Some(EmbeddingQueryInput { strategy: "suffix".to_string(), template: None });
+
+	let cfg = config_with_route("agent_private", provider);
+	let err = elf_config::validate(&cfg).expect_err("Expected query input validation error.");
+
+	assert!(
+		err.to_string().contains("providers.embeddings.large.query_input.strategy"),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn embedding_query_input_requires_query_placeholder_in_template() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.embedding.query_input = Some(EmbeddingQueryInput {
+		strategy: "template".to_string(),
+		template: Some("passage: {context}".to_string()),
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected query input validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"providers.embedding.query_input.template must contain {query} when strategy is template."
+		),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn local_model_provider_requires_model_dir() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.embedding.provider_id = "local_model".to_string();
+
+	let err = elf_config::validate(&cfg).expect_err("Expected model_dir validation error.");
+
+	assert!(
+		err.to_string()
+			.contains("providers.embedding.model_dir is required when provider_id is local_model."),
+		"Unexpected error: {err}"
+	);
+
+	cfg.providers.embedding.model_dir = Some("/models/all-MiniLM-L6-v2".to_string());
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn model_dir_requires_local_model_provider() {
+	let dim = helpers::base_config().storage.qdrant.vector_dim;
+	let mut provider = override_provider(dim);
+
+	provider.model_dir = Some("/models/all-MiniLM-L6-v2".to_string());
+
+	let cfg = config_with_route("agent_private", provider);
+	let err = elf_config::validate(&cfg).expect_err("Expected model_dir validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"providers.embeddings.large.model_dir is only valid when provider_id is local_model."
+		),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn embedding_cache_requires_entries_bounded_window_and_batch() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.embedding.cache =
+		Some(EmbeddingCache { max_entries: 1_000, batch_window_ms: 5, max_batch_texts: Some(64) });
+
+	assert!(elf_config::validate(&cfg).is_ok());
+
+	cfg.providers.embedding.cache =
+		Some(EmbeddingCache { max_entries: 1_000, batch_window_ms: 5, max_batch_texts: Some(0) });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected embedding cache validation error.");
+
+	assert!(
+		err.to_string()
+			.contains("providers.embedding.cache.max_batch_texts must be greater than zero."),
+		"Unexpected error: {err}"
+	);
+
+	cfg.providers.embedding.cache =
+		Some(EmbeddingCache { max_entries: 0, batch_window_ms: 5, max_batch_texts: None });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected embedding cache validation error.");
+
+	assert!(
+		err.to_string()
+			.contains("providers.embedding.cache.max_entries must be greater than zero."),
+		"Unexpected error: {err}"
+	);
+
+	let dim = cfg.storage.qdrant.vector_dim;
+	let mut provider = override_provider(dim);
+
+	provider.cache =
+		Some(EmbeddingCache { max_entries: 10, batch_window_ms: 5_000, max_batch_texts: None });
+
+	let cfg = config_with_route("agent_private", provider);
+	let err = elf_config::validate(&cfg).expect_err("Expected embedding cache validation error.");
+
+	assert!(
+		err.to_string()
+			.contains("providers.embeddings.large.cache.batch_window_ms must be 1000 or less."),
+		"Unexpected error: {err}"
+	);
+}
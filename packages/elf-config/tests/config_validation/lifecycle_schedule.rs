@@ -0,0 +1,78 @@
+use crate::helpers;
+use elf_config::{CronSchedule, LifecycleSchedule};
+
+#[test]
+fn lifecycle_schedule_cron_must_parse() {
+	let mut cfg = helpers::base_config();
+	let schedule =
+		LifecycleSchedule { enabled: true, cron: "0 3 * * *".to_string(), batch_size: 500 };
+
+	cfg.lifecycle.schedule = Some(schedule.clone());
+
+	elf_config::validate(&cfg).expect("Expected lifecycle schedule to validate.");
+
+	cfg.lifecycle.schedule = Some(LifecycleSchedule { cron: "0 25 * * *".to_string(), ..schedule });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected lifecycle cron error.");
+
+	assert!(
+		err.to_string().contains("lifecycle.schedule.cron is invalid: cron hour field"),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn lifecycle_schedule_batch_size_must_be_positive() {
+	let mut cfg = helpers::base_config();
+
+	cfg.lifecycle.schedule =
+		Some(LifecycleSchedule { enabled: true, cron: "*/30 * * * *".to_string(), batch_size: 0 });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected lifecycle batch size error.");
+
+	assert!(
+		err.to_string().contains("lifecycle.schedule.batch_size must be between 1 and 100000."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn cron_schedule_supports_lists_ranges_and_steps() {
+	let schedule = CronSchedule::parse("*/15 9-17 * * 1-5").expect("Expected a valid expression.");
+
+	assert!(schedule.matches_time(9, 0));
+	assert!(schedule.matches_time(17, 45));
+	assert!(!schedule.matches_time(18, 0));
+	assert!(!schedule.matches_time(9, 10));
+	assert!(schedule.matches_date(6, 1, 1));
+	assert!(!schedule.matches_date(6, 7, 0));
+
+	let sundays = CronSchedule::parse("0 0 * * 7").expect("Expected 7 to mean Sunday.");
+
+	assert!(sundays.matches_date(1, 4, 0));
+	assert!(!sundays.matches_date(1, 5, 1));
+
+	let lists = CronSchedule::parse("5,10-20/5 0 1,15 1-12/6 *").expect("Expected a valid list.");
+
+	assert!(lists.matches_time(0, 5));
+	assert!(lists.matches_time(0, 15));
+	assert!(!lists.matches_time(0, 25));
+	assert!(lists.matches_date(7, 15, 3));
+	assert!(!lists.matches_date(2, 15, 3));
+}
+
+#[test]
+fn cron_schedule_matches_either_restricted_day_field() {
+	let schedule = CronSchedule::parse("0 0 1 * 1").expect("Expected a valid expression.");
+
+	assert!(schedule.matches_date(3, 1, 5));
+	assert!(schedule.matches_date(3, 9, 1));
+	assert!(!schedule.matches_date(3, 9, 2));
+}
+
+#[test]
+fn cron_schedule_rejects_malformed_and_unreachable_expressions() {
+	for expr in ["0 3 * *", "60 * * * *", "*/0 * * * *", "a * * * *", "5-1 * * * *", "0 0 31 2 *"] {
+		assert!(CronSchedule::parse(expr).is_err(), "Expected {expr:?} to be rejected.");
+	}
+}
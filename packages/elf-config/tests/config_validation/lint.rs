@@ -0,0 +1,130 @@
+use std::fs;
+
+use toml::{Table, Value};
+
+use crate::helpers;
+use elf_config::{ConfigLintKind, Shadow};
+
+const DEPRECATED_KEYS_FIXTURE_TOML: &str = include_str!("../fixtures/deprecated_keys.toml");
+const FIXTURE_DEPRECATED_KEYS: &[(&str, &str)] = &[
+	("memory.legacy_limit", "memory.limit"),
+	("search.explain.legacy_flag", "search.explain.flag"),
+];
+
+fn deprecated_keys_fixture() -> Table {
+	toml::from_str(DEPRECATED_KEYS_FIXTURE_TOML).expect("Failed to parse deprecated keys fixture.")
+}
+
+#[test]
+fn sample_config_has_no_lints() {
+	let path = helpers::write_temp_config(helpers::sample_toml(true));
+	let result = elf_config::load_with_lints(&path);
+
+	fs::remove_file(&path).expect("Failed to remove test config.");
+
+	let (_, lints) = result.expect("Expected sample config to load.");
+
+	assert!(lints.is_empty(), "Unexpected lints: {lints:?}");
+}
+
+#[test]
+fn deprecated_key_is_migrated() {
+	let mut root = deprecated_keys_fixture();
+	let lints = elf_config::migrate_deprecated_keys(&mut root, FIXTURE_DEPRECATED_KEYS);
+	let memory = root["memory"].as_table().expect("Expected [memory].");
+
+	assert_eq!(memory.get("limit").and_then(Value::as_integer), Some(3));
+	assert!(!memory.contains_key("legacy_limit"));
+	assert_eq!(lints.len(), 2, "Unexpected lints: {lints:?}");
+	assert_eq!(lints[0].kind, ConfigLintKind::DeprecatedKey);
+	assert_eq!(lints[0].key, "memory.legacy_limit");
+	assert!(lints[0].hint.contains("memory.limit"));
+}
+
+#[test]
+fn deprecated_key_is_ignored_when_replacement_is_set() {
+	let mut root = deprecated_keys_fixture();
+	let lints = elf_config::migrate_deprecated_keys(&mut root, FIXTURE_DEPRECATED_KEYS);
+	let explain = root["search"]["explain"].as_table().expect("Expected [search.explain].");
+
+	assert_eq!(explain.get("flag").and_then(Value::as_bool), Some(false));
+	assert!(!explain.contains_key("legacy_flag"));
+	assert_eq!(lints[1].key, "search.explain.legacy_flag");
+	assert!(lints[1].message.contains("ignored"));
+}
+
+#[test]
+fn missing_deprecated_keys_are_not_reported() {
+	let mut root = deprecated_keys_fixture();
+	let lints =
+		elf_config::migrate_deprecated_keys(&mut root, &[("shadow.legacy_rate", "shadow.rate")]);
+
+	assert!(lints.is_empty(), "Unexpected lints: {lints:?}");
+	assert_eq!(root, deprecated_keys_fixture());
+}
+
+#[test]
+fn unknown_keys_are_reported() {
+	let payload = format!("{}\n[search.reranker]\nenabled = true\n", helpers::sample_toml(true));
+	let path = helpers::write_temp_config(payload);
+	let result = elf_config::load_with_lints(&path);
+
+	fs::remove_file(&path).expect("Failed to remove test config.");
+
+	let (_, lints) = result.expect("Expected unknown keys to be non-fatal.");
+
+	assert_eq!(lints.len(), 1, "Unexpected lints: {lints:?}");
+	assert_eq!(lints[0].kind, ConfigLintKind::UnknownKey);
+	assert_eq!(lints[0].key, "search.reranker");
+}
+
+#[test]
+fn disabled_optional_section_is_unused() {
+	let mut cfg = helpers::base_config();
+
+	cfg.shadow = Some(Shadow {
+		enabled: false,
+		base_url: "http://127.0.0.1:61892".to_string(),
+		sample_rate: 0.5,
+		timeout_ms: 1_000,
+		auth_token: None,
+	});
+
+	let lints = elf_config::lint(&cfg);
+
+	assert_eq!(lints.len(), 1, "Unexpected lints: {lints:?}");
+	assert_eq!(lints[0].kind, ConfigLintKind::UnusedSection);
+	assert_eq!(lints[0].key, "shadow");
+}
+
+#[test]
+fn deterministic_terms_without_parent_are_unused() {
+	let mut cfg = helpers::base_config();
+
+	cfg.ranking.deterministic.hits.enabled = true;
+
+	let lints = elf_config::lint(&cfg);
+
+	assert_eq!(lints.len(), 1, "Unexpected lints: {lints:?}");
+	assert_eq!(lints[0].key, "ranking.deterministic.hits");
+
+	cfg.ranking.deterministic.enabled = true;
+
+	assert!(elf_config::lint(&cfg).is_empty());
+}
+
+#[test]
+fn non_recommended_values_are_flagged() {
+	let mut cfg = helpers::base_config();
+
+	cfg.security.redact_secrets_on_write = false;
+	cfg.search.cache.enabled = false;
+
+	let keys = elf_config::lint(&cfg)
+		.into_iter()
+		.filter(|lint| lint.kind == ConfigLintKind::NotRecommended)
+		.map(|lint| lint.key)
+		.collect::<Vec<_>>();
+
+	assert_eq!(keys, vec!["security.redact_secrets_on_write", "search.cache.enabled"]);
+}
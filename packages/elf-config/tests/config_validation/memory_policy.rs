@@ -1,5 +1,7 @@
 use crate::helpers;
-use elf_config::MemoryPolicyRule;
+use elf_config::{
+	LifecycleConsolidation, LifecycleImportanceRescore, MemoryPolicyRule, MemoryWriteAnomaly,
+};
 
 #[test]
 fn memory_policy_min_confidence_must_be_finite() {
@@ -86,7 +88,7 @@ fn memory_policy_note_type_must_be_known_value() {
 
 	assert!(
 		err.to_string().contains(
-			"memory.policy.rules[1].note_type must be one of preference, constraint, decision, profile, fact, or plan."
+			"memory.policy.rules[1].note_type must be one of preference, constraint, decision, profile, fact, plan, or qa."
 		),
 		"Unexpected error: {err}"
 	);
@@ -161,3 +163,135 @@ fn memory_policy_scope_must_not_be_whitespace_only() {
 		"Unexpected error: {err}"
 	);
 }
+
+#[test]
+fn memory_write_anomaly_rejects_unknown_action_and_out_of_range_threshold() {
+	let mut cfg = helpers::base_config();
+
+	cfg.memory.write_anomaly = Some(MemoryWriteAnomaly {
+		window_seconds: 300,
+		max_similar_writes: 20,
+		similarity_threshold: 0.8,
+		action: "quarantine".to_string(),
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected write_anomaly action error.");
+
+	assert!(
+		err.to_string().contains("memory.write_anomaly.action must be one of reject or report."),
+		"Unexpected error: {err}"
+	);
+
+	cfg.memory.write_anomaly = Some(MemoryWriteAnomaly {
+		window_seconds: 300,
+		max_similar_writes: 20,
+		similarity_threshold: 1.5,
+		action: "reject".to_string(),
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected write_anomaly threshold error.");
+
+	assert!(
+		err.to_string()
+			.contains("memory.write_anomaly.similarity_threshold must be in (0.0, 1.0]."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn lifecycle_trash_retention_must_be_positive() {
+	let mut cfg = helpers::base_config();
+
+	assert_eq!(cfg.lifecycle.trash_retention_days(), elf_config::DEFAULT_PURGE_TRASHED_AFTER_DAYS);
+
+	cfg.lifecycle.purge_trashed_after_days = Some(0);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected trash retention validation error.");
+
+	assert!(
+		err.to_string().contains("lifecycle.purge_trashed_after_days must be greater than zero."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn lifecycle_consolidation_threshold_must_be_in_unit_interval() {
+	let mut cfg = helpers::base_config();
+	let consolidation = LifecycleConsolidation {
+		enabled: true,
+		interval_seconds: 3_600,
+		similarity_threshold: 0.95,
+		max_merges_per_run: 20,
+	};
+
+	cfg.lifecycle.consolidation = Some(consolidation.clone());
+
+	elf_config::validate(&cfg).expect("Expected consolidation settings to validate.");
+
+	cfg.lifecycle.consolidation =
+		Some(LifecycleConsolidation { similarity_threshold: 1.5, ..consolidation });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected consolidation threshold error.");
+
+	assert!(
+		err.to_string()
+			.contains("lifecycle.consolidation.similarity_threshold must be in (0.0, 1.0]."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn lifecycle_importance_rescore_weights_must_be_valid() {
+	let mut cfg = helpers::base_config();
+	let rescore = LifecycleImportanceRescore {
+		enabled: true,
+		interval_seconds: 3_600,
+		batch_size: 500,
+		min_delta: 0.01,
+		prior_weight: 0.7,
+		hits_weight: 0.2,
+		hits_half_saturation: 8.0,
+		last_hit_tau_days: 30.0,
+		feedback_weight: 0.1,
+		age_weight: 0.05,
+		age_tau_days: 180.0,
+	};
+
+	cfg.lifecycle.importance_rescore = Some(rescore.clone());
+
+	elf_config::validate(&cfg).expect("Expected importance rescore settings to validate.");
+
+	cfg.lifecycle.importance_rescore =
+		Some(LifecycleImportanceRescore { prior_weight: 1.2, ..rescore.clone() });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected prior weight error.");
+
+	assert!(
+		err.to_string()
+			.contains("lifecycle.importance_rescore.prior_weight must be in [0.0, 1.0]."),
+		"Unexpected error: {err}"
+	);
+
+	cfg.lifecycle.importance_rescore =
+		Some(LifecycleImportanceRescore { feedback_weight: -0.1, ..rescore.clone() });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected feedback weight error.");
+
+	assert!(
+		err.to_string().contains(
+			"lifecycle.importance_rescore.feedback_weight must be a finite number >= 0.0."
+		),
+		"Unexpected error: {err}"
+	);
+
+	cfg.lifecycle.importance_rescore =
+		Some(LifecycleImportanceRescore { age_tau_days: 0.0, ..rescore });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected age decay window error.");
+
+	assert!(
+		err.to_string()
+			.contains("lifecycle.importance_rescore.age_tau_days must be greater than zero."),
+		"Unexpected error: {err}"
+	);
+}
@@ -0,0 +1,57 @@
+use crate::helpers;
+use elf_config::ServiceOtel;
+
+fn otel() -> ServiceOtel {
+	ServiceOtel {
+		enabled: true,
+		endpoint: "http://127.0.0.1:4318/v1/traces".to_string(),
+		sample_ratio: 0.25,
+		timeout_ms: 3_000,
+	}
+}
+
+#[test]
+fn otel_accepts_valid_settings() {
+	let mut cfg = helpers::base_config();
+
+	cfg.service.otel = Some(otel());
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn otel_skips_checks_when_disabled() {
+	let mut cfg = helpers::base_config();
+
+	cfg.service.otel = Some(ServiceOtel { enabled: false, endpoint: String::new(), ..otel() });
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn otel_requires_endpoint_when_enabled() {
+	let mut cfg = helpers::base_config();
+
+	cfg.service.otel = Some(ServiceOtel { endpoint: "  ".to_string(), ..otel() });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected otel validation error.");
+
+	assert!(
+		err.to_string().contains("service.otel.endpoint must be non-empty when enabled."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn otel_rejects_sample_ratio_out_of_range() {
+	let mut cfg = helpers::base_config();
+
+	cfg.service.otel = Some(ServiceOtel { sample_ratio: 1.5, ..otel() });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected otel validation error.");
+
+	assert!(
+		err.to_string().contains("service.otel.sample_ratio must be between 0.0 and 1.0."),
+		"Unexpected error: {err}"
+	);
+}
@@ -0,0 +1,57 @@
+use crate::helpers;
+use elf_config::ProviderResilience;
+
+fn resilience() -> ProviderResilience {
+	ProviderResilience {
+		max_retries: 2,
+		initial_backoff_ms: 100,
+		max_backoff_ms: 1_000,
+		call_timeout_ms: Some(5_000),
+		failure_threshold: 5,
+		open_ms: 30_000,
+	}
+}
+
+#[test]
+fn provider_resilience_accepts_valid_settings() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.embedding.resilience = Some(resilience());
+	cfg.providers.rerank.resilience = Some(resilience());
+	cfg.providers.llm_extractor.resilience = Some(resilience());
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn provider_resilience_requires_max_backoff_at_least_initial_backoff() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.rerank.resilience =
+		Some(ProviderResilience { max_backoff_ms: 50, ..resilience() });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected resilience validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"providers.rerank.resilience.max_backoff_ms must be greater than or equal to initial_backoff_ms."
+		),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn provider_resilience_requires_open_window_with_breaker() {
+	let mut cfg = helpers::base_config();
+
+	cfg.providers.llm_extractor.resilience =
+		Some(ProviderResilience { open_ms: 0, ..resilience() });
+
+	let err = elf_config::validate(&cfg).expect_err("Expected resilience validation error.");
+
+	assert!(
+		err.to_string()
+			.contains("providers.llm_extractor.resilience.open_ms must be greater than zero"),
+		"Unexpected error: {err}"
+	);
+}
@@ -0,0 +1,78 @@
+use crate::helpers;
+use elf_config::QdrantMaintenance;
+
+fn qdrant_maintenance() -> QdrantMaintenance {
+	QdrantMaintenance {
+		enabled: true,
+		optimize_interval_seconds: Some(86_400),
+		snapshot_interval_seconds: Some(86_400),
+		verify_interval_seconds: Some(3_600),
+		snapshot_retention: 7,
+		max_count_drift: 0,
+		rest_url: Some("http://127.0.0.1:6333".to_string()),
+		alert_webhook: None,
+	}
+}
+
+#[test]
+fn qdrant_maintenance_accepts_valid_settings() {
+	let mut cfg = helpers::base_config();
+
+	cfg.qdrant_maintenance = Some(qdrant_maintenance());
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn qdrant_maintenance_intervals_must_be_at_least_a_minute() {
+	let mut cfg = helpers::base_config();
+	let mut settings = qdrant_maintenance();
+
+	settings.verify_interval_seconds = Some(30);
+	cfg.qdrant_maintenance = Some(settings);
+
+	let err =
+		elf_config::validate(&cfg).expect_err("Expected qdrant_maintenance validation error.");
+
+	assert!(
+		err.to_string()
+			.contains("qdrant_maintenance.verify_interval_seconds must be at least 60 seconds."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn qdrant_maintenance_enabled_requires_an_interval() {
+	let mut cfg = helpers::base_config();
+	let mut settings = qdrant_maintenance();
+
+	settings.optimize_interval_seconds = None;
+	settings.snapshot_interval_seconds = None;
+	settings.verify_interval_seconds = None;
+	cfg.qdrant_maintenance = Some(settings);
+
+	let err =
+		elf_config::validate(&cfg).expect_err("Expected qdrant_maintenance validation error.");
+
+	assert!(
+		err.to_string().contains("qdrant_maintenance requires at least one interval when enabled."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn qdrant_maintenance_urls_must_be_http() {
+	let mut cfg = helpers::base_config();
+	let mut settings = qdrant_maintenance();
+
+	settings.alert_webhook = Some("ftp://alerts.example".to_string());
+	cfg.qdrant_maintenance = Some(settings);
+
+	let err =
+		elf_config::validate(&cfg).expect_err("Expected qdrant_maintenance validation error.");
+
+	assert!(
+		err.to_string().contains("qdrant_maintenance.alert_webhook must be an http or https URL."),
+		"Unexpected error: {err}"
+	);
+}
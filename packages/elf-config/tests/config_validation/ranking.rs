@@ -31,3 +31,22 @@ fn retrieval_source_weights_require_at_least_one_positive() {
 		"Unexpected error: {err}"
 	);
 }
+
+#[test]
+fn diversity_scope_balance_requires_known_attribute() {
+	let mut cfg = helpers::base_config();
+
+	cfg.ranking.diversity.scope_balance = Some(elf_config::RankingDiversityScopeBalance {
+		enabled: true,
+		by: "agent".to_string(),
+		weight: 0.2,
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected scope_balance.by validation error.");
+
+	assert!(
+		err.to_string()
+			.contains("ranking.diversity.scope_balance.by must be one of scope or type."),
+		"Unexpected error: {err}"
+	);
+}
@@ -0,0 +1,73 @@
+use crate::helpers;
+use elf_config::{Config, Reembed};
+
+fn reembed(cfg: &Config) -> Reembed {
+	let mut provider = cfg.providers.embedding.clone();
+
+	provider.model = "next-embedding-model".to_string();
+	provider.dimensions = 1_024;
+
+	Reembed { collection: "mem_notes_v3".to_string(), batch_size: 64, provider }
+}
+
+#[test]
+fn reembed_accepts_valid_settings() {
+	let mut cfg = helpers::base_config();
+
+	cfg.reembed = Some(reembed(&cfg));
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn reembed_collection_must_differ_from_active_collection() {
+	let mut cfg = helpers::base_config();
+	let mut settings = reembed(&cfg);
+
+	settings.collection = cfg.storage.qdrant.collection.clone();
+	cfg.reembed = Some(settings);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected reembed validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"reembed.collection must differ from storage.qdrant.collection and docs_collection."
+		),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn reembed_provider_must_change_the_embedding() {
+	let mut cfg = helpers::base_config();
+	let mut settings = reembed(&cfg);
+
+	settings.provider = cfg.providers.embedding.clone();
+	settings.provider.dimensions = cfg.storage.qdrant.vector_dim;
+	cfg.reembed = Some(settings);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected reembed validation error.");
+
+	assert!(
+		err.to_string().contains(
+			"reembed.provider must change the embedding provider_id, model, or dimensions."
+		),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn reembed_batch_size_is_bounded() {
+	let mut cfg = helpers::base_config();
+	let mut settings = reembed(&cfg);
+
+	settings.batch_size = 0;
+	cfg.reembed = Some(settings);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected reembed validation error.");
+
+	assert!(
+		err.to_string().contains("reembed.batch_size must be between 1 and 1000."),
+		"Unexpected error: {err}"
+	);
+}
@@ -77,6 +77,18 @@ fn recursive_search_settings_require_reasonable_bounds() {
 		),
 		"Unexpected error: {err}"
 	);
+
+	cfg = helpers::base_config();
+	cfg.search.recursive.enabled = true;
+	cfg.search.recursive.max_elapsed_ms = Some(0);
+
+	let err = elf_config::validate(&cfg)
+		.expect_err("Expected recursive max_elapsed_ms validation error.");
+
+	assert!(
+		err.to_string().contains("search.recursive.max_elapsed_ms must be greater than zero."),
+		"Unexpected error: {err}"
+	);
 }
 
 #[test]
@@ -147,3 +159,90 @@ fn graph_context_settings_max_evidence_notes_per_fact_cannot_exceed_hard_limit()
 		"Unexpected error: {err}"
 	);
 }
+
+#[test]
+fn snippet_limits_require_a_cap_and_a_sane_char_floor() {
+	let mut cfg = helpers::base_config();
+
+	cfg.search.snippet = Some(elf_config::SearchSnippet {
+		l0: Some(elf_config::SearchSnippetLimit { max_chars: None, max_tokens: None }),
+		l1: None,
+		l2: None,
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected empty snippet limit error.");
+
+	assert!(
+		err.to_string().contains("search.snippet.l0 must set max_chars or max_tokens."),
+		"Unexpected error: {err}"
+	);
+
+	cfg.search.snippet = Some(elf_config::SearchSnippet {
+		l0: None,
+		l1: Some(elf_config::SearchSnippetLimit { max_chars: Some(8), max_tokens: None }),
+		l2: None,
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected snippet max_chars floor error.");
+
+	assert!(
+		err.to_string().contains("search.snippet.l1.max_chars must be at least 16."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn answer_settings_bound_sources_and_grounding() {
+	let mut cfg = helpers::base_config();
+
+	cfg.search.answer = Some(elf_config::SearchAnswer {
+		enabled: true,
+		max_sources: 0,
+		max_sentences: 4,
+		min_grounding: 0.6,
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected answer max_sources error.");
+
+	assert!(
+		err.to_string().contains("search.answer.max_sources must be between 1 and 20."),
+		"Unexpected error: {err}"
+	);
+
+	cfg.search.answer = Some(elf_config::SearchAnswer {
+		enabled: true,
+		max_sources: 8,
+		max_sentences: 4,
+		min_grounding: 1.2,
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected answer min_grounding error.");
+
+	assert!(
+		err.to_string().contains("search.answer.min_grounding must be between 0.0 and 1.0."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn result_cache_ttl_is_bounded() {
+	let mut cfg = helpers::base_config();
+
+	cfg.search.cache.results =
+		Some(elf_config::SearchResultCache { enabled: true, ttl_seconds: 300 });
+
+	elf_config::validate(&cfg).expect("Expected result cache settings to be valid.");
+
+	for ttl_seconds in [0, 3_601] {
+		cfg.search.cache.results =
+			Some(elf_config::SearchResultCache { enabled: true, ttl_seconds });
+
+		let err = elf_config::validate(&cfg).expect_err("Expected result cache TTL error.");
+
+		assert!(
+			err.to_string()
+				.contains("search.cache.results.ttl_seconds must be between 1 and 3600."),
+			"Unexpected error: {err}"
+		);
+	}
+}
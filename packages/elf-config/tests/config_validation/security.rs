@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+
 use crate::helpers;
+use elf_config::{SecurityAuthRole, SecurityRolePermissions};
 
 #[test]
 fn security_auth_keys_require_unique_token_ids() {
@@ -60,3 +63,191 @@ fn security_auth_keys_require_known_read_profile() {
 		"Unexpected error: {err}"
 	);
 }
+
+#[test]
+fn security_quotas_reject_zero_limits() {
+	let mut cfg = helpers::base_config();
+
+	cfg.security.quotas = Some(elf_config::SecurityQuotas {
+		max_notes_per_day: Some(1_000),
+		max_search_qps: Some(0),
+		max_total_notes: None,
+	});
+
+	let err = elf_config::validate(&cfg).expect_err("Expected quota validation error.");
+
+	assert!(
+		err.to_string()
+			.contains("security.quotas.max_search_qps must be greater than zero when set."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn security_secret_patterns_reject_invalid_custom_rules() {
+	let mut cfg = helpers::base_config();
+	let pattern = |name: &str, pattern: &str, min_entropy| elf_config::SecuritySecretPattern {
+		name: name.to_string(),
+		pattern: pattern.to_string(),
+		min_entropy,
+	};
+
+	for (custom, expected) in [
+		(
+			vec![pattern("corp_live_token", "corp_live_[", None)],
+			"security.secret_patterns.custom[0].pattern must be a valid regular expression",
+		),
+		(
+			vec![pattern("corp_live_token", "(corp_live_)?", None)],
+			"security.secret_patterns.custom[0].pattern must not match empty text.",
+		),
+		(
+			vec![pattern("Corp Token", r"corp_live_\w+", None)],
+			"security.secret_patterns.custom[0].name must be non-empty lowercase snake_case.",
+		),
+		(
+			vec![
+				pattern("corp_live_token", r"corp_live_\w+", None),
+				pattern("corp_live_token", r"corp_test_\w+", None),
+			],
+			"security.secret_patterns.custom[1].name must be unique",
+		),
+		(
+			vec![pattern("corp_live_token", r"corp_live_\w+", Some(9.0))],
+			"security.secret_patterns.custom[0].min_entropy must be in the range (0.0, 8.0].",
+		),
+		(vec![], "security.secret_patterns.custom must be non-empty when disable_builtin is true."),
+	] {
+		cfg.security.secret_patterns =
+			Some(elf_config::SecuritySecretPatterns { disable_builtin: true, custom });
+
+		let err =
+			elf_config::validate(&cfg).expect_err("Expected secret pattern validation error.");
+
+		assert!(err.to_string().contains(expected), "Unexpected error: {err}");
+	}
+
+	cfg.security.secret_patterns = Some(elf_config::SecuritySecretPatterns {
+		disable_builtin: false,
+		custom: vec![pattern("corp_live_token", "corp_live_[A-Za-z0-9]{16,}", Some(3.5))],
+	});
+
+	elf_config::validate(&cfg).expect("Expected valid secret patterns.");
+}
+
+fn jwt_config() -> elf_config::SecurityJwt {
+	elf_config::SecurityJwt {
+		jwks_url: "https://issuer.example/.well-known/jwks.json".to_string(),
+		issuer: "https://issuer.example/".to_string(),
+		audience: "elf".to_string(),
+		algorithms: vec!["RS256".to_string()],
+		jwks_cache_seconds: 300,
+		leeway_seconds: 30,
+		claims: elf_config::SecurityJwtClaims {
+			tenant_id: "elf_tenant".to_string(),
+			project_id: "elf_project".to_string(),
+			agent_id: "elf_agent".to_string(),
+			read_profile: "elf_read_profile".to_string(),
+			role: Some("elf_role".to_string()),
+		},
+	}
+}
+
+#[test]
+fn security_jwt_mode_requires_jwt_section() {
+	let mut cfg = helpers::base_config();
+
+	cfg.security.auth_mode = "jwt".to_string();
+
+	let err = elf_config::validate(&cfg).expect_err("Expected missing security.jwt error.");
+
+	assert!(
+		err.to_string().contains("security.jwt is required when security.auth_mode is jwt."),
+		"Unexpected error: {err}"
+	);
+
+	cfg.security.jwt = Some(jwt_config());
+
+	elf_config::validate(&cfg).expect("Expected jwt auth config to validate.");
+}
+
+#[test]
+fn security_jwt_rejects_symmetric_algorithms() {
+	let mut cfg = helpers::base_config();
+	let mut jwt = jwt_config();
+
+	jwt.algorithms = vec!["HS256".to_string()];
+	cfg.security.auth_mode = "jwt".to_string();
+	cfg.security.jwt = Some(jwt);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected jwt algorithm validation error.");
+
+	assert!(err.to_string().contains("security.jwt.algorithms"), "Unexpected error: {err}");
+}
+
+#[test]
+fn security_permissions_keep_readers_read_only_and_org_shared_admin_only() {
+	let mut cfg = helpers::base_config();
+
+	cfg.security.permissions = Some(HashMap::from([(
+		SecurityAuthRole::Reader,
+		SecurityRolePermissions {
+			add_note: vec!["agent_private".to_string()],
+			..Default::default()
+		},
+	)]));
+
+	let err = elf_config::validate(&cfg).expect_err("Expected reader permission error.");
+
+	assert!(
+		err.to_string().contains("security.permissions.reader.add_note must be empty"),
+		"Unexpected error: {err}"
+	);
+
+	cfg.security.permissions = Some(HashMap::from([(
+		SecurityAuthRole::User,
+		SecurityRolePermissions { update: vec!["org_shared".to_string()], ..Default::default() },
+	)]));
+
+	let err = elf_config::validate(&cfg).expect_err("Expected org_shared permission error.");
+
+	assert!(
+		err.to_string().contains("security.permissions.user.update cannot include org_shared"),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn security_egress_requires_bare_lowercase_hosts() {
+	let cases = [
+		(vec!["docs.example.com".to_string()], vec!["https://internal.example.com".to_string()]),
+		(vec!["Docs.Example.com".to_string()], Vec::new()),
+		(Vec::new(), vec!["internal.example.com:8080".to_string()]),
+		(vec![".example.com".to_string()], Vec::new()),
+	];
+	let expected = [
+		"security.egress.denied_hosts[0] must be a lowercase host name",
+		"security.egress.allowed_hosts[0] must be a lowercase host name",
+		"security.egress.denied_hosts[0] must be a lowercase host name",
+		"security.egress.allowed_hosts[0] must be a lowercase host name",
+	];
+
+	for ((allowed_hosts, denied_hosts), expected) in cases.into_iter().zip(expected) {
+		let mut cfg = helpers::base_config();
+
+		cfg.security.egress = Some(elf_config::SecurityEgress { allowed_hosts, denied_hosts });
+
+		let err = elf_config::validate(&cfg).expect_err("Expected egress validation error.");
+
+		assert!(err.to_string().contains(expected), "Unexpected error: {err}");
+	}
+
+	let mut cfg = helpers::base_config();
+
+	cfg.security.egress = Some(elf_config::SecurityEgress {
+		allowed_hosts: vec!["docs.example.com".to_string()],
+		denied_hosts: vec!["internal.example.com".to_string()],
+	});
+
+	elf_config::validate(&cfg).expect("Expected valid egress host lists.");
+}
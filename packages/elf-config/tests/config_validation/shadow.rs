@@ -0,0 +1,73 @@
+use crate::helpers;
+use elf_config::Shadow;
+
+fn shadow(sample_rate: f32) -> Shadow {
+	Shadow {
+		enabled: true,
+		base_url: "http://127.0.0.1:61892".to_string(),
+		sample_rate,
+		timeout_ms: 5_000,
+		auth_token: None,
+	}
+}
+
+#[test]
+fn shadow_is_optional() {
+	let cfg = helpers::base_config();
+
+	assert!(cfg.shadow.is_none());
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn shadow_accepts_valid_settings() {
+	let mut cfg = helpers::base_config();
+
+	cfg.shadow = Some(shadow(0.25));
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn shadow_sample_rate_must_be_in_range() {
+	for sample_rate in [0.0, 1.5, f32::NAN] {
+		let mut cfg = helpers::base_config();
+
+		cfg.shadow = Some(shadow(sample_rate));
+
+		let err = elf_config::validate(&cfg).expect_err("Expected shadow validation error.");
+
+		assert!(
+			err.to_string()
+				.contains("shadow.sample_rate must be greater than zero and at most 1.0."),
+			"Unexpected error: {err}"
+		);
+	}
+}
+
+#[test]
+fn shadow_base_url_must_be_http() {
+	let mut cfg = helpers::base_config();
+	let mut settings = shadow(0.5);
+
+	settings.base_url = "127.0.0.1:61892".to_string();
+	cfg.shadow = Some(settings);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected shadow validation error.");
+
+	assert!(
+		err.to_string().contains("shadow.base_url must be an http:// or https:// URL."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn disabled_shadow_skips_validation() {
+	let mut cfg = helpers::base_config();
+	let mut settings = shadow(0.0);
+
+	settings.enabled = false;
+	cfg.shadow = Some(settings);
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
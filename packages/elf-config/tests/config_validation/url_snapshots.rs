@@ -0,0 +1,55 @@
+use crate::helpers;
+use elf_config::UrlSnapshots;
+
+fn url_snapshots() -> UrlSnapshots {
+	UrlSnapshots {
+		enabled: true,
+		max_bytes: 1_048_576,
+		timeout_ms: 10_000,
+		user_agent: "elf-snapshot/1.0".to_string(),
+		max_attempts: 3,
+	}
+}
+
+#[test]
+fn url_snapshots_accepts_valid_settings() {
+	let mut cfg = helpers::base_config();
+
+	cfg.url_snapshots = Some(url_snapshots());
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn url_snapshots_max_bytes_must_be_bounded() {
+	for max_bytes in [0, 8 * 1_024 * 1_024] {
+		let mut cfg = helpers::base_config();
+		let mut settings = url_snapshots();
+
+		settings.max_bytes = max_bytes;
+		cfg.url_snapshots = Some(settings);
+
+		let err = elf_config::validate(&cfg).expect_err("Expected url_snapshots validation error.");
+
+		assert!(
+			err.to_string().contains("url_snapshots.max_bytes must be greater than zero"),
+			"Unexpected error: {err}"
+		);
+	}
+}
+
+#[test]
+fn url_snapshots_user_agent_must_be_non_empty() {
+	let mut cfg = helpers::base_config();
+	let mut settings = url_snapshots();
+
+	settings.user_agent = "  ".to_string();
+	cfg.url_snapshots = Some(settings);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected url_snapshots validation error.");
+
+	assert!(
+		err.to_string().contains("url_snapshots.user_agent must be a non-empty ASCII string."),
+		"Unexpected error: {err}"
+	);
+}
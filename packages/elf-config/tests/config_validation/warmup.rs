@@ -0,0 +1,60 @@
+use crate::helpers;
+use elf_config::Warmup;
+
+fn warmup(queries: &[&str]) -> Warmup {
+	Warmup {
+		enabled: true,
+		queries: queries.iter().map(|query| query.to_string()).collect(),
+		cache_rows: 200,
+		max_provider_latency_ms: 2_000,
+	}
+}
+
+#[test]
+fn warmup_accepts_valid_settings() {
+	let mut cfg = helpers::base_config();
+
+	cfg.warmup = Some(warmup(&["deployment checklist", "preferred language"]));
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
+
+#[test]
+fn warmup_queries_must_be_present_and_non_empty() {
+	for queries in [&[][..], &["deployment checklist", "  "][..]] {
+		let mut cfg = helpers::base_config();
+
+		cfg.warmup = Some(warmup(queries));
+
+		let err = elf_config::validate(&cfg).expect_err("Expected warmup validation error.");
+
+		assert!(err.to_string().contains("warmup.queries must"), "Unexpected error: {err}");
+	}
+}
+
+#[test]
+fn warmup_latency_budget_must_be_positive() {
+	let mut cfg = helpers::base_config();
+	let mut settings = warmup(&["deployment checklist"]);
+
+	settings.max_provider_latency_ms = 0;
+	cfg.warmup = Some(settings);
+
+	let err = elf_config::validate(&cfg).expect_err("Expected warmup validation error.");
+
+	assert!(
+		err.to_string().contains("warmup.max_provider_latency_ms must be greater than zero."),
+		"Unexpected error: {err}"
+	);
+}
+
+#[test]
+fn disabled_warmup_skips_validation() {
+	let mut cfg = helpers::base_config();
+	let mut settings = warmup(&[]);
+
+	settings.enabled = false;
+	cfg.warmup = Some(settings);
+
+	assert!(elf_config::validate(&cfg).is_ok());
+}
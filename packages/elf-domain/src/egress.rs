@@ -0,0 +1,143 @@
+//! Policy for outbound requests to caller-supplied URLs.
+//!
+//! URL snapshots and standing query webhooks fetch URLs that callers provide. These helpers
+//! keep such requests off loopback, private, link-local, and other non-public addresses, and
+//! apply the operator's `security.egress` host lists.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use elf_config::SecurityEgress;
+
+/// Why an outbound host was refused.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EgressDenial {
+	/// The host is an IP literal outside the public address space.
+	NonPublicAddress,
+	/// The host name always refers to the local machine, such as `localhost`.
+	LocalHostName,
+	/// The host matches `security.egress.denied_hosts`.
+	DeniedHost,
+	/// `security.egress.allowed_hosts` is set and the host matches no entry.
+	HostNotAllowed,
+}
+impl EgressDenial {
+	/// Returns a short, user-facing reason.
+	pub fn message(self) -> &'static str {
+		match self {
+			Self::NonPublicAddress => "target address is not public",
+			Self::LocalHostName => "target host is local",
+			Self::DeniedHost => "target host is denied by security.egress.denied_hosts",
+			Self::HostNotAllowed => "target host is not in security.egress.allowed_hosts",
+		}
+	}
+}
+
+/// Returns the host of an absolute `http` or `https` URL, without brackets around IPv6 literals.
+pub fn url_host(url: &str) -> Option<&str> {
+	let rest = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"))?;
+	let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+	let host_port = authority.rsplit_once('@').map_or(authority, |(_, host_port)| host_port);
+	let host = match host_port.strip_prefix('[') {
+		Some(bracketed) => bracketed.split_once(']')?.0,
+		None => host_port.split(':').next().unwrap_or_default(),
+	};
+
+	(!host.is_empty()).then_some(host)
+}
+
+/// Checks a host name or IP literal against the fixed local rules and the configured host lists.
+///
+/// Host names are not resolved here; callers that connect must also check every resolved address
+/// with [`is_public_ip`].
+pub fn check_host(host: &str, policy: Option<&SecurityEgress>) -> Result<(), EgressDenial> {
+	let host = host.trim_start_matches('[').trim_end_matches(']').trim_end_matches('.');
+	let host = host.to_ascii_lowercase();
+
+	if let Ok(ip) = host.parse::<IpAddr>() {
+		if !is_public_ip(ip) {
+			return Err(EgressDenial::NonPublicAddress);
+		}
+	} else if host == "localhost" || host.ends_with(".localhost") {
+		return Err(EgressDenial::LocalHostName);
+	}
+
+	let Some(policy) = policy else { return Ok(()) };
+
+	if policy.denied_hosts.iter().any(|entry| host_matches(&host, entry)) {
+		return Err(EgressDenial::DeniedHost);
+	}
+	if !policy.allowed_hosts.is_empty()
+		&& !policy.allowed_hosts.iter().any(|entry| host_matches(&host, entry))
+	{
+		return Err(EgressDenial::HostNotAllowed);
+	}
+
+	Ok(())
+}
+
+/// Returns whether `ip` is a globally routable unicast address.
+///
+/// Loopback, unspecified, private, shared (CGNAT), link-local, unique-local, multicast,
+/// broadcast, documentation, and benchmarking ranges are not public. IPv4-mapped and
+/// IPv4-compatible IPv6 addresses, and NAT64 addresses, are judged by their IPv4 address.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+	match ip {
+		IpAddr::V4(ip) => is_public_ipv4(ip),
+		IpAddr::V6(ip) => is_public_ipv6(ip),
+	}
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+	let [a, b, c, _] = ip.octets();
+
+	!(ip.is_unspecified()
+		|| ip.is_loopback()
+		|| ip.is_private()
+		|| ip.is_link_local()
+		|| ip.is_broadcast()
+		|| ip.is_documentation()
+		|| ip.is_multicast()
+		// 0.0.0.0/8 "this network".
+		|| a == 0
+		// 100.64.0.0/10 shared address space.
+		|| (a == 100 && (b & 0xc0) == 64)
+		// 192.0.0.0/24 protocol assignments.
+		|| (a == 192 && b == 0 && c == 0)
+		// 198.18.0.0/15 benchmarking.
+		|| (a == 198 && (b & 0xfe) == 18)
+		// 240.0.0.0/4 reserved.
+		|| a >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+	if let Some(v4) = ip.to_ipv4() {
+		return is_public_ipv4(v4);
+	}
+
+	let segments = ip.segments();
+
+	// 64:ff9b::/96 NAT64 reaches the embedded IPv4 address.
+	if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+		let [.., hi, lo] = segments;
+
+		return is_public_ipv4(Ipv4Addr::from((u32::from(hi) << 16) | u32::from(lo)));
+	}
+
+	let first = segments[0];
+
+	!(ip.is_unspecified()
+		|| ip.is_loopback()
+		|| ip.is_multicast()
+		// fc00::/7 unique local.
+		|| (first & 0xfe00) == 0xfc00
+		// fe80::/10 link local.
+		|| (first & 0xffc0) == 0xfe80
+		// fec0::/10 deprecated site local.
+		|| (first & 0xffc0) == 0xfec0
+		// 2001:db8::/32 documentation.
+		|| (first == 0x2001 && segments[1] == 0x0db8))
+}
+
+fn host_matches(host: &str, entry: &str) -> bool {
+	host == entry || host.strip_suffix(entry).is_some_and(|prefix| prefix.ends_with('.'))
+}
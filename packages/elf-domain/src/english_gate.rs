@@ -1,5 +1,7 @@
 //! English-gate helpers for request text and identifiers.
 
+use std::ops::Range;
+
 use unicode_normalization::UnicodeNormalization;
 use unicode_script::{Script, UnicodeScript};
 use whatlang::Lang;
@@ -25,6 +27,17 @@ pub enum EnglishGateRejectReason {
 	/// Language identification reported a confident non-English result.
 	LanguageIdNonEnglish,
 }
+impl EnglishGateRejectReason {
+	/// Returns the stable snake_case label for this reason.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::DisallowedControlChar => "disallowed_control_char",
+			Self::DisallowedZeroWidthChar => "disallowed_zero_width_char",
+			Self::DisallowedScript => "disallowed_script",
+			Self::LanguageIdNonEnglish => "language_id_non_english",
+		}
+	}
+}
 
 /// Applies ELF's English gate to an input string.
 pub fn english_gate(input: &str, kind: EnglishGateKind) -> Result<(), EnglishGateRejectReason> {
@@ -59,6 +72,29 @@ pub fn is_english_identifier(input: &str) -> bool {
 	english_gate(input, EnglishGateKind::Identifier).is_ok()
 }
 
+/// Locates the first character that fails the character-level checks of the English gate.
+///
+/// Returns the reason with the byte range of that character in `input`. Language identification
+/// judges the whole input, so it never yields a span.
+pub fn find_rejected_char(input: &str) -> Option<(EnglishGateRejectReason, Range<usize>)> {
+	for (start, ch) in input.char_indices() {
+		let normalized: String = ch.nfkc().collect();
+		let reason = if contains_disallowed_controls(normalized.as_str()) {
+			EnglishGateRejectReason::DisallowedControlChar
+		} else if contains_disallowed_zero_width(normalized.as_str()) {
+			EnglishGateRejectReason::DisallowedZeroWidthChar
+		} else if contains_disallowed_scripts(normalized.as_str()) {
+			EnglishGateRejectReason::DisallowedScript
+		} else {
+			continue;
+		};
+
+		return Some((reason, start..start + ch.len_utf8()));
+	}
+
+	None
+}
+
 fn contains_disallowed_controls(input: &str) -> bool {
 	for ch in input.chars() {
 		if !ch.is_control() {
@@ -162,7 +198,7 @@ fn is_confidently_non_english(input: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
-	use crate::english_gate::{self, EnglishGateKind};
+	use crate::english_gate::{self, EnglishGateKind, EnglishGateRejectReason};
 
 	#[test]
 	fn accepts_basic_english() {
@@ -209,6 +245,16 @@ mod tests {
 		assert!(english_gate::english_gate(long_french, EnglishGateKind::NaturalLanguage).is_err());
 	}
 
+	#[test]
+	fn rejected_char_span_points_at_the_first_offending_char() {
+		let (reason, span) =
+			english_gate::find_rejected_char("Use 日本 here.").expect("Expected a rejected char.");
+
+		assert_eq!(reason, EnglishGateRejectReason::DisallowedScript);
+		assert_eq!(span, 4..7);
+		assert_eq!(english_gate::find_rejected_char("Ｆｕｌｌｗｉｄｔｈ latin"), None);
+	}
+
 	#[test]
 	fn code_like_text_is_not_rejected_by_lid_thresholds() {
 		let codeish = "Error: expected `foo::bar()`; got `foo::baz()` at line 12.";
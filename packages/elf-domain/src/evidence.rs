@@ -8,3 +8,79 @@ pub fn evidence_matches(messages: &[String], index: usize, quote: &str) -> bool
 
 	messages.get(index).map(|msg| msg.contains(quote)).unwrap_or(false)
 }
+
+/// Minimum share of a claim's terms that one quote must contain for the claim to count as backed.
+pub const CLAIM_ALIGNMENT_MIN_RATIO: f32 = 0.6;
+
+/// Share of a note's claims that are backed by evidence quotes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EvidenceCoverage {
+	/// Number of sentence-level claims found in the note text.
+	pub claim_count: u32,
+	/// Number of claims aligned with at least one quote.
+	pub backed_claim_count: u32,
+	/// `backed_claim_count / claim_count`, in the range 0.0-1.0.
+	pub coverage: f32,
+}
+
+/// Splits note text into sentence-level claims, dropping fragments without any terms.
+pub fn split_claims(text: &str) -> Vec<&str> {
+	text.split(['.', '!', '?', ';', '\n'])
+		.map(str::trim)
+		.filter(|claim| !claim_terms(claim).is_empty())
+		.collect()
+}
+
+/// Returns whether `quote` backs `claim`: the claim appears verbatim (case-insensitive) in the
+/// quote, or the quote contains at least [`CLAIM_ALIGNMENT_MIN_RATIO`] of the claim's terms.
+pub fn claim_backed_by(claim: &str, quote: &str) -> bool {
+	let claim_terms = claim_terms(claim);
+
+	if claim_terms.is_empty() {
+		return false;
+	}
+	if quote.to_lowercase().contains(claim.to_lowercase().as_str()) {
+		return true;
+	}
+
+	let quote_terms = claim_terms_of(quote);
+	let matched = claim_terms.iter().filter(|term| quote_terms.contains(term)).count();
+
+	matched as f32 / claim_terms.len() as f32 >= CLAIM_ALIGNMENT_MIN_RATIO
+}
+
+/// Computes sentence-aligned evidence coverage for a note, or `None` when the text has no claims.
+pub fn evidence_coverage(text: &str, quotes: &[&str]) -> Option<EvidenceCoverage> {
+	let claims = split_claims(text);
+
+	if claims.is_empty() {
+		return None;
+	}
+
+	let backed = claims
+		.iter()
+		.filter(|claim| quotes.iter().any(|quote| claim_backed_by(claim, quote)))
+		.count();
+
+	Some(EvidenceCoverage {
+		claim_count: claims.len() as u32,
+		backed_claim_count: backed as u32,
+		coverage: backed as f32 / claims.len() as f32,
+	})
+}
+
+fn claim_terms(text: &str) -> Vec<String> {
+	let mut terms = claim_terms_of(text);
+
+	terms.sort();
+	terms.dedup();
+
+	terms
+}
+
+fn claim_terms_of(text: &str) -> Vec<String> {
+	text.split(|ch: char| !ch.is_alphanumeric())
+		.filter(|term| term.chars().count() >= 2)
+		.map(str::to_lowercase)
+		.collect()
+}
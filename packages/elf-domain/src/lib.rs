@@ -1,9 +1,12 @@
 //! Domain-level validation and policy helpers shared across ELF services.
 
 pub mod consolidation;
+pub mod egress;
 pub mod english_gate;
 pub mod evidence;
 pub mod knowledge;
 pub mod memory_policy;
+pub mod normalization;
+pub mod note_merge;
 pub mod ttl;
 pub mod writegate;
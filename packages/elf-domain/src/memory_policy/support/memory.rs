@@ -8,6 +8,8 @@ pub(crate) fn test_memory_config() -> Memory {
 		update_sim_threshold: 0.85,
 		candidate_k: 60,
 		top_k: 12,
+		version_coalesce_window_ms: None,
+		write_anomaly: None,
 		policy: MemoryPolicy {
 			rules: vec![
 				MemoryPolicyRule {
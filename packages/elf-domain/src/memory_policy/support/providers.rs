@@ -3,6 +3,9 @@ use elf_config::{EmbeddingProviderConfig, LlmProviderConfig, ProviderConfig, Pro
 pub(crate) fn test_providers_config() -> Providers {
 	Providers {
 		embedding: test_embedding_provider_config(),
+		embeddings: None,
+		embedding_scopes: None,
+		embedding_types: None,
 		rerank: test_rerank_provider_config(),
 		llm_extractor: test_llm_extractor_provider_config(),
 	}
@@ -18,6 +21,10 @@ fn test_embedding_provider_config() -> EmbeddingProviderConfig {
 		dimensions: 3,
 		timeout_ms: 1_000,
 		default_headers: Default::default(),
+		query_input: None,
+		model_dir: None,
+		resilience: None,
+		cache: None,
 	}
 }
 
@@ -30,6 +37,8 @@ fn test_rerank_provider_config() -> ProviderConfig {
 		model: "m".to_string(),
 		timeout_ms: 1_000,
 		default_headers: Default::default(),
+		resilience: None,
+		max_docs_per_call: None,
 	}
 }
 
@@ -43,5 +52,6 @@ fn test_llm_extractor_provider_config() -> LlmProviderConfig {
 		temperature: 0.1,
 		timeout_ms: 1_000,
 		default_headers: Default::default(),
+		resilience: None,
 	}
 }
@@ -24,6 +24,7 @@ pub(crate) fn test_ranking_config() -> Ranking {
 			sim_threshold: 0.88,
 			mmr_lambda: 0.7,
 			max_skips: 64,
+			scope_balance: None,
 		},
 		retrieval_sources: RankingRetrievalSources {
 			fusion_weight: 1.0,
@@ -51,5 +52,8 @@ fn test_ranking_deterministic_config() -> RankingDeterministic {
 			last_hit_tau_days: 14.0,
 		},
 		decay: RankingDeterministicDecay { enabled: false, weight: 0.05, tau_days: 30.0 },
+		evidence: None,
+		session: None,
+		feedback: None,
 	}
 }
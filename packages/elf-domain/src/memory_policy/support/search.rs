@@ -17,6 +17,7 @@ pub(crate) fn test_search_config() -> Search {
 			expansion_ttl_days: 7,
 			rerank_ttl_days: 7,
 			max_payload_bytes: Some(262_144),
+			results: None,
 		},
 		explain: SearchExplain {
 			retention_days: 7,
@@ -30,11 +31,15 @@ pub(crate) fn test_search_config() -> Search {
 			max_children_per_node: 4,
 			max_nodes_per_scope: 32,
 			max_total_nodes: 256,
+			max_elapsed_ms: None,
 		},
 		graph_context: SearchGraphContext {
 			enabled: false,
 			max_facts_per_item: 16,
 			max_evidence_notes_per_fact: 16,
 		},
+		snippet: None,
+		answer: None,
+		degraded: None,
 	}
 }
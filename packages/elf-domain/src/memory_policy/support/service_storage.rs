@@ -15,6 +15,13 @@ pub(crate) fn test_default_config() -> Config {
 		chunking: test_chunking_config(),
 		context: None,
 		mcp: None,
+		shadow: None,
+		embedding_projection: None,
+		url_snapshots: None,
+		warmup: None,
+		qdrant_maintenance: None,
+		reembed: None,
+		worker: None,
 	}
 }
 
@@ -24,6 +31,8 @@ fn test_service_config() -> Service {
 		mcp_bind: "127.0.0.1:8082".to_string(),
 		admin_bind: "127.0.0.1:8081".to_string(),
 		log_level: "info".to_string(),
+		mode: None,
+		otel: None,
 	}
 }
 
@@ -48,5 +57,6 @@ fn test_chunking_config() -> Chunking {
 		max_tokens: 512,
 		overlap_tokens: 128,
 		tokenizer_repo: "REPLACE_ME".to_string(),
+		per_type: None,
 	}
 }
@@ -0,0 +1,402 @@
+//! Locale-aware normalization of dates, quantities, and currency amounts found in note text.
+
+use std::{ops::Range, sync::LazyLock};
+
+use regex::{Captures, Regex};
+use time::{Date, Month};
+
+const NUMBER: &str = r"(?:\d{1,3}(?:[,.]\d{3})+|\d+)(?:[.,]\d+)?";
+const MONTHS: &str = "(?i:jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sep(?:t(?:ember)?)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)";
+const CURRENCY_CODES: &str = "USD|EUR|GBP|JPY|CNY|CHF|CAD|AUD";
+const MONTH_FIRST_REGIONS: [&str; 5] = ["US", "PH", "FM", "MH", "PW"];
+const DECIMAL_COMMA_LANGUAGES: [&str; 28] = [
+	"bg", "cs", "da", "de", "el", "es", "et", "fi", "fr", "hr", "hu", "id", "it", "lt", "lv", "nb",
+	"nl", "nn", "no", "pl", "pt", "ro", "ru", "sk", "sl", "sr", "sv", "tr",
+];
+// (surface, SI unit, scale, offset); the canonical value is `amount * scale + offset`.
+const UNITS: [(&str, &str, f64, f64); 48] = [
+	("km", "m", 1_000.0, 0.0),
+	("kilometers", "m", 1_000.0, 0.0),
+	("kilometres", "m", 1_000.0, 0.0),
+	("m", "m", 1.0, 0.0),
+	("meters", "m", 1.0, 0.0),
+	("metres", "m", 1.0, 0.0),
+	("cm", "m", 0.01, 0.0),
+	("mm", "m", 0.001, 0.0),
+	("mi", "m", 1_609.344, 0.0),
+	("miles", "m", 1_609.344, 0.0),
+	("ft", "m", 0.3048, 0.0),
+	("feet", "m", 0.3048, 0.0),
+	("inches", "m", 0.0254, 0.0),
+	("kg", "kg", 1.0, 0.0),
+	("kilograms", "kg", 1.0, 0.0),
+	("g", "kg", 0.001, 0.0),
+	("grams", "kg", 0.001, 0.0),
+	("mg", "kg", 0.000_001, 0.0),
+	("lb", "kg", 0.453_592_37, 0.0),
+	("lbs", "kg", 0.453_592_37, 0.0),
+	("oz", "kg", 0.028_349_523_125, 0.0),
+	("ms", "s", 0.001, 0.0),
+	("s", "s", 1.0, 0.0),
+	("sec", "s", 1.0, 0.0),
+	("seconds", "s", 1.0, 0.0),
+	("min", "s", 60.0, 0.0),
+	("mins", "s", 60.0, 0.0),
+	("minutes", "s", 60.0, 0.0),
+	("h", "s", 3_600.0, 0.0),
+	("hr", "s", 3_600.0, 0.0),
+	("hrs", "s", 3_600.0, 0.0),
+	("hours", "s", 3_600.0, 0.0),
+	("days", "s", 86_400.0, 0.0),
+	("weeks", "s", 604_800.0, 0.0),
+	("B", "B", 1.0, 0.0),
+	("bytes", "B", 1.0, 0.0),
+	("KB", "B", 1e3, 0.0),
+	("MB", "B", 1e6, 0.0),
+	("GB", "B", 1e9, 0.0),
+	("TB", "B", 1e12, 0.0),
+	("KiB", "B", 1_024.0, 0.0),
+	("MiB", "B", 1_048_576.0, 0.0),
+	("GiB", "B", 1_073_741_824.0, 0.0),
+	("TiB", "B", 1_099_511_627_776.0, 0.0),
+	("°C", "K", 1.0, 273.15),
+	("°F", "K", 5.0 / 9.0, 255.372_222_222_222_2),
+	("K", "K", 1.0, 0.0),
+	("kelvin", "K", 1.0, 0.0),
+];
+
+static ISO_DATE: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"\b(?P<year>\d{4})[-/](?P<month>\d{1,2})[-/](?P<day>\d{1,2})\b")
+		.expect("ISO date pattern must compile.")
+});
+static NUMERIC_DATE: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"\b(?P<first>\d{1,2})[/.](?P<second>\d{1,2})[/.](?P<year>\d{4})\b")
+		.expect("Numeric date pattern must compile.")
+});
+static MONTH_DAY_DATE: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"\b(?P<month>{MONTHS})\.?\s+(?P<day>\d{{1,2}})(?:st|nd|rd|th)?,?\s+(?P<year>\d{{4}})\b"
+	))
+	.expect("Month-day date pattern must compile.")
+});
+static DAY_MONTH_DATE: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"\b(?P<day>\d{{1,2}})(?:st|nd|rd|th)?\s+(?P<month>{MONTHS})\.?,?\s+(?P<year>\d{{4}})\b"
+	))
+	.expect("Day-month date pattern must compile.")
+});
+static SYMBOL_AMOUNT: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"(?P<symbol>[$€£¥])\s?(?P<number>{NUMBER})(?P<scale>[kKMB]\b|bn\b|\s(?:thousand|million|billion)\b)?"
+	))
+	.expect("Currency symbol pattern must compile.")
+});
+static CODE_AMOUNT: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"\b(?:(?P<prefix>{CURRENCY_CODES})\s?(?P<prefix_number>{NUMBER})|(?P<suffix_number>{NUMBER})\s?(?P<suffix>{CURRENCY_CODES}))\b"
+	))
+	.expect("Currency code pattern must compile.")
+});
+static QUANTITY: LazyLock<Regex> = LazyLock::new(|| {
+	let mut surfaces: Vec<&str> = UNITS.iter().map(|(surface, ..)| *surface).collect();
+
+	surfaces.sort_by_key(|surface| std::cmp::Reverse(surface.len()));
+
+	let units = surfaces.into_iter().map(regex::escape).collect::<Vec<_>>().join("|");
+
+	Regex::new(&format!(r"\b(?P<number>{NUMBER})\s?(?P<unit>{units})\b"))
+		.expect("Quantity pattern must compile.")
+});
+
+/// Order used to read all-numeric dates such as `03/04/2026`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DateOrder {
+	/// Ambiguous numeric dates are skipped; only dates with a day above 12 are read.
+	#[default]
+	Unknown,
+	/// Month before day, as in `en-US`.
+	MonthFirst,
+	/// Day before month, as in `en-GB` or `de-DE`.
+	DayFirst,
+}
+
+/// Reading conventions applied to dates and numbers in one request.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct NormalizationLocale {
+	/// How all-numeric dates are read.
+	pub date_order: DateOrder,
+	/// Whether `,` is the decimal separator and `.` groups thousands.
+	pub decimal_comma: bool,
+}
+impl NormalizationLocale {
+	/// Parses a BCP 47 language tag such as `en-US` or `de`, returning `None` when malformed.
+	pub fn from_tag(tag: &str) -> Option<Self> {
+		let mut parts = tag.trim().split(['-', '_']);
+		let language = parts.next()?.to_ascii_lowercase();
+		let region = match parts.next() {
+			Some(script) if script.len() == 4 => parts.next(),
+			part => part,
+		}
+		.map(str::to_ascii_uppercase);
+
+		if !(2..=3).contains(&language.len()) || !language.chars().all(|ch| ch.is_ascii_lowercase())
+		{
+			return None;
+		}
+		if let Some(region) = region.as_deref()
+			&& !matches!(region.len(), 2 | 3)
+		{
+			return None;
+		}
+
+		let date_order = match region.as_deref() {
+			Some(region) if MONTH_FIRST_REGIONS.contains(&region) => DateOrder::MonthFirst,
+			Some(_) => DateOrder::DayFirst,
+			None if language == "en" => DateOrder::Unknown,
+			None => DateOrder::DayFirst,
+		};
+
+		Some(Self {
+			date_order,
+			decimal_comma: DECIMAL_COMMA_LANGUAGES.contains(&language.as_str()),
+		})
+	}
+}
+
+/// Category of a normalized value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NormalizedValueKind {
+	/// Calendar date, normalized to ISO 8601.
+	Date,
+	/// Physical quantity, normalized to an SI unit (bytes for data sizes).
+	Quantity,
+	/// Currency amount, normalized to an ISO 4217 code.
+	Currency,
+}
+impl NormalizedValueKind {
+	/// Returns the storage label for this kind.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Date => "date",
+			Self::Quantity => "quantity",
+			Self::Currency => "currency",
+		}
+	}
+}
+
+/// One date, quantity, or currency mention with its canonical form.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NormalizedValue {
+	/// Value category.
+	pub kind: NormalizedValueKind,
+	/// Mention as written in the source text.
+	pub raw: String,
+	/// Canonical rendering, such as `2026-03-14`, `1500 m`, or `1200 USD`.
+	pub normalized: String,
+	/// Canonical magnitude for quantities and currency amounts.
+	pub number: Option<f64>,
+	/// SI unit symbol or ISO 4217 code.
+	pub unit: Option<String>,
+	/// Calendar date for date mentions.
+	pub date: Option<Date>,
+}
+
+/// Extracts normalized dates, quantities, and currency amounts from `text` in source order.
+///
+/// Overlapping mentions resolve in favor of dates, then currency amounts, then quantities.
+pub fn normalize_values(text: &str, locale: NormalizationLocale) -> Vec<NormalizedValue> {
+	let mut accepted: Vec<(Range<usize>, NormalizedValue)> = Vec::new();
+	let candidates = date_candidates(text, locale)
+		.chain(currency_candidates(text, locale))
+		.chain(quantity_candidates(text, locale));
+
+	for (span, value) in candidates {
+		if accepted.iter().all(|(taken, _)| span.end <= taken.start || span.start >= taken.end) {
+			accepted.push((span, value));
+		}
+	}
+
+	accepted.sort_by_key(|(span, _)| span.start);
+
+	accepted.into_iter().map(|(_, value)| value).collect()
+}
+
+/// Parses a number written with the locale's separators.
+pub fn parse_number(raw: &str, locale: NormalizationLocale) -> Option<f64> {
+	let (group, decimal) = if locale.decimal_comma { ('.', ',') } else { (',', '.') };
+	let cleaned: String = raw
+		.chars()
+		.filter(|ch| *ch != group)
+		.map(|ch| if ch == decimal { '.' } else { ch })
+		.collect();
+
+	cleaned.parse().ok().filter(|value: &f64| value.is_finite())
+}
+
+fn date_candidates(
+	text: &str,
+	locale: NormalizationLocale,
+) -> impl Iterator<Item = (Range<usize>, NormalizedValue)> {
+	let iso = ISO_DATE.captures_iter(text).filter_map(|caps| {
+		date_value(&caps, number_group(&caps, "year")?, number_group(&caps, "month")?, &caps["day"])
+	});
+	let numeric = NUMERIC_DATE.captures_iter(text).filter_map(move |caps| {
+		let first = number_group(&caps, "first")?;
+		let second = number_group(&caps, "second")?;
+		let (month, day) = match locale.date_order {
+			_ if first > 12 => (second, first),
+			_ if second > 12 => (first, second),
+			DateOrder::MonthFirst => (first, second),
+			DateOrder::DayFirst => (second, first),
+			DateOrder::Unknown => return None,
+		};
+
+		date_value(&caps, number_group(&caps, "year")?, month, &day.to_string())
+	});
+	let named = MONTH_DAY_DATE
+		.captures_iter(text)
+		.chain(DAY_MONTH_DATE.captures_iter(text))
+		.filter_map(|caps| {
+			date_value(
+				&caps,
+				number_group(&caps, "year")?,
+				month_number(&caps["month"])?,
+				&caps["day"],
+			)
+		});
+
+	iso.chain(numeric).chain(named).collect::<Vec<_>>().into_iter()
+}
+
+fn currency_candidates(
+	text: &str,
+	locale: NormalizationLocale,
+) -> impl Iterator<Item = (Range<usize>, NormalizedValue)> {
+	let symbols = SYMBOL_AMOUNT.captures_iter(text).filter_map(move |caps| {
+		let code = match &caps["symbol"] {
+			"$" => "USD",
+			"€" => "EUR",
+			"£" => "GBP",
+			_ => "JPY",
+		};
+		let scale = match caps.name("scale").map(|scale| scale.as_str().trim()) {
+			Some("k" | "K" | "thousand") => 1e3,
+			Some("M" | "million") => 1e6,
+			Some("B" | "bn" | "billion") => 1e9,
+			_ => 1.0,
+		};
+		let amount = parse_number(&caps["number"], locale)? * scale;
+
+		Some(scaled_value(&caps, NormalizedValueKind::Currency, amount, code))
+	});
+	let codes = CODE_AMOUNT.captures_iter(text).filter_map(move |caps| {
+		let (number, code) = match (caps.name("prefix"), caps.name("suffix")) {
+			(Some(code), _) => (caps.name("prefix_number")?, code),
+			(_, Some(code)) => (caps.name("suffix_number")?, code),
+			_ => return None,
+		};
+		let amount = parse_number(number.as_str(), locale)?;
+
+		Some(scaled_value(&caps, NormalizedValueKind::Currency, amount, code.as_str()))
+	});
+
+	symbols.chain(codes).collect::<Vec<_>>().into_iter()
+}
+
+fn quantity_candidates(
+	text: &str,
+	locale: NormalizationLocale,
+) -> impl Iterator<Item = (Range<usize>, NormalizedValue)> {
+	QUANTITY
+		.captures_iter(text)
+		.filter_map(move |caps| {
+			let (_, si_unit, scale, offset) =
+				UNITS.iter().find(|(surface, ..)| *surface == &caps["unit"])?;
+			let amount = parse_number(&caps["number"], locale)? * scale + offset;
+
+			Some(scaled_value(&caps, NormalizedValueKind::Quantity, amount, si_unit))
+		})
+		.collect::<Vec<_>>()
+		.into_iter()
+}
+
+fn date_value(
+	caps: &Captures<'_>,
+	year: u32,
+	month: u32,
+	day: &str,
+) -> Option<(Range<usize>, NormalizedValue)> {
+	let whole = caps.get(0)?;
+	let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+	let date =
+		Date::from_calendar_date(i32::try_from(year).ok()?, month, day.parse().ok()?).ok()?;
+
+	Some((
+		whole.range(),
+		NormalizedValue {
+			kind: NormalizedValueKind::Date,
+			raw: whole.as_str().to_string(),
+			normalized: format!(
+				"{:04}-{:02}-{:02}",
+				date.year(),
+				u8::from(date.month()),
+				date.day()
+			),
+			number: None,
+			unit: None,
+			date: Some(date),
+		},
+	))
+}
+
+fn scaled_value(
+	caps: &Captures<'_>,
+	kind: NormalizedValueKind,
+	amount: f64,
+	unit: &str,
+) -> (Range<usize>, NormalizedValue) {
+	let whole = caps.get(0).expect("Capture group 0 is always present.");
+
+	(
+		whole.range(),
+		NormalizedValue {
+			kind,
+			raw: whole.as_str().trim().to_string(),
+			normalized: format!("{} {unit}", format_number(amount)),
+			number: Some(amount),
+			unit: Some(unit.to_string()),
+			date: None,
+		},
+	)
+}
+
+fn number_group(caps: &Captures<'_>, name: &str) -> Option<u32> {
+	caps.name(name)?.as_str().parse().ok()
+}
+
+fn month_number(raw: &str) -> Option<u32> {
+	let prefix = raw.get(..3)?.to_ascii_lowercase();
+	let month = match prefix.as_str() {
+		"jan" => 1,
+		"feb" => 2,
+		"mar" => 3,
+		"apr" => 4,
+		"may" => 5,
+		"jun" => 6,
+		"jul" => 7,
+		"aug" => 8,
+		"sep" => 9,
+		"oct" => 10,
+		"nov" => 11,
+		"dec" => 12,
+		_ => return None,
+	};
+
+	Some(month)
+}
+
+fn format_number(value: f64) -> String {
+	let rendered = format!("{value:.6}");
+
+	rendered.trim_end_matches('0').trim_end_matches('.').to_string()
+}
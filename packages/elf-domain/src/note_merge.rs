@@ -0,0 +1,102 @@
+//! Note merge helpers shared by manual merges and automatic consolidation.
+
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+const MERGED_NOTE_IDS_KEY: &str = "merged_note_ids";
+const MERGED_SOURCE_REFS_KEY: &str = "merged_source_refs";
+const MERGED_INTO_KEY: &str = "merged_into";
+
+/// Builds the extractor prompt that rewrites two notes about the same subject as one note.
+pub fn build_merge_messages(primary: &str, secondary: &str) -> Vec<Value> {
+	let schema = serde_json::json!({ "text": "string" });
+	let schema_text = serde_json::to_string_pretty(&schema)
+		.unwrap_or_else(|_| "{\"text\": \"string\"}".to_string());
+	let system_prompt = "You are a memory merge engine for an agent memory system. \
+Output must be valid JSON only and must match the provided schema exactly. \
+Combine two notes about the same subject into one concise English note. \
+Keep every distinct fact from both notes, drop duplicated statements, and do not invent details. \
+Do not include any non-English text. Do not add explanations or extra fields.";
+	let user_prompt = format!(
+		"Return JSON matching this exact schema:\n{schema_text}\nPrimary note:\n{primary}\nSecondary note:\n{secondary}"
+	);
+
+	vec![
+		serde_json::json!({ "role": "system", "content": system_prompt }),
+		serde_json::json!({ "role": "user", "content": user_prompt }),
+	]
+}
+
+/// Returns the trimmed `text` field of a merge extractor response, or `None` when it is missing
+/// or blank.
+pub fn merged_text(raw: &Value) -> Option<String> {
+	let text = raw.get("text").and_then(Value::as_str).map(str::trim).unwrap_or_default();
+
+	(!text.is_empty()).then(|| text.to_string())
+}
+
+/// Unions the secondary source reference into the primary one.
+///
+/// The primary keys are kept as-is. The secondary reference, and any references it had already
+/// absorbed, are appended to `merged_source_refs`, and merged note ids accumulate in
+/// `merged_note_ids` so repeated merges stay flat.
+pub fn merge_source_refs(primary: &Value, secondary: &Value, secondary_note_id: Uuid) -> Value {
+	let (mut merged, mut refs, mut note_ids) = split_merge_lineage(primary);
+	let (secondary_base, secondary_refs, secondary_note_ids) = split_merge_lineage(secondary);
+	let primary_base = Value::Object(merged.clone());
+
+	for source_ref in std::iter::once(Value::Object(secondary_base)).chain(secondary_refs) {
+		let empty = source_ref.as_object().is_some_and(Map::is_empty);
+
+		if !empty && source_ref != primary_base && !refs.contains(&source_ref) {
+			refs.push(source_ref);
+		}
+	}
+	for note_id in secondary_note_ids
+		.into_iter()
+		.chain(std::iter::once(Value::from(secondary_note_id.to_string())))
+	{
+		if !note_ids.contains(&note_id) {
+			note_ids.push(note_id);
+		}
+	}
+
+	merged.insert(MERGED_SOURCE_REFS_KEY.to_string(), Value::Array(refs));
+	merged.insert(MERGED_NOTE_IDS_KEY.to_string(), Value::Array(note_ids));
+
+	Value::Object(merged)
+}
+
+/// Marks the secondary source reference with the note it was merged into.
+pub fn mark_merged_into(source_ref: &Value, primary_note_id: Uuid) -> Value {
+	let mut marked = source_ref_object(source_ref);
+
+	marked.insert(MERGED_INTO_KEY.to_string(), Value::from(primary_note_id.to_string()));
+
+	Value::Object(marked)
+}
+
+fn split_merge_lineage(source_ref: &Value) -> (Map<String, Value>, Vec<Value>, Vec<Value>) {
+	let mut base = source_ref_object(source_ref);
+	let refs = take_array(&mut base, MERGED_SOURCE_REFS_KEY);
+	let note_ids = take_array(&mut base, MERGED_NOTE_IDS_KEY);
+
+	base.remove(MERGED_INTO_KEY);
+
+	(base, refs, note_ids)
+}
+
+fn source_ref_object(source_ref: &Value) -> Map<String, Value> {
+	match source_ref {
+		Value::Object(map) => map.clone(),
+		Value::Null => Map::new(),
+		other => Map::from_iter([("source_ref".to_string(), other.clone())]),
+	}
+}
+
+fn take_array(map: &mut Map<String, Value>, key: &str) -> Vec<Value> {
+	match map.remove(key) {
+		Some(Value::Array(items)) => items,
+		_ => Vec::new(),
+	}
+}
@@ -1,16 +1,21 @@
 //! Writegate validation and redaction helpers.
 
+mod feedback;
+mod pii;
 mod policy;
 mod secrets;
 mod types;
 mod validation;
 
 pub use self::{
+	feedback::reject_feedback,
+	pii::{find_pii, find_rejected_pii, redact_pii},
 	policy::apply_write_policy,
-	secrets::contains_secrets,
+	secrets::{contains_secrets, find_configured_secret, find_secret},
 	types::{
-		NoteInput, RejectCode, WritePolicy, WritePolicyAudit, WritePolicyError, WritePolicyResult,
-		WriteRedaction, WriteRedactionResult, WriteSpan,
+		NoteInput, PiiCategory, PiiMatch, PiiRedaction, PiiRedactionResult, RejectCode,
+		RejectDetail, RejectFeedback, RejectFix, SecretMatch, WritePolicy, WritePolicyAudit,
+		WritePolicyError, WritePolicyResult, WriteRedaction, WriteRedactionResult, WriteSpan,
 	},
 	validation::writegate,
 };
@@ -19,6 +24,6 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::english_gate;
-use elf_config::Config;
+use elf_config::{Config, SecurityPii, SecurityPiiAction, SecuritySecretPatterns};
 
 #[cfg(test)] mod tests;
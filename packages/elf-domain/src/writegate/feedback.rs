@@ -0,0 +1,72 @@
+use crate::writegate::{
+	Config, NoteInput, RejectCode, RejectDetail, RejectFeedback, RejectFix, WriteSpan,
+	english_gate, pii, secrets,
+};
+
+/// Builds remediation data for a write-gate rejection of `note`.
+///
+/// Returns `None` when the rejection is not fixed by editing the text (empty text, note type, or
+/// scope).
+pub fn reject_feedback(note: &NoteInput, cfg: &Config, code: RejectCode) -> Option<RejectFeedback> {
+	let text = note.text.as_str();
+
+	match code {
+		RejectCode::RejectTooLong => {
+			let max_chars = cfg.memory.max_note_chars;
+			let (start, _) = text.char_indices().nth(max_chars as usize)?;
+
+			Some(RejectFeedback {
+				suggested_fix: RejectFix::Truncate,
+				detail: RejectDetail::TooLong {
+					max_chars,
+					char_count: text.chars().count() as u32,
+					overflow: WriteSpan { start, end: text.len() },
+				},
+			})
+		},
+		RejectCode::RejectNonEnglish => {
+			let feedback = match english_gate::find_rejected_char(text) {
+				Some((reason, span)) => RejectFeedback {
+					suggested_fix: RejectFix::ReplaceSpan,
+					detail: RejectDetail::NonEnglish {
+						reason: reason.as_str().to_string(),
+						span: Some(WriteSpan { start: span.start, end: span.end }),
+					},
+				},
+				None => RejectFeedback {
+					suggested_fix: RejectFix::RewriteInEnglish,
+					detail: RejectDetail::NonEnglish {
+						reason: english_gate::EnglishGateRejectReason::LanguageIdNonEnglish
+							.as_str()
+							.to_string(),
+						span: None,
+					},
+				},
+			};
+
+			Some(feedback)
+		},
+		RejectCode::RejectSecret => {
+			let found =
+				secrets::find_configured_secret(text, cfg.security.secret_patterns.as_ref())?;
+
+			Some(RejectFeedback {
+				suggested_fix: RejectFix::RedactSpan,
+				detail: RejectDetail::Secret {
+					pattern_class: found.pattern_class,
+					span: found.span,
+				},
+			})
+		},
+		RejectCode::RejectPii => {
+			let found = pii::find_rejected_pii(text, cfg.security.pii.as_ref())?;
+
+			Some(RejectFeedback {
+				suggested_fix: RejectFix::RedactSpan,
+				detail: RejectDetail::Pii { category: found.category, span: found.span },
+			})
+		},
+		RejectCode::RejectInvalidType | RejectCode::RejectScopeDenied | RejectCode::RejectEmpty =>
+			None,
+	}
+}
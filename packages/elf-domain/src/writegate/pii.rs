@@ -0,0 +1,155 @@
+use std::sync::LazyLock;
+
+use crate::writegate::{
+	PiiCategory, PiiMatch, PiiRedaction, PiiRedactionResult, Regex, SecurityPii, SecurityPiiAction,
+	WriteSpan,
+};
+
+static CREDIT_CARD: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"\b\d(?:[ -]?\d){12,18}\b").expect("Credit card pattern must compile.")
+});
+static NATIONAL_ID: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").expect("National ID pattern must compile.")
+});
+static EMAIL: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}\b")
+		.expect("Email pattern must compile.")
+});
+static PHONE: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"(?:\+\d{1,3}[ .-]?)?(?:\(\d{2,4}\)[ .-]?|\b\d{2,4}[ .-])\d{3,4}[ .-]\d{4}\b")
+		.expect("Phone pattern must compile.")
+});
+
+/// Returns every PII match in the input, ordered by position.
+///
+/// Categories are matched in priority order (credit card, national ID, email, phone), and a
+/// match that overlaps an earlier category's match is dropped, so a card number is never also
+/// reported as a phone number.
+pub fn find_pii(text: &str) -> Vec<PiiMatch> {
+	let mut found: Vec<PiiMatch> = Vec::new();
+
+	for category in PiiCategory::ALL {
+		for m in pattern(category).find_iter(text) {
+			let valid = match category {
+				PiiCategory::CreditCard => passes_luhn(m.as_str()),
+				PiiCategory::NationalId => is_plausible_ssn(m.as_str()),
+				PiiCategory::Phone => is_standalone_number(text, m.start(), m.end()),
+				PiiCategory::Email => true,
+			};
+			let span = WriteSpan { start: m.start(), end: m.end() };
+
+			if !valid
+				|| found.iter().any(|existing| {
+					span.start < existing.span.end && existing.span.start < span.end
+				}) {
+				continue;
+			}
+
+			found.push(PiiMatch { category, span });
+		}
+	}
+
+	found.sort_by_key(|m| m.span.start);
+
+	found
+}
+
+/// Returns the earliest PII match whose category the policy rejects, if any.
+pub fn find_rejected_pii(text: &str, policy: Option<&SecurityPii>) -> Option<PiiMatch> {
+	let policy = policy?;
+
+	find_pii(text).into_iter().find(|m| action_for(policy, m.category) == SecurityPiiAction::Reject)
+}
+
+/// Replaces every PII match whose category the policy redacts with a category placeholder.
+///
+/// `field` is the JSON path recorded on each redaction. Redaction spans refer to the input text,
+/// not the transformed output.
+pub fn redact_pii(field: &str, text: &str, policy: Option<&SecurityPii>) -> PiiRedactionResult {
+	let redactions: Vec<PiiRedaction> = match policy {
+		Some(policy) => find_pii(text)
+			.into_iter()
+			.filter(|m| action_for(policy, m.category) == SecurityPiiAction::Redact)
+			.map(|m| PiiRedaction {
+				field: field.to_string(),
+				category: m.category,
+				span: m.span,
+				replacement: m.category.placeholder().to_string(),
+			})
+			.collect(),
+		None => Vec::new(),
+	};
+	let mut transformed = text.to_string();
+
+	for redaction in redactions.iter().rev() {
+		transformed.replace_range(
+			redaction.span.start..redaction.span.end,
+			redaction.replacement.as_str(),
+		);
+	}
+
+	PiiRedactionResult { transformed, redactions }
+}
+
+fn pattern(category: PiiCategory) -> &'static Regex {
+	match category {
+		PiiCategory::Email => &EMAIL,
+		PiiCategory::Phone => &PHONE,
+		PiiCategory::CreditCard => &CREDIT_CARD,
+		PiiCategory::NationalId => &NATIONAL_ID,
+	}
+}
+
+fn action_for(policy: &SecurityPii, category: PiiCategory) -> SecurityPiiAction {
+	match category {
+		PiiCategory::Email => policy.email,
+		PiiCategory::Phone => policy.phone,
+		PiiCategory::CreditCard => policy.credit_card,
+		PiiCategory::NationalId => policy.national_id,
+	}
+}
+
+fn passes_luhn(raw: &str) -> bool {
+	let digits: Vec<u32> = raw.chars().filter_map(|ch| ch.to_digit(10)).collect();
+
+	if !(13..=19).contains(&digits.len()) {
+		return false;
+	}
+
+	let sum: u32 = digits
+		.iter()
+		.rev()
+		.enumerate()
+		.map(|(idx, digit)| {
+			if idx % 2 == 1 {
+				let doubled = digit * 2;
+
+				if doubled > 9 { doubled - 9 } else { doubled }
+			} else {
+				*digit
+			}
+		})
+		.sum();
+
+	sum.is_multiple_of(10)
+}
+
+/// Rejects phone-shaped matches that are only part of a longer digit group, such as the tail of
+/// a card-like number.
+fn is_standalone_number(text: &str, start: usize, end: usize) -> bool {
+	let before = text[..start].trim_end_matches([' ', '-', '.']);
+	let after = text[end..].trim_start_matches([' ', '-', '.']);
+
+	!before.ends_with(|ch: char| ch.is_ascii_digit())
+		&& !after.starts_with(|ch: char| ch.is_ascii_digit())
+}
+
+/// Rejects SSN shapes that are never issued: area 000, 666, or 9xx, group 00, or serial 0000.
+fn is_plausible_ssn(raw: &str) -> bool {
+	let mut parts = raw.split('-');
+	let (Some(area), Some(group), Some(serial)) = (parts.next(), parts.next(), parts.next()) else {
+		return false;
+	};
+
+	area != "000" && area != "666" && !area.starts_with('9') && group != "00" && serial != "0000"
+}
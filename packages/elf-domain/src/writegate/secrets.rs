@@ -1,23 +1,94 @@
-use crate::writegate::Regex;
+use std::collections::HashMap;
+
+use crate::writegate::{Regex, SecretMatch, SecuritySecretPatterns, WriteSpan};
+
+const SECRET_PATTERNS: [(&str, &str); 8] = [
+	("private_key", r"(?i)-----BEGIN (RSA|OPENSSH|EC|DSA) PRIVATE KEY-----"),
+	("ssh_key", r"(?i)ssh-rsa"),
+	("provider_api_key", r"(?i)sk-[a-z0-9]{20,}"),
+	("api_key", r"(?i)api[_-]?key\s*[:=]\s*\S+"),
+	("password", r"(?i)password\s*[:=]\s*\S+"),
+	("secret", r"(?i)secret\s*[:=]\s*\S+"),
+	("token", r"(?i)token\s*[:=]\s*\S+"),
+	("seed_phrase", r"(?i)seed phrase"),
+];
 
 /// Returns whether the input appears to contain secret material.
 pub fn contains_secrets(text: &str) -> bool {
-	let patterns = [
-		r"(?i)-----BEGIN (RSA|OPENSSH|EC|DSA) PRIVATE KEY-----",
-		r"(?i)ssh-rsa",
-		r"(?i)sk-[a-z0-9]{20,}",
-		r"(?i)api[_-]?key\s*[:=]\s*\S+",
-		r"(?i)password\s*[:=]\s*\S+",
-		r"(?i)secret\s*[:=]\s*\S+",
-		r"(?i)token\s*[:=]\s*\S+",
-		r"(?i)seed phrase",
-	];
-
-	for pattern in patterns {
-		if Regex::new(pattern).map(|re| re.is_match(text)).unwrap_or(false) {
-			return true;
+	find_secret(text).is_some()
+}
+
+/// Returns the earliest secret-like match in the input, if any.
+pub fn find_secret(text: &str) -> Option<SecretMatch> {
+	find_configured_secret(text, None)
+}
+
+/// Returns the earliest match of the configured secret rules, if any.
+///
+/// `None` uses the built-in rules only. Custom rules with `min_entropy` skip matches whose
+/// Shannon entropy is below the threshold and try the next match.
+pub fn find_configured_secret(
+	text: &str,
+	patterns: Option<&SecuritySecretPatterns>,
+) -> Option<SecretMatch> {
+	let mut found: Option<SecretMatch> = None;
+
+	if !patterns.is_some_and(|patterns| patterns.disable_builtin) {
+		for (pattern_class, pattern) in SECRET_PATTERNS {
+			let Some(m) = Regex::new(pattern).ok().and_then(|re| re.find(text)) else {
+				continue;
+			};
+
+			keep_earliest(&mut found, pattern_class, WriteSpan { start: m.start(), end: m.end() });
 		}
 	}
 
-	false
+	for custom in patterns.map(|patterns| patterns.custom.as_slice()).unwrap_or_default() {
+		let Ok(re) = Regex::new(custom.pattern.as_str()) else {
+			continue;
+		};
+		let Some(m) = re.find_iter(text).find(|m| {
+			custom.min_entropy.is_none_or(|min_entropy| shannon_entropy(m.as_str()) >= min_entropy)
+		}) else {
+			continue;
+		};
+
+		keep_earliest(
+			&mut found,
+			custom.name.as_str(),
+			WriteSpan { start: m.start(), end: m.end() },
+		);
+	}
+
+	found
+}
+
+fn keep_earliest(found: &mut Option<SecretMatch>, pattern_class: &str, span: WriteSpan) {
+	if found.as_ref().is_none_or(|current| span.start < current.span.start) {
+		*found = Some(SecretMatch { pattern_class: pattern_class.to_string(), span });
+	}
+}
+
+/// Shannon entropy of the text in bits per character.
+fn shannon_entropy(text: &str) -> f32 {
+	let mut counts: HashMap<char, u32> = HashMap::new();
+	let mut total = 0_u32;
+
+	for ch in text.chars() {
+		*counts.entry(ch).or_default() += 1;
+		total += 1;
+	}
+
+	if total == 0 {
+		return 0.0;
+	}
+
+	counts
+		.values()
+		.map(|&count| {
+			let p = count as f32 / total as f32;
+
+			-p * p.log2()
+		})
+		.sum()
 }
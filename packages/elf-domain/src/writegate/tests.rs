@@ -1,4 +1,7 @@
 pub(crate) mod config;
 
+mod feedback;
+mod pii;
 mod policy;
+mod secrets;
 mod validation;
@@ -17,6 +17,8 @@ pub(crate) fn config() -> Config {
 			mcp_bind: "127.0.0.1:8082".to_string(),
 			admin_bind: "127.0.0.1:8081".to_string(),
 			log_level: "info".to_string(),
+			mode: None,
+			otel: None,
 		},
 		storage: Storage {
 			postgres: Postgres {
@@ -32,6 +34,9 @@ pub(crate) fn config() -> Config {
 		},
 		providers: Providers {
 			embedding: dummy_embedding_provider(),
+			embeddings: None,
+			embedding_scopes: None,
+			embedding_types: None,
 			rerank: dummy_provider(),
 			llm_extractor: dummy_llm_provider(),
 		},
@@ -56,41 +61,11 @@ pub(crate) fn config() -> Config {
 			update_sim_threshold: 0.8,
 			candidate_k: 10,
 			top_k: 5,
+			version_coalesce_window_ms: None,
+			write_anomaly: None,
 			policy: MemoryPolicy { rules: vec![] },
 		},
-		search: Search {
-			expansion: SearchExpansion {
-				mode: "off".to_string(),
-				max_queries: 4,
-				include_original: true,
-			},
-			dynamic: SearchDynamic { min_candidates: 10, min_top_score: 0.12 },
-			prefilter: SearchPrefilter { max_candidates: 0 },
-			cache: SearchCache {
-				enabled: true,
-				expansion_ttl_days: 7,
-				rerank_ttl_days: 7,
-				max_payload_bytes: Some(262_144),
-			},
-			explain: SearchExplain {
-				retention_days: 7,
-				capture_candidates: false,
-				candidate_retention_days: 2,
-				write_mode: "outbox".to_string(),
-			},
-			recursive: SearchRecursive {
-				enabled: false,
-				max_depth: 2,
-				max_children_per_node: 4,
-				max_nodes_per_scope: 32,
-				max_total_nodes: 256,
-			},
-			graph_context: SearchGraphContext {
-				enabled: false,
-				max_facts_per_item: 16,
-				max_evidence_notes_per_fact: 16,
-			},
-		},
+		search: test_search(),
 		ranking: test_ranking(),
 		lifecycle: Lifecycle {
 			ttl_days: TtlDays {
@@ -103,6 +78,10 @@ pub(crate) fn config() -> Config {
 			},
 			purge_deleted_after_days: 30,
 			purge_deprecated_after_days: 180,
+			purge_trashed_after_days: None,
+			consolidation: None,
+			schedule: None,
+			importance_rescore: None,
 		},
 		security: Security {
 			bind_localhost_only: true,
@@ -113,15 +92,70 @@ pub(crate) fn config() -> Config {
 			evidence_max_quote_chars: 320,
 			auth_mode: "off".to_string(),
 			auth_keys: vec![],
+			jwt: None,
+			permissions: None,
+			pii: None,
+			secret_patterns: None,
+			egress: None,
+			quotas: None,
 		},
 		chunking: Chunking {
 			enabled: true,
 			max_tokens: 512,
 			overlap_tokens: 128,
 			tokenizer_repo: "REPLACE_ME".to_string(),
+			per_type: None,
 		},
 		context: None,
 		mcp: None,
+		shadow: None,
+		embedding_projection: None,
+		url_snapshots: None,
+		warmup: None,
+		qdrant_maintenance: None,
+		reembed: None,
+		worker: None,
+	}
+}
+
+fn test_search() -> Search {
+	Search {
+		expansion: SearchExpansion {
+			mode: "off".to_string(),
+			max_queries: 4,
+			include_original: true,
+		},
+		dynamic: SearchDynamic { min_candidates: 10, min_top_score: 0.12 },
+		prefilter: SearchPrefilter { max_candidates: 0 },
+		cache: SearchCache {
+			enabled: true,
+			expansion_ttl_days: 7,
+			rerank_ttl_days: 7,
+			max_payload_bytes: Some(262_144),
+			results: None,
+		},
+		explain: SearchExplain {
+			retention_days: 7,
+			capture_candidates: false,
+			candidate_retention_days: 2,
+			write_mode: "outbox".to_string(),
+		},
+		recursive: SearchRecursive {
+			enabled: false,
+			max_depth: 2,
+			max_children_per_node: 4,
+			max_nodes_per_scope: 32,
+			max_total_nodes: 256,
+			max_elapsed_ms: None,
+		},
+		graph_context: SearchGraphContext {
+			enabled: false,
+			max_facts_per_item: 16,
+			max_evidence_notes_per_fact: 16,
+		},
+		snippet: None,
+		answer: None,
+		degraded: None,
 	}
 }
 
@@ -145,6 +179,9 @@ fn test_ranking() -> Ranking {
 				last_hit_tau_days: 14.0,
 			},
 			decay: RankingDeterministicDecay { enabled: false, weight: 0.05, tau_days: 30.0 },
+			evidence: None,
+			session: None,
+			feedback: None,
 		},
 		blend: RankingBlend {
 			enabled: true,
@@ -161,6 +198,7 @@ fn test_ranking() -> Ranking {
 			sim_threshold: 0.88,
 			mmr_lambda: 0.7,
 			max_skips: 64,
+			scope_balance: None,
 		},
 		retrieval_sources: RankingRetrievalSources {
 			fusion_weight: 1.0,
@@ -181,6 +219,10 @@ fn dummy_embedding_provider() -> EmbeddingProviderConfig {
 		dimensions: 3,
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		query_input: None,
+		model_dir: None,
+		resilience: None,
+		cache: None,
 	}
 }
 
@@ -193,6 +235,8 @@ fn dummy_provider() -> ProviderConfig {
 		model: "m".to_string(),
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		resilience: None,
+		max_docs_per_call: None,
 	}
 }
 
@@ -206,5 +250,6 @@ fn dummy_llm_provider() -> LlmProviderConfig {
 		temperature: 0.1,
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		resilience: None,
 	}
 }
@@ -0,0 +1,66 @@
+use crate::writegate::{
+	self, NoteInput, RejectCode, RejectDetail, RejectFix, WriteSpan, tests::config,
+};
+
+fn note(text: &str) -> NoteInput {
+	NoteInput {
+		note_type: "fact".to_string(),
+		scope: "agent_private".to_string(),
+		text: text.to_string(),
+	}
+}
+
+#[test]
+fn too_long_feedback_reports_the_overflow_span() {
+	let cfg = config::config();
+	let note = note("12345678901é");
+	let feedback = writegate::reject_feedback(&note, &cfg, RejectCode::RejectTooLong)
+		.expect("Expected too-long feedback.");
+
+	assert_eq!(feedback.suggested_fix, RejectFix::Truncate);
+	assert_eq!(
+		feedback.detail,
+		RejectDetail::TooLong {
+			max_chars: 10,
+			char_count: 12,
+			overflow: WriteSpan { start: 10, end: 13 },
+		}
+	);
+}
+
+#[test]
+fn non_english_feedback_points_at_the_first_disallowed_char() {
+	let cfg = config::config();
+	let feedback = writegate::reject_feedback(&note("Hi 日本"), &cfg, RejectCode::RejectNonEnglish)
+		.expect("Expected non-English feedback.");
+
+	assert_eq!(feedback.suggested_fix, RejectFix::ReplaceSpan);
+	assert_eq!(
+		feedback.detail,
+		RejectDetail::NonEnglish {
+			reason: "disallowed_script".to_string(),
+			span: Some(WriteSpan { start: 3, end: 6 }),
+		}
+	);
+}
+
+#[test]
+fn secret_feedback_reports_the_pattern_class_and_span() {
+	let cfg = config::config();
+	let feedback = writegate::reject_feedback(
+		&note("Use password: hunter2 here."),
+		&cfg,
+		RejectCode::RejectSecret,
+	)
+	.expect("Expected secret feedback.");
+
+	assert_eq!(feedback.suggested_fix, RejectFix::RedactSpan);
+	assert_eq!(
+		feedback.detail,
+		RejectDetail::Secret {
+			pattern_class: "password".to_string(),
+			span: WriteSpan { start: 4, end: 21 },
+		}
+	);
+	assert_eq!(writegate::reject_feedback(&note("hello"), &cfg, RejectCode::RejectEmpty), None);
+}
@@ -0,0 +1,79 @@
+use crate::writegate::{
+	self, NoteInput, PiiCategory, RejectCode, RejectDetail, RejectFix, SecurityPii,
+	SecurityPiiAction, tests::config,
+};
+
+fn policy(action: SecurityPiiAction) -> SecurityPii {
+	SecurityPii { email: action, phone: action, credit_card: action, national_id: action }
+}
+
+#[test]
+fn detects_each_category_without_overlaps() {
+	let text =
+		"Mail ada@example.com, call +1 415 555 0132, card 4111 1111 1111 1111, SSN 123-45-6789.";
+	let categories: Vec<PiiCategory> =
+		writegate::find_pii(text).into_iter().map(|m| m.category).collect();
+
+	assert_eq!(
+		categories,
+		vec![
+			PiiCategory::Email,
+			PiiCategory::Phone,
+			PiiCategory::CreditCard,
+			PiiCategory::NationalId
+		]
+	);
+}
+
+#[test]
+fn ignores_dates_versions_and_invalid_numbers() {
+	for text in [
+		"Released on 2026-10-18 as version 1.2.3.",
+		"Card-like 4111 1111 1111 1112 fails the Luhn check.",
+		"Never issued: 000-12-3456 and 666-12-3456.",
+	] {
+		assert!(writegate::find_pii(text).is_empty(), "{text:?} should have no PII");
+	}
+}
+
+#[test]
+fn redacts_only_redact_categories_and_records_input_spans() {
+	let text = "Reach ada@example.com or 415-555-0132.";
+	let pii = SecurityPii { email: SecurityPiiAction::Redact, ..SecurityPii::default() };
+	let result = writegate::redact_pii("$.text", text, Some(&pii));
+
+	assert_eq!(result.transformed, "Reach [REDACTED_EMAIL] or 415-555-0132.");
+	assert_eq!(result.redactions.len(), 1);
+	assert_eq!(result.redactions[0].field, "$.text");
+	assert_eq!(
+		&text[result.redactions[0].span.start..result.redactions[0].span.end],
+		"ada@example.com"
+	);
+	assert_eq!(writegate::redact_pii("$.text", text, None).transformed, text);
+}
+
+#[test]
+fn writegate_rejects_pii_with_feedback() {
+	let mut cfg = config::config();
+
+	cfg.memory.max_note_chars = 200;
+	cfg.security.pii = Some(policy(SecurityPiiAction::Reject));
+
+	let note = NoteInput {
+		note_type: "fact".to_string(),
+		scope: "agent_private".to_string(),
+		text: "The user email is ada@example.com.".to_string(),
+	};
+
+	assert_eq!(writegate::writegate(&note, &cfg), Err(RejectCode::RejectPii));
+
+	let feedback = writegate::reject_feedback(&note, &cfg, RejectCode::RejectPii)
+		.expect("Expected PII feedback.");
+
+	assert_eq!(feedback.suggested_fix, RejectFix::RedactSpan);
+	assert!(matches!(feedback.detail, RejectDetail::Pii { category: PiiCategory::Email, .. }));
+
+	cfg.security.pii = Some(policy(SecurityPiiAction::Allow));
+
+	assert_eq!(writegate::writegate(&note, &cfg), Ok(()));
+}
@@ -0,0 +1,71 @@
+use crate::writegate::{
+	self, NoteInput, RejectCode, RejectDetail, SecuritySecretPatterns, tests::config,
+};
+use elf_config::SecuritySecretPattern;
+
+fn corp_patterns(disable_builtin: bool, min_entropy: Option<f32>) -> SecuritySecretPatterns {
+	SecuritySecretPatterns {
+		disable_builtin,
+		custom: vec![SecuritySecretPattern {
+			name: "corp_live_token".to_string(),
+			pattern: r"corp_live_[A-Za-z0-9]{16,}".to_string(),
+			min_entropy,
+		}],
+	}
+}
+
+#[test]
+fn custom_pattern_rejects_with_its_name_in_feedback() {
+	let mut cfg = config::config();
+
+	cfg.memory.max_note_chars = 200;
+	cfg.security.secret_patterns = Some(corp_patterns(false, None));
+
+	let note = NoteInput {
+		note_type: "fact".to_string(),
+		scope: "agent_private".to_string(),
+		text: "The deploy uses corp_live_9fQ2xLm7Rt4Kp8Zw for billing.".to_string(),
+	};
+
+	assert_eq!(writegate::writegate(&note, &cfg), Err(RejectCode::RejectSecret));
+
+	let feedback = writegate::reject_feedback(&note, &cfg, RejectCode::RejectSecret)
+		.expect("Expected secret feedback.");
+
+	assert_eq!(
+		feedback.detail,
+		RejectDetail::Secret {
+			pattern_class: "corp_live_token".to_string(),
+			span: writegate::WriteSpan { start: 16, end: 42 },
+		}
+	);
+}
+
+#[test]
+fn min_entropy_skips_low_entropy_matches() {
+	let patterns = corp_patterns(false, Some(3.0));
+
+	assert!(
+		writegate::find_configured_secret(
+			"Example corp_live_aaaaaaaaaaaaaaaa only.",
+			Some(&patterns)
+		)
+		.is_none()
+	);
+
+	let found = writegate::find_configured_secret(
+		"Example corp_live_aaaaaaaaaaaaaaaa then corp_live_9fQ2xLm7Rt4Kp8Zw.",
+		Some(&patterns),
+	)
+	.expect("Expected the high-entropy match.");
+
+	assert_eq!(found.span.start, 40);
+}
+
+#[test]
+fn disable_builtin_keeps_only_custom_patterns() {
+	let patterns = corp_patterns(true, None);
+
+	assert!(writegate::find_configured_secret("password: hunter2", Some(&patterns)).is_none());
+	assert!(writegate::find_configured_secret("password: hunter2", None).is_some());
+}
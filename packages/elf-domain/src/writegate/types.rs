@@ -15,6 +15,178 @@ pub enum RejectCode {
 	RejectScopeDenied,
 	/// The note text is empty after trimming.
 	RejectEmpty,
+	/// The note text contains PII in a category that `security.pii` rejects.
+	RejectPii,
+}
+
+/// PII category detected in note text at write time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiCategory {
+	/// Email address.
+	Email,
+	/// Phone number.
+	Phone,
+	/// Payment card number that passes the Luhn check.
+	CreditCard,
+	/// National identifier, currently US Social Security numbers.
+	NationalId,
+}
+impl PiiCategory {
+	/// Every category, in detection priority order.
+	pub const ALL: [Self; 4] = [Self::CreditCard, Self::NationalId, Self::Email, Self::Phone];
+
+	/// Returns the stable category label.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Email => "email",
+			Self::Phone => "phone",
+			Self::CreditCard => "credit_card",
+			Self::NationalId => "national_id",
+		}
+	}
+
+	/// Returns the placeholder that replaces a redacted match.
+	pub fn placeholder(self) -> &'static str {
+		match self {
+			Self::Email => "[REDACTED_EMAIL]",
+			Self::Phone => "[REDACTED_PHONE]",
+			Self::CreditCard => "[REDACTED_CREDIT_CARD]",
+			Self::NationalId => "[REDACTED_NATIONAL_ID]",
+		}
+	}
+}
+
+/// One PII match found in note text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PiiMatch {
+	/// Detected category.
+	pub category: PiiCategory,
+	/// Byte span of the matched text.
+	pub span: WriteSpan,
+}
+
+/// One PII redaction applied before persistence.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct PiiRedaction {
+	/// JSON path of the redacted field, such as `$.text` or `$.structured.facts[0]`.
+	#[serde(default = "default_redaction_field")]
+	pub field: String,
+	/// Detected category.
+	pub category: PiiCategory,
+	/// Byte span of the redacted text in the pre-redaction field value.
+	pub span: WriteSpan,
+	/// Placeholder written in place of the span.
+	pub replacement: String,
+}
+
+/// Result of applying PII redaction to one field value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PiiRedactionResult {
+	/// Field value with redacted spans replaced.
+	pub transformed: String,
+	/// Redactions that were applied, ordered by position.
+	pub redactions: Vec<PiiRedaction>,
+}
+
+/// One secret-like match found in note text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SecretMatch {
+	/// Stable label of the secret pattern that matched.
+	pub pattern_class: String,
+	/// Byte span of the matched text.
+	pub span: WriteSpan,
+}
+
+/// Remediation an agent can apply before retrying a rejected write.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectFix {
+	/// Cut the text at the start of the reported overflow span.
+	Truncate,
+	/// Rewrite the reported span in English.
+	ReplaceSpan,
+	/// Rewrite the whole text in English.
+	RewriteInEnglish,
+	/// Remove or redact the reported span, for example with a write-policy redaction.
+	RedactSpan,
+	/// Supply a number of evidence quotes inside the reported bounds.
+	AdjustQuoteCount,
+	/// Shorten the reported evidence quote.
+	ShortenQuote,
+	/// Copy the reported evidence quote verbatim from the referenced message.
+	QuoteVerbatim,
+}
+
+/// Machine-actionable details for a rejected write.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RejectFeedback {
+	/// Suggested repair before retrying.
+	pub suggested_fix: RejectFix,
+	/// Rejection-specific remediation data.
+	#[serde(flatten)]
+	pub detail: RejectDetail,
+}
+
+/// Rejection-specific remediation data, tagged by `kind`.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RejectDetail {
+	/// The text exceeded `memory.max_note_chars`.
+	TooLong {
+		/// Maximum allowed length in characters.
+		max_chars: u32,
+		/// Length of the rejected text in characters.
+		char_count: u32,
+		/// Byte span of the text past the limit.
+		overflow: WriteSpan,
+	},
+	/// The text failed the English gate.
+	NonEnglish {
+		/// English-gate reason label.
+		reason: String,
+		/// Byte span of the first offending character, when the check is character-level.
+		span: Option<WriteSpan>,
+	},
+	/// The text contained PII in a rejected category.
+	Pii {
+		/// Detected PII category.
+		category: PiiCategory,
+		/// Byte span of the earliest rejected match.
+		span: WriteSpan,
+	},
+	/// The text matched a secret pattern.
+	Secret {
+		/// Stable label of the matched pattern.
+		pattern_class: String,
+		/// Byte span of the earliest match.
+		span: WriteSpan,
+	},
+	/// The note carried too few or too many evidence quotes.
+	EvidenceQuoteCount {
+		/// Number of quotes supplied.
+		quote_count: usize,
+		/// Minimum number of quotes.
+		min_quotes: u32,
+		/// Maximum number of quotes.
+		max_quotes: u32,
+	},
+	/// One evidence quote exceeded `security.evidence_max_quote_chars`.
+	EvidenceQuoteTooLong {
+		/// Zero-based index of the failing quote.
+		quote_index: usize,
+		/// Length of the failing quote in bytes.
+		quote_len: usize,
+		/// Maximum allowed quote length in bytes.
+		max_quote_chars: u32,
+	},
+	/// One evidence quote was not found in its referenced message.
+	EvidenceQuoteNotFound {
+		/// Zero-based index of the failing quote.
+		quote_index: usize,
+		/// Message index the quote referenced.
+		message_index: usize,
+	},
 }
 
 /// One write-policy redaction operation.
@@ -104,3 +276,8 @@ pub struct NoteInput {
 	/// Note text after request decoding.
 	pub text: String,
 }
+
+/// Redactions recorded before fields were tracked only ever covered the note text.
+fn default_redaction_field() -> String {
+	"$.text".to_string()
+}
@@ -20,9 +20,14 @@ pub fn writegate(note: &NoteInput, cfg: &Config) -> Result<(), RejectCode> {
 	if !scope_write_allowed(cfg, &note.scope) {
 		return Err(RejectCode::RejectScopeDenied);
 	}
-	if crate::writegate::contains_secrets(&note.text) {
+	if crate::writegate::find_configured_secret(&note.text, cfg.security.secret_patterns.as_ref())
+		.is_some()
+	{
 		return Err(RejectCode::RejectSecret);
 	}
+	if crate::writegate::find_rejected_pii(&note.text, cfg.security.pii.as_ref()).is_some() {
+		return Err(RejectCode::RejectPii);
+	}
 
 	Ok(())
 }
@@ -37,5 +42,8 @@ fn scope_write_allowed(cfg: &Config, scope: &str) -> bool {
 }
 
 fn is_allowed_type(note_type: &str) -> bool {
-	matches!(note_type, "preference" | "constraint" | "decision" | "profile" | "fact" | "plan")
+	matches!(
+		note_type,
+		"preference" | "constraint" | "decision" | "profile" | "fact" | "plan" | "qa"
+	)
 }
@@ -12,9 +12,14 @@ use elf_config::{
 	RankingDeterministicLexical, RankingDiversity, RankingRetrievalSources, ReadProfiles,
 	ScopePrecedence, ScopeWriteAllowed, Scopes, Search, SearchCache, SearchDynamic,
 	SearchExpansion, SearchExplain, SearchGraphContext, SearchPrefilter, SearchRecursive, Security,
-	Service, Storage, TtlDays,
+	SecurityEgress, Service, Storage, TtlDays,
+};
+use elf_domain::{
+	egress::{self, EgressDenial},
+	evidence,
+	normalization::{self, DateOrder, NormalizationLocale, NormalizedValueKind},
+	ttl,
 };
-use elf_domain::{evidence, ttl};
 
 fn dummy_embedding_provider() -> EmbeddingProviderConfig {
 	EmbeddingProviderConfig {
@@ -26,6 +31,10 @@ fn dummy_embedding_provider() -> EmbeddingProviderConfig {
 		dimensions: 3,
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		query_input: None,
+		model_dir: None,
+		resilience: None,
+		cache: None,
 	}
 }
 
@@ -38,6 +47,8 @@ fn dummy_provider() -> ProviderConfig {
 		model: "m".to_string(),
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		resilience: None,
+		max_docs_per_call: None,
 	}
 }
 
@@ -51,6 +62,48 @@ fn dummy_llm_provider() -> LlmProviderConfig {
 		temperature: 0.1,
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		resilience: None,
+	}
+}
+
+fn test_search() -> Search {
+	Search {
+		expansion: SearchExpansion {
+			mode: "off".to_string(),
+			max_queries: 4,
+			include_original: true,
+		},
+		dynamic: SearchDynamic { min_candidates: 10, min_top_score: 0.12 },
+		prefilter: SearchPrefilter { max_candidates: 0 },
+		cache: SearchCache {
+			enabled: true,
+			expansion_ttl_days: 7,
+			rerank_ttl_days: 7,
+			max_payload_bytes: Some(262_144),
+			results: None,
+		},
+		explain: SearchExplain {
+			retention_days: 7,
+			capture_candidates: false,
+			candidate_retention_days: 2,
+			write_mode: "outbox".to_string(),
+		},
+		recursive: SearchRecursive {
+			enabled: false,
+			max_depth: 2,
+			max_children_per_node: 4,
+			max_nodes_per_scope: 32,
+			max_total_nodes: 256,
+			max_elapsed_ms: None,
+		},
+		graph_context: SearchGraphContext {
+			enabled: false,
+			max_facts_per_item: 16,
+			max_evidence_notes_per_fact: 16,
+		},
+		snippet: None,
+		answer: None,
+		degraded: None,
 	}
 }
 
@@ -74,6 +127,9 @@ fn test_ranking() -> Ranking {
 				last_hit_tau_days: 14.0,
 			},
 			decay: RankingDeterministicDecay { enabled: false, weight: 0.05, tau_days: 30.0 },
+			evidence: None,
+			session: None,
+			feedback: None,
 		},
 		blend: RankingBlend {
 			enabled: true,
@@ -90,6 +146,7 @@ fn test_ranking() -> Ranking {
 			sim_threshold: 0.88,
 			mmr_lambda: 0.7,
 			max_skips: 64,
+			scope_balance: None,
 		},
 		retrieval_sources: RankingRetrievalSources {
 			fusion_weight: 1.0,
@@ -107,6 +164,8 @@ fn base_config() -> Config {
 			mcp_bind: "127.0.0.1:8082".to_string(),
 			admin_bind: "127.0.0.1:8081".to_string(),
 			log_level: "info".to_string(),
+			mode: None,
+			otel: None,
 		},
 		storage: Storage {
 			postgres: Postgres {
@@ -122,6 +181,9 @@ fn base_config() -> Config {
 		},
 		providers: Providers {
 			embedding: dummy_embedding_provider(),
+			embeddings: None,
+			embedding_scopes: None,
+			embedding_types: None,
 			rerank: dummy_provider(),
 			llm_extractor: dummy_llm_provider(),
 		},
@@ -146,41 +208,11 @@ fn base_config() -> Config {
 			update_sim_threshold: 0.85,
 			candidate_k: 60,
 			top_k: 12,
+			version_coalesce_window_ms: None,
+			write_anomaly: None,
 			policy: MemoryPolicy { rules: vec![] },
 		},
-		search: Search {
-			expansion: SearchExpansion {
-				mode: "off".to_string(),
-				max_queries: 4,
-				include_original: true,
-			},
-			dynamic: SearchDynamic { min_candidates: 10, min_top_score: 0.12 },
-			prefilter: SearchPrefilter { max_candidates: 0 },
-			cache: SearchCache {
-				enabled: true,
-				expansion_ttl_days: 7,
-				rerank_ttl_days: 7,
-				max_payload_bytes: Some(262_144),
-			},
-			explain: SearchExplain {
-				retention_days: 7,
-				capture_candidates: false,
-				candidate_retention_days: 2,
-				write_mode: "outbox".to_string(),
-			},
-			recursive: SearchRecursive {
-				enabled: false,
-				max_depth: 2,
-				max_children_per_node: 4,
-				max_nodes_per_scope: 32,
-				max_total_nodes: 256,
-			},
-			graph_context: SearchGraphContext {
-				enabled: false,
-				max_facts_per_item: 16,
-				max_evidence_notes_per_fact: 16,
-			},
-		},
+		search: test_search(),
 		ranking: test_ranking(),
 		lifecycle: Lifecycle {
 			ttl_days: TtlDays {
@@ -193,6 +225,10 @@ fn base_config() -> Config {
 			},
 			purge_deleted_after_days: 30,
 			purge_deprecated_after_days: 180,
+			purge_trashed_after_days: None,
+			consolidation: None,
+			schedule: None,
+			importance_rescore: None,
 		},
 		security: Security {
 			bind_localhost_only: true,
@@ -203,15 +239,29 @@ fn base_config() -> Config {
 			evidence_max_quote_chars: 320,
 			auth_mode: "off".to_string(),
 			auth_keys: vec![],
+			jwt: None,
+			permissions: None,
+			pii: None,
+			secret_patterns: None,
+			egress: None,
+			quotas: None,
 		},
 		chunking: Chunking {
 			enabled: true,
 			max_tokens: 512,
 			overlap_tokens: 128,
 			tokenizer_repo: "REPLACE_ME".to_string(),
+			per_type: None,
 		},
 		context: None,
 		mcp: None,
+		shadow: None,
+		embedding_projection: None,
+		url_snapshots: None,
+		warmup: None,
+		qdrant_maintenance: None,
+		reembed: None,
+		worker: None,
 	}
 }
 
@@ -231,6 +281,88 @@ fn evidence_rejects_empty_quote() {
 	assert!(!evidence::evidence_matches(&messages, 0, "   "));
 }
 
+#[test]
+fn evidence_coverage_counts_backed_claims() {
+	let coverage = evidence::evidence_coverage(
+		"The deploy runs on Fridays. Rollbacks need approval. The team likes tea.",
+		&["We always deploy runs on Fridays", "rollbacks need manager approval first"],
+	)
+	.expect("Expected claims.");
+
+	assert_eq!(coverage.claim_count, 3);
+	assert_eq!(coverage.backed_claim_count, 2);
+	assert!((coverage.coverage - 2.0 / 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn evidence_coverage_is_none_without_claims() {
+	assert!(evidence::evidence_coverage("  ... ", &["quote"]).is_none());
+	assert_eq!(
+		evidence::evidence_coverage("Uses Postgres.", &[]).map(|coverage| coverage.coverage),
+		Some(0.0)
+	);
+}
+
+#[test]
+fn normalization_locale_reads_region_and_language() {
+	let us = NormalizationLocale::from_tag("en-US").expect("Expected locale.");
+	let de = NormalizationLocale::from_tag("de_DE").expect("Expected locale.");
+
+	assert_eq!(us.date_order, DateOrder::MonthFirst);
+	assert!(!us.decimal_comma);
+	assert_eq!(de.date_order, DateOrder::DayFirst);
+	assert!(de.decimal_comma);
+	assert_eq!(
+		NormalizationLocale::from_tag("en").map(|locale| locale.date_order),
+		Some(DateOrder::Unknown)
+	);
+	assert!(NormalizationLocale::from_tag("english").is_none());
+	assert!(NormalizationLocale::from_tag("en-Latn-US-x").is_some());
+	assert!(NormalizationLocale::from_tag("en-").is_none());
+}
+
+#[test]
+fn normalizes_dates_quantities_and_currency() {
+	let values = normalization::normalize_values(
+		"On March 14, 2026 the 2.5 km cable cost $1,200 and weighed 3 kg; renewal on 2026-04-01.",
+		NormalizationLocale::default(),
+	);
+	let rendered: Vec<(NormalizedValueKind, &str)> =
+		values.iter().map(|value| (value.kind, value.normalized.as_str())).collect();
+
+	assert_eq!(
+		rendered,
+		vec![
+			(NormalizedValueKind::Date, "2026-03-14"),
+			(NormalizedValueKind::Quantity, "2500 m"),
+			(NormalizedValueKind::Currency, "1200 USD"),
+			(NormalizedValueKind::Quantity, "3 kg"),
+			(NormalizedValueKind::Date, "2026-04-01"),
+		]
+	);
+	assert_eq!(values[2].raw, "$1,200");
+	assert_eq!(values[2].number, Some(1_200.0));
+}
+
+#[test]
+fn normalization_uses_locale_for_ambiguous_input() {
+	let text = "Shipped 03/04/2026 for EUR 1.250,50.";
+	let unknown = normalization::normalize_values(text, NormalizationLocale::default());
+	let us = normalization::normalize_values(
+		text,
+		NormalizationLocale::from_tag("en-US").expect("Expected locale."),
+	);
+	let de = normalization::normalize_values(
+		text,
+		NormalizationLocale::from_tag("de-DE").expect("Expected locale."),
+	);
+
+	assert!(unknown.iter().all(|value| value.kind != NormalizedValueKind::Date));
+	assert_eq!(us[0].normalized, "2026-03-04");
+	assert_eq!(de[0].normalized, "2026-04-03");
+	assert_eq!(de[1].normalized, "1250.5 EUR");
+}
+
 #[test]
 fn computes_ttl_from_defaults() {
 	let cfg = base_config();
@@ -239,3 +371,52 @@ fn computes_ttl_from_defaults() {
 
 	assert!(expires > now);
 }
+
+#[test]
+fn egress_refuses_internal_targets() {
+	for host in [
+		"127.0.0.1",
+		"10.0.0.8",
+		"169.254.169.254",
+		"192.168.1.1",
+		"100.64.0.1",
+		"0.0.0.0",
+		"[::1]",
+	] {
+		assert_eq!(egress::check_host(host, None), Err(EgressDenial::NonPublicAddress), "{host}");
+	}
+	for host in ["::ffff:127.0.0.1", "fd00::1", "fe80::1", "64:ff9b::a9fe:a9fe"] {
+		assert_eq!(egress::check_host(host, None), Err(EgressDenial::NonPublicAddress), "{host}");
+	}
+
+	assert_eq!(egress::check_host("localhost", None), Err(EgressDenial::LocalHostName));
+	assert_eq!(egress::check_host("api.localhost.", None), Err(EgressDenial::LocalHostName));
+	assert_eq!(egress::check_host("example.com", None), Ok(()));
+	assert_eq!(egress::check_host("93.184.216.34", None), Ok(()));
+}
+
+#[test]
+fn egress_applies_host_lists_to_subdomains() {
+	let policy = SecurityEgress {
+		allowed_hosts: vec!["example.com".to_string()],
+		denied_hosts: vec!["internal.example.com".to_string()],
+	};
+
+	assert_eq!(egress::check_host("docs.example.com", Some(&policy)), Ok(()));
+	assert_eq!(
+		egress::check_host("db.internal.example.com", Some(&policy)),
+		Err(EgressDenial::DeniedHost)
+	);
+	assert_eq!(
+		egress::check_host("badexample.com", Some(&policy)),
+		Err(EgressDenial::HostNotAllowed)
+	);
+}
+
+#[test]
+fn egress_extracts_url_hosts() {
+	assert_eq!(egress::url_host("https://example.com:8443/a?b"), Some("example.com"));
+	assert_eq!(egress::url_host("http://[::1]:8080/"), Some("::1"));
+	assert_eq!(egress::url_host("http://user@10.0.0.1/"), Some("10.0.0.1"));
+	assert_eq!(egress::url_host("ftp://example.com/"), None);
+}
@@ -12,6 +12,10 @@ pub(crate) fn memory_policy_lifecycle_config() -> Lifecycle {
 		},
 		purge_deleted_after_days: 30,
 		purge_deprecated_after_days: 180,
+		purge_trashed_after_days: None,
+		consolidation: None,
+		schedule: None,
+		importance_rescore: None,
 	}
 }
 
@@ -25,5 +29,11 @@ pub(crate) fn memory_policy_security_config() -> Security {
 		evidence_max_quote_chars: 320,
 		auth_mode: "off".to_string(),
 		auth_keys: vec![],
+		jwt: None,
+		permissions: None,
+		pii: None,
+		secret_patterns: None,
+		egress: None,
+		quotas: None,
 	}
 }
@@ -5,6 +5,9 @@ use elf_config::{EmbeddingProviderConfig, LlmProviderConfig, ProviderConfig, Pro
 pub(crate) fn memory_policy_providers_config() -> Providers {
 	Providers {
 		embedding: embedding_provider_config(),
+		embeddings: None,
+		embedding_scopes: None,
+		embedding_types: None,
 		rerank: rerank_provider_config(),
 		llm_extractor: llm_extractor_provider_config(),
 	}
@@ -20,6 +23,10 @@ fn embedding_provider_config() -> EmbeddingProviderConfig {
 		dimensions: 3,
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		query_input: None,
+		model_dir: None,
+		resilience: None,
+		cache: None,
 	}
 }
 
@@ -32,6 +39,8 @@ fn rerank_provider_config() -> ProviderConfig {
 		model: "m".to_string(),
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		resilience: None,
+		max_docs_per_call: None,
 	}
 }
 
@@ -45,5 +54,6 @@ fn llm_extractor_provider_config() -> LlmProviderConfig {
 		temperature: 0.1,
 		timeout_ms: 1_000,
 		default_headers: Map::new(),
+		resilience: None,
 	}
 }
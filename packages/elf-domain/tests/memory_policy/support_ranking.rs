@@ -24,6 +24,9 @@ pub(crate) fn memory_policy_ranking_config() -> Ranking {
 				last_hit_tau_days: 14.0,
 			},
 			decay: RankingDeterministicDecay { enabled: false, weight: 0.05, tau_days: 30.0 },
+			evidence: None,
+			session: None,
+			feedback: None,
 		},
 		blend: RankingBlend {
 			enabled: true,
@@ -36,6 +39,7 @@ pub(crate) fn memory_policy_ranking_config() -> Ranking {
 			sim_threshold: 0.88,
 			mmr_lambda: 0.7,
 			max_skips: 64,
+			scope_balance: None,
 		},
 		retrieval_sources: RankingRetrievalSources {
 			fusion_weight: 1.0,
@@ -18,6 +18,13 @@ pub(crate) fn memory_policy_default_config() -> Config {
 		chunking: memory_policy_chunking_config(),
 		context: None,
 		mcp: None,
+		shadow: None,
+		embedding_projection: None,
+		url_snapshots: None,
+		warmup: None,
+		qdrant_maintenance: None,
+		reembed: None,
+		worker: None,
 	}
 }
 
@@ -27,6 +34,8 @@ fn memory_policy_service_config() -> Service {
 		mcp_bind: "127.0.0.1:8082".to_string(),
 		admin_bind: "127.0.0.1:8081".to_string(),
 		log_level: "info".to_string(),
+		mode: None,
+		otel: None,
 	}
 }
 
@@ -51,5 +60,6 @@ fn memory_policy_chunking_config() -> Chunking {
 		max_tokens: 512,
 		overlap_tokens: 128,
 		tokenizer_repo: "REPLACE_ME".to_string(),
+		per_type: None,
 	}
 }
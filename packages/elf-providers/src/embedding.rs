@@ -1,21 +1,54 @@
 //! Embedding-provider client helpers.
 
+#[cfg(feature = "local-model")] pub mod local;
+
+mod cache;
+
 use std::time::Duration;
 
 use reqwest::Client;
 use serde_json::Value;
 
-use crate::{Error, Result};
+use crate::{Error, Result, resilience};
 use elf_config::EmbeddingProviderConfig;
 
 /// Embeds texts with the configured provider or local fallback implementation.
+///
+/// With `cache` configured, vectors for repeated texts come from an in-process LRU cache, and
+/// concurrent calls within `cache.batch_window_ms` share one provider request.
 pub async fn embed(cfg: &EmbeddingProviderConfig, texts: &[String]) -> Result<Vec<Vec<f32>>> {
 	if cfg.provider_id == "local" {
 		let dim = cfg.dimensions as usize;
 
 		return Ok(texts.iter().map(|text| local_embed(dim, text)).collect());
 	}
+	if texts.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	match cfg.cache.as_ref() {
+		Some(cache) => cache::embed_cached(cfg, cache, texts).await,
+		None => embed_uncached(cfg, texts).await,
+	}
+}
+
+async fn embed_uncached(cfg: &EmbeddingProviderConfig, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+	if cfg.provider_id == "local_model" {
+		#[cfg(feature = "local-model")]
+		return local::embed(cfg, texts).await;
+		#[cfg(not(feature = "local-model"))]
+		return Err(Error::InvalidConfig {
+			message: "provider_id local_model requires a build with the local-model feature."
+				.to_string(),
+		});
+	}
 
+	let key = resilience::provider_key("embedding", &cfg.api_base, &cfg.path, &cfg.model);
+
+	resilience::call(&key, cfg.resilience.as_ref(), || embed_remote(cfg, texts)).await
+}
+
+async fn embed_remote(cfg: &EmbeddingProviderConfig, texts: &[String]) -> Result<Vec<Vec<f32>>> {
 	let client = Client::builder().timeout(Duration::from_millis(cfg.timeout_ms)).build()?;
 	let url = format!("{}{}", cfg.api_base, cfg.path);
 	let body = serde_json::json!({
@@ -178,6 +211,28 @@ mod tests {
 		);
 	}
 
+	#[cfg(not(feature = "local-model"))]
+	#[tokio::test]
+	async fn local_model_requires_feature() {
+		let cfg = elf_config::EmbeddingProviderConfig {
+			provider_id: "local_model".to_string(),
+			api_base: String::new(),
+			api_key: "offline".to_string(),
+			path: String::new(),
+			model: "all-MiniLM-L6-v2".to_string(),
+			dimensions: 384,
+			timeout_ms: 1_000,
+			default_headers: serde_json::Map::new(),
+			query_input: None,
+			model_dir: Some("/models/all-MiniLM-L6-v2".to_string()),
+			resilience: None,
+			cache: None,
+		};
+		let err = embedding::embed(&cfg, &["text".to_string()]).await.expect_err("Expected error.");
+
+		assert!(err.to_string().contains("local-model feature"), "Unexpected error: {err}");
+	}
+
 	fn dot(a: &[f32], b: &[f32]) -> f32 {
 		a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
 	}
@@ -0,0 +1,399 @@
+//! In-process embedding vector cache and request coalescing.
+//!
+//! Caches and pending batches are kept per provider namespace for the life of the process, so
+//! every caller of the same provider endpoint, model, and dimension shares them.
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	future::Future,
+	ops::Range,
+	sync::{
+		Arc, Mutex, MutexGuard, OnceLock,
+		atomic::{AtomicU64, Ordering},
+	},
+	time::Duration,
+};
+
+use tokio::sync::oneshot;
+
+use crate::{Error, Result, embedding, resilience};
+use elf_config::{EmbeddingCache, EmbeddingProviderConfig};
+
+type TextHash = [u8; 32];
+type BatchResult = std::result::Result<Vec<Vec<f32>>, Arc<Error>>;
+
+const DEFAULT_MAX_BATCH_TEXTS: usize = 256;
+
+static NEXT_BATCH_ID: AtomicU64 = AtomicU64::new(0);
+static CACHES: OnceLock<Mutex<HashMap<String, VectorCache>>> = OnceLock::new();
+static PENDING: OnceLock<Mutex<HashMap<String, PendingBatch>>> = OnceLock::new();
+
+/// Least-recently-used vector cache of one provider namespace.
+#[derive(Default)]
+struct VectorCache {
+	entries: HashMap<TextHash, (Vec<f32>, u64)>,
+	recency: BTreeMap<u64, TextHash>,
+	clock: u64,
+}
+impl VectorCache {
+	fn get(&mut self, hash: &TextHash) -> Option<Vec<f32>> {
+		let tick = self.tick();
+		let (vector, last_used) = self.entries.get_mut(hash)?;
+
+		self.recency.remove(last_used);
+		self.recency.insert(tick, *hash);
+
+		*last_used = tick;
+
+		Some(vector.clone())
+	}
+
+	fn insert(&mut self, hash: TextHash, vector: Vec<f32>, max_entries: usize) {
+		let tick = self.tick();
+
+		if let Some((_, last_used)) = self.entries.insert(hash, (vector, tick)) {
+			self.recency.remove(&last_used);
+		}
+
+		self.recency.insert(tick, hash);
+
+		while self.entries.len() > max_entries {
+			let Some((_, oldest)) = self.recency.pop_first() else {
+				break;
+			};
+
+			self.entries.remove(&oldest);
+		}
+	}
+
+	fn tick(&mut self) -> u64 {
+		self.clock += 1;
+
+		self.clock
+	}
+}
+
+/// Texts collected from concurrent callers during one batch window.
+struct PendingBatch {
+	id: u64,
+	texts: Vec<String>,
+	waiters: Vec<(Range<usize>, oneshot::Sender<BatchResult>)>,
+}
+
+/// Embeds `texts`, serving repeated texts from the cache and fetching the rest in one call.
+pub(super) async fn embed_cached(
+	cfg: &EmbeddingProviderConfig,
+	cache: &EmbeddingCache,
+	texts: &[String],
+) -> Result<Vec<Vec<f32>>> {
+	let namespace = format!(
+		"{}:{}",
+		resilience::provider_key("embedding", &cfg.api_base, &cfg.path, &cfg.model),
+		cfg.dimensions
+	);
+	let hashes: Vec<TextHash> =
+		texts.iter().map(|text| *blake3::hash(text.as_bytes()).as_bytes()).collect();
+	let mut vectors = {
+		let mut caches = lock(&CACHES);
+		let vector_cache = caches.entry(namespace.clone()).or_default();
+
+		hashes.iter().map(|hash| vector_cache.get(hash)).collect::<Vec<_>>()
+	};
+	let mut missing_texts = Vec::new();
+	let mut missing_hashes = Vec::new();
+
+	for ((text, hash), vector) in texts.iter().zip(&hashes).zip(&vectors) {
+		if vector.is_none() && !missing_hashes.contains(hash) {
+			missing_texts.push(text.clone());
+			missing_hashes.push(*hash);
+		}
+	}
+
+	if !missing_texts.is_empty() {
+		let fetched = if cache.batch_window_ms > 0 {
+			let cfg = cfg.clone();
+			let fetch = move |texts: Vec<String>| {
+				let cfg = cfg.clone();
+
+				async move { embedding::embed_uncached(&cfg, &texts).await }
+			};
+
+			coalesce(
+				&namespace,
+				missing_texts,
+				Duration::from_millis(cache.batch_window_ms),
+				cache.max_batch_texts.map_or(DEFAULT_MAX_BATCH_TEXTS, |max| max as usize),
+				fetch,
+			)
+			.await?
+		} else {
+			embedding::embed_uncached(cfg, &missing_texts).await?
+		};
+
+		if fetched.len() != missing_hashes.len() {
+			return Err(Error::InvalidResponse {
+				message: format!(
+					"Embedding provider returned {} vectors for {} texts.",
+					fetched.len(),
+					missing_hashes.len()
+				),
+			});
+		}
+
+		let fetched: HashMap<TextHash, Vec<f32>> =
+			missing_hashes.into_iter().zip(fetched).collect();
+		let mut caches = lock(&CACHES);
+		let vector_cache = caches.entry(namespace).or_default();
+
+		for (hash, vector) in &fetched {
+			vector_cache.insert(*hash, vector.clone(), cache.max_entries as usize);
+		}
+		for (hash, vector) in hashes.iter().zip(vectors.iter_mut()) {
+			if vector.is_none() {
+				*vector = fetched.get(hash).cloned();
+			}
+		}
+	}
+
+	vectors.into_iter().collect::<Option<Vec<_>>>().ok_or_else(|| Error::InvalidResponse {
+		message: "Embedding cache is missing a vector for a requested text.".to_string(),
+	})
+}
+
+/// Joins the batch pending for `namespace`, or opens one that flushes after `window`.
+///
+/// A batch holds at most `max_texts` texts. A caller that would overflow the pending batch sends it
+/// right away and opens the next one, and a caller with `max_texts` or more texts of its own skips
+/// coalescing. Flushes run on their own task, so a caller that stops waiting does not strand the
+/// others.
+async fn coalesce<F, Fut>(
+	namespace: &str,
+	texts: Vec<String>,
+	window: Duration,
+	max_texts: usize,
+	fetch: F,
+) -> Result<Vec<Vec<f32>>>
+where
+	F: 'static + Clone + Send + Fn(Vec<String>) -> Fut,
+	Fut: 'static + Send + Future<Output = Result<Vec<Vec<f32>>>>,
+{
+	if texts.len() >= max_texts {
+		return fetch(texts).await;
+	}
+
+	let (tx, rx) = oneshot::channel();
+	let (full, opened) = {
+		let mut pending = lock(&PENDING);
+		let overflows =
+			pending.get(namespace).is_some_and(|batch| batch.texts.len() + texts.len() > max_texts);
+		let full = if overflows { pending.remove(namespace) } else { None };
+		let opened = match pending.get_mut(namespace) {
+			Some(batch) => {
+				let start = batch.texts.len();
+
+				batch.texts.extend(texts);
+				batch.waiters.push((start..batch.texts.len(), tx));
+
+				None
+			},
+			None => {
+				let id = NEXT_BATCH_ID.fetch_add(1, Ordering::Relaxed);
+				let range = 0..texts.len();
+
+				pending.insert(
+					namespace.to_string(),
+					PendingBatch { id, texts, waiters: vec![(range, tx)] },
+				);
+
+				Some(id)
+			},
+		};
+
+		(full, opened)
+	};
+
+	if let Some(batch) = full {
+		tokio::spawn(send(batch, fetch.clone()));
+	}
+	if let Some(id) = opened {
+		tokio::spawn(flush(namespace.to_string(), id, window, fetch));
+	}
+
+	match rx.await {
+		Ok(Ok(vectors)) => Ok(vectors),
+		Ok(Err(err)) => Err(Error::Coalesced(err)),
+		Err(_) => Err(Error::InvalidResponse {
+			message: "Coalesced embedding batch ended without a result.".to_string(),
+		}),
+	}
+}
+
+async fn flush<F, Fut>(namespace: String, id: u64, window: Duration, fetch: F)
+where
+	F: Fn(Vec<String>) -> Fut,
+	Fut: Future<Output = Result<Vec<Vec<f32>>>>,
+{
+	tokio::time::sleep(window).await;
+
+	let batch = {
+		let mut pending = lock(&PENDING);
+
+		if pending.get(&namespace).is_some_and(|batch| batch.id == id) {
+			pending.remove(&namespace)
+		} else {
+			None
+		}
+	};
+
+	// A batch that filled up before its window ended was already sent.
+	if let Some(batch) = batch {
+		send(batch, fetch).await;
+	}
+}
+
+async fn send<F, Fut>(batch: PendingBatch, fetch: F)
+where
+	F: Fn(Vec<String>) -> Fut,
+	Fut: Future<Output = Result<Vec<Vec<f32>>>>,
+{
+	let count = batch.texts.len();
+	let result = match fetch(batch.texts).await {
+		Ok(vectors) if vectors.len() == count => Ok(vectors),
+		Ok(vectors) => Err(Arc::new(Error::InvalidResponse {
+			message: format!(
+				"Embedding provider returned {} vectors for {count} texts.",
+				vectors.len()
+			),
+		})),
+		Err(err) => Err(Arc::new(err)),
+	};
+
+	for (range, tx) in batch.waiters {
+		let reply = match &result {
+			Ok(vectors) => Ok(vectors[range].to_vec()),
+			Err(err) => Err(Arc::clone(err)),
+		};
+
+		// A waiter that stopped listening no longer needs its vectors.
+		let _ = tx.send(reply);
+	}
+}
+
+fn lock<T>(
+	cell: &'static OnceLock<Mutex<HashMap<String, T>>>,
+) -> MutexGuard<'static, HashMap<String, T>> {
+	cell.get_or_init(|| Mutex::new(HashMap::new()))
+		.lock()
+		.unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		sync::{
+			Arc,
+			atomic::{AtomicU32, Ordering},
+		},
+		time::Duration,
+	};
+
+	use crate::{
+		Error, Result,
+		embedding::cache::{self, VectorCache},
+	};
+
+	const WINDOW: Duration = Duration::from_millis(20);
+
+	/// Returns a fake provider call that counts its calls and embeds each text as `[len]`.
+	fn counting_fetch(
+		calls: &Arc<AtomicU32>,
+		fail: bool,
+	) -> impl 'static + Clone + Send + Fn(Vec<String>) -> std::future::Ready<Result<Vec<Vec<f32>>>>
+	{
+		let calls = Arc::clone(calls);
+
+		move |texts: Vec<String>| {
+			calls.fetch_add(1, Ordering::SeqCst);
+
+			std::future::ready(if fail {
+				Err(Error::Timeout { message: "Provider call timed out.".to_string() })
+			} else {
+				Ok(texts.iter().map(|text| vec![text.len() as f32]).collect())
+			})
+		}
+	}
+
+	fn texts(texts: &[&str]) -> Vec<String> {
+		texts.iter().map(|text| text.to_string()).collect()
+	}
+
+	#[test]
+	fn vector_cache_evicts_the_least_recently_used_entry() {
+		let mut cache = VectorCache::default();
+
+		cache.insert([1; 32], vec![1.0], 2);
+		cache.insert([2; 32], vec![2.0], 2);
+
+		assert_eq!(cache.get(&[1; 32]), Some(vec![1.0]));
+
+		cache.insert([3; 32], vec![3.0], 2);
+
+		assert_eq!(cache.get(&[2; 32]), None);
+		assert_eq!(cache.get(&[1; 32]), Some(vec![1.0]));
+		assert_eq!(cache.get(&[3; 32]), Some(vec![3.0]));
+	}
+
+	#[tokio::test]
+	async fn coalesced_callers_share_one_call_and_get_their_own_vectors() {
+		let calls = Arc::new(AtomicU32::new(0));
+		let fetch = counting_fetch(&calls, false);
+		let (first, second) = tokio::join!(
+			cache::coalesce("test:share", texts(&["a"]), WINDOW, 8, fetch.clone()),
+			cache::coalesce("test:share", texts(&["bb", "ccc"]), WINDOW, 8, fetch),
+		);
+
+		assert_eq!(first.expect("Expected vectors."), vec![vec![1.0]]);
+		assert_eq!(second.expect("Expected vectors."), vec![vec![2.0], vec![3.0]]);
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
+	}
+
+	#[tokio::test]
+	async fn coalesced_callers_share_one_call_and_its_typed_error() {
+		let calls = Arc::new(AtomicU32::new(0));
+		let fetch = counting_fetch(&calls, true);
+		let (first, second) = tokio::join!(
+			cache::coalesce("test:error", texts(&["a"]), WINDOW, 8, fetch.clone()),
+			cache::coalesce("test:error", texts(&["b"]), WINDOW, 8, fetch),
+		);
+
+		for result in [first, second] {
+			let err = result.expect_err("Expected the shared call to fail.");
+
+			assert!(
+				matches!(
+					&err,
+					Error::Coalesced(source) if matches!(**source, Error::Timeout { .. })
+				),
+				"Unexpected error: {err:?}"
+			);
+		}
+
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
+	}
+
+	#[tokio::test]
+	async fn full_batches_are_sent_early_and_the_next_caller_opens_a_new_one() {
+		let calls = Arc::new(AtomicU32::new(0));
+		let fetch = counting_fetch(&calls, false);
+		let (first, second, third) = tokio::join!(
+			cache::coalesce("test:cap", texts(&["a"]), WINDOW, 2, fetch.clone()),
+			cache::coalesce("test:cap", texts(&["bb"]), WINDOW, 2, fetch.clone()),
+			cache::coalesce("test:cap", texts(&["ccc"]), WINDOW, 2, fetch),
+		);
+
+		assert_eq!(first.expect("Expected vectors."), vec![vec![1.0]]);
+		assert_eq!(second.expect("Expected vectors."), vec![vec![2.0]]);
+		assert_eq!(third.expect("Expected vectors."), vec![vec![3.0]]);
+		assert_eq!(calls.load(Ordering::SeqCst), 2);
+	}
+}
@@ -0,0 +1,216 @@
+//! Local sentence-transformer embedding backend for offline operation.
+//!
+//! Loads a BERT-family model from `model_dir` (`config.json`, `tokenizer.json`, and
+//! `model.safetensors`) and runs it on the CPU with candle. Embeddings are mean-pooled over the
+//! attention mask and L2-normalized, matching the usual sentence-transformer pipeline.
+
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex, OnceLock},
+};
+
+use candle_core::{DType, Device, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::bert::{BertModel, Config, DTYPE};
+use tokenizers::{PaddingParams, PaddingStrategy, Tokenizer, TruncationParams};
+
+use crate::{Error, Result};
+use elf_config::EmbeddingProviderConfig;
+
+const MAX_SEQUENCE_TOKENS: usize = 512;
+const BATCH_SIZE: usize = 32;
+
+static MODELS: OnceLock<Mutex<HashMap<PathBuf, Arc<LocalModel>>>> = OnceLock::new();
+
+struct LocalModel {
+	model: BertModel,
+	tokenizer: Tokenizer,
+	hidden_size: usize,
+	device: Device,
+}
+impl LocalModel {
+	fn load(dir: &Path) -> Result<Self> {
+		let device = Device::Cpu;
+		let raw_config: serde_json::Value = serde_json::from_slice(&read(dir, "config.json")?)?;
+		let hidden_size = raw_config
+			.get("hidden_size")
+			.and_then(|value| value.as_u64())
+			.ok_or_else(|| model_error(dir, "config.json is missing hidden_size"))?
+			as usize;
+		let config: Config = serde_json::from_value(raw_config)?;
+		let mut tokenizer = Tokenizer::from_file(dir.join("tokenizer.json"))
+			.map_err(|err| model_error(dir, err))?;
+
+		tokenizer.with_padding(Some(PaddingParams {
+			strategy: PaddingStrategy::BatchLongest,
+			..Default::default()
+		}));
+		tokenizer
+			.with_truncation(Some(TruncationParams {
+				max_length: MAX_SEQUENCE_TOKENS,
+				..Default::default()
+			}))
+			.map_err(|err| model_error(dir, err))?;
+
+		let weights = read(dir, "model.safetensors")?;
+		let vb = VarBuilder::from_buffered_safetensors(weights, DTYPE, &device)
+			.map_err(|err| model_error(dir, err))?;
+		let model = BertModel::load(vb, &config).map_err(|err| model_error(dir, err))?;
+
+		Ok(Self { model, tokenizer, hidden_size, device })
+	}
+
+	fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+		let encodings = self.tokenizer.encode_batch(texts.to_vec(), true).map_err(run_error)?;
+		let batch = encodings.len();
+		let seq_len = encodings.first().map(|encoding| encoding.len()).unwrap_or_default();
+		let mut ids = Vec::with_capacity(batch * seq_len);
+		let mut type_ids = Vec::with_capacity(batch * seq_len);
+		let mut mask = Vec::with_capacity(batch * seq_len);
+
+		for encoding in &encodings {
+			ids.extend_from_slice(encoding.get_ids());
+			type_ids.extend_from_slice(encoding.get_type_ids());
+			mask.extend_from_slice(encoding.get_attention_mask());
+		}
+
+		let input_ids = Tensor::from_vec(ids, (batch, seq_len), &self.device).map_err(run_error)?;
+		let token_type_ids =
+			Tensor::from_vec(type_ids, (batch, seq_len), &self.device).map_err(run_error)?;
+		let attention_mask =
+			Tensor::from_vec(mask.clone(), (batch, seq_len), &self.device).map_err(run_error)?;
+		let hidden: Vec<Vec<Vec<f32>>> = self
+			.model
+			.forward(&input_ids, &token_type_ids, Some(&attention_mask))
+			.and_then(|hidden| hidden.to_dtype(DType::F32))
+			.and_then(|hidden| hidden.to_vec3())
+			.map_err(run_error)?;
+		let mut out = Vec::with_capacity(batch);
+
+		for (row, tokens) in hidden.iter().enumerate() {
+			let mut vec = mean_pool(tokens, &mask[row * seq_len..(row + 1) * seq_len]);
+
+			super::l2_normalize(&mut vec);
+			out.push(vec);
+		}
+
+		Ok(out)
+	}
+}
+
+/// Embeds texts with the local model at `cfg.model_dir`.
+///
+/// The model is loaded on first use and cached per directory for the life of the process.
+/// Inference runs on the blocking thread pool so it does not stall the async runtime.
+pub async fn embed(cfg: &EmbeddingProviderConfig, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+	let Some(model_dir) = cfg.model_dir.as_deref().filter(|dir| !dir.trim().is_empty()) else {
+		return Err(Error::InvalidConfig {
+			message: "provider_id local_model requires model_dir.".to_string(),
+		});
+	};
+	let dir = PathBuf::from(model_dir);
+	let dimensions = cfg.dimensions as usize;
+	let texts = texts.to_vec();
+
+	tokio::task::spawn_blocking(move || {
+		let model = cached_model(&dir)?;
+
+		if model.hidden_size != dimensions {
+			return Err(Error::InvalidConfig {
+				message: format!(
+					"Local embedding model at {} produces {} dimensions but dimensions is {dimensions}.",
+					dir.display(),
+					model.hidden_size
+				),
+			});
+		}
+
+		let mut out = Vec::with_capacity(texts.len());
+
+		for batch in texts.chunks(BATCH_SIZE) {
+			out.extend(model.embed_batch(batch)?);
+		}
+
+		Ok(out)
+	})
+	.await
+	.map_err(run_error)?
+}
+
+fn cached_model(dir: &Path) -> Result<Arc<LocalModel>> {
+	let models = MODELS.get_or_init(|| Mutex::new(HashMap::new()));
+	let mut models = models.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+	if let Some(model) = models.get(dir) {
+		return Ok(model.clone());
+	}
+
+	let model = Arc::new(LocalModel::load(dir)?);
+
+	models.insert(dir.to_path_buf(), model.clone());
+
+	Ok(model)
+}
+
+fn read(dir: &Path, file: &str) -> Result<Vec<u8>> {
+	std::fs::read(dir.join(file)).map_err(|err| model_error(dir, format!("{file}: {err}")))
+}
+
+fn model_error(dir: &Path, err: impl std::fmt::Display) -> Error {
+	Error::LocalModel {
+		message: format!("Failed to load local embedding model from {}: {err}.", dir.display()),
+	}
+}
+
+fn run_error(err: impl std::fmt::Display) -> Error {
+	Error::LocalModel { message: format!("Local embedding model failed: {err}.") }
+}
+
+/// Averages token vectors whose attention mask is set.
+fn mean_pool(tokens: &[Vec<f32>], mask: &[u32]) -> Vec<f32> {
+	let dim = tokens.first().map(Vec::len).unwrap_or_default();
+	let mut sum = vec![0.0_f32; dim];
+	let mut count = 0.0_f32;
+
+	for (token, keep) in tokens.iter().zip(mask) {
+		if *keep == 0 {
+			continue;
+		}
+
+		for (acc, value) in sum.iter_mut().zip(token) {
+			*acc += value;
+		}
+
+		count += 1.0;
+	}
+
+	if count > 0.0 {
+		for value in sum.iter_mut() {
+			*value /= count;
+		}
+	}
+
+	sum
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::embedding::local;
+
+	#[test]
+	fn mean_pool_ignores_padding_tokens() {
+		let tokens = vec![vec![1.0, 3.0], vec![3.0, 5.0], vec![100.0, 100.0]];
+		let pooled = local::mean_pool(&tokens, &[1, 1, 0]);
+
+		assert_eq!(pooled, vec![2.0, 4.0]);
+	}
+
+	#[test]
+	fn missing_model_files_are_reported() {
+		let dir = std::env::temp_dir().join("elf-local-model-missing");
+		let err = local::cached_model(&dir).err().expect("Expected a load error.");
+
+		assert!(err.to_string().contains("config.json"), "Unexpected error: {err}");
+	}
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 /// Result alias for provider adapters.
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -22,6 +24,27 @@ pub enum Error {
 		/// Human-readable configuration error.
 		message: String,
 	},
+	/// Local embedding model failed to load or run.
+	#[error("{message}")]
+	LocalModel {
+		/// Human-readable model error.
+		message: String,
+	},
+	/// Provider circuit breaker is open, so the call was not attempted.
+	#[error("{message}")]
+	CircuitOpen {
+		/// Human-readable breaker state.
+		message: String,
+	},
+	/// Provider call exceeded its wall-clock budget across retries.
+	#[error("{message}")]
+	Timeout {
+		/// Human-readable timeout description.
+		message: String,
+	},
+	/// A provider call shared by coalesced callers failed with the wrapped error.
+	#[error(transparent)]
+	Coalesced(Arc<Error>),
 	/// Provider response shape was invalid.
 	#[error("{message}")]
 	InvalidResponse {
@@ -5,11 +5,20 @@ use std::time::Duration;
 use reqwest::Client;
 use serde_json::Value;
 
-use crate::{Error, Result};
+use crate::{Error, Result, resilience};
 use elf_config::LlmProviderConfig;
 
 /// Calls the configured extractor provider and returns parsed JSON content.
+///
+/// Transport failures are retried under `cfg.resilience`; unparseable content is re-requested up to
+/// three times within each attempt.
 pub async fn extract(cfg: &LlmProviderConfig, messages: &[Value]) -> Result<Value> {
+	let key = resilience::provider_key("llm_extractor", &cfg.api_base, &cfg.path, &cfg.model);
+
+	resilience::call(&key, cfg.resilience.as_ref(), || extract_remote(cfg, messages)).await
+}
+
+async fn extract_remote(cfg: &LlmProviderConfig, messages: &[Value]) -> Result<Value> {
 	let client = Client::builder().timeout(Duration::from_millis(cfg.timeout_ms)).build()?;
 	let url = format!("{}{}", cfg.api_base, cfg.path);
 
@@ -3,6 +3,7 @@
 pub mod embedding;
 pub mod extractor;
 pub mod rerank;
+pub mod resilience;
 
 mod error;
 
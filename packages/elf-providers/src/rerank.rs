@@ -1,26 +1,75 @@
 //! Rerank-provider client helpers.
 
+mod cohere;
 mod local;
 mod noise;
 mod response;
+mod voyage;
 
-use std::time::Duration;
+use std::{ops::Range, time::Duration};
 
+use futures::future;
 use reqwest::Client;
 use serde_json::Value;
 
-use crate::Result;
+use crate::{Result, resilience};
 use elf_config::ProviderConfig;
 
+/// Maximum number of rerank batches in flight at once when `max_docs_per_call` splits a request.
+const MAX_CONCURRENT_RERANK_BATCHES: usize = 4;
+
 /// Reranks documents with the configured provider or local fallback implementation.
+///
+/// `provider_id = "cohere"` and `provider_id = "voyage"` use the Cohere Rerank v3 and Voyage rerank
+/// schemas. Any other remote provider id uses the generic `{ model, query, documents }` shape.
+///
+/// When `max_docs_per_call` is set, remote requests are split into batches of at most that many
+/// documents. Up to [`MAX_CONCURRENT_RERANK_BATCHES`] batches run concurrently, and scores are
+/// returned in input order regardless of which batch finished first.
 pub async fn rerank(cfg: &ProviderConfig, query: &str, docs: &[String]) -> Result<Vec<f32>> {
 	if cfg.provider_id == "local" {
 		return Ok(local::local_rerank_dispatch(cfg.model.as_str(), query, docs));
 	}
+	if docs.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let key = resilience::provider_key("rerank", &cfg.api_base, &cfg.path, &cfg.model);
+	let batches = batch_ranges(docs.len(), cfg.max_docs_per_call);
+	let mut scores = Vec::with_capacity(docs.len());
+
+	for wave in batches.chunks(MAX_CONCURRENT_RERANK_BATCHES) {
+		let calls = wave.iter().map(|range| {
+			let batch = &docs[range.clone()];
+
+			resilience::call(&key, cfg.resilience.as_ref(), move || {
+				rerank_remote(cfg, query, batch)
+			})
+		});
+
+		for batch_scores in future::join_all(calls).await {
+			scores.extend(batch_scores?);
+		}
+	}
 
+	Ok(scores)
+}
+
+/// Splits `len` documents into consecutive ranges of at most `max_docs_per_call` documents.
+fn batch_ranges(len: usize, max_docs_per_call: Option<u32>) -> Vec<Range<usize>> {
+	let size = max_docs_per_call.map_or(len, |max| max as usize).max(1);
+
+	(0..len).step_by(size).map(|start| start..(start + size).min(len)).collect()
+}
+
+async fn rerank_remote(cfg: &ProviderConfig, query: &str, docs: &[String]) -> Result<Vec<f32>> {
 	let client = Client::builder().timeout(Duration::from_millis(cfg.timeout_ms)).build()?;
 	let url = format!("{}{}", cfg.api_base, cfg.path);
-	let body = serde_json::json!({ "model": cfg.model, "query": query, "documents": docs });
+	let body = match cfg.provider_id.as_str() {
+		"cohere" => cohere::request_body(&cfg.model, query, docs),
+		"voyage" => voyage::request_body(&cfg.model, query, docs),
+		_ => serde_json::json!({ "model": cfg.model, "query": query, "documents": docs }),
+	};
 	let res = client
 		.post(url)
 		.headers(crate::auth_headers(&cfg.api_key, &cfg.default_headers)?)
@@ -29,7 +78,11 @@ pub async fn rerank(cfg: &ProviderConfig, query: &str, docs: &[String]) -> Resul
 		.await?;
 	let json: Value = res.error_for_status()?.json().await?;
 
-	response::parse_rerank_response(json, docs.len())
+	match cfg.provider_id.as_str() {
+		"cohere" => cohere::parse_response(json, docs.len()),
+		"voyage" => voyage::parse_response(json, docs.len()),
+		_ => response::parse_rerank_response(json, docs.len()),
+	}
 }
 
 #[cfg(test)] mod tests;
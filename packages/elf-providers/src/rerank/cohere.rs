@@ -0,0 +1,25 @@
+//! Cohere Rerank v3 adapter (`POST /v2/rerank`).
+
+use serde_json::Value;
+
+use crate::{Error, Result, rerank::response};
+
+pub(super) fn request_body(model: &str, query: &str, docs: &[String]) -> Value {
+	// Cohere returns only the `top_n` best documents; ask for all of them so every document
+	// receives a score.
+	serde_json::json!({
+		"model": model,
+		"query": query,
+		"documents": docs,
+		"top_n": docs.len(),
+	})
+}
+
+pub(super) fn parse_response(json: Value, doc_count: usize) -> Result<Vec<f32>> {
+	let results =
+		json.get("results").and_then(|v| v.as_array()).ok_or_else(|| Error::InvalidResponse {
+			message: "Cohere rerank response is missing results array.".to_string(),
+		})?;
+
+	response::scores_by_index(results, doc_count)
+}
@@ -9,6 +9,14 @@ pub(super) fn parse_rerank_response(json: Value, doc_count: usize) -> Result<Vec
 				message: "Rerank response is missing results array.".to_string(),
 			},
 		)?;
+
+	scores_by_index(results, doc_count)
+}
+
+/// Aligns `{ index, relevance_score | score }` results to document order.
+///
+/// Documents without a result, such as those cut by a provider `top_n`, score zero.
+pub(super) fn scores_by_index(results: &[Value], doc_count: usize) -> Result<Vec<f32>> {
 	let mut scores = vec![0.0_f32; doc_count];
 
 	for item in results {
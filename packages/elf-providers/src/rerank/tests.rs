@@ -1,4 +1,4 @@
-use crate::rerank::{local, response};
+use crate::rerank::{self, cohere, local, response, voyage};
 
 #[test]
 fn aligns_scores_by_index() {
@@ -14,6 +14,54 @@ fn aligns_scores_by_index() {
 	assert_eq!(scores, vec![0.9, 0.2]);
 }
 
+#[test]
+fn cohere_requests_every_document_and_reads_results() {
+	let docs = [String::from("alpha"), String::from("beta"), String::from("gamma")];
+	let body = cohere::request_body("rerank-v3.5", "alpha", &docs);
+
+	assert_eq!(body["top_n"], 3);
+	assert_eq!(body["documents"][2], "gamma");
+
+	let json = serde_json::json!({
+		"id": "r-1",
+		"results": [
+			{ "index": 2, "relevance_score": 0.1 },
+			{ "index": 0, "relevance_score": 0.8 }
+		],
+		"meta": { "billed_units": { "search_units": 1 } }
+	});
+	let scores = cohere::parse_response(json, 3)
+		.expect("Cohere rerank parsing must succeed for the valid JSON fixture.");
+
+	assert_eq!(scores, vec![0.8, 0.0, 0.1]);
+	assert!(cohere::parse_response(serde_json::json!({ "data": [] }), 3).is_err());
+}
+
+#[test]
+fn voyage_requests_every_document_and_reads_data() {
+	let docs = [String::from("alpha"), String::from("beta")];
+	let body = voyage::request_body("rerank-2", "alpha", &docs);
+
+	assert_eq!(body["top_k"], 2);
+	assert_eq!(body["truncation"], true);
+	assert!(body.get("top_n").is_none());
+
+	let json = serde_json::json!({
+		"object": "list",
+		"data": [
+			{ "index": 1, "relevance_score": 0.3 },
+			{ "index": 0, "relevance_score": 0.7 }
+		],
+		"model": "rerank-2",
+		"usage": { "total_tokens": 12 }
+	});
+	let scores = voyage::parse_response(json, 2)
+		.expect("Voyage rerank parsing must succeed for the valid JSON fixture.");
+
+	assert_eq!(scores, vec![0.7, 0.3]);
+	assert!(voyage::parse_response(serde_json::json!({ "results": [] }), 2).is_err());
+}
+
 #[test]
 fn local_rerank_scores_match_token_overlap_fraction() {
 	let scores = local::local_rerank("alpha beta", &[String::from("alpha"), String::from("gamma")]);
@@ -56,3 +104,10 @@ fn local_rerank_noisy_varies_across_calls() {
 
 	assert!(varied, "Expected noisy rerank to vary across calls.");
 }
+
+#[test]
+fn batch_ranges_split_documents_in_order() {
+	assert_eq!(rerank::batch_ranges(5, None), vec![0..5]);
+	assert_eq!(rerank::batch_ranges(5, Some(2)), vec![0..2, 2..4, 4..5]);
+	assert_eq!(rerank::batch_ranges(4, Some(32)), vec![0..4]);
+}
@@ -0,0 +1,26 @@
+//! Voyage AI rerank adapter (`POST /v1/rerank`).
+
+use serde_json::Value;
+
+use crate::{Error, Result, rerank::response};
+
+pub(super) fn request_body(model: &str, query: &str, docs: &[String]) -> Value {
+	// Voyage names the result limit `top_k`; ask for every document so none is left unscored.
+	// `truncation` lets over-long snippets be cut instead of failing the whole request.
+	serde_json::json!({
+		"model": model,
+		"query": query,
+		"documents": docs,
+		"top_k": docs.len(),
+		"truncation": true,
+	})
+}
+
+pub(super) fn parse_response(json: Value, doc_count: usize) -> Result<Vec<f32>> {
+	let results =
+		json.get("data").and_then(|v| v.as_array()).ok_or_else(|| Error::InvalidResponse {
+			message: "Voyage rerank response is missing data array.".to_string(),
+		})?;
+
+	response::scores_by_index(results, doc_count)
+}
@@ -0,0 +1,357 @@
+//! Retry, backoff, and circuit-breaker wrapper shared by the provider adapters.
+//!
+//! Breaker state is kept per provider key for the life of the process, so every caller of the
+//! same provider endpoint and model sees the same health.
+
+use std::{
+	collections::HashMap,
+	future::Future,
+	sync::{Mutex, OnceLock},
+	time::{Duration, Instant},
+};
+
+use reqwest::StatusCode;
+
+use crate::{Error, Result};
+use elf_config::ProviderResilience;
+
+static BREAKERS: OnceLock<Mutex<HashMap<String, Breaker>>> = OnceLock::new();
+
+/// Circuit breaker state of one provider.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+	/// Calls pass through.
+	Closed,
+	/// Calls fail fast until the open window ends.
+	Open,
+	/// The open window ended; the next call is a trial that closes or reopens the breaker.
+	HalfOpen,
+}
+impl CircuitState {
+	/// Returns the stable wire name of the state.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Closed => "closed",
+			Self::Open => "open",
+			Self::HalfOpen => "half_open",
+		}
+	}
+}
+
+/// Point-in-time health of one provider.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderHealth {
+	/// Current breaker state.
+	pub state: CircuitState,
+	/// Failed calls since the last success.
+	pub consecutive_failures: u32,
+	/// Milliseconds left before an open breaker lets a trial call through.
+	pub open_remaining_ms: Option<u64>,
+}
+
+#[derive(Default)]
+struct Breaker {
+	consecutive_failures: u32,
+	opened_at: Option<Instant>,
+	trial_in_flight: bool,
+}
+impl Breaker {
+	fn state(&self, open_for: Duration, now: Instant) -> CircuitState {
+		match self.opened_at {
+			None => CircuitState::Closed,
+			Some(opened_at) if now.duration_since(opened_at) < open_for => CircuitState::Open,
+			Some(_) => CircuitState::HalfOpen,
+		}
+	}
+}
+
+/// Builds the breaker key for one provider endpoint and model.
+pub fn provider_key(kind: &str, api_base: &str, path: &str, model: &str) -> String {
+	format!("{kind}:{api_base}{path}:{model}")
+}
+
+/// Returns the health of the provider behind `key`.
+///
+/// Providers that were never called, or that run without a breaker, report a closed breaker.
+pub fn health(key: &str, policy: Option<&ProviderResilience>) -> ProviderHealth {
+	let breakers = lock_breakers();
+	let Some(breaker) = breakers.get(key) else {
+		return ProviderHealth {
+			state: CircuitState::Closed,
+			consecutive_failures: 0,
+			open_remaining_ms: None,
+		};
+	};
+	let open_for = Duration::from_millis(policy.map(|policy| policy.open_ms).unwrap_or_default());
+	let now = Instant::now();
+	let state = breaker.state(open_for, now);
+	let open_remaining_ms = breaker
+		.opened_at
+		.filter(|_| state == CircuitState::Open)
+		.map(|at| open_for.saturating_sub(now.duration_since(at)).as_millis() as u64);
+
+	ProviderHealth { state, consecutive_failures: breaker.consecutive_failures, open_remaining_ms }
+}
+
+/// Runs `op` under `policy`, retrying transient failures with exponential backoff.
+///
+/// Without a policy, `op` runs once. With a breaker configured, calls fail fast with
+/// [`Error::CircuitOpen`] while the breaker is open, and only one trial call runs once the open
+/// window ends.
+pub async fn call<T, F, Fut>(key: &str, policy: Option<&ProviderResilience>, mut op: F) -> Result<T>
+where
+	F: FnMut() -> Fut,
+	Fut: Future<Output = Result<T>>,
+{
+	let Some(policy) = policy else {
+		return op().await;
+	};
+	let admission = admit(key, policy)?;
+	let max_retries = if admission.trial { 0 } else { policy.max_retries };
+	let attempts = async {
+		let mut attempt = 0;
+
+		loop {
+			match op().await {
+				Ok(value) => return Ok(value),
+				Err(err) if attempt < max_retries && is_transient(&err) => {
+					tokio::time::sleep(backoff(policy, attempt)).await;
+
+					attempt += 1;
+				},
+				Err(err) => return Err(err),
+			}
+		}
+	};
+	let result = match policy.call_timeout_ms {
+		Some(timeout_ms) => tokio::time::timeout(Duration::from_millis(timeout_ms), attempts)
+			.await
+			.unwrap_or_else(|_| {
+				Err(Error::Timeout {
+					message: format!("Provider call exceeded call_timeout_ms of {timeout_ms}."),
+				})
+			}),
+		None => attempts.await,
+	};
+
+	admission.settle(result.is_ok());
+
+	result
+}
+
+/// One call let through the breaker.
+///
+/// A half-open trial that is dropped before it settles, such as when the caller's future is
+/// cancelled, counts as a failed trial so the breaker reopens instead of staying half-open.
+struct Admission<'a> {
+	key: &'a str,
+	policy: &'a ProviderResilience,
+	trial: bool,
+	settled: bool,
+}
+impl Admission<'_> {
+	fn settle(mut self, success: bool) {
+		self.settled = true;
+
+		record(self.key, self.policy, success);
+	}
+}
+impl Drop for Admission<'_> {
+	fn drop(&mut self) {
+		if self.trial && !self.settled {
+			record(self.key, self.policy, false);
+		}
+	}
+}
+
+/// Checks the breaker before a call and admits it, marking half-open trials.
+fn admit<'a>(key: &'a str, policy: &'a ProviderResilience) -> Result<Admission<'a>> {
+	let admission = |trial| Admission { key, policy, trial, settled: false };
+
+	if policy.failure_threshold == 0 {
+		return Ok(admission(false));
+	}
+
+	let mut breakers = lock_breakers();
+	let breaker = breakers.entry(key.to_string()).or_default();
+
+	match breaker.state(Duration::from_millis(policy.open_ms), Instant::now()) {
+		CircuitState::Closed => Ok(admission(false)),
+		CircuitState::HalfOpen if !breaker.trial_in_flight => {
+			breaker.trial_in_flight = true;
+
+			Ok(admission(true))
+		},
+		CircuitState::Open | CircuitState::HalfOpen => Err(Error::CircuitOpen {
+			message: format!(
+				"Provider circuit is open after {} consecutive failures.",
+				breaker.consecutive_failures
+			),
+		}),
+	}
+}
+
+fn record(key: &str, policy: &ProviderResilience, success: bool) {
+	if policy.failure_threshold == 0 {
+		return;
+	}
+
+	let mut breakers = lock_breakers();
+	let breaker = breakers.entry(key.to_string()).or_default();
+
+	breaker.trial_in_flight = false;
+
+	if success {
+		*breaker = Breaker::default();
+
+		return;
+	}
+
+	breaker.consecutive_failures = breaker.consecutive_failures.saturating_add(1);
+
+	if breaker.consecutive_failures >= policy.failure_threshold {
+		breaker.opened_at = Some(Instant::now());
+
+		tracing::warn!(
+			provider = key,
+			consecutive_failures = breaker.consecutive_failures,
+			open_ms = policy.open_ms,
+			"Provider circuit opened."
+		);
+	}
+}
+
+fn backoff(policy: &ProviderResilience, attempt: u32) -> Duration {
+	let delay = policy.initial_backoff_ms.saturating_mul(1_u64 << attempt.min(32));
+
+	Duration::from_millis(delay.min(policy.max_backoff_ms))
+}
+
+fn is_transient(err: &Error) -> bool {
+	match err {
+		Error::Reqwest(err) =>
+			err.is_timeout()
+				|| err.is_connect()
+				|| err.status().is_some_and(|status| {
+					status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+				}),
+		Error::Coalesced(err) => is_transient(err),
+		_ => false,
+	}
+}
+
+fn lock_breakers() -> std::sync::MutexGuard<'static, HashMap<String, Breaker>> {
+	BREAKERS
+		.get_or_init(|| Mutex::new(HashMap::new()))
+		.lock()
+		.unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		sync::atomic::{AtomicU32, Ordering},
+		time::Duration,
+	};
+
+	use crate::{
+		Error,
+		resilience::{self, CircuitState},
+	};
+	use elf_config::ProviderResilience;
+
+	fn policy(failure_threshold: u32) -> ProviderResilience {
+		ProviderResilience {
+			max_retries: 2,
+			initial_backoff_ms: 1,
+			max_backoff_ms: 2,
+			call_timeout_ms: None,
+			failure_threshold,
+			open_ms: 60_000,
+		}
+	}
+
+	#[test]
+	fn backoff_doubles_up_to_the_cap() {
+		let policy =
+			ProviderResilience { initial_backoff_ms: 100, max_backoff_ms: 350, ..policy(0) };
+
+		assert_eq!(resilience::backoff(&policy, 0).as_millis(), 100);
+		assert_eq!(resilience::backoff(&policy, 1).as_millis(), 200);
+		assert_eq!(resilience::backoff(&policy, 2).as_millis(), 350);
+	}
+
+	#[tokio::test]
+	async fn non_transient_failures_are_not_retried() {
+		let calls = AtomicU32::new(0);
+		let result: crate::Result<()> =
+			resilience::call("test:non_transient", Some(&policy(0)), || {
+				calls.fetch_add(1, Ordering::SeqCst);
+
+				async { Err(Error::InvalidResponse { message: "bad".to_string() }) }
+			})
+			.await;
+
+		assert!(result.is_err());
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
+	}
+
+	#[tokio::test]
+	async fn breaker_opens_after_consecutive_failures() {
+		let key = "test:breaker";
+		let policy = policy(2);
+		let calls = AtomicU32::new(0);
+
+		for _ in 0..2 {
+			let _ = resilience::call::<(), _, _>(key, Some(&policy), || {
+				calls.fetch_add(1, Ordering::SeqCst);
+
+				async { Err(Error::InvalidResponse { message: "bad".to_string() }) }
+			})
+			.await;
+		}
+
+		let err = resilience::call(key, Some(&policy), || async { Ok(()) })
+			.await
+			.expect_err("Expected the breaker to reject the call.");
+		let health = resilience::health(key, Some(&policy));
+
+		assert!(matches!(err, Error::CircuitOpen { .. }), "Unexpected error: {err}");
+		assert_eq!(calls.load(Ordering::SeqCst), 2);
+		assert_eq!(health.state, CircuitState::Open);
+		assert_eq!(health.consecutive_failures, 2);
+		assert!(health.open_remaining_ms.is_some());
+	}
+
+	#[tokio::test]
+	async fn dropped_trial_reopens_the_breaker_and_admits_the_next_trial() {
+		let key = "test:dropped_trial";
+		let policy = ProviderResilience { open_ms: 50, ..policy(2) };
+
+		for _ in 0..2 {
+			let _ = resilience::call::<(), _, _>(key, Some(&policy), || async {
+				Err(Error::InvalidResponse { message: "bad".to_string() })
+			})
+			.await;
+		}
+
+		tokio::time::sleep(Duration::from_millis(60)).await;
+
+		let trial = resilience::call::<(), _, _>(key, Some(&policy), || async {
+			tokio::time::sleep(Duration::from_secs(60)).await;
+
+			Ok(())
+		});
+
+		assert!(tokio::time::timeout(Duration::from_millis(10), trial).await.is_err());
+		assert_eq!(resilience::health(key, Some(&policy)).state, CircuitState::Open);
+
+		tokio::time::sleep(Duration::from_millis(60)).await;
+
+		resilience::call(key, Some(&policy), || async { Ok(()) })
+			.await
+			.expect("Expected the next trial to be admitted.");
+
+		assert_eq!(resilience::health(key, Some(&policy)).state, CircuitState::Closed);
+	}
+}
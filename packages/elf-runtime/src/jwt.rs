@@ -0,0 +1,390 @@
+//! Bearer JWT validation against a cached OIDC JSON Web Key Set.
+
+use std::{
+	str::FromStr,
+	time::{Duration, Instant},
+};
+
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, jwk::JwkSet};
+use serde_json::{Map, Value};
+use tokio::sync::RwLock;
+
+use elf_config::{SecurityAuthRole, SecurityJwt};
+
+/// Shortest gap between key set fetches triggered by an unknown `kid`, so forged key IDs cannot
+/// turn every request into a JWKS fetch.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+const JWKS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// JWT validation failures.
+#[derive(Debug, thiserror::Error)]
+pub enum JwtError {
+	/// The `security.jwt` settings cannot build a verifier.
+	#[error("Invalid JWT configuration: {0}")]
+	Config(String),
+	/// The token is malformed, unsigned by a known key, expired, or lacks a mapped claim.
+	#[error("Invalid token: {0}")]
+	InvalidToken(String),
+	/// The key set could not be fetched or parsed.
+	#[error("JWKS unavailable: {0}")]
+	Jwks(String),
+}
+
+/// Request context carried by a validated token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JwtIdentity {
+	/// Token subject, when the `sub` claim is present.
+	pub subject: Option<String>,
+	/// Tenant identifier claim.
+	pub tenant_id: String,
+	/// Project identifier claim.
+	pub project_id: String,
+	/// Agent identifier claim.
+	pub agent_id: String,
+	/// Read profile claim.
+	pub read_profile: String,
+	/// Role claim, defaulting to [`SecurityAuthRole::User`].
+	pub role: SecurityAuthRole,
+}
+impl JwtIdentity {
+	/// Stable identifier recorded as the request's token ID.
+	pub fn token_id(&self) -> String {
+		match self.subject.as_deref() {
+			Some(subject) => format!("jwt:{subject}"),
+			None => "jwt".to_string(),
+		}
+	}
+}
+
+struct CachedJwks {
+	keys: JwkSet,
+	fetched_at: Instant,
+}
+
+/// Validates bearer JWTs with keys fetched from `security.jwt.jwks_url`.
+///
+/// The key set is cached for `jwks_cache_seconds` and refetched early when a token names a key ID
+/// the cache does not hold, such as after the provider rotates keys.
+pub struct JwtVerifier {
+	cfg: SecurityJwt,
+	algorithms: Vec<Algorithm>,
+	client: reqwest::Client,
+	cache: RwLock<Option<CachedJwks>>,
+}
+impl std::fmt::Debug for JwtVerifier {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("JwtVerifier")
+			.field("jwks_url", &self.cfg.jwks_url)
+			.field("issuer", &self.cfg.issuer)
+			.finish_non_exhaustive()
+	}
+}
+impl JwtVerifier {
+	/// Builds a verifier for validated `security.jwt` settings.
+	pub fn new(cfg: &SecurityJwt) -> Result<Self, JwtError> {
+		let algorithms = cfg
+			.algorithms
+			.iter()
+			.map(|name| {
+				Algorithm::from_str(name.trim())
+					.map_err(|_| JwtError::Config(format!("Unsupported JWT algorithm {name}.")))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		let client = reqwest::Client::builder()
+			.timeout(JWKS_TIMEOUT)
+			.build()
+			.map_err(|err| JwtError::Config(err.to_string()))?;
+
+		Ok(Self { cfg: cfg.clone(), algorithms, client, cache: RwLock::new(None) })
+	}
+
+	/// Validates `token` and maps its claims onto a request identity.
+	pub async fn verify(&self, token: &str) -> Result<JwtIdentity, JwtError> {
+		let header = jsonwebtoken::decode_header(token)
+			.map_err(|err| JwtError::InvalidToken(err.to_string()))?;
+
+		if !self.algorithms.contains(&header.alg) {
+			return Err(JwtError::InvalidToken(format!(
+				"Algorithm {:?} is not accepted.",
+				header.alg
+			)));
+		}
+
+		let key = self.decoding_key(header.kid.as_deref()).await?;
+		let mut validation = Validation::new(header.alg);
+
+		// jsonwebtoken rejects a key when any listed algorithm is of another family, so a mixed
+		// `algorithms` list must narrow to the header's algorithm, already checked above.
+		validation.algorithms = vec![header.alg];
+		validation.leeway = self.cfg.leeway_seconds;
+		validation.validate_nbf = true;
+		validation.set_issuer(&[self.cfg.issuer.trim()]);
+		validation.set_audience(&[self.cfg.audience.trim()]);
+
+		let claims = jsonwebtoken::decode::<Map<String, Value>>(token, &key, &validation)
+			.map_err(|err| JwtError::InvalidToken(err.to_string()))?
+			.claims;
+
+		self.identity(&claims)
+	}
+
+	fn identity(&self, claims: &Map<String, Value>) -> Result<JwtIdentity, JwtError> {
+		let names = &self.cfg.claims;
+		let role = match names.role.as_deref() {
+			Some(name) => match claims.get(name).and_then(Value::as_str).map(str::trim) {
+				None | Some("user") => SecurityAuthRole::User,
+				Some("reader") => SecurityAuthRole::Reader,
+				Some("admin") => SecurityAuthRole::Admin,
+				Some(other) =>
+					return Err(JwtError::InvalidToken(format!(
+						"Claim {name} has unsupported role {other}."
+					))),
+			},
+			None => SecurityAuthRole::User,
+		};
+
+		Ok(JwtIdentity {
+			subject: claims.get("sub").and_then(Value::as_str).map(ToString::to_string),
+			tenant_id: required_claim(claims, &names.tenant_id)?,
+			project_id: required_claim(claims, &names.project_id)?,
+			agent_id: required_claim(claims, &names.agent_id)?,
+			read_profile: required_claim(claims, &names.read_profile)?,
+			role,
+		})
+	}
+
+	async fn decoding_key(&self, kid: Option<&str>) -> Result<DecodingKey, JwtError> {
+		let ttl = Duration::from_secs(self.cfg.jwks_cache_seconds);
+
+		{
+			let cache = self.cache.read().await;
+
+			if let Some(cached) = cache.as_ref() {
+				let fresh = cached.fetched_at.elapsed() < ttl;
+				let throttled = cached.fetched_at.elapsed() < MIN_REFRESH_INTERVAL;
+
+				match find_key(&cached.keys, kid) {
+					Some(key) if fresh => return key,
+					None if throttled => return Err(unknown_key(kid)),
+					_ => {},
+				}
+			}
+		}
+
+		let mut cache = self.cache.write().await;
+
+		// Another request may have refreshed the set while this one waited for the lock.
+		if let Some(cached) = cache.as_ref()
+			&& cached.fetched_at.elapsed() < MIN_REFRESH_INTERVAL
+		{
+			return find_key(&cached.keys, kid).unwrap_or_else(|| Err(unknown_key(kid)));
+		}
+
+		let keys = self.fetch_jwks().await?;
+		let key = find_key(&keys, kid).unwrap_or_else(|| Err(unknown_key(kid)));
+
+		*cache = Some(CachedJwks { keys, fetched_at: Instant::now() });
+
+		key
+	}
+
+	async fn fetch_jwks(&self) -> Result<JwkSet, JwtError> {
+		let response = self
+			.client
+			.get(self.cfg.jwks_url.trim())
+			.send()
+			.await
+			.and_then(reqwest::Response::error_for_status)
+			.map_err(|err| JwtError::Jwks(err.to_string()))?;
+
+		response.json::<JwkSet>().await.map_err(|err| JwtError::Jwks(err.to_string()))
+	}
+}
+
+fn find_key(keys: &JwkSet, kid: Option<&str>) -> Option<Result<DecodingKey, JwtError>> {
+	let jwk = match kid {
+		Some(kid) => keys.find(kid)?,
+		None if keys.keys.len() == 1 => keys.keys.first()?,
+		None => return None,
+	};
+
+	Some(DecodingKey::from_jwk(jwk).map_err(|err| JwtError::Jwks(err.to_string())))
+}
+
+fn unknown_key(kid: Option<&str>) -> JwtError {
+	match kid {
+		Some(kid) => JwtError::InvalidToken(format!("No JWKS key matches kid {kid}.")),
+		None =>
+			JwtError::InvalidToken("Token has no kid and the JWKS holds several keys.".to_string()),
+	}
+}
+
+fn required_claim(claims: &Map<String, Value>, name: &str) -> Result<String, JwtError> {
+	claims
+		.get(name)
+		.and_then(Value::as_str)
+		.map(str::trim)
+		.filter(|value| !value.is_empty())
+		.map(ToString::to_string)
+		.ok_or_else(|| JwtError::InvalidToken(format!("Token is missing the {name} claim.")))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{SystemTime, UNIX_EPOCH};
+
+	use axum::{Json, Router, routing};
+	use jsonwebtoken::{
+		Algorithm, EncodingKey, Header,
+		jwk::{Jwk, JwkSet},
+	};
+	use serde_json::{Map, Value, json};
+	use tokio::net::TcpListener;
+
+	use crate::jwt::{JwtError, JwtVerifier};
+	use elf_config::{SecurityAuthRole, SecurityJwt, SecurityJwtClaims};
+
+	fn verifier() -> JwtVerifier {
+		verifier_for("https://issuer.example/.well-known/jwks.json")
+	}
+
+	fn verifier_for(jwks_url: &str) -> JwtVerifier {
+		JwtVerifier::new(&SecurityJwt {
+			jwks_url: jwks_url.to_string(),
+			issuer: "https://issuer.example/".to_string(),
+			audience: "elf".to_string(),
+			algorithms: vec!["RS256".to_string(), "ES256".to_string()],
+			jwks_cache_seconds: 300,
+			leeway_seconds: 30,
+			claims: SecurityJwtClaims {
+				tenant_id: "elf_tenant".to_string(),
+				project_id: "elf_project".to_string(),
+				agent_id: "elf_agent".to_string(),
+				read_profile: "elf_read_profile".to_string(),
+				role: Some("elf_role".to_string()),
+			},
+		})
+		.expect("Expected verifier.")
+	}
+
+	fn claims(value: Value) -> Map<String, Value> {
+		value.as_object().cloned().expect("Expected claims object.")
+	}
+
+	#[test]
+	fn maps_configured_claims_onto_identity() {
+		let identity = verifier()
+			.identity(&claims(json!({
+				"sub": "user-1",
+				"elf_tenant": "t",
+				"elf_project": "p",
+				"elf_agent": "a",
+				"elf_read_profile": "private_plus_project",
+				"elf_role": "admin",
+			})))
+			.expect("Expected identity.");
+
+		assert_eq!(identity.tenant_id, "t");
+		assert_eq!(identity.read_profile, "private_plus_project");
+		assert_eq!(identity.role, SecurityAuthRole::Admin);
+		assert_eq!(identity.token_id(), "jwt:user-1");
+	}
+
+	#[test]
+	fn rejects_missing_context_claims_and_unknown_roles() {
+		let missing = verifier().identity(&claims(json!({
+			"elf_tenant": "t",
+			"elf_project": "p",
+			"elf_read_profile": "private_plus_project",
+		})));
+
+		assert!(
+			matches!(missing, Err(JwtError::InvalidToken(reason)) if reason.contains("elf_agent"))
+		);
+
+		let super_admin = verifier().identity(&claims(json!({
+			"elf_tenant": "t",
+			"elf_project": "p",
+			"elf_agent": "a",
+			"elf_read_profile": "private_plus_project",
+			"elf_role": "super_admin",
+		})));
+
+		assert!(matches!(super_admin, Err(JwtError::InvalidToken(_))));
+	}
+
+	#[tokio::test]
+	async fn verifies_rsa_and_ec_tokens_under_a_mixed_algorithm_list() {
+		let rsa =
+			EncodingKey::from_rsa_der(include_bytes!("../fixtures/jwt/rsa_private_pkcs1.der"));
+		let ec =
+			EncodingKey::from_ec_der(include_bytes!("../fixtures/jwt/ec_p256_private_pkcs8.der"));
+		let signers = [("rsa-key", Algorithm::RS256, &rsa), ("ec-key", Algorithm::ES256, &ec)];
+		let keys = signers
+			.iter()
+			.map(|(kid, alg, key)| {
+				let mut jwk = Jwk::from_encoding_key(key, *alg).expect("Expected public JWK.");
+
+				jwk.common.key_id = Some(kid.to_string());
+
+				jwk
+			})
+			.collect();
+		let verifier = verifier_for(&spawn_jwks_server(JwkSet { keys }).await);
+		let exp =
+			SystemTime::now().duration_since(UNIX_EPOCH).expect("Expected clock.").as_secs() + 300;
+
+		for (kid, alg, key) in signers {
+			let mut header = Header::new(alg);
+
+			header.kid = Some(kid.to_string());
+
+			let token = jsonwebtoken::encode(
+				&header,
+				&json!({
+					"sub": kid,
+					"iss": "https://issuer.example/",
+					"aud": "elf",
+					"exp": exp,
+					"elf_tenant": "t",
+					"elf_project": "p",
+					"elf_agent": "a",
+					"elf_read_profile": "private_only",
+				}),
+				key,
+			)
+			.expect("Expected signed token.");
+			let identity = verifier.verify(&token).await.expect("Expected token to verify.");
+
+			assert_eq!(identity.subject.as_deref(), Some(kid));
+			assert_eq!(identity.role, SecurityAuthRole::User);
+		}
+	}
+
+	async fn spawn_jwks_server(keys: JwkSet) -> String {
+		let app = Router::new().route(
+			"/.well-known/jwks.json",
+			routing::get(move || {
+				let keys = keys.clone();
+
+				async move { Json(keys) }
+			}),
+		);
+		let listener = match TcpListener::bind("127.0.0.1:0").await {
+			Ok(listener) => listener,
+			Err(err) => panic!("Failed to bind JWKS server: {err}."),
+		};
+		let addr = match listener.local_addr() {
+			Ok(addr) => addr,
+			Err(err) => panic!("Failed to read JWKS server address: {err}."),
+		};
+
+		tokio::spawn(async move {
+			if let Err(err) = axum::serve(listener, app).await {
+				panic!("JWKS server failed: {err}.");
+			}
+		});
+
+		format!("http://{addr}/.well-known/jwks.json")
+	}
+}
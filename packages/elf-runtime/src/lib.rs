@@ -0,0 +1,4 @@
+//! Process runtime support shared by the ELF binaries: bearer JWT verification and tracing setup.
+
+pub mod jwt;
+pub mod telemetry;
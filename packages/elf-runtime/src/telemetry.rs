@@ -0,0 +1,83 @@
+//! Tracing subscriber setup with optional OpenTelemetry span export.
+
+use std::time::Duration;
+
+use opentelemetry::{global, trace::TracerProvider as _};
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+	Resource,
+	propagation::TraceContextPropagator,
+	trace::{Sampler, SdkTracerProvider},
+};
+use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+
+use elf_config::{ConfigLint, Service};
+
+/// Flushes and shuts down the OpenTelemetry exporter when dropped.
+///
+/// Keep the guard alive for the life of the process so buffered spans are exported on exit.
+#[must_use]
+pub struct TelemetryGuard {
+	provider: Option<SdkTracerProvider>,
+}
+impl Drop for TelemetryGuard {
+	fn drop(&mut self) {
+		if let Some(provider) = self.provider.take()
+			&& let Err(err) = provider.shutdown()
+		{
+			eprintln!("Failed to shut down OpenTelemetry exporter: {err}.");
+		}
+	}
+}
+
+/// Installs the global tracing subscriber for `service_name`.
+///
+/// Logs go to stdout filtered by `service.log_level`. When `service.otel` is enabled, spans that
+/// pass the same filter are also exported over OTLP/HTTP, and W3C trace context is installed as
+/// the global propagator so incoming `traceparent` headers can parent request spans.
+pub fn init(
+	service: &Service,
+	service_name: &'static str,
+) -> Result<TelemetryGuard, ExporterBuildError> {
+	let filter = EnvFilter::try_new(&service.log_level).unwrap_or_else(|_| EnvFilter::new("info"));
+	let Some(otel) = service.otel.as_ref().filter(|otel| otel.enabled) else {
+		tracing_subscriber::fmt().with_env_filter(filter).init();
+
+		return Ok(TelemetryGuard { provider: None });
+	};
+	let exporter = SpanExporter::builder()
+		.with_http()
+		.with_endpoint(otel.endpoint.clone())
+		.with_timeout(Duration::from_millis(otel.timeout_ms))
+		.build()?;
+	let provider = SdkTracerProvider::builder()
+		.with_batch_exporter(exporter)
+		.with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(otel.sample_ratio))))
+		.with_resource(Resource::builder().with_service_name(service_name).build())
+		.build();
+
+	global::set_text_map_propagator(TraceContextPropagator::new());
+	tracing_subscriber::registry()
+		.with(filter)
+		.with(tracing_subscriber::fmt::layer())
+		.with(tracing_opentelemetry::layer().with_tracer(provider.tracer(service_name)))
+		.init();
+
+	Ok(TelemetryGuard { provider: Some(provider) })
+}
+
+/// Logs each config lint at warn level, as the binaries do on startup.
+pub fn log_lints(lints: &[ConfigLint]) {
+	for lint in lints {
+		tracing::warn!(kind = lint.kind.as_str(), key = %lint.key, "{lint}");
+	}
+}
+
+/// Prints each lint with its hint and a summary line, as `--check-config` reports them.
+pub fn print_lints(lints: &[ConfigLint]) {
+	for lint in lints {
+		println!("{lint}");
+	}
+
+	println!("Config is valid with {} warning(s).", lints.len());
+}
@@ -0,0 +1,13 @@
+#![cfg_attr(test, allow(unused_crate_dependencies))]
+
+//! In-memory test double for the public `ElfService` API.
+//!
+//! Applications that embed ELF can unit test against [`InMemoryElfService`] without Postgres or
+//! Qdrant. It accepts and returns the same request and response types as `elf_service`, keeps
+//! notes and traces in process memory, and ranks search results with a naive lexical score.
+
+mod notes;
+mod search;
+mod service;
+
+pub use self::service::InMemoryElfService;
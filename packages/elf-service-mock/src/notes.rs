@@ -0,0 +1,598 @@
+use serde_json::{Map, Value};
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::service::{
+	self, AGENT_PRIVATE, ALL_SCOPES, InMemoryElfService, ORG_SHARED, StoredNote, require_context,
+};
+use elf_domain::{
+	english_gate,
+	memory_policy::MemoryPolicyDecision,
+	writegate::{self, WritePolicyAudit, WritePolicyError},
+};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, AddNoteResponse, AddNoteResult, DeleteRequest, DeleteResponse,
+	Error, ListItem, ListRequest, ListResponse, ListTrashedRequest, ListTrashedResponse,
+	NoteFetchRequest, NoteFetchResponse, NoteMergeStrategy, NoteOp, NotesMergeRequest,
+	NotesMergeResponse, PinNoteRequest, PinNoteResponse, Result, TRASHED_STATUS, TrashedNote,
+	UndeleteRequest, UndeleteResponse, UpdateRequest, UpdateResponse, structured_fields,
+};
+
+const NOTE_TYPES: [&str; 7] =
+	["preference", "constraint", "decision", "profile", "fact", "plan", "qa"];
+
+impl InMemoryElfService {
+	/// Validates and stores notes, updating an existing note when its `key` matches.
+	pub async fn add_note(&self, req: AddNoteRequest) -> Result<AddNoteResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+		let scope = req.scope.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		let mut state = self.state();
+		let mut results = Vec::with_capacity(req.notes.len());
+
+		for note in req.notes {
+			if let Some(reason_code) = rejection_reason(scope, &note) {
+				results.push(rejected(reason_code));
+
+				continue;
+			}
+
+			let (text, write_policy_audit) = apply_write_policy(&note)?;
+			let expires_at =
+				note.ttl_days.filter(|days| *days > 0).map(|days| now + Duration::days(days));
+			let existing = note.key.as_deref().and_then(|key| {
+				state.notes.iter_mut().find(|stored| {
+					stored.status == "active"
+						&& stored.tenant_id == tenant_id
+						&& stored.project_id == project_id
+						&& stored.agent_id == agent_id
+						&& stored.scope == scope
+						&& stored.r#type == note.r#type
+						&& stored.key.as_deref() == Some(key)
+				})
+			});
+
+			if let Some(stored) = existing {
+				let unchanged = stored.text == text
+					&& stored.importance == note.importance
+					&& stored.confidence == note.confidence;
+
+				if !unchanged {
+					stored.text = text;
+					stored.structured = note.structured;
+					stored.importance = note.importance;
+					stored.confidence = note.confidence;
+					stored.expires_at = expires_at;
+					stored.source_ref = note.source_ref;
+					stored.updated_at = now;
+				}
+
+				results.push(AddNoteResult {
+					note_id: Some(stored.note_id),
+					op: if unchanged { NoteOp::None } else { NoteOp::Update },
+					policy_decision: MemoryPolicyDecision::Update,
+					reason_code: None,
+					field_path: None,
+					write_policy_audit,
+					reject_feedback: None,
+				});
+
+				continue;
+			}
+
+			let note_id = Uuid::new_v4();
+
+			state.notes.push(StoredNote {
+				note_id,
+				tenant_id: tenant_id.to_string(),
+				project_id: project_id.to_string(),
+				agent_id: agent_id.to_string(),
+				scope: scope.to_string(),
+				r#type: note.r#type,
+				key: note.key,
+				text,
+				structured: note.structured,
+				importance: note.importance,
+				confidence: note.confidence,
+				status: "active".to_string(),
+				updated_at: now,
+				expires_at,
+				source_ref: note.source_ref,
+				pinned: false,
+			});
+			results.push(AddNoteResult {
+				note_id: Some(note_id),
+				op: NoteOp::Add,
+				policy_decision: MemoryPolicyDecision::Remember,
+				reason_code: None,
+				field_path: None,
+				write_policy_audit,
+				reject_feedback: None,
+			});
+		}
+
+		Ok(AddNoteResponse { results })
+	}
+
+	/// Fetches one note when it is visible to the caller.
+	pub async fn get_note(&self, req: NoteFetchRequest) -> Result<NoteFetchResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		let all_scopes = ALL_SCOPES.map(str::to_string);
+		let state = self.state();
+		let note = state
+			.notes
+			.iter()
+			.find(|note| {
+				note.note_id == req.note_id
+					&& note.readable_by(tenant_id, project_id, agent_id, &all_scopes, now)
+			})
+			.ok_or_else(service::note_not_found)?;
+
+		Ok(NoteFetchResponse {
+			note_id: note.note_id,
+			tenant_id: note.tenant_id.clone(),
+			project_id: note.project_id.clone(),
+			agent_id: note.agent_id.clone(),
+			scope: note.scope.clone(),
+			r#type: note.r#type.clone(),
+			key: note.key.clone(),
+			text: note.text.clone(),
+			importance: note.importance,
+			confidence: note.confidence,
+			status: note.status.clone(),
+			updated_at: note.updated_at,
+			expires_at: note.expires_at,
+			source_ref: note.source_ref.clone(),
+			structured: note.structured.clone(),
+			access_stats: None,
+		})
+	}
+
+	/// Lists notes visible to the caller under the requested filters, newest first.
+	pub async fn list(&self, req: ListRequest) -> Result<ListResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.as_deref().map(str::trim).unwrap_or("");
+		let scope = req.scope.as_deref().map(str::trim);
+		let status = req
+			.status
+			.as_deref()
+			.map(str::trim)
+			.filter(|value| !value.is_empty())
+			.unwrap_or("active");
+
+		if tenant_id.is_empty() || project_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id and project_id are required.".to_string(),
+			});
+		}
+		if scope.is_some_and(|scope| !ALL_SCOPES.contains(&scope)) {
+			return Err(Error::ScopeDenied { message: "Scope is not allowed.".to_string() });
+		}
+		if scope == Some(AGENT_PRIVATE) && agent_id.is_empty() {
+			return Err(Error::ScopeDenied {
+				message: "agent_id is required for agent_private scope.".to_string(),
+			});
+		}
+
+		let state = self.state();
+		let mut notes = state
+			.notes
+			.iter()
+			.filter(|note| {
+				note.tenant_id == tenant_id
+					&& (note.project_id == project_id || note.scope == ORG_SHARED)
+					&& note.status.eq_ignore_ascii_case(status)
+					&& !(status == "active" && note.is_expired(now))
+					&& scope.is_none_or(|scope| note.scope == scope)
+					&& req.r#type.as_deref().is_none_or(|note_type| note.r#type == note_type)
+					&& (note.scope != AGENT_PRIVATE || note.agent_id == agent_id)
+			})
+			.collect::<Vec<_>>();
+
+		notes.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(a.note_id.cmp(&b.note_id)));
+
+		let items = notes
+			.into_iter()
+			.map(|note| ListItem {
+				note_id: note.note_id,
+				r#type: note.r#type.clone(),
+				key: note.key.clone(),
+				scope: note.scope.clone(),
+				status: note.status.clone(),
+				text: note.text.clone(),
+				importance: note.importance,
+				confidence: note.confidence,
+				updated_at: note.updated_at,
+				expires_at: note.expires_at,
+				source_ref: note.source_ref.clone(),
+			})
+			.collect();
+
+		Ok(ListResponse { items })
+	}
+
+	/// Updates text, scores, or TTL of a note owned by the caller.
+	pub async fn update(&self, req: UpdateRequest) -> Result<UpdateResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		if req.text.is_none()
+			&& req.importance.is_none()
+			&& req.confidence.is_none()
+			&& req.ttl_days.is_none()
+		{
+			return Err(Error::InvalidRequest { message: "No updates provided.".to_string() });
+		}
+		if req.text.as_deref().is_some_and(|text| !english_gate::is_english_natural_language(text))
+		{
+			return Err(Error::NonEnglishInput { field: "$.text".to_string() });
+		}
+
+		let mut state = self.state();
+		let note = find_owned_note(&mut state.notes, req.note_id, tenant_id, project_id, agent_id)?;
+
+		if note.status != "active" || note.is_expired(now) {
+			return Err(Error::InvalidRequest { message: "Note is not active.".to_string() });
+		}
+
+		let next_text = req.text.unwrap_or_else(|| note.text.clone());
+		let next_importance = req.importance.unwrap_or(note.importance);
+		let next_confidence = req.confidence.unwrap_or(note.confidence);
+		let next_expires_at = match req.ttl_days {
+			Some(days) if days > 0 => Some(now + Duration::days(days)),
+			Some(_) => None,
+			None => note.expires_at,
+		};
+		let changed = next_text != note.text
+			|| (next_importance - note.importance).abs() > f32::EPSILON
+			|| (next_confidence - note.confidence).abs() > f32::EPSILON
+			|| next_expires_at != note.expires_at;
+
+		if !changed {
+			return Ok(UpdateResponse {
+				note_id: note.note_id,
+				op: NoteOp::None,
+				reason_code: None,
+				reject_feedback: None,
+			});
+		}
+
+		note.text = next_text;
+		note.importance = next_importance;
+		note.confidence = next_confidence;
+		note.expires_at = next_expires_at;
+		note.updated_at = now;
+
+		Ok(UpdateResponse {
+			note_id: note.note_id,
+			op: NoteOp::Update,
+			reason_code: None,
+			reject_feedback: None,
+		})
+	}
+
+	/// Moves one note owned by the caller into the trash.
+	pub async fn delete(&self, req: DeleteRequest) -> Result<DeleteResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		let mut state = self.state();
+		let note = find_owned_note(&mut state.notes, req.note_id, tenant_id, project_id, agent_id)?;
+
+		if note.status == TRASHED_STATUS || note.status == "deleted" {
+			return Ok(DeleteResponse { note_id: note.note_id, op: NoteOp::None });
+		}
+
+		note.status = TRASHED_STATUS.to_string();
+		note.updated_at = now;
+
+		Ok(DeleteResponse { note_id: note.note_id, op: NoteOp::Delete })
+	}
+
+	/// Restores a trashed note owned by the caller.
+	pub async fn undelete(&self, req: UndeleteRequest) -> Result<UndeleteResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		let mut state = self.state();
+		let note = find_owned_note(&mut state.notes, req.note_id, tenant_id, project_id, agent_id)?;
+
+		if note.status == "active" {
+			return Ok(UndeleteResponse { note_id: note.note_id, op: NoteOp::None });
+		}
+		if note.status != TRASHED_STATUS {
+			return Err(Error::Conflict { message: "Note is not in the trash.".to_string() });
+		}
+
+		let restored = note.clone();
+		let key_taken = restored.key.is_some()
+			&& state.notes.iter().any(|other| {
+				other.status == "active"
+					&& other.tenant_id == restored.tenant_id
+					&& other.project_id == restored.project_id
+					&& other.agent_id == restored.agent_id
+					&& other.scope == restored.scope
+					&& other.r#type == restored.r#type
+					&& other.key == restored.key
+			});
+
+		if key_taken {
+			return Err(Error::Conflict {
+				message: "An active note already uses this key.".to_string(),
+			});
+		}
+
+		let note = find_owned_note(&mut state.notes, req.note_id, tenant_id, project_id, agent_id)?;
+
+		note.status = "active".to_string();
+		note.updated_at = now;
+
+		Ok(UndeleteResponse { note_id: note.note_id, op: NoteOp::Update })
+	}
+
+	/// Lists the caller's trashed notes, most recently trashed first.
+	pub async fn list_trashed(&self, req: ListTrashedRequest) -> Result<ListTrashedResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		let retention_days = elf_config::DEFAULT_PURGE_TRASHED_AFTER_DAYS;
+		let state = self.state();
+		let mut notes = state
+			.notes
+			.iter()
+			.filter(|note| {
+				note.tenant_id == tenant_id
+					&& (note.project_id == project_id || note.scope == ORG_SHARED)
+					&& note.agent_id == agent_id
+					&& note.status == TRASHED_STATUS
+			})
+			.collect::<Vec<_>>();
+
+		notes.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(a.note_id.cmp(&b.note_id)));
+
+		let items = notes
+			.into_iter()
+			.map(|note| TrashedNote {
+				note_id: note.note_id,
+				r#type: note.r#type.clone(),
+				key: note.key.clone(),
+				scope: note.scope.clone(),
+				text: note.text.clone(),
+				trashed_at: note.updated_at,
+				purge_after: note.updated_at + Duration::days(retention_days),
+			})
+			.collect();
+
+		Ok(ListTrashedResponse { retention_days, items })
+	}
+
+	/// Pins an active note owned by the caller so search always includes it.
+	pub async fn pin_note(&self, req: PinNoteRequest) -> Result<PinNoteResponse> {
+		self.set_note_pinned(req, true)
+	}
+
+	/// Unpins a note owned by the caller.
+	pub async fn unpin_note(&self, req: PinNoteRequest) -> Result<PinNoteResponse> {
+		self.set_note_pinned(req, false)
+	}
+
+	fn set_note_pinned(&self, req: PinNoteRequest, pinned: bool) -> Result<PinNoteResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		let mut state = self.state();
+		let note = find_owned_note(&mut state.notes, req.note_id, tenant_id, project_id, agent_id)?;
+
+		if pinned && note.status != "active" {
+			return Err(Error::Conflict {
+				message: "Only active notes can be pinned.".to_string(),
+			});
+		}
+		if note.pinned == pinned {
+			return Ok(PinNoteResponse { note_id: note.note_id, pinned, op: NoteOp::None });
+		}
+
+		note.pinned = pinned;
+
+		Ok(PinNoteResponse { note_id: note.note_id, pinned, op: NoteOp::Update })
+	}
+
+	/// Concatenates the secondary note into the primary note and supersedes the secondary note.
+	///
+	/// Only [`NoteMergeStrategy::Concatenate`] is supported because the double has no extractor.
+	pub async fn notes_merge(&self, req: NotesMergeRequest) -> Result<NotesMergeResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		if req.primary_note_id == req.secondary_note_id {
+			return Err(Error::InvalidRequest {
+				message: "primary_note_id and secondary_note_id must differ.".to_string(),
+			});
+		}
+		if req.strategy != NoteMergeStrategy::Concatenate {
+			return Err(Error::InvalidRequest {
+				message: "The in-memory service only supports the concatenate strategy."
+					.to_string(),
+			});
+		}
+
+		let mut state = self.state();
+		let secondary = find_owned_note(
+			&mut state.notes,
+			req.secondary_note_id,
+			tenant_id,
+			project_id,
+			agent_id,
+		)?
+		.clone();
+		let primary = find_owned_note(
+			&mut state.notes,
+			req.primary_note_id,
+			tenant_id,
+			project_id,
+			agent_id,
+		)?;
+
+		for note in [&*primary, &secondary] {
+			if note.status != "active" || note.is_expired(now) {
+				return Err(service::note_not_found());
+			}
+		}
+
+		if primary.project_id != secondary.project_id
+			|| primary.scope != secondary.scope
+			|| primary.r#type != secondary.r#type
+		{
+			return Err(Error::InvalidRequest {
+				message: "Merged notes must share the same project, scope, and type.".to_string(),
+			});
+		}
+
+		let primary_text = primary.text.trim();
+		let secondary_text = secondary.text.trim();
+
+		primary.text = if primary_text.contains(secondary_text) {
+			primary_text.to_string()
+		} else {
+			format!("{primary_text}\n\n{secondary_text}")
+		};
+		primary.importance = primary.importance.max(secondary.importance);
+		primary.confidence = primary.confidence.max(secondary.confidence);
+		primary.updated_at = now;
+
+		if let Value::Object(source_ref) = &mut primary.source_ref {
+			push_merged(source_ref, "merged_source_refs", secondary.source_ref.clone());
+			push_merged(source_ref, "merged_note_ids", Value::from(secondary.note_id.to_string()));
+		}
+
+		let primary_note_id = primary.note_id;
+		let secondary_note = find_owned_note(
+			&mut state.notes,
+			req.secondary_note_id,
+			tenant_id,
+			project_id,
+			agent_id,
+		)?;
+
+		secondary_note.status = "deprecated".to_string();
+		secondary_note.updated_at = now;
+
+		Ok(NotesMergeResponse {
+			note_id: primary_note_id,
+			secondary_note_id: secondary_note.note_id,
+			strategy: req.strategy,
+			op: NoteOp::Update,
+			reason_code: None,
+			evidence_links_added: 0,
+		})
+	}
+}
+
+fn push_merged(source_ref: &mut Map<String, Value>, key: &str, value: Value) {
+	match source_ref.get_mut(key) {
+		Some(Value::Array(items)) => items.push(value),
+		_ => {
+			source_ref.insert(key.to_string(), Value::Array(vec![value]));
+		},
+	}
+}
+
+fn rejection_reason(scope: &str, note: &AddNoteInput) -> Option<&'static str> {
+	if note.text.trim().is_empty() {
+		return Some("REJECT_EMPTY");
+	}
+	if !NOTE_TYPES.contains(&note.r#type.as_str()) {
+		return Some("REJECT_INVALID_TYPE");
+	}
+	if structured_fields::validate_note_type_fields(&note.r#type, note.structured.as_ref()).is_err()
+	{
+		return Some("REJECT_STRUCTURED_INVALID");
+	}
+	if !ALL_SCOPES.contains(&scope) {
+		return Some("REJECT_SCOPE_DENIED");
+	}
+	if !english_gate::is_english_natural_language(&note.text) {
+		return Some("REJECT_NON_ENGLISH");
+	}
+
+	None
+}
+
+fn rejected(reason_code: &str) -> AddNoteResult {
+	AddNoteResult {
+		note_id: None,
+		op: NoteOp::Rejected,
+		policy_decision: MemoryPolicyDecision::Reject,
+		reason_code: Some(reason_code.to_string()),
+		field_path: None,
+		write_policy_audit: None,
+		reject_feedback: None,
+	}
+}
+
+fn apply_write_policy(note: &AddNoteInput) -> Result<(String, Option<WritePolicyAudit>)> {
+	let policy = note.write_policy.as_ref();
+	let result = writegate::apply_write_policy(&note.text, policy).map_err(|err| {
+		let message = match err {
+			WritePolicyError::InvalidSpan => "Invalid write_policy span provided.",
+			WritePolicyError::OverlappingOps => "Overlapping write_policy spans provided.",
+		};
+
+		Error::InvalidRequest { message: message.to_string() }
+	})?;
+
+	Ok((result.transformed, policy.is_some().then_some(result.audit)))
+}
+
+fn find_owned_note<'a>(
+	notes: &'a mut [StoredNote],
+	note_id: Uuid,
+	tenant_id: &str,
+	project_id: &str,
+	agent_id: &str,
+) -> Result<&'a mut StoredNote> {
+	notes
+		.iter_mut()
+		.find(|note| {
+			note.note_id == note_id
+				&& note.tenant_id == tenant_id
+				&& (note.project_id == project_id || note.scope == ORG_SHARED)
+				&& note.agent_id == agent_id
+		})
+		.ok_or_else(service::note_not_found)
+}
@@ -0,0 +1,299 @@
+use std::collections::BTreeSet;
+
+use serde_json::json;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::service::{InMemoryElfService, ORG_SHARED, PROJECT_SHARED, StoredNote, require_context};
+use elf_service::{
+	Error, Result, SearchExplain, SearchExplainItem, SearchItem, SearchRequest, SearchResponse,
+	SearchTrace, TraceGetRequest, TraceGetResponse,
+	search::{SearchMatchExplain, SearchRankingExplain, SearchRankingTerm},
+};
+
+const DEFAULT_TOP_K: u32 = 12;
+const MAX_TOP_K: u32 = 100;
+const RANKING_EXPLAIN_SCHEMA: &str = "search_ranking_explain/v2";
+const RANKING_POLICY_ID: &str = "in_memory_lexical/v1";
+const TRACE_VERSION: i32 = 3;
+
+impl InMemoryElfService {
+	/// Ranks visible notes by the share of query terms they contain and records a trace.
+	///
+	/// Ties break by importance, then recency, then note id, so results are deterministic. Pinned
+	/// notes are always included, even when they match no query term.
+	pub async fn search_raw(&self, req: SearchRequest) -> Result<SearchResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+		let query = req.query.trim();
+
+		require_context(tenant_id, project_id, agent_id)?;
+
+		if query.is_empty() {
+			return Err(Error::InvalidRequest { message: "query is required.".to_string() });
+		}
+
+		let top_k = req.top_k.unwrap_or(DEFAULT_TOP_K);
+
+		if top_k == 0 || top_k > MAX_TOP_K {
+			return Err(Error::InvalidRequest {
+				message: format!("top_k must be between 1 and {MAX_TOP_K}."),
+			});
+		}
+
+		let allowed_scopes = self.resolve_read_profile(req.read_profile.trim())?;
+		let query_terms = tokenize(query);
+		let mut state = self.state();
+		let mut hits = state
+			.notes
+			.iter()
+			.filter(|note| note.readable_by(tenant_id, project_id, agent_id, &allowed_scopes, now))
+			.filter_map(|note| score_note(note, &query_terms))
+			.collect::<Vec<_>>();
+
+		hits.sort_by(|a, b| {
+			b.score
+				.total_cmp(&a.score)
+				.then(b.note.importance.total_cmp(&a.note.importance))
+				.then(b.note.updated_at.cmp(&a.note.updated_at))
+				.then(a.note.note_id.cmp(&b.note.note_id))
+		});
+
+		let candidate_count = hits.len() as u32;
+		let items = include_pinned(hits, top_k as usize)
+			.into_iter()
+			.map(to_search_item)
+			.collect::<Vec<_>>();
+		let trace_id = Uuid::new_v4();
+		let trace = TraceGetResponse {
+			trace: SearchTrace {
+				trace_id,
+				tenant_id: tenant_id.to_string(),
+				project_id: project_id.to_string(),
+				agent_id: agent_id.to_string(),
+				read_profile: req.read_profile.trim().to_string(),
+				query: query.to_string(),
+				expansion_mode: "off".to_string(),
+				expanded_queries: vec![query.to_string()],
+				allowed_scopes,
+				candidate_count,
+				top_k,
+				config_snapshot: json!({ "backend": "in_memory", "policy_id": RANKING_POLICY_ID }),
+				created_at: now,
+				trace_version: TRACE_VERSION,
+			},
+			items: items
+				.iter()
+				.enumerate()
+				.map(|(idx, item)| SearchExplainItem {
+					result_handle: item.result_handle,
+					note_id: item.note_id,
+					chunk_id: Some(item.chunk_id),
+					rank: idx as u32 + 1,
+					explain: item.explain.clone(),
+				})
+				.collect(),
+			trajectory_summary: None,
+		};
+
+		state.traces.insert(trace_id, trace);
+
+		Ok(SearchResponse { trace_id, items, trajectory_summary: None, warnings: Vec::new() })
+	}
+
+	/// Loads a trace recorded by [`Self::search_raw`].
+	///
+	/// Mirrors the service visibility rules without admin tokens: the owner agent sees its traces,
+	/// and other agents only see traces whose items all reference shared-scope notes.
+	pub async fn trace_get(&self, req: TraceGetRequest) -> Result<TraceGetResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if agent_id.is_empty() {
+			return Err(Error::InvalidRequest { message: "agent_id is required.".to_string() });
+		}
+		if tenant_id.is_empty() || project_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id and project_id are required.".to_string(),
+			});
+		}
+
+		let state = self.state();
+
+		state
+			.traces
+			.get(&req.trace_id)
+			.filter(|trace| {
+				trace.trace.tenant_id == tenant_id && trace.trace.project_id == project_id
+			})
+			.filter(|trace| {
+				trace.trace.agent_id == agent_id || trace_is_shared(&state.notes, trace)
+			})
+			.cloned()
+			.ok_or_else(|| Error::InvalidRequest { message: "Unknown trace_id.".to_string() })
+	}
+}
+
+struct LexicalHit<'a> {
+	note: &'a StoredNote,
+	score: f32,
+	matched_terms: Vec<String>,
+	matched_fields: Vec<String>,
+	pin_boost: Option<f32>,
+}
+
+fn trace_is_shared(notes: &[StoredNote], trace: &TraceGetResponse) -> bool {
+	!trace.items.is_empty()
+		&& trace.items.iter().all(|item| {
+			notes.iter().any(|note| {
+				note.note_id == item.note_id
+					&& matches!(note.scope.as_str(), PROJECT_SHARED | ORG_SHARED)
+			})
+		})
+}
+
+fn tokenize(text: &str) -> BTreeSet<String> {
+	text.split(|c: char| !c.is_alphanumeric())
+		.filter(|token| !token.is_empty())
+		.map(str::to_lowercase)
+		.collect()
+}
+
+fn score_note<'a>(note: &'a StoredNote, query_terms: &BTreeSet<String>) -> Option<LexicalHit<'a>> {
+	let text_terms = tokenize(&note.text);
+	let key_terms = note.key.as_deref().map(tokenize).unwrap_or_default();
+	let matched_terms = query_terms
+		.iter()
+		.filter(|term| text_terms.contains(*term) || key_terms.contains(*term))
+		.cloned()
+		.collect::<Vec<_>>();
+
+	if matched_terms.is_empty() {
+		return note.pinned.then(|| LexicalHit {
+			note,
+			score: 0.0,
+			matched_terms,
+			matched_fields: Vec::new(),
+			pin_boost: Some(0.0),
+		});
+	}
+
+	let mut matched_fields = Vec::new();
+
+	if matched_terms.iter().any(|term| text_terms.contains(term)) {
+		matched_fields.push("text".to_string());
+	}
+	if matched_terms.iter().any(|term| key_terms.contains(term)) {
+		matched_fields.push("key".to_string());
+	}
+
+	let score = matched_terms.len() as f32 / query_terms.len() as f32;
+
+	Some(LexicalHit {
+		note,
+		score,
+		matched_terms,
+		matched_fields,
+		pin_boost: note.pinned.then_some(0.0),
+	})
+}
+
+/// Keeps the top `top_k` hits while reserving slots for pinned notes, in score order.
+///
+/// Pinned hits that only made the cut because of their pin are lifted to the score of the last
+/// hit that would have been selected without pins.
+fn include_pinned(hits: Vec<LexicalHit<'_>>, top_k: usize) -> Vec<LexicalHit<'_>> {
+	let cutoff = hits.get(top_k.saturating_sub(1)).map(|hit| hit.score);
+	let mut pinned_slots = hits.iter().filter(|hit| hit.note.pinned).count().min(top_k);
+	let mut unpinned_slots = top_k - pinned_slots;
+	let mut selected = Vec::with_capacity(top_k);
+
+	for mut hit in hits {
+		if hit.note.pinned {
+			if pinned_slots == 0 {
+				continue;
+			}
+
+			pinned_slots -= 1;
+
+			if let Some(cutoff) = cutoff.filter(|cutoff| *cutoff > hit.score) {
+				hit.pin_boost = Some(cutoff - hit.score);
+				hit.score = cutoff;
+			}
+		} else {
+			if unpinned_slots == 0 {
+				continue;
+			}
+
+			unpinned_slots -= 1;
+		}
+
+		selected.push(hit);
+	}
+
+	selected
+}
+
+fn ranking_terms(score: f32, pin_boost: Option<f32>) -> Vec<SearchRankingTerm> {
+	let mut terms = vec![SearchRankingTerm {
+		name: "lexical.term_coverage".to_string(),
+		value: score - pin_boost.unwrap_or_default(),
+		inputs: None,
+	}];
+
+	if let Some(pin_boost) = pin_boost {
+		terms.push(SearchRankingTerm {
+			name: "pin_boost".to_string(),
+			value: pin_boost,
+			inputs: None,
+		});
+	}
+
+	terms
+}
+
+fn to_search_item(hit: LexicalHit<'_>) -> SearchItem {
+	let note = hit.note;
+
+	SearchItem {
+		result_handle: Uuid::new_v4(),
+		note_id: note.note_id,
+		chunk_id: note.note_id,
+		chunk_index: 0,
+		start_offset: 0,
+		end_offset: note.text.len() as i32,
+		snippet: note.text.clone(),
+		snippet_truncation: None,
+		highlights: Vec::new(),
+		r#type: note.r#type.clone(),
+		key: note.key.clone(),
+		scope: note.scope.clone(),
+		importance: note.importance,
+		confidence: note.confidence,
+		updated_at: note.updated_at,
+		expires_at: note.expires_at,
+		final_score: hit.score,
+		source_ref: note.source_ref.clone(),
+		explain: SearchExplain {
+			r#match: SearchMatchExplain {
+				matched_terms: hit.matched_terms,
+				matched_fields: hit.matched_fields,
+			},
+			ranking: SearchRankingExplain {
+				schema: RANKING_EXPLAIN_SCHEMA.to_string(),
+				policy_id: RANKING_POLICY_ID.to_string(),
+				final_score: hit.score,
+				terms: ranking_terms(hit.score, hit.pin_boost),
+				sensitivity: None,
+			},
+			relation_context: None,
+			diversity: None,
+			embedding_projection: None,
+			rendered: None,
+			packing: None,
+		},
+	}
+}
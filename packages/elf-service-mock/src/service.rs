@@ -0,0 +1,138 @@
+use std::{
+	collections::HashMap,
+	sync::{Mutex, MutexGuard},
+};
+
+use serde_json::Value;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use elf_service::{Error, Result, StructuredFields, TraceGetResponse};
+
+pub(crate) const AGENT_PRIVATE: &str = "agent_private";
+pub(crate) const PROJECT_SHARED: &str = "project_shared";
+pub(crate) const ORG_SHARED: &str = "org_shared";
+pub(crate) const ALL_SCOPES: [&str; 3] = [AGENT_PRIVATE, PROJECT_SHARED, ORG_SHARED];
+
+/// In-memory implementation of the note, search, and trace service APIs.
+///
+/// Read profiles default to the ones shipped in `elf.example.toml`: `private_only`,
+/// `private_plus_project`, and `all_scopes`.
+#[derive(Debug)]
+pub struct InMemoryElfService {
+	read_profiles: HashMap<String, Vec<String>>,
+	state: Mutex<State>,
+}
+impl InMemoryElfService {
+	/// Creates an empty service with the default read profiles.
+	pub fn new() -> Self {
+		let read_profiles = [
+			("private_only", vec![AGENT_PRIVATE]),
+			("private_plus_project", vec![AGENT_PRIVATE, PROJECT_SHARED]),
+			("all_scopes", ALL_SCOPES.to_vec()),
+		]
+		.into_iter()
+		.map(|(name, scopes)| (name.to_string(), scopes.into_iter().map(str::to_string).collect()))
+		.collect();
+
+		Self { read_profiles, state: Mutex::new(State::default()) }
+	}
+
+	/// Adds or replaces a read profile used by search.
+	pub fn with_read_profile<I, S>(mut self, name: impl Into<String>, scopes: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.read_profiles.insert(name.into(), scopes.into_iter().map(Into::into).collect());
+
+		self
+	}
+
+	pub(crate) fn resolve_read_profile(&self, profile: &str) -> Result<Vec<String>> {
+		self.read_profiles
+			.get(profile)
+			.cloned()
+			.ok_or_else(|| Error::InvalidRequest { message: "Unknown read_profile.".to_string() })
+	}
+
+	pub(crate) fn state(&self) -> MutexGuard<'_, State> {
+		// A panic while holding the lock leaves plain data behind, so keep serving it.
+		self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+impl Default for InMemoryElfService {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct State {
+	pub(crate) notes: Vec<StoredNote>,
+	pub(crate) traces: HashMap<Uuid, TraceGetResponse>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct StoredNote {
+	pub(crate) note_id: Uuid,
+	pub(crate) tenant_id: String,
+	pub(crate) project_id: String,
+	pub(crate) agent_id: String,
+	pub(crate) scope: String,
+	pub(crate) r#type: String,
+	pub(crate) key: Option<String>,
+	pub(crate) text: String,
+	pub(crate) structured: Option<StructuredFields>,
+	pub(crate) importance: f32,
+	pub(crate) confidence: f32,
+	pub(crate) status: String,
+	pub(crate) updated_at: OffsetDateTime,
+	pub(crate) expires_at: Option<OffsetDateTime>,
+	pub(crate) source_ref: Value,
+	pub(crate) pinned: bool,
+}
+impl StoredNote {
+	/// Mirrors the service read rules: owner-only private notes, project-wide shared notes, and
+	/// tenant-wide org notes.
+	pub(crate) fn readable_by(
+		&self,
+		tenant_id: &str,
+		project_id: &str,
+		agent_id: &str,
+		scopes: &[String],
+		now: OffsetDateTime,
+	) -> bool {
+		if self.tenant_id != tenant_id || self.status != "active" || self.is_expired(now) {
+			return false;
+		}
+		if !scopes.iter().any(|scope| scope == &self.scope) {
+			return false;
+		}
+
+		match self.scope.as_str() {
+			AGENT_PRIVATE => self.project_id == project_id && self.agent_id == agent_id,
+			PROJECT_SHARED => self.project_id == project_id,
+			ORG_SHARED => true,
+			_ => false,
+		}
+	}
+
+	pub(crate) fn is_expired(&self, now: OffsetDateTime) -> bool {
+		self.expires_at.is_some_and(|expires_at| expires_at <= now)
+	}
+}
+
+pub(crate) fn require_context(tenant_id: &str, project_id: &str, agent_id: &str) -> Result<()> {
+	if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+		return Err(Error::InvalidRequest {
+			message: "tenant_id, project_id, and agent_id are required.".to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+pub(crate) fn note_not_found() -> Error {
+	Error::InvalidRequest { message: "Note not found.".to_string() }
+}
@@ -0,0 +1,403 @@
+#![allow(unused_crate_dependencies)]
+
+//! Integration tests for the in-memory service test double.
+
+use serde_json::Value;
+
+use elf_service::{
+	AddNoteInput, AddNoteRequest, DeleteRequest, Error, ListRequest, ListTrashedRequest,
+	NoteFetchRequest, NoteMergeStrategy, NoteOp, NotesMergeRequest, PayloadLevel, PinNoteRequest,
+	SearchRequest, TraceGetRequest, UndeleteRequest,
+};
+use elf_service_mock::InMemoryElfService;
+
+fn note(key: &str, text: &str, importance: f32) -> AddNoteInput {
+	AddNoteInput {
+		r#type: "fact".to_string(),
+		key: Some(key.to_string()),
+		text: text.to_string(),
+		structured: None,
+		importance,
+		confidence: 0.9,
+		ttl_days: None,
+		source_ref: Value::Object(Default::default()),
+		write_policy: None,
+	}
+}
+
+fn add_request(agent_id: &str, scope: &str, notes: Vec<AddNoteInput>) -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: agent_id.to_string(),
+		role: None,
+		scope: scope.to_string(),
+		notes,
+	}
+}
+
+fn search_request(agent_id: &str, read_profile: &str, query: &str) -> SearchRequest {
+	SearchRequest {
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: agent_id.to_string(),
+		token_id: None,
+		payload_level: PayloadLevel::L0,
+		read_profile: read_profile.to_string(),
+		query: query.to_string(),
+		top_k: Some(5),
+		candidate_k: None,
+		filter: None,
+		types: Vec::new(),
+		updated_after: None,
+		updated_before: None,
+		min_importance: None,
+		max_result_tokens: None,
+		record_hits: None,
+		ranking: None,
+		session_id: None,
+		session_mode: None,
+	}
+}
+
+#[tokio::test]
+async fn search_ranks_by_term_coverage_and_records_trace() {
+	let service = InMemoryElfService::new();
+
+	service
+		.add_note(add_request(
+			"a",
+			"project_shared",
+			vec![
+				note("editor", "The team prefers Neovim for editing Rust code.", 0.5),
+				note("shell", "The team uses Fish as the default shell.", 0.9),
+				note("deploy", "Deployments run on Fridays after review.", 0.9),
+			],
+		))
+		.await
+		.expect("Failed to add notes.");
+
+	let response = service
+		.search_raw(search_request("b", "private_plus_project", "Which editor for Rust code?"))
+		.await
+		.expect("Search failed.");
+
+	assert_eq!(response.items.len(), 1);
+	assert_eq!(response.items[0].key.as_deref(), Some("editor"));
+	assert_eq!(
+		response.items[0].explain.r#match.matched_terms,
+		vec!["code", "editor", "for", "rust"]
+	);
+
+	let trace = service
+		.trace_get(TraceGetRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "b".to_string(),
+			token_id: None,
+			trace_id: response.trace_id,
+		})
+		.await
+		.expect("Trace lookup failed.");
+
+	assert_eq!(trace.items.len(), 1);
+	assert_eq!(trace.items[0].note_id, response.items[0].note_id);
+	assert_eq!(trace.trace.allowed_scopes, vec!["agent_private", "project_shared"]);
+}
+
+#[tokio::test]
+async fn private_notes_stay_with_their_owner() {
+	let service = InMemoryElfService::new();
+	let added = service
+		.add_note(add_request(
+			"a",
+			"agent_private",
+			vec![note("editor", "I prefer Helix for editing.", 0.5)],
+		))
+		.await
+		.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected a note id.");
+	let other = service
+		.search_raw(search_request("b", "all_scopes", "Helix editing"))
+		.await
+		.expect("Search failed.");
+
+	assert!(other.items.is_empty());
+
+	let err = service
+		.get_note(NoteFetchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "b".to_string(),
+			token_id: None,
+			note_id,
+			include_access_stats: false,
+		})
+		.await
+		.expect_err("Expected another agent to be denied.");
+
+	assert!(matches!(err, Error::InvalidRequest { .. }));
+
+	let owner = service
+		.search_raw(search_request("a", "private_only", "Helix editing"))
+		.await
+		.expect("Search failed.");
+
+	assert_eq!(owner.items.len(), 1);
+
+	let err = service
+		.trace_get(TraceGetRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "b".to_string(),
+			token_id: None,
+			trace_id: owner.trace_id,
+		})
+		.await
+		.expect_err("Expected another agent to be denied the owner's private trace.");
+
+	assert!(matches!(err, Error::InvalidRequest { .. }));
+}
+
+#[tokio::test]
+async fn keyed_notes_update_in_place_and_delete_hides_them() {
+	let service = InMemoryElfService::new();
+	let first = service
+		.add_note(add_request(
+			"a",
+			"project_shared",
+			vec![note("shell", "The team uses Bash.", 0.5)],
+		))
+		.await
+		.expect("Failed to add note.");
+	let second = service
+		.add_note(add_request(
+			"a",
+			"project_shared",
+			vec![note("shell", "The team uses Zsh.", 0.5)],
+		))
+		.await
+		.expect("Failed to update note.");
+
+	assert_eq!(first.results[0].op, NoteOp::Add);
+	assert_eq!(second.results[0].op, NoteOp::Update);
+	assert_eq!(first.results[0].note_id, second.results[0].note_id);
+
+	let note_id = first.results[0].note_id.expect("Expected a note id.");
+	let deleted = service
+		.delete(DeleteRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			role: None,
+			note_id,
+		})
+		.await
+		.expect("Delete failed.");
+
+	assert_eq!(deleted.op, NoteOp::Delete);
+
+	let listed = service
+		.list(ListRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: Some("a".to_string()),
+			scope: None,
+			status: None,
+			r#type: None,
+		})
+		.await
+		.expect("List failed.");
+
+	assert!(listed.items.is_empty());
+}
+
+#[tokio::test]
+async fn trashed_notes_are_listed_and_can_be_restored() {
+	let service = InMemoryElfService::new();
+	let added = service
+		.add_note(add_request(
+			"a",
+			"agent_private",
+			vec![note("editor", "The team uses Vim.", 0.5)],
+		))
+		.await
+		.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected a note id.");
+
+	service
+		.delete(DeleteRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			role: None,
+			note_id,
+		})
+		.await
+		.expect("Delete failed.");
+
+	let trash = service
+		.list_trashed(ListTrashedRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+		})
+		.await
+		.expect("Trash listing failed.");
+
+	assert_eq!(trash.items.len(), 1);
+	assert_eq!(trash.items[0].note_id, note_id);
+	assert!(trash.items[0].purge_after > trash.items[0].trashed_at);
+
+	let undelete = UndeleteRequest {
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		role: None,
+		note_id,
+	};
+	let restored = service.undelete(undelete.clone()).await.expect("Undelete failed.");
+	let repeated = service.undelete(undelete).await.expect("Repeated undelete failed.");
+
+	assert_eq!(restored.op, NoteOp::Update);
+	assert_eq!(repeated.op, NoteOp::None);
+
+	let fetched = service
+		.get_note(NoteFetchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			note_id,
+			include_access_stats: false,
+		})
+		.await
+		.expect("Restored note should be readable.");
+
+	assert_eq!(fetched.status, "active");
+}
+
+#[tokio::test]
+async fn pinned_notes_are_always_included_in_search() {
+	let service = InMemoryElfService::new();
+	let added = service
+		.add_note(add_request(
+			"a",
+			"agent_private",
+			vec![
+				note("deploy", "Deploys run on Fridays.", 0.4),
+				note("oncall", "The on-call rotation changes weekly.", 0.4),
+			],
+		))
+		.await
+		.expect("Failed to add notes.");
+	let pinned_id = added.results[1].note_id.expect("Expected a note id.");
+	let pin = PinNoteRequest {
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		role: None,
+		note_id: pinned_id,
+	};
+	let pinned = service.pin_note(pin.clone()).await.expect("Pin failed.");
+
+	assert!(pinned.pinned);
+	assert_eq!(pinned.op, NoteOp::Update);
+
+	let mut request = search_request("a", "private_only", "deploys fridays");
+
+	request.top_k = Some(1);
+
+	let response = service.search_raw(request.clone()).await.expect("Search failed.");
+
+	assert_eq!(response.items.len(), 1);
+	assert_eq!(response.items[0].note_id, pinned_id);
+	assert!(response.items[0].explain.ranking.terms.iter().any(|term| term.name == "pin_boost"));
+
+	let unpinned = service.unpin_note(pin).await.expect("Unpin failed.");
+
+	assert!(!unpinned.pinned);
+
+	let response = service.search_raw(request).await.expect("Search failed.");
+
+	assert_ne!(response.items[0].note_id, pinned_id);
+}
+
+#[tokio::test]
+async fn invalid_notes_are_rejected_per_item() {
+	let service = InMemoryElfService::new();
+	let mut bad_type = note("k", "The team uses Bash.", 0.5);
+
+	bad_type.r#type = "rumor".to_string();
+
+	let response = service
+		.add_note(add_request("a", "project_shared", vec![bad_type, note("e", "   ", 0.5)]))
+		.await
+		.expect("Failed to add notes.");
+	let codes =
+		response.results.iter().map(|result| result.reason_code.as_deref()).collect::<Vec<_>>();
+
+	assert_eq!(codes, vec![Some("REJECT_INVALID_TYPE"), Some("REJECT_EMPTY")]);
+}
+
+#[tokio::test]
+async fn merge_concatenates_into_primary_and_hides_secondary() {
+	let service = InMemoryElfService::new();
+	let added = service
+		.add_note(add_request(
+			"a",
+			"agent_private",
+			vec![
+				note("deploy", "Deploys run on Fridays.", 0.4),
+				note("rollback", "Rollbacks need approval.", 0.7),
+			],
+		))
+		.await
+		.expect("Failed to add notes.");
+	let primary = added.results[0].note_id.expect("Expected a primary note id.");
+	let secondary = added.results[1].note_id.expect("Expected a secondary note id.");
+	let merged = service
+		.notes_merge(NotesMergeRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			role: None,
+			primary_note_id: primary,
+			secondary_note_id: secondary,
+			strategy: NoteMergeStrategy::Concatenate,
+		})
+		.await
+		.expect("Merge failed.");
+
+	assert_eq!(merged.op, NoteOp::Update);
+
+	let fetched = service
+		.get_note(NoteFetchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			note_id: primary,
+			include_access_stats: false,
+		})
+		.await
+		.expect("Primary note should stay readable.");
+
+	assert_eq!(fetched.text, "Deploys run on Fridays.\n\nRollbacks need approval.");
+	assert!((fetched.importance - 0.7).abs() < f32::EPSILON);
+	assert_eq!(fetched.source_ref["merged_note_ids"][0], secondary.to_string());
+
+	let hidden = service
+		.get_note(NoteFetchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			note_id: secondary,
+			include_access_stats: false,
+		})
+		.await
+		.expect_err("Secondary note should be superseded.");
+
+	assert!(matches!(hidden, Error::InvalidRequest { .. }));
+}
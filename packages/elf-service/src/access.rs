@@ -4,7 +4,8 @@ use sqlx::PgExecutor;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::Result;
+use crate::{ElfService, Result};
+use elf_config::SecurityAuthRole;
 use elf_storage::models::MemoryNote;
 
 pub(crate) const ORG_PROJECT_ID: &str = "__org__";
@@ -15,6 +16,44 @@ pub(crate) struct SharedSpaceGrantKey {
 	pub(crate) space_owner_agent_id: String,
 }
 
+/// Note read rules in the order they are evaluated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum NoteReadRule {
+	StatusActive,
+	NotExpired,
+	ScopeAllowed,
+	PrivateOwner,
+	SharedScope,
+	OwnerOrGrant,
+}
+impl NoteReadRule {
+	pub(crate) fn as_str(self) -> &'static str {
+		match self {
+			Self::StatusActive => "status_active",
+			Self::NotExpired => "not_expired",
+			Self::ScopeAllowed => "scope_allowed",
+			Self::PrivateOwner => "private_owner",
+			Self::SharedScope => "shared_scope",
+			Self::OwnerOrGrant => "owner_or_grant",
+		}
+	}
+
+	/// Rules that apply to a note in `scope`, in evaluation order.
+	pub(crate) fn sequence_for(scope: &str) -> &'static [Self] {
+		if scope == "agent_private" {
+			&[Self::StatusActive, Self::NotExpired, Self::ScopeAllowed, Self::PrivateOwner]
+		} else {
+			&[
+				Self::StatusActive,
+				Self::NotExpired,
+				Self::ScopeAllowed,
+				Self::SharedScope,
+				Self::OwnerOrGrant,
+			]
+		}
+	}
+}
+
 pub(crate) fn note_read_allowed(
 	note: &MemoryNote,
 	requester_agent_id: &str,
@@ -22,29 +61,59 @@ pub(crate) fn note_read_allowed(
 	shared_grants: &HashSet<SharedSpaceGrantKey>,
 	now: OffsetDateTime,
 ) -> bool {
+	note_read_denial(note, requester_agent_id, allowed_scopes, shared_grants, now).is_none()
+}
+
+/// Returns the first read rule the note fails for the requester, or `None` when readable.
+pub(crate) fn note_read_denial(
+	note: &MemoryNote,
+	requester_agent_id: &str,
+	allowed_scopes: &[String],
+	shared_grants: &HashSet<SharedSpaceGrantKey>,
+	now: OffsetDateTime,
+) -> Option<NoteReadRule> {
 	if note.status != "active" {
-		return false;
+		return Some(NoteReadRule::StatusActive);
 	}
 	if note.expires_at.map(|expires_at| expires_at <= now).unwrap_or(false) {
-		return false;
+		return Some(NoteReadRule::NotExpired);
 	}
 	if !allowed_scopes.iter().any(|scope| scope == &note.scope) {
-		return false;
+		return Some(NoteReadRule::ScopeAllowed);
 	}
 	if note.scope == "agent_private" {
-		return note.agent_id == requester_agent_id;
+		return (note.agent_id != requester_agent_id).then_some(NoteReadRule::PrivateOwner);
 	}
 	if !is_shared_scope(note.scope.as_str()) {
-		return false;
+		return Some(NoteReadRule::SharedScope);
 	}
 	if note.agent_id == requester_agent_id {
-		return true;
+		return None;
 	}
 
-	shared_grants.contains(&SharedSpaceGrantKey {
+	let granted = shared_grants.contains(&SharedSpaceGrantKey {
 		scope: note.scope.clone(),
 		space_owner_agent_id: note.agent_id.clone(),
-	})
+	});
+
+	(!granted).then_some(NoteReadRule::OwnerOrGrant)
+}
+
+pub(crate) fn is_super_admin_token_id(service: &ElfService, token_id: Option<&str>) -> bool {
+	if service.cfg.security.auth_mode.trim() != "static_keys" {
+		return false;
+	}
+
+	let Some(token_id) = token_id.map(str::trim).filter(|value| !value.is_empty()) else {
+		return false;
+	};
+
+	service
+		.cfg
+		.security
+		.auth_keys
+		.iter()
+		.any(|key| key.token_id == token_id && matches!(key.role, SecurityAuthRole::SuperAdmin))
 }
 
 pub(crate) fn shared_scope_key_strings(
@@ -192,6 +261,6 @@ SET
 	Ok(())
 }
 
-fn is_shared_scope(scope: &str) -> bool {
+pub(crate) fn is_shared_scope(scope: &str) -> bool {
 	matches!(scope, "project_shared" | "org_shared")
 }
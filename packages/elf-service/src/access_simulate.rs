@@ -0,0 +1,280 @@
+//! Read-access simulation for debugging why an agent cannot see a note.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	access::{self, NoteReadRule, ORG_PROJECT_ID, SharedSpaceGrantKey},
+	search,
+};
+use elf_storage::models::MemoryNote;
+
+const RULE_READ_PROFILE: &str = "read_profile";
+const RULE_NOTE_VISIBLE: &str = "note_visible";
+
+/// Request payload for simulating a note read.
+#[derive(Clone, Debug)]
+pub struct AccessSimulateRequest {
+	/// Tenant the simulated read runs in.
+	pub tenant_id: String,
+	/// Project the simulated read runs in.
+	pub project_id: String,
+	/// Agent whose read is simulated.
+	pub agent_id: String,
+	/// Read profile the agent would search with.
+	pub read_profile: String,
+	/// Note the agent tries to read.
+	pub note_id: Uuid,
+}
+
+/// One evaluated access rule.
+#[derive(Clone, Debug, Serialize)]
+pub struct AccessDecisionStep {
+	/// Stable rule identifier.
+	pub rule: String,
+	/// Whether the rule passed.
+	pub passed: bool,
+	/// Human-readable explanation of the outcome.
+	pub detail: String,
+}
+
+/// Access-relevant fields of the simulated note.
+#[derive(Clone, Debug, Serialize)]
+pub struct AccessSimulateNote {
+	/// Project that owns the note.
+	pub project_id: String,
+	/// Agent that owns the note.
+	pub agent_id: String,
+	/// Note scope.
+	pub scope: String,
+	/// Note lifecycle status.
+	pub status: String,
+	#[serde(with = "crate::time_serde::option")]
+	/// TTL deadline, when set.
+	pub expires_at: Option<OffsetDateTime>,
+}
+
+/// Response payload for a simulated note read.
+#[derive(Clone, Debug, Serialize)]
+pub struct AccessSimulateResponse {
+	/// Simulated note identifier.
+	pub note_id: Uuid,
+	/// Simulated agent.
+	pub agent_id: String,
+	/// Simulated read profile.
+	pub read_profile: String,
+	/// Scopes the read profile resolves to.
+	pub resolved_scopes: Vec<String>,
+	/// Active shared-space grants visible to the agent, as `scope:owner_agent_id`.
+	pub grants_consulted: Vec<String>,
+	/// Note fields that drive the decision; absent when the note is not visible to the project.
+	pub note: Option<AccessSimulateNote>,
+	/// Evaluated rules in order, ending at the first failure.
+	pub steps: Vec<AccessDecisionStep>,
+	/// Final decision.
+	pub allowed: bool,
+	/// Rule that denied the read, when denied.
+	pub failing_rule: Option<String>,
+	#[serde(with = "crate::time_serde")]
+	/// Time used for the TTL check.
+	pub evaluated_at: OffsetDateTime,
+}
+
+impl ElfService {
+	/// Replays the note read path for an agent and read profile and returns every rule consulted.
+	///
+	/// The simulation uses the same rule order as search and note fetches, so a denial here names
+	/// the exact rule that hides the note from the agent.
+	pub async fn access_simulate(
+		&self,
+		req: AccessSimulateRequest,
+	) -> Result<AccessSimulateResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+		let read_profile = req.read_profile.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let resolved_scopes = search::resolve_read_profile_scopes(&self.cfg, read_profile)?;
+		let now = OffsetDateTime::now_utc();
+		let mut steps = vec![AccessDecisionStep {
+			rule: RULE_READ_PROFILE.to_string(),
+			passed: true,
+			detail: format!(
+				"Read profile {read_profile} resolves to scopes [{}].",
+				resolved_scopes.join(", ")
+			),
+		}];
+		let note: Option<MemoryNote> = sqlx::query_as::<_, MemoryNote>(
+			"\
+SELECT *
+FROM memory_notes
+WHERE note_id = $1
+  AND tenant_id = $2
+  AND (
+    project_id = $3
+    OR (project_id = $4 AND scope = 'org_shared')
+  )",
+		)
+		.bind(req.note_id)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(ORG_PROJECT_ID)
+		.fetch_optional(&self.db.pool)
+		.await?;
+		let Some(note) = note else {
+			steps.push(AccessDecisionStep {
+				rule: RULE_NOTE_VISIBLE.to_string(),
+				passed: false,
+				detail: format!(
+					"No note with this id exists in tenant {tenant_id} for project {project_id} or as an org-shared note."
+				),
+			});
+
+			return Ok(AccessSimulateResponse {
+				note_id: req.note_id,
+				agent_id: agent_id.to_string(),
+				read_profile: read_profile.to_string(),
+				resolved_scopes,
+				grants_consulted: Vec::new(),
+				note: None,
+				steps,
+				allowed: false,
+				failing_rule: Some(RULE_NOTE_VISIBLE.to_string()),
+				evaluated_at: now,
+			});
+		};
+
+		steps.push(AccessDecisionStep {
+			rule: RULE_NOTE_VISIBLE.to_string(),
+			passed: true,
+			detail: format!("Note belongs to project {}.", note.project_id),
+		});
+
+		let shared_grants = if note.scope == "agent_private" {
+			HashSet::new()
+		} else {
+			let org_shared_allowed = resolved_scopes.iter().any(|scope| scope == "org_shared");
+
+			access::load_shared_read_grants_with_org_shared(
+				&self.db.pool,
+				tenant_id,
+				project_id,
+				agent_id,
+				org_shared_allowed,
+			)
+			.await?
+		};
+		let denial =
+			access::note_read_denial(&note, agent_id, &resolved_scopes, &shared_grants, now);
+
+		steps.extend(trace_rules(&note, agent_id, &shared_grants, denial, now));
+
+		Ok(AccessSimulateResponse {
+			note_id: req.note_id,
+			agent_id: agent_id.to_string(),
+			read_profile: read_profile.to_string(),
+			resolved_scopes,
+			grants_consulted: access::shared_scope_key_strings(&shared_grants),
+			note: Some(AccessSimulateNote {
+				project_id: note.project_id,
+				agent_id: note.agent_id,
+				scope: note.scope,
+				status: note.status,
+				expires_at: note.expires_at,
+			}),
+			steps,
+			allowed: denial.is_none(),
+			failing_rule: denial.map(|rule| rule.as_str().to_string()),
+			evaluated_at: now,
+		})
+	}
+}
+
+/// Expands a read decision into per-rule steps, stopping after the denying rule.
+fn trace_rules(
+	note: &MemoryNote,
+	agent_id: &str,
+	shared_grants: &HashSet<SharedSpaceGrantKey>,
+	denial: Option<NoteReadRule>,
+	now: OffsetDateTime,
+) -> Vec<AccessDecisionStep> {
+	let mut steps = Vec::new();
+
+	for rule in NoteReadRule::sequence_for(note.scope.as_str()) {
+		let passed = denial != Some(*rule);
+
+		steps.push(AccessDecisionStep {
+			rule: rule.as_str().to_string(),
+			passed,
+			detail: rule_detail(*rule, passed, note, agent_id, shared_grants, now),
+		});
+
+		if !passed {
+			break;
+		}
+	}
+
+	steps
+}
+
+fn rule_detail(
+	rule: NoteReadRule,
+	passed: bool,
+	note: &MemoryNote,
+	agent_id: &str,
+	shared_grants: &HashSet<SharedSpaceGrantKey>,
+	now: OffsetDateTime,
+) -> String {
+	match (rule, passed) {
+		(NoteReadRule::StatusActive, true) => "Note is active.".to_string(),
+		(NoteReadRule::StatusActive, false) => format!("Note status is {}.", note.status),
+		(NoteReadRule::NotExpired, true) => match note.expires_at {
+			Some(expires_at) =>
+				format!("Note expires in {} seconds.", (expires_at - now).whole_seconds()),
+			None => "Note has no TTL.".to_string(),
+		},
+		(NoteReadRule::NotExpired, false) => match note.expires_at {
+			Some(expires_at) =>
+				format!("Note expired {} seconds ago.", (now - expires_at).whole_seconds()),
+			None => "Note has no TTL.".to_string(),
+		},
+		(NoteReadRule::ScopeAllowed, true) =>
+			format!("Scope {} is included in the read profile.", note.scope),
+		(NoteReadRule::ScopeAllowed, false) =>
+			format!("Scope {} is not included in the read profile.", note.scope),
+		(NoteReadRule::PrivateOwner, true) => "Agent owns the private note.".to_string(),
+		(NoteReadRule::PrivateOwner, false) =>
+			format!("Private note is owned by agent {}, not {agent_id}.", note.agent_id),
+		(NoteReadRule::SharedScope, true) => format!("Scope {} is a shared scope.", note.scope),
+		(NoteReadRule::SharedScope, false) =>
+			format!("Scope {} is neither private nor shared.", note.scope),
+		(NoteReadRule::OwnerOrGrant, true) => {
+			let key = SharedSpaceGrantKey {
+				scope: note.scope.clone(),
+				space_owner_agent_id: note.agent_id.clone(),
+			};
+
+			if shared_grants.contains(&key) {
+				format!("Grant {}:{} covers the agent.", note.scope, note.agent_id)
+			} else {
+				"Agent owns the note.".to_string()
+			}
+		},
+		(NoteReadRule::OwnerOrGrant, false) => format!(
+			"Agent {agent_id} does not own the note and no active grant covers {}:{}.",
+			note.scope, note.agent_id
+		),
+	}
+}
+
+#[cfg(test)] mod tests;
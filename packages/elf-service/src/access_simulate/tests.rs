@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{
+	access::{self, SharedSpaceGrantKey},
+	access_simulate,
+};
+use elf_storage::models::MemoryNote;
+
+fn note(scope: &str, owner: &str, now: OffsetDateTime) -> MemoryNote {
+	MemoryNote {
+		note_id: Uuid::from_u128(1),
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: owner.to_string(),
+		scope: scope.to_string(),
+		r#type: "fact".to_string(),
+		key: None,
+		text: "English text.".to_string(),
+		importance: 0.5,
+		confidence: 0.9,
+		status: "active".to_string(),
+		created_at: now,
+		updated_at: now,
+		expires_at: Some(now + Duration::days(1)),
+		embedding_version: "v1".to_string(),
+		source_ref: serde_json::json!({}),
+		hit_count: 0,
+		last_hit_at: None,
+	}
+}
+
+fn scopes() -> Vec<String> {
+	vec!["agent_private".to_string(), "project_shared".to_string()]
+}
+
+fn rules(steps: &[access_simulate::AccessDecisionStep]) -> Vec<(&str, bool)> {
+	steps.iter().map(|step| (step.rule.as_str(), step.passed)).collect()
+}
+
+#[test]
+fn expired_note_stops_the_trace_at_the_ttl_check() {
+	let now = OffsetDateTime::UNIX_EPOCH + Duration::days(10);
+	let mut note = note("project_shared", "owner", now);
+
+	note.expires_at = Some(now - Duration::hours(1));
+
+	let grants = HashSet::new();
+	let denial = access::note_read_denial(&note, "reader", &scopes(), &grants, now);
+	let steps = access_simulate::trace_rules(&note, "reader", &grants, denial, now);
+
+	assert_eq!(rules(&steps), vec![("status_active", true), ("not_expired", false)]);
+	assert_eq!(steps[1].detail, "Note expired 3600 seconds ago.");
+}
+
+#[test]
+fn shared_note_without_grant_fails_on_owner_or_grant() {
+	let now = OffsetDateTime::UNIX_EPOCH;
+	let note = note("project_shared", "owner", now);
+	let mut grants = HashSet::new();
+	let denial = access::note_read_denial(&note, "reader", &scopes(), &grants, now);
+	let steps = access_simulate::trace_rules(&note, "reader", &grants, denial, now);
+
+	assert_eq!(
+		rules(&steps),
+		vec![
+			("status_active", true),
+			("not_expired", true),
+			("scope_allowed", true),
+			("shared_scope", true),
+			("owner_or_grant", false),
+		]
+	);
+
+	grants.insert(SharedSpaceGrantKey {
+		scope: "project_shared".to_string(),
+		space_owner_agent_id: "owner".to_string(),
+	});
+
+	let denial = access::note_read_denial(&note, "reader", &scopes(), &grants, now);
+	let steps = access_simulate::trace_rules(&note, "reader", &grants, denial, now);
+
+	assert!(denial.is_none());
+	assert_eq!(
+		steps.last().map(|step| step.detail.as_str()),
+		Some("Grant project_shared:owner covers the agent.")
+	);
+}
+
+#[test]
+fn private_note_of_another_agent_fails_on_private_owner() {
+	let now = OffsetDateTime::UNIX_EPOCH;
+	let note = note("agent_private", "owner", now);
+	let grants = HashSet::new();
+	let denial = access::note_read_denial(&note, "reader", &scopes(), &grants, now);
+	let steps = access_simulate::trace_rules(&note, "reader", &grants, denial, now);
+
+	assert!(!access::note_read_allowed(&note, "reader", &scopes(), &grants, now));
+	assert_eq!(rules(&steps).last(), Some(&("private_owner", false)));
+	assert_eq!(steps.len(), 4);
+}
@@ -3,7 +3,7 @@ use uuid::Uuid;
 
 use crate::{
 	NoteOp, Result,
-	add_event::types::{AddEventContext, ExtractedNote},
+	add_event::types::{AddEventContext, NoteProcessingData},
 	ingest_audit::{self, IngestAuditArgs},
 };
 use elf_config::Config;
@@ -14,7 +14,7 @@ pub(super) async fn record_ingest_decision(
 	tx: &mut Transaction<'_, Postgres>,
 	cfg: &Config,
 	ctx: &AddEventContext<'_>,
-	note: &ExtractedNote,
+	note_data: &NoteProcessingData,
 	note_type: &str,
 	note_id: Option<Uuid>,
 	note_version_id: Option<Uuid>,
@@ -41,7 +41,7 @@ pub(super) async fn record_ingest_decision(
 		scope: ctx.scope,
 		pipeline: "add_event",
 		note_type,
-		note_key: note.key.as_deref(),
+		note_key: note_data.key.as_deref(),
 		note_id,
 		note_version_id,
 		base_decision,
@@ -53,8 +53,8 @@ pub(super) async fn record_ingest_decision(
 		matched_dup,
 		dup_sim_threshold: cfg.memory.dup_sim_threshold,
 		update_sim_threshold: cfg.memory.update_sim_threshold,
-		confidence: note.confidence.unwrap_or(0.0),
-		importance: note.importance.unwrap_or(0.0),
+		confidence: note_data.confidence,
+		importance: note_data.importance,
 		structured_present,
 		graph_present,
 		policy_rule,
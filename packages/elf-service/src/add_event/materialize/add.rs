@@ -26,6 +26,8 @@ pub(super) async fn persist_extracted_note_add(
 		args.req.agent_id.as_str(),
 	)
 	.await?;
+	crate::quotas::ensure_note_quota(tx, args.quotas, args.req.tenant_id.as_str(), args.now)
+		.await?;
 
 	let memory_note = MemoryNote {
 		note_id,
@@ -51,7 +53,7 @@ pub(super) async fn persist_extracted_note_add(
 	persistence::insert_memory_note_tx(tx, &memory_note).await?;
 
 	let note_version_id = crate::insert_version(
-		&mut **tx,
+		tx,
 		InsertVersionArgs {
 			note_id: memory_note.note_id,
 			op: "ADD",
@@ -75,7 +77,8 @@ pub(super) async fn persist_extracted_note_add(
 	persistence::upsert_structured_fields_tx(tx, args.structured, memory_note.note_id, args.now)
 		.await?;
 
-	if let Some(structured) = args.structured
+	if args.graph_enabled
+		&& let Some(structured) = args.structured
 		&& structured.has_graph_fields()
 	{
 		graph_ingestion::persist_graph_fields_tx(
@@ -100,6 +103,8 @@ pub(super) async fn persist_extracted_note_add(
 			reason: args.reason.cloned(),
 			field_path: None,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: None,
 		},
 		Some(note_version_id),
 	))
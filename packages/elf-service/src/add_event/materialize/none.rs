@@ -28,7 +28,7 @@ pub(super) async fn persist_extracted_note_none(
 		crate::enqueue_outbox_tx(&mut **tx, note_id, "UPSERT", args.embed_version, args.now)
 			.await?;
 	}
-	if structured.has_graph_fields() {
+	if args.graph_enabled && structured.has_graph_fields() {
 		graph_ingestion::persist_graph_fields_tx(
 			tx,
 			args.req.tenant_id.as_str(),
@@ -48,7 +48,7 @@ pub(super) async fn persist_extracted_note_none(
 		.await?;
 	let snapshot = crate::note_snapshot(&note_row);
 	let note_version_id = crate::insert_version(
-		&mut **tx,
+		tx,
 		InsertVersionArgs {
 			note_id,
 			op: "UPDATE",
@@ -81,6 +81,8 @@ pub(super) async fn persist_extracted_note_none(
 			reason: args.reason.cloned(),
 			field_path: None,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: None,
 		},
 		Some(note_version_id),
 	))
@@ -100,6 +102,8 @@ fn none_result(
 			reason,
 			field_path: None,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: None,
 		},
 		None,
 	)
@@ -44,8 +44,8 @@ pub(super) async fn persist_extracted_note_update(
 
 	persistence::update_memory_note_tx(tx, &existing).await?;
 
-	let note_version_id = crate::insert_version(
-		&mut **tx,
+	let version = crate::insert_update_version(
+		tx,
 		InsertVersionArgs {
 			note_id: existing.note_id,
 			op: "UPDATE",
@@ -55,21 +55,23 @@ pub(super) async fn persist_extracted_note_update(
 			actor: args.req.agent_id.as_str(),
 			ts: args.now,
 		},
+		args.version_coalesce_window_ms,
 	)
 	.await?;
 
-	crate::enqueue_outbox_tx(
-		&mut **tx,
+	crate::enqueue_upsert_outbox_tx(
+		tx,
 		existing.note_id,
-		"UPSERT",
 		existing.embedding_version.as_str(),
 		args.now,
+		version.coalesced,
 	)
 	.await?;
 	persistence::upsert_structured_fields_tx(tx, args.structured, existing.note_id, args.now)
 		.await?;
 
-	if let Some(structured) = args.structured
+	if args.graph_enabled
+		&& let Some(structured) = args.structured
 		&& structured.has_graph_fields()
 	{
 		graph_ingestion::persist_graph_fields_tx(
@@ -94,7 +96,9 @@ pub(super) async fn persist_extracted_note_update(
 			reason: args.reason.cloned(),
 			field_path: None,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: None,
 		},
-		Some(note_version_id),
+		Some(version.version_id),
 	))
 }
@@ -6,6 +6,10 @@ use crate::{
 	Result,
 	structured_fields::{self, StructuredFields},
 };
+use elf_domain::{
+	evidence::EvidenceCoverage,
+	normalization::{self, NormalizationLocale},
+};
 use elf_storage::models::MemoryNote;
 
 pub(super) async fn update_memory_note_tx(
@@ -122,3 +126,110 @@ pub(super) async fn upsert_structured_fields_tx(
 
 	Ok(())
 }
+
+pub(super) async fn upsert_note_evidence_tx(
+	tx: &mut Transaction<'_, Postgres>,
+	note_id: Uuid,
+	coverage: Option<EvidenceCoverage>,
+	quote_count: usize,
+	now: OffsetDateTime,
+) -> Result<()> {
+	let Some(coverage) = coverage else {
+		sqlx::query("DELETE FROM memory_note_evidence WHERE note_id = $1")
+			.bind(note_id)
+			.execute(&mut **tx)
+			.await?;
+
+		return Ok(());
+	};
+
+	sqlx::query(
+		"\
+INSERT INTO memory_note_evidence (
+	note_id,
+	claim_count,
+	backed_claim_count,
+	coverage,
+	quote_count,
+	computed_at
+)
+VALUES ($1, $2, $3, $4, $5, $6)
+ON CONFLICT (note_id) DO UPDATE
+SET
+	claim_count = EXCLUDED.claim_count,
+	backed_claim_count = EXCLUDED.backed_claim_count,
+	coverage = EXCLUDED.coverage,
+	quote_count = EXCLUDED.quote_count,
+	computed_at = EXCLUDED.computed_at",
+	)
+	.bind(note_id)
+	.bind(coverage.claim_count as i32)
+	.bind(coverage.backed_claim_count as i32)
+	.bind(coverage.coverage)
+	.bind(quote_count as i32)
+	.bind(now)
+	.execute(&mut **tx)
+	.await?;
+
+	Ok(())
+}
+
+/// Replaces the normalized dates, quantities, and currency amounts found in a note's text and
+/// facts.
+pub(super) async fn replace_note_values_tx(
+	tx: &mut Transaction<'_, Postgres>,
+	note_id: Uuid,
+	text: &str,
+	structured: Option<&StructuredFields>,
+	locale: Option<&str>,
+	now: OffsetDateTime,
+) -> Result<()> {
+	sqlx::query("DELETE FROM memory_note_values WHERE note_id = $1")
+		.bind(note_id)
+		.execute(&mut **tx)
+		.await?;
+
+	let normalization_locale = locale.and_then(NormalizationLocale::from_tag).unwrap_or_default();
+	let facts = structured.and_then(|structured| structured.facts.as_deref()).unwrap_or_default();
+	let sources = std::iter::once(("text", 0, text))
+		.chain(facts.iter().enumerate().map(|(idx, fact)| ("fact", idx, fact.as_str())));
+
+	for (field_kind, item_index, source) in sources {
+		for value in normalization::normalize_values(source, normalization_locale) {
+			sqlx::query(
+				"\
+INSERT INTO memory_note_values (
+	value_id,
+	note_id,
+	field_kind,
+	item_index,
+	value_kind,
+	raw,
+	normalized,
+	numeric_value,
+	unit,
+	date_value,
+	locale,
+	created_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+			)
+			.bind(Uuid::new_v4())
+			.bind(note_id)
+			.bind(field_kind)
+			.bind(item_index as i32)
+			.bind(value.kind.as_str())
+			.bind(value.raw.as_str())
+			.bind(value.normalized.as_str())
+			.bind(value.number)
+			.bind(value.unit.as_deref())
+			.bind(value.date)
+			.bind(locale)
+			.bind(now)
+			.execute(&mut **tx)
+			.await?;
+		}
+	}
+
+	Ok(())
+}
@@ -66,6 +66,8 @@ pub(super) fn build_result_from_decision(
 			reason,
 			field_path: None,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: None,
 		},
 		UpdateDecision::Update { note_id, .. } => AddEventResult {
 			note_id: Some(*note_id),
@@ -75,6 +77,8 @@ pub(super) fn build_result_from_decision(
 			reason,
 			field_path: None,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: None,
 		},
 		UpdateDecision::None { note_id, .. } => AddEventResult {
 			note_id: Some(*note_id),
@@ -84,6 +88,8 @@ pub(super) fn build_result_from_decision(
 			reason,
 			field_path: None,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: None,
 		},
 	}
 }
@@ -1,13 +1,14 @@
 use sqlx::{Postgres, Transaction};
 
 use crate::{
-	NoteOp, Result,
+	NoteOp, RATE_ANOMALY, Result,
 	add_event::{
 		audit,
 		types::{AddEventContext, AddEventResult, ExtractedNote, NoteProcessingData},
 		validation::{self, REJECT_STRUCTURED_INVALID},
 	},
 	ingestion_profiles::IngestionProfileRef,
+	write_anomaly::{self, WriteAnomalyAction, WriteAnomalyArgs},
 };
 use elf_config::Config;
 use elf_domain::{memory_policy::MemoryPolicyDecision, writegate::WritePolicyAudit};
@@ -23,6 +24,7 @@ pub(super) async fn record_extracted_note_rejections(
 	message_texts: &[String],
 	message_policy_applied: &[bool],
 	write_policy_audits: Option<&Vec<WritePolicyAudit>>,
+	dry_run: bool,
 ) -> Result<Option<AddEventResult>> {
 	if let Some(result) = validation::reject_extracted_note_if_evidence_invalid(
 		cfg,
@@ -35,66 +37,37 @@ pub(super) async fn record_extracted_note_rejections(
 
 		result.write_policy_audits = write_policy_audits.cloned();
 
-		audit::record_ingest_decision(
+		record_rejection(
 			tx,
 			cfg,
 			ctx,
-			note,
-			note_data.note_type.as_str(),
-			None,
-			None,
-			MemoryPolicyDecision::Reject,
-			MemoryPolicyDecision::Reject,
-			NoteOp::Rejected,
+			ingestion_profile,
+			note_data,
 			result.reason_code.as_deref(),
-			None,
-			None,
-			false,
-			false,
-			None,
-			None,
-			Some(ingestion_profile.id.as_str()),
-			Some(ingestion_profile.version),
-			note_data.structured_present,
-			note_data.graph_present,
-			write_policy_audits.cloned(),
+			write_policy_audits,
 		)
 		.await?;
 
 		return Ok(Some(result));
 	} else if let Some(result) = validation::reject_extracted_note_if_structured_invalid(
+		note_data.note_type.as_str(),
 		note_data.structured.as_ref(),
 		note_data.text.as_str(),
-		&note_data.evidence,
+		&note_data.persisted_evidence,
 		note.reason.as_ref(),
 	) {
 		let mut result = result;
 
 		result.write_policy_audits = write_policy_audits.cloned();
 
-		audit::record_ingest_decision(
+		record_rejection(
 			tx,
 			cfg,
 			ctx,
-			note,
-			note_data.note_type.as_str(),
-			None,
-			None,
-			MemoryPolicyDecision::Reject,
-			MemoryPolicyDecision::Reject,
-			NoteOp::Rejected,
+			ingestion_profile,
+			note_data,
 			Some(REJECT_STRUCTURED_INVALID),
-			None,
-			None,
-			false,
-			false,
-			None,
-			None,
-			Some(ingestion_profile.id.as_str()),
-			Some(ingestion_profile.version),
-			note_data.structured_present,
-			note_data.graph_present,
-			write_policy_audits.cloned(),
+			write_policy_audits,
 		)
 		.await?;
 
@@ -110,34 +83,99 @@ pub(super) async fn record_extracted_note_rejections(
 
 		result.write_policy_audits = write_policy_audits.cloned();
 
-		audit::record_ingest_decision(
+		record_rejection(
 			tx,
 			cfg,
 			ctx,
-			note,
-			note_data.note_type.as_str(),
-			None,
-			None,
-			MemoryPolicyDecision::Reject,
-			MemoryPolicyDecision::Reject,
-			NoteOp::Rejected,
+			ingestion_profile,
+			note_data,
 			result.reason_code.as_deref(),
-			None,
-			None,
-			false,
-			false,
-			None,
-			None,
-			Some(ingestion_profile.id.as_str()),
-			Some(ingestion_profile.version),
-			note_data.structured_present,
-			note_data.graph_present,
-			write_policy_audits.cloned(),
+			write_policy_audits,
 		)
 		.await?;
 
 		return Ok(Some(result));
 	}
 
+	let anomaly_args = WriteAnomalyArgs {
+		tenant_id: ctx.tenant_id,
+		project_id: ctx.project_id,
+		agent_id: ctx.agent_id,
+		pipeline: "add_event",
+		text: note_data.text.as_str(),
+		now: ctx.now,
+	};
+
+	if let Some(verdict) = write_anomaly::detect_write_anomaly(tx, cfg, &anomaly_args).await? {
+		if !dry_run {
+			write_anomaly::record_write_incident(tx, cfg, &anomaly_args, &verdict).await?;
+		}
+		if verdict.action == WriteAnomalyAction::Reject {
+			let result = AddEventResult {
+				note_id: None,
+				op: NoteOp::Rejected,
+				policy_decision: MemoryPolicyDecision::Reject,
+				reason_code: Some(RATE_ANOMALY.to_string()),
+				reason: note.reason.clone(),
+				field_path: None,
+				write_policy_audits: write_policy_audits.cloned(),
+				evidence_coverage: None,
+				reject_feedback: None,
+			};
+
+			record_rejection(
+				tx,
+				cfg,
+				ctx,
+				ingestion_profile,
+				note_data,
+				Some(RATE_ANOMALY),
+				write_policy_audits,
+			)
+			.await?;
+
+			return Ok(Some(result));
+		}
+	}
+
 	Ok(None)
 }
+
+#[allow(clippy::too_many_arguments)]
+async fn record_rejection(
+	tx: &mut Transaction<'_, Postgres>,
+	cfg: &Config,
+	ctx: &AddEventContext<'_>,
+	ingestion_profile: &IngestionProfileRef,
+	note_data: &NoteProcessingData,
+	reason_code: Option<&str>,
+	write_policy_audits: Option<&Vec<WritePolicyAudit>>,
+) -> Result<()> {
+	audit::record_ingest_decision(
+		tx,
+		cfg,
+		ctx,
+		note_data,
+		note_data.note_type.as_str(),
+		None,
+		None,
+		MemoryPolicyDecision::Reject,
+		MemoryPolicyDecision::Reject,
+		NoteOp::Rejected,
+		reason_code,
+		None,
+		None,
+		false,
+		false,
+		None,
+		None,
+		Some(ingestion_profile.id.as_str()),
+		Some(ingestion_profile.version),
+		note_data.structured_present,
+		note_data.graph_present,
+		write_policy_audits.cloned(),
+	)
+	.await?;
+
+	Ok(())
+}
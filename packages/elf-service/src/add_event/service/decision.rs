@@ -1,11 +1,12 @@
 use sqlx::{PgConnection, Postgres, Transaction};
 use time::OffsetDateTime;
+use uuid::Uuid;
 
 use crate::{
-	ElfService, ResolveUpdateArgs, Result, UpdateDecision,
+	ElfService, NoteOp, ResolveUpdateArgs, Result, UpdateDecision,
 	access::ORG_PROJECT_ID,
 	add_event::{
-		audit, materialize,
+		audit, materialize, persistence,
 		policy::{self},
 		types::{
 			AddEventContext, AddEventRequest, AddEventResult, ExtractedNote, NoteProcessingData,
@@ -33,7 +34,7 @@ impl ElfService {
 		dry_run: bool,
 		write_policy_audits: Option<&Vec<WritePolicyAudit>>,
 	) -> Result<AddEventResult> {
-		let decision = self.resolve_extracted_note_update(note, req, note_data, tx, now).await?;
+		let decision = self.resolve_extracted_note_update(req, note_data, tx, now).await?;
 		let metadata = decision.metadata();
 		let base_decision = policy::base_decision_for_update(
 			&decision,
@@ -66,13 +67,14 @@ impl ElfService {
 		);
 
 		let mut note_version_id = None;
+		let evidence_coverage = note_data.evidence_coverage();
 
 		if should_apply && !dry_run {
 			let persist_args = PersistExtractedNoteArgs {
 				req,
 				project_id,
 				structured: note_data.structured.as_ref(),
-				key: note.key.as_deref(),
+				key: note_data.key.as_deref(),
 				reason: note.reason.as_ref(),
 				note_type,
 				text: note_data.text.as_str(),
@@ -86,7 +88,7 @@ impl ElfService {
 					now,
 				),
 				source_ref: serde_json::json!({
-					"evidence": note_data.evidence.clone(),
+					"evidence": note_data.persisted_evidence.clone(),
 					"reason": note_data.reason.clone().unwrap_or_default(),
 					"ingestion_profile": serde_json::json!({
 						"id": ingestion_profile.id,
@@ -95,8 +97,11 @@ impl ElfService {
 				}),
 				now,
 				embed_version,
+				version_coalesce_window_ms: self.cfg.memory.version_coalesce_window_ms,
+				graph_enabled: self.subsystems.graph,
+				quotas: self.cfg.security.quotas.as_ref(),
 			};
-			let persisted = materialize::persist_extracted_note_decision(
+			(result, note_version_id) = materialize::persist_extracted_note_decision(
 				tx,
 				persist_args,
 				decision,
@@ -104,17 +109,18 @@ impl ElfService {
 			)
 			.await?;
 
-			result = persisted.0;
-			note_version_id = persisted.1;
+			persist_note_side_records(tx, req, note_data, &result, note_version_id, now).await?;
 		}
 
+		result.evidence_coverage = evidence_coverage.map(|coverage| coverage.coverage);
+
 		result.write_policy_audits = write_policy_audits.cloned();
 
 		audit::record_ingest_decision(
 			tx,
 			&self.cfg,
 			ctx,
-			note,
+			note_data,
 			note_data.note_type.as_str(),
 			result.note_id,
 			note_version_id,
@@ -141,7 +147,6 @@ impl ElfService {
 
 	async fn resolve_extracted_note_update(
 		&self,
-		note: &ExtractedNote,
 		req: &AddEventRequest,
 		note_data: &NoteProcessingData,
 		tx: &mut PgConnection,
@@ -161,11 +166,49 @@ impl ElfService {
 				agent_id: req.agent_id.as_str(),
 				scope: note_data.scope.as_str(),
 				note_type: note_data.note_type.as_str(),
-				key: note.key.as_deref(),
+				key: note_data.key.as_deref(),
 				text: note_data.text.as_str(),
 				now,
+				embedding: None,
 			},
 		)
 		.await
 	}
 }
+
+/// Records PII redactions, evidence coverage, and extracted values for an applied note.
+async fn persist_note_side_records(
+	tx: &mut Transaction<'_, Postgres>,
+	req: &AddEventRequest,
+	note_data: &NoteProcessingData,
+	result: &AddEventResult,
+	note_version_id: Option<Uuid>,
+	now: OffsetDateTime,
+) -> Result<()> {
+	if let Some(version_id) = note_version_id
+		&& !note_data.pii_redactions.is_empty()
+	{
+		crate::record_version_redactions(&mut **tx, version_id, &note_data.pii_redactions).await?;
+	}
+	if let (Some(note_id), NoteOp::Add | NoteOp::Update) = (result.note_id, result.op) {
+		persistence::upsert_note_evidence_tx(
+			tx,
+			note_id,
+			note_data.evidence_coverage(),
+			note_data.evidence.len(),
+			now,
+		)
+		.await?;
+		persistence::replace_note_values_tx(
+			tx,
+			note_id,
+			note_data.text.as_str(),
+			note_data.structured.as_ref(),
+			req.locale.as_deref(),
+			now,
+		)
+		.await?;
+	}
+
+	Ok(())
+}
@@ -1,19 +1,33 @@
 use time::{Duration, OffsetDateTime};
 
 use crate::{
-	ElfService, Error, Result,
+	ElfService, Error, Result, WriteOperation,
 	add_event::{
 		types::{AddEventRequest, AddEventResponse, ExtractorOutput},
 		validation,
 	},
+	audit::AuditScope,
 	ingestion_profiles,
 };
 
 impl ElfService {
 	/// Extracts notes from an event transcript and optionally persists the accepted results.
 	pub async fn add_event(&self, req: AddEventRequest) -> Result<AddEventResponse> {
+		let scope =
+			AuditScope::new("add_event").caller(&req.tenant_id, &req.project_id, &req.agent_id);
+
+		self.audited(scope, self.add_event_inner(req)).await
+	}
+
+	/// Runs [`ElfService::add_event`] without recording an audit entry.
+	async fn add_event_inner(&self, req: AddEventRequest) -> Result<AddEventResponse> {
 		validation::validate_add_event_request(&req)?;
 
+		match req.scope.as_deref() {
+			Some(scope) => self.authorize_write(req.role, WriteOperation::AddEvent, scope)?,
+			None => self.authorize_write_any(req.role, WriteOperation::AddEvent)?,
+		}
+
 		let resolved_profile = ingestion_profiles::resolve_add_event_profile(
 			&self.db.pool,
 			req.tenant_id.as_str(),
@@ -50,7 +64,6 @@ impl ElfService {
 			Error::InvalidRequest { message: "Failed to serialize extracted notes.".to_string() }
 		})?;
 		let base_now = OffsetDateTime::now_utc();
-		let embed_version = crate::embedding_version(&self.cfg);
 		let dry_run = req.dry_run.unwrap_or(false);
 		let mut results = Vec::with_capacity(extracted.notes.len());
 
@@ -66,7 +79,6 @@ impl ElfService {
 					write_policy_audits.as_ref(),
 					note,
 					now,
-					embed_version.as_str(),
 					dry_run,
 				)
 				.await?,
@@ -1,7 +1,7 @@
 use time::OffsetDateTime;
 
 use crate::{
-	ElfService, Result,
+	ElfService, NoteOp, Result, WriteOperation,
 	access::ORG_PROJECT_ID,
 	add_event::{
 		rejection,
@@ -11,7 +11,7 @@ use crate::{
 	},
 	ingestion_profiles::IngestionProfileRef,
 };
-use elf_domain::writegate::WritePolicyAudit;
+use elf_domain::{memory_policy::MemoryPolicyDecision, writegate::WritePolicyAudit};
 
 impl ElfService {
 	#[allow(clippy::too_many_arguments)]
@@ -24,10 +24,33 @@ impl ElfService {
 		write_policy_audits: Option<&Vec<WritePolicyAudit>>,
 		note: ExtractedNote,
 		now: OffsetDateTime,
-		embed_version: &str,
 		dry_run: bool,
 	) -> Result<AddEventResult> {
-		let note_data = NoteProcessingData::from_request_and_note(req, &note);
+		let note_data = NoteProcessingData::from_request_and_note(req, &note, &self.cfg);
+
+		// An explicit request scope was authorized up front; extractor-suggested scopes are
+		// checked per note so one denied suggestion does not fail the whole batch.
+		if req.scope.is_none()
+			&& let Err(err) =
+				self.authorize_write(req.role, WriteOperation::AddEvent, note_data.scope.as_str())
+		{
+			return Ok(AddEventResult {
+				note_id: None,
+				op: NoteOp::Rejected,
+				policy_decision: MemoryPolicyDecision::Reject,
+				reason_code: Some("REJECT_SCOPE_DENIED".to_string()),
+				reason: Some(err.to_string()),
+				field_path: None,
+				write_policy_audits: write_policy_audits.cloned(),
+				evidence_coverage: None,
+				reject_feedback: None,
+			});
+		}
+		let embed_version = crate::embedding_version_for_note(
+			&self.cfg,
+			note_data.scope.as_str(),
+			note_data.note_type.as_str(),
+		);
 		let effective_project_id = if note_data.scope.trim() == "org_shared" {
 			ORG_PROJECT_ID
 		} else {
@@ -52,6 +75,7 @@ impl ElfService {
 			message_texts,
 			message_policy_applied,
 			write_policy_audits,
+			dry_run,
 		)
 		.await?
 		{
@@ -71,7 +95,7 @@ impl ElfService {
 				note_data.note_type.as_str(),
 				effective_project_id,
 				now,
-				embed_version,
+				embed_version.as_str(),
 				dry_run,
 				write_policy_audits,
 			)
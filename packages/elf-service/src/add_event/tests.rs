@@ -1,10 +1,21 @@
+use std::path::PathBuf;
+
 use crate::{
-	Error,
+	Error, REJECT_EVIDENCE_MISMATCH,
 	add_event::{
-		types::{AddEventRequest, EventMessage},
+		types::{AddEventRequest, EventMessage, EvidenceQuote},
 		validation,
 	},
 };
+use elf_config::Config;
+use elf_domain::writegate::{RejectDetail, RejectFix};
+
+fn parse_example_config() -> Config {
+	let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+	let path = root_dir.join("elf.example.toml");
+
+	elf_config::load(&path).expect("elf.example.toml must remain parseable and valid.")
+}
 
 #[test]
 fn rejects_long_non_english_message_content() {
@@ -12,9 +23,11 @@ fn rejects_long_non_english_message_content() {
 			tenant_id: "t".to_string(),
 			project_id: "p".to_string(),
 			agent_id: "a".to_string(),
+			role: None,
 			scope: None,
 			dry_run: None,
 			ingestion_profile: None,
+			locale: None,
 			messages: vec![EventMessage {
 				role: "user".to_string(),
 					content: "Bonjour, je veux m'assurer que ce texte est suffisamment long et riche en lettres pour declencher la detection de langue. Merci beaucoup."
@@ -32,3 +45,66 @@ fn rejects_long_non_english_message_content() {
 		Error::NonEnglishInput { field } if field == "$.messages[0].content"
 	));
 }
+
+#[test]
+fn rejects_malformed_locale() {
+	let req = AddEventRequest {
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		role: None,
+		scope: None,
+		dry_run: None,
+		ingestion_profile: None,
+		locale: Some("english".to_string()),
+		messages: vec![EventMessage {
+			role: "user".to_string(),
+			content: "The rollout finished on March 14, 2026.".to_string(),
+			ts: None,
+			msg_id: None,
+			write_policy: None,
+		}],
+	};
+	let err = validation::validate_add_event_request(&req).expect_err("Expected locale rejection.");
+
+	assert!(matches!(err, Error::InvalidRequest { message } if message.starts_with("locale")));
+}
+
+#[test]
+fn evidence_rejections_carry_the_failing_quote_index() {
+	let cfg = parse_example_config();
+	let message_texts = vec!["Deploys run from the release branch.".to_string()];
+	let evidence = vec![
+		EvidenceQuote { message_index: 0, quote: "the release branch".to_string() },
+		EvidenceQuote { message_index: 0, quote: "the main branch".to_string() },
+	];
+	let result = validation::reject_extracted_note_if_evidence_invalid(
+		&cfg,
+		None,
+		&evidence,
+		&message_texts,
+		&[false],
+	)
+	.expect("Expected evidence rejection.");
+	let feedback = result.reject_feedback.expect("Expected reject feedback.");
+
+	assert_eq!(result.reason_code.as_deref(), Some(REJECT_EVIDENCE_MISMATCH));
+	assert_eq!(feedback.suggested_fix, RejectFix::QuoteVerbatim);
+	assert_eq!(
+		feedback.detail,
+		RejectDetail::EvidenceQuoteNotFound { quote_index: 1, message_index: 0 }
+	);
+
+	let result =
+		validation::reject_extracted_note_if_evidence_invalid(&cfg, None, &[], &message_texts, &[])
+			.expect("Expected evidence rejection.");
+
+	assert_eq!(
+		result.reject_feedback.map(|feedback| feedback.detail),
+		Some(RejectDetail::EvidenceQuoteCount {
+			quote_count: 0,
+			min_quotes: cfg.security.evidence_min_quotes,
+			max_quotes: cfg.security.evidence_max_quotes,
+		})
+	);
+}
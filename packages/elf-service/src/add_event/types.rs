@@ -4,13 +4,16 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::{
-	NoteOp,
+	NoteOp, PiiRedactor,
+	audit::AuditTargets,
 	ingestion_profiles::{IngestionProfileRef, IngestionProfileSelector},
 	structured_fields::StructuredFields,
 };
+use elf_config::{Config, SecurityAuthRole, SecurityQuotas};
 use elf_domain::{
+	evidence::{self, EvidenceCoverage},
 	memory_policy::MemoryPolicyDecision,
-	writegate::{WritePolicy, WritePolicyAudit},
+	writegate::{PiiRedaction, RejectFeedback, WritePolicy, WritePolicyAudit},
 };
 
 pub(super) type ProcessedEventOutput =
@@ -41,12 +44,20 @@ pub struct AddEventRequest {
 	pub project_id: String,
 	/// Agent that emitted the event batch.
 	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against each extracted note's scope; `None` when
+	/// auth is off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
 	/// Optional explicit scope override for extracted notes.
 	pub scope: Option<String>,
 	/// When true, performs validation and extraction without persisting notes.
 	pub dry_run: Option<bool>,
 	/// Optional ingestion profile selector.
 	pub ingestion_profile: Option<IngestionProfileSelector>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Optional BCP 47 locale tag, such as `en-US`, used to read ambiguous dates and numbers
+	/// when normalizing extracted values.
+	pub locale: Option<String>,
 	/// Source messages to extract notes from.
 	pub messages: Vec<EventMessage>,
 }
@@ -68,6 +79,13 @@ pub struct AddEventResult {
 	pub field_path: Option<String>,
 	/// Per-message write-policy audits when write policies were applied.
 	pub write_policy_audits: Option<Vec<WritePolicyAudit>>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Share of the note's sentence-level claims backed by its evidence quotes, in the range
+	/// 0.0-1.0. Absent for rejected notes and notes without claims.
+	pub evidence_coverage: Option<f32>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Remediation data for a write-gate or evidence rejection, if any.
+	pub reject_feedback: Option<RejectFeedback>,
 }
 
 /// Response payload for event-driven note extraction.
@@ -81,6 +99,20 @@ pub struct AddEventResponse {
 	pub ingestion_profile: Option<IngestionProfileRef>,
 }
 
+impl AuditTargets for AddEventResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		self.results.iter().filter_map(|result| result.note_id).collect()
+	}
+
+	fn audit_reject_reasons(&self) -> Vec<String> {
+		self.results
+			.iter()
+			.filter(|result| result.op == NoteOp::Rejected)
+			.filter_map(|result| result.reason_code.clone())
+			.collect()
+	}
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub(super) struct ExtractorOutput {
 	pub notes: Vec<ExtractedNote>,
@@ -108,6 +140,7 @@ pub(super) struct EvidenceQuote {
 
 pub(super) struct NoteProcessingData {
 	pub(super) note_type: String,
+	pub(super) key: Option<String>,
 	pub(super) text: String,
 	pub(super) structured: Option<StructuredFields>,
 	pub(super) importance: f32,
@@ -115,21 +148,45 @@ pub(super) struct NoteProcessingData {
 	pub(super) reason: Option<String>,
 	pub(super) ttl_days: Option<i64>,
 	pub(super) scope: String,
+	/// Evidence quotes as extracted, checked against the source messages.
 	pub(super) evidence: Vec<EvidenceQuote>,
+	/// Evidence quotes after `security.pii` redaction, as stored in the note's source_ref.
+	pub(super) persisted_evidence: Vec<EvidenceQuote>,
 	pub(super) structured_present: bool,
 	pub(super) graph_present: bool,
+	pub(super) pii_redactions: Vec<PiiRedaction>,
 }
 impl NoteProcessingData {
-	pub(super) fn from_request_and_note(req: &AddEventRequest, note: &ExtractedNote) -> Self {
+	/// Collects the effective note fields, with `security.pii` redaction already applied to the
+	/// key, text, structured fields, and persisted evidence quotes.
+	pub(super) fn from_request_and_note(
+		req: &AddEventRequest,
+		note: &ExtractedNote,
+		cfg: &Config,
+	) -> Self {
 		let note_type = note.r#type.clone().unwrap_or_default();
-		let text = note.text.clone().unwrap_or_default();
-		let structured = note.structured.clone();
+		let mut redactor = PiiRedactor::new(cfg);
+		let mut key = note.key.clone();
+		let mut text = note.text.clone().unwrap_or_default();
+		let mut structured = note.structured.clone();
+		let evidence = note.evidence.clone().unwrap_or_default();
+		let mut persisted_evidence = evidence.clone();
+
+		redactor.field("$.text", &mut text);
+		redactor.key(key.as_mut());
+		redactor.structured(structured.as_mut());
+
+		for (idx, quote) in persisted_evidence.iter_mut().enumerate() {
+			redactor.field(&format!("$.evidence[{idx}].quote"), &mut quote.quote);
+		}
+
 		let structured_present =
 			structured.as_ref().is_some_and(|value| !value.is_effectively_empty());
 		let graph_present = structured.as_ref().is_some_and(StructuredFields::has_graph_fields);
 
 		Self {
 			note_type,
+			key,
 			text,
 			structured,
 			importance: note.importance.unwrap_or(0.0),
@@ -137,11 +194,20 @@ impl NoteProcessingData {
 			reason: note.reason.clone(),
 			ttl_days: note.ttl_days,
 			scope: req.scope.clone().or(note.scope_suggestion.clone()).unwrap_or_default(),
-			evidence: note.evidence.clone().unwrap_or_default(),
+			evidence,
+			persisted_evidence,
 			structured_present,
 			graph_present,
+			pii_redactions: redactor.finish(),
 		}
 	}
+
+	pub(super) fn evidence_coverage(&self) -> Option<EvidenceCoverage> {
+		let quotes: Vec<&str> =
+			self.persisted_evidence.iter().map(|quote| quote.quote.as_str()).collect();
+
+		evidence::evidence_coverage(self.text.as_str(), &quotes)
+	}
 }
 
 pub(super) struct PersistExtractedNoteArgs<'a> {
@@ -159,6 +225,9 @@ pub(super) struct PersistExtractedNoteArgs<'a> {
 	pub(super) source_ref: Value,
 	pub(super) now: OffsetDateTime,
 	pub(super) embed_version: &'a str,
+	pub(super) version_coalesce_window_ms: Option<u64>,
+	pub(super) graph_enabled: bool,
+	pub(super) quotas: Option<&'a SecurityQuotas>,
 }
 
 pub(super) struct AddEventContext<'a> {
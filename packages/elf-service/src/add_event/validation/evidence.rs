@@ -3,7 +3,11 @@ use crate::{
 	add_event::types::{AddEventResult, EvidenceQuote},
 };
 use elf_config::Config;
-use elf_domain::{evidence, memory_policy::MemoryPolicyDecision};
+use elf_domain::{
+	evidence,
+	memory_policy::MemoryPolicyDecision,
+	writegate::{RejectDetail, RejectFeedback, RejectFix},
+};
 
 pub(in crate::add_event) fn reject_extracted_note_if_evidence_invalid(
 	cfg: &Config,
@@ -16,48 +20,74 @@ pub(in crate::add_event) fn reject_extracted_note_if_evidence_invalid(
 		|| evidence.len() < cfg.security.evidence_min_quotes as usize
 		|| evidence.len() > cfg.security.evidence_max_quotes as usize
 	{
-		return Some(AddEventResult {
-			note_id: None,
-			op: NoteOp::Rejected,
-			policy_decision: MemoryPolicyDecision::Reject,
-			reason_code: Some(REJECT_EVIDENCE_MISMATCH.to_string()),
-			reason: reason.cloned(),
-			field_path: None,
-			write_policy_audits: None,
-		});
+		return Some(evidence_rejection(
+			REJECT_EVIDENCE_MISMATCH,
+			reason,
+			RejectFeedback {
+				suggested_fix: RejectFix::AdjustQuoteCount,
+				detail: RejectDetail::EvidenceQuoteCount {
+					quote_count: evidence.len(),
+					min_quotes: cfg.security.evidence_min_quotes,
+					max_quotes: cfg.security.evidence_max_quotes,
+				},
+			},
+		));
 	}
 
-	for quote in evidence {
+	for (quote_index, quote) in evidence.iter().enumerate() {
 		if quote.quote.len() > cfg.security.evidence_max_quote_chars as usize {
-			return Some(AddEventResult {
-				note_id: None,
-				op: NoteOp::Rejected,
-				policy_decision: MemoryPolicyDecision::Reject,
-				reason_code: Some(REJECT_EVIDENCE_MISMATCH.to_string()),
-				reason: reason.cloned(),
-				field_path: None,
-				write_policy_audits: None,
-			});
+			return Some(evidence_rejection(
+				REJECT_EVIDENCE_MISMATCH,
+				reason,
+				RejectFeedback {
+					suggested_fix: RejectFix::ShortenQuote,
+					detail: RejectDetail::EvidenceQuoteTooLong {
+						quote_index,
+						quote_len: quote.quote.len(),
+						max_quote_chars: cfg.security.evidence_max_quote_chars,
+					},
+				},
+			));
 		}
 		if !evidence::evidence_matches(message_texts, quote.message_index, quote.quote.as_str()) {
-			let reason_code =
+			let policy_applied =
 				message_policy_applied.get(quote.message_index).is_some_and(|applied| *applied);
 
-			return Some(AddEventResult {
-				note_id: None,
-				op: NoteOp::Rejected,
-				policy_decision: MemoryPolicyDecision::Reject,
-				reason_code: Some(if reason_code {
-					REJECT_WRITE_POLICY_MISMATCH.to_string()
+			return Some(evidence_rejection(
+				if policy_applied {
+					REJECT_WRITE_POLICY_MISMATCH
 				} else {
-					REJECT_EVIDENCE_MISMATCH.to_string()
-				}),
-				reason: reason.cloned(),
-				field_path: None,
-				write_policy_audits: None,
-			});
+					REJECT_EVIDENCE_MISMATCH
+				},
+				reason,
+				RejectFeedback {
+					suggested_fix: RejectFix::QuoteVerbatim,
+					detail: RejectDetail::EvidenceQuoteNotFound {
+						quote_index,
+						message_index: quote.message_index,
+					},
+				},
+			));
 		}
 	}
 
 	None
 }
+
+fn evidence_rejection(
+	reason_code: &str,
+	reason: Option<&String>,
+	feedback: RejectFeedback,
+) -> AddEventResult {
+	AddEventResult {
+		note_id: None,
+		op: NoteOp::Rejected,
+		policy_decision: MemoryPolicyDecision::Reject,
+		reason_code: Some(reason_code.to_string()),
+		reason: reason.cloned(),
+		field_path: None,
+		write_policy_audits: None,
+		evidence_coverage: None,
+		reject_feedback: Some(feedback),
+	}
+}
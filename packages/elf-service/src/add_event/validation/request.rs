@@ -1,5 +1,5 @@
 use crate::{Error, Result, add_event::types::AddEventRequest};
-use elf_domain::english_gate;
+use elf_domain::{english_gate, normalization::NormalizationLocale};
 
 pub(in crate::add_event) fn validate_add_event_request(req: &AddEventRequest) -> Result<()> {
 	if req.messages.is_empty() {
@@ -37,6 +37,14 @@ pub(in crate::add_event) fn validate_add_event_request(req: &AddEventRequest) ->
 		}
 	}
 
+	if let Some(locale) = req.locale.as_deref()
+		&& NormalizationLocale::from_tag(locale).is_none()
+	{
+		return Err(Error::InvalidRequest {
+			message: "locale must be a BCP 47 language tag such as en-US.".to_string(),
+		});
+	}
+
 	for (idx, msg) in req.messages.iter().enumerate() {
 		if !english_gate::is_english_natural_language(msg.content.as_str()) {
 			return Err(Error::NonEnglishInput { field: format!("$.messages[{idx}].content") });
@@ -9,26 +9,31 @@ use crate::{
 use elf_domain::memory_policy::MemoryPolicyDecision;
 
 pub(in crate::add_event) fn reject_extracted_note_if_structured_invalid(
+	note_type: &str,
 	structured: Option<&StructuredFields>,
 	text: &str,
 	evidence: &[EvidenceQuote],
 	reason: Option<&String>,
 ) -> Option<AddEventResult> {
-	let structured = structured?;
+	let result =
+		structured_fields::validate_note_type_fields(note_type, structured).and_then(|()| {
+			match structured {
+				Some(structured) if !structured.is_effectively_empty() => {
+					let event_evidence: Vec<(usize, String)> =
+						evidence.iter().map(|q| (q.message_index, q.quote.clone())).collect();
 
-	if structured.is_effectively_empty() {
-		return None;
-	}
-
-	let event_evidence: Vec<(usize, String)> =
-		evidence.iter().map(|q| (q.message_index, q.quote.clone())).collect();
+					structured_fields::validate_structured_fields(
+						structured,
+						text,
+						&serde_json::json!({}),
+						Some(event_evidence.as_slice()),
+					)
+				},
+				_ => Ok(()),
+			}
+		});
 
-	if let Err(err) = structured_fields::validate_structured_fields(
-		structured,
-		text,
-		&serde_json::json!({}),
-		Some(event_evidence.as_slice()),
-	) {
+	if let Err(err) = result {
 		tracing::info!(error = %err, "Rejecting extracted note due to invalid structured fields.");
 
 		let field_path = extract_structured_rejection_field_path(&err);
@@ -41,6 +46,8 @@ pub(in crate::add_event) fn reject_extracted_note_if_structured_invalid(
 			reason: reason.cloned(),
 			field_path,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: None,
 		});
 	}
 
@@ -27,6 +27,8 @@ pub(in crate::add_event) fn reject_extracted_note_if_writegate_rejects(
 			reason: reason.cloned(),
 			field_path: None,
 			write_policy_audits: None,
+			evidence_coverage: None,
+			reject_feedback: writegate::reject_feedback(&gate_input, cfg, code),
 		});
 	}
 
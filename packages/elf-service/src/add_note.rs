@@ -1,6 +1,7 @@
 //! Direct note ingestion APIs.
 
 mod audit;
+mod bulk_import;
 mod materialize;
 mod persistence;
 mod policy;
@@ -9,6 +10,9 @@ mod service;
 mod types;
 mod validation;
 
-pub use types::{AddNoteInput, AddNoteRequest, AddNoteResponse, AddNoteResult};
+pub use self::{
+	bulk_import::{BulkImportLineResult, BulkImportRequest, BulkImportResponse, BulkImporter},
+	types::{AddNoteInput, AddNoteRequest, AddNoteResponse, AddNoteResult},
+};
 
 #[cfg(test)] mod tests;
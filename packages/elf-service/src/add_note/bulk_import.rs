@@ -0,0 +1,358 @@
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, NoteOp, Result, WriteOperation,
+	access::ORG_PROJECT_ID,
+	add_note::{
+		service,
+		types::{AddNoteContext, AddNoteInput, AddNoteRequest, AddNoteResult, NoteWriteTransforms},
+		validation,
+	},
+	audit::{AuditScope, AuditTargets, PendingAudit},
+};
+use elf_config::{EmbeddingProviderConfig, SecurityAuthRole};
+
+/// Notes processed per chunk: one embedding call and one transaction each.
+const BULK_IMPORT_CHUNK_SIZE: usize = 64;
+/// Maximum number of non-empty lines accepted by one import.
+const MAX_BULK_IMPORT_LINES: u64 = 50_000;
+
+/// Request header for a streaming bulk note import.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BulkImportRequest {
+	/// Tenant that owns the imported notes.
+	pub tenant_id: String,
+	/// Project that owns the imported notes.
+	pub project_id: String,
+	/// Agent that is writing the notes.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against `scope`; `None` when auth is off. Never
+	/// read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Scope applied to every imported note.
+	pub scope: String,
+}
+
+/// Outcome of one imported line.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BulkImportLineResult {
+	/// One-based line number among non-empty lines.
+	pub line: u64,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Ingestion result when the line parsed and validated.
+	pub result: Option<AddNoteResult>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Parse or validation failure for the line.
+	pub error: Option<String>,
+}
+
+/// Response payload for a streaming bulk note import.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BulkImportResponse {
+	/// Non-empty lines received.
+	pub received: u64,
+	/// Lines that produced an ingestion result.
+	pub processed: u64,
+	/// Lines that failed to parse or validate.
+	pub failed: u64,
+	/// Number of chunks committed.
+	pub chunks: u64,
+	/// One result per non-empty line, in input order.
+	pub results: Vec<BulkImportLineResult>,
+}
+
+impl AuditTargets for BulkImportResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		self.results
+			.iter()
+			.filter_map(|line| line.result.as_ref().and_then(|result| result.note_id))
+			.collect()
+	}
+
+	fn audit_reject_reasons(&self) -> Vec<String> {
+		self.results
+			.iter()
+			.filter_map(|line| line.result.as_ref())
+			.filter(|result| result.op == NoteOp::Rejected)
+			.filter_map(|result| result.reason_code.clone())
+			.collect()
+	}
+}
+
+/// Incremental importer fed one newline-delimited JSON note at a time.
+///
+/// Each line is an `add_note` note object. Lines are buffered into chunks; every chunk is
+/// validated per note, embedded with a single provider call, and persisted in one transaction
+/// that also enqueues the chunk's outbox entries.
+pub struct BulkImporter<'a> {
+	service: &'a ElfService,
+	req: BulkImportRequest,
+	base_now: OffsetDateTime,
+	pending: Vec<(u64, AddNoteInput)>,
+	response: BulkImportResponse,
+	audit: Option<PendingAudit>,
+}
+impl BulkImporter<'_> {
+	/// Buffers one line, flushing a chunk when it is full. Blank lines are skipped.
+	///
+	/// An error ends the import and records it in the audit log.
+	pub async fn push_line(&mut self, line: &str) -> Result<()> {
+		let result = self.buffer_line(line).await;
+
+		if let Err(err) = &result
+			&& let Some(audit) = self.audit.take()
+		{
+			audit.record_error(err).await;
+		}
+
+		result
+	}
+
+	/// Flushes the final partial chunk, records the import in the audit log, and returns the
+	/// import summary.
+	pub async fn finish(mut self) -> Result<BulkImportResponse> {
+		let audit = self.audit.take();
+		let result = self.complete().await;
+
+		if let Some(audit) = audit {
+			audit.record(&result).await;
+		}
+
+		result
+	}
+
+	/// Buffers one line; the caller records failures.
+	async fn buffer_line(&mut self, line: &str) -> Result<()> {
+		let line = line.trim();
+
+		if line.is_empty() {
+			return Ok(());
+		}
+		if self.response.received >= MAX_BULK_IMPORT_LINES {
+			return Err(Error::InvalidRequest {
+				message: format!("Bulk import accepts at most {MAX_BULK_IMPORT_LINES} notes."),
+			});
+		}
+
+		self.response.received += 1;
+
+		let line_no = self.response.received;
+
+		match serde_json::from_str::<AddNoteInput>(line) {
+			Ok(note) => self.pending.push((line_no, note)),
+			Err(err) => self.fail_line(line_no, format!("Invalid note JSON: {err}.")),
+		}
+
+		if self.pending.len() >= BULK_IMPORT_CHUNK_SIZE {
+			self.flush().await?;
+		}
+
+		Ok(())
+	}
+
+	/// Flushes the final partial chunk and returns the import summary.
+	async fn complete(mut self) -> Result<BulkImportResponse> {
+		self.flush().await?;
+		self.response.results.sort_by_key(|result| result.line);
+
+		tracing::info!(
+			tenant_id = %self.req.tenant_id,
+			project_id = %self.req.project_id,
+			scope = %self.req.scope,
+			received = self.response.received,
+			processed = self.response.processed,
+			failed = self.response.failed,
+			chunks = self.response.chunks,
+			"Bulk import finished."
+		);
+
+		Ok(self.response)
+	}
+
+	async fn flush(&mut self) -> Result<()> {
+		if self.pending.is_empty() {
+			return Ok(());
+		}
+
+		let pending = std::mem::take(&mut self.pending);
+		let mut prepared: Vec<(u64, AddNoteInput, NoteWriteTransforms)> =
+			Vec::with_capacity(pending.len());
+
+		for (line_no, note) in pending {
+			match self.prepare_note(note) {
+				Ok((note, transforms)) => prepared.push((line_no, note, transforms)),
+				Err(err) => self.fail_line(line_no, line_error_message(&err)),
+			}
+		}
+
+		if prepared.is_empty() {
+			return Ok(());
+		}
+
+		let scope = self.req.scope.as_str();
+		let vectors = self.embed_chunk(&prepared).await?;
+		let project_id =
+			if scope == "org_shared" { ORG_PROJECT_ID } else { self.req.project_id.as_str() };
+		let mut tx = self.service.db.pool.begin().await?;
+		let mut results = Vec::with_capacity(prepared.len());
+
+		for ((line_no, note, transforms), vector) in prepared.into_iter().zip(vectors.iter()) {
+			let embed_version =
+				crate::embedding_version_for_note(&self.service.cfg, scope, note.r#type.as_str());
+			let ctx = AddNoteContext {
+				tenant_id: self.req.tenant_id.as_str(),
+				project_id,
+				agent_id: self.req.agent_id.as_str(),
+				scope,
+				now: self.base_now + Duration::microseconds(line_no as i64),
+				embed_version: embed_version.as_str(),
+				graph_enabled: self.service.subsystems.graph,
+			};
+			let result = self
+				.service
+				.persist_add_note_input(&mut tx, &ctx, &note, transforms, Some(vector.as_slice()))
+				.await?;
+
+			results.push(BulkImportLineResult { line: line_no, result: Some(result), error: None });
+		}
+
+		tx.commit().await?;
+
+		self.response.processed += results.len() as u64;
+		self.response.chunks += 1;
+		self.response.results.extend(results);
+
+		Ok(())
+	}
+
+	/// Embeds the chunk with one provider call per distinct embedding provider, since note type
+	/// overrides can route notes of one chunk to different models.
+	async fn embed_chunk(
+		&self,
+		prepared: &[(u64, AddNoteInput, NoteWriteTransforms)],
+	) -> Result<Vec<Vec<f32>>> {
+		let cfg = &self.service.cfg;
+		let scope = self.req.scope.as_str();
+		let mut groups: Vec<(&EmbeddingProviderConfig, Vec<usize>)> = Vec::new();
+
+		for (idx, (_, note, _)) in prepared.iter().enumerate() {
+			let provider = cfg.providers.embedding_for_note(scope, note.r#type.as_str());
+
+			match groups.iter_mut().find(|(existing, _)| std::ptr::eq(*existing, provider)) {
+				Some((_, indexes)) => indexes.push(idx),
+				None => groups.push((provider, vec![idx])),
+			}
+		}
+
+		let mut vectors = vec![Vec::new(); prepared.len()];
+
+		for (provider, indexes) in groups {
+			let texts: Vec<String> =
+				indexes.iter().map(|idx| prepared[*idx].1.text.clone()).collect();
+			let embedded = self.service.providers.embedding.embed(provider, &texts).await?;
+
+			if embedded.len() != indexes.len() {
+				return Err(Error::Provider {
+					message: "Embedding provider returned a mismatched vector count.".to_string(),
+				});
+			}
+
+			for (idx, vector) in indexes.into_iter().zip(embedded) {
+				vectors[idx] = vector;
+			}
+		}
+
+		Ok(vectors)
+	}
+
+	fn prepare_note(&self, note: AddNoteInput) -> Result<(AddNoteInput, NoteWriteTransforms)> {
+		let req = validation::normalize_add_note_request(AddNoteRequest {
+			tenant_id: self.req.tenant_id.clone(),
+			project_id: self.req.project_id.clone(),
+			agent_id: self.req.agent_id.clone(),
+			role: self.req.role,
+			scope: self.req.scope.clone(),
+			notes: vec![note],
+		});
+
+		validation::validate_add_note_request(&req)?;
+
+		let Some(note) = req.notes.into_iter().next() else {
+			return Err(Error::InvalidRequest { message: "Notes list is empty.".to_string() });
+		};
+
+		service::prepare_add_note_input(&self.service.cfg, note)
+	}
+
+	fn fail_line(&mut self, line: u64, error: String) {
+		self.response.failed += 1;
+		self.response.results.push(BulkImportLineResult { line, result: None, error: Some(error) });
+	}
+}
+
+impl ElfService {
+	/// Starts a streaming bulk import of notes into one tenant, project, agent, and scope.
+	///
+	/// Feed newline-delimited note objects through [`BulkImporter::push_line`] and call
+	/// [`BulkImporter::finish`] at the end of the stream. Lines that fail to parse or validate are
+	/// reported per line; storage and provider failures abort the import, leaving earlier chunks
+	/// committed.
+	pub async fn bulk_import(&self, req: BulkImportRequest) -> Result<BulkImporter<'_>> {
+		let audit = self.audit(AuditScope::new("bulk_import").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		));
+
+		match self.start_bulk_import(req) {
+			Ok(importer) => Ok(BulkImporter { audit: Some(audit), ..importer }),
+			Err(err) => {
+				audit.record_error(&err).await;
+
+				Err(err)
+			},
+		}
+	}
+
+	/// Validates the import header and builds an importer without an audit entry.
+	fn start_bulk_import(&self, req: BulkImportRequest) -> Result<BulkImporter<'_>> {
+		if req.tenant_id.trim().is_empty()
+			|| req.project_id.trim().is_empty()
+			|| req.agent_id.trim().is_empty()
+			|| req.scope.trim().is_empty()
+		{
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, agent_id, and scope are required.".to_string(),
+			});
+		}
+
+		self.authorize_write(req.role, WriteOperation::AddNote, req.scope.as_str())?;
+
+		Ok(BulkImporter {
+			service: self,
+			req,
+			base_now: OffsetDateTime::now_utc(),
+			pending: Vec::with_capacity(BULK_IMPORT_CHUNK_SIZE),
+			response: BulkImportResponse {
+				received: 0,
+				processed: 0,
+				failed: 0,
+				chunks: 0,
+				results: Vec::new(),
+			},
+			audit: None,
+		})
+	}
+}
+
+/// Renders a per-line failure, rewriting single-note field paths to be line-relative.
+pub(super) fn line_error_message(err: &Error) -> String {
+	match err {
+		Error::NonEnglishInput { field } =>
+			format!("Non-English input detected at {}.", field.replacen("$.notes[0]", "$", 1)),
+		_ => err.to_string(),
+	}
+}
@@ -27,6 +27,13 @@ pub(in crate::add_note) async fn handle_add_note_add(
 		ctx.agent_id,
 	)
 	.await?;
+	crate::quotas::ensure_note_quota(
+		tx,
+		service.cfg.security.quotas.as_ref(),
+		ctx.tenant_id,
+		ctx.now,
+	)
+	.await?;
 
 	let expires_at =
 		ttl::compute_expires_at(note.ttl_days, note.r#type.as_str(), &service.cfg, ctx.now);
@@ -52,9 +59,10 @@ pub(in crate::add_note) async fn handle_add_note_add(
 	};
 
 	persistence::insert_memory_note_tx(tx, &memory_note).await?;
+	service.enqueue_url_snapshot_if_enabled(tx, &memory_note).await?;
 
 	let note_version_id = crate::insert_version(
-		&mut **tx,
+		tx,
 		InsertVersionArgs {
 			note_id: memory_note.note_id,
 			op: "ADD",
@@ -73,6 +81,7 @@ pub(in crate::add_note) async fn handle_add_note_add(
 		memory_note.note_id,
 		ctx.embed_version,
 		ctx.now,
+		false,
 	)
 	.await?;
 	structured_materialization::persist_graph_fields_if_present(
@@ -83,7 +92,7 @@ pub(in crate::add_note) async fn handle_add_note_add(
 		ctx.scope,
 		memory_note.note_id,
 		ctx.now,
-		note.structured.as_ref(),
+		note.structured.as_ref().filter(|_| ctx.graph_enabled),
 	)
 	.await?;
 
@@ -32,7 +32,7 @@ pub(in crate::add_note) async fn handle_add_note_none(
 
 			should_update = true;
 		}
-		if structured.has_graph_fields() {
+		if ctx.graph_enabled && structured.has_graph_fields() {
 			structured_materialization::persist_graph_fields_if_present(
 				tx,
 				ctx.tenant_id,
@@ -56,7 +56,7 @@ pub(in crate::add_note) async fn handle_add_note_none(
 			.await?;
 		let snapshot = crate::note_snapshot(&note_row);
 		let note_version_id = crate::insert_version(
-			&mut **tx,
+			tx,
 			InsertVersionArgs {
 				note_id,
 				op: "UPDATE",
@@ -88,6 +88,7 @@ pub(in crate::add_note) async fn handle_add_note_none(
 				reason_code: None,
 				field_path: None,
 				write_policy_audit: None,
+				reject_feedback: None,
 			},
 			Some(note_version_id),
 		));
@@ -101,6 +102,7 @@ pub(in crate::add_note) async fn handle_add_note_none(
 			reason_code: None,
 			field_path: None,
 			write_policy_audit: None,
+			reject_feedback: None,
 		},
 		None,
 	))
@@ -42,6 +42,7 @@ pub(super) async fn upsert_structured_and_enqueue_outbox(
 	note_id: Uuid,
 	embed_version: &str,
 	now: OffsetDateTime,
+	coalesced: bool,
 ) -> Result<()> {
 	if let Some(structured) = note.structured.as_ref()
 		&& !structured.is_effectively_empty()
@@ -49,7 +50,7 @@ pub(super) async fn upsert_structured_and_enqueue_outbox(
 		structured_fields::upsert_structured_fields_tx(tx, note_id, structured, now).await?;
 	}
 
-	crate::enqueue_outbox_tx(&mut **tx, note_id, "UPSERT", embed_version, now).await?;
+	crate::enqueue_upsert_outbox_tx(tx, note_id, embed_version, now, coalesced).await?;
 
 	Ok(())
 }
@@ -43,6 +43,7 @@ pub(in crate::add_note) async fn handle_add_note_update(
 				reason_code: None,
 				field_path: None,
 				write_policy_audit: None,
+				reject_feedback: None,
 			},
 			None,
 		));
@@ -65,9 +66,10 @@ pub(in crate::add_note) async fn handle_add_note_update(
 	existing.source_ref = note.source_ref.clone();
 
 	persistence::update_memory_note_tx(tx, &existing).await?;
+	service.enqueue_url_snapshot_if_enabled(tx, &existing).await?;
 
-	let note_version_id = crate::insert_version(
-		&mut **tx,
+	let version = crate::insert_update_version(
+		tx,
 		InsertVersionArgs {
 			note_id: existing.note_id,
 			op: "UPDATE",
@@ -77,6 +79,7 @@ pub(in crate::add_note) async fn handle_add_note_update(
 			actor: agent_id,
 			ts: now,
 		},
+		service.cfg.memory.version_coalesce_window_ms,
 	)
 	.await?;
 
@@ -88,7 +91,7 @@ pub(in crate::add_note) async fn handle_add_note_update(
 		existing.scope.as_str(),
 		existing.note_id,
 		now,
-		note.structured.as_ref(),
+		note.structured.as_ref().filter(|_| service.subsystems.graph),
 	)
 	.await?;
 	structured_materialization::upsert_structured_and_enqueue_outbox(
@@ -97,6 +100,7 @@ pub(in crate::add_note) async fn handle_add_note_update(
 		existing.note_id,
 		existing.embedding_version.as_str(),
 		now,
+		version.coalesced,
 	)
 	.await?;
 
@@ -108,8 +112,9 @@ pub(in crate::add_note) async fn handle_add_note_update(
 			reason_code: None,
 			field_path: None,
 			write_policy_audit: None,
+			reject_feedback: None,
 		},
-		Some(note_version_id),
+		Some(version.version_id),
 	))
 }
 
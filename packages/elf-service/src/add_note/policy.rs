@@ -113,6 +113,7 @@ pub(super) async fn apply_policy_result(
 						reason_code: None,
 						field_path: None,
 						write_policy_audit: None,
+						reject_feedback: None,
 					},
 					Some(note_version_id),
 				)
@@ -156,6 +157,7 @@ pub(super) async fn apply_policy_result(
 			reason_code: ignore_reason_code.map(str::to_string),
 			field_path: None,
 			write_policy_audit: None,
+			reject_feedback: None,
 		};
 
 		match decision {
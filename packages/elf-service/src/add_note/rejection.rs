@@ -1,12 +1,13 @@
 use sqlx::{Postgres, Transaction};
 
 use crate::{
-	NoteOp, Result,
+	NoteOp, RATE_ANOMALY, Result,
 	add_note::{
 		audit,
 		types::{AddNoteContext, AddNoteInput, AddNoteResult},
 		validation::{self},
 	},
+	write_anomaly::{self, WriteAnomalyAction, WriteAnomalyArgs},
 };
 use elf_config::Config;
 use elf_domain::{memory_policy::MemoryPolicyDecision, writegate::WritePolicyAudit};
@@ -75,5 +76,53 @@ pub(super) async fn handle_rejection_paths(
 		return Ok(Some(result));
 	}
 
+	let anomaly_args = WriteAnomalyArgs {
+		tenant_id: ctx.tenant_id,
+		project_id: ctx.project_id,
+		agent_id: ctx.agent_id,
+		pipeline: "add_note",
+		text: note.text.as_str(),
+		now: ctx.now,
+	};
+
+	if let Some(verdict) = write_anomaly::detect_write_anomaly(tx, cfg, &anomaly_args).await? {
+		write_anomaly::record_write_incident(tx, cfg, &anomaly_args, &verdict).await?;
+
+		if verdict.action == WriteAnomalyAction::Reject {
+			let result = AddNoteResult {
+				note_id: None,
+				op: NoteOp::Rejected,
+				policy_decision: MemoryPolicyDecision::Reject,
+				reason_code: Some(RATE_ANOMALY.to_string()),
+				field_path: None,
+				write_policy_audit: write_policy_audit.cloned(),
+				reject_feedback: None,
+			};
+
+			audit::record_ingest_decision(
+				tx,
+				cfg,
+				ctx,
+				note,
+				None,
+				None,
+				MemoryPolicyDecision::Reject,
+				MemoryPolicyDecision::Reject,
+				NoteOp::Rejected,
+				Some(RATE_ANOMALY),
+				None,
+				None,
+				false,
+				false,
+				None,
+				None,
+				write_policy_audit.cloned(),
+			)
+			.await?;
+
+			return Ok(Some(result));
+		}
+	}
+
 	Ok(None)
 }
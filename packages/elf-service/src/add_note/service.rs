@@ -2,33 +2,49 @@ use sqlx::{Postgres, Transaction};
 use time::{Duration, OffsetDateTime};
 
 use crate::{
-	ElfService, ResolveUpdateArgs, Result, UpdateDecision, UpdateDecisionMetadata,
+	ElfService, PiiRedactor, ResolveUpdateArgs, Result, UpdateDecision, UpdateDecisionMetadata,
+	WriteOperation,
 	access::ORG_PROJECT_ID,
 	add_note::{
 		audit,
 		policy::{self},
 		rejection,
-		types::{AddNoteContext, AddNoteInput, AddNoteRequest, AddNoteResponse, AddNoteResult},
+		types::{
+			AddNoteContext, AddNoteInput, AddNoteRequest, AddNoteResponse, AddNoteResult,
+			NoteWriteTransforms,
+		},
 		validation::{self},
 	},
+	audit::AuditScope,
 };
+use elf_config::Config;
 
 impl ElfService {
 	/// Validates and persists notes supplied directly by the caller.
 	pub async fn add_note(&self, req: AddNoteRequest) -> Result<AddNoteResponse> {
+		let scope =
+			AuditScope::new("add_note").caller(&req.tenant_id, &req.project_id, &req.agent_id);
+
+		self.audited(scope, self.add_note_inner(req)).await
+	}
+
+	/// Runs [`ElfService::add_note`] without recording an audit entry.
+	async fn add_note_inner(&self, req: AddNoteRequest) -> Result<AddNoteResponse> {
 		let req = validation::normalize_add_note_request(req);
 
 		validation::validate_add_note_request(&req)?;
+		self.authorize_write(req.role, WriteOperation::AddNote, req.scope.as_str())?;
 
 		let base_now = OffsetDateTime::now_utc();
-		let embed_version = crate::embedding_version(&self.cfg);
-		let AddNoteRequest { tenant_id, project_id, agent_id, scope, notes } = req;
+		let AddNoteRequest { tenant_id, project_id, agent_id, role: _, scope, notes } = req;
 		let effective_project_id =
 			if scope.trim() == "org_shared" { ORG_PROJECT_ID } else { project_id.as_str() };
 		let mut results = Vec::with_capacity(notes.len());
 
 		for (note_idx, note) in notes.into_iter().enumerate() {
 			let now = base_now + Duration::microseconds(note_idx as i64);
+			let embed_version =
+				crate::embedding_version_for_note(&self.cfg, scope.as_str(), note.r#type.as_str());
 			let ctx = AddNoteContext {
 				tenant_id: tenant_id.as_str(),
 				project_id: effective_project_id,
@@ -36,6 +52,7 @@ impl ElfService {
 				scope: scope.as_str(),
 				now,
 				embed_version: embed_version.as_str(),
+				graph_enabled: self.subsystems.graph,
 			};
 
 			results.push(self.process_add_note_input(&ctx, note).await?);
@@ -49,35 +66,43 @@ impl ElfService {
 		ctx: &AddNoteContext<'_>,
 		note: AddNoteInput,
 	) -> Result<AddNoteResult> {
-		let mut note = note;
-		let (transformed, write_policy_audit) =
-			validation::apply_write_policy_to_note(note.write_policy.as_ref(), note.text.as_str())?;
+		let (note, transforms) = prepare_add_note_input(&self.cfg, note)?;
+		let mut tx = self.db.pool.begin().await?;
+		let result = self.persist_add_note_input(&mut tx, ctx, &note, transforms, None).await?;
 
-		note.text = transformed;
+		tx.commit().await?;
+
+		Ok(result)
+	}
 
+	/// Runs rejection, update resolution, and policy for one prepared note inside `tx`.
+	///
+	/// `embedding` is a precomputed vector for the note text; the embedding provider is called
+	/// when it is absent.
+	pub(super) async fn persist_add_note_input(
+		&self,
+		tx: &mut Transaction<'_, Postgres>,
+		ctx: &AddNoteContext<'_>,
+		note: &AddNoteInput,
+		transforms: NoteWriteTransforms,
+		embedding: Option<&[f32]>,
+	) -> Result<AddNoteResult> {
+		let NoteWriteTransforms { write_policy_audit, pii_redactions } = transforms;
 		let (structured_present, graph_present) =
 			policy::structured_and_graph_present(note.structured.as_ref());
-		let mut tx = self.db.pool.begin().await?;
 
-		if let Some(result) = rejection::handle_rejection_paths(
-			&mut tx,
-			&self.cfg,
-			ctx,
-			&note,
-			write_policy_audit.as_ref(),
-		)
-		.await?
+		if let Some(result) =
+			rejection::handle_rejection_paths(tx, &self.cfg, ctx, note, write_policy_audit.as_ref())
+				.await?
 		{
-			tx.commit().await?;
-
 			return Ok(result);
 		}
 
-		let (decision, metadata) = self.resolve_update_decision(&mut tx, ctx, &note).await?;
+		let (decision, metadata) = self.resolve_update_decision(tx, ctx, note, embedding).await?;
 		let base_decision =
 			policy::base_decision_for_update(&decision, structured_present, graph_present);
 		let (policy_decision, decision_policy_rule, min_confidence, min_importance) =
-			policy::resolve_policy_for_update(&self.cfg, ctx.scope, &note, base_decision);
+			policy::resolve_policy_for_update(&self.cfg, ctx.scope, note, base_decision);
 		let note_id = decision.note_id();
 		let ignore_reason_code = policy::ignore_reason_code_for_policy(
 			base_decision,
@@ -86,10 +111,10 @@ impl ElfService {
 		);
 		let (result, note_op, note_version_id) = policy::apply_policy_result(
 			self,
-			&mut tx,
+			tx,
 			&decision,
 			ctx,
-			&note,
+			note,
 			note_id,
 			policy_decision,
 			ignore_reason_code,
@@ -97,13 +122,19 @@ impl ElfService {
 		.await?;
 		let mut result = result;
 
+		if let Some(version_id) = note_version_id
+			&& !pii_redactions.is_empty()
+		{
+			crate::record_version_redactions(&mut **tx, version_id, &pii_redactions).await?;
+		}
+
 		result.write_policy_audit = write_policy_audit.clone();
 
 		audit::record_ingest_decision(
-			&mut tx,
+			tx,
 			&self.cfg,
 			ctx,
-			&note,
+			note,
 			result.note_id,
 			note_version_id,
 			base_decision,
@@ -120,8 +151,6 @@ impl ElfService {
 		)
 		.await?;
 
-		tx.commit().await?;
-
 		Ok(result)
 	}
 
@@ -130,6 +159,7 @@ impl ElfService {
 		tx: &mut Transaction<'_, Postgres>,
 		ctx: &AddNoteContext<'_>,
 		note: &AddNoteInput,
+		embedding: Option<&[f32]>,
 	) -> Result<(UpdateDecision, UpdateDecisionMetadata)> {
 		let decision = crate::resolve_update(
 			&mut **tx,
@@ -144,6 +174,7 @@ impl ElfService {
 				key: note.key.as_deref(),
 				text: note.text.as_str(),
 				now: ctx.now,
+				embedding,
 			},
 		)
 		.await?;
@@ -152,3 +183,23 @@ impl ElfService {
 		Ok((decision, metadata))
 	}
 }
+
+/// Applies the note write policy, then `security.pii` redaction of the text, key, structured
+/// fields, and source_ref evidence quotes, and returns the transformed note with what was changed.
+pub(super) fn prepare_add_note_input(
+	cfg: &Config,
+	mut note: AddNoteInput,
+) -> Result<(AddNoteInput, NoteWriteTransforms)> {
+	let (transformed, write_policy_audit) =
+		validation::apply_write_policy_to_note(note.write_policy.as_ref(), note.text.as_str())?;
+	let mut redactor = PiiRedactor::new(cfg);
+
+	note.text = transformed;
+
+	redactor.field("$.text", &mut note.text);
+	redactor.key(note.key.as_mut());
+	redactor.structured(note.structured.as_mut());
+	redactor.source_ref_evidence(&mut note.source_ref);
+
+	Ok((note, NoteWriteTransforms { write_policy_audit, pii_redactions: redactor.finish() }))
+}
@@ -3,10 +3,11 @@ use serde_json::Value;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::{NoteOp, structured_fields::StructuredFields};
+use crate::{NoteOp, audit::AuditTargets, structured_fields::StructuredFields};
+use elf_config::SecurityAuthRole;
 use elf_domain::{
 	memory_policy::MemoryPolicyDecision,
-	writegate::{WritePolicy, WritePolicyAudit},
+	writegate::{PiiRedaction, RejectFeedback, WritePolicy, WritePolicyAudit},
 };
 
 /// Request payload for direct note ingestion.
@@ -18,6 +19,10 @@ pub struct AddNoteRequest {
 	pub project_id: String,
 	/// Agent that is writing the notes.
 	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against `scope`; `None` when auth is off. Never
+	/// read from request payloads.
+	pub role: Option<SecurityAuthRole>,
 	/// Scope to apply to all notes in the batch.
 	pub scope: String,
 	/// Notes to validate and persist.
@@ -63,6 +68,9 @@ pub struct AddNoteResult {
 	pub field_path: Option<String>,
 	/// Write-policy audit emitted for this note, if any.
 	pub write_policy_audit: Option<WritePolicyAudit>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Remediation data for a write-gate rejection, if any.
+	pub reject_feedback: Option<RejectFeedback>,
 }
 
 /// Response payload for direct note ingestion.
@@ -72,6 +80,20 @@ pub struct AddNoteResponse {
 	pub results: Vec<AddNoteResult>,
 }
 
+impl AuditTargets for AddNoteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		self.results.iter().filter_map(|result| result.note_id).collect()
+	}
+
+	fn audit_reject_reasons(&self) -> Vec<String> {
+		self.results
+			.iter()
+			.filter(|result| result.op == NoteOp::Rejected)
+			.filter_map(|result| result.reason_code.clone())
+			.collect()
+	}
+}
+
 pub(super) struct AddNoteContext<'a> {
 	pub(super) tenant_id: &'a str,
 	pub(super) project_id: &'a str,
@@ -79,6 +101,14 @@ pub(super) struct AddNoteContext<'a> {
 	pub(super) scope: &'a str,
 	pub(super) now: OffsetDateTime,
 	pub(super) embed_version: &'a str,
+	pub(super) graph_enabled: bool,
+}
+
+/// Write-time transforms applied to one note before persistence.
+#[derive(Clone, Debug, Default)]
+pub(super) struct NoteWriteTransforms {
+	pub(super) write_policy_audit: Option<WritePolicyAudit>,
+	pub(super) pii_redactions: Vec<PiiRedaction>,
 }
 
 pub(super) fn default_source_ref() -> Value {
@@ -3,7 +3,7 @@ mod non_english;
 use crate::{
 	Error, NoteOp, Result,
 	add_note::types::{self, AddNoteInput, AddNoteRequest, AddNoteResult},
-	structured_fields,
+	structured_fields, url_snapshots,
 };
 use elf_config::Config;
 use elf_domain::{
@@ -44,6 +44,9 @@ pub(super) fn validate_add_note_request(req: &AddNoteRequest) -> Result<()> {
 				message: "source_ref must be a JSON object.".to_string(),
 			});
 		}
+
+		url_snapshots::external_source_url(&note.source_ref)?;
+
 		if !english_gate::is_english_natural_language(note.text.as_str()) {
 			return Err(Error::NonEnglishInput { field: format!("$.notes[{idx}].text") });
 		}
@@ -71,13 +74,21 @@ pub(super) fn validate_add_note_request(req: &AddNoteRequest) -> Result<()> {
 }
 
 pub(super) fn reject_note_if_structured_invalid(note: &AddNoteInput) -> Option<AddNoteResult> {
-	if let Some(structured) = note.structured.as_ref()
-		&& let Err(err) = structured_fields::validate_structured_fields(
+	let result = structured_fields::validate_note_type_fields(
+		note.r#type.as_str(),
+		note.structured.as_ref(),
+	)
+	.and_then(|()| match note.structured.as_ref() {
+		Some(structured) => structured_fields::validate_structured_fields(
 			structured,
 			note.text.as_str(),
 			&note.source_ref,
 			None,
-		) {
+		),
+		None => Ok(()),
+	});
+
+	if let Err(err) = result {
 		tracing::info!(error = %err, "Rejecting note due to invalid structured fields.");
 
 		let field_path = extract_structured_rejection_field_path(&err);
@@ -89,6 +100,7 @@ pub(super) fn reject_note_if_structured_invalid(note: &AddNoteInput) -> Option<A
 			reason_code: Some(REJECT_STRUCTURED_INVALID.to_string()),
 			field_path,
 			write_policy_audit: None,
+			reject_feedback: None,
 		});
 	}
 
@@ -114,6 +126,7 @@ pub(super) fn reject_note_if_writegate_rejects(
 			reason_code: Some(crate::writegate_reason_code(code).to_string()),
 			field_path: None,
 			write_policy_audit: None,
+			reject_feedback: writegate::reject_feedback(&gate_input, cfg, code),
 		});
 	}
 
@@ -14,6 +14,16 @@ pub(super) fn find_non_english_path_in_structured(
 	{
 		return Some(format!("{base}.summary"));
 	}
+	if let Some(question) = structured.question.as_ref()
+		&& !english_gate::is_english_natural_language(question)
+	{
+		return Some(format!("{base}.question"));
+	}
+	if let Some(answer) = structured.answer.as_ref()
+		&& !english_gate::is_english_natural_language(answer)
+	{
+		return Some(format!("{base}.answer"));
+	}
 	if let Some(items) = structured.facts.as_ref() {
 		for (idx, item) in items.iter().enumerate() {
 			if !english_gate::is_english_natural_language(item) {
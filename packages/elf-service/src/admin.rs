@@ -12,7 +12,10 @@ use sqlx::FromRow;
 use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 use uuid::Uuid;
 
-use crate::{ElfService, Error, Result};
+use crate::{
+	ElfService, Error, Result,
+	audit::{AuditScope, AuditTargets},
+};
 use elf_storage::qdrant::{BM25_MODEL, BM25_VECTOR_NAME, DENSE_VECTOR_NAME};
 
 /// Summary of one Qdrant rebuild run.
@@ -24,6 +27,70 @@ pub struct RebuildReport {
 	pub missing_vector_count: u64,
 	/// Number of chunks skipped because rebuild failed.
 	pub error_count: u64,
+	#[serde(default)]
+	/// Number of rebuilt chunks whose legacy vectors were projected into the configured dimension.
+	pub projected_count: u64,
+	#[serde(default)]
+	/// Number of rebuilt chunks whose embedding version differs from the version configured for
+	/// the note's scope. These notes need re-embedding after a provider change.
+	pub stale_embedding_count: u64,
+}
+
+impl AuditTargets for RebuildReport {}
+
+/// Filters that restrict a Qdrant rebuild to a subset of active notes.
+///
+/// Every unset filter matches all notes, so the default request rebuilds the whole collection.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RebuildQdrantRequest {
+	/// Tenant whose notes are rebuilt.
+	pub tenant_id: Option<String>,
+	/// Project whose notes are rebuilt; requires `tenant_id`.
+	pub project_id: Option<String>,
+	/// Note scope to rebuild.
+	pub scope: Option<String>,
+	/// Note type to rebuild.
+	pub note_type: Option<String>,
+	/// RFC 3339 timestamp; only notes updated after it are rebuilt.
+	pub updated_after: Option<String>,
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct RebuildFilter {
+	tenant_id: Option<String>,
+	project_id: Option<String>,
+	scope: Option<String>,
+	note_type: Option<String>,
+	updated_after: Option<OffsetDateTime>,
+}
+impl RebuildFilter {
+	fn resolve(req: RebuildQdrantRequest) -> Result<Self> {
+		let filter = Self {
+			tenant_id: non_empty_filter(req.tenant_id, "tenant_id")?,
+			project_id: non_empty_filter(req.project_id, "project_id")?,
+			scope: non_empty_filter(req.scope, "scope")?,
+			note_type: non_empty_filter(req.note_type, "note_type")?,
+			updated_after: req
+				.updated_after
+				.as_deref()
+				.map(|value| {
+					OffsetDateTime::parse(value.trim(), &Rfc3339).map_err(|_| {
+						Error::InvalidRequest {
+							message: "updated_after must be an RFC 3339 timestamp.".to_string(),
+						}
+					})
+				})
+				.transpose()?,
+		};
+
+		if filter.project_id.is_some() && filter.tenant_id.is_none() {
+			return Err(Error::InvalidRequest {
+				message: "project_id requires tenant_id.".to_string(),
+			});
+		}
+
+		Ok(filter)
+	}
 }
 
 #[derive(FromRow)]
@@ -51,7 +118,21 @@ struct RebuildRow {
 
 impl ElfService {
 	/// Rebuilds Qdrant note points from persisted Postgres chunks and embeddings.
-	pub async fn rebuild_qdrant(&self) -> Result<RebuildReport> {
+	///
+	/// Request filters narrow the rebuild to matching notes; points of other notes are left as is.
+	pub async fn rebuild_qdrant(&self, req: RebuildQdrantRequest) -> Result<RebuildReport> {
+		let scope = AuditScope::new("admin_qdrant_rebuild").caller(
+			req.tenant_id.as_deref().unwrap_or_default(),
+			req.project_id.as_deref().unwrap_or_default(),
+			"",
+		);
+
+		self.audited(scope, self.rebuild_qdrant_inner(req)).await
+	}
+
+	/// Runs [`ElfService::rebuild_qdrant`] without recording an audit entry.
+	async fn rebuild_qdrant_inner(&self, req: RebuildQdrantRequest) -> Result<RebuildReport> {
+		let filter = RebuildFilter::resolve(req)?;
 		let now = OffsetDateTime::now_utc();
 		let rows: Vec<RebuildRow> = sqlx::query_as::<_, RebuildRow>(
 			"\
@@ -79,22 +160,34 @@ FROM memory_note_chunks c
 JOIN memory_notes n ON n.note_id = c.note_id
 LEFT JOIN note_chunk_embeddings e
 	ON e.chunk_id = c.chunk_id AND e.embedding_version = c.embedding_version
-	WHERE n.status = 'active' AND (n.expires_at IS NULL OR n.expires_at > $1)",
+	WHERE n.status = 'active' AND (n.expires_at IS NULL OR n.expires_at > $1)
+	AND ($2::text IS NULL OR n.tenant_id = $2)
+	AND ($3::text IS NULL OR n.project_id = $3)
+	AND ($4::text IS NULL OR n.scope = $4)
+	AND ($5::text IS NULL OR n.type = $5)
+	AND ($6::timestamptz IS NULL OR n.updated_at > $6)",
 		)
 		.bind(now)
+		.bind(filter.tenant_id.as_deref())
+		.bind(filter.project_id.as_deref())
+		.bind(filter.scope.as_deref())
+		.bind(filter.note_type.as_deref())
+		.bind(filter.updated_after)
 		.fetch_all(&self.db.pool)
 		.await?;
 		let mut rebuilt_count = 0_u64;
 		let mut missing_vector_count = 0_u64;
 		let mut error_count = 0_u64;
+		let mut projected_count = 0_u64;
+		let mut stale_embedding_count = 0_u64;
 
 		for row in rows {
-			let Some(vec_text) = row.vec_text else {
+			let Some(vec_text) = row.vec_text.as_deref() else {
 				missing_vector_count += 1;
 
 				continue;
 			};
-			let vec = match crate::parse_pg_vector(&vec_text) {
+			let vec = match crate::parse_pg_vector(vec_text) {
 				Ok(vec) => vec,
 				Err(_) => {
 					error_count += 1;
@@ -103,47 +196,19 @@ LEFT JOIN note_chunk_embeddings e
 				},
 			};
 
-			if vec.len() != self.cfg.storage.qdrant.vector_dim as usize {
+			let (vec, projected) = if vec.len() == self.cfg.storage.qdrant.vector_dim as usize {
+				(vec, false)
+			} else if let Some(projected) = crate::project_legacy_vector(&self.cfg, &vec) {
+				(projected, true)
+			} else {
 				error_count += 1;
 
 				continue;
-			}
-
-			let mut payload = Payload::new();
-
-			payload.insert("note_id", row.note_id.to_string());
-			payload.insert("chunk_id", row.chunk_id.to_string());
-			payload.insert("chunk_index", Value::from(row.chunk_index));
-			payload.insert("start_offset", Value::from(row.start_offset));
-			payload.insert("end_offset", Value::from(row.end_offset));
-			payload.insert("tenant_id", row.tenant_id);
-			payload.insert("project_id", row.project_id);
-			payload.insert("agent_id", row.agent_id);
-			payload.insert("scope", row.scope);
-			payload.insert("type", row.r#type);
-			payload.insert("key", row.key.map(Value::String).unwrap_or(Value::Null));
-			payload.insert("status", row.status);
-			payload.insert("updated_at", Value::String(format_timestamp(row.updated_at)?));
-
-			let expires_value = match row.expires_at {
-				Some(ts) => Value::String(format_timestamp(ts)?),
-				None => Value::Null,
 			};
 
-			payload.insert("expires_at", expires_value);
-			payload.insert("importance", Value::from(row.importance as f64));
-			payload.insert("confidence", Value::from(row.confidence as f64));
-			payload.insert("embedding_version", row.embedding_version.clone());
-
-			let mut vectors = HashMap::new();
-
-			vectors.insert(DENSE_VECTOR_NAME.to_string(), Vector::from(vec));
-			vectors.insert(
-				BM25_VECTOR_NAME.to_string(),
-				Vector::from(Document::new(row.chunk_text, BM25_MODEL)),
-			);
-
-			let point = PointStruct::new(row.chunk_id.to_string(), vectors, payload);
+			let stale_embedding = row.embedding_version
+				!= crate::embedding_version_for_note(&self.cfg, &row.scope, &row.r#type);
+			let point = rebuild_point(row, vec, projected)?;
 			let result = self
 				.qdrant
 				.client
@@ -160,13 +225,78 @@ LEFT JOIN note_chunk_embeddings e
 			}
 
 			rebuilt_count += 1;
+
+			if projected {
+				projected_count += 1;
+			}
+			if stale_embedding {
+				stale_embedding_count += 1;
+			}
 		}
 
-		Ok(RebuildReport { rebuilt_count, missing_vector_count, error_count })
+		Ok(RebuildReport {
+			rebuilt_count,
+			missing_vector_count,
+			error_count,
+			projected_count,
+			stale_embedding_count,
+		})
+	}
+}
+
+fn non_empty_filter(value: Option<String>, field: &str) -> Result<Option<String>> {
+	match value.map(|value| value.trim().to_string()) {
+		Some(value) if value.is_empty() =>
+			Err(Error::InvalidRequest { message: format!("{field} must not be empty.") }),
+		value => Ok(value),
+	}
+}
+
+fn rebuild_point(row: RebuildRow, vec: Vec<f32>, projected: bool) -> Result<PointStruct> {
+	let mut payload = Payload::new();
+
+	payload.insert("note_id", row.note_id.to_string());
+	payload.insert("chunk_id", row.chunk_id.to_string());
+	payload.insert("chunk_index", Value::from(row.chunk_index));
+	payload.insert("start_offset", Value::from(row.start_offset));
+	payload.insert("end_offset", Value::from(row.end_offset));
+	payload.insert("tenant_id", row.tenant_id);
+	payload.insert("project_id", row.project_id);
+	payload.insert("agent_id", row.agent_id);
+	payload.insert("scope", row.scope);
+	payload.insert("type", row.r#type);
+	payload.insert("key", row.key.map(Value::String).unwrap_or(Value::Null));
+	payload.insert("status", row.status);
+	payload.insert("updated_at", Value::String(format_timestamp(row.updated_at)?));
+
+	let expires_value = match row.expires_at {
+		Some(ts) => Value::String(format_timestamp(ts)?),
+		None => Value::Null,
+	};
+
+	payload.insert("expires_at", expires_value);
+	payload.insert("importance", Value::from(row.importance as f64));
+	payload.insert("confidence", Value::from(row.confidence as f64));
+	payload.insert("embedding_version", row.embedding_version);
+
+	if projected {
+		payload.insert("embedding_projection", crate::PROJECTION_MODE_TRUNCATE);
 	}
+
+	let mut vectors = HashMap::new();
+
+	vectors.insert(DENSE_VECTOR_NAME.to_string(), Vector::from(vec));
+	vectors.insert(
+		BM25_VECTOR_NAME.to_string(),
+		Vector::from(Document::new(row.chunk_text, BM25_MODEL)),
+	);
+
+	Ok(PointStruct::new(row.chunk_id.to_string(), vectors, payload))
 }
 
 fn format_timestamp(ts: OffsetDateTime) -> Result<String> {
 	ts.format(&Rfc3339)
 		.map_err(|_| Error::InvalidRequest { message: "Failed to format timestamp.".to_string() })
 }
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,37 @@
+use time::macros::datetime;
+
+use crate::admin::{RebuildFilter, RebuildQdrantRequest};
+
+#[test]
+fn rebuild_filter_defaults_to_the_whole_collection() {
+	assert_eq!(
+		RebuildFilter::resolve(RebuildQdrantRequest::default()).expect("Default is valid."),
+		RebuildFilter::default()
+	);
+}
+
+#[test]
+fn rebuild_filter_trims_values_and_parses_updated_after() {
+	let filter = RebuildFilter::resolve(RebuildQdrantRequest {
+		tenant_id: Some(" tenant-a ".to_string()),
+		project_id: Some("project-a".to_string()),
+		scope: Some("project_shared".to_string()),
+		note_type: Some("fact".to_string()),
+		updated_after: Some("2026-01-01T00:00:00Z".to_string()),
+	})
+	.expect("Filter is valid.");
+
+	assert_eq!(filter.tenant_id.as_deref(), Some("tenant-a"));
+	assert_eq!(filter.updated_after, Some(datetime!(2026-01-01 00:00:00 UTC)));
+}
+
+#[test]
+fn rebuild_filter_rejects_invalid_values() {
+	for req in [
+		RebuildQdrantRequest { project_id: Some("project-a".to_string()), ..Default::default() },
+		RebuildQdrantRequest { scope: Some("  ".to_string()), ..Default::default() },
+		RebuildQdrantRequest { updated_after: Some("yesterday".to_string()), ..Default::default() },
+	] {
+		assert!(RebuildFilter::resolve(req).is_err());
+	}
+}
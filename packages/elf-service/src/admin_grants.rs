@@ -0,0 +1,561 @@
+//! Admin management of shared-read grants with versioned audit history.
+//!
+//! Unlike the owner-facing space grant APIs, these methods act on any space owner within the
+//! caller's tenant. Every grant or revoke that changes a row also writes a
+//! `memory_space_grant_versions` row holding before and after snapshots, mirroring
+//! `memory_note_versions`. The owner-facing APIs share the lookup and version helpers below.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{FromRow, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	access::ORG_PROJECT_ID,
+	audit::{AuditScope, AuditTargets},
+	sharing::GranteeKind,
+};
+use elf_storage::search_watermarks;
+
+const DEFAULT_GRANTS_LIMIT: u32 = 100;
+const MAX_GRANTS_LIMIT: u32 = 1_000;
+
+/// Request payload for creating a shared-read grant as an admin.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AdminGrantPutRequest {
+	/// Tenant that owns the shared space.
+	pub tenant_id: String,
+	/// Project that owns the shared space; ignored for `org_shared`.
+	pub project_id: String,
+	/// Admin agent recorded as the grantor and audit actor.
+	pub actor_agent_id: String,
+	/// Shared scope to grant: `project_shared` or `org_shared`.
+	pub scope: String,
+	/// Agent whose shared notes become readable.
+	pub space_owner_agent_id: String,
+	/// Grantee class.
+	pub grantee_kind: GranteeKind,
+	/// Grantee agent identifier when `grantee_kind` is `agent`.
+	pub grantee_agent_id: Option<String>,
+	/// Audit reason; defaults to `admin_grant`.
+	pub reason: Option<String>,
+}
+
+/// Request payload for listing shared-read grants as an admin.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AdminGrantsListRequest {
+	/// Tenant to list grants for.
+	pub tenant_id: String,
+	/// Optional project filter; `org_shared` grants live under the org project.
+	pub project_id: Option<String>,
+	/// Optional scope filter.
+	pub scope: Option<String>,
+	/// Optional space owner filter.
+	pub space_owner_agent_id: Option<String>,
+	/// Whether revoked grants are returned alongside active ones.
+	pub include_revoked: bool,
+	/// Maximum grants to return.
+	pub limit: Option<u32>,
+}
+
+/// Request payload for revoking a shared-read grant as an admin.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AdminGrantRevokeRequest {
+	/// Tenant that owns the grant.
+	pub tenant_id: String,
+	/// Admin agent recorded as the revoker and audit actor.
+	pub actor_agent_id: String,
+	/// Grant to revoke.
+	pub grant_id: Uuid,
+	/// Audit reason; defaults to `admin_revoke`.
+	pub reason: Option<String>,
+}
+
+/// One shared-read grant.
+#[derive(Clone, Debug, FromRow, Serialize)]
+pub struct AdminGrantItem {
+	/// Grant identifier.
+	pub grant_id: Uuid,
+	/// Project that owns the shared space.
+	pub project_id: String,
+	/// Granted scope.
+	pub scope: String,
+	/// Agent whose shared notes are readable.
+	pub space_owner_agent_id: String,
+	/// Grantee class: `project` or `agent`.
+	pub grantee_kind: String,
+	/// Grantee agent identifier when applicable.
+	pub grantee_agent_id: Option<String>,
+	/// Agent that created the grant.
+	pub granted_by_agent_id: String,
+	#[serde(with = "crate::time_serde")]
+	/// Grant creation time.
+	pub granted_at: OffsetDateTime,
+	/// Agent that revoked the grant.
+	pub revoked_by_agent_id: Option<String>,
+	#[serde(with = "crate::time_serde::option")]
+	/// Revocation time.
+	pub revoked_at: Option<OffsetDateTime>,
+}
+
+/// Response payload for an admin grant or revoke.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminGrantResponse {
+	/// Grant after the operation.
+	pub grant: AdminGrantItem,
+	/// Whether the operation changed the grant and wrote an audit version.
+	pub changed: bool,
+	/// Audit version written by the operation.
+	pub version_id: Option<Uuid>,
+}
+
+impl AuditTargets for AdminGrantResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		[self.grant.grant_id].into_iter().chain(self.version_id).collect()
+	}
+}
+
+/// Response payload listing shared-read grants.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminGrantsListResponse {
+	/// Grants, newest first.
+	pub grants: Vec<AdminGrantItem>,
+}
+
+impl ElfService {
+	/// Creates a shared-read grant on behalf of any space owner in the tenant.
+	///
+	/// An existing active grant for the same space and grantee is returned unchanged without a new
+	/// audit version.
+	pub async fn grants_put(&self, req: AdminGrantPutRequest) -> Result<AdminGrantResponse> {
+		let scope = AuditScope::new("admin_grants_put").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.actor_agent_id,
+		);
+
+		self.audited(scope, self.grants_put_inner(req)).await
+	}
+
+	/// Runs [`ElfService::grants_put`] without recording an audit entry.
+	async fn grants_put_inner(&self, req: AdminGrantPutRequest) -> Result<AdminGrantResponse> {
+		let tenant_id = required(&req.tenant_id, "tenant_id")?;
+		let project_id = required(&req.project_id, "project_id")?;
+		let actor = required(&req.actor_agent_id, "actor_agent_id")?;
+		let owner = required(&req.space_owner_agent_id, "space_owner_agent_id")?;
+		let scope = req.scope.trim();
+
+		self.ensure_grant_scope(scope)?;
+
+		let grantee_agent_id =
+			req.grantee_agent_id.as_deref().map(str::trim).filter(|value| !value.is_empty());
+		let grantee_kind = match (req.grantee_kind, grantee_agent_id) {
+			(GranteeKind::Project, None) => "project",
+			(GranteeKind::Project, Some(_)) => {
+				return Err(Error::InvalidRequest {
+					message: "grantee_agent_id must be empty for project grantee_kind.".to_string(),
+				});
+			},
+			(GranteeKind::Agent, None) => {
+				return Err(Error::InvalidRequest {
+					message: "grantee_agent_id is required for agent grantee_kind.".to_string(),
+				});
+			},
+			(GranteeKind::Agent, Some(grantee)) if grantee == owner => {
+				return Err(Error::InvalidRequest {
+					message: "grantee_agent_id must differ from space_owner_agent_id.".to_string(),
+				});
+			},
+			(GranteeKind::Agent, Some(_)) => "agent",
+		};
+		let key = GrantKey {
+			tenant_id,
+			project_id: if scope == "org_shared" { ORG_PROJECT_ID } else { project_id },
+			scope,
+			space_owner_agent_id: owner,
+			grantee_kind,
+			grantee_agent_id,
+		};
+		let now = OffsetDateTime::now_utc();
+		let mut tx = self.db.pool.begin().await?;
+		let inserted: Option<AdminGrantItem> = sqlx::query_as(
+			"\
+INSERT INTO memory_space_grants (
+	grant_id,
+	tenant_id,
+	project_id,
+	scope,
+	space_owner_agent_id,
+	grantee_kind,
+	grantee_agent_id,
+	granted_by_agent_id,
+	granted_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT DO NOTHING
+RETURNING
+	grant_id,
+	project_id,
+	scope,
+	space_owner_agent_id,
+	grantee_kind,
+	grantee_agent_id,
+	granted_by_agent_id,
+	granted_at,
+	revoked_by_agent_id,
+	revoked_at",
+		)
+		.bind(Uuid::new_v4())
+		.bind(key.tenant_id)
+		.bind(key.project_id)
+		.bind(key.scope)
+		.bind(key.space_owner_agent_id)
+		.bind(key.grantee_kind)
+		.bind(key.grantee_agent_id)
+		.bind(actor)
+		.bind(now)
+		.fetch_optional(&mut *tx)
+		.await?;
+		let Some(grant) = inserted else {
+			// The conflicting grant can be revoked between the insert and this lookup.
+			let Some(existing) = active_grant_for_update(&mut tx, &key).await? else {
+				return Err(Error::Conflict {
+					message: "Grant was revoked concurrently; retry the request.".to_string(),
+				});
+			};
+
+			tx.commit().await?;
+
+			return Ok(AdminGrantResponse { grant: existing, changed: false, version_id: None });
+		};
+		let version_id = insert_grant_version(
+			&mut tx,
+			GrantVersionArgs {
+				grant_id: grant.grant_id,
+				tenant_id,
+				op: "grant",
+				prev: None,
+				new: Some(&grant),
+				reason: reason_or(req.reason.as_deref(), "admin_grant"),
+				actor,
+				ts: now,
+			},
+		)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok(AdminGrantResponse { grant, changed: true, version_id: Some(version_id) })
+	}
+
+	/// Lists shared-read grants in the tenant, newest first.
+	pub async fn grants_list(
+		&self,
+		req: AdminGrantsListRequest,
+	) -> Result<AdminGrantsListResponse> {
+		let tenant_id = required(&req.tenant_id, "tenant_id")?;
+		let filter = |value: &Option<String>| {
+			value
+				.as_deref()
+				.map(str::trim)
+				.filter(|value| !value.is_empty())
+				.map(ToString::to_string)
+		};
+		let scope = filter(&req.scope);
+
+		if let Some(scope) = scope.as_deref()
+			&& !matches!(scope, "project_shared" | "org_shared")
+		{
+			return Err(Error::InvalidRequest {
+				message: "scope must be project_shared or org_shared.".to_string(),
+			});
+		}
+
+		let limit = req.limit.unwrap_or(DEFAULT_GRANTS_LIMIT).clamp(1, MAX_GRANTS_LIMIT);
+		let grants = sqlx::query_as(
+			"\
+SELECT
+	grant_id,
+	project_id,
+	scope,
+	space_owner_agent_id,
+	grantee_kind,
+	grantee_agent_id,
+	granted_by_agent_id,
+	granted_at,
+	revoked_by_agent_id,
+	revoked_at
+FROM memory_space_grants
+WHERE tenant_id = $1
+	AND ($2::text IS NULL OR project_id = $2)
+	AND ($3::text IS NULL OR scope = $3)
+	AND ($4::text IS NULL OR space_owner_agent_id = $4)
+	AND ($5 OR revoked_at IS NULL)
+ORDER BY granted_at DESC, grant_id
+LIMIT $6",
+		)
+		.bind(tenant_id)
+		.bind(filter(&req.project_id))
+		.bind(scope)
+		.bind(filter(&req.space_owner_agent_id))
+		.bind(req.include_revoked)
+		.bind(i64::from(limit))
+		.fetch_all(&self.db.pool)
+		.await?;
+
+		Ok(AdminGrantsListResponse { grants })
+	}
+
+	/// Revokes a shared-read grant by identifier.
+	///
+	/// Revoking an already revoked grant returns it unchanged without a new audit version.
+	pub async fn grants_revoke(&self, req: AdminGrantRevokeRequest) -> Result<AdminGrantResponse> {
+		let scope = AuditScope::new("admin_grants_revoke")
+			.caller(&req.tenant_id, "", &req.actor_agent_id)
+			.targets([req.grant_id]);
+
+		self.audited(scope, self.grants_revoke_inner(req)).await
+	}
+
+	/// Runs [`ElfService::grants_revoke`] without recording an audit entry.
+	async fn grants_revoke_inner(
+		&self,
+		req: AdminGrantRevokeRequest,
+	) -> Result<AdminGrantResponse> {
+		let tenant_id = required(&req.tenant_id, "tenant_id")?;
+		let actor = required(&req.actor_agent_id, "actor_agent_id")?;
+		let mut tx = self.db.pool.begin().await?;
+		let prev: Option<AdminGrantItem> = sqlx::query_as(
+			"\
+SELECT
+	grant_id,
+	project_id,
+	scope,
+	space_owner_agent_id,
+	grantee_kind,
+	grantee_agent_id,
+	granted_by_agent_id,
+	granted_at,
+	revoked_by_agent_id,
+	revoked_at
+FROM memory_space_grants
+WHERE grant_id = $1 AND tenant_id = $2
+FOR UPDATE",
+		)
+		.bind(req.grant_id)
+		.bind(tenant_id)
+		.fetch_optional(&mut *tx)
+		.await?;
+		let Some(prev) = prev else {
+			return Err(Error::NotFound { message: "Grant not found.".to_string() });
+		};
+
+		if prev.revoked_at.is_some() {
+			tx.commit().await?;
+
+			return Ok(AdminGrantResponse { grant: prev, changed: false, version_id: None });
+		}
+
+		let now = OffsetDateTime::now_utc();
+		let grant = revoke_grant(&mut tx, req.grant_id, actor, now).await?;
+		let version_id = insert_grant_version(
+			&mut tx,
+			GrantVersionArgs {
+				grant_id: grant.grant_id,
+				tenant_id,
+				op: "revoke",
+				prev: Some(&prev),
+				new: Some(&grant),
+				reason: reason_or(req.reason.as_deref(), "admin_revoke"),
+				actor,
+				ts: now,
+			},
+		)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok(AdminGrantResponse { grant, changed: true, version_id: Some(version_id) })
+	}
+
+	fn ensure_grant_scope(&self, scope: &str) -> Result<()> {
+		let allowed = match scope {
+			"project_shared" => self.cfg.scopes.write_allowed.project_shared,
+			"org_shared" => self.cfg.scopes.write_allowed.org_shared,
+			_ => {
+				return Err(Error::InvalidRequest {
+					message: "scope must be project_shared or org_shared.".to_string(),
+				});
+			},
+		};
+
+		if !allowed {
+			return Err(Error::ScopeDenied { message: "Scope is not allowed.".to_string() });
+		}
+
+		Ok(())
+	}
+}
+
+/// Identifies the active grant of one shared space to one grantee.
+pub(crate) struct GrantKey<'a> {
+	pub(crate) tenant_id: &'a str,
+	/// Stored project; the org project for `org_shared`.
+	pub(crate) project_id: &'a str,
+	pub(crate) scope: &'a str,
+	pub(crate) space_owner_agent_id: &'a str,
+	pub(crate) grantee_kind: &'a str,
+	pub(crate) grantee_agent_id: Option<&'a str>,
+}
+
+/// Arguments for [`insert_grant_version`].
+pub(crate) struct GrantVersionArgs<'a> {
+	pub(crate) grant_id: Uuid,
+	pub(crate) tenant_id: &'a str,
+	pub(crate) op: &'a str,
+	pub(crate) prev: Option<&'a AdminGrantItem>,
+	pub(crate) new: Option<&'a AdminGrantItem>,
+	pub(crate) reason: &'a str,
+	pub(crate) actor: &'a str,
+	pub(crate) ts: OffsetDateTime,
+}
+
+/// Loads the active grant matching `key` and locks it for the rest of the transaction.
+pub(crate) async fn active_grant_for_update(
+	conn: &mut PgConnection,
+	key: &GrantKey<'_>,
+) -> Result<Option<AdminGrantItem>> {
+	let grant = sqlx::query_as(
+		"\
+SELECT
+	grant_id,
+	project_id,
+	scope,
+	space_owner_agent_id,
+	grantee_kind,
+	grantee_agent_id,
+	granted_by_agent_id,
+	granted_at,
+	revoked_by_agent_id,
+	revoked_at
+FROM memory_space_grants
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND scope = $3
+	AND space_owner_agent_id = $4
+	AND grantee_kind = $5
+	AND grantee_agent_id IS NOT DISTINCT FROM $6
+	AND revoked_at IS NULL
+FOR UPDATE",
+	)
+	.bind(key.tenant_id)
+	.bind(key.project_id)
+	.bind(key.scope)
+	.bind(key.space_owner_agent_id)
+	.bind(key.grantee_kind)
+	.bind(key.grantee_agent_id)
+	.fetch_optional(&mut *conn)
+	.await?;
+
+	Ok(grant)
+}
+
+/// Marks a grant locked by the caller as revoked by `actor` and returns it.
+pub(crate) async fn revoke_grant(
+	conn: &mut PgConnection,
+	grant_id: Uuid,
+	actor: &str,
+	now: OffsetDateTime,
+) -> Result<AdminGrantItem> {
+	let grant = sqlx::query_as(
+		"\
+UPDATE memory_space_grants
+SET revoked_at = $2,
+	revoked_by_agent_id = $3
+WHERE grant_id = $1
+RETURNING
+	grant_id,
+	project_id,
+	scope,
+	space_owner_agent_id,
+	grantee_kind,
+	grantee_agent_id,
+	granted_by_agent_id,
+	granted_at,
+	revoked_by_agent_id,
+	revoked_at",
+	)
+	.bind(grant_id)
+	.bind(now)
+	.bind(actor)
+	.fetch_one(&mut *conn)
+	.await?;
+
+	Ok(grant)
+}
+
+/// Writes one `memory_space_grant_versions` row and bumps the search watermark of the grant's
+/// space.
+pub(crate) async fn insert_grant_version(
+	conn: &mut PgConnection,
+	args: GrantVersionArgs<'_>,
+) -> Result<Uuid> {
+	let GrantVersionArgs { grant_id, tenant_id, op, prev, new, reason, actor, ts } = args;
+	let version_id = Uuid::new_v4();
+
+	sqlx::query(
+		"\
+INSERT INTO memory_space_grant_versions (
+	version_id,
+	grant_id,
+	tenant_id,
+	op,
+	prev_snapshot,
+	new_snapshot,
+	reason,
+	actor,
+	ts
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+	)
+	.bind(version_id)
+	.bind(grant_id)
+	.bind(tenant_id)
+	.bind(op)
+	.bind(prev.map(grant_snapshot).transpose()?)
+	.bind(new.map(grant_snapshot).transpose()?)
+	.bind(reason)
+	.bind(actor)
+	.bind(ts)
+	.execute(&mut *conn)
+	.await?;
+
+	if let Some(grant) = new.or(prev) {
+		search_watermarks::bump_space(&mut *conn, tenant_id, &grant.project_id, &grant.scope, ts)
+			.await?;
+	}
+
+	Ok(version_id)
+}
+
+fn grant_snapshot(grant: &AdminGrantItem) -> Result<Value> {
+	serde_json::to_value(grant).map_err(|err| Error::Storage {
+		message: format!("Failed to encode grant snapshot: {err}."),
+	})
+}
+
+fn required<'a>(value: &'a str, field: &str) -> Result<&'a str> {
+	let value = value.trim();
+
+	if value.is_empty() {
+		return Err(Error::InvalidRequest { message: format!("{field} is required.") });
+	}
+
+	Ok(value)
+}
+
+fn reason_or<'a>(reason: Option<&'a str>, default: &'a str) -> &'a str {
+	reason.map(str::trim).filter(|reason| !reason.is_empty()).unwrap_or(default)
+}
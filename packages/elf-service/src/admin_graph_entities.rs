@@ -0,0 +1,26 @@
+//! Administrative graph entity merge and split APIs.
+
+mod service;
+mod storage;
+mod types;
+
+pub use types::{
+	AdminGraphDeduplicatedFact, AdminGraphEntitiesMergeRequest, AdminGraphEntitiesMergeResponse,
+	AdminGraphEntityResponse, AdminGraphEntitySplitRequest, AdminGraphEntitySplitResponse,
+};
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{FromRow, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{ElfService, Error, Result};
+use elf_storage::{graph, models::GraphEntity};
+
+const MAX_GRAPH_ENTITY_MERGE_LOSERS: usize = 32;
+const MAX_GRAPH_ENTITY_SPLIT_ITEMS: usize = 500;
+
+#[cfg(test)] mod tests;
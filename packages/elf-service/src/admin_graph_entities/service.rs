@@ -0,0 +1,410 @@
+use crate::{
+	admin_graph_entities::{
+		AdminGraphDeduplicatedFact, AdminGraphEntitiesMergeRequest,
+		AdminGraphEntitiesMergeResponse, AdminGraphEntityResponse, AdminGraphEntitySplitRequest,
+		AdminGraphEntitySplitResponse, ElfService, Error, GraphEntity, HashSet,
+		MAX_GRAPH_ENTITY_MERGE_LOSERS, MAX_GRAPH_ENTITY_SPLIT_ITEMS, PgConnection, Result, Uuid,
+		graph,
+		storage::{self, GraphEntityEventArgs},
+	},
+	audit::AuditScope,
+};
+
+impl ElfService {
+	/// Folds duplicate entities into a winner entity.
+	///
+	/// Each loser's canonical surface and aliases become aliases of the winner, every fact that
+	/// references a loser is re-pointed to the winner, and the loser rows are removed. An active
+	/// fact that would duplicate an active winner fact is dropped after its evidence and
+	/// supersession links move to the surviving fact. The merge is recorded in
+	/// `graph_entity_events`.
+	pub async fn admin_graph_entities_merge(
+		&self,
+		req: AdminGraphEntitiesMergeRequest,
+	) -> Result<AdminGraphEntitiesMergeResponse> {
+		let scope = AuditScope::new("admin_graph_entities_merge")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets(
+				[req.winner_entity_id].into_iter().chain(req.loser_entity_ids.iter().copied()),
+			);
+
+		self.audited(scope, self.admin_graph_entities_merge_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_graph_entities_merge`] without recording an audit entry.
+	async fn admin_graph_entities_merge_inner(
+		&self,
+		req: AdminGraphEntitiesMergeRequest,
+	) -> Result<AdminGraphEntitiesMergeResponse> {
+		let loser_ids = validate_merge_losers(req.winner_entity_id, &req.loser_entity_ids)?;
+		let reason = normalize_reason(req.reason.as_deref());
+		let mut tx = self.db.pool.begin().await?;
+		let mut entity_ids = loser_ids.clone();
+
+		entity_ids.push(req.winner_entity_id);
+
+		let entities =
+			storage::lock_entities(&mut tx, &req.tenant_id, &req.project_id, &entity_ids).await?;
+		let find = |entity_id: Uuid| {
+			entities.iter().find(|entity| entity.entity_id == entity_id).ok_or_else(|| {
+				Error::NotFound {
+					message: format!("graph entity not found; entity_id={entity_id}"),
+				}
+			})
+		};
+		let winner = find(req.winner_entity_id)?;
+		let mut merged = Vec::with_capacity(loser_ids.len());
+		let mut repointed_fact_ids: Vec<Uuid> = Vec::new();
+		let mut deduplicated_facts = Vec::new();
+
+		for loser_id in &loser_ids {
+			let loser = find(*loser_id)?;
+			let aliases = storage::list_alias_surfaces(&mut tx, loser.entity_id).await?;
+
+			for surface in std::iter::once(&loser.canonical).chain(aliases.iter()) {
+				if graph::normalize_entity_name(surface) != winner.canonical_norm {
+					graph::upsert_entity_alias(&mut tx, winner.entity_id, surface).await?;
+				}
+			}
+
+			for fact in storage::lock_entity_facts(&mut tx, loser.entity_id).await? {
+				let subject_entity_id =
+					repoint(fact.subject_entity_id, loser.entity_id, winner.entity_id);
+				let object_entity_id = fact
+					.object_entity_id
+					.map(|object| repoint(object, loser.entity_id, winner.entity_id));
+				let duplicate = if fact.is_active {
+					storage::find_active_duplicate(
+						&mut tx,
+						&fact,
+						subject_entity_id,
+						object_entity_id,
+					)
+					.await?
+				} else {
+					None
+				};
+
+				if let Some(into_fact_id) = duplicate {
+					storage::fold_duplicate_fact(&mut tx, fact.fact_id, into_fact_id).await?;
+					repointed_fact_ids.retain(|fact_id| *fact_id != fact.fact_id);
+					deduplicated_facts
+						.push(AdminGraphDeduplicatedFact { fact_id: fact.fact_id, into_fact_id });
+				} else {
+					storage::repoint_fact(
+						&mut tx,
+						fact.fact_id,
+						subject_entity_id,
+						object_entity_id,
+					)
+					.await?;
+
+					if !repointed_fact_ids.contains(&fact.fact_id) {
+						repointed_fact_ids.push(fact.fact_id);
+					}
+				}
+			}
+
+			storage::delete_entity(&mut tx, loser.entity_id).await?;
+			merged.push(serde_json::json!({
+				"entity_id": loser.entity_id,
+				"canonical": loser.canonical,
+				"kind": loser.kind,
+				"aliases": aliases,
+			}));
+		}
+
+		storage::touch_entity(&mut tx, winner.entity_id).await?;
+
+		let event_id = storage::insert_entity_event(
+			&mut tx,
+			GraphEntityEventArgs {
+				tenant_id: &req.tenant_id,
+				project_id: &req.project_id,
+				actor_agent_id: &req.agent_id,
+				event_type: "merged",
+				entity_id: winner.entity_id,
+				related_entity_ids: &loser_ids,
+				detail: serde_json::json!({
+					"winner": { "entity_id": winner.entity_id, "canonical": winner.canonical },
+					"merged": merged,
+					"repointed_fact_ids": repointed_fact_ids,
+					"deduplicated_facts": deduplicated_facts,
+				}),
+				reason: reason.as_deref(),
+			},
+		)
+		.await?;
+		let entity = load_entity_response(&mut tx, winner.entity_id).await?;
+
+		tx.commit().await?;
+
+		tracing::info!(
+			actor_agent_id = %req.agent_id,
+			winner_entity_id = %entity.entity_id,
+			merged_count = loser_ids.len(),
+			repointed_fact_count = repointed_fact_ids.len(),
+			deduplicated_fact_count = deduplicated_facts.len(),
+			"Admin graph entities merged."
+		);
+
+		Ok(AdminGraphEntitiesMergeResponse {
+			event_id,
+			entity,
+			merged_entity_ids: loser_ids,
+			repointed_fact_ids,
+			deduplicated_facts,
+		})
+	}
+
+	/// Moves selected aliases and facts from an entity onto a new entity.
+	///
+	/// The new entity must not collide with an existing canonical surface. Every listed alias
+	/// must belong to the source entity and every listed fact must reference it; the source's
+	/// references in those facts are re-pointed to the new entity. The split is recorded in
+	/// `graph_entity_events`.
+	pub async fn admin_graph_entity_split(
+		&self,
+		req: AdminGraphEntitySplitRequest,
+	) -> Result<AdminGraphEntitySplitResponse> {
+		let scope = AuditScope::new("admin_graph_entity_split")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.entity_id].into_iter().chain(req.fact_ids.iter().copied()));
+
+		self.audited(scope, self.admin_graph_entity_split_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_graph_entity_split`] without recording an audit entry.
+	async fn admin_graph_entity_split_inner(
+		&self,
+		req: AdminGraphEntitySplitRequest,
+	) -> Result<AdminGraphEntitySplitResponse> {
+		let canonical = req.canonical.trim();
+		let (alias_norms, fact_ids) = validate_split_request(&req)?;
+		let reason = normalize_reason(req.reason.as_deref());
+		let mut tx = self.db.pool.begin().await?;
+		let source =
+			storage::lock_entities(&mut tx, &req.tenant_id, &req.project_id, &[req.entity_id])
+				.await?
+				.into_iter()
+				.next()
+				.ok_or_else(|| Error::NotFound {
+					message: format!("graph entity not found; entity_id={}", req.entity_id),
+				})?;
+		let canonical_norm = graph::normalize_entity_name(canonical);
+
+		if storage::entity_canonical_exists(
+			&mut tx,
+			&req.tenant_id,
+			&req.project_id,
+			&canonical_norm,
+		)
+		.await?
+		{
+			return Err(Error::Conflict {
+				message: format!("graph entity canonical already exists; canonical={canonical}"),
+			});
+		}
+
+		let kind = match req.kind.as_deref().map(str::trim).filter(|kind| !kind.is_empty()) {
+			Some(kind) => Some(
+				graph::resolve_or_register_entity_kind(
+					&mut tx,
+					&req.tenant_id,
+					&req.project_id,
+					kind,
+				)
+				.await?
+				.kind,
+			),
+			None => None,
+		};
+		let entity_id = graph::upsert_entity(
+			&mut tx,
+			&req.tenant_id,
+			&req.project_id,
+			canonical,
+			kind.as_deref(),
+		)
+		.await?;
+
+		for (alias, alias_norm) in req.aliases.iter().zip(alias_norms.iter()) {
+			if !storage::move_alias(&mut tx, source.entity_id, entity_id, alias_norm).await? {
+				return Err(Error::InvalidRequest {
+					message: format!(
+						"alias is not attached to the source entity: {}",
+						alias.trim()
+					),
+				});
+			}
+		}
+
+		let moved_fact_ids =
+			storage::repoint_listed_facts(&mut tx, source.entity_id, entity_id, &fact_ids).await?;
+
+		if moved_fact_ids.len() != fact_ids.len() {
+			let missing = fact_ids
+				.iter()
+				.filter(|fact_id| !moved_fact_ids.contains(fact_id))
+				.map(Uuid::to_string)
+				.collect::<Vec<_>>()
+				.join(", ");
+
+			return Err(Error::InvalidRequest {
+				message: format!("fact_ids must reference the source entity; missing=[{missing}]"),
+			});
+		}
+
+		storage::touch_entity(&mut tx, source.entity_id).await?;
+
+		let entity = load_entity_response(&mut tx, entity_id).await?;
+		let event_id = storage::insert_entity_event(
+			&mut tx,
+			GraphEntityEventArgs {
+				tenant_id: &req.tenant_id,
+				project_id: &req.project_id,
+				actor_agent_id: &req.agent_id,
+				event_type: "split",
+				entity_id: source.entity_id,
+				related_entity_ids: &[entity_id],
+				detail: serde_json::json!({
+					"source": { "entity_id": source.entity_id, "canonical": source.canonical },
+					"entity": {
+						"entity_id": entity.entity_id,
+						"canonical": entity.canonical,
+						"kind": entity.kind,
+					},
+					"aliases": entity.aliases,
+					"fact_ids": moved_fact_ids,
+				}),
+				reason: reason.as_deref(),
+			},
+		)
+		.await?;
+		let source = load_entity_response(&mut tx, source.entity_id).await?;
+
+		tx.commit().await?;
+
+		tracing::info!(
+			actor_agent_id = %req.agent_id,
+			source_entity_id = %source.entity_id,
+			entity_id = %entity.entity_id,
+			moved_alias_count = entity.aliases.len(),
+			moved_fact_count = moved_fact_ids.len(),
+			"Admin graph entity split."
+		);
+
+		Ok(AdminGraphEntitySplitResponse { event_id, source, entity, moved_fact_ids })
+	}
+}
+
+/// Deduplicates losers and rejects empty, oversized, or self-referencing merges.
+pub(super) fn validate_merge_losers(
+	winner_entity_id: Uuid,
+	loser_ids: &[Uuid],
+) -> Result<Vec<Uuid>> {
+	let loser_ids = dedupe_ids(loser_ids);
+
+	if loser_ids.is_empty() {
+		return Err(Error::InvalidRequest {
+			message: "loser_entity_ids must contain at least one entity.".to_string(),
+		});
+	}
+	if loser_ids.len() > MAX_GRAPH_ENTITY_MERGE_LOSERS {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"loser_entity_ids must contain at most {MAX_GRAPH_ENTITY_MERGE_LOSERS} entities."
+			),
+		});
+	}
+	if loser_ids.contains(&winner_entity_id) {
+		return Err(Error::InvalidRequest {
+			message: "loser_entity_ids must not contain winner_entity_id.".to_string(),
+		});
+	}
+
+	Ok(loser_ids)
+}
+
+/// Normalizes split aliases, rejecting blanks and duplicates.
+/// Validates the split target and returns the normalized aliases and deduplicated fact IDs.
+fn validate_split_request(req: &AdminGraphEntitySplitRequest) -> Result<(Vec<String>, Vec<Uuid>)> {
+	if req.canonical.trim().is_empty() {
+		return Err(Error::InvalidRequest { message: "canonical must be non-empty.".to_string() });
+	}
+
+	let alias_norms = validate_split_aliases(&req.aliases)?;
+	let fact_ids = dedupe_ids(&req.fact_ids);
+
+	if alias_norms.is_empty() && fact_ids.is_empty() {
+		return Err(Error::InvalidRequest {
+			message: "aliases or fact_ids must move at least one item.".to_string(),
+		});
+	}
+	if fact_ids.len() > MAX_GRAPH_ENTITY_SPLIT_ITEMS {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"fact_ids must contain at most {MAX_GRAPH_ENTITY_SPLIT_ITEMS} entries."
+			),
+		});
+	}
+
+	Ok((alias_norms, fact_ids))
+}
+
+pub(super) fn validate_split_aliases(aliases: &[String]) -> Result<Vec<String>> {
+	if aliases.len() > MAX_GRAPH_ENTITY_SPLIT_ITEMS {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"aliases must contain at most {MAX_GRAPH_ENTITY_SPLIT_ITEMS} entries."
+			),
+		});
+	}
+
+	let mut seen = HashSet::new();
+	let mut alias_norms = Vec::with_capacity(aliases.len());
+
+	for (idx, alias) in aliases.iter().enumerate() {
+		let alias_norm = graph::normalize_entity_name(alias);
+
+		if alias_norm.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: format!("aliases[{idx}] must be non-empty."),
+			});
+		}
+		if !seen.insert(alias_norm.clone()) {
+			return Err(Error::InvalidRequest {
+				message: format!("aliases[{idx}] duplicates an earlier alias."),
+			});
+		}
+
+		alias_norms.push(alias_norm);
+	}
+
+	Ok(alias_norms)
+}
+
+fn dedupe_ids(ids: &[Uuid]) -> Vec<Uuid> {
+	let mut seen = HashSet::new();
+
+	ids.iter().copied().filter(|id| seen.insert(*id)).collect()
+}
+
+fn repoint(entity_id: Uuid, from: Uuid, to: Uuid) -> Uuid {
+	if entity_id == from { to } else { entity_id }
+}
+
+fn normalize_reason(reason: Option<&str>) -> Option<String> {
+	reason.map(str::trim).filter(|reason| !reason.is_empty()).map(ToString::to_string)
+}
+
+async fn load_entity_response(
+	conn: &mut PgConnection,
+	entity_id: Uuid,
+) -> Result<AdminGraphEntityResponse> {
+	let GraphEntity { entity_id, canonical, kind, created_at, updated_at, .. } =
+		storage::get_entity(conn, entity_id).await?;
+	let aliases = storage::list_alias_surfaces(conn, entity_id).await?;
+
+	Ok(AdminGraphEntityResponse { entity_id, canonical, kind, aliases, created_at, updated_at })
+}
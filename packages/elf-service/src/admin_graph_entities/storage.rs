@@ -0,0 +1,364 @@
+use crate::admin_graph_entities::{FromRow, GraphEntity, PgConnection, Result, Uuid, Value};
+
+#[derive(Debug, FromRow)]
+pub(super) struct EntityFactRow {
+	pub(super) fact_id: Uuid,
+	pub(super) tenant_id: String,
+	pub(super) project_id: String,
+	pub(super) scope: String,
+	pub(super) subject_entity_id: Uuid,
+	pub(super) predicate_id: Option<Uuid>,
+	pub(super) object_entity_id: Option<Uuid>,
+	pub(super) object_value: Option<String>,
+	pub(super) is_active: bool,
+}
+
+pub(super) struct GraphEntityEventArgs<'a> {
+	pub(super) tenant_id: &'a str,
+	pub(super) project_id: &'a str,
+	pub(super) actor_agent_id: &'a str,
+	pub(super) event_type: &'a str,
+	pub(super) entity_id: Uuid,
+	pub(super) related_entity_ids: &'a [Uuid],
+	pub(super) detail: Value,
+	pub(super) reason: Option<&'a str>,
+}
+
+/// Locks the requested entities of one tenant/project in a stable order.
+pub(super) async fn lock_entities(
+	conn: &mut PgConnection,
+	tenant_id: &str,
+	project_id: &str,
+	entity_ids: &[Uuid],
+) -> Result<Vec<GraphEntity>> {
+	let entities = sqlx::query_as::<_, GraphEntity>(
+		"\
+SELECT
+	entity_id,
+	tenant_id,
+	project_id,
+	canonical,
+	canonical_norm,
+	kind,
+	created_at,
+	updated_at
+FROM graph_entities
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND entity_id = ANY($3::uuid[])
+ORDER BY entity_id
+FOR UPDATE",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(entity_ids)
+	.fetch_all(conn)
+	.await?;
+
+	Ok(entities)
+}
+
+pub(super) async fn get_entity(conn: &mut PgConnection, entity_id: Uuid) -> Result<GraphEntity> {
+	let entity = sqlx::query_as::<_, GraphEntity>(
+		"\
+SELECT
+	entity_id,
+	tenant_id,
+	project_id,
+	canonical,
+	canonical_norm,
+	kind,
+	created_at,
+	updated_at
+FROM graph_entities
+WHERE entity_id = $1",
+	)
+	.bind(entity_id)
+	.fetch_one(conn)
+	.await?;
+
+	Ok(entity)
+}
+
+pub(super) async fn entity_canonical_exists(
+	conn: &mut PgConnection,
+	tenant_id: &str,
+	project_id: &str,
+	canonical_norm: &str,
+) -> Result<bool> {
+	let exists = sqlx::query_scalar(
+		"\
+SELECT EXISTS (
+	SELECT 1
+	FROM graph_entities
+	WHERE tenant_id = $1 AND project_id = $2 AND canonical_norm = $3
+)",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(canonical_norm)
+	.fetch_one(conn)
+	.await?;
+
+	Ok(exists)
+}
+
+pub(super) async fn list_alias_surfaces(
+	conn: &mut PgConnection,
+	entity_id: Uuid,
+) -> Result<Vec<String>> {
+	let aliases = sqlx::query_scalar(
+		"SELECT alias FROM graph_entity_aliases WHERE entity_id = $1 ORDER BY alias_norm, alias_id",
+	)
+	.bind(entity_id)
+	.fetch_all(conn)
+	.await?;
+
+	Ok(aliases)
+}
+
+/// Moves one alias between entities and returns whether the source held it.
+pub(super) async fn move_alias(
+	conn: &mut PgConnection,
+	from_entity_id: Uuid,
+	to_entity_id: Uuid,
+	alias_norm: &str,
+) -> Result<bool> {
+	let result = sqlx::query(
+		"UPDATE graph_entity_aliases SET entity_id = $2 WHERE entity_id = $1 AND alias_norm = $3",
+	)
+	.bind(from_entity_id)
+	.bind(to_entity_id)
+	.bind(alias_norm)
+	.execute(conn)
+	.await?;
+
+	Ok(result.rows_affected() > 0)
+}
+
+/// Locks every fact that references the entity as subject or object.
+pub(super) async fn lock_entity_facts(
+	conn: &mut PgConnection,
+	entity_id: Uuid,
+) -> Result<Vec<EntityFactRow>> {
+	let facts = sqlx::query_as::<_, EntityFactRow>(
+		"\
+SELECT
+	fact_id,
+	tenant_id,
+	project_id,
+	scope,
+	subject_entity_id,
+	predicate_id,
+	object_entity_id,
+	object_value,
+	valid_to IS NULL AS is_active
+FROM graph_facts
+WHERE subject_entity_id = $1 OR object_entity_id = $1
+ORDER BY valid_from ASC, fact_id ASC
+FOR UPDATE",
+	)
+	.bind(entity_id)
+	.fetch_all(conn)
+	.await?;
+
+	Ok(facts)
+}
+
+/// Finds another active fact with the same identity key the re-pointed fact would take.
+pub(super) async fn find_active_duplicate(
+	conn: &mut PgConnection,
+	fact: &EntityFactRow,
+	subject_entity_id: Uuid,
+	object_entity_id: Option<Uuid>,
+) -> Result<Option<Uuid>> {
+	let Some(predicate_id) = fact.predicate_id else {
+		return Ok(None);
+	};
+	let duplicate = sqlx::query_scalar(
+		"\
+SELECT fact_id
+FROM graph_facts
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND scope = $3
+	AND subject_entity_id = $4
+	AND predicate_id = $5
+	AND object_entity_id IS NOT DISTINCT FROM $6
+	AND object_value IS NOT DISTINCT FROM $7
+	AND valid_to IS NULL
+	AND fact_id <> $8
+LIMIT 1",
+	)
+	.bind(fact.tenant_id.as_str())
+	.bind(fact.project_id.as_str())
+	.bind(fact.scope.as_str())
+	.bind(subject_entity_id)
+	.bind(predicate_id)
+	.bind(object_entity_id)
+	.bind(fact.object_value.as_deref())
+	.bind(fact.fact_id)
+	.fetch_optional(conn)
+	.await?;
+
+	Ok(duplicate)
+}
+
+/// Copies evidence and supersession links from a duplicate fact to its survivor, then deletes
+/// the duplicate.
+pub(super) async fn fold_duplicate_fact(
+	conn: &mut PgConnection,
+	duplicate_fact_id: Uuid,
+	into_fact_id: Uuid,
+) -> Result<()> {
+	sqlx::query(
+		"\
+INSERT INTO graph_fact_evidence (evidence_id, fact_id, note_id, created_at)
+SELECT gen_random_uuid(), $2, note_id, created_at
+FROM graph_fact_evidence
+WHERE fact_id = $1
+ON CONFLICT (fact_id, note_id) DO NOTHING",
+	)
+	.bind(duplicate_fact_id)
+	.bind(into_fact_id)
+	.execute(&mut *conn)
+	.await?;
+	sqlx::query(
+		"\
+INSERT INTO graph_fact_supersessions (
+	supersession_id,
+	tenant_id,
+	project_id,
+	from_fact_id,
+	to_fact_id,
+	note_id,
+	effective_at,
+	created_at
+)
+SELECT
+	gen_random_uuid(),
+	tenant_id,
+	project_id,
+	CASE WHEN from_fact_id = $1 THEN $2 ELSE from_fact_id END,
+	CASE WHEN to_fact_id = $1 THEN $2 ELSE to_fact_id END,
+	note_id,
+	effective_at,
+	created_at
+FROM graph_fact_supersessions
+WHERE (from_fact_id = $1 AND to_fact_id <> $2) OR (to_fact_id = $1 AND from_fact_id <> $2)
+ON CONFLICT (from_fact_id, to_fact_id, note_id) DO NOTHING",
+	)
+	.bind(duplicate_fact_id)
+	.bind(into_fact_id)
+	.execute(&mut *conn)
+	.await?;
+	sqlx::query("DELETE FROM graph_facts WHERE fact_id = $1")
+		.bind(duplicate_fact_id)
+		.execute(conn)
+		.await?;
+
+	Ok(())
+}
+
+pub(super) async fn repoint_fact(
+	conn: &mut PgConnection,
+	fact_id: Uuid,
+	subject_entity_id: Uuid,
+	object_entity_id: Option<Uuid>,
+) -> Result<()> {
+	sqlx::query(
+		"\
+UPDATE graph_facts
+SET subject_entity_id = $2, object_entity_id = $3, updated_at = now()
+WHERE fact_id = $1",
+	)
+	.bind(fact_id)
+	.bind(subject_entity_id)
+	.bind(object_entity_id)
+	.execute(conn)
+	.await?;
+
+	Ok(())
+}
+
+/// Re-points the listed facts from one entity to another and returns the facts that changed.
+pub(super) async fn repoint_listed_facts(
+	conn: &mut PgConnection,
+	from_entity_id: Uuid,
+	to_entity_id: Uuid,
+	fact_ids: &[Uuid],
+) -> Result<Vec<Uuid>> {
+	let moved = sqlx::query_scalar(
+		"\
+UPDATE graph_facts
+SET
+	subject_entity_id = CASE WHEN subject_entity_id = $1 THEN $2 ELSE subject_entity_id END,
+	object_entity_id = CASE WHEN object_entity_id = $1 THEN $2 ELSE object_entity_id END,
+	updated_at = now()
+WHERE fact_id = ANY($3::uuid[])
+	AND (subject_entity_id = $1 OR object_entity_id = $1)
+RETURNING fact_id",
+	)
+	.bind(from_entity_id)
+	.bind(to_entity_id)
+	.bind(fact_ids)
+	.fetch_all(conn)
+	.await?;
+
+	Ok(moved)
+}
+
+pub(super) async fn touch_entity(conn: &mut PgConnection, entity_id: Uuid) -> Result<()> {
+	sqlx::query("UPDATE graph_entities SET updated_at = now() WHERE entity_id = $1")
+		.bind(entity_id)
+		.execute(conn)
+		.await?;
+
+	Ok(())
+}
+
+pub(super) async fn delete_entity(conn: &mut PgConnection, entity_id: Uuid) -> Result<()> {
+	sqlx::query("DELETE FROM graph_entities WHERE entity_id = $1")
+		.bind(entity_id)
+		.execute(conn)
+		.await?;
+
+	Ok(())
+}
+
+pub(super) async fn insert_entity_event(
+	conn: &mut PgConnection,
+	args: GraphEntityEventArgs<'_>,
+) -> Result<Uuid> {
+	let event_id = Uuid::new_v4();
+
+	sqlx::query(
+		"\
+INSERT INTO graph_entity_events (
+	event_id,
+	tenant_id,
+	project_id,
+	actor_agent_id,
+	event_type,
+	entity_id,
+	related_entity_ids,
+	detail,
+	reason,
+	ts
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, now())",
+	)
+	.bind(event_id)
+	.bind(args.tenant_id)
+	.bind(args.project_id)
+	.bind(args.actor_agent_id)
+	.bind(args.event_type)
+	.bind(args.entity_id)
+	.bind(args.related_entity_ids)
+	.bind(args.detail)
+	.bind(args.reason)
+	.execute(conn)
+	.await?;
+
+	Ok(event_id)
+}
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+use crate::{Error, admin_graph_entities::service};
+
+#[test]
+fn merge_losers_are_deduplicated_and_exclude_the_winner() {
+	let winner = Uuid::new_v4();
+	let loser = Uuid::new_v4();
+	let losers = service::validate_merge_losers(winner, &[loser, loser])
+		.expect("duplicate losers should collapse");
+
+	assert_eq!(losers, vec![loser]);
+
+	for loser_ids in [vec![], vec![winner, loser]] {
+		let err = service::validate_merge_losers(winner, &loser_ids)
+			.expect_err("empty or self-referencing merge should fail");
+
+		assert!(matches!(err, Error::InvalidRequest { .. }));
+	}
+}
+
+#[test]
+fn split_aliases_are_normalized_and_must_be_unique() {
+	let norms = service::validate_split_aliases(&[" Postgres  DB ".to_string()])
+		.expect("alias should normalize");
+
+	assert_eq!(norms, vec!["postgres db".to_string()]);
+
+	let err = service::validate_split_aliases(&["Postgres".to_string(), "postgres".to_string()])
+		.expect_err("duplicate aliases should fail");
+
+	assert!(matches!(err, Error::InvalidRequest { message } if message.contains("aliases[1]")));
+}
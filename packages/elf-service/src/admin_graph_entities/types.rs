@@ -0,0 +1,119 @@
+use crate::{
+	admin_graph_entities::{OffsetDateTime, Serialize, Uuid},
+	audit::AuditTargets,
+};
+
+/// Request payload for merging duplicate graph entities into one winner.
+#[derive(Clone, Debug)]
+pub struct AdminGraphEntitiesMergeRequest {
+	/// Tenant that owns the entities.
+	pub tenant_id: String,
+	/// Project that owns the entities.
+	pub project_id: String,
+	/// Agent requesting the merge.
+	pub agent_id: String,
+	/// Entity that survives the merge.
+	pub winner_entity_id: Uuid,
+	/// Entities folded into the winner and removed.
+	pub loser_entity_ids: Vec<Uuid>,
+	/// Optional operator note stored with the audit event.
+	pub reason: Option<String>,
+}
+
+/// Request payload for splitting part of a graph entity into a new entity.
+#[derive(Clone, Debug)]
+pub struct AdminGraphEntitySplitRequest {
+	/// Tenant that owns the entity.
+	pub tenant_id: String,
+	/// Project that owns the entity.
+	pub project_id: String,
+	/// Agent requesting the split.
+	pub agent_id: String,
+	/// Entity that aliases and facts are moved away from.
+	pub entity_id: Uuid,
+	/// Canonical surface for the new entity.
+	pub canonical: String,
+	/// Optional kind for the new entity.
+	pub kind: Option<String>,
+	/// Alias surfaces moved from the source entity to the new entity.
+	pub aliases: Vec<String>,
+	/// Facts whose references to the source entity are re-pointed to the new entity.
+	pub fact_ids: Vec<Uuid>,
+	/// Optional operator note stored with the audit event.
+	pub reason: Option<String>,
+}
+
+/// Graph entity with its aliases, returned by admin entity APIs.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminGraphEntityResponse {
+	/// Entity identifier.
+	pub entity_id: Uuid,
+	/// Canonical entity surface.
+	pub canonical: String,
+	/// Optional entity kind.
+	pub kind: Option<String>,
+	/// Alias surfaces attached to the entity.
+	pub aliases: Vec<String>,
+	#[serde(with = "crate::time_serde")]
+	/// Creation timestamp.
+	pub created_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
+
+/// Active fact dropped during a merge because the winner already held the same fact.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminGraphDeduplicatedFact {
+	/// Removed duplicate fact identifier.
+	pub fact_id: Uuid,
+	/// Surviving fact that received the duplicate's evidence.
+	pub into_fact_id: Uuid,
+}
+
+/// Response payload for a graph entity merge.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminGraphEntitiesMergeResponse {
+	/// Audit event identifier.
+	pub event_id: Uuid,
+	/// Winner entity after the merge.
+	pub entity: AdminGraphEntityResponse,
+	/// Entities folded into the winner.
+	pub merged_entity_ids: Vec<Uuid>,
+	/// Facts re-pointed from a merged entity to the winner.
+	pub repointed_fact_ids: Vec<Uuid>,
+	/// Active facts removed as duplicates of existing winner facts.
+	pub deduplicated_facts: Vec<AdminGraphDeduplicatedFact>,
+}
+
+impl AuditTargets for AdminGraphEntitiesMergeResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		[self.event_id, self.entity.entity_id]
+			.into_iter()
+			.chain(self.merged_entity_ids.iter().copied())
+			.chain(self.repointed_fact_ids.iter().copied())
+			.collect()
+	}
+}
+
+/// Response payload for a graph entity split.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminGraphEntitySplitResponse {
+	/// Audit event identifier.
+	pub event_id: Uuid,
+	/// Source entity after the split.
+	pub source: AdminGraphEntityResponse,
+	/// Newly created entity.
+	pub entity: AdminGraphEntityResponse,
+	/// Facts re-pointed from the source entity to the new entity.
+	pub moved_fact_ids: Vec<Uuid>,
+}
+
+impl AuditTargets for AdminGraphEntitySplitResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		[self.event_id, self.source.entity_id, self.entity.entity_id]
+			.into_iter()
+			.chain(self.moved_fact_ids.iter().copied())
+			.collect()
+	}
+}
@@ -0,0 +1,171 @@
+//! Administrative graph entity-kind vocabulary APIs.
+
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	audit::{AuditScope, AuditTargets},
+};
+use elf_storage::{graph, models::GraphEntityKind};
+
+const GRAPH_SCOPE_TENANT_PREFIX: &str = "__tenant__:";
+
+/// Request payload for listing graph entity kinds visible in admin scope.
+#[derive(Clone, Debug)]
+pub struct AdminGraphEntityKindsListRequest {
+	/// Tenant to query within.
+	pub tenant_id: String,
+	/// Project to query within.
+	pub project_id: String,
+	/// Agent requesting the list.
+	pub agent_id: String,
+	/// Optional admin scope filter.
+	pub scope: Option<String>,
+}
+
+/// Request payload for promoting a project entity kind to the tenant vocabulary.
+#[derive(Clone, Debug)]
+pub struct AdminGraphEntityKindPromoteRequest {
+	/// Tenant to query within.
+	pub tenant_id: String,
+	/// Project that owns the entity kind.
+	pub project_id: String,
+	/// Agent requesting the promotion.
+	pub agent_id: String,
+	/// Entity kind identifier to promote.
+	pub kind_id: Uuid,
+}
+
+/// Serialized graph entity kind returned by admin APIs.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminGraphEntityKindResponse {
+	/// Entity kind identifier.
+	pub kind_id: Uuid,
+	/// Entity kind scope key.
+	pub scope_key: String,
+	/// Tenant that owns the kind.
+	pub tenant_id: String,
+	/// Project scope when project-specific.
+	pub project_id: Option<String>,
+	/// Canonical kind surface.
+	pub kind: String,
+	/// Normalized kind surface.
+	pub kind_norm: String,
+	#[serde(with = "crate::time_serde")]
+	/// Creation timestamp.
+	pub created_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
+
+impl AuditTargets for AdminGraphEntityKindResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.kind_id]
+	}
+}
+
+/// Response payload for listing graph entity kinds.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminGraphEntityKindsListResponse {
+	/// Returned entity kinds.
+	pub entity_kinds: Vec<AdminGraphEntityKindResponse>,
+}
+
+impl ElfService {
+	/// Lists graph entity kinds visible to the caller's admin context.
+	pub async fn admin_graph_entity_kinds_list(
+		&self,
+		req: AdminGraphEntityKindsListRequest,
+	) -> Result<AdminGraphEntityKindsListResponse> {
+		let tenant_project_key = scope_key_tenant_project(&req.tenant_id, &req.project_id);
+		let tenant_key = scope_key_tenant(&req.tenant_id);
+		let scope_keys = match req.scope.as_deref().unwrap_or("all").trim() {
+			"tenant_project" => vec![tenant_project_key],
+			"tenant" => vec![tenant_key],
+			"all" => vec![tenant_project_key, tenant_key],
+			_ => {
+				return Err(Error::InvalidRequest {
+					message: "scope must be one of tenant_project|tenant|all".to_string(),
+				});
+			},
+		};
+		let mut conn = self.db.pool.acquire().await?;
+		let entity_kinds = graph::list_entity_kinds_by_scope_keys(&mut conn, &scope_keys)
+			.await?
+			.into_iter()
+			.map(to_entity_kind_response)
+			.collect();
+
+		Ok(AdminGraphEntityKindsListResponse { entity_kinds })
+	}
+
+	/// Promotes a tenant-project entity kind to the tenant vocabulary shared by every project.
+	pub async fn admin_graph_entity_kind_promote(
+		&self,
+		req: AdminGraphEntityKindPromoteRequest,
+	) -> Result<AdminGraphEntityKindResponse> {
+		let scope = AuditScope::new("admin_graph_entity_kind_promote")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.kind_id]);
+
+		self.audited(scope, self.admin_graph_entity_kind_promote_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_graph_entity_kind_promote`] without recording an audit entry.
+	async fn admin_graph_entity_kind_promote_inner(
+		&self,
+		req: AdminGraphEntityKindPromoteRequest,
+	) -> Result<AdminGraphEntityKindResponse> {
+		let mut tx = self.db.pool.begin().await?;
+		let existing = graph::get_entity_kind_by_id(&mut tx, req.kind_id)
+			.await?
+			.filter(|kind| kind.tenant_id == req.tenant_id)
+			.ok_or_else(|| Error::NotFound {
+				message: format!("graph entity kind not found; kind_id={}", req.kind_id),
+			})?;
+
+		if existing.scope_key != scope_key_tenant_project(&req.tenant_id, &req.project_id) {
+			return Err(Error::InvalidRequest {
+				message: "Only tenant_project graph entity kinds can be promoted.".to_string(),
+			});
+		}
+
+		let promoted = graph::promote_entity_kind_to_tenant(&mut tx, req.kind_id).await?;
+
+		tx.commit().await?;
+
+		tracing::info!(
+			actor_agent_id = %req.agent_id,
+			kind_id = %req.kind_id,
+			from_scope_key = %existing.scope_key,
+			to_scope_key = %promoted.scope_key,
+			"Admin graph entity kind promoted."
+		);
+
+		Ok(to_entity_kind_response(promoted))
+	}
+}
+
+fn scope_key_tenant_project(tenant_id: &str, project_id: &str) -> String {
+	format!("{tenant_id}:{project_id}")
+}
+
+fn scope_key_tenant(tenant_id: &str) -> String {
+	format!("{GRAPH_SCOPE_TENANT_PREFIX}{tenant_id}")
+}
+
+fn to_entity_kind_response(kind: GraphEntityKind) -> AdminGraphEntityKindResponse {
+	AdminGraphEntityKindResponse {
+		kind_id: kind.kind_id,
+		scope_key: kind.scope_key,
+		tenant_id: kind.tenant_id,
+		project_id: kind.project_id,
+		kind: kind.kind,
+		kind_norm: kind.kind_norm,
+		created_at: kind.created_at,
+		updated_at: kind.updated_at,
+	}
+}
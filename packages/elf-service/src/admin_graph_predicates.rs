@@ -7,6 +7,6 @@ mod types;
 pub use types::{
 	AdminGraphPredicateAliasAddRequest, AdminGraphPredicateAliasResponse,
 	AdminGraphPredicateAliasesListRequest, AdminGraphPredicateAliasesResponse,
-	AdminGraphPredicatePatchRequest, AdminGraphPredicateResponse, AdminGraphPredicatesListRequest,
-	AdminGraphPredicatesListResponse,
+	AdminGraphPredicatePatchRequest, AdminGraphPredicatePromoteRequest,
+	AdminGraphPredicateResponse, AdminGraphPredicatesListRequest, AdminGraphPredicatesListResponse,
 };
@@ -14,11 +14,13 @@ use elf_storage::{
 
 const GRAPH_PREDICATE_SCOPE_GLOBAL: &str = "__global__";
 const GRAPH_PREDICATE_SCOPE_PROJECT_PREFIX: &str = "__project__:";
+const GRAPH_PREDICATE_SCOPE_TENANT_PREFIX: &str = "__tenant__:";
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(super) enum AdminGraphPredicateScope {
 	TenantProject,
 	Project,
+	Tenant,
 	Global,
 	All,
 }
@@ -27,6 +29,7 @@ impl AdminGraphPredicateScope {
 		match raw.trim() {
 			"tenant_project" => Some(Self::TenantProject),
 			"project" => Some(Self::Project),
+			"tenant" => Some(Self::Tenant),
 			"global" => Some(Self::Global),
 			"all" => Some(Self::All),
 			_ => None,
@@ -45,18 +48,29 @@ pub(super) fn graph_predicate_scope_keys(
 	project_id: &str,
 	scope: AdminGraphPredicateScope,
 ) -> Vec<String> {
-	let tenant_project_key = format!("{tenant_id}:{project_id}");
+	let tenant_project_key = graph_scope_key_tenant_project(tenant_id, project_id);
 	let project_key = format!("{GRAPH_PREDICATE_SCOPE_PROJECT_PREFIX}{project_id}");
+	let tenant_key = graph_scope_key_tenant(tenant_id);
 	let global_key = GRAPH_PREDICATE_SCOPE_GLOBAL.to_string();
 
 	match scope {
 		AdminGraphPredicateScope::TenantProject => vec![tenant_project_key],
 		AdminGraphPredicateScope::Project => vec![project_key],
+		AdminGraphPredicateScope::Tenant => vec![tenant_key],
 		AdminGraphPredicateScope::Global => vec![global_key],
-		AdminGraphPredicateScope::All => vec![tenant_project_key, project_key, global_key],
+		AdminGraphPredicateScope::All =>
+			vec![tenant_project_key, project_key, tenant_key, global_key],
 	}
 }
 
+pub(super) fn graph_scope_key_tenant_project(tenant_id: &str, project_id: &str) -> String {
+	format!("{tenant_id}:{project_id}")
+}
+
+pub(super) fn graph_scope_key_tenant(tenant_id: &str) -> String {
+	format!("{GRAPH_PREDICATE_SCOPE_TENANT_PREFIX}{tenant_id}")
+}
+
 pub(super) fn predicate_status_transition_allowed(old: &str, new: &str) -> bool {
 	matches!(
 		(old, new),
@@ -123,10 +137,11 @@ pub(super) async fn load_predicate_in_context(
 		.ok_or_else(|| crate::Error::NotFound {
 			message: format!("graph predicate not found; predicate_id={predicate_id}"),
 		})?;
-	let tenant_project_key = format!("{tenant_id}:{project_id}");
+	let tenant_project_key = graph_scope_key_tenant_project(tenant_id, project_id);
 	let project_key = format!("{GRAPH_PREDICATE_SCOPE_PROJECT_PREFIX}{project_id}");
-	let is_in_context =
-		predicate.scope_key == tenant_project_key || predicate.scope_key == project_key;
+	let is_in_context = predicate.scope_key == tenant_project_key
+		|| predicate.scope_key == project_key
+		|| predicate.scope_key == graph_scope_key_tenant(tenant_id);
 	let is_global = predicate.scope_key == GRAPH_PREDICATE_SCOPE_GLOBAL;
 
 	if !is_in_context && !is_global {
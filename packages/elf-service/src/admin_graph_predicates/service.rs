@@ -1,4 +1,4 @@
 mod aliases;
-mod auth;
 mod list;
 mod patch;
+mod promote;
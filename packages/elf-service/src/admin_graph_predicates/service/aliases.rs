@@ -2,15 +2,15 @@ use sqlx::PgConnection;
 use uuid::Uuid;
 
 use crate::{
-	ElfService, Error, Result,
+	ElfService, Error, Result, access,
 	admin_graph_predicates::{
 		helpers::{self, PredicateAccess, map_storage_error, to_alias_response},
-		service::auth,
 		types::{
 			AdminGraphPredicateAliasAddRequest, AdminGraphPredicateAliasesListRequest,
 			AdminGraphPredicateAliasesResponse,
 		},
 	},
+	audit::AuditScope,
 };
 use elf_storage::graph;
 
@@ -19,6 +19,18 @@ impl ElfService {
 	pub async fn admin_graph_predicate_alias_add(
 		&self,
 		req: AdminGraphPredicateAliasAddRequest,
+	) -> Result<AdminGraphPredicateAliasesResponse> {
+		let scope = AuditScope::new("admin_graph_predicate_alias_add")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.predicate_id]);
+
+		self.audited(scope, self.admin_graph_predicate_alias_add_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_graph_predicate_alias_add`] without recording an audit entry.
+	async fn admin_graph_predicate_alias_add_inner(
+		&self,
+		req: AdminGraphPredicateAliasAddRequest,
 	) -> Result<AdminGraphPredicateAliasesResponse> {
 		let alias = req.alias.trim();
 
@@ -26,7 +38,7 @@ impl ElfService {
 			return Err(Error::InvalidRequest { message: "alias must be non-empty.".to_string() });
 		}
 
-		let allow_global_mutation = auth::is_super_admin_token_id(self, req.token_id.as_deref());
+		let allow_global_mutation = access::is_super_admin_token_id(self, req.token_id.as_deref());
 		let mut conn = self.db.pool.acquire().await?;
 		let predicate = helpers::load_predicate_in_context(
 			&mut conn,
@@ -15,7 +15,7 @@ impl ElfService {
 	) -> Result<AdminGraphPredicatesListResponse> {
 		let raw = req.scope.as_deref().unwrap_or("all");
 		let scope = AdminGraphPredicateScope::parse(raw).ok_or_else(|| Error::InvalidRequest {
-			message: "scope must be one of tenant_project|project|global|all".to_string(),
+			message: "scope must be one of tenant_project|project|tenant|global|all".to_string(),
 		})?;
 		let scope_keys = helpers::graph_predicate_scope_keys(
 			req.tenant_id.as_str(),
@@ -1,10 +1,10 @@
 use crate::{
-	ElfService, Error, Result,
+	ElfService, Error, Result, access,
 	admin_graph_predicates::{
 		helpers::{self, PredicateAccess, map_storage_error},
-		service::auth,
 		types::{AdminGraphPredicatePatchRequest, AdminGraphPredicateResponse},
 	},
+	audit::AuditScope,
 };
 use elf_storage::graph;
 
@@ -13,6 +13,18 @@ impl ElfService {
 	pub async fn admin_graph_predicate_patch(
 		&self,
 		req: AdminGraphPredicatePatchRequest,
+	) -> Result<AdminGraphPredicateResponse> {
+		let scope = AuditScope::new("admin_graph_predicate_patch")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.predicate_id]);
+
+		self.audited(scope, self.admin_graph_predicate_patch_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_graph_predicate_patch`] without recording an audit entry.
+	async fn admin_graph_predicate_patch_inner(
+		&self,
+		req: AdminGraphPredicatePatchRequest,
 	) -> Result<AdminGraphPredicateResponse> {
 		if req.status.is_none() && req.cardinality.is_none() {
 			return Err(Error::InvalidRequest {
@@ -34,7 +46,7 @@ impl ElfService {
 			});
 		}
 
-		let allow_global_mutation = auth::is_super_admin_token_id(self, req.token_id.as_deref());
+		let allow_global_mutation = access::is_super_admin_token_id(self, req.token_id.as_deref());
 		let mut conn = self.db.pool.acquire().await?;
 		let existing = helpers::load_predicate_in_context(
 			&mut conn,
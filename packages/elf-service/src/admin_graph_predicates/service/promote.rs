@@ -0,0 +1,71 @@
+use crate::{
+	ElfService, Error, Result,
+	admin_graph_predicates::{
+		helpers::{self, PredicateAccess, map_storage_error},
+		types::{AdminGraphPredicatePromoteRequest, AdminGraphPredicateResponse},
+	},
+	audit::AuditScope,
+};
+use elf_storage::graph;
+
+impl ElfService {
+	/// Promotes a tenant-project predicate to the tenant vocabulary shared by every project.
+	pub async fn admin_graph_predicate_promote(
+		&self,
+		req: AdminGraphPredicatePromoteRequest,
+	) -> Result<AdminGraphPredicateResponse> {
+		let scope = AuditScope::new("admin_graph_predicate_promote")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.predicate_id]);
+
+		self.audited(scope, self.admin_graph_predicate_promote_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_graph_predicate_promote`] without recording an audit entry.
+	async fn admin_graph_predicate_promote_inner(
+		&self,
+		req: AdminGraphPredicatePromoteRequest,
+	) -> Result<AdminGraphPredicateResponse> {
+		let mut tx = self.db.pool.begin().await?;
+		let existing = helpers::load_predicate_in_context(
+			&mut tx,
+			req.tenant_id.as_str(),
+			req.project_id.as_str(),
+			req.predicate_id,
+			PredicateAccess::Mutate,
+			false,
+		)
+		.await?;
+		let tenant_project_key = helpers::graph_scope_key_tenant_project(
+			req.tenant_id.as_str(),
+			req.project_id.as_str(),
+		);
+
+		if existing.scope_key != tenant_project_key {
+			return Err(Error::InvalidRequest {
+				message: "Only tenant_project graph predicates can be promoted.".to_string(),
+			});
+		}
+		if existing.status == "deprecated" {
+			return Err(Error::Conflict {
+				message: "graph predicate is deprecated and cannot be modified.".to_string(),
+			});
+		}
+
+		let promoted = graph::promote_predicate_to_tenant(&mut tx, req.predicate_id)
+			.await
+			.map_err(map_storage_error)?;
+
+		tx.commit().await?;
+
+		tracing::info!(
+			actor_agent_id = %req.agent_id,
+			predicate_id = %req.predicate_id,
+			from_scope_key = %existing.scope_key,
+			to_scope_key = %promoted.scope_key,
+			"Admin graph predicate promoted."
+		);
+
+		Ok(helpers::to_predicate_response(promoted))
+	}
+}
@@ -1,3 +1,4 @@
+use crate::audit::AuditTargets;
 use serde::Serialize;
 use time::OffsetDateTime;
 use uuid::Uuid;
@@ -34,6 +35,19 @@ pub struct AdminGraphPredicatePatchRequest {
 	pub cardinality: Option<String>,
 }
 
+/// Request payload for promoting a project predicate to the tenant vocabulary.
+#[derive(Clone, Debug)]
+pub struct AdminGraphPredicatePromoteRequest {
+	/// Tenant to query within.
+	pub tenant_id: String,
+	/// Project that owns the predicate.
+	pub project_id: String,
+	/// Agent requesting the promotion.
+	pub agent_id: String,
+	/// Predicate identifier to promote.
+	pub predicate_id: Uuid,
+}
+
 /// Request payload for adding a graph predicate alias.
 #[derive(Clone, Debug)]
 pub struct AdminGraphPredicateAliasAddRequest {
@@ -91,6 +105,12 @@ pub struct AdminGraphPredicateResponse {
 	pub updated_at: OffsetDateTime,
 }
 
+impl AuditTargets for AdminGraphPredicateResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.predicate_id]
+	}
+}
+
 /// Serialized graph predicate alias returned by admin APIs.
 #[derive(Clone, Debug, Serialize)]
 pub struct AdminGraphPredicateAliasResponse {
@@ -124,3 +144,9 @@ pub struct AdminGraphPredicateAliasesResponse {
 	/// Returned aliases.
 	pub aliases: Vec<AdminGraphPredicateAliasResponse>,
 }
+
+impl AuditTargets for AdminGraphPredicateAliasesResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.predicate_id]
+	}
+}
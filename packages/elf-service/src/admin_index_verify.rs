@@ -0,0 +1,531 @@
+//! Postgres-to-Qdrant index drift verification.
+//!
+//! Postgres is the source of truth: every chunk of an active note should have exactly one point,
+//! keyed by chunk id and tagged with the chunk's embedding version. Repair enqueues outbox rows
+//! instead of writing Qdrant directly, so the worker remains the only index writer.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use qdrant_client::qdrant::{
+	PointId, RetrievedPoint, ScrollPointsBuilder, Value, point_id::PointIdOptions, value::Kind,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgExecutor};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{ElfService, Error, Result};
+
+/// Index verification report schema identifier.
+pub const ELF_INDEX_VERIFY_SCHEMA_V1: &str = "elf.index_verify/v1";
+
+const SCROLL_PAGE_SIZE: u32 = 256;
+const DEFAULT_MAX_FINDINGS: u32 = 500;
+const MAX_FINDINGS: u32 = 5_000;
+
+/// Request payload for a Postgres-to-Qdrant index verification.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IndexVerifyRequest {
+	#[serde(default)]
+	/// When true, enqueues the outbox rows that bring drifted notes back in sync.
+	pub repair: bool,
+	/// Maximum number of findings returned in the report. Counts always cover every point.
+	pub max_findings: Option<u32>,
+}
+
+/// Result of one index verification.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IndexVerifyReport {
+	/// Response schema identifier.
+	pub schema: String,
+	/// Verified Qdrant collection.
+	pub collection: String,
+	/// Number of active-note chunks in Postgres.
+	pub expected_count: u64,
+	/// Number of points scanned in Qdrant.
+	pub scanned_count: u64,
+	/// Drift counts by finding kind.
+	pub counts: IndexVerifyCounts,
+	/// Drift counts per embedding version, ordered by version.
+	pub versions: Vec<IndexVerifyVersion>,
+	/// Drifted chunks and points, capped at `max_findings`.
+	pub findings: Vec<IndexVerifyFinding>,
+	/// True when more drift was found than findings returned.
+	pub findings_truncated: bool,
+	/// Outbox rows enqueued by repair mode; `None` when repair was not requested.
+	pub repair: Option<IndexVerifyRepair>,
+}
+
+/// Drift counts by finding kind.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IndexVerifyCounts {
+	/// Active-note chunks without a point.
+	pub missing: u64,
+	/// Points without an active-note chunk.
+	pub orphaned: u64,
+	/// Points whose embedding version differs from their chunk's.
+	pub stale: u64,
+}
+
+/// Drift counts for one embedding version.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IndexVerifyVersion {
+	/// Embedding version.
+	pub embedding_version: String,
+	/// Active-note chunks in Postgres at this version.
+	pub expected_count: u64,
+	/// Qdrant points tagged with this version.
+	pub point_count: u64,
+	/// Chunks at this version without a point.
+	pub missing: u64,
+	/// Points tagged with this version without an active-note chunk.
+	pub orphaned: u64,
+	/// Chunks at this version whose point carries another version.
+	pub stale: u64,
+}
+
+/// One drifted chunk or point.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IndexVerifyFinding {
+	/// Finding kind: missing, orphaned, or stale.
+	pub kind: String,
+	/// Qdrant point identifier; the chunk id for missing and stale findings.
+	pub point_id: String,
+	/// Note identifier from Postgres or, for orphaned points, the payload.
+	pub note_id: Option<Uuid>,
+	/// Embedding version of the Postgres chunk, when one exists.
+	pub expected_embedding_version: Option<String>,
+	/// Embedding version from the point payload, when a point exists.
+	pub point_embedding_version: Option<String>,
+}
+
+/// Outbox rows enqueued by repair mode.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IndexVerifyRepair {
+	/// Notes enqueued for re-indexing.
+	pub upsert_enqueued: u64,
+	/// Notes enqueued for point deletion.
+	pub delete_enqueued: u64,
+	/// Drifted notes skipped because an indexing job is already open for them.
+	pub skipped_open_jobs: u64,
+	/// Orphaned points skipped because their payload has no parseable note_id.
+	pub unrepairable: u64,
+}
+
+#[derive(Clone, Debug, FromRow)]
+struct ExpectedChunkRow {
+	chunk_id: Uuid,
+	note_id: Uuid,
+	embedding_version: String,
+}
+
+#[derive(Clone, Debug, FromRow)]
+struct NoteVersionRow {
+	note_id: Uuid,
+	embedding_version: String,
+}
+
+#[derive(Clone, Debug, Default)]
+struct IndexPoint {
+	point_id: String,
+	chunk_id: Option<Uuid>,
+	note_id: Option<Uuid>,
+	embedding_version: Option<String>,
+}
+
+/// Notes the repair pass should enqueue, keyed by outbox op.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct RepairPlan {
+	upsert: BTreeSet<Uuid>,
+	delete: BTreeMap<Uuid, Option<String>>,
+	unrepairable: u64,
+}
+
+impl ElfService {
+	/// Compares active-note chunks in Postgres with the note collection and reports missing,
+	/// orphaned, and stale points.
+	///
+	/// With `repair`, enqueues one UPSERT per active note with drift and one DELETE per note
+	/// that still has points but is no longer active.
+	pub async fn admin_verify_index(&self, req: IndexVerifyRequest) -> Result<IndexVerifyReport> {
+		let max_findings =
+			req.max_findings.unwrap_or(DEFAULT_MAX_FINDINGS).clamp(1, MAX_FINDINGS) as usize;
+		let now = OffsetDateTime::now_utc();
+		let collection = self.qdrant.collection.clone();
+		let mut expected: HashMap<Uuid, ExpectedChunkRow> =
+			fetch_expected_chunks(&self.db.pool, now)
+				.await?
+				.into_iter()
+				.map(|row| (row.chunk_id, row))
+				.collect();
+		let mut report = IndexVerifyReport {
+			schema: ELF_INDEX_VERIFY_SCHEMA_V1.to_string(),
+			collection: collection.clone(),
+			expected_count: expected.len() as u64,
+			scanned_count: 0,
+			counts: IndexVerifyCounts::default(),
+			versions: Vec::new(),
+			findings: Vec::new(),
+			findings_truncated: false,
+			repair: None,
+		};
+		let mut versions: BTreeMap<String, IndexVerifyVersion> = BTreeMap::new();
+		let mut findings = Vec::new();
+		let mut offset: Option<PointId> = None;
+
+		for row in expected.values() {
+			version_entry(&mut versions, &row.embedding_version).expected_count += 1;
+		}
+
+		loop {
+			let mut scroll = ScrollPointsBuilder::new(collection.clone())
+				.limit(SCROLL_PAGE_SIZE)
+				.with_payload(true)
+				.with_vectors(false);
+
+			if let Some(offset) = offset.take() {
+				scroll = scroll.offset(offset);
+			}
+
+			let page = self
+				.qdrant
+				.client
+				.scroll(scroll)
+				.await
+				.map_err(|err| Error::Qdrant { message: err.to_string() })?;
+
+			for point in page.result.iter().map(read_index_point) {
+				report.scanned_count += 1;
+
+				if let Some(version) = point.embedding_version.as_deref() {
+					version_entry(&mut versions, version).point_count += 1;
+				}
+
+				let chunk = point.chunk_id.and_then(|chunk_id| expected.remove(&chunk_id));
+
+				if let Some(finding) = classify_point(&point, chunk.as_ref()) {
+					findings.push(finding);
+				}
+			}
+
+			match page.next_page_offset {
+				Some(next) => offset = Some(next),
+				None => break,
+			}
+		}
+
+		let mut missing: Vec<ExpectedChunkRow> = expected.into_values().collect();
+
+		missing.sort_by_key(|row| (row.note_id, row.chunk_id));
+		findings.extend(missing.iter().map(missing_finding));
+
+		for finding in &findings {
+			report.counts.record(finding.kind.as_str());
+
+			let version = match finding.kind.as_str() {
+				"orphaned" => finding.point_embedding_version.as_deref(),
+				_ => finding.expected_embedding_version.as_deref(),
+			};
+
+			if let Some(version) = version {
+				version_entry(&mut versions, version).record(finding.kind.as_str());
+			}
+		}
+
+		if req.repair {
+			let active_notes = fetch_active_note_ids(&self.db.pool, &findings, now).await?;
+			let plan = plan_repairs(&findings, &active_notes);
+
+			report.repair = Some(self.enqueue_repairs(plan, now).await?);
+		}
+
+		report.versions = versions.into_values().collect();
+		report.findings_truncated = findings.len() > max_findings;
+
+		findings.truncate(max_findings);
+
+		report.findings = findings;
+
+		let drift = report.counts.missing + report.counts.orphaned + report.counts.stale;
+
+		if drift > 0 {
+			tracing::warn!(
+				collection = %report.collection,
+				missing = report.counts.missing,
+				orphaned = report.counts.orphaned,
+				stale = report.counts.stale,
+				"Index verification found Postgres and Qdrant drift."
+			);
+		}
+
+		Ok(report)
+	}
+
+	async fn enqueue_repairs(
+		&self,
+		plan: RepairPlan,
+		now: OffsetDateTime,
+	) -> Result<IndexVerifyRepair> {
+		let note_ids: Vec<Uuid> =
+			plan.upsert.iter().chain(plan.delete.keys()).copied().collect::<Vec<_>>();
+		let open_jobs: HashSet<Uuid> =
+			fetch_notes_with_open_jobs(&self.db.pool, &note_ids).await?.into_iter().collect();
+		let note_versions: HashMap<Uuid, String> = fetch_note_versions(&self.db.pool, &note_ids)
+			.await?
+			.into_iter()
+			.map(|row| (row.note_id, row.embedding_version))
+			.collect();
+		let default_version = crate::provider_embedding_version(
+			&self.cfg.providers.embedding,
+			self.cfg.storage.qdrant.vector_dim,
+		);
+		let mut repair =
+			IndexVerifyRepair { unrepairable: plan.unrepairable, ..IndexVerifyRepair::default() };
+		let mut tx = self.db.pool.begin().await?;
+
+		for note_id in plan.upsert {
+			if open_jobs.contains(&note_id) {
+				repair.skipped_open_jobs += 1;
+
+				continue;
+			}
+
+			let version = note_versions.get(&note_id).unwrap_or(&default_version);
+
+			crate::enqueue_outbox_tx(&mut *tx, note_id, "UPSERT", version, now).await?;
+
+			repair.upsert_enqueued += 1;
+		}
+		for (note_id, point_version) in plan.delete {
+			if open_jobs.contains(&note_id) {
+				repair.skipped_open_jobs += 1;
+
+				continue;
+			}
+
+			let version =
+				note_versions.get(&note_id).or(point_version.as_ref()).unwrap_or(&default_version);
+
+			crate::enqueue_outbox_tx(&mut *tx, note_id, "DELETE", version, now).await?;
+
+			repair.delete_enqueued += 1;
+		}
+
+		tx.commit().await?;
+
+		Ok(repair)
+	}
+}
+
+impl IndexVerifyCounts {
+	fn record(&mut self, kind: &str) {
+		match kind {
+			"missing" => self.missing += 1,
+			"orphaned" => self.orphaned += 1,
+			"stale" => self.stale += 1,
+			_ => {},
+		}
+	}
+}
+
+impl IndexVerifyVersion {
+	fn record(&mut self, kind: &str) {
+		match kind {
+			"missing" => self.missing += 1,
+			"orphaned" => self.orphaned += 1,
+			"stale" => self.stale += 1,
+			_ => {},
+		}
+	}
+}
+
+/// Classifies one scanned point against the active-note chunk with the same id, if any.
+fn classify_point(
+	point: &IndexPoint,
+	chunk: Option<&ExpectedChunkRow>,
+) -> Option<IndexVerifyFinding> {
+	let Some(chunk) = chunk else {
+		return Some(IndexVerifyFinding {
+			kind: "orphaned".to_string(),
+			point_id: point.point_id.clone(),
+			note_id: point.note_id,
+			expected_embedding_version: None,
+			point_embedding_version: point.embedding_version.clone(),
+		});
+	};
+
+	if point.embedding_version.as_deref() == Some(chunk.embedding_version.as_str()) {
+		return None;
+	}
+
+	Some(IndexVerifyFinding {
+		kind: "stale".to_string(),
+		point_id: point.point_id.clone(),
+		note_id: Some(chunk.note_id),
+		expected_embedding_version: Some(chunk.embedding_version.clone()),
+		point_embedding_version: point.embedding_version.clone(),
+	})
+}
+
+fn missing_finding(chunk: &ExpectedChunkRow) -> IndexVerifyFinding {
+	IndexVerifyFinding {
+		kind: "missing".to_string(),
+		point_id: chunk.chunk_id.to_string(),
+		note_id: Some(chunk.note_id),
+		expected_embedding_version: Some(chunk.embedding_version.clone()),
+		point_embedding_version: None,
+	}
+}
+
+/// Picks the minimal outbox rows that repair every finding.
+///
+/// An UPSERT replaces all of a note's points, so one per active note covers its missing, stale,
+/// and leftover points. Points of notes that are gone or inactive need a DELETE instead.
+fn plan_repairs(findings: &[IndexVerifyFinding], active_notes: &HashSet<Uuid>) -> RepairPlan {
+	let mut plan = RepairPlan::default();
+
+	for finding in findings {
+		let Some(note_id) = finding.note_id else {
+			plan.unrepairable += 1;
+
+			continue;
+		};
+
+		if active_notes.contains(&note_id) {
+			plan.upsert.insert(note_id);
+		} else {
+			plan.delete.entry(note_id).or_insert_with(|| finding.point_embedding_version.clone());
+		}
+	}
+
+	plan
+}
+
+fn version_entry<'a>(
+	versions: &'a mut BTreeMap<String, IndexVerifyVersion>,
+	version: &str,
+) -> &'a mut IndexVerifyVersion {
+	versions.entry(version.to_string()).or_insert_with(|| IndexVerifyVersion {
+		embedding_version: version.to_string(),
+		..IndexVerifyVersion::default()
+	})
+}
+
+fn read_index_point(point: &RetrievedPoint) -> IndexPoint {
+	let point_id = point.id.as_ref().map(format_point_id).unwrap_or_default();
+
+	IndexPoint {
+		chunk_id: Uuid::parse_str(point_id.as_str()).ok(),
+		point_id,
+		note_id: payload_string(&point.payload, "note_id")
+			.and_then(|value| Uuid::parse_str(value.as_str()).ok()),
+		embedding_version: payload_string(&point.payload, "embedding_version"),
+	}
+}
+
+fn format_point_id(point_id: &PointId) -> String {
+	match &point_id.point_id_options {
+		Some(PointIdOptions::Uuid(id)) => id.clone(),
+		Some(PointIdOptions::Num(id)) => id.to_string(),
+		None => String::new(),
+	}
+}
+
+fn payload_string(payload: &HashMap<String, Value>, key: &str) -> Option<String> {
+	match &payload.get(key)?.kind {
+		Some(Kind::StringValue(text)) if !text.trim().is_empty() => Some(text.to_string()),
+		_ => None,
+	}
+}
+
+async fn fetch_expected_chunks<'e, E>(
+	executor: E,
+	now: OffsetDateTime,
+) -> Result<Vec<ExpectedChunkRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, ExpectedChunkRow>(
+		"\
+SELECT c.chunk_id, c.note_id, c.embedding_version
+FROM memory_note_chunks c
+JOIN memory_notes n ON n.note_id = c.note_id
+WHERE n.status = 'active' AND (n.expires_at IS NULL OR n.expires_at > $1)",
+	)
+	.bind(now)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_active_note_ids<'e, E>(
+	executor: E,
+	findings: &[IndexVerifyFinding],
+	now: OffsetDateTime,
+) -> Result<HashSet<Uuid>>
+where
+	E: PgExecutor<'e>,
+{
+	let note_ids: Vec<Uuid> = findings
+		.iter()
+		.filter_map(|finding| finding.note_id)
+		.collect::<BTreeSet<_>>()
+		.into_iter()
+		.collect();
+
+	if note_ids.is_empty() {
+		return Ok(HashSet::new());
+	}
+
+	let rows = sqlx::query_scalar::<_, Uuid>(
+		"\
+SELECT note_id
+FROM memory_notes
+WHERE note_id = ANY($1) AND status = 'active' AND (expires_at IS NULL OR expires_at > $2)",
+	)
+	.bind(note_ids.as_slice())
+	.bind(now)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows.into_iter().collect())
+}
+
+async fn fetch_note_versions<'e, E>(executor: E, note_ids: &[Uuid]) -> Result<Vec<NoteVersionRow>>
+where
+	E: PgExecutor<'e>,
+{
+	if note_ids.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	sqlx::query_as::<_, NoteVersionRow>(
+		"SELECT note_id, embedding_version FROM memory_notes WHERE note_id = ANY($1)",
+	)
+	.bind(note_ids)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_notes_with_open_jobs<'e, E>(executor: E, note_ids: &[Uuid]) -> Result<Vec<Uuid>>
+where
+	E: PgExecutor<'e>,
+{
+	if note_ids.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	sqlx::query_scalar::<_, Uuid>(
+		"\
+SELECT DISTINCT note_id
+FROM indexing_outbox
+WHERE note_id = ANY($1) AND status IN ('PENDING','FAILED')",
+	)
+	.bind(note_ids)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::admin_index_verify::{self, ExpectedChunkRow, IndexPoint, IndexVerifyFinding};
+
+fn chunk(note_id: Uuid, version: &str) -> ExpectedChunkRow {
+	ExpectedChunkRow { chunk_id: Uuid::new_v4(), note_id, embedding_version: version.to_string() }
+}
+
+fn point(chunk: &ExpectedChunkRow, version: &str) -> IndexPoint {
+	IndexPoint {
+		point_id: chunk.chunk_id.to_string(),
+		chunk_id: Some(chunk.chunk_id),
+		note_id: Some(chunk.note_id),
+		embedding_version: Some(version.to_string()),
+	}
+}
+
+fn finding(kind: &str, note_id: Option<Uuid>) -> IndexVerifyFinding {
+	IndexVerifyFinding {
+		kind: kind.to_string(),
+		point_id: Uuid::new_v4().to_string(),
+		note_id,
+		expected_embedding_version: None,
+		point_embedding_version: Some("v1".to_string()),
+	}
+}
+
+#[test]
+fn classify_point_flags_orphaned_and_stale_points() {
+	let chunk = chunk(Uuid::new_v4(), "v2");
+
+	assert_eq!(admin_index_verify::classify_point(&point(&chunk, "v2"), Some(&chunk)), None);
+
+	let stale = admin_index_verify::classify_point(&point(&chunk, "v1"), Some(&chunk))
+		.expect("Expected a stale finding.");
+
+	assert_eq!(stale.kind, "stale");
+	assert_eq!(stale.expected_embedding_version.as_deref(), Some("v2"));
+	assert_eq!(stale.point_embedding_version.as_deref(), Some("v1"));
+
+	let orphaned = admin_index_verify::classify_point(&point(&chunk, "v2"), None)
+		.expect("Expected an orphaned finding.");
+
+	assert_eq!(orphaned.kind, "orphaned");
+	assert_eq!(orphaned.note_id, Some(chunk.note_id));
+}
+
+#[test]
+fn plan_repairs_enqueues_one_row_per_note() {
+	let active = Uuid::new_v4();
+	let deleted = Uuid::new_v4();
+	let findings = vec![
+		finding("missing", Some(active)),
+		finding("stale", Some(active)),
+		finding("orphaned", Some(active)),
+		finding("orphaned", Some(deleted)),
+		finding("orphaned", Some(deleted)),
+		finding("orphaned", None),
+	];
+	let plan = admin_index_verify::plan_repairs(&findings, &HashSet::from([active]));
+
+	assert_eq!(plan.upsert.into_iter().collect::<Vec<_>>(), vec![active]);
+	assert_eq!(
+		plan.delete.into_iter().collect::<Vec<_>>(),
+		vec![(deleted, Some("v1".to_string()))]
+	);
+	assert_eq!(plan.unrepairable, 1);
+}
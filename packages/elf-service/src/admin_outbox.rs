@@ -0,0 +1,162 @@
+//! Admin readback and replay of dead-lettered note-indexing outbox jobs.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	audit::{AuditScope, AuditTargets},
+};
+use elf_storage::{models::IndexingOutboxEntry, outbox};
+
+const DEFAULT_DEAD_LETTERS_LIMIT: u32 = 50;
+const MAX_DEAD_LETTERS_LIMIT: u32 = 500;
+const MAX_REPLAY_IDS: usize = 500;
+
+/// Request payload for listing dead-lettered outbox jobs.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AdminOutboxDeadLetterListRequest {
+	/// Tenant whose jobs are listed.
+	pub tenant_id: String,
+	/// Maximum jobs to return.
+	pub limit: Option<u32>,
+}
+
+/// One note-indexing outbox job that exhausted its retries.
+#[derive(Clone, Debug, Serialize)]
+pub struct OutboxDeadLetter {
+	/// Outbox identifier.
+	pub outbox_id: Uuid,
+	/// Note the job indexes.
+	pub note_id: Uuid,
+	/// Indexing operation: `UPSERT` or `DELETE`.
+	pub op: String,
+	/// Embedding version the job targets.
+	pub embedding_version: String,
+	/// Attempts made before the job was dead-lettered.
+	pub attempts: i32,
+	/// Error text of the final attempt.
+	pub last_error: Option<String>,
+	#[serde(with = "crate::time_serde")]
+	/// When the job was enqueued.
+	pub created_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// When the job was dead-lettered.
+	pub dead_lettered_at: OffsetDateTime,
+}
+
+/// Response payload for listing dead-lettered outbox jobs.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminOutboxDeadLetterListResponse {
+	/// Dead-lettered jobs, most recently dead-lettered first.
+	pub dead_letters: Vec<OutboxDeadLetter>,
+}
+
+/// Request payload for requeueing dead-lettered outbox jobs.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AdminOutboxReplayRequest {
+	/// Tenant whose jobs may be requeued.
+	pub tenant_id: String,
+	/// Dead-lettered jobs to requeue.
+	pub outbox_ids: Vec<Uuid>,
+}
+
+/// Response payload for requeueing dead-lettered outbox jobs.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminOutboxReplayResponse {
+	/// Jobs requeued with a fresh retry budget.
+	pub replayed: Vec<Uuid>,
+	/// Requested ids that are not dead-lettered jobs of the tenant.
+	pub not_found: Vec<Uuid>,
+}
+
+impl AuditTargets for AdminOutboxReplayResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		self.replayed.clone()
+	}
+}
+
+impl ElfService {
+	/// Lists the tenant's note-indexing outbox jobs that exhausted their retries.
+	pub async fn admin_outbox_dead_letter_list(
+		&self,
+		req: AdminOutboxDeadLetterListRequest,
+	) -> Result<AdminOutboxDeadLetterListResponse> {
+		let limit = req.limit.unwrap_or(DEFAULT_DEAD_LETTERS_LIMIT);
+
+		if limit == 0 || limit > MAX_DEAD_LETTERS_LIMIT {
+			return Err(Error::InvalidRequest {
+				message: format!("limit must be between 1 and {MAX_DEAD_LETTERS_LIMIT}."),
+			});
+		}
+
+		let rows = outbox::list_indexing_outbox_dead_letters(
+			&self.db.pool,
+			req.tenant_id.as_str(),
+			i64::from(limit),
+		)
+		.await?;
+
+		Ok(AdminOutboxDeadLetterListResponse {
+			dead_letters: rows.into_iter().map(dead_letter_from_entry).collect(),
+		})
+	}
+
+	/// Requeues the tenant's dead-lettered note-indexing jobs once their root cause is fixed.
+	pub async fn admin_outbox_replay(
+		&self,
+		req: AdminOutboxReplayRequest,
+	) -> Result<AdminOutboxReplayResponse> {
+		let scope = AuditScope::new("admin_outbox_replay")
+			.caller(&req.tenant_id, "", "")
+			.targets(req.outbox_ids.iter().copied());
+
+		self.audited(scope, self.admin_outbox_replay_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_outbox_replay`] without recording an audit entry.
+	async fn admin_outbox_replay_inner(
+		&self,
+		req: AdminOutboxReplayRequest,
+	) -> Result<AdminOutboxReplayResponse> {
+		let AdminOutboxReplayRequest { tenant_id, mut outbox_ids } = req;
+
+		outbox_ids.sort_unstable();
+		outbox_ids.dedup();
+
+		if outbox_ids.is_empty() || outbox_ids.len() > MAX_REPLAY_IDS {
+			return Err(Error::InvalidRequest {
+				message: format!("outbox_ids must contain between 1 and {MAX_REPLAY_IDS} ids."),
+			});
+		}
+
+		let mut replayed = outbox::replay_indexing_outbox_dead_letters(
+			&self.db.pool,
+			tenant_id.as_str(),
+			&outbox_ids,
+			OffsetDateTime::now_utc(),
+		)
+		.await?;
+
+		replayed.sort_unstable();
+
+		let not_found =
+			outbox_ids.into_iter().filter(|id| replayed.binary_search(id).is_err()).collect();
+
+		Ok(AdminOutboxReplayResponse { replayed, not_found })
+	}
+}
+
+fn dead_letter_from_entry(entry: IndexingOutboxEntry) -> OutboxDeadLetter {
+	OutboxDeadLetter {
+		outbox_id: entry.outbox_id,
+		note_id: entry.note_id,
+		op: entry.op,
+		embedding_version: entry.embedding_version,
+		attempts: entry.attempts,
+		last_error: entry.last_error,
+		created_at: entry.created_at,
+		dead_lettered_at: entry.updated_at,
+	}
+}
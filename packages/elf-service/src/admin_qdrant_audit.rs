@@ -0,0 +1,313 @@
+//! Qdrant payload isolation audit.
+
+use std::collections::HashMap;
+
+use qdrant_client::qdrant::{
+	DeletePointsBuilder, PointId, PointsIdsList, RetrievedPoint, ScrollPointsBuilder, Value,
+	point_id::PointIdOptions, value::Kind,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgExecutor};
+use uuid::Uuid;
+
+use crate::{ElfService, Error, Result};
+
+/// Qdrant audit report schema identifier.
+pub const ELF_QDRANT_AUDIT_SCHEMA_V1: &str = "elf.qdrant_audit/v1";
+
+const SCROLL_PAGE_SIZE: u32 = 256;
+const DEFAULT_MAX_FINDINGS: u32 = 500;
+const MAX_FINDINGS: u32 = 5_000;
+
+/// Request payload for a Qdrant payload isolation audit.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct QdrantAuditRequest {
+	#[serde(default)]
+	/// When true, flagged points are deleted from the note collection.
+	pub quarantine: bool,
+	/// Maximum number of findings returned in the report. Counts always cover every point.
+	pub max_findings: Option<u32>,
+}
+
+/// Result of one Qdrant payload isolation audit.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct QdrantAuditReport {
+	/// Response schema identifier.
+	pub schema: String,
+	/// Audited Qdrant collection.
+	pub collection: String,
+	/// Number of points scanned.
+	pub scanned_count: u64,
+	/// Number of points with at least one finding.
+	pub flagged_count: u64,
+	/// Number of flagged points deleted from the collection.
+	pub quarantined_count: u64,
+	/// Flagged point counts by finding kind.
+	pub counts: QdrantAuditCounts,
+	/// Flagged points, capped at `max_findings`.
+	pub findings: Vec<QdrantAuditFinding>,
+	/// True when more points were flagged than findings returned.
+	pub findings_truncated: bool,
+}
+
+/// Flagged point counts by finding kind.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct QdrantAuditCounts {
+	/// Points missing a parseable note_id, tenant_id, project_id, or scope payload value.
+	pub missing_payload: u64,
+	/// Points whose note_id has no Postgres note.
+	pub orphan_note: u64,
+	/// Points whose tenant_id differs from the note's tenant.
+	pub tenant_mismatch: u64,
+	/// Points whose project_id differs from the note's project.
+	pub project_mismatch: u64,
+	/// Points whose scope differs from the note's scope.
+	pub scope_mismatch: u64,
+}
+
+/// One flagged Qdrant point.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct QdrantAuditFinding {
+	/// Qdrant point identifier.
+	pub point_id: String,
+	/// Finding kind: missing_payload, orphan_note, tenant_mismatch, project_mismatch, or
+	/// scope_mismatch.
+	pub kind: String,
+	/// Note identifier from the payload, when parseable.
+	pub note_id: Option<Uuid>,
+	/// Tenant identifier from the payload.
+	pub payload_tenant_id: Option<String>,
+	/// Project identifier from the payload.
+	pub payload_project_id: Option<String>,
+	/// Scope from the payload.
+	pub payload_scope: Option<String>,
+	/// Tenant identifier stored in Postgres, when the note exists.
+	pub note_tenant_id: Option<String>,
+	/// Project identifier stored in Postgres, when the note exists.
+	pub note_project_id: Option<String>,
+	/// Scope stored in Postgres, when the note exists.
+	pub note_scope: Option<String>,
+}
+
+#[derive(Clone, Debug, FromRow)]
+struct NoteOwnerRow {
+	note_id: Uuid,
+	tenant_id: String,
+	project_id: String,
+	scope: String,
+}
+
+#[derive(Clone, Debug, Default)]
+struct PointPayload {
+	point_id: String,
+	note_id: Option<Uuid>,
+	tenant_id: Option<String>,
+	project_id: Option<String>,
+	scope: Option<String>,
+}
+
+impl ElfService {
+	/// Scans the note collection for points whose tenant, project, or scope payload does not
+	/// match Postgres.
+	///
+	/// Postgres is the source of truth. With `quarantine`, flagged points are deleted; Qdrant is
+	/// derived, so a rebuild restores points for notes that still exist.
+	pub async fn admin_qdrant_audit(&self, req: QdrantAuditRequest) -> Result<QdrantAuditReport> {
+		let max_findings =
+			req.max_findings.unwrap_or(DEFAULT_MAX_FINDINGS).clamp(1, MAX_FINDINGS) as usize;
+		let collection = self.qdrant.collection.clone();
+		let mut report = QdrantAuditReport {
+			schema: ELF_QDRANT_AUDIT_SCHEMA_V1.to_string(),
+			collection: collection.clone(),
+			scanned_count: 0,
+			flagged_count: 0,
+			quarantined_count: 0,
+			counts: QdrantAuditCounts::default(),
+			findings: Vec::new(),
+			findings_truncated: false,
+		};
+		let mut offset: Option<PointId> = None;
+
+		loop {
+			let mut scroll = ScrollPointsBuilder::new(collection.clone())
+				.limit(SCROLL_PAGE_SIZE)
+				.with_payload(true)
+				.with_vectors(false);
+
+			if let Some(offset) = offset.take() {
+				scroll = scroll.offset(offset);
+			}
+
+			let page = self
+				.qdrant
+				.client
+				.scroll(scroll)
+				.await
+				.map_err(|err| Error::Qdrant { message: err.to_string() })?;
+			let points: Vec<PointPayload> = page.result.iter().map(read_point_payload).collect();
+			let note_ids: Vec<Uuid> = points.iter().filter_map(|point| point.note_id).collect();
+			let owners: HashMap<Uuid, NoteOwnerRow> = fetch_note_owners(&self.db.pool, &note_ids)
+				.await?
+				.into_iter()
+				.map(|row| (row.note_id, row))
+				.collect();
+			let mut flagged_ids = Vec::new();
+
+			for (point, retrieved) in points.iter().zip(page.result.iter()) {
+				report.scanned_count += 1;
+
+				let owner = point.note_id.and_then(|note_id| owners.get(&note_id));
+				let Some(kind) = classify_point(point, owner) else { continue };
+
+				report.flagged_count += 1;
+				report.counts.record(kind);
+
+				if let Some(id) = retrieved.id.clone() {
+					flagged_ids.push(id);
+				}
+				if report.findings.len() < max_findings {
+					report.findings.push(build_finding(point, owner, kind));
+				} else {
+					report.findings_truncated = true;
+				}
+			}
+
+			if req.quarantine && !flagged_ids.is_empty() {
+				let quarantined = flagged_ids.len() as u64;
+
+				self.qdrant
+					.client
+					.delete_points(
+						DeletePointsBuilder::new(collection.clone())
+							.points(PointsIdsList { ids: flagged_ids })
+							.wait(true),
+					)
+					.await
+					.map_err(|err| Error::Qdrant { message: err.to_string() })?;
+
+				report.quarantined_count += quarantined;
+			}
+
+			match page.next_page_offset {
+				Some(next) => offset = Some(next),
+				None => break,
+			}
+		}
+
+		if report.flagged_count > 0 {
+			tracing::warn!(
+				collection = %report.collection,
+				flagged_count = report.flagged_count,
+				quarantined_count = report.quarantined_count,
+				"Qdrant audit flagged points with mismatched payloads."
+			);
+		}
+
+		Ok(report)
+	}
+}
+
+impl QdrantAuditCounts {
+	fn record(&mut self, kind: &str) {
+		match kind {
+			"missing_payload" => self.missing_payload += 1,
+			"orphan_note" => self.orphan_note += 1,
+			"tenant_mismatch" => self.tenant_mismatch += 1,
+			"project_mismatch" => self.project_mismatch += 1,
+			"scope_mismatch" => self.scope_mismatch += 1,
+			_ => {},
+		}
+	}
+}
+
+/// Returns the first finding for a point, checking tenant before project and scope so the most
+/// severe isolation failure is reported.
+fn classify_point(point: &PointPayload, owner: Option<&NoteOwnerRow>) -> Option<&'static str> {
+	let (Some(_), Some(tenant_id), Some(project_id), Some(scope)) = (
+		point.note_id,
+		point.tenant_id.as_deref(),
+		point.project_id.as_deref(),
+		point.scope.as_deref(),
+	) else {
+		return Some("missing_payload");
+	};
+	let Some(owner) = owner else { return Some("orphan_note") };
+
+	if owner.tenant_id != tenant_id {
+		return Some("tenant_mismatch");
+	}
+	if owner.project_id != project_id {
+		return Some("project_mismatch");
+	}
+	if owner.scope != scope {
+		return Some("scope_mismatch");
+	}
+
+	None
+}
+
+fn build_finding(
+	point: &PointPayload,
+	owner: Option<&NoteOwnerRow>,
+	kind: &str,
+) -> QdrantAuditFinding {
+	QdrantAuditFinding {
+		point_id: point.point_id.clone(),
+		kind: kind.to_string(),
+		note_id: point.note_id,
+		payload_tenant_id: point.tenant_id.clone(),
+		payload_project_id: point.project_id.clone(),
+		payload_scope: point.scope.clone(),
+		note_tenant_id: owner.map(|owner| owner.tenant_id.clone()),
+		note_project_id: owner.map(|owner| owner.project_id.clone()),
+		note_scope: owner.map(|owner| owner.scope.clone()),
+	}
+}
+
+fn read_point_payload(point: &RetrievedPoint) -> PointPayload {
+	PointPayload {
+		point_id: point.id.as_ref().map(format_point_id).unwrap_or_default(),
+		note_id: payload_string(&point.payload, "note_id")
+			.and_then(|value| Uuid::parse_str(value.as_str()).ok()),
+		tenant_id: payload_string(&point.payload, "tenant_id"),
+		project_id: payload_string(&point.payload, "project_id"),
+		scope: payload_string(&point.payload, "scope"),
+	}
+}
+
+fn format_point_id(point_id: &PointId) -> String {
+	match &point_id.point_id_options {
+		Some(PointIdOptions::Uuid(id)) => id.clone(),
+		Some(PointIdOptions::Num(id)) => id.to_string(),
+		None => String::new(),
+	}
+}
+
+fn payload_string(payload: &HashMap<String, Value>, key: &str) -> Option<String> {
+	match &payload.get(key)?.kind {
+		Some(Kind::StringValue(text)) if !text.trim().is_empty() => Some(text.to_string()),
+		_ => None,
+	}
+}
+
+async fn fetch_note_owners<'e, E>(executor: E, note_ids: &[Uuid]) -> Result<Vec<NoteOwnerRow>>
+where
+	E: PgExecutor<'e>,
+{
+	if note_ids.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	sqlx::query_as::<_, NoteOwnerRow>(
+		"\
+SELECT note_id, tenant_id, project_id, scope
+FROM memory_notes
+WHERE note_id = ANY($1)",
+	)
+	.bind(note_ids)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+#[cfg(test)] mod tests;
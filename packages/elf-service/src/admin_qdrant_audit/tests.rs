@@ -0,0 +1,81 @@
+use uuid::Uuid;
+
+use crate::admin_qdrant_audit::{self, NoteOwnerRow, PointPayload, QdrantAuditCounts};
+
+fn owner(note_id: Uuid) -> NoteOwnerRow {
+	NoteOwnerRow {
+		note_id,
+		tenant_id: "tenant-a".to_string(),
+		project_id: "project-a".to_string(),
+		scope: "project_shared".to_string(),
+	}
+}
+
+fn point(note_id: Uuid) -> PointPayload {
+	PointPayload {
+		point_id: Uuid::new_v4().to_string(),
+		note_id: Some(note_id),
+		tenant_id: Some("tenant-a".to_string()),
+		project_id: Some("project-a".to_string()),
+		scope: Some("project_shared".to_string()),
+	}
+}
+
+#[test]
+fn matching_points_are_not_flagged() {
+	let note_id = Uuid::new_v4();
+
+	assert_eq!(admin_qdrant_audit::classify_point(&point(note_id), Some(&owner(note_id))), None);
+}
+
+#[test]
+fn classify_point_reports_missing_and_orphaned_points() {
+	let note_id = Uuid::new_v4();
+	let missing_scope = PointPayload { scope: None, ..point(note_id) };
+
+	assert_eq!(
+		admin_qdrant_audit::classify_point(&missing_scope, Some(&owner(note_id))),
+		Some("missing_payload")
+	);
+	assert_eq!(admin_qdrant_audit::classify_point(&point(note_id), None), Some("orphan_note"));
+}
+
+#[test]
+fn tenant_mismatch_takes_precedence_over_other_mismatches() {
+	let note_id = Uuid::new_v4();
+	let leaked = PointPayload {
+		tenant_id: Some("tenant-b".to_string()),
+		scope: Some("agent_private".to_string()),
+		..point(note_id)
+	};
+	let moved = PointPayload { scope: Some("org_shared".to_string()), ..point(note_id) };
+
+	assert_eq!(
+		admin_qdrant_audit::classify_point(&leaked, Some(&owner(note_id))),
+		Some("tenant_mismatch")
+	);
+	assert_eq!(
+		admin_qdrant_audit::classify_point(&moved, Some(&owner(note_id))),
+		Some("scope_mismatch")
+	);
+}
+
+#[test]
+fn counts_record_each_kind() {
+	let mut counts = QdrantAuditCounts::default();
+
+	for kind in ["tenant_mismatch", "tenant_mismatch", "orphan_note", "project_mismatch"] {
+		counts.record(kind);
+	}
+
+	assert_eq!(
+		counts,
+		QdrantAuditCounts {
+			missing_payload: 0,
+			orphan_note: 1,
+			tenant_mismatch: 2,
+			project_mismatch: 1,
+			scope_mismatch: 0,
+		}
+	);
+}
@@ -0,0 +1,542 @@
+//! Admin re-embed runs that migrate notes to the `[reembed]` embedding provider.
+//!
+//! A run re-embeds active notes in batches into parallel Postgres rows keyed by the target
+//! embedding version and into the `reembed.collection` Qdrant collection, leaving the active
+//! version untouched. Activation then flips every re-embedded chunk and note to the target
+//! version in one transaction.
+
+use std::collections::HashMap;
+
+use qdrant_client::{
+	Payload,
+	qdrant::{
+		Condition, DeletePointsBuilder, Document, Filter, PointStruct, UpsertPointsBuilder, Vector,
+	},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	audit::{AuditScope, AuditTargets},
+};
+use elf_config::Reembed;
+use elf_storage::{
+	models::{EmbeddingReembedRun, MemoryNote, MemoryNoteChunk},
+	qdrant::{BM25_MODEL, BM25_VECTOR_NAME, DENSE_VECTOR_NAME, QdrantStore},
+	queries,
+	reembed::{self, ReembedField},
+};
+
+const DEFAULT_RUNS_LIMIT: u32 = 50;
+const MAX_RUNS_LIMIT: u32 = 500;
+
+/// Request payload for starting or resuming a re-embed run.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AdminReembedRunRequest {
+	/// Maximum batches to process in this call; unset processes every pending note.
+	pub max_batches: Option<u32>,
+}
+
+/// Request payload for listing re-embed runs.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AdminReembedRunsListRequest {
+	/// Maximum runs to return.
+	pub limit: Option<u32>,
+}
+
+/// Request payload for activating a completed re-embed run.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AdminReembedActivateRequest {
+	/// Run to activate.
+	pub run_id: Uuid,
+}
+
+/// One re-embed run.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminReembedRunItem {
+	/// Run identifier.
+	pub run_id: Uuid,
+	/// Embedding version the run writes.
+	pub target_version: String,
+	/// Qdrant collection that receives the re-embedded points.
+	pub target_collection: String,
+	/// Vector dimension of the target embedding version.
+	pub target_dim: i32,
+	/// Run status: `running`, `completed`, or `activated`.
+	pub status: String,
+	/// Notes re-embedded so far.
+	pub notes_processed: i64,
+	/// Chunk vectors written so far.
+	pub chunks_embedded: i64,
+	/// Structured field vectors written so far.
+	pub fields_embedded: i64,
+	/// Failure message of the most recent failed batch.
+	pub last_error: Option<String>,
+	#[serde(with = "crate::time_serde")]
+	/// Run start time.
+	pub started_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// Time of the last recorded progress.
+	pub updated_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde::option")]
+	/// Time the run found no more notes to re-embed.
+	pub completed_at: Option<OffsetDateTime>,
+	#[serde(with = "crate::time_serde::option")]
+	/// Time the target version became the active version.
+	pub activated_at: Option<OffsetDateTime>,
+}
+
+impl AuditTargets for AdminReembedRunItem {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.run_id]
+	}
+}
+impl From<EmbeddingReembedRun> for AdminReembedRunItem {
+	fn from(run: EmbeddingReembedRun) -> Self {
+		Self {
+			run_id: run.run_id,
+			target_version: run.target_version,
+			target_collection: run.target_collection,
+			target_dim: run.target_dim,
+			status: run.status,
+			notes_processed: run.notes_processed,
+			chunks_embedded: run.chunks_embedded,
+			fields_embedded: run.fields_embedded,
+			last_error: run.last_error,
+			started_at: run.started_at,
+			updated_at: run.updated_at,
+			completed_at: run.completed_at,
+			activated_at: run.activated_at,
+		}
+	}
+}
+
+/// Response payload listing re-embed runs.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminReembedRunsResponse {
+	/// Runs, newest first.
+	pub runs: Vec<AdminReembedRunItem>,
+}
+
+/// Response payload for an activation.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminReembedActivateResponse {
+	/// Activated run.
+	pub run: AdminReembedRunItem,
+	/// Chunks moved to the target embedding version.
+	pub chunks_activated: u64,
+	/// Notes moved to the target embedding version.
+	pub notes_activated: u64,
+}
+
+impl AuditTargets for AdminReembedActivateResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.run.run_id]
+	}
+}
+
+struct BatchCounts {
+	cursor: Uuid,
+	notes: i64,
+	chunks: i64,
+	fields: i64,
+}
+
+impl ElfService {
+	/// Re-embeds pending active notes into the `[reembed]` target version.
+	///
+	/// Resumes the unfinished run toward the target version when one exists. Each batch is
+	/// checkpointed after its Postgres rows and Qdrant points are written, so a failed call can be
+	/// repeated without redoing finished batches.
+	pub async fn admin_reembed_run(
+		&self,
+		req: AdminReembedRunRequest,
+	) -> Result<AdminReembedRunItem> {
+		let scope = AuditScope::new("admin_reembed_run");
+
+		self.audited(scope, self.admin_reembed_run_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_reembed_run`] without recording an audit entry.
+	async fn admin_reembed_run_inner(
+		&self,
+		req: AdminReembedRunRequest,
+	) -> Result<AdminReembedRunItem> {
+		let Some(cfg) = self.cfg.reembed.as_ref() else {
+			return Err(Error::InvalidRequest {
+				message: "reembed is not configured.".to_string(),
+			});
+		};
+
+		if req.max_batches == Some(0) {
+			return Err(Error::InvalidRequest {
+				message: "max_batches must be greater than zero.".to_string(),
+			});
+		}
+
+		let target_version =
+			crate::provider_embedding_version(&cfg.provider, cfg.provider.dimensions);
+		let target_dim =
+			i32::try_from(cfg.provider.dimensions).map_err(|_| Error::InvalidRequest {
+				message: "reembed.provider.dimensions is too large.".to_string(),
+			})?;
+		let mut run =
+			match reembed::find_running_reembed_run(&self.db.pool, &target_version).await? {
+				Some(run) => run,
+				None =>
+					reembed::insert_reembed_run(
+						&self.db.pool,
+						&target_version,
+						cfg.collection.trim(),
+						target_dim,
+						OffsetDateTime::now_utc(),
+					)
+					.await?,
+			};
+		let mut store =
+			QdrantStore::new_with_collection(&self.cfg.storage.qdrant, &cfg.collection)?;
+
+		store.vector_dim = cfg.provider.dimensions;
+		store.ensure_collection().await?;
+
+		let mut batches = 0_u32;
+
+		while req.max_batches.is_none_or(|max| batches < max) {
+			let now = OffsetDateTime::now_utc();
+			let notes = reembed::fetch_pending_reembed_notes(
+				&self.db.pool,
+				&target_version,
+				run.cursor_note_id,
+				i64::from(cfg.batch_size),
+				now,
+			)
+			.await?;
+
+			if notes.is_empty() {
+				run = reembed::complete_reembed_run(&self.db.pool, run.run_id, now).await?;
+
+				break;
+			}
+
+			match self.reembed_batch(cfg, &store, &run, &notes).await {
+				Ok(counts) => {
+					run = reembed::record_reembed_batch(
+						&self.db.pool,
+						run.run_id,
+						counts.cursor,
+						counts.notes,
+						counts.chunks,
+						counts.fields,
+						OffsetDateTime::now_utc(),
+					)
+					.await?;
+				},
+				Err(err) => {
+					reembed::record_reembed_error(
+						&self.db.pool,
+						run.run_id,
+						&err.to_string(),
+						OffsetDateTime::now_utc(),
+					)
+					.await?;
+
+					return Err(err);
+				},
+			}
+
+			batches += 1;
+		}
+
+		Ok(run.into())
+	}
+
+	/// Lists re-embed runs, newest first.
+	pub async fn admin_reembed_runs_list(
+		&self,
+		req: AdminReembedRunsListRequest,
+	) -> Result<AdminReembedRunsResponse> {
+		let limit = req.limit.unwrap_or(DEFAULT_RUNS_LIMIT);
+
+		if limit == 0 || limit > MAX_RUNS_LIMIT {
+			return Err(Error::InvalidRequest {
+				message: format!("limit must be between 1 and {MAX_RUNS_LIMIT}."),
+			});
+		}
+
+		let runs = reembed::list_reembed_runs(&self.db.pool, i64::from(limit)).await?;
+
+		Ok(AdminReembedRunsResponse { runs: runs.into_iter().map(Into::into).collect() })
+	}
+
+	/// Makes the target version of a completed run the active embedding version.
+	///
+	/// Fails while any active note still lacks target vectors, such as notes written after the
+	/// run completed; another run call catches those up. Processes keep embedding queries and
+	/// writes with their configured provider until they restart with the target provider,
+	/// dimension, and collection.
+	pub async fn admin_reembed_activate(
+		&self,
+		req: AdminReembedActivateRequest,
+	) -> Result<AdminReembedActivateResponse> {
+		let scope = AuditScope::new("admin_reembed_activate").targets([req.run_id]);
+
+		self.audited(scope, self.admin_reembed_activate_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_reembed_activate`] without recording an audit entry.
+	async fn admin_reembed_activate_inner(
+		&self,
+		req: AdminReembedActivateRequest,
+	) -> Result<AdminReembedActivateResponse> {
+		let now = OffsetDateTime::now_utc();
+		let mut tx = self.db.pool.begin().await?;
+		let Some(run) = reembed::lock_reembed_run(&mut tx, req.run_id).await? else {
+			return Err(Error::NotFound { message: "Re-embed run not found.".to_string() });
+		};
+
+		match run.status.as_str() {
+			"completed" => {},
+			"activated" =>
+				return Err(Error::Conflict {
+					message: "Re-embed run is already activated.".to_string(),
+				}),
+			_ =>
+				return Err(Error::Conflict {
+					message: "Re-embed run has not completed.".to_string(),
+				}),
+		}
+
+		let pending =
+			reembed::count_pending_reembed_notes(&mut *tx, &run.target_version, now).await?;
+
+		if pending > 0 {
+			return Err(Error::Conflict {
+				message: format!(
+					"{pending} active notes lack {} vectors. Run the re-embed again before activating.",
+					run.target_version
+				),
+			});
+		}
+
+		let activation =
+			reembed::activate_embedding_version(&mut tx, &run.target_version, now).await?;
+		let run = reembed::mark_reembed_run_activated(&mut tx, run.run_id, now).await?;
+
+		tx.commit().await?;
+
+		tracing::info!(
+			run_id = %run.run_id,
+			target_version = %run.target_version,
+			chunks = activation.chunks,
+			notes = activation.notes,
+			"Re-embed target version activated."
+		);
+
+		Ok(AdminReembedActivateResponse {
+			run: run.into(),
+			chunks_activated: activation.chunks,
+			notes_activated: activation.notes,
+		})
+	}
+
+	async fn reembed_batch(
+		&self,
+		cfg: &Reembed,
+		store: &QdrantStore,
+		run: &EmbeddingReembedRun,
+		notes: &[MemoryNote],
+	) -> Result<BatchCounts> {
+		let note_ids = notes.iter().map(|note| note.note_id).collect::<Vec<_>>();
+		let chunks = reembed::fetch_reembed_chunks(&self.db.pool, &note_ids).await?;
+		let fields = reembed::fetch_reembed_fields(&self.db.pool, &note_ids).await?;
+		let texts = chunks
+			.iter()
+			.map(|chunk| chunk.text.clone())
+			.chain(fields.iter().map(|field| field.text.clone()))
+			.collect::<Vec<_>>();
+		let vectors = self.providers.embedding.embed(&cfg.provider, &texts).await?;
+
+		if vectors.len() != texts.len() {
+			return Err(Error::Provider {
+				message: format!(
+					"Embedding provider returned {} vectors for {} inputs.",
+					vectors.len(),
+					texts.len()
+				),
+			});
+		}
+		if vectors.iter().any(|vec| vec.len() != cfg.provider.dimensions as usize) {
+			return Err(Error::Provider {
+				message: format!(
+					"Embedding provider returned vectors that are not {} dimensions.",
+					cfg.provider.dimensions
+				),
+			});
+		}
+
+		let (chunk_vectors, field_vectors) = vectors.split_at(chunks.len());
+
+		self.upsert_reembed_points(store, &run.target_version, notes, &chunks, chunk_vectors)
+			.await?;
+		self.write_reembed_rows(
+			&run.target_version,
+			&chunks,
+			chunk_vectors,
+			&fields,
+			field_vectors,
+		)
+		.await?;
+
+		Ok(BatchCounts {
+			cursor: note_ids.last().copied().unwrap_or_default(),
+			notes: notes.len() as i64,
+			chunks: chunks.len() as i64,
+			fields: fields.len() as i64,
+		})
+	}
+
+	async fn upsert_reembed_points(
+		&self,
+		store: &QdrantStore,
+		target_version: &str,
+		notes: &[MemoryNote],
+		chunks: &[MemoryNoteChunk],
+		vectors: &[Vec<f32>],
+	) -> Result<()> {
+		let by_id = notes.iter().map(|note| (note.note_id, note)).collect::<HashMap<_, _>>();
+		let mut points = Vec::with_capacity(chunks.len());
+
+		for (chunk, vec) in chunks.iter().zip(vectors) {
+			let Some(note) = by_id.get(&chunk.note_id) else { continue };
+
+			points.push(chunk_point(note, chunk, target_version, vec)?);
+		}
+
+		// Clear points of earlier chunkings so re-chunked notes leave no stale points behind.
+		let note_ids = notes.iter().map(|note| note.note_id.to_string()).collect::<Vec<_>>();
+
+		store
+			.client
+			.delete_points(
+				DeletePointsBuilder::new(store.collection.clone())
+					.points(Filter::must([Condition::matches("note_id", note_ids)]))
+					.wait(true),
+			)
+			.await
+			.map_err(|err| Error::Qdrant { message: err.to_string() })?;
+		store
+			.client
+			.upsert_points(UpsertPointsBuilder::new(store.collection.clone(), points).wait(true))
+			.await
+			.map_err(|err| Error::Qdrant { message: err.to_string() })?;
+
+		Ok(())
+	}
+
+	async fn write_reembed_rows(
+		&self,
+		target_version: &str,
+		chunks: &[MemoryNoteChunk],
+		chunk_vectors: &[Vec<f32>],
+		fields: &[ReembedField],
+		field_vectors: &[Vec<f32>],
+	) -> Result<()> {
+		let mut pooled: HashMap<Uuid, (Vec<f32>, usize)> = HashMap::new();
+		let mut tx = self.db.pool.begin().await?;
+
+		for (chunk, vec) in chunks.iter().zip(chunk_vectors) {
+			queries::insert_note_chunk_embedding(
+				&mut *tx,
+				chunk.chunk_id,
+				target_version,
+				vec.len() as i32,
+				&crate::vector_to_pg(vec),
+			)
+			.await?;
+
+			let (sum, count) =
+				pooled.entry(chunk.note_id).or_insert_with(|| (vec![0.0; vec.len()], 0));
+
+			sum.iter_mut().zip(vec).for_each(|(total, value)| *total += value);
+
+			*count += 1;
+		}
+		for (note_id, (sum, count)) in pooled {
+			let mean = sum.into_iter().map(|total| total / count as f32).collect::<Vec<_>>();
+
+			queries::insert_note_embedding(
+				&mut *tx,
+				note_id,
+				target_version,
+				mean.len() as i32,
+				&crate::vector_to_pg(&mean),
+			)
+			.await?;
+		}
+		for (field, vec) in fields.iter().zip(field_vectors) {
+			queries::insert_note_field_embedding(
+				&mut *tx,
+				field.field_id,
+				target_version,
+				vec.len() as i32,
+				&crate::vector_to_pg(vec),
+			)
+			.await?;
+		}
+
+		tx.commit().await?;
+
+		Ok(())
+	}
+}
+
+fn chunk_point(
+	note: &MemoryNote,
+	chunk: &MemoryNoteChunk,
+	target_version: &str,
+	vec: &[f32],
+) -> Result<PointStruct> {
+	let mut payload = Payload::new();
+
+	payload.insert("note_id", note.note_id.to_string());
+	payload.insert("chunk_id", chunk.chunk_id.to_string());
+	payload.insert("chunk_index", Value::from(chunk.chunk_index));
+	payload.insert("start_offset", Value::from(chunk.start_offset));
+	payload.insert("end_offset", Value::from(chunk.end_offset));
+	payload.insert("tenant_id", note.tenant_id.clone());
+	payload.insert("project_id", note.project_id.clone());
+	payload.insert("agent_id", note.agent_id.clone());
+	payload.insert("scope", note.scope.clone());
+	payload.insert("type", note.r#type.clone());
+	payload.insert("key", note.key.clone().map(Value::String).unwrap_or(Value::Null));
+	payload.insert("status", note.status.clone());
+	payload.insert("updated_at", Value::String(format_timestamp(note.updated_at)?));
+	payload.insert(
+		"expires_at",
+		match note.expires_at {
+			Some(ts) => Value::String(format_timestamp(ts)?),
+			None => Value::Null,
+		},
+	);
+	payload.insert("importance", Value::from(note.importance as f64));
+	payload.insert("confidence", Value::from(note.confidence as f64));
+	payload.insert("embedding_version", target_version.to_string());
+
+	let mut vectors = HashMap::new();
+
+	vectors.insert(DENSE_VECTOR_NAME.to_string(), Vector::from(vec.to_vec()));
+	vectors.insert(
+		BM25_VECTOR_NAME.to_string(),
+		Vector::from(Document::new(chunk.text.clone(), BM25_MODEL)),
+	);
+
+	Ok(PointStruct::new(chunk.chunk_id.to_string(), vectors, payload))
+}
+
+fn format_timestamp(ts: OffsetDateTime) -> Result<String> {
+	ts.format(&Rfc3339)
+		.map_err(|_| Error::InvalidRequest { message: "Failed to format timestamp.".to_string() })
+}
@@ -0,0 +1,701 @@
+//! Append-only audit log of mutating operations and the sinks that receive audit entries.
+//!
+//! Every mutating service method wraps its work in [`ElfService::audited`], so the entry's
+//! operation, targets, and outcome come from the method's own request and result no matter which
+//! transport called it.
+
+use std::{future::Future, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgExecutor, PgPool};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{BoxFuture, ElfService, Error, Result};
+
+tokio::task_local! {
+	static AUDIT_TRANSPORT: AuditTransport;
+	static AUDIT_IN_PROGRESS: ();
+}
+
+/// `prev_hash` of the first entry in a tenant's chain.
+pub const AUDIT_CHAIN_GENESIS: &str =
+	"0000000000000000000000000000000000000000000000000000000000000000";
+
+const DEFAULT_LIST_LIMIT: u32 = 100;
+const MAX_LIST_LIMIT: u32 = 500;
+
+/// Result of an audited operation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+	/// The operation completed and nothing in it was rejected.
+	Succeeded,
+	/// The operation completed, but at least one item was rejected.
+	Rejected,
+	/// The operation returned an error.
+	Failed,
+}
+impl AuditOutcome {
+	/// Returns the stored outcome name.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Succeeded => "succeeded",
+			Self::Rejected => "rejected",
+			Self::Failed => "failed",
+		}
+	}
+
+	fn parse(raw: &str) -> Result<Self> {
+		match raw {
+			"succeeded" => Ok(Self::Succeeded),
+			"rejected" => Ok(Self::Rejected),
+			"failed" => Ok(Self::Failed),
+			other => Err(Error::Storage { message: format!("Unknown audit outcome: {other}.") }),
+		}
+	}
+}
+
+/// One mutating operation handed to every registered [`AuditSink`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditEntry {
+	/// Entry identifier.
+	pub audit_id: Uuid,
+	/// Tenant the operation ran in.
+	pub tenant_id: String,
+	/// Project the operation ran in.
+	pub project_id: String,
+	/// Authenticated token identifier, when auth is enabled.
+	pub token_id: Option<String>,
+	/// Agent that issued the operation.
+	pub actor: String,
+	/// Stable operation name, such as `add_note` or `admin_grants_put`.
+	pub operation: String,
+	/// Request method, when the operation arrived over HTTP.
+	pub method: Option<String>,
+	/// Route template of the request, when the operation arrived over HTTP.
+	pub path: Option<String>,
+	/// Result of the operation.
+	pub outcome: AuditOutcome,
+	/// Notes, grants, or other records the operation targeted or produced.
+	pub target_ids: Vec<Uuid>,
+	/// Reject reason codes for rejected items, or the error code of a failed operation.
+	pub reject_reasons: Vec<String>,
+	/// Time the operation finished.
+	pub ts: OffsetDateTime,
+}
+
+/// Caller metadata a transport attaches to the audit entries of the operations it runs.
+///
+/// Service methods take the tenant, project, and actor from their own request when it names them;
+/// the transport values attribute admin operations whose requests do not.
+#[derive(Clone, Debug, Default)]
+pub struct AuditTransport {
+	/// Tenant of the caller.
+	pub tenant_id: Option<String>,
+	/// Project of the caller.
+	pub project_id: Option<String>,
+	/// Agent of the caller.
+	pub actor: Option<String>,
+	/// Authenticated token identifier, when auth is enabled.
+	pub token_id: Option<String>,
+	/// Request method, such as `POST`.
+	pub method: Option<String>,
+	/// Route template of the request.
+	pub path: Option<String>,
+}
+
+/// Operation name, caller, and request-side targets of one audited operation.
+pub(crate) struct AuditScope {
+	operation: &'static str,
+	tenant_id: Option<String>,
+	project_id: Option<String>,
+	actor: Option<String>,
+	target_ids: Vec<Uuid>,
+}
+impl AuditScope {
+	/// Starts a scope for `operation`; the caller comes from the transport until set.
+	pub(crate) fn new(operation: &'static str) -> Self {
+		Self { operation, tenant_id: None, project_id: None, actor: None, target_ids: Vec::new() }
+	}
+
+	/// Attributes the operation to `tenant_id`, `project_id`, and `actor` from the request.
+	///
+	/// Blank values fall back to the transport's caller.
+	pub(crate) fn caller(mut self, tenant_id: &str, project_id: &str, actor: &str) -> Self {
+		self.tenant_id = non_empty(Some(tenant_id)).map(str::to_string);
+		self.project_id = non_empty(Some(project_id)).map(str::to_string);
+		self.actor = non_empty(Some(actor)).map(str::to_string);
+
+		self
+	}
+
+	/// Adds records the request targets.
+	pub(crate) fn targets(mut self, ids: impl IntoIterator<Item = Uuid>) -> Self {
+		for id in ids {
+			push_unique(&mut self.target_ids, id);
+		}
+
+		self
+	}
+}
+
+/// Records and rejections an audited response contributes to its audit entry.
+pub(crate) trait AuditTargets {
+	/// Records the operation produced or changed.
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		Vec::new()
+	}
+
+	/// Reason codes of items the operation rejected.
+	fn audit_reject_reasons(&self) -> Vec<String> {
+		Vec::new()
+	}
+}
+
+/// An audited operation that started but has not recorded its entry yet.
+///
+/// Streaming operations hold one across calls and record it when the stream ends or fails.
+pub(crate) struct PendingAudit {
+	sinks: Vec<Arc<dyn AuditSink>>,
+	scope: AuditScope,
+	transport: AuditTransport,
+}
+impl PendingAudit {
+	/// Records the entry for `result` in every sink.
+	///
+	/// Operations without a tenant, from the request or the transport, have no chain to join and
+	/// are not recorded.
+	pub(crate) async fn record<T>(self, result: &Result<T>)
+	where
+		T: AuditTargets,
+	{
+		let response = match result {
+			Ok(response) => response,
+			Err(err) => return self.record_error(err).await,
+		};
+		let reject_reasons = response.audit_reject_reasons();
+		let outcome = if reject_reasons.is_empty() {
+			AuditOutcome::Succeeded
+		} else {
+			AuditOutcome::Rejected
+		};
+
+		self.finish(response.audit_target_ids(), outcome, reject_reasons).await;
+	}
+
+	/// Records the entry for an operation that failed with `err`.
+	pub(crate) async fn record_error(self, err: &Error) {
+		self.finish(Vec::new(), AuditOutcome::Failed, vec![err.code().to_string()]).await;
+	}
+
+	/// Builds the entry and hands it to every sink.
+	async fn finish(
+		self,
+		produced_ids: Vec<Uuid>,
+		outcome: AuditOutcome,
+		reject_reasons: Vec<String>,
+	) {
+		let Self { sinks, scope, transport } = self;
+		let Some(tenant_id) = scope.tenant_id.or(transport.tenant_id) else {
+			return;
+		};
+		let mut target_ids = scope.target_ids;
+
+		for id in produced_ids {
+			push_unique(&mut target_ids, id);
+		}
+
+		let entry = AuditEntry {
+			audit_id: Uuid::new_v4(),
+			tenant_id,
+			project_id: scope.project_id.or(transport.project_id).unwrap_or_default(),
+			token_id: transport.token_id,
+			actor: scope.actor.or(transport.actor).unwrap_or_default(),
+			operation: scope.operation.to_string(),
+			method: transport.method,
+			path: transport.path,
+			outcome,
+			target_ids,
+			reject_reasons,
+			ts: OffsetDateTime::now_utc(),
+		};
+
+		record_in_sinks(&sinks, &entry).await;
+	}
+}
+
+/// Receiver for audit entries.
+///
+/// The Postgres sink that backs the admin audit-log API is always registered first; embedders may
+/// add sinks that forward entries elsewhere. A sink error is logged and never fails the audited
+/// operation.
+pub trait AuditSink
+where
+	Self: Send + Sync,
+{
+	/// Stable sink name used in logs.
+	fn name(&self) -> &str;
+
+	/// Records one entry.
+	fn record<'a>(&'a self, entry: &'a AuditEntry) -> BoxFuture<'a, Result<()>>;
+}
+
+/// Audit sink that appends entries to the hash-chained `audit_log` table.
+pub struct PostgresAuditSink {
+	pool: PgPool,
+}
+impl PostgresAuditSink {
+	/// Creates a sink writing through `pool`.
+	pub fn new(pool: PgPool) -> Self {
+		Self { pool }
+	}
+}
+
+impl AuditSink for PostgresAuditSink {
+	fn name(&self) -> &str {
+		"postgres"
+	}
+
+	fn record<'a>(&'a self, entry: &'a AuditEntry) -> BoxFuture<'a, Result<()>> {
+		Box::pin(async move { append_audit_entry(&self.pool, entry).await })
+	}
+}
+
+/// Request payload for reading the audit log.
+#[derive(Clone, Debug)]
+pub struct AuditLogListRequest {
+	/// Tenant whose log is read.
+	pub tenant_id: String,
+	/// Optional operation filter.
+	pub operation: Option<String>,
+	/// Optional token filter.
+	pub token_id: Option<String>,
+	/// Optional actor filter.
+	pub actor: Option<String>,
+	/// Cursor returned as `next_before` by a previous page.
+	pub before: Option<String>,
+	/// Maximum entries to return.
+	pub limit: Option<u32>,
+}
+
+/// One stored audit-log entry.
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditLogItem {
+	/// Position of the entry in the log.
+	pub seq: i64,
+	/// Entry identifier.
+	pub audit_id: Uuid,
+	/// Project the operation ran in.
+	pub project_id: String,
+	/// Authenticated token identifier, when auth is enabled.
+	pub token_id: Option<String>,
+	/// Agent that issued the operation.
+	pub actor: String,
+	/// Stable operation name.
+	pub operation: String,
+	/// Request method, when the operation arrived over HTTP.
+	pub method: Option<String>,
+	/// Route template of the request, when the operation arrived over HTTP.
+	pub path: Option<String>,
+	/// Result of the operation.
+	pub outcome: AuditOutcome,
+	/// Records the operation targeted or produced.
+	pub target_ids: Vec<Uuid>,
+	/// Reject reason codes, or the error code of a failed operation.
+	pub reject_reasons: Vec<String>,
+	#[serde(with = "crate::time_serde")]
+	/// Time the operation finished.
+	pub ts: OffsetDateTime,
+	/// Hash of the previous entry in the tenant's chain.
+	pub prev_hash: String,
+	/// Hash of this entry.
+	pub entry_hash: String,
+	/// Whether `entry_hash` matches the stored fields.
+	pub hash_valid: bool,
+}
+
+/// Response payload for reading the audit log.
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditLogListResponse {
+	/// Entries, newest first.
+	pub entries: Vec<AuditLogItem>,
+	/// Cursor for the next older page, when more entries may exist.
+	pub next_before: Option<String>,
+	/// Whether each entry links to the next older one. `None` when filters make the page
+	/// non-contiguous.
+	pub chain_intact: Option<bool>,
+}
+
+#[derive(FromRow)]
+struct AuditLogRow {
+	seq: i64,
+	audit_id: Uuid,
+	tenant_id: String,
+	project_id: String,
+	token_id: Option<String>,
+	actor: String,
+	operation: String,
+	method: Option<String>,
+	path: Option<String>,
+	outcome: String,
+	target_ids: Vec<Uuid>,
+	reject_reasons: Vec<String>,
+	ts: OffsetDateTime,
+	prev_hash: String,
+	entry_hash: String,
+}
+
+#[derive(Serialize)]
+struct AuditHashInput<'a> {
+	prev_hash: &'a str,
+	audit_id: Uuid,
+	tenant_id: &'a str,
+	project_id: &'a str,
+	token_id: Option<&'a str>,
+	actor: &'a str,
+	operation: &'a str,
+	method: Option<&'a str>,
+	path: Option<&'a str>,
+	outcome: &'a str,
+	target_ids: &'a [Uuid],
+	reject_reasons: &'a [String],
+	ts_micros: i64,
+}
+
+impl ElfService {
+	/// Registers an additional audit sink. Sinks run in registration order.
+	pub fn with_audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+		self.audit_sinks.push(sink);
+
+		self
+	}
+
+	/// Hands `entry` to every registered audit sink.
+	pub async fn record_audit(&self, entry: AuditEntry) {
+		record_in_sinks(&self.audit_sinks, &entry).await;
+	}
+
+	/// Starts an audited operation whose entry the caller records later.
+	pub(crate) fn audit(&self, scope: AuditScope) -> PendingAudit {
+		let transport = AUDIT_TRANSPORT.try_with(AuditTransport::clone).unwrap_or_default();
+
+		PendingAudit { sinks: self.audit_sinks.clone(), scope, transport }
+	}
+
+	/// Runs `operation` and records its audit entry from the result.
+	///
+	/// Operations nested inside another audited operation run unrecorded, so one call produces
+	/// one entry.
+	pub(crate) async fn audited<T, F>(&self, scope: AuditScope, operation: F) -> Result<T>
+	where
+		T: AuditTargets,
+		F: Future<Output = Result<T>>,
+	{
+		if AUDIT_IN_PROGRESS.try_with(|_| ()).is_ok() {
+			return operation.await;
+		}
+
+		let pending = self.audit(scope);
+		let result = AUDIT_IN_PROGRESS.scope((), operation).await;
+
+		pending.record(&result).await;
+
+		result
+	}
+
+	/// Lists the caller tenant's audit log, newest first, and checks each entry's hash.
+	pub async fn audit_log_list(&self, req: AuditLogListRequest) -> Result<AuditLogListResponse> {
+		let tenant_id = req.tenant_id.trim();
+
+		if tenant_id.is_empty() {
+			return Err(Error::InvalidRequest { message: "tenant_id is required.".to_string() });
+		}
+
+		let limit = req.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+
+		if limit == 0 || limit > MAX_LIST_LIMIT {
+			return Err(Error::InvalidRequest {
+				message: format!("limit must be between 1 and {MAX_LIST_LIMIT}."),
+			});
+		}
+
+		let before = match req.before.as_deref().map(str::trim).filter(|raw| !raw.is_empty()) {
+			Some(raw) => Some(raw.parse::<i64>().map_err(|_| Error::InvalidRequest {
+				message: "before must be a cursor returned by the audit log.".to_string(),
+			})?),
+			None => None,
+		};
+		let operation = non_empty(req.operation.as_deref());
+		let token_id = non_empty(req.token_id.as_deref());
+		let actor = non_empty(req.actor.as_deref());
+		let filtered = operation.is_some() || token_id.is_some() || actor.is_some();
+		let rows = sqlx::query_as::<_, AuditLogRow>(
+			"\
+SELECT
+	seq,
+	audit_id,
+	tenant_id,
+	project_id,
+	token_id,
+	actor,
+	operation,
+	method,
+	path,
+	outcome,
+	target_ids,
+	reject_reasons,
+	ts,
+	prev_hash,
+	entry_hash
+FROM audit_log
+WHERE tenant_id = $1
+	AND ($2::bigint IS NULL OR seq < $2)
+	AND ($3::text IS NULL OR operation = $3)
+	AND ($4::text IS NULL OR token_id = $4)
+	AND ($5::text IS NULL OR actor = $5)
+ORDER BY seq DESC
+LIMIT $6",
+		)
+		.bind(tenant_id)
+		.bind(before)
+		.bind(operation)
+		.bind(token_id)
+		.bind(actor)
+		.bind(i64::from(limit))
+		.fetch_all(&self.db.pool)
+		.await?;
+		let next_before =
+			(rows.len() >= limit as usize).then(|| rows.last().map(|row| row.seq.to_string()));
+		let chain_intact = (!filtered).then(|| {
+			rows.windows(2).all(|pair| pair[0].prev_hash == pair[1].entry_hash)
+				&& (rows.len() >= limit as usize
+					|| rows.last().is_none_or(|row| row.prev_hash == AUDIT_CHAIN_GENESIS))
+		});
+		let mut entries = Vec::with_capacity(rows.len());
+
+		for row in rows {
+			entries.push(audit_log_item(row)?);
+		}
+
+		Ok(AuditLogListResponse { entries, next_before: next_before.flatten(), chain_intact })
+	}
+}
+
+/// Runs `operation` with `transport` attached to every audit entry it records.
+pub async fn with_audit_transport<F>(transport: AuditTransport, operation: F) -> F::Output
+where
+	F: Future,
+{
+	AUDIT_TRANSPORT.scope(transport, operation).await
+}
+
+/// Appends `entry` to its tenant's chain.
+///
+/// A per-tenant advisory lock serializes appends so each entry links to the newest one.
+pub(crate) async fn append_audit_entry(pool: &PgPool, entry: &AuditEntry) -> Result<()> {
+	let ts = truncate_to_micros(entry.ts)?;
+	let mut tx = pool.begin().await?;
+
+	sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended('audit_log:' || $1, 0))")
+		.bind(entry.tenant_id.as_str())
+		.execute(&mut *tx)
+		.await?;
+
+	let prev_hash = latest_entry_hash(&mut *tx, entry.tenant_id.as_str())
+		.await?
+		.unwrap_or_else(|| AUDIT_CHAIN_GENESIS.to_string());
+	let entry_hash = audit_entry_hash(prev_hash.as_str(), entry, ts)?;
+
+	sqlx::query(
+		"\
+INSERT INTO audit_log (
+	audit_id,
+	tenant_id,
+	project_id,
+	token_id,
+	actor,
+	operation,
+	method,
+	path,
+	outcome,
+	target_ids,
+	reject_reasons,
+	ts,
+	prev_hash,
+	entry_hash
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+	)
+	.bind(entry.audit_id)
+	.bind(entry.tenant_id.as_str())
+	.bind(entry.project_id.as_str())
+	.bind(entry.token_id.as_deref())
+	.bind(entry.actor.as_str())
+	.bind(entry.operation.as_str())
+	.bind(entry.method.as_deref())
+	.bind(entry.path.as_deref())
+	.bind(entry.outcome.as_str())
+	.bind(entry.target_ids.as_slice())
+	.bind(entry.reject_reasons.as_slice())
+	.bind(ts)
+	.bind(prev_hash.as_str())
+	.bind(entry_hash.as_str())
+	.execute(&mut *tx)
+	.await?;
+
+	tx.commit().await?;
+
+	Ok(())
+}
+
+/// Hashes the entry fields together with the previous entry's hash.
+pub(crate) fn audit_entry_hash(
+	prev_hash: &str,
+	entry: &AuditEntry,
+	ts: OffsetDateTime,
+) -> Result<String> {
+	let input = AuditHashInput {
+		prev_hash,
+		audit_id: entry.audit_id,
+		tenant_id: entry.tenant_id.as_str(),
+		project_id: entry.project_id.as_str(),
+		token_id: entry.token_id.as_deref(),
+		actor: entry.actor.as_str(),
+		operation: entry.operation.as_str(),
+		method: entry.method.as_deref(),
+		path: entry.path.as_deref(),
+		outcome: entry.outcome.as_str(),
+		target_ids: entry.target_ids.as_slice(),
+		reject_reasons: entry.reject_reasons.as_slice(),
+		ts_micros: (ts.unix_timestamp_nanos() / 1_000) as i64,
+	};
+	let raw = serde_json::to_vec(&input).map_err(|err| Error::Storage {
+		message: format!("Failed to encode audit entry: {err}"),
+	})?;
+
+	Ok(blake3::hash(&raw).to_hex().to_string())
+}
+
+async fn latest_entry_hash<'e, E>(executor: E, tenant_id: &str) -> Result<Option<String>>
+where
+	E: PgExecutor<'e>,
+{
+	let hash = sqlx::query_scalar::<_, String>(
+		"SELECT entry_hash FROM audit_log WHERE tenant_id = $1 ORDER BY seq DESC LIMIT 1",
+	)
+	.bind(tenant_id)
+	.fetch_optional(executor)
+	.await?;
+
+	Ok(hash)
+}
+
+fn audit_log_item(row: AuditLogRow) -> Result<AuditLogItem> {
+	let entry = AuditEntry {
+		audit_id: row.audit_id,
+		tenant_id: row.tenant_id,
+		project_id: row.project_id,
+		token_id: row.token_id,
+		actor: row.actor,
+		operation: row.operation,
+		method: row.method,
+		path: row.path,
+		outcome: AuditOutcome::parse(row.outcome.as_str())?,
+		target_ids: row.target_ids,
+		reject_reasons: row.reject_reasons,
+		ts: row.ts,
+	};
+	let hash_valid = audit_entry_hash(row.prev_hash.as_str(), &entry, row.ts)? == row.entry_hash;
+
+	Ok(AuditLogItem {
+		seq: row.seq,
+		audit_id: entry.audit_id,
+		project_id: entry.project_id,
+		token_id: entry.token_id,
+		actor: entry.actor,
+		operation: entry.operation,
+		method: entry.method,
+		path: entry.path,
+		outcome: entry.outcome,
+		target_ids: entry.target_ids,
+		reject_reasons: entry.reject_reasons,
+		ts: entry.ts,
+		prev_hash: row.prev_hash,
+		entry_hash: row.entry_hash,
+		hash_valid,
+	})
+}
+
+/// Postgres stores microseconds; hashing the truncated time keeps stored entries verifiable.
+fn truncate_to_micros(ts: OffsetDateTime) -> Result<OffsetDateTime> {
+	ts.replace_nanosecond(ts.nanosecond() / 1_000 * 1_000)
+		.map_err(|err| Error::InvalidRequest { message: format!("Invalid audit time: {err}.") })
+}
+
+async fn record_in_sinks(sinks: &[Arc<dyn AuditSink>], entry: &AuditEntry) {
+	for sink in sinks {
+		if let Err(err) = sink.record(entry).await {
+			tracing::error!(
+				error = %err,
+				sink = sink.name(),
+				operation = entry.operation.as_str(),
+				audit_id = %entry.audit_id,
+				"Audit sink failed."
+			);
+		}
+	}
+}
+
+fn push_unique(ids: &mut Vec<Uuid>, id: Uuid) {
+	if !ids.contains(&id) {
+		ids.push(id);
+	}
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+	value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+	use time::OffsetDateTime;
+	use uuid::Uuid;
+
+	use crate::audit::{self, AUDIT_CHAIN_GENESIS, AuditEntry, AuditOutcome};
+
+	fn entry() -> AuditEntry {
+		AuditEntry {
+			audit_id: Uuid::nil(),
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			token_id: Some("k1".to_string()),
+			actor: "a".to_string(),
+			operation: "add_note".to_string(),
+			method: Some("POST".to_string()),
+			path: Some("/v2/notes/ingest".to_string()),
+			outcome: AuditOutcome::Rejected,
+			target_ids: vec![Uuid::nil()],
+			reject_reasons: vec!["REJECT_SECRET".to_string()],
+			ts: OffsetDateTime::UNIX_EPOCH,
+		}
+	}
+
+	#[test]
+	fn entry_hash_covers_fields_and_previous_hash() {
+		let ts = OffsetDateTime::UNIX_EPOCH;
+		let base = audit::audit_entry_hash(AUDIT_CHAIN_GENESIS, &entry(), ts)
+			.expect("Expected an entry hash.");
+		let mut tampered = entry();
+
+		tampered.reject_reasons.clear();
+
+		let hash = |prev_hash: &str, entry: &AuditEntry| {
+			audit::audit_entry_hash(prev_hash, entry, ts).expect("Expected an entry hash.")
+		};
+
+		assert_eq!(base, hash(AUDIT_CHAIN_GENESIS, &entry()));
+		assert_ne!(base, hash(AUDIT_CHAIN_GENESIS, &tampered));
+		assert_ne!(base, hash(base.as_str(), &entry()));
+	}
+}
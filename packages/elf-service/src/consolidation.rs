@@ -13,4 +13,6 @@ pub use types::{
 	ConsolidationRunResponse, ConsolidationRunsListRequest, ConsolidationRunsListResponse,
 };
 
+pub(crate) use validation::validate_context;
+
 #[cfg(test)] mod tests;
@@ -8,11 +8,12 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::{
-	Error, InsertVersionArgs, Result,
+	Error, InsertVersionArgs, PiiRedactor, Result,
 	access::{self, ORG_PROJECT_ID},
+	consolidation::types::PromotedMemoryPayload,
 };
 use elf_config::Config;
-use elf_domain::ttl;
+use elf_domain::{ttl, writegate::PiiRedaction};
 use elf_storage::{
 	models::{ConsolidationProposal, MemoryNote},
 	queries,
@@ -26,7 +27,8 @@ pub(super) async fn create_promoted_memory_note(
 	cfg: &Config,
 	now: OffsetDateTime,
 ) -> Result<Uuid> {
-	let payload = payload::decode_promoted_memory_payload(proposal)?;
+	let mut payload = payload::decode_promoted_memory_payload(proposal)?;
+	let pii_redactions = redact_promoted_payload(&mut payload, cfg);
 	let scope = payload::promoted_memory_scope(&payload, "agent_private")?;
 
 	payload::validate_promoted_memory_payload(&payload, &scope, cfg)?;
@@ -51,7 +53,16 @@ pub(super) async fn create_promoted_memory_note(
 		proposal.agent_id.as_str(),
 	)
 	.await?;
+	crate::quotas::ensure_note_quota(
+		tx,
+		cfg.security.quotas.as_ref(),
+		proposal.tenant_id.as_str(),
+		now,
+	)
+	.await?;
 
+	let embedding_version =
+		crate::embedding_version_for_note(cfg, scope.as_str(), note_type.as_str());
 	let note = MemoryNote {
 		note_id,
 		tenant_id: proposal.tenant_id.clone(),
@@ -67,15 +78,16 @@ pub(super) async fn create_promoted_memory_note(
 		created_at: now,
 		updated_at: now,
 		expires_at,
-		embedding_version: crate::embedding_version(cfg),
+		embedding_version,
 		source_ref,
 		hit_count: 0,
 		last_hit_at: None,
 	};
 
 	queries::insert_note(&mut **tx, &note).await?;
-	crate::insert_version(
-		&mut **tx,
+
+	let version_id = crate::insert_version(
+		tx,
 		InsertVersionArgs {
 			note_id,
 			op: "ADD",
@@ -87,6 +99,11 @@ pub(super) async fn create_promoted_memory_note(
 		},
 	)
 	.await?;
+
+	if !pii_redactions.is_empty() {
+		crate::record_version_redactions(&mut **tx, version_id, &pii_redactions).await?;
+	}
+
 	crate::enqueue_outbox_tx(&mut **tx, note_id, "UPSERT", &note.embedding_version, now).await?;
 
 	Ok(note_id)
@@ -100,7 +117,8 @@ pub(super) async fn update_promoted_memory_note(
 	cfg: &Config,
 	now: OffsetDateTime,
 ) -> Result<Uuid> {
-	let payload = payload::decode_promoted_memory_payload(proposal)?;
+	let mut payload = payload::decode_promoted_memory_payload(proposal)?;
+	let pii_redactions = redact_promoted_payload(&mut payload, cfg);
 	let note_id = payload::target_note_id(proposal)?;
 	let mut note = sqlx::query_as::<_, MemoryNote>(
 		"\
@@ -169,8 +187,8 @@ FOR UPDATE",
 
 	update_promoted_note_row(tx, &note).await?;
 
-	crate::insert_version(
-		&mut **tx,
+	let version_id = crate::insert_version(
+		tx,
 		InsertVersionArgs {
 			note_id,
 			op: "UPDATE",
@@ -182,11 +200,28 @@ FOR UPDATE",
 		},
 	)
 	.await?;
+
+	if !pii_redactions.is_empty() {
+		crate::record_version_redactions(&mut **tx, version_id, &pii_redactions).await?;
+	}
+
 	crate::enqueue_outbox_tx(&mut **tx, note_id, "UPSERT", &note.embedding_version, now).await?;
 
 	Ok(note_id)
 }
 
+/// Applies `security.pii` redaction to the promoted text, key, and source_ref evidence quotes
+/// before the payload is validated.
+fn redact_promoted_payload(payload: &mut PromotedMemoryPayload, cfg: &Config) -> Vec<PiiRedaction> {
+	let mut redactor = PiiRedactor::new(cfg);
+
+	redactor.field("$.text", &mut payload.text);
+	redactor.key(payload.key.as_mut());
+	redactor.source_ref_evidence(&mut payload.source_ref);
+
+	redactor.finish()
+}
+
 async fn update_promoted_note_row(
 	tx: &mut Transaction<'_, Postgres>,
 	note: &MemoryNote,
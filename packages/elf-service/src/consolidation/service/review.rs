@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::{
 	ElfService, Error, Result,
+	audit::AuditScope,
 	consolidation::{
 		promotion::{self},
 		types::{
@@ -27,6 +28,18 @@ impl ElfService {
 	pub async fn consolidation_proposal_review(
 		&self,
 		req: ConsolidationProposalReviewRequest,
+	) -> Result<ConsolidationProposalResponse> {
+		let scope = AuditScope::new("admin_consolidation_proposal_review")
+			.caller(&req.tenant_id, &req.project_id, &req.reviewer_agent_id)
+			.targets([req.proposal_id]);
+
+		self.audited(scope, self.consolidation_proposal_review_inner(req)).await
+	}
+
+	/// Runs [`ElfService::consolidation_proposal_review`] without recording an audit entry.
+	async fn consolidation_proposal_review_inner(
+		&self,
+		req: ConsolidationProposalReviewRequest,
 	) -> Result<ConsolidationProposalResponse> {
 		validation::validate_context(
 			req.tenant_id.as_str(),
@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::{
 	ElfService, Error, Result,
+	audit::AuditScope,
 	consolidation::{
 		types::{
 			self, ConsolidationProposalInput, ConsolidationRunCreateRequest,
@@ -22,6 +23,20 @@ impl ElfService {
 	pub async fn consolidation_run_create(
 		&self,
 		req: ConsolidationRunCreateRequest,
+	) -> Result<ConsolidationRunCreateResponse> {
+		let scope = AuditScope::new("admin_consolidation_run_create").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.consolidation_run_create_inner(req)).await
+	}
+
+	/// Runs [`ElfService::consolidation_run_create`] without recording an audit entry.
+	async fn consolidation_run_create_inner(
+		&self,
+		req: ConsolidationRunCreateRequest,
 	) -> Result<ConsolidationRunCreateResponse> {
 		validation::validate_context(
 			req.tenant_id.as_str(),
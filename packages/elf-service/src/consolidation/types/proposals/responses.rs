@@ -1,3 +1,4 @@
+use crate::audit::AuditTargets;
 use serde::Serialize;
 use serde_json::Value;
 use time::OffsetDateTime;
@@ -110,6 +111,12 @@ pub struct ConsolidationProposalResponse {
 	/// Append-only review events for detail readback.
 	pub review_events: Vec<ConsolidationProposalReviewEventResponse>,
 }
+
+impl AuditTargets for ConsolidationProposalResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.proposal_id, self.run_id]
+	}
+}
 impl From<ConsolidationProposal> for ConsolidationProposalResponse {
 	fn from(proposal: ConsolidationProposal) -> Self {
 		Self {
@@ -3,8 +3,11 @@ use serde_json::Value;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::consolidation::types::{
-	ConsolidationProposalInput, ConsolidationProposalResponse, empty_object,
+use crate::{
+	audit::AuditTargets,
+	consolidation::types::{
+		ConsolidationProposalInput, ConsolidationProposalResponse, empty_object,
+	},
 };
 use elf_domain::consolidation::{ConsolidationInputRef, ConsolidationLineage};
 use elf_storage::models::ConsolidationRun;
@@ -43,6 +46,15 @@ pub struct ConsolidationRunCreateResponse {
 	pub proposals: Vec<ConsolidationProposalResponse>,
 }
 
+impl AuditTargets for ConsolidationRunCreateResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		[self.run.run_id, self.job_id]
+			.into_iter()
+			.chain(self.proposals.iter().map(|proposal| proposal.proposal_id))
+			.collect()
+	}
+}
+
 /// Request to get one consolidation run.
 #[derive(Clone, Debug, Deserialize)]
 pub struct ConsolidationRunGetRequest {
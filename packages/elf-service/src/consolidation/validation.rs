@@ -11,7 +11,7 @@ use elf_domain::consolidation::{
 	ConsolidationValidationError,
 };
 
-pub(super) fn validate_context(tenant_id: &str, project_id: &str, agent_id: &str) -> Result<()> {
+pub(crate) fn validate_context(tenant_id: &str, project_id: &str, agent_id: &str) -> Result<()> {
 	validate_non_empty("tenant_id", tenant_id)?;
 	validate_non_empty("project_id", project_id)?;
 
@@ -1,3 +1,5 @@
+/// Rejection code emitted when an agent writes too many similar notes inside the anomaly window.
+pub const RATE_ANOMALY: &str = "RATE_ANOMALY";
 /// Rejection code emitted when event evidence quotes do not match the source messages.
 pub const REJECT_EVIDENCE_MISMATCH: &str = "REJECT_EVIDENCE_MISMATCH";
 /// Rejection code emitted when a write policy and extracted output disagree.
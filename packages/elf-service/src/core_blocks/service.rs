@@ -2,6 +2,7 @@ use time::OffsetDateTime;
 
 use crate::{
 	ElfService, Error, Result, access,
+	audit::AuditScope,
 	core_blocks::{
 		persistence::{self},
 		types::{
@@ -55,6 +56,18 @@ impl ElfService {
 	pub async fn core_block_upsert(
 		&self,
 		req: CoreBlockUpsertRequest,
+	) -> Result<CoreBlockUpsertResponse> {
+		let scope = AuditScope::new("admin_core_block_upsert")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets(req.block_id);
+
+		self.audited(scope, self.core_block_upsert_inner(req)).await
+	}
+
+	/// Runs [`ElfService::core_block_upsert`] without recording an audit entry.
+	async fn core_block_upsert_inner(
+		&self,
+		req: CoreBlockUpsertRequest,
 	) -> Result<CoreBlockUpsertResponse> {
 		let prepared = validation::prepare_upsert_request(&self.cfg, req)?;
 		let now = OffsetDateTime::now_utc();
@@ -97,6 +110,18 @@ impl ElfService {
 	pub async fn core_block_attach(
 		&self,
 		req: CoreBlockAttachRequest,
+	) -> Result<CoreBlockAttachResponse> {
+		let scope = AuditScope::new("admin_core_block_attach")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.block_id]);
+
+		self.audited(scope, self.core_block_attach_inner(req)).await
+	}
+
+	/// Runs [`ElfService::core_block_attach`] without recording an audit entry.
+	async fn core_block_attach_inner(
+		&self,
+		req: CoreBlockAttachRequest,
 	) -> Result<CoreBlockAttachResponse> {
 		let prepared = validation::prepare_attach_request(&self.cfg, req)?;
 		let now = OffsetDateTime::now_utc();
@@ -159,6 +184,18 @@ impl ElfService {
 	pub async fn core_block_detach(
 		&self,
 		req: CoreBlockDetachRequest,
+	) -> Result<CoreBlockDetachResponse> {
+		let scope = AuditScope::new("admin_core_block_detach")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.attachment_id]);
+
+		self.audited(scope, self.core_block_detach_inner(req)).await
+	}
+
+	/// Runs [`ElfService::core_block_detach`] without recording an audit entry.
+	async fn core_block_detach_inner(
+		&self,
+		req: CoreBlockDetachRequest,
 	) -> Result<CoreBlockDetachResponse> {
 		let prepared = validation::prepare_detach_request(req)?;
 		let now = OffsetDateTime::now_utc();
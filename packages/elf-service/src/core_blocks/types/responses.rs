@@ -3,7 +3,7 @@ use serde_json::Value;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::core_blocks::types::events::CoreBlockAuditEvent;
+use crate::{audit::AuditTargets, core_blocks::types::events::CoreBlockAuditEvent};
 
 /// Response payload for attached core block readback.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -66,6 +66,12 @@ pub struct CoreBlockUpsertResponse {
 	pub block: CoreBlockRecord,
 }
 
+impl AuditTargets for CoreBlockUpsertResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.block.block_id]
+	}
+}
+
 /// Core block record returned by admin mutation APIs.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CoreBlockRecord {
@@ -115,6 +121,12 @@ pub struct CoreBlockAttachResponse {
 	pub attached_at: OffsetDateTime,
 }
 
+impl AuditTargets for CoreBlockAttachResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.attachment_id, self.block_id]
+	}
+}
+
 /// Response payload for detaching a core block.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CoreBlockDetachResponse {
@@ -123,3 +135,9 @@ pub struct CoreBlockDetachResponse {
 	/// Whether an active attachment was detached.
 	pub detached: bool,
 }
+
+impl AuditTargets for CoreBlockDetachResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.attachment_id]
+	}
+}
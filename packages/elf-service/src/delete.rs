@@ -1,12 +1,23 @@
-//! Note deletion APIs.
+//! Note deletion, trash, and undelete APIs.
 
 use serde::{Deserialize, Serialize};
-use time::OffsetDateTime;
+use sqlx::PgConnection;
+use time::{Duration, OffsetDateTime};
 use uuid::Uuid;
 
-use crate::{ElfService, Error, InsertVersionArgs, NoteOp, Result, access::ORG_PROJECT_ID};
+use crate::{
+	ElfService, Error, InsertVersionArgs, NoteOp, OutboxJob, Result,
+	access::ORG_PROJECT_ID,
+	audit::{AuditScope, AuditTargets},
+	note_batch::NoteWriter,
+	permissions::WriteOperation,
+};
+use elf_config::SecurityAuthRole;
 use elf_storage::models::MemoryNote;
 
+/// Status of a deleted note that can still be restored.
+pub const TRASHED_STATUS: &str = "trashed";
+
 /// Request payload for note deletion.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteRequest {
@@ -16,6 +27,10 @@ pub struct DeleteRequest {
 	pub project_id: String,
 	/// Agent requesting the deletion.
 	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the note's scope; `None` when auth is
+	/// off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
 	/// Identifier of the note to delete.
 	pub note_id: Uuid,
 }
@@ -29,9 +44,101 @@ pub struct DeleteResponse {
 	pub op: NoteOp,
 }
 
+impl AuditTargets for DeleteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.note_id]
+	}
+}
+
+/// Request payload for restoring a trashed note.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UndeleteRequest {
+	/// Tenant that owns the note.
+	pub tenant_id: String,
+	/// Project that owns the note.
+	pub project_id: String,
+	/// Agent requesting the restore; must own the note.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the note's scope; `None` when auth is
+	/// off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Identifier of the note to restore.
+	pub note_id: Uuid,
+}
+
+/// Response payload for restoring a trashed note.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UndeleteResponse {
+	/// Identifier of the affected note.
+	pub note_id: Uuid,
+	/// `update` when the note was restored, `none` when it was already active.
+	pub op: NoteOp,
+}
+
+impl AuditTargets for UndeleteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.note_id]
+	}
+}
+
+/// Request payload for listing the caller's trashed notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ListTrashedRequest {
+	/// Tenant to list trashed notes from.
+	pub tenant_id: String,
+	/// Project to list trashed notes from, alongside org-shared notes.
+	pub project_id: String,
+	/// Agent whose trashed notes are listed.
+	pub agent_id: String,
+}
+
+/// One note waiting in the trash.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TrashedNote {
+	/// Note identifier.
+	pub note_id: Uuid,
+	/// Note type discriminator.
+	pub r#type: String,
+	/// Optional application-defined key.
+	pub key: Option<String>,
+	/// Scope key for the note.
+	pub scope: String,
+	/// Note body text.
+	pub text: String,
+	#[serde(with = "crate::time_serde")]
+	/// Time the note was trashed.
+	pub trashed_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// Time after which the worker may purge the note.
+	pub purge_after: OffsetDateTime,
+}
+
+/// Response payload for listing trashed notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ListTrashedResponse {
+	/// Days a trashed note is kept before purge.
+	pub retention_days: i64,
+	/// Trashed notes, most recently trashed first.
+	pub items: Vec<TrashedNote>,
+}
+
 impl ElfService {
-	/// Soft-deletes one note when the caller owns it and the scope is writable.
+	/// Moves one note into the trash when the caller owns it and the scope is writable.
+	///
+	/// Trashed notes leave search and listing immediately and can be restored with
+	/// [`ElfService::undelete`] until the worker purges them after
+	/// `lifecycle.purge_trashed_after_days`.
 	pub async fn delete(&self, req: DeleteRequest) -> Result<DeleteResponse> {
+		let scope = AuditScope::new("delete")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.note_id]);
+
+		self.audited(scope, self.delete_inner(req)).await
+	}
+
+	/// Runs [`ElfService::delete`] without recording an audit entry.
+	async fn delete_inner(&self, req: DeleteRequest) -> Result<DeleteResponse> {
 		let now = OffsetDateTime::now_utc();
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
@@ -43,46 +150,157 @@ impl ElfService {
 			});
 		}
 
+		let writer = NoteWriter { tenant_id, project_id, agent_id, role: req.role };
 		let mut tx = self.db.pool.begin().await?;
-		let mut note: MemoryNote = sqlx::query_as::<_, MemoryNote>(
-			"\
-SELECT *
-FROM memory_notes
-WHERE note_id = $1 AND tenant_id = $2 AND project_id IN ($3, $4)
-FOR UPDATE",
-		)
-		.bind(req.note_id)
-		.bind(tenant_id)
-		.bind(project_id)
-		.bind(ORG_PROJECT_ID)
-		.fetch_optional(&mut *tx)
-		.await?
-		.ok_or_else(|| Error::InvalidRequest { message: "Note not found.".to_string() })?;
+		let (response, outbox) = self.apply_delete(&mut tx, &writer, req.note_id, now).await?;
 
-		if note.agent_id != agent_id {
-			return Err(Error::InvalidRequest { message: "Note not found.".to_string() });
+		if let Some(job) = outbox {
+			crate::enqueue_outbox_tx(&mut *tx, job.note_id, job.op, &job.embedding_version, now)
+				.await?;
 		}
 
-		let scope_allowed = self.cfg.scopes.allowed.iter().any(|scope| scope == &note.scope);
-		let write_allowed = match note.scope.as_str() {
-			"agent_private" => self.cfg.scopes.write_allowed.agent_private,
-			"project_shared" => self.cfg.scopes.write_allowed.project_shared,
-			"org_shared" => self.cfg.scopes.write_allowed.org_shared,
-			_ => false,
-		};
+		tx.commit().await?;
 
-		if !scope_allowed || !write_allowed {
-			return Err(Error::ScopeDenied { message: "Scope is not allowed.".to_string() });
+		Ok(response)
+	}
+
+	/// Trashes one note inside the caller's transaction and returns its outbox job instead of
+	/// enqueuing it.
+	///
+	/// Caller-facing failures (missing note, denied scope or role) are returned before anything
+	/// is written.
+	pub(crate) async fn apply_delete(
+		&self,
+		conn: &mut PgConnection,
+		writer: &NoteWriter<'_>,
+		note_id: Uuid,
+		now: OffsetDateTime,
+	) -> Result<(DeleteResponse, Option<OutboxJob>)> {
+		let mut note = self
+			.lock_owned_note(
+				&mut *conn,
+				note_id,
+				writer.tenant_id,
+				writer.project_id,
+				writer.agent_id,
+			)
+			.await?;
+
+		self.authorize_write(writer.role, WriteOperation::Delete, note.scope.as_str())?;
+
+		if note.status == TRASHED_STATUS || note.status == "deleted" {
+			return Ok((DeleteResponse { note_id: note.note_id, op: NoteOp::None }, None));
 		}
-		if note.status == "deleted" {
+
+		let prev_snapshot = crate::note_snapshot(&note);
+
+		note.status = TRASHED_STATUS.to_string();
+		note.updated_at = now;
+
+		sqlx::query("UPDATE memory_notes SET status = $1, updated_at = $2 WHERE note_id = $3")
+			.bind(note.status.as_str())
+			.bind(note.updated_at)
+			.bind(note.note_id)
+			.execute(&mut *conn)
+			.await?;
+		crate::insert_version(
+			&mut *conn,
+			InsertVersionArgs {
+				note_id: note.note_id,
+				op: "DELETE",
+				prev_snapshot: Some(prev_snapshot),
+				new_snapshot: Some(crate::note_snapshot(&note)),
+				reason: "delete",
+				actor: writer.agent_id,
+				ts: now,
+			},
+		)
+		.await?;
+
+		Ok((
+			DeleteResponse { note_id: note.note_id, op: NoteOp::Delete },
+			Some(OutboxJob {
+				note_id: note.note_id,
+				op: "DELETE",
+				embedding_version: note.embedding_version,
+				coalesced: false,
+			}),
+		))
+	}
+
+	/// Restores a trashed note owned by the caller and queues it for reindexing.
+	///
+	/// Fails with a conflict when the note is not in the trash, or when an active note already
+	/// holds the same key.
+	pub async fn undelete(&self, req: UndeleteRequest) -> Result<UndeleteResponse> {
+		let scope = AuditScope::new("undelete")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.note_id]);
+
+		self.audited(scope, self.undelete_inner(req)).await
+	}
+
+	/// Runs [`ElfService::undelete`] without recording an audit entry.
+	async fn undelete_inner(&self, req: UndeleteRequest) -> Result<UndeleteResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let mut tx = self.db.pool.begin().await?;
+		let mut note =
+			self.lock_owned_note(&mut tx, req.note_id, tenant_id, project_id, agent_id).await?;
+
+		self.authorize_write(req.role, WriteOperation::Delete, note.scope.as_str())?;
+
+		if note.status == "active" {
 			tx.commit().await?;
 
-			return Ok(DeleteResponse { note_id: note.note_id, op: NoteOp::None });
+			return Ok(UndeleteResponse { note_id: note.note_id, op: NoteOp::None });
+		}
+		if note.status != TRASHED_STATUS {
+			return Err(Error::Conflict { message: "Note is not in the trash.".to_string() });
+		}
+		if let Some(key) = note.key.as_deref() {
+			let key_taken: bool = sqlx::query_scalar(
+				"\
+SELECT EXISTS (
+	SELECT 1
+	FROM memory_notes
+	WHERE tenant_id = $1
+		AND project_id = $2
+		AND agent_id = $3
+		AND scope = $4
+		AND type = $5
+		AND key = $6
+		AND status = 'active'
+)",
+			)
+			.bind(note.tenant_id.as_str())
+			.bind(note.project_id.as_str())
+			.bind(note.agent_id.as_str())
+			.bind(note.scope.as_str())
+			.bind(note.r#type.as_str())
+			.bind(key)
+			.fetch_one(&mut *tx)
+			.await?;
+
+			if key_taken {
+				return Err(Error::Conflict {
+					message: "An active note already uses this key.".to_string(),
+				});
+			}
 		}
 
 		let prev_snapshot = crate::note_snapshot(&note);
 
-		note.status = "deleted".to_string();
+		note.status = "active".to_string();
 		note.updated_at = now;
 
 		sqlx::query("UPDATE memory_notes SET status = $1, updated_at = $2 WHERE note_id = $3")
@@ -92,23 +310,110 @@ FOR UPDATE",
 			.execute(&mut *tx)
 			.await?;
 		crate::insert_version(
-			&mut *tx,
+			&mut tx,
 			InsertVersionArgs {
 				note_id: note.note_id,
-				op: "DELETE",
+				op: "UNDELETE",
 				prev_snapshot: Some(prev_snapshot),
 				new_snapshot: Some(crate::note_snapshot(&note)),
-				reason: "delete",
+				reason: "undelete",
 				actor: agent_id,
 				ts: now,
 			},
 		)
 		.await?;
-		crate::enqueue_outbox_tx(&mut *tx, note.note_id, "DELETE", &note.embedding_version, now)
+		crate::enqueue_outbox_tx(&mut *tx, note.note_id, "UPSERT", &note.embedding_version, now)
 			.await?;
 
 		tx.commit().await?;
 
-		Ok(DeleteResponse { note_id: note.note_id, op: NoteOp::Delete })
+		Ok(UndeleteResponse { note_id: note.note_id, op: NoteOp::Update })
+	}
+
+	/// Lists the caller's trashed notes with the time each becomes eligible for purge.
+	pub async fn list_trashed(&self, req: ListTrashedRequest) -> Result<ListTrashedResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let retention_days = self.cfg.lifecycle.trash_retention_days();
+		let notes = sqlx::query_as::<_, MemoryNote>(
+			"\
+SELECT *
+FROM memory_notes
+WHERE tenant_id = $1 AND project_id IN ($2, $3) AND agent_id = $4 AND status = $5
+ORDER BY updated_at DESC, note_id ASC",
+		)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(ORG_PROJECT_ID)
+		.bind(agent_id)
+		.bind(TRASHED_STATUS)
+		.fetch_all(&self.db.pool)
+		.await?;
+		let items = notes
+			.into_iter()
+			.filter(|note| self.cfg.scopes.allowed.iter().any(|scope| scope == &note.scope))
+			.map(|note| TrashedNote {
+				note_id: note.note_id,
+				r#type: note.r#type,
+				key: note.key,
+				scope: note.scope,
+				text: note.text,
+				trashed_at: note.updated_at,
+				purge_after: note.updated_at + Duration::days(retention_days),
+			})
+			.collect();
+
+		Ok(ListTrashedResponse { retention_days, items })
+	}
+
+	/// Locks a note the caller owns in a writable scope.
+	pub(crate) async fn lock_owned_note(
+		&self,
+		conn: &mut PgConnection,
+		note_id: Uuid,
+		tenant_id: &str,
+		project_id: &str,
+		agent_id: &str,
+	) -> Result<MemoryNote> {
+		let note: MemoryNote = sqlx::query_as::<_, MemoryNote>(
+			"\
+SELECT *
+FROM memory_notes
+WHERE note_id = $1 AND tenant_id = $2 AND project_id IN ($3, $4)
+FOR UPDATE",
+		)
+		.bind(note_id)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(ORG_PROJECT_ID)
+		.fetch_optional(&mut *conn)
+		.await?
+		.ok_or_else(|| Error::InvalidRequest { message: "Note not found.".to_string() })?;
+
+		if note.agent_id != agent_id {
+			return Err(Error::InvalidRequest { message: "Note not found.".to_string() });
+		}
+
+		let scope_allowed = self.cfg.scopes.allowed.iter().any(|scope| scope == &note.scope);
+		let write_allowed = match note.scope.as_str() {
+			"agent_private" => self.cfg.scopes.write_allowed.agent_private,
+			"project_shared" => self.cfg.scopes.write_allowed.project_shared,
+			"org_shared" => self.cfg.scopes.write_allowed.org_shared,
+			_ => false,
+		};
+
+		if !scope_allowed || !write_allowed {
+			return Err(Error::ScopeDenied { message: "Scope is not allowed.".to_string() });
+		}
+
+		Ok(note)
 	}
 }
@@ -14,11 +14,15 @@ pub use api::{
 	DocRetrievalTrajectory, DocRetrievalTrajectoryStage, DocType, DocsDeleteRequest,
 	DocsDeleteResponse, DocsExcerptLocator, DocsExcerptResponse, DocsExcerptVerification,
 	DocsExcerptsGetRequest, DocsGetRequest, DocsGetResponse, DocsPutRequest, DocsPutResponse,
-	DocsSearchL0Item, DocsSearchL0ItemHashes, DocsSearchL0ItemLocator, DocsSearchL0ItemPointer,
-	DocsSearchL0ItemReference, DocsSearchL0ItemState, DocsSearchL0Request, DocsSearchL0Response,
-	DocsSourceCaptureSummary, DocsSourceSpanRef, TextPositionSelector, TextQuoteSelector,
+	DocsSearchExcerpt, DocsSearchItem, DocsSearchL0Item, DocsSearchL0ItemHashes,
+	DocsSearchL0ItemLocator, DocsSearchL0ItemPointer, DocsSearchL0ItemReference,
+	DocsSearchL0ItemState, DocsSearchL0Request, DocsSearchL0Response, DocsSearchRequest,
+	DocsSearchResponse, DocsSourceCaptureSummary, DocsSourceSpanRef, TextPositionSelector,
+	TextQuoteSelector,
 };
 
+pub(crate) use chunking::load_tokenizer;
+
 use std::{
 	collections::{HashMap, HashSet},
 	slice,
@@ -41,7 +45,7 @@ use crate::{
 	ElfService, Error, NoteOp, Result,
 	access::{ORG_PROJECT_ID, SharedSpaceGrantKey},
 };
-use chunking::{load_tokenizer, split_tokens_by_offsets};
+use chunking::split_tokens_by_offsets;
 use elf_config::Config;
 use elf_domain::{
 	english_gate,
@@ -54,9 +58,9 @@ use elf_storage::{
 };
 #[cfg(test)] use excerpts::should_enable_sparse_auto;
 use excerpts::{
-	build_doc_search_filter, build_docs_l0_pointer, doc_read_allowed, docs_excerpt_locator,
-	docs_excerpts_resolve_windowed_match, docs_search_sparse_enabled, load_docs_excerpt_context,
-	parse_scored_point_uuid_id, truncate_bytes,
+	bounded_window, build_doc_search_filter, build_docs_l0_pointer, doc_read_allowed,
+	docs_excerpt_locator, docs_excerpts_resolve_windowed_match, docs_search_sparse_enabled,
+	load_docs_excerpt_context, parse_scored_point_uuid_id, truncate_bytes,
 };
 use queries::{load_doc_search_rows, run_doc_fusion_query};
 use search_support::{
@@ -2,6 +2,7 @@ mod doc_type;
 mod excerpts;
 mod put;
 mod read;
+mod search;
 mod search_l0;
 mod selectors;
 mod trajectory;
@@ -13,6 +14,7 @@ pub use self::{
 	},
 	put::{DocsPutRequest, DocsPutResponse, DocsSourceCaptureSummary, DocsSourceSpanRef},
 	read::{DocsDeleteRequest, DocsDeleteResponse, DocsGetRequest, DocsGetResponse},
+	search::{DocsSearchExcerpt, DocsSearchItem, DocsSearchRequest, DocsSearchResponse},
 	search_l0::{
 		DocsSearchL0Item, DocsSearchL0ItemHashes, DocsSearchL0ItemLocator, DocsSearchL0ItemPointer,
 		DocsSearchL0ItemReference, DocsSearchL0ItemState, DocsSearchL0Request,
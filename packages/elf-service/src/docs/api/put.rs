@@ -1,3 +1,4 @@
+use crate::audit::AuditTargets;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
@@ -46,6 +47,12 @@ pub struct DocsPutResponse {
 	pub write_policy_audit: Option<WritePolicyAudit>,
 }
 
+impl AuditTargets for DocsPutResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.doc_id]
+	}
+}
+
 /// Normalized Source Library capture metadata returned by `docs_put`.
 #[derive(Clone, Debug, Serialize)]
 pub struct DocsSourceCaptureSummary {
@@ -3,7 +3,7 @@ use serde_json::Value;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::NoteOp;
+use crate::{NoteOp, audit::AuditTargets};
 
 /// Request payload for document metadata lookup.
 #[derive(Clone, Debug, Deserialize)]
@@ -74,3 +74,9 @@ pub struct DocsDeleteResponse {
 	/// Number of persisted chunks queued for derived-index deletion.
 	pub chunk_delete_count: u32,
 }
+
+impl AuditTargets for DocsDeleteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.doc_id]
+	}
+}
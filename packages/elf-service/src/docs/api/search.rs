@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::docs::api::{
+	search_l0::{DocsSearchL0Item, DocsSearchL0Request},
+	trajectory::DocRetrievalTrajectory,
+};
+
+/// Request payload for cross-document search with bounded excerpts.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DocsSearchRequest {
+	#[serde(flatten)]
+	/// Retrieval parameters shared with `docs_search_l0`.
+	pub search: DocsSearchL0Request,
+	/// Excerpt budget level (`L0`, `L1`, or `L2`); defaults to `L1`.
+	pub level: Option<String>,
+}
+
+/// One chunk-level hit returned by `docs_search`.
+#[derive(Clone, Debug, Serialize)]
+pub struct DocsSearchItem {
+	#[serde(flatten)]
+	/// Underlying chunk hit, including its stable pointer.
+	pub hit: DocsSearchL0Item,
+	/// Optional document title.
+	pub title: Option<String>,
+	/// Bounded excerpt around the hit chunk.
+	pub excerpt: DocsSearchExcerpt,
+}
+
+/// Bounded excerpt window for a `docs_search` hit.
+#[derive(Clone, Debug, Serialize)]
+pub struct DocsSearchExcerpt {
+	/// Excerpt budget level that bounded the window.
+	pub level: String,
+	/// Excerpt text.
+	pub text: String,
+	/// Excerpt start byte offset in the document content.
+	pub start_offset: usize,
+	/// Excerpt end byte offset in the document content.
+	pub end_offset: usize,
+	/// BLAKE3 hash of the excerpt text.
+	pub excerpt_hash: String,
+}
+
+/// Response payload for `docs_search`.
+#[derive(Clone, Debug, Serialize)]
+pub struct DocsSearchResponse {
+	/// Retrieval trace identifier.
+	pub trace_id: Uuid,
+	/// Returned chunk hits with excerpts.
+	pub items: Vec<DocsSearchItem>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Optional retrieval trajectory emitted in explain mode.
+	pub trajectory: Option<DocRetrievalTrajectory>,
+}
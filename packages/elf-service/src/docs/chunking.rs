@@ -1,6 +1,6 @@
 use crate::docs::{ByteChunk, Config, Error, Result, Tokenizer, Uuid};
 
-pub(super) fn load_tokenizer(cfg: &Config) -> Result<Tokenizer> {
+pub(crate) fn load_tokenizer(cfg: &Config) -> Result<Tokenizer> {
 	let tokenizer_repo = cfg.chunking.tokenizer_repo.trim();
 
 	if tokenizer_repo.is_empty() {
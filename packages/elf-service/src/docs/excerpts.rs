@@ -11,4 +11,4 @@ pub(super) use self::{
 	match_resolution::{docs_excerpts_resolve_windowed_match, load_docs_excerpt_context},
 };
 #[cfg(test)] pub(super) use text::should_enable_sparse_auto;
-pub(super) use text::{docs_search_sparse_enabled, truncate_bytes};
+pub(super) use text::{bounded_window, docs_search_sparse_enabled, truncate_bytes};
@@ -37,6 +37,8 @@ pub(in crate::docs) fn locate_quote(
 	None
 }
 
+/// Returns a window of at most `max_bytes` centered on the match, clamped to `text` and snapped to
+/// char boundaries so it always slices `text`, even when the match offsets are stale.
 pub(in crate::docs) fn bounded_window(
 	match_start: usize,
 	match_end: usize,
@@ -46,7 +48,7 @@ pub(in crate::docs) fn bounded_window(
 	let len = text.len();
 	let match_center = match_start.saturating_add(match_end.saturating_sub(match_start) / 2);
 	let half = max_bytes / 2;
-	let mut start = match_center.saturating_sub(half);
+	let mut start = match_center.saturating_sub(half).min(len);
 	let mut end = (start + max_bytes).min(len);
 
 	if end - start < max_bytes && start > 0 {
@@ -0,0 +1,78 @@
+use crate::docs::service::{
+	self, DocsSearchExcerpt, DocsSearchItem, DocsSearchRequest, DocsSearchResponse, ElfService,
+	HashMap, Result, Uuid,
+};
+
+const DEFAULT_SEARCH_LEVEL: &str = "L1";
+
+impl ElfService {
+	/// Searches across readable documents and returns each hit with a bounded excerpt.
+	///
+	/// Retrieval, scope filtering, and ranking are shared with [`ElfService::docs_search_l0`]; each
+	/// excerpt is the `level`-bounded window over the hit chunk's position, so it matches what
+	/// `docs_excerpts_get` returns for the hit's position selector.
+	pub async fn docs_search(&self, req: DocsSearchRequest) -> Result<DocsSearchResponse> {
+		let level = req.level.as_deref().map(str::trim).unwrap_or(DEFAULT_SEARCH_LEVEL).to_string();
+		let level_max = service::excerpt_level_max(level.as_str())?;
+		let tenant_id = req.search.tenant_id.trim().to_string();
+		let hits = self.docs_search_l0(req.search).await?;
+		let mut doc_ids: Vec<Uuid> = hits.items.iter().map(|item| item.doc_id).collect();
+
+		doc_ids.sort_unstable();
+		doc_ids.dedup();
+
+		let docs: HashMap<Uuid, (Option<String>, String)> =
+			sqlx::query_as::<_, (Uuid, Option<String>, String)>(
+				"\
+SELECT doc_id, title, content
+FROM doc_documents
+WHERE tenant_id = $1 AND doc_id = ANY($2)",
+			)
+			.bind(tenant_id.as_str())
+			.bind(doc_ids.as_slice())
+			.fetch_all(&self.db.pool)
+			.await?
+			.into_iter()
+			.map(|(doc_id, title, content)| (doc_id, (title, content)))
+			.collect();
+		let items = hits
+			.items
+			.into_iter()
+			.filter_map(|hit| {
+				let (title, content) = docs.get(&hit.doc_id)?;
+				let position = &hit.pointer.locator.position;
+				let (start_offset, end_offset) = service::bounded_window(
+					position.start,
+					position.end,
+					content.as_str(),
+					level_max,
+				);
+				let Some(text) = content.get(start_offset..end_offset).map(str::to_string) else {
+					tracing::warn!(
+						doc_id = %hit.doc_id,
+						start_offset,
+						end_offset,
+						"Skipping docs search hit whose excerpt window does not slice the document."
+					);
+
+					return None;
+				};
+				let excerpt_hash = blake3::hash(text.as_bytes()).to_hex().to_string();
+
+				Some(DocsSearchItem {
+					title: title.clone(),
+					excerpt: DocsSearchExcerpt {
+						level: level.clone(),
+						text,
+						start_offset,
+						end_offset,
+						excerpt_hash,
+					},
+					hit,
+				})
+			})
+			.collect();
+
+		Ok(DocsSearchResponse { trace_id: hits.trace_id, items, trajectory: hits.trajectory })
+	}
+}
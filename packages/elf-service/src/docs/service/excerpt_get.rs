@@ -9,6 +9,8 @@ impl ElfService {
 		&self,
 		req: DocsExcerptsGetRequest,
 	) -> Result<DocsExcerptResponse> {
+		self.ensure_subsystem(self.subsystems.docs, "docs")?;
+
 		let explain = req.explain.unwrap_or(false);
 		let trace_id = Uuid::new_v4();
 		let tenant_id = req.tenant_id.trim();
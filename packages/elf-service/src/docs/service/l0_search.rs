@@ -8,6 +8,8 @@ use crate::docs::service::{
 impl ElfService {
 	/// Runs L0 document retrieval with access filtering and optional explain output.
 	pub async fn docs_search_l0(&self, req: DocsSearchL0Request) -> Result<DocsSearchL0Response> {
+		self.ensure_subsystem(self.subsystems.docs, "docs")?;
+
 		let trace_id = Uuid::new_v4();
 		let filters = service::validate_docs_search_l0(&req)?;
 		let mut prepared = self.prepare_docs_search_l0_request(&req, &filters).await?;
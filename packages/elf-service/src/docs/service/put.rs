@@ -1,17 +1,31 @@
-use crate::docs::service::{
-	self, DocDocument, DocsPutRequest, DocsPutResponse, ElfService, Error, ORG_PROJECT_ID,
-	OffsetDateTime, Result, SourceCaptureSummaryInput, ValidatedDocsPut, access, doc_outbox, docs,
+use crate::{
+	audit::AuditScope,
+	docs::service::{
+		self, DocDocument, DocsPutRequest, DocsPutResponse, ElfService, Error, ORG_PROJECT_ID,
+		OffsetDateTime, Result, SourceCaptureSummaryInput, ValidatedDocsPut, access, doc_outbox,
+		docs,
+	},
 };
 
 impl ElfService {
 	/// Validates, chunks, stores, and enqueues a document for indexing.
 	pub async fn docs_put(&self, req: DocsPutRequest) -> Result<DocsPutResponse> {
+		let scope =
+			AuditScope::new("docs_put").caller(&req.tenant_id, &req.project_id, &req.agent_id);
+
+		self.audited(scope, self.docs_put_inner(req)).await
+	}
+
+	/// Runs [`ElfService::docs_put`] without recording an audit entry.
+	async fn docs_put_inner(&self, req: DocsPutRequest) -> Result<DocsPutResponse> {
+		self.ensure_subsystem(self.subsystems.docs, "docs")?;
+
 		let ValidatedDocsPut { doc_type, content, write_policy_audit } =
 			service::validate_docs_put(&req)?;
 		let now = OffsetDateTime::now_utc();
 		let embed_version = crate::embedding_version(&self.cfg);
 		let chunking_profile = service::resolve_doc_chunking_profile(doc_type);
-		let tokenizer = service::load_tokenizer(&self.cfg)?;
+		let tokenizer = self.tokenizer()?;
 		let tenant_id = req.tenant_id.clone();
 		let project_id = req.project_id.clone();
 		let agent_id = req.agent_id.clone();
@@ -40,7 +54,7 @@ impl ElfService {
 			chunking_profile.max_tokens,
 			chunking_profile.overlap_tokens,
 			chunking_profile.max_chunks,
-			&tokenizer,
+			tokenizer,
 		)?;
 
 		for (chunk_index, chunk) in chunks.iter_mut().enumerate() {
@@ -1,12 +1,17 @@
-use crate::docs::service::{
-	self, DocDocument, DocsDeleteRequest, DocsDeleteResponse, DocsGetRequest, DocsGetResponse,
-	ElfService, Error, HashSet, NoteOp, ORG_PROJECT_ID, OffsetDateTime, Result, access, doc_outbox,
-	docs, search,
+use crate::{
+	audit::AuditScope,
+	docs::service::{
+		self, DocDocument, DocsDeleteRequest, DocsDeleteResponse, DocsGetRequest, DocsGetResponse,
+		ElfService, Error, HashSet, NoteOp, ORG_PROJECT_ID, OffsetDateTime, Result, access,
+		doc_outbox, docs, search,
+	},
 };
 
 impl ElfService {
 	/// Loads document metadata when the caller can read the requested scope.
 	pub async fn docs_get(&self, req: DocsGetRequest) -> Result<DocsGetResponse> {
+		self.ensure_subsystem(self.subsystems.docs, "docs")?;
+
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
 		let agent_id = req.agent_id.trim();
@@ -103,6 +108,17 @@ LIMIT 1",
 
 	/// Soft-deletes one Source Library document and enqueues doc-vector deletion.
 	pub async fn docs_delete(&self, req: DocsDeleteRequest) -> Result<DocsDeleteResponse> {
+		let scope = AuditScope::new("docs_delete")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.doc_id]);
+
+		self.audited(scope, self.docs_delete_inner(req)).await
+	}
+
+	/// Runs [`ElfService::docs_delete`] without recording an audit entry.
+	async fn docs_delete_inner(&self, req: DocsDeleteRequest) -> Result<DocsDeleteResponse> {
+		self.ensure_subsystem(self.subsystems.docs, "docs")?;
+
 		let now = OffsetDateTime::now_utc();
 		let embed_version = crate::embedding_version(&self.cfg);
 		let tenant_id = req.tenant_id.trim();
@@ -77,3 +77,16 @@ fn split_tokens_by_offsets_preserves_original_substring_offsets() {
 		assert_eq!(chunk.text, "alpha bravo charlie delta"[chunk.start_offset..chunk.end_offset]);
 	}
 }
+
+#[test]
+fn bounded_window_slices_multibyte_text_and_clamps_stale_offsets() {
+	let text = "é".repeat(20);
+	let (start, end) = docs::bounded_window(3, 5, text.as_str(), 7);
+
+	assert!(text.get(start..end).is_some());
+	assert!(end - start <= 7);
+
+	let (start, end) = docs::bounded_window(500, 520, text.as_str(), 8);
+
+	assert_eq!(text.get(start..end), Some("éééé"));
+}
@@ -11,7 +11,7 @@ pub(in crate::entity_memory) fn note_lifecycle(
 		"active" if expires_at.is_some_and(|expires_at| expires_at <= as_of) => "stale".to_string(),
 		"active" => "current".to_string(),
 		"deprecated" => "superseded".to_string(),
-		"deleted" => "tombstoned".to_string(),
+		"deleted" | "trashed" => "tombstoned".to_string(),
 		other => other.to_string(),
 	}
 }
@@ -34,6 +34,12 @@ pub enum Error {
 		/// Human-readable conflict reason.
 		message: String,
 	},
+	/// The tenant exceeded a configured quota.
+	#[error("Quota exceeded: {message}")]
+	QuotaExceeded {
+		/// Human-readable quota failure.
+		message: String,
+	},
 	/// An external model or provider returned an error.
 	#[error("Provider error: {message}")]
 	Provider {
@@ -53,6 +59,21 @@ pub enum Error {
 		message: String,
 	},
 }
+impl Error {
+	/// Stable error code reported to callers and recorded in the audit log.
+	pub fn code(&self) -> &'static str {
+		match self {
+			Self::NonEnglishInput { .. } => "NON_ENGLISH_INPUT",
+			Self::InvalidRequest { .. } => "INVALID_REQUEST",
+			Self::ScopeDenied { .. } => "SCOPE_DENIED",
+			Self::NotFound { .. } => "NOT_FOUND",
+			Self::Conflict { .. } => "CONFLICT",
+			Self::QuotaExceeded { .. } => "QUOTA_EXCEEDED",
+			Self::Provider { .. } | Self::Storage { .. } | Self::Qdrant { .. } => "INTERNAL_ERROR",
+		}
+	}
+}
+
 impl From<sqlx::Error> for Error {
 	fn from(err: sqlx::Error) -> Self {
 		Self::Storage { message: err.to_string() }
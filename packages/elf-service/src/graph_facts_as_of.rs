@@ -0,0 +1,130 @@
+//! Point-in-time graph fact reads that keep superseded history visible.
+
+mod service;
+mod storage;
+mod types;
+mod validation;
+
+pub use types::{GraphFactAsOf, GraphFactsAsOfRequest, GraphFactsAsOfResponse};
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	access::ORG_PROJECT_ID,
+	graph::RelationTemporalStatus,
+	graph_query::{
+		GraphQueryEntity, GraphQueryEntityRef, GraphQueryObject, GraphQueryObjectEntity,
+		GraphQueryPredicate, GraphQueryPredicateRef,
+	},
+};
+use storage::{GraphFactAsOfRow, GraphFactsAsOfRowsFetchParams};
+use validation::PreparedGraphFactsAsOf;
+
+const DEFAULT_GRAPH_FACTS_AS_OF_LIMIT: u32 = 50;
+const MAX_GRAPH_FACTS_AS_OF_LIMIT: u32 = 200;
+const GRAPH_FACTS_AS_OF_EVIDENCE_LIMIT: i64 = 16;
+// Evidence notes deprecated after the fact was recorded still explain what was believed at
+// `as_of`; only deleted notes and notes already expired at `as_of` are excluded.
+const GRAPH_FACTS_AS_OF_SQL: &str = "\
+SELECT
+	gf.fact_id,
+	gf.scope,
+	gf.agent_id AS actor,
+	gf.subject_entity_id,
+	subject_entity.canonical AS subject_canonical,
+	subject_entity.kind AS subject_kind,
+	gf.predicate,
+	gf.predicate_id,
+	gf.object_entity_id,
+	object_entity.canonical AS object_canonical,
+	object_entity.kind AS object_kind,
+	gf.object_value,
+	gf.valid_from,
+	gf.valid_to,
+	(SELECT MIN(s.effective_at)
+	 FROM graph_fact_supersessions s
+	 WHERE s.from_fact_id = gf.fact_id) AS superseded_at,
+	COALESCE(
+		(SELECT ARRAY_AGG(s.to_fact_id ORDER BY s.effective_at ASC, s.to_fact_id ASC)
+		 FROM graph_fact_supersessions s
+		 WHERE s.from_fact_id = gf.fact_id),
+		'{}'::uuid[]
+	) AS superseded_by_fact_ids,
+	COALESCE(
+		(SELECT ARRAY_AGG(e.note_id ORDER BY e.created_at ASC, e.note_id ASC)
+		 FROM (
+		 	SELECT evidence.note_id, evidence.created_at
+		 	FROM graph_fact_evidence evidence
+			JOIN memory_notes note ON note.note_id = evidence.note_id
+		 	WHERE evidence.fact_id = gf.fact_id
+				AND note.tenant_id = gf.tenant_id
+				AND note.project_id = gf.project_id
+				AND note.status IN ('active', 'deprecated')
+				AND (note.expires_at IS NULL OR note.expires_at > $5)
+				AND note.scope = ANY($4::text[])
+				AND (
+					(note.scope = 'agent_private' AND note.agent_id = $6)
+					OR (note.scope <> 'agent_private' AND (
+						note.agent_id = $6 OR (note.scope || ':' || note.agent_id) = ANY($7::text[])
+					))
+				)
+		 	ORDER BY evidence.created_at ASC, evidence.note_id ASC
+		 	LIMIT $9
+		 ) e),
+		'{}'::uuid[]
+	) AS evidence_note_ids
+FROM graph_facts AS gf
+JOIN graph_entities AS subject_entity
+	ON subject_entity.entity_id = gf.subject_entity_id
+	AND subject_entity.tenant_id = gf.tenant_id
+	AND subject_entity.project_id = gf.project_id
+LEFT JOIN graph_entities AS object_entity
+	ON object_entity.entity_id = gf.object_entity_id
+	AND object_entity.tenant_id = gf.tenant_id
+	AND object_entity.project_id = gf.project_id
+WHERE gf.tenant_id = $1
+	AND (gf.project_id = $2 OR (gf.project_id = $10 AND gf.scope = 'org_shared'))
+	AND (gf.subject_entity_id = $3 OR gf.object_entity_id = $3)
+	AND gf.scope = ANY($4::text[])
+	AND gf.valid_from <= $5
+	AND (gf.valid_to IS NULL OR gf.valid_to > $5)
+	AND ($11::uuid IS NULL OR gf.predicate_id = $11)
+	AND (
+		$12::boolean
+		OR NOT EXISTS (
+			SELECT 1 FROM graph_fact_supersessions s WHERE s.from_fact_id = gf.fact_id
+		)
+	)
+	AND (
+		(gf.scope = 'agent_private' AND gf.agent_id = $6)
+		OR (gf.scope <> 'agent_private' AND (
+			gf.agent_id = $6 OR (gf.scope || ':' || gf.agent_id) = ANY($7::text[])
+		))
+	)
+	AND EXISTS (
+		SELECT 1
+		FROM graph_fact_evidence evidence
+		JOIN memory_notes note ON note.note_id = evidence.note_id
+		WHERE evidence.fact_id = gf.fact_id
+			AND note.tenant_id = gf.tenant_id
+			AND note.project_id = gf.project_id
+			AND note.status IN ('active', 'deprecated')
+			AND (note.expires_at IS NULL OR note.expires_at > $5)
+			AND note.scope = ANY($4::text[])
+			AND (
+				(note.scope = 'agent_private' AND note.agent_id = $6)
+				OR (note.scope <> 'agent_private' AND (
+					note.agent_id = $6 OR (note.scope || ':' || note.agent_id) = ANY($7::text[])
+				))
+			)
+	)
+	ORDER BY gf.valid_from DESC, gf.fact_id ASC
+	LIMIT $8";
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,121 @@
+use crate::{
+	access, graph,
+	graph_facts_as_of::{
+		ElfService, GraphFactAsOf, GraphFactAsOfRow, GraphFactsAsOfRequest, GraphFactsAsOfResponse,
+		GraphFactsAsOfRowsFetchParams, GraphQueryEntity, GraphQueryObject, GraphQueryObjectEntity,
+		OffsetDateTime, PreparedGraphFactsAsOf, Result, storage, validation,
+	},
+	graph_query, search,
+};
+
+impl ElfService {
+	/// Returns the graph facts about one entity that were valid at `as_of`.
+	///
+	/// Unlike `graph_query`, facts that have since been superseded stay visible together with
+	/// their replacement fact ids, and their evidence may come from notes deprecated after the
+	/// fact was recorded. This answers questions such as who owned a service last quarter.
+	pub async fn graph_facts_as_of(
+		&self,
+		req: GraphFactsAsOfRequest,
+	) -> Result<GraphFactsAsOfResponse> {
+		self.ensure_subsystem(self.subsystems.graph, "graph")?;
+
+		let PreparedGraphFactsAsOf {
+			tenant_id,
+			project_id,
+			agent_id,
+			read_profile,
+			entity,
+			as_of,
+			predicate,
+			requested_scopes,
+			include_superseded,
+			limit,
+		} = validation::validate_graph_facts_as_of_request(req)?;
+		let allowed_scopes = search::resolve_read_profile_scopes(&self.cfg, read_profile.as_str())?;
+		let effective_scopes =
+			graph_query::resolve_effective_scopes(&allowed_scopes, requested_scopes.as_slice())?;
+		let org_shared_allowed = allowed_scopes.iter().any(|scope| scope.trim() == "org_shared");
+		let mut conn = self.db.pool.acquire().await?;
+		let entity =
+			graph_query::resolve_graph_entity(&mut conn, &tenant_id, &project_id, entity).await?;
+		let predicate = match predicate {
+			Some(predicate) => Some(
+				graph_query::resolve_graph_predicate(&mut conn, &tenant_id, &project_id, predicate)
+					.await?,
+			),
+			None => None,
+		};
+		let shared_scope_keys: Vec<String> = access::load_shared_read_grants_with_org_shared(
+			conn.as_mut(),
+			tenant_id.as_str(),
+			project_id.as_str(),
+			agent_id.as_str(),
+			org_shared_allowed,
+		)
+		.await?
+		.into_iter()
+		.map(|item| format!("{}:{}", item.scope, item.space_owner_agent_id))
+		.collect();
+		let rows = storage::fetch_graph_facts_as_of_rows(
+			&mut conn,
+			GraphFactsAsOfRowsFetchParams {
+				tenant_id: tenant_id.as_str(),
+				project_id: project_id.as_str(),
+				entity_id: entity.entity_id,
+				scopes: effective_scopes.as_slice(),
+				as_of,
+				actor: agent_id.as_str(),
+				shared_scope_keys: shared_scope_keys.as_slice(),
+				predicate_id: predicate.as_ref().map(|predicate| predicate.predicate_id),
+				include_superseded,
+				limit_plus_one: limit as i64 + 1,
+			},
+		)
+		.await?;
+		let truncated = rows.len() > limit;
+		let read_at = OffsetDateTime::now_utc();
+		let facts = rows.into_iter().take(limit).map(|row| fact_from_row(row, read_at)).collect();
+
+		Ok(GraphFactsAsOfResponse {
+			as_of,
+			entity,
+			predicate,
+			scopes: effective_scopes,
+			include_superseded,
+			truncated,
+			facts,
+		})
+	}
+}
+
+pub(super) fn fact_from_row(row: GraphFactAsOfRow, read_at: OffsetDateTime) -> GraphFactAsOf {
+	let object_entity = match (row.object_entity_id, row.object_canonical) {
+		(Some(entity_id), Some(canonical)) =>
+			Some(GraphQueryObjectEntity { entity_id, canonical, kind: row.object_kind }),
+		_ => None,
+	};
+
+	GraphFactAsOf {
+		fact_id: row.fact_id,
+		scope: row.scope,
+		actor: row.actor,
+		subject: GraphQueryEntity {
+			entity_id: row.subject_entity_id,
+			canonical: row.subject_canonical,
+			kind: row.subject_kind,
+		},
+		predicate: row.predicate,
+		predicate_id: row.predicate_id,
+		object: GraphQueryObject {
+			value: if object_entity.is_some() { None } else { row.object_value },
+			entity: object_entity,
+		},
+		valid_from: row.valid_from,
+		valid_to: row.valid_to,
+		temporal_status: graph::relation_temporal_status(row.valid_from, row.valid_to, read_at),
+		superseded_at: row.superseded_at,
+		superseded_by_fact_ids: row.superseded_by_fact_ids,
+		evidence_note_ids: row.evidence_note_ids,
+	}
+}
@@ -0,0 +1,75 @@
+use crate::graph_facts_as_of::{
+	FromRow, GRAPH_FACTS_AS_OF_EVIDENCE_LIMIT, GRAPH_FACTS_AS_OF_SQL, ORG_PROJECT_ID,
+	OffsetDateTime, PgConnection, Result, Uuid,
+};
+
+#[derive(Debug)]
+pub(super) struct GraphFactsAsOfRowsFetchParams<'a> {
+	pub(super) tenant_id: &'a str,
+	pub(super) project_id: &'a str,
+	pub(super) entity_id: Uuid,
+	pub(super) scopes: &'a [String],
+	pub(super) as_of: OffsetDateTime,
+	pub(super) actor: &'a str,
+	pub(super) shared_scope_keys: &'a [String],
+	pub(super) predicate_id: Option<Uuid>,
+	pub(super) include_superseded: bool,
+	pub(super) limit_plus_one: i64,
+}
+
+#[derive(Debug, FromRow)]
+pub(super) struct GraphFactAsOfRow {
+	pub(super) fact_id: Uuid,
+	pub(super) scope: String,
+	pub(super) actor: String,
+	pub(super) subject_entity_id: Uuid,
+	pub(super) subject_canonical: String,
+	pub(super) subject_kind: Option<String>,
+	pub(super) predicate: String,
+	pub(super) predicate_id: Option<Uuid>,
+	pub(super) object_entity_id: Option<Uuid>,
+	pub(super) object_canonical: Option<String>,
+	pub(super) object_kind: Option<String>,
+	pub(super) object_value: Option<String>,
+	pub(super) valid_from: OffsetDateTime,
+	pub(super) valid_to: Option<OffsetDateTime>,
+	pub(super) superseded_at: Option<OffsetDateTime>,
+	pub(super) superseded_by_fact_ids: Vec<Uuid>,
+	pub(super) evidence_note_ids: Vec<Uuid>,
+}
+
+/// Loads facts valid at `as_of` that mention the entity as subject or object.
+pub(super) async fn fetch_graph_facts_as_of_rows(
+	conn: &mut PgConnection,
+	params: GraphFactsAsOfRowsFetchParams<'_>,
+) -> Result<Vec<GraphFactAsOfRow>> {
+	let GraphFactsAsOfRowsFetchParams {
+		tenant_id,
+		project_id,
+		entity_id,
+		scopes,
+		as_of,
+		actor,
+		shared_scope_keys,
+		predicate_id,
+		include_superseded,
+		limit_plus_one,
+	} = params;
+	let rows = sqlx::query_as::<_, GraphFactAsOfRow>(GRAPH_FACTS_AS_OF_SQL)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(entity_id)
+		.bind(scopes)
+		.bind(as_of)
+		.bind(actor)
+		.bind(shared_scope_keys)
+		.bind(limit_plus_one)
+		.bind(GRAPH_FACTS_AS_OF_EVIDENCE_LIMIT)
+		.bind(ORG_PROJECT_ID)
+		.bind(predicate_id)
+		.bind(include_superseded)
+		.fetch_all(conn)
+		.await?;
+
+	Ok(rows)
+}
@@ -0,0 +1,85 @@
+use time::Duration;
+use uuid::Uuid;
+
+use crate::{
+	Error,
+	graph_facts_as_of::{
+		GraphFactAsOfRow, GraphFactsAsOfRequest, GraphQueryEntityRef, OffsetDateTime,
+		RelationTemporalStatus, service, validation,
+	},
+};
+
+fn base_request() -> GraphFactsAsOfRequest {
+	GraphFactsAsOfRequest {
+		tenant_id: "tenant".to_string(),
+		project_id: "project".to_string(),
+		agent_id: "agent".to_string(),
+		read_profile: "private_plus_project".to_string(),
+		entity: GraphQueryEntityRef::Surface { surface: " billing-service ".to_string() },
+		as_of: OffsetDateTime::UNIX_EPOCH,
+		predicate: None,
+		scopes: None,
+		include_superseded: None,
+		limit: None,
+	}
+}
+
+#[test]
+fn validation_defaults_to_including_superseded_facts() {
+	let prepared = validation::validate_graph_facts_as_of_request(base_request())
+		.expect("default request should validate");
+
+	assert!(prepared.include_superseded);
+	assert_eq!(prepared.limit, 50);
+	assert_eq!(prepared.as_of, OffsetDateTime::UNIX_EPOCH);
+	assert!(matches!(
+		prepared.entity,
+		GraphQueryEntityRef::Surface { ref surface } if surface == "billing-service"
+	));
+
+	for limit in [0, 201] {
+		let err = validation::validate_graph_facts_as_of_request(GraphFactsAsOfRequest {
+			limit: Some(limit),
+			..base_request()
+		})
+		.expect_err("out-of-range limit should fail");
+
+		assert!(matches!(err, Error::InvalidRequest { .. }));
+	}
+}
+
+#[test]
+fn superseded_fact_reports_replacement_and_historical_status() {
+	let valid_from = OffsetDateTime::UNIX_EPOCH;
+	let superseded_at = valid_from + Duration::days(90);
+	let replacement = Uuid::new_v4();
+	let owner = Uuid::new_v4();
+	let fact = service::fact_from_row(
+		GraphFactAsOfRow {
+			fact_id: Uuid::new_v4(),
+			scope: "project_shared".to_string(),
+			actor: "agent".to_string(),
+			subject_entity_id: Uuid::new_v4(),
+			subject_canonical: "billing-service".to_string(),
+			subject_kind: None,
+			predicate: "owned_by".to_string(),
+			predicate_id: None,
+			object_entity_id: Some(owner),
+			object_canonical: Some("alice".to_string()),
+			object_kind: Some("person".to_string()),
+			object_value: None,
+			valid_from,
+			valid_to: Some(superseded_at),
+			superseded_at: Some(superseded_at),
+			superseded_by_fact_ids: vec![replacement],
+			evidence_note_ids: vec![Uuid::new_v4()],
+		},
+		superseded_at + Duration::days(1),
+	);
+
+	assert_eq!(fact.temporal_status, RelationTemporalStatus::Historical);
+	assert_eq!(fact.superseded_at, Some(superseded_at));
+	assert_eq!(fact.superseded_by_fact_ids, vec![replacement]);
+	assert_eq!(fact.object.entity.as_ref().map(|entity| entity.entity_id), Some(owner));
+	assert!(fact.object.value.is_none());
+}
@@ -0,0 +1,86 @@
+use crate::graph_facts_as_of::{
+	Deserialize, GraphQueryEntity, GraphQueryEntityRef, GraphQueryObject, GraphQueryPredicate,
+	GraphQueryPredicateRef, OffsetDateTime, RelationTemporalStatus, Serialize, Uuid,
+};
+
+/// Request payload for point-in-time graph fact reads.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GraphFactsAsOfRequest {
+	/// Tenant to query within.
+	pub tenant_id: String,
+	/// Project to query within.
+	pub project_id: String,
+	/// Agent requesting the read.
+	pub agent_id: String,
+	/// Read profile that determines visible scopes.
+	pub read_profile: String,
+	/// Entity whose facts are returned, matched as subject or object.
+	pub entity: GraphQueryEntityRef,
+	#[serde(with = "crate::time_serde")]
+	/// Point in time the facts must have been valid at.
+	pub as_of: OffsetDateTime,
+	/// Optional predicate selector used to narrow the results.
+	pub predicate: Option<GraphQueryPredicateRef>,
+	/// Optional requested scopes.
+	pub scopes: Option<Vec<String>>,
+	/// When false, drops facts that were later superseded; defaults to true.
+	pub include_superseded: Option<bool>,
+	/// Optional maximum number of returned facts.
+	pub limit: Option<u32>,
+}
+
+/// Response payload for point-in-time graph fact reads.
+#[derive(Clone, Debug, Serialize)]
+pub struct GraphFactsAsOfResponse {
+	#[serde(with = "crate::time_serde")]
+	/// Point in time the facts were valid at.
+	pub as_of: OffsetDateTime,
+	/// Resolved entity.
+	pub entity: GraphQueryEntity,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Resolved predicate, when the request filtered by predicate.
+	pub predicate: Option<GraphQueryPredicate>,
+	/// Effective scopes used for the query.
+	pub scopes: Vec<String>,
+	/// Whether superseded facts were eligible.
+	pub include_superseded: bool,
+	/// Whether the result set was truncated by the limit.
+	pub truncated: bool,
+	/// Facts valid at `as_of`, newest first.
+	pub facts: Vec<GraphFactAsOf>,
+}
+
+/// One graph fact as it stood at the requested point in time.
+#[derive(Clone, Debug, Serialize)]
+pub struct GraphFactAsOf {
+	/// Fact identifier.
+	pub fact_id: Uuid,
+	/// Scope key for the fact.
+	pub scope: String,
+	/// Agent that emitted the fact.
+	pub actor: String,
+	/// Subject entity of the fact.
+	pub subject: GraphQueryEntity,
+	/// Predicate surface recorded on the fact.
+	pub predicate: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Resolved predicate identifier, when available.
+	pub predicate_id: Option<Uuid>,
+	/// Object payload for the fact.
+	pub object: GraphQueryObject,
+	#[serde(with = "crate::time_serde")]
+	/// Start of the fact validity window.
+	pub valid_from: OffsetDateTime,
+	#[serde(with = "crate::time_serde::option")]
+	/// End of the fact validity window, if superseded or explicitly bounded.
+	pub valid_to: Option<OffsetDateTime>,
+	/// Temporal state for the fact relative to the service read timestamp.
+	pub temporal_status: RelationTemporalStatus,
+	#[serde(with = "crate::time_serde::option")]
+	/// Earliest time a replacement fact took effect, if the fact was superseded.
+	pub superseded_at: Option<OffsetDateTime>,
+	/// Replacement fact ids that supersede this fact.
+	pub superseded_by_fact_ids: Vec<Uuid>,
+	/// Evidence note identifiers supporting the fact.
+	pub evidence_note_ids: Vec<Uuid>,
+}
@@ -0,0 +1,93 @@
+use crate::graph_facts_as_of::{
+	DEFAULT_GRAPH_FACTS_AS_OF_LIMIT, Error, GraphFactsAsOfRequest, GraphQueryEntityRef,
+	GraphQueryPredicateRef, HashSet, MAX_GRAPH_FACTS_AS_OF_LIMIT, OffsetDateTime, Result,
+};
+
+#[derive(Debug)]
+pub(super) struct PreparedGraphFactsAsOf {
+	pub(super) tenant_id: String,
+	pub(super) project_id: String,
+	pub(super) agent_id: String,
+	pub(super) read_profile: String,
+	pub(super) entity: GraphQueryEntityRef,
+	pub(super) as_of: OffsetDateTime,
+	pub(super) predicate: Option<GraphQueryPredicateRef>,
+	pub(super) requested_scopes: Vec<String>,
+	pub(super) include_superseded: bool,
+	pub(super) limit: usize,
+}
+
+pub(super) fn validate_graph_facts_as_of_request(
+	req: GraphFactsAsOfRequest,
+) -> Result<PreparedGraphFactsAsOf> {
+	let tenant_id = normalize_required_field(req.tenant_id.as_str(), "tenant_id")?;
+	let project_id = normalize_required_field(req.project_id.as_str(), "project_id")?;
+	let agent_id = normalize_required_field(req.agent_id.as_str(), "agent_id")?;
+	let read_profile = normalize_required_field(req.read_profile.as_str(), "read_profile")?;
+	let entity = match req.entity {
+		GraphQueryEntityRef::EntityId { entity_id } => GraphQueryEntityRef::EntityId { entity_id },
+		GraphQueryEntityRef::Surface { surface } => GraphQueryEntityRef::Surface {
+			surface: normalize_required_field(surface.as_str(), "entity.surface")?,
+		},
+	};
+	let predicate = match req.predicate {
+		Some(GraphQueryPredicateRef::PredicateId { predicate_id }) =>
+			Some(GraphQueryPredicateRef::PredicateId { predicate_id }),
+		Some(GraphQueryPredicateRef::Surface { surface }) =>
+			Some(GraphQueryPredicateRef::Surface {
+				surface: normalize_required_field(surface.as_str(), "predicate.surface")?,
+			}),
+		None => None,
+	};
+	let requested_scopes = normalize_scopes(req.scopes)?;
+	let limit = req.limit.unwrap_or(DEFAULT_GRAPH_FACTS_AS_OF_LIMIT);
+
+	if !matches!(limit, 1..=MAX_GRAPH_FACTS_AS_OF_LIMIT) {
+		return Err(Error::InvalidRequest {
+			message: format!("limit must be between 1 and {MAX_GRAPH_FACTS_AS_OF_LIMIT}."),
+		});
+	}
+
+	Ok(PreparedGraphFactsAsOf {
+		tenant_id,
+		project_id,
+		agent_id,
+		read_profile,
+		entity,
+		as_of: req.as_of,
+		predicate,
+		requested_scopes,
+		include_superseded: req.include_superseded.unwrap_or(true),
+		limit: limit as usize,
+	})
+}
+
+fn normalize_required_field(value: &str, field: &str) -> Result<String> {
+	let trimmed = value.trim();
+
+	if trimmed.is_empty() {
+		return Err(Error::InvalidRequest { message: format!("{field} is required.") });
+	}
+
+	Ok(trimmed.to_string())
+}
+
+fn normalize_scopes(scopes: Option<Vec<String>>) -> Result<Vec<String>> {
+	let mut seen = HashSet::new();
+	let mut normalized = Vec::new();
+
+	for scope in scopes.unwrap_or_default() {
+		let scope = scope.trim().to_string();
+
+		if scope.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "scopes entries must be non-empty strings.".to_string(),
+			});
+		}
+		if seen.insert(scope.clone()) {
+			normalized.push(scope);
+		}
+	}
+
+	Ok(normalized)
+}
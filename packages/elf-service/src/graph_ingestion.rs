@@ -154,10 +154,18 @@ async fn upsert_graph_entity_and_aliases(
 		message: format!("{context_path}.canonical is required."),
 	})?;
 	let canonical = canonical.trim();
-	let entity_id =
-		graph::upsert_entity(tx, tenant_id, project_id, canonical, entity.kind.as_deref())
-			.await
-			.map_err(|err| Error::Storage { message: err.to_string() })?;
+	let kind = match entity.kind.as_deref().map(str::trim).filter(|kind| !kind.is_empty()) {
+		Some(kind) => Some(
+			graph::resolve_or_register_entity_kind(tx, tenant_id, project_id, kind)
+				.await
+				.map_err(|err| Error::Storage { message: err.to_string() })?
+				.kind,
+		),
+		None => None,
+	};
+	let entity_id = graph::upsert_entity(tx, tenant_id, project_id, canonical, kind.as_deref())
+		.await
+		.map_err(|err| Error::Storage { message: err.to_string() })?;
 
 	if let Some(aliases) = entity.aliases.as_ref() {
 		for (alias_idx, alias) in aliases.iter().enumerate() {
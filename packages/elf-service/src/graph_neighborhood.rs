@@ -0,0 +1,120 @@
+//! Bounded entity-neighborhood traversal over graph facts.
+
+mod service;
+mod storage;
+mod types;
+mod validation;
+
+pub use types::{
+	GraphNeighborhoodEntity, GraphNeighborhoodRequest, GraphNeighborhoodResponse,
+	GraphNeighborhoodTriple,
+};
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	access::ORG_PROJECT_ID,
+	graph::RelationTemporalStatus,
+	graph_query::{
+		GraphQueryEntity, GraphQueryEntityRef, GraphQueryObject, GraphQueryObjectEntity,
+		GraphQueryPredicate, GraphQueryPredicateRef,
+	},
+};
+use storage::{GraphNeighborhoodFactRow, GraphNeighborhoodRowsFetchParams};
+use validation::PreparedGraphNeighborhood;
+
+const DEFAULT_GRAPH_NEIGHBORHOOD_DEPTH: u32 = 1;
+const MAX_GRAPH_NEIGHBORHOOD_DEPTH: u32 = 3;
+const DEFAULT_GRAPH_NEIGHBORHOOD_LIMIT: u32 = 50;
+const MAX_GRAPH_NEIGHBORHOOD_LIMIT: u32 = 200;
+const MAX_GRAPH_NEIGHBORHOOD_PREDICATES: usize = 16;
+const GRAPH_NEIGHBORHOOD_EVIDENCE_LIMIT: i64 = 16;
+const GRAPH_NEIGHBORHOOD_FACTS_SQL: &str = "\
+SELECT
+	gf.fact_id,
+	gf.scope,
+	gf.agent_id AS actor,
+	gf.subject_entity_id,
+	subject_entity.canonical AS subject_canonical,
+	subject_entity.kind AS subject_kind,
+	gf.predicate,
+	gf.predicate_id,
+	gf.object_entity_id,
+	object_entity.canonical AS object_canonical,
+	object_entity.kind AS object_kind,
+	gf.object_value,
+	gf.valid_from,
+	gf.valid_to,
+	COALESCE(
+		(SELECT ARRAY_AGG(e.note_id ORDER BY e.created_at ASC, e.note_id ASC)
+		 FROM (
+		 	SELECT evidence.note_id, evidence.created_at
+		 	FROM graph_fact_evidence evidence
+			JOIN memory_notes note ON note.note_id = evidence.note_id
+		 	WHERE evidence.fact_id = gf.fact_id
+				AND note.tenant_id = gf.tenant_id
+				AND note.project_id = gf.project_id
+				AND note.status = 'active'
+				AND (note.expires_at IS NULL OR note.expires_at > now())
+				AND note.scope = ANY($4::text[])
+				AND (
+					(note.scope = 'agent_private' AND note.agent_id = $6)
+					OR (note.scope <> 'agent_private' AND (
+						note.agent_id = $6 OR (note.scope || ':' || note.agent_id) = ANY($7::text[])
+					))
+				)
+		 	ORDER BY evidence.created_at ASC, evidence.note_id ASC
+		 	LIMIT $9
+		 ) e),
+		'{}'::uuid[]
+	) AS evidence_note_ids
+FROM graph_facts AS gf
+JOIN graph_entities AS subject_entity
+	ON subject_entity.entity_id = gf.subject_entity_id
+	AND subject_entity.tenant_id = gf.tenant_id
+	AND subject_entity.project_id = gf.project_id
+LEFT JOIN graph_entities AS object_entity
+	ON object_entity.entity_id = gf.object_entity_id
+	AND object_entity.tenant_id = gf.tenant_id
+	AND object_entity.project_id = gf.project_id
+WHERE gf.tenant_id = $1
+	AND (gf.project_id = $2 OR (gf.project_id = $10 AND gf.scope = 'org_shared'))
+	AND (gf.subject_entity_id = ANY($3::uuid[]) OR gf.object_entity_id = ANY($3::uuid[]))
+	AND NOT (gf.fact_id = ANY($12::uuid[]))
+	AND gf.scope = ANY($4::text[])
+	AND gf.valid_from <= $5
+	AND (gf.valid_to IS NULL OR gf.valid_to > $5)
+	AND (cardinality($11::uuid[]) = 0 OR gf.predicate_id = ANY($11::uuid[]))
+	AND (
+		(gf.scope = 'agent_private' AND gf.agent_id = $6)
+		OR (gf.scope <> 'agent_private' AND (
+			gf.agent_id = $6 OR (gf.scope || ':' || gf.agent_id) = ANY($7::text[])
+		))
+	)
+	AND EXISTS (
+		SELECT 1
+		FROM graph_fact_evidence evidence
+		JOIN memory_notes note ON note.note_id = evidence.note_id
+		WHERE evidence.fact_id = gf.fact_id
+			AND note.tenant_id = gf.tenant_id
+			AND note.project_id = gf.project_id
+			AND note.status = 'active'
+			AND (note.expires_at IS NULL OR note.expires_at > now())
+			AND note.scope = ANY($4::text[])
+			AND (
+				(note.scope = 'agent_private' AND note.agent_id = $6)
+				OR (note.scope <> 'agent_private' AND (
+					note.agent_id = $6 OR (note.scope || ':' || note.agent_id) = ANY($7::text[])
+				))
+			)
+	)
+	ORDER BY gf.valid_from DESC, gf.fact_id ASC
+	LIMIT $8";
+
+#[cfg(test)] mod tests;
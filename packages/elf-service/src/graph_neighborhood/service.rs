@@ -0,0 +1,232 @@
+use crate::{
+	access, graph,
+	graph_neighborhood::{
+		ElfService, GraphNeighborhoodEntity, GraphNeighborhoodFactRow, GraphNeighborhoodRequest,
+		GraphNeighborhoodResponse, GraphNeighborhoodRowsFetchParams, GraphNeighborhoodTriple,
+		GraphQueryEntity, GraphQueryObject, GraphQueryObjectEntity, GraphQueryPredicate, HashSet,
+		OffsetDateTime, PreparedGraphNeighborhood, Result, Uuid, storage, validation,
+	},
+	graph_query, search,
+};
+
+impl ElfService {
+	/// Walks graph facts outward from one entity and returns bounded subject/predicate/object
+	/// triples with their evidence note ids.
+	///
+	/// Each hop follows facts that touch a frontier entity as subject or object, so the
+	/// neighborhood is undirected. Facts are filtered by scope, predicate, temporal validity at
+	/// `as_of`, and active readable evidence exactly like `graph_query`.
+	pub async fn graph_neighborhood(
+		&self,
+		req: GraphNeighborhoodRequest,
+	) -> Result<GraphNeighborhoodResponse> {
+		self.ensure_subsystem(self.subsystems.graph, "graph")?;
+
+		let PreparedGraphNeighborhood {
+			tenant_id,
+			project_id,
+			agent_id,
+			read_profile,
+			entity,
+			depth,
+			predicates,
+			requested_scopes,
+			as_of,
+			limit,
+		} = validation::validate_graph_neighborhood_request(req)?;
+		let allowed_scopes = search::resolve_read_profile_scopes(&self.cfg, read_profile.as_str())?;
+		let effective_scopes =
+			graph_query::resolve_effective_scopes(&allowed_scopes, requested_scopes.as_slice())?;
+		let org_shared_allowed = allowed_scopes.iter().any(|scope| scope.trim() == "org_shared");
+		let mut conn = self.db.pool.acquire().await?;
+		let root =
+			graph_query::resolve_graph_entity(&mut conn, &tenant_id, &project_id, entity).await?;
+		let mut resolved_predicates = Vec::with_capacity(predicates.len());
+
+		for predicate in predicates {
+			let predicate =
+				graph_query::resolve_graph_predicate(&mut conn, &tenant_id, &project_id, predicate)
+					.await?;
+
+			if !resolved_predicates.iter().any(|resolved: &GraphQueryPredicate| {
+				resolved.predicate_id == predicate.predicate_id
+			}) {
+				resolved_predicates.push(predicate);
+			}
+		}
+
+		let predicate_ids: Vec<Uuid> =
+			resolved_predicates.iter().map(|predicate| predicate.predicate_id).collect();
+		let shared_scope_keys: Vec<String> = access::load_shared_read_grants_with_org_shared(
+			conn.as_mut(),
+			tenant_id.as_str(),
+			project_id.as_str(),
+			agent_id.as_str(),
+			org_shared_allowed,
+		)
+		.await?
+		.into_iter()
+		.map(|item| format!("{}:{}", item.scope, item.space_owner_agent_id))
+		.collect();
+		let read_at = OffsetDateTime::now_utc();
+		let mut traversal = NeighborhoodTraversal::new(&root, limit);
+		let mut frontier = vec![root.entity_id];
+
+		for hop in 1..=depth {
+			let rows = storage::fetch_graph_neighborhood_rows(
+				&mut conn,
+				GraphNeighborhoodRowsFetchParams {
+					tenant_id: tenant_id.as_str(),
+					project_id: project_id.as_str(),
+					frontier: frontier.as_slice(),
+					scopes: effective_scopes.as_slice(),
+					as_of,
+					actor: agent_id.as_str(),
+					shared_scope_keys: shared_scope_keys.as_slice(),
+					predicate_ids: predicate_ids.as_slice(),
+					seen_fact_ids: traversal.seen_fact_ids.as_slice(),
+					limit_plus_one: traversal.remaining() as i64 + 1,
+				},
+			)
+			.await?;
+
+			frontier = traversal.expand(rows, hop, read_at);
+
+			if traversal.truncated || frontier.is_empty() {
+				break;
+			}
+		}
+
+		Ok(GraphNeighborhoodResponse {
+			as_of,
+			entity: root,
+			depth,
+			predicates: resolved_predicates,
+			scopes: effective_scopes,
+			truncated: traversal.truncated,
+			entities: traversal.entities,
+			triples: traversal.triples,
+		})
+	}
+}
+
+/// Breadth-first traversal state shared across hops.
+pub(super) struct NeighborhoodTraversal {
+	pub(super) entities: Vec<GraphNeighborhoodEntity>,
+	pub(super) triples: Vec<GraphNeighborhoodTriple>,
+	pub(super) seen_fact_ids: Vec<Uuid>,
+	pub(super) truncated: bool,
+	visited: HashSet<Uuid>,
+	limit: usize,
+}
+impl NeighborhoodTraversal {
+	pub(super) fn new(root: &GraphQueryEntity, limit: usize) -> Self {
+		Self {
+			entities: vec![GraphNeighborhoodEntity {
+				entity_id: root.entity_id,
+				canonical: root.canonical.clone(),
+				kind: root.kind.clone(),
+				hop: 0,
+			}],
+			triples: Vec::new(),
+			seen_fact_ids: Vec::new(),
+			truncated: false,
+			visited: HashSet::from([root.entity_id]),
+			limit,
+		}
+	}
+
+	pub(super) fn remaining(&self) -> usize {
+		self.limit.saturating_sub(self.triples.len())
+	}
+
+	/// Records one hop of fact rows and returns the entities first reached at this hop.
+	pub(super) fn expand(
+		&mut self,
+		rows: Vec<GraphNeighborhoodFactRow>,
+		hop: u32,
+		read_at: OffsetDateTime,
+	) -> Vec<Uuid> {
+		let remaining = self.remaining();
+		let mut next_frontier = Vec::new();
+
+		if rows.len() > remaining {
+			self.truncated = true;
+		}
+
+		for row in rows.into_iter().take(remaining) {
+			let subject = GraphQueryEntity {
+				entity_id: row.subject_entity_id,
+				canonical: row.subject_canonical,
+				kind: row.subject_kind,
+			};
+			let object_entity = match (row.object_entity_id, row.object_canonical) {
+				(Some(entity_id), Some(canonical)) =>
+					Some(GraphQueryObjectEntity { entity_id, canonical, kind: row.object_kind }),
+				_ => None,
+			};
+
+			self.visit(
+				subject.entity_id,
+				&subject.canonical,
+				&subject.kind,
+				hop,
+				&mut next_frontier,
+			);
+
+			if let Some(object) = object_entity.as_ref() {
+				self.visit(
+					object.entity_id,
+					&object.canonical,
+					&object.kind,
+					hop,
+					&mut next_frontier,
+				);
+			}
+
+			self.seen_fact_ids.push(row.fact_id);
+			self.triples.push(GraphNeighborhoodTriple {
+				fact_id: row.fact_id,
+				hop,
+				scope: row.scope,
+				actor: row.actor,
+				subject,
+				predicate: row.predicate,
+				predicate_id: row.predicate_id,
+				object: GraphQueryObject {
+					value: if object_entity.is_some() { None } else { row.object_value },
+					entity: object_entity,
+				},
+				valid_from: row.valid_from,
+				valid_to: row.valid_to,
+				temporal_status: graph::relation_temporal_status(
+					row.valid_from,
+					row.valid_to,
+					read_at,
+				),
+				evidence_note_ids: row.evidence_note_ids,
+			});
+		}
+
+		next_frontier
+	}
+
+	fn visit(
+		&mut self,
+		entity_id: Uuid,
+		canonical: &str,
+		kind: &Option<String>,
+		hop: u32,
+		next_frontier: &mut Vec<Uuid>,
+	) {
+		if self.visited.insert(entity_id) {
+			self.entities.push(GraphNeighborhoodEntity {
+				entity_id,
+				canonical: canonical.to_string(),
+				kind: kind.clone(),
+				hop,
+			});
+			next_frontier.push(entity_id);
+		}
+	}
+}
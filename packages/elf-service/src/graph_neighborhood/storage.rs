@@ -0,0 +1,73 @@
+use crate::graph_neighborhood::{
+	FromRow, GRAPH_NEIGHBORHOOD_EVIDENCE_LIMIT, GRAPH_NEIGHBORHOOD_FACTS_SQL, ORG_PROJECT_ID,
+	OffsetDateTime, PgConnection, Result, Uuid,
+};
+
+#[derive(Debug)]
+pub(super) struct GraphNeighborhoodRowsFetchParams<'a> {
+	pub(super) tenant_id: &'a str,
+	pub(super) project_id: &'a str,
+	pub(super) frontier: &'a [Uuid],
+	pub(super) scopes: &'a [String],
+	pub(super) as_of: OffsetDateTime,
+	pub(super) actor: &'a str,
+	pub(super) shared_scope_keys: &'a [String],
+	pub(super) predicate_ids: &'a [Uuid],
+	pub(super) seen_fact_ids: &'a [Uuid],
+	pub(super) limit_plus_one: i64,
+}
+
+#[derive(Debug, FromRow)]
+pub(super) struct GraphNeighborhoodFactRow {
+	pub(super) fact_id: Uuid,
+	pub(super) scope: String,
+	pub(super) actor: String,
+	pub(super) subject_entity_id: Uuid,
+	pub(super) subject_canonical: String,
+	pub(super) subject_kind: Option<String>,
+	pub(super) predicate: String,
+	pub(super) predicate_id: Option<Uuid>,
+	pub(super) object_entity_id: Option<Uuid>,
+	pub(super) object_canonical: Option<String>,
+	pub(super) object_kind: Option<String>,
+	pub(super) object_value: Option<String>,
+	pub(super) valid_from: OffsetDateTime,
+	pub(super) valid_to: Option<OffsetDateTime>,
+	pub(super) evidence_note_ids: Vec<Uuid>,
+}
+
+/// Loads visible facts that touch any frontier entity as subject or object.
+pub(super) async fn fetch_graph_neighborhood_rows(
+	conn: &mut PgConnection,
+	params: GraphNeighborhoodRowsFetchParams<'_>,
+) -> Result<Vec<GraphNeighborhoodFactRow>> {
+	let GraphNeighborhoodRowsFetchParams {
+		tenant_id,
+		project_id,
+		frontier,
+		scopes,
+		as_of,
+		actor,
+		shared_scope_keys,
+		predicate_ids,
+		seen_fact_ids,
+		limit_plus_one,
+	} = params;
+	let rows = sqlx::query_as::<_, GraphNeighborhoodFactRow>(GRAPH_NEIGHBORHOOD_FACTS_SQL)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(frontier)
+		.bind(scopes)
+		.bind(as_of)
+		.bind(actor)
+		.bind(shared_scope_keys)
+		.bind(limit_plus_one)
+		.bind(GRAPH_NEIGHBORHOOD_EVIDENCE_LIMIT)
+		.bind(ORG_PROJECT_ID)
+		.bind(predicate_ids)
+		.bind(seen_fact_ids)
+		.fetch_all(conn)
+		.await?;
+
+	Ok(rows)
+}
@@ -0,0 +1,110 @@
+use uuid::Uuid;
+
+use crate::{
+	Error,
+	graph_neighborhood::{
+		GraphNeighborhoodFactRow, GraphNeighborhoodRequest, GraphQueryEntity, GraphQueryEntityRef,
+		GraphQueryPredicateRef, OffsetDateTime, service::NeighborhoodTraversal, validation,
+	},
+};
+
+fn base_request() -> GraphNeighborhoodRequest {
+	GraphNeighborhoodRequest {
+		tenant_id: "tenant".to_string(),
+		project_id: "project".to_string(),
+		agent_id: "agent".to_string(),
+		read_profile: "private_plus_project".to_string(),
+		entity: GraphQueryEntityRef::Surface { surface: " Alice ".to_string() },
+		depth: None,
+		predicates: None,
+		scopes: None,
+		as_of: None,
+		limit: None,
+	}
+}
+
+fn fact_row(subject: Uuid, object: Option<Uuid>) -> GraphNeighborhoodFactRow {
+	GraphNeighborhoodFactRow {
+		fact_id: Uuid::new_v4(),
+		scope: "agent_private".to_string(),
+		actor: "agent".to_string(),
+		subject_entity_id: subject,
+		subject_canonical: format!("entity-{subject}"),
+		subject_kind: None,
+		predicate: "mentors".to_string(),
+		predicate_id: None,
+		object_entity_id: object,
+		object_canonical: object.map(|object| format!("entity-{object}")),
+		object_kind: None,
+		object_value: object.is_none().then(|| "scalar".to_string()),
+		valid_from: OffsetDateTime::UNIX_EPOCH,
+		valid_to: None,
+		evidence_note_ids: vec![Uuid::new_v4()],
+	}
+}
+
+#[test]
+fn validation_applies_defaults_and_bounds() {
+	let prepared = validation::validate_graph_neighborhood_request(base_request())
+		.expect("default request should validate");
+
+	assert_eq!(prepared.depth, 1);
+	assert_eq!(prepared.limit, 50);
+	assert!(matches!(
+		prepared.entity,
+		GraphQueryEntityRef::Surface { ref surface } if surface == "Alice"
+	));
+
+	for depth in [0, 4] {
+		let err = validation::validate_graph_neighborhood_request(GraphNeighborhoodRequest {
+			depth: Some(depth),
+			..base_request()
+		})
+		.expect_err("out-of-range depth should fail");
+
+		assert!(matches!(err, Error::InvalidRequest { .. }));
+	}
+
+	let err = validation::validate_graph_neighborhood_request(GraphNeighborhoodRequest {
+		predicates: Some(vec![GraphQueryPredicateRef::Surface { surface: " ".to_string() }]),
+		..base_request()
+	})
+	.expect_err("blank predicate surface should fail");
+
+	assert!(matches!(err, Error::InvalidRequest { message } if message.contains("predicates[0]")));
+}
+
+#[test]
+fn traversal_records_new_entities_once_and_truncates_at_limit() {
+	let root =
+		GraphQueryEntity { entity_id: Uuid::new_v4(), canonical: "Alice".to_string(), kind: None };
+	let bob = Uuid::new_v4();
+	let carol = Uuid::new_v4();
+	let mut traversal = NeighborhoodTraversal::new(&root, 3);
+	let frontier = traversal.expand(
+		vec![fact_row(root.entity_id, Some(bob)), fact_row(root.entity_id, None)],
+		1,
+		OffsetDateTime::UNIX_EPOCH,
+	);
+
+	assert_eq!(frontier, vec![bob]);
+	assert_eq!(traversal.triples.len(), 2);
+	assert_eq!(traversal.triples[1].object.value.as_deref(), Some("scalar"));
+	assert!(!traversal.truncated);
+
+	let frontier = traversal.expand(
+		vec![fact_row(bob, Some(carol)), fact_row(carol, Some(root.entity_id))],
+		2,
+		OffsetDateTime::UNIX_EPOCH,
+	);
+
+	assert_eq!(frontier, vec![carol]);
+	assert!(traversal.truncated);
+	assert_eq!(traversal.triples.len(), 3);
+	assert_eq!(traversal.seen_fact_ids.len(), 3);
+	assert_eq!(
+		traversal.entities.iter().map(|entity| (entity.entity_id, entity.hop)).collect::<Vec<_>>(),
+		vec![(root.entity_id, 0), (bob, 1), (carol, 2)]
+	);
+	assert_eq!(traversal.remaining(), 0);
+}
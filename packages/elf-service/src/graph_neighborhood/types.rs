@@ -0,0 +1,98 @@
+use crate::graph_neighborhood::{
+	Deserialize, GraphQueryEntity, GraphQueryEntityRef, GraphQueryObject, GraphQueryPredicate,
+	GraphQueryPredicateRef, OffsetDateTime, RelationTemporalStatus, Serialize, Uuid,
+};
+
+/// Request payload for entity-neighborhood traversal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GraphNeighborhoodRequest {
+	/// Tenant to query within.
+	pub tenant_id: String,
+	/// Project to query within.
+	pub project_id: String,
+	/// Agent requesting the read.
+	pub agent_id: String,
+	/// Read profile that determines visible scopes.
+	pub read_profile: String,
+	/// Entity the traversal starts from.
+	pub entity: GraphQueryEntityRef,
+	/// Number of hops to traverse, from 1 to 3; defaults to 1.
+	pub depth: Option<u32>,
+	/// Optional predicates that traversed facts must match; empty follows every predicate.
+	pub predicates: Option<Vec<GraphQueryPredicateRef>>,
+	/// Optional requested scopes.
+	pub scopes: Option<Vec<String>>,
+	#[serde(with = "crate::time_serde::option")]
+	/// Point-in-time view for temporal facts.
+	pub as_of: Option<OffsetDateTime>,
+	/// Optional maximum number of returned triples across all hops.
+	pub limit: Option<u32>,
+}
+
+/// Response payload for entity-neighborhood traversal.
+#[derive(Clone, Debug, Serialize)]
+pub struct GraphNeighborhoodResponse {
+	#[serde(with = "crate::time_serde")]
+	/// Effective point-in-time view used for the traversal.
+	pub as_of: OffsetDateTime,
+	/// Resolved start entity.
+	pub entity: GraphQueryEntity,
+	/// Number of hops requested.
+	pub depth: u32,
+	/// Resolved predicate filter; empty when every predicate was followed.
+	pub predicates: Vec<GraphQueryPredicate>,
+	/// Effective scopes used for the traversal.
+	pub scopes: Vec<String>,
+	/// Whether the traversal stopped early because it reached the triple limit.
+	pub truncated: bool,
+	/// Entities reached by the traversal, including the start entity at hop 0.
+	pub entities: Vec<GraphNeighborhoodEntity>,
+	/// Subject/predicate/object triples in traversal order.
+	pub triples: Vec<GraphNeighborhoodTriple>,
+}
+
+/// Entity reached by a neighborhood traversal.
+#[derive(Clone, Debug, Serialize)]
+pub struct GraphNeighborhoodEntity {
+	/// Entity identifier.
+	pub entity_id: Uuid,
+	/// Canonical entity surface.
+	pub canonical: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Optional entity kind.
+	pub kind: Option<String>,
+	/// Number of hops from the start entity.
+	pub hop: u32,
+}
+
+/// One subject/predicate/object triple returned by a neighborhood traversal.
+#[derive(Clone, Debug, Serialize)]
+pub struct GraphNeighborhoodTriple {
+	/// Fact identifier.
+	pub fact_id: Uuid,
+	/// Hop at which the fact was reached, starting at 1.
+	pub hop: u32,
+	/// Scope key for the fact.
+	pub scope: String,
+	/// Agent that emitted the fact.
+	pub actor: String,
+	/// Subject entity.
+	pub subject: GraphQueryEntity,
+	/// Predicate surface recorded on the fact.
+	pub predicate: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Resolved predicate identifier, when available.
+	pub predicate_id: Option<Uuid>,
+	/// Object payload for the fact.
+	pub object: GraphQueryObject,
+	#[serde(with = "crate::time_serde")]
+	/// Start of the fact validity window.
+	pub valid_from: OffsetDateTime,
+	#[serde(with = "crate::time_serde::option")]
+	/// End of the fact validity window, if superseded.
+	pub valid_to: Option<OffsetDateTime>,
+	/// Temporal state for the fact relative to the service read timestamp.
+	pub temporal_status: RelationTemporalStatus,
+	/// Evidence note identifiers supporting the fact.
+	pub evidence_note_ids: Vec<Uuid>,
+}
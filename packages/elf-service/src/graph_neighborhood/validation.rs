@@ -0,0 +1,118 @@
+use crate::graph_neighborhood::{
+	DEFAULT_GRAPH_NEIGHBORHOOD_DEPTH, DEFAULT_GRAPH_NEIGHBORHOOD_LIMIT, Error,
+	GraphNeighborhoodRequest, GraphQueryEntityRef, GraphQueryPredicateRef, HashSet,
+	MAX_GRAPH_NEIGHBORHOOD_DEPTH, MAX_GRAPH_NEIGHBORHOOD_LIMIT, MAX_GRAPH_NEIGHBORHOOD_PREDICATES,
+	OffsetDateTime, Result,
+};
+
+#[derive(Debug)]
+pub(super) struct PreparedGraphNeighborhood {
+	pub(super) tenant_id: String,
+	pub(super) project_id: String,
+	pub(super) agent_id: String,
+	pub(super) read_profile: String,
+	pub(super) entity: GraphQueryEntityRef,
+	pub(super) depth: u32,
+	pub(super) predicates: Vec<GraphQueryPredicateRef>,
+	pub(super) requested_scopes: Vec<String>,
+	pub(super) as_of: OffsetDateTime,
+	pub(super) limit: usize,
+}
+
+pub(super) fn validate_graph_neighborhood_request(
+	req: GraphNeighborhoodRequest,
+) -> Result<PreparedGraphNeighborhood> {
+	let tenant_id = normalize_required_field(req.tenant_id.as_str(), "tenant_id")?;
+	let project_id = normalize_required_field(req.project_id.as_str(), "project_id")?;
+	let agent_id = normalize_required_field(req.agent_id.as_str(), "agent_id")?;
+	let read_profile = normalize_required_field(req.read_profile.as_str(), "read_profile")?;
+	let entity = match req.entity {
+		GraphQueryEntityRef::EntityId { entity_id } => GraphQueryEntityRef::EntityId { entity_id },
+		GraphQueryEntityRef::Surface { surface } => GraphQueryEntityRef::Surface {
+			surface: normalize_required_field(surface.as_str(), "entity.surface")?,
+		},
+	};
+	let depth = req.depth.unwrap_or(DEFAULT_GRAPH_NEIGHBORHOOD_DEPTH);
+
+	if !matches!(depth, 1..=MAX_GRAPH_NEIGHBORHOOD_DEPTH) {
+		return Err(Error::InvalidRequest {
+			message: format!("depth must be between 1 and {MAX_GRAPH_NEIGHBORHOOD_DEPTH}."),
+		});
+	}
+
+	let predicates = req.predicates.unwrap_or_default();
+
+	if predicates.len() > MAX_GRAPH_NEIGHBORHOOD_PREDICATES {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"predicates must contain at most {MAX_GRAPH_NEIGHBORHOOD_PREDICATES} entries."
+			),
+		});
+	}
+
+	let predicates = predicates
+		.into_iter()
+		.enumerate()
+		.map(|(idx, predicate)| match predicate {
+			GraphQueryPredicateRef::PredicateId { predicate_id } =>
+				Ok(GraphQueryPredicateRef::PredicateId { predicate_id }),
+			GraphQueryPredicateRef::Surface { surface } => Ok(GraphQueryPredicateRef::Surface {
+				surface: normalize_required_field(
+					surface.as_str(),
+					format!("predicates[{idx}].surface").as_str(),
+				)?,
+			}),
+		})
+		.collect::<Result<Vec<_>>>()?;
+	let requested_scopes = normalize_scopes(req.scopes)?;
+	let limit = req.limit.unwrap_or(DEFAULT_GRAPH_NEIGHBORHOOD_LIMIT);
+
+	if !matches!(limit, 1..=MAX_GRAPH_NEIGHBORHOOD_LIMIT) {
+		return Err(Error::InvalidRequest {
+			message: format!("limit must be between 1 and {MAX_GRAPH_NEIGHBORHOOD_LIMIT}."),
+		});
+	}
+
+	Ok(PreparedGraphNeighborhood {
+		tenant_id,
+		project_id,
+		agent_id,
+		read_profile,
+		entity,
+		depth,
+		predicates,
+		requested_scopes,
+		as_of: req.as_of.unwrap_or_else(OffsetDateTime::now_utc),
+		limit: limit as usize,
+	})
+}
+
+fn normalize_required_field(value: &str, field: &str) -> Result<String> {
+	let trimmed = value.trim();
+
+	if trimmed.is_empty() {
+		return Err(Error::InvalidRequest { message: format!("{field} is required.") });
+	}
+
+	Ok(trimmed.to_string())
+}
+
+fn normalize_scopes(scopes: Option<Vec<String>>) -> Result<Vec<String>> {
+	let mut seen = HashSet::new();
+	let mut normalized = Vec::new();
+
+	for scope in scopes.unwrap_or_default() {
+		let scope = scope.trim().to_string();
+
+		if scope.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "scopes entries must be non-empty strings.".to_string(),
+			});
+		}
+		if seen.insert(scope.clone()) {
+			normalized.push(scope);
+		}
+	}
+
+	Ok(normalized)
+}
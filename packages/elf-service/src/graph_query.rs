@@ -112,6 +112,36 @@ WHERE gf.tenant_id = $1
 	ORDER BY gf.valid_from DESC, gf.fact_id ASC
 	LIMIT $8";
 
+/// Resolves a subject selector to a stored graph entity.
+pub(crate) async fn resolve_graph_entity(
+	conn: &mut PgConnection,
+	tenant_id: &str,
+	project_id: &str,
+	entity: GraphQueryEntityRef,
+) -> Result<GraphQueryEntity> {
+	let resolved = resolve_subject(conn, tenant_id, project_id, entity).await?;
+
+	Ok(GraphQueryEntity {
+		entity_id: resolved.entity_id,
+		canonical: resolved.canonical,
+		kind: resolved.kind,
+	})
+}
+
+/// Resolves a predicate selector to a stored graph predicate without registering new surfaces.
+pub(crate) async fn resolve_graph_predicate(
+	conn: &mut PgConnection,
+	tenant_id: &str,
+	project_id: &str,
+	predicate: GraphQueryPredicateRef,
+) -> Result<GraphQueryPredicate> {
+	let resolved = resolve_predicate(conn, tenant_id, project_id, Some(predicate))
+		.await?
+		.ok_or_else(|| Error::NotFound { message: "graph predicate not found".to_string() })?;
+
+	Ok(GraphQueryPredicate { predicate_id: resolved.id, canonical: resolved.canonical })
+}
+
 pub(crate) fn resolve_effective_scopes(
 	allowed_scopes: &[String],
 	requested_scopes: &[String],
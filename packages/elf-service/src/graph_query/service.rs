@@ -10,6 +10,8 @@ use crate::{
 impl ElfService {
 	/// Resolves a subject and returns active graph facts visible to the caller.
 	pub async fn graph_query(&self, req: GraphQueryRequest) -> Result<GraphQueryResponse> {
+		self.ensure_subsystem(self.subsystems.graph, "graph")?;
+
 		let prepared = graph_query::validate_graph_query_request(req)?;
 		let allowed_scopes =
 			search::resolve_read_profile_scopes(&self.cfg, prepared.read_profile.as_str())?;
@@ -11,6 +11,8 @@ use crate::{
 impl ElfService {
 	/// Builds a source-backed graph report for one subject entity.
 	pub async fn graph_report(&self, req: GraphReportRequest) -> Result<GraphReportResponse> {
+		self.ensure_subsystem(self.subsystems.graph, "graph")?;
+
 		let prepared = graph_report::validate_graph_report_request(req)?;
 		let allowed_scopes =
 			search::resolve_read_profile_scopes(&self.cfg, prepared.read_profile.as_str())?;
@@ -1,10 +1,11 @@
 use serde_json::Value;
-use sqlx::PgExecutor;
-use time::OffsetDateTime;
+use sqlx::{PgConnection, PgExecutor};
+use time::{Duration, OffsetDateTime};
 use uuid::Uuid;
 
-use crate::Result;
-use elf_storage::models::MemoryNote;
+use crate::{Error, Result};
+use elf_domain::writegate::PiiRedaction;
+use elf_storage::{models::MemoryNote, search_watermarks};
 
 pub(crate) struct InsertVersionArgs<'a> {
 	pub(crate) note_id: Uuid,
@@ -16,6 +17,34 @@ pub(crate) struct InsertVersionArgs<'a> {
 	pub(crate) ts: OffsetDateTime,
 }
 
+/// Outbox job collected by a batch write and enqueued with [`enqueue_outbox_batch_tx`].
+#[derive(Clone, Debug)]
+pub(crate) struct OutboxJob {
+	pub(crate) note_id: Uuid,
+	pub(crate) op: &'static str,
+	pub(crate) embedding_version: String,
+	pub(crate) coalesced: bool,
+}
+
+/// Reason suffix that records how many successive updates share one version row.
+pub(crate) const COALESCED_REASON_MARKER: &str = ";coalesced=";
+
+/// Outcome of writing an update version, possibly folded into a recent row.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct UpdateVersionWrite {
+	pub(crate) version_id: Uuid,
+	pub(crate) coalesced: bool,
+}
+
+#[derive(sqlx::FromRow)]
+struct LatestVersionRow {
+	version_id: Uuid,
+	op: String,
+	actor: String,
+	reason: String,
+	ts: OffsetDateTime,
+}
+
 pub(crate) fn note_snapshot(note: &MemoryNote) -> Value {
 	serde_json::json!({
 		"note_id": note.note_id,
@@ -39,41 +68,213 @@ pub(crate) fn note_snapshot(note: &MemoryNote) -> Value {
 	})
 }
 
-pub(crate) async fn insert_version<'e, E>(executor: E, args: InsertVersionArgs<'_>) -> Result<Uuid>
-where
-	E: PgExecutor<'e>,
-{
+/// Writes a note version and its change-feed event, and advances the search result watermark of
+/// the spaces the snapshots name.
+pub(crate) async fn insert_version(
+	conn: &mut PgConnection,
+	args: InsertVersionArgs<'_>,
+) -> Result<Uuid> {
 	let InsertVersionArgs { note_id, op, prev_snapshot, new_snapshot, reason, actor, ts } = args;
 	let version_id = Uuid::new_v4();
 
 	sqlx::query(
 		"\
-INSERT INTO memory_note_versions (
-	version_id,
-	note_id,
-	op,
-	prev_snapshot,
-	new_snapshot,
-	reason,
-	actor,
-	ts
+WITH version AS (
+	INSERT INTO memory_note_versions (
+		version_id,
+		note_id,
+		op,
+		prev_snapshot,
+		new_snapshot,
+		reason,
+		actor,
+		ts
+	)
+	VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
 )
-VALUES ($1,$2,$3,$4,$5,$6,$7,$8)",
+INSERT INTO memory_note_events (version_id, note_id, tenant_id, project_id, agent_id, scope, op, ts)
+SELECT
+	$1,
+	$2,
+	snapshot->>'tenant_id',
+	snapshot->>'project_id',
+	snapshot->>'agent_id',
+	snapshot->>'scope',
+	$3,
+	$8
+FROM (SELECT COALESCE($5::jsonb, $4::jsonb) AS snapshot) s
+WHERE snapshot ? 'tenant_id'",
 	)
 	.bind(version_id)
 	.bind(note_id)
 	.bind(op)
-	.bind(prev_snapshot)
-	.bind(new_snapshot)
+	.bind(&prev_snapshot)
+	.bind(&new_snapshot)
 	.bind(reason)
 	.bind(actor)
 	.bind(ts)
-	.execute(executor)
+	.execute(&mut *conn)
 	.await?;
 
+	let snapshots = prev_snapshot.iter().chain(new_snapshot.iter()).collect::<Vec<_>>();
+
+	search_watermarks::bump_for_snapshots(&mut *conn, &snapshots, ts).await?;
+
 	Ok(version_id)
 }
 
+/// Records the write-time PII redactions applied to the fields captured by a version.
+///
+/// An empty list clears the column, so a folded update does not keep redactions that no longer
+/// describe its snapshot.
+pub(crate) async fn record_version_redactions<'e, E>(
+	executor: E,
+	version_id: Uuid,
+	redactions: &[PiiRedaction],
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	let redactions =
+		(!redactions.is_empty()).then(|| serde_json::to_value(redactions)).transpose().map_err(
+			|err| Error::Storage { message: format!("Failed to encode version redactions: {err}") },
+		)?;
+
+	sqlx::query("UPDATE memory_note_versions SET redactions = $2 WHERE version_id = $1")
+		.bind(version_id)
+		.bind(redactions)
+		.execute(executor)
+		.await?;
+
+	Ok(())
+}
+
+/// Writes an `UPDATE` version, folding it into the note's latest version when that row is an
+/// update by the same actor within `window_ms`. A folded row keeps its original
+/// `prev_snapshot`, takes the newest `new_snapshot` and timestamp, and counts the folded
+/// updates in its reason.
+pub(crate) async fn insert_update_version(
+	conn: &mut PgConnection,
+	args: InsertVersionArgs<'_>,
+	window_ms: Option<u64>,
+) -> Result<UpdateVersionWrite> {
+	if let Some(window_ms) = window_ms {
+		let latest = sqlx::query_as::<_, LatestVersionRow>(
+			"\
+SELECT version_id, op, actor, reason, ts
+FROM memory_note_versions
+WHERE note_id = $1
+ORDER BY ts DESC
+LIMIT 1
+FOR UPDATE",
+		)
+		.bind(args.note_id)
+		.fetch_optional(&mut *conn)
+		.await?;
+		let window = Duration::milliseconds(i64::try_from(window_ms).unwrap_or(i64::MAX));
+
+		if let Some(latest) = latest
+			&& latest.op == args.op
+			&& latest.actor == args.actor
+			&& args.ts - latest.ts <= window
+		{
+			sqlx::query(
+				"\
+UPDATE memory_note_versions
+SET new_snapshot = $1, reason = $2, ts = $3
+WHERE version_id = $4",
+			)
+			.bind(&args.new_snapshot)
+			.bind(coalesced_version_reason(latest.reason.as_str(), args.reason))
+			.bind(args.ts)
+			.bind(latest.version_id)
+			.execute(&mut *conn)
+			.await?;
+			insert_note_event(&mut *conn, latest.version_id, &args).await?;
+
+			return Ok(UpdateVersionWrite { version_id: latest.version_id, coalesced: true });
+		}
+	}
+
+	let version_id = insert_version(&mut *conn, args).await?;
+
+	Ok(UpdateVersionWrite { version_id, coalesced: false })
+}
+
+/// Records a change-feed event for a version that was folded into an existing row and advances
+/// the search result watermark of its spaces.
+async fn insert_note_event(
+	conn: &mut PgConnection,
+	version_id: Uuid,
+	args: &InsertVersionArgs<'_>,
+) -> Result<()> {
+	let Some(snapshot) = args.new_snapshot.as_ref().or(args.prev_snapshot.as_ref()) else {
+		return Ok(());
+	};
+
+	sqlx::query(
+		"\
+INSERT INTO memory_note_events (version_id, note_id, tenant_id, project_id, agent_id, scope, op, ts)
+SELECT $1, $2, $3->>'tenant_id', $3->>'project_id', $3->>'agent_id', $3->>'scope', $4, $5
+WHERE $3 ? 'tenant_id'",
+	)
+	.bind(version_id)
+	.bind(args.note_id)
+	.bind(snapshot)
+	.bind(args.op)
+	.bind(args.ts)
+	.execute(&mut *conn)
+	.await?;
+
+	let snapshots = args.prev_snapshot.iter().chain(args.new_snapshot.iter()).collect::<Vec<_>>();
+
+	search_watermarks::bump_for_snapshots(&mut *conn, &snapshots, args.ts).await?;
+
+	Ok(())
+}
+
+/// Builds the reason for a coalesced version: the latest writer's reason plus the number of
+/// updates the row now represents.
+pub(crate) fn coalesced_version_reason(existing: &str, incoming: &str) -> String {
+	let folded = existing
+		.rsplit_once(COALESCED_REASON_MARKER)
+		.and_then(|(_, count)| count.parse::<u32>().ok())
+		.unwrap_or(1);
+
+	format!("{incoming}{COALESCED_REASON_MARKER}{}", folded.saturating_add(1))
+}
+
+/// Enqueues an `UPSERT` outbox job. When the version was coalesced, a pending job for the note
+/// that no worker has leased yet is refreshed instead so one indexing pass covers the burst.
+pub(crate) async fn enqueue_upsert_outbox_tx(
+	conn: &mut PgConnection,
+	note_id: Uuid,
+	embedding_version: &str,
+	now: OffsetDateTime,
+	coalesced: bool,
+) -> Result<()> {
+	if coalesced {
+		let refreshed = sqlx::query(
+			"\
+UPDATE indexing_outbox
+SET embedding_version = $1, updated_at = $2
+WHERE note_id = $3 AND op = 'UPSERT' AND status = 'PENDING' AND available_at <= $2",
+		)
+		.bind(embedding_version)
+		.bind(now)
+		.bind(note_id)
+		.execute(&mut *conn)
+		.await?
+		.rows_affected();
+
+		if refreshed > 0 {
+			return Ok(());
+		}
+	}
+
+	enqueue_outbox_tx(conn, note_id, "UPSERT", embedding_version, now).await
+}
+
 pub(crate) async fn enqueue_outbox_tx<'e, E>(
 	executor: E,
 	note_id: Uuid,
@@ -89,6 +290,7 @@ where
 INSERT INTO indexing_outbox (
 	outbox_id,
 	note_id,
+	tenant_id,
 	op,
 	embedding_version,
 	status,
@@ -96,7 +298,7 @@ INSERT INTO indexing_outbox (
 	updated_at,
 	available_at
 )
-VALUES ($1,$2,$3,$4,'PENDING',$5,$6,$7)",
+VALUES ($1,$2,(SELECT tenant_id FROM memory_notes WHERE note_id = $2),$3,$4,'PENDING',$5,$6,$7)",
 	)
 	.bind(Uuid::new_v4())
 	.bind(note_id)
@@ -110,3 +312,85 @@ VALUES ($1,$2,$3,$4,'PENDING',$5,$6,$7)",
 
 	Ok(())
 }
+
+/// Enqueues every job of a batch write with one refresh and one insert.
+///
+/// Coalesced `UPSERT` jobs refresh a pending, unleased job for their note like
+/// [`enqueue_upsert_outbox_tx`]; every other job gets a new outbox row.
+pub(crate) async fn enqueue_outbox_batch_tx(
+	conn: &mut PgConnection,
+	jobs: &[OutboxJob],
+	now: OffsetDateTime,
+) -> Result<()> {
+	let (coalesced_ids, coalesced_versions): (Vec<Uuid>, Vec<String>) = jobs
+		.iter()
+		.filter(|job| job.coalesced && job.op == "UPSERT")
+		.map(|job| (job.note_id, job.embedding_version.clone()))
+		.unzip();
+	let refreshed: Vec<Uuid> = if coalesced_ids.is_empty() {
+		Vec::new()
+	} else {
+		sqlx::query_scalar(
+			"\
+UPDATE indexing_outbox o
+SET embedding_version = j.embedding_version, updated_at = $3
+FROM unnest($1::uuid[], $2::text[]) AS j(note_id, embedding_version)
+WHERE o.note_id = j.note_id
+	AND o.op = 'UPSERT'
+	AND o.status = 'PENDING'
+	AND o.available_at <= $3
+RETURNING o.note_id",
+		)
+		.bind(coalesced_ids.as_slice())
+		.bind(coalesced_versions.as_slice())
+		.bind(now)
+		.fetch_all(&mut *conn)
+		.await?
+	};
+	let pending = jobs
+		.iter()
+		.filter(|job| !(job.coalesced && job.op == "UPSERT" && refreshed.contains(&job.note_id)))
+		.collect::<Vec<_>>();
+
+	if pending.is_empty() {
+		return Ok(());
+	}
+
+	let outbox_ids = pending.iter().map(|_| Uuid::new_v4()).collect::<Vec<_>>();
+	let note_ids = pending.iter().map(|job| job.note_id).collect::<Vec<_>>();
+	let ops = pending.iter().map(|job| job.op).collect::<Vec<_>>();
+	let embedding_versions =
+		pending.iter().map(|job| job.embedding_version.as_str()).collect::<Vec<_>>();
+
+	sqlx::query(
+		"\
+INSERT INTO indexing_outbox (
+	outbox_id,
+	note_id,
+	tenant_id,
+	op,
+	embedding_version,
+	status,
+	created_at,
+	updated_at,
+	available_at
+)
+SELECT j.outbox_id, j.note_id, n.tenant_id, j.op, j.embedding_version, 'PENDING', $5, $5, $5
+FROM unnest($1::uuid[], $2::uuid[], $3::text[], $4::text[])
+	AS j(outbox_id, note_id, op, embedding_version)
+LEFT JOIN memory_notes n ON n.note_id = j.note_id",
+	)
+	.bind(outbox_ids.as_slice())
+	.bind(note_ids.as_slice())
+	.bind(ops.as_slice())
+	.bind(embedding_versions.as_slice())
+	.bind(now)
+	.execute(&mut *conn)
+	.await?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+#[path = "history/tests.rs"]
+mod tests;
@@ -0,0 +1,10 @@
+use crate::history;
+
+#[test]
+fn coalesced_version_reason_counts_folded_updates() {
+	assert_eq!(history::coalesced_version_reason("update", "update"), "update;coalesced=2");
+	assert_eq!(
+		history::coalesced_version_reason("update;coalesced=2", "add_event"),
+		"add_event;coalesced=3"
+	);
+}
@@ -1,5 +1,6 @@
 use crate::{
 	ElfService, Error, Result,
+	audit::AuditScope,
 	ingestion_profiles::{
 		ADD_EVENT_PIPELINE, profile, storage,
 		types::{AdminIngestionProfileCreateRequest, AdminIngestionProfileResponse},
@@ -11,6 +12,20 @@ impl ElfService {
 	pub async fn admin_ingestion_profile_create(
 		&self,
 		req: AdminIngestionProfileCreateRequest,
+	) -> Result<AdminIngestionProfileResponse> {
+		let scope = AuditScope::new("admin_ingestion_profile_create").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.created_by,
+		);
+
+		self.audited(scope, self.admin_ingestion_profile_create_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_ingestion_profile_create`] without recording an audit entry.
+	async fn admin_ingestion_profile_create_inner(
+		&self,
+		req: AdminIngestionProfileCreateRequest,
 	) -> Result<AdminIngestionProfileResponse> {
 		let profile_id = req.profile_id.trim().to_string();
 		let created_by = req.created_by.trim().to_string();
@@ -2,6 +2,7 @@ use time::OffsetDateTime;
 
 use crate::{
 	ElfService, Error, Result,
+	audit::AuditScope,
 	ingestion_profiles::{
 		storage,
 		types::{
@@ -59,6 +60,20 @@ impl ElfService {
 	pub async fn admin_ingestion_profile_default_set(
 		&self,
 		req: AdminIngestionProfileDefaultSetRequest,
+	) -> Result<AdminIngestionProfileDefaultResponse> {
+		let scope = AuditScope::new("admin_ingestion_profile_default_set").caller(
+			&req.tenant_id,
+			&req.project_id,
+			"",
+		);
+
+		self.audited(scope, self.admin_ingestion_profile_default_set_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_ingestion_profile_default_set`] without recording an audit entry.
+	async fn admin_ingestion_profile_default_set_inner(
+		&self,
+		req: AdminIngestionProfileDefaultSetRequest,
 	) -> Result<AdminIngestionProfileDefaultResponse> {
 		let profile_id = req.profile_id.trim().to_string();
 
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use time::OffsetDateTime;
 
-use crate::{Error, Result};
+use crate::{Error, Result, audit::AuditTargets};
 use elf_config::LlmProviderConfig;
 
 /// Selector for an ingestion profile and optional version.
@@ -111,6 +111,8 @@ pub struct AdminIngestionProfileResponse {
 	pub created_by: String,
 }
 
+impl AuditTargets for AdminIngestionProfileResponse {}
+
 /// Summary row for an ingestion profile version.
 #[derive(Clone, Debug, Serialize)]
 pub struct AdminIngestionProfileSummary {
@@ -151,6 +153,8 @@ pub struct AdminIngestionProfileDefaultResponse {
 	pub updated_at: OffsetDateTime,
 }
 
+impl AuditTargets for AdminIngestionProfileDefaultResponse {}
+
 #[derive(Clone, Debug)]
 pub(crate) struct ResolvedIngestionProfile {
 	pub profile_ref: IngestionProfileRef,
@@ -195,6 +199,7 @@ impl ResolvedIngestionProfile {
 			temperature: self.temperature.unwrap_or(base.temperature),
 			timeout_ms: self.timeout_ms.unwrap_or(base.timeout_ms),
 			default_headers: base.default_headers.clone(),
+			resilience: base.resilience.clone(),
 		}
 	}
 }
@@ -1,5 +1,9 @@
-use crate::knowledge::api::{
-	KnowledgePageLintFindingResponse, KnowledgePageResponse, KnowledgePageSummary, Serialize, Uuid,
+use crate::{
+	audit::AuditTargets,
+	knowledge::api::{
+		KnowledgePageLintFindingResponse, KnowledgePageResponse, KnowledgePageSummary, Serialize,
+		Uuid,
+	},
 };
 
 /// Response returned after rebuilding a derived knowledge page.
@@ -9,6 +13,12 @@ pub struct KnowledgePageRebuildResponse {
 	pub page: KnowledgePageResponse,
 }
 
+impl AuditTargets for KnowledgePageRebuildResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.page.page.page_id]
+	}
+}
+
 /// Response returned by derived knowledge page listing.
 #[derive(Clone, Debug, Serialize)]
 pub struct KnowledgePagesListResponse {
@@ -24,3 +34,9 @@ pub struct KnowledgePageLintResponse {
 	/// Current lint findings.
 	pub findings: Vec<KnowledgePageLintFindingResponse>,
 }
+
+impl AuditTargets for KnowledgePageLintResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.page_id]
+	}
+}
@@ -1,5 +1,9 @@
-use crate::knowledge::api::{
-	ConsolidationInputRef, ConsolidationProposalDiff, KnowledgePageResponse, Serialize, Uuid, Value,
+use crate::{
+	audit::AuditTargets,
+	knowledge::api::{
+		ConsolidationInputRef, ConsolidationProposalDiff, KnowledgePageResponse, Serialize, Uuid,
+		Value,
+	},
 };
 
 /// Response returned after rebuilding pages affected by changed sources.
@@ -19,6 +23,12 @@ pub struct KnowledgePageWatchRebuildResponse {
 	pub operator_summary: Vec<String>,
 }
 
+impl AuditTargets for KnowledgePageWatchRebuildResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		self.pages.iter().map(|page| page.page_id).collect()
+	}
+}
+
 /// Aggregate watch/rebuild outcome counters.
 #[derive(Clone, Debug, Serialize)]
 pub struct KnowledgePageWatchRebuildSummary {
@@ -1,7 +1,10 @@
-use crate::knowledge::{
-	ElfService, Error, KnowledgePage, KnowledgePageLintFindingResponse, KnowledgePageLintRequest,
-	KnowledgePageLintResponse, KnowledgePageSourceRef, LintDraft, OffsetDateTime, Result,
-	SourceIds, knowledge,
+use crate::{
+	audit::AuditScope,
+	knowledge::{
+		ElfService, Error, KnowledgePage, KnowledgePageLintFindingResponse,
+		KnowledgePageLintRequest, KnowledgePageLintResponse, KnowledgePageSourceRef, LintDraft,
+		OffsetDateTime, Result, SourceIds, knowledge,
+	},
 };
 
 impl ElfService {
@@ -9,6 +12,18 @@ impl ElfService {
 	pub async fn knowledge_page_lint(
 		&self,
 		req: KnowledgePageLintRequest,
+	) -> Result<KnowledgePageLintResponse> {
+		let scope = AuditScope::new("admin_knowledge_page_lint")
+			.caller(&req.tenant_id, &req.project_id, "")
+			.targets([req.page_id]);
+
+		self.audited(scope, self.knowledge_page_lint_inner(req)).await
+	}
+
+	/// Runs [`ElfService::knowledge_page_lint`] without recording an audit entry.
+	async fn knowledge_page_lint_inner(
+		&self,
+		req: KnowledgePageLintRequest,
 	) -> Result<KnowledgePageLintResponse> {
 		let page = knowledge::get_knowledge_page(
 			&self.db.pool,
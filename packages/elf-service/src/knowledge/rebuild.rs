@@ -1,7 +1,10 @@
-use crate::knowledge::{
-	ElfService, KNOWLEDGE_PAGE_CONTRACT_SCHEMA_V1, KnowledgePageRebuildRequest,
-	KnowledgePageRebuildResponse, KnowledgePageUpsert, OffsetDateTime, Result, SourceIds, Uuid,
-	knowledge,
+use crate::{
+	audit::AuditScope,
+	knowledge::{
+		ElfService, KNOWLEDGE_PAGE_CONTRACT_SCHEMA_V1, KnowledgePageRebuildRequest,
+		KnowledgePageRebuildResponse, KnowledgePageUpsert, OffsetDateTime, Result, SourceIds, Uuid,
+		knowledge,
+	},
 };
 
 impl ElfService {
@@ -9,6 +12,20 @@ impl ElfService {
 	pub async fn knowledge_page_rebuild(
 		&self,
 		req: KnowledgePageRebuildRequest,
+	) -> Result<KnowledgePageRebuildResponse> {
+		let scope = AuditScope::new("admin_knowledge_page_rebuild").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.knowledge_page_rebuild_inner(req)).await
+	}
+
+	/// Runs [`ElfService::knowledge_page_rebuild`] without recording an audit entry.
+	async fn knowledge_page_rebuild_inner(
+		&self,
+		req: KnowledgePageRebuildRequest,
 	) -> Result<KnowledgePageRebuildResponse> {
 		crate::knowledge::validate_context(
 			req.tenant_id.as_str(),
@@ -1,10 +1,13 @@
-use crate::knowledge::{
-	ConsolidationLineage, ConsolidationRunCreateRequest, ElfService,
-	KNOWLEDGE_PAGE_WATCH_REBUILD_SCHEMA_V1, KnowledgeDeltaMemoryCandidate, KnowledgePage,
-	KnowledgePageChangedSource, KnowledgePageKind, KnowledgePageProposalRunSummary,
-	KnowledgePageSection, KnowledgePageSourceRef, KnowledgePageWatchRebuildRequest,
-	KnowledgePageWatchRebuildResponse, LintDraft, Result, WatchRebuildOutcome,
-	candidate_proposal_input, knowledge,
+use crate::{
+	audit::AuditScope,
+	knowledge::{
+		ConsolidationLineage, ConsolidationRunCreateRequest, ElfService,
+		KNOWLEDGE_PAGE_WATCH_REBUILD_SCHEMA_V1, KnowledgeDeltaMemoryCandidate, KnowledgePage,
+		KnowledgePageChangedSource, KnowledgePageKind, KnowledgePageProposalRunSummary,
+		KnowledgePageSection, KnowledgePageSourceRef, KnowledgePageWatchRebuildRequest,
+		KnowledgePageWatchRebuildResponse, LintDraft, Result, WatchRebuildOutcome,
+		candidate_proposal_input, knowledge,
+	},
 };
 
 impl ElfService {
@@ -12,6 +15,20 @@ impl ElfService {
 	pub async fn knowledge_pages_watch_rebuild(
 		&self,
 		req: KnowledgePageWatchRebuildRequest,
+	) -> Result<KnowledgePageWatchRebuildResponse> {
+		let scope = AuditScope::new("admin_knowledge_pages_watch_rebuild").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.knowledge_pages_watch_rebuild_inner(req)).await
+	}
+
+	/// Runs [`ElfService::knowledge_pages_watch_rebuild`] without recording an audit entry.
+	async fn knowledge_pages_watch_rebuild_inner(
+		&self,
+		req: KnowledgePageWatchRebuildRequest,
 	) -> Result<KnowledgePageWatchRebuildResponse> {
 		crate::knowledge::validate_context(
 			req.tenant_id.as_str(),
@@ -2,10 +2,19 @@
 
 //! Service-layer request models and orchestration for ELF.
 
+pub mod access_simulate;
 pub mod add_event;
 pub mod add_note;
 pub mod admin;
+pub mod admin_grants;
+pub mod admin_graph_entities;
+pub mod admin_graph_entity_kinds;
 pub mod admin_graph_predicates;
+pub mod admin_index_verify;
+pub mod admin_outbox;
+pub mod admin_qdrant_audit;
+pub mod admin_reembed;
+pub mod audit;
 pub mod consolidation;
 pub mod core_blocks;
 pub mod delete;
@@ -13,21 +22,52 @@ pub mod docs;
 pub mod dreaming_review_queue;
 pub mod entity_memory;
 pub mod graph;
+pub mod graph_facts_as_of;
+pub mod graph_neighborhood;
 pub mod graph_query;
 pub mod graph_report;
 pub mod knowledge;
 pub mod list;
 pub mod memory_corrections;
+pub mod memory_stats;
+pub mod memory_timeline;
+pub mod merge;
+pub mod note_batch;
+pub mod note_events;
+pub mod note_history;
+pub mod note_relations;
 pub mod notes;
+pub mod org_stats;
+pub mod permissions;
+pub mod pin;
 pub mod progressive_search;
 pub mod provenance;
+pub mod provider_health;
+pub mod qdrant_maintenance;
+pub mod quotas;
+pub mod recall_context;
 pub mod recall_debug;
+pub mod scoped_search;
 pub mod search;
+pub mod search_answer;
+pub mod search_batch;
+pub mod search_hooks;
+pub mod search_profiles;
+pub mod search_stream;
+pub mod search_v2;
+pub mod sessions;
+pub mod shadow;
 pub mod sharing;
+pub mod standing_queries;
+pub mod storage_report;
 pub mod structured_fields;
+pub mod tenant_export;
 pub mod time_serde;
 pub mod update;
+pub mod url_snapshots;
+pub mod warmup;
 pub mod work_journal;
+pub mod write_anomaly;
 
 mod access;
 mod constants;
@@ -40,19 +80,60 @@ mod ops;
 mod providers;
 mod ranking_explain_v2;
 mod service;
+mod session_hits;
 mod update_resolution;
 mod vectors;
 mod write_policy;
 
 pub use self::{
+	access_simulate::{
+		AccessDecisionStep, AccessSimulateNote, AccessSimulateRequest, AccessSimulateResponse,
+	},
 	add_event::{AddEventRequest, AddEventResponse, AddEventResult, EventMessage},
-	add_note::{AddNoteInput, AddNoteRequest, AddNoteResponse, AddNoteResult},
-	admin::RebuildReport,
+	add_note::{
+		AddNoteInput, AddNoteRequest, AddNoteResponse, AddNoteResult, BulkImportLineResult,
+		BulkImportRequest, BulkImportResponse, BulkImporter,
+	},
+	admin::{RebuildQdrantRequest, RebuildReport},
+	admin_grants::{
+		AdminGrantItem, AdminGrantPutRequest, AdminGrantResponse, AdminGrantRevokeRequest,
+		AdminGrantsListRequest, AdminGrantsListResponse,
+	},
+	admin_graph_entities::{
+		AdminGraphDeduplicatedFact, AdminGraphEntitiesMergeRequest,
+		AdminGraphEntitiesMergeResponse, AdminGraphEntityResponse, AdminGraphEntitySplitRequest,
+		AdminGraphEntitySplitResponse,
+	},
+	admin_graph_entity_kinds::{
+		AdminGraphEntityKindPromoteRequest, AdminGraphEntityKindResponse,
+		AdminGraphEntityKindsListRequest, AdminGraphEntityKindsListResponse,
+	},
 	admin_graph_predicates::{
 		AdminGraphPredicateAliasAddRequest, AdminGraphPredicateAliasResponse,
 		AdminGraphPredicateAliasesListRequest, AdminGraphPredicateAliasesResponse,
-		AdminGraphPredicatePatchRequest, AdminGraphPredicateResponse,
-		AdminGraphPredicatesListRequest, AdminGraphPredicatesListResponse,
+		AdminGraphPredicatePatchRequest, AdminGraphPredicatePromoteRequest,
+		AdminGraphPredicateResponse, AdminGraphPredicatesListRequest,
+		AdminGraphPredicatesListResponse,
+	},
+	admin_index_verify::{
+		ELF_INDEX_VERIFY_SCHEMA_V1, IndexVerifyCounts, IndexVerifyFinding, IndexVerifyRepair,
+		IndexVerifyReport, IndexVerifyRequest, IndexVerifyVersion,
+	},
+	admin_outbox::{
+		AdminOutboxDeadLetterListRequest, AdminOutboxDeadLetterListResponse,
+		AdminOutboxReplayRequest, AdminOutboxReplayResponse, OutboxDeadLetter,
+	},
+	admin_qdrant_audit::{
+		ELF_QDRANT_AUDIT_SCHEMA_V1, QdrantAuditCounts, QdrantAuditFinding, QdrantAuditReport,
+		QdrantAuditRequest,
+	},
+	admin_reembed::{
+		AdminReembedActivateRequest, AdminReembedActivateResponse, AdminReembedRunItem,
+		AdminReembedRunRequest, AdminReembedRunsListRequest, AdminReembedRunsResponse,
+	},
+	audit::{
+		AUDIT_CHAIN_GENESIS, AuditEntry, AuditLogItem, AuditLogListRequest, AuditLogListResponse,
+		AuditOutcome, AuditSink, AuditTransport, PostgresAuditSink, with_audit_transport,
 	},
 	consolidation::{
 		ConsolidationProposalGetRequest, ConsolidationProposalInput, ConsolidationProposalResponse,
@@ -61,18 +142,22 @@ pub use self::{
 		ConsolidationRunCreateRequest, ConsolidationRunCreateResponse, ConsolidationRunGetRequest,
 		ConsolidationRunResponse, ConsolidationRunsListRequest, ConsolidationRunsListResponse,
 	},
-	constants::{REJECT_EVIDENCE_MISMATCH, REJECT_WRITE_POLICY_MISMATCH},
+	constants::{RATE_ANOMALY, REJECT_EVIDENCE_MISMATCH, REJECT_WRITE_POLICY_MISMATCH},
 	core_blocks::{
 		CoreBlockAttachRequest, CoreBlockAttachResponse, CoreBlockDetachRequest,
 		CoreBlockDetachResponse, CoreBlockItem, CoreBlockRecord, CoreBlockUpsertRequest,
 		CoreBlockUpsertResponse, CoreBlocksGetRequest, CoreBlocksResponse,
 		ELF_CORE_MEMORY_BLOCKS_SCHEMA_V1,
 	},
-	delete::{DeleteRequest, DeleteResponse},
+	delete::{
+		DeleteRequest, DeleteResponse, ListTrashedRequest, ListTrashedResponse, TRASHED_STATUS,
+		TrashedNote, UndeleteRequest, UndeleteResponse,
+	},
 	docs::{
 		DocType, DocsDeleteRequest, DocsDeleteResponse, DocsExcerptResponse,
 		DocsExcerptsGetRequest, DocsGetRequest, DocsGetResponse, DocsPutRequest, DocsPutResponse,
-		DocsSearchL0Request, DocsSearchL0Response, TextPositionSelector, TextQuoteSelector,
+		DocsSearchExcerpt, DocsSearchItem, DocsSearchL0Request, DocsSearchL0Response,
+		DocsSearchRequest, DocsSearchResponse, TextPositionSelector, TextQuoteSelector,
 	},
 	dreaming_review_queue::{
 		DreamingReviewQueueAudit, DreamingReviewQueueItem, DreamingReviewQueueItemPolicy,
@@ -86,6 +171,11 @@ pub use self::{
 	},
 	error::{Error, Result},
 	graph::RelationTemporalStatus,
+	graph_facts_as_of::{GraphFactAsOf, GraphFactsAsOfRequest, GraphFactsAsOfResponse},
+	graph_neighborhood::{
+		GraphNeighborhoodEntity, GraphNeighborhoodRequest, GraphNeighborhoodResponse,
+		GraphNeighborhoodTriple,
+	},
 	graph_query::{
 		ELF_GRAPH_QUERY_SCHEMA_V1, GraphQueryEntity, GraphQueryEntityRef, GraphQueryExplain,
 		GraphQueryFact, GraphQueryObject, GraphQueryObjectEntity, GraphQueryPredicate,
@@ -119,8 +209,41 @@ pub use self::{
 	memory_corrections::{
 		MemoryCorrectionAction, MemoryCorrectionRequest, MemoryCorrectionResponse,
 	},
-	notes::{NoteFetchRequest, NoteFetchResponse},
+	memory_stats::{
+		ELF_MEMORY_STATS_SCHEMA_V1, MemoryStatsCount, MemoryStatsExpiring, MemoryStatsOutbox,
+		MemoryStatsRequest, MemoryStatsResponse, MemoryStatsStorage, MemoryStatsTopHit,
+	},
+	memory_timeline::{
+		ELF_MEMORY_TIMELINE_SCHEMA_V1, MemoryTimelineBucket, MemoryTimelineBucketSummary,
+		MemoryTimelineEntity, MemoryTimelineNote, MemoryTimelineRequest, MemoryTimelineResponse,
+	},
+	merge::{NoteMergeStrategy, NotesMergeRequest, NotesMergeResponse},
+	note_batch::{
+		DeleteBatchRequest, MAX_NOTE_BATCH_ITEMS, NoteBatchItemResult, NoteBatchResponse,
+		UpdateBatchItem, UpdateBatchRequest,
+	},
+	note_events::{NOTE_EVENTS_CURSOR_LATEST, NoteEvent, NoteEventsRequest, NoteEventsResponse},
+	note_history::{
+		MAX_NOTE_HISTORY_VERSIONS, NoteHistoryRequest, NoteHistoryResponse, NoteHistoryVersion,
+		TextDiffKind, TextDiffSpan,
+	},
+	note_relations::{
+		NoteRelation, NoteRelationCreateRequest, NoteRelationCreateResponse,
+		NoteRelationDeleteRequest, NoteRelationDeleteResponse, NoteRelationKind,
+		NoteRelationsListRequest, NoteRelationsListResponse,
+	},
+	notes::{
+		NoteAccessQuery, NoteAccessStats, NoteCitation, NoteCitationQuoteSource, NoteFetchRequest,
+		NoteFetchResponse, NotesCiteRequest, NotesCiteResponse, SourceRefResolution,
+		SourceRefResolutionStatus, SourceRefsResolveRequest, SourceRefsResolveResponse,
+	},
 	ops::NoteOp,
+	org_stats::{
+		ELF_ORG_MEMORY_STATS_SCHEMA_V1, OrgMemoryStatsItem, OrgMemoryStatsRequest,
+		OrgMemoryStatsResponse, OrgMemoryStatsThresholds,
+	},
+	permissions::WriteOperation,
+	pin::{PinNoteRequest, PinNoteResponse},
 	progressive_search::{
 		SearchDetailsError, SearchDetailsRequest, SearchDetailsResponse, SearchDetailsResult,
 		SearchIndexItem, SearchIndexPlannedResponse, SearchIndexResponse, SearchSessionGetRequest,
@@ -132,46 +255,112 @@ pub use self::{
 		NoteProvenanceIngestDecision, NoteProvenanceNote, NoteProvenanceNoteVersion,
 		NoteProvenanceRecentTrace,
 	},
+	provider_health::{ELF_PROVIDER_HEALTH_SCHEMA_V1, ProviderHealthItem, ProviderHealthResponse},
 	providers::{BoxFuture, EmbeddingProvider, ExtractorProvider, Providers, RerankProvider},
+	qdrant_maintenance::{
+		QdrantMaintenanceRunItem, QdrantMaintenanceRunRequest, QdrantMaintenanceRunsListRequest,
+		QdrantMaintenanceRunsResponse,
+	},
+	quotas::{ELF_QUOTA_USAGE_SCHEMA_V1, QuotaUsageItem, QuotaUsageRequest, QuotaUsageResponse},
+	recall_context::{
+		MAX_RECALL_CONTEXT_QUERIES, MAX_RECALL_CONTEXT_TURNS, RecallContextCitation,
+		RecallContextRequest, RecallContextResponse, RecallContextTurn,
+	},
 	recall_debug::{
 		ELF_RECALL_DEBUG_PANEL_SCHEMA_V1, ELF_RECALL_TRACE_SCHEMA_V1, RecallDebugLayer,
 		RecallDebugPanelRequest, RecallDebugPanelRequestEcho, RecallDebugPanelResponse,
 		RecallDebugPanelSummary, RecallDebugRow, RecallTrace, RecallTraceEntry, RecallTraceSummary,
 	},
+	scoped_search::{SearchScopeSection, SearchScopedRequest, SearchScopedResponse},
 	search::{
 		BlendRankingOverride, BlendSegmentOverride, PayloadLevel, QueryPlan, QueryPlanBlendSegment,
 		QueryPlanBudget, QueryPlanDynamicGate, QueryPlanFusionPolicy, QueryPlanIntent,
 		QueryPlanRerankPolicy, QueryPlanRetrievalStage, QueryPlanRewrite, QueryPlanStage,
-		RankingRequestOverride, SearchExplain, SearchExplainItem, SearchExplainRequest,
-		SearchExplainResponse, SearchExplainTrajectory, SearchExplainTrajectoryStage, SearchItem,
-		SearchRawPlannedResponse, SearchRequest, SearchResponse, SearchTrace,
-		SearchTrajectoryResponse, SearchTrajectoryStage, SearchTrajectoryStageItem,
-		SearchTrajectorySummary, SearchTrajectorySummaryStage, TraceBundleGetRequest,
-		TraceBundleResponse, TraceGetRequest, TraceGetResponse, TraceRecentListRequest,
-		TraceRecentListResponse, TraceTrajectoryGetRequest,
-	},
-	service::ElfService,
+		RankDocument, RankDocumentsRequest, RankDocumentsResponse, RankedDocument,
+		RankingRequestOverride, SearchEmbeddingProjectionExplain, SearchExplain, SearchExplainItem,
+		SearchExplainRequest, SearchExplainResponse, SearchExplainTrajectory,
+		SearchExplainTrajectoryStage, SearchFeedbackKind, SearchFeedbackRequest,
+		SearchFeedbackResponse, SearchItem, SearchPackingExplain, SearchRankingRendered,
+		SearchRawPlannedResponse, SearchRequest, SearchResponse, SearchSnippetTruncation,
+		SearchTrace, SearchTrajectoryResponse, SearchTrajectoryStage, SearchTrajectoryStageItem,
+		SearchTrajectorySummary, SearchTrajectorySummaryStage, SearchWarning, TraceArtifact,
+		TraceArtifactGetRequest, TraceBundleGetRequest, TraceBundleResponse, TraceGetRequest,
+		TraceGetResponse, TraceRecentListRequest, TraceRecentListResponse,
+		TraceTrajectoryGetRequest,
+	},
+	search_answer::{
+		SearchAnswerCitation, SearchAnswerRefusal, SearchAnswerRequest, SearchAnswerResponse,
+		SearchAnswerSentence,
+	},
+	search_batch::{MAX_SEARCH_BATCH_QUERIES, SearchBatchRequest, SearchBatchResponse},
+	search_hooks::{
+		SearchHookCandidate, SearchHookOutput, SearchHookStage, SearchStageContext, SearchStageHook,
+	},
+	search_profiles::{
+		SearchProfileDeleteResponse, SearchProfileGetRequest, SearchProfileRef,
+		SearchProfileResponse, SearchProfileTemplate, SearchProfileUpsertRequest,
+		SearchProfilesListRequest, SearchProfilesListResponse, SearchWithProfileRequest,
+		SearchWithProfileResponse,
+	},
+	search_v2::{
+		SearchV2Delivery, SearchV2Mode, SearchV2Request, SearchV2Response, SearchV2Session,
+	},
+	service::{ElfService, ElfServiceBuilder, ServiceSubsystems},
+	sessions::{
+		SessionAppendRequest, SessionAppendResponse, SessionGetRequest, SessionGetResponse,
+		SessionMessageInput, SessionMessageItem, SessionSummarizeRequest, SessionSummarizeResponse,
+	},
+	shadow::{
+		ELF_SEARCH_SHADOW_REPORT_SCHEMA_V1, SearchShadowComparison, SearchShadowComparisonInput,
+		SearchShadowReportRequest, SearchShadowReportResponse, SearchShadowSummary, ShadowOverlap,
+	},
 	sharing::{
 		GranteeKind, PublishNoteRequest, PublishNoteResponse, ShareScope, SpaceGrantItem,
 		SpaceGrantRevokeRequest, SpaceGrantRevokeResponse, SpaceGrantUpsertRequest,
 		SpaceGrantUpsertResponse, SpaceGrantsListRequest, SpaceGrantsListResponse,
 		UnpublishNoteRequest, UnpublishNoteResponse,
 	},
+	standing_queries::{
+		StandingQueriesListRequest, StandingQueriesListResponse, StandingQueryCreateRequest,
+		StandingQueryDeleteResponse, StandingQueryFilter, StandingQueryGetRequest,
+		StandingQueryMatchItem, StandingQueryMatchesRequest, StandingQueryMatchesResponse,
+		StandingQueryResponse,
+	},
+	storage_report::{
+		ELF_STORAGE_REPORT_SCHEMA_V1, StorageCategoryUsage, StorageIndexUsage,
+		StorageMaintenanceAction, StorageReportResponse, StorageTableUsage, StorageTenantUsage,
+	},
 	structured_fields::StructuredFields,
+	tenant_export::{
+		SnapshotRestoreRequest, SnapshotRestoreResponse, SnapshotRestorer, TenantExportLine,
+		TenantExportManifest, TenantExportRequest, TenantExporter,
+	},
 	update::{UpdateRequest, UpdateResponse},
+	url_snapshots::EXTERNAL_URL_RESOLVER_V1,
+	warmup::WarmupReport,
 	work_journal::{
 		ELF_WORK_JOURNAL_SCHEMA_V1, WorkJournalEntryCreateRequest, WorkJournalEntryCreateResponse,
 		WorkJournalEntryFamily, WorkJournalEntryGetRequest, WorkJournalEntryResponse,
 		WorkJournalSessionReadbackRequest, WorkJournalSessionReadbackResponse,
 		WorkJournalWhereStopped,
 	},
+	write_anomaly::{
+		AdminWriteIncidentsListRequest, AdminWriteIncidentsListResponse, WriteIncident,
+	},
 };
 
 use self::{
-	history::{InsertVersionArgs, enqueue_outbox_tx, insert_version, note_snapshot},
+	history::{
+		InsertVersionArgs, OutboxJob, enqueue_outbox_batch_tx, enqueue_outbox_tx,
+		enqueue_upsert_outbox_tx, insert_update_version, insert_version, note_snapshot,
+		record_version_redactions,
+	},
 	update_resolution::{
 		ResolveUpdateArgs, UpdateDecision, UpdateDecisionMetadata, resolve_update,
 	},
-	vectors::{embedding_version, parse_pg_vector, vector_to_pg},
-	write_policy::writegate_reason_code,
+	vectors::{
+		PROJECTION_MODE_TRUNCATE, embedding_version, embedding_version_for_note, parse_pg_vector,
+		project_legacy_vector, provider_embedding_version, vector_to_pg,
+	},
+	write_policy::{PiiRedactor, writegate_reason_code},
 };
@@ -2,6 +2,7 @@ use time::OffsetDateTime;
 
 use crate::{
 	ElfService, NoteOp, Result,
+	audit::AuditScope,
 	memory_corrections::{
 		storage::{self, RestoreNoteArgs},
 		types::{MemoryCorrectionAction, MemoryCorrectionRequest, MemoryCorrectionResponse},
@@ -14,6 +15,18 @@ impl ElfService {
 	pub async fn memory_correction_apply(
 		&self,
 		req: MemoryCorrectionRequest,
+	) -> Result<MemoryCorrectionResponse> {
+		let scope = AuditScope::new("admin_note_correction")
+			.caller(&req.tenant_id, &req.project_id, &req.actor_agent_id)
+			.targets([req.note_id].into_iter().chain(req.restore_version_id));
+
+		self.audited(scope, self.memory_correction_apply_inner(req)).await
+	}
+
+	/// Runs [`ElfService::memory_correction_apply`] without recording an audit entry.
+	async fn memory_correction_apply_inner(
+		&self,
+		req: MemoryCorrectionRequest,
 	) -> Result<MemoryCorrectionResponse> {
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
@@ -57,7 +70,11 @@ impl ElfService {
 				)
 				.await?,
 			MemoryCorrectionAction::Restore => {
-				let embed_version = crate::embedding_version(&self.cfg);
+				let embed_version = crate::embedding_version_for_note(
+					&self.cfg,
+					note.scope.as_str(),
+					note.r#type.as_str(),
+				);
 
 				storage::restore_note(
 					&mut tx,
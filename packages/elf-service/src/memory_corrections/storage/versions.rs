@@ -18,7 +18,7 @@ pub(super) async fn insert_correction_version(
 	let reason = format!("memory_correction.{}: {reason}", op.to_ascii_lowercase());
 
 	crate::insert_version(
-		&mut **tx,
+		tx,
 		InsertVersionArgs {
 			note_id: note.note_id,
 			op,
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
-use crate::NoteOp;
+use crate::{NoteOp, audit::AuditTargets};
 
 /// Review-backed correction action for an approved memory record.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -61,3 +61,9 @@ pub struct MemoryCorrectionResponse {
 	/// Version row written for this correction, when a change occurred.
 	pub version_id: Option<Uuid>,
 }
+
+impl AuditTargets for MemoryCorrectionResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		[self.note_id].into_iter().chain(self.version_id).collect()
+	}
+}
@@ -0,0 +1,380 @@
+//! Per-project memory usage summary for operators.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgExecutor};
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{ElfService, Error, Result};
+
+/// Memory stats response schema identifier.
+pub const ELF_MEMORY_STATS_SCHEMA_V1: &str = "elf.memory_stats/v1";
+
+const DEFAULT_EXPIRING_WITHIN_DAYS: u32 = 7;
+const MAX_EXPIRING_WITHIN_DAYS: u32 = 365;
+const DEFAULT_TOP_HITS_LIMIT: u32 = 10;
+const MAX_TOP_HITS_LIMIT: u32 = 100;
+
+/// Request payload for a memory usage summary.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemoryStatsRequest {
+	/// Tenant to summarize.
+	pub tenant_id: String,
+	/// Project to summarize.
+	pub project_id: String,
+	/// Window in days for counting active notes that are about to expire.
+	pub expiring_within_days: Option<u32>,
+	/// Maximum number of most-hit notes to return.
+	pub top_hits_limit: Option<u32>,
+}
+
+/// Note counts, expiry, hits, storage, and indexing backlog of one project.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemoryStatsResponse {
+	/// Response schema identifier.
+	pub schema: String,
+	#[serde(with = "crate::time_serde")]
+	/// Timestamp the summary was generated at.
+	pub generated_at: OffsetDateTime,
+	/// Tenant the summary covers.
+	pub tenant_id: String,
+	/// Project the summary covers.
+	pub project_id: String,
+	/// Notes in the project, across every status.
+	pub total_notes: i64,
+	/// Note counts by scope, type, and status.
+	pub counts: Vec<MemoryStatsCount>,
+	/// Active notes that expire within the requested window.
+	pub expiring: MemoryStatsExpiring,
+	/// Active notes with the most hits, most hits first.
+	pub top_hits: Vec<MemoryStatsTopHit>,
+	/// Estimated row bytes of the project's notes and derived rows.
+	pub storage: MemoryStatsStorage,
+	/// Open note-indexing outbox jobs for the project's notes.
+	pub outbox: MemoryStatsOutbox,
+}
+
+/// Note count for one scope, type, and status.
+#[derive(Clone, Debug, Deserialize, Serialize, FromRow)]
+pub struct MemoryStatsCount {
+	/// Note scope.
+	pub scope: String,
+	#[serde(rename = "type")]
+	#[sqlx(rename = "type")]
+	/// Note type.
+	pub note_type: String,
+	/// Note status.
+	pub status: String,
+	/// Number of notes.
+	pub notes: i64,
+}
+
+/// Active notes nearing TTL expiry.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemoryStatsExpiring {
+	/// Window in days the count covers.
+	pub within_days: u32,
+	/// Active notes whose `expires_at` falls within the window.
+	pub notes: i64,
+	#[serde(with = "crate::time_serde::option")]
+	/// Earliest expiry within the window.
+	pub earliest_expires_at: Option<OffsetDateTime>,
+}
+
+/// One frequently retrieved active note.
+#[derive(Clone, Debug, Deserialize, Serialize, FromRow)]
+pub struct MemoryStatsTopHit {
+	/// Note identifier.
+	pub note_id: Uuid,
+	/// Note scope.
+	pub scope: String,
+	#[serde(rename = "type")]
+	#[sqlx(rename = "type")]
+	/// Note type.
+	pub note_type: String,
+	/// Note key, when set.
+	pub key: Option<String>,
+	/// Times the note was returned by search.
+	pub hit_count: i64,
+	#[serde(with = "crate::time_serde::option")]
+	/// Most recent hit.
+	pub last_hit_at: Option<OffsetDateTime>,
+}
+
+/// Estimated row bytes, measured with `pg_column_size`; index and page overhead are excluded.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, FromRow)]
+pub struct MemoryStatsStorage {
+	/// Bytes of note rows.
+	pub notes_bytes: i64,
+	/// Bytes of note chunk rows.
+	pub chunks_bytes: i64,
+	/// Bytes of note and chunk embedding rows.
+	pub embeddings_bytes: i64,
+	/// Sum of the other byte counts.
+	pub total_bytes: i64,
+}
+
+/// Open note-indexing outbox jobs.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, FromRow)]
+pub struct MemoryStatsOutbox {
+	/// Jobs waiting for their first attempt.
+	pub pending: i64,
+	/// Jobs waiting for a retry after a failed attempt.
+	pub failed: i64,
+	/// Jobs that exhausted their retries.
+	pub dead_letter: i64,
+	#[serde(with = "crate::time_serde::option")]
+	/// Creation time of the oldest pending or failed job.
+	pub oldest_open_at: Option<OffsetDateTime>,
+}
+
+#[derive(Clone, Debug, FromRow)]
+struct ExpiringRow {
+	notes: i64,
+	earliest_expires_at: Option<OffsetDateTime>,
+}
+
+impl ElfService {
+	/// Summarizes how much memory one project holds and how it is used.
+	///
+	/// Counts cover every status; expiry and hit rankings cover active notes only.
+	pub async fn memory_stats(&self, req: MemoryStatsRequest) -> Result<MemoryStatsResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id and project_id are required.".to_string(),
+			});
+		}
+
+		let (within_days, top_hits_limit) =
+			resolve_limits(req.expiring_within_days, req.top_hits_limit)?;
+		let now = OffsetDateTime::now_utc();
+		let counts = fetch_counts(&self.db.pool, tenant_id, project_id).await?;
+		let expiring = fetch_expiring(
+			&self.db.pool,
+			tenant_id,
+			project_id,
+			now,
+			now + Duration::days(i64::from(within_days)),
+		)
+		.await?;
+		let top_hits =
+			fetch_top_hits(&self.db.pool, tenant_id, project_id, now, top_hits_limit).await?;
+		let storage = fetch_storage(&self.db.pool, tenant_id, project_id).await?;
+		let outbox = fetch_outbox(&self.db.pool, tenant_id, project_id).await?;
+
+		Ok(MemoryStatsResponse {
+			schema: ELF_MEMORY_STATS_SCHEMA_V1.to_string(),
+			generated_at: now,
+			tenant_id: tenant_id.to_string(),
+			project_id: project_id.to_string(),
+			total_notes: counts.iter().map(|count| count.notes).sum(),
+			counts,
+			expiring: MemoryStatsExpiring {
+				within_days,
+				notes: expiring.notes,
+				earliest_expires_at: expiring.earliest_expires_at,
+			},
+			top_hits,
+			storage,
+			outbox,
+		})
+	}
+}
+
+fn resolve_limits(
+	expiring_within_days: Option<u32>,
+	top_hits_limit: Option<u32>,
+) -> Result<(u32, u32)> {
+	let within_days = expiring_within_days.unwrap_or(DEFAULT_EXPIRING_WITHIN_DAYS);
+	let top_hits_limit = top_hits_limit.unwrap_or(DEFAULT_TOP_HITS_LIMIT);
+
+	if within_days == 0 || within_days > MAX_EXPIRING_WITHIN_DAYS {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"expiring_within_days must be between 1 and {MAX_EXPIRING_WITHIN_DAYS}."
+			),
+		});
+	}
+	if top_hits_limit > MAX_TOP_HITS_LIMIT {
+		return Err(Error::InvalidRequest {
+			message: format!("top_hits_limit must be at most {MAX_TOP_HITS_LIMIT}."),
+		});
+	}
+
+	Ok((within_days, top_hits_limit))
+}
+
+async fn fetch_counts<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+) -> Result<Vec<MemoryStatsCount>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, MemoryStatsCount>(
+		"\
+SELECT scope, type, status, count(*) AS notes
+FROM memory_notes
+WHERE tenant_id = $1 AND project_id = $2
+GROUP BY scope, type, status
+ORDER BY scope, type, status",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_expiring<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	now: OffsetDateTime,
+	until: OffsetDateTime,
+) -> Result<ExpiringRow>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, ExpiringRow>(
+		"\
+SELECT count(*) AS notes, min(expires_at) AS earliest_expires_at
+FROM memory_notes
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND status = 'active'
+	AND expires_at > $3
+	AND expires_at <= $4",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(now)
+	.bind(until)
+	.fetch_one(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_top_hits<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	now: OffsetDateTime,
+	limit: u32,
+) -> Result<Vec<MemoryStatsTopHit>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, MemoryStatsTopHit>(
+		"\
+SELECT note_id, scope, type, key, hit_count, last_hit_at
+FROM memory_notes
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND status = 'active'
+	AND (expires_at IS NULL OR expires_at > $3)
+	AND hit_count > 0
+ORDER BY hit_count DESC, last_hit_at DESC NULLS LAST, note_id
+LIMIT $4",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(now)
+	.bind(i64::from(limit))
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_storage<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+) -> Result<MemoryStatsStorage>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, MemoryStatsStorage>(
+		"\
+WITH notes AS (
+	SELECT note_id, pg_column_size(n.*)::bigint AS bytes
+	FROM memory_notes n
+	WHERE tenant_id = $1 AND project_id = $2
+),
+chunks AS (
+	SELECT c.chunk_id, pg_column_size(c.*)::bigint AS bytes
+	FROM memory_note_chunks c
+	JOIN notes n ON n.note_id = c.note_id
+),
+embeddings AS (
+	SELECT pg_column_size(e.*)::bigint AS bytes
+	FROM note_chunk_embeddings e
+	JOIN chunks c ON c.chunk_id = e.chunk_id
+	UNION ALL
+	SELECT pg_column_size(e.*)::bigint
+	FROM note_embeddings e
+	JOIN notes n ON n.note_id = e.note_id
+),
+sizes AS (
+	SELECT
+		COALESCE((SELECT sum(bytes) FROM notes), 0)::bigint AS notes_bytes,
+		COALESCE((SELECT sum(bytes) FROM chunks), 0)::bigint AS chunks_bytes,
+		COALESCE((SELECT sum(bytes) FROM embeddings), 0)::bigint AS embeddings_bytes
+)
+SELECT
+	notes_bytes,
+	chunks_bytes,
+	embeddings_bytes,
+	notes_bytes + chunks_bytes + embeddings_bytes AS total_bytes
+FROM sizes",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.fetch_one(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_outbox<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+) -> Result<MemoryStatsOutbox>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, MemoryStatsOutbox>(
+		"\
+SELECT
+	count(*) FILTER (WHERE o.status = 'PENDING') AS pending,
+	count(*) FILTER (WHERE o.status = 'FAILED') AS failed,
+	count(*) FILTER (WHERE o.status = 'DEAD_LETTER') AS dead_letter,
+	min(o.created_at) FILTER (WHERE o.status IN ('PENDING', 'FAILED')) AS oldest_open_at
+FROM indexing_outbox o
+JOIN memory_notes n ON n.note_id = o.note_id
+WHERE n.tenant_id = $1 AND n.project_id = $2 AND o.status IN ('PENDING', 'FAILED', 'DEAD_LETTER')",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.fetch_one(executor)
+	.await
+	.map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::memory_stats;
+
+	#[test]
+	fn limits_default_and_stay_bounded() {
+		assert_eq!(memory_stats::resolve_limits(None, None).expect("defaults are valid"), (7, 10));
+		assert_eq!(memory_stats::resolve_limits(Some(30), Some(0)).expect("valid"), (30, 0));
+		assert!(memory_stats::resolve_limits(Some(0), None).is_err());
+		assert!(memory_stats::resolve_limits(Some(366), None).is_err());
+		assert!(memory_stats::resolve_limits(None, Some(101)).is_err());
+	}
+}
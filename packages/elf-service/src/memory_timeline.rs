@@ -0,0 +1,284 @@
+//! Time-bucketed overview of the memory visible to one agent.
+
+mod storage;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{ElfService, Error, Result, access, search};
+
+/// Memory timeline response schema identifier.
+pub const ELF_MEMORY_TIMELINE_SCHEMA_V1: &str = "elf.memory_timeline/v1";
+
+const DEFAULT_MAX_BUCKETS: u32 = 30;
+const MAX_BUCKETS: u32 = 366;
+const DEFAULT_REPRESENTATIVES: u32 = 3;
+const MAX_REPRESENTATIVES: u32 = 10;
+const DEFAULT_TOP_ENTITIES: u32 = 5;
+const MAX_TOP_ENTITIES: u32 = 20;
+
+/// Calendar unit used to bucket notes by creation time (UTC).
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryTimelineBucket {
+	/// One bucket per UTC day.
+	#[default]
+	Day,
+	/// One bucket per ISO week, starting on Monday.
+	Week,
+}
+impl MemoryTimelineBucket {
+	/// Returns the wire and `date_trunc` name of the bucket unit.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Day => "day",
+			Self::Week => "week",
+		}
+	}
+}
+
+/// Request payload for a bucketed memory overview.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemoryTimelineRequest {
+	/// Tenant to aggregate within.
+	pub tenant_id: String,
+	/// Project to aggregate within, alongside org-shared memory.
+	pub project_id: String,
+	/// Agent requesting the overview; only notes it can read are counted.
+	pub agent_id: String,
+	/// Read profile that determines which scopes are aggregated.
+	pub read_profile: String,
+	#[serde(default)]
+	/// Bucket unit.
+	pub bucket: MemoryTimelineBucket,
+	#[serde(default, with = "crate::time_serde::option")]
+	/// Inclusive lower bound on note creation time.
+	pub since: Option<OffsetDateTime>,
+	#[serde(default, with = "crate::time_serde::option")]
+	/// Exclusive upper bound on note creation time.
+	pub until: Option<OffsetDateTime>,
+	/// Maximum number of most recent buckets to return.
+	pub max_buckets: Option<u32>,
+	/// Maximum representative notes per bucket.
+	pub representatives_per_bucket: Option<u32>,
+	/// Maximum graph entities per bucket.
+	pub top_entities_per_bucket: Option<u32>,
+}
+
+/// Response payload for a bucketed memory overview.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemoryTimelineResponse {
+	/// Response schema identifier.
+	pub schema: String,
+	/// Bucket unit applied.
+	pub bucket: MemoryTimelineBucket,
+	/// Scopes the overview was computed over.
+	pub scopes: Vec<String>,
+	#[serde(with = "crate::time_serde")]
+	/// Timestamp used to exclude expired notes.
+	pub as_of: OffsetDateTime,
+	/// Buckets, newest first.
+	pub buckets: Vec<MemoryTimelineBucketSummary>,
+	/// Whether older buckets were dropped by `max_buckets`.
+	pub truncated: bool,
+}
+
+/// Aggregates for one time bucket.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemoryTimelineBucketSummary {
+	/// First day of the bucket as `YYYY-MM-DD`.
+	pub bucket_start: String,
+	/// Number of notes created in the bucket.
+	pub note_count: u32,
+	/// Note counts keyed by note type.
+	pub type_counts: BTreeMap<String, u32>,
+	/// Graph entities most often referenced by the bucket's notes.
+	pub top_entities: Vec<MemoryTimelineEntity>,
+	/// Highest-importance notes in the bucket.
+	pub representatives: Vec<MemoryTimelineNote>,
+}
+
+/// Graph entity referenced within a bucket.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MemoryTimelineEntity {
+	/// Canonical entity surface.
+	pub label: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Entity kind, when known.
+	pub kind: Option<String>,
+	/// Distinct notes in the bucket that reference the entity.
+	pub note_count: u32,
+}
+
+/// Representative note for a bucket.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemoryTimelineNote {
+	/// Note identifier.
+	pub note_id: Uuid,
+	/// Note type discriminator.
+	pub r#type: String,
+	/// Scope key for the note.
+	pub scope: String,
+	/// Optional application-defined key.
+	pub key: Option<String>,
+	/// Note body text.
+	pub text: String,
+	/// Importance score used for selection.
+	pub importance: f32,
+	#[serde(with = "crate::time_serde")]
+	/// Note creation timestamp.
+	pub created_at: OffsetDateTime,
+}
+
+impl ElfService {
+	/// Buckets the caller's readable notes by creation time and summarizes each bucket.
+	///
+	/// Counts, entities, and representatives are computed in the database, so the overview stays
+	/// cheap regardless of how many notes fall inside the window.
+	pub async fn memory_timeline(
+		&self,
+		req: MemoryTimelineRequest,
+	) -> Result<MemoryTimelineResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+		if let (Some(since), Some(until)) = (req.since, req.until)
+			&& since >= until
+		{
+			return Err(Error::InvalidRequest {
+				message: "since must be earlier than until.".to_string(),
+			});
+		}
+
+		let scopes = search::resolve_read_profile_scopes(&self.cfg, req.read_profile.trim())?;
+		let org_shared_allowed = scopes.iter().any(|scope| scope == "org_shared");
+		let shared_grants = access::load_shared_read_grants_with_org_shared(
+			&self.db.pool,
+			tenant_id,
+			project_id,
+			agent_id,
+			org_shared_allowed,
+		)
+		.await?;
+		let shared_scope_keys = access::shared_scope_key_strings(&shared_grants);
+		let as_of = OffsetDateTime::now_utc();
+		let filter = storage::TimelineFilter {
+			tenant_id,
+			project_id,
+			agent_id,
+			scopes: scopes.as_slice(),
+			shared_scope_keys: shared_scope_keys.as_slice(),
+			as_of,
+			since: req.since,
+			until: req.until,
+			bucket: req.bucket,
+		};
+		let type_rows = storage::fetch_type_counts(&self.db.pool, &filter).await?;
+		let max_buckets =
+			req.max_buckets.unwrap_or(DEFAULT_MAX_BUCKETS).clamp(1, MAX_BUCKETS) as usize;
+		let (selected, truncated) = select_buckets(&type_rows, max_buckets);
+		let representatives = req
+			.representatives_per_bucket
+			.unwrap_or(DEFAULT_REPRESENTATIVES)
+			.min(MAX_REPRESENTATIVES);
+		let top_entities =
+			req.top_entities_per_bucket.unwrap_or(DEFAULT_TOP_ENTITIES).min(MAX_TOP_ENTITIES);
+		let note_rows = if representatives == 0 || selected.is_empty() {
+			Vec::new()
+		} else {
+			storage::fetch_representatives(&self.db.pool, &filter, &selected, representatives)
+				.await?
+		};
+		let entity_rows = if top_entities == 0 || selected.is_empty() {
+			Vec::new()
+		} else {
+			storage::fetch_top_entities(&self.db.pool, &filter, &selected, top_entities).await?
+		};
+
+		Ok(MemoryTimelineResponse {
+			schema: ELF_MEMORY_TIMELINE_SCHEMA_V1.to_string(),
+			bucket: req.bucket,
+			scopes,
+			as_of,
+			buckets: assemble_buckets(&selected, type_rows, entity_rows, note_rows),
+			truncated,
+		})
+	}
+}
+
+/// Picks the `max_buckets` most recent buckets, newest first, and reports whether any were
+/// dropped.
+fn select_buckets(type_rows: &[storage::TypeCountRow], max_buckets: usize) -> (Vec<Date>, bool) {
+	let all: BTreeSet<Date> = type_rows.iter().map(|row| row.bucket_start).collect();
+	let truncated = all.len() > max_buckets;
+
+	(all.into_iter().rev().take(max_buckets).collect(), truncated)
+}
+
+fn assemble_buckets(
+	selected: &[Date],
+	type_rows: Vec<storage::TypeCountRow>,
+	entity_rows: Vec<storage::EntityRow>,
+	note_rows: Vec<storage::RepresentativeRow>,
+) -> Vec<MemoryTimelineBucketSummary> {
+	let mut summaries: HashMap<Date, MemoryTimelineBucketSummary> = selected
+		.iter()
+		.map(|bucket_start| {
+			(
+				*bucket_start,
+				MemoryTimelineBucketSummary {
+					bucket_start: bucket_start.to_string(),
+					note_count: 0,
+					type_counts: BTreeMap::new(),
+					top_entities: Vec::new(),
+					representatives: Vec::new(),
+				},
+			)
+		})
+		.collect();
+
+	for row in type_rows {
+		if let Some(summary) = summaries.get_mut(&row.bucket_start) {
+			let count = u32::try_from(row.note_count).unwrap_or(u32::MAX);
+
+			summary.note_count = summary.note_count.saturating_add(count);
+
+			summary.type_counts.insert(row.note_type, count);
+		}
+	}
+	for row in entity_rows {
+		if let Some(summary) = summaries.get_mut(&row.bucket_start) {
+			summary.top_entities.push(MemoryTimelineEntity {
+				label: row.label,
+				kind: row.kind,
+				note_count: u32::try_from(row.note_count).unwrap_or(u32::MAX),
+			});
+		}
+	}
+	for row in note_rows {
+		if let Some(summary) = summaries.get_mut(&row.bucket_start) {
+			summary.representatives.push(MemoryTimelineNote {
+				note_id: row.note_id,
+				r#type: row.note_type,
+				scope: row.scope,
+				key: row.key,
+				text: row.text,
+				importance: row.importance,
+				created_at: row.created_at,
+			});
+		}
+	}
+
+	selected.iter().filter_map(|bucket_start| summaries.remove(bucket_start)).collect()
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,232 @@
+use sqlx::{FromRow, PgExecutor};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{Result, access::ORG_PROJECT_ID, memory_timeline::MemoryTimelineBucket};
+
+const TYPE_COUNTS_SQL: &str = "\
+SELECT
+	date_trunc($8, n.created_at AT TIME ZONE 'UTC')::date AS bucket_start,
+	n.type AS note_type,
+	count(*) AS note_count
+FROM memory_notes n
+WHERE n.tenant_id = $1
+	AND (n.project_id = $2 OR (n.project_id = $4 AND n.scope = 'org_shared'))
+	AND n.scope = ANY($3::text[])
+	AND n.status = 'active'
+	AND (n.expires_at IS NULL OR n.expires_at > $5)
+	AND (
+		(n.scope = 'agent_private' AND n.agent_id = $6)
+		OR (
+			n.scope <> 'agent_private'
+			AND (n.agent_id = $6 OR (n.scope || ':' || n.agent_id) = ANY($7::text[]))
+		)
+	)
+	AND ($9::timestamptz IS NULL OR n.created_at >= $9)
+	AND ($10::timestamptz IS NULL OR n.created_at < $10)
+GROUP BY 1, 2";
+const REPRESENTATIVES_SQL: &str = "\
+WITH bucketed AS (
+	SELECT
+		date_trunc($8, n.created_at AT TIME ZONE 'UTC')::date AS bucket_start,
+		n.note_id,
+		n.type AS note_type,
+		n.scope,
+		n.key,
+		n.text,
+		n.importance,
+		n.created_at
+	FROM memory_notes n
+	WHERE n.tenant_id = $1
+		AND (n.project_id = $2 OR (n.project_id = $4 AND n.scope = 'org_shared'))
+		AND n.scope = ANY($3::text[])
+		AND n.status = 'active'
+		AND (n.expires_at IS NULL OR n.expires_at > $5)
+		AND (
+			(n.scope = 'agent_private' AND n.agent_id = $6)
+			OR (
+				n.scope <> 'agent_private'
+				AND (n.agent_id = $6 OR (n.scope || ':' || n.agent_id) = ANY($7::text[]))
+			)
+		)
+		AND ($9::timestamptz IS NULL OR n.created_at >= $9)
+		AND ($10::timestamptz IS NULL OR n.created_at < $10)
+),
+ranked AS (
+	SELECT
+		b.*,
+		row_number() OVER (
+			PARTITION BY b.bucket_start
+			ORDER BY b.importance DESC, b.created_at DESC, b.note_id ASC
+		) AS rank
+	FROM bucketed b
+	WHERE b.bucket_start = ANY($11::date[])
+)
+SELECT bucket_start, note_id, note_type, scope, key, text, importance, created_at
+FROM ranked
+WHERE rank <= $12
+ORDER BY bucket_start DESC, rank ASC";
+const TOP_ENTITIES_SQL: &str = "\
+WITH bucketed AS (
+	SELECT
+		date_trunc($8, n.created_at AT TIME ZONE 'UTC')::date AS bucket_start,
+		n.note_id
+	FROM memory_notes n
+	WHERE n.tenant_id = $1
+		AND (n.project_id = $2 OR (n.project_id = $4 AND n.scope = 'org_shared'))
+		AND n.scope = ANY($3::text[])
+		AND n.status = 'active'
+		AND (n.expires_at IS NULL OR n.expires_at > $5)
+		AND (
+			(n.scope = 'agent_private' AND n.agent_id = $6)
+			OR (
+				n.scope <> 'agent_private'
+				AND (n.agent_id = $6 OR (n.scope || ':' || n.agent_id) = ANY($7::text[]))
+			)
+		)
+		AND ($9::timestamptz IS NULL OR n.created_at >= $9)
+		AND ($10::timestamptz IS NULL OR n.created_at < $10)
+),
+counted AS (
+	SELECT
+		b.bucket_start,
+		e.canonical AS label,
+		e.kind,
+		count(DISTINCT b.note_id) AS note_count
+	FROM bucketed b
+	JOIN graph_fact_evidence gfe ON gfe.note_id = b.note_id
+	JOIN graph_facts gf ON gf.fact_id = gfe.fact_id
+	JOIN graph_entities e
+		ON e.entity_id = gf.subject_entity_id OR e.entity_id = gf.object_entity_id
+	WHERE b.bucket_start = ANY($11::date[])
+	GROUP BY b.bucket_start, e.entity_id, e.canonical, e.kind
+),
+ranked AS (
+	SELECT
+		c.*,
+		row_number() OVER (
+			PARTITION BY c.bucket_start
+			ORDER BY c.note_count DESC, c.label ASC
+		) AS rank
+	FROM counted c
+)
+SELECT bucket_start, label, kind, note_count
+FROM ranked
+WHERE rank <= $12
+ORDER BY bucket_start DESC, rank ASC";
+
+pub(super) struct TimelineFilter<'a> {
+	pub(super) tenant_id: &'a str,
+	pub(super) project_id: &'a str,
+	pub(super) agent_id: &'a str,
+	pub(super) scopes: &'a [String],
+	pub(super) shared_scope_keys: &'a [String],
+	pub(super) as_of: OffsetDateTime,
+	pub(super) since: Option<OffsetDateTime>,
+	pub(super) until: Option<OffsetDateTime>,
+	pub(super) bucket: MemoryTimelineBucket,
+}
+
+#[derive(Clone, Debug, FromRow)]
+pub(super) struct TypeCountRow {
+	pub(super) bucket_start: Date,
+	pub(super) note_type: String,
+	pub(super) note_count: i64,
+}
+
+#[derive(Clone, Debug, FromRow)]
+pub(super) struct EntityRow {
+	pub(super) bucket_start: Date,
+	pub(super) label: String,
+	pub(super) kind: Option<String>,
+	pub(super) note_count: i64,
+}
+
+#[derive(Clone, Debug, FromRow)]
+pub(super) struct RepresentativeRow {
+	pub(super) bucket_start: Date,
+	pub(super) note_id: Uuid,
+	pub(super) note_type: String,
+	pub(super) scope: String,
+	pub(super) key: Option<String>,
+	pub(super) text: String,
+	pub(super) importance: f32,
+	pub(super) created_at: OffsetDateTime,
+}
+
+pub(super) async fn fetch_type_counts<'e, E>(
+	executor: E,
+	filter: &TimelineFilter<'_>,
+) -> Result<Vec<TypeCountRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, TypeCountRow>(TYPE_COUNTS_SQL)
+		.bind(filter.tenant_id)
+		.bind(filter.project_id)
+		.bind(filter.scopes)
+		.bind(ORG_PROJECT_ID)
+		.bind(filter.as_of)
+		.bind(filter.agent_id)
+		.bind(filter.shared_scope_keys)
+		.bind(filter.bucket.as_str())
+		.bind(filter.since)
+		.bind(filter.until)
+		.fetch_all(executor)
+		.await
+		.map_err(Into::into)
+}
+
+pub(super) async fn fetch_representatives<'e, E>(
+	executor: E,
+	filter: &TimelineFilter<'_>,
+	buckets: &[Date],
+	per_bucket: u32,
+) -> Result<Vec<RepresentativeRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, RepresentativeRow>(REPRESENTATIVES_SQL)
+		.bind(filter.tenant_id)
+		.bind(filter.project_id)
+		.bind(filter.scopes)
+		.bind(ORG_PROJECT_ID)
+		.bind(filter.as_of)
+		.bind(filter.agent_id)
+		.bind(filter.shared_scope_keys)
+		.bind(filter.bucket.as_str())
+		.bind(filter.since)
+		.bind(filter.until)
+		.bind(buckets)
+		.bind(i64::from(per_bucket))
+		.fetch_all(executor)
+		.await
+		.map_err(Into::into)
+}
+
+pub(super) async fn fetch_top_entities<'e, E>(
+	executor: E,
+	filter: &TimelineFilter<'_>,
+	buckets: &[Date],
+	per_bucket: u32,
+) -> Result<Vec<EntityRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, EntityRow>(TOP_ENTITIES_SQL)
+		.bind(filter.tenant_id)
+		.bind(filter.project_id)
+		.bind(filter.scopes)
+		.bind(ORG_PROJECT_ID)
+		.bind(filter.as_of)
+		.bind(filter.agent_id)
+		.bind(filter.shared_scope_keys)
+		.bind(filter.bucket.as_str())
+		.bind(filter.since)
+		.bind(filter.until)
+		.bind(buckets)
+		.bind(i64::from(per_bucket))
+		.fetch_all(executor)
+		.await
+		.map_err(Into::into)
+}
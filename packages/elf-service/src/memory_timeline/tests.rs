@@ -0,0 +1,70 @@
+use time::{Date, OffsetDateTime, macros::date};
+use uuid::Uuid;
+
+use crate::memory_timeline::{
+	self,
+	storage::{EntityRow, RepresentativeRow, TypeCountRow},
+};
+
+fn type_row(bucket_start: Date, note_type: &str, note_count: i64) -> TypeCountRow {
+	TypeCountRow { bucket_start, note_type: note_type.to_string(), note_count }
+}
+
+#[test]
+fn select_buckets_keeps_most_recent_first() {
+	let rows = vec![
+		type_row(date!(2026 - 03 - 01), "fact", 2),
+		type_row(date!(2026 - 03 - 03), "fact", 1),
+		type_row(date!(2026 - 03 - 02), "plan", 4),
+		type_row(date!(2026 - 03 - 03), "decision", 1),
+	];
+	let (selected, truncated) = memory_timeline::select_buckets(&rows, 2);
+
+	assert_eq!(selected, vec![date!(2026 - 03 - 03), date!(2026 - 03 - 02)]);
+	assert!(truncated);
+
+	let (selected, truncated) = memory_timeline::select_buckets(&rows, 3);
+
+	assert_eq!(selected.len(), 3);
+	assert!(!truncated);
+}
+
+#[test]
+fn assemble_buckets_sums_types_and_drops_unselected_rows() {
+	let newest = date!(2026 - 03 - 03);
+	let older = date!(2026 - 03 - 01);
+	let note_id = Uuid::new_v4();
+	let type_rows = vec![
+		type_row(newest, "fact", 2),
+		type_row(newest, "decision", 1),
+		type_row(older, "plan", 7),
+	];
+	let entity_rows = vec![EntityRow {
+		bucket_start: newest,
+		label: "Billing".to_string(),
+		kind: Some("system".to_string()),
+		note_count: 2,
+	}];
+	let note_rows = vec![RepresentativeRow {
+		bucket_start: newest,
+		note_id,
+		note_type: "decision".to_string(),
+		scope: "project_shared".to_string(),
+		key: None,
+		text: "Billing moves to the new ledger.".to_string(),
+		importance: 0.9,
+		created_at: OffsetDateTime::UNIX_EPOCH,
+	}];
+	let buckets = memory_timeline::assemble_buckets(&[newest], type_rows, entity_rows, note_rows);
+
+	assert_eq!(buckets.len(), 1);
+
+	let bucket = &buckets[0];
+
+	assert_eq!(bucket.bucket_start, "2026-03-03");
+	assert_eq!(bucket.note_count, 3);
+	assert_eq!(bucket.type_counts.get("fact"), Some(&2));
+	assert_eq!(bucket.type_counts.get("decision"), Some(&1));
+	assert_eq!(bucket.top_entities[0].label, "Billing");
+	assert_eq!(bucket.representatives[0].note_id, note_id);
+}
@@ -0,0 +1,271 @@
+//! Note merge APIs.
+
+mod combine;
+mod persistence;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, NoteOp, PiiRedactor, Result, WriteOperation,
+	audit::{AuditScope, AuditTargets},
+	structured_fields, update,
+};
+use elf_config::SecurityAuthRole;
+use elf_domain::{
+	english_gate,
+	writegate::{self, NoteInput},
+};
+use elf_storage::models::MemoryNote;
+
+/// Strategy used to combine the text of two merged notes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteMergeStrategy {
+	/// Append the secondary text to the primary text unless one already contains the other.
+	#[default]
+	Concatenate,
+	/// Ask the configured LLM extractor to rewrite both texts as one note.
+	Extractor,
+}
+impl NoteMergeStrategy {
+	/// Returns the canonical strategy string.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Concatenate => "concatenate",
+			Self::Extractor => "extractor",
+		}
+	}
+}
+
+/// Request payload for merging one note into another.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NotesMergeRequest {
+	/// Tenant that owns both notes.
+	pub tenant_id: String,
+	/// Project that owns both notes.
+	pub project_id: String,
+	/// Agent requesting the merge; must own both notes.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the scope of both notes; `None` when auth
+	/// is off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Note that survives the merge and receives the combined content.
+	pub primary_note_id: Uuid,
+	/// Note that is superseded by the primary note.
+	pub secondary_note_id: Uuid,
+	#[serde(default)]
+	/// Strategy used to combine the note text.
+	pub strategy: NoteMergeStrategy,
+}
+
+/// Response payload for note merges.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NotesMergeResponse {
+	/// Identifier of the surviving primary note.
+	pub note_id: Uuid,
+	/// Identifier of the superseded secondary note.
+	pub secondary_note_id: Uuid,
+	/// Strategy used to combine the note text.
+	pub strategy: NoteMergeStrategy,
+	/// Operation applied to the primary note.
+	pub op: NoteOp,
+	/// Machine-readable rejection code, if the merged text was rejected.
+	pub reason_code: Option<String>,
+	/// Graph fact evidence links copied from the secondary note to the primary note.
+	pub evidence_links_added: u64,
+}
+
+impl AuditTargets for NotesMergeResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.note_id, self.secondary_note_id]
+	}
+
+	fn audit_reject_reasons(&self) -> Vec<String> {
+		if self.op == NoteOp::Rejected {
+			self.reason_code.iter().cloned().collect()
+		} else {
+			Vec::new()
+		}
+	}
+}
+
+impl ElfService {
+	/// Merges the secondary note into the primary note and supersedes the secondary note.
+	///
+	/// Text is combined with the requested strategy, source references and structured fields are
+	/// unioned, graph fact evidence is copied, and the primary note is re-indexed.
+	pub async fn notes_merge(&self, req: NotesMergeRequest) -> Result<NotesMergeResponse> {
+		let scope = AuditScope::new("merge")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.primary_note_id, req.secondary_note_id]);
+
+		self.audited(scope, self.notes_merge_inner(req)).await
+	}
+
+	/// Runs [`ElfService::notes_merge`] without recording an audit entry.
+	async fn notes_merge_inner(&self, req: NotesMergeRequest) -> Result<NotesMergeResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+		if req.primary_note_id == req.secondary_note_id {
+			return Err(Error::InvalidRequest {
+				message: "primary_note_id and secondary_note_id must differ.".to_string(),
+			});
+		}
+
+		let (primary, secondary) = persistence::fetch_merge_pair(
+			&mut *self.db.pool.acquire().await?,
+			req.primary_note_id,
+			req.secondary_note_id,
+			tenant_id,
+			project_id,
+		)
+		.await?;
+
+		validate_merge_pair(&primary, &secondary, agent_id, now)?;
+
+		for note in [&primary, &secondary] {
+			self.authorize_write(req.role, WriteOperation::Update, note.scope.as_str())?;
+		}
+
+		let mut redactor = PiiRedactor::new(&self.cfg);
+		let mut merged_text = match req.strategy {
+			NoteMergeStrategy::Concatenate =>
+				combine::concatenate_texts(primary.text.as_str(), secondary.text.as_str()),
+			NoteMergeStrategy::Extractor => self.extract_merged_text(&primary, &secondary).await?,
+		};
+
+		redactor.field("$.text", &mut merged_text);
+		let gate = NoteInput {
+			note_type: primary.r#type.clone(),
+			scope: primary.scope.clone(),
+			text: merged_text.clone(),
+		};
+
+		if let Err(code) = writegate::writegate(&gate, &self.cfg) {
+			return Ok(NotesMergeResponse {
+				note_id: primary.note_id,
+				secondary_note_id: secondary.note_id,
+				strategy: req.strategy,
+				op: NoteOp::Rejected,
+				reason_code: Some(crate::writegate_reason_code(code).to_string()),
+				evidence_links_added: 0,
+			});
+		}
+
+		let mut structured = structured_fields::fetch_structured_fields(
+			&self.db.pool,
+			&[primary.note_id, secondary.note_id],
+		)
+		.await?;
+		let mut merged_structured = combine::merge_structured_fields(
+			structured.remove(&primary.note_id),
+			structured.remove(&secondary.note_id),
+		);
+
+		redactor.structured(merged_structured.as_mut());
+
+		let merged = persistence::MergedPrimary {
+			text: merged_text,
+			source_ref: combine::merge_source_refs(
+				&primary.source_ref,
+				&secondary.source_ref,
+				secondary.note_id,
+			),
+			structured: merged_structured,
+			pii_redactions: redactor.finish(),
+		};
+		let mut tx = self.db.pool.begin().await?;
+		let (mut locked_primary, mut locked_secondary) = persistence::fetch_merge_pair(
+			&mut tx,
+			primary.note_id,
+			secondary.note_id,
+			tenant_id,
+			project_id,
+		)
+		.await?;
+
+		if locked_primary.updated_at != primary.updated_at
+			|| locked_secondary.updated_at != secondary.updated_at
+		{
+			return Err(Error::Conflict {
+				message: "Notes changed during merge; retry the request.".to_string(),
+			});
+		}
+
+		let evidence_links_added = persistence::apply_merge(
+			&mut tx,
+			&mut locked_primary,
+			&mut locked_secondary,
+			merged,
+			agent_id,
+			req.strategy,
+			now,
+		)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok(NotesMergeResponse {
+			note_id: locked_primary.note_id,
+			secondary_note_id: locked_secondary.note_id,
+			strategy: req.strategy,
+			op: NoteOp::Update,
+			reason_code: None,
+			evidence_links_added,
+		})
+	}
+
+	async fn extract_merged_text(
+		&self,
+		primary: &MemoryNote,
+		secondary: &MemoryNote,
+	) -> Result<String> {
+		let messages =
+			combine::build_merge_messages(primary.text.as_str(), secondary.text.as_str());
+		let raw =
+			self.providers.extractor.extract(&self.cfg.providers.llm_extractor, &messages).await?;
+		let text = combine::parse_merged_text(raw)?;
+
+		if !english_gate::is_english_natural_language(text.as_str()) {
+			return Err(Error::Provider {
+				message: "Merge extractor returned non-English text.".to_string(),
+			});
+		}
+
+		Ok(text)
+	}
+}
+
+fn validate_merge_pair(
+	primary: &MemoryNote,
+	secondary: &MemoryNote,
+	agent_id: &str,
+	now: OffsetDateTime,
+) -> Result<()> {
+	update::validate_note_is_updatable(primary, agent_id, now)?;
+	update::validate_note_is_updatable(secondary, agent_id, now)?;
+
+	if primary.project_id != secondary.project_id
+		|| primary.scope != secondary.scope
+		|| primary.r#type != secondary.r#type
+	{
+		return Err(Error::InvalidRequest {
+			message: "Merged notes must share the same project, scope, and type.".to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+#[cfg(test)] mod tests;
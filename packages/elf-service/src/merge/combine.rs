@@ -0,0 +1,76 @@
+pub(super) use elf_domain::note_merge::{
+	build_merge_messages, mark_merged_into, merge_source_refs,
+};
+
+use serde_json::Value;
+
+use crate::{Error, Result, structured_fields::StructuredFields};
+use elf_domain::note_merge;
+
+/// Appends the secondary text to the primary text unless one already contains the other.
+pub(super) fn concatenate_texts(primary: &str, secondary: &str) -> String {
+	let primary = primary.trim();
+	let secondary = secondary.trim();
+
+	if primary.contains(secondary) {
+		return primary.to_string();
+	}
+	if secondary.contains(primary) {
+		return secondary.to_string();
+	}
+
+	format!("{primary}\n\n{secondary}")
+}
+
+pub(super) fn parse_merged_text(raw: Value) -> Result<String> {
+	note_merge::merged_text(&raw).ok_or_else(|| Error::Provider {
+		message: "Merge extractor response is missing a non-empty text field.".to_string(),
+	})
+}
+
+/// Keeps the primary summary, question, and answer when present and unions facts and concepts in
+/// order.
+pub(super) fn merge_structured_fields(
+	primary: Option<StructuredFields>,
+	secondary: Option<StructuredFields>,
+) -> Option<StructuredFields> {
+	let (primary, secondary) = match (primary, secondary) {
+		(None, None) => return None,
+		(Some(fields), None) | (None, Some(fields)) => return Some(fields),
+		(Some(primary), Some(secondary)) => (primary, secondary),
+	};
+
+	Some(StructuredFields {
+		summary: primary.summary.filter(|summary| !summary.trim().is_empty()).or(secondary.summary),
+		facts: union_items(primary.facts, secondary.facts),
+		concepts: union_items(primary.concepts, secondary.concepts),
+		question: primary
+			.question
+			.filter(|question| !question.trim().is_empty())
+			.or(secondary.question),
+		answer: primary.answer.filter(|answer| !answer.trim().is_empty()).or(secondary.answer),
+		entities: None,
+		relations: None,
+	})
+}
+
+fn union_items(
+	primary: Option<Vec<String>>,
+	secondary: Option<Vec<String>>,
+) -> Option<Vec<String>> {
+	if primary.is_none() && secondary.is_none() {
+		return None;
+	}
+
+	let mut out: Vec<String> = Vec::new();
+
+	for item in primary.into_iter().flatten().chain(secondary.into_iter().flatten()) {
+		let trimmed = item.trim();
+
+		if !trimmed.is_empty() && !out.iter().any(|existing| existing == trimmed) {
+			out.push(trimmed.to_string());
+		}
+	}
+
+	Some(out)
+}
@@ -0,0 +1,215 @@
+use serde_json::Value;
+use sqlx::{PgConnection, Postgres, Transaction};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	Error, InsertVersionArgs, Result,
+	access::ORG_PROJECT_ID,
+	merge::{NoteMergeStrategy, combine},
+	structured_fields::{self, StructuredFields},
+};
+use elf_domain::writegate::PiiRedaction;
+use elf_storage::models::MemoryNote;
+
+/// Combined content written to the primary note.
+pub(super) struct MergedPrimary {
+	pub(super) text: String,
+	pub(super) source_ref: Value,
+	pub(super) structured: Option<StructuredFields>,
+	pub(super) pii_redactions: Vec<PiiRedaction>,
+}
+
+/// Loads both merge notes, locking them in note-id order when called inside a transaction.
+pub(super) async fn fetch_merge_pair(
+	executor: &mut PgConnection,
+	primary_note_id: Uuid,
+	secondary_note_id: Uuid,
+	tenant_id: &str,
+	project_id: &str,
+) -> Result<(MemoryNote, MemoryNote)> {
+	let notes = sqlx::query_as::<_, MemoryNote>(
+		"\
+SELECT *
+FROM memory_notes
+WHERE note_id = ANY($1::uuid[]) AND tenant_id = $2 AND project_id IN ($3, $4)
+ORDER BY note_id
+FOR UPDATE",
+	)
+	.bind(vec![primary_note_id, secondary_note_id])
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(ORG_PROJECT_ID)
+	.fetch_all(executor)
+	.await?;
+	let mut primary = None;
+	let mut secondary = None;
+
+	for note in notes {
+		if note.note_id == primary_note_id {
+			primary = Some(note);
+		} else {
+			secondary = Some(note);
+		}
+	}
+
+	match (primary, secondary) {
+		(Some(primary), Some(secondary)) => Ok((primary, secondary)),
+		_ => Err(Error::InvalidRequest { message: "Note not found.".to_string() }),
+	}
+}
+
+/// Writes the merged primary note, supersedes the secondary note, and queues both for indexing.
+///
+/// Returns the number of graph fact evidence links copied to the primary note.
+pub(super) async fn apply_merge(
+	tx: &mut Transaction<'_, Postgres>,
+	primary: &mut MemoryNote,
+	secondary: &mut MemoryNote,
+	merged: MergedPrimary,
+	actor: &str,
+	strategy: NoteMergeStrategy,
+	now: OffsetDateTime,
+) -> Result<u64> {
+	let reason = format!("merge:{}", strategy.as_str());
+	let primary_prev = crate::note_snapshot(primary);
+
+	primary.text = merged.text;
+	primary.source_ref = merged.source_ref;
+	primary.importance = primary.importance.max(secondary.importance);
+	primary.confidence = primary.confidence.max(secondary.confidence);
+	primary.expires_at = match (primary.expires_at, secondary.expires_at) {
+		(Some(primary_expiry), Some(secondary_expiry)) =>
+			Some(primary_expiry.max(secondary_expiry)),
+		_ => None,
+	};
+	primary.updated_at = now;
+
+	update_note(tx, primary).await?;
+
+	let primary_version_id = crate::insert_version(
+		tx,
+		InsertVersionArgs {
+			note_id: primary.note_id,
+			op: "UPDATE",
+			prev_snapshot: Some(primary_prev),
+			new_snapshot: Some(crate::note_snapshot(primary)),
+			reason: reason.as_str(),
+			actor,
+			ts: now,
+		},
+	)
+	.await?;
+
+	if !merged.pii_redactions.is_empty() {
+		crate::record_version_redactions(&mut **tx, primary_version_id, &merged.pii_redactions)
+			.await?;
+	}
+	if let Some(structured) = merged.structured.as_ref() {
+		structured_fields::upsert_structured_fields_tx(tx, primary.note_id, structured, now)
+			.await?;
+	}
+
+	let evidence_links_added =
+		copy_fact_evidence(tx, secondary.note_id, primary.note_id, now).await?;
+	let secondary_prev = crate::note_snapshot(secondary);
+
+	secondary.status = "deprecated".to_string();
+	secondary.source_ref = combine::mark_merged_into(&secondary.source_ref, primary.note_id);
+	secondary.updated_at = now;
+
+	update_note(tx, secondary).await?;
+	crate::insert_version(
+		tx,
+		InsertVersionArgs {
+			note_id: secondary.note_id,
+			op: "DEPRECATE",
+			prev_snapshot: Some(secondary_prev),
+			new_snapshot: Some(crate::note_snapshot(secondary)),
+			reason: reason.as_str(),
+			actor,
+			ts: now,
+		},
+	)
+	.await?;
+	crate::enqueue_outbox_tx(&mut **tx, primary.note_id, "UPSERT", &primary.embedding_version, now)
+		.await?;
+	crate::enqueue_outbox_tx(
+		&mut **tx,
+		secondary.note_id,
+		"DELETE",
+		&secondary.embedding_version,
+		now,
+	)
+	.await?;
+
+	Ok(evidence_links_added)
+}
+
+async fn update_note(tx: &mut Transaction<'_, Postgres>, note: &MemoryNote) -> Result<()> {
+	sqlx::query(
+		"\
+UPDATE memory_notes
+SET
+	text = $1,
+	importance = $2,
+	confidence = $3,
+	status = $4,
+	source_ref = $5,
+	expires_at = $6,
+	updated_at = $7
+WHERE note_id = $8",
+	)
+	.bind(note.text.as_str())
+	.bind(note.importance)
+	.bind(note.confidence)
+	.bind(note.status.as_str())
+	.bind(&note.source_ref)
+	.bind(note.expires_at)
+	.bind(note.updated_at)
+	.bind(note.note_id)
+	.execute(&mut **tx)
+	.await?;
+
+	Ok(())
+}
+
+async fn copy_fact_evidence(
+	tx: &mut Transaction<'_, Postgres>,
+	from_note_id: Uuid,
+	to_note_id: Uuid,
+	now: OffsetDateTime,
+) -> Result<u64> {
+	let fact_ids: Vec<Uuid> = sqlx::query_scalar(
+		"\
+SELECT fact_id
+FROM graph_fact_evidence
+WHERE note_id = $1
+	AND fact_id NOT IN (SELECT fact_id FROM graph_fact_evidence WHERE note_id = $2)
+ORDER BY fact_id",
+	)
+	.bind(from_note_id)
+	.bind(to_note_id)
+	.fetch_all(&mut **tx)
+	.await?;
+	let mut added = 0;
+
+	for fact_id in fact_ids {
+		let result = sqlx::query(
+			"\
+INSERT INTO graph_fact_evidence (evidence_id, fact_id, note_id, created_at)
+VALUES ($1, $2, $3, $4)
+ON CONFLICT (fact_id, note_id) DO NOTHING",
+		)
+		.bind(Uuid::new_v4())
+		.bind(fact_id)
+		.bind(to_note_id)
+		.bind(now)
+		.execute(&mut **tx)
+		.await?;
+
+		added += result.rows_affected();
+	}
+
+	Ok(added)
+}
@@ -0,0 +1,106 @@
+use uuid::Uuid;
+
+use crate::{merge::combine, structured_fields::StructuredFields};
+
+#[test]
+fn concatenate_skips_text_already_contained() {
+	assert_eq!(
+		combine::concatenate_texts("Deploys run on Fridays.", "Rollbacks need approval."),
+		"Deploys run on Fridays.\n\nRollbacks need approval."
+	);
+	assert_eq!(
+		combine::concatenate_texts(
+			"Deploys run on Fridays after review.",
+			"Deploys run on Fridays"
+		),
+		"Deploys run on Fridays after review."
+	);
+	assert_eq!(
+		combine::concatenate_texts(
+			"Deploys run on Fridays",
+			" Deploys run on Fridays after review. "
+		),
+		"Deploys run on Fridays after review."
+	);
+}
+
+#[test]
+fn merged_text_requires_non_empty_text_field() {
+	assert_eq!(
+		combine::parse_merged_text(serde_json::json!({ "text": " Combined note. " }))
+			.expect("text should parse"),
+		"Combined note."
+	);
+	assert!(combine::parse_merged_text(serde_json::json!({ "text": "  " })).is_err());
+	assert!(combine::parse_merged_text(serde_json::json!({ "summary": "x" })).is_err());
+}
+
+#[test]
+fn source_refs_union_stays_flat_across_repeated_merges() {
+	let first = Uuid::new_v4();
+	let second = Uuid::new_v4();
+	let primary = serde_json::json!({ "schema": "test/source", "thread": "a" });
+	let secondary = serde_json::json!({ "schema": "test/source", "thread": "b" });
+	let merged = combine::merge_source_refs(&primary, &secondary, first);
+
+	assert_eq!(merged["thread"], "a");
+	assert_eq!(merged["merged_source_refs"], serde_json::json!([secondary]));
+	assert_eq!(merged["merged_note_ids"], serde_json::json!([first.to_string()]));
+
+	let other = serde_json::json!({
+		"schema": "test/source",
+		"thread": "c",
+		"merged_source_refs": [secondary, primary],
+		"merged_note_ids": [first.to_string()],
+	});
+	let remerged = combine::merge_source_refs(&merged, &other, second);
+
+	assert_eq!(
+		remerged["merged_source_refs"],
+		serde_json::json!([secondary, { "schema": "test/source", "thread": "c" }])
+	);
+	assert_eq!(
+		remerged["merged_note_ids"],
+		serde_json::json!([first.to_string(), second.to_string()])
+	);
+
+	let marked = combine::mark_merged_into(&secondary, second);
+
+	assert_eq!(marked["thread"], "b");
+	assert_eq!(marked["merged_into"], second.to_string());
+}
+
+#[test]
+fn structured_fields_keep_primary_summary_and_union_items() {
+	let primary = StructuredFields {
+		summary: Some("Primary summary.".to_string()),
+		facts: Some(vec!["Deploys run on Fridays.".to_string()]),
+		concepts: None,
+		question: None,
+		answer: None,
+		entities: None,
+		relations: None,
+	};
+	let secondary = StructuredFields {
+		summary: Some("Secondary summary.".to_string()),
+		facts: Some(vec![
+			"Deploys run on Fridays.".to_string(),
+			"Rollbacks need approval.".to_string(),
+		]),
+		concepts: Some(vec!["deployment".to_string()]),
+		question: None,
+		answer: None,
+		entities: None,
+		relations: None,
+	};
+	let merged = combine::merge_structured_fields(Some(primary), Some(secondary))
+		.expect("merged fields should exist");
+
+	assert_eq!(merged.summary.as_deref(), Some("Primary summary."));
+	assert_eq!(
+		merged.facts,
+		Some(vec!["Deploys run on Fridays.".to_string(), "Rollbacks need approval.".to_string()])
+	);
+	assert_eq!(merged.concepts, Some(vec!["deployment".to_string()]));
+	assert!(combine::merge_structured_fields(None, None).is_none());
+}
@@ -0,0 +1,372 @@
+//! Batch note update and delete that share one transaction and one outbox enqueue.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgConnection;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, NoteOp, Result,
+	audit::{AuditScope, AuditTargets},
+};
+use elf_config::SecurityAuthRole;
+use elf_domain::writegate::RejectFeedback;
+
+/// Maximum number of notes accepted in one batch update or delete.
+pub const MAX_NOTE_BATCH_ITEMS: usize = 200;
+
+/// Caller identity shared by every item of a note write.
+pub(crate) struct NoteWriter<'a> {
+	pub(crate) tenant_id: &'a str,
+	pub(crate) project_id: &'a str,
+	pub(crate) agent_id: &'a str,
+	/// Role checked against each note's scope; `None` skips the check.
+	pub(crate) role: Option<SecurityAuthRole>,
+}
+
+/// One note edit in a batch update.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UpdateBatchItem {
+	/// Identifier of the note to update.
+	pub note_id: Uuid,
+	/// Optional replacement note text.
+	pub text: Option<String>,
+	/// Optional replacement importance score.
+	pub importance: Option<f32>,
+	/// Optional replacement confidence score.
+	pub confidence: Option<f32>,
+	/// Optional TTL override in days.
+	pub ttl_days: Option<i64>,
+}
+
+/// Request payload for batch note updates.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UpdateBatchRequest {
+	/// Tenant that owns the notes.
+	pub tenant_id: String,
+	/// Project that owns the notes.
+	pub project_id: String,
+	/// Agent requesting the updates.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against each note's scope; `None` when auth is
+	/// off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Edits to apply, at most [`MAX_NOTE_BATCH_ITEMS`].
+	pub items: Vec<UpdateBatchItem>,
+}
+
+/// Request payload for batch note deletion.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeleteBatchRequest {
+	/// Tenant that owns the notes.
+	pub tenant_id: String,
+	/// Project that owns the notes.
+	pub project_id: String,
+	/// Agent requesting the deletions.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against each note's scope; `None` when auth is
+	/// off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Notes to move into the trash, at most [`MAX_NOTE_BATCH_ITEMS`].
+	pub note_ids: Vec<Uuid>,
+}
+
+/// Outcome of one note in a batch update or delete.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteBatchItemResult {
+	/// Identifier of the note.
+	pub note_id: Uuid,
+	/// Operation that was applied, or `REJECTED`.
+	pub op: NoteOp,
+	/// Machine-readable rejection code, if the item was rejected.
+	pub reason_code: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Remediation data for a write-gate rejection, if any.
+	pub reject_feedback: Option<RejectFeedback>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Human-readable reason for a rejection that is not a write-gate rejection.
+	pub message: Option<String>,
+}
+
+/// Response payload for batch note updates and deletions.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteBatchResponse {
+	/// Items that changed a note.
+	pub applied: u64,
+	/// Items that were rejected.
+	pub rejected: u64,
+	/// One result per item, in request order.
+	pub results: Vec<NoteBatchItemResult>,
+}
+
+impl AuditTargets for NoteBatchResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		self.results.iter().map(|result| result.note_id).collect()
+	}
+
+	fn audit_reject_reasons(&self) -> Vec<String> {
+		self.results
+			.iter()
+			.filter(|result| result.op == NoteOp::Rejected)
+			.filter_map(|result| result.reason_code.clone())
+			.collect()
+	}
+}
+
+impl ElfService {
+	/// Applies up to [`MAX_NOTE_BATCH_ITEMS`] note updates in one transaction.
+	///
+	/// Each item behaves like [`ElfService::update`] and is additionally checked against the
+	/// caller's role. A missing note, denied scope, non-English text, or write-gate rejection
+	/// rejects only that item; storage failures roll back the whole batch. Reindex jobs for every
+	/// changed note are enqueued together before commit.
+	pub async fn update_batch(&self, req: UpdateBatchRequest) -> Result<NoteBatchResponse> {
+		let scope = AuditScope::new("update_batch")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets(req.items.iter().map(|item| item.note_id));
+
+		self.audited(scope, self.update_batch_inner(req)).await
+	}
+
+	/// Runs [`ElfService::update_batch`] without recording an audit entry.
+	async fn update_batch_inner(&self, req: UpdateBatchRequest) -> Result<NoteBatchResponse> {
+		let now = OffsetDateTime::now_utc();
+		let writer = batch_writer(&req.tenant_id, &req.project_id, &req.agent_id, req.role)?;
+		let note_ids = req.items.iter().map(|item| item.note_id).collect::<Vec<_>>();
+
+		validate_batch_note_ids(&note_ids)?;
+
+		let mut tx = self.db.pool.begin().await?;
+
+		lock_batch_notes(&mut tx, &note_ids, writer.tenant_id).await?;
+
+		let mut jobs = Vec::new();
+		let mut results = Vec::with_capacity(req.items.len());
+
+		for item in &req.items {
+			let result = match self.apply_update(&mut tx, &writer, item, now).await {
+				Ok(applied) => {
+					jobs.extend(applied.outbox);
+
+					NoteBatchItemResult {
+						note_id: applied.response.note_id,
+						op: applied.response.op,
+						reason_code: applied.response.reason_code,
+						reject_feedback: applied.response.reject_feedback,
+						message: None,
+					}
+				},
+				Err(err) => rejected_item(item.note_id, err)?,
+			};
+
+			results.push(result);
+		}
+
+		crate::enqueue_outbox_batch_tx(&mut tx, &jobs, now).await?;
+
+		tx.commit().await?;
+
+		tracing::debug!(items = results.len(), applied = jobs.len(), "Batch update applied.");
+
+		Ok(batch_response(results))
+	}
+
+	/// Moves up to [`MAX_NOTE_BATCH_ITEMS`] notes into the trash in one transaction.
+	///
+	/// Each item behaves like [`ElfService::delete`] and is additionally checked against the
+	/// caller's role. A missing note or denied scope rejects only that item; storage failures
+	/// roll back the whole batch. Delete jobs for every trashed note are enqueued together before
+	/// commit.
+	pub async fn delete_batch(&self, req: DeleteBatchRequest) -> Result<NoteBatchResponse> {
+		let scope = AuditScope::new("delete_batch")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets(req.note_ids.iter().copied());
+
+		self.audited(scope, self.delete_batch_inner(req)).await
+	}
+
+	/// Runs [`ElfService::delete_batch`] without recording an audit entry.
+	async fn delete_batch_inner(&self, req: DeleteBatchRequest) -> Result<NoteBatchResponse> {
+		let now = OffsetDateTime::now_utc();
+		let writer = batch_writer(&req.tenant_id, &req.project_id, &req.agent_id, req.role)?;
+
+		validate_batch_note_ids(&req.note_ids)?;
+
+		let mut tx = self.db.pool.begin().await?;
+
+		lock_batch_notes(&mut tx, &req.note_ids, writer.tenant_id).await?;
+
+		let mut jobs = Vec::new();
+		let mut results = Vec::with_capacity(req.note_ids.len());
+
+		for note_id in &req.note_ids {
+			let result = match self.apply_delete(&mut tx, &writer, *note_id, now).await {
+				Ok((response, outbox)) => {
+					jobs.extend(outbox);
+
+					NoteBatchItemResult {
+						note_id: response.note_id,
+						op: response.op,
+						reason_code: None,
+						reject_feedback: None,
+						message: None,
+					}
+				},
+				Err(err) => rejected_item(*note_id, err)?,
+			};
+
+			results.push(result);
+		}
+
+		crate::enqueue_outbox_batch_tx(&mut tx, &jobs, now).await?;
+
+		tx.commit().await?;
+
+		tracing::debug!(items = results.len(), applied = jobs.len(), "Batch delete applied.");
+
+		Ok(batch_response(results))
+	}
+}
+
+fn batch_writer<'a>(
+	tenant_id: &'a str,
+	project_id: &'a str,
+	agent_id: &'a str,
+	role: Option<SecurityAuthRole>,
+) -> Result<NoteWriter<'a>> {
+	let writer = NoteWriter {
+		tenant_id: tenant_id.trim(),
+		project_id: project_id.trim(),
+		agent_id: agent_id.trim(),
+		role,
+	};
+
+	if writer.tenant_id.is_empty() || writer.project_id.is_empty() || writer.agent_id.is_empty() {
+		return Err(Error::InvalidRequest {
+			message: "tenant_id, project_id, and agent_id are required.".to_string(),
+		});
+	}
+
+	Ok(writer)
+}
+
+fn validate_batch_note_ids(note_ids: &[Uuid]) -> Result<()> {
+	if note_ids.is_empty() {
+		return Err(Error::InvalidRequest {
+			message: "A batch must contain at least one note.".to_string(),
+		});
+	}
+	if note_ids.len() > MAX_NOTE_BATCH_ITEMS {
+		return Err(Error::InvalidRequest {
+			message: format!("A batch must contain at most {MAX_NOTE_BATCH_ITEMS} notes."),
+		});
+	}
+
+	let mut seen = HashSet::with_capacity(note_ids.len());
+
+	if let Some(duplicate) = note_ids.iter().find(|note_id| !seen.insert(**note_id)) {
+		return Err(Error::InvalidRequest {
+			message: format!("Note {duplicate} appears more than once in the batch."),
+		});
+	}
+
+	Ok(())
+}
+
+/// Locks every existing batch note in `note_id` order so concurrent batches cannot deadlock on
+/// request order.
+async fn lock_batch_notes(
+	conn: &mut PgConnection,
+	note_ids: &[Uuid],
+	tenant_id: &str,
+) -> Result<()> {
+	sqlx::query(
+		"\
+SELECT note_id
+FROM memory_notes
+WHERE note_id = ANY($1) AND tenant_id = $2
+ORDER BY note_id
+FOR UPDATE",
+	)
+	.bind(note_ids)
+	.bind(tenant_id)
+	.execute(conn)
+	.await?;
+
+	Ok(())
+}
+
+/// Turns a caller-facing item failure into a rejected result; infrastructure failures abort the
+/// batch.
+fn rejected_item(note_id: Uuid, err: Error) -> Result<NoteBatchItemResult> {
+	let (reason_code, message) = match err {
+		Error::NonEnglishInput { field } =>
+			("NON_ENGLISH_INPUT", format!("Non-English input detected at {field}.")),
+		Error::InvalidRequest { message } => ("INVALID_REQUEST", message),
+		Error::ScopeDenied { message } => ("SCOPE_DENIED", message),
+		Error::NotFound { message } => ("NOT_FOUND", message),
+		Error::Conflict { message } => ("CONFLICT", message),
+		err => return Err(err),
+	};
+
+	Ok(NoteBatchItemResult {
+		note_id,
+		op: NoteOp::Rejected,
+		reason_code: Some(reason_code.to_string()),
+		reject_feedback: None,
+		message: Some(message),
+	})
+}
+
+fn batch_response(results: Vec<NoteBatchItemResult>) -> NoteBatchResponse {
+	let applied = results
+		.iter()
+		.filter(|result| matches!(result.op, NoteOp::Update | NoteOp::Delete))
+		.count() as u64;
+	let rejected = results.iter().filter(|result| result.op == NoteOp::Rejected).count() as u64;
+
+	NoteBatchResponse { applied, rejected, results }
+}
+
+#[cfg(test)]
+mod tests {
+	use uuid::Uuid;
+
+	use crate::{Error, NoteOp, note_batch};
+
+	#[test]
+	fn batch_note_ids_must_be_non_empty_bounded_and_unique() {
+		let note_id = Uuid::new_v4();
+
+		assert!(note_batch::validate_batch_note_ids(&[]).is_err());
+		assert!(note_batch::validate_batch_note_ids(&[note_id, note_id]).is_err());
+		assert!(
+			note_batch::validate_batch_note_ids(
+				&(0..=note_batch::MAX_NOTE_BATCH_ITEMS).map(|_| Uuid::new_v4()).collect::<Vec<_>>()
+			)
+			.is_err()
+		);
+		assert!(note_batch::validate_batch_note_ids(&[note_id, Uuid::new_v4()]).is_ok());
+	}
+
+	#[test]
+	fn caller_facing_failures_reject_the_item_and_storage_failures_abort() {
+		let note_id = Uuid::new_v4();
+		let rejected = note_batch::rejected_item(
+			note_id,
+			Error::ScopeDenied { message: "Scope is not allowed.".to_string() },
+		)
+		.expect("Expected a rejected item.");
+
+		assert_eq!(rejected.op, NoteOp::Rejected);
+		assert_eq!(rejected.reason_code.as_deref(), Some("SCOPE_DENIED"));
+		assert!(
+			note_batch::rejected_item(note_id, Error::Storage { message: "down".to_string() })
+				.is_err()
+		);
+	}
+}
@@ -0,0 +1,314 @@
+//! Change feed over note writes for clients that mirror memory into their own caches.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::{ElfService, Error, Result, access, search};
+
+/// Cursor value that resolves to the newest committed event.
+pub const NOTE_EVENTS_CURSOR_LATEST: &str = "latest";
+
+const DEFAULT_LIMIT: u32 = 100;
+const MAX_LIMIT: u32 = 500;
+const MAX_WAIT_MS: u64 = 30_000;
+const POLL_INTERVAL_MS: u64 = 250;
+
+/// Request payload for reading the note change feed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteEventsRequest {
+	/// Tenant to read events from.
+	pub tenant_id: String,
+	/// Project to read events from, alongside org-shared memory.
+	pub project_id: String,
+	/// Agent reading the feed; only events for notes it can read are returned.
+	pub agent_id: String,
+	/// Read profile that determines which scopes are delivered.
+	pub read_profile: String,
+	/// Cursor returned by a previous call, or `latest` to start after the newest event.
+	///
+	/// The feed starts from the oldest retained event when omitted.
+	pub after: Option<String>,
+	/// Maximum number of events to return.
+	pub limit: Option<u32>,
+	/// How long to wait for new events before returning an empty page.
+	pub wait_ms: Option<u64>,
+}
+
+/// One note write delivered by the change feed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteEvent {
+	/// Position of the event; pass it as `after` to resume behind this event.
+	pub cursor: String,
+	/// Version row written alongside the event.
+	pub version_id: Uuid,
+	/// Note that changed.
+	pub note_id: Uuid,
+	/// Write operation (`ADD`, `UPDATE`, `DELETE`, ...).
+	pub op: String,
+	/// Project that owns the note.
+	pub project_id: String,
+	/// Agent that owns the note.
+	pub agent_id: String,
+	/// Scope of the note.
+	pub scope: String,
+	#[serde(with = "crate::time_serde")]
+	/// Time the write happened.
+	pub ts: OffsetDateTime,
+	/// Note snapshot after the write, when the version recorded one. Deletes carry the
+	/// `deleted` status here.
+	pub note: Option<Value>,
+}
+
+/// Response payload for the note change feed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteEventsResponse {
+	/// Events in commit order.
+	pub events: Vec<NoteEvent>,
+	/// Cursor to pass as `after` on the next call.
+	pub next_cursor: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+struct FeedCursor {
+	tx_id: u64,
+	event_seq: i64,
+}
+impl FeedCursor {
+	fn parse(raw: &str) -> Result<Self> {
+		let invalid = || Error::InvalidRequest {
+			message: "after must be a cursor returned by the feed or latest.".to_string(),
+		};
+		let (tx_id, event_seq) = raw.split_once(':').ok_or_else(invalid)?;
+
+		Ok(Self {
+			tx_id: tx_id.parse().map_err(|_| invalid())?,
+			event_seq: event_seq.parse().map_err(|_| invalid())?,
+		})
+	}
+
+	fn encode(self) -> String {
+		format!("{}:{}", self.tx_id, self.event_seq)
+	}
+}
+
+#[derive(sqlx::FromRow)]
+struct NoteEventRow {
+	event_seq: i64,
+	tx_id: String,
+	version_id: Uuid,
+	note_id: Uuid,
+	project_id: String,
+	agent_id: String,
+	scope: String,
+	op: String,
+	ts: OffsetDateTime,
+	note: Option<Value>,
+}
+
+struct EventVisibility<'a> {
+	agent_id: &'a str,
+	scopes: &'a [String],
+	shared_grants: &'a HashSet<access::SharedSpaceGrantKey>,
+}
+impl EventVisibility<'_> {
+	fn allows(&self, row: &NoteEventRow) -> bool {
+		if !self.scopes.iter().any(|scope| scope == &row.scope) {
+			return false;
+		}
+		if row.scope == "agent_private" {
+			return row.agent_id == self.agent_id;
+		}
+		if !access::is_shared_scope(row.scope.as_str()) {
+			return false;
+		}
+
+		row.agent_id == self.agent_id
+			|| self.shared_grants.contains(&access::SharedSpaceGrantKey {
+				scope: row.scope.clone(),
+				space_owner_agent_id: row.agent_id.clone(),
+			})
+	}
+}
+
+impl ElfService {
+	/// Returns note writes committed after `after` that the caller can read.
+	///
+	/// Events are ordered by commit, and an event is only delivered once every transaction that
+	/// started before it has finished, so resuming from `next_cursor` never skips a write that
+	/// committed late. Deletes are delivered like any other write. When no event is ready the
+	/// call waits up to `wait_ms` for one, which lets clients long-poll instead of re-listing.
+	pub async fn note_events(&self, req: NoteEventsRequest) -> Result<NoteEventsResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let limit = req.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+		let wait = Duration::from_millis(req.wait_ms.unwrap_or(0).min(MAX_WAIT_MS));
+		let scopes = search::resolve_read_profile_scopes(&self.cfg, req.read_profile.trim())?;
+		let org_shared_allowed = scopes.iter().any(|scope| scope == "org_shared");
+		let mut project_ids = vec![project_id.to_string()];
+
+		if org_shared_allowed && project_id != access::ORG_PROJECT_ID {
+			project_ids.push(access::ORG_PROJECT_ID.to_string());
+		}
+
+		let mut cursor = match req.after.as_deref().map(str::trim).filter(|raw| !raw.is_empty()) {
+			None => FeedCursor::default(),
+			Some(NOTE_EVENTS_CURSOR_LATEST) =>
+				latest_cursor(&self.db.pool, tenant_id, &project_ids).await?,
+			Some(raw) => FeedCursor::parse(raw)?,
+		};
+		let shared_grants = access::load_shared_read_grants_with_org_shared(
+			&self.db.pool,
+			tenant_id,
+			project_id,
+			agent_id,
+			org_shared_allowed,
+		)
+		.await?;
+		let visibility =
+			EventVisibility { agent_id, scopes: scopes.as_slice(), shared_grants: &shared_grants };
+		let deadline = Instant::now() + wait;
+
+		loop {
+			let rows = fetch_events(&self.db.pool, tenant_id, &project_ids, cursor, limit).await?;
+			let page_full = rows.len() >= limit as usize;
+			let mut events = Vec::new();
+
+			for row in rows {
+				cursor = FeedCursor {
+					tx_id: row.tx_id.parse().map_err(|_| Error::Storage {
+						message: format!("Invalid transaction id in note event: {}.", row.tx_id),
+					})?,
+					event_seq: row.event_seq,
+				};
+
+				if visibility.allows(&row) {
+					events.push(NoteEvent {
+						cursor: cursor.encode(),
+						version_id: row.version_id,
+						note_id: row.note_id,
+						op: row.op,
+						project_id: row.project_id,
+						agent_id: row.agent_id,
+						scope: row.scope,
+						ts: row.ts,
+						note: row.note,
+					});
+				}
+			}
+
+			if !events.is_empty() || (!page_full && Instant::now() >= deadline) {
+				return Ok(NoteEventsResponse { events, next_cursor: cursor.encode() });
+			}
+			if !page_full {
+				tokio::time::sleep(
+					Duration::from_millis(POLL_INTERVAL_MS)
+						.min(deadline.saturating_duration_since(Instant::now())),
+				)
+				.await;
+			}
+		}
+	}
+}
+
+/// Loads up to `limit` events after `cursor` from transactions that can no longer be overtaken.
+async fn fetch_events<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_ids: &[String],
+	cursor: FeedCursor,
+	limit: u32,
+) -> Result<Vec<NoteEventRow>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, NoteEventRow>(
+		"\
+SELECT
+	e.event_seq,
+	e.tx_id::text AS tx_id,
+	e.version_id,
+	e.note_id,
+	e.project_id,
+	e.agent_id,
+	e.scope,
+	e.op,
+	e.ts,
+	v.new_snapshot AS note
+FROM memory_note_events e
+LEFT JOIN memory_note_versions v ON v.version_id = e.version_id
+WHERE e.tenant_id = $1
+	AND e.project_id = ANY($2)
+	AND (e.tx_id, e.event_seq) > ($3::text::xid8, $4)
+	AND e.tx_id < pg_snapshot_xmin(pg_current_snapshot())
+ORDER BY e.tx_id, e.event_seq
+LIMIT $5",
+	)
+	.bind(tenant_id)
+	.bind(project_ids)
+	.bind(cursor.tx_id.to_string())
+	.bind(cursor.event_seq)
+	.bind(i64::from(limit))
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+async fn latest_cursor<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_ids: &[String],
+) -> Result<FeedCursor>
+where
+	E: PgExecutor<'e>,
+{
+	let row: Option<(String, i64)> = sqlx::query_as(
+		"\
+SELECT tx_id::text, event_seq
+FROM memory_note_events
+WHERE tenant_id = $1
+	AND project_id = ANY($2)
+	AND tx_id < pg_snapshot_xmin(pg_current_snapshot())
+ORDER BY tx_id DESC, event_seq DESC
+LIMIT 1",
+	)
+	.bind(tenant_id)
+	.bind(project_ids)
+	.fetch_optional(executor)
+	.await?;
+	let Some((tx_id, event_seq)) = row else {
+		return Ok(FeedCursor::default());
+	};
+
+	FeedCursor::parse(&format!("{tx_id}:{event_seq}"))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::note_events::FeedCursor;
+
+	#[test]
+	fn cursor_round_trips_and_orders_by_transaction_first() {
+		let early = FeedCursor::parse("41:900").expect("Expected a valid cursor.");
+		let late = FeedCursor::parse("42:7").expect("Expected a valid cursor.");
+
+		assert_eq!(early.encode(), "41:900");
+		assert!(early < late);
+		assert!(FeedCursor::parse("42").is_err());
+		assert!(FeedCursor::parse("x:1").is_err());
+	}
+}
@@ -0,0 +1,362 @@
+//! Caller-facing version history of a note.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	access::{self, ORG_PROJECT_ID},
+};
+use elf_storage::models::MemoryNote;
+
+/// Most versions returned by one history read; older versions are dropped first.
+pub const MAX_NOTE_HISTORY_VERSIONS: i64 = 200;
+
+/// Largest token grid diffed word by word. Larger edits are reported as one replacement.
+const MAX_DIFF_CELLS: usize = 1_000_000;
+/// Snapshot fields that change on reads or bookkeeping and are left out of `changed_fields`.
+const IGNORED_SNAPSHOT_FIELDS: &[&str] = &["updated_at", "hit_count", "last_hit_at"];
+
+/// Request payload for a note's version history.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteHistoryRequest {
+	/// Tenant that owns the note.
+	pub tenant_id: String,
+	/// Project the caller is working in.
+	pub project_id: String,
+	/// Agent reading the history.
+	pub agent_id: String,
+	/// Identifier of the note to inspect.
+	pub note_id: Uuid,
+}
+
+/// Version chain of one note, oldest first.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteHistoryResponse {
+	/// Inspected note identifier.
+	pub note_id: Uuid,
+	/// Whether older versions were dropped to respect [`MAX_NOTE_HISTORY_VERSIONS`].
+	pub truncated: bool,
+	/// Recorded versions in commit order.
+	pub versions: Vec<NoteHistoryVersion>,
+}
+
+/// One recorded change to a note.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteHistoryVersion {
+	/// Version identifier.
+	pub version_id: Uuid,
+	/// Version operation, such as `ADD`, `UPDATE`, or `DELETE`.
+	pub op: String,
+	/// Reason recorded with the change.
+	pub reason: String,
+	/// Actor that made the change.
+	pub actor: String,
+	/// Change timestamp.
+	#[serde(with = "crate::time_serde")]
+	pub ts: OffsetDateTime,
+	/// Note text after the change, when the version stored a snapshot.
+	pub text: Option<String>,
+	/// Word-level diff from the previous text to `text`.
+	pub text_diff: Vec<TextDiffSpan>,
+	/// Snapshot fields whose values changed, sorted by name.
+	pub changed_fields: Vec<String>,
+}
+
+/// Kind of a text diff span.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextDiffKind {
+	/// Text present in both versions.
+	Equal,
+	/// Text added by the change.
+	Insert,
+	/// Text removed by the change.
+	Delete,
+}
+
+/// Run of text sharing one diff kind.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct TextDiffSpan {
+	/// Span kind.
+	pub kind: TextDiffKind,
+	/// Span text, including surrounding whitespace.
+	pub text: String,
+}
+
+#[derive(FromRow)]
+struct NoteVersionRow {
+	version_id: Uuid,
+	op: String,
+	prev_snapshot: Option<Value>,
+	new_snapshot: Option<Value>,
+	reason: String,
+	actor: String,
+	ts: OffsetDateTime,
+}
+
+impl ElfService {
+	/// Returns the version chain of a note with text diffs between consecutive versions.
+	///
+	/// The note's owner can read the history in any status. Other agents need read access to the
+	/// active note.
+	pub async fn note_history(&self, req: NoteHistoryRequest) -> Result<NoteHistoryResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let note = sqlx::query_as::<_, MemoryNote>(
+			"\
+SELECT *
+FROM memory_notes
+WHERE note_id = $1
+	AND tenant_id = $2
+	AND (project_id = $3 OR (project_id = $4 AND scope = 'org_shared'))",
+		)
+		.bind(req.note_id)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(ORG_PROJECT_ID)
+		.fetch_optional(&self.db.pool)
+		.await?
+		.ok_or_else(|| Error::InvalidRequest { message: "Note not found.".to_string() })?;
+
+		if note.agent_id != agent_id {
+			let shared_grants = access::load_shared_read_grants_with_org_shared(
+				&self.db.pool,
+				tenant_id,
+				project_id,
+				agent_id,
+				self.cfg.scopes.allowed.iter().any(|scope| scope == "org_shared"),
+			)
+			.await?;
+
+			if !access::note_read_allowed(
+				&note,
+				agent_id,
+				&self.cfg.scopes.allowed,
+				&shared_grants,
+				now,
+			) {
+				return Err(Error::InvalidRequest { message: "Note not found.".to_string() });
+			}
+		}
+
+		let mut rows = sqlx::query_as::<_, NoteVersionRow>(
+			"\
+SELECT version_id, op, prev_snapshot, new_snapshot, reason, actor, ts
+FROM memory_note_versions
+WHERE note_id = $1
+ORDER BY ts DESC, version_id DESC
+LIMIT $2",
+		)
+		.bind(req.note_id)
+		.bind(MAX_NOTE_HISTORY_VERSIONS + 1)
+		.fetch_all(&self.db.pool)
+		.await?;
+		let truncated = rows.len() as i64 > MAX_NOTE_HISTORY_VERSIONS;
+
+		rows.truncate(MAX_NOTE_HISTORY_VERSIONS as usize);
+		rows.reverse();
+
+		Ok(NoteHistoryResponse {
+			note_id: req.note_id,
+			truncated,
+			versions: rows.into_iter().map(history_version).collect(),
+		})
+	}
+}
+
+fn history_version(row: NoteVersionRow) -> NoteHistoryVersion {
+	let prev_text = snapshot_text(row.prev_snapshot.as_ref());
+	let text = snapshot_text(row.new_snapshot.as_ref()).map(ToString::to_string);
+	let text_diff = match (prev_text, text.as_deref()) {
+		(None, None) => Vec::new(),
+		(prev, new) => diff_text(prev.unwrap_or_default(), new.unwrap_or_default()),
+	};
+
+	NoteHistoryVersion {
+		version_id: row.version_id,
+		op: row.op,
+		reason: row.reason,
+		actor: row.actor,
+		ts: row.ts,
+		text,
+		text_diff,
+		changed_fields: changed_fields(row.prev_snapshot.as_ref(), row.new_snapshot.as_ref()),
+	}
+}
+
+fn snapshot_text(snapshot: Option<&Value>) -> Option<&str> {
+	snapshot.and_then(|snapshot| snapshot.get("text")).and_then(Value::as_str)
+}
+
+fn changed_fields(prev: Option<&Value>, new: Option<&Value>) -> Vec<String> {
+	let (Some(prev), Some(new)) = (prev.and_then(Value::as_object), new.and_then(Value::as_object))
+	else {
+		return Vec::new();
+	};
+
+	prev.keys()
+		.chain(new.keys())
+		.filter(|key| !IGNORED_SNAPSHOT_FIELDS.contains(&key.as_str()))
+		.filter(|key| prev.get(key.as_str()) != new.get(key.as_str()))
+		.map(ToString::to_string)
+		.collect::<BTreeSet<_>>()
+		.into_iter()
+		.collect()
+}
+
+/// Diffs two texts over words and whitespace runs using a longest common subsequence.
+fn diff_text(prev: &str, new: &str) -> Vec<TextDiffSpan> {
+	let prev_tokens = tokenize(prev);
+	let new_tokens = tokenize(new);
+	let mut spans = Vec::new();
+
+	if prev_tokens.len().saturating_mul(new_tokens.len()) > MAX_DIFF_CELLS {
+		push_span(&mut spans, TextDiffKind::Delete, prev);
+		push_span(&mut spans, TextDiffKind::Insert, new);
+
+		return spans;
+	}
+
+	let rows = prev_tokens.len();
+	let cols = new_tokens.len();
+	// lcs[i][j] holds the common subsequence length of prev_tokens[i..] and new_tokens[j..].
+	let mut lcs = vec![vec![0_usize; cols + 1]; rows + 1];
+
+	for i in (0..rows).rev() {
+		for j in (0..cols).rev() {
+			lcs[i][j] = if prev_tokens[i] == new_tokens[j] {
+				lcs[i + 1][j + 1] + 1
+			} else {
+				lcs[i + 1][j].max(lcs[i][j + 1])
+			};
+		}
+	}
+
+	let (mut i, mut j) = (0, 0);
+
+	while i < rows && j < cols {
+		if prev_tokens[i] == new_tokens[j] {
+			push_span(&mut spans, TextDiffKind::Equal, prev_tokens[i]);
+
+			i += 1;
+			j += 1;
+		} else if lcs[i + 1][j] >= lcs[i][j + 1] {
+			push_span(&mut spans, TextDiffKind::Delete, prev_tokens[i]);
+
+			i += 1;
+		} else {
+			push_span(&mut spans, TextDiffKind::Insert, new_tokens[j]);
+
+			j += 1;
+		}
+	}
+
+	for token in &prev_tokens[i..] {
+		push_span(&mut spans, TextDiffKind::Delete, token);
+	}
+	for token in &new_tokens[j..] {
+		push_span(&mut spans, TextDiffKind::Insert, token);
+	}
+
+	spans
+}
+
+fn tokenize(text: &str) -> Vec<&str> {
+	let mut tokens = Vec::new();
+	let mut start = 0;
+	let mut in_whitespace = None;
+
+	for (idx, ch) in text.char_indices() {
+		let is_whitespace = ch.is_whitespace();
+
+		if in_whitespace.is_some_and(|current| current != is_whitespace) {
+			tokens.push(&text[start..idx]);
+
+			start = idx;
+		}
+
+		in_whitespace = Some(is_whitespace);
+	}
+
+	if start < text.len() {
+		tokens.push(&text[start..]);
+	}
+
+	tokens
+}
+
+fn push_span(spans: &mut Vec<TextDiffSpan>, kind: TextDiffKind, text: &str) {
+	if text.is_empty() {
+		return;
+	}
+
+	match spans.last_mut() {
+		Some(last) if last.kind == kind => last.text.push_str(text),
+		_ => spans.push(TextDiffSpan { kind, text: text.to_string() }),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use crate::note_history::{self, TextDiffKind, TextDiffSpan};
+
+	fn span(kind: TextDiffKind, text: &str) -> TextDiffSpan {
+		TextDiffSpan { kind, text: text.to_string() }
+	}
+
+	#[test]
+	fn diff_text_marks_replaced_words() {
+		let spans = note_history::diff_text(
+			"Fact: The deploy window is Friday.",
+			"Fact: The deploy window is Thursday.",
+		);
+
+		assert_eq!(
+			spans,
+			vec![
+				span(TextDiffKind::Equal, "Fact: The deploy window is "),
+				span(TextDiffKind::Delete, "Friday."),
+				span(TextDiffKind::Insert, "Thursday."),
+			]
+		);
+	}
+
+	#[test]
+	fn diff_text_from_empty_text_is_one_insert() {
+		assert_eq!(
+			note_history::diff_text("", "Fact: New note."),
+			vec![span(TextDiffKind::Insert, "Fact: New note.")]
+		);
+		assert!(
+			note_history::diff_text("Same text.", "Same text.")
+				.iter()
+				.all(|span| { span.kind == TextDiffKind::Equal })
+		);
+	}
+
+	#[test]
+	fn changed_fields_skips_bookkeeping_fields() {
+		let prev = json!({ "text": "a", "importance": 0.5, "updated_at": "x", "hit_count": 1 });
+		let new = json!({ "text": "a", "importance": 0.7, "updated_at": "y", "hit_count": 2 });
+
+		assert_eq!(note_history::changed_fields(Some(&prev), Some(&new)), vec!["importance"]);
+		assert!(note_history::changed_fields(None, Some(&new)).is_empty());
+	}
+}
@@ -0,0 +1,563 @@
+//! Typed relations between notes.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgConnection, PgExecutor};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, NoteOp, Result, WriteOperation,
+	access::{self, ORG_PROJECT_ID, SharedSpaceGrantKey},
+	audit::{AuditScope, AuditTargets},
+};
+use elf_config::SecurityAuthRole;
+use elf_storage::models::MemoryNote;
+
+/// Kind of link from one note to another.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteRelationKind {
+	/// The source note replaces the target note.
+	Supersedes,
+	/// The source note was produced from the target note.
+	DerivedFrom,
+	/// The notes are related without an ordering.
+	RelatedTo,
+}
+impl NoteRelationKind {
+	/// Returns the stored relation name.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Supersedes => "supersedes",
+			Self::DerivedFrom => "derived_from",
+			Self::RelatedTo => "related_to",
+		}
+	}
+
+	fn parse(raw: &str) -> Result<Self> {
+		match raw {
+			"supersedes" => Ok(Self::Supersedes),
+			"derived_from" => Ok(Self::DerivedFrom),
+			"related_to" => Ok(Self::RelatedTo),
+			other => Err(Error::Storage { message: format!("Unknown note relation: {other}.") }),
+		}
+	}
+}
+
+/// One stored relation between two notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteRelation {
+	/// Relation identifier.
+	pub relation_id: Uuid,
+	/// Note the relation starts from.
+	pub from_note_id: Uuid,
+	/// Note the relation points to.
+	pub to_note_id: Uuid,
+	/// Relation kind.
+	pub relation: NoteRelationKind,
+	/// Agent that created the relation.
+	pub agent_id: String,
+	#[serde(with = "crate::time_serde")]
+	/// Creation timestamp.
+	pub created_at: OffsetDateTime,
+}
+
+/// Request payload for linking two notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteRelationCreateRequest {
+	/// Tenant that owns the notes.
+	pub tenant_id: String,
+	/// Project that owns the source note.
+	pub project_id: String,
+	/// Agent creating the relation; must own the source note.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the source note's scope; `None` when auth
+	/// is off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Source note.
+	pub from_note_id: Uuid,
+	/// Target note; must be readable by the caller.
+	pub to_note_id: Uuid,
+	/// Relation kind.
+	pub relation: NoteRelationKind,
+}
+
+/// Response payload for linking two notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteRelationCreateResponse {
+	/// Stored relation.
+	pub relation: NoteRelation,
+	/// `add` when the relation was created, `none` when it already existed.
+	pub op: NoteOp,
+}
+
+impl AuditTargets for NoteRelationCreateResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.relation.relation_id]
+	}
+}
+
+/// Request payload for removing a relation.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteRelationDeleteRequest {
+	/// Tenant that owns the notes.
+	pub tenant_id: String,
+	/// Project that owns the source note.
+	pub project_id: String,
+	/// Agent removing the relation; must own the source note.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the source note's scope; `None` when auth
+	/// is off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Source note of the relation.
+	pub from_note_id: Uuid,
+	/// Relation to remove.
+	pub relation_id: Uuid,
+}
+
+/// Response payload for removing a relation.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteRelationDeleteResponse {
+	/// Relation identifier.
+	pub relation_id: Uuid,
+	/// `delete` when the relation was removed.
+	pub op: NoteOp,
+}
+
+impl AuditTargets for NoteRelationDeleteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.relation_id]
+	}
+}
+
+/// Request payload for listing a note's direct relations.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteRelationsListRequest {
+	/// Tenant that owns the note.
+	pub tenant_id: String,
+	/// Project to read from, alongside org-shared notes.
+	pub project_id: String,
+	/// Agent requesting the read.
+	pub agent_id: String,
+	/// Note whose relations are listed.
+	pub note_id: Uuid,
+}
+
+/// Response payload for listing a note's direct relations.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteRelationsListResponse {
+	/// Note whose relations are listed.
+	pub note_id: Uuid,
+	/// Outgoing and incoming relations whose other note the caller can read, oldest first.
+	pub relations: Vec<NoteRelation>,
+}
+
+#[derive(FromRow)]
+struct NoteRelationRow {
+	relation_id: Uuid,
+	from_note_id: Uuid,
+	to_note_id: Uuid,
+	relation: String,
+	agent_id: String,
+	created_at: OffsetDateTime,
+}
+
+impl ElfService {
+	/// Links a note the caller owns to another note the caller can read.
+	///
+	/// Creating a relation that already exists returns it with `op = none`. A `supersedes` or
+	/// `derived_from` relation is rejected when the target already points back at the source with
+	/// the same kind.
+	pub async fn note_relation_create(
+		&self,
+		req: NoteRelationCreateRequest,
+	) -> Result<NoteRelationCreateResponse> {
+		let scope = AuditScope::new("note_relation_create")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.from_note_id, req.to_note_id]);
+
+		self.audited(scope, self.note_relation_create_inner(req)).await
+	}
+
+	/// Runs [`ElfService::note_relation_create`] without recording an audit entry.
+	async fn note_relation_create_inner(
+		&self,
+		req: NoteRelationCreateRequest,
+	) -> Result<NoteRelationCreateResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+		if req.from_note_id == req.to_note_id {
+			return Err(Error::InvalidRequest {
+				message: "A note cannot be related to itself.".to_string(),
+			});
+		}
+
+		let mut tx = self.db.pool.begin().await?;
+		let from_note = self
+			.lock_owned_note(&mut tx, req.from_note_id, tenant_id, project_id, agent_id)
+			.await?;
+
+		self.authorize_write(req.role, WriteOperation::Update, from_note.scope.as_str())?;
+
+		if from_note.status != "active" {
+			return Err(Error::InvalidRequest { message: "Note not found.".to_string() });
+		}
+
+		self.ensure_relation_target_readable(
+			&mut tx,
+			req.to_note_id,
+			tenant_id,
+			project_id,
+			agent_id,
+			now,
+		)
+		.await?;
+
+		if req.relation != NoteRelationKind::RelatedTo {
+			let reversed: bool = sqlx::query_scalar(
+				"\
+SELECT EXISTS (
+	SELECT 1
+	FROM memory_note_relations
+	WHERE from_note_id = $1 AND to_note_id = $2 AND relation = $3
+)",
+			)
+			.bind(req.to_note_id)
+			.bind(req.from_note_id)
+			.bind(req.relation.as_str())
+			.fetch_one(&mut *tx)
+			.await?;
+
+			if reversed {
+				return Err(Error::Conflict {
+					message: format!(
+						"The target note already has a {} relation to the source note.",
+						req.relation.as_str()
+					),
+				});
+			}
+		}
+
+		let inserted = sqlx::query_as::<_, NoteRelationRow>(
+			"\
+INSERT INTO memory_note_relations (
+	relation_id,
+	tenant_id,
+	project_id,
+	from_note_id,
+	to_note_id,
+	relation,
+	agent_id,
+	created_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+ON CONFLICT (from_note_id, to_note_id, relation) DO NOTHING
+RETURNING relation_id, from_note_id, to_note_id, relation, agent_id, created_at",
+		)
+		.bind(Uuid::new_v4())
+		.bind(tenant_id)
+		.bind(from_note.project_id.as_str())
+		.bind(req.from_note_id)
+		.bind(req.to_note_id)
+		.bind(req.relation.as_str())
+		.bind(agent_id)
+		.bind(now)
+		.fetch_optional(&mut *tx)
+		.await?;
+		let (row, op) = match inserted {
+			Some(row) => (row, NoteOp::Add),
+			None => {
+				let row = sqlx::query_as::<_, NoteRelationRow>(
+					"\
+SELECT relation_id, from_note_id, to_note_id, relation, agent_id, created_at
+FROM memory_note_relations
+WHERE from_note_id = $1 AND to_note_id = $2 AND relation = $3",
+				)
+				.bind(req.from_note_id)
+				.bind(req.to_note_id)
+				.bind(req.relation.as_str())
+				.fetch_one(&mut *tx)
+				.await?;
+
+				(row, NoteOp::None)
+			},
+		};
+
+		tx.commit().await?;
+
+		Ok(NoteRelationCreateResponse { relation: note_relation(row)?, op })
+	}
+
+	/// Rejects a relation target the caller cannot read.
+	async fn ensure_relation_target_readable(
+		&self,
+		conn: &mut PgConnection,
+		to_note_id: Uuid,
+		tenant_id: &str,
+		project_id: &str,
+		agent_id: &str,
+		now: OffsetDateTime,
+	) -> Result<()> {
+		let to_note = sqlx::query_as::<_, MemoryNote>(
+			"\
+SELECT *
+FROM memory_notes
+WHERE note_id = $1
+	AND tenant_id = $2
+	AND (project_id = $3 OR (project_id = $4 AND scope = 'org_shared'))",
+		)
+		.bind(to_note_id)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(ORG_PROJECT_ID)
+		.fetch_optional(&mut *conn)
+		.await?
+		.ok_or_else(|| Error::InvalidRequest { message: "Target note not found.".to_string() })?;
+		let shared_grants = access::load_shared_read_grants_with_org_shared(
+			&mut *conn,
+			tenant_id,
+			project_id,
+			agent_id,
+			self.cfg.scopes.allowed.iter().any(|scope| scope == "org_shared"),
+		)
+		.await?;
+
+		if !access::note_read_allowed(
+			&to_note,
+			agent_id,
+			&self.cfg.scopes.allowed,
+			&shared_grants,
+			now,
+		) {
+			return Err(Error::InvalidRequest { message: "Target note not found.".to_string() });
+		}
+
+		Ok(())
+	}
+
+	/// Removes a relation that starts from a note the caller owns.
+	pub async fn note_relation_delete(
+		&self,
+		req: NoteRelationDeleteRequest,
+	) -> Result<NoteRelationDeleteResponse> {
+		let scope = AuditScope::new("note_relation_delete")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.from_note_id, req.relation_id]);
+
+		self.audited(scope, self.note_relation_delete_inner(req)).await
+	}
+
+	/// Runs [`ElfService::note_relation_delete`] without recording an audit entry.
+	async fn note_relation_delete_inner(
+		&self,
+		req: NoteRelationDeleteRequest,
+	) -> Result<NoteRelationDeleteResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let mut tx = self.db.pool.begin().await?;
+		let from_note = self
+			.lock_owned_note(&mut tx, req.from_note_id, tenant_id, project_id, agent_id)
+			.await?;
+
+		self.authorize_write(req.role, WriteOperation::Update, from_note.scope.as_str())?;
+
+		let deleted = sqlx::query(
+			"\
+DELETE FROM memory_note_relations
+WHERE relation_id = $1 AND from_note_id = $2 AND tenant_id = $3",
+		)
+		.bind(req.relation_id)
+		.bind(req.from_note_id)
+		.bind(tenant_id)
+		.execute(&mut *tx)
+		.await?
+		.rows_affected();
+
+		if deleted == 0 {
+			return Err(Error::NotFound { message: "Relation not found.".to_string() });
+		}
+
+		tx.commit().await?;
+
+		Ok(NoteRelationDeleteResponse { relation_id: req.relation_id, op: NoteOp::Delete })
+	}
+
+	/// Lists a readable note's outgoing and incoming relations.
+	pub async fn note_relations_list(
+		&self,
+		req: NoteRelationsListRequest,
+	) -> Result<NoteRelationsListResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let shared_grants = access::load_shared_read_grants_with_org_shared(
+			&self.db.pool,
+			tenant_id,
+			project_id,
+			agent_id,
+			self.cfg.scopes.allowed.iter().any(|scope| scope == "org_shared"),
+		)
+		.await?;
+		let ctx = ReadContext {
+			tenant_id,
+			project_id,
+			agent_id,
+			allowed_scopes: &self.cfg.scopes.allowed,
+			shared_grants: &shared_grants,
+			now,
+		};
+
+		if load_readable_notes(&self.db.pool, &[req.note_id], ctx).await?.is_empty() {
+			return Err(Error::InvalidRequest { message: "Note not found.".to_string() });
+		}
+
+		let mut relations = load_direct_relations(&self.db.pool, &[req.note_id], ctx).await?;
+
+		Ok(NoteRelationsListResponse {
+			note_id: req.note_id,
+			relations: relations.remove(&req.note_id).unwrap_or_default(),
+		})
+	}
+}
+
+/// Caller context used to decide which related notes may be shown.
+#[derive(Clone, Copy)]
+pub(crate) struct ReadContext<'a> {
+	pub(crate) tenant_id: &'a str,
+	pub(crate) project_id: &'a str,
+	pub(crate) agent_id: &'a str,
+	pub(crate) allowed_scopes: &'a [String],
+	pub(crate) shared_grants: &'a HashSet<SharedSpaceGrantKey>,
+	pub(crate) now: OffsetDateTime,
+}
+
+/// Loads the direct relations of `note_ids`, keyed by each requested note.
+///
+/// A relation is kept only when the note on its other end is readable by the caller, so private
+/// notes of other agents never leak through relation ids.
+pub(crate) async fn load_direct_relations<'e, E>(
+	executor: E,
+	note_ids: &[Uuid],
+	ctx: ReadContext<'_>,
+) -> Result<HashMap<Uuid, Vec<NoteRelation>>>
+where
+	E: PgExecutor<'e> + Copy,
+{
+	if note_ids.is_empty() {
+		return Ok(HashMap::new());
+	}
+
+	let rows = sqlx::query_as::<_, NoteRelationRow>(
+		"\
+SELECT relation_id, from_note_id, to_note_id, relation, agent_id, created_at
+FROM memory_note_relations
+WHERE tenant_id = $1 AND (from_note_id = ANY($2::uuid[]) OR to_note_id = ANY($2::uuid[]))
+ORDER BY created_at ASC, relation_id ASC",
+	)
+	.bind(ctx.tenant_id)
+	.bind(note_ids)
+	.fetch_all(executor)
+	.await?;
+	let requested = note_ids.iter().copied().collect::<HashSet<_>>();
+	let endpoints = rows
+		.iter()
+		.flat_map(|row| [row.from_note_id, row.to_note_id])
+		.collect::<HashSet<_>>()
+		.into_iter()
+		.collect::<Vec<_>>();
+	let readable = load_readable_notes(executor, &endpoints, ctx).await?;
+	let mut out: HashMap<Uuid, Vec<NoteRelation>> = HashMap::new();
+
+	for row in rows {
+		if !readable.contains(&row.from_note_id) || !readable.contains(&row.to_note_id) {
+			continue;
+		}
+
+		let relation = note_relation(row)?;
+
+		for note_id in [relation.from_note_id, relation.to_note_id] {
+			if requested.contains(&note_id) {
+				out.entry(note_id).or_default().push(relation.clone());
+			}
+		}
+	}
+
+	Ok(out)
+}
+
+async fn load_readable_notes<'e, E>(
+	executor: E,
+	note_ids: &[Uuid],
+	ctx: ReadContext<'_>,
+) -> Result<HashSet<Uuid>>
+where
+	E: PgExecutor<'e>,
+{
+	let notes = sqlx::query_as::<_, MemoryNote>(
+		"\
+SELECT *
+FROM memory_notes
+WHERE note_id = ANY($1::uuid[])
+	AND tenant_id = $2
+	AND (project_id = $3 OR (project_id = $4 AND scope = 'org_shared'))",
+	)
+	.bind(note_ids)
+	.bind(ctx.tenant_id)
+	.bind(ctx.project_id)
+	.bind(ORG_PROJECT_ID)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(notes
+		.into_iter()
+		.filter(|note| {
+			access::note_read_allowed(
+				note,
+				ctx.agent_id,
+				ctx.allowed_scopes,
+				ctx.shared_grants,
+				ctx.now,
+			)
+		})
+		.map(|note| note.note_id)
+		.collect())
+}
+
+fn note_relation(row: NoteRelationRow) -> Result<NoteRelation> {
+	Ok(NoteRelation {
+		relation_id: row.relation_id,
+		from_note_id: row.from_note_id,
+		to_note_id: row.to_note_id,
+		relation: NoteRelationKind::parse(row.relation.as_str())?,
+		agent_id: row.agent_id,
+		created_at: row.created_at,
+	})
+}
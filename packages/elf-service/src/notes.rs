@@ -1,4 +1,17 @@
-//! Individual note fetch APIs.
+//! Individual note fetch, citation, and source_ref resolution APIs.
+
+mod access_stats;
+mod cite;
+mod source_refs;
+
+pub use self::{
+	access_stats::{NoteAccessQuery, NoteAccessStats},
+	cite::{NoteCitation, NoteCitationQuoteSource, NotesCiteRequest, NotesCiteResponse},
+	source_refs::{
+		SourceRefResolution, SourceRefResolutionStatus, SourceRefsResolveRequest,
+		SourceRefsResolveResponse,
+	},
+};
 
 use std::{collections::HashSet, slice};
 
@@ -23,8 +36,13 @@ pub struct NoteFetchRequest {
 	pub project_id: String,
 	/// Agent requesting the read.
 	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
 	/// Identifier of the note to fetch.
 	pub note_id: Uuid,
+	#[serde(default)]
+	/// Whether to attach retrieval analytics for the note.
+	pub include_access_stats: bool,
 }
 
 /// Response payload for fetching one note.
@@ -62,6 +80,9 @@ pub struct NoteFetchResponse {
 	pub source_ref: Value,
 	/// Structured fields stored for the note, when present.
 	pub structured: Option<StructuredFields>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Retrieval analytics, present only when requested.
+	pub access_stats: Option<NoteAccessStats>,
 }
 
 impl ElfService {
@@ -123,6 +144,21 @@ WHERE note_id = $1
 		)
 		.await?
 		.remove(&note.note_id);
+		let access_stats = if req.include_access_stats {
+			Some(
+				access_stats::load_access_stats(
+					&self.db.pool,
+					&note,
+					tenant_id,
+					project_id,
+					agent_id,
+					self.trace_admin_override(req.token_id.as_deref()),
+				)
+				.await?,
+			)
+		} else {
+			None
+		};
 
 		Ok(NoteFetchResponse {
 			note_id: note.note_id,
@@ -140,6 +176,9 @@ WHERE note_id = $1
 			expires_at: note.expires_at,
 			source_ref: note.source_ref,
 			structured,
+			access_stats,
 		})
 	}
 }
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use time::OffsetDateTime;
+
+use crate::{Result, search::shared_trace_predicate};
+use elf_storage::models::MemoryNote;
+
+const MAX_RECENT_ACCESS_QUERIES: i64 = 10;
+
+/// Retrieval analytics for one note.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteAccessStats {
+	/// Number of times the note was returned by search.
+	pub hit_count: i64,
+	#[serde(with = "crate::time_serde::option")]
+	/// Timestamp of the most recent counted hit.
+	pub last_hit_at: Option<OffsetDateTime>,
+	#[serde(with = "crate::time_serde::option")]
+	/// Timestamp of the earliest recorded retrieval by any agent.
+	pub first_retrieved_at: Option<OffsetDateTime>,
+	#[serde(with = "crate::time_serde::option")]
+	/// Timestamp of the latest recorded retrieval by any agent.
+	pub last_retrieved_at: Option<OffsetDateTime>,
+	/// Distinct recent queries that retrieved the note, newest first.
+	pub recent_queries: Vec<NoteAccessQuery>,
+}
+
+/// One distinct query that retrieved a note.
+#[derive(Clone, Debug, Deserialize, Serialize, FromRow)]
+pub struct NoteAccessQuery {
+	/// Query text recorded on the search trace.
+	pub query: String,
+	/// Number of retained traces that returned the note for this query.
+	pub retrievals: i64,
+	/// Best (lowest) rank observed for the note.
+	pub best_rank: i32,
+	#[serde(with = "crate::time_serde")]
+	/// Most recent trace timestamp for this query.
+	pub last_retrieved_at: OffsetDateTime,
+}
+
+#[derive(FromRow)]
+struct HitWindowRow {
+	first_retrieved_at: Option<OffsetDateTime>,
+	last_retrieved_at: Option<OffsetDateTime>,
+}
+
+/// Loads retrieval analytics for `note` as seen by `caller_agent_id`.
+///
+/// Recent queries only come from traces the caller may read under the search trace visibility
+/// rule. The retrieval window is intentionally global, like `hit_count`: `memory_hits` rows carry
+/// no agent or trace, and timestamps alone do not reveal query text.
+pub(super) async fn load_access_stats(
+	pool: &PgPool,
+	note: &MemoryNote,
+	tenant_id: &str,
+	project_id: &str,
+	caller_agent_id: &str,
+	admin_override: bool,
+) -> Result<NoteAccessStats> {
+	let window = sqlx::query_as::<_, HitWindowRow>(
+		"\
+SELECT
+	min(ts) AS first_retrieved_at,
+	max(ts) AS last_retrieved_at
+FROM memory_hits
+WHERE note_id = $1",
+	)
+	.bind(note.note_id)
+	.fetch_one(pool)
+	.await?;
+	let recent_queries = sqlx::query_as::<_, NoteAccessQuery>(concat!(
+		"\
+SELECT
+	t.query,
+	count(*) AS retrievals,
+	min(i.rank) AS best_rank,
+	max(t.created_at) AS last_retrieved_at
+FROM search_trace_items i
+JOIN search_traces t ON t.trace_id = i.trace_id
+WHERE i.note_id = $1
+	AND t.tenant_id = $2
+	AND t.project_id = $3
+	AND (
+		$6::bool
+		OR t.agent_id = $5
+		OR ",
+		shared_trace_predicate!("t.trace_id"),
+		"
+	)
+GROUP BY t.query
+ORDER BY last_retrieved_at DESC, t.query ASC
+LIMIT $4",
+	))
+	.bind(note.note_id)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(MAX_RECENT_ACCESS_QUERIES)
+	.bind(caller_agent_id)
+	.bind(admin_override)
+	.fetch_all(pool)
+	.await?;
+
+	Ok(NoteAccessStats {
+		hit_count: note.hit_count,
+		last_hit_at: note.last_hit_at,
+		first_retrieved_at: window.first_retrieved_at,
+		last_retrieved_at: window.last_retrieved_at,
+		recent_queries,
+	})
+}
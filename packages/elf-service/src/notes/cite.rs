@@ -0,0 +1,313 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	access::{self, ORG_PROJECT_ID},
+};
+use elf_storage::models::MemoryNote;
+
+const MAX_CITE_NOTE_IDS: usize = 50;
+const MAX_CITATION_QUOTE_CHARS: usize = 280;
+pub(super) const DOC_POINTER_RESOLVER: &str = "elf_doc_ext/v1";
+const EXTERNAL_URL_RESOLVER: &str = "external_url/v1";
+
+/// Request payload for formatting citations for a batch of notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NotesCiteRequest {
+	/// Tenant that owns the notes.
+	pub tenant_id: String,
+	/// Project that owns the notes.
+	pub project_id: String,
+	/// Agent requesting the citations.
+	pub agent_id: String,
+	/// Notes to cite, in the order the citations should be returned.
+	pub note_ids: Vec<Uuid>,
+}
+
+/// Response payload for note citations.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NotesCiteResponse {
+	/// One citation per readable note, in request order.
+	pub citations: Vec<NoteCitation>,
+	/// Requested notes that do not exist or are not visible to the caller.
+	pub missing_note_ids: Vec<Uuid>,
+}
+
+/// Where a citation quote was taken from.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteCitationQuoteSource {
+	/// `locator.quote.exact` of an `elf_doc_ext/v1` doc pointer.
+	DocQuote,
+	/// First evidence quote recorded by `add_event`.
+	EventEvidence,
+	/// The note text itself, used when no evidence quote is stored.
+	NoteText,
+}
+
+/// Citation block for one note, ready for inclusion in an agent answer.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NoteCitation {
+	/// Cited note identifier.
+	pub note_id: Uuid,
+	/// Scope key for the note.
+	pub scope: String,
+	#[serde(with = "crate::time_serde")]
+	/// Note creation timestamp.
+	pub created_at: OffsetDateTime,
+	/// Key evidence quote, whitespace-collapsed and bounded in length.
+	pub quote: String,
+	/// Origin of `quote`.
+	pub quote_source: NoteCitationQuoteSource,
+	/// Human-readable source locator.
+	pub locator: String,
+	/// Backing document when the note points into Doc Extension v1.
+	pub doc_id: Option<Uuid>,
+	/// Formatted two-line citation block: the quote, then locator, scope, and creation date.
+	pub text: String,
+}
+
+impl ElfService {
+	/// Builds citation blocks for the readable notes among `note_ids`.
+	pub async fn notes_cite(&self, req: NotesCiteRequest) -> Result<NotesCiteResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let mut seen = HashSet::new();
+		let note_ids: Vec<Uuid> =
+			req.note_ids.into_iter().filter(|note_id| seen.insert(*note_id)).collect();
+
+		if note_ids.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "note_ids must contain at least one note.".to_string(),
+			});
+		}
+		if note_ids.len() > MAX_CITE_NOTE_IDS {
+			return Err(Error::InvalidRequest {
+				message: format!("note_ids must contain at most {MAX_CITE_NOTE_IDS} notes."),
+			});
+		}
+
+		let allowed_scopes = self.cfg.scopes.allowed.clone();
+		let mut readable = self
+			.load_readable_notes(tenant_id, project_id, agent_id, &allowed_scopes, &note_ids, now)
+			.await?;
+		let doc_ids: Vec<Uuid> =
+			readable.values().filter_map(|note| doc_pointer_id(&note.source_ref)).collect();
+		let doc_titles = self.load_doc_titles(tenant_id, doc_ids.as_slice()).await?;
+		let mut citations = Vec::with_capacity(readable.len());
+		let mut missing_note_ids = Vec::new();
+
+		for note_id in note_ids {
+			match readable.remove(&note_id) {
+				Some(note) => citations.push(build_note_citation(&note, &doc_titles)),
+				None => missing_note_ids.push(note_id),
+			}
+		}
+
+		Ok(NotesCiteResponse { citations, missing_note_ids })
+	}
+
+	/// Loads the notes among `note_ids` that the agent may read within `allowed_scopes`.
+	pub(super) async fn load_readable_notes(
+		&self,
+		tenant_id: &str,
+		project_id: &str,
+		agent_id: &str,
+		allowed_scopes: &[String],
+		note_ids: &[Uuid],
+		now: OffsetDateTime,
+	) -> Result<HashMap<Uuid, MemoryNote>> {
+		let org_shared_allowed = allowed_scopes.iter().any(|scope| scope == "org_shared");
+		let rows: Vec<MemoryNote> = sqlx::query_as::<_, MemoryNote>(
+			"\
+SELECT *
+FROM memory_notes
+WHERE note_id = ANY($1)
+  AND tenant_id = $2
+  AND (
+    project_id = $3
+    OR (project_id = $4 AND scope = 'org_shared')
+  )",
+		)
+		.bind(note_ids)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(ORG_PROJECT_ID)
+		.fetch_all(&self.db.pool)
+		.await?;
+		let shared_grants = access::load_shared_read_grants_with_org_shared(
+			&self.db.pool,
+			tenant_id,
+			project_id,
+			agent_id,
+			org_shared_allowed,
+		)
+		.await?;
+
+		Ok(rows
+			.into_iter()
+			.filter(|note| {
+				access::note_read_allowed(note, agent_id, allowed_scopes, &shared_grants, now)
+			})
+			.map(|note| (note.note_id, note))
+			.collect())
+	}
+
+	async fn load_doc_titles(
+		&self,
+		tenant_id: &str,
+		doc_ids: &[Uuid],
+	) -> Result<HashMap<Uuid, String>> {
+		if doc_ids.is_empty() {
+			return Ok(HashMap::new());
+		}
+
+		let rows: Vec<(Uuid, String)> = sqlx::query_as(
+			"\
+SELECT doc_id, title
+FROM doc_documents
+WHERE doc_id = ANY($1) AND tenant_id = $2 AND status = 'active' AND title IS NOT NULL",
+		)
+		.bind(doc_ids)
+		.bind(tenant_id)
+		.fetch_all(&self.db.pool)
+		.await?;
+
+		Ok(rows.into_iter().collect())
+	}
+}
+
+pub(crate) fn build_note_citation(
+	note: &MemoryNote,
+	doc_titles: &HashMap<Uuid, String>,
+) -> NoteCitation {
+	let source_ref = &note.source_ref;
+	let doc_id = doc_pointer_id(source_ref);
+	let (quote, quote_source) = citation_quote(note);
+	let locator = citation_locator(note, doc_id, doc_titles);
+	let text = format!(
+		"> {quote}\n-- {locator}; scope {}; created {}",
+		note.scope,
+		note.created_at.date()
+	);
+
+	NoteCitation {
+		note_id: note.note_id,
+		scope: note.scope.clone(),
+		created_at: note.created_at,
+		quote,
+		quote_source,
+		locator,
+		doc_id,
+		text,
+	}
+}
+
+fn citation_quote(note: &MemoryNote) -> (String, NoteCitationQuoteSource) {
+	let source_ref = &note.source_ref;
+	let doc_quote = (resolver(source_ref) == Some(DOC_POINTER_RESOLVER))
+		.then(|| source_ref.pointer("/locator/quote/exact").and_then(Value::as_str))
+		.flatten();
+	let event_quote = source_ref.get("evidence").and_then(Value::as_array).and_then(|evidence| {
+		evidence.iter().find_map(|item| item.get("quote").and_then(Value::as_str))
+	});
+	let (raw, source) = match (doc_quote, event_quote) {
+		(Some(quote), _) if !quote.trim().is_empty() => (quote, NoteCitationQuoteSource::DocQuote),
+		(_, Some(quote)) if !quote.trim().is_empty() =>
+			(quote, NoteCitationQuoteSource::EventEvidence),
+		_ => (note.text.as_str(), NoteCitationQuoteSource::NoteText),
+	};
+
+	(bounded_quote(raw), source)
+}
+
+fn citation_locator(
+	note: &MemoryNote,
+	doc_id: Option<Uuid>,
+	doc_titles: &HashMap<Uuid, String>,
+) -> String {
+	let source_ref = &note.source_ref;
+
+	if let Some(doc_id) = doc_id {
+		let title = doc_titles
+			.get(&doc_id)
+			.map(String::as_str)
+			.or_else(|| source_ref.pointer("/hints/title").and_then(Value::as_str));
+		let mut locator = match title {
+			Some(title) => format!("\"{title}\" (doc {doc_id})"),
+			None => format!("doc {doc_id}"),
+		};
+
+		if let Some(span_id) = source_ref
+			.pointer("/locator/span_id")
+			.or_else(|| source_ref.pointer("/ref/source_span_id"))
+			.and_then(Value::as_str)
+		{
+			locator.push_str(&format!(", span {span_id}"));
+		} else if let Some(chunk_id) = source_ref.pointer("/ref/chunk_id").and_then(Value::as_str) {
+			locator.push_str(&format!(", chunk {chunk_id}"));
+		}
+
+		return locator;
+	}
+	if resolver(source_ref) == Some(EXTERNAL_URL_RESOLVER)
+		&& let Some(url) = source_ref.pointer("/ref/url").and_then(Value::as_str)
+	{
+		return url.to_string();
+	}
+	if let Some(message_index) = source_ref
+		.get("evidence")
+		.and_then(Value::as_array)
+		.and_then(|evidence| evidence.first())
+		.and_then(|item| item.get("message_index"))
+		.and_then(Value::as_u64)
+	{
+		return format!("event message {message_index}");
+	}
+	if let Some(uri) = source_ref.pointer("/hints/uri").and_then(Value::as_str) {
+		return uri.to_string();
+	}
+
+	format!("note {}", note.note_id)
+}
+
+pub(super) fn doc_pointer_id(source_ref: &Value) -> Option<Uuid> {
+	if resolver(source_ref) != Some(DOC_POINTER_RESOLVER) {
+		return None;
+	}
+
+	source_ref.pointer("/ref/doc_id").and_then(Value::as_str).and_then(|raw| raw.parse().ok())
+}
+
+pub(super) fn resolver(source_ref: &Value) -> Option<&str> {
+	source_ref.get("resolver").and_then(Value::as_str)
+}
+
+fn bounded_quote(raw: &str) -> String {
+	let collapsed = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+
+	if collapsed.chars().count() <= MAX_CITATION_QUOTE_CHARS {
+		return collapsed;
+	}
+
+	let mut truncated: String = collapsed.chars().take(MAX_CITATION_QUOTE_CHARS - 3).collect();
+
+	truncated.truncate(truncated.trim_end().len());
+	truncated.push_str("...");
+
+	truncated
+}
@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	DocsExcerptResponse, DocsExcerptsGetRequest, ElfService, Error, Result, TextPositionSelector,
+	TextQuoteSelector,
+	notes::cite::{self, DOC_POINTER_RESOLVER},
+	search,
+};
+use elf_storage::models::MemoryNote;
+
+const MAX_RESOLVE_NOTE_IDS: usize = 50;
+const DEFAULT_RESOLVE_LEVEL: &str = "L1";
+
+/// Request payload for dereferencing note source_refs into doc excerpts.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SourceRefsResolveRequest {
+	/// Tenant that owns the notes.
+	pub tenant_id: String,
+	/// Project that owns the notes.
+	pub project_id: String,
+	/// Agent requesting the evidence.
+	pub agent_id: String,
+	/// Read profile that determines visible note and doc scopes.
+	pub read_profile: String,
+	/// Notes to resolve, in the order the resolutions should be returned.
+	pub note_ids: Vec<Uuid>,
+	/// Excerpt budget level used when a pointer has no `locator.level`; defaults to `L1`.
+	pub level: Option<String>,
+}
+
+/// Outcome of resolving one note's source_ref.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceRefResolutionStatus {
+	/// The doc pointer was dereferenced into an excerpt.
+	Resolved,
+	/// The source_ref is not an `elf_doc_ext/v1` doc pointer.
+	Unsupported,
+	/// The pointed-to doc is missing, unreadable by the caller, or rejected the selectors.
+	Unavailable,
+}
+
+/// Resolved evidence for one note.
+#[derive(Clone, Debug, Serialize)]
+pub struct SourceRefResolution {
+	/// Resolved note identifier.
+	pub note_id: Uuid,
+	/// `resolver` of the note's source_ref, when present.
+	pub resolver: Option<String>,
+	/// Resolution outcome.
+	pub status: SourceRefResolutionStatus,
+	/// Verified excerpt when `status` is `resolved`.
+	pub excerpt: Option<DocsExcerptResponse>,
+	/// Failure reason when `status` is `unavailable`.
+	pub error: Option<String>,
+}
+
+/// Response payload for source_ref resolution.
+#[derive(Clone, Debug, Serialize)]
+pub struct SourceRefsResolveResponse {
+	/// One resolution per readable note, in request order.
+	pub resolutions: Vec<SourceRefResolution>,
+	/// Requested notes that do not exist or are not visible to the caller.
+	pub missing_note_ids: Vec<Uuid>,
+}
+
+impl ElfService {
+	/// Dereferences the doc pointers of the readable notes among `note_ids` into excerpts.
+	///
+	/// Each excerpt is hydrated through [`ElfService::docs_excerpts_get`] with the pointer's chunk
+	/// and selectors, so the caller's doc read access is checked per note.
+	pub async fn resolve_source_refs(
+		&self,
+		req: SourceRefsResolveRequest,
+	) -> Result<SourceRefsResolveResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+		let read_profile = req.read_profile.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let level = req.level.as_deref().map(str::trim).unwrap_or(DEFAULT_RESOLVE_LEVEL);
+
+		if !matches!(level, "L0" | "L1" | "L2") {
+			return Err(Error::InvalidRequest {
+				message: "level must be one of L0, L1, or L2.".to_string(),
+			});
+		}
+
+		let mut seen = HashSet::new();
+		let note_ids: Vec<Uuid> =
+			req.note_ids.iter().copied().filter(|note_id| seen.insert(*note_id)).collect();
+
+		if note_ids.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "note_ids must contain at least one note.".to_string(),
+			});
+		}
+		if note_ids.len() > MAX_RESOLVE_NOTE_IDS {
+			return Err(Error::InvalidRequest {
+				message: format!("note_ids must contain at most {MAX_RESOLVE_NOTE_IDS} notes."),
+			});
+		}
+
+		let allowed_scopes = search::resolve_read_profile_scopes(&self.cfg, read_profile)?;
+		let mut readable = self
+			.load_readable_notes(tenant_id, project_id, agent_id, &allowed_scopes, &note_ids, now)
+			.await?;
+		let mut resolutions = Vec::with_capacity(readable.len());
+		let mut missing_note_ids = Vec::new();
+
+		for note_id in note_ids {
+			let Some(note) = readable.remove(&note_id) else {
+				missing_note_ids.push(note_id);
+
+				continue;
+			};
+			let resolver = cite::resolver(&note.source_ref).map(ToString::to_string);
+			let Some(excerpt_req) = doc_pointer_request(&note, &req, level) else {
+				resolutions.push(SourceRefResolution {
+					note_id,
+					resolver,
+					status: SourceRefResolutionStatus::Unsupported,
+					excerpt: None,
+					error: None,
+				});
+
+				continue;
+			};
+			let resolution = match self.docs_excerpts_get(excerpt_req).await {
+				Ok(excerpt) => SourceRefResolution {
+					note_id,
+					resolver,
+					status: SourceRefResolutionStatus::Resolved,
+					excerpt: Some(excerpt),
+					error: None,
+				},
+				Err(
+					err @ (Error::NotFound { .. }
+					| Error::ScopeDenied { .. }
+					| Error::InvalidRequest { .. }),
+				) => SourceRefResolution {
+					note_id,
+					resolver,
+					status: SourceRefResolutionStatus::Unavailable,
+					excerpt: None,
+					error: Some(err.to_string()),
+				},
+				Err(err) => return Err(err),
+			};
+
+			resolutions.push(resolution);
+		}
+
+		Ok(SourceRefsResolveResponse { resolutions, missing_note_ids })
+	}
+}
+
+pub(super) fn doc_pointer_request(
+	note: &MemoryNote,
+	req: &SourceRefsResolveRequest,
+	default_level: &str,
+) -> Option<DocsExcerptsGetRequest> {
+	let source_ref = &note.source_ref;
+
+	if cite::resolver(source_ref) != Some(DOC_POINTER_RESOLVER) {
+		return None;
+	}
+
+	let doc_id = cite::doc_pointer_id(source_ref)?;
+	let chunk_id = source_ref
+		.pointer("/ref/chunk_id")
+		.and_then(Value::as_str)
+		.and_then(|raw| raw.parse().ok());
+	let quote = source_ref
+		.pointer("/locator/quote")
+		.and_then(|value| serde_json::from_value::<TextQuoteSelector>(value.clone()).ok());
+	let position = source_ref
+		.pointer("/locator/position")
+		.and_then(|value| serde_json::from_value::<TextPositionSelector>(value.clone()).ok());
+	let level = source_ref
+		.pointer("/locator/level")
+		.and_then(Value::as_str)
+		.unwrap_or(default_level)
+		.to_string();
+
+	Some(DocsExcerptsGetRequest {
+		tenant_id: req.tenant_id.trim().to_string(),
+		project_id: req.project_id.trim().to_string(),
+		agent_id: req.agent_id.trim().to_string(),
+		read_profile: req.read_profile.trim().to_string(),
+		doc_id,
+		level,
+		chunk_id,
+		quote,
+		position,
+		explain: None,
+	})
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::notes::{NoteCitationQuoteSource, SourceRefsResolveRequest, cite, source_refs};
+use elf_storage::models::MemoryNote;
+
+fn note_with_source_ref(source_ref: serde_json::Value) -> MemoryNote {
+	let created_at = OffsetDateTime::from_unix_timestamp(1_760_000_000).expect("valid timestamp");
+
+	MemoryNote {
+		note_id: Uuid::from_u128(7),
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		scope: "project_shared".to_string(),
+		r#type: "fact".to_string(),
+		key: None,
+		text: "Deploys   run on\nFridays.".to_string(),
+		importance: 0.5,
+		confidence: 0.9,
+		status: "active".to_string(),
+		created_at,
+		updated_at: created_at,
+		expires_at: None,
+		embedding_version: "v1".to_string(),
+		source_ref,
+		hit_count: 0,
+		last_hit_at: None,
+	}
+}
+
+#[test]
+fn citation_prefers_doc_quote_and_titles_the_doc() {
+	let doc_id = Uuid::from_u128(42);
+	let note = note_with_source_ref(serde_json::json!({
+		"schema": "source_ref/v1",
+		"resolver": "elf_doc_ext/v1",
+		"ref": { "doc_id": doc_id.to_string() },
+		"locator": { "quote": { "exact": "Deploys happen every Friday." }, "span_id": "s-1" },
+	}));
+	let titles = HashMap::from([(doc_id, "Release guide".to_string())]);
+	let citation = cite::build_note_citation(&note, &titles);
+
+	assert_eq!(citation.quote_source, NoteCitationQuoteSource::DocQuote);
+	assert_eq!(citation.doc_id, Some(doc_id));
+	assert_eq!(
+		citation.text,
+		format!(
+			"> Deploys happen every Friday.\n-- \"Release guide\" (doc {doc_id}), span s-1; scope project_shared; created 2025-10-09"
+		)
+	);
+}
+
+#[test]
+fn citation_falls_back_to_event_evidence_then_note_text() {
+	let evidence_note = note_with_source_ref(serde_json::json!({
+		"evidence": [{ "message_index": 2, "quote": "We ship on Fridays." }],
+	}));
+	let citation = cite::build_note_citation(&evidence_note, &HashMap::new());
+
+	assert_eq!(citation.quote, "We ship on Fridays.");
+	assert_eq!(citation.quote_source, NoteCitationQuoteSource::EventEvidence);
+	assert_eq!(citation.locator, "event message 2");
+
+	let bare_note = note_with_source_ref(serde_json::json!({}));
+	let citation = cite::build_note_citation(&bare_note, &HashMap::new());
+
+	assert_eq!(citation.quote, "Deploys run on Fridays.");
+	assert_eq!(citation.quote_source, NoteCitationQuoteSource::NoteText);
+	assert_eq!(citation.locator, format!("note {}", bare_note.note_id));
+}
+
+#[test]
+fn source_ref_resolution_maps_doc_pointer_onto_excerpt_request() {
+	let doc_id = Uuid::from_u128(42);
+	let chunk_id = Uuid::from_u128(43);
+	let req = SourceRefsResolveRequest {
+		tenant_id: " t ".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		read_profile: "private_plus_project".to_string(),
+		note_ids: vec![Uuid::from_u128(7)],
+		level: None,
+	};
+	let pointer = note_with_source_ref(serde_json::json!({
+		"schema": "source_ref/v1",
+		"resolver": "elf_doc_ext/v1",
+		"ref": { "doc_id": doc_id.to_string(), "chunk_id": chunk_id.to_string() },
+		"locator": {
+			"level": "L2",
+			"quote": { "exact": "Deploys happen every Friday." },
+			"position": { "start": 10, "end": 40 },
+		},
+	}));
+	let excerpt_req = source_refs::doc_pointer_request(&pointer, &req, "L1")
+		.expect("Expected a doc pointer request.");
+
+	assert_eq!(excerpt_req.tenant_id, "t");
+	assert_eq!(excerpt_req.doc_id, doc_id);
+	assert_eq!(excerpt_req.chunk_id, Some(chunk_id));
+	assert_eq!(excerpt_req.level, "L2");
+	assert_eq!(
+		excerpt_req.quote.map(|quote| quote.exact).as_deref(),
+		Some("Deploys happen every Friday.")
+	);
+	assert_eq!(excerpt_req.position.map(|position| (position.start, position.end)), Some((10, 40)));
+
+	let bare = note_with_source_ref(serde_json::json!({
+		"resolver": "elf_doc_ext/v1",
+		"ref": { "doc_id": doc_id.to_string() },
+	}));
+
+	assert_eq!(
+		source_refs::doc_pointer_request(&bare, &req, "L1").map(|excerpt_req| excerpt_req.level),
+		Some("L1".to_string())
+	);
+
+	let url = note_with_source_ref(serde_json::json!({
+		"resolver": "external_url/v1",
+		"ref": { "url": "https://example.com" },
+	}));
+
+	assert!(source_refs::doc_pointer_request(&url, &req, "L1").is_none());
+}
@@ -0,0 +1,278 @@
+//! Thresholded topic and entity frequency summaries over shared memory.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgExecutor};
+use time::OffsetDateTime;
+
+use crate::{ElfService, Error, Result, access::ORG_PROJECT_ID, search};
+
+/// Org memory stats response schema identifier.
+pub const ELF_ORG_MEMORY_STATS_SCHEMA_V1: &str = "elf.org_memory_stats/v1";
+
+/// Smallest number of distinct notes an item must appear in before it is reported.
+pub const MIN_NOTE_COUNT: u32 = 5;
+/// Smallest number of distinct authoring agents an item must have before it is reported.
+pub const MIN_AGENT_COUNT: u32 = 3;
+
+const SHARED_SCOPES: [&str; 2] = ["project_shared", "org_shared"];
+const DEFAULT_LIMIT: u32 = 50;
+const MAX_LIMIT: u32 = 200;
+
+/// Request payload for shared memory frequency summaries.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OrgMemoryStatsRequest {
+	/// Tenant to aggregate within.
+	pub tenant_id: String,
+	/// Project to aggregate within, alongside org-shared memory.
+	pub project_id: String,
+	/// Agent requesting the summary.
+	pub agent_id: String,
+	/// Read profile that determines which shared scopes are aggregated.
+	pub read_profile: String,
+	/// Optional note-count threshold; values below the built-in minimum are raised to it.
+	pub min_count: Option<u32>,
+	/// Maximum number of topics and entities to return.
+	pub limit: Option<u32>,
+}
+
+/// Response payload for shared memory frequency summaries.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OrgMemoryStatsResponse {
+	/// Response schema identifier.
+	pub schema: String,
+	/// Shared scopes the counts were computed over.
+	pub scopes: Vec<String>,
+	/// Thresholds applied before reporting an item.
+	pub thresholds: OrgMemoryStatsThresholds,
+	#[serde(with = "crate::time_serde")]
+	/// Timestamp used to exclude expired notes.
+	pub as_of: OffsetDateTime,
+	/// Most frequent structured concepts.
+	pub topics: Vec<OrgMemoryStatsItem>,
+	/// Most frequent graph entities referenced by shared evidence.
+	pub entities: Vec<OrgMemoryStatsItem>,
+	/// Number of topics withheld because they fell below a threshold.
+	pub suppressed_topics: u64,
+	/// Number of entities withheld because they fell below a threshold.
+	pub suppressed_entities: u64,
+}
+
+/// Minimum population required for an item to be reported.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct OrgMemoryStatsThresholds {
+	/// Minimum number of distinct notes.
+	pub min_note_count: u32,
+	/// Minimum number of distinct authoring agents.
+	pub min_agent_count: u32,
+}
+
+/// One reported topic or entity with its population counts.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct OrgMemoryStatsItem {
+	/// Normalized concept text or canonical entity surface.
+	pub label: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Entity kind, when known.
+	pub kind: Option<String>,
+	/// Distinct shared notes mentioning the item.
+	pub note_count: u32,
+	/// Distinct agents that authored those notes.
+	pub agent_count: u32,
+}
+
+#[derive(Clone, Debug, FromRow)]
+struct StatsRow {
+	label: String,
+	kind: Option<String>,
+	note_count: i64,
+	agent_count: i64,
+}
+
+impl ElfService {
+	/// Summarizes topic and entity frequencies over shared scopes only.
+	///
+	/// Private notes never contribute. Items seen in fewer than the threshold number of notes or
+	/// authoring agents are withheld and only counted, so a single agent's or a small group's
+	/// memory cannot be inferred from the summary.
+	pub async fn org_memory_stats(
+		&self,
+		req: OrgMemoryStatsRequest,
+	) -> Result<OrgMemoryStatsResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+		let read_profile = req.read_profile.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let allowed_scopes = search::resolve_read_profile_scopes(&self.cfg, read_profile)?;
+		let scopes = shared_scopes(&allowed_scopes);
+
+		if scopes.is_empty() {
+			return Err(Error::ScopeDenied {
+				message: "Read profile does not include a shared scope.".to_string(),
+			});
+		}
+
+		let thresholds = resolve_thresholds(req.min_count);
+		let limit = req.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as usize;
+		let as_of = OffsetDateTime::now_utc();
+		let topic_rows =
+			fetch_topic_rows(&self.db.pool, tenant_id, project_id, &scopes, as_of).await?;
+		let entity_rows =
+			fetch_entity_rows(&self.db.pool, tenant_id, project_id, &scopes, as_of).await?;
+		let (topics, suppressed_topics) = apply_thresholds(topic_rows, thresholds, limit);
+		let (entities, suppressed_entities) = apply_thresholds(entity_rows, thresholds, limit);
+
+		Ok(OrgMemoryStatsResponse {
+			schema: ELF_ORG_MEMORY_STATS_SCHEMA_V1.to_string(),
+			scopes,
+			thresholds,
+			as_of,
+			topics,
+			entities,
+			suppressed_topics,
+			suppressed_entities,
+		})
+	}
+}
+
+fn shared_scopes(allowed_scopes: &[String]) -> Vec<String> {
+	SHARED_SCOPES
+		.iter()
+		.filter(|scope| allowed_scopes.iter().any(|allowed| allowed == *scope))
+		.map(|scope| (*scope).to_string())
+		.collect()
+}
+
+fn resolve_thresholds(min_count: Option<u32>) -> OrgMemoryStatsThresholds {
+	OrgMemoryStatsThresholds {
+		min_note_count: min_count.unwrap_or(MIN_NOTE_COUNT).max(MIN_NOTE_COUNT),
+		min_agent_count: MIN_AGENT_COUNT,
+	}
+}
+
+/// Drops items below either threshold, orders the rest by frequency, and truncates to `limit`.
+///
+/// Returns the reported items and the number of withheld items.
+fn apply_thresholds(
+	rows: Vec<StatsRow>,
+	thresholds: OrgMemoryStatsThresholds,
+	limit: usize,
+) -> (Vec<OrgMemoryStatsItem>, u64) {
+	let mut suppressed = 0;
+	let mut items = Vec::new();
+
+	for row in rows {
+		let note_count = u32::try_from(row.note_count).unwrap_or(u32::MAX);
+		let agent_count = u32::try_from(row.agent_count).unwrap_or(u32::MAX);
+
+		if note_count < thresholds.min_note_count || agent_count < thresholds.min_agent_count {
+			suppressed += 1;
+
+			continue;
+		}
+
+		items.push(OrgMemoryStatsItem {
+			label: row.label,
+			kind: row.kind,
+			note_count,
+			agent_count,
+		});
+	}
+
+	items.sort_by(|a, b| {
+		b.note_count
+			.cmp(&a.note_count)
+			.then_with(|| b.agent_count.cmp(&a.agent_count))
+			.then_with(|| a.label.cmp(&b.label))
+	});
+	items.truncate(limit);
+
+	(items, suppressed)
+}
+
+async fn fetch_topic_rows<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	scopes: &[String],
+	as_of: OffsetDateTime,
+) -> Result<Vec<StatsRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, StatsRow>(
+		"\
+SELECT
+	lower(btrim(f.text)) AS label,
+	NULL::text AS kind,
+	count(DISTINCT n.note_id) AS note_count,
+	count(DISTINCT n.agent_id) AS agent_count
+FROM memory_note_fields f
+JOIN memory_notes n ON n.note_id = f.note_id
+WHERE f.field_kind = 'concept'
+	AND btrim(f.text) <> ''
+	AND n.tenant_id = $1
+	AND (n.project_id = $2 OR (n.project_id = $4 AND n.scope = 'org_shared'))
+	AND n.scope = ANY($3::text[])
+	AND n.status = 'active'
+	AND (n.expires_at IS NULL OR n.expires_at > $5)
+GROUP BY lower(btrim(f.text))",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(scopes)
+	.bind(ORG_PROJECT_ID)
+	.bind(as_of)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_entity_rows<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	scopes: &[String],
+	as_of: OffsetDateTime,
+) -> Result<Vec<StatsRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, StatsRow>(
+		"\
+SELECT
+	e.canonical AS label,
+	e.kind,
+	count(DISTINCT n.note_id) AS note_count,
+	count(DISTINCT n.agent_id) AS agent_count
+FROM graph_facts gf
+JOIN graph_fact_evidence gfe ON gfe.fact_id = gf.fact_id
+JOIN memory_notes n ON n.note_id = gfe.note_id
+JOIN graph_entities e
+	ON e.entity_id = gf.subject_entity_id OR e.entity_id = gf.object_entity_id
+WHERE gf.tenant_id = $1
+	AND gf.scope = ANY($3::text[])
+	AND n.tenant_id = $1
+	AND (n.project_id = $2 OR (n.project_id = $4 AND n.scope = 'org_shared'))
+	AND n.scope = ANY($3::text[])
+	AND n.status = 'active'
+	AND (n.expires_at IS NULL OR n.expires_at > $5)
+GROUP BY e.entity_id, e.canonical, e.kind",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(scopes)
+	.bind(ORG_PROJECT_ID)
+	.bind(as_of)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,35 @@
+use crate::org_stats::{self, MIN_AGENT_COUNT, MIN_NOTE_COUNT, StatsRow};
+
+fn row(label: &str, note_count: i64, agent_count: i64) -> StatsRow {
+	StatsRow { label: label.to_string(), kind: None, note_count, agent_count }
+}
+
+#[test]
+fn thresholds_withhold_small_populations() {
+	let thresholds = org_stats::resolve_thresholds(None);
+	let rows = vec![
+		row("billing", 9, 4),
+		row("deploys", 12, 5),
+		row("one agent", 40, 1),
+		row("rare", 2, 2),
+	];
+	let (items, suppressed) = org_stats::apply_thresholds(rows, thresholds, 10);
+	let labels: Vec<&str> = items.iter().map(|item| item.label.as_str()).collect();
+
+	assert_eq!(labels, vec!["deploys", "billing"]);
+	assert_eq!(suppressed, 2);
+}
+
+#[test]
+fn requested_min_count_cannot_lower_the_floor() {
+	assert_eq!(org_stats::resolve_thresholds(Some(1)).min_note_count, MIN_NOTE_COUNT);
+	assert_eq!(org_stats::resolve_thresholds(Some(20)).min_note_count, 20);
+	assert_eq!(org_stats::resolve_thresholds(Some(1)).min_agent_count, MIN_AGENT_COUNT);
+}
+
+#[test]
+fn shared_scopes_exclude_private_scope() {
+	let allowed = vec!["agent_private".to_string(), "org_shared".to_string()];
+
+	assert_eq!(org_stats::shared_scopes(&allowed), vec!["org_shared".to_string()]);
+}
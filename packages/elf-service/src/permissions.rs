@@ -0,0 +1,177 @@
+//! Role-based write permissions configured under `[security.permissions]`.
+
+use std::collections::HashMap;
+
+use crate::{ElfService, Error, Result};
+use elf_config::{SecurityAuthRole, SecurityRolePermissions};
+
+/// Write operations governed by `[security.permissions]`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WriteOperation {
+	/// Notes ingest and bulk import.
+	AddNote,
+	/// Events ingest.
+	AddEvent,
+	/// Note patch, merge, publish, and unpublish.
+	Update,
+	/// Note trash and restore.
+	Delete,
+}
+impl WriteOperation {
+	/// Returns the operation name used in `[security.permissions]`.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::AddNote => "add_note",
+			Self::AddEvent => "add_event",
+			Self::Update => "update",
+			Self::Delete => "delete",
+		}
+	}
+}
+
+impl ElfService {
+	/// Fails with [`Error::ScopeDenied`] unless `role` may perform `operation` on `scope`.
+	///
+	/// `role` is `None` when `security.auth_mode` is `off`, which allows every write.
+	pub fn authorize_write(
+		&self,
+		role: Option<SecurityAuthRole>,
+		operation: WriteOperation,
+		scope: &str,
+	) -> Result<()> {
+		let Some(role) = role else { return Ok(()) };
+
+		if role_may_write(self.cfg.security.permissions.as_ref(), role, operation, scope.trim()) {
+			return Ok(());
+		}
+
+		Err(Error::ScopeDenied {
+			message: format!(
+				"Role {} may not {} in scope {}.",
+				role.as_str(),
+				operation.as_str(),
+				scope.trim()
+			),
+		})
+	}
+
+	/// Fails with [`Error::ScopeDenied`] unless `role` may perform `operation` on at least one
+	/// scope, for writes whose scope is not known up front.
+	pub(crate) fn authorize_write_any(
+		&self,
+		role: Option<SecurityAuthRole>,
+		operation: WriteOperation,
+	) -> Result<()> {
+		let Some(role) = role else { return Ok(()) };
+
+		if self.cfg.scopes.allowed.iter().any(|scope| {
+			role_may_write(self.cfg.security.permissions.as_ref(), role, operation, scope)
+		}) {
+			return Ok(());
+		}
+
+		Err(Error::ScopeDenied {
+			message: format!("Role {} may not {} in any scope.", role.as_str(), operation.as_str()),
+		})
+	}
+}
+
+/// Resolves one cell of the role × operation × scope matrix.
+///
+/// Roles without a configured entry fall back to the defaults: readers never write, users write
+/// every scope except `org_shared`, and admins write everything.
+pub(crate) fn role_may_write(
+	permissions: Option<&HashMap<SecurityAuthRole, SecurityRolePermissions>>,
+	role: SecurityAuthRole,
+	operation: WriteOperation,
+	scope: &str,
+) -> bool {
+	if let Some(entry) = permissions.and_then(|permissions| permissions.get(&role)) {
+		let scopes = match operation {
+			WriteOperation::AddNote => &entry.add_note,
+			WriteOperation::AddEvent => &entry.add_event,
+			WriteOperation::Update => &entry.update,
+			WriteOperation::Delete => &entry.delete,
+		};
+
+		return scopes.iter().any(|allowed| allowed == scope);
+	}
+
+	match role {
+		SecurityAuthRole::Reader => false,
+		SecurityAuthRole::User => scope != "org_shared",
+		SecurityAuthRole::Admin | SecurityAuthRole::SuperAdmin => true,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashMap;
+
+	use crate::permissions::{self, WriteOperation};
+	use elf_config::{SecurityAuthRole, SecurityRolePermissions};
+
+	#[test]
+	fn default_matrix_keeps_readers_read_only_and_org_shared_admin_only() {
+		assert!(!permissions::role_may_write(
+			None,
+			SecurityAuthRole::Reader,
+			WriteOperation::AddNote,
+			"agent_private"
+		));
+		assert!(permissions::role_may_write(
+			None,
+			SecurityAuthRole::User,
+			WriteOperation::Delete,
+			"project_shared"
+		));
+		assert!(!permissions::role_may_write(
+			None,
+			SecurityAuthRole::User,
+			WriteOperation::AddEvent,
+			"org_shared"
+		));
+		assert!(permissions::role_may_write(
+			None,
+			SecurityAuthRole::Admin,
+			WriteOperation::Update,
+			"org_shared"
+		));
+	}
+
+	#[test]
+	fn configured_role_replaces_its_defaults() {
+		let permissions = HashMap::from([(
+			SecurityAuthRole::User,
+			SecurityRolePermissions {
+				add_note: vec!["agent_private".to_string()],
+				..Default::default()
+			},
+		)]);
+
+		assert!(permissions::role_may_write(
+			Some(&permissions),
+			SecurityAuthRole::User,
+			WriteOperation::AddNote,
+			"agent_private"
+		));
+		assert!(!permissions::role_may_write(
+			Some(&permissions),
+			SecurityAuthRole::User,
+			WriteOperation::AddNote,
+			"project_shared"
+		));
+		assert!(!permissions::role_may_write(
+			Some(&permissions),
+			SecurityAuthRole::User,
+			WriteOperation::Delete,
+			"agent_private"
+		));
+		assert!(permissions::role_may_write(
+			Some(&permissions),
+			SecurityAuthRole::Admin,
+			WriteOperation::Delete,
+			"org_shared"
+		));
+	}
+}
@@ -0,0 +1,135 @@
+//! Note pinning APIs.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, InsertVersionArgs, NoteOp, Result, WriteOperation,
+	audit::{AuditScope, AuditTargets},
+};
+use elf_config::SecurityAuthRole;
+
+/// Request payload for pinning or unpinning a note.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PinNoteRequest {
+	/// Tenant that owns the note.
+	pub tenant_id: String,
+	/// Project that owns the note.
+	pub project_id: String,
+	/// Agent requesting the change; must own the note.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the note's scope; `None` when auth is
+	/// off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Identifier of the note to pin or unpin.
+	pub note_id: Uuid,
+}
+
+/// Response payload for pinning or unpinning a note.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PinNoteResponse {
+	/// Identifier of the affected note.
+	pub note_id: Uuid,
+	/// Pin state after the request.
+	pub pinned: bool,
+	/// `update` when the pin state changed, `none` when it already matched.
+	pub op: NoteOp,
+}
+
+impl AuditTargets for PinNoteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.note_id]
+	}
+}
+
+impl ElfService {
+	/// Pins an active note owned by the caller.
+	///
+	/// Search force-includes pinned notes that the caller can read, even when their rerank
+	/// scores would leave them out of the top results.
+	pub async fn pin_note(&self, req: PinNoteRequest) -> Result<PinNoteResponse> {
+		let scope = AuditScope::new("pin")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.note_id]);
+
+		self.audited(scope, self.set_note_pinned(req, true)).await
+	}
+
+	/// Unpins a note owned by the caller so search ranks it normally again.
+	pub async fn unpin_note(&self, req: PinNoteRequest) -> Result<PinNoteResponse> {
+		let scope = AuditScope::new("unpin")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.note_id]);
+
+		self.audited(scope, self.set_note_pinned(req, false)).await
+	}
+
+	async fn set_note_pinned(&self, req: PinNoteRequest, pinned: bool) -> Result<PinNoteResponse> {
+		let now = OffsetDateTime::now_utc();
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() || agent_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id, project_id, and agent_id are required.".to_string(),
+			});
+		}
+
+		let mut tx = self.db.pool.begin().await?;
+		let note =
+			self.lock_owned_note(&mut tx, req.note_id, tenant_id, project_id, agent_id).await?;
+
+		self.authorize_write(req.role, WriteOperation::Update, note.scope.as_str())?;
+
+		if pinned && note.status != "active" {
+			return Err(Error::Conflict {
+				message: "Only active notes can be pinned.".to_string(),
+			});
+		}
+
+		let was_pinned: bool =
+			sqlx::query_scalar("SELECT pinned FROM memory_notes WHERE note_id = $1")
+				.bind(note.note_id)
+				.fetch_one(&mut *tx)
+				.await?;
+
+		if was_pinned == pinned {
+			tx.commit().await?;
+
+			return Ok(PinNoteResponse { note_id: note.note_id, pinned, op: NoteOp::None });
+		}
+
+		sqlx::query("UPDATE memory_notes SET pinned = $1 WHERE note_id = $2")
+			.bind(pinned)
+			.bind(note.note_id)
+			.execute(&mut *tx)
+			.await?;
+
+		let mut prev_snapshot = crate::note_snapshot(&note);
+		let mut new_snapshot = prev_snapshot.clone();
+
+		prev_snapshot["pinned"] = serde_json::json!(was_pinned);
+		new_snapshot["pinned"] = serde_json::json!(pinned);
+
+		crate::insert_version(
+			&mut tx,
+			InsertVersionArgs {
+				note_id: note.note_id,
+				op: if pinned { "PIN" } else { "UNPIN" },
+				prev_snapshot: Some(prev_snapshot),
+				new_snapshot: Some(new_snapshot),
+				reason: if pinned { "pin" } else { "unpin" },
+				actor: agent_id,
+				ts: now,
+			},
+		)
+		.await?;
+
+		tx.commit().await?;
+
+		Ok(PinNoteResponse { note_id: note.note_id, pinned, op: NoteOp::Update })
+	}
+}
@@ -7,5 +7,5 @@ pub(super) use self::{
 	access::{resolve_read_scopes, validate_search_session_access},
 	results::{SearchDetailsBuildArgs, build_search_details_results},
 	text::build_summary,
-	timeline::build_timeline_by_day,
+	timeline::{TimelineGrouping, build_timeline, validate_group_limit},
 };
@@ -48,6 +48,7 @@ pub(crate) fn build_search_details_results(
 					code: "NOT_IN_SESSION".to_string(),
 					message: "Requested note_id is not present in the search session.".to_string(),
 				}),
+				relations: Vec::new(),
 			});
 
 			continue;
@@ -60,6 +61,7 @@ pub(crate) fn build_search_details_results(
 					code: "NOTE_NOT_FOUND".to_string(),
 					message: "Note not found.".to_string(),
 				}),
+				relations: Vec::new(),
 			});
 
 			continue;
@@ -73,7 +75,12 @@ pub(crate) fn build_search_details_results(
 		);
 
 		if let Some(error) = error {
-			results.push(SearchDetailsResult { note_id, note: None, error: Some(error) });
+			results.push(SearchDetailsResult {
+				note_id,
+				note: None,
+				error: Some(error),
+				relations: Vec::new(),
+			});
 
 			continue;
 		}
@@ -110,9 +117,15 @@ pub(crate) fn build_search_details_results(
 			expires_at: note.expires_at,
 			source_ref,
 			structured,
+			access_stats: None,
 		};
 
-		results.push(SearchDetailsResult { note_id, note: Some(note_response), error: None });
+		results.push(SearchDetailsResult {
+			note_id,
+			note: Some(note_response),
+			error: None,
+			relations: Vec::new(),
+		});
 
 		if args.record_hits_enabled && hit_seen.insert(note_id) {
 			hits.push(HitItem {
@@ -4,36 +4,247 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::{
-	Result,
+	Error, Result,
 	progressive_search::types::{
 		SearchIndexItem, SearchTimelineGroup, SearchTimelineResponse,
 		session::SearchSessionItemRecord,
 	},
 };
 
-pub(crate) fn build_timeline_by_day(
+const MAX_GROUP_LIMIT: u32 = 100;
+
+/// How session items are bucketed into timeline groups.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum TimelineGrouping {
+	Day,
+	Week,
+	Month,
+	Type,
+	Scope,
+	None,
+}
+impl TimelineGrouping {
+	pub(crate) fn parse(value: &str) -> Result<Self> {
+		match value {
+			"day" => Ok(Self::Day),
+			"week" => Ok(Self::Week),
+			"month" => Ok(Self::Month),
+			"type" => Ok(Self::Type),
+			"scope" => Ok(Self::Scope),
+			"none" => Ok(Self::None),
+			_ => Err(Error::InvalidRequest {
+				message: "group_by must be one of: day, week, month, type, scope, none."
+					.to_string(),
+			}),
+		}
+	}
+
+	fn is_time_bucket(self) -> bool {
+		matches!(self, Self::Day | Self::Week | Self::Month)
+	}
+
+	fn key(self, item: &SearchSessionItemRecord) -> String {
+		let date = item.updated_at.date();
+
+		match self {
+			Self::Day => date.to_string(),
+			Self::Week => {
+				let (year, week, _) = date.to_iso_week_date();
+
+				format!("{year}-W{week:02}")
+			},
+			Self::Month => format!("{}-{:02}", date.year(), u8::from(date.month())),
+			Self::Type => item.r#type.clone(),
+			Self::Scope => item.scope.clone(),
+			Self::None => "all".to_string(),
+		}
+	}
+}
+
+pub(crate) fn validate_group_limit(group_limit: Option<u32>) -> Result<Option<usize>> {
+	match group_limit {
+		None => Ok(None),
+		Some(limit) if (1..=MAX_GROUP_LIMIT).contains(&limit) => Ok(Some(limit as usize)),
+		Some(_) => Err(Error::InvalidRequest {
+			message: format!("group_limit must be between 1 and {MAX_GROUP_LIMIT}."),
+		}),
+	}
+}
+
+/// Buckets session items by `grouping`, keeping at most `group_limit` items per bucket.
+///
+/// Time buckets are ordered newest first and list their items newest first. Type and scope buckets
+/// are ordered by key and list their items by score. `none` keeps the session's rank order.
+pub(crate) fn build_timeline(
 	search_session_id: Uuid,
 	expires_at: OffsetDateTime,
 	items: &[SearchSessionItemRecord],
-) -> Result<SearchTimelineResponse> {
+	grouping: TimelineGrouping,
+	group_limit: Option<usize>,
+) -> SearchTimelineResponse {
 	let mut grouped: BTreeMap<String, Vec<SearchIndexItem>> = BTreeMap::new();
 
 	for item in items {
-		let date = item.updated_at.date().to_string();
+		grouped.entry(grouping.key(item)).or_default().push(item.to_index_item());
+	}
 
-		grouped.entry(date).or_default().push(item.to_index_item());
+	// `none` always answers with its single `all` group, even for an empty session.
+	if grouping == TimelineGrouping::None && grouped.is_empty() {
+		grouped.insert("all".to_string(), Vec::new());
 	}
 
-	let mut groups = Vec::with_capacity(grouped.len());
+	let ordered: Vec<(String, Vec<SearchIndexItem>)> = if grouping.is_time_bucket() {
+		grouped.into_iter().rev().collect()
+	} else {
+		grouped.into_iter().collect()
+	};
+	let mut groups = Vec::with_capacity(ordered.len());
 
-	for (date, mut items) in grouped.into_iter().rev() {
-		items.sort_by(|a, b| {
-			b.updated_at
-				.cmp(&a.updated_at)
-				.then_with(|| b.final_score.partial_cmp(&a.final_score).unwrap_or(Ordering::Equal))
-		});
-		groups.push(SearchTimelineGroup { date, items });
+	for (key, mut items) in ordered {
+		match grouping {
+			TimelineGrouping::None => {},
+			TimelineGrouping::Type | TimelineGrouping::Scope => items.sort_by(|a, b| {
+				b.final_score
+					.partial_cmp(&a.final_score)
+					.unwrap_or(Ordering::Equal)
+					.then_with(|| b.updated_at.cmp(&a.updated_at))
+			}),
+			TimelineGrouping::Day | TimelineGrouping::Week | TimelineGrouping::Month => items
+				.sort_by(|a, b| {
+					b.updated_at.cmp(&a.updated_at).then_with(|| {
+						b.final_score.partial_cmp(&a.final_score).unwrap_or(Ordering::Equal)
+					})
+				}),
+		}
+
+		let total_items = items.len() as u32;
+
+		if let Some(limit) = group_limit {
+			items.truncate(limit);
+		}
+
+		groups.push(SearchTimelineGroup { key: key.clone(), date: key, total_items, items });
 	}
 
-	Ok(SearchTimelineResponse { search_session_id, expires_at, groups })
+	SearchTimelineResponse { search_session_id, expires_at, groups }
+}
+
+#[cfg(test)]
+mod tests {
+	use time::{OffsetDateTime, macros::datetime};
+	use uuid::Uuid;
+
+	use crate::progressive_search::{
+		details::timeline::{self, TimelineGrouping},
+		types::session::SearchSessionItemRecord,
+	};
+
+	fn item(
+		rank: u32,
+		updated_at: OffsetDateTime,
+		note_type: &str,
+		final_score: f32,
+	) -> SearchSessionItemRecord {
+		SearchSessionItemRecord {
+			rank,
+			note_id: Uuid::new_v4(),
+			chunk_id: Uuid::new_v4(),
+			final_score,
+			updated_at,
+			expires_at: None,
+			r#type: note_type.to_string(),
+			key: None,
+			scope: "project_shared".to_string(),
+			importance: 0.5,
+			confidence: 0.9,
+			summary: format!("item {rank}"),
+		}
+	}
+
+	fn items() -> Vec<SearchSessionItemRecord> {
+		vec![
+			item(1, datetime!(2026-03-02 10:00:00 UTC), "decision", 0.4),
+			item(2, datetime!(2026-05-20 10:00:00 UTC), "fact", 0.9),
+			item(3, datetime!(2026-05-03 10:00:00 UTC), "decision", 0.7),
+			item(4, datetime!(2026-05-25 10:00:00 UTC), "decision", 0.2),
+		]
+	}
+
+	#[test]
+	fn month_groups_are_newest_first_and_limited() {
+		let items = items();
+		let response = timeline::build_timeline(
+			Uuid::new_v4(),
+			OffsetDateTime::UNIX_EPOCH,
+			&items,
+			TimelineGrouping::Month,
+			Some(2),
+		);
+		let keys: Vec<_> = response.groups.iter().map(|group| group.key.as_str()).collect();
+
+		assert_eq!(keys, ["2026-05", "2026-03"]);
+		assert_eq!(response.groups[0].total_items, 3);
+		assert_eq!(response.groups[0].items.len(), 2);
+		assert_eq!(response.groups[0].items[0].summary, "item 4");
+		assert_eq!(response.groups[0].items[1].summary, "item 2");
+		assert_eq!(response.groups[0].date, "2026-05");
+	}
+
+	#[test]
+	fn week_keys_use_iso_weeks() {
+		let items = items();
+		let response = timeline::build_timeline(
+			Uuid::new_v4(),
+			OffsetDateTime::UNIX_EPOCH,
+			&items,
+			TimelineGrouping::Week,
+			None,
+		);
+		let keys: Vec<_> = response.groups.iter().map(|group| group.key.as_str()).collect();
+
+		assert_eq!(keys, ["2026-W22", "2026-W21", "2026-W18", "2026-W10"]);
+	}
+
+	#[test]
+	fn type_groups_order_items_by_score() {
+		let items = items();
+		let response = timeline::build_timeline(
+			Uuid::new_v4(),
+			OffsetDateTime::UNIX_EPOCH,
+			&items,
+			TimelineGrouping::Type,
+			None,
+		);
+		let decisions = &response.groups[0];
+
+		assert_eq!(decisions.key, "decision");
+		assert_eq!(
+			decisions.items.iter().map(|item| item.summary.as_str()).collect::<Vec<_>>(),
+			["item 3", "item 1", "item 4"]
+		);
+		assert_eq!(response.groups[1].key, "fact");
+	}
+
+	#[test]
+	fn none_keeps_a_single_all_group() {
+		let response = timeline::build_timeline(
+			Uuid::new_v4(),
+			OffsetDateTime::UNIX_EPOCH,
+			&[],
+			TimelineGrouping::None,
+			None,
+		);
+
+		assert_eq!(response.groups.len(), 1);
+		assert_eq!(response.groups[0].key, "all");
+		assert_eq!(response.groups[0].total_items, 0);
+	}
+
+	#[test]
+	fn rejects_unknown_grouping_and_out_of_range_limits() {
+		assert!(TimelineGrouping::parse("year").is_err());
+		assert!(timeline::validate_group_limit(Some(0)).is_err());
+		assert!(timeline::validate_group_limit(Some(101)).is_err());
+		assert_eq!(timeline::validate_group_limit(Some(5)).unwrap(), Some(5));
+	}
 }
@@ -6,11 +6,16 @@ use uuid::Uuid;
 use crate::{
 	ElfService, Error, PayloadLevel, Result,
 	access::{self, ORG_PROJECT_ID},
+	note_relations::{self, ReadContext},
 	progressive_search::{
 		details::{self, SearchDetailsBuildArgs},
 		storage,
-		types::{SearchDetailsRequest, SearchDetailsResponse, session::SearchSessionItemRecord},
+		types::{
+			SearchDetailsRequest, SearchDetailsResponse,
+			session::{HitItem, SearchSession, SearchSessionItemRecord},
+		},
 	},
+	session_hits::{self, SessionHitKey},
 	structured_fields,
 };
 use elf_storage::models::MemoryNote;
@@ -28,6 +33,7 @@ impl ElfService {
 			});
 		}
 
+		let session_id = session_hits::normalize_session_id(req.session_id.as_deref())?;
 		let now = OffsetDateTime::now_utc();
 		let session =
 			storage::load_search_session(&self.db.pool, req.search_session_id, now).await?;
@@ -107,20 +113,80 @@ WHERE note_id = ANY($1::uuid[])
 			payload_level: req.payload_level,
 			max_note_chars: self.cfg.memory.max_note_chars as usize,
 		};
-		let (results, hits) = details::build_search_details_results(req.note_ids, details_args);
-
-		if !hits.is_empty() {
-			let mut tx = self.db.pool.begin().await?;
-
-			storage::record_detail_hits(&mut *tx, &session.query, &hits, now).await?;
+		let (mut results, hits) = details::build_search_details_results(req.note_ids, details_args);
+		let detailed_note_ids: Vec<Uuid> = results
+			.iter()
+			.filter(|result| result.note.is_some())
+			.map(|result| result.note_id)
+			.collect();
+		let relations = note_relations::load_direct_relations(
+			&self.db.pool,
+			detailed_note_ids.as_slice(),
+			ReadContext {
+				tenant_id: session.tenant_id.as_str(),
+				project_id: session.project_id.as_str(),
+				agent_id,
+				allowed_scopes: &allowed_scopes,
+				shared_grants: &shared_grants,
+				now,
+			},
+		)
+		.await?;
 
-			tx.commit().await?;
+		for result in &mut results {
+			if result.note.is_some() {
+				result.relations = relations.get(&result.note_id).cloned().unwrap_or_default();
+			}
 		}
 
+		self.record_search_details_hits(&session, agent_id, session_id.as_deref(), &hits, now)
+			.await?;
+
 		Ok(SearchDetailsResponse {
 			search_session_id: session.search_session_id,
 			expires_at,
 			results,
 		})
 	}
+
+	/// Records detail hits and, for a caller session, the per-session hit counts in one
+	/// transaction.
+	async fn record_search_details_hits(
+		&self,
+		session: &SearchSession,
+		agent_id: &str,
+		session_id: Option<&str>,
+		hits: &[HitItem],
+		now: OffsetDateTime,
+	) -> Result<()> {
+		if hits.is_empty() {
+			return Ok(());
+		}
+
+		let mut tx = self.db.pool.begin().await?;
+
+		storage::record_detail_hits(&mut *tx, &session.query, hits, now).await?;
+
+		if let Some(session_id) = session_id {
+			let note_ids: Vec<Uuid> = hits.iter().map(|hit| hit.note_id).collect();
+
+			session_hits::record_session_hits(
+				&mut *tx,
+				SessionHitKey {
+					tenant_id: session.tenant_id.as_str(),
+					project_id: session.project_id.as_str(),
+					agent_id,
+					session_id,
+				},
+				note_ids.as_slice(),
+				now,
+				session_hits::session_ttl_seconds(&self.cfg),
+			)
+			.await?;
+		}
+
+		tx.commit().await?;
+
+		Ok(())
+	}
 }
@@ -4,10 +4,7 @@ use crate::{
 	ElfService, Error, PayloadLevel, Result,
 	progressive_search::{
 		details, storage,
-		types::{
-			SearchTimelineGroup, SearchTimelineRequest, SearchTimelineResponse,
-			session::SearchSessionItemRecord,
-		},
+		types::{SearchTimelineRequest, SearchTimelineResponse},
 	},
 };
 
@@ -35,31 +32,19 @@ impl ElfService {
 
 		let expires_at = storage::touch_search_session(&self.db.pool, &session, now).await?;
 		let payload_level = req.payload_level;
-		let group_by = req.group_by.unwrap_or_else(|| {
-			if payload_level == PayloadLevel::L0 { "none".to_string() } else { "day".to_string() }
-		});
+		let grouping = match req.group_by.as_deref() {
+			Some(group_by) => details::TimelineGrouping::parse(group_by)?,
+			None if payload_level == PayloadLevel::L0 => details::TimelineGrouping::None,
+			None => details::TimelineGrouping::Day,
+		};
+		let group_limit = details::validate_group_limit(req.group_limit)?;
 
-		match group_by.as_str() {
-			"day" => details::build_timeline_by_day(
-				session.search_session_id,
-				expires_at,
-				&session.items,
-			),
-			"none" => Ok(SearchTimelineResponse {
-				search_session_id: session.search_session_id,
-				expires_at,
-				groups: vec![SearchTimelineGroup {
-					date: "all".to_string(),
-					items: session
-						.items
-						.iter()
-						.map(SearchSessionItemRecord::to_index_item)
-						.collect(),
-				}],
-			}),
-			_ => Err(Error::InvalidRequest {
-				message: "group_by must be one of: day, none.".to_string(),
-			}),
-		}
+		Ok(details::build_timeline(
+			session.search_session_id,
+			expires_at,
+			&session.items,
+			grouping,
+			group_limit,
+		))
 	}
 }
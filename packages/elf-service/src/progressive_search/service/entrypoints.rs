@@ -1,8 +1,7 @@
 use crate::{
-	ElfService, Error, Result, SearchRequest,
-	progressive_search::types::{
-		SearchIndexPlannedResponse, SearchIndexResponse, session::SearchSessionizePath,
-	},
+	ElfService, Error, QueryPlan, Result, SearchRequest, SearchV2Delivery, SearchV2Mode,
+	SearchV2Request, SearchV2Response,
+	progressive_search::types::{SearchIndexPlannedResponse, SearchIndexResponse},
 };
 
 impl ElfService {
@@ -16,28 +15,59 @@ impl ElfService {
 			expires_at: response.expires_at,
 			items: response.items,
 			trajectory_summary: response.trajectory_summary,
+			warnings: response.warnings,
 		})
 	}
 
 	/// Runs quick-find search and stores a quick session without a query plan.
 	pub async fn search_quick(&self, req: SearchRequest) -> Result<SearchIndexResponse> {
-		self.search_sessionized(req, SearchSessionizePath::Quick).await.map(|output| output.index)
+		let (index, _) = self.search_v2_session(req, SearchV2Mode::QuickFind).await?;
+
+		Ok(index)
 	}
 
 	/// Runs planned search and stores a session with a query plan.
 	pub async fn search_planned(&self, req: SearchRequest) -> Result<SearchIndexPlannedResponse> {
-		let output = self.search_sessionized(req, SearchSessionizePath::Planned).await?;
-		let query_plan = output.query_plan.ok_or_else(|| Error::Storage {
+		let (index, query_plan) = self.search_v2_session(req, SearchV2Mode::PlannedSearch).await?;
+		let query_plan = query_plan.ok_or_else(|| Error::Storage {
 			message: "Planned search response is missing query_plan.".to_string(),
 		})?;
 
 		Ok(SearchIndexPlannedResponse {
-			trace_id: output.index.trace_id,
-			search_session_id: output.index.search_session_id,
-			expires_at: output.index.expires_at,
-			items: output.index.items,
-			trajectory_summary: output.index.trajectory_summary,
+			trace_id: index.trace_id,
+			search_session_id: index.search_session_id,
+			expires_at: index.expires_at,
+			items: index.items,
+			trajectory_summary: index.trajectory_summary,
+			warnings: index.warnings,
 			query_plan,
 		})
 	}
+
+	async fn search_v2_session(
+		&self,
+		req: SearchRequest,
+		mode: SearchV2Mode,
+	) -> Result<(SearchIndexResponse, Option<QueryPlan>)> {
+		let SearchV2Response {
+			trace_id, session, query_plan, trajectory_summary, warnings, ..
+		} = self
+			.search_v2(SearchV2Request { search: req, mode, delivery: SearchV2Delivery::Session })
+			.await?;
+		let session = session.ok_or_else(|| Error::Storage {
+			message: "Session search response is missing the search session.".to_string(),
+		})?;
+
+		Ok((
+			SearchIndexResponse {
+				trace_id,
+				search_session_id: session.search_session_id,
+				expires_at: session.expires_at,
+				items: session.items,
+				trajectory_summary,
+				warnings,
+			},
+			query_plan,
+		))
+	}
 }
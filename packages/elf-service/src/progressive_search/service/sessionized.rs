@@ -2,14 +2,14 @@ use time::{Duration, OffsetDateTime};
 use uuid::Uuid;
 
 use crate::{
-	ElfService, Result, SearchRequest,
+	ElfService, Result, SearchRequest, SearchV2Mode,
 	progressive_search::{
 		details, storage,
 		types::{
 			SearchIndexItem, SearchIndexResponse, SearchSessionMode,
 			session::{
 				NewSearchSession, SESSION_SLIDING_TTL_HOURS, SearchSessionItemRecord,
-				SearchSessionizePath, SearchSessionizedOutput,
+				SearchSessionizedOutput,
 			},
 		},
 	},
@@ -17,10 +17,10 @@ use crate::{
 };
 
 impl ElfService {
-	pub(super) async fn search_sessionized(
+	pub(crate) async fn search_sessionized(
 		&self,
 		req: SearchRequest,
-		path: SearchSessionizePath,
+		mode: SearchV2Mode,
 	) -> Result<SearchSessionizedOutput> {
 		let top_k = req.top_k.unwrap_or(self.cfg.memory.top_k).max(1);
 		let candidate_k = req.candidate_k.unwrap_or(self.cfg.memory.candidate_k).max(top_k);
@@ -29,18 +29,12 @@ impl ElfService {
 		raw_req.top_k = Some(candidate_k);
 		raw_req.record_hits = Some(false);
 
-		let (trace_id, raw_items, trajectory_summary, query_plan) = match path {
-			SearchSessionizePath::Quick => {
-				let raw = self.search_raw_quick(raw_req).await?;
-
-				(raw.trace_id, raw.items, raw.trajectory_summary, None)
-			},
-			SearchSessionizePath::Planned => {
-				let raw = self.search_raw_planned(raw_req).await?;
-
-				(raw.trace_id, raw.items, raw.trajectory_summary, Some(raw.query_plan))
-			},
-		};
+		let raw = self.search_raw_for_mode(raw_req, mode).await?;
+		let trace_id = raw.trace_id;
+		let raw_items = raw.items;
+		let trajectory_summary = raw.trajectory_summary;
+		let warnings = raw.warnings;
+		let query_plan = (mode == SearchV2Mode::PlannedSearch).then_some(raw.query_plan);
 		let now = OffsetDateTime::now_utc();
 		let expires_at = now + Duration::hours(SESSION_SLIDING_TTL_HOURS);
 		let search_session_id = Uuid::new_v4();
@@ -83,7 +77,7 @@ impl ElfService {
 				agent_id: &req.agent_id,
 				read_profile: &req.read_profile,
 				query: &req.query,
-				mode: SearchSessionMode::from(path),
+				mode: SearchSessionMode::from(mode),
 				query_plan: query_plan.as_ref(),
 				trajectory_summary: trajectory_summary.as_ref(),
 				items: &items,
@@ -103,6 +97,7 @@ impl ElfService {
 				expires_at,
 				items: response_items,
 				trajectory_summary,
+				warnings,
 			},
 			query_plan,
 		})
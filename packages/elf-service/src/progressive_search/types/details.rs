@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::{NoteFetchResponse, PayloadLevel};
+use crate::{NoteFetchResponse, NoteRelation, PayloadLevel};
 
 /// Request payload for materializing details from a search session.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -22,6 +22,9 @@ pub struct SearchDetailsRequest {
 	pub note_ids: Vec<Uuid>,
 	/// When true, records note-hit metrics for returned details.
 	pub record_hits: Option<bool>,
+	#[serde(default)]
+	/// Caller session that also remembers the recorded notes for the within-session ranking term.
+	pub session_id: Option<String>,
 }
 
 /// Per-note error payload for detail materialization.
@@ -42,6 +45,9 @@ pub struct SearchDetailsResult {
 	pub note: Option<NoteFetchResponse>,
 	/// Per-note failure, when loading failed.
 	pub error: Option<SearchDetailsError>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	/// Direct relations of the note whose other note the caller can read.
+	pub relations: Vec<NoteRelation>,
 }
 
 /// Response payload for detail materialization.
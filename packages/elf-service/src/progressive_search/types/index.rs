@@ -3,7 +3,8 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::{
-	PayloadLevel, QueryPlan, SearchTrajectorySummary, progressive_search::types::SearchSessionMode,
+	PayloadLevel, QueryPlan, SearchTrajectorySummary, SearchWarning,
+	progressive_search::types::SearchSessionMode,
 };
 
 /// Lightweight session-storable search hit used by progressive-search APIs.
@@ -47,6 +48,9 @@ pub struct SearchIndexResponse {
 	pub items: Vec<SearchIndexItem>,
 	/// Optional condensed explain output.
 	pub trajectory_summary: Option<SearchTrajectorySummary>,
+	#[serde(default)]
+	/// Non-fatal conditions observed while serving the search.
+	pub warnings: Vec<SearchWarning>,
 }
 
 /// Response payload for reloading a stored search session.
@@ -83,6 +87,9 @@ pub struct SearchIndexPlannedResponse {
 	pub items: Vec<SearchIndexItem>,
 	/// Optional condensed explain output.
 	pub trajectory_summary: Option<SearchTrajectorySummary>,
+	#[serde(default)]
+	/// Non-fatal conditions observed while serving the search.
+	pub warnings: Vec<SearchWarning>,
 	/// Stored query plan for the session.
 	pub query_plan: QueryPlan,
 }
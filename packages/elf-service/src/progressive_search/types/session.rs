@@ -19,15 +19,9 @@ pub(in crate::progressive_search) struct HitItem {
 	pub(in crate::progressive_search) final_score: f32,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub(in crate::progressive_search) enum SearchSessionizePath {
-	Quick,
-	Planned,
-}
-
-pub(in crate::progressive_search) struct SearchSessionizedOutput {
-	pub(in crate::progressive_search) index: SearchIndexResponse,
-	pub(in crate::progressive_search) query_plan: Option<QueryPlan>,
+pub(crate) struct SearchSessionizedOutput {
+	pub(crate) index: SearchIndexResponse,
+	pub(crate) query_plan: Option<QueryPlan>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{Error, progressive_search::types::session::SearchSessionizePath};
+use crate::Error;
 
 /// Search-session mode used by progressive-search APIs.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -33,12 +33,3 @@ impl FromStr for SearchSessionMode {
 		}
 	}
 }
-
-impl From<SearchSessionizePath> for SearchSessionMode {
-	fn from(path: SearchSessionizePath) -> Self {
-		match path {
-			SearchSessionizePath::Quick => Self::QuickFind,
-			SearchSessionizePath::Planned => Self::PlannedSearch,
-		}
-	}
-}
@@ -17,15 +17,22 @@ pub struct SearchTimelineRequest {
 	pub search_session_id: Uuid,
 	/// Desired payload-detail level.
 	pub payload_level: PayloadLevel,
-	/// Optional timeline grouping mode.
+	/// Optional timeline grouping mode: `day`, `week`, `month`, `type`, `scope`, or `none`.
 	pub group_by: Option<String>,
+	/// Optional maximum number of items returned per group.
+	#[serde(default)]
+	pub group_limit: Option<u32>,
 }
 
 /// One timeline bucket for a search session.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchTimelineGroup {
-	/// Group key, usually a day string.
+	/// Group key: a day, ISO week, month, note type, scope, or `all`.
+	pub key: String,
+	/// Same value as `key`, kept for clients written against day-only grouping.
 	pub date: String,
+	/// Items in the group before `group_limit` was applied.
+	pub total_items: u32,
 	/// Items that belong to the group.
 	pub items: Vec<SearchIndexItem>,
 }
@@ -20,6 +20,7 @@ pub(in crate::provenance) fn version_history_event(
 		"reason": version.reason,
 		"prev_snapshot": version.prev_snapshot,
 		"new_snapshot": version.new_snapshot,
+		"redactions": version.redactions,
 		"ingest_decision": decision.map(|decision| serde_json::json!({
 			"decision_id": decision.decision_id,
 			"pipeline": decision.pipeline,
@@ -67,6 +67,7 @@ SELECT
 	memory_note_versions.op,
 	memory_note_versions.prev_snapshot,
 	memory_note_versions.new_snapshot,
+	memory_note_versions.redactions,
 	memory_note_versions.reason,
 	memory_note_versions.actor,
 	memory_note_versions.ts
@@ -96,6 +96,7 @@ fn note_version(
 		op: op.to_string(),
 		prev_snapshot: None,
 		new_snapshot: Some(serde_json::json!({ "note_id": note_id })),
+		redactions: None,
 		reason: "add_note".to_string(),
 		actor: "agent-a".to_string(),
 		ts: ts(ts_value),
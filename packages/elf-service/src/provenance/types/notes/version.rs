@@ -20,6 +20,9 @@ pub struct NoteProvenanceNoteVersion {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	/// Snapshot after the operation, when available.
 	pub new_snapshot: Option<Value>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Write-time PII redactions applied to the fields captured by this version, when any.
+	pub redactions: Option<Value>,
 	/// Human-readable reason for the change.
 	pub reason: String,
 	/// Actor that performed the change.
@@ -36,6 +39,7 @@ impl From<NoteVersionRow> for NoteProvenanceNoteVersion {
 			op: row.op,
 			prev_snapshot: row.prev_snapshot,
 			new_snapshot: row.new_snapshot,
+			redactions: row.redactions,
 			reason: row.reason,
 			actor: row.actor,
 			ts: row.ts,
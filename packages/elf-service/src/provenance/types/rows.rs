@@ -30,6 +30,7 @@ pub(in crate::provenance) struct NoteVersionRow {
 	pub(in crate::provenance) op: String,
 	pub(in crate::provenance) prev_snapshot: Option<Value>,
 	pub(in crate::provenance) new_snapshot: Option<Value>,
+	pub(in crate::provenance) redactions: Option<Value>,
 	pub(in crate::provenance) reason: String,
 	pub(in crate::provenance) actor: String,
 	pub(in crate::provenance) ts: OffsetDateTime,
@@ -0,0 +1,125 @@
+//! Circuit-breaker health of the configured model providers.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::ElfService;
+use elf_config::ProviderResilience;
+use elf_providers::resilience;
+
+/// Provider health response schema identifier.
+pub const ELF_PROVIDER_HEALTH_SCHEMA_V1: &str = "elf.provider_health/v1";
+
+/// Breaker health of every configured provider.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProviderHealthResponse {
+	/// Response schema identifier.
+	pub schema: String,
+	#[serde(with = "crate::time_serde")]
+	/// Timestamp the report was generated at.
+	pub generated_at: OffsetDateTime,
+	/// One entry per configured provider, in config order.
+	pub providers: Vec<ProviderHealthItem>,
+}
+
+/// Breaker health of one configured provider.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProviderHealthItem {
+	/// Config path of the provider, such as `providers.rerank`.
+	pub config_path: String,
+	/// Provider kind: `embedding`, `rerank`, or `llm_extractor`.
+	pub kind: String,
+	/// Provider implementation identifier.
+	pub provider_id: String,
+	/// Provider model identifier.
+	pub model: String,
+	/// Whether the provider has a resilience policy.
+	pub resilience_enabled: bool,
+	/// Breaker state: `closed`, `open`, or `half_open`.
+	pub state: String,
+	/// Failed calls since the last success.
+	pub consecutive_failures: u32,
+	/// Milliseconds left before an open breaker lets a trial call through.
+	pub open_remaining_ms: Option<u64>,
+}
+
+impl ElfService {
+	/// Reports the breaker state of the embedding, rerank, and extractor providers.
+	///
+	/// State is process-local, so each API and worker process reports the calls it made itself.
+	pub fn provider_health(&self) -> ProviderHealthResponse {
+		let providers = &self.cfg.providers;
+		let mut embeddings = vec![("providers.embedding".to_string(), &providers.embedding)];
+		let mut named = providers.embeddings.iter().flatten().collect::<Vec<_>>();
+
+		named.sort_by(|left, right| left.0.cmp(right.0));
+		embeddings.extend(
+			named
+				.into_iter()
+				.map(|(name, provider)| (format!("providers.embeddings.{name}"), provider)),
+		);
+
+		let mut items = embeddings
+			.into_iter()
+			.map(|(config_path, provider)| {
+				health_item(
+					config_path,
+					"embedding",
+					&provider.provider_id,
+					&provider.api_base,
+					&provider.path,
+					&provider.model,
+					provider.resilience.as_ref(),
+				)
+			})
+			.collect::<Vec<_>>();
+
+		items.push(health_item(
+			"providers.rerank".to_string(),
+			"rerank",
+			&providers.rerank.provider_id,
+			&providers.rerank.api_base,
+			&providers.rerank.path,
+			&providers.rerank.model,
+			providers.rerank.resilience.as_ref(),
+		));
+		items.push(health_item(
+			"providers.llm_extractor".to_string(),
+			"llm_extractor",
+			&providers.llm_extractor.provider_id,
+			&providers.llm_extractor.api_base,
+			&providers.llm_extractor.path,
+			&providers.llm_extractor.model,
+			providers.llm_extractor.resilience.as_ref(),
+		));
+
+		ProviderHealthResponse {
+			schema: ELF_PROVIDER_HEALTH_SCHEMA_V1.to_string(),
+			generated_at: OffsetDateTime::now_utc(),
+			providers: items,
+		}
+	}
+}
+
+fn health_item(
+	config_path: String,
+	kind: &str,
+	provider_id: &str,
+	api_base: &str,
+	path: &str,
+	model: &str,
+	policy: Option<&ProviderResilience>,
+) -> ProviderHealthItem {
+	let health = resilience::health(&resilience::provider_key(kind, api_base, path, model), policy);
+
+	ProviderHealthItem {
+		config_path,
+		kind: kind.to_string(),
+		provider_id: provider_id.to_string(),
+		model: model.to_string(),
+		resilience_enabled: policy.is_some(),
+		state: health.state.as_str().to_string(),
+		consecutive_failures: health.consecutive_failures,
+		open_remaining_ms: health.open_remaining_ms,
+	}
+}
@@ -0,0 +1,308 @@
+//! Admin-triggered Qdrant maintenance and maintenance run history.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	audit::{AuditScope, AuditTargets},
+};
+use elf_config::QdrantMaintenance;
+use elf_storage::{
+	models::QdrantMaintenanceRun,
+	qdrant::QdrantStore,
+	qdrant_maintenance::{
+		self, COLLECTION_DOCS, COLLECTION_NOTES, QdrantMaintenanceOperation,
+		QdrantMaintenanceRunInsert,
+	},
+};
+
+const DEFAULT_RUNS_LIMIT: u32 = 50;
+const MAX_RUNS_LIMIT: u32 = 500;
+const RESTORE_TIMEOUT_SECONDS: u64 = 600;
+
+/// Request payload for running one Qdrant maintenance operation now.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct QdrantMaintenanceRunRequest {
+	/// Operation: `optimize`, `snapshot`, `verify_counts`, or `restore_snapshot`.
+	pub operation: String,
+	/// Collection kind: `notes` or `docs`. Defaults to both, except for restores.
+	pub collection: Option<String>,
+	/// Snapshot to recover from; required for `restore_snapshot`.
+	pub snapshot_name: Option<String>,
+}
+
+/// Request payload for listing Qdrant maintenance history.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct QdrantMaintenanceRunsListRequest {
+	/// Optional operation filter.
+	pub operation: Option<String>,
+	/// Maximum runs to return.
+	pub limit: Option<u32>,
+}
+
+/// One recorded Qdrant maintenance run.
+#[derive(Clone, Debug, Serialize)]
+pub struct QdrantMaintenanceRunItem {
+	/// Run identifier.
+	pub run_id: Uuid,
+	/// Operation that ran.
+	pub operation: String,
+	/// Collection kind: `notes` or `docs`.
+	pub collection: String,
+	/// Run trigger: `scheduled` or `manual`.
+	pub trigger: String,
+	/// Run status: `succeeded` or `failed`.
+	pub status: String,
+	/// Operation-specific result details.
+	pub detail: Value,
+	/// Failure message for failed runs.
+	pub error: Option<String>,
+	/// Alert delivery status: `none`, `sent`, `failed`, or `not_configured`.
+	pub alert_status: String,
+	#[serde(with = "crate::time_serde")]
+	/// Run start time.
+	pub started_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// Run end time.
+	pub finished_at: OffsetDateTime,
+}
+impl From<QdrantMaintenanceRun> for QdrantMaintenanceRunItem {
+	fn from(run: QdrantMaintenanceRun) -> Self {
+		Self {
+			run_id: run.run_id,
+			operation: run.operation,
+			collection: run.collection,
+			trigger: run.trigger,
+			status: run.status,
+			detail: run.detail,
+			error: run.error,
+			alert_status: run.alert_status,
+			started_at: run.started_at,
+			finished_at: run.finished_at,
+		}
+	}
+}
+
+/// Response payload listing Qdrant maintenance runs.
+#[derive(Clone, Debug, Serialize)]
+pub struct QdrantMaintenanceRunsResponse {
+	/// Runs, newest first for listings and in collection order for manual runs.
+	pub runs: Vec<QdrantMaintenanceRunItem>,
+}
+
+impl AuditTargets for QdrantMaintenanceRunsResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		self.runs.iter().map(|run| run.run_id).collect()
+	}
+}
+
+impl ElfService {
+	/// Runs one Qdrant maintenance operation immediately and records it in the run history.
+	///
+	/// Failures are recorded and returned as failed runs rather than request errors, matching
+	/// what the scheduled worker records.
+	pub async fn admin_qdrant_maintenance_run(
+		&self,
+		req: QdrantMaintenanceRunRequest,
+	) -> Result<QdrantMaintenanceRunsResponse> {
+		let scope = AuditScope::new("admin_qdrant_maintenance_run");
+
+		self.audited(scope, self.admin_qdrant_maintenance_run_inner(req)).await
+	}
+
+	/// Runs [`ElfService::admin_qdrant_maintenance_run`] without recording an audit entry.
+	async fn admin_qdrant_maintenance_run_inner(
+		&self,
+		req: QdrantMaintenanceRunRequest,
+	) -> Result<QdrantMaintenanceRunsResponse> {
+		let Some(cfg) = self.cfg.qdrant_maintenance.as_ref() else {
+			return Err(Error::InvalidRequest {
+				message: "qdrant_maintenance is not configured.".to_string(),
+			});
+		};
+		let operation = parse_operation(req.operation.as_str())?;
+		let collections = resolve_collections(operation, req.collection.as_deref())?;
+		let restore = if operation == QdrantMaintenanceOperation::RestoreSnapshot {
+			Some(resolve_restore_target(cfg, req.snapshot_name.as_deref())?)
+		} else {
+			None
+		};
+		let mut runs = Vec::with_capacity(collections.len());
+
+		for collection in collections {
+			let store = self.maintenance_store(collection)?;
+			let started_at = OffsetDateTime::now_utc();
+			let (detail, error) = match restore {
+				Some((rest_url, snapshot_name)) =>
+					match restore_snapshot(rest_url, &store, snapshot_name).await {
+						Ok(detail) => (detail, None),
+						Err(err) => (
+							serde_json::json!({ "snapshot_name": snapshot_name }),
+							Some(err.to_string()),
+						),
+					},
+				None =>
+					qdrant_maintenance::run_qdrant_maintenance(
+						&self.db.pool,
+						&store,
+						collection,
+						operation,
+						cfg,
+						started_at,
+					)
+					.await,
+			};
+			let run = qdrant_maintenance::insert_qdrant_maintenance_run(
+				&self.db.pool,
+				&QdrantMaintenanceRunInsert {
+					operation,
+					collection,
+					trigger: "manual",
+					detail: &detail,
+					error: error.as_deref(),
+					alert_status: "none",
+					started_at,
+					finished_at: OffsetDateTime::now_utc(),
+				},
+			)
+			.await?;
+
+			runs.push(run.into());
+		}
+
+		Ok(QdrantMaintenanceRunsResponse { runs })
+	}
+
+	/// Lists scheduled and manual Qdrant maintenance runs, newest first.
+	pub async fn admin_qdrant_maintenance_runs_list(
+		&self,
+		req: QdrantMaintenanceRunsListRequest,
+	) -> Result<QdrantMaintenanceRunsResponse> {
+		let limit = req.limit.unwrap_or(DEFAULT_RUNS_LIMIT);
+
+		if limit == 0 || limit > MAX_RUNS_LIMIT {
+			return Err(Error::InvalidRequest {
+				message: format!("limit must be between 1 and {MAX_RUNS_LIMIT}."),
+			});
+		}
+
+		let operation = req
+			.operation
+			.as_deref()
+			.map(str::trim)
+			.filter(|operation| !operation.is_empty())
+			.map(parse_operation)
+			.transpose()?;
+		let runs = qdrant_maintenance::list_qdrant_maintenance_runs(
+			&self.db.pool,
+			operation,
+			i64::from(limit),
+		)
+		.await?;
+
+		Ok(QdrantMaintenanceRunsResponse { runs: runs.into_iter().map(Into::into).collect() })
+	}
+
+	fn maintenance_store(&self, collection: &str) -> Result<QdrantStore> {
+		let name = match collection {
+			COLLECTION_DOCS => self.cfg.storage.qdrant.docs_collection.as_str(),
+			_ => self.cfg.storage.qdrant.collection.as_str(),
+		};
+
+		Ok(QdrantStore::new_with_collection(&self.cfg.storage.qdrant, name)?)
+	}
+}
+
+fn parse_operation(value: &str) -> Result<QdrantMaintenanceOperation> {
+	QdrantMaintenanceOperation::parse(value.trim()).ok_or_else(|| Error::InvalidRequest {
+		message: "operation must be one of optimize, snapshot, verify_counts, or restore_snapshot."
+			.to_string(),
+	})
+}
+
+fn resolve_collections(
+	operation: QdrantMaintenanceOperation,
+	collection: Option<&str>,
+) -> Result<Vec<&'static str>> {
+	match collection.map(str::trim).filter(|collection| !collection.is_empty()) {
+		Some(COLLECTION_NOTES) => Ok(vec![COLLECTION_NOTES]),
+		Some(COLLECTION_DOCS) => Ok(vec![COLLECTION_DOCS]),
+		Some(_) =>
+			Err(Error::InvalidRequest { message: "collection must be notes or docs.".to_string() }),
+		None if operation == QdrantMaintenanceOperation::RestoreSnapshot =>
+			Err(Error::InvalidRequest {
+				message: "collection is required for restore_snapshot.".to_string(),
+			}),
+		None => Ok(vec![COLLECTION_NOTES, COLLECTION_DOCS]),
+	}
+}
+
+/// Returns the Qdrant REST base URL and snapshot name for a restore.
+///
+/// The gRPC API has no recover call, so restores need `qdrant_maintenance.rest_url`.
+fn resolve_restore_target<'a>(
+	cfg: &'a QdrantMaintenance,
+	snapshot_name: Option<&'a str>,
+) -> Result<(&'a str, &'a str)> {
+	let Some(snapshot_name) = snapshot_name.map(str::trim).filter(|name| !name.is_empty()) else {
+		return Err(Error::InvalidRequest {
+			message: "snapshot_name is required for restore_snapshot.".to_string(),
+		});
+	};
+	let Some(rest_url) = cfg.rest_url.as_deref().map(|url| url.trim().trim_end_matches('/')) else {
+		return Err(Error::InvalidRequest {
+			message: "qdrant_maintenance.rest_url is required to restore snapshots.".to_string(),
+		});
+	};
+
+	Ok((rest_url, snapshot_name))
+}
+
+/// Recovers the collection from one of its own snapshots through the Qdrant REST API.
+async fn restore_snapshot(
+	rest_url: &str,
+	store: &QdrantStore,
+	snapshot_name: &str,
+) -> Result<Value> {
+	let snapshots = qdrant_maintenance::list_collection_snapshots(store).await?;
+
+	if !snapshots.iter().any(|snapshot| snapshot.name == snapshot_name) {
+		return Err(Error::Qdrant {
+			message: format!(
+				"Snapshot {snapshot_name} does not exist for collection {}.",
+				store.collection
+			),
+		});
+	}
+
+	let collection_url = format!("{rest_url}/collections/{}", store.collection);
+	let client = reqwest::Client::builder()
+		.timeout(std::time::Duration::from_secs(RESTORE_TIMEOUT_SECONDS))
+		.build()
+		.map_err(|err| Error::Qdrant { message: err.to_string() })?;
+	let response = client
+		.put(format!("{collection_url}/snapshots/recover"))
+		.json(&serde_json::json!({
+			"location": format!("{collection_url}/snapshots/{snapshot_name}"),
+			"priority": "snapshot",
+		}))
+		.send()
+		.await
+		.map_err(|err| Error::Qdrant { message: format!("Snapshot recovery failed: {err}.") })?;
+	let status = response.status();
+
+	if !status.is_success() {
+		let body = response.text().await.unwrap_or_default();
+
+		return Err(Error::Qdrant {
+			message: format!("Snapshot recovery returned HTTP {status}: {body}"),
+		});
+	}
+
+	Ok(serde_json::json!({ "snapshot_name": snapshot_name }))
+}
+
+#[cfg(test)] mod tests;
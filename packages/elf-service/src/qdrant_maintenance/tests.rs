@@ -0,0 +1,55 @@
+use crate::qdrant_maintenance;
+use elf_config::QdrantMaintenance;
+use elf_storage::qdrant_maintenance::QdrantMaintenanceOperation;
+
+fn cfg(rest_url: Option<&str>) -> QdrantMaintenance {
+	QdrantMaintenance {
+		enabled: false,
+		optimize_interval_seconds: None,
+		snapshot_interval_seconds: None,
+		verify_interval_seconds: None,
+		snapshot_retention: 3,
+		max_count_drift: 0,
+		rest_url: rest_url.map(ToString::to_string),
+		alert_webhook: None,
+	}
+}
+
+#[test]
+fn manual_runs_cover_both_collections_unless_restoring() {
+	assert_eq!(
+		qdrant_maintenance::resolve_collections(QdrantMaintenanceOperation::Snapshot, None)
+			.expect("Snapshot should default to both collections."),
+		vec!["notes", "docs"]
+	);
+	assert_eq!(
+		qdrant_maintenance::resolve_collections(QdrantMaintenanceOperation::Optimize, Some("docs"))
+			.expect("Docs collection should resolve."),
+		vec!["docs"]
+	);
+	assert!(
+		qdrant_maintenance::resolve_collections(QdrantMaintenanceOperation::RestoreSnapshot, None)
+			.is_err()
+	);
+	assert!(
+		qdrant_maintenance::resolve_collections(QdrantMaintenanceOperation::Optimize, Some("all"))
+			.is_err()
+	);
+}
+
+#[test]
+fn restores_require_a_snapshot_name_and_rest_url() {
+	assert!(qdrant_maintenance::resolve_restore_target(&cfg(None), Some("snap")).is_err());
+	assert!(
+		qdrant_maintenance::resolve_restore_target(&cfg(Some("http://qdrant:6333")), None).is_err()
+	);
+
+	let with_rest = cfg(Some("http://qdrant:6333/"));
+	let (rest_url, snapshot_name) =
+		qdrant_maintenance::resolve_restore_target(&with_rest, Some(" snap "))
+			.expect("Restore should resolve.");
+
+	assert_eq!(rest_url, "http://qdrant:6333");
+	assert_eq!(snapshot_name, "snap");
+	assert!(qdrant_maintenance::parse_operation("compact").is_err());
+}
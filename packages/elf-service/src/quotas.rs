@@ -0,0 +1,319 @@
+//! Per-tenant note and search quotas configured under `[security.quotas]`.
+
+use std::{
+	collections::HashMap,
+	sync::{Mutex, MutexGuard},
+	time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{PgConnection, PgExecutor};
+use time::{OffsetDateTime, Time};
+
+use crate::{ElfService, Error, Result};
+use elf_config::SecurityQuotas;
+
+/// Quota usage response schema identifier.
+pub const ELF_QUOTA_USAGE_SCHEMA_V1: &str = "elf.quota_usage/v1";
+
+const SEARCH_WINDOW: Duration = Duration::from_secs(1);
+
+/// Request payload for a tenant's quota usage.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct QuotaUsageRequest {
+	/// Tenant to report.
+	pub tenant_id: String,
+}
+
+/// Usage of every per-tenant quota.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct QuotaUsageResponse {
+	/// Response schema identifier.
+	pub schema: String,
+	/// Reported tenant.
+	pub tenant_id: String,
+	#[serde(with = "crate::time_serde")]
+	/// Timestamp the report was generated at.
+	pub generated_at: OffsetDateTime,
+	/// One entry per quota, including quotas without a configured limit.
+	pub quotas: Vec<QuotaUsageItem>,
+	/// Searches this API process rejected for the tenant since it started.
+	pub searches_throttled: u64,
+}
+
+/// Usage of one quota.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct QuotaUsageItem {
+	/// Quota name: `max_notes_per_day`, `max_total_notes`, or `max_search_qps`.
+	pub quota: String,
+	/// Configured limit; `None` when the quota is not enforced.
+	pub limit: Option<u64>,
+	/// Current usage counted against the limit.
+	pub used: u64,
+}
+
+/// Fixed one-second search windows per tenant, local to one process.
+#[derive(Default)]
+pub(crate) struct SearchRateLimiter {
+	state: Mutex<SearchRateState>,
+}
+impl SearchRateLimiter {
+	fn admit(&self, tenant_id: &str, limit: u32, now: Instant) -> bool {
+		let mut state = self.lock();
+
+		state.evict_expired(now);
+
+		let window = state
+			.windows
+			.entry(tenant_id.to_string())
+			.or_insert(SearchWindow { started_at: now, count: 0 });
+
+		if now.duration_since(window.started_at) >= SEARCH_WINDOW {
+			window.started_at = now;
+			window.count = 0;
+		}
+		if window.count >= limit {
+			let throttled = state.throttled.entry(tenant_id.to_string()).or_default();
+
+			*throttled = throttled.saturating_add(1);
+
+			return false;
+		}
+
+		window.count += 1;
+
+		true
+	}
+
+	fn usage(&self, tenant_id: &str, now: Instant) -> (u32, u64) {
+		let state = self.lock();
+		let count = state
+			.windows
+			.get(tenant_id)
+			.filter(|window| now.duration_since(window.started_at) < SEARCH_WINDOW)
+			.map_or(0, |window| window.count);
+
+		(count, state.throttled.get(tenant_id).copied().unwrap_or(0))
+	}
+
+	fn lock(&self) -> MutexGuard<'_, SearchRateState> {
+		self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+/// Open windows, plus throttle totals kept only for tenants that were ever rejected.
+#[derive(Default)]
+struct SearchRateState {
+	windows: HashMap<String, SearchWindow>,
+	throttled: HashMap<String, u64>,
+	evicted_at: Option<Instant>,
+}
+impl SearchRateState {
+	/// Drops windows that have ended, at most once per window length, so idle tenants do not
+	/// accumulate.
+	fn evict_expired(&mut self, now: Instant) {
+		if self.evicted_at.is_some_and(|at| now.duration_since(at) < SEARCH_WINDOW) {
+			return;
+		}
+
+		self.windows.retain(|_, window| now.duration_since(window.started_at) < SEARCH_WINDOW);
+
+		self.evicted_at = Some(now);
+	}
+}
+
+struct SearchWindow {
+	started_at: Instant,
+	count: u32,
+}
+
+impl ElfService {
+	/// Counts one search against the tenant's `max_search_qps` quota.
+	///
+	/// Fails with [`Error::QuotaExceeded`] once the tenant has started `max_search_qps` searches in
+	/// the current one-second window.
+	pub fn check_search_quota(&self, tenant_id: &str) -> Result<()> {
+		let Some(limit) =
+			self.cfg.security.quotas.as_ref().and_then(|quotas| quotas.max_search_qps)
+		else {
+			return Ok(());
+		};
+
+		if self.search_limiter.admit(tenant_id, limit, Instant::now()) {
+			return Ok(());
+		}
+
+		tracing::warn!(tenant_id, limit, "Search quota exceeded.");
+
+		Err(Error::QuotaExceeded {
+			message: format!("Tenant search rate exceeds max_search_qps of {limit}."),
+		})
+	}
+
+	/// Reports the tenant's usage of every quota.
+	pub async fn quota_usage(&self, req: QuotaUsageRequest) -> Result<QuotaUsageResponse> {
+		let tenant_id = req.tenant_id.trim();
+
+		if tenant_id.is_empty() {
+			return Err(Error::InvalidRequest { message: "tenant_id is required.".to_string() });
+		}
+
+		let quotas = self.cfg.security.quotas.as_ref();
+		let now = OffsetDateTime::now_utc();
+		let notes_today =
+			count_notes_created_since(&self.db.pool, tenant_id, day_start(now)).await?;
+		let total_notes = count_retained_notes(&self.db.pool, tenant_id).await?;
+		let (searches, searches_throttled) = self.search_limiter.usage(tenant_id, Instant::now());
+
+		Ok(QuotaUsageResponse {
+			schema: ELF_QUOTA_USAGE_SCHEMA_V1.to_string(),
+			tenant_id: tenant_id.to_string(),
+			generated_at: now,
+			quotas: vec![
+				QuotaUsageItem {
+					quota: "max_notes_per_day".to_string(),
+					limit: quotas.and_then(|quotas| quotas.max_notes_per_day).map(u64::from),
+					used: notes_today,
+				},
+				QuotaUsageItem {
+					quota: "max_total_notes".to_string(),
+					limit: quotas.and_then(|quotas| quotas.max_total_notes),
+					used: total_notes,
+				},
+				QuotaUsageItem {
+					quota: "max_search_qps".to_string(),
+					limit: quotas.and_then(|quotas| quotas.max_search_qps).map(u64::from),
+					used: u64::from(searches),
+				},
+			],
+			searches_throttled,
+		})
+	}
+}
+
+/// Fails with [`Error::QuotaExceeded`] when one more note would exceed a tenant note quota.
+///
+/// Runs inside the write transaction right before a note row is inserted, so rejected writes leave
+/// nothing behind. A per-tenant advisory lock held until commit serializes concurrent writers, so
+/// two transactions cannot both pass the count for the last free slot.
+pub(crate) async fn ensure_note_quota(
+	conn: &mut PgConnection,
+	quotas: Option<&SecurityQuotas>,
+	tenant_id: &str,
+	now: OffsetDateTime,
+) -> Result<()> {
+	let Some(quotas) = quotas else { return Ok(()) };
+
+	if quotas.max_notes_per_day.is_none() && quotas.max_total_notes.is_none() {
+		return Ok(());
+	}
+
+	sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended('note_quota:' || $1, 0))")
+		.bind(tenant_id)
+		.execute(&mut *conn)
+		.await?;
+
+	let (created_today, retained): (i64, i64) = sqlx::query_as(
+		"\
+SELECT
+	count(*) FILTER (WHERE created_at >= $2),
+	count(*) FILTER (WHERE status <> 'deleted')
+FROM memory_notes
+WHERE tenant_id = $1",
+	)
+	.bind(tenant_id)
+	.bind(day_start(now))
+	.fetch_one(&mut *conn)
+	.await?;
+
+	if let Some(limit) = quotas.max_notes_per_day
+		&& created_today as u64 >= u64::from(limit)
+	{
+		return Err(Error::QuotaExceeded {
+			message: format!("Tenant reached max_notes_per_day of {limit}."),
+		});
+	}
+	if let Some(limit) = quotas.max_total_notes
+		&& retained as u64 >= limit
+	{
+		return Err(Error::QuotaExceeded {
+			message: format!("Tenant reached max_total_notes of {limit}."),
+		});
+	}
+
+	Ok(())
+}
+
+async fn count_notes_created_since<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	since: OffsetDateTime,
+) -> Result<u64>
+where
+	E: PgExecutor<'e>,
+{
+	let count: i64 = sqlx::query_scalar(
+		"SELECT count(*) FROM memory_notes WHERE tenant_id = $1 AND created_at >= $2",
+	)
+	.bind(tenant_id)
+	.bind(since)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(count as u64)
+}
+
+async fn count_retained_notes<'e, E>(executor: E, tenant_id: &str) -> Result<u64>
+where
+	E: PgExecutor<'e>,
+{
+	let count: i64 = sqlx::query_scalar(
+		"SELECT count(*) FROM memory_notes WHERE tenant_id = $1 AND status <> 'deleted'",
+	)
+	.bind(tenant_id)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(count as u64)
+}
+
+fn day_start(now: OffsetDateTime) -> OffsetDateTime {
+	now.replace_time(Time::MIDNIGHT)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use crate::quotas::SearchRateLimiter;
+
+	#[test]
+	fn search_limiter_rejects_past_the_limit_and_resets_each_window() {
+		let limiter = SearchRateLimiter::default();
+		let start = Instant::now();
+
+		assert!(limiter.admit("t", 2, start));
+		assert!(limiter.admit("t", 2, start));
+		assert!(!limiter.admit("t", 2, start));
+		assert!(limiter.admit("other", 2, start));
+		assert_eq!(limiter.usage("t", start), (2, 1));
+		assert!(limiter.admit("t", 2, start + Duration::from_secs(1)));
+		assert_eq!(limiter.usage("t", start + Duration::from_secs(1)), (1, 1));
+	}
+
+	#[test]
+	fn search_limiter_evicts_ended_windows_but_keeps_throttle_totals() {
+		let limiter = SearchRateLimiter::default();
+		let start = Instant::now();
+
+		assert!(limiter.admit("idle", 1, start));
+		assert!(!limiter.admit("idle", 1, start));
+		assert!(limiter.admit("busy", 1, start + Duration::from_secs(2)));
+
+		let state = limiter.lock();
+
+		assert!(!state.windows.contains_key("idle"));
+		assert!(state.windows.contains_key("busy"));
+		assert_eq!(state.throttled.get("idle"), Some(&1));
+	}
+}
@@ -8,6 +8,9 @@ use elf_config::Config;
 /// Schema identifier for ranking explanations returned by the search service.
 pub const SEARCH_RANKING_EXPLAIN_SCHEMA_V2: &str = "search_ranking_explain/v2";
 
+/// Relative perturbations applied to each ranking knob by the sensitivity analysis.
+pub const RANK_SENSITIVITY_FACTORS: [f32; 2] = [0.8, 1.2];
+
 /// One named term that contributed to a ranking score.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchRankingTerm {
@@ -31,6 +34,166 @@ pub struct SearchRankingExplain {
 	pub final_score: f32,
 	/// Individual score terms.
 	pub terms: Vec<SearchRankingTerm>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Rank changes under single-knob perturbations, when computed for the result set.
+	pub sensitivity: Option<Vec<SearchRankingSensitivity>>,
+}
+impl SearchRankingExplain {
+	/// Renders a one-line summary of the final score and its non-zero terms.
+	///
+	/// Terms are ordered by absolute contribution, largest first, so the dominant signals lead.
+	pub fn render_compact(&self) -> String {
+		let mut terms = self.terms.iter().filter(|term| term.value != 0.0).collect::<Vec<_>>();
+
+		terms.sort_by(|a, b| b.value.abs().total_cmp(&a.value.abs()));
+
+		let mut out = format!("score {:.4}", self.final_score);
+
+		if terms.is_empty() {
+			return out;
+		}
+
+		out.push_str(" = ");
+		out.push_str(
+			&terms
+				.iter()
+				.map(|term| format!("{} {:+.4}", term.name, term.value))
+				.collect::<Vec<_>>()
+				.join(", "),
+		);
+
+		out
+	}
+
+	/// Renders every term as a markdown table in policy order, ending with the final score.
+	pub fn render_markdown(&self) -> String {
+		let mut out = String::from("| term | value |\n| --- | ---: |\n");
+
+		for term in &self.terms {
+			out.push_str(&format!("| {} | {:+.4} |\n", term.name, term.value));
+		}
+
+		out.push_str(&format!("| **final_score** | **{:.4}** |", self.final_score));
+
+		out
+	}
+}
+
+/// Counterfactual score and rank of one result when a single ranking knob is scaled.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SearchRankingSensitivity {
+	/// Perturbed knob: `rerank_weight`, `recency_tau_days`, or `lexical_bonus`.
+	pub knob: String,
+	/// Multiplier applied to the knob, such as 0.8 or 1.2.
+	pub factor: f32,
+	/// Final score under the perturbation.
+	pub final_score: f32,
+	/// 1-based rank among the returned results under the perturbation.
+	pub rank: u32,
+	/// Rank change versus the unperturbed scores; negative values move the result up.
+	pub rank_shift: i32,
+}
+
+/// Term values of one returned result used by [`build_rank_sensitivity`].
+#[derive(Clone, Copy, Debug)]
+pub struct RankSensitivityInput {
+	/// Final blended score.
+	pub final_score: f32,
+	/// Rerank contribution term.
+	pub rerank_term: f32,
+	/// Deterministic lexical bonus contribution.
+	pub lexical_bonus: f32,
+	/// Item importance score.
+	pub importance: f32,
+	/// Item age in days.
+	pub age_days: f32,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum SensitivityKnob {
+	RerankWeight,
+	RecencyTauDays,
+	LexicalBonus,
+}
+impl SensitivityKnob {
+	const ALL: [Self; 3] = [Self::RerankWeight, Self::RecencyTauDays, Self::LexicalBonus];
+
+	fn as_str(self) -> &'static str {
+		match self {
+			Self::RerankWeight => "rerank_weight",
+			Self::RecencyTauDays => "recency_tau_days",
+			Self::LexicalBonus => "lexical_bonus",
+		}
+	}
+
+	fn perturbed_score(self, cfg: &Config, input: &RankSensitivityInput, factor: f32) -> f32 {
+		match self {
+			Self::RerankWeight => input.final_score + (factor - 1.0) * input.rerank_term,
+			Self::LexicalBonus => input.final_score + (factor - 1.0) * input.lexical_bonus,
+			Self::RecencyTauDays => {
+				let tau = cfg.ranking.recency_tau_days;
+
+				if tau <= 0.0 {
+					return input.final_score;
+				}
+
+				let base = cfg.ranking.tie_breaker_weight * (1.0 + 0.6 * input.importance);
+				let decay = (-input.age_days / tau).exp();
+				let perturbed_decay = (-input.age_days / (tau * factor)).exp();
+
+				input.final_score + base * (perturbed_decay - decay)
+			},
+		}
+	}
+}
+
+/// Recomputes scores and ranks of the returned results with each ranking knob scaled by every
+/// factor in [`RANK_SENSITIVITY_FACTORS`], one knob at a time.
+///
+/// Only already-computed term values are used, so no retrieval or rerank call is repeated. The
+/// output is aligned with `inputs`.
+pub fn build_rank_sensitivity(
+	cfg: &Config,
+	inputs: &[RankSensitivityInput],
+) -> Vec<Vec<SearchRankingSensitivity>> {
+	let baseline =
+		ranks_by_score(&inputs.iter().map(|input| input.final_score).collect::<Vec<_>>());
+	let mut out = vec![Vec::new(); inputs.len()];
+
+	for knob in SensitivityKnob::ALL {
+		for factor in RANK_SENSITIVITY_FACTORS {
+			let scores = inputs
+				.iter()
+				.map(|input| knob.perturbed_score(cfg, input, factor))
+				.collect::<Vec<_>>();
+			let ranks = ranks_by_score(&scores);
+
+			for (idx, entries) in out.iter_mut().enumerate() {
+				entries.push(SearchRankingSensitivity {
+					knob: knob.as_str().to_string(),
+					factor,
+					final_score: scores[idx],
+					rank: ranks[idx],
+					rank_shift: ranks[idx] as i32 - baseline[idx] as i32,
+				});
+			}
+		}
+	}
+
+	out
+}
+
+fn ranks_by_score(scores: &[f32]) -> Vec<u32> {
+	let mut order = (0..scores.len()).collect::<Vec<_>>();
+	let mut ranks = vec![0; scores.len()];
+
+	order.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]).then(a.cmp(b)));
+
+	for (rank, idx) in order.into_iter().enumerate() {
+		ranks[idx] = rank as u32 + 1;
+	}
+
+	ranks
 }
 
 /// Arguments used to build per-term ranking explanations for a trace item.
@@ -81,6 +244,26 @@ pub struct TraceTermsArgs<'a> {
 	pub deterministic_hit_boost: f32,
 	/// Deterministic decay penalty contribution.
 	pub deterministic_decay_penalty: f32,
+	/// Recorded evidence coverage of the note, when known.
+	pub deterministic_evidence_coverage: Option<f32>,
+	/// Deterministic evidence penalty contribution.
+	pub deterministic_evidence_penalty: f32,
+	/// Within-session mode label, or `None` when the mode was not recorded.
+	pub deterministic_session_mode: Option<&'a str>,
+	/// Times the search session already retrieved the note.
+	pub deterministic_session_hit_count: i64,
+	/// Within-session boost or penalty contribution.
+	pub deterministic_session_adjustment: f32,
+	/// Recorded `useful` feedback on the note.
+	pub deterministic_feedback_useful: i64,
+	/// Recorded `not_useful` feedback on the note.
+	pub deterministic_feedback_not_useful: i64,
+	/// Recorded `wrong` feedback on the note.
+	pub deterministic_feedback_wrong: i64,
+	/// Feedback boost or penalty contribution.
+	pub deterministic_feedback_adjustment: f32,
+	/// Score lift that kept a pinned note in the results, or `None` when the note is not pinned.
+	pub pin_boost: Option<f32>,
 }
 
 /// Removes raw inputs from ranking terms while keeping names and values.
@@ -172,6 +355,18 @@ pub fn build_trace_terms_v2(args: TraceTermsArgs<'_>) -> Vec<SearchRankingTerm>
 
 	push_deterministic_terms(&mut terms, cfg, &args);
 
+	if let Some(pin_boost) = args.pin_boost {
+		let mut pin_inputs = BTreeMap::new();
+
+		pin_inputs.insert("pinned".to_string(), serde_json::json!(true));
+		pin_inputs.insert("forced".to_string(), serde_json::json!(pin_boost > 0.0));
+		terms.push(SearchRankingTerm {
+			name: "pin_boost".to_string(),
+			value: pin_boost,
+			inputs: Some(pin_inputs),
+		});
+	}
+
 	terms
 }
 
@@ -228,4 +423,62 @@ fn push_deterministic_terms(
 		value: args.deterministic_decay_penalty,
 		inputs: Some(decay_inputs),
 	});
+
+	if let Some(evidence) = det.evidence.as_ref() {
+		let mut evidence_inputs = BTreeMap::new();
+
+		evidence_inputs
+			.insert("enabled".to_string(), serde_json::json!(det.enabled && evidence.enabled));
+		evidence_inputs.insert("weight".to_string(), serde_json::json!(evidence.weight));
+		evidence_inputs
+			.insert("strong_coverage".to_string(), serde_json::json!(evidence.strong_coverage));
+		evidence_inputs.insert(
+			"coverage".to_string(),
+			serde_json::json!(args.deterministic_evidence_coverage),
+		);
+		terms.push(SearchRankingTerm {
+			name: "deterministic.evidence_penalty".to_string(),
+			value: args.deterministic_evidence_penalty,
+			inputs: Some(evidence_inputs),
+		});
+	}
+
+	if let Some(session) = det.session.as_ref() {
+		let mut session_inputs = BTreeMap::new();
+
+		session_inputs
+			.insert("enabled".to_string(), serde_json::json!(det.enabled && session.enabled));
+		session_inputs
+			.insert("mode".to_string(), serde_json::json!(args.deterministic_session_mode));
+		session_inputs.insert("weight".to_string(), serde_json::json!(session.weight));
+		session_inputs.insert(
+			"session_hit_count".to_string(),
+			serde_json::json!(args.deterministic_session_hit_count),
+		);
+		terms.push(SearchRankingTerm {
+			name: "deterministic.session_adjustment".to_string(),
+			value: args.deterministic_session_adjustment,
+			inputs: Some(session_inputs),
+		});
+	}
+
+	let Some(feedback) = det.feedback.as_ref() else { return };
+	let mut feedback_inputs = BTreeMap::new();
+
+	feedback_inputs
+		.insert("enabled".to_string(), serde_json::json!(det.enabled && feedback.enabled));
+	feedback_inputs.insert("weight".to_string(), serde_json::json!(feedback.weight));
+	feedback_inputs
+		.insert("useful".to_string(), serde_json::json!(args.deterministic_feedback_useful));
+	feedback_inputs.insert(
+		"not_useful".to_string(),
+		serde_json::json!(args.deterministic_feedback_not_useful),
+	);
+	feedback_inputs
+		.insert("wrong".to_string(), serde_json::json!(args.deterministic_feedback_wrong));
+	terms.push(SearchRankingTerm {
+		name: "deterministic.feedback_adjustment".to_string(),
+		value: args.deterministic_feedback_adjustment,
+		inputs: Some(feedback_inputs),
+	});
 }
@@ -0,0 +1,340 @@
+//! One-shot recall: derive retrieval queries from recent conversation turns and pack the hits
+//! into a compact, cited context block.
+
+mod prompt;
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, PayloadLevel, Result, SearchBatchRequest, SearchIndexResponse,
+	SearchRequest, SearchV2Mode, SearchWarning,
+};
+use elf_domain::english_gate;
+
+/// Maximum number of conversation turns accepted in one request.
+pub const MAX_RECALL_CONTEXT_TURNS: usize = 20;
+/// Maximum number of retrieval queries derived from the turns.
+pub const MAX_RECALL_CONTEXT_QUERIES: u32 = 3;
+
+const MAX_TURN_CHARS: usize = 4_000;
+const MAX_QUERY_CHARS: usize = 256;
+const DEFAULT_TOP_K: u32 = 8;
+const MAX_TOP_K: u32 = 20;
+const DEFAULT_MAX_CONTEXT_CHARS: u32 = 2_000;
+const MIN_MAX_CONTEXT_CHARS: u32 = 200;
+const MAX_MAX_CONTEXT_CHARS: u32 = 8_000;
+
+/// One conversation turn handed to recall.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecallContextTurn {
+	/// Speaker role, such as `user` or `assistant`.
+	pub role: String,
+	/// Turn text.
+	pub content: String,
+}
+
+/// Request payload for one-shot recall over recent conversation turns.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecallContextRequest {
+	/// Tenant identifier.
+	pub tenant_id: String,
+	/// Project identifier.
+	pub project_id: String,
+	/// Agent identifier.
+	pub agent_id: String,
+	/// Optional authenticated token identifier.
+	pub token_id: Option<String>,
+	/// Read profile used for retrieval.
+	pub read_profile: String,
+	/// Recent conversation turns, oldest first.
+	pub turns: Vec<RecallContextTurn>,
+	/// Upper bound on derived queries, 1 to [`MAX_RECALL_CONTEXT_QUERIES`].
+	pub max_queries: Option<u32>,
+	/// Maximum number of cited notes in the context block.
+	pub top_k: Option<u32>,
+	/// Character budget for the context block.
+	pub max_context_chars: Option<u32>,
+	#[serde(default)]
+	/// Retrieval mode applied to every derived query.
+	pub mode: SearchV2Mode,
+}
+
+/// One note cited by the context block.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecallContextCitation {
+	/// One-based marker used in the context block.
+	pub index: u32,
+	/// Cited note identifier.
+	pub note_id: Uuid,
+	/// Note type discriminator.
+	pub r#type: String,
+	/// Scope key for the note.
+	pub scope: String,
+	/// Best final score across the queries that returned the note.
+	pub final_score: f32,
+	/// Zero-based indexes into `queries` of every query that returned the note.
+	pub query_indexes: Vec<u32>,
+}
+
+/// Response payload for one-shot recall.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecallContextResponse {
+	/// Retrieval mode that served the request.
+	pub mode: SearchV2Mode,
+	/// Queries that were searched, in batch order.
+	pub queries: Vec<String>,
+	/// Whether the extractor produced no usable query and the last user turn was searched.
+	pub fallback_query: bool,
+	/// Context block with one `[n]` line per cited note, ready to paste into a prompt.
+	pub context: String,
+	/// Notes cited by the context block, in marker order.
+	pub citations: Vec<RecallContextCitation>,
+	/// Whether notes were left out because of the character budget.
+	pub truncated: bool,
+	/// Search session identifiers, one per query, for follow-up detail reads.
+	pub search_session_ids: Vec<Uuid>,
+	#[serde(default)]
+	/// Non-fatal conditions observed while serving the searches.
+	pub warnings: Vec<SearchWarning>,
+}
+
+struct RecallCandidate {
+	note_id: Uuid,
+	r#type: String,
+	scope: String,
+	summary: String,
+	final_score: f32,
+	query_indexes: Vec<u32>,
+}
+
+struct PackedContext {
+	context: String,
+	citations: Vec<RecallContextCitation>,
+	truncated: bool,
+}
+
+impl ElfService {
+	/// Derives up to three retrieval queries from recent conversation turns, runs them as one
+	/// batch search, and returns the merged hits as a compact context block with citations.
+	///
+	/// Hits are interleaved by rank across queries and deduplicated by note. When the extractor
+	/// yields no usable query, the last user turn is searched instead.
+	pub async fn recall_for_context(
+		&self,
+		req: RecallContextRequest,
+	) -> Result<RecallContextResponse> {
+		validate_request(&req)?;
+
+		let max_queries = req.max_queries.unwrap_or(MAX_RECALL_CONTEXT_QUERIES);
+		let top_k = req.top_k.unwrap_or(DEFAULT_TOP_K);
+		let max_context_chars = req.max_context_chars.unwrap_or(DEFAULT_MAX_CONTEXT_CHARS);
+		let messages = prompt::build_query_messages(&req.turns, max_queries);
+		let raw =
+			self.providers.extractor.extract(&self.cfg.providers.llm_extractor, &messages).await?;
+		let mut queries = prompt::parse_queries(&raw, max_queries as usize, MAX_QUERY_CHARS);
+		let fallback_query = queries.is_empty();
+
+		if fallback_query {
+			let Some(query) = fallback_query_from_turns(&req.turns) else {
+				return Err(Error::InvalidRequest {
+					message: "turns must contain a user turn when no query can be derived."
+						.to_string(),
+				});
+			};
+
+			queries.push(query);
+		}
+
+		let searches = queries
+			.iter()
+			.map(|query| SearchRequest {
+				tenant_id: req.tenant_id.clone(),
+				project_id: req.project_id.clone(),
+				agent_id: req.agent_id.clone(),
+				token_id: req.token_id.clone(),
+				read_profile: req.read_profile.clone(),
+				payload_level: PayloadLevel::L0,
+				query: query.clone(),
+				top_k: Some(top_k),
+				candidate_k: None,
+				filter: None,
+				types: Vec::new(),
+				updated_after: None,
+				updated_before: None,
+				min_importance: None,
+				max_result_tokens: None,
+				record_hits: Some(false),
+				session_id: None,
+				session_mode: None,
+				ranking: None,
+			})
+			.collect();
+		let batch = self.search_batch(SearchBatchRequest { searches, mode: req.mode }).await?;
+		let search_session_ids =
+			batch.results.iter().map(|result| result.search_session_id).collect();
+		let warnings = batch.results.iter().flat_map(|result| result.warnings.clone()).collect();
+		let candidates = merge_results(&batch.results, top_k as usize);
+		let packed = pack_context(candidates, max_context_chars as usize);
+
+		tracing::debug!(
+			query_count = queries.len(),
+			citation_count = packed.citations.len(),
+			fallback_query,
+			"Recall for context served."
+		);
+
+		Ok(RecallContextResponse {
+			mode: batch.mode,
+			queries,
+			fallback_query,
+			context: packed.context,
+			citations: packed.citations,
+			truncated: packed.truncated,
+			search_session_ids,
+			warnings,
+		})
+	}
+}
+
+fn validate_request(req: &RecallContextRequest) -> Result<()> {
+	if req.turns.is_empty() || req.turns.len() > MAX_RECALL_CONTEXT_TURNS {
+		return Err(Error::InvalidRequest {
+			message: format!("turns must contain 1 to {MAX_RECALL_CONTEXT_TURNS} turns."),
+		});
+	}
+
+	for (idx, turn) in req.turns.iter().enumerate() {
+		if turn.role.trim().is_empty() {
+			return Err(Error::InvalidRequest {
+				message: format!("$.turns[{idx}].role is required."),
+			});
+		}
+
+		let content = turn.content.trim();
+
+		if content.is_empty() || content.chars().count() > MAX_TURN_CHARS {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"$.turns[{idx}].content must be 1 to {MAX_TURN_CHARS} characters."
+				),
+			});
+		}
+		if !english_gate::is_english_natural_language(content) {
+			return Err(Error::NonEnglishInput { field: format!("$.turns[{idx}].content") });
+		}
+	}
+
+	if req.max_queries.is_some_and(|value| value == 0 || value > MAX_RECALL_CONTEXT_QUERIES) {
+		return Err(Error::InvalidRequest {
+			message: format!("max_queries must be between 1 and {MAX_RECALL_CONTEXT_QUERIES}."),
+		});
+	}
+	if req.top_k.is_some_and(|value| value == 0 || value > MAX_TOP_K) {
+		return Err(Error::InvalidRequest {
+			message: format!("top_k must be between 1 and {MAX_TOP_K}."),
+		});
+	}
+	if req
+		.max_context_chars
+		.is_some_and(|value| !(MIN_MAX_CONTEXT_CHARS..=MAX_MAX_CONTEXT_CHARS).contains(&value))
+	{
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"max_context_chars must be between {MIN_MAX_CONTEXT_CHARS} and {MAX_MAX_CONTEXT_CHARS}."
+			),
+		});
+	}
+
+	Ok(())
+}
+
+fn fallback_query_from_turns(turns: &[RecallContextTurn]) -> Option<String> {
+	let turn = turns.iter().rev().find(|turn| turn.role.trim().eq_ignore_ascii_case("user"))?;
+
+	Some(turn.content.trim().chars().take(MAX_QUERY_CHARS).collect())
+}
+
+/// Interleaves hits by rank across queries, keeps the first occurrence of each note, and stops
+/// at `limit` notes.
+fn merge_results(results: &[SearchIndexResponse], limit: usize) -> Vec<RecallCandidate> {
+	let mut candidates: Vec<RecallCandidate> = Vec::new();
+	let mut positions: HashMap<Uuid, usize> = HashMap::new();
+	let depth = results.iter().map(|result| result.items.len()).max().unwrap_or(0);
+
+	for rank in 0..depth {
+		for (query_idx, result) in results.iter().enumerate() {
+			let Some(item) = result.items.get(rank) else {
+				continue;
+			};
+			let query_idx = query_idx as u32;
+
+			if let Some(&position) = positions.get(&item.note_id) {
+				let candidate = &mut candidates[position];
+
+				candidate.final_score = candidate.final_score.max(item.final_score);
+
+				if !candidate.query_indexes.contains(&query_idx) {
+					candidate.query_indexes.push(query_idx);
+				}
+
+				continue;
+			}
+			if candidates.len() >= limit {
+				continue;
+			}
+
+			positions.insert(item.note_id, candidates.len());
+			candidates.push(RecallCandidate {
+				note_id: item.note_id,
+				r#type: item.r#type.clone(),
+				scope: item.scope.clone(),
+				summary: item.summary.clone(),
+				final_score: item.final_score,
+				query_indexes: vec![query_idx],
+			});
+		}
+	}
+
+	candidates
+}
+
+/// Renders one `[n] (type, scope) summary` line per candidate until the character budget is
+/// spent. Summaries are collapsed to a single line.
+fn pack_context(candidates: Vec<RecallCandidate>, max_chars: usize) -> PackedContext {
+	let mut context = String::new();
+	let mut citations = Vec::new();
+	let mut truncated = false;
+
+	for candidate in candidates {
+		let index = citations.len() as u32 + 1;
+		let summary = candidate.summary.split_whitespace().collect::<Vec<_>>().join(" ");
+		let line = format!("[{index}] ({}, {}) {summary}", candidate.r#type, candidate.scope);
+		let separator = usize::from(!context.is_empty());
+
+		if context.chars().count() + separator + line.chars().count() > max_chars {
+			truncated = true;
+
+			break;
+		}
+		if separator == 1 {
+			context.push('\n');
+		}
+
+		context.push_str(line.as_str());
+		citations.push(RecallContextCitation {
+			index,
+			note_id: candidate.note_id,
+			r#type: candidate.r#type,
+			scope: candidate.scope,
+			final_score: candidate.final_score,
+			query_indexes: candidate.query_indexes,
+		});
+	}
+
+	PackedContext { context, citations, truncated }
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use super::RecallContextTurn;
+use elf_domain::english_gate;
+
+pub(super) fn build_query_messages(turns: &[RecallContextTurn], max_queries: u32) -> Vec<Value> {
+	let schema = serde_json::json!({ "queries": ["string"] });
+	let schema_text = serde_json::to_string_pretty(&schema)
+		.unwrap_or_else(|_| "{\"queries\": [\"string\"]}".to_string());
+	let system_prompt = format!(
+		"You are a retrieval planner for an agent memory system. \
+Output must be valid JSON only and must match the provided schema exactly. \
+Read the conversation and write between 1 and {max_queries} short English search queries \
+that would find stored memories the agent needs to continue the conversation. \
+Focus on the latest turns. Each query must target a different need; prefer concrete names, \
+decisions, preferences, and constraints over generic wording. \
+Do not answer the conversation. Do not include any non-English text. \
+Do not add explanations or extra fields."
+	);
+	let conversation = turns
+		.iter()
+		.map(|turn| format!("{}: {}", turn.role.trim(), turn.content.trim()))
+		.collect::<Vec<_>>()
+		.join("\n");
+	let user_prompt = format!(
+		"Return JSON matching this exact schema:\n{schema_text}\nConversation:\n{conversation}"
+	);
+
+	vec![
+		serde_json::json!({ "role": "system", "content": system_prompt }),
+		serde_json::json!({ "role": "user", "content": user_prompt }),
+	]
+}
+
+/// Reads the `queries` array, dropping blank, overlong, non-English, and case-insensitive
+/// duplicate entries, and keeps at most `max_queries`.
+pub(super) fn parse_queries(raw: &Value, max_queries: usize, max_chars: usize) -> Vec<String> {
+	let Some(items) = raw.get("queries").and_then(Value::as_array) else {
+		return Vec::new();
+	};
+	let mut seen = HashSet::new();
+
+	items
+		.iter()
+		.filter_map(Value::as_str)
+		.map(|query| query.split_whitespace().collect::<Vec<_>>().join(" "))
+		.filter(|query| {
+			!query.is_empty()
+				&& query.chars().count() <= max_chars
+				&& english_gate::is_english_natural_language(query)
+		})
+		.filter(|query| seen.insert(query.to_lowercase()))
+		.take(max_queries)
+		.collect()
+}
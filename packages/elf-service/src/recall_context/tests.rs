@@ -0,0 +1,101 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	SearchIndexItem, SearchIndexResponse,
+	recall_context::{self, RecallContextTurn, prompt},
+};
+
+fn item(note_id: u128, final_score: f32, summary: &str) -> SearchIndexItem {
+	SearchIndexItem {
+		note_id: Uuid::from_u128(note_id),
+		r#type: "decision".to_string(),
+		key: None,
+		scope: "project_shared".to_string(),
+		importance: 0.5,
+		confidence: 0.9,
+		updated_at: OffsetDateTime::UNIX_EPOCH,
+		expires_at: None,
+		final_score,
+		summary: summary.to_string(),
+	}
+}
+
+fn result(items: Vec<SearchIndexItem>) -> SearchIndexResponse {
+	SearchIndexResponse {
+		trace_id: Uuid::new_v4(),
+		search_session_id: Uuid::new_v4(),
+		expires_at: OffsetDateTime::UNIX_EPOCH,
+		items,
+		trajectory_summary: None,
+		warnings: Vec::new(),
+	}
+}
+
+#[test]
+fn parse_queries_normalizes_dedupes_and_caps() {
+	let raw = serde_json::json!({
+		"queries": [
+			"  deployment   schedule for production ",
+			"Deployment schedule for production",
+			"",
+			42,
+			"billing service ownership",
+			"release freeze policy"
+		]
+	});
+
+	assert_eq!(
+		prompt::parse_queries(&raw, 2, 256),
+		vec!["deployment schedule for production", "billing service ownership"]
+	);
+	assert!(prompt::parse_queries(&serde_json::json!({ "query": "x" }), 3, 256).is_empty());
+	assert!(
+		prompt::parse_queries(&serde_json::json!({ "queries": ["long query"] }), 3, 5).is_empty()
+	);
+}
+
+#[test]
+fn fallback_query_uses_the_last_user_turn() {
+	let turns = vec![
+		RecallContextTurn { role: "user".to_string(), content: "First question.".to_string() },
+		RecallContextTurn { role: "User".to_string(), content: " When do we deploy? ".to_string() },
+		RecallContextTurn { role: "assistant".to_string(), content: "Let me check.".to_string() },
+	];
+
+	assert_eq!(
+		recall_context::fallback_query_from_turns(&turns).as_deref(),
+		Some("When do we deploy?")
+	);
+	assert!(recall_context::fallback_query_from_turns(&turns[2..]).is_none());
+}
+
+#[test]
+fn merge_interleaves_by_rank_and_dedupes_notes() {
+	let results = vec![
+		result(vec![item(1, 0.9, "a"), item(2, 0.8, "b"), item(3, 0.7, "c")]),
+		result(vec![item(2, 0.95, "b"), item(4, 0.6, "d")]),
+	];
+	let merged = recall_context::merge_results(&results, 3);
+	let ids = merged.iter().map(|candidate| candidate.note_id).collect::<Vec<_>>();
+
+	assert_eq!(ids, vec![Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(4)]);
+	assert_eq!(merged[1].query_indexes, vec![1, 0]);
+	assert!((merged[1].final_score - 0.95).abs() < f32::EPSILON);
+}
+
+#[test]
+fn pack_context_numbers_lines_and_respects_the_budget() {
+	let results = vec![result(vec![
+		item(1, 0.9, "Deploys run on\nFridays."),
+		item(2, 0.8, "Billing owns invoices."),
+	])];
+	let merged = recall_context::merge_results(&results, 8);
+	let first_line = "[1] (decision, project_shared) Deploys run on Fridays.";
+	let packed = recall_context::pack_context(merged, first_line.len() + 5);
+
+	assert_eq!(packed.context, first_line);
+	assert_eq!(packed.citations.len(), 1);
+	assert_eq!(packed.citations[0].index, 1);
+	assert!(packed.truncated);
+}
@@ -10,6 +10,7 @@ pub(super) fn public_error_class(err: &Error) -> &'static str {
 		Error::ScopeDenied { .. } => "scope_denied",
 		Error::NotFound { .. } => "not_found",
 		Error::Conflict { .. } => "conflict",
+		Error::QuotaExceeded { .. } => "quota_exceeded",
 		Error::Provider { .. } => "provider_unavailable",
 		Error::Storage { .. } => "storage_unavailable",
 		Error::Qdrant { .. } => "vector_store_unavailable",
@@ -27,6 +27,7 @@ impl ElfService {
 				tenant_id: req.tenant_id.clone(),
 				project_id: req.project_id.clone(),
 				agent_id: req.agent_id.clone(),
+				token_id: req.token_id.clone(),
 				trace_id,
 				mode: TraceBundleMode::Bounded,
 				stage_items_limit: Some(limit),
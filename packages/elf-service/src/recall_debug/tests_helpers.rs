@@ -101,6 +101,9 @@ pub(super) fn compact_replay_selected_candidate(
 		note_updated_at: now,
 		note_hit_count: 0,
 		note_last_hit_at: None,
+		note_evidence_coverage: None,
+		note_session_adjustment: None,
+		note_feedback_adjustment: None,
 		diversity_selected: Some(true),
 		diversity_selected_rank: Some(1),
 		diversity_selected_reason: Some("mmr".to_string()),
@@ -109,6 +112,7 @@ pub(super) fn compact_replay_selected_candidate(
 		diversity_similarity: None,
 		diversity_mmr_score: Some(0.8),
 		diversity_missing_embedding: Some(false),
+		diversity_balance_penalty: None,
 	}
 }
 
@@ -131,6 +135,9 @@ pub(super) fn compact_replay_dropped_candidate(
 		note_updated_at: now,
 		note_hit_count: 0,
 		note_last_hit_at: None,
+		note_evidence_coverage: None,
+		note_session_adjustment: None,
+		note_feedback_adjustment: None,
 		diversity_selected: Some(false),
 		diversity_selected_rank: None,
 		diversity_selected_reason: None,
@@ -139,6 +146,7 @@ pub(super) fn compact_replay_dropped_candidate(
 		diversity_similarity: Some(0.92),
 		diversity_mmr_score: Some(0.1),
 		diversity_missing_embedding: Some(false),
+		diversity_balance_penalty: None,
 	}
 }
 
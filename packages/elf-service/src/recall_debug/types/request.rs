@@ -12,6 +12,9 @@ pub struct RecallDebugPanelRequest {
 	pub project_id: String,
 	/// Agent requesting the readback.
 	pub agent_id: String,
+	#[serde(skip)]
+	/// Trusted auth token identifier used for trace visibility role checks.
+	pub token_id: Option<String>,
 	/// Read profile used for memory, document, and graph visibility.
 	pub read_profile: String,
 	/// Optional search trace anchor for memory selected/dropped rows.
@@ -0,0 +1,126 @@
+//! Scope-sectioned search that retrieves each allowed scope separately.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, QueryPlan, Result, SearchItem, SearchRequest, SearchTrajectorySummary,
+	SearchV2Mode, SearchWarning, search,
+};
+
+/// Scopes that can form a section, in response order.
+const SECTION_SCOPES: [&str; 3] = ["agent_private", "project_shared", "org_shared"];
+
+/// Request payload for scope-sectioned search.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchScopedRequest {
+	#[serde(flatten)]
+	/// Shared search parameters; `top_k` is the default per-section limit.
+	pub search: SearchRequest,
+	#[serde(default)]
+	/// Retrieval mode applied to every section.
+	pub mode: SearchV2Mode,
+	#[serde(default)]
+	/// Per-scope `top_k` overrides keyed by scope. Keys must be allowed by the read profile.
+	pub scope_top_k: HashMap<String, u32>,
+}
+
+/// Ranked results retrieved from a single scope.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchScopeSection {
+	/// Scope the section was retrieved from.
+	pub scope: String,
+	/// Result limit applied to the section.
+	pub top_k: u32,
+	/// Trace identifier of the section search.
+	pub trace_id: Uuid,
+	/// Ranked items from this scope only.
+	pub items: Vec<SearchItem>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Query plan for planned-search mode.
+	pub query_plan: Option<QueryPlan>,
+	/// Optional condensed explain output for the section search.
+	pub trajectory_summary: Option<SearchTrajectorySummary>,
+	#[serde(default)]
+	/// Non-fatal conditions observed while serving the section search.
+	pub warnings: Vec<SearchWarning>,
+}
+
+/// Response payload for scope-sectioned search.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchScopedResponse {
+	/// Retrieval mode that served the request.
+	pub mode: SearchV2Mode,
+	/// One section per allowed scope, ordered private, project, then org.
+	pub sections: Vec<SearchScopeSection>,
+}
+
+impl ElfService {
+	/// Searches every scope the read profile allows concurrently and returns one section per scope.
+	///
+	/// Each section is an independent raw search restricted to its scope, with its own `top_k`
+	/// and trace. The section trace records the single scope in `allowed_scopes`, so its stage
+	/// stats describe that scope alone.
+	pub async fn search_scoped(&self, req: SearchScopedRequest) -> Result<SearchScopedResponse> {
+		let SearchScopedRequest { search, mode, scope_top_k } = req;
+		let allowed_scopes =
+			search::resolve_read_profile_scopes(&self.cfg, search.read_profile.as_str())?;
+
+		for (scope, top_k) in &scope_top_k {
+			if !allowed_scopes.contains(scope) {
+				return Err(Error::InvalidRequest {
+					message: format!("scope_top_k.{scope} is not allowed by the read_profile."),
+				});
+			}
+			if *top_k == 0 {
+				return Err(Error::InvalidRequest {
+					message: format!("scope_top_k.{scope} must be greater than zero."),
+				});
+			}
+		}
+
+		let default_top_k = search.top_k.unwrap_or(self.cfg.memory.top_k).max(1);
+		let section = |scope: &'static str| {
+			let allowed = allowed_scopes.iter().any(|allowed| allowed == scope);
+			let top_k = scope_top_k.get(scope).copied().unwrap_or(default_top_k);
+			let mut req = search.clone();
+
+			req.top_k = Some(top_k);
+
+			async move {
+				if !allowed {
+					return Ok(None);
+				}
+
+				let raw = self.search_raw_for_mode_in_scope(req, mode, scope).await?;
+
+				Ok::<_, Error>(Some(SearchScopeSection {
+					scope: scope.to_string(),
+					top_k,
+					trace_id: raw.trace_id,
+					items: raw.items,
+					query_plan: (mode == SearchV2Mode::PlannedSearch).then_some(raw.query_plan),
+					trajectory_summary: raw.trajectory_summary,
+					warnings: raw.warnings,
+				}))
+			}
+		};
+		let (private, project, org) = tokio::join!(
+			section(SECTION_SCOPES[0]),
+			section(SECTION_SCOPES[1]),
+			section(SECTION_SCOPES[2]),
+		);
+		let sections: Vec<SearchScopeSection> =
+			[private?, project?, org?].into_iter().flatten().collect();
+
+		tracing::debug!(
+			mode = mode.as_str(),
+			section_count = sections.len(),
+			"Scoped search served."
+		);
+
+		Ok(SearchScopedResponse { mode, sections })
+	}
+}
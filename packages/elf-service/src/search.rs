@@ -8,7 +8,10 @@ mod finish;
 mod helpers;
 mod hits;
 mod item_builders;
+mod packing;
+mod payload_filters;
 mod query_plan;
+mod rank_documents;
 mod ranking;
 mod replay_helpers;
 mod retrieval;
@@ -21,23 +24,36 @@ mod trace;
 mod trace_persistence;
 mod trace_stages;
 mod trajectory_loaders;
+mod warnings;
 
-pub use crate::ranking_explain_v2::{SearchRankingExplain, SearchRankingTerm};
+pub use crate::ranking_explain_v2::{
+	SearchRankingExplain, SearchRankingSensitivity, SearchRankingTerm,
+};
 pub use api::{
 	BlendRankingOverride, BlendSegmentOverride, DiversityRankingOverride, PayloadLevel, QueryPlan,
 	QueryPlanBlendSegment, QueryPlanBudget, QueryPlanDynamicGate, QueryPlanFusionPolicy,
 	QueryPlanIntent, QueryPlanRerankPolicy, QueryPlanRetrievalStage, QueryPlanRewrite,
 	QueryPlanStage, RankingRequestOverride, RecentTraceHeader, RetrievalSourcesRankingOverride,
-	SearchDiversityExplain, SearchExplain, SearchExplainItem, SearchExplainRelationContext,
-	SearchExplainRelationContextObject, SearchExplainRelationEntityRef, SearchExplainRequest,
-	SearchExplainResponse, SearchExplainTrajectory, SearchExplainTrajectoryMatch,
-	SearchExplainTrajectoryStage, SearchItem, SearchMatchExplain, SearchRawPlannedResponse,
-	SearchRequest, SearchResponse, SearchTrace, SearchTrajectoryResponse, SearchTrajectoryStage,
-	SearchTrajectoryStageItem, SearchTrajectorySummary, SearchTrajectorySummaryStage,
-	TraceBundleGetRequest, TraceBundleMode, TraceBundleResponse, TraceGetRequest, TraceGetResponse,
-	TraceRecentCursor, TraceRecentListRequest, TraceRecentListResponse, TraceReplayCandidate,
-	TraceReplayContext, TraceReplayItem, TraceTrajectoryGetRequest,
+	SearchDiversityExplain, SearchEmbeddingProjectionExplain, SearchExplain, SearchExplainItem,
+	SearchExplainRelationContext, SearchExplainRelationContextObject,
+	SearchExplainRelationEntityRef, SearchExplainRequest, SearchExplainResponse,
+	SearchExplainTrajectory, SearchExplainTrajectoryMatch, SearchExplainTrajectoryStage,
+	SearchFeedbackKind, SearchFeedbackRequest, SearchFeedbackResponse, SearchItem,
+	SearchMatchExplain, SearchPackingExplain, SearchRankingRendered, SearchRawPlannedResponse,
+	SearchRequest, SearchResponse, SearchSnippetTruncation, SearchTrace, SearchTrajectoryResponse,
+	SearchTrajectoryStage, SearchTrajectoryStageItem, SearchTrajectorySummary,
+	SearchTrajectorySummaryStage, SearchWarning, TraceArtifact, TraceArtifactGetRequest,
+	TraceArtifactManifest, TraceArtifactNote, TraceArtifactSection, TraceBundleGetRequest,
+	TraceBundleMode, TraceBundleResponse, TraceGetRequest, TraceGetResponse, TraceRecentCursor,
+	TraceRecentListRequest, TraceRecentListResponse, TraceReplayCandidate, TraceReplayContext,
+	TraceReplayItem, TraceTrajectoryGetRequest,
+};
+pub use rank_documents::{
+	RankDocument, RankDocumentsRequest, RankDocumentsResponse, RankedDocument,
 };
+pub use trace::{decode_trace_artifact, encode_trace_artifact};
+
+pub(crate) use trace::shared_trace_predicate;
 
 use std::{
 	cmp::Ordering,
@@ -60,10 +76,12 @@ use crate::{
 	access::ORG_PROJECT_ID,
 	graph::RelationTemporalStatus,
 	ranking_explain_v2::{SEARCH_RANKING_EXPLAIN_SCHEMA_V2, TraceTermsArgs},
+	search_hooks::{self, SearchHookCandidate, SearchHookRun, SearchHookStage, SearchStageContext},
+	session_hits::{self, SessionHitKey, SessionRankingMode},
 };
-use cache::{fetch_cache_payload, store_cache_payload};
-use db_helpers::{fetch_chunks_by_pair, fetch_note_vectors_for_diversity};
-use elf_config::{Config, SearchCache};
+use cache::{fetch_cache_payload, fetch_result_watermark, store_cache_payload};
+use db_helpers::{fetch_chunks_by_pair, fetch_note_vectors_for_diversity, fetch_qa_answers};
+use elf_config::{Config, EmbeddingProviderConfig, SearchCache};
 use elf_domain::english_gate;
 use elf_storage::{
 	models::MemoryNote,
@@ -71,54 +89,72 @@ use elf_storage::{
 };
 use filter::{SearchFilter, SearchFilterImpact};
 use helpers::{
-	apply_payload_level_to_search_item, build_search_filter, build_trajectory_summary_from_stages,
-	raw_search_path_label, sorted_unique_strings, validate_search_request_inputs,
+	SNIPPET_TRUNCATION_MARKER, apply_payload_level_to_search_item, build_search_filter,
+	build_trajectory_summary_from_stages, highlight_terms, raw_search_path_label,
+	snippet_limit_for, sorted_unique_strings, validate_search_request_inputs,
 };
 use hits::record_hits;
 use item_builders::{build_search_item_and_trace_item, build_trace_candidate_record};
+use packing::ResultPacker;
+use payload_filters::SearchPayloadFilters;
 use ranking::{
 	NormalizationKind, ResolvedBlendPolicy, ResolvedDiversityPolicy, ResolvedRetrievalSourcesPolicy,
 };
 use replay_helpers::cmp_scored_replay;
-use scoring_helpers::{score_chunk_candidate, select_best_scored_chunks};
+use scoring_helpers::{
+	hook_candidates_from_chunks, hook_candidates_from_scored, score_chunk_candidate,
+	select_best_scored_chunks,
+};
 use sql::{
 	DEFAULT_BOUNDED_CANDIDATES_LIMIT, DEFAULT_BOUNDED_STAGE_ITEMS_LIMIT,
 	DEFAULT_FULL_CANDIDATES_LIMIT, DEFAULT_FULL_STAGE_ITEMS_LIMIT, DEFAULT_RECENT_TRACES_LIMIT,
 	MAX_RECENT_TRACES_LIMIT, MAX_TRACE_BUNDLE_CANDIDATES_LIMIT, MAX_TRACE_BUNDLE_ITEMS_LIMIT,
 	RECENT_TRACES_SCHEMA_V1, RELATION_CONTEXT_SQL, SEARCH_FILTER_IMPACT_SCHEMA_V1,
-	SEARCH_RETRIEVAL_TRAJECTORY_SCHEMA_V1, TRACE_BUNDLE_SCHEMA_V1,
+	SEARCH_RETRIEVAL_TRAJECTORY_SCHEMA_V1, TRACE_ARTIFACT_SCHEMA_V1, TRACE_BUNDLE_SCHEMA_V1,
 };
 use state::{
 	BestChunkForNoteRow, BuildQueryPlanArgs, BuildSearchItemArgs, BuildTraceArgs, CacheKind,
 	CachePayload, ChunkCandidate, ChunkMeta, ChunkRow, ChunkSnippet, DeterministicRankingTerms,
 	DiversityDecision, DynamicGateSummary, ExpansionCachePayload, ExpansionMode, ExpansionOutput,
 	FieldHit, FinishSearchArgs, FinishSearchPolicies, FinishSearchScoringResult,
-	MaybeDynamicSearchArgs, NoteMeta, NoteVectorRow, QueryEmbedding, QueryPlanStagesArgs,
-	RawSearchExecutionContext, RawSearchPath, RecursiveRetrievalArgs, RecursiveRetrievalResult,
-	RerankCacheCandidate, RerankCacheItem, RerankCachePayload, RetrievalSourceCandidates,
-	RetrievalSourceKind, ScoreCandidateCtx, ScoreSnippetArgs, ScoredChunk, ScoredReplay,
-	SearchExplainTraceRow, SearchRecentTraceRow, SearchRelationContextRow, SearchRetrievalArgs,
-	SearchRetrievalResult, SearchTraceBuilder, SearchTraceItemRow, SearchTraceRow,
-	StructuredFieldHitArgs, StructuredFieldHitRow, StructuredFieldRetrievalArgs,
+	MaybeDynamicSearchArgs, NoteFeedbackCounts, NoteField, NoteMeta, NoteValue, NoteVectorRow,
+	QueryEmbedding, QueryPlanStagesArgs, RawSearchExecutionContext, RawSearchPath,
+	RecursiveRetrievalArgs, RecursiveRetrievalResult, RerankCacheCandidate, RerankCacheItem,
+	RerankCachePayload, RetrievalSourceCandidates, RetrievalSourceKind, ScopedQueryVector,
+	ScoreCandidateCtx, ScoreSnippetArgs, ScoredChunk, ScoredReplay, SearchExplainTraceRow,
+	SearchRecentTraceRow, SearchRelationContextRow, SearchResultWatermark, SearchRetrievalArgs,
+	SearchRetrievalResult, SearchSessionRanking, SearchTraceBuilder, SearchTraceItemRow,
+	SearchTraceRow, StructuredFieldHitArgs, StructuredFieldHitRow, StructuredFieldRetrievalArgs,
 	StructuredFieldRetrievalResult, TraceCandidateRecord, TraceCandidateSnapshotRow, TraceContext,
 	TraceItemRecord, TracePayload, TraceRecord, TraceTrajectoryStageItemRecord,
 	TraceTrajectoryStageRecord,
 };
-use structured::{build_structured_field_candidates, build_structured_field_matches};
+use structured::{
+	build_structured_field_candidates, build_structured_field_matches,
+	merge_structured_field_results,
+};
 use trace_persistence::{enqueue_trace, persist_trace_inline};
 use trace_stages::{build_trace_audit, build_trace_trajectory_stages};
 use trajectory_loaders::{
 	load_item_trajectory, load_trace_trajectory_stages, load_trace_trajectory_summary,
 };
+use warnings::{candidate_k_warnings, filter_drop_warning, scope_candidate_warnings};
 
 const TRACE_VERSION: i32 = 3;
 const MAX_MATCHED_TERMS: usize = 8;
 const MAX_TRAJECTORY_STAGE_ITEMS: usize = 256;
 const MAX_CANDIDATE_K: u32 = 1_024;
+const DEGRADED_MODE_BM25_ONLY: &str = "bm25_only";
 pub(crate) fn resolve_read_profile_scopes(cfg: &Config, profile: &str) -> Result<Vec<String>> {
 	ranking::resolve_scopes(cfg, profile)
 }
 
+pub(crate) fn validate_search_filter(filter: &Value) -> Result<()> {
+	SearchFilter::parse(filter)
+		.map(|_| ())
+		.map_err(|err| crate::Error::InvalidRequest { message: err.to_string() })
+}
+
 /// Computes the stable ranking-policy identifier for a search configuration.
 pub fn ranking_policy_id(
 	cfg: &Config,
@@ -175,6 +211,9 @@ pub fn replay_ranking_from_candidates(
 	let total_retrieval = trace.candidate_count.max(1);
 	let rerank_ranks = ranking::build_rerank_ranks_for_replay(candidates);
 	let replay_diversity_decisions = ranking::extract_replay_diversity_decisions(candidates);
+	let best_evidence_coverage = ranking::best_evidence_coverage(
+		candidates.iter().map(|candidate| candidate.note_evidence_coverage),
+	);
 	let score_ctx = ScoreCandidateCtx {
 		cfg,
 		blend_policy: &blend_policy,
@@ -183,6 +222,8 @@ pub fn replay_ranking_from_candidates(
 		now,
 		total_rerank,
 		total_retrieval,
+		best_evidence_coverage,
+		session_mode: SessionRankingMode::Off,
 	};
 	let mut best_by_note: BTreeMap<Uuid, ScoredReplay> = BTreeMap::new();
 
@@ -3,14 +3,16 @@ mod payload;
 mod query_plan;
 mod request;
 mod trace;
+mod warnings;
 
 pub use self::{
 	explain::{
-		SearchDiversityExplain, SearchExplain, SearchExplainRelationContext,
-		SearchExplainRelationContextObject, SearchExplainRelationEntityRef, SearchItem,
-		SearchMatchExplain, SearchResponse,
+		SearchDiversityExplain, SearchEmbeddingProjectionExplain, SearchExplain,
+		SearchExplainRelationContext, SearchExplainRelationContextObject,
+		SearchExplainRelationEntityRef, SearchItem, SearchMatchExplain, SearchPackingExplain,
+		SearchRankingRendered, SearchResponse,
 	},
-	payload::PayloadLevel,
+	payload::{PayloadLevel, SearchSnippetTruncation},
 	query_plan::{
 		QueryPlan, QueryPlanBlendSegment, QueryPlanBudget, QueryPlanDynamicGate,
 		QueryPlanFusionPolicy, QueryPlanIntent, QueryPlanRerankPolicy, QueryPlanRetrievalStage,
@@ -23,12 +25,15 @@ pub use self::{
 	trace::{
 		RecentTraceHeader, SearchExplainItem, SearchExplainRequest, SearchExplainResponse,
 		SearchExplainTrajectory, SearchExplainTrajectoryMatch, SearchExplainTrajectoryStage,
-		SearchTrace, SearchTrajectoryResponse, SearchTrajectoryStage, SearchTrajectoryStageItem,
-		SearchTrajectorySummary, SearchTrajectorySummaryStage, TraceBundleGetRequest,
-		TraceBundleMode, TraceBundleResponse, TraceGetRequest, TraceGetResponse, TraceRecentCursor,
-		TraceRecentListRequest, TraceRecentListResponse, TraceReplayCandidate, TraceReplayContext,
-		TraceReplayItem, TraceTrajectoryGetRequest,
+		SearchFeedbackKind, SearchFeedbackRequest, SearchFeedbackResponse, SearchTrace,
+		SearchTrajectoryResponse, SearchTrajectoryStage, SearchTrajectoryStageItem,
+		SearchTrajectorySummary, SearchTrajectorySummaryStage, TraceArtifact,
+		TraceArtifactGetRequest, TraceArtifactManifest, TraceArtifactNote, TraceArtifactSection,
+		TraceBundleGetRequest, TraceBundleMode, TraceBundleResponse, TraceGetRequest,
+		TraceGetResponse, TraceRecentCursor, TraceRecentListRequest, TraceRecentListResponse,
+		TraceReplayCandidate, TraceReplayContext, TraceReplayItem, TraceTrajectoryGetRequest,
 	},
+	warnings::SearchWarning,
 };
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
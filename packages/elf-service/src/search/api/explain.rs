@@ -1,6 +1,6 @@
 use crate::search::api::{
 	Deserialize, OffsetDateTime, RelationTemporalStatus, SearchRankingExplain,
-	SearchTrajectorySummary, Serialize, Uuid, Value,
+	SearchSnippetTruncation, SearchTrajectorySummary, SearchWarning, Serialize, Uuid, Value,
 };
 
 /// Full explanation attached to one search item.
@@ -16,6 +16,44 @@ pub struct SearchExplain {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	/// Optional diversity-selection explanation.
 	pub diversity: Option<SearchDiversityExplain>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Present when the match came from a projected legacy embedding and has reduced fidelity.
+	pub embedding_projection: Option<SearchEmbeddingProjectionExplain>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Server-rendered ranking summaries, present only at payload level `l2`.
+	pub rendered: Option<SearchRankingRendered>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Token accounting, present when the request set `max_result_tokens`.
+	pub packing: Option<SearchPackingExplain>,
+}
+
+/// How one item's snippet was fitted into the request's `max_result_tokens` budget.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SearchPackingExplain {
+	/// Tokens the returned snippet spends from the budget.
+	pub tokens: u32,
+	/// Tokens the snippet had before packing.
+	pub full_tokens: u32,
+	/// Whether packing trimmed the snippet to fit the remaining budget.
+	pub truncated: bool,
+}
+
+/// Human-readable renderings of the ranking explanation.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchRankingRendered {
+	/// One-line summary of the final score and its non-zero terms.
+	pub compact: String,
+	/// Markdown table listing every term and the final score.
+	pub markdown: String,
+}
+
+/// Reduced-fidelity marker for hits served from projected legacy embeddings.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchEmbeddingProjectionExplain {
+	/// Projection method used for the legacy vector.
+	pub mode: String,
+	/// Embedding version the note was originally indexed with.
+	pub source_embedding_version: String,
 }
 
 /// Relation-context row attached to a search explanation.
@@ -95,6 +133,9 @@ pub struct SearchDiversityExplain {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	/// MMR score used by diversity selection.
 	pub mmr_score: Option<f32>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Scope-balance penalty included in the MMR score, when scope balancing is enabled.
+	pub balance_penalty: Option<f32>,
 	#[serde(default)]
 	/// Whether the item lacked an embedding needed for diversity scoring.
 	pub missing_embedding: bool,
@@ -117,6 +158,12 @@ pub struct SearchItem {
 	pub end_offset: i32,
 	/// Returned snippet text.
 	pub snippet: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Present when the snippet was shortened for the requested payload level.
+	pub snippet_truncation: Option<SearchSnippetTruncation>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	/// Byte ranges `[start, end)` in `snippet` that match a query or expanded-query term.
+	pub highlights: Vec<(u32, u32)>,
 	/// Note type discriminator.
 	pub r#type: String,
 	/// Optional application-defined key.
@@ -150,4 +197,7 @@ pub struct SearchResponse {
 	pub items: Vec<SearchItem>,
 	/// Optional condensed explain output.
 	pub trajectory_summary: Option<SearchTrajectorySummary>,
+	#[serde(default)]
+	/// Non-fatal conditions observed while serving the search.
+	pub warnings: Vec<SearchWarning>,
 }
@@ -30,6 +30,17 @@ impl PayloadLevel {
 	}
 }
 
+/// Where a search snippet was shortened to fit the payload level's snippet caps.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SearchSnippetTruncation {
+	/// Byte offset in the full snippet where the returned text ends, before the marker.
+	pub kept_end: u32,
+	/// Byte length of the full snippet; `[0, full_end)` is the untruncated range.
+	pub full_end: u32,
+	/// Whether the cut fell on a sentence boundary rather than a word boundary.
+	pub sentence_boundary: bool,
+}
+
 impl Serialize for PayloadLevel {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
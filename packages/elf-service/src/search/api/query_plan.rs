@@ -1,5 +1,5 @@
 use crate::search::api::{
-	Deserialize, SearchItem, SearchTrajectorySummary, Serialize, Uuid, Value,
+	Deserialize, SearchItem, SearchTrajectorySummary, SearchWarning, Serialize, Uuid, Value,
 };
 
 /// Planned-search variant of the raw search response.
@@ -11,6 +11,9 @@ pub struct SearchRawPlannedResponse {
 	pub items: Vec<SearchItem>,
 	/// Optional condensed explain output.
 	pub trajectory_summary: Option<SearchTrajectorySummary>,
+	#[serde(default)]
+	/// Non-fatal conditions observed while serving the search.
+	pub warnings: Vec<SearchWarning>,
 	/// Query plan used for the search.
 	pub query_plan: QueryPlan,
 }
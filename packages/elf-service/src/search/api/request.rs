@@ -25,8 +25,26 @@ pub struct SearchRequest {
 
 	/// Optional structured filter expression.
 	pub filter: Option<Value>,
+	#[serde(default)]
+	/// Note types to keep. Empty keeps every type.
+	pub types: Vec<String>,
+	/// Optional exclusive lower bound for note `updated_at` (RFC3339).
+	pub updated_after: Option<String>,
+	/// Optional exclusive upper bound for note `updated_at` (RFC3339).
+	pub updated_before: Option<String>,
+	/// Optional inclusive lower bound for note importance.
+	pub min_importance: Option<f32>,
+	#[serde(default)]
+	/// Optional token budget for returned snippets; lower-ranked items are trimmed or dropped.
+	pub max_result_tokens: Option<u32>,
 	/// When true, records note-hit metrics for returned items.
 	pub record_hits: Option<bool>,
+	#[serde(default)]
+	/// Caller session that tracks retrieved notes for the within-session ranking term.
+	pub session_id: Option<String>,
+	#[serde(default)]
+	/// Within-session mode override: `boost`, `penalty`, or `off`. Requires `session_id`.
+	pub session_mode: Option<String>,
 	/// Optional ranking-policy overrides.
 	pub ranking: Option<RankingRequestOverride>,
 }
@@ -1,5 +1,7 @@
+mod artifact;
 mod bundle;
 mod explain;
+mod feedback;
 mod get;
 mod metadata;
 mod recent;
@@ -7,11 +9,16 @@ mod replay;
 mod trajectory;
 
 pub use self::{
+	artifact::{
+		TraceArtifact, TraceArtifactGetRequest, TraceArtifactManifest, TraceArtifactNote,
+		TraceArtifactSection,
+	},
 	bundle::{TraceBundleGetRequest, TraceBundleMode, TraceBundleResponse},
 	explain::{
 		SearchExplainItem, SearchExplainRequest, SearchExplainResponse, SearchExplainTrajectory,
 		SearchExplainTrajectoryMatch, SearchExplainTrajectoryStage,
 	},
+	feedback::{SearchFeedbackKind, SearchFeedbackRequest, SearchFeedbackResponse},
 	get::{TraceGetRequest, TraceGetResponse, TraceTrajectoryGetRequest},
 	metadata::SearchTrace,
 	recent::{
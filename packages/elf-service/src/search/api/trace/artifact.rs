@@ -0,0 +1,84 @@
+use sqlx::FromRow;
+
+use crate::search::api::trace::{
+	Deserialize, OffsetDateTime, Serialize, Uuid, bundle::TraceBundleResponse,
+};
+
+/// Request payload for packaging a trace into a portable artifact.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TraceArtifactGetRequest {
+	/// Tenant that owns the trace.
+	pub tenant_id: String,
+	/// Project that owns the trace.
+	pub project_id: String,
+	/// Agent requesting the artifact.
+	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
+	/// Trace identifier.
+	pub trace_id: Uuid,
+}
+
+/// Self-contained trace artifact suitable for offline replay and bug reports.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TraceArtifact {
+	/// Artifact manifest with per-section hashes.
+	pub manifest: TraceArtifactManifest,
+	/// Full trace bundle.
+	pub bundle: TraceBundleResponse,
+	/// Snapshots of notes referenced by trace items and candidates.
+	pub notes: Vec<TraceArtifactNote>,
+}
+
+/// Manifest describing the sections stored in a trace artifact.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TraceArtifactManifest {
+	/// Artifact schema identifier.
+	pub schema: String,
+	/// Trace identifier.
+	pub trace_id: Uuid,
+	#[serde(with = "crate::time_serde")]
+	/// Artifact generation timestamp.
+	pub generated_at: OffsetDateTime,
+	/// Compression applied to the serialized artifact.
+	pub compression: String,
+	/// Section inventory with content hashes.
+	pub sections: Vec<TraceArtifactSection>,
+}
+
+/// One hashed section of a trace artifact.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TraceArtifactSection {
+	/// Section name.
+	pub name: String,
+	/// Number of records in the section.
+	pub count: u64,
+	/// BLAKE3 hex digest of the section's JSON encoding.
+	pub blake3: String,
+}
+
+/// Snapshot of one note referenced by a trace.
+#[derive(Clone, Debug, Deserialize, Serialize, FromRow)]
+pub struct TraceArtifactNote {
+	/// Note identifier.
+	pub note_id: Uuid,
+	/// Note type discriminator.
+	pub r#type: String,
+	/// Optional application-defined key.
+	pub key: Option<String>,
+	/// Scope key for the note.
+	pub scope: String,
+	/// Lifecycle status at export time.
+	pub status: String,
+	/// Note text at export time.
+	pub text: String,
+	/// Importance score.
+	pub importance: f32,
+	/// Confidence score.
+	pub confidence: f32,
+	/// Embedding version the note is indexed with.
+	pub embedding_version: String,
+	#[serde(with = "crate::time_serde")]
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
@@ -15,6 +15,8 @@ pub struct TraceBundleGetRequest {
 	pub project_id: String,
 	/// Agent requesting the bundle.
 	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
 	/// Trace identifier.
 	pub trace_id: Uuid,
 	#[serde(default)]
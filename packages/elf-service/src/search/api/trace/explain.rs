@@ -11,6 +11,8 @@ pub struct SearchExplainRequest {
 	pub project_id: String,
 	/// Agent requesting the explain payload.
 	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
 	/// Result-handle identifier returned by search.
 	pub result_handle: Uuid,
 }
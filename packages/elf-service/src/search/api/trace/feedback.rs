@@ -0,0 +1,65 @@
+use crate::{
+	audit::AuditTargets,
+	search::api::trace::{Deserialize, OffsetDateTime, Serialize, Uuid},
+};
+
+/// Request payload for recording feedback on one search result.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchFeedbackRequest {
+	/// Tenant that owns the trace.
+	pub tenant_id: String,
+	/// Project that owns the trace.
+	pub project_id: String,
+	/// Agent recording the feedback.
+	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
+	/// Result-handle identifier returned by search.
+	pub result_handle: Uuid,
+	/// Feedback on the result.
+	pub feedback: SearchFeedbackKind,
+}
+
+/// Response payload for recorded search feedback.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchFeedbackResponse {
+	/// Result-handle identifier the feedback applies to.
+	pub result_handle: Uuid,
+	/// Trace that produced the result.
+	pub trace_id: Uuid,
+	/// Note the result referenced.
+	pub note_id: Uuid,
+	/// Recorded feedback.
+	pub feedback: SearchFeedbackKind,
+	#[serde(with = "crate::time_serde")]
+	/// Time the feedback was last recorded.
+	pub updated_at: OffsetDateTime,
+}
+
+impl AuditTargets for SearchFeedbackResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.trace_id, self.note_id]
+	}
+}
+
+/// Caller judgement of one search result.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchFeedbackKind {
+	/// The result helped answer the query.
+	Useful,
+	/// The result was irrelevant to the query.
+	NotUseful,
+	/// The result was actively misleading.
+	Wrong,
+}
+impl SearchFeedbackKind {
+	/// Returns the stored feedback label.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Useful => "useful",
+			Self::NotUseful => "not_useful",
+			Self::Wrong => "wrong",
+		}
+	}
+}
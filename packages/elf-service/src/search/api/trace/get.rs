@@ -12,6 +12,8 @@ pub struct TraceGetRequest {
 	pub project_id: String,
 	/// Agent requesting the trace.
 	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
 	/// Trace identifier.
 	pub trace_id: Uuid,
 }
@@ -25,6 +27,8 @@ pub struct TraceTrajectoryGetRequest {
 	pub project_id: String,
 	/// Agent requesting the trajectory.
 	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
 	/// Trace identifier.
 	pub trace_id: Uuid,
 }
@@ -9,6 +9,8 @@ pub struct TraceRecentListRequest {
 	pub project_id: String,
 	/// Agent requesting the list.
 	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
 
 	/// Maximum number of traces to return.
 	pub limit: Option<u32>,
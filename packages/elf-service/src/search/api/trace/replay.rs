@@ -46,6 +46,15 @@ pub struct TraceReplayCandidate {
 	#[serde(with = "crate::time_serde::option")]
 	/// Timestamp of the note's most recent hit.
 	pub note_last_hit_at: Option<OffsetDateTime>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Recorded evidence coverage of the note, when known.
+	pub note_evidence_coverage: Option<f32>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Within-session adjustment applied when the trace was recorded, when nonzero.
+	pub note_session_adjustment: Option<f32>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Feedback adjustment applied when the trace was recorded, when nonzero.
+	pub note_feedback_adjustment: Option<f32>,
 	/// Whether the candidate was selected by diversity ranking.
 	pub diversity_selected: Option<bool>,
 	/// Final selected rank under diversity ranking.
@@ -60,6 +69,9 @@ pub struct TraceReplayCandidate {
 	pub diversity_similarity: Option<f32>,
 	/// MMR score used for diversity selection.
 	pub diversity_mmr_score: Option<f32>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Scope-balance penalty included in the MMR score.
+	pub diversity_balance_penalty: Option<f32>,
 	/// Whether the candidate lacked an embedding for diversity scoring.
 	pub diversity_missing_embedding: Option<bool>,
 }
@@ -0,0 +1,66 @@
+use crate::search::api::{Deserialize, Serialize};
+
+/// Machine-readable non-fatal condition observed while serving a search.
+///
+/// Warnings let callers tell a genuinely empty memory apart from a pipeline that ran in a
+/// degraded mode. A warning never fails the request.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum SearchWarning {
+	/// An allowed scope contributed no retrieval candidates.
+	ScopeNoCandidates {
+		/// Scope that had no candidates.
+		scope: String,
+	},
+	/// The structured filter dropped more than 90% of retrieval candidates.
+	FilterDroppedMostCandidates {
+		/// Candidate count before the filter ran.
+		candidate_count_pre: u32,
+		/// Candidate count after the filter ran.
+		candidate_count_post: u32,
+	},
+	/// An LLM result cache could not be read, so the provider was called directly.
+	CacheUnavailable {
+		/// Cache kind that failed: `expansion`, `rerank`, or `search_result`.
+		cache_kind: String,
+	},
+	/// The rerank provider failed, so candidates kept their retrieval order.
+	RerankDegraded {
+		/// Provider failure description.
+		reason: String,
+	},
+	/// The requested `candidate_k` was adjusted before retrieval.
+	CandidateKClamped {
+		/// Candidate breadth implied by the request.
+		requested: u32,
+		/// Candidate breadth actually used.
+		effective: u32,
+	},
+	/// A registered search stage hook reported a message or failed.
+	SearchHook {
+		/// Hook name.
+		hook: String,
+		/// Stage the hook ran at.
+		stage: String,
+		/// Hook message, or the failure description.
+		message: String,
+	},
+	/// `max_result_tokens` forced lower-ranked snippets to be trimmed or dropped.
+	ResultTokenBudget {
+		/// Requested token budget.
+		max_result_tokens: u32,
+		/// Tokens spent by the returned snippets.
+		packed_tokens: u32,
+		/// Returned items whose snippets were trimmed to fit.
+		truncated_count: u32,
+		/// Ranked items left out because no budget remained.
+		dropped_count: u32,
+	},
+	/// Query embedding failed, so the search was served in a degraded retrieval mode.
+	DegradedMode {
+		/// Degraded mode that served the search, such as `bm25_only`.
+		mode: String,
+		/// Provider failure description.
+		reason: String,
+	},
+}
@@ -1,6 +1,9 @@
 use crate::{
 	Error,
-	search::{CacheKind, CachePayload, OffsetDateTime, PgExecutor, Result, Uuid, Value},
+	search::{
+		CacheKind, CachePayload, ORG_PROJECT_ID, OffsetDateTime, PgExecutor, Result,
+		SearchResultWatermark, Uuid, Value,
+	},
 };
 
 pub(super) async fn fetch_cache_payload<'e, E>(
@@ -98,3 +101,37 @@ payload = EXCLUDED.payload,
 
 	Ok(Some(payload_size))
 }
+
+/// Reads the freshness watermark of the spaces in `scopes`, including the org-shared space when
+/// the scope list allows it.
+///
+/// The read touches at most two rows per scope, independent of how many notes the spaces hold.
+pub(super) async fn fetch_result_watermark<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	scopes: &[String],
+) -> Result<SearchResultWatermark>
+where
+	E: PgExecutor<'e>,
+{
+	let watermark = sqlx::query_as::<_, SearchResultWatermark>(
+		"\
+SELECT
+	COALESCE(sum(generation), 0)::bigint AS generation,
+	max(updated_at) AS updated_at
+FROM search_result_watermarks
+WHERE
+	tenant_id = $1
+	AND project_id IN ($2, $3)
+	AND scope = ANY($4)",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(ORG_PROJECT_ID)
+	.bind(scopes)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(watermark)
+}
@@ -35,6 +35,36 @@ where
 	Ok(rows)
 }
 
+pub(super) async fn fetch_qa_answers<'e, E>(
+	executor: E,
+	note_ids: &[Uuid],
+) -> Result<HashMap<Uuid, String>>
+where
+	E: PgExecutor<'e>,
+{
+	if note_ids.is_empty() {
+		return Ok(HashMap::new());
+	}
+
+	let rows = sqlx::query_as::<_, (Uuid, String)>(
+		"\
+SELECT note_id, text
+FROM memory_note_fields
+WHERE note_id = ANY($1::uuid[]) AND field_kind = 'answer'
+ORDER BY note_id ASC, item_index ASC",
+	)
+	.bind(note_ids)
+	.fetch_all(executor)
+	.await?;
+	let mut out = HashMap::new();
+
+	for (note_id, text) in rows {
+		out.entry(note_id).or_insert(text);
+	}
+
+	Ok(out)
+}
+
 pub(super) async fn fetch_note_vectors_for_diversity<'e, E>(
 	executor: E,
 	scored: &[ScoredChunk],
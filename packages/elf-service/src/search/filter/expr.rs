@@ -21,6 +21,42 @@ pub(super) enum FilterExpr {
 	Lt { field: FilterField, value: FilterValue },
 	Lte { field: FilterField, value: FilterValue },
 }
+impl FilterExpr {
+	/// Returns the leaf predicates that read `fields.<kind>` structured fields, in filter order.
+	pub(super) fn structured_field_predicates(&self) -> Vec<&Self> {
+		let mut out = Vec::new();
+
+		self.collect_structured_field_predicates(&mut out);
+
+		out
+	}
+
+	pub(super) fn leaf_field(&self) -> Option<&FilterField> {
+		match self {
+			Self::And(_) | Self::Or(_) | Self::Not(_) => None,
+			Self::Eq { field, .. }
+			| Self::Neq { field, .. }
+			| Self::In { field, .. }
+			| Self::Contains { field, .. }
+			| Self::Gt { field, .. }
+			| Self::Gte { field, .. }
+			| Self::Lt { field, .. }
+			| Self::Lte { field, .. } => Some(field),
+		}
+	}
+
+	fn collect_structured_field_predicates<'a>(&'a self, out: &mut Vec<&'a Self>) {
+		match self {
+			Self::And(nodes) | Self::Or(nodes) =>
+				nodes.iter().for_each(|node| node.collect_structured_field_predicates(out)),
+			Self::Not(node) => node.collect_structured_field_predicates(out),
+			_ =>
+				if self.leaf_field().and_then(FilterField::structured_field_kind).is_some() {
+					out.push(self);
+				},
+		}
+	}
+}
 impl Default for FilterExpr {
 	fn default() -> Self {
 		Self::Eq { field: FilterField::Type, value: FilterValue::Null }
@@ -1,8 +1,10 @@
+use std::cmp::Ordering;
+
 use crate::search::{
 	NoteMeta,
 	filter::{
 		expr::{FilterExpr, FilterField},
-		value::{FilterNodeValue, FilterValue},
+		value::{self, FilterNodeValue, FilterValue},
 	},
 };
 
@@ -12,14 +14,35 @@ impl FilterExpr {
 			Self::And(nodes) => Self::evaluate_and(nodes, note),
 			Self::Or(nodes) => Self::evaluate_or(nodes, note),
 			Self::Not(node) => Self::evaluate_not(node, note),
-			Self::Eq { field, value } => Self::evaluate_eq(field, value, note),
+			Self::Eq { field, value } => {
+				let filter_value = value.to_node_value();
+
+				Self::evaluate_any("eq", field, note, |note_value| *note_value == filter_value)
+			},
 			Self::Neq { field, value } => Self::evaluate_neq(field, value, note),
-			Self::In { field, values } => Self::evaluate_in(field, values, note),
-			Self::Contains { field, value } => Self::evaluate_contains(field, value, note),
-			Self::Gt { field, value } => Self::evaluate_gt(field, value, note),
-			Self::Gte { field, value } => Self::evaluate_gte(field, value, note),
-			Self::Lt { field, value } => Self::evaluate_lt(field, value, note),
-			Self::Lte { field, value } => Self::evaluate_lte(field, value, note),
+			Self::In { field, values } => Self::evaluate_any("in", field, note, |note_value| {
+				values.iter().any(|value| *note_value == FilterNodeValue::from(value))
+			}),
+			Self::Contains { field, value } =>
+				Self::evaluate_any("contains", field, note, |note_value| match note_value {
+					FilterNodeValue::String(note_text) => note_text.contains(value.as_str()),
+					_ => false,
+				}),
+			Self::Gt { field, value } => Self::evaluate_any("gt", field, note, |note_value| {
+				compare(field, note_value, value) == Some(Ordering::Greater)
+			}),
+			Self::Gte { field, value } => Self::evaluate_any("gte", field, note, |note_value| {
+				matches!(
+					compare(field, note_value, value),
+					Some(Ordering::Greater | Ordering::Equal)
+				)
+			}),
+			Self::Lt { field, value } => Self::evaluate_any("lt", field, note, |note_value| {
+				compare(field, note_value, value) == Some(Ordering::Less)
+			}),
+			Self::Lte { field, value } => Self::evaluate_any("lte", field, note, |note_value| {
+				matches!(compare(field, note_value, value), Some(Ordering::Less | Ordering::Equal))
+			}),
 		}
 	}
 
@@ -58,16 +81,16 @@ impl FilterExpr {
 		if passed { (false, Some("not.true".to_string())) } else { (true, reason) }
 	}
 
-	fn evaluate_eq(
+	// Multi-valued fields such as extracted values pass when any value matches.
+	fn evaluate_any(
+		op: &str,
 		field: &FilterField,
-		value: &FilterValue,
 		note: &NoteMeta,
+		predicate: impl Fn(&FilterNodeValue) -> bool,
 	) -> (bool, Option<String>) {
-		let note_value = field.lookup_note_value(note);
-		let filter_value = value.to_node_value();
-		let matches = note_value == filter_value;
+		let matches = field.lookup_note_values(note).iter().any(predicate);
 
-		(matches, Some(format!("eq:{}", field.as_str())).filter(|_| !matches))
+		(matches, (!matches).then(|| format!("{op}:{}", field.as_str())))
 	}
 
 	fn evaluate_neq(
@@ -75,130 +98,36 @@ impl FilterExpr {
 		value: &FilterValue,
 		note: &NoteMeta,
 	) -> (bool, Option<String>) {
-		let note_value = field.lookup_note_value(note);
 		let filter_value = value.to_node_value();
-		let matches = note_value != filter_value;
+		let matches =
+			field.lookup_note_values(note).iter().all(|note_value| *note_value != filter_value);
 
-		(matches, Some(format!("neq:{}", field.as_str())).filter(|_| !matches))
+		(matches, (!matches).then(|| format!("neq:{}", field.as_str())))
 	}
+}
 
-	fn evaluate_in(
-		field: &FilterField,
-		values: &[FilterValue],
-		note: &NoteMeta,
-	) -> (bool, Option<String>) {
-		let note_value = field.lookup_note_value(note);
-		let matches = values.iter().any(|value| note_value == FilterNodeValue::from(value));
-
-		(matches, Some(format!("in:{}", field.as_str())).filter(|_| !matches))
-	}
-
-	fn evaluate_contains(
-		field: &FilterField,
-		value: &str,
-		note: &NoteMeta,
-	) -> (bool, Option<String>) {
-		let note_value = field.lookup_note_value(note);
-		let note_text = match note_value {
-			FilterNodeValue::String(s) => s,
-			_ => {
-				return (false, Some(format!("contains:{}", field.as_str())));
-			},
-		};
-		let matches = note_text.contains(value);
-
-		(matches, Some(format!("contains:{}", field.as_str())).filter(|_| !matches))
-	}
-
-	fn evaluate_gt(
-		field: &FilterField,
-		value: &FilterValue,
-		note: &NoteMeta,
-	) -> (bool, Option<String>) {
-		match field.lookup_note_value(note) {
-			FilterNodeValue::Number(note_value) => {
-				let matches = note_value > value.to_numeric();
-
-				(matches, Some(format!("gt:{}", field.as_str())).filter(|_| !matches))
-			},
-			FilterNodeValue::DateTime(note_value) => {
-				let matches = match value {
-					FilterValue::DateTime(filter_value) => note_value > *filter_value,
-					_ => false,
-				};
-
-				(matches, Some(format!("gt:{}", field.as_str())).filter(|_| !matches))
-			},
-			_ => (false, Some(format!("gt:{}", field.as_str()))),
-		}
-	}
-
-	fn evaluate_gte(
-		field: &FilterField,
-		value: &FilterValue,
-		note: &NoteMeta,
-	) -> (bool, Option<String>) {
-		match field.lookup_note_value(note) {
-			FilterNodeValue::Number(note_value) => {
-				let matches = note_value >= value.to_numeric();
-
-				(matches, Some(format!("gte:{}", field.as_str())).filter(|_| !matches))
-			},
-			FilterNodeValue::DateTime(note_value) => {
-				let matches = match value {
-					FilterValue::DateTime(filter_value) => note_value >= *filter_value,
-					_ => false,
-				};
-
-				(matches, Some(format!("gte:{}", field.as_str())).filter(|_| !matches))
-			},
-			_ => (false, Some(format!("gte:{}", field.as_str()))),
-		}
-	}
-
-	fn evaluate_lt(
-		field: &FilterField,
-		value: &FilterValue,
-		note: &NoteMeta,
-	) -> (bool, Option<String>) {
-		match field.lookup_note_value(note) {
-			FilterNodeValue::Number(note_value) => {
-				let matches = note_value < value.to_numeric();
-
-				(matches, Some(format!("lt:{}", field.as_str())).filter(|_| !matches))
-			},
-			FilterNodeValue::DateTime(note_value) => {
-				let matches = match value {
-					FilterValue::DateTime(filter_value) => note_value < *filter_value,
-					_ => false,
-				};
-
-				(matches, Some(format!("lt:{}", field.as_str())).filter(|_| !matches))
-			},
-			_ => (false, Some(format!("lt:{}", field.as_str()))),
-		}
-	}
-
-	fn evaluate_lte(
-		field: &FilterField,
-		value: &FilterValue,
-		note: &NoteMeta,
-	) -> (bool, Option<String>) {
-		match field.lookup_note_value(note) {
-			FilterNodeValue::Number(note_value) => {
-				let matches = note_value <= value.to_numeric();
-
-				(matches, Some(format!("lte:{}", field.as_str())).filter(|_| !matches))
-			},
-			FilterNodeValue::DateTime(note_value) => {
-				let matches = match value {
-					FilterValue::DateTime(filter_value) => note_value <= *filter_value,
-					_ => false,
-				};
-
-				(matches, Some(format!("lte:{}", field.as_str())).filter(|_| !matches))
+fn compare(
+	field: &FilterField,
+	note_value: &FilterNodeValue,
+	value: &FilterValue,
+) -> Option<Ordering> {
+	match (note_value, value) {
+		(FilterNodeValue::Number(note_value), value) => note_value.partial_cmp(&value.to_numeric()),
+		(FilterNodeValue::DateTime(note_value), FilterValue::DateTime(filter_value)) =>
+			Some(note_value.cmp(filter_value)),
+		// Structured field text compares as a date or a number when both sides parse as one.
+		(FilterNodeValue::String(note_text), FilterValue::String(filter_text))
+			if field.structured_field_kind().is_some() =>
+			match (
+				value::parse_structured_scalar(note_text)?,
+				value::parse_structured_scalar(filter_text)?,
+			) {
+				(FilterNodeValue::DateTime(lhs), FilterNodeValue::DateTime(rhs)) =>
+					Some(lhs.cmp(&rhs)),
+				(FilterNodeValue::Number(lhs), FilterNodeValue::Number(rhs)) =>
+					lhs.partial_cmp(&rhs),
+				_ => None,
 			},
-			_ => (false, Some(format!("lte:{}", field.as_str()))),
-		}
+		_ => None,
 	}
 }
@@ -17,9 +17,12 @@ pub(in crate::search::filter) enum FilterField {
 	ExpiresAt,
 	HitCount,
 	LastHitAt,
+	ValueDate,
+	ValueAmount { name: String },
+	StructuredField { name: String },
 }
 impl FilterField {
-	pub(in crate::search::filter) fn as_str(&self) -> &'static str {
+	pub(in crate::search::filter) fn as_str(&self) -> &str {
 		match self {
 			Self::Type => "type",
 			Self::Key => "key",
@@ -31,6 +34,16 @@ impl FilterField {
 			Self::ExpiresAt => "expires_at",
 			Self::HitCount => "hit_count",
 			Self::LastHitAt => "last_hit_at",
+			Self::ValueDate => "value.date",
+			Self::ValueAmount { name } | Self::StructuredField { name } => name.as_str(),
+		}
+	}
+
+	/// Returns the `memory_note_fields.field_kind` a `fields.<kind>` predicate reads.
+	pub(in crate::search::filter) fn structured_field_kind(&self) -> Option<&str> {
+		match self {
+			Self::StructuredField { name } => Some(&name["fields.".len()..]),
+			_ => None,
 		}
 	}
 
@@ -57,18 +70,30 @@ impl FilterField {
 			"expires_at" => Ok(Self::ExpiresAt),
 			"hit_count" => Ok(Self::HitCount),
 			"last_hit_at" => Ok(Self::LastHitAt),
+			"value.date" => Ok(Self::ValueDate),
+			_ if field.strip_prefix("value.").is_some_and(|unit| {
+				!unit.is_empty() && unit.chars().all(|ch| ch.is_ascii_alphanumeric())
+			}) =>
+				Ok(Self::ValueAmount { name: field }),
+			_ if field.strip_prefix("fields.").is_some_and(|kind| {
+				!kind.is_empty() && kind.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+			}) =>
+				Ok(Self::StructuredField { name: field }),
 			_ => Err(FilterParseError {
 				path: path.to_string(),
 				message: format!(
-					"field '{}' is not in allowlist: type, key, scope, agent_id, importance, confidence, updated_at, expires_at, hit_count, last_hit_at",
+					"field '{}' is not in allowlist: type, key, scope, agent_id, importance, confidence, updated_at, expires_at, hit_count, last_hit_at, value.date, value.<unit>, fields.<kind>",
 					field,
 				),
 			}),
 		}
 	}
 
-	pub(in crate::search::filter) fn lookup_note_value(&self, note: &NoteMeta) -> FilterNodeValue {
-		match self {
+	pub(in crate::search::filter) fn lookup_note_values(
+		&self,
+		note: &NoteMeta,
+	) -> Vec<FilterNodeValue> {
+		let value = match self {
 			Self::Type => FilterNodeValue::String(note.note_type.clone()),
 			Self::Key => FilterNodeValue::String(note.key.clone().unwrap_or_default()),
 			Self::Scope => FilterNodeValue::String(note.scope.clone()),
@@ -81,6 +106,38 @@ impl FilterField {
 				note.expires_at.map_or(FilterNodeValue::Null, FilterNodeValue::DateTime),
 			Self::LastHitAt =>
 				note.last_hit_at.map_or(FilterNodeValue::Null, FilterNodeValue::DateTime),
-		}
+			Self::ValueDate =>
+				return note
+					.values
+					.iter()
+					.filter_map(|value| value.date_value)
+					.map(|date| FilterNodeValue::DateTime(date.midnight().assume_utc()))
+					.collect(),
+			Self::ValueAmount { name } => {
+				let unit = &name["value.".len()..];
+
+				return note
+					.values
+					.iter()
+					.filter(|value| {
+						value.unit.as_deref().is_some_and(|value| value.eq_ignore_ascii_case(unit))
+					})
+					.filter_map(|value| value.numeric_value)
+					.map(FilterNodeValue::Number)
+					.collect();
+			},
+			Self::StructuredField { name } => {
+				let kind = &name["fields.".len()..];
+
+				return note
+					.fields
+					.iter()
+					.filter(|field| field.field_kind == kind)
+					.map(|field| FilterNodeValue::String(field.text.clone()))
+					.collect();
+			},
+		};
+
+		vec![value]
 	}
 }
@@ -6,7 +6,7 @@ use crate::search::filter::{
 		self, FilterParseError, FilterParseState, MAX_FILTER_DEPTH, MAX_FILTER_NODES,
 		MAX_IN_LIST_ITEMS,
 	},
-	value::FilterValue,
+	value::{self, FilterValue},
 };
 
 impl FilterExpr {
@@ -120,6 +120,16 @@ impl FilterExpr {
 		})?;
 		let value = parser::parse_value(&field, value_raw, &path_value)?;
 
+		if matches!(op, "gt" | "gte" | "lt" | "lte")
+			&& field.structured_field_kind().is_some()
+			&& !matches!(&value, FilterValue::String(text) if value::parse_structured_scalar(text).is_some())
+		{
+			return Err(FilterParseError {
+				path: path_value,
+				message: "structured field comparisons require a date (RFC3339 or YYYY-MM-DD) or numeric string value.".to_string(),
+			});
+		}
+
 		match op {
 			"eq" => Ok(Self::Eq { field, value }),
 			"neq" => Ok(Self::Neq { field, value }),
@@ -15,6 +15,7 @@ pub(crate) struct SearchFilterImpact {
 	pub(crate) dropped_total: usize,
 	pub(crate) top_drop_reasons: Vec<SearchFilterDropReason>,
 	pub(crate) filter: Value,
+	pub(crate) field_predicates: Vec<Value>,
 }
 impl SearchFilterImpact {
 	pub(crate) fn from_eval(
@@ -70,6 +71,7 @@ impl SearchFilterImpact {
 			dropped_total: pre.saturating_sub(post),
 			top_drop_reasons,
 			filter: filter.as_value(),
+			field_predicates: filter.structured_field_predicates(),
 		}
 	}
 
@@ -83,6 +85,7 @@ impl SearchFilterImpact {
 			"dropped_total": self.dropped_total,
 			"top_drop_reasons": self.top_drop_reasons,
 			"filter": self.filter,
+			"field_predicates": self.field_predicates,
 		})
 	}
 }
@@ -26,6 +26,26 @@ impl SearchFilter {
 		self.expr.evaluate(note)
 	}
 
+	/// Lists the structured field kinds the filter reads, sorted and deduplicated.
+	pub(crate) fn structured_field_kinds(&self) -> Vec<String> {
+		let mut kinds: Vec<String> = self
+			.expr
+			.structured_field_predicates()
+			.into_iter()
+			.filter_map(FilterExpr::leaf_field)
+			.filter_map(|field| field.structured_field_kind().map(str::to_string))
+			.collect();
+
+		kinds.sort();
+		kinds.dedup();
+
+		kinds
+	}
+
+	pub(in crate::search::filter) fn structured_field_predicates(&self) -> Vec<Value> {
+		self.expr.structured_field_predicates().into_iter().map(FilterExpr::to_value).collect()
+	}
+
 	pub(crate) fn parse(raw: &Value) -> Result<Self, FilterParseError> {
 		let path = "$.filter";
 		let obj = raw.as_object().ok_or_else(|| FilterParseError {
@@ -1,5 +1,7 @@
 use serde_json::Value;
-use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use time::{
+	Date, OffsetDateTime, format_description::well_known::Rfc3339, macros::format_description,
+};
 
 use crate::search::filter::{
 	expr::FilterField,
@@ -13,18 +15,24 @@ pub(in crate::search::filter) fn parse_value(
 	path: &str,
 ) -> Result<FilterValue, FilterParseError> {
 	match field {
-		FilterField::Type | FilterField::Key | FilterField::Scope | FilterField::AgentId =>
-			match raw {
-				Value::String(_) | Value::Null if matches!(field, FilterField::Key) => {
-					if raw.is_null() {
-						Ok(FilterValue::Null)
-					} else {
-						parse_string(path, raw).map(FilterValue::String)
-					}
-				},
-				_ => parse_string(path, raw).map(FilterValue::String),
+		FilterField::Type
+		| FilterField::Key
+		| FilterField::Scope
+		| FilterField::AgentId
+		| FilterField::StructuredField { .. } => match raw {
+			Value::String(_) | Value::Null if matches!(field, FilterField::Key) => {
+				if raw.is_null() {
+					Ok(FilterValue::Null)
+				} else {
+					parse_string(path, raw).map(FilterValue::String)
+				}
 			},
-		FilterField::Importance | FilterField::Confidence | FilterField::HitCount => {
+			_ => parse_string(path, raw).map(FilterValue::String),
+		},
+		FilterField::Importance
+		| FilterField::Confidence
+		| FilterField::HitCount
+		| FilterField::ValueAmount { .. } => {
 			let value = raw.as_f64().ok_or_else(|| FilterParseError {
 				path: path.to_string(),
 				message: "numeric value expected.".to_string(),
@@ -39,6 +47,20 @@ pub(in crate::search::filter) fn parse_value(
 					path: path.to_string(),
 					message: "datetime value must be RFC3339.".to_string(),
 				}),
+		FilterField::ValueDate => {
+			let value = parse_string(path, raw)?;
+
+			OffsetDateTime::parse(value.as_str(), &Rfc3339)
+				.or_else(|_| {
+					Date::parse(value.as_str(), format_description!("[year]-[month]-[day]"))
+						.map(|date| date.midnight().assume_utc())
+				})
+				.map(FilterValue::DateTime)
+				.map_err(|_| FilterParseError {
+					path: path.to_string(),
+					message: "date value must be RFC3339 or YYYY-MM-DD.".to_string(),
+				})
+		},
 		FilterField::ExpiresAt | FilterField::LastHitAt =>
 			if raw.is_null() {
 				Ok(FilterValue::Null)
@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 
 use serde_json::{Map, Value};
-use time::OffsetDateTime;
+use time::{Date, Month, OffsetDateTime};
 use uuid::Uuid;
 
 use crate::search::{
-	ChunkCandidate, NoteMeta,
+	ChunkCandidate, NoteFeedbackCounts, NoteField, NoteMeta, NoteValue,
 	filter::{
 		SearchFilter,
 		parser::{
@@ -29,6 +29,12 @@ fn note_meta() -> NoteMeta {
 		embedding_version: "provider:model:1".to_string(),
 		hit_count: 4,
 		last_hit_at: None,
+		session_hit_count: 0,
+		feedback: NoteFeedbackCounts::default(),
+		evidence_coverage: None,
+		values: Vec::new(),
+		fields: Vec::new(),
+		pinned: false,
 	}
 }
 
@@ -151,6 +157,7 @@ fn eval_filters_note_metadata() {
 		scope: Some("project_shared".to_string()),
 		updated_at: None,
 		embedding_version: None,
+		embedding_projection: None,
 	};
 	let (result, impact) = filter.eval(vec![candidate], &note_meta, 10, 12);
 
@@ -159,6 +166,138 @@ fn eval_filters_note_metadata() {
 	assert_eq!(impact.effective_candidate_k, 12);
 }
 
+#[test]
+fn eval_ranges_over_extracted_values() {
+	let note = NoteMeta {
+		values: vec![
+			NoteValue {
+				unit: Some("USD".to_string()),
+				numeric_value: Some(1_200.0),
+				date_value: None,
+			},
+			NoteValue {
+				unit: Some("m".to_string()),
+				numeric_value: Some(2_500.0),
+				date_value: None,
+			},
+			NoteValue {
+				unit: None,
+				numeric_value: None,
+				date_value: Some(
+					Date::from_calendar_date(2026, Month::March, 14).expect("valid date"),
+				),
+			},
+		],
+		..note_meta()
+	};
+	let in_range = SearchFilter::parse(&serde_json::json!({
+		"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+		"expr": {
+			"op": "and",
+			"args": [
+				{ "op": "gte", "field": "value.usd", "value": 1000 },
+				{ "op": "lt", "field": "value.date", "value": "2026-04-01" },
+			],
+		},
+	}))
+	.expect("valid filter");
+	let out_of_range = SearchFilter::parse(&serde_json::json!({
+		"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+		"expr": { "op": "gt", "field": "value.m", "value": 3000 },
+	}))
+	.expect("valid filter");
+	let missing_unit = SearchFilter::parse(&serde_json::json!({
+		"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+		"expr": { "op": "lte", "field": "value.kg", "value": 10 },
+	}))
+	.expect("valid filter");
+
+	assert_eq!(in_range.evaluate(&note), (true, None));
+	assert_eq!(out_of_range.evaluate(&note), (false, Some("gt:value.m".to_string())));
+	assert!(!missing_unit.evaluate(&note).0);
+}
+
+#[test]
+fn eval_matches_structured_fields() {
+	let note = NoteMeta {
+		fields: vec![
+			NoteField { field_kind: "owner".to_string(), text: "alice".to_string() },
+			NoteField { field_kind: "deadline".to_string(), text: "2024-12-15".to_string() },
+			NoteField { field_kind: "estimate".to_string(), text: "8".to_string() },
+		],
+		..note_meta()
+	};
+	let matching = SearchFilter::parse(&serde_json::json!({
+		"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+		"expr": {
+			"op": "and",
+			"args": [
+				{ "op": "eq", "field": "fields.owner", "value": "alice" },
+				{ "op": "lt", "field": "fields.deadline", "value": "2025-01-01" },
+				{ "op": "gte", "field": "fields.estimate", "value": "5" },
+			],
+		},
+	}))
+	.expect("valid filter");
+	let late = SearchFilter::parse(&serde_json::json!({
+		"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+		"expr": { "op": "gt", "field": "fields.deadline", "value": "2025-01-01T00:00:00Z" },
+	}))
+	.expect("valid filter");
+	let missing_kind = SearchFilter::parse(&serde_json::json!({
+		"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+		"expr": { "op": "eq", "field": "fields.reviewer", "value": "alice" },
+	}))
+	.expect("valid filter");
+
+	assert_eq!(matching.evaluate(&note), (true, None));
+	assert_eq!(late.evaluate(&note), (false, Some("gt:fields.deadline".to_string())));
+	assert!(!missing_kind.evaluate(&note).0);
+	assert_eq!(
+		matching.structured_field_kinds(),
+		vec!["deadline".to_string(), "estimate".to_string(), "owner".to_string()]
+	);
+}
+
+#[test]
+fn parse_rejects_structured_field_comparison_without_orderable_value() {
+	let err = SearchFilter::parse(&serde_json::json!({
+		"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+		"expr": { "op": "lt", "field": "fields.deadline", "value": "soon" },
+	}))
+	.expect_err("expected non-orderable comparison error");
+
+	assert!(err.to_string().contains("$.filter.expr.value"));
+	assert!(
+		SearchFilter::parse(&serde_json::json!({
+			"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+			"expr": { "op": "eq", "field": "fields.", "value": "alice" },
+		}))
+		.is_err()
+	);
+}
+
+#[test]
+fn filter_impact_reports_structured_field_predicates() {
+	let filter = SearchFilter::parse(&serde_json::json!({
+		"schema": SEARCH_FILTER_EXPR_SCHEMA_V1,
+		"expr": {
+			"op": "or",
+			"args": [
+				{ "op": "eq", "field": "scope", "value": "project_shared" },
+				{ "op": "not", "expr": { "op": "eq", "field": "fields.owner", "value": "bob" } },
+			],
+		},
+	}))
+	.expect("valid filter");
+	let (_, impact) = filter.eval(Vec::new(), &HashMap::new(), 10, 30);
+
+	assert_eq!(
+		impact.field_predicates,
+		vec![serde_json::json!({ "op": "eq", "field": "fields.owner", "value": "bob" })]
+	);
+}
+
 #[test]
 fn filter_impact_lists_top_drop_reasons_deterministically() {
 	let filter = SearchFilter::parse(&serde_json::json!({
@@ -187,6 +326,12 @@ fn filter_impact_lists_top_drop_reasons_deterministically() {
 			embedding_version: "provider:model:1".to_string(),
 			hit_count: 0,
 			last_hit_at: None,
+			session_hit_count: 0,
+			feedback: NoteFeedbackCounts::default(),
+			evidence_coverage: None,
+			values: Vec::new(),
+			fields: Vec::new(),
+			pinned: false,
 		},
 	);
 	note_meta.insert(
@@ -205,6 +350,12 @@ fn filter_impact_lists_top_drop_reasons_deterministically() {
 			embedding_version: "provider:model:1".to_string(),
 			hit_count: 0,
 			last_hit_at: None,
+			session_hit_count: 0,
+			feedback: NoteFeedbackCounts::default(),
+			evidence_coverage: None,
+			values: Vec::new(),
+			fields: Vec::new(),
+			pinned: false,
 		},
 	);
 
@@ -218,6 +369,7 @@ fn filter_impact_lists_top_drop_reasons_deterministically() {
 			scope: None,
 			updated_at: None,
 			embedding_version: None,
+			embedding_projection: None,
 		},
 		ChunkCandidate {
 			note_id: second,
@@ -228,6 +380,7 @@ fn filter_impact_lists_top_drop_reasons_deterministically() {
 			scope: None,
 			updated_at: None,
 			embedding_version: None,
+			embedding_projection: None,
 		},
 		ChunkCandidate {
 			note_id: third,
@@ -238,6 +391,7 @@ fn filter_impact_lists_top_drop_reasons_deterministically() {
 			scope: None,
 			updated_at: None,
 			embedding_version: None,
+			embedding_projection: None,
 		},
 	];
 	let (_, impact) = filter.eval(candidates, &note_meta, 10, 20);
@@ -1,5 +1,7 @@
 use serde_json::Value;
-use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use time::{
+	Date, OffsetDateTime, format_description::well_known::Rfc3339, macros::format_description,
+};
 
 #[derive(Clone, Debug)]
 pub(super) enum FilterValue {
@@ -47,6 +49,20 @@ impl PartialEq for FilterValue {
 	}
 }
 
+/// Reads structured field text as an orderable value: a date (RFC3339 or YYYY-MM-DD) or a number.
+pub(super) fn parse_structured_scalar(text: &str) -> Option<FilterNodeValue> {
+	let text = text.trim();
+
+	if let Ok(value) = OffsetDateTime::parse(text, &Rfc3339) {
+		return Some(FilterNodeValue::DateTime(value));
+	}
+	if let Ok(date) = Date::parse(text, format_description!("[year]-[month]-[day]")) {
+		return Some(FilterNodeValue::DateTime(date.midnight().assume_utc()));
+	}
+
+	text.parse::<f64>().ok().filter(|value| value.is_finite()).map(FilterNodeValue::Number)
+}
+
 #[derive(Clone, Debug)]
 pub(super) enum FilterNodeValue {
 	String(String),
@@ -1,28 +1,30 @@
 use crate::search::{
-	BuildTraceArgs, ElfService, FinishSearchArgs, FinishSearchScoringResult, OffsetDateTime,
-	RawSearchPath, Result, SearchResponse, Uuid, ranking,
+	self, BuildTraceArgs, ElfService, FinishSearchArgs, FinishSearchScoringResult, HashMap,
+	NoteMeta, OffsetDateTime, RawSearchPath, Result, SearchFilter, SearchResponse, SessionHitKey,
+	SessionRankingMode, Uuid, ranking, search_hooks,
 };
 
 impl ElfService {
 	pub(in crate::search) async fn finish_search(
 		&self,
-		args: FinishSearchArgs<'_>,
+		mut args: FinishSearchArgs<'_>,
 	) -> Result<SearchResponse> {
 		let now = OffsetDateTime::now_utc();
-		let candidate_count = args.candidates.len();
-		let candidate_note_ids: Vec<Uuid> =
-			args.candidates.iter().map(|candidate| candidate.note_id).collect();
 		let policies = self.resolve_finish_search_policies(args.ranking_override.as_ref())?;
-		let note_meta = self
-			.fetch_note_meta_for_candidates(
-				args.tenant_id,
-				args.project_id,
-				args.agent_id,
-				args.allowed_scopes,
-				candidate_note_ids.as_slice(),
-				now,
-			)
-			.await?;
+		let hook_ctx = args.hook_context();
+		let session_key = args.session_hit_key();
+		let session_mode =
+			args.session.map(|session| session.mode).unwrap_or(SessionRankingMode::Off);
+		let (candidate_count, note_meta) =
+			self.prepare_finish_candidates(&mut args, session_key, now).await?;
+		let mut hook_runs = args.hook_runs;
+		let mut warnings = search::scope_candidate_warnings(
+			args.allowed_scopes,
+			args.candidates
+				.iter()
+				.filter_map(|candidate| note_meta.get(&candidate.note_id))
+				.map(|meta| meta.scope.as_str()),
+		);
 		let scoring = self
 			.build_finish_search_scoring(
 				args.query,
@@ -35,7 +37,10 @@ impl ElfService {
 				args.requested_candidate_k,
 				args.effective_candidate_k,
 				now,
-				args.path == RawSearchPath::Quick,
+				args.path == RawSearchPath::Quick || args.degraded_mode.is_some(),
+				session_mode,
+				&hook_ctx,
+				&mut hook_runs,
 			)
 			.await?;
 		let FinishSearchScoringResult {
@@ -50,6 +55,7 @@ impl ElfService {
 			selected_results,
 			diversity_decisions,
 			selected_count,
+			warnings: scoring_warnings,
 		} = scoring;
 		let relation_contexts = self
 			.build_relation_context_for_selected_results(
@@ -62,15 +68,24 @@ impl ElfService {
 			)
 			.await?;
 
+		warnings.extend(scoring_warnings);
+		warnings.extend(search_hooks::hook_warnings(&hook_runs));
+
 		ranking::attach_diversity_decisions_to_trace_candidates(
 			&mut trace_candidates,
 			&diversity_decisions,
 		);
 
-		self.record_hits_if_enabled(args.record_hits_enabled, args.query, &selected_results, now)
-			.await?;
+		self.record_hits_if_enabled(
+			args.record_hits_enabled,
+			args.query,
+			&selected_results,
+			session_key,
+			now,
+		)
+		.await?;
 
-		let (items, trajectory_summary) = self
+		let (items, trajectory_summary, packing_warning) = self
 			.build_items_and_write_trace(BuildTraceArgs {
 				path: args.path,
 				trace_id: args.trace_id,
@@ -104,13 +119,63 @@ impl ElfService {
 				ranking_override: &args.ranking_override,
 				filter_impact,
 				payload_level: args.payload_level,
+				max_result_tokens: args.max_result_tokens,
+				hook_runs: &hook_runs,
+				degraded_mode: args.degraded_mode,
 			})
 			.await?;
 
+		warnings.extend(packing_warning);
+
 		Ok(SearchResponse {
 			trace_id: args.trace_id,
 			items,
 			trajectory_summary: Some(trajectory_summary),
+			warnings,
 		})
 	}
+
+	/// Appends pinned candidates, loads note metadata, and drops candidates that fail the payload
+	/// filters. Returns the candidate count before filtering together with the metadata.
+	async fn prepare_finish_candidates(
+		&self,
+		args: &mut FinishSearchArgs<'_>,
+		session_key: Option<SessionHitKey<'_>>,
+		now: OffsetDateTime,
+	) -> Result<(usize, HashMap<Uuid, NoteMeta>)> {
+		self.append_pinned_candidates(
+			&mut args.candidates,
+			args.tenant_id,
+			args.project_id,
+			args.allowed_scopes,
+			now,
+		)
+		.await?;
+
+		let candidate_count = args.candidates.len();
+		let candidate_note_ids: Vec<Uuid> =
+			args.candidates.iter().map(|candidate| candidate.note_id).collect();
+		let structured_field_kinds =
+			args.filter.map(SearchFilter::structured_field_kinds).unwrap_or_default();
+		let note_meta = self
+			.fetch_note_meta_for_candidates(
+				args.tenant_id,
+				args.project_id,
+				args.agent_id,
+				args.allowed_scopes,
+				candidate_note_ids.as_slice(),
+				structured_field_kinds.as_slice(),
+				session_key,
+				now,
+			)
+			.await?;
+		let payload_filters = args.payload_filters;
+
+		// Structured-field and pinned candidates bypass the Qdrant payload filter.
+		args.candidates.retain(|candidate| {
+			note_meta.get(&candidate.note_id).is_none_or(|note| payload_filters.matches(note))
+		});
+
+		Ok((candidate_count, note_meta))
+	}
 }
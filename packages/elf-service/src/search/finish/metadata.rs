@@ -1,11 +1,16 @@
+use time::Date;
+
 use crate::{
 	access,
 	search::{
-		ElfService, HashMap, MemoryNote, NoteMeta, ORG_PROJECT_ID, OffsetDateTime, Result, Uuid,
+		ElfService, HashMap, HashSet, MemoryNote, NoteFeedbackCounts, NoteField, NoteMeta,
+		NoteValue, ORG_PROJECT_ID, OffsetDateTime, Result, SessionHitKey, Uuid,
 	},
+	session_hits,
 };
 
 impl ElfService {
+	#[allow(clippy::too_many_arguments)]
 	pub(in crate::search) async fn fetch_note_meta_for_candidates(
 		&self,
 		tenant_id: &str,
@@ -13,6 +18,8 @@ impl ElfService {
 		agent_id: &str,
 		allowed_scopes: &[String],
 		candidate_note_ids: &[Uuid],
+		structured_field_kinds: &[String],
+		session: Option<SessionHitKey<'_>>,
 		now: OffsetDateTime,
 	) -> Result<HashMap<Uuid, NoteMeta>> {
 		if candidate_note_ids.is_empty() {
@@ -45,6 +52,37 @@ WHERE note_id = ANY($1::uuid[])
 		.bind(ORG_PROJECT_ID)
 		.fetch_all(&self.db.pool)
 		.await?;
+		let evidence_coverage: HashMap<Uuid, f32> = sqlx::query_as::<_, (Uuid, f32)>(
+			"\
+SELECT note_id, coverage
+FROM memory_note_evidence
+WHERE note_id = ANY($1::uuid[])",
+		)
+		.bind(candidate_note_ids)
+		.fetch_all(&self.db.pool)
+		.await?
+		.into_iter()
+		.collect();
+		let pinned_note_ids: HashSet<Uuid> = sqlx::query_scalar(
+			"\
+SELECT note_id
+FROM memory_notes
+WHERE note_id = ANY($1::uuid[]) AND pinned",
+		)
+		.bind(candidate_note_ids)
+		.fetch_all(&self.db.pool)
+		.await?
+		.into_iter()
+		.collect();
+		let session_hit_counts = match session {
+			Some(key) =>
+				session_hits::load_session_hit_counts(&self.db.pool, key, candidate_note_ids, now)
+					.await?,
+			None => HashMap::new(),
+		};
+		let feedback = self.load_note_feedback_counts(candidate_note_ids).await?;
+		let mut values = self.load_note_values(candidate_note_ids).await?;
+		let mut fields = self.load_note_fields(candidate_note_ids, structured_field_kinds).await?;
 		let mut note_meta = HashMap::new();
 
 		for note in notes {
@@ -68,10 +106,106 @@ WHERE note_id = ANY($1::uuid[])
 					embedding_version: note.embedding_version,
 					hit_count: note.hit_count,
 					last_hit_at: note.last_hit_at,
+					session_hit_count: session_hit_counts
+						.get(&note.note_id)
+						.copied()
+						.unwrap_or_default(),
+					feedback: feedback.get(&note.note_id).copied().unwrap_or_default(),
+					evidence_coverage: evidence_coverage.get(&note.note_id).copied(),
+					values: values.remove(&note.note_id).unwrap_or_default(),
+					fields: fields.remove(&note.note_id).unwrap_or_default(),
+					pinned: pinned_note_ids.contains(&note.note_id),
 				},
 			);
 		}
 
 		Ok(note_meta)
 	}
+
+	async fn load_note_values(
+		&self,
+		candidate_note_ids: &[Uuid],
+	) -> Result<HashMap<Uuid, Vec<NoteValue>>> {
+		let mut values: HashMap<Uuid, Vec<NoteValue>> = HashMap::new();
+
+		for (note_id, unit, numeric_value, date_value) in
+			sqlx::query_as::<_, (Uuid, Option<String>, Option<f64>, Option<Date>)>(
+				"\
+SELECT note_id, unit, numeric_value, date_value
+FROM memory_note_values
+WHERE note_id = ANY($1::uuid[])",
+			)
+			.bind(candidate_note_ids)
+			.fetch_all(&self.db.pool)
+			.await?
+		{
+			values.entry(note_id).or_default().push(NoteValue { unit, numeric_value, date_value });
+		}
+
+		Ok(values)
+	}
+
+	/// Loads structured fields, limited to the kinds a search filter reads.
+	async fn load_note_fields(
+		&self,
+		candidate_note_ids: &[Uuid],
+		structured_field_kinds: &[String],
+	) -> Result<HashMap<Uuid, Vec<NoteField>>> {
+		let mut fields: HashMap<Uuid, Vec<NoteField>> = HashMap::new();
+
+		if structured_field_kinds.is_empty() {
+			return Ok(fields);
+		}
+
+		for (note_id, field_kind, text) in sqlx::query_as::<_, (Uuid, String, String)>(
+			"\
+SELECT note_id, field_kind, text
+FROM memory_note_fields
+WHERE note_id = ANY($1::uuid[]) AND field_kind = ANY($2::text[])
+ORDER BY note_id ASC, field_kind ASC, item_index ASC",
+		)
+		.bind(candidate_note_ids)
+		.bind(structured_field_kinds)
+		.fetch_all(&self.db.pool)
+		.await?
+		{
+			fields.entry(note_id).or_default().push(NoteField { field_kind, text });
+		}
+
+		Ok(fields)
+	}
+
+	/// Aggregates recorded result feedback per note when the feedback term is enabled.
+	async fn load_note_feedback_counts(
+		&self,
+		candidate_note_ids: &[Uuid],
+	) -> Result<HashMap<Uuid, NoteFeedbackCounts>> {
+		let det = &self.cfg.ranking.deterministic;
+
+		if !det.enabled || !det.feedback.as_ref().is_some_and(|feedback| feedback.enabled) {
+			return Ok(HashMap::new());
+		}
+
+		let rows = sqlx::query_as::<_, (Uuid, i64, i64, i64)>(
+			"\
+SELECT
+	note_id,
+	COUNT(*) FILTER (WHERE feedback = 'useful'),
+	COUNT(*) FILTER (WHERE feedback = 'not_useful'),
+	COUNT(*) FILTER (WHERE feedback = 'wrong')
+FROM memory_feedback
+WHERE note_id = ANY($1::uuid[])
+GROUP BY note_id",
+		)
+		.bind(candidate_note_ids)
+		.fetch_all(&self.db.pool)
+		.await?;
+
+		Ok(rows
+			.into_iter()
+			.map(|(note_id, useful, not_useful, wrong)| {
+				(note_id, NoteFeedbackCounts { useful, not_useful, wrong })
+			})
+			.collect())
+	}
 }
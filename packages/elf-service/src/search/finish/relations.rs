@@ -17,7 +17,7 @@ impl ElfService {
 		allowed_scopes: &[String],
 		now: OffsetDateTime,
 	) -> Result<HashMap<Uuid, Vec<SearchExplainRelationContext>>> {
-		if !self.cfg.search.graph_context.enabled {
+		if !self.subsystems.graph || !self.cfg.search.graph_context.enabled {
 			return Ok(HashMap::new());
 		}
 
@@ -1,6 +1,6 @@
 use crate::search::{
 	self, CacheKind, Duration, ElfService, OffsetDateTime, RerankCacheCandidate, RerankCacheItem,
-	RerankCachePayload, SearchCache, ranking,
+	RerankCachePayload, SearchCache, SearchWarning, ranking,
 };
 
 impl ElfService {
@@ -10,6 +10,7 @@ impl ElfService {
 		cache_candidates: &[RerankCacheCandidate],
 		cache_cfg: &SearchCache,
 		now: OffsetDateTime,
+		warnings: &mut Vec<SearchWarning>,
 	) -> Option<Vec<f32>> {
 		match search::fetch_cache_payload(&self.db.pool, CacheKind::Rerank, key, now).await {
 			Ok(Some(payload)) => {
@@ -71,6 +72,10 @@ impl ElfService {
 					"Cache read failed."
 				);
 
+				warnings.push(SearchWarning::CacheUnavailable {
+					cache_kind: CacheKind::Rerank.as_str().to_string(),
+				});
+
 				None
 			},
 		}
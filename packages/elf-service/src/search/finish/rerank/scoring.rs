@@ -1,28 +1,27 @@
-use crate::{
-	Error,
-	search::{
-		CacheKind, ChunkSnippet, ElfService, OffsetDateTime, RerankCacheCandidate, Result,
-		SearchCache, Uuid, ranking,
-	},
+use crate::search::{
+	CacheKind, ChunkSnippet, ElfService, OffsetDateTime, RerankCacheCandidate, Result, SearchCache,
+	SearchWarning, Uuid, ranking,
 };
 
 impl ElfService {
+	#[tracing::instrument(name = "search.rerank", skip_all)]
 	pub(in crate::search) async fn rerank_snippet_items(
 		&self,
 		query: &str,
 		snippet_items: &[ChunkSnippet],
 		cache_cfg: &SearchCache,
 		now: OffsetDateTime,
-	) -> Result<Vec<f32>> {
+	) -> Result<(Vec<f32>, Vec<SearchWarning>)> {
 		if snippet_items.is_empty() {
-			return Ok(Vec::new());
+			return Ok((Vec::new(), Vec::new()));
 		}
 
 		let (cache_candidates, signature) = Self::build_rerank_cache_signature(snippet_items);
 		let mut cache_key: Option<String> = None;
 		let mut cached_scores: Option<Vec<f32>> = None;
+		let mut warnings = Vec::new();
 
-		if cache_cfg.enabled {
+		if cache_cfg.enabled && self.subsystems.caches {
 			match ranking::build_rerank_cache_key(
 				query,
 				self.cfg.providers.rerank.provider_id.as_str(),
@@ -32,7 +31,13 @@ impl ElfService {
 				Ok(key) => {
 					cache_key = Some(key.clone());
 					cached_scores = self
-						.read_rerank_cache_scores(&key, cache_candidates.as_slice(), cache_cfg, now)
+						.read_rerank_cache_scores(
+							&key,
+							cache_candidates.as_slice(),
+							cache_cfg,
+							now,
+							&mut warnings,
+						)
 						.await;
 				},
 				Err(err) => {
@@ -46,18 +51,29 @@ impl ElfService {
 		}
 
 		if let Some(scores) = cached_scores {
-			return Ok(scores);
+			return Ok((scores, warnings));
 		}
 
 		let docs: Vec<String> = snippet_items.iter().map(|item| item.snippet.clone()).collect();
-		let scores = self.providers.rerank.rerank(&self.cfg.providers.rerank, query, &docs).await?;
+		let scores = match self
+			.providers
+			.rerank
+			.rerank(&self.cfg.providers.rerank, query, &docs)
+			.await
+		{
+			Ok(scores) if scores.len() == snippet_items.len() => scores,
+			Ok(_) => {
+				let reason = "Rerank provider returned mismatched score count.".to_string();
+
+				return Ok(Self::degraded_rerank_scores(snippet_items, reason, warnings));
+			},
+			Err(err) => {
+				return Ok(Self::degraded_rerank_scores(snippet_items, err.to_string(), warnings));
+			},
+		};
 
-		if scores.len() != snippet_items.len() {
-			return Err(Error::Provider {
-				message: "Rerank provider returned mismatched score count.".to_string(),
-			});
-		}
 		if cache_cfg.enabled
+			&& self.subsystems.caches
 			&& let Some(key) = cache_key.as_ref()
 			&& !cache_candidates.is_empty()
 		{
@@ -70,7 +86,19 @@ impl ElfService {
 			.await;
 		}
 
-		Ok(scores)
+		Ok((scores, warnings))
+	}
+
+	fn degraded_rerank_scores(
+		snippet_items: &[ChunkSnippet],
+		reason: String,
+		mut warnings: Vec<SearchWarning>,
+	) -> (Vec<f32>, Vec<SearchWarning>) {
+		tracing::warn!(reason = %reason, "Rerank failed; falling back to retrieval order.");
+
+		warnings.push(SearchWarning::RerankDegraded { reason });
+
+		(Self::build_quick_find_rerank_scores(snippet_items), warnings)
 	}
 
 	pub(in crate::search) fn build_rerank_cache_signature(
@@ -1,6 +1,9 @@
-use crate::search::{
-	self, ChunkCandidate, ChunkMeta, ChunkSnippet, ElfService, HashMap, NoteMeta, Result, Uuid,
-	ranking,
+use crate::{
+	search::{
+		self, ChunkCandidate, ChunkMeta, ChunkSnippet, ElfService, HashMap, NoteMeta, Result, Uuid,
+		ranking,
+	},
+	structured_fields::QA_NOTE_TYPE,
 };
 
 impl ElfService {
@@ -23,6 +26,15 @@ impl ElfService {
 			chunk_by_id.insert(row.chunk_id, row);
 		}
 
+		// `qa` notes surface their answer as the snippet instead of the stitched chunk text.
+		let qa_note_ids: Vec<Uuid> = filtered_candidates
+			.iter()
+			.filter(|candidate| {
+				note_meta.get(&candidate.note_id).is_some_and(|note| note.note_type == QA_NOTE_TYPE)
+			})
+			.map(|candidate| candidate.note_id)
+			.collect();
+		let qa_answers = search::fetch_qa_answers(&self.db.pool, &qa_note_ids).await?;
 		let mut items = Vec::new();
 
 		for candidate in filtered_candidates {
@@ -34,11 +46,14 @@ impl ElfService {
 
 				continue;
 			};
-			let snippet = ranking::stitch_snippet(
-				candidate.note_id,
-				chunk_row.chunk_index,
-				&chunk_by_note_index,
-			);
+			let snippet = match qa_answers.get(&candidate.note_id) {
+				Some(answer) => answer.clone(),
+				None => ranking::stitch_snippet(
+					candidate.note_id,
+					chunk_row.chunk_index,
+					&chunk_by_note_index,
+				),
+			};
 
 			if snippet.is_empty() {
 				continue;
@@ -58,6 +73,7 @@ impl ElfService {
 				snippet,
 				retrieval_rank: candidate.retrieval_rank,
 				retrieval_score: candidate.retrieval_score,
+				embedding_projection: candidate.embedding_projection.clone(),
 			});
 		}
 
@@ -1,7 +1,11 @@
-use crate::search::{
-	self, ChunkCandidate, ElfService, FinishSearchPolicies, FinishSearchScoringResult, HashMap,
-	MAX_MATCHED_TERMS, NoteMeta, OffsetDateTime, Result, ScoreSnippetArgs, SearchFilter, Uuid,
-	ranking, structured,
+use crate::{
+	search::{
+		self, ChunkCandidate, ElfService, FinishSearchPolicies, FinishSearchScoringResult, HashMap,
+		MAX_MATCHED_TERMS, NoteMeta, OffsetDateTime, Result, ScoreSnippetArgs, SearchFilter,
+		SearchHookRun, SearchHookStage, SearchStageContext, SessionRankingMode, Uuid, ranking,
+		structured,
+	},
+	search_stream,
 };
 
 impl ElfService {
@@ -19,7 +23,18 @@ impl ElfService {
 		effective_candidate_k: u32,
 		now: OffsetDateTime,
 		skip_rerank: bool,
+		session_mode: SessionRankingMode,
+		hook_ctx: &SearchStageContext<'_>,
+		hook_runs: &mut Vec<SearchHookRun>,
 	) -> Result<FinishSearchScoringResult> {
+		self.run_search_hooks(
+			hook_ctx,
+			SearchHookStage::PostRetrieval,
+			|| search::hook_candidates_from_chunks(&candidates),
+			hook_runs,
+		)
+		.await;
+
 		let (filtered_candidates, filter_impact) = self.apply_filter_to_candidates(
 			candidates,
 			note_meta,
@@ -28,13 +43,24 @@ impl ElfService {
 			effective_candidate_k,
 		);
 		let filtered_candidate_count = filtered_candidates.len();
+
+		search_stream::emit_stage(
+			2,
+			"recall.candidates",
+			filtered_candidate_count,
+			serde_json::json!({
+				"candidate_count_before_filter": candidate_count,
+				"candidate_count_after_filter": filtered_candidate_count,
+			}),
+		);
+
 		let snippet_items = self.build_snippet_items(&filtered_candidates, note_meta).await?;
 		let snippet_count = snippet_items.len();
 		let query_tokens = ranking::tokenize_query(query, MAX_MATCHED_TERMS);
 		let scope_context_boost_by_scope =
 			ranking::build_scope_context_boost_by_scope(&query_tokens, self.cfg.context.as_ref());
 		let det_query_tokens = structured::build_deterministic_query_tokens(&self.cfg, query);
-		let scored = self
+		let (scored, mut warnings) = self
 			.score_snippet_items(ScoreSnippetArgs {
 				query,
 				snippet_items,
@@ -45,16 +71,67 @@ impl ElfService {
 				now,
 				candidate_count,
 				skip_rerank,
+				session_mode,
 			})
 			.await?;
 		let scored_count = scored.len();
+
+		// Quick searches keep retrieval order without calling the reranker, so no rerank stage ran.
+		if !skip_rerank {
+			search_stream::emit_stage(
+				4,
+				"rerank.score",
+				scored_count,
+				serde_json::json!({ "reranked_count": scored_count }),
+			);
+		}
+
 		let trace_candidates = self.build_trace_candidates(&scored, now);
+
+		self.run_search_hooks(
+			hook_ctx,
+			SearchHookStage::PostRerank,
+			|| search::hook_candidates_from_scored(&scored),
+			hook_runs,
+		)
+		.await;
+
 		let results = search::select_best_scored_chunks(scored);
+
+		search_stream::emit_stage(
+			3,
+			"fusion.merge",
+			results.len(),
+			serde_json::json!({ "scored_count": scored_count, "fused_count": results.len() }),
+		);
+		self.run_search_hooks(
+			hook_ctx,
+			SearchHookStage::PreSelection,
+			|| search::hook_candidates_from_scored(&results),
+			hook_runs,
+		)
+		.await;
+
 		let fused_results = results.clone();
-		let (selected_results, diversity_decisions) =
+		let (selected_results, mut diversity_decisions) =
 			self.apply_diversity_policy(results, top_k, &policies.diversity_policy).await?;
+		let selected_results = ranking::include_pinned_results(
+			selected_results,
+			&fused_results,
+			top_k,
+			&mut diversity_decisions,
+		);
 		let selected_count = selected_results.len();
 
+		search_stream::emit_stage(
+			5,
+			"selection.final",
+			selected_count,
+			serde_json::json!({ "selected_count": selected_count, "top_k": top_k }),
+		);
+
+		warnings.extend(search::filter_drop_warning(filter_impact.as_ref()));
+
 		Ok(FinishSearchScoringResult {
 			query_tokens,
 			filtered_candidates,
@@ -67,6 +144,7 @@ impl ElfService {
 			selected_results,
 			diversity_decisions,
 			selected_count,
+			warnings,
 		})
 	}
 }
@@ -1,8 +1,80 @@
 use crate::search::{
-	ChunkCandidate, ElfService, HashMap, NoteMeta, SearchFilter, SearchFilterImpact, Uuid, ranking,
+	ChunkCandidate, ElfService, HashMap, HashSet, NoteMeta, ORG_PROJECT_ID, OffsetDateTime, Result,
+	SearchFilter, SearchFilterImpact, Uuid, ranking,
 };
 
+const MAX_PINNED_CANDIDATES: i64 = 32;
+
 impl ElfService {
+	/// Appends the first chunk of each readable pinned note that retrieval did not return, so
+	/// pinned notes reach scoring even when the query does not match them.
+	pub(in crate::search) async fn append_pinned_candidates(
+		&self,
+		candidates: &mut Vec<ChunkCandidate>,
+		tenant_id: &str,
+		project_id: &str,
+		allowed_scopes: &[String],
+		now: OffsetDateTime,
+	) -> Result<()> {
+		let rows = sqlx::query_as::<_, (Uuid, Uuid, i32, String, OffsetDateTime, String)>(
+			"\
+SELECT note_id, chunk_id, chunk_index, scope, updated_at, embedding_version
+FROM (
+	SELECT DISTINCT ON (n.note_id)
+		n.note_id,
+		c.chunk_id,
+		c.chunk_index,
+		n.scope,
+		n.updated_at,
+		n.embedding_version
+	FROM memory_notes n
+	JOIN memory_note_chunks c ON c.note_id = n.note_id
+	WHERE n.tenant_id = $1
+		AND (n.project_id = $2 OR (n.project_id = $3 AND n.scope = 'org_shared'))
+		AND n.pinned
+		AND n.status = 'active'
+		AND n.scope = ANY($4::text[])
+		AND (n.expires_at IS NULL OR n.expires_at > $5)
+	ORDER BY n.note_id, c.chunk_index
+) pinned
+ORDER BY updated_at DESC, note_id ASC
+LIMIT $6",
+		)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(ORG_PROJECT_ID)
+		.bind(allowed_scopes)
+		.bind(now)
+		.bind(MAX_PINNED_CANDIDATES)
+		.fetch_all(&self.db.pool)
+		.await?;
+		let present: HashSet<Uuid> = candidates.iter().map(|candidate| candidate.note_id).collect();
+		let mut next_rank =
+			candidates.iter().map(|candidate| candidate.retrieval_rank).max().unwrap_or(0);
+
+		for (note_id, chunk_id, chunk_index, scope, updated_at, embedding_version) in rows {
+			if present.contains(&note_id) {
+				continue;
+			}
+
+			next_rank += 1;
+
+			candidates.push(ChunkCandidate {
+				chunk_id,
+				note_id,
+				chunk_index,
+				retrieval_rank: next_rank,
+				retrieval_score: None,
+				scope: Some(scope),
+				updated_at: Some(updated_at),
+				embedding_version: Some(embedding_version),
+				embedding_projection: None,
+			});
+		}
+
+		Ok(())
+	}
+
 	pub(in crate::search) fn apply_filter_to_candidates(
 		&self,
 		candidates: Vec<ChunkCandidate>,
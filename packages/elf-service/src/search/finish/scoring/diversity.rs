@@ -4,6 +4,7 @@ use crate::search::{
 };
 
 impl ElfService {
+	#[tracing::instrument(name = "search.diversity", skip_all)]
 	pub(in crate::search) async fn apply_diversity_policy(
 		&self,
 		results: Vec<ScoredChunk>,
@@ -1,4 +1,7 @@
-use crate::search::{self, ElfService, OffsetDateTime, Result, ScoredChunk};
+use crate::{
+	search::{self, ElfService, OffsetDateTime, Result, ScoredChunk, SessionHitKey, Uuid},
+	session_hits,
+};
 
 impl ElfService {
 	pub(in crate::search) async fn record_hits_if_enabled(
@@ -6,6 +9,7 @@ impl ElfService {
 		enabled: bool,
 		query: &str,
 		selected_results: &[ScoredChunk],
+		session: Option<SessionHitKey<'_>>,
 		now: OffsetDateTime,
 	) -> Result<()> {
 		if !enabled || selected_results.is_empty() {
@@ -16,6 +20,23 @@ impl ElfService {
 
 		search::record_hits(&mut *tx, query, selected_results, now).await?;
 
+		if let Some(key) = session {
+			let mut note_ids: Vec<Uuid> =
+				selected_results.iter().map(|scored| scored.item.note.note_id).collect();
+
+			note_ids.sort_unstable();
+			note_ids.dedup();
+
+			session_hits::record_session_hits(
+				&mut *tx,
+				key,
+				note_ids.as_slice(),
+				now,
+				session_hits::session_ttl_seconds(&self.cfg),
+			)
+			.await?;
+		}
+
 		tx.commit().await?;
 
 		Ok(())
@@ -1,13 +1,13 @@
 use crate::search::{
 	self, ChunkSnippet, ElfService, Ordering, Result, ScoreCandidateCtx, ScoreSnippetArgs,
-	ScoredChunk, ranking,
+	ScoredChunk, SearchWarning, ranking,
 };
 
 impl ElfService {
 	pub(in crate::search) async fn score_snippet_items(
 		&self,
 		args: ScoreSnippetArgs<'_, '_>,
-	) -> Result<Vec<ScoredChunk>> {
+	) -> Result<(Vec<ScoredChunk>, Vec<SearchWarning>)> {
 		let ScoreSnippetArgs {
 			query,
 			snippet_items,
@@ -18,20 +18,24 @@ impl ElfService {
 			now,
 			candidate_count,
 			skip_rerank,
+			session_mode,
 		} = args;
 
 		if snippet_items.is_empty() {
-			return Ok(Vec::new());
+			return Ok((Vec::new(), Vec::new()));
 		}
 
-		let scores = if skip_rerank {
-			Self::build_quick_find_rerank_scores(&snippet_items)
+		let (scores, warnings) = if skip_rerank {
+			(Self::build_quick_find_rerank_scores(&snippet_items), Vec::new())
 		} else {
 			self.rerank_snippet_items(query, snippet_items.as_slice(), cache_cfg, now).await?
 		};
 		let rerank_ranks = ranking::build_rerank_ranks(&snippet_items, &scores);
 		let total_rerank = u32::try_from(scores.len()).unwrap_or(1).max(1);
 		let total_retrieval = u32::try_from(candidate_count).unwrap_or(1).max(1);
+		let best_evidence_coverage = ranking::best_evidence_coverage(
+			snippet_items.iter().map(|item| item.note.evidence_coverage),
+		);
 		let score_ctx = ScoreCandidateCtx {
 			cfg: &self.cfg,
 			blend_policy,
@@ -40,6 +44,8 @@ impl ElfService {
 			now,
 			total_rerank,
 			total_retrieval,
+			best_evidence_coverage,
+			session_mode,
 		};
 		let mut scored = Vec::with_capacity(snippet_items.len());
 
@@ -49,7 +55,7 @@ impl ElfService {
 			scored.push(search::score_chunk_candidate(&score_ctx, item, rerank_score, rerank_rank));
 		}
 
-		Ok(scored)
+		Ok((scored, warnings))
 	}
 
 	pub(in crate::search) fn build_quick_find_rerank_scores(
@@ -1,21 +1,29 @@
-use crate::search::{
-	self, BuildSearchItemArgs, BuildTraceArgs, Duration, ElfService, OffsetDateTime, Result,
-	ScoredChunk, SearchItem, SearchTraceBuilder, SearchTrajectoryStage, SearchTrajectoryStageItem,
-	SearchTrajectorySummary, TraceCandidateRecord, TraceContext, TracePayload,
-	TraceTrajectoryStageItemRecord, Uuid, ranking,
+use crate::{
+	ranking_explain_v2::{self, RankSensitivityInput, SearchRankingSensitivity},
+	search::{
+		self, BuildSearchItemArgs, BuildTraceArgs, Duration, ElfService, OffsetDateTime,
+		PayloadLevel, Result, ResultPacker, ScoredChunk, SearchItem, SearchTraceBuilder,
+		SearchTrajectoryStage, SearchTrajectoryStageItem, SearchTrajectorySummary, SearchWarning,
+		TraceCandidateRecord, TraceContext, TracePayload, TraceTrajectoryStageItemRecord, Uuid,
+		Value, ranking, search_hooks,
+	},
 };
+use elf_chunking::{SnippetLimit, Tokenizer};
+use elf_config::Config;
 
 impl ElfService {
+	#[tracing::instrument(name = "search.trace_write", skip_all)]
 	pub(in crate::search) async fn build_items_and_write_trace(
 		&self,
 		args: BuildTraceArgs<'_>,
-	) -> Result<(Vec<SearchItem>, SearchTrajectorySummary)> {
+	) -> Result<(Vec<SearchItem>, SearchTrajectorySummary, Option<SearchWarning>)> {
 		let trace_id = args.trace_id;
-		let (items, trajectory_summary, trace_payload) = self.build_items_and_trace_payload(args);
+		let (items, trajectory_summary, trace_payload, packing_warning) =
+			self.build_items_and_trace_payload(args);
 
 		self.write_trace_payload(trace_id, trace_payload).await?;
 
-		Ok((items, trajectory_summary))
+		Ok((items, trajectory_summary, packing_warning))
 	}
 
 	pub(in crate::search) fn build_trace_candidates(
@@ -41,7 +49,7 @@ impl ElfService {
 	pub(in crate::search) fn build_items_and_trace_payload(
 		&self,
 		args: BuildTraceArgs<'_>,
-	) -> (Vec<SearchItem>, SearchTrajectorySummary, TracePayload) {
+	) -> (Vec<SearchItem>, SearchTrajectorySummary, TracePayload, Option<SearchWarning>) {
 		let mut trajectory_stages = search::build_trace_trajectory_stages(&args);
 		let trace_context = TraceContext {
 			trace_id: args.trace_id,
@@ -56,24 +64,13 @@ impl ElfService {
 			candidate_count: args.candidate_count,
 			top_k: args.top_k,
 		};
-		let mut config_snapshot = ranking::build_config_snapshot(
-			&self.cfg,
-			&args.policies.blend_policy,
-			&args.policies.diversity_policy,
-			&args.policies.retrieval_sources_policy,
-			args.ranking_override.as_ref(),
-			args.policies.policy_id.as_str(),
-			&args.policies.policy_snapshot,
-		);
-
-		if let Some(object) = config_snapshot.as_object_mut() {
-			object.insert(
-				"audit".to_string(),
-				search::build_trace_audit(args.agent_id, args.token_id),
-			);
-		}
-
+		let config_snapshot = self.trace_config_snapshot(&args);
 		let mut items = Vec::with_capacity(args.selected_results.len());
+		let (snippet_limit, tokenizer) = self.snippet_caps(args.payload_level);
+		let highlight_terms = search::highlight_terms(args.query_tokens, &args.expanded_queries);
+		let mut packer = args.max_result_tokens.and_then(|max_result_tokens| {
+			self.tokenizer().ok().map(|tokenizer| ResultPacker::new(tokenizer, max_result_tokens))
+		});
 		let mut trace_builder = SearchTraceBuilder::new(
 			trace_context,
 			config_snapshot,
@@ -85,9 +82,13 @@ impl ElfService {
 		for candidate in args.trace_candidates {
 			trace_builder.push_candidate(candidate);
 		}
-		for (idx, scored_chunk) in args.selected_results.into_iter().enumerate() {
+		let sensitivity = rank_sensitivity(&self.cfg, &args.selected_results);
+
+		for (idx, (scored_chunk, sensitivity)) in
+			args.selected_results.into_iter().zip(sensitivity).enumerate()
+		{
 			let rank = idx as u32 + 1;
-			let (item, trace_item) =
+			let (item, mut trace_item) =
 				search::build_search_item_and_trace_item(BuildSearchItemArgs {
 					cfg: &self.cfg,
 					policy_id: args.policies.policy_id.as_str(),
@@ -99,8 +100,26 @@ impl ElfService {
 					relation_contexts: &args.relation_contexts,
 					scored_chunk,
 					rank,
+					sensitivity,
 				});
-			let item = search::apply_payload_level_to_search_item(item, args.payload_level);
+			let item = search::apply_payload_level_to_search_item(
+				item,
+				args.payload_level,
+				snippet_limit,
+				tokenizer,
+			);
+			// Dropped items stay out of the response, the trace, and the final stage alike.
+			let mut item = match packer.as_mut() {
+				Some(packer) => match packer.pack(item) {
+					Some(item) => item,
+					None => continue,
+				},
+				None => item,
+			};
+
+			trace_item.explain.packing = item.explain.packing;
+			// Spans index the returned snippet, after any capping or packing.
+			item.highlights = ranking::highlight_spans(&highlight_terms, &item.snippet);
 
 			final_stage_items.push(TraceTrajectoryStageItemRecord {
 				id: Uuid::new_v4(),
@@ -147,7 +166,65 @@ impl ElfService {
 			trace_builder.push_stage(stage);
 		}
 
-		(items, trajectory_summary, trace_builder.build())
+		let packing_warning = packer.and_then(|packer| packer.warning());
+
+		(items, trajectory_summary, trace_builder.build(), packing_warning)
+	}
+
+	/// Builds the ranking config snapshot recorded on the trace, with audit, embedding input,
+	/// hook, and degraded-mode details.
+	fn trace_config_snapshot(&self, args: &BuildTraceArgs<'_>) -> Value {
+		let mut config_snapshot = ranking::build_config_snapshot(
+			&self.cfg,
+			&args.policies.blend_policy,
+			&args.policies.diversity_policy,
+			&args.policies.retrieval_sources_policy,
+			args.ranking_override.as_ref(),
+			args.policies.policy_id.as_str(),
+			&args.policies.policy_snapshot,
+		);
+
+		if let Some(object) = config_snapshot.as_object_mut() {
+			object.insert(
+				"audit".to_string(),
+				search::build_trace_audit(args.agent_id, args.token_id),
+			);
+			object.insert(
+				"embedding_input".to_string(),
+				self.embedding_input_snapshot(args.allowed_scopes),
+			);
+
+			if let Some(hooks) = search_hooks::hook_runs_snapshot(args.hook_runs) {
+				object.insert("hooks".to_string(), hooks);
+			}
+			if let Some(degraded_mode) = args.degraded_mode {
+				object.insert("degraded_mode".to_string(), serde_json::json!(degraded_mode));
+			}
+		}
+
+		config_snapshot
+	}
+
+	/// Resolves snippet caps for a payload level, loading the tokenizer only for token caps.
+	///
+	/// A tokenizer that fails to load leaves the character cap in force instead of failing the
+	/// search.
+	fn snippet_caps(
+		&self,
+		payload_level: PayloadLevel,
+	) -> (Option<SnippetLimit>, Option<&Tokenizer>) {
+		let snippet_limit = search::snippet_limit_for(&self.cfg, payload_level);
+		let tokenizer = if snippet_limit.is_some_and(|limit| limit.max_tokens.is_some()) {
+			self.tokenizer()
+				.inspect_err(|err| {
+					tracing::warn!(error = %err, "Snippet token cap skipped; tokenizer unavailable.");
+				})
+				.ok()
+		} else {
+			None
+		};
+
+		(snippet_limit, tokenizer)
 	}
 
 	pub(in crate::search) async fn write_trace_payload(
@@ -155,6 +232,10 @@ impl ElfService {
 		trace_id: Uuid,
 		trace_payload: TracePayload,
 	) -> Result<()> {
+		if !self.subsystems.trace_persistence {
+			return Ok(());
+		}
+
 		match self.cfg.search.explain.write_mode.trim().to_ascii_lowercase().as_str() {
 			"inline" => {
 				let mut tx = self.db.pool.begin().await?;
@@ -176,3 +257,18 @@ impl ElfService {
 		Ok(())
 	}
 }
+
+fn rank_sensitivity(cfg: &Config, selected: &[ScoredChunk]) -> Vec<Vec<SearchRankingSensitivity>> {
+	let inputs = selected
+		.iter()
+		.map(|scored_chunk| RankSensitivityInput {
+			final_score: scored_chunk.final_score,
+			rerank_term: scored_chunk.rerank_term,
+			lexical_bonus: scored_chunk.deterministic_lexical_bonus,
+			importance: scored_chunk.importance,
+			age_days: scored_chunk.age_days,
+		})
+		.collect::<Vec<_>>();
+
+	ranking_explain_v2::build_rank_sensitivity(cfg, &inputs)
+}
@@ -1,25 +1,94 @@
 use crate::{
 	Error,
 	search::{
-		Condition, Filter, MinShould, ORG_PROJECT_ID, PayloadLevel, RawSearchPath, Result,
-		SEARCH_RETRIEVAL_TRAJECTORY_SCHEMA_V1, SearchItem, SearchTrajectoryStage,
-		SearchTrajectorySummary, SearchTrajectorySummaryStage, english_gate,
+		Condition, Filter, MAX_MATCHED_TERMS, MinShould, ORG_PROJECT_ID, PayloadLevel,
+		RawSearchPath, Result, SEARCH_RETRIEVAL_TRAJECTORY_SCHEMA_V1, SearchItem,
+		SearchPayloadFilters, SearchRankingRendered, SearchSnippetTruncation,
+		SearchTrajectoryStage, SearchTrajectorySummary, SearchTrajectorySummaryStage, english_gate,
+		ranking,
 	},
 };
+use elf_chunking::{SnippetLimit, Tokenizer};
+use elf_config::Config;
+
+pub(super) const SNIPPET_TRUNCATION_MARKER: &str = "...";
+
+/// Resolves the configured snippet caps for one payload level, if any.
+pub(super) fn snippet_limit_for(cfg: &Config, payload_level: PayloadLevel) -> Option<SnippetLimit> {
+	let snippet = cfg.search.snippet.as_ref()?;
+	let limit = match payload_level {
+		PayloadLevel::L0 => snippet.l0.as_ref(),
+		PayloadLevel::L1 => snippet.l1.as_ref(),
+		PayloadLevel::L2 => snippet.l2.as_ref(),
+	}?;
+
+	Some(SnippetLimit {
+		max_chars: limit.max_chars.map(|max_chars| max_chars as usize),
+		max_tokens: limit.max_tokens.map(|max_tokens| max_tokens as usize),
+	})
+}
+
+/// Shortens `snippet` to fit `limit` at a sentence or word boundary and appends the marker.
+pub(super) fn cap_snippet(
+	snippet: &str,
+	limit: SnippetLimit,
+	tokenizer: Option<&Tokenizer>,
+) -> (String, Option<SearchSnippetTruncation>) {
+	let marker_chars = SNIPPET_TRUNCATION_MARKER.chars().count();
+	let Some(cut) = elf_chunking::truncate_snippet(snippet, limit, marker_chars, tokenizer) else {
+		return (snippet.to_string(), None);
+	};
+	let truncation = SearchSnippetTruncation {
+		kept_end: u32::try_from(cut.end).unwrap_or(u32::MAX),
+		full_end: u32::try_from(snippet.len()).unwrap_or(u32::MAX),
+		sentence_boundary: cut.sentence_boundary,
+	};
+
+	(format!("{}{SNIPPET_TRUNCATION_MARKER}", &snippet[..cut.end]), Some(truncation))
+}
 
 pub(super) fn apply_payload_level_to_search_item(
 	mut item: SearchItem,
 	payload_level: PayloadLevel,
+	snippet_limit: Option<SnippetLimit>,
+	tokenizer: Option<&Tokenizer>,
 ) -> SearchItem {
+	if let Some(limit) = snippet_limit {
+		let (snippet, truncation) = cap_snippet(&item.snippet, limit, tokenizer);
+
+		item.snippet = snippet;
+		item.snippet_truncation = truncation;
+	}
 	if payload_level == PayloadLevel::L2 {
+		item.explain.rendered = Some(SearchRankingRendered {
+			compact: item.explain.ranking.render_compact(),
+			markdown: item.explain.ranking.render_markdown(),
+		});
+
 		return item;
 	}
 
 	item.source_ref = serde_json::json!({});
+	item.explain.ranking.sensitivity = None;
 
 	item
 }
 
+/// Collects the distinct query and expanded-query terms used to highlight snippets.
+pub(super) fn highlight_terms(query_tokens: &[String], expanded_queries: &[String]) -> Vec<String> {
+	let mut terms = query_tokens.to_vec();
+
+	for expanded in expanded_queries {
+		for token in ranking::tokenize_query(expanded, MAX_MATCHED_TERMS) {
+			if !terms.contains(&token) {
+				terms.push(token);
+			}
+		}
+	}
+
+	terms
+}
+
 pub(super) fn validate_search_request_inputs(
 	tenant_id: &str,
 	project_id: &str,
@@ -81,6 +150,7 @@ pub(super) fn build_search_filter(
 	project_id: &str,
 	agent_id: &str,
 	allowed_scopes: &[String],
+	payload_filters: &SearchPayloadFilters,
 ) -> Filter {
 	let private_scope = "agent_private".to_string();
 	let non_private_scopes: Vec<String> =
@@ -120,11 +190,15 @@ pub(super) fn build_search_filter(
 		project_or_org_branches.push(Condition::from(org_filter));
 	}
 
+	let mut must = vec![
+		Condition::matches("tenant_id", tenant_id.to_string()),
+		Condition::matches("status", "active".to_string()),
+	];
+
+	must.extend(payload_filters.conditions());
+
 	Filter {
-		must: vec![
-			Condition::matches("tenant_id", tenant_id.to_string()),
-			Condition::matches("status", "active".to_string()),
-		],
+		must,
 		should: Vec::new(),
 		must_not: Vec::new(),
 		min_should: Some(MinShould { min_count: 1, conditions: project_or_org_branches }),
@@ -2,8 +2,9 @@ use crate::{
 	ranking_explain_v2,
 	search::{
 		BuildSearchItemArgs, MAX_MATCHED_TERMS, OffsetDateTime, SEARCH_RANKING_EXPLAIN_SCHEMA_V2,
-		ScoredChunk, SearchExplain, SearchItem, SearchMatchExplain, SearchRankingExplain,
-		TraceCandidateRecord, TraceItemRecord, TraceReplayCandidate, TraceTermsArgs, Uuid, ranking,
+		ScoredChunk, SearchEmbeddingProjectionExplain, SearchExplain, SearchItem,
+		SearchMatchExplain, SearchRankingExplain, SearchRankingTerm, TraceCandidateRecord,
+		TraceItemRecord, TraceReplayCandidate, TraceTermsArgs, Uuid, ranking,
 	},
 };
 
@@ -33,6 +34,11 @@ pub(super) fn build_trace_candidate_record(
 			note_updated_at: note.updated_at,
 			note_hit_count: note.hit_count,
 			note_last_hit_at: note.last_hit_at,
+			note_evidence_coverage: note.evidence_coverage,
+			note_session_adjustment: (scored_chunk.deterministic_session_adjustment != 0.0)
+				.then_some(scored_chunk.deterministic_session_adjustment),
+			note_feedback_adjustment: (scored_chunk.deterministic_feedback_adjustment != 0.0)
+				.then_some(scored_chunk.deterministic_feedback_adjustment),
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -41,6 +47,7 @@ pub(super) fn build_trace_candidate_record(
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		})
 		.unwrap_or_else(|_| serde_json::json!({})),
 		retrieval_rank: scored_chunk.item.retrieval_rank,
@@ -68,31 +75,7 @@ pub(super) fn build_search_item_and_trace_item(
 		matched_fields,
 		args.structured_matches.get(&args.scored_chunk.item.note.note_id),
 	);
-	let trace_terms = ranking_explain_v2::build_trace_terms_v2(TraceTermsArgs {
-		cfg: args.cfg,
-		blend_enabled: args.blend_policy.enabled,
-		retrieval_normalization: args.blend_policy.retrieval_normalization.as_str(),
-		rerank_normalization: args.blend_policy.rerank_normalization.as_str(),
-		blend_retrieval_weight: args.scored_chunk.blend_retrieval_weight,
-		retrieval_rank: args.scored_chunk.item.retrieval_rank,
-		retrieval_norm: args.scored_chunk.retrieval_norm,
-		retrieval_term: args.scored_chunk.retrieval_term,
-		rerank_score: args.scored_chunk.rerank_score,
-		rerank_rank: args.scored_chunk.rerank_rank,
-		rerank_norm: args.scored_chunk.rerank_norm,
-		rerank_term: args.scored_chunk.rerank_term,
-		tie_breaker_score: args.scored_chunk.tie_breaker_score,
-		importance: args.scored_chunk.importance,
-		age_days: args.scored_chunk.age_days,
-		scope: args.scored_chunk.item.note.scope.as_str(),
-		scope_context_boost: args.scored_chunk.scope_context_boost,
-		deterministic_lexical_overlap_ratio: args.scored_chunk.deterministic_lexical_overlap_ratio,
-		deterministic_lexical_bonus: args.scored_chunk.deterministic_lexical_bonus,
-		deterministic_hit_count: args.scored_chunk.deterministic_hit_count,
-		deterministic_last_hit_age_days: args.scored_chunk.deterministic_last_hit_age_days,
-		deterministic_hit_boost: args.scored_chunk.deterministic_hit_boost,
-		deterministic_decay_penalty: args.scored_chunk.deterministic_decay_penalty,
-	});
+	let trace_terms = build_trace_terms(&args);
 	let response_terms = ranking_explain_v2::strip_term_inputs(&trace_terms);
 	let relation_context =
 		args.relation_contexts.get(&args.scored_chunk.item.note.note_id).cloned();
@@ -103,6 +86,12 @@ pub(super) fn build_search_item_and_trace_item(
 	} else {
 		None
 	};
+	let embedding_projection = args.scored_chunk.item.embedding_projection.as_ref().map(|mode| {
+		SearchEmbeddingProjectionExplain {
+			mode: mode.clone(),
+			source_embedding_version: args.scored_chunk.item.note.embedding_version.clone(),
+		}
+	});
 	let response_explain = SearchExplain {
 		r#match: SearchMatchExplain {
 			matched_terms: matched_terms.clone(),
@@ -113,9 +102,13 @@ pub(super) fn build_search_item_and_trace_item(
 			policy_id: args.policy_id.to_string(),
 			final_score: args.scored_chunk.final_score,
 			terms: response_terms,
+			sensitivity: Some(args.sensitivity.clone()),
 		},
 		relation_context: relation_context.clone(),
 		diversity: diversity.clone(),
+		embedding_projection: embedding_projection.clone(),
+		rendered: None,
+		packing: None,
 	};
 	let trace_explain = SearchExplain {
 		r#match: SearchMatchExplain { matched_terms, matched_fields },
@@ -124,9 +117,13 @@ pub(super) fn build_search_item_and_trace_item(
 			policy_id: args.policy_id.to_string(),
 			final_score: args.scored_chunk.final_score,
 			terms: trace_terms,
+			sensitivity: Some(args.sensitivity),
 		},
 		relation_context,
 		diversity,
+		embedding_projection,
+		rendered: None,
+		packing: None,
 	};
 	let result_handle = Uuid::new_v4();
 	let note = &args.scored_chunk.item.note;
@@ -139,6 +136,8 @@ pub(super) fn build_search_item_and_trace_item(
 		start_offset: chunk.start_offset,
 		end_offset: chunk.end_offset,
 		snippet: args.scored_chunk.item.snippet.clone(),
+		snippet_truncation: None,
+		highlights: Vec::new(),
 		r#type: note.note_type.clone(),
 		key: note.key.clone(),
 		scope: note.scope.clone(),
@@ -161,3 +160,41 @@ pub(super) fn build_search_item_and_trace_item(
 
 	(item, trace_item)
 }
+
+fn build_trace_terms(args: &BuildSearchItemArgs<'_>) -> Vec<SearchRankingTerm> {
+	ranking_explain_v2::build_trace_terms_v2(TraceTermsArgs {
+		cfg: args.cfg,
+		blend_enabled: args.blend_policy.enabled,
+		retrieval_normalization: args.blend_policy.retrieval_normalization.as_str(),
+		rerank_normalization: args.blend_policy.rerank_normalization.as_str(),
+		blend_retrieval_weight: args.scored_chunk.blend_retrieval_weight,
+		retrieval_rank: args.scored_chunk.item.retrieval_rank,
+		retrieval_norm: args.scored_chunk.retrieval_norm,
+		retrieval_term: args.scored_chunk.retrieval_term,
+		rerank_score: args.scored_chunk.rerank_score,
+		rerank_rank: args.scored_chunk.rerank_rank,
+		rerank_norm: args.scored_chunk.rerank_norm,
+		rerank_term: args.scored_chunk.rerank_term,
+		tie_breaker_score: args.scored_chunk.tie_breaker_score,
+		importance: args.scored_chunk.importance,
+		age_days: args.scored_chunk.age_days,
+		scope: args.scored_chunk.item.note.scope.as_str(),
+		scope_context_boost: args.scored_chunk.scope_context_boost,
+		deterministic_lexical_overlap_ratio: args.scored_chunk.deterministic_lexical_overlap_ratio,
+		deterministic_lexical_bonus: args.scored_chunk.deterministic_lexical_bonus,
+		deterministic_hit_count: args.scored_chunk.deterministic_hit_count,
+		deterministic_last_hit_age_days: args.scored_chunk.deterministic_last_hit_age_days,
+		deterministic_hit_boost: args.scored_chunk.deterministic_hit_boost,
+		deterministic_decay_penalty: args.scored_chunk.deterministic_decay_penalty,
+		deterministic_evidence_coverage: args.scored_chunk.deterministic_evidence_coverage,
+		deterministic_evidence_penalty: args.scored_chunk.deterministic_evidence_penalty,
+		deterministic_session_mode: Some(args.scored_chunk.deterministic_session_mode.as_str()),
+		deterministic_session_hit_count: args.scored_chunk.deterministic_session_hit_count,
+		deterministic_session_adjustment: args.scored_chunk.deterministic_session_adjustment,
+		deterministic_feedback_useful: args.scored_chunk.deterministic_feedback.useful,
+		deterministic_feedback_not_useful: args.scored_chunk.deterministic_feedback.not_useful,
+		deterministic_feedback_wrong: args.scored_chunk.deterministic_feedback.wrong,
+		deterministic_feedback_adjustment: args.scored_chunk.deterministic_feedback_adjustment,
+		pin_boost: args.scored_chunk.item.note.pinned.then_some(args.scored_chunk.pin_boost),
+	})
+}
@@ -0,0 +1,143 @@
+use crate::{
+	Error,
+	search::{
+		Result, SNIPPET_TRUNCATION_MARKER, SearchItem, SearchPackingExplain,
+		SearchSnippetTruncation, SearchWarning,
+	},
+};
+use elf_chunking::{SnippetLimit, Tokenizer};
+
+const MAX_RESULT_TOKENS: u32 = 65_536;
+
+pub(super) fn validate_max_result_tokens(max_result_tokens: Option<u32>) -> Result<Option<u32>> {
+	match max_result_tokens {
+		Some(value) if value == 0 || value > MAX_RESULT_TOKENS => Err(Error::InvalidRequest {
+			message: format!("$.max_result_tokens must be between 1 and {MAX_RESULT_TOKENS}."),
+		}),
+		value => Ok(value),
+	}
+}
+
+/// Greedily fits ranked snippets into a token budget.
+///
+/// Items are offered in rank order. A snippet that fits is kept whole; the first one that does
+/// not is trimmed to the remaining budget at a sentence or word boundary, and items are dropped
+/// once too little budget remains for a trimmed snippet. Later, shorter snippets can still use
+/// leftover budget, so returned items keep their relative ranking.
+pub(super) struct ResultPacker<'a> {
+	tokenizer: &'a Tokenizer,
+	max_result_tokens: u32,
+	remaining: usize,
+	marker_tokens: usize,
+	truncated_count: u32,
+	dropped_count: u32,
+}
+impl<'a> ResultPacker<'a> {
+	pub(super) fn new(tokenizer: &'a Tokenizer, max_result_tokens: u32) -> Self {
+		let marker_tokens =
+			elf_chunking::count_tokens(SNIPPET_TRUNCATION_MARKER, tokenizer).unwrap_or(1);
+
+		Self {
+			tokenizer,
+			max_result_tokens,
+			remaining: max_result_tokens as usize,
+			marker_tokens,
+			truncated_count: 0,
+			dropped_count: 0,
+		}
+	}
+
+	/// Returns the item with its snippet fitted to the budget, or `None` when it was dropped.
+	pub(super) fn pack(&mut self, mut item: SearchItem) -> Option<SearchItem> {
+		let Some(full_tokens) = elf_chunking::count_tokens(&item.snippet, self.tokenizer) else {
+			self.dropped_count += 1;
+
+			return None;
+		};
+
+		if full_tokens <= self.remaining {
+			self.remaining -= full_tokens;
+			item.explain.packing = Some(packing_explain(full_tokens, full_tokens, false));
+
+			return Some(item);
+		}
+
+		match self.trim(&item.snippet, item.snippet_truncation) {
+			Some((snippet, truncation, tokens)) => {
+				self.remaining -= tokens;
+				self.truncated_count += 1;
+				item.snippet = snippet;
+				item.snippet_truncation = Some(truncation);
+				item.explain.packing = Some(packing_explain(tokens, full_tokens, true));
+
+				Some(item)
+			},
+			None => {
+				self.dropped_count += 1;
+
+				None
+			},
+		}
+	}
+
+	/// Reports what packing changed, or `None` when every offered item fit whole.
+	pub(super) fn warning(&self) -> Option<SearchWarning> {
+		if self.truncated_count == 0 && self.dropped_count == 0 {
+			return None;
+		}
+
+		Some(SearchWarning::ResultTokenBudget {
+			max_result_tokens: self.max_result_tokens,
+			packed_tokens: self.max_result_tokens - self.remaining as u32,
+			truncated_count: self.truncated_count,
+			dropped_count: self.dropped_count,
+		})
+	}
+
+	fn trim(
+		&self,
+		snippet: &str,
+		prior: Option<SearchSnippetTruncation>,
+	) -> Option<(String, SearchSnippetTruncation, usize)> {
+		if self.remaining <= self.marker_tokens {
+			return None;
+		}
+
+		// Cut the text the payload-level cap kept, not its marker, so offsets stay comparable.
+		let text = prior.map_or(snippet, |prior| &snippet[..prior.kept_end as usize]);
+		let limit =
+			SnippetLimit { max_chars: None, max_tokens: Some(self.remaining - self.marker_tokens) };
+		let (end, sentence_boundary) =
+			elf_chunking::truncate_snippet(text, limit, 0, Some(self.tokenizer))
+				.map_or((text.len(), false), |cut| (cut.end, cut.sentence_boundary));
+
+		if end == 0 {
+			return None;
+		}
+
+		let trimmed = format!("{}{SNIPPET_TRUNCATION_MARKER}", &text[..end]);
+		let tokens = elf_chunking::count_tokens(&trimmed, self.tokenizer)?;
+
+		if tokens > self.remaining {
+			return None;
+		}
+
+		let truncation = SearchSnippetTruncation {
+			kept_end: u32::try_from(end).unwrap_or(u32::MAX),
+			full_end: prior
+				.map(|prior| prior.full_end)
+				.unwrap_or_else(|| u32::try_from(text.len()).unwrap_or(u32::MAX)),
+			sentence_boundary,
+		};
+
+		Some((trimmed, truncation, tokens))
+	}
+}
+
+fn packing_explain(tokens: usize, full_tokens: usize, truncated: bool) -> SearchPackingExplain {
+	SearchPackingExplain {
+		tokens: u32::try_from(tokens).unwrap_or(u32::MAX),
+		full_tokens: u32::try_from(full_tokens).unwrap_or(u32::MAX),
+		truncated,
+	}
+}
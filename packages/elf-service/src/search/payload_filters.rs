@@ -0,0 +1,121 @@
+use qdrant_client::qdrant::{DatetimeRange, Range, Timestamp};
+use time::format_description::well_known::Rfc3339;
+
+use crate::{
+	Error,
+	search::{Condition, NoteMeta, OffsetDateTime, Result, SearchRequest},
+};
+
+const MAX_SEARCH_TYPES: usize = 16;
+
+/// Typed note filters that are pushed down into the Qdrant payload filter.
+///
+/// Candidates from sources that bypass Qdrant (structured fields, pinned notes) are checked
+/// against the same bounds once authoritative note metadata is loaded.
+#[derive(Clone, Debug, Default)]
+pub(super) struct SearchPayloadFilters {
+	pub(super) types: Vec<String>,
+	pub(super) updated_after: Option<OffsetDateTime>,
+	pub(super) updated_before: Option<OffsetDateTime>,
+	pub(super) min_importance: Option<f32>,
+}
+impl SearchPayloadFilters {
+	pub(super) fn parse(req: &SearchRequest) -> Result<Self> {
+		if req.types.len() > MAX_SEARCH_TYPES {
+			return Err(Error::InvalidRequest {
+				message: format!("$.types must contain at most {MAX_SEARCH_TYPES} items."),
+			});
+		}
+
+		let mut types = Vec::with_capacity(req.types.len());
+
+		for (index, note_type) in req.types.iter().enumerate() {
+			let note_type = note_type.trim();
+
+			if note_type.is_empty() {
+				return Err(Error::InvalidRequest {
+					message: format!("$.types[{index}] must be non-empty."),
+				});
+			}
+
+			types.push(note_type.to_string());
+		}
+
+		types.sort();
+		types.dedup();
+
+		let updated_after =
+			parse_optional_rfc3339(req.updated_after.as_deref(), "$.updated_after")?;
+		let updated_before =
+			parse_optional_rfc3339(req.updated_before.as_deref(), "$.updated_before")?;
+
+		if let (Some(updated_after), Some(updated_before)) = (updated_after, updated_before)
+			&& updated_after >= updated_before
+		{
+			return Err(Error::InvalidRequest {
+				message: "updated_after must be earlier than updated_before.".to_string(),
+			});
+		}
+		if let Some(min_importance) = req.min_importance
+			&& !(0.0..=1.0).contains(&min_importance)
+		{
+			return Err(Error::InvalidRequest {
+				message: "$.min_importance must be between 0.0 and 1.0.".to_string(),
+			});
+		}
+
+		Ok(Self { types, updated_after, updated_before, min_importance: req.min_importance })
+	}
+
+	pub(super) fn conditions(&self) -> Vec<Condition> {
+		let mut conditions = Vec::new();
+
+		if !self.types.is_empty() {
+			conditions.push(Condition::matches("type", self.types.clone()));
+		}
+		if self.updated_after.is_some() || self.updated_before.is_some() {
+			conditions.push(Condition::datetime_range(
+				"updated_at",
+				DatetimeRange {
+					gt: self.updated_after.map(timestamp),
+					lt: self.updated_before.map(timestamp),
+					gte: None,
+					lte: None,
+				},
+			));
+		}
+		if let Some(min_importance) = self.min_importance {
+			conditions.push(Condition::range(
+				"importance",
+				Range { gte: Some(f64::from(min_importance)), ..Default::default() },
+			));
+		}
+
+		conditions
+	}
+
+	pub(super) fn matches(&self, note: &NoteMeta) -> bool {
+		(self.types.is_empty() || self.types.contains(&note.note_type))
+			&& self.updated_after.is_none_or(|updated_after| note.updated_at > updated_after)
+			&& self.updated_before.is_none_or(|updated_before| note.updated_at < updated_before)
+			&& self.min_importance.is_none_or(|min_importance| note.importance >= min_importance)
+	}
+}
+
+fn parse_optional_rfc3339(raw: Option<&str>, path: &str) -> Result<Option<OffsetDateTime>> {
+	let Some(raw) = raw.map(str::trim) else {
+		return Ok(None);
+	};
+
+	if raw.is_empty() {
+		return Err(Error::InvalidRequest { message: format!("{path} must be non-empty.") });
+	}
+
+	OffsetDateTime::parse(raw, &Rfc3339).map(Some).map_err(|_| Error::InvalidRequest {
+		message: format!("{path} must be an RFC3339 datetime string."),
+	})
+}
+
+fn timestamp(value: OffsetDateTime) -> Timestamp {
+	Timestamp { seconds: value.unix_timestamp(), nanos: value.nanosecond() as i32 }
+}
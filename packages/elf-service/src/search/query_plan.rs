@@ -159,7 +159,7 @@ impl ElfService {
 			candidate_k,
 			prefilter_max_candidates: self.cfg.search.prefilter.max_candidates,
 			expansion_max_queries: self.cfg.search.expansion.max_queries,
-			cache_enabled: self.cfg.search.cache.enabled,
+			cache_enabled: self.search_cache_enabled(),
 		}
 	}
 
@@ -0,0 +1,299 @@
+use crate::{
+	Error,
+	ranking_explain_v2::{self, SearchRankingExplain},
+	search::{
+		ChunkMeta, ChunkSnippet, Deserialize, ElfService, HashMap, MAX_MATCHED_TERMS,
+		NoteFeedbackCounts, NoteMeta, OffsetDateTime, RankingRequestOverride, Result,
+		SEARCH_RANKING_EXPLAIN_SCHEMA_V2, ScoreSnippetArgs, ScoredChunk, SearchCache,
+		SearchWarning, Serialize, SessionRankingMode, TraceTermsArgs, Uuid, Value, english_gate,
+		ranking, scoring_helpers, structured,
+	},
+};
+
+const MAX_RANK_DOCUMENTS: usize = 256;
+const MAX_RANK_DOCUMENT_CHARS: usize = 16_384;
+
+/// Request payload for ranking a caller-provided candidate set.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RankDocumentsRequest {
+	/// Tenant issuing the request.
+	pub tenant_id: String,
+	/// Project issuing the request.
+	pub project_id: String,
+	/// Agent issuing the request.
+	pub agent_id: String,
+	/// Query the documents are ranked against.
+	pub query: String,
+	/// Candidate documents, in the caller's retrieval order.
+	pub docs: Vec<RankDocument>,
+	/// Maximum number of ranked documents to return; defaults to all.
+	pub top_k: Option<u32>,
+	/// Optional ranking-policy overrides.
+	pub ranking: Option<RankingRequestOverride>,
+}
+
+/// One caller-provided candidate document.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RankDocument {
+	/// Optional caller identifier echoed in the response.
+	pub id: Option<String>,
+	/// Document text passed to the reranker and lexical signals.
+	pub text: String,
+	/// Optional importance in `[0, 1]`; defaults to 0.
+	pub importance: Option<f32>,
+	#[serde(default, with = "crate::time_serde::option")]
+	/// Optional last-updated timestamp used for recency; defaults to the request time.
+	pub updated_at: Option<OffsetDateTime>,
+}
+
+/// Response payload for ranked caller-provided documents.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RankDocumentsResponse {
+	/// Ranking-policy fingerprint used to compute the scores.
+	pub policy_id: String,
+	/// Documents ordered by final score.
+	pub items: Vec<RankedDocument>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	/// Non-fatal ranking warnings, such as a degraded reranker.
+	pub warnings: Vec<SearchWarning>,
+}
+
+/// One ranked caller-provided document.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RankedDocument {
+	/// Zero-based position of the document in the request.
+	pub index: u32,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Caller identifier from the request.
+	pub id: Option<String>,
+	/// One-based rank after scoring.
+	pub rank: u32,
+	/// Final blended score.
+	pub final_score: f32,
+	/// Query terms matched in the document text.
+	pub matched_terms: Vec<String>,
+	/// Ranking-term explanation.
+	pub explain: SearchRankingExplain,
+}
+
+impl ElfService {
+	/// Ranks caller-provided documents with the search rerank and deterministic signal pipeline.
+	///
+	/// No retrieval runs: request order is used as the retrieval rank for blending, and documents
+	/// carry no hit history. Rerank scores are not cached because the documents have no stable
+	/// identity.
+	pub async fn rank_documents(&self, req: RankDocumentsRequest) -> Result<RankDocumentsResponse> {
+		let query = req.query.trim();
+
+		validate_rank_documents_request(&req, query)?;
+
+		let now = OffsetDateTime::now_utc();
+		let policies = self.resolve_finish_search_policies(req.ranking.as_ref())?;
+		let doc_ids: Vec<Option<String>> = req.docs.iter().map(|doc| doc.id.clone()).collect();
+		let snippet_items: Vec<ChunkSnippet> = req
+			.docs
+			.into_iter()
+			.enumerate()
+			.map(|(index, doc)| build_document_snippet(index, doc, now))
+			.collect();
+		let candidate_count = snippet_items.len();
+		let cache_cfg = SearchCache {
+			enabled: false,
+			expansion_ttl_days: self.cfg.search.cache.expansion_ttl_days,
+			rerank_ttl_days: self.cfg.search.cache.rerank_ttl_days,
+			max_payload_bytes: self.cfg.search.cache.max_payload_bytes,
+			results: None,
+		};
+		let query_tokens = ranking::tokenize_query(query, MAX_MATCHED_TERMS);
+		let det_query_tokens = structured::build_deterministic_query_tokens(&self.cfg, query);
+		let (mut scored, warnings) = self
+			.score_snippet_items(ScoreSnippetArgs {
+				query,
+				snippet_items,
+				scope_context_boost_by_scope: &HashMap::new(),
+				det_query_tokens: det_query_tokens.as_slice(),
+				blend_policy: &policies.blend_policy,
+				cache_cfg: &cache_cfg,
+				now,
+				candidate_count,
+				skip_rerank: false,
+				session_mode: SessionRankingMode::Off,
+			})
+			.await?;
+
+		scored.sort_by(scoring_helpers::cmp_scored_chunk);
+
+		let top_k = req.top_k.map_or(candidate_count, |top_k| top_k as usize);
+		let items = scored
+			.iter()
+			.take(top_k)
+			.enumerate()
+			.map(|(rank, scored_chunk)| {
+				let index = scored_chunk.item.retrieval_rank - 1;
+
+				RankedDocument {
+					index,
+					id: doc_ids.get(index as usize).cloned().flatten(),
+					rank: rank as u32 + 1,
+					final_score: scored_chunk.final_score,
+					matched_terms: ranking::match_terms_in_text(
+						&query_tokens,
+						scored_chunk.item.snippet.as_str(),
+						None,
+						MAX_MATCHED_TERMS,
+					)
+					.0,
+					explain: SearchRankingExplain {
+						schema: SEARCH_RANKING_EXPLAIN_SCHEMA_V2.to_string(),
+						policy_id: policies.policy_id.clone(),
+						final_score: scored_chunk.final_score,
+						terms: self.build_document_terms(scored_chunk, &policies.blend_policy),
+						sensitivity: None,
+					},
+				}
+			})
+			.collect();
+
+		Ok(RankDocumentsResponse { policy_id: policies.policy_id, items, warnings })
+	}
+
+	fn build_document_terms(
+		&self,
+		scored_chunk: &ScoredChunk,
+		blend_policy: &ranking::ResolvedBlendPolicy,
+	) -> Vec<ranking_explain_v2::SearchRankingTerm> {
+		let terms = ranking_explain_v2::build_trace_terms_v2(TraceTermsArgs {
+			cfg: &self.cfg,
+			blend_enabled: blend_policy.enabled,
+			retrieval_normalization: blend_policy.retrieval_normalization.as_str(),
+			rerank_normalization: blend_policy.rerank_normalization.as_str(),
+			blend_retrieval_weight: scored_chunk.blend_retrieval_weight,
+			retrieval_rank: scored_chunk.item.retrieval_rank,
+			retrieval_norm: scored_chunk.retrieval_norm,
+			retrieval_term: scored_chunk.retrieval_term,
+			rerank_score: scored_chunk.rerank_score,
+			rerank_rank: scored_chunk.rerank_rank,
+			rerank_norm: scored_chunk.rerank_norm,
+			rerank_term: scored_chunk.rerank_term,
+			tie_breaker_score: scored_chunk.tie_breaker_score,
+			importance: scored_chunk.importance,
+			age_days: scored_chunk.age_days,
+			scope: scored_chunk.item.note.scope.as_str(),
+			scope_context_boost: scored_chunk.scope_context_boost,
+			deterministic_lexical_overlap_ratio: scored_chunk.deterministic_lexical_overlap_ratio,
+			deterministic_lexical_bonus: scored_chunk.deterministic_lexical_bonus,
+			deterministic_hit_count: scored_chunk.deterministic_hit_count,
+			deterministic_last_hit_age_days: scored_chunk.deterministic_last_hit_age_days,
+			deterministic_hit_boost: scored_chunk.deterministic_hit_boost,
+			deterministic_decay_penalty: scored_chunk.deterministic_decay_penalty,
+			deterministic_evidence_coverage: scored_chunk.deterministic_evidence_coverage,
+			deterministic_evidence_penalty: scored_chunk.deterministic_evidence_penalty,
+			deterministic_session_mode: Some(scored_chunk.deterministic_session_mode.as_str()),
+			deterministic_session_hit_count: scored_chunk.deterministic_session_hit_count,
+			deterministic_session_adjustment: scored_chunk.deterministic_session_adjustment,
+			deterministic_feedback_useful: scored_chunk.deterministic_feedback.useful,
+			deterministic_feedback_not_useful: scored_chunk.deterministic_feedback.not_useful,
+			deterministic_feedback_wrong: scored_chunk.deterministic_feedback.wrong,
+			deterministic_feedback_adjustment: scored_chunk.deterministic_feedback_adjustment,
+			pin_boost: None,
+		});
+
+		ranking_explain_v2::strip_term_inputs(&terms)
+	}
+}
+
+pub(super) fn validate_rank_documents_request(
+	req: &RankDocumentsRequest,
+	query: &str,
+) -> Result<()> {
+	if req.tenant_id.trim().is_empty()
+		|| req.project_id.trim().is_empty()
+		|| req.agent_id.trim().is_empty()
+	{
+		return Err(Error::InvalidRequest {
+			message: "tenant_id, project_id, and agent_id are required.".to_string(),
+		});
+	}
+	if query.is_empty() {
+		return Err(Error::InvalidRequest { message: "query is required.".to_string() });
+	}
+	if !english_gate::is_english_natural_language(query) {
+		return Err(Error::NonEnglishInput { field: "$.query".to_string() });
+	}
+	if req.docs.is_empty() || req.docs.len() > MAX_RANK_DOCUMENTS {
+		return Err(Error::InvalidRequest {
+			message: format!("docs must contain between 1 and {MAX_RANK_DOCUMENTS} documents."),
+		});
+	}
+	if req.top_k == Some(0) {
+		return Err(Error::InvalidRequest {
+			message: "top_k must be greater than zero.".to_string(),
+		});
+	}
+
+	for (index, doc) in req.docs.iter().enumerate() {
+		if doc.text.trim().is_empty() {
+			return Err(Error::InvalidRequest {
+				message: format!("docs[{index}].text must be non-empty."),
+			});
+		}
+		if doc.text.chars().count() > MAX_RANK_DOCUMENT_CHARS {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"docs[{index}].text must be at most {MAX_RANK_DOCUMENT_CHARS} characters."
+				),
+			});
+		}
+		if let Some(importance) = doc.importance
+			&& !(0.0..=1.0).contains(&importance)
+		{
+			return Err(Error::InvalidRequest {
+				message: format!("docs[{index}].importance must be between 0 and 1."),
+			});
+		}
+	}
+
+	Ok(())
+}
+
+/// Wraps one caller document as a single-chunk snippet so it can share the search scorer.
+///
+/// The request position becomes the 1-based retrieval rank, and the synthetic note carries no
+/// scope, hit history, or persisted identity.
+pub(super) fn build_document_snippet(
+	index: usize,
+	doc: RankDocument,
+	now: OffsetDateTime,
+) -> ChunkSnippet {
+	let retrieval_rank = u32::try_from(index).unwrap_or(u32::MAX - 1) + 1;
+	let end_offset = i32::try_from(doc.text.len()).unwrap_or(i32::MAX);
+
+	ChunkSnippet {
+		note: NoteMeta {
+			note_id: Uuid::new_v4(),
+			note_type: String::new(),
+			key: None,
+			scope: String::new(),
+			agent_id: String::new(),
+			importance: doc.importance.unwrap_or(0.0),
+			confidence: 1.0,
+			updated_at: doc.updated_at.unwrap_or(now),
+			expires_at: None,
+			source_ref: Value::Null,
+			embedding_version: String::new(),
+			hit_count: 0,
+			last_hit_at: None,
+			evidence_coverage: None,
+			session_hit_count: 0,
+			feedback: NoteFeedbackCounts::default(),
+			values: Vec::new(),
+			fields: Vec::new(),
+			pinned: false,
+		},
+		chunk: ChunkMeta { chunk_id: Uuid::new_v4(), chunk_index: 0, start_offset: 0, end_offset },
+		snippet: doc.text,
+		retrieval_rank,
+		retrieval_score: None,
+		embedding_projection: None,
+	}
+}
@@ -1,5 +1,6 @@
 mod cache;
 mod diversity;
+mod pinning;
 mod policy;
 mod query;
 mod retrieval;
@@ -7,14 +8,16 @@ mod text;
 
 pub(super) use self::{
 	cache::{
-		build_cached_scores, build_expansion_cache_key, build_rerank_cache_key, cache_key_prefix,
-		decode_json, hash_query,
+		build_cached_scores, build_expansion_cache_key, build_rerank_cache_key,
+		build_search_result_cache_key, cache_key_prefix, decode_json, hash_query,
+		normalize_cache_query,
 	},
 	diversity::{
 		attach_diversity_decisions_to_trace_candidates, build_diversity_explain,
 		build_rerank_ranks, build_rerank_ranks_for_replay, extract_replay_diversity_decisions,
 		select_diverse_results,
 	},
+	pinning::include_pinned_results,
 	policy::{
 		NormalizationKind, ResolvedBlendPolicy, ResolvedDiversityPolicy,
 		ResolvedRetrievalSourcesPolicy, build_config_snapshot, build_policy_snapshot,
@@ -30,9 +33,10 @@ pub(super) use self::{
 		merge_retrieval_candidates, rank_normalize, stitch_snippet,
 	},
 	text::{
-		build_dense_embedding_input, build_scope_context_boost_by_scope,
-		compute_deterministic_ranking_terms, match_terms_in_text, merge_matched_fields,
-		tokenize_query,
+		EmbeddingInputStrategy, best_evidence_coverage, build_dense_embedding_input,
+		build_scope_context_boost_by_scope, compute_deterministic_ranking_terms,
+		compute_evidence_penalty, compute_feedback_adjustment, compute_session_adjustment,
+		highlight_spans, match_terms_in_text, merge_matched_fields, tokenize_query,
 	},
 };
 #[cfg(test)] pub(super) use self::{policy::types::BlendSegment, text::lexical_overlap_ratio};
@@ -15,6 +15,7 @@ use crate::{
 
 const EXPANSION_CACHE_SCHEMA_VERSION: i32 = 1;
 const RERANK_CACHE_SCHEMA_VERSION: i32 = 1;
+const SEARCH_RESULT_CACHE_SCHEMA_VERSION: i32 = 1;
 
 pub fn decode_json<T>(value: Value, label: &str) -> Result<T>
 where
@@ -95,6 +96,28 @@ pub fn build_rerank_cache_key(
 	hash_cache_key(&payload)
 }
 
+/// Collapses runs of whitespace so that trivially different spellings of a query share one
+/// result-cache entry.
+pub fn normalize_cache_query(query: &str) -> String {
+	query.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn build_search_result_cache_key(
+	request: &Value,
+	policy_id: &str,
+	watermark: &Value,
+) -> Result<String> {
+	let payload = serde_json::json!({
+		"kind": "search_result",
+		"schema_version": SEARCH_RESULT_CACHE_SCHEMA_VERSION,
+		"request": request,
+		"policy_id": policy_id,
+		"watermark": watermark,
+	});
+
+	hash_cache_key(&payload)
+}
+
 pub fn build_cached_scores(
 	payload: &RerankCachePayload,
 	candidates: &[RerankCacheCandidate],
@@ -36,6 +36,7 @@ pub(super) fn select_diverse_results_disabled(
 				nearest_selected_note_id: None,
 				similarity: None,
 				mmr_score: None,
+				balance_penalty: None,
 				missing_embedding,
 			},
 		);
@@ -6,7 +6,7 @@ use crate::search::{
 	DiversityDecision, ScoredChunk,
 	ranking::{
 		diversity::{selection::pick::DiversityPick, similarity},
-		policy::ResolvedDiversityPolicy,
+		policy::{ResolvedDiversityPolicy, ScopeBalanceKey},
 		retrieval,
 	},
 };
@@ -38,6 +38,7 @@ pub(super) fn select_diverse_results_enabled(
 			nearest_selected_note_id: None,
 			similarity: None,
 			mmr_score: Some(relevance_by_idx[first_idx]),
+			balance_penalty: policy.scope_balance.map(|_| 0.0),
 			missing_embedding: first_missing_embedding,
 		},
 	);
@@ -69,6 +70,7 @@ pub(super) fn select_diverse_results_enabled(
 				nearest_selected_note_id: selected_pick.nearest_note_id,
 				similarity: selected_pick.similarity,
 				mmr_score: Some(selected_pick.mmr_score),
+				balance_penalty: selected_pick.balance_penalty,
 				missing_embedding: selected_pick.missing_embedding,
 			},
 		);
@@ -90,8 +92,11 @@ pub(super) fn select_diverse_results_enabled(
 				"lower_mmr"
 			};
 		let redundancy = similarity.unwrap_or(0.0);
+		let balance_penalty =
+			scope_balance_penalty(candidate_idx, &candidates, &selected_indices, policy);
 		let mmr_score = policy.mmr_lambda * relevance_by_idx[candidate_idx]
-			- (1.0 - policy.mmr_lambda) * redundancy;
+			- (1.0 - policy.mmr_lambda) * redundancy
+			- balance_penalty.unwrap_or(0.0);
 
 		decisions.insert(
 			note_id,
@@ -103,6 +108,7 @@ pub(super) fn select_diverse_results_enabled(
 				nearest_selected_note_id: nearest_note_id,
 				similarity,
 				mmr_score: Some(mmr_score),
+				balance_penalty,
 				missing_embedding,
 			},
 		);
@@ -136,8 +142,11 @@ fn pick_next_candidate(
 				note_vectors,
 			);
 		let redundancy = similarity.unwrap_or(0.0);
+		let balance_penalty =
+			scope_balance_penalty(candidate_idx, candidates, selected_indices, policy);
 		let mmr_score = policy.mmr_lambda * relevance_by_idx[candidate_idx]
-			- (1.0 - policy.mmr_lambda) * redundancy;
+			- (1.0 - policy.mmr_lambda) * redundancy
+			- balance_penalty.unwrap_or(0.0);
 		let high_similarity = similarity.map(|value| value > policy.sim_threshold).unwrap_or(false);
 
 		if high_similarity {
@@ -147,6 +156,7 @@ fn pick_next_candidate(
 		let candidate_pick = DiversityPick {
 			remaining_pos,
 			mmr_score,
+			balance_penalty,
 			nearest_note_id,
 			similarity,
 			missing_embedding,
@@ -186,3 +196,32 @@ fn pick_next_candidate(
 
 	best_filtered.map(|best| (best, "threshold_backfill"))
 }
+
+/// Penalizes a candidate by the share of already selected results that have the same scope or
+/// note type, so one over-represented attribute cannot fill the whole result set.
+fn scope_balance_penalty(
+	candidate_idx: usize,
+	candidates: &[ScoredChunk],
+	selected_indices: &[usize],
+	policy: &ResolvedDiversityPolicy,
+) -> Option<f32> {
+	let balance = policy.scope_balance?;
+
+	if selected_indices.is_empty() {
+		return Some(0.0);
+	}
+
+	let attribute = |idx: usize| {
+		let note = &candidates[idx].item.note;
+
+		match balance.by {
+			ScopeBalanceKey::Scope => note.scope.as_str(),
+			ScopeBalanceKey::Type => note.note_type.as_str(),
+		}
+	};
+	let candidate_attribute = attribute(candidate_idx);
+	let shared =
+		selected_indices.iter().filter(|idx| attribute(**idx) == candidate_attribute).count();
+
+	Some(balance.weight * shared as f32 / selected_indices.len() as f32)
+}
@@ -4,6 +4,7 @@ use uuid::Uuid;
 pub(super) struct DiversityPick {
 	pub(super) remaining_pos: usize,
 	pub(super) mmr_score: f32,
+	pub(super) balance_penalty: Option<f32>,
 	pub(super) nearest_note_id: Option<Uuid>,
 	pub(super) similarity: Option<f32>,
 	pub(super) missing_embedding: bool,
@@ -14,6 +14,7 @@ pub fn build_diversity_explain(decision: &DiversityDecision) -> SearchDiversityE
 		nearest_selected_note_id: decision.nearest_selected_note_id,
 		similarity: decision.similarity,
 		mmr_score: decision.mmr_score,
+		balance_penalty: decision.balance_penalty,
 		missing_embedding: decision.missing_embedding,
 	}
 }
@@ -46,6 +47,10 @@ pub fn attach_diversity_decisions_to_trace_candidates(
 		);
 		object.insert("diversity_similarity".to_string(), serde_json::json!(decision.similarity));
 		object.insert("diversity_mmr_score".to_string(), serde_json::json!(decision.mmr_score));
+		object.insert(
+			"diversity_balance_penalty".to_string(),
+			serde_json::json!(decision.balance_penalty),
+		);
 		object.insert(
 			"diversity_missing_embedding".to_string(),
 			serde_json::json!(decision.missing_embedding),
@@ -86,6 +91,7 @@ pub fn extract_replay_diversity_decisions(
 			nearest_selected_note_id: candidate.diversity_nearest_selected_note_id,
 			similarity: candidate.diversity_similarity,
 			mmr_score: candidate.diversity_mmr_score,
+			balance_penalty: candidate.diversity_balance_penalty,
 			missing_embedding: candidate.diversity_missing_embedding.unwrap_or(false),
 		};
 		let replace = match out.get(&candidate.note_id) {
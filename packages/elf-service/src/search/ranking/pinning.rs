@@ -0,0 +1,83 @@
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::search::{DiversityDecision, ScoredChunk};
+
+/// Forces pinned notes from `fused` into `selected` while keeping at most `top_k` results.
+///
+/// Missing pinned notes replace the lowest-positioned unpinned results and are appended in fused
+/// order. Each forced note is lifted to the lowest score of the original selection, and the lift
+/// is recorded as its `pin_boost`.
+pub fn include_pinned_results(
+	mut selected: Vec<ScoredChunk>,
+	fused: &[ScoredChunk],
+	top_k: u32,
+	decisions: &mut HashMap<Uuid, DiversityDecision>,
+) -> Vec<ScoredChunk> {
+	let selected_ids: HashSet<Uuid> =
+		selected.iter().map(|result| result.item.note.note_id).collect();
+	let pinned_selected = selected.iter().filter(|result| result.item.note.pinned).count();
+	let missing: Vec<ScoredChunk> = fused
+		.iter()
+		.filter(|result| {
+			result.item.note.pinned && !selected_ids.contains(&result.item.note.note_id)
+		})
+		.take((top_k as usize).saturating_sub(pinned_selected))
+		.cloned()
+		.collect();
+
+	if missing.is_empty() {
+		return selected;
+	}
+
+	let cutoff = selected.iter().map(|result| result.final_score).reduce(f32::min);
+
+	while selected.len() + missing.len() > top_k as usize {
+		let Some(pos) = selected.iter().rposition(|result| !result.item.note.pinned) else {
+			break;
+		};
+		let displaced = selected.remove(pos);
+
+		if let Some(decision) = decisions.get_mut(&displaced.item.note.note_id) {
+			decision.selected = false;
+			decision.selected_rank = None;
+			decision.skipped_reason = Some("pinned_displacement".to_string());
+		}
+	}
+
+	for mut pinned in missing {
+		if let Some(cutoff) = cutoff {
+			pinned.pin_boost = (cutoff - pinned.final_score).max(0.0);
+			pinned.final_score += pinned.pin_boost;
+		}
+
+		decisions
+			.entry(pinned.item.note.note_id)
+			.and_modify(|decision| {
+				decision.selected = true;
+				decision.selected_reason = "pinned".to_string();
+				decision.skipped_reason = None;
+			})
+			.or_insert_with(|| DiversityDecision {
+				selected: true,
+				selected_rank: None,
+				selected_reason: "pinned".to_string(),
+				skipped_reason: None,
+				nearest_selected_note_id: None,
+				similarity: None,
+				mmr_score: None,
+				balance_penalty: None,
+				missing_embedding: false,
+			});
+		selected.push(pinned);
+	}
+
+	for (idx, result) in selected.iter().enumerate() {
+		if let Some(decision) = decisions.get_mut(&result.item.note.note_id) {
+			decision.selected_rank = Some(idx as u32 + 1);
+		}
+	}
+
+	selected
+}
@@ -11,6 +11,6 @@ pub use self::{
 	snapshot::{build_config_snapshot, build_policy_snapshot, hash_policy_snapshot},
 	types::{
 		NormalizationKind, ResolvedBlendPolicy, ResolvedDiversityPolicy,
-		ResolvedRetrievalSourcesPolicy,
+		ResolvedRetrievalSourcesPolicy, ScopeBalanceKey,
 	},
 };
@@ -4,7 +4,7 @@ use crate::{
 		BlendRankingOverride, DiversityRankingOverride, RetrievalSourcesRankingOverride,
 		ranking::policy::types::{
 			BlendSegment, NormalizationKind, ResolvedBlendPolicy, ResolvedDiversityPolicy,
-			ResolvedRetrievalSourcesPolicy,
+			ResolvedRetrievalSourcesPolicy, ResolvedScopeBalance, ScopeBalanceKey,
 		},
 	},
 };
@@ -80,7 +80,19 @@ pub fn resolve_diversity_policy(
 		});
 	}
 
-	Ok(ResolvedDiversityPolicy { enabled, sim_threshold, mmr_lambda, max_skips })
+	let scope_balance = match cfg.scope_balance.as_ref() {
+		Some(scope_balance) if scope_balance.enabled && scope_balance.weight > 0.0 =>
+			Some(ResolvedScopeBalance {
+				by: parse_scope_balance_key(
+					scope_balance.by.as_str(),
+					"ranking.diversity.scope_balance.by",
+				)?,
+				weight: scope_balance.weight,
+			}),
+		_ => None,
+	};
+
+	Ok(ResolvedDiversityPolicy { enabled, sim_threshold, mmr_lambda, max_skips, scope_balance })
 }
 
 pub fn resolve_retrieval_sources_policy(
@@ -145,6 +157,16 @@ pub fn parse_normalization_kind(value: &str, label: &str) -> Result<Normalizatio
 	}
 }
 
+pub fn parse_scope_balance_key(value: &str, label: &str) -> Result<ScopeBalanceKey> {
+	match value.trim().to_ascii_lowercase().as_str() {
+		"scope" => Ok(ScopeBalanceKey::Scope),
+		"type" => Ok(ScopeBalanceKey::Type),
+		other => Err(Error::InvalidRequest {
+			message: format!("{label} must be one of: scope, type. Got {other}."),
+		}),
+	}
+}
+
 pub fn validate_blend_segments(segments: &[BlendSegment]) -> Result<()> {
 	if segments.is_empty() {
 		return Err(Error::InvalidRequest {
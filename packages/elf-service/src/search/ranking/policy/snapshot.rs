@@ -21,8 +21,7 @@ pub fn build_config_snapshot(
 	policy_snapshot: &Value,
 ) -> Value {
 	let override_json = ranking_override.and_then(|value| serde_json::to_value(value).ok());
-
-	serde_json::json!({
+	let mut snapshot = serde_json::json!({
 		"search": {
 			"expansion": {
 				"mode": cfg.search.expansion.mode.as_str(),
@@ -129,7 +128,11 @@ pub fn build_config_snapshot(
 				.map(|descriptions| descriptions.len())
 				.unwrap_or(0),
 		},
-	})
+	});
+
+	insert_scope_balance(&mut snapshot, diversity_policy);
+
+	snapshot
 }
 
 pub fn build_policy_snapshot(
@@ -140,8 +143,7 @@ pub fn build_policy_snapshot(
 	ranking_override: Option<&RankingRequestOverride>,
 ) -> Value {
 	let override_json = ranking_override.and_then(|value| serde_json::to_value(value).ok());
-
-	serde_json::json!({
+	let mut snapshot = serde_json::json!({
 		"ranking": {
 			"recency_tau_days": cfg.ranking.recency_tau_days,
 			"tie_breaker_weight": cfg.ranking.tie_breaker_weight,
@@ -212,7 +214,11 @@ pub fn build_policy_snapshot(
 				.map(|descriptions| descriptions.len())
 				.unwrap_or(0),
 		},
-	})
+	});
+
+	insert_scope_balance(&mut snapshot, diversity_policy);
+
+	snapshot
 }
 
 pub fn hash_policy_snapshot(payload: &Value) -> Result<String> {
@@ -222,3 +228,19 @@ pub fn hash_policy_snapshot(payload: &Value) -> Result<String> {
 
 	Ok(blake3::hash(&raw).to_hex().to_string())
 }
+
+/// Adds scope balancing only when active, so policy ids without it stay unchanged.
+fn insert_scope_balance(snapshot: &mut Value, diversity_policy: &ResolvedDiversityPolicy) {
+	if let Some(scope_balance) = diversity_policy.scope_balance
+		&& let Some(diversity) =
+			snapshot.pointer_mut("/ranking/diversity").and_then(Value::as_object_mut)
+	{
+		diversity.insert(
+			"scope_balance".to_string(),
+			serde_json::json!({
+				"by": scope_balance.by.as_str(),
+				"weight": scope_balance.weight,
+			}),
+		);
+	}
+}
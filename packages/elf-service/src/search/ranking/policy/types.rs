@@ -30,6 +30,27 @@ pub struct ResolvedDiversityPolicy {
 	pub sim_threshold: f32,
 	pub mmr_lambda: f32,
 	pub max_skips: u32,
+	pub scope_balance: Option<ResolvedScopeBalance>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ResolvedScopeBalance {
+	pub by: ScopeBalanceKey,
+	pub weight: f32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScopeBalanceKey {
+	Scope,
+	Type,
+}
+impl ScopeBalanceKey {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Scope => "scope",
+			Self::Type => "type",
+		}
+	}
 }
 
 #[derive(Clone, Debug)]
@@ -55,6 +55,7 @@ pub fn collect_chunk_candidates(
 		let updated_at = payload::payload_rfc3339(&point.payload, "updated_at");
 		let embedding_version = payload::payload_string(&point.payload, "embedding_version");
 		let scope = payload::payload_string(&point.payload, "scope");
+		let embedding_projection = payload::payload_string(&point.payload, "embedding_projection");
 
 		out.push(ChunkCandidate {
 			chunk_id,
@@ -64,6 +65,7 @@ pub fn collect_chunk_candidates(
 			retrieval_score: Some(point.score),
 			updated_at,
 			embedding_version,
+			embedding_projection,
 			scope,
 		});
 	}
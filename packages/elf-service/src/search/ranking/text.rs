@@ -6,9 +6,12 @@ mod tokenization;
 
 #[cfg(test)] pub(in crate::search) use self::tokenization::lexical_overlap_ratio;
 pub(in crate::search) use self::{
-	deterministic::compute_deterministic_ranking_terms,
-	embedding::build_dense_embedding_input,
-	matching::{match_terms_in_text, merge_matched_fields},
+	deterministic::{
+		best_evidence_coverage, compute_deterministic_ranking_terms, compute_evidence_penalty,
+		compute_feedback_adjustment, compute_session_adjustment,
+	},
+	embedding::{EmbeddingInputStrategy, build_dense_embedding_input},
+	matching::{highlight_spans, match_terms_in_text, merge_matched_fields},
 	scope::build_scope_context_boost_by_scope,
 	tokenization::tokenize_query,
 };
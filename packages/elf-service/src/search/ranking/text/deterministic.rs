@@ -1,6 +1,8 @@
 use time::OffsetDateTime;
 
-use crate::search::{DeterministicRankingTerms, ranking::text::tokenization};
+use crate::search::{
+	DeterministicRankingTerms, NoteFeedbackCounts, SessionRankingMode, ranking::text::tokenization,
+};
 use elf_config::Config;
 
 pub(crate) fn compute_deterministic_ranking_terms(
@@ -80,3 +82,69 @@ pub(crate) fn compute_deterministic_ranking_terms(
 
 	out
 }
+
+/// Returns the highest known evidence coverage among candidates.
+pub(crate) fn best_evidence_coverage(coverages: impl Iterator<Item = Option<f32>>) -> Option<f32> {
+	coverages.flatten().filter(|coverage| coverage.is_finite()).reduce(f32::max)
+}
+
+/// Penalizes a candidate by its coverage gap to the best candidate once that best candidate is
+/// strongly evidenced. Candidates without recorded coverage are left unchanged.
+pub(crate) fn compute_evidence_penalty(
+	cfg: &Config,
+	coverage: Option<f32>,
+	best_coverage: Option<f32>,
+) -> f32 {
+	let det = &cfg.ranking.deterministic;
+	let Some(evidence) = det.evidence.as_ref() else { return 0.0 };
+
+	if !det.enabled || !evidence.enabled || evidence.weight <= 0.0 {
+		return 0.0;
+	}
+
+	let (Some(coverage), Some(best_coverage)) = (coverage, best_coverage) else { return 0.0 };
+
+	if best_coverage < evidence.strong_coverage {
+		return 0.0;
+	}
+
+	-evidence.weight * (best_coverage - coverage).clamp(0.0, 1.0)
+}
+
+/// Boosts or penalizes a note the session already retrieved by the configured session weight.
+pub(crate) fn compute_session_adjustment(
+	cfg: &Config,
+	mode: SessionRankingMode,
+	session_hit_count: i64,
+) -> f32 {
+	let det = &cfg.ranking.deterministic;
+	let Some(session) = det.session.as_ref() else { return 0.0 };
+
+	if !det.enabled || !session.enabled || session.weight <= 0.0 || session_hit_count <= 0 {
+		return 0.0;
+	}
+
+	match mode {
+		SessionRankingMode::Off => 0.0,
+		SessionRankingMode::Boost => session.weight,
+		SessionRankingMode::Penalty => -session.weight,
+	}
+}
+
+/// Boosts notes with mostly `useful` feedback and penalizes notes with mostly negative feedback.
+///
+/// `wrong` counts twice as much as `not_useful`. The signal shrinks toward zero for notes with
+/// little feedback, so the adjustment stays strictly within the configured feedback weight.
+pub(crate) fn compute_feedback_adjustment(cfg: &Config, feedback: NoteFeedbackCounts) -> f32 {
+	let det = &cfg.ranking.deterministic;
+	let Some(term) = det.feedback.as_ref() else { return 0.0 };
+
+	if !det.enabled || !term.enabled || term.weight <= 0.0 || feedback.is_empty() {
+		return 0.0;
+	}
+
+	let useful = feedback.useful as f32;
+	let negative = feedback.not_useful as f32 + 2.0 * feedback.wrong as f32;
+
+	term.weight * (useful - negative) / (useful + negative + 1.0)
+}
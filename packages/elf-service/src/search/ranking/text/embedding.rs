@@ -1,13 +1,57 @@
+use elf_config::EmbeddingProviderConfig;
+
+/// How a search query is turned into dense embedding input for one embedding model.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum EmbeddingInputStrategy<'a> {
+	/// The query alone.
+	QueryOnly,
+	/// The query followed by the project context description.
+	ContextSuffix,
+	/// The project context description followed by the query.
+	ContextPrefix,
+	/// A model-specific template with `{query}` and `{context}` placeholders.
+	Template(&'a str),
+}
+impl<'a> EmbeddingInputStrategy<'a> {
+	/// Resolves the strategy configured for a provider, defaulting to `ContextSuffix`.
+	pub(crate) fn for_provider(provider: &'a EmbeddingProviderConfig) -> Self {
+		let Some(query_input) = provider.query_input.as_ref() else { return Self::ContextSuffix };
+
+		match (query_input.strategy.as_str(), query_input.template.as_deref()) {
+			("query_only", _) => Self::QueryOnly,
+			("context_prefix", _) => Self::ContextPrefix,
+			("template", Some(template)) => Self::Template(template),
+			_ => Self::ContextSuffix,
+		}
+	}
+
+	pub(crate) fn as_str(self) -> &'static str {
+		match self {
+			Self::QueryOnly => "query_only",
+			Self::ContextSuffix => "context_suffix",
+			Self::ContextPrefix => "context_prefix",
+			Self::Template(_) => "template",
+		}
+	}
+}
+
 pub(crate) fn build_dense_embedding_input(
 	query: &str,
 	project_context_description: Option<&str>,
+	strategy: EmbeddingInputStrategy<'_>,
 ) -> String {
-	let Some(description) = project_context_description else { return query.to_string() };
-	let trimmed = description.trim();
+	let context = project_context_description.map(str::trim).filter(|value| !value.is_empty());
 
-	if trimmed.is_empty() {
-		return query.to_string();
+	match (strategy, context) {
+		(EmbeddingInputStrategy::Template(template), context) => template
+			.split("{query}")
+			.map(|part| part.replace("{context}", context.unwrap_or_default()))
+			.collect::<Vec<_>>()
+			.join(query),
+		(EmbeddingInputStrategy::ContextSuffix, Some(context)) =>
+			format!("{query}\n\nProject context:\n{context}"),
+		(EmbeddingInputStrategy::ContextPrefix, Some(context)) =>
+			format!("Project context:\n{context}\n\n{query}"),
+		_ => query.to_string(),
 	}
-
-	format!("{query}\n\nProject context:\n{trimmed}")
 }
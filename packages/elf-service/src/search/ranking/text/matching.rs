@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, iter};
 
 pub(crate) fn match_terms_in_text(
 	tokens: &[String],
@@ -48,6 +48,34 @@ pub(crate) fn match_terms_in_text(
 	(matched_terms, fields)
 }
 
+/// Returns byte spans of the `text` tokens that equal one of `terms`, in text order.
+///
+/// Tokens are ASCII alphanumeric runs compared case-insensitively, as in `tokenize_query`, so a
+/// term never highlights part of a longer word.
+pub(crate) fn highlight_spans(terms: &[String], text: &str) -> Vec<(u32, u32)> {
+	if terms.is_empty() {
+		return Vec::new();
+	}
+
+	let mut spans = Vec::new();
+	let mut token_start = None;
+
+	for (idx, byte) in text.bytes().chain(iter::once(b' ')).enumerate() {
+		if byte.is_ascii_alphanumeric() {
+			token_start.get_or_insert(idx);
+
+			continue;
+		}
+		if let Some(start) = token_start.take()
+			&& terms.iter().any(|term| term.eq_ignore_ascii_case(&text[start..idx]))
+		{
+			spans.push((start as u32, idx as u32));
+		}
+	}
+
+	spans
+}
+
 pub(crate) fn merge_matched_fields(
 	mut base: Vec<String>,
 	extra: Option<&Vec<String>>,
@@ -1,10 +1,10 @@
 use crate::{
 	ranking_explain_v2,
 	search::{
-		Config, DiversityDecision, HashMap, NormalizationKind, Ordering, ResolvedBlendPolicy,
-		ResolvedDiversityPolicy, SEARCH_RANKING_EXPLAIN_SCHEMA_V2, ScoreCandidateCtx, ScoredReplay,
-		SearchExplain, SearchMatchExplain, SearchRankingExplain, TraceReplayCandidate,
-		TraceReplayItem, TraceTermsArgs, Uuid, ranking,
+		Config, DiversityDecision, HashMap, NormalizationKind, NoteFeedbackCounts, Ordering,
+		ResolvedBlendPolicy, ResolvedDiversityPolicy, SEARCH_RANKING_EXPLAIN_SCHEMA_V2,
+		ScoreCandidateCtx, ScoredReplay, SearchExplain, SearchMatchExplain, SearchRankingExplain,
+		TraceReplayCandidate, TraceReplayItem, TraceTermsArgs, Uuid, ranking,
 	},
 };
 
@@ -47,13 +47,25 @@ pub(super) fn score_replay_candidate(
 		age_days,
 		ctx.now,
 	);
+	let evidence_penalty = ranking::compute_evidence_penalty(
+		ctx.cfg,
+		candidate.note_evidence_coverage,
+		ctx.best_evidence_coverage,
+	);
+	// Session hits are not part of the trace, so replay reuses the recorded adjustment.
+	let session_adjustment = candidate.note_session_adjustment.unwrap_or(0.0);
+	// Feedback keeps arriving after the trace, so replay also reuses the recorded adjustment.
+	let feedback_adjustment = candidate.note_feedback_adjustment.unwrap_or(0.0);
 	let final_score = retrieval_term
 		+ rerank_term
 		+ tie_breaker_score
 		+ scope_context_boost
 		+ det_terms.lexical_bonus
 		+ det_terms.hit_boost
-		+ det_terms.decay_penalty;
+		+ det_terms.decay_penalty
+		+ evidence_penalty
+		+ session_adjustment
+		+ feedback_adjustment;
 
 	ScoredReplay {
 		note_id: candidate.note_id,
@@ -78,6 +90,12 @@ pub(super) fn score_replay_candidate(
 		deterministic_last_hit_age_days: det_terms.last_hit_age_days,
 		deterministic_hit_boost: det_terms.hit_boost,
 		deterministic_decay_penalty: det_terms.decay_penalty,
+		deterministic_evidence_coverage: candidate.note_evidence_coverage,
+		deterministic_evidence_penalty: evidence_penalty,
+		deterministic_session_hit_count: 0,
+		deterministic_session_adjustment: session_adjustment,
+		deterministic_feedback: NoteFeedbackCounts::default(),
+		deterministic_feedback_adjustment: feedback_adjustment,
 	}
 }
 
@@ -194,6 +212,16 @@ pub(super) fn build_replay_items(
 			deterministic_last_hit_age_days: scored.deterministic_last_hit_age_days,
 			deterministic_hit_boost: scored.deterministic_hit_boost,
 			deterministic_decay_penalty: scored.deterministic_decay_penalty,
+			deterministic_evidence_coverage: scored.deterministic_evidence_coverage,
+			deterministic_evidence_penalty: scored.deterministic_evidence_penalty,
+			deterministic_session_mode: None,
+			deterministic_session_hit_count: scored.deterministic_session_hit_count,
+			deterministic_session_adjustment: scored.deterministic_session_adjustment,
+			deterministic_feedback_useful: scored.deterministic_feedback.useful,
+			deterministic_feedback_not_useful: scored.deterministic_feedback.not_useful,
+			deterministic_feedback_wrong: scored.deterministic_feedback.wrong,
+			deterministic_feedback_adjustment: scored.deterministic_feedback_adjustment,
+			pin_boost: None,
 		});
 		let explain = SearchExplain {
 			r#match: SearchMatchExplain { matched_terms: Vec::new(), matched_fields: Vec::new() },
@@ -202,6 +230,7 @@ pub(super) fn build_replay_items(
 				policy_id: policy_id.to_string(),
 				final_score: scored.final_score,
 				terms,
+				sensitivity: None,
 			},
 			relation_context: None,
 			diversity: if diversity_policy.enabled {
@@ -211,6 +240,9 @@ pub(super) fn build_replay_items(
 			} else {
 				None
 			},
+			embedding_projection: None,
+			rendered: None,
+			packing: None,
 		};
 
 		out.push(TraceReplayItem {
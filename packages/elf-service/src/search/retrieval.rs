@@ -1,5 +1,5 @@
 mod embedding;
 mod expansion;
 mod flow;
-mod recursive;
+pub(in crate::search) mod recursive;
 mod structured;
@@ -1,13 +1,100 @@
+use std::{collections::HashMap, sync::Mutex};
+
 use crate::{
 	Error,
 	search::{
-		BM25_MODEL, BM25_VECTOR_NAME, DENSE_VECTOR_NAME, Document, ElfService, Filter, Fusion,
-		PrefetchQueryBuilder, Query, QueryEmbedding, QueryPointsBuilder, Result, ScoredPoint,
-		english_gate, ranking, slice,
+		self, BM25_MODEL, BM25_VECTOR_NAME, Condition, DENSE_VECTOR_NAME, Document, ElfService,
+		EmbeddingProviderConfig, Filter, Fusion, PrefetchQueryBuilder, Query, QueryEmbedding,
+		QueryPointsBuilder, Result, ScopedQueryVector, ScoredPoint, SearchRequest, english_gate,
+		ranking,
 	},
 };
 
+tokio::task_local! {
+	static BATCH_QUERY_VECTORS: BatchQueryVectors;
+}
+
+/// Query vectors embedded up front for every search of one batch, keyed by embedding version and
+/// embedding input.
+#[derive(Default)]
+struct BatchQueryVectors {
+	vectors: Mutex<HashMap<(String, String), Vec<f32>>>,
+}
+impl BatchQueryVectors {
+	fn lookup(&self, embedding_version: &str, inputs: &[String]) -> Vec<Option<Vec<f32>>> {
+		let vectors = self.vectors.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+		inputs
+			.iter()
+			.map(|input| vectors.get(&(embedding_version.to_string(), input.clone())).cloned())
+			.collect()
+	}
+}
+
 impl ElfService {
+	/// Runs `searches` with the query vectors of every request embedded up front.
+	///
+	/// Each embedding provider that applies to any request is called once with the deduplicated
+	/// inputs of all requests. Searches running inside `searches` read those vectors instead of
+	/// calling the provider; expansion queries and any other input outside the batch still embed
+	/// on demand.
+	pub(crate) async fn with_batch_query_vectors<F>(
+		&self,
+		requests: &[SearchRequest],
+		searches: F,
+	) -> Result<F::Output>
+	where
+		F: Future,
+	{
+		let default_provider = &self.cfg.providers.embedding;
+		let mut groups: Vec<(String, &EmbeddingProviderConfig, Vec<String>)> = Vec::new();
+
+		for req in requests {
+			let allowed_scopes =
+				search::resolve_read_profile_scopes(&self.cfg, req.read_profile.as_str())?;
+			let project_context_description = self
+				.resolve_project_context_description(req.tenant_id.trim(), req.project_id.trim());
+			let providers = [(crate::embedding_version(&self.cfg), default_provider)]
+				.into_iter()
+				.chain(self.scoped_embedding_providers(&allowed_scopes));
+
+			for (embedding_version, provider) in providers {
+				let input = ranking::build_dense_embedding_input(
+					req.query.as_str(),
+					project_context_description,
+					ranking::EmbeddingInputStrategy::for_provider(provider),
+				);
+				let idx =
+					match groups.iter().position(|(version, ..)| *version == embedding_version) {
+						Some(idx) => idx,
+						None => {
+							groups.push((embedding_version, provider, Vec::new()));
+
+							groups.len() - 1
+						},
+					};
+				let group_inputs = &mut groups[idx].2;
+
+				if !group_inputs.contains(&input) {
+					group_inputs.push(input);
+				}
+			}
+		}
+
+		let batch = BatchQueryVectors::default();
+
+		for (embedding_version, provider, inputs) in groups {
+			let embedded = self.embed_provider_inputs(provider, &inputs).await?;
+			let mut vectors = batch.vectors.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+			for (input, vector) in inputs.into_iter().zip(embedded) {
+				vectors.insert((embedding_version.clone(), input), vector);
+			}
+		}
+
+		Ok(BATCH_QUERY_VECTORS.scope(batch, searches).await)
+	}
+
 	pub(in crate::search) fn resolve_project_context_description<'a>(
 		&'a self,
 		tenant_id: &str,
@@ -56,90 +143,211 @@ impl ElfService {
 		&self,
 		query: &str,
 		project_context_description: Option<&str>,
-	) -> Result<Vec<f32>> {
-		let input = ranking::build_dense_embedding_input(query, project_context_description);
-		let embeddings = self
-			.providers
-			.embedding
-			.embed(&self.cfg.providers.embedding, slice::from_ref(&input))
+		allowed_scopes: &[String],
+	) -> Result<QueryEmbedding> {
+		let queries = [query.to_string()];
+		let mut embedded = self
+			.embed_queries(&queries, query, None, project_context_description, allowed_scopes)
 			.await?;
-		let query_vec = embeddings.into_iter().next().ok_or_else(|| Error::Provider {
-			message: "Embedding provider returned no vectors.".to_string(),
-		})?;
-
-		if query_vec.len() != self.cfg.storage.qdrant.vector_dim as usize {
-			return Err(Error::Provider {
-				message: "Embedding vector dimension mismatch.".to_string(),
-			});
-		}
 
-		Ok(query_vec)
+		embedded.pop().ok_or_else(|| Error::Provider {
+			message: "Embedding provider returned no vectors.".to_string(),
+		})
 	}
 
+	/// Embeds each query with the default provider and with every distinct per-scope override
+	/// that applies to `allowed_scopes`.
 	pub(in crate::search::retrieval) async fn embed_queries(
 		&self,
 		queries: &[String],
 		original_query: &str,
-		baseline_vector: Option<&Vec<f32>>,
+		baseline: Option<&QueryEmbedding>,
 		project_context_description: Option<&str>,
+		allowed_scopes: &[String],
 	) -> Result<Vec<QueryEmbedding>> {
-		let mut extra_queries = Vec::new();
-		let mut extra_inputs = Vec::new();
+		let reuse_baseline = |query: &String| baseline.is_some() && query == original_query;
+		let build_inputs = |provider: &EmbeddingProviderConfig| {
+			let strategy = ranking::EmbeddingInputStrategy::for_provider(provider);
 
-		for query in queries {
-			if baseline_vector.is_some() && query == original_query {
-				continue;
-			}
+			queries
+				.iter()
+				.filter(|query| !reuse_baseline(query))
+				.map(|query| {
+					ranking::build_dense_embedding_input(
+						query,
+						project_context_description,
+						strategy,
+					)
+				})
+				.collect::<Vec<_>>()
+		};
+		let default_provider = &self.cfg.providers.embedding;
+		let mut default_iter =
+			self.embed_inputs(default_provider, &build_inputs(default_provider)).await?.into_iter();
+		let mut scoped_iters = Vec::new();
 
-			extra_queries.push(query.clone());
-			extra_inputs
-				.push(ranking::build_dense_embedding_input(query, project_context_description));
-		}
+		for (embedding_version, provider) in self.scoped_embedding_providers(allowed_scopes) {
+			let vectors = self.embed_inputs(provider, &build_inputs(provider)).await?;
 
-		let mut embedded_iter = if extra_queries.is_empty() {
-			Vec::new().into_iter()
-		} else {
-			let embedded = self
-				.providers
-				.embedding
-				.embed(&self.cfg.providers.embedding, &extra_inputs)
-				.await?;
-
-			if embedded.len() != extra_queries.len() {
-				return Err(Error::Provider {
-					message: "Embedding provider returned mismatched vector count.".to_string(),
-				});
-			}
+			scoped_iters.push((embedding_version, vectors.into_iter()));
+		}
 
-			embedded.into_iter()
-		};
 		let mut out = Vec::with_capacity(queries.len());
 
 		for query in queries {
-			let vector = if baseline_vector.is_some() && query == original_query {
-				baseline_vector
-					.ok_or_else(|| Error::Provider {
-						message: "Embedding baseline vector is missing.".to_string(),
-					})?
-					.clone()
-			} else {
-				embedded_iter.next().ok_or_else(|| Error::Provider {
+			if reuse_baseline(query) {
+				let baseline = baseline.ok_or_else(|| Error::Provider {
+					message: "Embedding baseline vector is missing.".to_string(),
+				})?;
+
+				out.push(baseline.clone());
+
+				continue;
+			}
+
+			let vector = default_iter.next().ok_or_else(|| Error::Provider {
+				message: "Embedding provider returned no vectors.".to_string(),
+			})?;
+			let mut scoped_vectors = Vec::with_capacity(scoped_iters.len());
+
+			for (embedding_version, vectors) in &mut scoped_iters {
+				let vector = vectors.next().ok_or_else(|| Error::Provider {
 					message: "Embedding provider returned no vectors.".to_string(),
-				})?
-			};
+				})?;
 
-			if vector.len() != self.cfg.storage.qdrant.vector_dim as usize {
-				return Err(Error::Provider {
-					message: "Embedding vector dimension mismatch.".to_string(),
+				scoped_vectors.push(ScopedQueryVector {
+					embedding_version: embedding_version.clone(),
+					vector,
 				});
 			}
 
-			out.push(QueryEmbedding { text: query.clone(), vector });
+			out.push(QueryEmbedding { text: query.clone(), vector, scoped_vectors });
 		}
 
 		Ok(out)
 	}
 
+	/// Describes the query input strategy of every embedding provider used for `allowed_scopes`,
+	/// for the trace config snapshot.
+	pub(in crate::search) fn embedding_input_snapshot(
+		&self,
+		allowed_scopes: &[String],
+	) -> serde_json::Value {
+		let default_provider = &self.cfg.providers.embedding;
+		let scoped = self
+			.scoped_embedding_providers(allowed_scopes)
+			.into_iter()
+			.map(|(embedding_version, provider)| {
+				serde_json::json!({
+					"embedding_version": embedding_version,
+					"model": provider.model,
+					"strategy": ranking::EmbeddingInputStrategy::for_provider(provider).as_str(),
+				})
+			})
+			.collect::<Vec<_>>();
+
+		serde_json::json!({
+			"model": default_provider.model,
+			"strategy": ranking::EmbeddingInputStrategy::for_provider(default_provider).as_str(),
+			"scoped": scoped,
+		})
+	}
+
+	/// Returns the distinct per-scope and per-note-type routed embedding providers that differ
+	/// from the default provider, keyed by the embedding version they write. Scope routes are
+	/// limited to `allowed_scopes`; note type routes apply in every scope.
+	fn scoped_embedding_providers(
+		&self,
+		allowed_scopes: &[String],
+	) -> Vec<(String, &EmbeddingProviderConfig)> {
+		let cfg_providers = &self.cfg.providers;
+		let mut type_routes = cfg_providers.embedding_types.iter().flatten().collect::<Vec<_>>();
+
+		// Sort for a stable query-vector order across processes.
+		type_routes.sort_by(|left, right| left.0.cmp(right.0));
+
+		let default_version = crate::embedding_version(&self.cfg);
+		let mut providers: Vec<(String, &EmbeddingProviderConfig)> = Vec::new();
+
+		for provider in
+			allowed_scopes.iter().map(|scope| cfg_providers.embedding_for_scope(scope)).chain(
+				type_routes.into_iter().filter_map(|(_, name)| cfg_providers.embedding_named(name)),
+			) {
+			let embedding_version =
+				crate::provider_embedding_version(provider, self.cfg.storage.qdrant.vector_dim);
+
+			if embedding_version == default_version
+				|| providers.iter().any(|(version, _)| *version == embedding_version)
+			{
+				continue;
+			}
+
+			providers.push((embedding_version, provider));
+		}
+
+		providers
+	}
+
+	/// Embeds `inputs`, reusing vectors prepared by [`Self::with_batch_query_vectors`] when the
+	/// search runs inside a batch.
+	async fn embed_inputs(
+		&self,
+		provider: &EmbeddingProviderConfig,
+		inputs: &[String],
+	) -> Result<Vec<Vec<f32>>> {
+		let embedding_version =
+			crate::provider_embedding_version(provider, self.cfg.storage.qdrant.vector_dim);
+		let mut vectors = BATCH_QUERY_VECTORS
+			.try_with(|batch| batch.lookup(embedding_version.as_str(), inputs))
+			.unwrap_or_else(|_| vec![None; inputs.len()]);
+		let missing = inputs
+			.iter()
+			.zip(&vectors)
+			.filter(|(_, vector)| vector.is_none())
+			.map(|(input, _)| input.clone())
+			.collect::<Vec<_>>();
+		let mut embedded = self.embed_provider_inputs(provider, &missing).await?.into_iter();
+
+		for slot in vectors.iter_mut().filter(|slot| slot.is_none()) {
+			*slot = embedded.next();
+		}
+
+		vectors
+			.into_iter()
+			.map(|vector| {
+				vector.ok_or_else(|| Error::Provider {
+					message: "Embedding provider returned no vectors.".to_string(),
+				})
+			})
+			.collect()
+	}
+
+	async fn embed_provider_inputs(
+		&self,
+		provider: &EmbeddingProviderConfig,
+		inputs: &[String],
+	) -> Result<Vec<Vec<f32>>> {
+		if inputs.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let embedded = self.providers.embedding.embed(provider, inputs).await?;
+
+		if embedded.len() != inputs.len() {
+			return Err(Error::Provider {
+				message: "Embedding provider returned mismatched vector count.".to_string(),
+			});
+		}
+		if embedded.iter().any(|vector| vector.len() != self.cfg.storage.qdrant.vector_dim as usize)
+		{
+			return Err(Error::Provider {
+				message: "Embedding vector dimension mismatch.".to_string(),
+			});
+		}
+
+		Ok(embedded)
+	}
+
 	pub(in crate::search::retrieval) async fn run_fusion_query(
 		&self,
 		queries: &[QueryEmbedding],
@@ -149,11 +357,44 @@ impl ElfService {
 		let mut search = QueryPointsBuilder::new(self.qdrant.collection.clone());
 
 		for query in queries {
+			let mut default_filter = filter.clone();
+
+			// Points written by a per-scope provider must only be compared with query vectors from
+			// the same model, so the default vector skips them.
+			if !query.scoped_vectors.is_empty() {
+				default_filter.must_not.push(Condition::matches(
+					"embedding_version",
+					query
+						.scoped_vectors
+						.iter()
+						.map(|scoped| scoped.embedding_version.clone())
+						.collect::<Vec<_>>(),
+				));
+			}
+
 			let dense_prefetch = PrefetchQueryBuilder::default()
 				.query(Query::new_nearest(query.vector.clone()))
 				.using(DENSE_VECTOR_NAME)
-				.filter(filter.clone())
+				.filter(default_filter)
 				.limit(candidate_k as u64);
+
+			for scoped in &query.scoped_vectors {
+				let mut scoped_filter = filter.clone();
+
+				scoped_filter.must.push(Condition::matches(
+					"embedding_version",
+					scoped.embedding_version.clone(),
+				));
+
+				search = search.add_prefetch(
+					PrefetchQueryBuilder::default()
+						.query(Query::new_nearest(scoped.vector.clone()))
+						.using(DENSE_VECTOR_NAME)
+						.filter(scoped_filter)
+						.limit(candidate_k as u64),
+				);
+			}
+
 			let bm25_prefetch = PrefetchQueryBuilder::default()
 				.query(Query::new_nearest(Document::new(query.text.clone(), BM25_MODEL)))
 				.using(BM25_VECTOR_NAME)
@@ -173,4 +414,27 @@ impl ElfService {
 
 		Ok(response.result)
 	}
+
+	/// Runs a BM25-only query for `query`, used when query embedding is unavailable.
+	pub(in crate::search) async fn run_bm25_query(
+		&self,
+		query: &str,
+		filter: &Filter,
+		candidate_k: u32,
+	) -> Result<Vec<ScoredPoint>> {
+		let search = QueryPointsBuilder::new(self.qdrant.collection.clone())
+			.query(Query::new_nearest(Document::new(query, BM25_MODEL)))
+			.using(BM25_VECTOR_NAME)
+			.filter(filter.clone())
+			.with_payload(true)
+			.limit(candidate_k as u64);
+		let response = self
+			.qdrant
+			.client
+			.query(search)
+			.await
+			.map_err(|err| Error::Qdrant { message: err.to_string() })?;
+
+		Ok(response.result)
+	}
 }
@@ -1,14 +1,19 @@
 use crate::search::{
 	self, CacheKind, Duration, ElfService, ExpansionCachePayload, ExpansionOutput, OffsetDateTime,
-	SearchCache, ranking,
+	SearchCache, SearchWarning, ranking,
 };
 
 impl ElfService {
-	pub(in crate::search::retrieval) async fn expand_queries(&self, query: &str) -> Vec<String> {
+	#[tracing::instrument(name = "search.expansion", skip_all)]
+	pub(in crate::search::retrieval) async fn expand_queries(
+		&self,
+		query: &str,
+		warnings: &mut Vec<SearchWarning>,
+	) -> Vec<String> {
 		let cfg = &self.cfg.search.expansion;
 		let cache_cfg = &self.cfg.search.cache;
 		let now = OffsetDateTime::now_utc();
-		let cache_key = if cache_cfg.enabled {
+		let cache_key = if self.search_cache_enabled() {
 			match ranking::build_expansion_cache_key(
 				query,
 				cfg.max_queries,
@@ -33,7 +38,8 @@ impl ElfService {
 		};
 
 		if let Some(key) = cache_key.as_ref()
-			&& let Some(queries) = self.read_expansion_cache_queries(key, cache_cfg, now).await
+			&& let Some(queries) =
+				self.read_expansion_cache_queries(key, cache_cfg, now, warnings).await
 		{
 			return queries;
 		}
@@ -81,6 +87,7 @@ impl ElfService {
 		key: &str,
 		cache_cfg: &SearchCache,
 		now: OffsetDateTime,
+		warnings: &mut Vec<SearchWarning>,
 	) -> Option<Vec<String>> {
 		match search::fetch_cache_payload(&self.db.pool, CacheKind::Expansion, key, now).await {
 			Ok(Some(payload)) => {
@@ -129,6 +136,10 @@ impl ElfService {
 					"Cache read failed."
 				);
 
+				warnings.push(SearchWarning::CacheUnavailable {
+					cache_kind: CacheKind::Expansion.as_str().to_string(),
+				});
+
 				None
 			},
 		}
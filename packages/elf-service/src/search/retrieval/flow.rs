@@ -1,27 +1,29 @@
-use crate::search::{
-	DynamicGateSummary, ElfService, ExpansionMode, FinishSearchArgs, MaybeDynamicSearchArgs,
-	OffsetDateTime, QueryEmbedding, RecursiveRetrievalArgs, Result, RetrievalSourceCandidates,
-	RetrievalSourceKind, SearchResponse, SearchRetrievalArgs, SearchRetrievalResult,
-	StructuredFieldRetrievalArgs, StructuredFieldRetrievalResult, ranking,
+use crate::{
+	search::{
+		DynamicGateSummary, ElfService, ExpansionMode, FinishSearchArgs, MaybeDynamicSearchArgs,
+		OffsetDateTime, QueryEmbedding, RecursiveRetrievalArgs, Result, RetrievalSourceCandidates,
+		RetrievalSourceKind, SearchResponse, SearchRetrievalArgs, SearchRetrievalResult,
+		SearchWarning, StructuredFieldRetrievalArgs, StructuredFieldRetrievalResult, ranking,
+		slice,
+	},
+	search_stream,
 };
 
 impl ElfService {
+	#[tracing::instrument(name = "search.dynamic_gate", skip_all)]
 	pub(in crate::search) async fn maybe_finish_dynamic_search(
 		&self,
 		args: MaybeDynamicSearchArgs<'_>,
-	) -> Result<(Option<Vec<f32>>, Option<SearchResponse>, DynamicGateSummary)> {
+	) -> Result<(Option<QueryEmbedding>, Option<SearchResponse>, DynamicGateSummary)> {
 		if !args.enabled {
 			return Ok((None, None, DynamicGateSummary::default()));
 		}
 
-		let query_vec =
-			self.embed_single_query(args.query, args.project_context_description).await?;
+		let query_embedding = self
+			.embed_single_query(args.query, args.project_context_description, args.allowed_scopes)
+			.await?;
 		let baseline_points = self
-			.run_fusion_query(
-				&[QueryEmbedding { text: args.query.to_string(), vector: query_vec.clone() }],
-				args.filter,
-				args.candidate_k,
-			)
+			.run_fusion_query(slice::from_ref(&query_embedding), args.filter, args.candidate_k)
 			.await?;
 		let top_score = baseline_points.first().map(|point| point.score).unwrap_or(0.0);
 		let fusion_candidates = ranking::collect_chunk_candidates(
@@ -42,7 +44,7 @@ impl ElfService {
 		};
 
 		if should_expand {
-			return Ok((Some(query_vec), None, dynamic_gate));
+			return Ok((Some(query_embedding), None, dynamic_gate));
 		}
 
 		let StructuredFieldRetrievalResult {
@@ -54,7 +56,7 @@ impl ElfService {
 				project_id: args.project_id,
 				agent_id: args.agent_id,
 				allowed_scopes: args.allowed_scopes,
-				query_vec: query_vec.as_slice(),
+				query_embedding: &query_embedding,
 				candidate_k: args.candidate_k,
 				now: OffsetDateTime::now_utc(),
 			})
@@ -67,8 +69,7 @@ impl ElfService {
 
 		let recursive = self
 			.run_recursive_retrieval(RecursiveRetrievalArgs {
-				query: args.query,
-				query_vec: query_vec.as_slice(),
+				query_embedding: &query_embedding,
 				filter: args.filter,
 				candidate_k: args.candidate_k,
 				retrieval_sources_policy: args.retrieval_sources_policy,
@@ -116,32 +117,49 @@ impl ElfService {
 				recursive_retrieval: Some(recursive),
 				top_k: args.top_k,
 				record_hits_enabled: args.record_hits_enabled,
+				session: args.session,
 				ranking_override: args.ranking_override.cloned(),
 				payload_level: args.payload_level,
+				max_result_tokens: args.max_result_tokens,
 				filter: args.service_filter,
+				payload_filters: args.payload_filters,
 				requested_candidate_k: args.requested_candidate_k,
 				effective_candidate_k: args.effective_candidate_k,
+				hook_runs: args.hook_runs.to_vec(),
+				degraded_mode: None,
 			})
 			.await?;
 
-		Ok((Some(query_vec), Some(response), dynamic_gate))
+		Ok((Some(query_embedding), Some(response), dynamic_gate))
 	}
 
+	#[tracing::instrument(name = "search.retrieval", skip_all)]
 	pub(in crate::search) async fn retrieve_search_candidates(
 		&self,
 		args: SearchRetrievalArgs<'_>,
 	) -> Result<SearchRetrievalResult> {
+		let mut warnings: Vec<SearchWarning> = Vec::new();
 		let queries = match args.expansion_mode {
 			ExpansionMode::Off => vec![args.query.to_string()],
-			ExpansionMode::Always | ExpansionMode::Dynamic => self.expand_queries(args.query).await,
+			ExpansionMode::Always | ExpansionMode::Dynamic =>
+				self.expand_queries(args.query, &mut warnings).await,
 		};
 		let expanded_queries = queries.clone();
+
+		search_stream::emit_stage(
+			1,
+			"rewrite.expansion",
+			0,
+			serde_json::json!({ "expanded_query_count": expanded_queries.len() }),
+		);
+
 		let query_embeddings = self
 			.embed_queries(
 				queries.as_slice(),
 				args.query,
-				args.baseline_vector,
+				args.baseline_embedding,
 				args.project_context_description,
+				args.allowed_scopes,
 			)
 			.await?;
 		let fusion_points =
@@ -151,16 +169,17 @@ impl ElfService {
 			self.cfg.search.prefilter.max_candidates,
 			args.candidate_k,
 		);
-		let original_query_vec = query_embeddings
-			.iter()
-			.find(|embedded| embedded.text == args.query)
-			.map(|embedded| embedded.vector.clone())
-			.unwrap_or_else(Vec::new);
-		let original_query_vec = if original_query_vec.is_empty() {
-			self.embed_single_query(args.query, args.project_context_description).await?
-		} else {
-			original_query_vec
-		};
+		let original_query_embedding =
+			match query_embeddings.iter().find(|embedded| embedded.text == args.query) {
+				Some(embedded) => embedded.clone(),
+				None =>
+					self.embed_single_query(
+						args.query,
+						args.project_context_description,
+						args.allowed_scopes,
+					)
+					.await?,
+			};
 		let StructuredFieldRetrievalResult {
 			candidates: structured_candidates,
 			structured_matches,
@@ -170,7 +189,7 @@ impl ElfService {
 				project_id: args.project_id,
 				agent_id: args.agent_id,
 				allowed_scopes: args.allowed_scopes,
-				query_vec: original_query_vec.as_slice(),
+				query_embedding: &original_query_embedding,
 				candidate_k: args.candidate_k,
 				now: OffsetDateTime::now_utc(),
 			})
@@ -183,8 +202,7 @@ impl ElfService {
 
 		let recursive = self
 			.run_recursive_retrieval(RecursiveRetrievalArgs {
-				query: args.query,
-				query_vec: original_query_vec.as_slice(),
+				query_embedding: &original_query_embedding,
 				filter: args.filter,
 				candidate_k: args.candidate_k,
 				retrieval_sources_policy: args.retrieval_sources_policy,
@@ -220,6 +238,7 @@ impl ElfService {
 			candidates: merged_candidates,
 			structured_matches,
 			recursive: Some(recursive),
+			warnings,
 		})
 	}
 }
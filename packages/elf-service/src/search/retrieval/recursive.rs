@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use crate::search::{
 	ChunkCandidate, Condition, ElfService, HashMap, HashSet, QueryEmbedding,
 	RecursiveRetrievalArgs, RecursiveRetrievalResult, Result, VecDeque, ranking, slice,
@@ -8,6 +10,7 @@ impl ElfService {
 		&self,
 		args: RecursiveRetrievalArgs<'_>,
 	) -> Result<RecursiveRetrievalResult> {
+		let started = Instant::now();
 		let recursive_config = &self.cfg.search.recursive;
 		let mut result = RecursiveRetrievalResult {
 			enabled: recursive_config.enabled
@@ -20,7 +23,7 @@ impl ElfService {
 
 			return Ok(result);
 		}
-		if args.query_vec.is_empty() {
+		if args.query_embedding.vector.is_empty() {
 			result.stop_reason = Some("missing_query_vector".to_string());
 
 			return Ok(result);
@@ -52,11 +55,13 @@ impl ElfService {
 			usize::try_from(recursive_config.max_nodes_per_scope).unwrap_or(usize::MAX);
 		let max_total_nodes =
 			usize::try_from(recursive_config.max_total_nodes).unwrap_or(usize::MAX);
-		let child_query_embedding =
-			QueryEmbedding { text: args.query.to_string(), vector: args.query_vec.to_vec() };
+		let child_query_embedding = args.query_embedding.clone();
 		let per_query_candidate_k =
 			args.candidate_k.min(recursive_config.max_nodes_per_scope).max(1);
-		let (candidates, queried_scopes, rounds_executed, stop_reason) = self
+		let deadline = recursive_config
+			.max_elapsed_ms
+			.and_then(|max_elapsed_ms| started.checked_add(Duration::from_millis(max_elapsed_ms)));
+		let (candidates, queried_scopes, rounds_executed, round_elapsed_ms, stop_reason) = self
 			.collect_recursive_candidates(
 				&args,
 				seed_scopes,
@@ -67,16 +72,19 @@ impl ElfService {
 				max_total_nodes,
 				per_query_candidate_k,
 				self.cfg.search.prefilter.max_candidates,
+				deadline,
 			)
 			.await?;
 
 		result.scopes_queried = queried_scopes;
 		result.rounds_executed = rounds_executed;
+		result.round_elapsed_ms = round_elapsed_ms;
 		result.total_queries = rounds_executed;
 		result.candidates = candidates;
 		result.candidates_added = result.candidates.len();
 		result.candidates_after = result.candidates_before + result.candidates_added;
 		result.stop_reason = stop_reason.or(Some("converged".to_string()));
+		result.elapsed_ms = elapsed_ms_since(started);
 
 		Ok(result)
 	}
@@ -93,7 +101,8 @@ impl ElfService {
 		max_total_nodes: usize,
 		per_query_candidate_k: u32,
 		prefilter_max_candidates: u32,
-	) -> Result<(Vec<ChunkCandidate>, usize, u32, Option<String>)> {
+		deadline: Option<Instant>,
+	) -> Result<(Vec<ChunkCandidate>, usize, u32, Vec<u64>, Option<String>)> {
 		let mut queued_scopes: VecDeque<(String, u32)> = VecDeque::new();
 		let mut discovered_scopes = seed_scopes.clone();
 		let mut recursion_candidates = Vec::<ChunkCandidate>::new();
@@ -102,6 +111,7 @@ impl ElfService {
 		let mut scope_counts: HashMap<String, u32> = HashMap::new();
 		let mut queried_scopes = 0_usize;
 		let mut rounds_executed = 0_u32;
+		let mut round_elapsed_ms = Vec::<u64>::new();
 		let mut stop_reason: Option<String> = None;
 
 		for scope in seed_scopes {
@@ -115,6 +125,18 @@ impl ElfService {
 				break;
 			}
 
+			let remaining = match deadline {
+				Some(deadline) => match recursive_budget_remaining(deadline, Instant::now()) {
+					Some(remaining) => Some(remaining),
+					None => {
+						stop_reason = Some("budget_exhausted".to_string());
+
+						break;
+					},
+				},
+				None => None,
+			};
+
 			queried_scopes = queried_scopes.saturating_add(1);
 			rounds_executed = rounds_executed.saturating_add(1);
 
@@ -122,13 +144,25 @@ impl ElfService {
 
 			scoped_filter.must.push(Condition::matches("scope", scope.clone()));
 
-			let recursive_points = self
-				.run_fusion_query(
-					slice::from_ref(&child_query_embedding),
-					&scoped_filter,
-					per_query_candidate_k,
-				)
-				.await?;
+			let round_started = Instant::now();
+			let query = self.run_fusion_query(
+				slice::from_ref(&child_query_embedding),
+				&scoped_filter,
+				per_query_candidate_k,
+			);
+			let recursive_points = match remaining {
+				Some(remaining) => match tokio::time::timeout(remaining, query).await {
+					Ok(points) => points?,
+					Err(_) => {
+						round_elapsed_ms.push(elapsed_ms_since(round_started));
+
+						stop_reason = Some("budget_exhausted".to_string());
+
+						break;
+					},
+				},
+				None => query.await?,
+			};
 			let scope_query_limit = per_query_candidate_k.min(max_nodes_per_scope as u32);
 			let recursive_candidates_for_scope = ranking::collect_chunk_candidates(
 				&recursive_points,
@@ -169,11 +203,25 @@ impl ElfService {
 				}
 			}
 
+			round_elapsed_ms.push(elapsed_ms_since(round_started));
+
 			if stop_reason.is_some() {
 				break;
 			}
 		}
 
-		Ok((recursion_candidates, queried_scopes, rounds_executed, stop_reason))
+		Ok((recursion_candidates, queried_scopes, rounds_executed, round_elapsed_ms, stop_reason))
 	}
 }
+
+/// Returns the time left before `deadline`, or `None` once the recursive budget is spent.
+pub(in crate::search) fn recursive_budget_remaining(
+	deadline: Instant,
+	now: Instant,
+) -> Option<Duration> {
+	deadline.checked_duration_since(now).filter(|remaining| !remaining.is_zero())
+}
+
+fn elapsed_ms_since(started: Instant) -> u64 {
+	u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
@@ -8,36 +8,59 @@ impl ElfService {
 		&self,
 		args: StructuredFieldRetrievalArgs<'_>,
 	) -> Result<StructuredFieldRetrievalResult> {
-		let StructuredFieldRetrievalArgs {
-			tenant_id,
-			project_id,
-			agent_id,
-			allowed_scopes,
-			query_vec,
-			candidate_k,
-			now,
-		} = args;
+		let query_embedding = args.query_embedding;
 
-		if query_vec.is_empty() {
+		if query_embedding.vector.is_empty() {
 			return Ok(StructuredFieldRetrievalResult {
 				candidates: Vec::new(),
 				structured_matches: HashMap::new(),
 			});
 		}
 
-		let embed_version = crate::embedding_version(&self.cfg);
+		let default_version = crate::embedding_version(&self.cfg);
+		let mut results = Vec::with_capacity(1 + query_embedding.scoped_vectors.len());
+
+		results.push(
+			self.retrieve_structured_field_candidates_for_version(
+				&args,
+				default_version.as_str(),
+				query_embedding.vector.as_slice(),
+			)
+			.await?,
+		);
+
+		for scoped in &query_embedding.scoped_vectors {
+			results.push(
+				self.retrieve_structured_field_candidates_for_version(
+					&args,
+					scoped.embedding_version.as_str(),
+					scoped.vector.as_slice(),
+				)
+				.await?,
+			);
+		}
+
+		Ok(search::merge_structured_field_results(results, args.candidate_k))
+	}
+
+	async fn retrieve_structured_field_candidates_for_version(
+		&self,
+		args: &StructuredFieldRetrievalArgs<'_>,
+		embed_version: &str,
+		query_vec: &[f32],
+	) -> Result<StructuredFieldRetrievalResult> {
 		let vec_text = crate::vector_to_pg(query_vec);
-		let private_allowed = allowed_scopes.iter().any(|scope| scope == "agent_private");
+		let private_allowed = args.allowed_scopes.iter().any(|scope| scope == "agent_private");
 		let non_private_scopes: Vec<String> =
-			allowed_scopes.iter().filter(|scope| *scope != "agent_private").cloned().collect();
-		let retrieval_limit = i64::from(candidate_k.saturating_mul(4).clamp(16, 400));
+			args.allowed_scopes.iter().filter(|scope| *scope != "agent_private").cloned().collect();
+		let retrieval_limit = i64::from(args.candidate_k.saturating_mul(4).clamp(16, 400));
 		let rows = self
 			.fetch_structured_field_hits(StructuredFieldHitArgs {
-				embed_version: embed_version.as_str(),
-				tenant_id,
-				project_id,
-				agent_id,
-				now,
+				embed_version,
+				tenant_id: args.tenant_id,
+				project_id: args.project_id,
+				agent_id: args.agent_id,
+				now: args.now,
 				vec_text: vec_text.as_str(),
 				retrieval_limit,
 				private_allowed,
@@ -56,16 +79,16 @@ impl ElfService {
 
 		let best_by_note = self
 			.fetch_best_chunks_for_notes(
-				embed_version.as_str(),
+				embed_version,
 				ordered_note_ids.as_slice(),
 				vec_text.as_str(),
 			)
 			.await?;
 		let structured_candidates = search::build_structured_field_candidates(
-			candidate_k,
+			args.candidate_k,
 			ordered_note_ids,
 			best_by_note,
-			embed_version.as_str(),
+			embed_version,
 		);
 
 		Ok(StructuredFieldRetrievalResult {
@@ -1,6 +1,6 @@
 use crate::search::{
-	ChunkSnippet, HashMap, NormalizationKind, Ordering, ScoreCandidateCtx, ScoredChunk, Uuid,
-	ranking,
+	ChunkCandidate, ChunkSnippet, HashMap, NormalizationKind, Ordering, ScoreCandidateCtx,
+	ScoredChunk, SearchHookCandidate, Uuid, ranking,
 };
 
 pub(super) fn select_best_scored_chunks(scored: Vec<ScoredChunk>) -> Vec<ScoredChunk> {
@@ -87,13 +87,27 @@ pub(super) fn score_chunk_candidate(
 		age_days,
 		ctx.now,
 	);
+	let evidence_penalty = ranking::compute_evidence_penalty(
+		ctx.cfg,
+		item.note.evidence_coverage,
+		ctx.best_evidence_coverage,
+	);
+	let session_hit_count = item.note.session_hit_count;
+	let session_adjustment =
+		ranking::compute_session_adjustment(ctx.cfg, ctx.session_mode, session_hit_count);
+	let feedback = item.note.feedback;
+	let feedback_adjustment = ranking::compute_feedback_adjustment(ctx.cfg, feedback);
 	let final_score = retrieval_term
 		+ rerank_term
 		+ tie_breaker_score
 		+ scope_context_boost
 		+ det_terms.lexical_bonus
 		+ det_terms.hit_boost
-		+ det_terms.decay_penalty;
+		+ det_terms.decay_penalty
+		+ evidence_penalty
+		+ session_adjustment
+		+ feedback_adjustment;
+	let evidence_coverage = item.note.evidence_coverage;
 
 	ScoredChunk {
 		item,
@@ -115,5 +129,39 @@ pub(super) fn score_chunk_candidate(
 		deterministic_last_hit_age_days: det_terms.last_hit_age_days,
 		deterministic_hit_boost: det_terms.hit_boost,
 		deterministic_decay_penalty: det_terms.decay_penalty,
+		deterministic_evidence_coverage: evidence_coverage,
+		deterministic_evidence_penalty: evidence_penalty,
+		deterministic_session_mode: ctx.session_mode,
+		deterministic_session_hit_count: session_hit_count,
+		deterministic_session_adjustment: session_adjustment,
+		deterministic_feedback: feedback,
+		deterministic_feedback_adjustment: feedback_adjustment,
+		pin_boost: 0.0,
 	}
 }
+
+pub(super) fn hook_candidates_from_chunks(
+	candidates: &[ChunkCandidate],
+) -> Vec<SearchHookCandidate> {
+	candidates
+		.iter()
+		.map(|candidate| SearchHookCandidate {
+			note_id: candidate.note_id,
+			chunk_id: candidate.chunk_id,
+			retrieval_rank: candidate.retrieval_rank,
+			score: candidate.retrieval_score,
+		})
+		.collect()
+}
+
+pub(super) fn hook_candidates_from_scored(scored: &[ScoredChunk]) -> Vec<SearchHookCandidate> {
+	scored
+		.iter()
+		.map(|scored_chunk| SearchHookCandidate {
+			note_id: scored_chunk.item.note.note_id,
+			chunk_id: scored_chunk.item.chunk.chunk_id,
+			retrieval_rank: scored_chunk.item.retrieval_rank,
+			score: Some(scored_chunk.final_score),
+		})
+		.collect()
+}
@@ -2,3 +2,4 @@ mod context;
 mod entrypoint;
 mod execution;
 mod response;
+mod result_cache;
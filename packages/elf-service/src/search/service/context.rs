@@ -2,7 +2,8 @@ use crate::{
 	Error,
 	search::{
 		self, ElfService, ExpansionMode, MAX_CANDIDATE_K, RawSearchExecutionContext, RawSearchPath,
-		Result, SearchFilter, SearchRequest, Uuid, ranking,
+		Result, SearchFilter, SearchPayloadFilters, SearchRequest, Uuid, packing, ranking,
+		session_hits,
 	},
 };
 
@@ -11,6 +12,7 @@ impl ElfService {
 		&self,
 		req: SearchRequest,
 		path: RawSearchPath,
+		scope: Option<&str>,
 	) -> Result<RawSearchExecutionContext> {
 		let tenant_id = req.tenant_id.trim().to_string();
 		let project_id = req.project_id.trim().to_string();
@@ -38,14 +40,52 @@ impl ElfService {
 			.map(SearchFilter::parse)
 			.transpose()
 			.map_err(|err| Error::InvalidRequest { message: err.to_string() })?;
+		let payload_filters = SearchPayloadFilters::parse(&req)?;
+		let max_result_tokens = packing::validate_max_result_tokens(req.max_result_tokens)?;
+
+		if max_result_tokens.is_some() {
+			// Fail before retrieval instead of silently ignoring the budget.
+			self.tokenizer()?;
+		}
+
 		let effective_candidate_k = if filter.is_some() {
 			requested_candidate_k.saturating_mul(3).min(MAX_CANDIDATE_K).max(top_k)
 		} else {
 			requested_candidate_k
 		};
+		let warnings = search::candidate_k_warnings(
+			req.candidate_k,
+			candidate_k,
+			filter.is_some(),
+			effective_candidate_k,
+		);
+		let cache_request = serde_json::json!({
+			"path": search::raw_search_path_label(path),
+			"tenant_id": tenant_id,
+			"project_id": project_id,
+			"agent_id": agent_id,
+			"token_id": token_id,
+			"read_profile": req.read_profile,
+			"query": ranking::normalize_cache_query(req.query.as_str()),
+			"top_k": top_k,
+			"candidate_k": candidate_k,
+			"payload_level": req.payload_level,
+			"max_result_tokens": max_result_tokens,
+			"filter": req.filter,
+			"types": search::sorted_unique_strings(req.types.clone()),
+			"updated_after": req.updated_after,
+			"updated_before": req.updated_before,
+			"min_importance": req.min_importance,
+		});
 		let query = req.query;
 		let read_profile = req.read_profile;
 		let record_hits_enabled = req.record_hits.unwrap_or(false);
+		let session_id = session_hits::normalize_session_id(req.session_id.as_deref())?;
+		let session_mode = session_hits::resolve_session_mode(
+			&self.cfg,
+			req.session_mode.as_deref(),
+			session_id.is_some(),
+		)?;
 		let ranking_override = req.ranking;
 		let retrieval_sources_policy = ranking::resolve_retrieval_sources_policy(
 			&self.cfg.ranking.retrieval_sources,
@@ -59,7 +99,12 @@ impl ElfService {
 		let project_context_description = self
 			.resolve_project_context_description(tenant_id.as_str(), project_id.as_str())
 			.map(|value| value.to_string());
-		let allowed_scopes = ranking::resolve_scopes(&self.cfg, read_profile.as_str())?;
+		let mut allowed_scopes = ranking::resolve_scopes(&self.cfg, read_profile.as_str())?;
+
+		if let Some(scope) = scope {
+			allowed_scopes.retain(|allowed| allowed == scope);
+		}
+
 		let policies = self.resolve_finish_search_policies(ranking_override.as_ref())?;
 
 		Ok(RawSearchExecutionContext {
@@ -72,10 +117,14 @@ impl ElfService {
 			requested_candidate_k,
 			effective_candidate_k,
 			filter,
+			payload_filters,
 			query,
 			read_profile,
 			payload_level: req.payload_level,
+			max_result_tokens,
 			record_hits_enabled,
+			session_id,
+			session_mode,
 			ranking_override,
 			retrieval_sources_policy,
 			expansion_mode,
@@ -83,6 +132,9 @@ impl ElfService {
 			project_context_description,
 			allowed_scopes,
 			policies,
+			warnings,
+			hook_runs: Vec::new(),
+			cache_request,
 		})
 	}
 }
@@ -1,22 +1,49 @@
-use crate::search::{
-	ElfService, RawSearchPath, Result, SearchRawPlannedResponse, SearchRequest, SearchResponse,
+use crate::{
+	Error, SearchV2Delivery, SearchV2Mode, SearchV2Request,
+	search::{
+		ElfService, RawSearchPath, Result, SearchRawPlannedResponse, SearchRequest, SearchResponse,
+	},
 };
 
 impl ElfService {
 	/// Runs the quick raw-search path and returns ranked items without a query plan.
 	pub async fn search_raw_quick(&self, req: SearchRequest) -> Result<SearchResponse> {
-		self.execute_search_raw_path(req, RawSearchPath::Quick).await.map(|response| {
-			SearchResponse {
-				trace_id: response.trace_id,
-				items: response.items,
-				trajectory_summary: response.trajectory_summary,
-			}
+		let response = self
+			.search_v2(SearchV2Request {
+				search: req,
+				mode: SearchV2Mode::QuickFind,
+				delivery: SearchV2Delivery::Raw,
+			})
+			.await?;
+
+		Ok(SearchResponse {
+			trace_id: response.trace_id,
+			items: response.items,
+			trajectory_summary: response.trajectory_summary,
+			warnings: response.warnings,
 		})
 	}
 
 	/// Runs the planned raw-search path and returns ranked items plus a query plan.
 	pub async fn search_raw_planned(&self, req: SearchRequest) -> Result<SearchRawPlannedResponse> {
-		self.execute_search_raw_path(req, RawSearchPath::Planned).await
+		let response = self
+			.search_v2(SearchV2Request {
+				search: req,
+				mode: SearchV2Mode::PlannedSearch,
+				delivery: SearchV2Delivery::Raw,
+			})
+			.await?;
+		let query_plan = response.query_plan.ok_or_else(|| Error::Storage {
+			message: "Planned search response is missing query_plan.".to_string(),
+		})?;
+
+		Ok(SearchRawPlannedResponse {
+			trace_id: response.trace_id,
+			items: response.items,
+			trajectory_summary: response.trajectory_summary,
+			warnings: response.warnings,
+			query_plan,
+		})
 	}
 
 	/// Runs the default raw-search path and returns ranked items.
@@ -25,6 +52,32 @@ impl ElfService {
 			trace_id: response.trace_id,
 			items: response.items,
 			trajectory_summary: response.trajectory_summary,
+			warnings: response.warnings,
 		})
 	}
+
+	pub(crate) async fn search_raw_for_mode(
+		&self,
+		req: SearchRequest,
+		mode: SearchV2Mode,
+	) -> Result<SearchRawPlannedResponse> {
+		self.execute_search_raw_path(req, raw_search_path(mode), None).await
+	}
+
+	/// Runs raw search with retrieval restricted to one scope the read profile allows.
+	pub(crate) async fn search_raw_for_mode_in_scope(
+		&self,
+		req: SearchRequest,
+		mode: SearchV2Mode,
+		scope: &str,
+	) -> Result<SearchRawPlannedResponse> {
+		self.execute_search_raw_path(req, raw_search_path(mode), Some(scope)).await
+	}
+}
+
+fn raw_search_path(mode: SearchV2Mode) -> RawSearchPath {
+	match mode {
+		SearchV2Mode::QuickFind => RawSearchPath::Quick,
+		SearchV2Mode::PlannedSearch => RawSearchPath::Planned,
+	}
 }
@@ -1,16 +1,52 @@
-use crate::search::{
-	self, DynamicGateSummary, ElfService, ExpansionMode, FinishSearchArgs, HashMap,
-	MaybeDynamicSearchArgs, RawSearchExecutionContext, RawSearchPath, Result,
-	SearchRawPlannedResponse, SearchRequest, SearchRetrievalArgs,
+use tracing::{Span, field};
+
+use crate::{
+	Error,
+	search::{
+		self, DEGRADED_MODE_BM25_ONLY, DynamicGateSummary, ElfService, ExpansionMode, Filter,
+		FinishSearchArgs, HashMap, MaybeDynamicSearchArgs, RawSearchExecutionContext,
+		RawSearchPath, Result, SearchHookStage, SearchRawPlannedResponse, SearchRequest,
+		SearchRetrievalArgs, SearchStageContext, SearchWarning, ranking,
+	},
 };
 
 impl ElfService {
+	#[tracing::instrument(name = "search", skip_all, fields(path = ?path, trace_id = field::Empty))]
 	pub(in crate::search) async fn execute_search_raw_path(
 		&self,
 		req: SearchRequest,
 		path: RawSearchPath,
+		scope: Option<&str>,
 	) -> Result<SearchRawPlannedResponse> {
-		let context = self.prepare_raw_search_execution(req, path)?;
+		let mut context = self.prepare_raw_search_execution(req, path, scope)?;
+
+		Span::current().record("trace_id", field::display(context.trace_id));
+
+		let cache_key = self.search_result_cache_key(&context).await;
+		let mut cache_warnings = Vec::new();
+
+		if let Some(key) = cache_key.as_deref()
+			&& let Some(response) = self.read_search_result_cache(key, &mut cache_warnings).await
+		{
+			return Ok(response);
+		}
+
+		let hook_ctx = SearchStageContext {
+			stage: SearchHookStage::PreRetrieval,
+			trace_id: context.trace_id,
+			tenant_id: context.tenant_id.as_str(),
+			project_id: context.project_id.as_str(),
+			agent_id: context.agent_id.as_str(),
+			read_profile: context.read_profile.as_str(),
+			query: context.query.as_str(),
+			candidates: &[],
+		};
+		let mut hook_runs = Vec::new();
+
+		self.run_search_hooks(&hook_ctx, SearchHookStage::PreRetrieval, Vec::new, &mut hook_runs)
+			.await;
+
+		context.hook_runs = hook_runs;
 
 		if context.allowed_scopes.is_empty() {
 			return self.execute_search_raw_no_allowed_scopes(&context, path).await;
@@ -18,8 +54,17 @@ impl ElfService {
 
 		let dynamic_gate_enabled =
 			path == RawSearchPath::Planned && context.expansion_mode == ExpansionMode::Dynamic;
+		let mut response = self
+			.execute_search_raw_with_allowed_scopes(&context, path, dynamic_gate_enabled)
+			.await?;
+
+		if let Some(key) = cache_key.as_deref() {
+			self.store_search_result_cache(key, &response).await;
+		}
 
-		self.execute_search_raw_with_allowed_scopes(&context, path, dynamic_gate_enabled).await
+		response.warnings.extend(cache_warnings);
+
+		Ok(response)
 	}
 
 	async fn execute_search_raw_no_allowed_scopes(
@@ -46,11 +91,16 @@ impl ElfService {
 				recursive_retrieval: None,
 				top_k: context.top_k,
 				record_hits_enabled: context.record_hits_enabled,
+				session: context.session(),
 				ranking_override: context.ranking_override.clone(),
 				payload_level: context.payload_level,
+				max_result_tokens: context.max_result_tokens,
 				filter: context.filter.as_ref(),
+				payload_filters: &context.payload_filters,
 				requested_candidate_k: context.requested_candidate_k,
 				effective_candidate_k: context.effective_candidate_k,
+				hook_runs: context.hook_runs.clone(),
+				degraded_mode: None,
 			})
 			.await?;
 
@@ -74,13 +124,14 @@ impl ElfService {
 			context.project_id.as_str(),
 			context.agent_id.as_str(),
 			&context.allowed_scopes,
+			&context.payload_filters,
 		);
 		let retrieval_candidate_k = if context.filter.is_some() {
 			context.effective_candidate_k
 		} else {
 			context.candidate_k
 		};
-		let (baseline_vector, early_response, dynamic_gate) = self
+		let dynamic = self
 			.maybe_finish_dynamic_search(MaybeDynamicSearchArgs {
 				path,
 				enabled: dynamic_gate_enabled,
@@ -95,16 +146,27 @@ impl ElfService {
 				project_context_description: context.project_context_description.as_deref(),
 				filter: &filter,
 				service_filter: context.filter.as_ref(),
+				payload_filters: &context.payload_filters,
 				candidate_k: retrieval_candidate_k,
 				requested_candidate_k: context.requested_candidate_k,
 				effective_candidate_k: context.effective_candidate_k,
 				top_k: context.top_k,
 				record_hits_enabled: context.record_hits_enabled,
+				session: context.session(),
 				ranking_override: context.ranking_override.as_ref(),
 				retrieval_sources_policy: &context.retrieval_sources_policy,
 				payload_level: context.payload_level,
+				max_result_tokens: context.max_result_tokens,
+				hook_runs: &context.hook_runs,
 			})
-			.await?;
+			.await;
+		let (baseline_embedding, early_response, dynamic_gate) = match dynamic {
+			Ok(dynamic) => dynamic,
+			Err(err) =>
+				return self
+					.execute_search_raw_degraded(context, path, &filter, retrieval_candidate_k, err)
+					.await,
+		};
 
 		if let Some(response) = early_response {
 			return Ok(self.build_raw_planned_response(
@@ -116,23 +178,30 @@ impl ElfService {
 			));
 		}
 
-		let retrieval = self
+		let retrieval = match self
 			.retrieve_search_candidates(SearchRetrievalArgs {
 				query: context.query.as_str(),
 				expansion_mode: context.expansion_mode,
 				project_context_description: context.project_context_description.as_deref(),
 				filter: &filter,
 				candidate_k: retrieval_candidate_k,
-				baseline_vector: baseline_vector.as_ref(),
+				baseline_embedding: baseline_embedding.as_ref(),
 				tenant_id: context.tenant_id.as_str(),
 				project_id: context.project_id.as_str(),
 				agent_id: context.agent_id.as_str(),
 				allowed_scopes: &context.allowed_scopes,
 				retrieval_sources_policy: &context.retrieval_sources_policy,
 			})
-			.await?;
+			.await
+		{
+			Ok(retrieval) => retrieval,
+			Err(err) =>
+				return self
+					.execute_search_raw_degraded(context, path, &filter, retrieval_candidate_k, err)
+					.await,
+		};
 		let expanded_queries = retrieval.expanded_queries.clone();
-		let response = self
+		let mut response = self
 			.finish_search(FinishSearchArgs {
 				path,
 				trace_id: context.trace_id,
@@ -150,14 +219,97 @@ impl ElfService {
 				recursive_retrieval: retrieval.recursive,
 				top_k: context.top_k,
 				record_hits_enabled: context.record_hits_enabled,
+				session: context.session(),
 				ranking_override: context.ranking_override.clone(),
 				payload_level: context.payload_level,
+				max_result_tokens: context.max_result_tokens,
 				filter: context.filter.as_ref(),
+				payload_filters: &context.payload_filters,
 				requested_candidate_k: context.requested_candidate_k,
 				effective_candidate_k: context.effective_candidate_k,
+				hook_runs: context.hook_runs.clone(),
+				degraded_mode: None,
 			})
 			.await?;
 
+		response.warnings.extend(retrieval.warnings);
+
 		Ok(self.build_raw_planned_response(context, path, response, expanded_queries, dynamic_gate))
 	}
+
+	/// Serves the search with BM25-only retrieval and no rerank when query embedding failed and
+	/// `search.degraded.bm25_fallback` is enabled; otherwise returns `err` unchanged.
+	async fn execute_search_raw_degraded(
+		&self,
+		context: &RawSearchExecutionContext,
+		path: RawSearchPath,
+		filter: &Filter,
+		candidate_k: u32,
+		err: Error,
+	) -> Result<SearchRawPlannedResponse> {
+		let Error::Provider { message } = err else {
+			return Err(err);
+		};
+
+		if !self.cfg.search.degraded.as_ref().is_some_and(|degraded| degraded.bm25_fallback) {
+			return Err(Error::Provider { message });
+		}
+
+		tracing::warn!(
+			trace_id = %context.trace_id,
+			error = %message,
+			"Query embedding failed. Serving BM25-only search."
+		);
+
+		let points = self.run_bm25_query(context.query.as_str(), filter, candidate_k).await?;
+		let candidates = ranking::collect_chunk_candidates(
+			&points,
+			self.cfg.search.prefilter.max_candidates,
+			candidate_k,
+		);
+		let expanded_queries = vec![context.query.clone()];
+		let mut response = self
+			.finish_search(FinishSearchArgs {
+				path,
+				trace_id: context.trace_id,
+				query: context.query.as_str(),
+				tenant_id: context.tenant_id.as_str(),
+				project_id: context.project_id.as_str(),
+				agent_id: context.agent_id.as_str(),
+				token_id: context.token_id.as_deref(),
+				read_profile: context.read_profile.as_str(),
+				allowed_scopes: &context.allowed_scopes,
+				expanded_queries: expanded_queries.clone(),
+				expansion_mode: ExpansionMode::Off,
+				candidates,
+				structured_matches: HashMap::new(),
+				recursive_retrieval: None,
+				top_k: context.top_k,
+				record_hits_enabled: context.record_hits_enabled,
+				session: context.session(),
+				ranking_override: context.ranking_override.clone(),
+				payload_level: context.payload_level,
+				max_result_tokens: context.max_result_tokens,
+				filter: context.filter.as_ref(),
+				payload_filters: &context.payload_filters,
+				requested_candidate_k: context.requested_candidate_k,
+				effective_candidate_k: context.effective_candidate_k,
+				hook_runs: context.hook_runs.clone(),
+				degraded_mode: Some(DEGRADED_MODE_BM25_ONLY),
+			})
+			.await?;
+
+		response.warnings.push(SearchWarning::DegradedMode {
+			mode: DEGRADED_MODE_BM25_ONLY.to_string(),
+			reason: message,
+		});
+
+		Ok(self.build_raw_planned_response(
+			context,
+			path,
+			response,
+			expanded_queries,
+			DynamicGateSummary::default(),
+		))
+	}
 }
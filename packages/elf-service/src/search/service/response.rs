@@ -30,10 +30,15 @@ impl ElfService {
 			dynamic_gate,
 		});
 
+		let mut warnings = context.warnings.clone();
+
+		warnings.extend(response.warnings);
+
 		SearchRawPlannedResponse {
 			trace_id: response.trace_id,
 			items: response.items,
 			trajectory_summary: response.trajectory_summary,
+			warnings,
 			query_plan,
 		}
 	}
@@ -0,0 +1,212 @@
+use crate::{
+	Error,
+	search::{
+		self, CacheKind, Duration, ElfService, OffsetDateTime, RawSearchExecutionContext,
+		SearchRawPlannedResponse, SearchWarning, ranking,
+	},
+};
+
+impl ElfService {
+	/// Builds the whole-result cache key for a search, or returns `None` when the result cache is
+	/// off or the search has side effects (hit recording, session ranking) that must run.
+	pub(in crate::search) async fn search_result_cache_key(
+		&self,
+		context: &RawSearchExecutionContext,
+	) -> Option<String> {
+		if !self.search_cache_enabled()
+			|| !self.cfg.search.cache.results.as_ref().is_some_and(|results| results.enabled)
+			|| context.record_hits_enabled
+			|| context.session_id.is_some()
+			|| context.allowed_scopes.is_empty()
+		{
+			return None;
+		}
+
+		let watermark = match search::fetch_result_watermark(
+			&self.db.pool,
+			context.tenant_id.as_str(),
+			context.project_id.as_str(),
+			&context.allowed_scopes,
+		)
+		.await
+		{
+			Ok(watermark) => watermark,
+			Err(err) => {
+				tracing::warn!(
+					error = %err,
+					cache_kind = CacheKind::SearchResult.as_str(),
+					"Cache watermark read failed."
+				);
+
+				return None;
+			},
+		};
+		let mut request = context.cache_request.clone();
+
+		request["allowed_scopes"] = serde_json::json!(context.allowed_scopes);
+
+		let key = serde_json::to_value(&watermark)
+			.map_err(|err| Error::Storage {
+				message: format!("Failed to encode cache watermark: {err}"),
+			})
+			.and_then(|watermark| {
+				ranking::build_search_result_cache_key(
+					&request,
+					context.policies.policy_id.as_str(),
+					&watermark,
+				)
+			});
+
+		match key {
+			Ok(key) => Some(key),
+			Err(err) => {
+				tracing::warn!(
+					error = %err,
+					cache_kind = CacheKind::SearchResult.as_str(),
+					"Cache key build failed."
+				);
+
+				None
+			},
+		}
+	}
+
+	pub(in crate::search) async fn read_search_result_cache(
+		&self,
+		key: &str,
+		warnings: &mut Vec<SearchWarning>,
+	) -> Option<SearchRawPlannedResponse> {
+		let now = OffsetDateTime::now_utc();
+
+		match search::fetch_cache_payload(&self.db.pool, CacheKind::SearchResult, key, now).await {
+			Ok(Some(payload)) => {
+				tracing::info!(
+					cache_kind = CacheKind::SearchResult.as_str(),
+					cache_key_prefix = ranking::cache_key_prefix(key),
+					hit = true,
+					payload_size = payload.size_bytes,
+					"Cache hit."
+				);
+
+				match serde_json::from_value(payload.value) {
+					Ok(response) => Some(response),
+					Err(err) => {
+						tracing::warn!(
+							error = %err,
+							cache_kind = CacheKind::SearchResult.as_str(),
+							cache_key_prefix = ranking::cache_key_prefix(key),
+							"Cache payload decode failed."
+						);
+
+						None
+					},
+				}
+			},
+			Ok(None) => {
+				tracing::info!(
+					cache_kind = CacheKind::SearchResult.as_str(),
+					cache_key_prefix = ranking::cache_key_prefix(key),
+					hit = false,
+					payload_size = 0_u64,
+					"Cache miss."
+				);
+
+				None
+			},
+			Err(err) => {
+				tracing::warn!(
+					error = %err,
+					cache_kind = CacheKind::SearchResult.as_str(),
+					cache_key_prefix = ranking::cache_key_prefix(key),
+					"Cache read failed."
+				);
+
+				warnings.push(SearchWarning::CacheUnavailable {
+					cache_kind: CacheKind::SearchResult.as_str().to_string(),
+				});
+
+				None
+			},
+		}
+	}
+
+	/// Stores a finished search result. Degraded results are skipped so that a recovered provider
+	/// is used on the next identical search.
+	pub(in crate::search) async fn store_search_result_cache(
+		&self,
+		key: &str,
+		response: &SearchRawPlannedResponse,
+	) {
+		let Some(results_cfg) = self.cfg.search.cache.results.as_ref() else {
+			return;
+		};
+
+		if response.warnings.iter().any(|warning| {
+			matches!(
+				warning,
+				SearchWarning::DegradedMode { .. }
+					| SearchWarning::RerankDegraded { .. }
+					| SearchWarning::CacheUnavailable { .. }
+			)
+		}) {
+			return;
+		}
+
+		let payload_json = match serde_json::to_value(response) {
+			Ok(value) => value,
+			Err(err) => {
+				tracing::warn!(
+					error = %err,
+					cache_kind = CacheKind::SearchResult.as_str(),
+					cache_key_prefix = ranking::cache_key_prefix(key),
+					"Cache payload encode failed."
+				);
+
+				return;
+			},
+		};
+		let stored_at = OffsetDateTime::now_utc();
+		let expires_at = stored_at + Duration::seconds(results_cfg.ttl_seconds);
+
+		match search::store_cache_payload(
+			&self.db.pool,
+			CacheKind::SearchResult,
+			key,
+			payload_json,
+			stored_at,
+			expires_at,
+			self.cfg.search.cache.max_payload_bytes,
+		)
+		.await
+		{
+			Ok(Some(payload_size)) => {
+				tracing::info!(
+					cache_kind = CacheKind::SearchResult.as_str(),
+					cache_key_prefix = ranking::cache_key_prefix(key),
+					hit = false,
+					payload_size,
+					ttl_seconds = results_cfg.ttl_seconds,
+					"Cache stored."
+				);
+			},
+			Ok(None) => {
+				tracing::warn!(
+					cache_kind = CacheKind::SearchResult.as_str(),
+					cache_key_prefix = ranking::cache_key_prefix(key),
+					hit = false,
+					payload_size = 0_u64,
+					ttl_seconds = results_cfg.ttl_seconds,
+					"Cache payload skipped due to size."
+				);
+			},
+			Err(err) => {
+				tracing::warn!(
+					error = %err,
+					cache_kind = CacheKind::SearchResult.as_str(),
+					cache_key_prefix = ranking::cache_key_prefix(key),
+					"Cache write failed."
+				);
+			},
+		}
+	}
+}
@@ -2,6 +2,7 @@ pub(super) const SEARCH_RETRIEVAL_TRAJECTORY_SCHEMA_V1: &str = "search_retrieval
 pub(super) const SEARCH_FILTER_IMPACT_SCHEMA_V1: &str = "search_filter_impact/v1";
 pub(super) const RECENT_TRACES_SCHEMA_V1: &str = "elf.recent_traces/v1";
 pub(super) const TRACE_BUNDLE_SCHEMA_V1: &str = "elf.trace_bundle/v1";
+pub(super) const TRACE_ARTIFACT_SCHEMA_V1: &str = "elf.trace_artifact/v1";
 pub(super) const MAX_RECENT_TRACES_LIMIT: u32 = 200;
 pub(super) const DEFAULT_RECENT_TRACES_LIMIT: u32 = 50;
 pub(super) const DEFAULT_BOUNDED_STAGE_ITEMS_LIMIT: u32 = 64;
@@ -9,23 +9,24 @@ mod trace;
 pub(super) use self::{
 	cache::{
 		CacheKind, CachePayload, ExpansionCachePayload, ExpansionOutput, RerankCacheItem,
-		RerankCachePayload,
+		RerankCachePayload, SearchResultWatermark,
 	},
 	finish::{
 		BuildQueryPlanArgs, BuildSearchItemArgs, BuildTraceArgs, FinishSearchArgs,
 		FinishSearchPolicies, FinishSearchScoringResult, QueryPlanStagesArgs,
-		RawSearchExecutionContext,
+		RawSearchExecutionContext, SearchSessionRanking,
 	},
 	modes::{ExpansionMode, RawSearchPath, RetrievalSourceKind},
 	records::{
-		BestChunkForNoteRow, ChunkMeta, ChunkRow, ChunkSnippet, NoteMeta, NoteVectorRow,
-		SearchExplainTraceRow, SearchRecentTraceRow, SearchRelationContextRow, SearchTraceItemRow,
-		SearchTraceRow, StructuredFieldHitRow, TraceCandidateSnapshotRow,
+		BestChunkForNoteRow, ChunkMeta, ChunkRow, ChunkSnippet, NoteFeedbackCounts, NoteField,
+		NoteMeta, NoteValue, NoteVectorRow, SearchExplainTraceRow, SearchRecentTraceRow,
+		SearchRelationContextRow, SearchTraceItemRow, SearchTraceRow, StructuredFieldHitRow,
+		TraceCandidateSnapshotRow,
 	},
 	retrieval::{
 		ChunkCandidate, DynamicGateSummary, FieldHit, MaybeDynamicSearchArgs, QueryEmbedding,
 		RecursiveRetrievalArgs, RecursiveRetrievalResult, RerankCacheCandidate,
-		RetrievalSourceCandidates, SearchRetrievalArgs, SearchRetrievalResult,
+		RetrievalSourceCandidates, ScopedQueryVector, SearchRetrievalArgs, SearchRetrievalResult,
 		StructuredFieldHitArgs, StructuredFieldRetrievalArgs, StructuredFieldRetrievalResult,
 	},
 	scoring::{
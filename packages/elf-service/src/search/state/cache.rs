@@ -1,4 +1,4 @@
-use crate::search::{Deserialize, OffsetDateTime, Serialize, Uuid, Value};
+use crate::search::{Deserialize, FromRow, OffsetDateTime, Serialize, Uuid, Value};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub(in crate::search) struct ExpansionCachePayload {
@@ -22,6 +22,15 @@ pub(in crate::search) struct RerankCachePayload {
 	pub(in crate::search) items: Vec<RerankCacheItem>,
 }
 
+/// Freshness state of the spaces a search can see: the summed generations of their
+/// `search_result_watermarks` rows, which every note write, index update, grant change, and
+/// feedback write advances.
+#[derive(Clone, Debug, FromRow, Serialize)]
+pub(in crate::search) struct SearchResultWatermark {
+	pub(in crate::search) generation: i64,
+	pub(in crate::search) updated_at: Option<OffsetDateTime>,
+}
+
 #[derive(Clone, Debug)]
 pub(in crate::search) struct CachePayload {
 	pub(in crate::search) value: Value,
@@ -32,12 +41,14 @@ pub(in crate::search) struct CachePayload {
 pub(in crate::search) enum CacheKind {
 	Expansion,
 	Rerank,
+	SearchResult,
 }
 impl CacheKind {
 	pub(in crate::search) fn as_str(self) -> &'static str {
 		match self {
 			Self::Expansion => "expansion",
 			Self::Rerank => "rerank",
+			Self::SearchResult => "search_result",
 		}
 	}
 }
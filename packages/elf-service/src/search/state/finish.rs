@@ -4,7 +4,9 @@ use crate::search::{
 	QueryPlanRetrievalStage, QueryPlanRewrite, RankingRequestOverride, RawSearchPath,
 	RecursiveRetrievalResult, ResolvedBlendPolicy, ResolvedDiversityPolicy,
 	ResolvedRetrievalSourcesPolicy, ScoredChunk, SearchExplainRelationContext, SearchFilter,
-	SearchFilterImpact, TraceCandidateRecord, Uuid, Value,
+	SearchFilterImpact, SearchHookRun, SearchHookStage, SearchPayloadFilters,
+	SearchRankingSensitivity, SearchStageContext, SearchWarning, SessionHitKey, SessionRankingMode,
+	TraceCandidateRecord, Uuid, Value,
 };
 
 pub(in crate::search) struct FinishSearchArgs<'a> {
@@ -24,11 +26,46 @@ pub(in crate::search) struct FinishSearchArgs<'a> {
 	pub(in crate::search) recursive_retrieval: Option<RecursiveRetrievalResult>,
 	pub(in crate::search) top_k: u32,
 	pub(in crate::search) record_hits_enabled: bool,
+	pub(in crate::search) session: Option<SearchSessionRanking<'a>>,
 	pub(in crate::search) ranking_override: Option<RankingRequestOverride>,
 	pub(in crate::search) filter: Option<&'a SearchFilter>,
+	pub(in crate::search) payload_filters: &'a SearchPayloadFilters,
 	pub(in crate::search) requested_candidate_k: u32,
 	pub(in crate::search) effective_candidate_k: u32,
 	pub(in crate::search) payload_level: PayloadLevel,
+	pub(in crate::search) max_result_tokens: Option<u32>,
+	pub(in crate::search) hook_runs: Vec<SearchHookRun>,
+	pub(in crate::search) degraded_mode: Option<&'static str>,
+}
+impl<'a> FinishSearchArgs<'a> {
+	pub(in crate::search) fn session_hit_key(&self) -> Option<SessionHitKey<'a>> {
+		self.session.map(|session| SessionHitKey {
+			tenant_id: self.tenant_id,
+			project_id: self.project_id,
+			agent_id: self.agent_id,
+			session_id: session.session_id,
+		})
+	}
+
+	pub(in crate::search) fn hook_context(&self) -> SearchStageContext<'a> {
+		SearchStageContext {
+			stage: SearchHookStage::PostRetrieval,
+			trace_id: self.trace_id,
+			tenant_id: self.tenant_id,
+			project_id: self.project_id,
+			agent_id: self.agent_id,
+			read_profile: self.read_profile,
+			query: self.query,
+			candidates: &[],
+		}
+	}
+}
+
+/// Caller session whose earlier retrievals drive the within-session term.
+#[derive(Clone, Copy, Debug)]
+pub(in crate::search) struct SearchSessionRanking<'a> {
+	pub(in crate::search) session_id: &'a str,
+	pub(in crate::search) mode: SessionRankingMode,
 }
 
 pub(in crate::search) struct FinishSearchPolicies {
@@ -51,6 +88,7 @@ pub(in crate::search) struct FinishSearchScoringResult {
 	pub(in crate::search) selected_results: Vec<ScoredChunk>,
 	pub(in crate::search) diversity_decisions: HashMap<Uuid, DiversityDecision>,
 	pub(in crate::search) selected_count: usize,
+	pub(in crate::search) warnings: Vec<SearchWarning>,
 }
 
 pub(in crate::search) struct BuildTraceArgs<'a> {
@@ -86,6 +124,9 @@ pub(in crate::search) struct BuildTraceArgs<'a> {
 	pub(in crate::search) ranking_override: &'a Option<RankingRequestOverride>,
 	pub(in crate::search) filter_impact: Option<SearchFilterImpact>,
 	pub(in crate::search) payload_level: PayloadLevel,
+	pub(in crate::search) max_result_tokens: Option<u32>,
+	pub(in crate::search) hook_runs: &'a [SearchHookRun],
+	pub(in crate::search) degraded_mode: Option<&'static str>,
 }
 
 pub(in crate::search) struct BuildQueryPlanArgs<'a> {
@@ -118,8 +159,12 @@ pub(in crate::search) struct RawSearchExecutionContext {
 	pub(in crate::search) query: String,
 	pub(in crate::search) read_profile: String,
 	pub(in crate::search) payload_level: PayloadLevel,
+	pub(in crate::search) max_result_tokens: Option<u32>,
 	pub(in crate::search) filter: Option<SearchFilter>,
+	pub(in crate::search) payload_filters: SearchPayloadFilters,
 	pub(in crate::search) record_hits_enabled: bool,
+	pub(in crate::search) session_id: Option<String>,
+	pub(in crate::search) session_mode: SessionRankingMode,
 	pub(in crate::search) ranking_override: Option<RankingRequestOverride>,
 	pub(in crate::search) retrieval_sources_policy: ResolvedRetrievalSourcesPolicy,
 	pub(in crate::search) expansion_mode: ExpansionMode,
@@ -127,6 +172,17 @@ pub(in crate::search) struct RawSearchExecutionContext {
 	pub(in crate::search) project_context_description: Option<String>,
 	pub(in crate::search) allowed_scopes: Vec<String>,
 	pub(in crate::search) policies: FinishSearchPolicies,
+	pub(in crate::search) warnings: Vec<SearchWarning>,
+	pub(in crate::search) hook_runs: Vec<SearchHookRun>,
+	pub(in crate::search) cache_request: Value,
+}
+
+impl RawSearchExecutionContext {
+	pub(in crate::search) fn session(&self) -> Option<SearchSessionRanking<'_>> {
+		self.session_id
+			.as_deref()
+			.map(|session_id| SearchSessionRanking { session_id, mode: self.session_mode })
+	}
 }
 
 pub(in crate::search) struct QueryPlanStagesArgs<'a> {
@@ -152,4 +208,5 @@ pub(in crate::search) struct BuildSearchItemArgs<'a> {
 	pub(in crate::search) relation_contexts: &'a HashMap<Uuid, Vec<SearchExplainRelationContext>>,
 	pub(in crate::search) scored_chunk: ScoredChunk,
 	pub(in crate::search) rank: u32,
+	pub(in crate::search) sensitivity: Vec<SearchRankingSensitivity>,
 }
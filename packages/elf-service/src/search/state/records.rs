@@ -1,3 +1,5 @@
+use time::Date;
+
 use crate::search::{FromRow, OffsetDateTime, Uuid, Value};
 
 #[derive(Clone, Debug)]
@@ -15,6 +17,38 @@ pub(in crate::search) struct NoteMeta {
 	pub(in crate::search) embedding_version: String,
 	pub(in crate::search) hit_count: i64,
 	pub(in crate::search) last_hit_at: Option<OffsetDateTime>,
+	pub(in crate::search) evidence_coverage: Option<f32>,
+	pub(in crate::search) session_hit_count: i64,
+	pub(in crate::search) feedback: NoteFeedbackCounts,
+	pub(in crate::search) values: Vec<NoteValue>,
+	pub(in crate::search) fields: Vec<NoteField>,
+	pub(in crate::search) pinned: bool,
+}
+
+/// Aggregated result feedback recorded against one note.
+#[derive(Clone, Copy, Debug, Default)]
+pub(in crate::search) struct NoteFeedbackCounts {
+	pub(in crate::search) useful: i64,
+	pub(in crate::search) not_useful: i64,
+	pub(in crate::search) wrong: i64,
+}
+impl NoteFeedbackCounts {
+	pub(in crate::search) fn is_empty(self) -> bool {
+		self.useful == 0 && self.not_useful == 0 && self.wrong == 0
+	}
+}
+
+#[derive(Clone, Debug)]
+pub(in crate::search) struct NoteField {
+	pub(in crate::search) field_kind: String,
+	pub(in crate::search) text: String,
+}
+
+#[derive(Clone, Debug)]
+pub(in crate::search) struct NoteValue {
+	pub(in crate::search) unit: Option<String>,
+	pub(in crate::search) numeric_value: Option<f64>,
+	pub(in crate::search) date_value: Option<Date>,
 }
 
 #[derive(Clone, Debug, FromRow)]
@@ -145,4 +179,5 @@ pub(in crate::search) struct ChunkSnippet {
 	pub(in crate::search) snippet: String,
 	pub(in crate::search) retrieval_rank: u32,
 	pub(in crate::search) retrieval_score: Option<f32>,
+	pub(in crate::search) embedding_projection: Option<String>,
 }
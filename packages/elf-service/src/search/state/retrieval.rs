@@ -1,6 +1,7 @@
 use crate::search::{
 	ExpansionMode, Filter, HashMap, OffsetDateTime, PayloadLevel, RankingRequestOverride,
-	RawSearchPath, ResolvedRetrievalSourcesPolicy, RetrievalSourceKind, SearchFilter, Uuid,
+	RawSearchPath, ResolvedRetrievalSourcesPolicy, RetrievalSourceKind, SearchFilter,
+	SearchHookRun, SearchPayloadFilters, SearchSessionRanking, SearchWarning, Uuid,
 };
 
 pub(in crate::search) struct MaybeDynamicSearchArgs<'a> {
@@ -17,14 +18,18 @@ pub(in crate::search) struct MaybeDynamicSearchArgs<'a> {
 	pub(in crate::search) project_context_description: Option<&'a str>,
 	pub(in crate::search) filter: &'a Filter,
 	pub(in crate::search) service_filter: Option<&'a SearchFilter>,
+	pub(in crate::search) payload_filters: &'a SearchPayloadFilters,
 	pub(in crate::search) candidate_k: u32,
 	pub(in crate::search) requested_candidate_k: u32,
 	pub(in crate::search) effective_candidate_k: u32,
 	pub(in crate::search) top_k: u32,
 	pub(in crate::search) record_hits_enabled: bool,
+	pub(in crate::search) session: Option<SearchSessionRanking<'a>>,
 	pub(in crate::search) ranking_override: Option<&'a RankingRequestOverride>,
 	pub(in crate::search) retrieval_sources_policy: &'a ResolvedRetrievalSourcesPolicy,
 	pub(in crate::search) payload_level: PayloadLevel,
+	pub(in crate::search) max_result_tokens: Option<u32>,
+	pub(in crate::search) hook_runs: &'a [SearchHookRun],
 }
 
 pub(in crate::search) struct SearchRetrievalArgs<'a> {
@@ -33,7 +38,7 @@ pub(in crate::search) struct SearchRetrievalArgs<'a> {
 	pub(in crate::search) project_context_description: Option<&'a str>,
 	pub(in crate::search) filter: &'a Filter,
 	pub(in crate::search) candidate_k: u32,
-	pub(in crate::search) baseline_vector: Option<&'a Vec<f32>>,
+	pub(in crate::search) baseline_embedding: Option<&'a QueryEmbedding>,
 	pub(in crate::search) tenant_id: &'a str,
 	pub(in crate::search) project_id: &'a str,
 	pub(in crate::search) agent_id: &'a str,
@@ -42,8 +47,7 @@ pub(in crate::search) struct SearchRetrievalArgs<'a> {
 }
 
 pub(in crate::search) struct RecursiveRetrievalArgs<'a> {
-	pub(in crate::search) query: &'a str,
-	pub(in crate::search) query_vec: &'a [f32],
+	pub(in crate::search) query_embedding: &'a QueryEmbedding,
 	pub(in crate::search) filter: &'a Filter,
 	pub(in crate::search) candidate_k: u32,
 	pub(in crate::search) retrieval_sources_policy: &'a ResolvedRetrievalSourcesPolicy,
@@ -55,6 +59,7 @@ pub(in crate::search) struct SearchRetrievalResult {
 	pub(in crate::search) candidates: Vec<ChunkCandidate>,
 	pub(in crate::search) structured_matches: HashMap<Uuid, Vec<String>>,
 	pub(in crate::search) recursive: Option<RecursiveRetrievalResult>,
+	pub(in crate::search) warnings: Vec<SearchWarning>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -68,6 +73,8 @@ pub(in crate::search) struct RecursiveRetrievalResult {
 	pub(in crate::search) candidates_added: usize,
 	pub(in crate::search) total_queries: u32,
 	pub(in crate::search) stop_reason: Option<String>,
+	pub(in crate::search) elapsed_ms: u64,
+	pub(in crate::search) round_elapsed_ms: Vec<u64>,
 	pub(in crate::search) candidates: Vec<ChunkCandidate>,
 }
 
@@ -75,6 +82,14 @@ pub(in crate::search) struct RecursiveRetrievalResult {
 pub(in crate::search) struct QueryEmbedding {
 	pub(in crate::search) text: String,
 	pub(in crate::search) vector: Vec<f32>,
+	pub(in crate::search) scoped_vectors: Vec<ScopedQueryVector>,
+}
+
+/// Query vector produced by a per-scope embedding provider.
+#[derive(Clone, Debug)]
+pub(in crate::search) struct ScopedQueryVector {
+	pub(in crate::search) embedding_version: String,
+	pub(in crate::search) vector: Vec<f32>,
 }
 
 #[derive(Clone, Debug)]
@@ -87,6 +102,8 @@ pub(in crate::search) struct ChunkCandidate {
 	pub(in crate::search) scope: Option<String>,
 	pub(in crate::search) updated_at: Option<OffsetDateTime>,
 	pub(in crate::search) embedding_version: Option<String>,
+	/// Projection mode recorded on the Qdrant point, when its vector was projected.
+	pub(in crate::search) embedding_projection: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -100,7 +117,7 @@ pub(in crate::search) struct StructuredFieldRetrievalArgs<'a> {
 	pub(in crate::search) project_id: &'a str,
 	pub(in crate::search) agent_id: &'a str,
 	pub(in crate::search) allowed_scopes: &'a [String],
-	pub(in crate::search) query_vec: &'a [f32],
+	pub(in crate::search) query_embedding: &'a QueryEmbedding,
 	pub(in crate::search) candidate_k: u32,
 	pub(in crate::search) now: OffsetDateTime,
 }
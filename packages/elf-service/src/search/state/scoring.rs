@@ -1,5 +1,6 @@
 use crate::search::{
-	ChunkSnippet, Config, HashMap, OffsetDateTime, ResolvedBlendPolicy, SearchCache, Uuid,
+	ChunkSnippet, Config, HashMap, NoteFeedbackCounts, OffsetDateTime, ResolvedBlendPolicy,
+	SearchCache, SessionRankingMode, Uuid,
 };
 
 pub(in crate::search) struct ScoreSnippetArgs<'a, 'k> {
@@ -12,6 +13,7 @@ pub(in crate::search) struct ScoreSnippetArgs<'a, 'k> {
 	pub(in crate::search) now: OffsetDateTime,
 	pub(in crate::search) candidate_count: usize,
 	pub(in crate::search) skip_rerank: bool,
+	pub(in crate::search) session_mode: SessionRankingMode,
 }
 
 pub(in crate::search) struct ScoreCandidateCtx<'a, 'k> {
@@ -22,6 +24,8 @@ pub(in crate::search) struct ScoreCandidateCtx<'a, 'k> {
 	pub(in crate::search) now: OffsetDateTime,
 	pub(in crate::search) total_rerank: u32,
 	pub(in crate::search) total_retrieval: u32,
+	pub(in crate::search) best_evidence_coverage: Option<f32>,
+	pub(in crate::search) session_mode: SessionRankingMode,
 }
 
 #[derive(Clone, Debug)]
@@ -45,6 +49,14 @@ pub(in crate::search) struct ScoredChunk {
 	pub(in crate::search) deterministic_last_hit_age_days: Option<f32>,
 	pub(in crate::search) deterministic_hit_boost: f32,
 	pub(in crate::search) deterministic_decay_penalty: f32,
+	pub(in crate::search) deterministic_evidence_coverage: Option<f32>,
+	pub(in crate::search) deterministic_evidence_penalty: f32,
+	pub(in crate::search) deterministic_session_mode: SessionRankingMode,
+	pub(in crate::search) deterministic_session_hit_count: i64,
+	pub(in crate::search) deterministic_session_adjustment: f32,
+	pub(in crate::search) deterministic_feedback: NoteFeedbackCounts,
+	pub(in crate::search) deterministic_feedback_adjustment: f32,
+	pub(in crate::search) pin_boost: f32,
 }
 
 #[derive(Clone, Debug)]
@@ -56,6 +68,7 @@ pub(in crate::search) struct DiversityDecision {
 	pub(in crate::search) nearest_selected_note_id: Option<Uuid>,
 	pub(in crate::search) similarity: Option<f32>,
 	pub(in crate::search) mmr_score: Option<f32>,
+	pub(in crate::search) balance_penalty: Option<f32>,
 	pub(in crate::search) missing_embedding: bool,
 }
 
@@ -105,4 +118,10 @@ pub(in crate::search) struct ScoredReplay {
 	pub(in crate::search) deterministic_last_hit_age_days: Option<f32>,
 	pub(in crate::search) deterministic_hit_boost: f32,
 	pub(in crate::search) deterministic_decay_penalty: f32,
+	pub(in crate::search) deterministic_evidence_coverage: Option<f32>,
+	pub(in crate::search) deterministic_evidence_penalty: f32,
+	pub(in crate::search) deterministic_session_hit_count: i64,
+	pub(in crate::search) deterministic_session_adjustment: f32,
+	pub(in crate::search) deterministic_feedback: NoteFeedbackCounts,
+	pub(in crate::search) deterministic_feedback_adjustment: f32,
 }
@@ -1,27 +1,47 @@
-use crate::search::{ChunkCandidate, Config, FieldHit, HashMap, HashSet, Uuid, ranking};
+use crate::search::{
+	ChunkCandidate, Config, FieldHit, HashMap, HashSet, StructuredFieldRetrievalResult, Uuid,
+	ranking,
+};
 
+/// Groups field hits by note and orders notes by their best hit.
+///
+/// Notes matched through a `qa` question vector are ordered ahead of notes matched only through
+/// other fields.
 pub(super) fn build_structured_field_matches(
 	rows: Vec<FieldHit>,
 ) -> (Vec<Uuid>, HashMap<Uuid, Vec<String>>) {
 	let mut structured_matches: HashMap<Uuid, HashSet<String>> = HashMap::new();
-	let mut ordered_note_ids = Vec::new();
-	let mut seen_notes = HashSet::new();
+	let mut question_note_ids = Vec::new();
+	let mut other_note_ids = Vec::new();
+	let mut seen_question_notes = HashSet::new();
+	let mut seen_other_notes = HashSet::new();
 
 	for row in rows {
 		let label = match row.field_kind.as_str() {
 			"summary" => "summary",
 			"fact" => "facts",
 			"concept" => "concepts",
+			"question" => "question",
 			_ => continue,
 		};
 
 		structured_matches.entry(row.note_id).or_default().insert(label.to_string());
 
-		if seen_notes.insert(row.note_id) {
-			ordered_note_ids.push(row.note_id);
+		if label == "question" {
+			if seen_question_notes.insert(row.note_id) {
+				question_note_ids.push(row.note_id);
+			}
+		} else if seen_other_notes.insert(row.note_id) {
+			other_note_ids.push(row.note_id);
 		}
 	}
 
+	let mut ordered_note_ids = question_note_ids;
+
+	ordered_note_ids.extend(
+		other_note_ids.into_iter().filter(|note_id| !seen_question_notes.contains(note_id)),
+	);
+
 	let mut structured_matches_out: HashMap<Uuid, Vec<String>> = HashMap::new();
 
 	for (note_id, fields) in structured_matches {
@@ -59,6 +79,7 @@ pub(super) fn build_structured_field_candidates(
 			scope: None,
 			updated_at: None,
 			embedding_version: Some(embed_version.to_string()),
+			embedding_projection: None,
 		});
 
 		next_rank = next_rank.saturating_add(1);
@@ -67,6 +88,62 @@ pub(super) fn build_structured_field_candidates(
 	structured_candidates
 }
 
+/// Merges structured-field results retrieved with different embedding models.
+///
+/// Distances from different models are not comparable, so candidates are interleaved
+/// round-robin in per-model rank order and re-ranked.
+pub(super) fn merge_structured_field_results(
+	mut results: Vec<StructuredFieldRetrievalResult>,
+	candidate_k: u32,
+) -> StructuredFieldRetrievalResult {
+	if results.len() <= 1 {
+		return results.pop().unwrap_or_else(|| StructuredFieldRetrievalResult {
+			candidates: Vec::new(),
+			structured_matches: HashMap::new(),
+		});
+	}
+
+	let mut structured_matches: HashMap<Uuid, Vec<String>> = HashMap::new();
+	let mut queues = Vec::with_capacity(results.len());
+
+	for result in results {
+		for (note_id, fields) in result.structured_matches {
+			let merged = structured_matches.entry(note_id).or_default();
+
+			merged.extend(fields);
+			merged.sort();
+			merged.dedup();
+		}
+
+		queues.push(result.candidates.into_iter());
+	}
+
+	let limit = candidate_k as usize;
+	let mut candidates = Vec::new();
+	let mut seen_notes = HashSet::new();
+	let mut progressed = true;
+
+	while progressed && candidates.len() < limit {
+		progressed = false;
+
+		for queue in &mut queues {
+			let Some(mut candidate) = queue.next() else { continue };
+
+			progressed = true;
+
+			if candidates.len() >= limit || !seen_notes.insert(candidate.note_id) {
+				continue;
+			}
+
+			candidate.retrieval_rank = candidates.len() as u32 + 1;
+
+			candidates.push(candidate);
+		}
+	}
+
+	StructuredFieldRetrievalResult { candidates, structured_matches }
+}
+
 pub(super) fn build_deterministic_query_tokens(cfg: &Config, query: &str) -> Vec<String> {
 	if cfg.ranking.deterministic.enabled
 		&& cfg.ranking.deterministic.lexical.enabled
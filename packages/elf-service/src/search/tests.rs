@@ -1,7 +1,14 @@
 mod tests_cache_keys;
 mod tests_deterministic;
 mod tests_diversity;
+mod tests_explain_render;
+mod tests_payload_filters;
 mod tests_policy_id;
 mod tests_query_basics;
+mod tests_rank_documents;
 mod tests_relation_context;
+mod tests_result_packing;
 mod tests_retrieval_merge;
+mod tests_snippet_caps;
+mod tests_trace_artifact;
+mod tests_warnings;
@@ -48,3 +48,20 @@ fn cache_key_prefix_is_stable() {
 
 	assert_eq!(prefix, "abcd1234efgh");
 }
+
+#[test]
+fn search_result_cache_key_changes_with_watermark_and_policy() {
+	let request = serde_json::json!({ "query": ranking::normalize_cache_query("  alpha\tbeta ") });
+	let watermark_a = serde_json::json!({ "generation": 3, "updated_at": null });
+	let watermark_b = serde_json::json!({ "generation": 4, "updated_at": null });
+	let key_a = ranking::build_search_result_cache_key(&request, "policy-a", &watermark_a)
+		.expect("Expected cache key.");
+	let key_b = ranking::build_search_result_cache_key(&request, "policy-a", &watermark_b)
+		.expect("Expected cache key.");
+	let key_c = ranking::build_search_result_cache_key(&request, "policy-b", &watermark_a)
+		.expect("Expected cache key.");
+
+	assert_eq!(request["query"], "alpha beta");
+	assert_ne!(key_a, key_b);
+	assert_ne!(key_a, key_c);
+}
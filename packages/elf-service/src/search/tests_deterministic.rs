@@ -1,7 +1,8 @@
 use std::path::PathBuf;
 
 use crate::search::{
-	ChunkMeta, ChunkSnippet, NoteMeta, OffsetDateTime, ScoredChunk, Uuid, ranking,
+	ChunkMeta, ChunkSnippet, NoteFeedbackCounts, NoteMeta, OffsetDateTime, ScoredChunk,
+	SessionRankingMode, Uuid, ranking,
 };
 use elf_config::Config;
 
@@ -49,6 +50,12 @@ fn deterministic_ranking_terms_do_not_apply_when_disabled() {
 		embedding_version: "v1".to_string(),
 		hit_count: 8,
 		last_hit_at: Some(now),
+		session_hit_count: 0,
+		feedback: NoteFeedbackCounts::default(),
+		evidence_coverage: None,
+		values: Vec::new(),
+		fields: Vec::new(),
+		pinned: false,
 	};
 	let chunk =
 		ChunkMeta { chunk_id: Uuid::new_v4(), chunk_index: 0, start_offset: 0, end_offset: 10 };
@@ -58,6 +65,7 @@ fn deterministic_ranking_terms_do_not_apply_when_disabled() {
 		snippet: "deploy steps".to_string(),
 		retrieval_rank: 1,
 		retrieval_score: None,
+		embedding_projection: None,
 	};
 	let mut scored = ScoredChunk {
 		item,
@@ -79,6 +87,14 @@ fn deterministic_ranking_terms_do_not_apply_when_disabled() {
 		deterministic_last_hit_age_days: None,
 		deterministic_hit_boost: 0.0,
 		deterministic_decay_penalty: 0.0,
+		deterministic_evidence_coverage: None,
+		deterministic_evidence_penalty: 0.0,
+		deterministic_session_mode: SessionRankingMode::Off,
+		deterministic_session_hit_count: 0,
+		deterministic_session_adjustment: 0.0,
+		deterministic_feedback: NoteFeedbackCounts::default(),
+		deterministic_feedback_adjustment: 0.0,
+		pin_boost: 0.0,
 	};
 	let terms = ranking::compute_deterministic_ranking_terms(
 		&cfg,
@@ -131,6 +147,12 @@ fn deterministic_ranking_terms_apply_and_are_bounded() {
 		embedding_version: "v1".to_string(),
 		hit_count: 8,
 		last_hit_at: Some(now),
+		session_hit_count: 0,
+		feedback: NoteFeedbackCounts::default(),
+		evidence_coverage: None,
+		values: Vec::new(),
+		fields: Vec::new(),
+		pinned: false,
 	};
 	let chunk =
 		ChunkMeta { chunk_id: Uuid::new_v4(), chunk_index: 0, start_offset: 0, end_offset: 10 };
@@ -140,6 +162,7 @@ fn deterministic_ranking_terms_apply_and_are_bounded() {
 		snippet: "deploy steps".to_string(),
 		retrieval_rank: 1,
 		retrieval_score: None,
+		embedding_projection: None,
 	};
 	let mut scored = ScoredChunk {
 		item,
@@ -161,6 +184,14 @@ fn deterministic_ranking_terms_apply_and_are_bounded() {
 		deterministic_last_hit_age_days: None,
 		deterministic_hit_boost: 0.0,
 		deterministic_decay_penalty: 0.0,
+		deterministic_evidence_coverage: None,
+		deterministic_evidence_penalty: 0.0,
+		deterministic_session_mode: SessionRankingMode::Off,
+		deterministic_session_hit_count: 0,
+		deterministic_session_adjustment: 0.0,
+		deterministic_feedback: NoteFeedbackCounts::default(),
+		deterministic_feedback_adjustment: 0.0,
+		pin_boost: 0.0,
 	};
 	let terms = ranking::compute_deterministic_ranking_terms(
 		&cfg,
@@ -197,3 +228,80 @@ fn deterministic_ranking_terms_apply_and_are_bounded() {
 
 	assert!((scored.deterministic_hit_boost - expected_hit).abs() < 1e-6);
 }
+
+#[test]
+fn evidence_penalty_applies_only_when_best_candidate_is_strongly_evidenced() {
+	let mut cfg = parse_example_config();
+
+	cfg.ranking.deterministic.enabled = true;
+	cfg.ranking.deterministic.evidence = Some(elf_config::RankingDeterministicEvidence {
+		enabled: true,
+		weight: 0.2,
+		strong_coverage: 0.8,
+	});
+
+	let best = ranking::best_evidence_coverage([Some(0.9), None, Some(0.4)].into_iter());
+
+	assert_eq!(best, Some(0.9));
+
+	let penalty = ranking::compute_evidence_penalty(&cfg, Some(0.4), best);
+
+	assert!((penalty + 0.1).abs() < 1e-6, "Unexpected penalty: {penalty}");
+	assert_eq!(ranking::compute_evidence_penalty(&cfg, None, best), 0.0);
+	assert_eq!(ranking::compute_evidence_penalty(&cfg, Some(0.4), Some(0.7)), 0.0);
+
+	cfg.ranking.deterministic.enabled = false;
+
+	assert_eq!(ranking::compute_evidence_penalty(&cfg, Some(0.4), best), 0.0);
+}
+
+#[test]
+fn session_adjustment_follows_mode_for_notes_the_session_already_retrieved() {
+	let mut cfg = parse_example_config();
+
+	cfg.ranking.deterministic.enabled = true;
+	cfg.ranking.deterministic.session = Some(elf_config::RankingDeterministicSession {
+		enabled: true,
+		mode: "penalty".to_string(),
+		weight: 0.15,
+		ttl_seconds: 3_600,
+	});
+
+	let boost = ranking::compute_session_adjustment(&cfg, SessionRankingMode::Boost, 2);
+	let penalty = ranking::compute_session_adjustment(&cfg, SessionRankingMode::Penalty, 1);
+
+	assert!((boost - 0.15).abs() < 1e-6, "Unexpected boost: {boost}");
+	assert!((penalty + 0.15).abs() < 1e-6, "Unexpected penalty: {penalty}");
+	assert_eq!(ranking::compute_session_adjustment(&cfg, SessionRankingMode::Boost, 0), 0.0);
+	assert_eq!(ranking::compute_session_adjustment(&cfg, SessionRankingMode::Off, 3), 0.0);
+
+	cfg.ranking.deterministic.enabled = false;
+
+	assert_eq!(ranking::compute_session_adjustment(&cfg, SessionRankingMode::Boost, 2), 0.0);
+}
+
+#[test]
+fn feedback_adjustment_is_bounded_by_weight_and_penalizes_wrong_results() {
+	let mut cfg = parse_example_config();
+	let counts = |useful, not_useful, wrong| NoteFeedbackCounts { useful, not_useful, wrong };
+
+	cfg.ranking.deterministic.enabled = true;
+	cfg.ranking.deterministic.feedback =
+		Some(elf_config::RankingDeterministicFeedback { enabled: true, weight: 0.2 });
+
+	let boost = ranking::compute_feedback_adjustment(&cfg, counts(3, 0, 0));
+	let not_useful = ranking::compute_feedback_adjustment(&cfg, counts(0, 1, 0));
+	let wrong = ranking::compute_feedback_adjustment(&cfg, counts(0, 0, 1));
+	let saturated = ranking::compute_feedback_adjustment(&cfg, counts(0, 0, 1_000));
+
+	assert!((boost - 0.15).abs() < 1e-6, "Unexpected boost: {boost}");
+	assert!((not_useful + 0.1).abs() < 1e-6, "Unexpected penalty: {not_useful}");
+	assert!(wrong < not_useful, "A wrong result must cost more than a not_useful one.");
+	assert!(saturated > -0.2, "Penalty must stay within the weight: {saturated}");
+	assert_eq!(ranking::compute_feedback_adjustment(&cfg, counts(1, 1, 0)), 0.0);
+	assert_eq!(ranking::compute_feedback_adjustment(&cfg, counts(0, 0, 0)), 0.0);
+
+	cfg.ranking.deterministic.enabled = false;
+
+	assert_eq!(ranking::compute_feedback_adjustment(&cfg, counts(0, 0, 1)), 0.0);
+}
@@ -1,8 +1,9 @@
 use crate::search::{
-	ChunkMeta, ChunkSnippet, HashMap, NoteMeta, OffsetDateTime, ScoredChunk, TraceReplayCandidate,
-	Uuid,
+	ChunkMeta, ChunkSnippet, HashMap, NoteFeedbackCounts, NoteMeta, OffsetDateTime, ScoredChunk,
+	SessionRankingMode, TraceReplayCandidate, Uuid,
 	ranking::{self, ResolvedDiversityPolicy},
 };
+use elf_config::{RankingDiversity, RankingDiversityScopeBalance};
 
 fn test_scored_chunk(note_id: Uuid, retrieval_rank: u32, now: OffsetDateTime) -> ScoredChunk {
 	let note = NoteMeta {
@@ -19,6 +20,12 @@ fn test_scored_chunk(note_id: Uuid, retrieval_rank: u32, now: OffsetDateTime) ->
 		embedding_version: "v1".to_string(),
 		hit_count: 0,
 		last_hit_at: None,
+		session_hit_count: 0,
+		feedback: NoteFeedbackCounts::default(),
+		evidence_coverage: None,
+		values: Vec::new(),
+		fields: Vec::new(),
+		pinned: false,
 	};
 	let chunk = ChunkMeta {
 		chunk_id: Uuid::new_v4(),
@@ -32,6 +39,7 @@ fn test_scored_chunk(note_id: Uuid, retrieval_rank: u32, now: OffsetDateTime) ->
 		snippet: format!("snippet-{retrieval_rank}"),
 		retrieval_rank,
 		retrieval_score: None,
+		embedding_projection: None,
 	};
 
 	ScoredChunk {
@@ -54,6 +62,14 @@ fn test_scored_chunk(note_id: Uuid, retrieval_rank: u32, now: OffsetDateTime) ->
 		deterministic_last_hit_age_days: None,
 		deterministic_hit_boost: 0.0,
 		deterministic_decay_penalty: 0.0,
+		deterministic_evidence_coverage: None,
+		deterministic_evidence_penalty: 0.0,
+		deterministic_session_mode: SessionRankingMode::Off,
+		deterministic_session_hit_count: 0,
+		deterministic_session_adjustment: 0.0,
+		deterministic_feedback: NoteFeedbackCounts::default(),
+		deterministic_feedback_adjustment: 0.0,
+		pin_boost: 0.0,
 	}
 }
 
@@ -79,6 +95,7 @@ fn diversity_selection_skips_high_similarity_when_alternative_exists() {
 		sim_threshold: 0.9,
 		mmr_lambda: 0.7,
 		max_skips: 64,
+		scope_balance: None,
 	};
 	let (selected, decisions) = ranking::select_diverse_results(candidates, 2, &policy, &vectors);
 	let selected_ids: Vec<Uuid> = selected.iter().map(|item| item.item.note.note_id).collect();
@@ -106,6 +123,7 @@ fn diversity_selection_backfills_when_max_skips_is_reached() {
 		sim_threshold: 0.9,
 		mmr_lambda: 0.7,
 		max_skips: 0,
+		scope_balance: None,
 	};
 	let (selected, decisions) = ranking::select_diverse_results(candidates, 2, &policy, &vectors);
 	let selected_ids: Vec<Uuid> = selected.iter().map(|item| item.item.note.note_id).collect();
@@ -115,6 +133,64 @@ fn diversity_selection_backfills_when_max_skips_is_reached() {
 	assert_eq!(selected_reason, Some("max_skips_backfill"));
 }
 
+#[test]
+fn diversity_scope_balance_prefers_under_represented_scope() {
+	let now = OffsetDateTime::from_unix_timestamp(0).expect("Valid timestamp.");
+	let note_a = Uuid::new_v4();
+	let note_b = Uuid::new_v4();
+	let note_c = Uuid::new_v4();
+	let mut private_chunk = test_scored_chunk(note_c, 3, now);
+
+	private_chunk.item.note.scope = "agent_private".to_string();
+
+	let candidates =
+		vec![test_scored_chunk(note_a, 1, now), test_scored_chunk(note_b, 2, now), private_chunk];
+	let mut vectors = HashMap::new();
+
+	vectors.insert(note_a, vec![1.0, 0.0, 0.0]);
+	vectors.insert(note_b, vec![0.0, 1.0, 0.0]);
+	vectors.insert(note_c, vec![0.0, 0.0, 1.0]);
+
+	let mut policy = ResolvedDiversityPolicy {
+		enabled: true,
+		sim_threshold: 0.9,
+		mmr_lambda: 0.7,
+		max_skips: 64,
+		scope_balance: None,
+	};
+	let (selected, _) = ranking::select_diverse_results(candidates.clone(), 2, &policy, &vectors);
+	let selected_ids: Vec<Uuid> = selected.iter().map(|item| item.item.note.note_id).collect();
+
+	assert_eq!(selected_ids, vec![note_a, note_b]);
+
+	policy = ranking::resolve_diversity_policy(
+		&RankingDiversity {
+			enabled: true,
+			sim_threshold: 0.9,
+			mmr_lambda: 0.7,
+			max_skips: 64,
+			scope_balance: Some(RankingDiversityScopeBalance {
+				enabled: true,
+				by: "scope".to_string(),
+				weight: 0.5,
+			}),
+		},
+		None,
+	)
+	.expect("Valid diversity policy.");
+
+	let (selected, decisions) = ranking::select_diverse_results(candidates, 2, &policy, &vectors);
+	let selected_ids: Vec<Uuid> = selected.iter().map(|item| item.item.note.note_id).collect();
+
+	assert_eq!(selected_ids, vec![note_a, note_c]);
+	assert_eq!(decisions.get(&note_c).and_then(|decision| decision.balance_penalty), Some(0.0));
+	assert_eq!(decisions.get(&note_b).and_then(|decision| decision.balance_penalty), Some(0.25));
+	assert_eq!(
+		decisions.get(&note_b).and_then(|decision| decision.skipped_reason.as_deref()),
+		Some("lower_mmr")
+	);
+}
+
 #[test]
 fn replay_diversity_decisions_prefer_selected_entry_for_same_note() {
 	let now = OffsetDateTime::from_unix_timestamp(0).expect("Valid timestamp.");
@@ -132,6 +208,9 @@ fn replay_diversity_decisions_prefer_selected_entry_for_same_note() {
 		note_updated_at: now,
 		note_hit_count: 0,
 		note_last_hit_at: None,
+		note_evidence_coverage: None,
+		note_session_adjustment: None,
+		note_feedback_adjustment: None,
 		diversity_selected: Some(false),
 		diversity_selected_rank: None,
 		diversity_selected_reason: Some("not_selected".to_string()),
@@ -140,6 +219,7 @@ fn replay_diversity_decisions_prefer_selected_entry_for_same_note() {
 		diversity_similarity: Some(0.95),
 		diversity_mmr_score: Some(0.12),
 		diversity_missing_embedding: Some(false),
+		diversity_balance_penalty: None,
 	};
 	let second = TraceReplayCandidate {
 		note_id,
@@ -154,6 +234,9 @@ fn replay_diversity_decisions_prefer_selected_entry_for_same_note() {
 		note_updated_at: now,
 		note_hit_count: 0,
 		note_last_hit_at: None,
+		note_evidence_coverage: None,
+		note_session_adjustment: None,
+		note_feedback_adjustment: None,
 		diversity_selected: Some(true),
 		diversity_selected_rank: Some(2),
 		diversity_selected_reason: Some("mmr".to_string()),
@@ -162,6 +245,7 @@ fn replay_diversity_decisions_prefer_selected_entry_for_same_note() {
 		diversity_similarity: Some(0.35),
 		diversity_mmr_score: Some(0.44),
 		diversity_missing_embedding: Some(false),
+		diversity_balance_penalty: None,
 	};
 	let decisions = ranking::extract_replay_diversity_decisions(&[first, second]);
 	let decision = decisions.get(&note_id).expect("Expected merged decision.");
@@ -170,3 +254,61 @@ fn replay_diversity_decisions_prefer_selected_entry_for_same_note() {
 	assert_eq!(decision.selected_rank, Some(2));
 	assert_eq!(decision.selected_reason, "mmr");
 }
+
+#[test]
+fn pinned_results_displace_lowest_unpinned_selection() {
+	let now = OffsetDateTime::from_unix_timestamp(0).expect("Valid timestamp.");
+	let note_a = Uuid::new_v4();
+	let note_b = Uuid::new_v4();
+	let note_c = Uuid::new_v4();
+	let mut fused = vec![
+		test_scored_chunk(note_a, 1, now),
+		test_scored_chunk(note_b, 2, now),
+		test_scored_chunk(note_c, 3, now),
+	];
+
+	fused[0].final_score = 0.9;
+	fused[1].final_score = 0.6;
+	fused[2].final_score = 0.1;
+	fused[2].item.note.pinned = true;
+
+	let policy = ResolvedDiversityPolicy {
+		enabled: false,
+		sim_threshold: 0.9,
+		mmr_lambda: 0.7,
+		max_skips: 64,
+		scope_balance: None,
+	};
+	let (selected, mut decisions) =
+		ranking::select_diverse_results(fused.clone(), 2, &policy, &HashMap::new());
+	let selected = ranking::include_pinned_results(selected, &fused, 2, &mut decisions);
+	let selected_ids: Vec<Uuid> = selected.iter().map(|item| item.item.note.note_id).collect();
+	let pinned = decisions.get(&note_c).expect("Expected pinned decision.");
+	let displaced = decisions.get(&note_b).expect("Expected displaced decision.");
+
+	assert_eq!(selected_ids, vec![note_a, note_c]);
+	assert!((selected[1].pin_boost - 0.5).abs() < 1e-6);
+	assert!((selected[1].final_score - 0.6).abs() < 1e-6);
+	assert_eq!(pinned.selected_reason, "pinned");
+	assert_eq!(pinned.selected_rank, Some(2));
+	assert!(!displaced.selected);
+	assert_eq!(displaced.skipped_reason.as_deref(), Some("pinned_displacement"));
+}
+
+#[test]
+fn pinned_results_keep_selection_when_pins_already_selected() {
+	let now = OffsetDateTime::from_unix_timestamp(0).expect("Valid timestamp.");
+	let note_a = Uuid::new_v4();
+	let note_b = Uuid::new_v4();
+	let mut fused = vec![test_scored_chunk(note_a, 1, now), test_scored_chunk(note_b, 2, now)];
+
+	fused[0].item.note.pinned = true;
+
+	let mut decisions = HashMap::new();
+	let selected = ranking::include_pinned_results(fused[..1].to_vec(), &fused, 1, &mut decisions);
+
+	assert_eq!(selected.len(), 1);
+	assert_eq!(selected[0].item.note.note_id, note_a);
+	assert_eq!(selected[0].pin_boost, 0.0);
+	assert!(decisions.is_empty());
+}
@@ -0,0 +1,101 @@
+use std::path::PathBuf;
+
+use crate::{
+	ranking_explain_v2::{self, RankSensitivityInput},
+	search::{SEARCH_RANKING_EXPLAIN_SCHEMA_V2, SearchRankingExplain, SearchRankingTerm},
+};
+use elf_config::Config;
+
+fn parse_example_config() -> Config {
+	let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+	let path = root_dir.join("elf.example.toml");
+
+	elf_config::load(&path).expect("elf.example.toml must remain parseable and valid.")
+}
+
+fn term(name: &str, value: f32) -> SearchRankingTerm {
+	SearchRankingTerm { name: name.to_string(), value, inputs: None }
+}
+
+fn test_explain() -> SearchRankingExplain {
+	SearchRankingExplain {
+		schema: SEARCH_RANKING_EXPLAIN_SCHEMA_V2.to_string(),
+		policy_id: "ranking_v2:test".to_string(),
+		final_score: 0.8125,
+		terms: vec![
+			term("blend.retrieval", 0.25),
+			term("blend.rerank", 0.625),
+			term("context.scope_boost", 0.0),
+			term("deterministic.decay_penalty", -0.0625),
+		],
+		sensitivity: None,
+	}
+}
+
+#[test]
+fn render_compact_orders_non_zero_terms_by_magnitude() {
+	assert_eq!(
+		test_explain().render_compact(),
+		"score 0.8125 = blend.rerank +0.6250, blend.retrieval +0.2500, \
+		 deterministic.decay_penalty -0.0625",
+	);
+}
+
+#[test]
+fn render_compact_without_non_zero_terms_reports_score_only() {
+	let explain =
+		SearchRankingExplain { terms: vec![term("blend.retrieval", 0.0)], ..test_explain() };
+
+	assert_eq!(explain.render_compact(), "score 0.8125");
+}
+
+#[test]
+fn render_markdown_lists_every_term_in_policy_order() {
+	assert_eq!(
+		test_explain().render_markdown(),
+		"| term | value |\n\
+		 | --- | ---: |\n\
+		 | blend.retrieval | +0.2500 |\n\
+		 | blend.rerank | +0.6250 |\n\
+		 | context.scope_boost | +0.0000 |\n\
+		 | deterministic.decay_penalty | -0.0625 |\n\
+		 | **final_score** | **0.8125** |",
+	);
+}
+
+#[test]
+fn rank_sensitivity_reports_rank_shifts_per_knob() {
+	let cfg = parse_example_config();
+	let inputs = [
+		RankSensitivityInput {
+			final_score: 0.9,
+			rerank_term: 0.5,
+			lexical_bonus: 0.0,
+			importance: 0.5,
+			age_days: 0.0,
+		},
+		RankSensitivityInput {
+			final_score: 0.85,
+			rerank_term: 0.1,
+			lexical_bonus: 0.2,
+			importance: 0.5,
+			age_days: 0.0,
+		},
+	];
+	let sensitivity = ranking_explain_v2::build_rank_sensitivity(&cfg, &inputs);
+	let find = |idx: usize, knob: &str, factor: f32| {
+		sensitivity[idx]
+			.iter()
+			.find(|entry| entry.knob == knob && entry.factor == factor)
+			.cloned()
+			.expect("Expected sensitivity entry.")
+	};
+
+	assert_eq!(sensitivity.len(), 2);
+	assert_eq!(sensitivity[0].len(), 6);
+	assert_eq!(find(0, "rerank_weight", 0.8).rank_shift, 1);
+	assert_eq!(find(1, "rerank_weight", 0.8).rank_shift, -1);
+	assert_eq!(find(1, "rerank_weight", 0.8).rank, 1);
+	assert_eq!(find(1, "lexical_bonus", 1.2).rank_shift, 0);
+	assert_eq!(find(0, "recency_tau_days", 1.2).final_score, 0.9);
+}
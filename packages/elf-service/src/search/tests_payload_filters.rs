@@ -0,0 +1,90 @@
+use serde_json::json;
+
+use crate::search::{
+	NoteFeedbackCounts, NoteMeta, OffsetDateTime, SearchPayloadFilters, SearchRequest, Uuid,
+};
+
+fn request(extra: serde_json::Value) -> SearchRequest {
+	let mut raw = json!({
+		"tenant_id": "t",
+		"project_id": "p",
+		"agent_id": "a",
+		"token_id": null,
+		"read_profile": "private_only",
+		"query": "alpha",
+		"top_k": null,
+		"candidate_k": null,
+		"filter": null,
+		"record_hits": null,
+		"ranking": null,
+	});
+
+	raw.as_object_mut()
+		.expect("request object")
+		.extend(extra.as_object().expect("extra object").clone());
+
+	serde_json::from_value(raw).expect("Failed to build search request.")
+}
+
+fn note(note_type: &str, updated_at: i64, importance: f32) -> NoteMeta {
+	NoteMeta {
+		note_id: Uuid::new_v4(),
+		note_type: note_type.to_string(),
+		key: None,
+		scope: "agent_private".to_string(),
+		agent_id: "a".to_string(),
+		importance,
+		confidence: 0.5,
+		updated_at: OffsetDateTime::from_unix_timestamp(updated_at).expect("timestamp"),
+		expires_at: None,
+		source_ref: json!({}),
+		embedding_version: "provider:model:1".to_string(),
+		hit_count: 0,
+		last_hit_at: None,
+		evidence_coverage: None,
+		session_hit_count: 0,
+		feedback: NoteFeedbackCounts::default(),
+		values: Vec::new(),
+		fields: Vec::new(),
+		pinned: false,
+	}
+}
+
+#[test]
+fn payload_filters_default_to_no_conditions() {
+	let filters = SearchPayloadFilters::parse(&request(json!({}))).expect("valid filters");
+
+	assert!(filters.conditions().is_empty());
+	assert!(filters.matches(&note("fact", 1_700_000_000, 0.1)));
+}
+
+#[test]
+fn payload_filters_push_down_one_condition_per_bound() {
+	let filters = SearchPayloadFilters::parse(&request(json!({
+		"types": [" fact ", "decision", "fact"],
+		"updated_after": "2023-11-01T00:00:00Z",
+		"updated_before": "2023-12-01T00:00:00Z",
+		"min_importance": 0.5,
+	})))
+	.expect("valid filters");
+
+	assert_eq!(filters.types, vec!["decision".to_string(), "fact".to_string()]);
+	assert_eq!(filters.conditions().len(), 3);
+	// 2023-11-14T22:13:20Z
+	assert!(filters.matches(&note("fact", 1_700_000_000, 0.5)));
+	assert!(!filters.matches(&note("plan", 1_700_000_000, 0.9)));
+	assert!(!filters.matches(&note("fact", 1_700_000_000, 0.4)));
+	assert!(!filters.matches(&note("fact", 1_702_000_000, 0.9)));
+}
+
+#[test]
+fn payload_filters_reject_invalid_bounds() {
+	for extra in [
+		json!({ "types": [""] }),
+		json!({ "updated_after": "yesterday" }),
+		json!({ "updated_after": "2024-02-01T00:00:00Z", "updated_before": "2024-01-01T00:00:00Z" }),
+		json!({ "min_importance": 1.5 }),
+	] {
+		assert!(SearchPayloadFilters::parse(&request(extra.clone())).is_err(), "{extra}");
+	}
+}
@@ -92,6 +92,9 @@ fn replay_ranking_policy_id_matches_ranking_policy_id() {
 			note_updated_at: now,
 			note_hit_count: 0,
 			note_last_hit_at: None,
+			note_evidence_coverage: None,
+			note_session_adjustment: None,
+			note_feedback_adjustment: None,
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -100,6 +103,7 @@ fn replay_ranking_policy_id_matches_ranking_policy_id() {
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		},
 		TraceReplayCandidate {
 			note_id: Uuid::new_v4(),
@@ -114,6 +118,9 @@ fn replay_ranking_policy_id_matches_ranking_policy_id() {
 			note_updated_at: now,
 			note_hit_count: 0,
 			note_last_hit_at: None,
+			note_evidence_coverage: None,
+			note_session_adjustment: None,
+			note_feedback_adjustment: None,
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -122,6 +129,7 @@ fn replay_ranking_policy_id_matches_ranking_policy_id() {
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		},
 		TraceReplayCandidate {
 			note_id: Uuid::new_v4(),
@@ -136,6 +144,9 @@ fn replay_ranking_policy_id_matches_ranking_policy_id() {
 			note_updated_at: now,
 			note_hit_count: 0,
 			note_last_hit_at: None,
+			note_evidence_coverage: None,
+			note_session_adjustment: None,
+			note_feedback_adjustment: None,
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -144,6 +155,7 @@ fn replay_ranking_policy_id_matches_ranking_policy_id() {
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		},
 	];
 	let out = search::replay_ranking_from_candidates(&cfg, &trace, None, &candidates, 2)
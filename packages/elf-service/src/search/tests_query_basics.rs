@@ -5,8 +5,11 @@ use elf_config::SearchDynamic;
 
 #[test]
 fn dense_embedding_input_includes_project_context_suffix() {
-	let input =
-		ranking::build_dense_embedding_input("Find payments code.", Some("This is a billing API."));
+	let input = ranking::build_dense_embedding_input(
+		"Find payments code.",
+		Some("This is a billing API."),
+		ranking::EmbeddingInputStrategy::ContextSuffix,
+	);
 
 	assert!(input.starts_with("Find payments code.\n\nProject context:\n"));
 	assert!(input.contains("This is a billing API."));
@@ -14,11 +17,45 @@ fn dense_embedding_input_includes_project_context_suffix() {
 
 #[test]
 fn dense_embedding_input_skips_empty_project_context() {
-	let input = ranking::build_dense_embedding_input("Find payments code.", Some("   "));
+	let input = ranking::build_dense_embedding_input(
+		"Find payments code.",
+		Some("   "),
+		ranking::EmbeddingInputStrategy::ContextSuffix,
+	);
 
 	assert_eq!(input, "Find payments code.");
 }
 
+#[test]
+fn dense_embedding_input_follows_strategy() {
+	let context = Some("This is a billing API.");
+	let query_only = ranking::build_dense_embedding_input(
+		"Find payments code.",
+		context,
+		ranking::EmbeddingInputStrategy::QueryOnly,
+	);
+	let prefix = ranking::build_dense_embedding_input(
+		"Find payments code.",
+		context,
+		ranking::EmbeddingInputStrategy::ContextPrefix,
+	);
+	let template = ranking::build_dense_embedding_input(
+		"Find payments code.",
+		context,
+		ranking::EmbeddingInputStrategy::Template("query: {query} | domain: {context}"),
+	);
+	let template_without_context = ranking::build_dense_embedding_input(
+		"Find {context} code.",
+		None,
+		ranking::EmbeddingInputStrategy::Template("query: {query}{context}"),
+	);
+
+	assert_eq!(query_only, "Find payments code.");
+	assert_eq!(prefix, "Project context:\nThis is a billing API.\n\nFind payments code.");
+	assert_eq!(template, "query: Find payments code. | domain: This is a billing API.");
+	assert_eq!(template_without_context, "query: Find {context} code.");
+}
+
 #[test]
 fn normalize_queries_includes_original_and_dedupes() {
 	let queries = vec!["alpha".to_string(), "beta".to_string(), "alpha".to_string()];
@@ -36,6 +73,31 @@ fn normalize_queries_respects_max_queries() {
 	assert_eq!(normalized.len(), 3);
 }
 
+#[test]
+fn highlight_terms_add_expanded_query_tokens_once() {
+	let terms = search::highlight_terms(
+		&["deploy".to_string(), "friday".to_string()],
+		&["deploy friday".to_string(), "Release schedule on Friday".to_string()],
+	);
+
+	assert_eq!(terms, vec!["deploy", "friday", "release", "schedule", "on"]);
+}
+
+#[test]
+fn highlight_spans_match_whole_tokens_case_insensitively() {
+	let snippet = "Deploys run Friday; deploy freeze starts FRIDAY... café deploy";
+	let spans = ranking::highlight_spans(&["deploy".to_string(), "friday".to_string()], snippet);
+
+	let matched = spans
+		.iter()
+		.map(|(start, end)| &snippet[*start as usize..*end as usize])
+		.collect::<Vec<_>>();
+
+	assert_eq!(spans, vec![(12, 18), (20, 26), (41, 47), (57, 63)]);
+	assert_eq!(matched, vec!["Friday", "deploy", "FRIDAY", "deploy"]);
+	assert!(ranking::highlight_spans(&[], snippet).is_empty());
+}
+
 #[test]
 fn dynamic_trigger_checks_candidates_and_score() {
 	let cfg = SearchDynamic { min_candidates: 10, min_top_score: 0.2 };
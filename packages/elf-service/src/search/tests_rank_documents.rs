@@ -0,0 +1,55 @@
+use crate::search::rank_documents::{self, RankDocument, RankDocumentsRequest};
+
+fn request(docs: Vec<RankDocument>) -> RankDocumentsRequest {
+	RankDocumentsRequest {
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		query: "deploy pipeline".to_string(),
+		docs,
+		top_k: None,
+		ranking: None,
+	}
+}
+
+fn doc(text: &str, importance: Option<f32>) -> RankDocument {
+	RankDocument { id: None, text: text.to_string(), importance, updated_at: None }
+}
+
+#[test]
+fn rejects_empty_and_invalid_documents() {
+	assert!(
+		rank_documents::validate_rank_documents_request(&request(Vec::new()), "deploy").is_err()
+	);
+	assert!(
+		rank_documents::validate_rank_documents_request(&request(vec![doc("  ", None)]), "deploy")
+			.is_err()
+	);
+	assert!(
+		rank_documents::validate_rank_documents_request(
+			&request(vec![doc("Deploys run nightly.", Some(1.5))]),
+			"deploy"
+		)
+		.is_err()
+	);
+	assert!(
+		rank_documents::validate_rank_documents_request(
+			&request(vec![doc("Deploys run nightly.", Some(0.5))]),
+			"deploy"
+		)
+		.is_ok()
+	);
+}
+
+#[test]
+fn document_snippet_uses_request_position_as_retrieval_rank() {
+	let snippet = rank_documents::build_document_snippet(
+		2,
+		doc("Deploys run nightly.", None),
+		time::OffsetDateTime::UNIX_EPOCH,
+	);
+
+	assert_eq!(snippet.retrieval_rank, 3);
+	assert_eq!(snippet.note.hit_count, 0);
+	assert_eq!(snippet.note.updated_at, time::OffsetDateTime::UNIX_EPOCH);
+}
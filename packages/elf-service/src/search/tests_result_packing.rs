@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::search::{
+	ResultPacker, SearchItem, SearchPackingExplain, SearchSnippetTruncation, SearchWarning, packing,
+};
+use elf_chunking::Tokenizer;
+
+fn tokenizer() -> Tokenizer {
+	let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+		.join("../../config/local/tokenizer.wordlevel.json");
+
+	elf_chunking::load_tokenizer(path.to_str().expect("Path must be valid UTF-8."))
+		.expect("Local dev tokenizer must load.")
+}
+
+fn item(snippet: &str) -> SearchItem {
+	serde_json::from_value(json!({
+		"result_handle": "00000000-0000-0000-0000-000000000001",
+		"note_id": "00000000-0000-0000-0000-000000000002",
+		"chunk_id": "00000000-0000-0000-0000-000000000003",
+		"chunk_index": 0,
+		"start_offset": 0,
+		"end_offset": snippet.len(),
+		"snippet": snippet,
+		"type": "fact",
+		"key": null,
+		"scope": "agent_private",
+		"importance": 0.5,
+		"confidence": 0.5,
+		"updated_at": "2026-01-01T00:00:00Z",
+		"expires_at": null,
+		"final_score": 1.0,
+		"source_ref": {},
+		"explain": {
+			"match": { "matched_terms": [], "matched_fields": [] },
+			"ranking": { "schema": "test", "policy_id": "test", "final_score": 1.0, "terms": [] },
+		},
+	}))
+	.expect("Failed to build search item.")
+}
+
+#[test]
+fn packer_keeps_fitting_items_trims_the_boundary_item_and_drops_the_rest() {
+	let tokenizer = tokenizer();
+	let mut packer = ResultPacker::new(&tokenizer, 10);
+	let first = packer.pack(item("alpha beta gamma.")).expect("First item fits whole.");
+	let trimmed_text = "delta epsilon zeta eta theta iota kappa.";
+	let second = packer.pack(item(trimmed_text)).expect("Second item is trimmed to fit.");
+
+	assert_eq!(
+		first.explain.packing,
+		Some(SearchPackingExplain { tokens: 4, full_tokens: 4, truncated: false })
+	);
+	assert!(first.snippet_truncation.is_none());
+	assert_eq!(second.snippet, "delta epsilon zeta eta theta...");
+	assert_eq!(
+		second.explain.packing,
+		Some(SearchPackingExplain { tokens: 6, full_tokens: 8, truncated: true })
+	);
+	assert_eq!(
+		second.snippet_truncation,
+		Some(SearchSnippetTruncation {
+			kept_end: 28,
+			full_end: trimmed_text.len() as u32,
+			sentence_boundary: false,
+		})
+	);
+	assert!(packer.pack(item("mu nu.")).is_none());
+	assert_eq!(
+		packer.warning(),
+		Some(SearchWarning::ResultTokenBudget {
+			max_result_tokens: 10,
+			packed_tokens: 10,
+			truncated_count: 1,
+			dropped_count: 1,
+		})
+	);
+}
+
+#[test]
+fn packer_retrims_capped_snippets_against_the_original_text() {
+	let tokenizer = tokenizer();
+	let mut packer = ResultPacker::new(&tokenizer, 4);
+	let mut capped = item("one two three four five...");
+
+	capped.snippet_truncation =
+		Some(SearchSnippetTruncation { kept_end: 23, full_end: 60, sentence_boundary: false });
+
+	let packed = packer.pack(capped).expect("Capped item is trimmed further.");
+
+	assert_eq!(packed.snippet, "one two three...");
+	assert_eq!(
+		packed.snippet_truncation,
+		Some(SearchSnippetTruncation { kept_end: 13, full_end: 60, sentence_boundary: false })
+	);
+}
+
+#[test]
+fn packer_reports_nothing_when_every_item_fits() {
+	let tokenizer = tokenizer();
+	let mut packer = ResultPacker::new(&tokenizer, 64);
+
+	assert!(packer.pack(item("Short note.")).is_some());
+	assert_eq!(packer.warning(), None);
+}
+
+#[test]
+fn max_result_tokens_must_be_positive_and_bounded() {
+	assert_eq!(packing::validate_max_result_tokens(None).expect("unset is valid"), None);
+	assert_eq!(packing::validate_max_result_tokens(Some(256)).expect("valid"), Some(256));
+	assert!(packing::validate_max_result_tokens(Some(0)).is_err());
+	assert!(packing::validate_max_result_tokens(Some(1_000_000)).is_err());
+}
@@ -1,5 +1,6 @@
 use crate::search::{
-	ChunkCandidate, RetrievalSourceCandidates, RetrievalSourceKind, Uuid, ranking,
+	self, ChunkCandidate, FieldHit, HashMap, RetrievalSourceCandidates, RetrievalSourceKind,
+	StructuredFieldRetrievalResult, Uuid, ranking,
 };
 
 fn test_chunk_candidate(note_id: Uuid, retrieval_rank: u32) -> ChunkCandidate {
@@ -12,6 +13,7 @@ fn test_chunk_candidate(note_id: Uuid, retrieval_rank: u32) -> ChunkCandidate {
 		scope: None,
 		updated_at: None,
 		embedding_version: Some("v1".to_string()),
+		embedding_projection: None,
 	}
 }
 
@@ -71,6 +73,7 @@ fn merge_retrieval_candidates_prefers_dual_source_signal_on_tie() {
 			scope: None,
 			updated_at: None,
 			embedding_version: Some("v1".to_string()),
+			embedding_projection: None,
 		},
 		ChunkCandidate {
 			chunk_id: fusion_only_chunk_id,
@@ -81,6 +84,7 @@ fn merge_retrieval_candidates_prefers_dual_source_signal_on_tie() {
 			scope: None,
 			updated_at: None,
 			embedding_version: Some("v1".to_string()),
+			embedding_projection: None,
 		},
 	];
 	let structured = vec![ChunkCandidate {
@@ -92,6 +96,7 @@ fn merge_retrieval_candidates_prefers_dual_source_signal_on_tie() {
 		scope: None,
 		updated_at: None,
 		embedding_version: Some("v1".to_string()),
+		embedding_projection: None,
 	}];
 	let merged = ranking::merge_retrieval_candidates(
 		vec![
@@ -184,3 +189,77 @@ fn blend_math_is_linear_and_additive() {
 
 	assert!((final_score - expected).abs() < 1e-6, "Unexpected final_score: {final_score}");
 }
+
+#[test]
+fn merge_structured_field_results_interleaves_embedding_versions() {
+	let default_notes = [Uuid::new_v4(), Uuid::new_v4()];
+	let scoped_notes = [Uuid::new_v4(), default_notes[1]];
+	let default_result = StructuredFieldRetrievalResult {
+		candidates: default_notes
+			.iter()
+			.enumerate()
+			.map(|(idx, note_id)| test_chunk_candidate(*note_id, idx as u32 + 1))
+			.collect(),
+		structured_matches: HashMap::from([(default_notes[1], vec!["summary".to_string()])]),
+	};
+	let scoped_result = StructuredFieldRetrievalResult {
+		candidates: scoped_notes
+			.iter()
+			.enumerate()
+			.map(|(idx, note_id)| test_chunk_candidate(*note_id, idx as u32 + 1))
+			.collect(),
+		structured_matches: HashMap::from([(
+			default_notes[1],
+			vec!["facts".to_string(), "summary".to_string()],
+		)]),
+	};
+	let merged = search::merge_structured_field_results(vec![default_result, scoped_result], 10);
+	let merged_notes: Vec<Uuid> =
+		merged.candidates.iter().map(|candidate| candidate.note_id).collect();
+	let ranks: Vec<u32> =
+		merged.candidates.iter().map(|candidate| candidate.retrieval_rank).collect();
+
+	assert_eq!(merged_notes, vec![default_notes[0], scoped_notes[0], default_notes[1]]);
+	assert_eq!(ranks, vec![1, 2, 3]);
+	assert_eq!(
+		merged.structured_matches.get(&default_notes[1]),
+		Some(&vec!["facts".to_string(), "summary".to_string()])
+	);
+}
+
+#[test]
+fn build_structured_field_matches_orders_question_hits_first() {
+	let summary_note = Uuid::new_v4();
+	let qa_note = Uuid::new_v4();
+	let hit = |note_id, field_kind: &str| FieldHit { note_id, field_kind: field_kind.to_string() };
+	let (ordered, matches) = search::build_structured_field_matches(vec![
+		hit(summary_note, "summary"),
+		hit(qa_note, "fact"),
+		hit(qa_note, "question"),
+		hit(qa_note, "answer"),
+	]);
+
+	assert_eq!(ordered, vec![qa_note, summary_note]);
+	assert_eq!(matches.get(&qa_note), Some(&vec!["facts".to_string(), "question".to_string()]));
+	assert_eq!(matches.get(&summary_note), Some(&vec!["summary".to_string()]));
+}
+
+#[test]
+fn recursive_budget_remaining_stops_at_deadline() {
+	let now = std::time::Instant::now();
+	let budget = std::time::Duration::from_millis(250);
+	let deadline = now + budget;
+
+	assert_eq!(
+		search::retrieval::recursive::recursive_budget_remaining(deadline, now),
+		Some(budget)
+	);
+	assert_eq!(search::retrieval::recursive::recursive_budget_remaining(deadline, deadline), None);
+	assert_eq!(
+		search::retrieval::recursive::recursive_budget_remaining(
+			deadline,
+			deadline + std::time::Duration::from_millis(1),
+		),
+		None
+	);
+}
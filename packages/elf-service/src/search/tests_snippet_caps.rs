@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use crate::search::{self, PayloadLevel, helpers};
+use elf_chunking::SnippetLimit;
+
+#[test]
+fn example_config_caps_l0_snippets_and_leaves_l2_uncapped() {
+	let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+	let cfg = elf_config::load(&root_dir.join("elf.example.toml"))
+		.expect("elf.example.toml must remain parseable and valid.");
+
+	assert_eq!(
+		search::snippet_limit_for(&cfg, PayloadLevel::L0),
+		Some(SnippetLimit { max_chars: Some(240), max_tokens: None })
+	);
+	assert_eq!(search::snippet_limit_for(&cfg, PayloadLevel::L2), None);
+}
+
+#[test]
+fn cap_snippet_cuts_at_sentence_and_reports_offsets() {
+	let snippet = "Deploys run on Fridays. The rollback plan lives in the ops runbook and is long.";
+	let limit = SnippetLimit { max_chars: Some(32), max_tokens: None };
+	let (capped, truncation) = helpers::cap_snippet(snippet, limit, None);
+	let truncation = truncation.expect("Snippet should be truncated.");
+
+	assert_eq!(capped, "Deploys run on Fridays....");
+	assert_eq!(truncation.kept_end, 23);
+	assert_eq!(truncation.full_end as usize, snippet.len());
+	assert!(truncation.sentence_boundary);
+
+	let (short, truncation) = helpers::cap_snippet("Short note.", limit, None);
+
+	assert_eq!(short, "Short note.");
+	assert!(truncation.is_none());
+}
@@ -0,0 +1,85 @@
+use crate::search::{
+	OffsetDateTime, SearchTrace, TRACE_ARTIFACT_SCHEMA_V1, TRACE_BUNDLE_SCHEMA_V1, TraceArtifact,
+	TraceArtifactManifest, TraceBundleResponse, Uuid, trace,
+};
+
+fn test_artifact() -> TraceArtifact {
+	let trace_id = Uuid::new_v4();
+	let now = OffsetDateTime::UNIX_EPOCH;
+	let bundle = TraceBundleResponse {
+		schema: TRACE_BUNDLE_SCHEMA_V1.to_string(),
+		generated_at: now,
+		trace: SearchTrace {
+			trace_id,
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			read_profile: "private_only".to_string(),
+			query: "deployment steps".to_string(),
+			expansion_mode: "off".to_string(),
+			expanded_queries: Vec::new(),
+			allowed_scopes: vec!["agent_private".to_string()],
+			candidate_count: 0,
+			top_k: 5,
+			config_snapshot: serde_json::json!({ "policy": "v2" }),
+			created_at: now,
+			trace_version: 3,
+		},
+		items: Vec::new(),
+		trajectory_summary: None,
+		stages: Vec::new(),
+		candidates: None,
+	};
+
+	TraceArtifact {
+		manifest: TraceArtifactManifest {
+			schema: TRACE_ARTIFACT_SCHEMA_V1.to_string(),
+			trace_id,
+			generated_at: now,
+			compression: "gzip".to_string(),
+			sections: Vec::new(),
+		},
+		bundle,
+		notes: Vec::new(),
+	}
+}
+
+fn sealed_artifact() -> TraceArtifact {
+	let mut artifact = test_artifact();
+
+	artifact.manifest.sections =
+		trace::artifact_sections(&artifact).expect("Sections should hash.");
+
+	artifact
+}
+
+#[test]
+fn trace_artifact_round_trips_through_gzip() {
+	let artifact = sealed_artifact();
+	let bytes = trace::encode_trace_artifact(&artifact).expect("Artifact should encode.");
+
+	assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+
+	let decoded = trace::decode_trace_artifact(&bytes).expect("Artifact should decode.");
+
+	assert_eq!(decoded.manifest.trace_id, artifact.manifest.trace_id);
+	assert_eq!(decoded.manifest.sections, artifact.manifest.sections);
+	assert_eq!(decoded.bundle.trace.query, "deployment steps");
+}
+
+#[test]
+fn trace_artifact_rejects_tampered_sections() {
+	let mut artifact = sealed_artifact();
+
+	artifact.bundle.trace.query = "tampered".to_string();
+
+	let bytes = trace::encode_trace_artifact(&artifact).expect("Artifact should encode.");
+	let err = trace::decode_trace_artifact(&bytes).expect_err("Tampered artifact must fail.");
+
+	assert!(err.to_string().contains("section hashes do not match"), "Unexpected error: {err}");
+}
+
+#[test]
+fn trace_artifact_rejects_non_gzip_input() {
+	assert!(trace::decode_trace_artifact(b"{}").is_err());
+}
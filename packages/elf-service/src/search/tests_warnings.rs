@@ -0,0 +1,61 @@
+use serde_json::json;
+
+use crate::search::{self, SearchFilterImpact, SearchWarning};
+
+fn impact(pre: usize, post: usize) -> SearchFilterImpact {
+	SearchFilterImpact {
+		requested_candidate_k: 10,
+		effective_candidate_k: 30,
+		candidate_count_pre: pre,
+		candidate_count_post: post,
+		dropped_total: pre - post,
+		top_drop_reasons: Vec::new(),
+		filter: json!({}),
+		field_predicates: Vec::new(),
+	}
+}
+
+#[test]
+fn candidate_k_warnings_report_clamped_and_unwidened_values() {
+	assert!(search::candidate_k_warnings(Some(40), 40, false, 40).is_empty());
+	assert_eq!(
+		search::candidate_k_warnings(Some(5000), 1000, true, 2000),
+		vec![
+			SearchWarning::CandidateKClamped { requested: 5000, effective: 1000 },
+			SearchWarning::CandidateKClamped { requested: 3000, effective: 2000 },
+		],
+	);
+}
+
+#[test]
+fn scope_candidate_warnings_list_allowed_scopes_without_candidates() {
+	let allowed = vec!["agent_private".to_string(), "project_shared".to_string()];
+
+	assert_eq!(
+		search::scope_candidate_warnings(&allowed, ["project_shared", "org_shared"]),
+		vec![SearchWarning::ScopeNoCandidates { scope: "agent_private".to_string() }],
+	);
+}
+
+#[test]
+fn filter_drop_warning_requires_more_than_ninety_percent_dropped() {
+	assert_eq!(search::filter_drop_warning(None), None);
+	assert_eq!(search::filter_drop_warning(Some(&impact(10, 1))), None);
+	assert_eq!(
+		search::filter_drop_warning(Some(&impact(20, 1))),
+		Some(SearchWarning::FilterDroppedMostCandidates {
+			candidate_count_pre: 20,
+			candidate_count_post: 1,
+		}),
+	);
+}
+
+#[test]
+fn warnings_serialize_with_code_tag() {
+	let warning = SearchWarning::CacheUnavailable { cache_kind: "rerank".to_string() };
+
+	assert_eq!(
+		serde_json::to_value(&warning).expect("Failed to serialize warning."),
+		json!({ "code": "cache_unavailable", "cache_kind": "rerank" }),
+	);
+}
@@ -1,5 +1,12 @@
+mod artifact;
 mod bundle;
 mod explain;
+mod feedback;
 mod get;
 mod recent;
 mod trajectory;
+mod visibility;
+
+#[cfg(test)] pub(super) use self::artifact::artifact_sections;
+pub use self::artifact::{decode_trace_artifact, encode_trace_artifact};
+pub(crate) use self::visibility::shared_trace_predicate;
@@ -0,0 +1,153 @@
+use std::{
+	collections::BTreeSet,
+	io::{Read, Write},
+};
+
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use serde::Serialize;
+
+use crate::{
+	Error,
+	search::{
+		ElfService, MAX_TRACE_BUNDLE_CANDIDATES_LIMIT, MAX_TRACE_BUNDLE_ITEMS_LIMIT,
+		OffsetDateTime, Result, TRACE_ARTIFACT_SCHEMA_V1, TraceArtifact, TraceArtifactGetRequest,
+		TraceArtifactManifest, TraceArtifactNote, TraceArtifactSection, TraceBundleGetRequest,
+		TraceBundleMode, Uuid,
+	},
+};
+
+const TRACE_ARTIFACT_COMPRESSION: &str = "gzip";
+const MAX_TRACE_ARTIFACT_DECODED_BYTES: u64 = 256 * 1_024 * 1_024;
+
+impl ElfService {
+	/// Packages a full trace bundle and referenced note snapshots into one artifact.
+	pub async fn trace_artifact_get(&self, req: TraceArtifactGetRequest) -> Result<TraceArtifact> {
+		let tenant_id = req.tenant_id.trim().to_string();
+		let project_id = req.project_id.trim().to_string();
+		let bundle = self
+			.trace_bundle_get(TraceBundleGetRequest {
+				tenant_id: tenant_id.clone(),
+				project_id: project_id.clone(),
+				agent_id: req.agent_id,
+				token_id: req.token_id,
+				trace_id: req.trace_id,
+				mode: TraceBundleMode::Full,
+				stage_items_limit: Some(MAX_TRACE_BUNDLE_ITEMS_LIMIT),
+				candidates_limit: Some(MAX_TRACE_BUNDLE_CANDIDATES_LIMIT),
+			})
+			.await?;
+		let note_ids: BTreeSet<Uuid> = bundle
+			.items
+			.iter()
+			.map(|item| item.note_id)
+			.chain(bundle.candidates.iter().flatten().map(|candidate| candidate.note_id))
+			.collect();
+		let note_ids: Vec<Uuid> = note_ids.into_iter().collect();
+		let notes = sqlx::query_as::<_, TraceArtifactNote>(
+			"\
+SELECT
+	note_id,
+	type,
+	key,
+	scope,
+	status,
+	text,
+	importance,
+	confidence,
+	embedding_version,
+	updated_at
+FROM memory_notes
+WHERE note_id = ANY($1::uuid[])
+	AND tenant_id = $2
+	AND project_id = $3
+ORDER BY note_id ASC",
+		)
+		.bind(note_ids.as_slice())
+		.bind(tenant_id.as_str())
+		.bind(project_id.as_str())
+		.fetch_all(&self.db.pool)
+		.await?;
+		let manifest = TraceArtifactManifest {
+			schema: TRACE_ARTIFACT_SCHEMA_V1.to_string(),
+			trace_id: req.trace_id,
+			generated_at: OffsetDateTime::now_utc(),
+			compression: TRACE_ARTIFACT_COMPRESSION.to_string(),
+			sections: Vec::new(),
+		};
+		let mut artifact = TraceArtifact { manifest, bundle, notes };
+
+		artifact.manifest.sections = artifact_sections(&artifact)?;
+
+		Ok(artifact)
+	}
+}
+
+/// Serializes a trace artifact as gzip-compressed JSON.
+pub fn encode_trace_artifact(artifact: &TraceArtifact) -> Result<Vec<u8>> {
+	let json = serde_json::to_vec(artifact).map_err(|err| Error::InvalidRequest {
+		message: format!("Failed to encode trace artifact: {err}."),
+	})?;
+	let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+
+	encoder.write_all(&json).and_then(|()| encoder.finish()).map_err(|err| Error::InvalidRequest {
+		message: format!("Failed to compress trace artifact: {err}."),
+	})
+}
+
+/// Decodes a gzip-compressed trace artifact and verifies its manifest hashes.
+pub fn decode_trace_artifact(bytes: &[u8]) -> Result<TraceArtifact> {
+	let mut json = Vec::new();
+
+	GzDecoder::new(bytes).take(MAX_TRACE_ARTIFACT_DECODED_BYTES).read_to_end(&mut json).map_err(
+		|err| Error::InvalidRequest {
+			message: format!("Failed to decompress trace artifact: {err}."),
+		},
+	)?;
+
+	let artifact: TraceArtifact = serde_json::from_slice(&json).map_err(|err| {
+		Error::InvalidRequest { message: format!("Trace artifact is not valid JSON: {err}.") }
+	})?;
+
+	if artifact.manifest.schema != TRACE_ARTIFACT_SCHEMA_V1 {
+		return Err(Error::InvalidRequest {
+			message: format!("Unsupported trace artifact schema: {}.", artifact.manifest.schema),
+		});
+	}
+	if artifact.manifest.sections != artifact_sections(&artifact)? {
+		return Err(Error::InvalidRequest {
+			message: "Trace artifact section hashes do not match the manifest.".to_string(),
+		});
+	}
+
+	Ok(artifact)
+}
+
+pub(in crate::search) fn artifact_sections(
+	artifact: &TraceArtifact,
+) -> Result<Vec<TraceArtifactSection>> {
+	let bundle = &artifact.bundle;
+	let candidates = bundle.candidates.as_deref().unwrap_or_default();
+
+	Ok(vec![
+		hash_section("trace", 1, &bundle.trace)?,
+		hash_section("items", bundle.items.len(), &bundle.items)?,
+		hash_section("stages", bundle.stages.len(), &bundle.stages)?,
+		hash_section("candidates", candidates.len(), candidates)?,
+		hash_section("notes", artifact.notes.len(), &artifact.notes)?,
+	])
+}
+
+fn hash_section<T>(name: &str, count: usize, value: &T) -> Result<TraceArtifactSection>
+where
+	T: ?Sized + Serialize,
+{
+	let bytes = serde_json::to_vec(value).map_err(|err| Error::InvalidRequest {
+		message: format!("Failed to encode trace artifact section {name}: {err}."),
+	})?;
+
+	Ok(TraceArtifactSection {
+		name: name.to_string(),
+		count: count as u64,
+		blake3: blake3::hash(&bytes).to_hex().to_string(),
+	})
+}
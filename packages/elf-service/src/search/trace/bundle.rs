@@ -32,6 +32,7 @@ impl ElfService {
 				tenant_id: tenant_id.to_string(),
 				project_id: project_id.to_string(),
 				agent_id: req.agent_id.trim().to_string(),
+				token_id: req.token_id,
 				trace_id: req.trace_id,
 			})
 			.await?;
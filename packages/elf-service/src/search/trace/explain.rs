@@ -2,7 +2,7 @@ use crate::{
 	Error,
 	search::{
 		self, ElfService, Result, SearchExplain, SearchExplainItem, SearchExplainRequest,
-		SearchExplainResponse, SearchExplainTraceRow, SearchTrace, ranking,
+		SearchExplainResponse, SearchExplainTraceRow, SearchTrace, ranking, trace::visibility,
 	},
 };
 
@@ -11,7 +11,11 @@ impl ElfService {
 	pub async fn search_explain(&self, req: SearchExplainRequest) -> Result<SearchExplainResponse> {
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
+		let caller_agent_id = req.agent_id.trim();
 
+		if caller_agent_id.is_empty() {
+			return Err(Error::InvalidRequest { message: "agent_id is required.".to_string() });
+		}
 		if tenant_id.is_empty() || project_id.is_empty() {
 			return Err(Error::InvalidRequest {
 				message: "tenant_id and project_id are required.".to_string(),
@@ -55,6 +59,22 @@ WHERE i.item_id = $1 AND t.tenant_id = $2 AND t.project_id = $3",
 				message: "Unknown result_handle or trace not yet persisted.".to_string(),
 			});
 		};
+		let admin_override = self.trace_admin_override(req.token_id.as_deref());
+
+		if !visibility::trace_visible(
+			&self.db.pool,
+			row.trace_id,
+			row.agent_id.as_str(),
+			caller_agent_id,
+			admin_override,
+		)
+		.await?
+		{
+			return Err(Error::InvalidRequest {
+				message: "Unknown result_handle or trace not yet persisted.".to_string(),
+			});
+		}
+
 		let expanded_queries: Vec<String> =
 			ranking::decode_json(row.expanded_queries, "expanded_queries")?;
 		let allowed_scopes: Vec<String> =
@@ -0,0 +1,132 @@
+use crate::{
+	Error,
+	audit::AuditScope,
+	search::{
+		ElfService, OffsetDateTime, Result, SearchFeedbackRequest, SearchFeedbackResponse, Uuid,
+		trace::visibility,
+	},
+};
+use elf_storage::search_watermarks;
+
+impl ElfService {
+	/// Records the caller's feedback on one search result.
+	///
+	/// Feedback is keyed by result handle and agent, so a repeated call replaces the earlier
+	/// judgement. Aggregated feedback per note feeds the optional deterministic feedback term.
+	pub async fn record_feedback(
+		&self,
+		req: SearchFeedbackRequest,
+	) -> Result<SearchFeedbackResponse> {
+		let scope = AuditScope::new("search_feedback")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.result_handle]);
+
+		self.audited(scope, self.record_feedback_inner(req)).await
+	}
+
+	/// Runs [`ElfService::record_feedback`] without recording an audit entry.
+	async fn record_feedback_inner(
+		&self,
+		req: SearchFeedbackRequest,
+	) -> Result<SearchFeedbackResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let caller_agent_id = req.agent_id.trim();
+
+		if caller_agent_id.is_empty() {
+			return Err(Error::InvalidRequest { message: "agent_id is required.".to_string() });
+		}
+		if tenant_id.is_empty() || project_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id and project_id are required.".to_string(),
+			});
+		}
+
+		let row = sqlx::query_as::<_, (Uuid, String, Uuid)>(
+			"\
+SELECT t.trace_id, t.agent_id, i.note_id
+FROM search_trace_items i
+JOIN search_traces t ON i.trace_id = t.trace_id
+WHERE i.item_id = $1 AND t.tenant_id = $2 AND t.project_id = $3",
+		)
+		.bind(req.result_handle)
+		.bind(tenant_id)
+		.bind(project_id)
+		.fetch_optional(&self.db.pool)
+		.await?;
+		let Some((trace_id, owner_agent_id, note_id)) = row else {
+			return Err(Error::InvalidRequest {
+				message: "Unknown result_handle or trace not yet persisted.".to_string(),
+			});
+		};
+		let admin_override = self.trace_admin_override(req.token_id.as_deref());
+
+		if !visibility::trace_visible(
+			&self.db.pool,
+			trace_id,
+			owner_agent_id.as_str(),
+			caller_agent_id,
+			admin_override,
+		)
+		.await?
+		{
+			return Err(Error::InvalidRequest {
+				message: "Unknown result_handle or trace not yet persisted.".to_string(),
+			});
+		}
+
+		let now = OffsetDateTime::now_utc();
+		let mut tx = self.db.pool.begin().await?;
+		let inserted = sqlx::query(
+			"\
+INSERT INTO memory_feedback (
+	feedback_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	note_id,
+	trace_id,
+	result_handle,
+	feedback,
+	created_at,
+	updated_at
+)
+SELECT $1, $2, $3, $4, n.note_id, $6, $7, $8, $9, $9
+FROM memory_notes n
+WHERE n.note_id = $5
+ON CONFLICT (result_handle, agent_id) DO UPDATE
+SET feedback = EXCLUDED.feedback, updated_at = EXCLUDED.updated_at",
+		)
+		.bind(Uuid::new_v4())
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(caller_agent_id)
+		.bind(note_id)
+		.bind(trace_id)
+		.bind(req.result_handle)
+		.bind(req.feedback.as_str())
+		.bind(now)
+		.execute(&mut *tx)
+		.await?
+		.rows_affected();
+
+		if inserted == 0 {
+			return Err(Error::InvalidRequest {
+				message: "The note behind result_handle no longer exists.".to_string(),
+			});
+		}
+
+		// Feedback feeds the deterministic feedback term, so cached results for the note's space
+		// must not outlive it.
+		search_watermarks::bump_for_notes(&mut *tx, &[note_id], now).await?;
+		tx.commit().await?;
+
+		Ok(SearchFeedbackResponse {
+			result_handle: req.result_handle,
+			trace_id,
+			note_id,
+			feedback: req.feedback,
+			updated_at: now,
+		})
+	}
+}
@@ -3,6 +3,7 @@ use crate::{
 	search::{
 		self, ElfService, Result, SearchExplain, SearchExplainItem, SearchTrace,
 		SearchTraceItemRow, SearchTraceRow, TraceGetRequest, TraceGetResponse, ranking,
+		trace::visibility,
 	},
 };
 
@@ -11,8 +12,9 @@ impl ElfService {
 	pub async fn trace_get(&self, req: TraceGetRequest) -> Result<TraceGetResponse> {
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
+		let caller_agent_id = req.agent_id.trim();
 
-		if req.agent_id.trim().is_empty() {
+		if caller_agent_id.is_empty() {
 			return Err(Error::InvalidRequest { message: "agent_id is required.".to_string() });
 		}
 		if tenant_id.is_empty() || project_id.is_empty() {
@@ -49,6 +51,21 @@ WHERE trace_id = $1 AND tenant_id = $2 AND project_id = $3",
 		let Some(row) = row else {
 			return Err(Error::InvalidRequest { message: "Unknown trace_id.".to_string() });
 		};
+		let admin_override = self.trace_admin_override(req.token_id.as_deref());
+
+		// Hidden traces report the same error as missing ones so trace ids do not leak.
+		if !visibility::trace_visible(
+			&self.db.pool,
+			row.trace_id,
+			row.agent_id.as_str(),
+			caller_agent_id,
+			admin_override,
+		)
+		.await?
+		{
+			return Err(Error::InvalidRequest { message: "Unknown trace_id.".to_string() });
+		}
+
 		let expanded_queries: Vec<String> =
 			ranking::decode_json(row.expanded_queries, "expanded_queries")?;
 		let allowed_scopes: Vec<String> =
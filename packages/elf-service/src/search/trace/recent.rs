@@ -3,7 +3,7 @@ use crate::{
 	search::{
 		DEFAULT_RECENT_TRACES_LIMIT, ElfService, MAX_RECENT_TRACES_LIMIT, RECENT_TRACES_SCHEMA_V1,
 		RecentTraceHeader, Result, SearchRecentTraceRow, TraceRecentCursor, TraceRecentListRequest,
-		TraceRecentListResponse,
+		TraceRecentListResponse, shared_trace_predicate,
 	},
 };
 
@@ -13,47 +13,20 @@ impl ElfService {
 		&self,
 		req: TraceRecentListRequest,
 	) -> Result<TraceRecentListResponse> {
+		let limit = req.limit.unwrap_or(DEFAULT_RECENT_TRACES_LIMIT);
+
+		validate_recent_list_request(&req, limit)?;
+
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
 		let caller_agent_id = req.agent_id.trim();
 		let cursor_created_at = req.cursor_created_at;
 		let cursor_trace_id = req.cursor_trace_id;
-		let agent_id_filter = req.agent_id_filter.map(|value| value.trim().to_string());
-		let read_profile = req.read_profile.map(|value| value.trim().to_string());
-		let limit = req.limit.unwrap_or(DEFAULT_RECENT_TRACES_LIMIT);
-
-		if cursor_created_at.is_some() != cursor_trace_id.is_some() {
-			return Err(Error::InvalidRequest {
-				message: "cursor_created_at and cursor_trace_id must be both set or both omitted."
-					.to_string(),
-			});
-		}
-		if caller_agent_id.is_empty() {
-			return Err(Error::InvalidRequest { message: "agent_id is required.".to_string() });
-		}
-		if tenant_id.is_empty() || project_id.is_empty() {
-			return Err(Error::InvalidRequest {
-				message: "tenant_id and project_id are required.".to_string(),
-			});
-		}
-		if limit == 0 || limit > MAX_RECENT_TRACES_LIMIT {
-			return Err(Error::InvalidRequest {
-				message: format!("limit must be between 1 and {MAX_RECENT_TRACES_LIMIT}."),
-			});
-		}
-
-		if let (Some(created_after), Some(created_before)) = (req.created_after, req.created_before)
-			&& created_after >= created_before
-		{
-			return Err(Error::InvalidRequest {
-				message: "created_after must be before created_before.".to_string(),
-			});
-		}
-
-		let agent_id_filter = agent_id_filter.as_deref();
-		let read_profile = read_profile.as_deref();
+		let agent_id_filter = req.agent_id_filter.as_deref().map(str::trim);
+		let read_profile = req.read_profile.as_deref().map(str::trim);
 		let fetch_limit = (limit + 1).min(MAX_RECENT_TRACES_LIMIT + 1);
-		let rows = sqlx::query_as::<_, SearchRecentTraceRow>(
+		let admin_override = self.trace_admin_override(req.token_id.as_deref());
+		let rows = sqlx::query_as::<_, SearchRecentTraceRow>(concat!(
 			"\
 SELECT
 	trace_id,
@@ -63,9 +36,16 @@ SELECT
 	read_profile,
 	query,
 	created_at
-FROM search_traces
+FROM search_traces t
 WHERE tenant_id = $1
 	AND project_id = $2
+	AND (
+		$10::bool
+		OR agent_id = $11
+		OR ",
+			shared_trace_predicate!("t.trace_id"),
+			"
+	)
 	AND ($3::text IS NULL OR agent_id = $3)
 	AND ($4::text IS NULL OR read_profile = $4)
 	AND ($5::timestamptz IS NULL OR created_at > $5)
@@ -74,7 +54,7 @@ WHERE tenant_id = $1
 ORDER BY created_at DESC, trace_id DESC
 LIMIT $9
 ",
-		)
+		))
 		.bind(tenant_id)
 		.bind(project_id)
 		.bind(agent_id_filter)
@@ -84,6 +64,8 @@ LIMIT $9
 		.bind(cursor_created_at)
 		.bind(cursor_trace_id)
 		.bind(fetch_limit as i64)
+		.bind(admin_override)
+		.bind(caller_agent_id)
 		.fetch_all(&self.db.pool)
 		.await?;
 		let next_cursor = if rows.len() > limit as usize {
@@ -121,3 +103,35 @@ LIMIT $9
 		})
 	}
 }
+
+fn validate_recent_list_request(req: &TraceRecentListRequest, limit: u32) -> Result<()> {
+	if req.cursor_created_at.is_some() != req.cursor_trace_id.is_some() {
+		return Err(Error::InvalidRequest {
+			message: "cursor_created_at and cursor_trace_id must be both set or both omitted."
+				.to_string(),
+		});
+	}
+	if req.agent_id.trim().is_empty() {
+		return Err(Error::InvalidRequest { message: "agent_id is required.".to_string() });
+	}
+	if req.tenant_id.trim().is_empty() || req.project_id.trim().is_empty() {
+		return Err(Error::InvalidRequest {
+			message: "tenant_id and project_id are required.".to_string(),
+		});
+	}
+	if limit == 0 || limit > MAX_RECENT_TRACES_LIMIT {
+		return Err(Error::InvalidRequest {
+			message: format!("limit must be between 1 and {MAX_RECENT_TRACES_LIMIT}."),
+		});
+	}
+
+	if let (Some(created_after), Some(created_before)) = (req.created_after, req.created_before)
+		&& created_after >= created_before
+	{
+		return Err(Error::InvalidRequest {
+			message: "created_after must be before created_before.".to_string(),
+		});
+	}
+
+	Ok(())
+}
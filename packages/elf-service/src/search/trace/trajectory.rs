@@ -13,6 +13,7 @@ impl ElfService {
 				tenant_id: req.tenant_id,
 				project_id: req.project_id,
 				agent_id: req.agent_id,
+				token_id: req.token_id,
 				trace_id: req.trace_id,
 			})
 			.await?;
@@ -0,0 +1,72 @@
+use crate::{
+	access,
+	search::{ElfService, PgExecutor, Result, Uuid},
+};
+
+/// Expands to a SQL predicate that holds when the trace identified by `$trace_id` has items and
+/// every item and candidate references a shared-scope note.
+///
+/// Trace reads, the recent list, and note access stats all embed this predicate, so queries stay
+/// static strings while the rule lives in one place.
+macro_rules! shared_trace_predicate {
+	($trace_id:literal) => {
+		concat!(
+			"(
+	EXISTS (SELECT 1 FROM search_trace_items WHERE trace_id = ",
+			$trace_id,
+			")
+	AND NOT EXISTS (
+		SELECT 1
+		FROM search_trace_items vi
+		LEFT JOIN memory_notes vn ON vn.note_id = vi.note_id
+		WHERE vi.trace_id = ",
+			$trace_id,
+			"
+			AND (vn.note_id IS NULL OR vn.scope NOT IN ('project_shared', 'org_shared'))
+	)
+	AND NOT EXISTS (
+		SELECT 1
+		FROM search_trace_candidates vc
+		WHERE vc.trace_id = ",
+			$trace_id,
+			"
+			AND vc.note_scope NOT IN ('project_shared', 'org_shared')
+	)
+)"
+		)
+	};
+}
+pub(crate) use shared_trace_predicate;
+
+impl ElfService {
+	/// Returns whether the caller may read another agent's traces.
+	pub(crate) fn trace_admin_override(&self, token_id: Option<&str>) -> bool {
+		access::is_super_admin_token_id(self, token_id)
+	}
+}
+
+/// Returns whether a trace owned by `owner_agent_id` is visible to the caller.
+///
+/// Owners always see their traces and `super_admin` tokens see every trace in the project. Other
+/// agents only see traces matching [`shared_trace_predicate`].
+pub(super) async fn trace_visible<'e, E>(
+	executor: E,
+	trace_id: Uuid,
+	owner_agent_id: &str,
+	caller_agent_id: &str,
+	admin_override: bool,
+) -> Result<bool>
+where
+	E: PgExecutor<'e>,
+{
+	if admin_override || owner_agent_id == caller_agent_id {
+		return Ok(true);
+	}
+
+	let shared: bool = sqlx::query_scalar(concat!("SELECT ", shared_trace_predicate!("$1")))
+		.bind(trace_id)
+		.fetch_one(executor)
+		.await?;
+
+	Ok(shared)
+}
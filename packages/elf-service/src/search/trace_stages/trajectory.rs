@@ -80,6 +80,8 @@ fn build_trace_recall_stage(
 				"candidates_after": recursive_retrieval.candidates_after,
 				"rounds_executed": recursive_retrieval.rounds_executed,
 				"total_queries": recursive_retrieval.total_queries,
+				"elapsed_ms": recursive_retrieval.elapsed_ms,
+				"round_elapsed_ms": recursive_retrieval.round_elapsed_ms,
 				"stop_reason": recursive_retrieval
 					.stop_reason
 					.clone()
@@ -0,0 +1,65 @@
+use crate::search::{BTreeMap, SearchFilterImpact, SearchWarning};
+
+const FILTER_DROP_WARNING_RATIO: f64 = 0.9;
+
+pub(super) fn candidate_k_warnings(
+	requested: Option<u32>,
+	candidate_k: u32,
+	filtered: bool,
+	effective_candidate_k: u32,
+) -> Vec<SearchWarning> {
+	let mut warnings = Vec::new();
+
+	if let Some(requested) = requested
+		&& requested != candidate_k
+	{
+		warnings.push(SearchWarning::CandidateKClamped { requested, effective: candidate_k });
+	}
+
+	let widened = candidate_k.saturating_mul(3);
+
+	if filtered && effective_candidate_k < widened {
+		warnings.push(SearchWarning::CandidateKClamped {
+			requested: widened,
+			effective: effective_candidate_k,
+		});
+	}
+
+	warnings
+}
+
+pub(super) fn scope_candidate_warnings<'a>(
+	allowed_scopes: &[String],
+	candidate_scopes: impl IntoIterator<Item = &'a str>,
+) -> Vec<SearchWarning> {
+	let mut counts: BTreeMap<&str, usize> =
+		allowed_scopes.iter().map(|scope| (scope.as_str(), 0)).collect();
+
+	for scope in candidate_scopes {
+		if let Some(count) = counts.get_mut(scope) {
+			*count += 1;
+		}
+	}
+
+	allowed_scopes
+		.iter()
+		.filter(|scope| counts.get(scope.as_str()).copied().unwrap_or(0) == 0)
+		.map(|scope| SearchWarning::ScopeNoCandidates { scope: scope.clone() })
+		.collect()
+}
+
+pub(super) fn filter_drop_warning(impact: Option<&SearchFilterImpact>) -> Option<SearchWarning> {
+	let impact = impact?;
+
+	if impact.candidate_count_pre == 0
+		|| (impact.dropped_total as f64)
+			<= impact.candidate_count_pre as f64 * FILTER_DROP_WARNING_RATIO
+	{
+		return None;
+	}
+
+	Some(SearchWarning::FilterDroppedMostCandidates {
+		candidate_count_pre: u32::try_from(impact.candidate_count_pre).unwrap_or(u32::MAX),
+		candidate_count_post: u32::try_from(impact.candidate_count_post).unwrap_or(u32::MAX),
+	})
+}
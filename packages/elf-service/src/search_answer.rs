@@ -0,0 +1,240 @@
+//! Grounded answer synthesis over retrieved notes.
+
+mod grounding;
+mod prompt;
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result, SearchRequest, SearchV2Delivery, SearchV2Mode, SearchV2Request,
+	SearchWarning,
+};
+
+/// Request payload for grounded answer synthesis.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchAnswerRequest {
+	#[serde(flatten)]
+	/// Shared search parameters; `top_k` is capped at `search.answer.max_sources`.
+	pub search: SearchRequest,
+	#[serde(default)]
+	/// Retrieval mode.
+	pub mode: SearchV2Mode,
+}
+
+/// Why an answer was refused.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchAnswerRefusal {
+	/// Retrieval returned no readable notes.
+	NoSources,
+	/// The extractor declined to answer from the provided sources.
+	ExtractorRefused,
+	/// Too little of the drafted answer is supported by verbatim source quotes.
+	LowGrounding,
+}
+
+/// One cited span of a source note.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchAnswerCitation {
+	/// Cited note identifier.
+	pub note_id: Uuid,
+	/// Start byte offset of the quote within the note text.
+	pub start_offset: i32,
+	/// End byte offset of the quote within the note text.
+	pub end_offset: i32,
+	/// Verbatim quote from the note text.
+	pub quote: String,
+}
+
+/// One answer sentence with the spans that support it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchAnswerSentence {
+	/// Sentence text.
+	pub text: String,
+	/// Share of the sentence's content words found in its quotes, in 0.0-1.0.
+	pub grounding: f32,
+	/// Supporting citations; never empty.
+	pub citations: Vec<SearchAnswerCitation>,
+}
+
+/// Response payload for grounded answer synthesis.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchAnswerResponse {
+	/// Trace identifier of the underlying search.
+	pub trace_id: Uuid,
+	/// Answer text composed of the grounded sentences, absent when refused.
+	pub answer: Option<String>,
+	/// Grounded sentences in answer order. Empty when refused.
+	pub sentences: Vec<SearchAnswerSentence>,
+	/// Overall grounding score; unsupported drafted sentences count as zero.
+	pub grounding: f32,
+	/// Whether the answer was refused.
+	pub refused: bool,
+	/// Refusal reason when `refused` is true.
+	pub refusal_reason: Option<SearchAnswerRefusal>,
+	/// Notes handed to the extractor as sources, in rank order.
+	pub source_note_ids: Vec<Uuid>,
+	#[serde(default)]
+	/// Non-fatal conditions observed while serving the underlying search.
+	pub warnings: Vec<SearchWarning>,
+}
+impl SearchAnswerResponse {
+	fn refused(
+		trace_id: Uuid,
+		reason: SearchAnswerRefusal,
+		grounding: f32,
+		source_note_ids: Vec<Uuid>,
+		warnings: Vec<SearchWarning>,
+	) -> Self {
+		Self {
+			trace_id,
+			answer: None,
+			sentences: Vec::new(),
+			grounding,
+			refused: true,
+			refusal_reason: Some(reason),
+			source_note_ids,
+			warnings,
+		}
+	}
+}
+
+struct AnswerSource {
+	note_id: Uuid,
+	text: String,
+}
+
+impl ElfService {
+	/// Retrieves notes for the query and synthesizes a short answer from them.
+	///
+	/// Every returned sentence carries at least one verbatim quote from a retrieved note. The
+	/// answer is refused when retrieval finds nothing, the extractor declines, or the overall
+	/// grounding falls below `search.answer.min_grounding`.
+	pub async fn search_answer(&self, req: SearchAnswerRequest) -> Result<SearchAnswerResponse> {
+		let Some(answer_cfg) = self.cfg.search.answer.as_ref().filter(|answer| answer.enabled)
+		else {
+			return Err(Error::InvalidRequest {
+				message: "search_answer is disabled; set search.answer.enabled to true."
+					.to_string(),
+			});
+		};
+		let SearchAnswerRequest { mut search, mode } = req;
+		let max_sources = answer_cfg.max_sources;
+
+		search.top_k = Some(search.top_k.map_or(max_sources, |top_k| top_k.min(max_sources)));
+
+		let tenant_id = search.tenant_id.trim().to_string();
+		let query = search.query.clone();
+		let response = self
+			.search_v2(SearchV2Request { search, mode, delivery: SearchV2Delivery::Raw })
+			.await?;
+		let trace_id = response.trace_id;
+		let warnings = response.warnings;
+		let mut seen = HashSet::new();
+		let source_note_ids: Vec<Uuid> = response
+			.items
+			.iter()
+			.map(|item| item.note_id)
+			.filter(|note_id| seen.insert(*note_id))
+			.collect();
+		let sources = self.load_answer_sources(tenant_id.as_str(), &source_note_ids).await?;
+
+		if sources.is_empty() {
+			return Ok(SearchAnswerResponse::refused(
+				trace_id,
+				SearchAnswerRefusal::NoSources,
+				0.0,
+				source_note_ids,
+				warnings,
+			));
+		}
+
+		let messages =
+			prompt::build_answer_messages(query.as_str(), &sources, answer_cfg.max_sentences);
+		let raw =
+			self.providers.extractor.extract(&self.cfg.providers.llm_extractor, &messages).await?;
+		let draft = prompt::parse_answer_draft(raw)?;
+
+		if draft.refused || draft.sentences.is_empty() {
+			return Ok(SearchAnswerResponse::refused(
+				trace_id,
+				SearchAnswerRefusal::ExtractorRefused,
+				0.0,
+				source_note_ids,
+				warnings,
+			));
+		}
+
+		let grounded = grounding::ground_sentences(
+			&draft.sentences,
+			&sources,
+			answer_cfg.max_sentences as usize,
+			answer_cfg.min_grounding,
+		);
+
+		if grounded.sentences.is_empty() || grounded.grounding < answer_cfg.min_grounding {
+			return Ok(SearchAnswerResponse::refused(
+				trace_id,
+				SearchAnswerRefusal::LowGrounding,
+				grounded.grounding,
+				source_note_ids,
+				warnings,
+			));
+		}
+
+		let answer = grounded
+			.sentences
+			.iter()
+			.map(|sentence| sentence.text.as_str())
+			.collect::<Vec<_>>()
+			.join(" ");
+
+		Ok(SearchAnswerResponse {
+			trace_id,
+			answer: Some(answer),
+			sentences: grounded.sentences,
+			grounding: grounded.grounding,
+			refused: false,
+			refusal_reason: None,
+			source_note_ids,
+			warnings,
+		})
+	}
+
+	/// Loads full note text for the retrieved notes. Search already applied read access, so the
+	/// lookup is bounded to the tenant only.
+	async fn load_answer_sources(
+		&self,
+		tenant_id: &str,
+		note_ids: &[Uuid],
+	) -> Result<Vec<AnswerSource>> {
+		if note_ids.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let rows: Vec<(Uuid, String)> = sqlx::query_as(
+			"\
+SELECT note_id, text
+FROM memory_notes
+WHERE note_id = ANY($1)
+	AND tenant_id = $2",
+		)
+		.bind(note_ids)
+		.bind(tenant_id)
+		.fetch_all(&self.db.pool)
+		.await?;
+		let mut texts: HashMap<Uuid, String> = rows.into_iter().collect();
+
+		Ok(note_ids
+			.iter()
+			.filter_map(|note_id| {
+				texts.remove(note_id).map(|text| AnswerSource { note_id: *note_id, text })
+			})
+			.collect())
+	}
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use super::{
+	AnswerSource, SearchAnswerCitation, SearchAnswerSentence,
+	prompt::{DraftCitation, DraftSentence},
+};
+use elf_domain::english_gate;
+
+const MIN_CONTENT_TOKEN_CHARS: usize = 3;
+
+pub(super) struct GroundedAnswer {
+	pub sentences: Vec<SearchAnswerSentence>,
+	pub grounding: f32,
+}
+
+/// Keeps drafted sentences that are English, cite at least one verbatim quote, and reach
+/// `min_grounding`, up to `max_sentences`.
+///
+/// The overall score averages sentence grounding across every considered draft sentence, so
+/// dropped sentences pull the answer towards refusal.
+pub(super) fn ground_sentences(
+	draft: &[DraftSentence],
+	sources: &[AnswerSource],
+	max_sentences: usize,
+	min_grounding: f32,
+) -> GroundedAnswer {
+	let considered = &draft[..draft.len().min(max_sentences)];
+	let mut sentences = Vec::new();
+	let mut total = 0.0_f32;
+
+	for sentence in considered {
+		if !english_gate::is_english_natural_language(sentence.text.as_str()) {
+			continue;
+		}
+
+		let citations: Vec<SearchAnswerCitation> =
+			sentence.citations.iter().filter_map(|citation| locate(citation, sources)).collect();
+
+		if citations.is_empty() {
+			continue;
+		}
+
+		let grounding = sentence_grounding(
+			sentence.text.as_str(),
+			citations.iter().map(|citation| citation.quote.as_str()),
+		);
+
+		if grounding < min_grounding {
+			continue;
+		}
+
+		total += grounding;
+
+		sentences.push(SearchAnswerSentence { text: sentence.text.clone(), grounding, citations });
+	}
+
+	let grounding = if considered.is_empty() { 0.0 } else { total / considered.len() as f32 };
+
+	GroundedAnswer { sentences, grounding }
+}
+
+/// Resolves a drafted citation to byte offsets of its quote within the cited note text.
+fn locate(citation: &DraftCitation, sources: &[AnswerSource]) -> Option<SearchAnswerCitation> {
+	let source = sources.get(citation.source.checked_sub(1)?)?;
+	let quote = citation.quote.trim();
+
+	if quote.is_empty() {
+		return None;
+	}
+
+	let start = source.text.find(quote)?;
+	let end = start + quote.len();
+
+	Some(SearchAnswerCitation {
+		note_id: source.note_id,
+		start_offset: i32::try_from(start).ok()?,
+		end_offset: i32::try_from(end).ok()?,
+		quote: quote.to_string(),
+	})
+}
+
+/// Share of the sentence's content words that appear in any of its quotes.
+fn sentence_grounding<'a>(sentence: &str, quotes: impl Iterator<Item = &'a str>) -> f32 {
+	let sentence_tokens = content_tokens(sentence);
+
+	if sentence_tokens.is_empty() {
+		return 0.0;
+	}
+
+	let quote_tokens: HashSet<String> = quotes.flat_map(content_tokens).collect();
+	let supported = sentence_tokens.iter().filter(|token| quote_tokens.contains(*token)).count();
+
+	supported as f32 / sentence_tokens.len() as f32
+}
+
+fn content_tokens(text: &str) -> HashSet<String> {
+	text.split(|ch: char| !ch.is_alphanumeric())
+		.filter(|token| token.chars().count() >= MIN_CONTENT_TOKEN_CHARS)
+		.map(str::to_lowercase)
+		.collect()
+}
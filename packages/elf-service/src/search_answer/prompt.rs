@@ -0,0 +1,99 @@
+use serde_json::Value;
+
+use super::AnswerSource;
+use crate::{Error, Result};
+
+pub(super) struct DraftCitation {
+	/// One-based index into the numbered sources.
+	pub source: usize,
+	pub quote: String,
+}
+
+pub(super) struct DraftSentence {
+	pub text: String,
+	pub citations: Vec<DraftCitation>,
+}
+
+pub(super) struct AnswerDraft {
+	pub refused: bool,
+	pub sentences: Vec<DraftSentence>,
+}
+
+pub(super) fn build_answer_messages(
+	query: &str,
+	sources: &[AnswerSource],
+	max_sentences: u32,
+) -> Vec<Value> {
+	let schema = serde_json::json!({
+		"refused": "boolean",
+		"sentences": [{
+			"text": "string",
+			"citations": [{ "source": "integer", "quote": "string" }]
+		}]
+	});
+	let schema_text = serde_json::to_string_pretty(&schema).unwrap_or_else(|_| {
+		"{\"refused\": \"boolean\", \"sentences\": [{\"text\": \"string\", \"citations\": [{\"source\": \"integer\", \"quote\": \"string\"}]}]}".to_string()
+	});
+	let system_prompt = format!(
+		"You are an answer engine for an agent memory system. \
+Output must be valid JSON only and must match the provided schema exactly. \
+Answer the question in at most {max_sentences} short English sentences using only the numbered sources. \
+Every sentence must cite at least one source by number and include a quote copied verbatim from that source. \
+Do not state anything the quotes do not support and do not invent details. \
+If the sources do not answer the question, set refused to true and return no sentences. \
+Do not include any non-English text. Do not add explanations or extra fields."
+	);
+	let sources_text = sources
+		.iter()
+		.enumerate()
+		.map(|(idx, source)| format!("[{}] {}", idx + 1, source.text))
+		.collect::<Vec<_>>()
+		.join("\n");
+	let user_prompt = format!(
+		"Return JSON matching this exact schema:\n{schema_text}\nQuestion:\n{query}\nSources:\n{sources_text}"
+	);
+
+	vec![
+		serde_json::json!({ "role": "system", "content": system_prompt }),
+		serde_json::json!({ "role": "user", "content": user_prompt }),
+	]
+}
+
+pub(super) fn parse_answer_draft(raw: Value) -> Result<AnswerDraft> {
+	let Some(object) = raw.as_object() else {
+		return Err(Error::Provider {
+			message: "Answer extractor response must be a JSON object.".to_string(),
+		});
+	};
+	let refused = object.get("refused").and_then(Value::as_bool).unwrap_or(false);
+	let sentences = object
+		.get("sentences")
+		.and_then(Value::as_array)
+		.map(|sentences| sentences.iter().filter_map(parse_sentence).collect())
+		.unwrap_or_default();
+
+	Ok(AnswerDraft { refused, sentences })
+}
+
+fn parse_sentence(value: &Value) -> Option<DraftSentence> {
+	let text = value.get("text").and_then(Value::as_str).map(str::trim)?;
+
+	if text.is_empty() {
+		return None;
+	}
+
+	let citations = value
+		.get("citations")
+		.and_then(Value::as_array)
+		.map(|citations| citations.iter().filter_map(parse_citation).collect())
+		.unwrap_or_default();
+
+	Some(DraftSentence { text: text.to_string(), citations })
+}
+
+fn parse_citation(value: &Value) -> Option<DraftCitation> {
+	let source = value.get("source").and_then(Value::as_u64)?;
+	let quote = value.get("quote").and_then(Value::as_str)?;
+
+	Some(DraftCitation { source: usize::try_from(source).ok()?, quote: quote.to_string() })
+}
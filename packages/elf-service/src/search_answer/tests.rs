@@ -0,0 +1,72 @@
+use uuid::Uuid;
+
+use crate::search_answer::{AnswerSource, grounding, prompt};
+
+fn sources() -> Vec<AnswerSource> {
+	vec![
+		AnswerSource {
+			note_id: Uuid::from_u128(1),
+			text: "Deployments to production run every Friday at noon.".to_string(),
+		},
+		AnswerSource {
+			note_id: Uuid::from_u128(2),
+			text: "The billing service owns invoice generation.".to_string(),
+		},
+	]
+}
+
+#[test]
+fn grounded_sentences_get_note_offsets_and_unsupported_ones_are_dropped() {
+	let draft = prompt::parse_answer_draft(serde_json::json!({
+		"refused": false,
+		"sentences": [
+			{
+				"text": "Production deployments run every Friday at noon.",
+				"citations": [{ "source": 1, "quote": "run every Friday at noon" }]
+			},
+			{
+				"text": "Invoices are emailed to customers weekly.",
+				"citations": [{ "source": 2, "quote": "invoices are emailed weekly" }]
+			}
+		]
+	}))
+	.expect("Draft should parse.");
+	let sources = sources();
+	let grounded = grounding::ground_sentences(&draft.sentences, &sources, 4, 0.5);
+
+	assert_eq!(grounded.sentences.len(), 1);
+
+	let citation = &grounded.sentences[0].citations[0];
+
+	assert_eq!(citation.note_id, Uuid::from_u128(1));
+	assert_eq!(
+		&sources[0].text[citation.start_offset as usize..citation.end_offset as usize],
+		"run every Friday at noon"
+	);
+	assert!(grounded.grounding < 0.5);
+}
+
+#[test]
+fn citations_to_unknown_sources_do_not_ground_a_sentence() {
+	let draft = prompt::parse_answer_draft(serde_json::json!({
+		"sentences": [{
+			"text": "The billing service owns invoice generation.",
+			"citations": [{ "source": 3, "quote": "The billing service owns invoice generation." }]
+		}]
+	}))
+	.expect("Draft should parse.");
+	let grounded = grounding::ground_sentences(&draft.sentences, &sources(), 4, 0.0);
+
+	assert!(grounded.sentences.is_empty());
+	assert_eq!(grounded.grounding, 0.0);
+}
+
+#[test]
+fn extractor_refusal_and_non_object_responses_are_handled() {
+	let draft = prompt::parse_answer_draft(serde_json::json!({ "refused": true, "sentences": [] }))
+		.expect("Refusal should parse.");
+
+	assert!(draft.refused);
+	assert!(draft.sentences.is_empty());
+	assert!(prompt::parse_answer_draft(serde_json::json!(["not", "an", "object"])).is_err());
+}
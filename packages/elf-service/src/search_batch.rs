@@ -0,0 +1,79 @@
+//! Multi-query search that shares query embedding calls across one request.
+
+use futures::future;
+use serde::{Deserialize, Serialize};
+
+use crate::{ElfService, Error, Result, SearchIndexResponse, SearchRequest, SearchV2Mode};
+
+/// Maximum number of searches accepted in one batch.
+pub const MAX_SEARCH_BATCH_QUERIES: usize = 8;
+
+/// Request payload for batch search.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchBatchRequest {
+	/// Searches to run, at most [`MAX_SEARCH_BATCH_QUERIES`].
+	pub searches: Vec<SearchRequest>,
+	#[serde(default)]
+	/// Retrieval mode applied to every search.
+	pub mode: SearchV2Mode,
+}
+
+/// Response payload for batch search.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchBatchResponse {
+	/// Retrieval mode that served the request.
+	pub mode: SearchV2Mode,
+	/// One indexed result per search, in request order.
+	pub results: Vec<SearchIndexResponse>,
+}
+
+impl ElfService {
+	/// Runs up to [`MAX_SEARCH_BATCH_QUERIES`] searches concurrently and returns one indexed
+	/// result per search.
+	///
+	/// Query vectors for every search are embedded before the searches start, with one provider
+	/// call per embedding provider for the whole batch. Each search otherwise behaves like
+	/// [`ElfService::search_quick`] or [`ElfService::search`]: it stores its own session and
+	/// trace, and reads and writes the expansion and rerank caches as usual, so repeated queries
+	/// in a batch hit the caches written by earlier requests. The batch fails if any search fails.
+	pub async fn search_batch(&self, req: SearchBatchRequest) -> Result<SearchBatchResponse> {
+		let SearchBatchRequest { searches, mode } = req;
+
+		if searches.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "searches must contain at least one search.".to_string(),
+			});
+		}
+		if searches.len() > MAX_SEARCH_BATCH_QUERIES {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"searches must contain at most {MAX_SEARCH_BATCH_QUERIES} searches."
+				),
+			});
+		}
+
+		let run_all = future::join_all(
+			searches.iter().cloned().map(|search| self.search_for_mode(search, mode)),
+		);
+		let results = self
+			.with_batch_query_vectors(&searches, run_all)
+			.await?
+			.into_iter()
+			.collect::<Result<Vec<_>>>()?;
+
+		tracing::debug!(mode = mode.as_str(), search_count = results.len(), "Batch search served.");
+
+		Ok(SearchBatchResponse { mode, results })
+	}
+
+	async fn search_for_mode(
+		&self,
+		req: SearchRequest,
+		mode: SearchV2Mode,
+	) -> Result<SearchIndexResponse> {
+		match mode {
+			SearchV2Mode::QuickFind => self.search_quick(req).await,
+			SearchV2Mode::PlannedSearch => self.search(req).await,
+		}
+	}
+}
@@ -0,0 +1,223 @@
+//! Stage-level hooks that embedders attach to the search pipeline.
+
+use std::{collections::BTreeMap, sync::Arc, time::Instant};
+
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{BoxFuture, ElfService, Result, SearchWarning};
+
+/// Search pipeline stage at which a hook runs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchHookStage {
+	/// After request validation, before any retrieval. Candidates are empty.
+	PreRetrieval,
+	/// After retrieval sources are merged, before filtering and scoring.
+	PostRetrieval,
+	/// After rerank and blend scoring, before chunk deduplication.
+	PostRerank,
+	/// After chunk deduplication, before diversity selection and top-k truncation.
+	PreSelection,
+}
+impl SearchHookStage {
+	/// Every stage, in pipeline order.
+	pub const ALL: [Self; 4] =
+		[Self::PreRetrieval, Self::PostRetrieval, Self::PostRerank, Self::PreSelection];
+
+	/// Returns the wire name of the stage.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::PreRetrieval => "pre_retrieval",
+			Self::PostRetrieval => "post_retrieval",
+			Self::PostRerank => "post_rerank",
+			Self::PreSelection => "pre_selection",
+		}
+	}
+}
+
+/// Read-only view of one candidate passed to a hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchHookCandidate {
+	/// Note identifier.
+	pub note_id: Uuid,
+	/// Chunk identifier.
+	pub chunk_id: Uuid,
+	/// One-based rank from retrieval.
+	pub retrieval_rank: u32,
+	/// Retrieval score before `post_rerank`, final blended score from `post_rerank` on.
+	pub score: Option<f32>,
+}
+
+/// Read-only context passed to a hook at one stage.
+#[derive(Clone, Copy, Debug)]
+pub struct SearchStageContext<'a> {
+	/// Stage being run.
+	pub stage: SearchHookStage,
+	/// Search trace identifier.
+	pub trace_id: Uuid,
+	/// Tenant identifier.
+	pub tenant_id: &'a str,
+	/// Project identifier.
+	pub project_id: &'a str,
+	/// Agent identifier.
+	pub agent_id: &'a str,
+	/// Read profile used for scope resolution.
+	pub read_profile: &'a str,
+	/// Original query text.
+	pub query: &'a str,
+	/// Candidates at this stage, in pipeline order.
+	pub candidates: &'a [SearchHookCandidate],
+}
+
+/// Output a hook may attach to the search.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchHookOutput {
+	/// Messages surfaced as `search_hook` warnings on the response.
+	pub warnings: Vec<String>,
+	/// Named metrics recorded on the search trace.
+	pub metrics: BTreeMap<String, f64>,
+}
+
+/// Custom check attached to search pipeline stages.
+///
+/// Hooks observe the pipeline and cannot change candidates or ranking. A hook error is recorded
+/// on the trace and surfaced as a warning; it never fails the search.
+pub trait SearchStageHook
+where
+	Self: Send + Sync,
+{
+	/// Stable hook name recorded in traces and warnings.
+	fn name(&self) -> &str;
+
+	/// Stages this hook runs at. Defaults to every stage.
+	fn stages(&self) -> &[SearchHookStage] {
+		&SearchHookStage::ALL
+	}
+
+	/// Runs the hook for one stage.
+	fn on_stage<'a>(
+		&'a self,
+		ctx: &'a SearchStageContext<'a>,
+	) -> BoxFuture<'a, Result<SearchHookOutput>>;
+}
+
+/// Recorded outcome of one hook invocation.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct SearchHookRun {
+	pub(crate) hook: String,
+	pub(crate) stage: SearchHookStage,
+	pub(crate) elapsed_ms: f64,
+	pub(crate) warnings: Vec<String>,
+	pub(crate) metrics: BTreeMap<String, f64>,
+	pub(crate) error: Option<String>,
+}
+
+impl ElfService {
+	/// Registers a search stage hook. Hooks run in registration order.
+	pub fn with_search_hook(mut self, hook: Arc<dyn SearchStageHook>) -> Self {
+		self.search_hooks.push(hook);
+
+		self
+	}
+
+	/// Runs every hook registered for `stage` and appends one run record per hook.
+	///
+	/// `candidates` is only evaluated when at least one hook is registered, so searches without
+	/// hooks pay no conversion cost.
+	pub(crate) async fn run_search_hooks(
+		&self,
+		base: &SearchStageContext<'_>,
+		stage: SearchHookStage,
+		candidates: impl FnOnce() -> Vec<SearchHookCandidate>,
+		runs: &mut Vec<SearchHookRun>,
+	) {
+		if self.search_hooks.is_empty() {
+			return;
+		}
+
+		let candidates = candidates();
+		let ctx = SearchStageContext { stage, candidates: &candidates, ..*base };
+
+		for hook in &self.search_hooks {
+			if !hook.stages().contains(&ctx.stage) {
+				continue;
+			}
+
+			let started = Instant::now();
+			let outcome = hook.on_stage(&ctx).await;
+			let elapsed_ms = started.elapsed().as_secs_f64() * 1_000.0;
+			let run = build_run(hook.name(), ctx.stage, elapsed_ms, outcome);
+
+			if let Some(error) = run.error.as_deref() {
+				tracing::warn!(
+					trace_id = %ctx.trace_id,
+					hook = %run.hook,
+					stage = ctx.stage.as_str(),
+					error,
+					"Search hook failed."
+				);
+			}
+
+			runs.push(run);
+		}
+	}
+}
+
+pub(crate) fn build_run(
+	hook: &str,
+	stage: SearchHookStage,
+	elapsed_ms: f64,
+	outcome: Result<SearchHookOutput>,
+) -> SearchHookRun {
+	let (output, error) = match outcome {
+		Ok(output) => (output, None),
+		Err(err) => (SearchHookOutput::default(), Some(err.to_string())),
+	};
+
+	SearchHookRun {
+		hook: hook.to_string(),
+		stage,
+		elapsed_ms,
+		warnings: output.warnings,
+		metrics: output.metrics.into_iter().filter(|(_, value)| value.is_finite()).collect(),
+		error,
+	}
+}
+
+/// Converts hook runs into response warnings: one per hook message and one per hook error.
+pub(crate) fn hook_warnings(runs: &[SearchHookRun]) -> Vec<SearchWarning> {
+	let mut warnings = Vec::new();
+
+	for run in runs {
+		for message in &run.warnings {
+			warnings.push(SearchWarning::SearchHook {
+				hook: run.hook.clone(),
+				stage: run.stage.as_str().to_string(),
+				message: message.clone(),
+			});
+		}
+
+		if let Some(error) = &run.error {
+			warnings.push(SearchWarning::SearchHook {
+				hook: run.hook.clone(),
+				stage: run.stage.as_str().to_string(),
+				message: format!("Hook failed: {error}"),
+			});
+		}
+	}
+
+	warnings
+}
+
+/// Builds the `hooks` trace snapshot entry, or `None` when no hook ran.
+pub(crate) fn hook_runs_snapshot(runs: &[SearchHookRun]) -> Option<Value> {
+	if runs.is_empty() {
+		return None;
+	}
+
+	serde_json::to_value(runs).ok()
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use crate::{
+	Error, SearchHookOutput, SearchHookStage, SearchWarning,
+	search_hooks::{self, SearchHookRun},
+};
+
+fn run(
+	hook: &str,
+	stage: SearchHookStage,
+	warnings: &[&str],
+	error: Option<&str>,
+) -> SearchHookRun {
+	SearchHookRun {
+		hook: hook.to_string(),
+		stage,
+		elapsed_ms: 1.5,
+		warnings: warnings.iter().map(|warning| warning.to_string()).collect(),
+		metrics: BTreeMap::new(),
+		error: error.map(str::to_string),
+	}
+}
+
+#[test]
+fn build_run_records_errors_without_output() {
+	let run = search_hooks::build_run(
+		"policy",
+		SearchHookStage::PostRerank,
+		2.0,
+		Err(Error::InvalidRequest { message: "bad tenant".to_string() }),
+	);
+
+	assert!(run.warnings.is_empty());
+	assert!(run.metrics.is_empty());
+	assert!(run.error.as_deref().is_some_and(|error| error.contains("bad tenant")));
+}
+
+#[test]
+fn build_run_drops_non_finite_metrics() {
+	let output = SearchHookOutput {
+		warnings: vec!["low recall".to_string()],
+		metrics: BTreeMap::from([
+			("candidates".to_string(), 12.0),
+			("ratio".to_string(), f64::NAN),
+			("spread".to_string(), f64::INFINITY),
+		]),
+	};
+	let run = search_hooks::build_run("policy", SearchHookStage::PostRetrieval, 0.5, Ok(output));
+
+	assert_eq!(run.metrics.keys().collect::<Vec<_>>(), vec!["candidates"]);
+	assert_eq!(run.warnings, vec!["low recall".to_string()]);
+	assert!(run.error.is_none());
+}
+
+#[test]
+fn hook_warnings_cover_messages_and_failures() {
+	let warnings = search_hooks::hook_warnings(&[
+		run("policy", SearchHookStage::PreSelection, &["stale note"], None),
+		run("audit", SearchHookStage::PreRetrieval, &[], Some("timeout")),
+	]);
+
+	assert_eq!(
+		warnings,
+		vec![
+			SearchWarning::SearchHook {
+				hook: "policy".to_string(),
+				stage: "pre_selection".to_string(),
+				message: "stale note".to_string(),
+			},
+			SearchWarning::SearchHook {
+				hook: "audit".to_string(),
+				stage: "pre_retrieval".to_string(),
+				message: "Hook failed: timeout".to_string(),
+			},
+		]
+	);
+}
+
+#[test]
+fn hook_runs_snapshot_is_omitted_without_runs() {
+	assert!(search_hooks::hook_runs_snapshot(&[]).is_none());
+
+	let snapshot =
+		search_hooks::hook_runs_snapshot(&[run("policy", SearchHookStage::PostRerank, &[], None)])
+			.expect("Expected a hooks snapshot.");
+
+	assert_eq!(snapshot[0]["hook"], "policy");
+	assert_eq!(snapshot[0]["stage"], "post_rerank");
+	assert_eq!(snapshot[0]["elapsed_ms"], 1.5);
+}
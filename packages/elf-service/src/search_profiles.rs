@@ -0,0 +1,12 @@
+//! Named search profiles: stored search templates that agents invoke by name.
+
+mod service;
+mod types;
+
+pub use types::{
+	SearchProfileDeleteResponse, SearchProfileGetRequest, SearchProfileRef, SearchProfileResponse,
+	SearchProfileTemplate, SearchProfileUpsertRequest, SearchProfilesListRequest,
+	SearchProfilesListResponse, SearchWithProfileRequest, SearchWithProfileResponse,
+};
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,351 @@
+use time::OffsetDateTime;
+
+use crate::{
+	ElfService, Error, Result, SearchRequest, SearchV2Delivery, SearchV2Request,
+	audit::AuditScope,
+	search,
+	search_profiles::types::{
+		SearchProfileDeleteResponse, SearchProfileGetRequest, SearchProfileRef,
+		SearchProfileResponse, SearchProfileTemplate, SearchProfileUpsertRequest,
+		SearchProfilesListRequest, SearchProfilesListResponse, SearchWithProfileRequest,
+		SearchWithProfileResponse,
+	},
+};
+use elf_config::Config;
+use elf_domain::english_gate;
+use elf_storage::{models::SearchProfile, search_profiles};
+
+const MAX_SEARCH_PROFILES_PER_PROJECT: i64 = 100;
+const MAX_NAME_CHARS: usize = 64;
+const MAX_DESCRIPTION_CHARS: usize = 512;
+pub(in crate::search_profiles) const MAX_TOP_K: u32 = 100;
+pub(in crate::search_profiles) const MAX_CANDIDATE_K: u32 = 1_000;
+
+impl ElfService {
+	/// Creates a search profile, or replaces the template and description of an existing one.
+	///
+	/// The template is validated the way a search request would be, including its ranking
+	/// overrides, so a stored profile always runs.
+	pub async fn search_profile_upsert(
+		&self,
+		req: SearchProfileUpsertRequest,
+	) -> Result<SearchProfileResponse> {
+		let scope = AuditScope::new("admin_search_profile_put").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.search_profile_upsert_inner(req)).await
+	}
+
+	/// Runs [`ElfService::search_profile_upsert`] without recording an audit entry.
+	async fn search_profile_upsert_inner(
+		&self,
+		req: SearchProfileUpsertRequest,
+	) -> Result<SearchProfileResponse> {
+		validate_context(&req.tenant_id, &req.project_id)?;
+
+		let agent_id = req.agent_id.trim();
+
+		if agent_id.is_empty() {
+			return Err(Error::InvalidRequest { message: "agent_id is required.".to_string() });
+		}
+
+		let name = validate_name(&req.name)?;
+		let description = req.description.as_deref().map(validate_description).transpose()?;
+
+		validate_template(&self.cfg, &req.template)?;
+
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let existing =
+			search_profiles::get_search_profile(&self.db.pool, tenant_id, project_id, name).await?;
+
+		if existing.is_none()
+			&& search_profiles::count_search_profiles(&self.db.pool, tenant_id, project_id).await?
+				>= MAX_SEARCH_PROFILES_PER_PROJECT
+		{
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"A project may store at most {MAX_SEARCH_PROFILES_PER_PROJECT} search profiles."
+				),
+			});
+		}
+
+		let now = OffsetDateTime::now_utc();
+		let template = serde_json::to_value(&req.template)
+			.map_err(|err| Error::InvalidRequest { message: err.to_string() })?;
+		let row = search_profiles::upsert_search_profile(
+			&self.db.pool,
+			&SearchProfile {
+				tenant_id: tenant_id.to_string(),
+				project_id: project_id.to_string(),
+				name: name.to_string(),
+				description,
+				template,
+				version: 1,
+				created_by: agent_id.to_string(),
+				updated_by: agent_id.to_string(),
+				created_at: now,
+				updated_at: now,
+			},
+		)
+		.await?;
+
+		row_to_response(&self.cfg, row)
+	}
+
+	/// Lists the search profiles of one project.
+	pub async fn search_profiles_list(
+		&self,
+		req: SearchProfilesListRequest,
+	) -> Result<SearchProfilesListResponse> {
+		validate_context(&req.tenant_id, &req.project_id)?;
+
+		let rows = search_profiles::list_search_profiles(
+			&self.db.pool,
+			req.tenant_id.trim(),
+			req.project_id.trim(),
+		)
+		.await?;
+		let items = rows
+			.into_iter()
+			.map(|row| row_to_response(&self.cfg, row))
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(SearchProfilesListResponse { items })
+	}
+
+	/// Reads one search profile.
+	pub async fn search_profile_get(
+		&self,
+		req: SearchProfileGetRequest,
+	) -> Result<SearchProfileResponse> {
+		let row = self.load_search_profile(&req).await?;
+
+		row_to_response(&self.cfg, row)
+	}
+
+	/// Deletes one search profile.
+	pub async fn search_profile_delete(
+		&self,
+		req: SearchProfileGetRequest,
+	) -> Result<SearchProfileDeleteResponse> {
+		let scope = AuditScope::new("admin_search_profile_delete").caller(
+			&req.tenant_id,
+			&req.project_id,
+			"",
+		);
+
+		self.audited(scope, self.search_profile_delete_inner(req)).await
+	}
+
+	/// Runs [`ElfService::search_profile_delete`] without recording an audit entry.
+	async fn search_profile_delete_inner(
+		&self,
+		req: SearchProfileGetRequest,
+	) -> Result<SearchProfileDeleteResponse> {
+		validate_context(&req.tenant_id, &req.project_id)?;
+
+		let name = req.name.trim();
+		let deleted = search_profiles::delete_search_profile(
+			&self.db.pool,
+			req.tenant_id.trim(),
+			req.project_id.trim(),
+			name,
+		)
+		.await?;
+
+		if !deleted {
+			return Err(search_profile_not_found());
+		}
+
+		Ok(SearchProfileDeleteResponse {
+			name: name.to_string(),
+			deleted_at: OffsetDateTime::now_utc(),
+		})
+	}
+
+	/// Runs a session-delivered search from a named profile.
+	///
+	/// The profile supplies mode, filters, ranking overrides, and limits. The caller supplies the
+	/// query and may override `top_k`, the payload level, the `updated_at` window, and session
+	/// settings.
+	pub async fn search_with_profile(
+		&self,
+		req: SearchWithProfileRequest,
+	) -> Result<SearchWithProfileResponse> {
+		let row = self
+			.load_search_profile(&SearchProfileGetRequest {
+				tenant_id: req.tenant_id.clone(),
+				project_id: req.project_id.clone(),
+				name: req.profile.clone(),
+			})
+			.await?;
+		let profile = row_to_response(&self.cfg, row)?;
+		let template = profile.template;
+		let top_k = req.top_k.or(template.top_k);
+
+		if top_k.is_some_and(|top_k| top_k == 0 || top_k > MAX_TOP_K) {
+			return Err(Error::InvalidRequest {
+				message: format!("top_k must be between 1 and {MAX_TOP_K}."),
+			});
+		}
+
+		let search = self
+			.search_v2(SearchV2Request {
+				search: SearchRequest {
+					tenant_id: req.tenant_id,
+					project_id: req.project_id,
+					agent_id: req.agent_id,
+					token_id: req.token_id,
+					payload_level: req.payload_level.or(template.payload_level).unwrap_or_default(),
+					read_profile: req.read_profile,
+					query: req.query,
+					top_k,
+					candidate_k: template.candidate_k,
+					filter: template.filter,
+					types: template.types,
+					updated_after: req.updated_after,
+					updated_before: req.updated_before,
+					min_importance: template.min_importance,
+					max_result_tokens: template.max_result_tokens,
+					record_hits: Some(false),
+					session_id: req.session_id,
+					session_mode: req.session_mode,
+					ranking: template.ranking,
+				},
+				mode: template.mode,
+				delivery: SearchV2Delivery::Session,
+			})
+			.await?;
+
+		Ok(SearchWithProfileResponse {
+			profile: SearchProfileRef {
+				name: profile.name,
+				version: profile.version,
+				ranking_policy_id: profile.ranking_policy_id,
+			},
+			search,
+		})
+	}
+
+	async fn load_search_profile(&self, req: &SearchProfileGetRequest) -> Result<SearchProfile> {
+		validate_context(&req.tenant_id, &req.project_id)?;
+
+		search_profiles::get_search_profile(
+			&self.db.pool,
+			req.tenant_id.trim(),
+			req.project_id.trim(),
+			req.name.trim(),
+		)
+		.await?
+		.ok_or_else(search_profile_not_found)
+	}
+}
+
+pub(in crate::search_profiles) fn validate_name(raw: &str) -> Result<&str> {
+	let name = raw.trim();
+
+	if name.is_empty()
+		|| name.len() > MAX_NAME_CHARS
+		|| !name.bytes().all(|byte| {
+			byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_' || byte == b'-'
+		}) {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"name must be 1 to {MAX_NAME_CHARS} lowercase ASCII letters, digits, '_', or '-'."
+			),
+		});
+	}
+
+	Ok(name)
+}
+
+/// Validates a template and returns the ranking-policy identifier it resolves to.
+pub(in crate::search_profiles) fn validate_template(
+	cfg: &Config,
+	template: &SearchProfileTemplate,
+) -> Result<String> {
+	if template.top_k.is_some_and(|top_k| top_k == 0 || top_k > MAX_TOP_K) {
+		return Err(Error::InvalidRequest {
+			message: format!("$.template.top_k must be between 1 and {MAX_TOP_K}."),
+		});
+	}
+	if template
+		.candidate_k
+		.is_some_and(|candidate_k| candidate_k == 0 || candidate_k > MAX_CANDIDATE_K)
+	{
+		return Err(Error::InvalidRequest {
+			message: format!("$.template.candidate_k must be between 1 and {MAX_CANDIDATE_K}."),
+		});
+	}
+	if let Some(filter) = template.filter.as_ref() {
+		search::validate_search_filter(filter)?;
+	}
+
+	for (idx, note_type) in template.types.iter().enumerate() {
+		if note_type.trim().is_empty() {
+			return Err(Error::InvalidRequest {
+				message: format!("$.template.types[{idx}] must be non-empty."),
+			});
+		}
+	}
+
+	if template.min_importance.is_some_and(|value| !(0.0..=1.0).contains(&value)) {
+		return Err(Error::InvalidRequest {
+			message: "$.template.min_importance must be between 0.0 and 1.0.".to_string(),
+		});
+	}
+
+	search::ranking_policy_id(cfg, template.ranking.as_ref())
+}
+
+fn validate_description(raw: &str) -> Result<String> {
+	let description = raw.trim();
+
+	if description.chars().count() > MAX_DESCRIPTION_CHARS {
+		return Err(Error::InvalidRequest {
+			message: format!("description must be at most {MAX_DESCRIPTION_CHARS} characters."),
+		});
+	}
+	if !description.is_empty() && !english_gate::is_english_natural_language(description) {
+		return Err(Error::NonEnglishInput { field: "$.description".to_string() });
+	}
+
+	Ok(description.to_string())
+}
+
+fn validate_context(tenant_id: &str, project_id: &str) -> Result<()> {
+	if tenant_id.trim().is_empty() || project_id.trim().is_empty() {
+		return Err(Error::InvalidRequest {
+			message: "tenant_id and project_id are required.".to_string(),
+		});
+	}
+
+	Ok(())
+}
+
+fn search_profile_not_found() -> Error {
+	Error::NotFound { message: "Search profile not found.".to_string() }
+}
+
+fn row_to_response(cfg: &Config, row: SearchProfile) -> Result<SearchProfileResponse> {
+	let template: SearchProfileTemplate = serde_json::from_value(row.template).map_err(|err| {
+		Error::Storage { message: format!("Stored search profile template is invalid: {err}") }
+	})?;
+	let ranking_policy_id = search::ranking_policy_id(cfg, template.ranking.as_ref())?;
+
+	Ok(SearchProfileResponse {
+		name: row.name,
+		description: row.description,
+		template,
+		ranking_policy_id,
+		version: u32::try_from(row.version).unwrap_or(0),
+		created_by: row.created_by,
+		updated_by: row.updated_by,
+		created_at: row.created_at,
+		updated_at: row.updated_at,
+	})
+}
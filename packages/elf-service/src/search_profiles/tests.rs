@@ -0,0 +1,111 @@
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::{
+	BlendRankingOverride, Error, RankingRequestOverride, search,
+	search_profiles::{
+		SearchProfileTemplate,
+		service::{self, MAX_CANDIDATE_K, MAX_TOP_K},
+	},
+};
+use elf_config::Config;
+
+fn parse_example_config() -> Config {
+	let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+	let path = root_dir.join("elf.example.toml");
+
+	elf_config::load(&path).expect("elf.example.toml must remain parseable and valid.")
+}
+
+#[test]
+fn validate_name_accepts_slugs_and_rejects_everything_else() {
+	assert_eq!(
+		service::validate_name(" decisions-by-month ").expect("valid name"),
+		"decisions-by-month"
+	);
+	assert_eq!(service::validate_name("recent_2").expect("valid name"), "recent_2");
+
+	for raw in ["", "Decisions", "a b", "a/b", &"x".repeat(65)] {
+		assert!(
+			matches!(service::validate_name(raw), Err(Error::InvalidRequest { .. })),
+			"{raw:?} should be rejected"
+		);
+	}
+}
+
+#[test]
+fn validate_template_returns_the_ranking_policy_id() {
+	let cfg = parse_example_config();
+	let base = search::ranking_policy_id(&cfg, None).expect("Expected base policy id.");
+	let plain = SearchProfileTemplate {
+		top_k: Some(MAX_TOP_K),
+		candidate_k: Some(MAX_CANDIDATE_K),
+		types: vec!["decision".to_string()],
+		min_importance: Some(0.3),
+		..Default::default()
+	};
+	let tuned = SearchProfileTemplate {
+		ranking: Some(RankingRequestOverride {
+			blend: Some(BlendRankingOverride {
+				enabled: Some(false),
+				rerank_normalization: None,
+				retrieval_normalization: None,
+				segments: None,
+			}),
+			diversity: None,
+			retrieval_sources: None,
+		}),
+		..Default::default()
+	};
+
+	assert_eq!(service::validate_template(&cfg, &plain).expect("valid template"), base);
+	assert_ne!(service::validate_template(&cfg, &tuned).expect("valid template"), base);
+}
+
+#[test]
+fn validate_template_rejects_out_of_range_limits_and_bad_filters() {
+	let cfg = parse_example_config();
+	let cases = [
+		(SearchProfileTemplate { top_k: Some(0), ..Default::default() }, "top_k"),
+		(SearchProfileTemplate { top_k: Some(MAX_TOP_K + 1), ..Default::default() }, "top_k"),
+		(
+			SearchProfileTemplate { candidate_k: Some(MAX_CANDIDATE_K + 1), ..Default::default() },
+			"candidate_k",
+		),
+		(
+			SearchProfileTemplate { min_importance: Some(1.5), ..Default::default() },
+			"min_importance",
+		),
+		(SearchProfileTemplate { types: vec![" ".to_string()], ..Default::default() }, "types[0]"),
+	];
+
+	for (template, field) in cases {
+		assert!(
+			matches!(
+				service::validate_template(&cfg, &template),
+				Err(Error::InvalidRequest { message }) if message.contains(field)
+			),
+			"{field} should be rejected"
+		);
+	}
+
+	let bad_filter = SearchProfileTemplate {
+		filter: Some(
+			json!({ "schema": "unknown", "expr": { "op": "eq", "field": "scope", "value": "project_shared" } }),
+		),
+		..Default::default()
+	};
+
+	assert!(matches!(
+		service::validate_template(&cfg, &bad_filter),
+		Err(Error::InvalidRequest { .. })
+	));
+}
+
+#[test]
+fn template_rejects_unknown_fields() {
+	let parsed = serde_json::from_value::<SearchProfileTemplate>(json!({ "topk": 5 }));
+
+	assert!(parsed.is_err());
+}
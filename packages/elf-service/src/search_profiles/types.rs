@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::{
+	PayloadLevel, RankingRequestOverride, SearchV2Mode, SearchV2Response, audit::AuditTargets,
+};
+
+/// Search settings stored in a profile and applied each time it is invoked.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SearchProfileTemplate {
+	#[serde(default)]
+	/// Retrieval mode.
+	pub mode: SearchV2Mode,
+	/// Requested number of returned items.
+	pub top_k: Option<u32>,
+	/// Retrieval breadth before ranking and projection.
+	pub candidate_k: Option<u32>,
+	/// Optional structured filter expression.
+	pub filter: Option<Value>,
+	#[serde(default)]
+	/// Note types to keep. Empty keeps every type.
+	pub types: Vec<String>,
+	/// Optional inclusive lower bound for note importance.
+	pub min_importance: Option<f32>,
+	/// Optional token budget for returned snippets.
+	pub max_result_tokens: Option<u32>,
+	/// Default payload-detail level.
+	pub payload_level: Option<PayloadLevel>,
+	/// Optional ranking-policy overrides.
+	pub ranking: Option<RankingRequestOverride>,
+}
+
+/// Request payload for creating or replacing a search profile.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchProfileUpsertRequest {
+	/// Tenant that owns the profile.
+	pub tenant_id: String,
+	/// Project that owns the profile.
+	pub project_id: String,
+	/// Agent recorded as the profile's author.
+	pub agent_id: String,
+	/// Profile name: 1-64 lowercase ASCII letters, digits, `_`, or `-`.
+	pub name: String,
+	/// Optional human-readable description.
+	pub description: Option<String>,
+	/// Search template stored under the name.
+	pub template: SearchProfileTemplate,
+}
+
+/// Request payload for reading or deleting one search profile.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchProfileGetRequest {
+	/// Tenant that owns the profile.
+	pub tenant_id: String,
+	/// Project that owns the profile.
+	pub project_id: String,
+	/// Profile name.
+	pub name: String,
+}
+
+/// Request payload for listing the search profiles of one project.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchProfilesListRequest {
+	/// Tenant that owns the profiles.
+	pub tenant_id: String,
+	/// Project that owns the profiles.
+	pub project_id: String,
+}
+
+/// Stored search profile.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchProfileResponse {
+	/// Profile name.
+	pub name: String,
+	/// Optional human-readable description.
+	pub description: Option<String>,
+	/// Stored search template.
+	pub template: SearchProfileTemplate,
+	/// Ranking-policy identifier produced by the template's ranking overrides.
+	pub ranking_policy_id: String,
+	/// Revision counter, incremented on every update.
+	pub version: u32,
+	/// Agent that created the profile.
+	pub created_by: String,
+	/// Agent that last updated the profile.
+	pub updated_by: String,
+	#[serde(with = "crate::time_serde")]
+	/// Creation timestamp.
+	pub created_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
+
+impl AuditTargets for SearchProfileResponse {}
+
+/// Search profiles of one project.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchProfilesListResponse {
+	/// Profiles in name order.
+	pub items: Vec<SearchProfileResponse>,
+}
+
+/// Result of deleting a search profile.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchProfileDeleteResponse {
+	/// Deleted profile name.
+	pub name: String,
+	#[serde(with = "crate::time_serde")]
+	/// Deletion timestamp.
+	pub deleted_at: OffsetDateTime,
+}
+
+impl AuditTargets for SearchProfileDeleteResponse {}
+
+/// Request payload for running a search from a named profile.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchWithProfileRequest {
+	/// Tenant to search within.
+	pub tenant_id: String,
+	/// Project to search within.
+	pub project_id: String,
+	/// Agent requesting the search.
+	pub agent_id: String,
+	/// Optional auth token identifier used for role checks.
+	pub token_id: Option<String>,
+	/// Read profile that determines visible scopes.
+	pub read_profile: String,
+	/// Search profile name.
+	pub profile: String,
+	/// Search query text.
+	pub query: String,
+	/// Optional override for the profile's `top_k`.
+	pub top_k: Option<u32>,
+	/// Optional override for the profile's payload level.
+	pub payload_level: Option<PayloadLevel>,
+	/// Optional exclusive lower bound for note `updated_at` (RFC3339).
+	pub updated_after: Option<String>,
+	/// Optional exclusive upper bound for note `updated_at` (RFC3339).
+	pub updated_before: Option<String>,
+	/// Caller session that tracks retrieved notes for the within-session ranking term.
+	pub session_id: Option<String>,
+	/// Within-session mode override: `boost`, `penalty`, or `off`. Requires `session_id`.
+	pub session_mode: Option<String>,
+}
+
+/// Profile revision that served a search.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchProfileRef {
+	/// Profile name.
+	pub name: String,
+	/// Profile revision at the time of the search.
+	pub version: u32,
+	/// Ranking-policy identifier the search ran with.
+	pub ranking_policy_id: String,
+}
+
+/// Search run from a named profile.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchWithProfileResponse {
+	/// Profile revision that served the search.
+	pub profile: SearchProfileRef,
+	/// Search response with session delivery.
+	pub search: SearchV2Response,
+}
@@ -0,0 +1,75 @@
+//! Progressive stage events for searches whose caller wants feedback before the final result.
+
+use serde_json::Value;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{ElfService, Result, SearchTrajectorySummaryStage, SearchV2Request, SearchV2Response};
+
+tokio::task_local! {
+	static SEARCH_STAGE_EVENTS: UnboundedSender<SearchTrajectorySummaryStage>;
+}
+
+impl ElfService {
+	/// Runs [`ElfService::search_v2`] and sends one trajectory summary stage to `events` as each
+	/// pipeline stage completes.
+	///
+	/// Stages use the names and `stage_order` of the stored search trajectory: `rewrite.expansion`,
+	/// `recall.candidates`, `fusion.merge`, `rerank.score`, and `selection.final`. Events are sent
+	/// in completion order, so `rerank.score` arrives before `fusion.merge`, which merges the
+	/// reranked chunks per note. Paths that skip a stage, such as `quick_find` searches that do not
+	/// rerank or searches with no readable scope, send only the stages they run. Send failures are
+	/// ignored, so a disconnected receiver never fails the search.
+	pub async fn search_v2_streaming(
+		&self,
+		req: SearchV2Request,
+		events: UnboundedSender<SearchTrajectorySummaryStage>,
+	) -> Result<SearchV2Response> {
+		SEARCH_STAGE_EVENTS.scope(events, self.search_v2(req)).await
+	}
+}
+
+/// Sends a stage event when the current search runs inside
+/// [`ElfService::search_v2_streaming`]; otherwise does nothing.
+pub(crate) fn emit_stage(stage_order: u32, stage_name: &str, item_count: usize, stats: Value) {
+	let _ = SEARCH_STAGE_EVENTS.try_with(|events| {
+		let _ = events.send(SearchTrajectorySummaryStage {
+			stage_order,
+			stage_name: stage_name.to_string(),
+			item_count: u32::try_from(item_count).unwrap_or(u32::MAX),
+			stats,
+		});
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use tokio::sync::mpsc;
+
+	use crate::search_stream::{self, SEARCH_STAGE_EVENTS};
+
+	#[tokio::test]
+	async fn emit_stage_is_a_no_op_outside_a_stream_and_delivers_inside_one() {
+		search_stream::emit_stage(1, "rewrite.expansion", 0, serde_json::json!({}));
+
+		let (tx, mut rx) = mpsc::unbounded_channel();
+
+		SEARCH_STAGE_EVENTS
+			.scope(tx, async {
+				search_stream::emit_stage(
+					2,
+					"recall.candidates",
+					12,
+					serde_json::json!({ "snippet_count": 12 }),
+				);
+			})
+			.await;
+
+		let stage = rx.recv().await.expect("Expected one stage event.");
+
+		assert_eq!(stage.stage_order, 2);
+		assert_eq!(stage.stage_name, "recall.candidates");
+		assert_eq!(stage.item_count, 12);
+		assert_eq!(stage.stats["snippet_count"], 12);
+		assert!(rx.recv().await.is_none());
+	}
+}
@@ -0,0 +1,165 @@
+//! Unified search entry point shared by the raw and progressive search surfaces.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, QueryPlan, Result, SearchIndexItem, SearchItem, SearchRequest,
+	SearchTrajectorySummary, SearchWarning, progressive_search::SearchSessionMode,
+};
+
+/// Retrieval mode selected on a unified search request.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchV2Mode {
+	/// Single-query retrieval without expansion or a query plan.
+	QuickFind,
+	#[default]
+	/// Planned retrieval with query expansion and a query plan.
+	PlannedSearch,
+}
+impl SearchV2Mode {
+	/// Returns the wire name of the mode.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::QuickFind => "quick_find",
+			Self::PlannedSearch => "planned_search",
+		}
+	}
+}
+
+impl From<SearchV2Mode> for SearchSessionMode {
+	fn from(mode: SearchV2Mode) -> Self {
+		match mode {
+			SearchV2Mode::QuickFind => Self::QuickFind,
+			SearchV2Mode::PlannedSearch => Self::PlannedSearch,
+		}
+	}
+}
+
+/// How unified search results are returned.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchV2Delivery {
+	/// Ranked items are returned directly and no session is stored.
+	Raw,
+	#[default]
+	/// Ranked items are stored in a search session for timeline and details follow-ups.
+	Session,
+}
+impl SearchV2Delivery {
+	/// Returns the wire name of the delivery.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Raw => "raw",
+			Self::Session => "session",
+		}
+	}
+}
+
+/// Request payload for the unified search entry point.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchV2Request {
+	#[serde(flatten)]
+	/// Shared search parameters.
+	pub search: SearchRequest,
+	#[serde(default)]
+	/// Retrieval mode.
+	pub mode: SearchV2Mode,
+	#[serde(default)]
+	/// Result delivery.
+	pub delivery: SearchV2Delivery,
+}
+
+/// Response payload for the unified search entry point.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchV2Response {
+	/// Retrieval mode that served the request.
+	pub mode: SearchV2Mode,
+	/// Result delivery that served the request.
+	pub delivery: SearchV2Delivery,
+	/// Search trace identifier.
+	pub trace_id: Uuid,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	/// Ranked items for raw delivery. Empty for session delivery.
+	pub items: Vec<SearchItem>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Stored session for session delivery.
+	pub session: Option<SearchV2Session>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Query plan for planned-search mode.
+	pub query_plan: Option<QueryPlan>,
+	/// Optional condensed explain output.
+	pub trajectory_summary: Option<SearchTrajectorySummary>,
+	#[serde(default)]
+	/// Non-fatal conditions observed while serving the search.
+	pub warnings: Vec<SearchWarning>,
+}
+
+/// Search session created by session delivery.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchV2Session {
+	/// Search session identifier used for follow-up requests.
+	pub search_session_id: Uuid,
+	#[serde(with = "crate::time_serde")]
+	/// Session expiry timestamp.
+	pub expires_at: OffsetDateTime,
+	/// Stored search hits, trimmed to the requested top_k.
+	pub items: Vec<SearchIndexItem>,
+}
+
+impl ElfService {
+	/// Runs search with an explicit retrieval mode and result delivery.
+	///
+	/// Quick and planned raw search and progressive index search are adapters over this entry
+	/// point, so request validation, retrieval, ranking, and trace persistence run on one path
+	/// for every surface.
+	pub async fn search_v2(&self, req: SearchV2Request) -> Result<SearchV2Response> {
+		let SearchV2Request { search, mode, delivery } = req;
+		let response = match delivery {
+			SearchV2Delivery::Raw => {
+				let raw = self.search_raw_for_mode(search, mode).await?;
+
+				SearchV2Response {
+					mode,
+					delivery,
+					trace_id: raw.trace_id,
+					items: raw.items,
+					session: None,
+					query_plan: (mode == SearchV2Mode::PlannedSearch).then_some(raw.query_plan),
+					trajectory_summary: raw.trajectory_summary,
+					warnings: raw.warnings,
+				}
+			},
+			SearchV2Delivery::Session => {
+				let output = self.search_sessionized(search, mode).await?;
+
+				SearchV2Response {
+					mode,
+					delivery,
+					trace_id: output.index.trace_id,
+					items: Vec::new(),
+					session: Some(SearchV2Session {
+						search_session_id: output.index.search_session_id,
+						expires_at: output.index.expires_at,
+						items: output.index.items,
+					}),
+					query_plan: output.query_plan,
+					trajectory_summary: output.index.trajectory_summary,
+					warnings: output.index.warnings,
+				}
+			},
+		};
+
+		tracing::debug!(
+			trace_id = %response.trace_id,
+			mode = mode.as_str(),
+			delivery = delivery.as_str(),
+			warning_count = response.warnings.len(),
+			"Search served."
+		);
+
+		Ok(response)
+	}
+}
@@ -1,4 +1,11 @@
-use crate::Providers;
+use std::sync::{Arc, OnceLock};
+
+use tokenizers::Tokenizer;
+
+use crate::{
+	AuditSink, Error, PostgresAuditSink, Providers, Result, SearchStageHook, docs,
+	quotas::SearchRateLimiter,
+};
 use elf_config::Config;
 use elf_storage::{db::Db, qdrant::QdrantStore};
 
@@ -12,15 +19,169 @@ pub struct ElfService {
 	pub qdrant: QdrantStore,
 	/// External model-provider adapters.
 	pub providers: Providers,
+	tokenizer: OnceLock<Tokenizer>,
+	pub(crate) search_hooks: Vec<Arc<dyn SearchStageHook>>,
+	pub(crate) audit_sinks: Vec<Arc<dyn AuditSink>>,
+	pub(crate) subsystems: ServiceSubsystems,
+	pub(crate) search_limiter: SearchRateLimiter,
 }
 impl ElfService {
 	/// Builds a service with the default provider adapters.
 	pub fn new(cfg: Config, db: Db, qdrant: QdrantStore) -> Self {
-		Self { cfg, db, qdrant, providers: Providers::default() }
+		Self::builder(cfg, db, qdrant).build()
 	}
 
 	/// Builds a service with explicit provider adapters.
 	pub fn with_providers(cfg: Config, db: Db, qdrant: QdrantStore, providers: Providers) -> Self {
-		Self { cfg, db, qdrant, providers }
+		Self::builder(cfg, db, qdrant).providers(providers).build()
+	}
+
+	/// Starts a builder for embedding the service in-process with optional subsystems.
+	pub fn builder(cfg: Config, db: Db, qdrant: QdrantStore) -> ElfServiceBuilder {
+		ElfServiceBuilder {
+			cfg,
+			db,
+			qdrant,
+			providers: Providers::default(),
+			search_hooks: Vec::new(),
+			audit_sinks: Vec::new(),
+			subsystems: ServiceSubsystems::default(),
+		}
+	}
+
+	/// Returns the subsystems enabled for this service.
+	pub fn subsystems(&self) -> ServiceSubsystems {
+		self.subsystems
+	}
+
+	/// Returns the chunking tokenizer, loading it on first use.
+	pub(crate) fn tokenizer(&self) -> Result<&Tokenizer> {
+		if let Some(tokenizer) = self.tokenizer.get() {
+			return Ok(tokenizer);
+		}
+
+		let tokenizer = docs::load_tokenizer(&self.cfg)?;
+
+		Ok(self.tokenizer.get_or_init(|| tokenizer))
+	}
+
+	/// Rejects the call when `enabled` is false for the named subsystem.
+	pub(crate) fn ensure_subsystem(&self, enabled: bool, name: &str) -> Result<()> {
+		if enabled {
+			return Ok(());
+		}
+
+		Err(Error::InvalidRequest { message: format!("The {name} subsystem is disabled.") })
+	}
+
+	/// Whether search expansion and rerank caches are read and written.
+	pub(crate) fn search_cache_enabled(&self) -> bool {
+		self.subsystems.caches && self.cfg.search.cache.enabled
+	}
+}
+
+/// Optional subsystems of an [`ElfService`]. Every subsystem is enabled by default.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ServiceSubsystems {
+	/// Persist search traces for explain, trajectory, and replay reads.
+	pub trace_persistence: bool,
+	/// Ingest graph fields on writes and serve graph queries, reports, and search context.
+	pub graph: bool,
+	/// Serve document storage, excerpt, and L0 search calls.
+	pub docs: bool,
+	/// Read and write the search expansion and rerank caches.
+	pub caches: bool,
+}
+impl Default for ServiceSubsystems {
+	fn default() -> Self {
+		Self { trace_persistence: true, graph: true, docs: true, caches: true }
+	}
+}
+
+/// Builder for an [`ElfService`] embedded in another Rust application.
+pub struct ElfServiceBuilder {
+	cfg: Config,
+	db: Db,
+	qdrant: QdrantStore,
+	providers: Providers,
+	search_hooks: Vec<Arc<dyn SearchStageHook>>,
+	audit_sinks: Vec<Arc<dyn AuditSink>>,
+	subsystems: ServiceSubsystems,
+}
+impl ElfServiceBuilder {
+	/// Replaces the default provider adapters.
+	pub fn providers(mut self, providers: Providers) -> Self {
+		self.providers = providers;
+
+		self
+	}
+
+	/// Registers a search stage hook. Hooks run in registration order.
+	pub fn search_hook(mut self, hook: Arc<dyn SearchStageHook>) -> Self {
+		self.search_hooks.push(hook);
+
+		self
+	}
+
+	/// Registers an audit sink that runs after the built-in Postgres sink.
+	pub fn audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+		self.audit_sinks.push(sink);
+
+		self
+	}
+
+	/// Replaces the enabled subsystem set.
+	pub fn subsystems(mut self, subsystems: ServiceSubsystems) -> Self {
+		self.subsystems = subsystems;
+
+		self
+	}
+
+	/// Enables or disables search trace persistence.
+	pub fn trace_persistence(mut self, enabled: bool) -> Self {
+		self.subsystems.trace_persistence = enabled;
+
+		self
+	}
+
+	/// Enables or disables graph ingestion and graph reads.
+	pub fn graph(mut self, enabled: bool) -> Self {
+		self.subsystems.graph = enabled;
+
+		self
+	}
+
+	/// Enables or disables the document subsystem.
+	pub fn docs(mut self, enabled: bool) -> Self {
+		self.subsystems.docs = enabled;
+
+		self
+	}
+
+	/// Enables or disables the search expansion and rerank caches.
+	pub fn caches(mut self, enabled: bool) -> Self {
+		self.subsystems.caches = enabled;
+
+		self
+	}
+
+	/// Builds the service.
+	pub fn build(self) -> ElfService {
+		let mut audit_sinks: Vec<Arc<dyn AuditSink>> =
+			vec![Arc::new(PostgresAuditSink::new(self.db.pool.clone()))];
+
+		audit_sinks.extend(self.audit_sinks);
+
+		ElfService {
+			cfg: self.cfg,
+			db: self.db,
+			qdrant: self.qdrant,
+			providers: self.providers,
+			tokenizer: OnceLock::new(),
+			search_hooks: self.search_hooks,
+			audit_sinks,
+			subsystems: self.subsystems,
+			search_limiter: SearchRateLimiter::default(),
+		}
 	}
 }
@@ -0,0 +1,214 @@
+//! Session-scoped retrieval tracking for the within-session ranking term.
+
+use std::collections::HashMap;
+
+use sqlx::PgExecutor;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{Error, Result};
+use elf_config::Config;
+use elf_domain::english_gate;
+
+const DEFAULT_TTL_SECONDS: i64 = 6 * 3_600;
+const MAX_SESSION_ID_CHARS: usize = 128;
+
+/// Agent session whose retrieved notes are tracked.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SessionHitKey<'a> {
+	pub(crate) tenant_id: &'a str,
+	pub(crate) project_id: &'a str,
+	pub(crate) agent_id: &'a str,
+	pub(crate) session_id: &'a str,
+}
+
+/// How the within-session term treats notes the session already retrieved.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SessionRankingMode {
+	Off,
+	Boost,
+	Penalty,
+}
+impl SessionRankingMode {
+	pub(crate) fn as_str(self) -> &'static str {
+		match self {
+			Self::Off => "off",
+			Self::Boost => "boost",
+			Self::Penalty => "penalty",
+		}
+	}
+
+	fn parse(value: &str) -> Option<Self> {
+		match value {
+			"off" => Some(Self::Off),
+			"boost" => Some(Self::Boost),
+			"penalty" => Some(Self::Penalty),
+			_ => None,
+		}
+	}
+}
+
+/// Trims a caller-supplied session id and rejects ids that cannot key session hits.
+pub(crate) fn normalize_session_id(session_id: Option<&str>) -> Result<Option<String>> {
+	let Some(session_id) = session_id.map(str::trim).filter(|value| !value.is_empty()) else {
+		return Ok(None);
+	};
+
+	if session_id.chars().count() > MAX_SESSION_ID_CHARS {
+		return Err(Error::InvalidRequest {
+			message: format!("session_id must be at most {MAX_SESSION_ID_CHARS} characters."),
+		});
+	}
+	if !english_gate::is_english_identifier(session_id) {
+		return Err(Error::NonEnglishInput { field: "$.session_id".to_string() });
+	}
+
+	Ok(Some(session_id.to_string()))
+}
+
+/// Resolves the effective within-session mode for one search.
+///
+/// A requested mode overrides `ranking.deterministic.session.mode`. The term stays off without a
+/// session id or when the configured term is disabled.
+pub(crate) fn resolve_session_mode(
+	cfg: &Config,
+	requested: Option<&str>,
+	has_session: bool,
+) -> Result<SessionRankingMode> {
+	let requested = requested
+		.map(str::trim)
+		.filter(|value| !value.is_empty())
+		.map(|value| {
+			SessionRankingMode::parse(value).ok_or_else(|| Error::InvalidRequest {
+				message: "session_mode must be one of boost, penalty, or off.".to_string(),
+			})
+		})
+		.transpose()?;
+
+	if requested.is_some() && !has_session {
+		return Err(Error::InvalidRequest {
+			message: "session_mode requires session_id.".to_string(),
+		});
+	}
+
+	let det = &cfg.ranking.deterministic;
+	let Some(session) = det.session.as_ref().filter(|session| det.enabled && session.enabled)
+	else {
+		return Ok(SessionRankingMode::Off);
+	};
+
+	if !has_session {
+		return Ok(SessionRankingMode::Off);
+	}
+
+	Ok(requested
+		.or_else(|| SessionRankingMode::parse(session.mode.as_str()))
+		.unwrap_or(SessionRankingMode::Off))
+}
+
+/// Seconds a session remembers a retrieved note after its last recorded hit.
+pub(crate) fn session_ttl_seconds(cfg: &Config) -> i64 {
+	cfg.ranking
+		.deterministic
+		.session
+		.as_ref()
+		.map(|session| session.ttl_seconds)
+		.unwrap_or(DEFAULT_TTL_SECONDS)
+}
+
+/// Records that the session retrieved each note and extends the session memory of those notes.
+pub(crate) async fn record_session_hits<'e, E>(
+	executor: E,
+	key: SessionHitKey<'_>,
+	note_ids: &[Uuid],
+	now: OffsetDateTime,
+	ttl_seconds: i64,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	if note_ids.is_empty() {
+		return Ok(());
+	}
+
+	sqlx::query(
+		"\
+INSERT INTO memory_session_hits (
+	tenant_id,
+	project_id,
+	agent_id,
+	session_id,
+	note_id,
+	hit_count,
+	first_hit_at,
+	last_hit_at,
+	expires_at
+)
+SELECT DISTINCT $1, $2, $3, $4, note_id, 1, $6, $6, $7
+FROM unnest($5::uuid[]) AS t(note_id)
+ON CONFLICT (tenant_id, project_id, agent_id, session_id, note_id) DO UPDATE
+SET
+	hit_count = CASE
+		WHEN memory_session_hits.expires_at > EXCLUDED.last_hit_at
+			THEN memory_session_hits.hit_count + 1
+		ELSE 1
+	END,
+	first_hit_at = CASE
+		WHEN memory_session_hits.expires_at > EXCLUDED.last_hit_at
+			THEN memory_session_hits.first_hit_at
+		ELSE EXCLUDED.first_hit_at
+	END,
+	last_hit_at = EXCLUDED.last_hit_at,
+	expires_at = EXCLUDED.expires_at",
+	)
+	.bind(key.tenant_id)
+	.bind(key.project_id)
+	.bind(key.agent_id)
+	.bind(key.session_id)
+	.bind(note_ids)
+	.bind(now)
+	.bind(now + Duration::seconds(ttl_seconds))
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Loads unexpired session hit counts for the given notes.
+pub(crate) async fn load_session_hit_counts<'e, E>(
+	executor: E,
+	key: SessionHitKey<'_>,
+	note_ids: &[Uuid],
+	now: OffsetDateTime,
+) -> Result<HashMap<Uuid, i64>>
+where
+	E: PgExecutor<'e>,
+{
+	if note_ids.is_empty() {
+		return Ok(HashMap::new());
+	}
+
+	let rows: Vec<(Uuid, i32)> = sqlx::query_as(
+		"\
+SELECT note_id, hit_count
+FROM memory_session_hits
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND agent_id = $3
+	AND session_id = $4
+	AND note_id = ANY($5::uuid[])
+	AND expires_at > $6",
+	)
+	.bind(key.tenant_id)
+	.bind(key.project_id)
+	.bind(key.agent_id)
+	.bind(key.session_id)
+	.bind(note_ids)
+	.bind(now)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows.into_iter().map(|(note_id, hit_count)| (note_id, i64::from(hit_count))).collect())
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use crate::session_hits::{self, SessionRankingMode};
+use elf_config::{Config, RankingDeterministicSession};
+
+fn parse_example_config() -> Config {
+	let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+	let path = root_dir.join("elf.example.toml");
+
+	elf_config::load(&path).expect("elf.example.toml must remain parseable and valid.")
+}
+
+fn session_config(mode: &str) -> Config {
+	let mut cfg = parse_example_config();
+
+	cfg.ranking.deterministic.enabled = true;
+	cfg.ranking.deterministic.session = Some(RankingDeterministicSession {
+		enabled: true,
+		mode: mode.to_string(),
+		weight: 0.1,
+		ttl_seconds: 3_600,
+	});
+
+	cfg
+}
+
+#[test]
+fn requested_mode_overrides_the_configured_mode() {
+	let cfg = session_config("boost");
+
+	assert_eq!(
+		session_hits::resolve_session_mode(&cfg, None, true).unwrap(),
+		SessionRankingMode::Boost
+	);
+	assert_eq!(
+		session_hits::resolve_session_mode(&cfg, Some("penalty"), true).unwrap(),
+		SessionRankingMode::Penalty
+	);
+	assert_eq!(
+		session_hits::resolve_session_mode(&cfg, Some("off"), true).unwrap(),
+		SessionRankingMode::Off
+	);
+	assert_eq!(
+		session_hits::resolve_session_mode(&cfg, None, false).unwrap(),
+		SessionRankingMode::Off
+	);
+	assert!(session_hits::resolve_session_mode(&cfg, Some("penalty"), false).is_err());
+	assert!(session_hits::resolve_session_mode(&cfg, Some("novelty"), true).is_err());
+}
+
+#[test]
+fn session_term_stays_off_when_the_config_disables_it() {
+	let mut cfg = session_config("penalty");
+
+	cfg.ranking.deterministic.enabled = false;
+
+	assert_eq!(
+		session_hits::resolve_session_mode(&cfg, Some("boost"), true).unwrap(),
+		SessionRankingMode::Off
+	);
+
+	let cfg = parse_example_config();
+
+	assert_eq!(
+		session_hits::resolve_session_mode(&cfg, None, true).unwrap(),
+		SessionRankingMode::Off
+	);
+	assert_eq!(session_hits::session_ttl_seconds(&cfg), 21_600);
+}
+
+#[test]
+fn session_ids_are_trimmed_and_bounded() {
+	assert_eq!(session_hits::normalize_session_id(Some("  ")).unwrap(), None);
+	assert_eq!(
+		session_hits::normalize_session_id(Some(" run-42 ")).unwrap().as_deref(),
+		Some("run-42")
+	);
+	assert!(session_hits::normalize_session_id(Some("x".repeat(129).as_str())).is_err());
+}
@@ -0,0 +1,11 @@
+//! Short-term session memory that agents can later summarize into long-term notes.
+
+mod service;
+mod types;
+
+pub use types::{
+	SessionAppendRequest, SessionAppendResponse, SessionGetRequest, SessionGetResponse,
+	SessionMessageInput, SessionMessageItem, SessionSummarizeRequest, SessionSummarizeResponse,
+};
+
+#[cfg(test)] mod tests;
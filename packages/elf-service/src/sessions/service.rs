@@ -0,0 +1,259 @@
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+	AddEventRequest, ElfService, Error, EventMessage, Result, WriteOperation,
+	audit::AuditScope,
+	consolidation, session_hits,
+	sessions::types::{
+		SessionAppendRequest, SessionAppendResponse, SessionGetRequest, SessionGetResponse,
+		SessionMessageInput, SessionMessageItem, SessionSummarizeRequest, SessionSummarizeResponse,
+	},
+};
+use elf_domain::english_gate;
+use elf_storage::{
+	models::SessionMessage,
+	sessions::{self, SessionKey, SessionMessageInsert},
+};
+
+pub(in crate::sessions) const DEFAULT_TTL_SECONDS: i64 = 6 * 3_600;
+pub(in crate::sessions) const MIN_TTL_SECONDS: i64 = 60;
+pub(in crate::sessions) const MAX_TTL_SECONDS: i64 = 7 * 24 * 3_600;
+pub(in crate::sessions) const MAX_SESSION_MESSAGES: usize = 256;
+pub(in crate::sessions) const MAX_MESSAGE_CHARS: usize = 16_384;
+
+impl ElfService {
+	/// Appends messages to a working-memory session and slides the session TTL.
+	///
+	/// Session messages are not notes: they are never indexed or searched, and they are purged once
+	/// the session expires unless promoted through [`ElfService::session_summarize_to_notes`].
+	pub async fn session_append(&self, req: SessionAppendRequest) -> Result<SessionAppendResponse> {
+		let scope = AuditScope::new("session_append").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.session_append_inner(req)).await
+	}
+
+	/// Runs [`ElfService::session_append`] without recording an audit entry.
+	async fn session_append_inner(
+		&self,
+		req: SessionAppendRequest,
+	) -> Result<SessionAppendResponse> {
+		consolidation::validate_context(&req.tenant_id, &req.project_id, &req.agent_id)?;
+		self.authorize_write_any(req.role, WriteOperation::AddEvent)?;
+
+		let session_id = validate_session_id(&req.session_id)?;
+		let ttl_seconds = validate_ttl_seconds(req.ttl_seconds)?;
+
+		validate_messages(&req.messages)?;
+
+		let key = SessionKey {
+			tenant_id: req.tenant_id.trim(),
+			project_id: req.project_id.trim(),
+			agent_id: req.agent_id.trim(),
+			session_id: session_id.as_str(),
+		};
+		let now = OffsetDateTime::now_utc();
+		let expires_at = now + Duration::seconds(ttl_seconds);
+		let mut tx = self.db.pool.begin().await?;
+		let existing = sessions::count_session_messages(&mut *tx, key, now).await? as usize;
+		let message_count = existing + req.messages.len();
+
+		if message_count > MAX_SESSION_MESSAGES {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"A session may hold at most {MAX_SESSION_MESSAGES} messages; summarize it or start a new session."
+				),
+			});
+		}
+
+		sessions::extend_session_expiry(&mut *tx, key, now, expires_at).await?;
+
+		for message in &req.messages {
+			sessions::insert_session_message(
+				&mut *tx,
+				key,
+				SessionMessageInsert {
+					role: message.role.trim(),
+					content: message.content.as_str(),
+					ts: message.ts.as_deref(),
+					msg_id: message.msg_id.as_deref(),
+				},
+				now,
+				expires_at,
+			)
+			.await?;
+		}
+
+		tx.commit().await?;
+
+		Ok(SessionAppendResponse {
+			session_id,
+			appended: req.messages.len() as u32,
+			message_count: message_count as u32,
+			expires_at,
+		})
+	}
+
+	/// Reads the unexpired messages of one session owned by the requesting agent.
+	pub async fn session_get(&self, req: SessionGetRequest) -> Result<SessionGetResponse> {
+		consolidation::validate_context(&req.tenant_id, &req.project_id, &req.agent_id)?;
+
+		let session_id = validate_session_id(&req.session_id)?;
+		let rows = self
+			.load_session_messages(
+				req.tenant_id.trim(),
+				req.project_id.trim(),
+				req.agent_id.trim(),
+				session_id.as_str(),
+			)
+			.await?;
+		// Appends move every message of a session to the same expiry.
+		let expires_at =
+			rows.iter().map(|row| row.expires_at).max().unwrap_or_else(OffsetDateTime::now_utc);
+
+		Ok(SessionGetResponse {
+			session_id,
+			messages: rows.into_iter().map(row_to_item).collect(),
+			expires_at,
+		})
+	}
+
+	/// Extracts long-term notes from a session's messages.
+	///
+	/// The messages go through [`ElfService::add_event`], so extraction, the write gate, evidence
+	/// binding, scope permissions, and quotas apply exactly as for event ingestion. The session
+	/// itself is left in place and still expires on its TTL.
+	pub async fn session_summarize_to_notes(
+		&self,
+		req: SessionSummarizeRequest,
+	) -> Result<SessionSummarizeResponse> {
+		let scope = AuditScope::new("session_summarize").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.session_summarize_to_notes_inner(req)).await
+	}
+
+	/// Runs [`ElfService::session_summarize_to_notes`] without recording an audit entry.
+	async fn session_summarize_to_notes_inner(
+		&self,
+		req: SessionSummarizeRequest,
+	) -> Result<SessionSummarizeResponse> {
+		consolidation::validate_context(&req.tenant_id, &req.project_id, &req.agent_id)?;
+
+		let session_id = validate_session_id(&req.session_id)?;
+		let rows = self
+			.load_session_messages(
+				req.tenant_id.trim(),
+				req.project_id.trim(),
+				req.agent_id.trim(),
+				session_id.as_str(),
+			)
+			.await?;
+		let message_count = rows.len() as u32;
+		let messages = rows
+			.into_iter()
+			.map(|row| EventMessage {
+				role: row.role,
+				content: row.content,
+				ts: row.ts,
+				msg_id: row.msg_id,
+				write_policy: None,
+			})
+			.collect();
+		let extraction = self
+			.add_event(AddEventRequest {
+				tenant_id: req.tenant_id,
+				project_id: req.project_id,
+				agent_id: req.agent_id,
+				role: req.role,
+				scope: req.scope,
+				dry_run: req.dry_run,
+				ingestion_profile: None,
+				locale: req.locale,
+				messages,
+			})
+			.await?;
+
+		Ok(SessionSummarizeResponse { session_id, message_count, extraction })
+	}
+
+	async fn load_session_messages(
+		&self,
+		tenant_id: &str,
+		project_id: &str,
+		agent_id: &str,
+		session_id: &str,
+	) -> Result<Vec<SessionMessage>> {
+		let key = SessionKey { tenant_id, project_id, agent_id, session_id };
+		let rows =
+			sessions::list_session_messages(&self.db.pool, key, OffsetDateTime::now_utc()).await?;
+
+		if rows.is_empty() {
+			return Err(Error::NotFound { message: "Session not found or expired.".to_string() });
+		}
+
+		Ok(rows)
+	}
+}
+
+pub(in crate::sessions) fn validate_session_id(session_id: &str) -> Result<String> {
+	session_hits::normalize_session_id(Some(session_id))?
+		.ok_or_else(|| Error::InvalidRequest { message: "session_id is required.".to_string() })
+}
+
+pub(in crate::sessions) fn validate_ttl_seconds(ttl_seconds: Option<i64>) -> Result<i64> {
+	let ttl_seconds = ttl_seconds.unwrap_or(DEFAULT_TTL_SECONDS);
+
+	if !(MIN_TTL_SECONDS..=MAX_TTL_SECONDS).contains(&ttl_seconds) {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"ttl_seconds must be between {MIN_TTL_SECONDS} and {MAX_TTL_SECONDS}."
+			),
+		});
+	}
+
+	Ok(ttl_seconds)
+}
+
+pub(in crate::sessions) fn validate_messages(messages: &[SessionMessageInput]) -> Result<()> {
+	if messages.is_empty() {
+		return Err(Error::InvalidRequest { message: "Messages list is empty.".to_string() });
+	}
+
+	for (idx, message) in messages.iter().enumerate() {
+		if message.role.trim().is_empty() {
+			return Err(Error::InvalidRequest {
+				message: format!("$.messages[{idx}].role is required."),
+			});
+		}
+		if message.content.chars().count() > MAX_MESSAGE_CHARS {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"$.messages[{idx}].content must be at most {MAX_MESSAGE_CHARS} characters."
+				),
+			});
+		}
+		if !english_gate::is_english_natural_language(message.content.as_str()) {
+			return Err(Error::NonEnglishInput { field: format!("$.messages[{idx}].content") });
+		}
+	}
+
+	Ok(())
+}
+
+fn row_to_item(row: SessionMessage) -> SessionMessageItem {
+	SessionMessageItem {
+		message_seq: row.message_seq,
+		role: row.role,
+		content: row.content,
+		ts: row.ts,
+		msg_id: row.msg_id,
+		created_at: row.created_at,
+	}
+}
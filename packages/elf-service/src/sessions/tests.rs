@@ -0,0 +1,59 @@
+use crate::{
+	Error,
+	sessions::{
+		SessionMessageInput,
+		service::{self, DEFAULT_TTL_SECONDS, MAX_MESSAGE_CHARS, MAX_TTL_SECONDS, MIN_TTL_SECONDS},
+	},
+};
+
+fn message(role: &str, content: &str) -> SessionMessageInput {
+	SessionMessageInput {
+		role: role.to_string(),
+		content: content.to_string(),
+		ts: None,
+		msg_id: None,
+	}
+}
+
+#[test]
+fn validate_ttl_seconds_defaults_and_rejects_out_of_range_values() {
+	assert_eq!(service::validate_ttl_seconds(None).expect("default is valid"), DEFAULT_TTL_SECONDS);
+	assert_eq!(
+		service::validate_ttl_seconds(Some(MAX_TTL_SECONDS)).expect("cap is accepted"),
+		MAX_TTL_SECONDS
+	);
+	assert!(matches!(
+		service::validate_ttl_seconds(Some(MIN_TTL_SECONDS - 1)),
+		Err(Error::InvalidRequest { .. })
+	));
+	assert!(matches!(
+		service::validate_ttl_seconds(Some(MAX_TTL_SECONDS + 1)),
+		Err(Error::InvalidRequest { .. })
+	));
+}
+
+#[test]
+fn validate_session_id_trims_and_requires_a_value() {
+	assert_eq!(service::validate_session_id(" run-42 ").expect("id is valid"), "run-42");
+	assert!(matches!(service::validate_session_id("  "), Err(Error::InvalidRequest { .. })));
+}
+
+#[test]
+fn validate_messages_rejects_empty_roles_long_content_and_non_english_text() {
+	let long = "word ".repeat(MAX_MESSAGE_CHARS);
+
+	assert!(
+		service::validate_messages(&[message("user", "Deploy the staging build tonight.")]).is_ok()
+	);
+	assert!(matches!(service::validate_messages(&[]), Err(Error::InvalidRequest { .. })));
+	assert!(
+		matches!(service::validate_messages(&[message(" ", "Hello there.")]), Err(Error::InvalidRequest { message }) if message.contains("$.messages[0].role"))
+	);
+	assert!(
+		matches!(service::validate_messages(&[message("user", long.as_str())]), Err(Error::InvalidRequest { message }) if message.contains("$.messages[0].content"))
+	);
+	assert!(matches!(
+		service::validate_messages(&[message("user", "请今晚部署测试环境。")]),
+		Err(Error::NonEnglishInput { .. })
+	));
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{AddEventResponse, audit::AuditTargets};
+use elf_config::SecurityAuthRole;
+
+/// One message appended to a working-memory session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionMessageInput {
+	/// Speaker or message role.
+	pub role: String,
+	/// Message body content.
+	pub content: String,
+	/// Optional source timestamp string.
+	pub ts: Option<String>,
+	/// Optional message identifier from the upstream source.
+	pub msg_id: Option<String>,
+}
+
+/// Request payload for appending messages to a session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionAppendRequest {
+	/// Tenant that owns the session.
+	pub tenant_id: String,
+	/// Project that owns the session.
+	pub project_id: String,
+	/// Agent that owns the session.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, which must be allowed to add_event in some scope; `None`
+	/// when auth is off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Caller-chosen session identifier.
+	pub session_id: String,
+	/// Seconds the session is kept after this append; defaults to six hours.
+	pub ttl_seconds: Option<i64>,
+	/// Messages to append, in order.
+	pub messages: Vec<SessionMessageInput>,
+}
+
+/// Result of appending messages to a session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionAppendResponse {
+	/// Session identifier.
+	pub session_id: String,
+	/// Number of messages appended by this request.
+	pub appended: u32,
+	/// Number of unexpired messages in the session after the append.
+	pub message_count: u32,
+	#[serde(with = "crate::time_serde")]
+	/// Time after which the session's messages are purged.
+	pub expires_at: OffsetDateTime,
+}
+
+impl AuditTargets for SessionAppendResponse {}
+
+/// Request payload for reading a session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionGetRequest {
+	/// Tenant that owns the session.
+	pub tenant_id: String,
+	/// Project that owns the session.
+	pub project_id: String,
+	/// Agent that owns the session.
+	pub agent_id: String,
+	/// Caller-chosen session identifier.
+	pub session_id: String,
+}
+
+/// One stored session message.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionMessageItem {
+	/// Append order; increases within a session.
+	pub message_seq: i64,
+	/// Speaker or message role.
+	pub role: String,
+	/// Message body content.
+	pub content: String,
+	/// Optional source timestamp string.
+	pub ts: Option<String>,
+	/// Optional message identifier from the upstream source.
+	pub msg_id: Option<String>,
+	#[serde(with = "crate::time_serde")]
+	/// Append timestamp.
+	pub created_at: OffsetDateTime,
+}
+
+/// Unexpired messages of one session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionGetResponse {
+	/// Session identifier.
+	pub session_id: String,
+	/// Messages in append order.
+	pub messages: Vec<SessionMessageItem>,
+	#[serde(with = "crate::time_serde")]
+	/// Time after which the session's messages are purged.
+	pub expires_at: OffsetDateTime,
+}
+
+/// Request payload for summarizing a session into long-term notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionSummarizeRequest {
+	/// Tenant that owns the session.
+	pub tenant_id: String,
+	/// Project that owns the session.
+	pub project_id: String,
+	/// Agent that owns the session and the promoted notes.
+	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against each promoted note's scope; `None` when
+	/// auth is off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
+	/// Caller-chosen session identifier.
+	pub session_id: String,
+	/// Optional explicit scope override for promoted notes.
+	pub scope: Option<String>,
+	/// When true, extracts and gates notes without persisting them.
+	pub dry_run: Option<bool>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Optional BCP 47 locale tag used when normalizing extracted values.
+	pub locale: Option<String>,
+}
+
+/// Result of summarizing a session into long-term notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionSummarizeResponse {
+	/// Session identifier.
+	pub session_id: String,
+	/// Number of session messages sent to the extractor.
+	pub message_count: u32,
+	/// Extraction and per-note write outcomes, as returned by `add_event`.
+	pub extraction: AddEventResponse,
+}
+
+impl AuditTargets for SessionSummarizeResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		self.extraction.audit_target_ids()
+	}
+
+	fn audit_reject_reasons(&self) -> Vec<String> {
+		self.extraction.audit_reject_reasons()
+	}
+}
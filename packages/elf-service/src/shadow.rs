@@ -0,0 +1,15 @@
+//! Search shadowing comparisons against a second ELF deployment.
+
+mod compare;
+mod service;
+mod types;
+
+pub use self::{
+	compare::ShadowOverlap,
+	types::{
+		ELF_SEARCH_SHADOW_REPORT_SCHEMA_V1, SearchShadowComparison, SearchShadowComparisonInput,
+		SearchShadowReportRequest, SearchShadowReportResponse, SearchShadowSummary,
+	},
+};
+
+#[cfg(test)] mod tests;
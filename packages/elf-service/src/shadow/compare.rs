@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+/// Result-set agreement between a primary and a shadow search.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShadowOverlap {
+	/// Share of the primary top-k that also appears in the shadow top-k.
+	pub overlap_at_k: f32,
+	/// Jaccard similarity between the two result sets.
+	pub jaccard: f32,
+	/// Whether both deployments returned the same first result.
+	pub top1_match: bool,
+}
+impl ShadowOverlap {
+	/// Compares two ranked note-id lists. Returns `None` when both lists are empty.
+	pub fn compute(primary: &[Uuid], shadow: &[Uuid]) -> Option<Self> {
+		if primary.is_empty() && shadow.is_empty() {
+			return None;
+		}
+
+		let primary_set: HashSet<Uuid> = primary.iter().copied().collect();
+		let shadow_set: HashSet<Uuid> = shadow.iter().copied().collect();
+		let shared = primary_set.intersection(&shadow_set).count();
+		let union = primary_set.union(&shadow_set).count();
+		let overlap_at_k =
+			if primary_set.is_empty() { 0.0 } else { shared as f32 / primary_set.len() as f32 };
+		let jaccard = if union == 0 { 0.0 } else { shared as f32 / union as f32 };
+		let top1_match = !primary.is_empty() && primary.first() == shadow.first();
+
+		Some(Self { overlap_at_k, jaccard, top1_match })
+	}
+}
@@ -0,0 +1,236 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	shadow::{
+		ShadowOverlap,
+		types::{
+			ELF_SEARCH_SHADOW_REPORT_SCHEMA_V1, SearchShadowComparison,
+			SearchShadowComparisonInput, SearchShadowReportRequest, SearchShadowReportResponse,
+			SearchShadowSummary,
+		},
+	},
+};
+
+const DEFAULT_REPORT_LIMIT: u32 = 50;
+const MAX_REPORT_LIMIT: u32 = 500;
+const MAX_ERROR_CHARS: usize = 512;
+
+#[derive(FromRow)]
+struct SummaryRow {
+	comparisons: i64,
+	errors: i64,
+	mean_overlap_at_k: Option<f64>,
+	mean_jaccard: Option<f64>,
+	top1_match_rate: Option<f64>,
+	mean_primary_latency_ms: Option<f64>,
+	mean_shadow_latency_ms: Option<f64>,
+	p95_latency_delta_ms: Option<f64>,
+}
+
+#[derive(FromRow)]
+struct ComparisonRow {
+	comparison_id: Uuid,
+	agent_id: String,
+	mode: String,
+	query: String,
+	primary_trace_id: Option<Uuid>,
+	shadow_trace_id: Option<Uuid>,
+	overlap_at_k: Option<f32>,
+	jaccard: Option<f32>,
+	top1_match: Option<bool>,
+	primary_latency_ms: i32,
+	shadow_latency_ms: Option<i32>,
+	shadow_status: Option<i32>,
+	error: Option<String>,
+	created_at: OffsetDateTime,
+}
+
+impl ElfService {
+	/// Persists one primary-versus-shadow search comparison.
+	pub async fn shadow_comparison_record(
+		&self,
+		input: SearchShadowComparisonInput,
+	) -> Result<Uuid> {
+		if input.tenant_id.trim().is_empty() || input.project_id.trim().is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id and project_id are required.".to_string(),
+			});
+		}
+
+		let comparison_id = Uuid::new_v4();
+		let overlap = if input.error.is_none() {
+			ShadowOverlap::compute(&input.primary_note_ids, &input.shadow_note_ids)
+		} else {
+			None
+		};
+		let error =
+			input.error.map(|message| message.chars().take(MAX_ERROR_CHARS).collect::<String>());
+
+		sqlx::query(
+			"\
+INSERT INTO search_shadow_comparisons (
+	comparison_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	mode,
+	query,
+	primary_trace_id,
+	shadow_trace_id,
+	primary_note_ids,
+	shadow_note_ids,
+	overlap_at_k,
+	jaccard,
+	top1_match,
+	primary_latency_ms,
+	shadow_latency_ms,
+	shadow_status,
+	error,
+	created_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)",
+		)
+		.bind(comparison_id)
+		.bind(input.tenant_id.trim())
+		.bind(input.project_id.trim())
+		.bind(input.agent_id.trim())
+		.bind(input.mode.as_str())
+		.bind(input.query.as_str())
+		.bind(input.primary_trace_id)
+		.bind(input.shadow_trace_id)
+		.bind(&input.primary_note_ids)
+		.bind(&input.shadow_note_ids)
+		.bind(overlap.map(|value| value.overlap_at_k))
+		.bind(overlap.map(|value| value.jaccard))
+		.bind(overlap.map(|value| value.top1_match))
+		.bind(clamp_latency(input.primary_latency_ms))
+		.bind(input.shadow_latency_ms.map(clamp_latency))
+		.bind(input.shadow_status.map(i32::from))
+		.bind(error)
+		.bind(OffsetDateTime::now_utc())
+		.execute(&self.db.pool)
+		.await?;
+
+		Ok(comparison_id)
+	}
+
+	/// Summarizes recorded shadow comparisons for one tenant and project.
+	pub async fn shadow_report(
+		&self,
+		req: SearchShadowReportRequest,
+	) -> Result<SearchShadowReportResponse> {
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+
+		if tenant_id.is_empty() || project_id.is_empty() {
+			return Err(Error::InvalidRequest {
+				message: "tenant_id and project_id are required.".to_string(),
+			});
+		}
+
+		let limit = req.limit.unwrap_or(DEFAULT_REPORT_LIMIT);
+
+		if limit == 0 || limit > MAX_REPORT_LIMIT {
+			return Err(Error::InvalidRequest {
+				message: format!("limit must be between 1 and {MAX_REPORT_LIMIT}."),
+			});
+		}
+
+		let summary = sqlx::query_as::<_, SummaryRow>(
+			"\
+SELECT
+	count(*) AS comparisons,
+	count(*) FILTER (WHERE error IS NOT NULL OR coalesce(shadow_status, 0) NOT BETWEEN 200 AND 299)
+		AS errors,
+	avg(overlap_at_k)::float8 AS mean_overlap_at_k,
+	avg(jaccard)::float8 AS mean_jaccard,
+	avg(CASE WHEN top1_match THEN 1.0 ELSE 0.0 END) FILTER (WHERE top1_match IS NOT NULL)::float8
+		AS top1_match_rate,
+	avg(primary_latency_ms)::float8 AS mean_primary_latency_ms,
+	avg(shadow_latency_ms)::float8 AS mean_shadow_latency_ms,
+	percentile_cont(0.95) WITHIN GROUP (ORDER BY shadow_latency_ms - primary_latency_ms)
+		FILTER (WHERE shadow_latency_ms IS NOT NULL) AS p95_latency_delta_ms
+FROM search_shadow_comparisons
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND ($3::timestamptz IS NULL OR created_at >= $3)",
+		)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(req.since)
+		.fetch_one(&self.db.pool)
+		.await?;
+		let rows = sqlx::query_as::<_, ComparisonRow>(
+			"\
+SELECT
+	comparison_id,
+	agent_id,
+	mode,
+	query,
+	primary_trace_id,
+	shadow_trace_id,
+	overlap_at_k,
+	jaccard,
+	top1_match,
+	primary_latency_ms,
+	shadow_latency_ms,
+	shadow_status,
+	error,
+	created_at
+FROM search_shadow_comparisons
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND ($3::timestamptz IS NULL OR created_at >= $3)
+ORDER BY created_at DESC, comparison_id DESC
+LIMIT $4",
+		)
+		.bind(tenant_id)
+		.bind(project_id)
+		.bind(req.since)
+		.bind(i64::from(limit))
+		.fetch_all(&self.db.pool)
+		.await?;
+
+		Ok(SearchShadowReportResponse {
+			schema: ELF_SEARCH_SHADOW_REPORT_SCHEMA_V1.to_string(),
+			generated_at: OffsetDateTime::now_utc(),
+			summary: SearchShadowSummary {
+				comparisons: summary.comparisons,
+				errors: summary.errors,
+				mean_overlap_at_k: summary.mean_overlap_at_k,
+				mean_jaccard: summary.mean_jaccard,
+				top1_match_rate: summary.top1_match_rate,
+				mean_primary_latency_ms: summary.mean_primary_latency_ms,
+				mean_shadow_latency_ms: summary.mean_shadow_latency_ms,
+				p95_latency_delta_ms: summary.p95_latency_delta_ms,
+			},
+			recent: rows.into_iter().map(comparison_from_row).collect(),
+		})
+	}
+}
+
+fn clamp_latency(ms: u64) -> i32 {
+	i32::try_from(ms).unwrap_or(i32::MAX)
+}
+
+fn comparison_from_row(row: ComparisonRow) -> SearchShadowComparison {
+	SearchShadowComparison {
+		comparison_id: row.comparison_id,
+		agent_id: row.agent_id,
+		mode: row.mode,
+		query: row.query,
+		primary_trace_id: row.primary_trace_id,
+		shadow_trace_id: row.shadow_trace_id,
+		overlap_at_k: row.overlap_at_k,
+		jaccard: row.jaccard,
+		top1_match: row.top1_match,
+		primary_latency_ms: row.primary_latency_ms,
+		shadow_latency_ms: row.shadow_latency_ms,
+		shadow_status: row.shadow_status,
+		error: row.error,
+		created_at: row.created_at,
+	}
+}
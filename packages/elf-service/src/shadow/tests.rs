@@ -0,0 +1,41 @@
+use uuid::Uuid;
+
+use crate::shadow::ShadowOverlap;
+
+#[test]
+fn overlap_is_none_when_both_sides_are_empty() {
+	assert_eq!(ShadowOverlap::compute(&[], &[]), None);
+}
+
+#[test]
+fn identical_result_sets_fully_overlap() {
+	let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+	let overlap = ShadowOverlap::compute(&ids, &ids).expect("overlap should be computed");
+
+	assert!((overlap.overlap_at_k - 1.0).abs() < f32::EPSILON);
+	assert!((overlap.jaccard - 1.0).abs() < f32::EPSILON);
+	assert!(overlap.top1_match);
+}
+
+#[test]
+fn partial_overlap_reports_shared_fraction() {
+	let a = Uuid::new_v4();
+	let b = Uuid::new_v4();
+	let c = Uuid::new_v4();
+	let d = Uuid::new_v4();
+	let overlap = ShadowOverlap::compute(&[a, b], &[b, c, d]).expect("overlap should be computed");
+
+	assert!((overlap.overlap_at_k - 0.5).abs() < f32::EPSILON);
+	assert!((overlap.jaccard - 0.25).abs() < f32::EPSILON);
+	assert!(!overlap.top1_match);
+}
+
+#[test]
+fn empty_shadow_results_have_zero_overlap() {
+	let overlap =
+		ShadowOverlap::compute(&[Uuid::new_v4()], &[]).expect("overlap should be computed");
+
+	assert_eq!(overlap.overlap_at_k, 0.0);
+	assert_eq!(overlap.jaccard, 0.0);
+	assert!(!overlap.top1_match);
+}
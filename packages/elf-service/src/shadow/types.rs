@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Schema identifier for search shadow reports.
+pub const ELF_SEARCH_SHADOW_REPORT_SCHEMA_V1: &str = "elf.search_shadow_report/v1";
+
+/// One primary-versus-shadow search observation to persist.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchShadowComparisonInput {
+	/// Tenant that issued the search.
+	pub tenant_id: String,
+	/// Project that issued the search.
+	pub project_id: String,
+	/// Agent that issued the search.
+	pub agent_id: String,
+	/// Search mode label, such as `quick_find`.
+	pub mode: String,
+	/// Query text sent to both deployments.
+	pub query: String,
+	/// Trace identifier from the primary deployment.
+	pub primary_trace_id: Option<Uuid>,
+	/// Trace identifier reported by the shadow deployment.
+	pub shadow_trace_id: Option<Uuid>,
+	/// Ranked note identifiers returned by the primary deployment.
+	pub primary_note_ids: Vec<Uuid>,
+	/// Ranked note identifiers returned by the shadow deployment.
+	pub shadow_note_ids: Vec<Uuid>,
+	/// Primary request latency in milliseconds.
+	pub primary_latency_ms: u64,
+	/// Shadow request latency in milliseconds, when a response arrived.
+	pub shadow_latency_ms: Option<u64>,
+	/// HTTP status returned by the shadow deployment, when a response arrived.
+	pub shadow_status: Option<u16>,
+	/// Transport or decode failure, when the shadow call did not complete.
+	pub error: Option<String>,
+}
+
+/// Request payload for the shadow comparison report.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchShadowReportRequest {
+	/// Tenant to report on.
+	pub tenant_id: String,
+	/// Project to report on.
+	pub project_id: String,
+	#[serde(default, with = "crate::time_serde::option")]
+	/// Optional lower bound on comparison timestamps.
+	pub since: Option<OffsetDateTime>,
+	/// Optional cap for recent comparisons in the response.
+	pub limit: Option<u32>,
+}
+
+/// Aggregate shadow comparison report.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchShadowReportResponse {
+	/// Response schema identifier.
+	pub schema: String,
+	#[serde(with = "crate::time_serde")]
+	/// Report generation timestamp.
+	pub generated_at: OffsetDateTime,
+	/// Aggregates across the report window.
+	pub summary: SearchShadowSummary,
+	/// Most recent comparisons, newest first.
+	pub recent: Vec<SearchShadowComparison>,
+}
+
+/// Aggregate statistics across shadow comparisons.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SearchShadowSummary {
+	/// Number of comparisons in the window.
+	pub comparisons: i64,
+	/// Number of comparisons where the shadow call failed.
+	pub errors: i64,
+	/// Mean share of the primary top-k found in the shadow top-k.
+	pub mean_overlap_at_k: Option<f64>,
+	/// Mean Jaccard similarity between result sets.
+	pub mean_jaccard: Option<f64>,
+	/// Share of comparisons whose first result matched.
+	pub top1_match_rate: Option<f64>,
+	/// Mean primary latency in milliseconds.
+	pub mean_primary_latency_ms: Option<f64>,
+	/// Mean shadow latency in milliseconds.
+	pub mean_shadow_latency_ms: Option<f64>,
+	/// 95th percentile of shadow-minus-primary latency in milliseconds.
+	pub p95_latency_delta_ms: Option<f64>,
+}
+
+/// One persisted shadow comparison.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchShadowComparison {
+	/// Comparison identifier.
+	pub comparison_id: Uuid,
+	/// Agent that issued the search.
+	pub agent_id: String,
+	/// Search mode label.
+	pub mode: String,
+	/// Query text.
+	pub query: String,
+	/// Primary trace identifier.
+	pub primary_trace_id: Option<Uuid>,
+	/// Shadow trace identifier.
+	pub shadow_trace_id: Option<Uuid>,
+	/// Share of the primary top-k found in the shadow top-k.
+	pub overlap_at_k: Option<f32>,
+	/// Jaccard similarity between result sets.
+	pub jaccard: Option<f32>,
+	/// Whether the first results matched.
+	pub top1_match: Option<bool>,
+	/// Primary latency in milliseconds.
+	pub primary_latency_ms: i32,
+	/// Shadow latency in milliseconds.
+	pub shadow_latency_ms: Option<i32>,
+	/// Shadow HTTP status.
+	pub shadow_status: Option<i32>,
+	/// Shadow failure message.
+	pub error: Option<String>,
+	#[serde(with = "crate::time_serde")]
+	/// Comparison timestamp.
+	pub created_at: OffsetDateTime,
+}
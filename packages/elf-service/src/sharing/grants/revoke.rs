@@ -3,6 +3,8 @@ use time::OffsetDateTime;
 use crate::{
 	ElfService, Error, Result,
 	access::ORG_PROJECT_ID,
+	admin_grants::{self, GrantKey, GrantVersionArgs},
+	audit::AuditScope,
 	sharing::types::{GranteeKind, SpaceGrantRevokeRequest, SpaceGrantRevokeResponse},
 };
 
@@ -11,6 +13,20 @@ impl ElfService {
 	pub async fn space_grant_revoke(
 		&self,
 		req: SpaceGrantRevokeRequest,
+	) -> Result<SpaceGrantRevokeResponse> {
+		let scope = AuditScope::new("space_grant_revoke").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.space_grant_revoke_inner(req)).await
+	}
+
+	/// Runs [`ElfService::space_grant_revoke`] without recording an audit entry.
+	async fn space_grant_revoke_inner(
+		&self,
+		req: SpaceGrantRevokeRequest,
 	) -> Result<SpaceGrantRevokeResponse> {
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
@@ -50,38 +66,40 @@ impl ElfService {
 			return Err(Error::ScopeDenied { message: "Scope is not allowed.".to_string() });
 		}
 
-		let effective_project_id = if scope == "org_shared" { ORG_PROJECT_ID } else { project_id };
-		let revocation = sqlx::query(
-			"\
-UPDATE memory_space_grants
-SET revoked_at = $7,
-	revoked_by_agent_id = $8
-WHERE tenant_id = $1
-  AND project_id = $2
-  AND scope = $3
-  AND space_owner_agent_id = $4
-  AND grantee_kind = $5
-  AND ((grantee_kind = 'project' AND grantee_agent_id IS NULL)
-  OR (grantee_kind = 'agent' AND grantee_agent_id = $6))
-  AND revoked_at IS NULL",
+		let key = GrantKey {
+			tenant_id,
+			project_id: if scope == "org_shared" { ORG_PROJECT_ID } else { project_id },
+			scope,
+			space_owner_agent_id: agent_id,
+			grantee_kind: match req.grantee_kind {
+				GranteeKind::Project => "project",
+				GranteeKind::Agent => "agent",
+			},
+			grantee_agent_id,
+		};
+		let now = OffsetDateTime::now_utc();
+		let mut tx = self.db.pool.begin().await?;
+		let Some(prev) = admin_grants::active_grant_for_update(&mut tx, &key).await? else {
+			return Err(Error::InvalidRequest { message: "No active grant found.".to_string() });
+		};
+		let grant = admin_grants::revoke_grant(&mut tx, prev.grant_id, agent_id, now).await?;
+
+		admin_grants::insert_grant_version(
+			&mut tx,
+			GrantVersionArgs {
+				grant_id: grant.grant_id,
+				tenant_id,
+				op: "revoke",
+				prev: Some(&prev),
+				new: Some(&grant),
+				reason: "space_revoke",
+				actor: agent_id,
+				ts: now,
+			},
 		)
-		.bind(tenant_id)
-		.bind(effective_project_id)
-		.bind(scope)
-		.bind(agent_id)
-		.bind(match req.grantee_kind {
-			GranteeKind::Project => "project",
-			GranteeKind::Agent => "agent",
-		})
-		.bind(grantee_agent_id)
-		.bind(OffsetDateTime::now_utc())
-		.bind(agent_id)
-		.execute(&self.db.pool)
 		.await?;
 
-		if revocation.rows_affected() == 0 {
-			return Err(Error::InvalidRequest { message: "No active grant found.".to_string() });
-		}
+		tx.commit().await?;
 
 		Ok(SpaceGrantRevokeResponse { revoked: true })
 	}
@@ -1,8 +1,11 @@
+use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::{
-	ElfService, Error, Result,
+	AdminGrantItem, ElfService, Error, Result,
 	access::ORG_PROJECT_ID,
+	admin_grants::{self, GrantKey, GrantVersionArgs},
+	audit::AuditScope,
 	sharing::{
 		sql::{AGENT_SPACE_GRANT_UPSERT_SQL, PROJECT_SPACE_GRANT_UPSERT_SQL},
 		types::{GranteeKind, SpaceGrantUpsertRequest, SpaceGrantUpsertResponse},
@@ -14,6 +17,20 @@ impl ElfService {
 	pub async fn space_grant_upsert(
 		&self,
 		req: SpaceGrantUpsertRequest,
+	) -> Result<SpaceGrantUpsertResponse> {
+		let scope = AuditScope::new("space_grant_upsert").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.space_grant_upsert_inner(req)).await
+	}
+
+	/// Runs [`ElfService::space_grant_upsert`] without recording an audit entry.
+	async fn space_grant_upsert_inner(
+		&self,
+		req: SpaceGrantUpsertRequest,
 	) -> Result<SpaceGrantUpsertResponse> {
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
@@ -56,24 +73,23 @@ impl ElfService {
 			});
 		}
 
-		let grantee_agent_id_ref = grantee_agent_id.as_deref();
-		let now = time::OffsetDateTime::now_utc();
-		let effective_project_id = if scope == "org_shared" { ORG_PROJECT_ID } else { project_id };
+		let now = OffsetDateTime::now_utc();
 
-		if req.grantee_kind == GranteeKind::Project {
-			self.upsert_project_grant(tenant_id, effective_project_id, scope, agent_id, now)
-				.await?;
-		} else {
-			self.upsert_agent_grant(
+		self.upsert_grant(
+			GrantKey {
 				tenant_id,
-				effective_project_id,
+				project_id: if scope == "org_shared" { ORG_PROJECT_ID } else { project_id },
 				scope,
-				agent_id,
-				grantee_agent_id_ref,
-				now,
-			)
-			.await?;
-		}
+				space_owner_agent_id: agent_id,
+				grantee_kind: match req.grantee_kind {
+					GranteeKind::Project => "project",
+					GranteeKind::Agent => "agent",
+				},
+				grantee_agent_id: grantee_agent_id.as_deref(),
+			},
+			now,
+		)
+		.await?;
 
 		Ok(SpaceGrantUpsertResponse {
 			scope: scope.to_string(),
@@ -83,51 +99,43 @@ impl ElfService {
 		})
 	}
 
-	async fn upsert_project_grant(
-		&self,
-		tenant_id: &str,
-		project_id: &str,
-		scope: &str,
-		agent_id: &str,
-		now: time::OffsetDateTime,
-	) -> Result<()> {
-		sqlx::query(PROJECT_SPACE_GRANT_UPSERT_SQL)
+	/// Creates or refreshes the grant for `key` and records a `grant` version.
+	async fn upsert_grant(&self, key: GrantKey<'_>, now: OffsetDateTime) -> Result<()> {
+		let sql = match key.grantee_kind {
+			"project" => PROJECT_SPACE_GRANT_UPSERT_SQL,
+			_ => AGENT_SPACE_GRANT_UPSERT_SQL,
+		};
+		let mut tx = self.db.pool.begin().await?;
+		let prev = admin_grants::active_grant_for_update(&mut tx, &key).await?;
+		let grant: AdminGrantItem = sqlx::query_as(sql)
 			.bind(Uuid::new_v4())
-			.bind(tenant_id)
-			.bind(project_id)
-			.bind(scope)
-			.bind(agent_id)
-			.bind("project")
-			.bind::<Option<&str>>(None)
-			.bind(agent_id)
+			.bind(key.tenant_id)
+			.bind(key.project_id)
+			.bind(key.scope)
+			.bind(key.space_owner_agent_id)
+			.bind(key.grantee_kind)
+			.bind(key.grantee_agent_id)
+			.bind(key.space_owner_agent_id)
 			.bind(now)
-			.execute(&self.db.pool)
+			.fetch_one(&mut *tx)
 			.await?;
 
-		Ok(())
-	}
+		admin_grants::insert_grant_version(
+			&mut tx,
+			GrantVersionArgs {
+				grant_id: grant.grant_id,
+				tenant_id: key.tenant_id,
+				op: "grant",
+				prev: prev.as_ref(),
+				new: Some(&grant),
+				reason: "space_grant",
+				actor: key.space_owner_agent_id,
+				ts: now,
+			},
+		)
+		.await?;
 
-	async fn upsert_agent_grant(
-		&self,
-		tenant_id: &str,
-		project_id: &str,
-		scope: &str,
-		agent_id: &str,
-		grantee_agent_id: Option<&str>,
-		now: time::OffsetDateTime,
-	) -> Result<()> {
-		sqlx::query(AGENT_SPACE_GRANT_UPSERT_SQL)
-			.bind(Uuid::new_v4())
-			.bind(tenant_id)
-			.bind(project_id)
-			.bind(scope)
-			.bind(agent_id)
-			.bind("agent")
-			.bind(grantee_agent_id)
-			.bind(agent_id)
-			.bind(now)
-			.execute(&self.db.pool)
-			.await?;
+		tx.commit().await?;
 
 		Ok(())
 	}
@@ -1,8 +1,9 @@
 use time::OffsetDateTime;
 
 use crate::{
-	ElfService, Error, InsertVersionArgs, Result,
+	ElfService, Error, InsertVersionArgs, Result, WriteOperation,
 	access::{self, ORG_PROJECT_ID},
+	audit::AuditScope,
 	sharing::types::{PublishNoteRequest, PublishNoteResponse},
 };
 use elf_storage::models::MemoryNote;
@@ -10,6 +11,15 @@ use elf_storage::models::MemoryNote;
 impl ElfService {
 	/// Publishes an owned note into a shared scope.
 	pub async fn publish_note(&self, req: PublishNoteRequest) -> Result<PublishNoteResponse> {
+		let scope = AuditScope::new("publish")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.note_id]);
+
+		self.audited(scope, self.publish_note_inner(req)).await
+	}
+
+	/// Runs [`ElfService::publish_note`] without recording an audit entry.
+	async fn publish_note_inner(&self, req: PublishNoteRequest) -> Result<PublishNoteResponse> {
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
 		let agent_id = req.agent_id.trim();
@@ -48,6 +58,8 @@ FOR UPDATE",
 			return Err(Error::InvalidRequest { message: "Note not found.".to_string() });
 		}
 
+		self.authorize_write(req.role, WriteOperation::Update, note.scope.as_str())?;
+
 		let scope = req.scope.as_str();
 		let scope_allowed = match scope {
 			"project_shared" => self.cfg.scopes.write_allowed.project_shared,
@@ -59,6 +71,8 @@ FOR UPDATE",
 			return Err(Error::ScopeDenied { message: "Scope is not allowed.".to_string() });
 		}
 
+		self.authorize_write(req.role, WriteOperation::Update, scope)?;
+
 		let target_project_id = if scope == "org_shared" { ORG_PROJECT_ID } else { project_id };
 
 		access::ensure_active_project_scope_grant(
@@ -82,7 +96,7 @@ FOR UPDATE",
 		note.updated_at = now;
 
 		crate::insert_version(
-			&mut *tx,
+			&mut tx,
 			InsertVersionArgs {
 				note_id: note.note_id,
 				op: "PUBLISH",
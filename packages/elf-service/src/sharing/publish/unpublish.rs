@@ -1,8 +1,9 @@
 use time::OffsetDateTime;
 
 use crate::{
-	ElfService, Error, InsertVersionArgs, Result,
+	ElfService, Error, InsertVersionArgs, Result, WriteOperation,
 	access::ORG_PROJECT_ID,
+	audit::AuditScope,
 	sharing::types::{UnpublishNoteRequest, UnpublishNoteResponse},
 };
 use elf_storage::models::MemoryNote;
@@ -10,6 +11,18 @@ use elf_storage::models::MemoryNote;
 impl ElfService {
 	/// Returns a previously published note to its non-shared scope.
 	pub async fn unpublish_note(&self, req: UnpublishNoteRequest) -> Result<UnpublishNoteResponse> {
+		let scope = AuditScope::new("unpublish")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.note_id]);
+
+		self.audited(scope, self.unpublish_note_inner(req)).await
+	}
+
+	/// Runs [`ElfService::unpublish_note`] without recording an audit entry.
+	async fn unpublish_note_inner(
+		&self,
+		req: UnpublishNoteRequest,
+	) -> Result<UnpublishNoteResponse> {
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
 		let agent_id = req.agent_id.trim();
@@ -47,9 +60,13 @@ FOR UPDATE",
 		if note.expires_at.map(|ts| ts <= OffsetDateTime::now_utc()).unwrap_or(false) {
 			return Err(Error::InvalidRequest { message: "Note not found.".to_string() });
 		}
+
+		self.authorize_write(req.role, WriteOperation::Update, note.scope.as_str())?;
 		if !self.cfg.scopes.write_allowed.agent_private {
 			return Err(Error::ScopeDenied { message: "Scope is not allowed.".to_string() });
 		}
+
+		self.authorize_write(req.role, WriteOperation::Update, "agent_private")?;
 		if note.scope == "agent_private" {
 			return Ok(UnpublishNoteResponse { note_id: note.note_id, scope: note.scope });
 		}
@@ -65,7 +82,7 @@ FOR UPDATE",
 		note.updated_at = now;
 
 		crate::insert_version(
-			&mut *tx,
+			&mut tx,
 			InsertVersionArgs {
 				note_id: note.note_id,
 				op: "UNPUBLISH",
@@ -28,7 +28,18 @@ SET
 	granted_by_agent_id = EXCLUDED.granted_by_agent_id,
 	granted_at = EXCLUDED.granted_at,
 	revoked_at = NULL,
-	revoked_by_agent_id = NULL";
+	revoked_by_agent_id = NULL
+RETURNING
+	grant_id,
+	project_id,
+	scope,
+	space_owner_agent_id,
+	grantee_kind,
+	grantee_agent_id,
+	granted_by_agent_id,
+	granted_at,
+	revoked_by_agent_id,
+	revoked_at";
 pub(super) const AGENT_SPACE_GRANT_UPSERT_SQL: &str = "\
 INSERT INTO memory_space_grants (
 	grant_id,
@@ -59,4 +70,15 @@ SET
 	granted_by_agent_id = EXCLUDED.granted_by_agent_id,
 	granted_at = EXCLUDED.granted_at,
 	revoked_at = NULL,
-	revoked_by_agent_id = NULL";
+	revoked_by_agent_id = NULL
+RETURNING
+	grant_id,
+	project_id,
+	scope,
+	space_owner_agent_id,
+	grantee_kind,
+	grantee_agent_id,
+	granted_by_agent_id,
+	granted_at,
+	revoked_by_agent_id,
+	revoked_at";
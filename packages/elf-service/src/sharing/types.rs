@@ -1,9 +1,12 @@
+use crate::audit::AuditTargets;
 use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
+use elf_config::SecurityAuthRole;
+
 /// Shareable scopes that can be published or granted.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -47,6 +50,10 @@ pub struct PublishNoteRequest {
 	pub project_id: String,
 	/// Agent requesting the publish operation.
 	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the note's scope and the target scope;
+	/// `None` when auth is off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
 	/// Identifier of the note to publish.
 	pub note_id: Uuid,
 	/// Target shared scope.
@@ -62,6 +69,12 @@ pub struct PublishNoteResponse {
 	pub scope: String,
 }
 
+impl AuditTargets for PublishNoteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.note_id]
+	}
+}
+
 /// Request payload for returning a note to its non-shared scope.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnpublishNoteRequest {
@@ -71,6 +84,10 @@ pub struct UnpublishNoteRequest {
 	pub project_id: String,
 	/// Agent requesting the unpublish operation.
 	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the note's scope and the target scope;
+	/// `None` when auth is off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
 	/// Identifier of the note to unpublish.
 	pub note_id: Uuid,
 }
@@ -84,6 +101,12 @@ pub struct UnpublishNoteResponse {
 	pub scope: String,
 }
 
+impl AuditTargets for UnpublishNoteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.note_id]
+	}
+}
+
 /// Request payload for granting a shared scope.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SpaceGrantUpsertRequest {
@@ -114,6 +137,8 @@ pub struct SpaceGrantUpsertResponse {
 	pub granted: bool,
 }
 
+impl AuditTargets for SpaceGrantUpsertResponse {}
+
 /// Request payload for revoking a shared-scope grant.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SpaceGrantRevokeRequest {
@@ -138,6 +163,8 @@ pub struct SpaceGrantRevokeResponse {
 	pub revoked: bool,
 }
 
+impl AuditTargets for SpaceGrantRevokeResponse {}
+
 /// Request payload for listing shared-scope grants.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SpaceGrantsListRequest {
@@ -0,0 +1,13 @@
+//! Standing queries evaluated by the worker as new notes are indexed.
+
+mod service;
+mod types;
+
+pub use types::{
+	StandingQueriesListRequest, StandingQueriesListResponse, StandingQueryCreateRequest,
+	StandingQueryDeleteResponse, StandingQueryFilter, StandingQueryGetRequest,
+	StandingQueryMatchItem, StandingQueryMatchesRequest, StandingQueryMatchesResponse,
+	StandingQueryResponse,
+};
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,346 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	audit::AuditScope,
+	consolidation, search,
+	standing_queries::types::{
+		StandingQueriesListRequest, StandingQueriesListResponse, StandingQueryCreateRequest,
+		StandingQueryDeleteResponse, StandingQueryFilter, StandingQueryGetRequest,
+		StandingQueryMatchItem, StandingQueryMatchesRequest, StandingQueryMatchesResponse,
+		StandingQueryResponse,
+	},
+};
+use elf_config::SecurityEgress;
+use elf_domain::{egress, english_gate};
+use elf_storage::{
+	models::{StandingQuery, StandingQueryMatch},
+	standing_queries,
+};
+
+const MAX_STANDING_QUERIES_PER_AGENT: i64 = 50;
+pub(in crate::standing_queries) const MAX_TOP_K: u32 = 100;
+const MAX_WEBHOOK_URL_CHARS: usize = 2_048;
+const DEFAULT_MATCHES_LIMIT: u32 = 50;
+const MAX_MATCHES_LIMIT: u32 = 500;
+const NOTE_TYPES: &[&str] =
+	&["preference", "constraint", "decision", "profile", "fact", "plan", "qa"];
+
+impl ElfService {
+	/// Registers a standing query that the worker evaluates as new notes are indexed.
+	pub async fn standing_query_create(
+		&self,
+		req: StandingQueryCreateRequest,
+	) -> Result<StandingQueryResponse> {
+		let scope = AuditScope::new("standing_query_create").caller(
+			&req.tenant_id,
+			&req.project_id,
+			&req.agent_id,
+		);
+
+		self.audited(scope, self.standing_query_create_inner(req)).await
+	}
+
+	/// Runs [`ElfService::standing_query_create`] without recording an audit entry.
+	async fn standing_query_create_inner(
+		&self,
+		req: StandingQueryCreateRequest,
+	) -> Result<StandingQueryResponse> {
+		consolidation::validate_context(&req.tenant_id, &req.project_id, &req.agent_id)?;
+
+		let query = req.query.trim();
+		let top_k = validate_top_k(req.top_k.unwrap_or(self.cfg.memory.top_k))?;
+		let filter = validate_filter(req.filter)?;
+		let webhook_url = req
+			.webhook_url
+			.as_deref()
+			.map(|raw| validate_webhook_url(raw, self.cfg.security.egress.as_ref()))
+			.transpose()?;
+
+		validate_query(query)?;
+
+		let allowed_scopes =
+			search::resolve_read_profile_scopes(&self.cfg, req.read_profile.trim())?;
+		let tenant_id = req.tenant_id.trim();
+		let project_id = req.project_id.trim();
+		let agent_id = req.agent_id.trim();
+		let existing = standing_queries::count_active_standing_queries(
+			&self.db.pool,
+			tenant_id,
+			project_id,
+			agent_id,
+		)
+		.await?;
+
+		if existing >= MAX_STANDING_QUERIES_PER_AGENT {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"An agent may register at most {MAX_STANDING_QUERIES_PER_AGENT} standing queries."
+				),
+			});
+		}
+
+		let now = OffsetDateTime::now_utc();
+		let row = StandingQuery {
+			standing_query_id: Uuid::new_v4(),
+			tenant_id: tenant_id.to_string(),
+			project_id: project_id.to_string(),
+			agent_id: agent_id.to_string(),
+			query: query.to_string(),
+			allowed_scopes,
+			note_types: filter.types,
+			min_importance: filter.min_importance,
+			min_confidence: filter.min_confidence,
+			top_k: top_k as i32,
+			webhook_url,
+			status: "active".to_string(),
+			created_at: now,
+			updated_at: now,
+		};
+
+		standing_queries::insert_standing_query(&self.db.pool, &row).await?;
+
+		Ok(row_to_response(row))
+	}
+
+	/// Lists the active standing queries registered by the requesting agent.
+	pub async fn standing_queries_list(
+		&self,
+		req: StandingQueriesListRequest,
+	) -> Result<StandingQueriesListResponse> {
+		consolidation::validate_context(&req.tenant_id, &req.project_id, &req.agent_id)?;
+
+		let rows = standing_queries::list_standing_queries(
+			&self.db.pool,
+			req.tenant_id.trim(),
+			req.project_id.trim(),
+			req.agent_id.trim(),
+		)
+		.await?;
+
+		Ok(StandingQueriesListResponse { items: rows.into_iter().map(row_to_response).collect() })
+	}
+
+	/// Reads one standing query registered by the requesting agent.
+	pub async fn standing_query_get(
+		&self,
+		req: StandingQueryGetRequest,
+	) -> Result<StandingQueryResponse> {
+		let row = self.load_owned_standing_query(&req).await?;
+
+		Ok(row_to_response(row))
+	}
+
+	/// Deletes one standing query and cancels its pending webhook deliveries.
+	pub async fn standing_query_delete(
+		&self,
+		req: StandingQueryGetRequest,
+	) -> Result<StandingQueryDeleteResponse> {
+		let scope = AuditScope::new("standing_query_delete")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.standing_query_id]);
+
+		self.audited(scope, self.standing_query_delete_inner(req)).await
+	}
+
+	/// Runs [`ElfService::standing_query_delete`] without recording an audit entry.
+	async fn standing_query_delete_inner(
+		&self,
+		req: StandingQueryGetRequest,
+	) -> Result<StandingQueryDeleteResponse> {
+		consolidation::validate_context(&req.tenant_id, &req.project_id, &req.agent_id)?;
+
+		let now = OffsetDateTime::now_utc();
+		let deleted = standing_queries::delete_standing_query(
+			&self.db.pool,
+			req.tenant_id.trim(),
+			req.project_id.trim(),
+			req.agent_id.trim(),
+			req.standing_query_id,
+			now,
+		)
+		.await?;
+
+		if !deleted {
+			return Err(standing_query_not_found());
+		}
+
+		Ok(StandingQueryDeleteResponse {
+			standing_query_id: req.standing_query_id,
+			deleted_at: now,
+		})
+	}
+
+	/// Reads the match change feed of one standing query in recording order.
+	pub async fn standing_query_matches(
+		&self,
+		req: StandingQueryMatchesRequest,
+	) -> Result<StandingQueryMatchesResponse> {
+		let row = self
+			.load_owned_standing_query(&StandingQueryGetRequest {
+				tenant_id: req.tenant_id.clone(),
+				project_id: req.project_id.clone(),
+				agent_id: req.agent_id.clone(),
+				standing_query_id: req.standing_query_id,
+			})
+			.await?;
+		let after_seq = req.after_seq.unwrap_or(0).max(0);
+		let limit = req.limit.unwrap_or(DEFAULT_MATCHES_LIMIT).clamp(1, MAX_MATCHES_LIMIT);
+		let matches = standing_queries::list_standing_query_matches(
+			&self.db.pool,
+			row.standing_query_id,
+			after_seq,
+			i64::from(limit),
+		)
+		.await?;
+		let next_after_seq = matches.last().map_or(after_seq, |item| item.seq);
+
+		Ok(StandingQueryMatchesResponse {
+			standing_query_id: row.standing_query_id,
+			items: matches.into_iter().map(match_to_item).collect(),
+			next_after_seq,
+		})
+	}
+
+	async fn load_owned_standing_query(
+		&self,
+		req: &StandingQueryGetRequest,
+	) -> Result<StandingQuery> {
+		consolidation::validate_context(&req.tenant_id, &req.project_id, &req.agent_id)?;
+
+		standing_queries::get_standing_query(
+			&self.db.pool,
+			req.tenant_id.trim(),
+			req.project_id.trim(),
+			req.agent_id.trim(),
+			req.standing_query_id,
+		)
+		.await?
+		.ok_or_else(standing_query_not_found)
+	}
+}
+
+pub(in crate::standing_queries) fn validate_top_k(top_k: u32) -> Result<u32> {
+	if top_k == 0 || top_k > MAX_TOP_K {
+		return Err(Error::InvalidRequest {
+			message: format!("top_k must be between 1 and {MAX_TOP_K}."),
+		});
+	}
+
+	Ok(top_k)
+}
+
+pub(in crate::standing_queries) fn validate_filter(
+	filter: StandingQueryFilter,
+) -> Result<StandingQueryFilter> {
+	let mut types = Vec::with_capacity(filter.types.len());
+
+	for (idx, note_type) in filter.types.iter().enumerate() {
+		let note_type = note_type.trim();
+
+		if !NOTE_TYPES.contains(&note_type) {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"$.filter.types[{idx}] must be one of: {}.",
+					NOTE_TYPES.join(", ")
+				),
+			});
+		}
+		if !types.iter().any(|existing| existing == note_type) {
+			types.push(note_type.to_string());
+		}
+	}
+
+	for (field, value) in
+		[("min_importance", filter.min_importance), ("min_confidence", filter.min_confidence)]
+	{
+		if value.is_some_and(|value| !(0.0..=1.0).contains(&value)) {
+			return Err(Error::InvalidRequest {
+				message: format!("$.filter.{field} must be between 0.0 and 1.0."),
+			});
+		}
+	}
+
+	Ok(StandingQueryFilter {
+		types,
+		min_importance: filter.min_importance,
+		min_confidence: filter.min_confidence,
+	})
+}
+
+pub(in crate::standing_queries) fn validate_webhook_url(
+	raw: &str,
+	policy: Option<&SecurityEgress>,
+) -> Result<String> {
+	let url = raw.trim();
+	let has_host = url
+		.strip_prefix("https://")
+		.or_else(|| url.strip_prefix("http://"))
+		.is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'));
+
+	if !has_host || url.chars().any(char::is_whitespace) || url.len() > MAX_WEBHOOK_URL_CHARS {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"webhook_url must be an http or https URL of at most {MAX_WEBHOOK_URL_CHARS} characters."
+			),
+		});
+	}
+	if let Some(denial) =
+		egress::url_host(url).and_then(|host| egress::check_host(host, policy).err())
+	{
+		return Err(Error::InvalidRequest {
+			message: format!("webhook_url is not deliverable: {}.", denial.message()),
+		});
+	}
+
+	Ok(url.to_string())
+}
+
+fn validate_query(query: &str) -> Result<()> {
+	if query.is_empty() {
+		return Err(Error::InvalidRequest { message: "query is required.".to_string() });
+	}
+	if !english_gate::is_english_natural_language(query) {
+		return Err(Error::NonEnglishInput { field: "$.query".to_string() });
+	}
+
+	Ok(())
+}
+
+fn standing_query_not_found() -> Error {
+	Error::NotFound { message: "Standing query not found.".to_string() }
+}
+
+fn row_to_response(row: StandingQuery) -> StandingQueryResponse {
+	StandingQueryResponse {
+		standing_query_id: row.standing_query_id,
+		query: row.query,
+		allowed_scopes: row.allowed_scopes,
+		filter: StandingQueryFilter {
+			types: row.note_types,
+			min_importance: row.min_importance,
+			min_confidence: row.min_confidence,
+		},
+		top_k: u32::try_from(row.top_k).unwrap_or(0),
+		webhook_url: row.webhook_url,
+		created_at: row.created_at,
+		updated_at: row.updated_at,
+	}
+}
+
+fn match_to_item(row: StandingQueryMatch) -> StandingQueryMatchItem {
+	StandingQueryMatchItem {
+		match_id: row.match_id,
+		seq: row.seq,
+		note_id: row.note_id,
+		embedding_version: row.embedding_version,
+		rank: u32::try_from(row.rank).unwrap_or(0),
+		score: row.score,
+		notify_status: row.notify_status,
+		notify_attempts: u32::try_from(row.notify_attempts).unwrap_or(0),
+		notify_error: row.notify_error,
+		notified_at: row.notified_at,
+		matched_at: row.created_at,
+	}
+}
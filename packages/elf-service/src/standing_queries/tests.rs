@@ -0,0 +1,93 @@
+use elf_config::SecurityEgress;
+
+use crate::{
+	Error,
+	standing_queries::{
+		StandingQueryFilter,
+		service::{self, MAX_TOP_K},
+	},
+};
+
+#[test]
+fn validate_top_k_rejects_zero_and_values_above_the_cap() {
+	assert!(matches!(service::validate_top_k(0), Err(Error::InvalidRequest { .. })));
+	assert!(matches!(service::validate_top_k(MAX_TOP_K + 1), Err(Error::InvalidRequest { .. })));
+	assert_eq!(service::validate_top_k(MAX_TOP_K).expect("cap is accepted"), MAX_TOP_K);
+}
+
+#[test]
+fn validate_filter_trims_and_dedupes_types() {
+	let filter = service::validate_filter(StandingQueryFilter {
+		types: vec![" fact".to_string(), "decision".to_string(), "fact".to_string()],
+		min_importance: Some(0.5),
+		min_confidence: None,
+	})
+	.expect("filter is valid");
+
+	assert_eq!(filter.types, vec!["fact".to_string(), "decision".to_string()]);
+	assert_eq!(filter.min_importance, Some(0.5));
+}
+
+#[test]
+fn validate_filter_rejects_unknown_types_and_out_of_range_thresholds() {
+	let unknown = service::validate_filter(StandingQueryFilter {
+		types: vec!["rumor".to_string()],
+		..Default::default()
+	});
+	let out_of_range = service::validate_filter(StandingQueryFilter {
+		min_confidence: Some(1.5),
+		..Default::default()
+	});
+
+	assert!(
+		matches!(unknown, Err(Error::InvalidRequest { message }) if message.contains("$.filter.types[0]"))
+	);
+	assert!(
+		matches!(out_of_range, Err(Error::InvalidRequest { message }) if message.contains("min_confidence"))
+	);
+}
+
+#[test]
+fn validate_webhook_url_requires_http_scheme_and_host() {
+	assert_eq!(
+		service::validate_webhook_url(" https://hooks.example.com/elf ", None).expect("valid URL"),
+		"https://hooks.example.com/elf"
+	);
+
+	for raw in [
+		"ftp://hooks.example.com",
+		"https://",
+		"http:///path",
+		"https://a b.example.com",
+		"http://127.0.0.1:8080/hook",
+		"http://169.254.169.254/latest/meta-data/",
+		"http://10.1.2.3/hook",
+		"http://localhost/hook",
+		"http://[::1]/hook",
+	] {
+		assert!(
+			matches!(service::validate_webhook_url(raw, None), Err(Error::InvalidRequest { .. })),
+			"{raw} should be rejected"
+		);
+	}
+}
+
+#[test]
+fn validate_webhook_url_applies_egress_host_lists() {
+	let policy = SecurityEgress {
+		allowed_hosts: vec!["example.com".to_string()],
+		denied_hosts: vec!["internal.example.com".to_string()],
+	};
+
+	assert!(service::validate_webhook_url("https://hooks.example.com/elf", Some(&policy)).is_ok());
+
+	for raw in ["https://hooks.internal.example.com/elf", "https://hooks.example.org/elf"] {
+		assert!(
+			matches!(
+				service::validate_webhook_url(raw, Some(&policy)),
+				Err(Error::InvalidRequest { .. })
+			),
+			"{raw} should be rejected"
+		);
+	}
+}
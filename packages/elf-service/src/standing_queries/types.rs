@@ -0,0 +1,171 @@
+use crate::audit::AuditTargets;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Note filter applied when the worker evaluates a standing query.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StandingQueryFilter {
+	#[serde(default)]
+	/// Note types to match; empty matches every type.
+	pub types: Vec<String>,
+	/// Optional minimum note importance.
+	pub min_importance: Option<f32>,
+	/// Optional minimum note confidence.
+	pub min_confidence: Option<f32>,
+}
+
+/// Request payload for registering a standing query.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueryCreateRequest {
+	/// Tenant that owns the standing query.
+	pub tenant_id: String,
+	/// Project that owns the standing query.
+	pub project_id: String,
+	/// Agent registering the standing query.
+	pub agent_id: String,
+	/// Read profile whose scopes bound the notes the standing query can match.
+	pub read_profile: String,
+	/// Query text matched against newly indexed notes.
+	pub query: String,
+	/// Rank cutoff a new note must reach to record a match.
+	pub top_k: Option<u32>,
+	#[serde(default)]
+	/// Optional note filter.
+	pub filter: StandingQueryFilter,
+	/// Optional webhook notified for each new match.
+	pub webhook_url: Option<String>,
+}
+
+/// Request payload for reading or deleting one standing query.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueryGetRequest {
+	/// Tenant that owns the standing query.
+	pub tenant_id: String,
+	/// Project that owns the standing query.
+	pub project_id: String,
+	/// Agent that registered the standing query.
+	pub agent_id: String,
+	/// Standing query identifier.
+	pub standing_query_id: Uuid,
+}
+
+/// Request payload for listing an agent's standing queries.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueriesListRequest {
+	/// Tenant that owns the standing queries.
+	pub tenant_id: String,
+	/// Project that owns the standing queries.
+	pub project_id: String,
+	/// Agent that registered the standing queries.
+	pub agent_id: String,
+}
+
+/// Request payload for reading the match change feed of one standing query.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueryMatchesRequest {
+	/// Tenant that owns the standing query.
+	pub tenant_id: String,
+	/// Project that owns the standing query.
+	pub project_id: String,
+	/// Agent that registered the standing query.
+	pub agent_id: String,
+	/// Standing query identifier.
+	pub standing_query_id: Uuid,
+	/// Return only matches after this change-feed position.
+	pub after_seq: Option<i64>,
+	/// Optional cap for returned matches.
+	pub limit: Option<u32>,
+}
+
+/// Registered standing query.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueryResponse {
+	/// Standing query identifier.
+	pub standing_query_id: Uuid,
+	/// Query text matched against newly indexed notes.
+	pub query: String,
+	/// Scopes resolved from the registering read profile.
+	pub allowed_scopes: Vec<String>,
+	/// Note filter.
+	pub filter: StandingQueryFilter,
+	/// Rank cutoff a new note must reach to record a match.
+	pub top_k: u32,
+	/// Webhook notified for each new match, if any.
+	pub webhook_url: Option<String>,
+	#[serde(with = "crate::time_serde")]
+	/// Registration timestamp.
+	pub created_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
+
+impl AuditTargets for StandingQueryResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.standing_query_id]
+	}
+}
+
+/// Standing queries registered by one agent.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueriesListResponse {
+	/// Standing queries in newest-first order.
+	pub items: Vec<StandingQueryResponse>,
+}
+
+/// Result of deleting a standing query.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueryDeleteResponse {
+	/// Deleted standing query identifier.
+	pub standing_query_id: Uuid,
+	#[serde(with = "crate::time_serde")]
+	/// Deletion timestamp.
+	pub deleted_at: OffsetDateTime,
+}
+
+impl AuditTargets for StandingQueryDeleteResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.standing_query_id]
+	}
+}
+
+/// One note that entered a standing query's top-k when it was indexed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueryMatchItem {
+	/// Match identifier.
+	pub match_id: Uuid,
+	/// Change-feed position.
+	pub seq: i64,
+	/// Matched note identifier.
+	pub note_id: Uuid,
+	/// Embedding version used to rank the note.
+	pub embedding_version: String,
+	/// 1-based rank of the note when it was indexed.
+	pub rank: u32,
+	/// Cosine similarity between the query and the note.
+	pub score: f32,
+	/// Webhook delivery status: `none`, `pending`, `delivered`, or `failed`.
+	pub notify_status: String,
+	/// Number of webhook delivery attempts.
+	pub notify_attempts: u32,
+	/// Last webhook delivery error.
+	pub notify_error: Option<String>,
+	#[serde(with = "crate::time_serde::option")]
+	/// Successful webhook delivery timestamp.
+	pub notified_at: Option<OffsetDateTime>,
+	#[serde(with = "crate::time_serde")]
+	/// Match timestamp.
+	pub matched_at: OffsetDateTime,
+}
+
+/// Page of the match change feed for one standing query.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StandingQueryMatchesResponse {
+	/// Standing query identifier.
+	pub standing_query_id: Uuid,
+	/// Matches in change-feed order.
+	pub items: Vec<StandingQueryMatchItem>,
+	/// Position to pass as `after_seq` on the next poll.
+	pub next_after_seq: i64,
+}
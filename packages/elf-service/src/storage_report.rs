@@ -0,0 +1,555 @@
+//! Storage usage breakdown and maintenance advisor.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgExecutor};
+use time::{Duration, OffsetDateTime};
+
+use crate::{ElfService, Result};
+
+/// Storage report response schema identifier.
+pub const ELF_STORAGE_REPORT_SCHEMA_V1: &str = "elf.storage_report/v1";
+
+const VACUUM_MIN_DEAD_ROWS: i64 = 10_000;
+const VACUUM_MIN_DEAD_RATIO: f64 = 0.2;
+const REINDEX_MIN_BLOAT_BYTES: i64 = 64 * 1_024 * 1_024;
+const UNUSED_INDEX_MIN_BYTES: i64 = 16 * 1_024 * 1_024;
+const EXPIRED_ROWS_MIN: i64 = 1_000;
+const OUTBOX_DONE_RETENTION_DAYS: i64 = 7;
+
+/// Storage usage, bloat estimates, and suggested maintenance for the ELF database.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StorageReportResponse {
+	/// Response schema identifier.
+	pub schema: String,
+	#[serde(with = "crate::time_serde")]
+	/// Timestamp the report was generated at.
+	pub generated_at: OffsetDateTime,
+	/// Total size of the current database in bytes.
+	pub database_bytes: i64,
+	/// Per-table usage, largest first.
+	pub tables: Vec<StorageTableUsage>,
+	/// Per-tenant usage, largest first.
+	pub tenants: Vec<StorageTenantUsage>,
+	/// Per-index usage and bloat estimates, largest estimated bloat first.
+	pub indexes: Vec<StorageIndexUsage>,
+	/// Suggested maintenance actions. Nothing is executed by the report.
+	pub actions: Vec<StorageMaintenanceAction>,
+}
+
+/// Storage usage for one table.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StorageTableUsage {
+	/// Table name.
+	pub table: String,
+	/// Storage category: notes, chunks, embeddings, traces, caches, docs, graph, or other.
+	pub category: String,
+	/// Heap, index, and TOAST bytes.
+	pub total_bytes: i64,
+	/// Main heap bytes.
+	pub table_bytes: i64,
+	/// Bytes across all indexes on the table.
+	pub index_bytes: i64,
+	/// Estimated live rows.
+	pub live_rows: i64,
+	/// Estimated dead rows awaiting vacuum.
+	pub dead_rows: i64,
+	/// Dead rows as a fraction of live plus dead rows.
+	pub dead_ratio: f64,
+	#[serde(with = "crate::time_serde::option")]
+	/// Last manual vacuum, if any.
+	pub last_vacuum_at: Option<OffsetDateTime>,
+	#[serde(with = "crate::time_serde::option")]
+	/// Last autovacuum, if any.
+	pub last_autovacuum_at: Option<OffsetDateTime>,
+}
+
+/// Storage usage attributed to one tenant.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StorageTenantUsage {
+	/// Tenant identifier.
+	pub tenant_id: String,
+	/// Sum of row bytes across categories.
+	pub total_bytes: i64,
+	/// Usage per storage category.
+	pub categories: Vec<StorageCategoryUsage>,
+}
+
+/// Row count and row bytes for one storage category.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct StorageCategoryUsage {
+	/// Storage category.
+	pub category: String,
+	/// Number of rows.
+	pub row_count: i64,
+	/// Sum of row sizes in bytes, excluding index and page overhead.
+	pub bytes: i64,
+}
+
+/// Usage and bloat estimate for one index.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StorageIndexUsage {
+	/// Index name.
+	pub index: String,
+	/// Table the index belongs to.
+	pub table: String,
+	/// Index size in bytes.
+	pub index_bytes: i64,
+	/// Number of index scans since statistics were last reset.
+	pub scans: i64,
+	/// Whether the index backs a primary key or unique constraint.
+	pub unique: bool,
+	/// Estimated reclaimable bytes, derived from the table's dead-row ratio.
+	pub estimated_bloat_bytes: i64,
+}
+
+/// One suggested maintenance action.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct StorageMaintenanceAction {
+	/// Action kind: vacuum, reindex, review_unused_index, cache_purge, trace_purge,
+	/// session_purge, outbox_purge, or note_purge.
+	pub kind: String,
+	/// Table or index the action applies to.
+	pub target: String,
+	/// Why the action is suggested.
+	pub reason: String,
+	/// Rough bytes the action could reclaim, when estimable.
+	pub estimated_reclaim_bytes: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	/// Suggested SQL, when the action is a single safe statement.
+	pub sql: Option<String>,
+}
+
+#[derive(Clone, Debug, FromRow)]
+struct TableRow {
+	table_name: String,
+	total_bytes: i64,
+	table_bytes: i64,
+	index_bytes: i64,
+	live_rows: i64,
+	dead_rows: i64,
+	last_vacuum: Option<OffsetDateTime>,
+	last_autovacuum: Option<OffsetDateTime>,
+}
+
+#[derive(Clone, Debug, FromRow)]
+struct IndexRow {
+	index_name: String,
+	table_name: String,
+	index_bytes: i64,
+	scans: i64,
+	is_unique: bool,
+}
+
+#[derive(Clone, Debug, FromRow)]
+struct TenantRow {
+	tenant_id: String,
+	category: String,
+	row_count: i64,
+	bytes: i64,
+}
+
+#[derive(Clone, Debug, Default, FromRow)]
+struct BacklogRow {
+	expired_cache_rows: i64,
+	expired_trace_rows: i64,
+	expired_session_rows: i64,
+	done_outbox_rows: i64,
+	purgeable_deleted_notes: i64,
+	purgeable_deprecated_notes: i64,
+}
+
+impl ElfService {
+	/// Reports per-table and per-tenant storage, index bloat estimates, and suggested maintenance.
+	///
+	/// The report only reads catalog statistics and row sizes; it never runs the actions it
+	/// suggests.
+	pub async fn storage_report(&self) -> Result<StorageReportResponse> {
+		let now = OffsetDateTime::now_utc();
+		let mut conn = self.db.pool.acquire().await?;
+		let database_bytes: i64 = sqlx::query_scalar("SELECT pg_database_size(current_database())")
+			.fetch_one(&mut *conn)
+			.await?;
+		let tables: Vec<StorageTableUsage> =
+			fetch_table_rows(&mut *conn).await?.into_iter().map(build_table_usage).collect();
+		let indexes = build_index_usage(fetch_index_rows(&mut *conn).await?, &tables);
+		let tenants = group_tenant_rows(fetch_tenant_rows(&mut *conn).await?);
+		let lifecycle = &self.cfg.lifecycle;
+		let backlog = fetch_backlog(
+			&mut *conn,
+			now,
+			now - Duration::days(OUTBOX_DONE_RETENTION_DAYS),
+			now - Duration::days(lifecycle.purge_deleted_after_days),
+			now - Duration::days(lifecycle.purge_deprecated_after_days),
+		)
+		.await?;
+		let actions = build_maintenance_actions(&tables, &indexes, &backlog);
+
+		Ok(StorageReportResponse {
+			schema: ELF_STORAGE_REPORT_SCHEMA_V1.to_string(),
+			generated_at: now,
+			database_bytes,
+			tables,
+			tenants,
+			indexes,
+			actions,
+		})
+	}
+}
+
+fn table_category(table: &str) -> &'static str {
+	match table {
+		"memory_note_chunks" => "chunks",
+		"note_embeddings"
+		| "note_chunk_embeddings"
+		| "note_field_embeddings"
+		| "chunk_content_embeddings"
+		| "standing_query_embeddings" => "embeddings",
+		"llm_cache" | "search_sessions" => "caches",
+		_ if table.starts_with("doc_") => "docs",
+		_ if table.starts_with("search_trace") => "traces",
+		_ if table.starts_with("graph_") => "graph",
+		_ if table.starts_with("memory_note") || table == "memory_hits" => "notes",
+		_ => "other",
+	}
+}
+
+fn dead_ratio(live_rows: i64, dead_rows: i64) -> f64 {
+	let total = live_rows.max(0) + dead_rows.max(0);
+
+	if total == 0 { 0.0 } else { dead_rows.max(0) as f64 / total as f64 }
+}
+
+fn build_table_usage(row: TableRow) -> StorageTableUsage {
+	StorageTableUsage {
+		category: table_category(row.table_name.as_str()).to_string(),
+		dead_ratio: dead_ratio(row.live_rows, row.dead_rows),
+		table: row.table_name,
+		total_bytes: row.total_bytes,
+		table_bytes: row.table_bytes,
+		index_bytes: row.index_bytes,
+		live_rows: row.live_rows,
+		dead_rows: row.dead_rows,
+		last_vacuum_at: row.last_vacuum,
+		last_autovacuum_at: row.last_autovacuum,
+	}
+}
+
+fn build_index_usage(rows: Vec<IndexRow>, tables: &[StorageTableUsage]) -> Vec<StorageIndexUsage> {
+	let ratios: HashMap<&str, f64> =
+		tables.iter().map(|table| (table.table.as_str(), table.dead_ratio)).collect();
+	let mut indexes: Vec<StorageIndexUsage> = rows
+		.into_iter()
+		.map(|row| {
+			let ratio = ratios.get(row.table_name.as_str()).copied().unwrap_or(0.0);
+
+			StorageIndexUsage {
+				estimated_bloat_bytes: (row.index_bytes as f64 * ratio) as i64,
+				index: row.index_name,
+				table: row.table_name,
+				index_bytes: row.index_bytes,
+				scans: row.scans,
+				unique: row.is_unique,
+			}
+		})
+		.collect();
+
+	indexes.sort_by(|a, b| {
+		b.estimated_bloat_bytes
+			.cmp(&a.estimated_bloat_bytes)
+			.then_with(|| b.index_bytes.cmp(&a.index_bytes))
+			.then_with(|| a.index.cmp(&b.index))
+	});
+
+	indexes
+}
+
+fn group_tenant_rows(rows: Vec<TenantRow>) -> Vec<StorageTenantUsage> {
+	let mut by_tenant: BTreeMap<String, Vec<StorageCategoryUsage>> = BTreeMap::new();
+
+	for row in rows {
+		by_tenant.entry(row.tenant_id).or_default().push(StorageCategoryUsage {
+			category: row.category,
+			row_count: row.row_count,
+			bytes: row.bytes,
+		});
+	}
+
+	let mut tenants: Vec<StorageTenantUsage> = by_tenant
+		.into_iter()
+		.map(|(tenant_id, mut categories)| {
+			categories.sort_by(|a, b| a.category.cmp(&b.category));
+
+			StorageTenantUsage {
+				tenant_id,
+				total_bytes: categories.iter().map(|category| category.bytes).sum(),
+				categories,
+			}
+		})
+		.collect();
+
+	tenants.sort_by(|a, b| b.total_bytes.cmp(&a.total_bytes).then(a.tenant_id.cmp(&b.tenant_id)));
+
+	tenants
+}
+
+/// Estimates bytes held by `rows` rows of `table` from its average heap row size.
+fn estimate_row_bytes(tables: &[StorageTableUsage], table: &str, rows: i64) -> Option<i64> {
+	let usage = tables.iter().find(|usage| usage.table == table)?;
+	let total_rows = usage.live_rows + usage.dead_rows;
+
+	if total_rows <= 0 {
+		return None;
+	}
+
+	Some(usage.total_bytes / total_rows * rows)
+}
+
+fn build_maintenance_actions(
+	tables: &[StorageTableUsage],
+	indexes: &[StorageIndexUsage],
+	backlog: &BacklogRow,
+) -> Vec<StorageMaintenanceAction> {
+	let mut actions = Vec::new();
+
+	for table in tables {
+		if table.dead_rows >= VACUUM_MIN_DEAD_ROWS && table.dead_ratio >= VACUUM_MIN_DEAD_RATIO {
+			actions.push(StorageMaintenanceAction {
+				kind: "vacuum".to_string(),
+				target: table.table.clone(),
+				reason: format!(
+					"{} dead rows ({:.0}% of the table) are awaiting vacuum.",
+					table.dead_rows,
+					table.dead_ratio * 100.0
+				),
+				estimated_reclaim_bytes: Some((table.table_bytes as f64 * table.dead_ratio) as i64),
+				sql: Some(format!("VACUUM (ANALYZE) {};", table.table)),
+			});
+		}
+	}
+	for index in indexes {
+		if index.estimated_bloat_bytes >= REINDEX_MIN_BLOAT_BYTES {
+			actions.push(StorageMaintenanceAction {
+				kind: "reindex".to_string(),
+				target: index.index.clone(),
+				reason: format!(
+					"An estimated {} bytes of the index are bloat from dead rows in {}.",
+					index.estimated_bloat_bytes, index.table
+				),
+				estimated_reclaim_bytes: Some(index.estimated_bloat_bytes),
+				sql: Some(format!("REINDEX INDEX CONCURRENTLY {};", index.index)),
+			});
+		}
+		if index.scans == 0 && !index.unique && index.index_bytes >= UNUSED_INDEX_MIN_BYTES {
+			actions.push(StorageMaintenanceAction {
+				kind: "review_unused_index".to_string(),
+				target: index.index.clone(),
+				reason: "The index has not been scanned since statistics were last reset."
+					.to_string(),
+				estimated_reclaim_bytes: Some(index.index_bytes),
+				sql: None,
+			});
+		}
+	}
+
+	push_backlog_actions(&mut actions, tables, backlog);
+
+	actions
+}
+
+fn push_backlog_actions(
+	actions: &mut Vec<StorageMaintenanceAction>,
+	tables: &[StorageTableUsage],
+	backlog: &BacklogRow,
+) {
+	let expired = [
+		("cache_purge", "llm_cache", backlog.expired_cache_rows),
+		("trace_purge", "search_traces", backlog.expired_trace_rows),
+		("session_purge", "search_sessions", backlog.expired_session_rows),
+	];
+
+	for (kind, table, rows) in expired {
+		if rows >= EXPIRED_ROWS_MIN {
+			actions.push(StorageMaintenanceAction {
+				kind: kind.to_string(),
+				target: table.to_string(),
+				reason: format!(
+					"{rows} expired rows remain; the worker cleanup may be lagging or stopped."
+				),
+				estimated_reclaim_bytes: estimate_row_bytes(tables, table, rows),
+				sql: Some(format!("DELETE FROM {table} WHERE expires_at <= now();")),
+			});
+		}
+	}
+
+	if backlog.done_outbox_rows > 0 {
+		let rows = backlog.done_outbox_rows;
+
+		actions.push(StorageMaintenanceAction {
+			kind: "outbox_purge".to_string(),
+			target: "indexing_outbox".to_string(),
+			reason: format!(
+				"{rows} completed outbox jobs are older than {OUTBOX_DONE_RETENTION_DAYS} days and are never cleaned up automatically."
+			),
+			estimated_reclaim_bytes: estimate_row_bytes(tables, "indexing_outbox", rows),
+			sql: Some(format!(
+				"DELETE FROM indexing_outbox WHERE status = 'DONE' AND updated_at <= now() - interval '{OUTBOX_DONE_RETENTION_DAYS} days';"
+			)),
+		});
+	}
+
+	let purgeable_notes = backlog.purgeable_deleted_notes + backlog.purgeable_deprecated_notes;
+
+	if purgeable_notes > 0 {
+		actions.push(StorageMaintenanceAction {
+			kind: "note_purge".to_string(),
+			target: "memory_notes".to_string(),
+			reason: format!(
+				"{} deleted and {} deprecated notes are past the lifecycle purge windows.",
+				backlog.purgeable_deleted_notes, backlog.purgeable_deprecated_notes
+			),
+			estimated_reclaim_bytes: estimate_row_bytes(tables, "memory_notes", purgeable_notes),
+			sql: None,
+		});
+	}
+}
+
+async fn fetch_table_rows<'e, E>(executor: E) -> Result<Vec<TableRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, TableRow>(
+		"\
+SELECT
+	s.relname::text AS table_name,
+	pg_total_relation_size(s.relid) AS total_bytes,
+	pg_relation_size(s.relid) AS table_bytes,
+	pg_indexes_size(s.relid) AS index_bytes,
+	s.n_live_tup AS live_rows,
+	s.n_dead_tup AS dead_rows,
+	s.last_vacuum,
+	s.last_autovacuum
+FROM pg_stat_user_tables s
+WHERE s.schemaname = current_schema()
+ORDER BY total_bytes DESC, table_name ASC",
+	)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_index_rows<'e, E>(executor: E) -> Result<Vec<IndexRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, IndexRow>(
+		"\
+SELECT
+	s.indexrelname::text AS index_name,
+	s.relname::text AS table_name,
+	pg_relation_size(s.indexrelid) AS index_bytes,
+	s.idx_scan AS scans,
+	(i.indisunique OR i.indisprimary) AS is_unique
+FROM pg_stat_user_indexes s
+JOIN pg_index i ON i.indexrelid = s.indexrelid
+WHERE s.schemaname = current_schema()",
+	)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_tenant_rows<'e, E>(executor: E) -> Result<Vec<TenantRow>>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, TenantRow>(
+		"\
+WITH usage AS (
+	SELECT n.tenant_id, 'notes' AS category, pg_column_size(n.*)::bigint AS bytes
+	FROM memory_notes n
+	UNION ALL
+	SELECT n.tenant_id, 'chunks', pg_column_size(c.*)::bigint
+	FROM memory_note_chunks c
+	JOIN memory_notes n ON n.note_id = c.note_id
+	UNION ALL
+	SELECT n.tenant_id, 'embeddings', pg_column_size(e.*)::bigint
+	FROM note_chunk_embeddings e
+	JOIN memory_note_chunks c ON c.chunk_id = e.chunk_id
+	JOIN memory_notes n ON n.note_id = c.note_id
+	UNION ALL
+	SELECT n.tenant_id, 'embeddings', pg_column_size(e.*)::bigint
+	FROM note_embeddings e
+	JOIN memory_notes n ON n.note_id = e.note_id
+	UNION ALL
+	SELECT t.tenant_id, 'traces', pg_column_size(t.*)::bigint
+	FROM search_traces t
+	UNION ALL
+	SELECT t.tenant_id, 'traces', pg_column_size(i.*)::bigint
+	FROM search_trace_items i
+	JOIN search_traces t ON t.trace_id = i.trace_id
+	UNION ALL
+	SELECT d.tenant_id, 'docs', pg_column_size(d.*)::bigint
+	FROM doc_documents d
+	UNION ALL
+	SELECT d.tenant_id, 'docs', pg_column_size(c.*)::bigint
+	FROM doc_chunks c
+	JOIN doc_documents d ON d.doc_id = c.doc_id
+	UNION ALL
+	SELECT d.tenant_id, 'embeddings', pg_column_size(e.*)::bigint
+	FROM doc_chunk_embeddings e
+	JOIN doc_chunks c ON c.chunk_id = e.chunk_id
+	JOIN doc_documents d ON d.doc_id = c.doc_id
+)
+SELECT tenant_id, category, count(*) AS row_count, sum(bytes)::bigint AS bytes
+FROM usage
+GROUP BY tenant_id, category",
+	)
+	.fetch_all(executor)
+	.await
+	.map_err(Into::into)
+}
+
+async fn fetch_backlog<'e, E>(
+	executor: E,
+	now: OffsetDateTime,
+	outbox_cutoff: OffsetDateTime,
+	deleted_cutoff: OffsetDateTime,
+	deprecated_cutoff: OffsetDateTime,
+) -> Result<BacklogRow>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query_as::<_, BacklogRow>(
+		"\
+SELECT
+	(SELECT count(*) FROM llm_cache WHERE expires_at <= $1) AS expired_cache_rows,
+	(SELECT count(*) FROM search_traces WHERE expires_at <= $1) AS expired_trace_rows,
+	(SELECT count(*) FROM search_sessions WHERE expires_at <= $1) AS expired_session_rows,
+	(
+		SELECT count(*)
+		FROM indexing_outbox
+		WHERE status = 'DONE' AND updated_at <= $2
+	) AS done_outbox_rows,
+	(
+		SELECT count(*)
+		FROM memory_notes
+		WHERE status = 'deleted' AND updated_at <= $3
+	) AS purgeable_deleted_notes,
+	(
+		SELECT count(*)
+		FROM memory_notes
+		WHERE status = 'deprecated' AND updated_at <= $4
+	) AS purgeable_deprecated_notes",
+	)
+	.bind(now)
+	.bind(outbox_cutoff)
+	.bind(deleted_cutoff)
+	.bind(deprecated_cutoff)
+	.fetch_one(executor)
+	.await
+	.map_err(Into::into)
+}
+
+#[cfg(test)] mod tests;
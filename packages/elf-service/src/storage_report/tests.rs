@@ -0,0 +1,136 @@
+use crate::storage_report::{self, BacklogRow, IndexRow, StorageTableUsage, TableRow, TenantRow};
+
+fn table(name: &str, live_rows: i64, dead_rows: i64, table_bytes: i64) -> StorageTableUsage {
+	storage_report::build_table_usage(TableRow {
+		table_name: name.to_string(),
+		total_bytes: table_bytes * 2,
+		table_bytes,
+		index_bytes: table_bytes,
+		live_rows,
+		dead_rows,
+		last_vacuum: None,
+		last_autovacuum: None,
+	})
+}
+
+#[test]
+fn table_category_groups_known_tables() {
+	assert_eq!(storage_report::table_category("memory_notes"), "notes");
+	assert_eq!(storage_report::table_category("memory_note_chunks"), "chunks");
+	assert_eq!(storage_report::table_category("note_chunk_embeddings"), "embeddings");
+	assert_eq!(storage_report::table_category("search_trace_items"), "traces");
+	assert_eq!(storage_report::table_category("llm_cache"), "caches");
+	assert_eq!(storage_report::table_category("doc_chunks"), "docs");
+	assert_eq!(storage_report::table_category("graph_facts"), "graph");
+	assert_eq!(storage_report::table_category("indexing_outbox"), "other");
+}
+
+#[test]
+fn index_bloat_follows_parent_dead_ratio() {
+	let tables = vec![table("memory_notes", 750, 250, 1_000)];
+	let indexes = storage_report::build_index_usage(
+		vec![
+			IndexRow {
+				index_name: "idx_small".to_string(),
+				table_name: "memory_notes".to_string(),
+				index_bytes: 400,
+				scans: 3,
+				is_unique: false,
+			},
+			IndexRow {
+				index_name: "idx_large".to_string(),
+				table_name: "memory_notes".to_string(),
+				index_bytes: 800,
+				scans: 3,
+				is_unique: false,
+			},
+			IndexRow {
+				index_name: "idx_orphan".to_string(),
+				table_name: "missing".to_string(),
+				index_bytes: 10_000,
+				scans: 3,
+				is_unique: false,
+			},
+		],
+		&tables,
+	);
+	let names: Vec<&str> = indexes.iter().map(|index| index.index.as_str()).collect();
+
+	assert_eq!(names, vec!["idx_large", "idx_small", "idx_orphan"]);
+	assert_eq!(indexes[0].estimated_bloat_bytes, 200);
+	assert_eq!(indexes[2].estimated_bloat_bytes, 0);
+}
+
+#[test]
+fn tenant_rows_are_grouped_and_ordered_by_size() {
+	let row = |tenant_id: &str, category: &str, bytes: i64| TenantRow {
+		tenant_id: tenant_id.to_string(),
+		category: category.to_string(),
+		row_count: 1,
+		bytes,
+	};
+	let tenants = storage_report::group_tenant_rows(vec![
+		row("t-small", "notes", 10),
+		row("t-big", "traces", 300),
+		row("t-big", "notes", 200),
+	]);
+
+	assert_eq!(tenants.len(), 2);
+	assert_eq!(tenants[0].tenant_id, "t-big");
+	assert_eq!(tenants[0].total_bytes, 500);
+	assert_eq!(tenants[0].categories[0].category, "notes");
+	assert_eq!(tenants[1].tenant_id, "t-small");
+}
+
+#[test]
+fn maintenance_actions_cover_vacuum_and_backlogs() {
+	let tables = vec![
+		table("memory_notes", 20_000, 20_000, 4_000_000),
+		table("llm_cache", 900, 100, 100_000),
+		table("indexing_outbox", 1_000, 0, 50_000),
+	];
+	let backlog = BacklogRow {
+		expired_cache_rows: 1_000,
+		expired_trace_rows: 999,
+		done_outbox_rows: 10,
+		purgeable_deleted_notes: 2,
+		purgeable_deprecated_notes: 1,
+		..Default::default()
+	};
+	let actions = storage_report::build_maintenance_actions(&tables, &[], &backlog);
+	let kinds: Vec<&str> = actions.iter().map(|action| action.kind.as_str()).collect();
+
+	assert_eq!(kinds, vec!["vacuum", "cache_purge", "outbox_purge", "note_purge"]);
+	assert_eq!(actions[0].target, "memory_notes");
+	assert_eq!(actions[0].estimated_reclaim_bytes, Some(2_000_000));
+	assert_eq!(actions[1].estimated_reclaim_bytes, Some(200_000));
+	assert!(actions[3].sql.is_none());
+}
+
+#[test]
+fn unused_unique_indexes_are_not_flagged() {
+	let indexes = storage_report::build_index_usage(
+		vec![
+			IndexRow {
+				index_name: "memory_notes_pkey".to_string(),
+				table_name: "memory_notes".to_string(),
+				index_bytes: 64 * 1_024 * 1_024,
+				scans: 0,
+				is_unique: true,
+			},
+			IndexRow {
+				index_name: "idx_unused".to_string(),
+				table_name: "memory_notes".to_string(),
+				index_bytes: 32 * 1_024 * 1_024,
+				scans: 0,
+				is_unique: false,
+			},
+		],
+		&[],
+	);
+	let actions = storage_report::build_maintenance_actions(&[], &indexes, &BacklogRow::default());
+
+	assert_eq!(actions.len(), 1);
+	assert_eq!(actions[0].kind, "review_unused_index");
+	assert_eq!(actions[0].target, "idx_unused");
+}
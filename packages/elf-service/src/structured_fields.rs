@@ -7,7 +7,10 @@ mod validation;
 pub use self::{
 	persistence::{fetch_structured_fields, upsert_structured_fields_tx},
 	types::{StructuredEntity, StructuredFields, StructuredRelation, StructuredRelationObject},
-	validation::{event_evidence_quotes, validate_structured_fields},
+	validation::{event_evidence_quotes, validate_note_type_fields, validate_structured_fields},
 };
 
+/// Note type whose question and answer are stored as structured fields.
+pub const QA_NOTE_TYPE: &str = "qa";
+
 #[cfg(test)] mod tests;
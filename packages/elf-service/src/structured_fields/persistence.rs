@@ -6,7 +6,8 @@ use uuid::Uuid;
 
 use crate::{Result, structured_fields::types::StructuredFields};
 
-/// Upserts summary, fact, and concept fields for one note inside an existing transaction.
+/// Upserts summary, fact, concept, question, and answer fields for one note inside an existing
+/// transaction.
 pub async fn upsert_structured_fields_tx(
 	executor: &mut PgConnection,
 	note_id: Uuid,
@@ -22,6 +23,12 @@ pub async fn upsert_structured_fields_tx(
 	if let Some(concepts) = structured.concepts.as_ref() {
 		replace_kind(executor, note_id, "concept", concepts.as_slice(), now).await?;
 	}
+	if let Some(question) = structured.question.as_ref() {
+		replace_kind(executor, note_id, "question", slice_single(question), now).await?;
+	}
+	if let Some(answer) = structured.answer.as_ref() {
+		replace_kind(executor, note_id, "answer", slice_single(answer), now).await?;
+	}
 
 	Ok(())
 }
@@ -66,6 +73,12 @@ ORDER BY note_id ASC, field_kind ASC, item_index ASC",
 			"concept" => {
 				entry.concepts.get_or_insert_with(Vec::new).push(text);
 			},
+			"question" if entry.question.is_none() && !text.trim().is_empty() => {
+				entry.question = Some(text);
+			},
+			"answer" if entry.answer.is_none() && !text.trim().is_empty() => {
+				entry.answer = Some(text);
+			},
 			_ => {},
 		}
 	}
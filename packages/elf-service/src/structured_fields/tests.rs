@@ -18,6 +18,8 @@ fn structured_relation(
 		summary: None,
 		facts: None,
 		concepts: None,
+		question: None,
+		answer: None,
 		entities: None,
 		relations: Some(vec![StructuredRelation {
 			subject: Some(StructuredEntity {
@@ -39,6 +41,8 @@ fn fact_binding_accepts_note_text_substring() {
 		summary: None,
 		facts: Some(vec!["Deploy uses reranking".to_string()]),
 		concepts: None,
+		question: None,
+		answer: None,
 		entities: None,
 		relations: None,
 	};
@@ -58,6 +62,8 @@ fn fact_binding_rejects_without_text_or_evidence() {
 		summary: None,
 		facts: Some(vec!["Nonexistent claim.".to_string()]),
 		concepts: None,
+		question: None,
+		answer: None,
 		entities: None,
 		relations: None,
 	};
@@ -220,3 +226,40 @@ fn relation_accepts_valid_structured_relation() {
 
 	assert!(res.is_ok());
 }
+
+#[test]
+fn qa_note_requires_question_and_answer() {
+	let structured = StructuredFields {
+		question: Some("How do I rotate the deploy key?".to_string()),
+		..StructuredFields::default()
+	};
+	let message = match structured_fields::validate_note_type_fields("qa", Some(&structured)) {
+		Err(Error::InvalidRequest { message }) => message,
+		other => panic!("expected invalid request, got {other:?}"),
+	};
+
+	assert_eq!(message, "structured.answer is required.");
+	assert!(structured_fields::validate_note_type_fields("qa", None).is_err());
+
+	let structured = StructuredFields {
+		answer: Some("Run the rotate-key job from the deploy dashboard.".to_string()),
+		..structured
+	};
+
+	assert!(structured_fields::validate_note_type_fields("qa", Some(&structured)).is_ok());
+}
+
+#[test]
+fn non_qa_note_rejects_question_and_answer() {
+	let structured = StructuredFields {
+		answer: Some("Run the rotate-key job.".to_string()),
+		..StructuredFields::default()
+	};
+	let message = match structured_fields::validate_note_type_fields("fact", Some(&structured)) {
+		Err(Error::InvalidRequest { message }) => message,
+		other => panic!("expected invalid request, got {other:?}"),
+	};
+
+	assert_eq!(message, "structured.answer is only allowed on qa notes.");
+	assert!(structured_fields::validate_note_type_fields("fact", None).is_ok());
+}
@@ -10,15 +10,22 @@ pub struct StructuredFields {
 	pub facts: Option<Vec<String>>,
 	/// Optional concept labels grounded in the note text.
 	pub concepts: Option<Vec<String>>,
+	/// Question text of a `qa` note; embedded on its own for question-side retrieval.
+	pub question: Option<String>,
+	/// Answer text of a `qa` note; returned as the search snippet.
+	pub answer: Option<String>,
 	/// Optional graph entities extracted from the note.
 	pub entities: Option<Vec<StructuredEntity>>,
 	/// Optional graph relations extracted from the note.
 	pub relations: Option<Vec<StructuredRelation>>,
 }
 impl StructuredFields {
-	/// Returns `true` when no persisted summary, fact, or concept content is present.
+	/// Returns `true` when no persisted summary, fact, concept, question, or answer content is
+	/// present.
 	pub fn is_effectively_empty(&self) -> bool {
 		let summary_empty = self.summary.as_ref().map(|v| v.trim().is_empty()).unwrap_or(true);
+		let question_empty = self.question.as_ref().map(|v| v.trim().is_empty()).unwrap_or(true);
+		let answer_empty = self.answer.as_ref().map(|v| v.trim().is_empty()).unwrap_or(true);
 		let facts_empty = self
 			.facts
 			.as_ref()
@@ -30,7 +37,7 @@ impl StructuredFields {
 			.map(|items| items.iter().all(|v| v.trim().is_empty()))
 			.unwrap_or(true);
 
-		summary_empty && facts_empty && concepts_empty
+		summary_empty && facts_empty && concepts_empty && question_empty && answer_empty
 	}
 
 	/// Returns `true` when graph entities or relations are present.
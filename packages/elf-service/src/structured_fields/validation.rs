@@ -9,6 +9,7 @@ use serde_json::Value;
 use crate::{
 	Error, Result,
 	structured_fields::{
+		QA_NOTE_TYPE,
 		types::StructuredFields,
 		validation::bounds::{MAX_ENTITIES, MAX_RELATIONS},
 	},
@@ -30,6 +31,12 @@ pub fn validate_structured_fields(
 	if let Some(summary) = structured.summary.as_ref() {
 		text::validate_text_field(summary, "structured.summary")?;
 	}
+	if let Some(question) = structured.question.as_ref() {
+		text::validate_text_field(question, "structured.question")?;
+	}
+	if let Some(answer) = structured.answer.as_ref() {
+		text::validate_text_field(answer, "structured.answer")?;
+	}
 	if let Some(entities) = structured.entities.as_ref() {
 		bounds::validate_list_field_count(entities.len(), MAX_ENTITIES, "structured.entities")?;
 
@@ -77,6 +84,38 @@ pub fn validate_structured_fields(
 	Ok(())
 }
 
+/// Validates that question and answer fields match the note type.
+///
+/// `qa` notes require both a question and an answer; other note types must not carry either.
+pub fn validate_note_type_fields(
+	note_type: &str,
+	structured: Option<&StructuredFields>,
+) -> Result<()> {
+	if note_type == QA_NOTE_TYPE {
+		let question = structured.and_then(|structured| structured.question.as_ref());
+		let answer = structured.and_then(|structured| structured.answer.as_ref());
+
+		text::validate_required_text_field(question, "structured.question")?;
+		text::validate_required_text_field(answer, "structured.answer")?;
+
+		return Ok(());
+	}
+
+	let Some(structured) = structured else { return Ok(()) };
+
+	for (value, label) in
+		[(&structured.question, "structured.question"), (&structured.answer, "structured.answer")]
+	{
+		if value.is_some() {
+			return Err(Error::InvalidRequest {
+				message: format!("{label} is only allowed on {QA_NOTE_TYPE} notes."),
+			});
+		}
+	}
+
+	Ok(())
+}
+
 /// Validates event-evidence quotes against their source messages.
 pub fn event_evidence_quotes(messages: &[String], evidence: &[(usize, String)]) -> Result<()> {
 	quotes::event_evidence_quotes(messages, evidence)
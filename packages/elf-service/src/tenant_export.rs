@@ -0,0 +1,300 @@
+//! Versioned JSONL export of all durable memory for one tenant.
+
+mod restore;
+
+pub use self::restore::{
+	SnapshotRestoreRequest, SnapshotRestoreResponse, SnapshotRestorer, restored_id,
+};
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{AssertSqlSafe, Postgres, Transaction};
+use time::OffsetDateTime;
+
+use crate::{ElfService, Error, Result};
+
+/// Archive format identifier written in the manifest.
+pub const TENANT_EXPORT_FORMAT: &str = "elf.tenant_export";
+/// Archive format version written in the manifest.
+pub const TENANT_EXPORT_VERSION: u32 = 1;
+
+const EXPORT_PAGE_SIZE: i64 = 500;
+const TENANT_NOTES: &str = "SELECT note_id FROM memory_notes WHERE tenant_id = $1";
+const TENANT_ENTITIES: &str = "SELECT entity_id FROM graph_entities WHERE tenant_id = $1";
+const TENANT_FACTS: &str = "SELECT fact_id FROM graph_facts WHERE tenant_id = $1";
+
+/// One exported table: rows are selected by `filter` and paged by `key`, a text expression.
+///
+/// `ids` lists the uuid columns that a restore rewrites, including references to other sections.
+struct ExportSection {
+	name: &'static str,
+	table: &'static str,
+	key: &'static str,
+	filter: Filter,
+	row: &'static str,
+	ids: &'static [&'static str],
+}
+
+enum Filter {
+	Tenant,
+	In { column: &'static str, subquery: &'static str },
+}
+
+const SECTIONS: [ExportSection; 11] = [
+	ExportSection {
+		name: "memory_notes",
+		table: "memory_notes",
+		key: "t.note_id::text",
+		filter: Filter::Tenant,
+		row: "to_jsonb(t)",
+		ids: &["note_id"],
+	},
+	ExportSection {
+		name: "memory_note_versions",
+		table: "memory_note_versions",
+		key: "t.version_id::text",
+		filter: Filter::In { column: "note_id", subquery: TENANT_NOTES },
+		row: "to_jsonb(t)",
+		ids: &["version_id", "note_id"],
+	},
+	ExportSection {
+		name: "memory_note_fields",
+		table: "memory_note_fields",
+		key: "t.field_id::text",
+		filter: Filter::In { column: "note_id", subquery: TENANT_NOTES },
+		row: "to_jsonb(t)",
+		ids: &["field_id", "note_id"],
+	},
+	ExportSection {
+		name: "memory_note_values",
+		table: "memory_note_values",
+		key: "t.value_id::text",
+		filter: Filter::In { column: "note_id", subquery: TENANT_NOTES },
+		row: "to_jsonb(t)",
+		ids: &["value_id", "note_id"],
+	},
+	ExportSection {
+		name: "memory_note_evidence",
+		table: "memory_note_evidence",
+		key: "t.note_id::text",
+		filter: Filter::In { column: "note_id", subquery: TENANT_NOTES },
+		row: "to_jsonb(t)",
+		ids: &["note_id"],
+	},
+	ExportSection {
+		name: "graph_entities",
+		table: "graph_entities",
+		key: "t.entity_id::text",
+		filter: Filter::Tenant,
+		row: "to_jsonb(t)",
+		ids: &["entity_id"],
+	},
+	ExportSection {
+		name: "graph_entity_aliases",
+		table: "graph_entity_aliases",
+		key: "t.alias_id::text",
+		filter: Filter::In { column: "entity_id", subquery: TENANT_ENTITIES },
+		row: "to_jsonb(t)",
+		ids: &["alias_id", "entity_id"],
+	},
+	ExportSection {
+		name: "graph_facts",
+		table: "graph_facts",
+		key: "t.fact_id::text",
+		filter: Filter::Tenant,
+		row: "to_jsonb(t)",
+		ids: &["fact_id", "subject_entity_id", "object_entity_id"],
+	},
+	ExportSection {
+		name: "graph_fact_evidence",
+		table: "graph_fact_evidence",
+		key: "t.evidence_id::text",
+		filter: Filter::In { column: "fact_id", subquery: TENANT_FACTS },
+		row: "to_jsonb(t)",
+		ids: &["evidence_id", "fact_id", "note_id"],
+	},
+	ExportSection {
+		name: "graph_fact_supersessions",
+		table: "graph_fact_supersessions",
+		key: "t.supersession_id::text",
+		filter: Filter::Tenant,
+		row: "to_jsonb(t)",
+		ids: &["supersession_id", "from_fact_id", "to_fact_id", "note_id"],
+	},
+	ExportSection {
+		name: "note_embeddings",
+		table: "note_embeddings",
+		key: "t.note_id::text || '/' || t.embedding_version",
+		filter: Filter::In { column: "note_id", subquery: TENANT_NOTES },
+		row: "to_jsonb(t) - 'vec' || jsonb_build_object('vec', t.vec::real[])",
+		ids: &["note_id"],
+	},
+];
+
+/// Request payload for a tenant export.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TenantExportRequest {
+	/// Tenant whose memory is exported.
+	pub tenant_id: String,
+}
+
+/// First line of an export archive.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TenantExportManifest {
+	/// Archive format identifier.
+	pub format: String,
+	/// Archive format version.
+	pub version: u32,
+	/// Exported tenant.
+	pub tenant_id: String,
+	#[serde(with = "crate::time_serde")]
+	/// Snapshot time of the export transaction.
+	pub exported_at: OffsetDateTime,
+	/// Vector dimension of exported embeddings.
+	pub vector_dim: u32,
+	/// Section names in the order their records appear.
+	pub sections: Vec<String>,
+}
+
+/// One line of an export archive.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TenantExportLine {
+	/// Archive header; always the first line.
+	Manifest(TenantExportManifest),
+	/// One table row.
+	Record {
+		/// Section the row belongs to.
+		section: String,
+		/// Row columns as JSON.
+		row: Value,
+	},
+	/// Archive trailer; always the last line of a complete export.
+	Summary {
+		/// Exported row count per section.
+		counts: BTreeMap<String, u64>,
+	},
+}
+
+/// Cursor over the lines of a tenant export.
+///
+/// All pages are read from one repeatable-read, read-only transaction, so the archive reflects a
+/// single snapshot.
+pub struct TenantExporter {
+	tx: Option<Transaction<'static, Postgres>>,
+	tenant_id: String,
+	manifest: Option<TenantExportManifest>,
+	section_idx: usize,
+	cursor: String,
+	counts: BTreeMap<String, u64>,
+}
+impl TenantExporter {
+	/// Returns the next batch of archive lines, or `None` once the summary has been returned.
+	pub async fn next_batch(&mut self) -> Result<Option<Vec<TenantExportLine>>> {
+		if let Some(manifest) = self.manifest.take() {
+			return Ok(Some(vec![TenantExportLine::Manifest(manifest)]));
+		}
+
+		let Some(tx) = self.tx.as_mut() else {
+			return Ok(None);
+		};
+
+		while let Some(section) = SECTIONS.get(self.section_idx) {
+			// The query is assembled only from the static section table.
+			let rows: Vec<(String, Value)> = sqlx::query_as(AssertSqlSafe(section_query(section)))
+				.bind(self.tenant_id.as_str())
+				.bind(self.cursor.as_str())
+				.bind(EXPORT_PAGE_SIZE)
+				.fetch_all(&mut **tx)
+				.await?;
+
+			if rows.is_empty() {
+				self.section_idx += 1;
+				self.cursor.clear();
+
+				continue;
+			}
+
+			if let Some((key, _)) = rows.last() {
+				self.cursor = key.clone();
+			}
+
+			*self.counts.entry(section.name.to_string()).or_default() += rows.len() as u64;
+
+			let lines = rows
+				.into_iter()
+				.map(|(_, row)| TenantExportLine::Record { section: section.name.to_string(), row })
+				.collect();
+
+			return Ok(Some(lines));
+		}
+
+		if let Some(tx) = self.tx.take() {
+			tx.commit().await?;
+		}
+
+		tracing::info!(tenant_id = %self.tenant_id, counts = ?self.counts, "Tenant export finished.");
+
+		Ok(Some(vec![TenantExportLine::Summary { counts: std::mem::take(&mut self.counts) }]))
+	}
+}
+
+impl ElfService {
+	/// Opens a snapshot export of every memory note, version, structured field, graph fact, and
+	/// note embedding owned by a tenant.
+	///
+	/// Drain the returned [`TenantExporter`] and write each line as JSON to produce an archive:
+	/// a manifest, one record per row grouped by section, then a summary with row counts.
+	pub async fn tenant_export(&self, req: TenantExportRequest) -> Result<TenantExporter> {
+		let tenant_id = req.tenant_id.trim().to_string();
+
+		if tenant_id.is_empty() {
+			return Err(Error::InvalidRequest { message: "tenant_id is required.".to_string() });
+		}
+
+		let mut tx = self.db.pool.begin().await?;
+
+		sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+			.execute(&mut *tx)
+			.await?;
+
+		let exported_at: OffsetDateTime =
+			sqlx::query_scalar("SELECT now()").fetch_one(&mut *tx).await?;
+		let manifest = TenantExportManifest {
+			format: TENANT_EXPORT_FORMAT.to_string(),
+			version: TENANT_EXPORT_VERSION,
+			tenant_id: tenant_id.clone(),
+			exported_at,
+			vector_dim: self.cfg.storage.qdrant.vector_dim,
+			sections: SECTIONS.iter().map(|section| section.name.to_string()).collect(),
+		};
+
+		Ok(TenantExporter {
+			tx: Some(tx),
+			tenant_id,
+			manifest: Some(manifest),
+			section_idx: 0,
+			cursor: String::new(),
+			counts: BTreeMap::new(),
+		})
+	}
+}
+
+fn section_query(section: &ExportSection) -> String {
+	let filter = match section.filter {
+		Filter::Tenant => "t.tenant_id = $1".to_string(),
+		Filter::In { column, subquery } => format!("t.{column} IN ({subquery})"),
+	};
+
+	format!(
+		"SELECT {key} AS export_key, {row} AS row FROM {table} t WHERE {filter} AND {key} > $2 \
+		 ORDER BY export_key LIMIT $3",
+		key = section.key,
+		row = section.row,
+		table = section.table,
+	)
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,376 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{AssertSqlSafe, Postgres, Transaction};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	ElfService, Error, Result,
+	audit::{AuditScope, AuditTargets, PendingAudit},
+	tenant_export::{
+		ExportSection, Filter, SECTIONS, TENANT_EXPORT_FORMAT, TENANT_EXPORT_VERSION,
+		TenantExportLine, TenantExportManifest,
+	},
+};
+
+/// Request header for restoring a tenant export archive.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SnapshotRestoreRequest {
+	/// Tenant that receives the restored memory.
+	pub tenant_id: String,
+}
+
+/// Response payload for a snapshot restore.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SnapshotRestoreResponse {
+	/// Tenant recorded in the archive manifest.
+	pub source_tenant_id: String,
+	/// Tenant that received the restored memory.
+	pub tenant_id: String,
+	#[serde(with = "crate::time_serde")]
+	/// Snapshot time recorded in the archive manifest.
+	pub exported_at: OffsetDateTime,
+	/// Inserted row count per section.
+	pub restored: BTreeMap<String, u64>,
+	/// Row count per section that already existed and was left unchanged.
+	pub skipped: BTreeMap<String, u64>,
+	/// Indexing outbox entries enqueued for restored active notes.
+	pub outbox_enqueued: u64,
+}
+
+impl AuditTargets for SnapshotRestoreResponse {}
+
+/// Incremental restore fed one archive line at a time.
+///
+/// Every row is written in a single transaction that only commits from
+/// [`SnapshotRestorer::finish`], so an archive that is truncated or rejected halfway leaves the
+/// database unchanged.
+pub struct SnapshotRestorer {
+	tx: Transaction<'static, Postgres>,
+	tenant_id: String,
+	vector_dim: u32,
+	manifest: Option<TenantExportManifest>,
+	summary: Option<BTreeMap<String, u64>>,
+	section_idx: usize,
+	line_no: u64,
+	restored: BTreeMap<String, u64>,
+	skipped: BTreeMap<String, u64>,
+	active_notes: Vec<(Uuid, String)>,
+	audit: Option<PendingAudit>,
+}
+impl SnapshotRestorer {
+	/// Restores one archive line. Blank lines are skipped.
+	///
+	/// An error ends the restore and records it in the audit log.
+	pub async fn push_line(&mut self, line: &str) -> Result<()> {
+		let result = self.restore_line(line).await;
+
+		if let Err(err) = &result
+			&& let Some(audit) = self.audit.take()
+		{
+			audit.record_error(err).await;
+		}
+
+		result
+	}
+
+	/// Checks the archive summary, enqueues indexing for restored notes, commits, and records the
+	/// restore in the audit log.
+	///
+	/// The worker rebuilds chunks, chunk embeddings, and Qdrant points from the enqueued outbox
+	/// entries. Chunk ids derive from note ids, so a restored tenant indexes to the same points on
+	/// every restore.
+	pub async fn finish(mut self) -> Result<SnapshotRestoreResponse> {
+		let audit = self.audit.take();
+		let result = self.commit().await;
+
+		if let Some(audit) = audit {
+			audit.record(&result).await;
+		}
+
+		result
+	}
+
+	/// Restores one archive line; the caller records failures.
+	async fn restore_line(&mut self, line: &str) -> Result<()> {
+		let line = line.trim();
+
+		if line.is_empty() {
+			return Ok(());
+		}
+
+		self.line_no += 1;
+
+		let parsed: TenantExportLine =
+			serde_json::from_str(line).map_err(|err| Error::InvalidRequest {
+				message: format!(
+					"Archive line {} is not a valid export line: {err}.",
+					self.line_no
+				),
+			})?;
+
+		if self.summary.is_some() {
+			return Err(Error::InvalidRequest {
+				message: "Archive has lines after its summary.".to_string(),
+			});
+		}
+
+		match parsed {
+			TenantExportLine::Manifest(manifest) => self.accept_manifest(manifest),
+			TenantExportLine::Record { section, row } => self.restore_record(&section, row).await,
+			TenantExportLine::Summary { counts } => {
+				if self.manifest.is_none() {
+					return Err(Error::InvalidRequest {
+						message: "Archive must start with a manifest.".to_string(),
+					});
+				}
+
+				self.summary = Some(counts);
+
+				Ok(())
+			},
+		}
+	}
+
+	/// Checks the archive summary, enqueues indexing for restored notes, and commits.
+	async fn commit(mut self) -> Result<SnapshotRestoreResponse> {
+		let Some(manifest) = self.manifest.take() else {
+			return Err(Error::InvalidRequest { message: "Archive is empty.".to_string() });
+		};
+		let Some(summary) = self.summary.take() else {
+			return Err(Error::InvalidRequest {
+				message: "Archive ended without a summary line; it is incomplete.".to_string(),
+			});
+		};
+
+		for section in SECTIONS.iter() {
+			let expected = summary.get(section.name).copied().unwrap_or_default();
+			let seen = self.restored.get(section.name).copied().unwrap_or_default()
+				+ self.skipped.get(section.name).copied().unwrap_or_default();
+
+			if seen != expected {
+				return Err(Error::InvalidRequest {
+					message: format!(
+						"Archive section {} has {seen} records but its summary reports {expected}.",
+						section.name
+					),
+				});
+			}
+		}
+
+		let now = OffsetDateTime::now_utc();
+
+		for (note_id, embedding_version) in &self.active_notes {
+			crate::enqueue_outbox_tx(&mut *self.tx, *note_id, "UPSERT", embedding_version, now)
+				.await?;
+		}
+
+		self.tx.commit().await?;
+
+		let response = SnapshotRestoreResponse {
+			source_tenant_id: manifest.tenant_id,
+			tenant_id: self.tenant_id,
+			exported_at: manifest.exported_at,
+			restored: self.restored,
+			skipped: self.skipped,
+			outbox_enqueued: self.active_notes.len() as u64,
+		};
+
+		tracing::info!(
+			source_tenant_id = %response.source_tenant_id,
+			tenant_id = %response.tenant_id,
+			restored = ?response.restored,
+			skipped = ?response.skipped,
+			outbox_enqueued = response.outbox_enqueued,
+			"Snapshot restore finished."
+		);
+
+		Ok(response)
+	}
+
+	fn accept_manifest(&mut self, manifest: TenantExportManifest) -> Result<()> {
+		if self.manifest.is_some() || self.line_no != 1 {
+			return Err(Error::InvalidRequest {
+				message: "Archive manifest must be the first and only manifest line.".to_string(),
+			});
+		}
+		if manifest.format != TENANT_EXPORT_FORMAT || manifest.version != TENANT_EXPORT_VERSION {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"Unsupported archive format {} version {}; expected {TENANT_EXPORT_FORMAT} version {TENANT_EXPORT_VERSION}.",
+					manifest.format, manifest.version
+				),
+			});
+		}
+		if manifest.vector_dim != self.vector_dim {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"Archive vector_dim {} does not match the configured vector_dim {}.",
+					manifest.vector_dim, self.vector_dim
+				),
+			});
+		}
+
+		self.manifest = Some(manifest);
+
+		Ok(())
+	}
+
+	async fn restore_record(&mut self, section_name: &str, mut row: Value) -> Result<()> {
+		if self.manifest.is_none() {
+			return Err(Error::InvalidRequest {
+				message: "Archive must start with a manifest.".to_string(),
+			});
+		}
+
+		let Some(section_idx) = SECTIONS.iter().position(|section| section.name == section_name)
+		else {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"Archive line {} has unknown section {section_name}.",
+					self.line_no
+				),
+			});
+		};
+
+		if section_idx < self.section_idx {
+			return Err(Error::InvalidRequest {
+				message: format!(
+					"Archive line {} is out of section order: {section_name}.",
+					self.line_no
+				),
+			});
+		}
+
+		let section = &SECTIONS[section_idx];
+
+		self.section_idx = section_idx;
+
+		rewrite_row(section, &mut row, &self.tenant_id)?;
+
+		// The statement is assembled only from the static section table.
+		let inserted = sqlx::query(AssertSqlSafe(restore_query(section)))
+			.bind(&row)
+			.execute(&mut *self.tx)
+			.await?
+			.rows_affected()
+			> 0;
+		let counts = if inserted { &mut self.restored } else { &mut self.skipped };
+
+		*counts.entry(section.name.to_string()).or_default() += 1;
+
+		if inserted && section.name == "memory_notes" && row["status"] == "active" {
+			let note_id = row["note_id"].as_str().and_then(|id| Uuid::parse_str(id).ok());
+			let embedding_version = row["embedding_version"].as_str();
+
+			if let (Some(note_id), Some(embedding_version)) = (note_id, embedding_version) {
+				self.active_notes.push((note_id, embedding_version.to_string()));
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl ElfService {
+	/// Starts restoring a tenant export archive into `tenant_id`.
+	///
+	/// Every uuid in the archive is rewritten with [`restored_id`], so the same archive restored
+	/// into the same tenant always produces the same note ids, and restoring it twice skips rows
+	/// that already exist. Feed lines through [`SnapshotRestorer::push_line`] and call
+	/// [`SnapshotRestorer::finish`] to commit.
+	pub async fn restore_snapshot(&self, req: SnapshotRestoreRequest) -> Result<SnapshotRestorer> {
+		let audit =
+			self.audit(AuditScope::new("admin_snapshot_restore").caller(&req.tenant_id, "", ""));
+
+		match self.start_restore(req).await {
+			Ok(restorer) => Ok(SnapshotRestorer { audit: Some(audit), ..restorer }),
+			Err(err) => {
+				audit.record_error(&err).await;
+
+				Err(err)
+			},
+		}
+	}
+
+	/// Opens the restore transaction without an audit entry.
+	async fn start_restore(&self, req: SnapshotRestoreRequest) -> Result<SnapshotRestorer> {
+		let tenant_id = req.tenant_id.trim().to_string();
+
+		if tenant_id.is_empty() {
+			return Err(Error::InvalidRequest { message: "tenant_id is required.".to_string() });
+		}
+
+		let tx = self.db.pool.begin().await?;
+
+		Ok(SnapshotRestorer {
+			tx,
+			tenant_id,
+			vector_dim: self.cfg.storage.qdrant.vector_dim,
+			manifest: None,
+			summary: None,
+			section_idx: 0,
+			line_no: 0,
+			restored: BTreeMap::new(),
+			skipped: BTreeMap::new(),
+			active_notes: Vec::new(),
+			audit: None,
+		})
+	}
+}
+
+/// Deterministic id assigned to an archived row id when it is restored into `tenant_id`.
+pub fn restored_id(tenant_id: &str, archived_id: Uuid) -> Uuid {
+	let name = format!("elf:tenant_restore:{tenant_id}:{archived_id}");
+
+	Uuid::new_v5(&Uuid::NAMESPACE_OID, name.as_bytes())
+}
+
+pub(super) fn rewrite_row(section: &ExportSection, row: &mut Value, tenant_id: &str) -> Result<()> {
+	let Some(object) = row.as_object_mut() else {
+		return Err(Error::InvalidRequest {
+			message: format!("Archive {} record must be a JSON object.", section.name),
+		});
+	};
+
+	for column in section.ids {
+		let Some(value) = object.get_mut(*column) else {
+			continue;
+		};
+		let Some(archived) = value.as_str() else {
+			continue;
+		};
+		let archived = Uuid::parse_str(archived).map_err(|_| Error::InvalidRequest {
+			message: format!("Archive {} record has an invalid {column}.", section.name),
+		})?;
+
+		*value = Value::String(restored_id(tenant_id, archived).to_string());
+	}
+
+	if matches!(section.filter, Filter::Tenant) {
+		object.insert("tenant_id".to_string(), Value::String(tenant_id.to_string()));
+	}
+
+	Ok(())
+}
+
+pub(super) fn restore_query(section: &ExportSection) -> String {
+	let table = section.table;
+	let select = match table {
+		// Predicates are not archived; keep predicate_id only when the target already has it.
+		"graph_facts" => "SELECT * FROM jsonb_populate_record(NULL::graph_facts, $1 || \
+		                  jsonb_build_object('predicate_id', (SELECT p.predicate_id FROM \
+		                  graph_predicates p WHERE p.predicate_id::text = $1->>'predicate_id')))"
+			.to_string(),
+		"note_embeddings" => "SELECT r.note_id, r.embedding_version, r.embedding_dim, \
+		                      ARRAY(SELECT e.v::real FROM jsonb_array_elements_text($1->'vec') WITH \
+		                      ORDINALITY AS e(v, i) ORDER BY e.i)::vector, r.created_at FROM \
+		                      jsonb_populate_record(NULL::note_embeddings, $1 - 'vec') r"
+			.to_string(),
+		_ => format!("SELECT * FROM jsonb_populate_record(NULL::{table}, $1)"),
+	};
+
+	format!("INSERT INTO {table} {select} ON CONFLICT DO NOTHING")
+}
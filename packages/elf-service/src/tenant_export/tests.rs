@@ -0,0 +1,76 @@
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::tenant_export::{self, ExportSection, SECTIONS, restore, restored_id};
+
+fn section(name: &str) -> &'static ExportSection {
+	SECTIONS.iter().find(|section| section.name == name).expect("section exists")
+}
+
+#[test]
+fn section_queries_page_by_key_within_the_tenant() {
+	let notes = tenant_export::section_query(&SECTIONS[0]);
+
+	assert_eq!(
+		notes,
+		"SELECT t.note_id::text AS export_key, to_jsonb(t) AS row FROM memory_notes t WHERE \
+		 t.tenant_id = $1 AND t.note_id::text > $2 ORDER BY export_key LIMIT $3"
+	);
+
+	let versions = tenant_export::section_query(&SECTIONS[1]);
+
+	assert!(
+		versions.contains("t.note_id IN (SELECT note_id FROM memory_notes WHERE tenant_id = $1)")
+	);
+}
+
+#[test]
+fn embeddings_export_vectors_as_arrays() {
+	let query = tenant_export::section_query(section("note_embeddings"));
+
+	assert!(query.contains("jsonb_build_object('vec', t.vec::real[])"));
+}
+
+#[test]
+fn restored_ids_are_deterministic_per_tenant() {
+	let archived = Uuid::parse_str("00000000-0000-0000-0000-000000000001").expect("uuid");
+
+	assert_eq!(restored_id("t", archived), restored_id("t", archived));
+	assert_ne!(restored_id("t", archived), restored_id("u", archived));
+	assert_ne!(restored_id("t", archived), archived);
+}
+
+#[test]
+fn rewrite_row_maps_ids_and_references_into_the_target_tenant() {
+	let fact_id = "00000000-0000-0000-0000-000000000002";
+	let subject = "00000000-0000-0000-0000-000000000003";
+	let mut row = serde_json::json!({
+		"fact_id": fact_id,
+		"tenant_id": "source",
+		"subject_entity_id": subject,
+		"object_entity_id": Value::Null,
+		"predicate": "uses",
+	});
+
+	restore::rewrite_row(section("graph_facts"), &mut row, "target").expect("rewrite");
+
+	let expected_fact = restored_id("target", Uuid::parse_str(fact_id).expect("uuid"));
+	let expected_subject = restored_id("target", Uuid::parse_str(subject).expect("uuid"));
+
+	assert_eq!(row["fact_id"], expected_fact.to_string());
+	assert_eq!(row["subject_entity_id"], expected_subject.to_string());
+	assert_eq!(row["object_entity_id"], Value::Null);
+	assert_eq!(row["tenant_id"], "target");
+}
+
+#[test]
+fn restore_queries_skip_existing_rows() {
+	let notes = restore::restore_query(section("memory_notes"));
+
+	assert_eq!(
+		notes,
+		"INSERT INTO memory_notes SELECT * FROM jsonb_populate_record(NULL::memory_notes, $1) \
+		 ON CONFLICT DO NOTHING"
+	);
+	assert!(restore::restore_query(section("note_embeddings")).contains("::vector"));
+}
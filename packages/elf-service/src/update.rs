@@ -6,10 +6,17 @@ use sqlx::{Postgres, Transaction};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::{ElfService, Error, InsertVersionArgs, NoteOp, Result, access::ORG_PROJECT_ID};
+use crate::{
+	ElfService, Error, InsertVersionArgs, NoteOp, OutboxJob, PiiRedactor, Result,
+	access::ORG_PROJECT_ID,
+	audit::{AuditScope, AuditTargets},
+	note_batch::{NoteWriter, UpdateBatchItem},
+	permissions::WriteOperation,
+};
+use elf_config::SecurityAuthRole;
 use elf_domain::{
 	english_gate, ttl,
-	writegate::{self, NoteInput},
+	writegate::{self, NoteInput, PiiRedaction, RejectFeedback},
 };
 use elf_storage::models::MemoryNote;
 
@@ -22,6 +29,10 @@ pub struct UpdateRequest {
 	pub project_id: String,
 	/// Agent requesting the update.
 	pub agent_id: String,
+	#[serde(skip)]
+	/// Role of the authenticated caller, checked against the note's scope; `None` when auth is
+	/// off. Never read from request payloads.
+	pub role: Option<SecurityAuthRole>,
 	/// Identifier of the note to update.
 	pub note_id: Uuid,
 	/// Optional replacement note text.
@@ -43,11 +54,44 @@ pub struct UpdateResponse {
 	pub op: NoteOp,
 	/// Machine-readable rejection code, if the update was rejected.
 	pub reason_code: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	/// Remediation data for a write-gate rejection, if any.
+	pub reject_feedback: Option<RejectFeedback>,
+}
+
+impl AuditTargets for UpdateResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.note_id]
+	}
+
+	fn audit_reject_reasons(&self) -> Vec<String> {
+		if self.op == NoteOp::Rejected {
+			self.reason_code.iter().cloned().collect()
+		} else {
+			Vec::new()
+		}
+	}
+}
+
+/// Outcome of [`ElfService::apply_update`].
+pub(crate) struct AppliedUpdate {
+	pub(crate) response: UpdateResponse,
+	/// Reindex job for a changed note; `None` when nothing was written.
+	pub(crate) outbox: Option<OutboxJob>,
 }
 
 impl ElfService {
 	/// Updates mutable note fields when the caller still owns an active note.
 	pub async fn update(&self, req: UpdateRequest) -> Result<UpdateResponse> {
+		let scope = AuditScope::new("update")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets([req.note_id]);
+
+		self.audited(scope, self.update_inner(req)).await
+	}
+
+	/// Runs [`ElfService::update`] without recording an audit entry.
+	async fn update_inner(&self, req: UpdateRequest) -> Result<UpdateResponse> {
 		let now = OffsetDateTime::now_utc();
 		let tenant_id = req.tenant_id.trim();
 		let project_id = req.project_id.trim();
@@ -58,48 +102,96 @@ impl ElfService {
 				message: "tenant_id, project_id, and agent_id are required.".to_string(),
 			});
 		}
-		if req.text.is_none()
-			&& req.importance.is_none()
-			&& req.confidence.is_none()
-			&& req.ttl_days.is_none()
+
+		let writer = NoteWriter { tenant_id, project_id, agent_id, role: req.role };
+		let item = UpdateBatchItem {
+			note_id: req.note_id,
+			text: req.text,
+			importance: req.importance,
+			confidence: req.confidence,
+			ttl_days: req.ttl_days,
+		};
+		let mut tx = self.db.pool.begin().await?;
+		let applied = self.apply_update(&mut tx, &writer, &item, now).await?;
+
+		if let Some(job) = applied.outbox {
+			crate::enqueue_upsert_outbox_tx(
+				&mut tx,
+				job.note_id,
+				&job.embedding_version,
+				now,
+				job.coalesced,
+			)
+			.await?;
+		}
+
+		tx.commit().await?;
+
+		Ok(applied.response)
+	}
+
+	/// Applies one note update inside the caller's transaction without enqueuing outbox work.
+	///
+	/// Caller-facing failures (missing note, denied role, non-English text, nothing to update)
+	/// are returned before anything is written, so a batch may record them and continue.
+	pub(crate) async fn apply_update(
+		&self,
+		tx: &mut Transaction<'_, Postgres>,
+		writer: &NoteWriter<'_>,
+		item: &UpdateBatchItem,
+		now: OffsetDateTime,
+	) -> Result<AppliedUpdate> {
+		if item.text.is_none()
+			&& item.importance.is_none()
+			&& item.confidence.is_none()
+			&& item.ttl_days.is_none()
 		{
 			return Err(Error::InvalidRequest { message: "No updates provided.".to_string() });
 		}
 
-		let text_update = req.text.clone();
-		let mut tx = self.db.pool.begin().await?;
-		let mut note = load_note_for_update(&mut tx, req.note_id, tenant_id, project_id).await?;
+		let mut note =
+			load_note_for_update(tx, item.note_id, writer.tenant_id, writer.project_id).await?;
 
-		validate_note_is_updatable(&note, agent_id, now)?;
+		validate_note_is_updatable(&note, writer.agent_id, now)?;
+		self.authorize_write(writer.role, WriteOperation::Update, note.scope.as_str())?;
 
 		let prev_snapshot = crate::note_snapshot(&note);
-		let candidate_text = if let Some(text) = text_update.as_ref() {
+		let mut redactor = PiiRedactor::new(&self.cfg);
+		let candidate_text = if let Some(text) = item.text.as_ref() {
 			if !english_gate::is_english_natural_language(text) {
 				return Err(Error::NonEnglishInput { field: "$.text".to_string() });
 			}
 
-			text.clone()
+			let mut text = text.clone();
+
+			redactor.field("$.text", &mut text);
+
+			text
 		} else {
 			note.text.clone()
 		};
 		let gate = NoteInput {
 			note_type: note.r#type.clone(),
 			scope: note.scope.clone(),
-			text: candidate_text,
+			text: candidate_text.clone(),
 		};
 
 		if let Err(code) = writegate::writegate(&gate, &self.cfg) {
-			return Ok(UpdateResponse {
-				note_id: note.note_id,
-				op: NoteOp::Rejected,
-				reason_code: Some(crate::writegate_reason_code(code).to_string()),
+			return Ok(AppliedUpdate {
+				response: UpdateResponse {
+					note_id: note.note_id,
+					op: NoteOp::Rejected,
+					reason_code: Some(crate::writegate_reason_code(code).to_string()),
+					reject_feedback: writegate::reject_feedback(&gate, &self.cfg, code),
+				},
+				outbox: None,
 			});
 		}
 
-		let next_text = text_update.unwrap_or_else(|| note.text.clone());
-		let next_importance = req.importance.unwrap_or(note.importance);
-		let next_confidence = req.confidence.unwrap_or(note.confidence);
-		let next_expires_at = match req.ttl_days {
+		let next_text = candidate_text;
+		let next_importance = item.importance.unwrap_or(note.importance);
+		let next_confidence = item.confidence.unwrap_or(note.confidence);
+		let next_expires_at = match item.ttl_days {
 			Some(ttl_days) => ttl::compute_expires_at(Some(ttl_days), &note.r#type, &self.cfg, now),
 			None => note.expires_at,
 		};
@@ -109,12 +201,14 @@ impl ElfService {
 			|| next_expires_at != note.expires_at;
 
 		if !changed {
-			tx.commit().await?;
-
-			return Ok(UpdateResponse {
-				note_id: note.note_id,
-				op: NoteOp::None,
-				reason_code: None,
+			return Ok(AppliedUpdate {
+				response: UpdateResponse {
+					note_id: note.note_id,
+					op: NoteOp::None,
+					reason_code: None,
+					reject_feedback: None,
+				},
+				outbox: None,
 			});
 		}
 
@@ -124,15 +218,34 @@ impl ElfService {
 		note.expires_at = next_expires_at;
 		note.updated_at = now;
 
-		persist_note_update(&mut tx, &note, prev_snapshot, agent_id).await?;
-
-		tx.commit().await?;
+		let coalesced = persist_note_update(
+			tx,
+			&note,
+			prev_snapshot,
+			writer.agent_id,
+			self.cfg.memory.version_coalesce_window_ms,
+			&redactor.finish(),
+		)
+		.await?;
 
-		Ok(UpdateResponse { note_id: note.note_id, op: NoteOp::Update, reason_code: None })
+		Ok(AppliedUpdate {
+			response: UpdateResponse {
+				note_id: note.note_id,
+				op: NoteOp::Update,
+				reason_code: None,
+				reject_feedback: None,
+			},
+			outbox: Some(OutboxJob {
+				note_id: note.note_id,
+				op: "UPSERT",
+				embedding_version: note.embedding_version,
+				coalesced,
+			}),
+		})
 	}
 }
 
-fn validate_note_is_updatable(
+pub(crate) fn validate_note_is_updatable(
 	note: &MemoryNote,
 	agent_id: &str,
 	now: OffsetDateTime,
@@ -153,7 +266,7 @@ fn validate_note_is_updatable(
 	Ok(())
 }
 
-async fn load_note_for_update(
+pub(crate) async fn load_note_for_update(
 	tx: &mut Transaction<'_, Postgres>,
 	note_id: Uuid,
 	tenant_id: &str,
@@ -180,7 +293,9 @@ async fn persist_note_update(
 	note: &MemoryNote,
 	prev_snapshot: Value,
 	request_agent_id: &str,
-) -> Result<()> {
+	version_coalesce_window_ms: Option<u64>,
+	pii_redactions: &[PiiRedaction],
+) -> Result<bool> {
 	sqlx::query(
 		"\
 UPDATE memory_notes
@@ -200,8 +315,8 @@ WHERE note_id = $6",
 	.bind(note.note_id)
 	.execute(&mut **tx)
 	.await?;
-	crate::insert_version(
-		&mut **tx,
+	let version = crate::insert_update_version(
+		tx,
 		InsertVersionArgs {
 			note_id: note.note_id,
 			op: "UPDATE",
@@ -211,16 +326,13 @@ WHERE note_id = $6",
 			actor: request_agent_id,
 			ts: note.updated_at,
 		},
-	)
-	.await?;
-	crate::enqueue_outbox_tx(
-		&mut **tx,
-		note.note_id,
-		"UPSERT",
-		&note.embedding_version,
-		note.updated_at,
+		version_coalesce_window_ms,
 	)
 	.await?;
 
-	Ok(())
+	if version.coalesced || !pii_redactions.is_empty() {
+		crate::record_version_redactions(&mut **tx, version.version_id, pii_redactions).await?;
+	}
+
+	Ok(version.coalesced)
 }
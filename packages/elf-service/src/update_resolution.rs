@@ -88,6 +88,8 @@ pub(crate) struct ResolveUpdateArgs<'a> {
 	pub(crate) key: Option<&'a str>,
 	pub(crate) text: &'a str,
 	pub(crate) now: OffsetDateTime,
+	/// Precomputed embedding of `text`; the provider is called when absent.
+	pub(crate) embedding: Option<&'a [f32]>,
 }
 
 pub(crate) async fn resolve_update<'e, E>(
@@ -108,13 +110,20 @@ where
 		key,
 		text,
 		now,
+		embedding,
 	} = args;
-	let embeddings =
-		providers.embedding.embed(&cfg.providers.embedding, &[text.to_string()]).await?;
-	let Some(vec) = embeddings.into_iter().next() else {
-		return Err(Error::Provider {
-			message: "Embedding provider returned no vectors.".to_string(),
-		});
+	let vec = match embedding {
+		Some(embedding) => embedding.to_vec(),
+		None => {
+			let embeddings = providers
+				.embedding
+				.embed(cfg.providers.embedding_for_note(scope, note_type), &[text.to_string()])
+				.await?;
+
+			embeddings.into_iter().next().ok_or_else(|| Error::Provider {
+				message: "Embedding provider returned no vectors.".to_string(),
+			})?
+		},
 	};
 
 	if vec.len() != cfg.storage.qdrant.vector_dim as usize {
@@ -124,7 +133,7 @@ where
 	}
 
 	let vec_text = crate::vector_to_pg(&vec);
-	let embed_version = crate::embedding_version(cfg);
+	let embed_version = crate::embedding_version_for_note(cfg, scope, note_type);
 	let key = key.map(|value| value.trim()).filter(|value| !value.is_empty());
 	let row: (Option<Uuid>, Option<Uuid>, Option<f32>) = sqlx::query_as(RESOLVE_UPDATE_QUERY)
 		.bind(tenant_id)
@@ -0,0 +1,91 @@
+//! External URL evidence pointers and snapshot queueing.
+
+use serde_json::Value;
+use sqlx::PgConnection;
+
+use crate::{ElfService, Error, Result};
+use elf_domain::egress;
+use elf_storage::{
+	models::MemoryNote,
+	url_snapshots::{self, SourceUrlSnapshotInsert},
+};
+
+/// `source_ref.resolver` identifier for notes whose evidence is an external web page.
+pub const EXTERNAL_URL_RESOLVER_V1: &str = "external_url/v1";
+
+const MAX_EXTERNAL_URL_CHARS: usize = 2_048;
+
+/// Returns the URL of an `external_url/v1` source ref, or `None` for any other resolver.
+///
+/// The URL must be an absolute `http` or `https` URL without embedded credentials whose host is
+/// not a local name or a non-public IP literal.
+pub fn external_source_url(source_ref: &Value) -> Result<Option<&str>> {
+	if source_ref.get("resolver").and_then(Value::as_str) != Some(EXTERNAL_URL_RESOLVER_V1) {
+		return Ok(None);
+	}
+
+	let url = source_ref.pointer("/ref/url").and_then(Value::as_str).unwrap_or_default();
+	let authority = url
+		.strip_prefix("https://")
+		.or_else(|| url.strip_prefix("http://"))
+		.map(|rest| rest.split(['/', '?', '#']).next().unwrap_or_default());
+	let valid = authority
+		.is_some_and(|authority| !authority.is_empty() && !authority.contains('@'))
+		&& url.is_ascii()
+		&& !url.chars().any(|c| c.is_ascii_whitespace() || c.is_ascii_control())
+		&& url.len() <= MAX_EXTERNAL_URL_CHARS;
+
+	if !valid {
+		return Err(Error::InvalidRequest {
+			message: format!(
+				"source_ref.ref.url must be an http or https URL without credentials of at most {MAX_EXTERNAL_URL_CHARS} characters."
+			),
+		});
+	}
+
+	if let Some(denial) =
+		egress::url_host(url).and_then(|host| egress::check_host(host, None).err())
+	{
+		return Err(Error::InvalidRequest {
+			message: format!("source_ref.ref.url is not fetchable: {}.", denial.message()),
+		});
+	}
+
+	Ok(Some(url))
+}
+
+impl ElfService {
+	/// Queues a snapshot when `note` points at an external URL and snapshotting is enabled.
+	///
+	/// A note rewritten with an already captured URL gets its snapshot reference restored.
+	pub(crate) async fn enqueue_url_snapshot_if_enabled(
+		&self,
+		conn: &mut PgConnection,
+		note: &MemoryNote,
+	) -> Result<()> {
+		if !self.cfg.url_snapshots.as_ref().is_some_and(|snapshots| snapshots.enabled) {
+			return Ok(());
+		}
+
+		let Some(url) = external_source_url(&note.source_ref)? else { return Ok(()) };
+		let embedding_version = crate::embedding_version(&self.cfg);
+
+		url_snapshots::enqueue_source_url_snapshot(
+			&mut *conn,
+			&SourceUrlSnapshotInsert {
+				note_id: note.note_id,
+				tenant_id: note.tenant_id.as_str(),
+				project_id: note.project_id.as_str(),
+				agent_id: note.agent_id.as_str(),
+				scope: note.scope.as_str(),
+				url,
+				embedding_version: embedding_version.as_str(),
+				now: note.updated_at,
+			},
+		)
+		.await?;
+		url_snapshots::attach_source_url_snapshot(&mut *conn, note.note_id, url).await?;
+
+		Ok(())
+	}
+}
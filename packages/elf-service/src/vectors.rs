@@ -1,15 +1,57 @@
+#[cfg(test)] mod tests;
+
 use crate::{Error, Result};
-use elf_config::Config;
+use elf_config::{Config, EmbeddingProviderConfig};
+
+/// Projection mode label recorded on Qdrant points built from projected legacy vectors.
+pub(crate) const PROJECTION_MODE_TRUNCATE: &str = "truncate";
 
 pub(crate) fn embedding_version(cfg: &Config) -> String {
-	format!(
-		"{}:{}:{}",
-		cfg.providers.embedding.provider_id,
-		cfg.providers.embedding.model,
-		cfg.storage.qdrant.vector_dim
+	provider_embedding_version(&cfg.providers.embedding, cfg.storage.qdrant.vector_dim)
+}
+
+/// Returns the embedding version recorded on notes of `note_type` written to `scope`.
+pub(crate) fn embedding_version_for_note(cfg: &Config, scope: &str, note_type: &str) -> String {
+	provider_embedding_version(
+		cfg.providers.embedding_for_note(scope, note_type),
+		cfg.storage.qdrant.vector_dim,
 	)
 }
 
+pub(crate) fn provider_embedding_version(
+	provider: &EmbeddingProviderConfig,
+	vector_dim: u32,
+) -> String {
+	format!("{}:{}:{}", provider.provider_id, provider.model, vector_dim)
+}
+
+/// Projects a legacy vector into the configured dimension when `[embedding_projection]` allows it.
+///
+/// Returns `None` when projection is disabled or the vector does not have the configured source
+/// dimension.
+pub(crate) fn project_legacy_vector(cfg: &Config, vec: &[f32]) -> Option<Vec<f32>> {
+	let projection = cfg.embedding_projection.as_ref().filter(|projection| projection.enabled)?;
+
+	if vec.len() != projection.source_dim as usize {
+		return None;
+	}
+
+	Some(truncate_and_normalize(vec, cfg.storage.qdrant.vector_dim as usize))
+}
+
+fn truncate_and_normalize(vec: &[f32], dim: usize) -> Vec<f32> {
+	let mut out: Vec<f32> = vec.iter().take(dim).copied().collect();
+	let norm = out.iter().map(|value| value * value).sum::<f32>().sqrt();
+
+	if norm > 0.0 {
+		for value in &mut out {
+			*value /= norm;
+		}
+	}
+
+	out
+}
+
 pub(crate) fn vector_to_pg(vec: &[f32]) -> String {
 	let mut out = String::with_capacity(vec.len() * 8);
 
@@ -0,0 +1,93 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::vectors;
+use elf_config::{Config, EmbeddingProviderConfig};
+
+fn parse_example_config() -> Config {
+	let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+	let path = root_dir.join("elf.example.toml");
+
+	elf_config::load(&path).expect("elf.example.toml must remain parseable and valid.")
+}
+
+fn large_provider(base: &EmbeddingProviderConfig) -> EmbeddingProviderConfig {
+	EmbeddingProviderConfig {
+		provider_id: base.provider_id.clone(),
+		api_base: base.api_base.clone(),
+		api_key: base.api_key.clone(),
+		path: base.path.clone(),
+		model: "large-model".to_string(),
+		dimensions: base.dimensions,
+		timeout_ms: base.timeout_ms,
+		default_headers: base.default_headers.clone(),
+		query_input: None,
+		model_dir: None,
+		resilience: base.resilience.clone(),
+		cache: base.cache.clone(),
+	}
+}
+
+#[test]
+fn scope_without_route_uses_default_embedding_version() {
+	let cfg = parse_example_config();
+
+	assert_eq!(
+		vectors::embedding_version_for_note(&cfg, "agent_private", "fact"),
+		vectors::embedding_version(&cfg)
+	);
+}
+
+#[test]
+fn scope_route_changes_embedding_version() {
+	let mut cfg = parse_example_config();
+	let provider = large_provider(&cfg.providers.embedding);
+
+	cfg.providers.embeddings = Some(HashMap::from([("large".to_string(), provider)]));
+	cfg.providers.embedding_scopes =
+		Some(HashMap::from([("org_shared".to_string(), "large".to_string())]));
+
+	let org_version = vectors::embedding_version_for_note(&cfg, "org_shared", "fact");
+
+	assert_ne!(org_version, vectors::embedding_version(&cfg));
+	assert!(org_version.contains(":large-model:"));
+	assert_eq!(cfg.providers.embedding_for_scope("org_shared").model, "large-model");
+}
+
+#[test]
+fn note_type_route_takes_precedence_over_scope_route() {
+	let mut cfg = parse_example_config();
+	let mut local = large_provider(&cfg.providers.embedding);
+
+	local.model = "local-model".to_string();
+	cfg.providers.embeddings = Some(HashMap::from([
+		("large".to_string(), large_provider(&cfg.providers.embedding)),
+		("local".to_string(), local),
+	]));
+	cfg.providers.embedding_scopes =
+		Some(HashMap::from([("agent_private".to_string(), "large".to_string())]));
+	cfg.providers.embedding_types =
+		Some(HashMap::from([("profile".to_string(), "local".to_string())]));
+
+	assert!(
+		vectors::embedding_version_for_note(&cfg, "agent_private", "decision")
+			.contains(":large-model:")
+	);
+	assert!(
+		vectors::embedding_version_for_note(&cfg, "project_shared", "profile")
+			.contains(":local-model:")
+	);
+}
+
+#[test]
+fn truncation_keeps_leading_components_and_renormalizes() {
+	let projected = vectors::truncate_and_normalize(&[3.0, 4.0, 12.0], 2);
+
+	assert_eq!(projected.len(), 2);
+	assert!((projected[0] - 0.6).abs() < 1e-6);
+	assert!((projected[1] - 0.8).abs() < 1e-6);
+}
+
+#[test]
+fn truncation_leaves_zero_vectors_untouched() {
+	assert_eq!(vectors::truncate_and_normalize(&[0.0, 0.0, 1.0], 2), vec![0.0, 0.0]);
+}
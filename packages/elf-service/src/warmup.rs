@@ -0,0 +1,125 @@
+//! Startup warm-up that primes providers, Qdrant, and caches before serving traffic.
+
+use std::time::Instant;
+
+use qdrant_client::qdrant::{Query, QueryPointsBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::{ElfService, Error, Result};
+use elf_config::Warmup;
+use elf_storage::qdrant::DENSE_VECTOR_NAME;
+
+/// Timings and counts recorded by one successful warm-up run.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WarmupReport {
+	/// Time spent loading the chunking tokenizer.
+	pub tokenizer_ms: u64,
+	/// Latency of the embedding call for the warm-up queries.
+	pub embedding_latency_ms: u64,
+	/// Latency of the rerank call for the warm-up queries.
+	pub rerank_latency_ms: u64,
+	/// Number of Qdrant queries issued to prime the collection.
+	pub qdrant_queries: usize,
+	/// Number of recent search cache rows loaded.
+	pub cache_rows_loaded: i64,
+	/// Total warm-up duration.
+	pub elapsed_ms: u64,
+}
+
+impl ElfService {
+	/// Loads the tokenizer, primes Qdrant and the search cache, and measures provider latency.
+	///
+	/// Fails when a step errors or when a provider call exceeds `max_provider_latency_ms`, so the
+	/// caller can keep reporting not-ready instead of serving cold.
+	pub async fn warm_up(&self, warmup: &Warmup) -> Result<WarmupReport> {
+		let started = Instant::now();
+		let step = Instant::now();
+
+		self.tokenizer()?;
+
+		let tokenizer_ms = elapsed_ms(step);
+		let step = Instant::now();
+		let vectors =
+			self.providers.embedding.embed(&self.cfg.providers.embedding, &warmup.queries).await?;
+		let embedding_latency_ms = elapsed_ms(step);
+
+		check_provider_latency("embedding", embedding_latency_ms, warmup.max_provider_latency_ms)?;
+
+		for vector in &vectors {
+			let query = QueryPointsBuilder::new(self.qdrant.collection.clone())
+				.query(Query::new_nearest(vector.clone()))
+				.using(DENSE_VECTOR_NAME)
+				.limit(1);
+
+			self.qdrant
+				.client
+				.query(query)
+				.await
+				.map_err(|err| Error::Qdrant { message: err.to_string() })?;
+		}
+
+		let step = Instant::now();
+
+		self.providers
+			.rerank
+			.rerank(&self.cfg.providers.rerank, warmup.queries[0].as_str(), &warmup.queries)
+			.await?;
+
+		let rerank_latency_ms = elapsed_ms(step);
+
+		check_provider_latency("rerank", rerank_latency_ms, warmup.max_provider_latency_ms)?;
+
+		let cache_rows_loaded = self.load_recent_cache_rows(warmup.cache_rows).await?;
+
+		Ok(WarmupReport {
+			tokenizer_ms,
+			embedding_latency_ms,
+			rerank_latency_ms,
+			qdrant_queries: vectors.len(),
+			cache_rows_loaded,
+			elapsed_ms: elapsed_ms(started),
+		})
+	}
+
+	/// Reads the most recently accessed cache rows so their pages are resident in Postgres.
+	async fn load_recent_cache_rows(&self, limit: u32) -> Result<i64> {
+		if limit == 0 {
+			return Ok(0);
+		}
+
+		let loaded = sqlx::query_scalar::<_, i64>(
+			"\
+SELECT count(*)
+FROM (
+	SELECT payload
+	FROM llm_cache
+	WHERE expires_at > now()
+	ORDER BY last_accessed_at DESC
+	LIMIT $1
+) recent",
+		)
+		.bind(i64::from(limit))
+		.fetch_one(&self.db.pool)
+		.await?;
+
+		Ok(loaded)
+	}
+}
+
+fn check_provider_latency(provider: &str, latency_ms: u64, max_latency_ms: u64) -> Result<()> {
+	if latency_ms > max_latency_ms {
+		return Err(Error::Provider {
+			message: format!(
+				"Warm-up {provider} latency {latency_ms} ms exceeds the {max_latency_ms} ms limit."
+			),
+		});
+	}
+
+	Ok(())
+}
+
+fn elapsed_ms(since: Instant) -> u64 {
+	u64::try_from(since.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,16 @@
+use crate::{Error, warmup};
+
+#[test]
+fn provider_latency_within_limit_passes() {
+	assert!(warmup::check_provider_latency("embedding", 250, 250).is_ok());
+}
+
+#[test]
+fn provider_latency_over_limit_fails() {
+	let message = match warmup::check_provider_latency("rerank", 900, 500) {
+		Err(Error::Provider { message }) => message,
+		other => panic!("expected provider error, got {other:?}"),
+	};
+
+	assert_eq!(message, "Warm-up rerank latency 900 ms exceeds the 500 ms limit.");
+}
@@ -6,6 +6,7 @@ use time::OffsetDateTime;
 use crate::{
 	ElfService, Error, Result,
 	access::{self, ORG_PROJECT_ID},
+	audit::AuditScope,
 	search,
 	work_journal::{
 		types::{
@@ -27,6 +28,18 @@ impl ElfService {
 	pub async fn work_journal_entry_create(
 		&self,
 		req: WorkJournalEntryCreateRequest,
+	) -> Result<WorkJournalEntryCreateResponse> {
+		let scope = AuditScope::new("work_journal_entry_create")
+			.caller(&req.tenant_id, &req.project_id, &req.agent_id)
+			.targets(req.entry_id);
+
+		self.audited(scope, self.work_journal_entry_create_inner(req)).await
+	}
+
+	/// Runs [`ElfService::work_journal_entry_create`] without recording an audit entry.
+	async fn work_journal_entry_create_inner(
+		&self,
+		req: WorkJournalEntryCreateRequest,
 	) -> Result<WorkJournalEntryCreateResponse> {
 		let mut validated = validation::validate_work_journal_create(&self.cfg, &req)?;
 		let now = OffsetDateTime::now_utc();
@@ -3,7 +3,7 @@ use serde_json::Value;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use crate::work_journal::types::WorkJournalEntryFamily;
+use crate::{audit::AuditTargets, work_journal::types::WorkJournalEntryFamily};
 use elf_domain::writegate::WritePolicyAudit;
 
 /// Response payload after Work Journal capture.
@@ -13,6 +13,12 @@ pub struct WorkJournalEntryCreateResponse {
 	pub entry: WorkJournalEntryResponse,
 }
 
+impl AuditTargets for WorkJournalEntryCreateResponse {
+	fn audit_target_ids(&self) -> Vec<Uuid> {
+		vec![self.entry.entry_id]
+	}
+}
+
 /// Session-level Work Journal readback.
 #[derive(Clone, Debug, Serialize)]
 pub struct WorkJournalSessionReadbackResponse {
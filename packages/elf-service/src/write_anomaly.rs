@@ -0,0 +1,318 @@
+//! Runaway write-loop detection and admin incident readback.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+use sqlx::{FromRow, Postgres, Transaction};
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{ElfService, Error, RATE_ANOMALY, Result, access::ORG_PROJECT_ID};
+use elf_config::Config;
+
+const RECENT_NOTES_SCAN_LIMIT: i64 = 500;
+const SAMPLE_TEXT_MAX_CHARS: usize = 240;
+const DEFAULT_INCIDENTS_LIMIT: u32 = 50;
+const MAX_INCIDENTS_LIMIT: u32 = 500;
+
+/// Request payload for listing write-anomaly incidents.
+#[derive(Clone, Debug)]
+pub struct AdminWriteIncidentsListRequest {
+	/// Tenant to query within.
+	pub tenant_id: String,
+	/// Project to query within; org-shared incidents are included.
+	pub project_id: String,
+	/// Optional writing-agent filter.
+	pub agent_id: Option<String>,
+	/// Maximum incidents to return.
+	pub limit: Option<u32>,
+}
+
+/// One detected runaway write loop for a single agent.
+#[derive(Clone, Debug, FromRow, Serialize)]
+pub struct WriteIncident {
+	/// Incident identifier.
+	pub incident_id: Uuid,
+	/// Tenant that owns the writes.
+	pub tenant_id: String,
+	/// Project the writes targeted.
+	pub project_id: String,
+	/// Agent that issued the writes.
+	pub agent_id: String,
+	/// Ingestion pipeline that first flagged the incident.
+	pub pipeline: String,
+	/// Configured action taken on flagged writes: `reject` or `report`.
+	pub action: String,
+	/// Reason code returned for rejected writes.
+	pub reason_code: String,
+	/// Sliding window length in seconds.
+	pub window_seconds: i64,
+	/// Similarity threshold in force when the incident opened.
+	pub similarity_threshold: f32,
+	/// Similar-write limit in force when the incident opened.
+	pub max_similar_writes: i32,
+	/// Highest number of similar notes seen inside one window.
+	pub peak_similar_writes: i32,
+	/// Number of writes flagged while the incident stayed open.
+	pub flagged_writes: i64,
+	/// Truncated text of the write that opened the incident.
+	pub sample_text: String,
+	#[serde(with = "crate::time_serde")]
+	/// First flagged write.
+	pub first_seen_at: OffsetDateTime,
+	#[serde(with = "crate::time_serde")]
+	/// Most recent flagged write.
+	pub last_seen_at: OffsetDateTime,
+}
+
+/// Response payload for listing write-anomaly incidents.
+#[derive(Clone, Debug, Serialize)]
+pub struct AdminWriteIncidentsListResponse {
+	/// Incidents ordered by most recent activity.
+	pub incidents: Vec<WriteIncident>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum WriteAnomalyAction {
+	Reject,
+	Report,
+}
+
+pub(crate) struct WriteAnomalyArgs<'a> {
+	pub tenant_id: &'a str,
+	pub project_id: &'a str,
+	pub agent_id: &'a str,
+	pub pipeline: &'a str,
+	pub text: &'a str,
+	pub now: OffsetDateTime,
+}
+
+pub(crate) struct WriteAnomalyVerdict {
+	pub action: WriteAnomalyAction,
+	pub similar_writes: usize,
+}
+
+impl ElfService {
+	/// Lists write-anomaly incidents for the caller's project, newest activity first.
+	pub async fn admin_write_incidents_list(
+		&self,
+		req: AdminWriteIncidentsListRequest,
+	) -> Result<AdminWriteIncidentsListResponse> {
+		let limit = req.limit.unwrap_or(DEFAULT_INCIDENTS_LIMIT);
+
+		if limit == 0 || limit > MAX_INCIDENTS_LIMIT {
+			return Err(Error::InvalidRequest {
+				message: format!("limit must be between 1 and {MAX_INCIDENTS_LIMIT}."),
+			});
+		}
+
+		let agent_id = req.agent_id.as_deref().map(str::trim).filter(|agent| !agent.is_empty());
+		let incidents = sqlx::query_as::<_, WriteIncident>(
+			"\
+SELECT
+	incident_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	pipeline,
+	action,
+	reason_code,
+	window_seconds,
+	similarity_threshold,
+	max_similar_writes,
+	peak_similar_writes,
+	flagged_writes,
+	sample_text,
+	first_seen_at,
+	last_seen_at
+FROM memory_write_incidents
+WHERE tenant_id = $1
+	AND project_id IN ($2, $3)
+	AND ($4::text IS NULL OR agent_id = $4)
+ORDER BY last_seen_at DESC, incident_id
+LIMIT $5",
+		)
+		.bind(req.tenant_id.as_str())
+		.bind(req.project_id.as_str())
+		.bind(ORG_PROJECT_ID)
+		.bind(agent_id)
+		.bind(i64::from(limit))
+		.fetch_all(&self.db.pool)
+		.await?;
+
+		Ok(AdminWriteIncidentsListResponse { incidents })
+	}
+}
+
+/// Counts the agent's recent notes similar to `args.text` and flags the write once the
+/// configured limit is reached inside the sliding window.
+pub(crate) async fn detect_write_anomaly(
+	tx: &mut Transaction<'_, Postgres>,
+	cfg: &Config,
+	args: &WriteAnomalyArgs<'_>,
+) -> Result<Option<WriteAnomalyVerdict>> {
+	let Some(anomaly) = cfg.memory.write_anomaly.as_ref() else {
+		return Ok(None);
+	};
+	let window_start = args.now - Duration::seconds(anomaly.window_seconds as i64);
+	let recent_texts: Vec<String> = sqlx::query_scalar(
+		"\
+SELECT text
+FROM memory_notes
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND agent_id = $3
+	AND created_at >= $4
+ORDER BY created_at DESC
+LIMIT $5",
+	)
+	.bind(args.tenant_id)
+	.bind(args.project_id)
+	.bind(args.agent_id)
+	.bind(window_start)
+	.bind(RECENT_NOTES_SCAN_LIMIT)
+	.fetch_all(&mut **tx)
+	.await?;
+	let similar_writes = count_similar_texts(
+		args.text,
+		recent_texts.iter().map(String::as_str),
+		anomaly.similarity_threshold,
+	);
+
+	if similar_writes < anomaly.max_similar_writes as usize {
+		return Ok(None);
+	}
+
+	let action = match anomaly.action.as_str() {
+		"report" => WriteAnomalyAction::Report,
+		_ => WriteAnomalyAction::Reject,
+	};
+
+	tracing::warn!(
+		tenant_id = %args.tenant_id,
+		project_id = %args.project_id,
+		agent_id = %args.agent_id,
+		pipeline = %args.pipeline,
+		similar_writes,
+		action = ?action,
+		"Write anomaly detected."
+	);
+
+	Ok(Some(WriteAnomalyVerdict { action, similar_writes }))
+}
+
+/// Opens an incident for the agent, or extends the one still active inside the window.
+pub(crate) async fn record_write_incident(
+	tx: &mut Transaction<'_, Postgres>,
+	cfg: &Config,
+	args: &WriteAnomalyArgs<'_>,
+	verdict: &WriteAnomalyVerdict,
+) -> Result<()> {
+	let Some(anomaly) = cfg.memory.write_anomaly.as_ref() else {
+		return Ok(());
+	};
+	let window_start = args.now - Duration::seconds(anomaly.window_seconds as i64);
+	let similar_writes = i32::try_from(verdict.similar_writes).unwrap_or(i32::MAX);
+	let extended = sqlx::query(
+		"\
+UPDATE memory_write_incidents
+SET
+	flagged_writes = flagged_writes + 1,
+	peak_similar_writes = GREATEST(peak_similar_writes, $5),
+	last_seen_at = $6
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND agent_id = $3
+	AND last_seen_at >= $4",
+	)
+	.bind(args.tenant_id)
+	.bind(args.project_id)
+	.bind(args.agent_id)
+	.bind(window_start)
+	.bind(similar_writes)
+	.bind(args.now)
+	.execute(&mut **tx)
+	.await?
+	.rows_affected();
+
+	if extended > 0 {
+		return Ok(());
+	}
+
+	let action = match verdict.action {
+		WriteAnomalyAction::Reject => "reject",
+		WriteAnomalyAction::Report => "report",
+	};
+
+	sqlx::query(
+		"\
+INSERT INTO memory_write_incidents (
+	incident_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	pipeline,
+	action,
+	reason_code,
+	window_seconds,
+	similarity_threshold,
+	max_similar_writes,
+	peak_similar_writes,
+	flagged_writes,
+	sample_text,
+	first_seen_at,
+	last_seen_at
+)
+VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,1,$12,$13,$13)",
+	)
+	.bind(Uuid::new_v4())
+	.bind(args.tenant_id)
+	.bind(args.project_id)
+	.bind(args.agent_id)
+	.bind(args.pipeline)
+	.bind(action)
+	.bind(RATE_ANOMALY)
+	.bind(anomaly.window_seconds as i64)
+	.bind(anomaly.similarity_threshold)
+	.bind(anomaly.max_similar_writes as i32)
+	.bind(similar_writes)
+	.bind(args.text.chars().take(SAMPLE_TEXT_MAX_CHARS).collect::<String>())
+	.bind(args.now)
+	.execute(&mut **tx)
+	.await?;
+
+	Ok(())
+}
+
+/// Jaccard similarity over lowercase alphanumeric word sets.
+fn token_set_similarity(left: &HashSet<String>, right: &HashSet<String>) -> f32 {
+	if left.is_empty() && right.is_empty() {
+		return 1.0;
+	}
+
+	let intersection = left.intersection(right).count();
+	let union = left.len() + right.len() - intersection;
+
+	intersection as f32 / union as f32
+}
+
+fn token_set(text: &str) -> HashSet<String> {
+	text.split(|ch: char| !ch.is_alphanumeric())
+		.filter(|token| !token.is_empty())
+		.map(str::to_lowercase)
+		.collect()
+}
+
+fn count_similar_texts<'a>(
+	text: &str,
+	recent_texts: impl Iterator<Item = &'a str>,
+	threshold: f32,
+) -> usize {
+	let tokens = token_set(text);
+
+	recent_texts
+		.filter(|recent| token_set_similarity(&tokens, &token_set(recent)) >= threshold)
+		.count()
+}
+
+#[cfg(test)] mod tests;
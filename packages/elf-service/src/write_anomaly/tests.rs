@@ -0,0 +1,30 @@
+use crate::write_anomaly;
+
+#[test]
+fn similar_loop_writes_are_counted_and_distinct_notes_are_not() {
+	let recent = [
+		"Retrying deploy step 41 for service api.",
+		"Retrying deploy step 42 for service api.",
+		"retrying DEPLOY step 43 for service api",
+		"The billing team prefers invoices on Mondays.",
+	];
+	let similar = write_anomaly::count_similar_texts(
+		"Retrying deploy step 44 for service api.",
+		recent.into_iter(),
+		0.7,
+	);
+
+	assert_eq!(similar, 3);
+}
+
+#[test]
+fn token_set_similarity_is_case_and_punctuation_insensitive() {
+	let left = write_anomaly::token_set("Deploys run on Fridays.");
+	let right = write_anomaly::token_set("deploys, run on fridays");
+
+	assert_eq!(write_anomaly::token_set_similarity(&left, &right), 1.0);
+	assert_eq!(
+		write_anomaly::token_set_similarity(&left, &write_anomaly::token_set("unrelated words")),
+		0.0
+	);
+}
@@ -1,4 +1,10 @@
-use elf_domain::writegate::RejectCode;
+#[cfg(test)] mod tests;
+
+use serde_json::Value;
+
+use crate::structured_fields::{StructuredEntity, StructuredFields};
+use elf_config::{Config, SecurityPii};
+use elf_domain::writegate::{self, PiiRedaction, RejectCode};
 
 pub(crate) fn writegate_reason_code(code: RejectCode) -> &'static str {
 	match code {
@@ -8,5 +14,107 @@ pub(crate) fn writegate_reason_code(code: RejectCode) -> &'static str {
 		RejectCode::RejectInvalidType => "REJECT_INVALID_TYPE",
 		RejectCode::RejectScopeDenied => "REJECT_SCOPE_DENIED",
 		RejectCode::RejectEmpty => "REJECT_EMPTY",
+		RejectCode::RejectPii => "REJECT_PII",
+	}
+}
+
+/// Applies `security.pii` redaction to the persisted text fields of one note write and collects
+/// what was replaced, keyed by field path.
+pub(crate) struct PiiRedactor<'a> {
+	policy: Option<&'a SecurityPii>,
+	redactions: Vec<PiiRedaction>,
+}
+impl<'a> PiiRedactor<'a> {
+	pub(crate) fn new(cfg: &'a Config) -> Self {
+		Self { policy: cfg.security.pii.as_ref(), redactions: Vec::new() }
+	}
+
+	/// Redacts `value` in place and records the replaced spans under `field`.
+	pub(crate) fn field(&mut self, field: &str, value: &mut String) {
+		if self.policy.is_none() {
+			return;
+		}
+
+		let result = writegate::redact_pii(field, value.as_str(), self.policy);
+
+		if !result.redactions.is_empty() {
+			*value = result.transformed;
+
+			self.redactions.extend(result.redactions);
+		}
+	}
+
+	/// Redacts the note key, when present.
+	pub(crate) fn key(&mut self, key: Option<&mut String>) {
+		if let Some(key) = key {
+			self.field("$.key", key);
+		}
+	}
+
+	/// Redacts every text value of the structured fields, including graph entity surfaces.
+	pub(crate) fn structured(&mut self, structured: Option<&mut StructuredFields>) {
+		let Some(structured) = structured else { return };
+
+		for (field, value) in [
+			("$.structured.summary", structured.summary.as_mut()),
+			("$.structured.question", structured.question.as_mut()),
+			("$.structured.answer", structured.answer.as_mut()),
+		] {
+			if let Some(value) = value {
+				self.field(field, value);
+			}
+		}
+		for (field, values) in [
+			("$.structured.facts", structured.facts.as_mut()),
+			("$.structured.concepts", structured.concepts.as_mut()),
+		] {
+			for (idx, value) in values.into_iter().flatten().enumerate() {
+				self.field(&format!("{field}[{idx}]"), value);
+			}
+		}
+		for (idx, entity) in structured.entities.iter_mut().flatten().enumerate() {
+			self.entity(&format!("$.structured.entities[{idx}]"), entity);
+		}
+		for (idx, relation) in structured.relations.iter_mut().flatten().enumerate() {
+			let path = format!("$.structured.relations[{idx}]");
+
+			if let Some(subject) = relation.subject.as_mut() {
+				self.entity(&format!("{path}.subject"), subject);
+			}
+			if let Some(object) = relation.object.as_mut() {
+				if let Some(entity) = object.entity.as_mut() {
+					self.entity(&format!("{path}.object.entity"), entity);
+				}
+				if let Some(value) = object.value.as_mut() {
+					self.field(&format!("{path}.object.value"), value);
+				}
+			}
+		}
+	}
+
+	/// Redacts the `quote` of each entry in a caller-supplied `source_ref.evidence` array.
+	pub(crate) fn source_ref_evidence(&mut self, source_ref: &mut Value) {
+		let Some(evidence) = source_ref.get_mut("evidence").and_then(Value::as_array_mut) else {
+			return;
+		};
+
+		for (idx, entry) in evidence.iter_mut().enumerate() {
+			let Some(Value::String(quote)) = entry.get_mut("quote") else { continue };
+
+			self.field(&format!("$.source_ref.evidence[{idx}].quote"), quote);
+		}
+	}
+
+	pub(crate) fn finish(self) -> Vec<PiiRedaction> {
+		self.redactions
+	}
+
+	fn entity(&mut self, path: &str, entity: &mut StructuredEntity) {
+		if let Some(canonical) = entity.canonical.as_mut() {
+			self.field(&format!("{path}.canonical"), canonical);
+		}
+		for (idx, alias) in entity.aliases.iter_mut().flatten().enumerate() {
+			self.field(&format!("{path}.aliases[{idx}]"), alias);
+		}
 	}
 }
@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use crate::{
+	PiiRedactor,
+	structured_fields::{
+		StructuredEntity, StructuredFields, StructuredRelation, StructuredRelationObject,
+	},
+};
+use elf_config::{Config, SecurityPii, SecurityPiiAction};
+
+fn parse_example_config() -> Config {
+	let root_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+	let path = root_dir.join("elf.example.toml");
+
+	elf_config::load(&path).expect("elf.example.toml must remain parseable and valid.")
+}
+
+#[test]
+fn pii_redactor_covers_structured_and_evidence_fields() {
+	let mut cfg = parse_example_config();
+
+	cfg.security.pii =
+		Some(SecurityPii { email: SecurityPiiAction::Redact, ..SecurityPii::default() });
+
+	let mut redactor = PiiRedactor::new(&cfg);
+	let mut key = Some("contact:ada@example.com".to_string());
+	let mut structured = StructuredFields {
+		summary: Some("Reach ada@example.com.".to_string()),
+		facts: Some(vec!["No contact here.".to_string(), "Mail ada@example.com.".to_string()]),
+		relations: Some(vec![StructuredRelation {
+			object: Some(StructuredRelationObject {
+				entity: Some(StructuredEntity {
+					aliases: Some(vec!["ada@example.com".to_string()]),
+					..StructuredEntity::default()
+				}),
+				value: None,
+			}),
+			..StructuredRelation::default()
+		}]),
+		..StructuredFields::default()
+	};
+	let mut source_ref =
+		serde_json::json!({ "evidence": [{ "quote": "Write to ada@example.com." }] });
+
+	redactor.key(key.as_mut());
+	redactor.structured(Some(&mut structured));
+	redactor.source_ref_evidence(&mut source_ref);
+
+	let fields: Vec<String> =
+		redactor.finish().into_iter().map(|redaction| redaction.field).collect();
+
+	assert_eq!(
+		fields,
+		vec![
+			"$.key",
+			"$.structured.summary",
+			"$.structured.facts[1]",
+			"$.structured.relations[0].object.entity.aliases[0]",
+			"$.source_ref.evidence[0].quote",
+		]
+	);
+	assert_eq!(key.as_deref(), Some("contact:[REDACTED_EMAIL]"));
+	assert_eq!(structured.summary.as_deref(), Some("Reach [REDACTED_EMAIL]."));
+	assert_eq!(source_ref["evidence"][0]["quote"], "Write to [REDACTED_EMAIL].");
+}
+
+#[test]
+fn pii_redactor_is_a_no_op_without_a_policy() {
+	let mut cfg = parse_example_config();
+
+	cfg.security.pii = None;
+
+	let mut redactor = PiiRedactor::new(&cfg);
+	let mut text = "Reach ada@example.com.".to_string();
+
+	redactor.field("$.text", &mut text);
+
+	assert_eq!(text, "Reach ada@example.com.");
+	assert!(redactor.finish().is_empty());
+}
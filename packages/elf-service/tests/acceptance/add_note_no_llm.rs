@@ -45,6 +45,7 @@ async fn add_note_does_not_call_llm() {
 		tenant_id: "t".to_string(),
 		project_id: "p".to_string(),
 		agent_id: "a".to_string(),
+		role: None,
 		scope: "agent_private".to_string(),
 		notes: vec![AddNoteInput {
 			r#type: "preference".to_string(),
@@ -0,0 +1,218 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	AdminGrantPutRequest, AdminGrantRevokeRequest, AdminGrantsListRequest, ElfService, Error,
+	GranteeKind, Providers, ShareScope, SpaceGrantRevokeRequest, SpaceGrantUpsertRequest,
+};
+
+fn put_request() -> AdminGrantPutRequest {
+	AdminGrantPutRequest {
+		tenant_id: "tenant-grants".to_string(),
+		project_id: "project-grants".to_string(),
+		actor_agent_id: "admin-grants".to_string(),
+		scope: "project_shared".to_string(),
+		space_owner_agent_id: "owner-grants".to_string(),
+		grantee_kind: GranteeKind::Agent,
+		grantee_agent_id: Some("reader-grants".to_string()),
+		reason: Some("onboarding".to_string()),
+	}
+}
+
+fn space_grant_request() -> SpaceGrantUpsertRequest {
+	SpaceGrantUpsertRequest {
+		tenant_id: "tenant-grants".to_string(),
+		project_id: "project-grants".to_string(),
+		agent_id: "owner-grants".to_string(),
+		scope: ShareScope::ProjectShared,
+		grantee_kind: GranteeKind::Agent,
+		grantee_agent_id: Some("reader-grants".to_string()),
+	}
+}
+
+async fn grant_versions(service: &ElfService) -> Vec<(String, String, String, bool)> {
+	sqlx::query_as(
+		"\
+SELECT op, reason, actor, prev_snapshot IS NULL
+FROM memory_space_grant_versions
+WHERE tenant_id = 'tenant-grants'
+ORDER BY ts",
+	)
+	.fetch_all(&service.db.pool)
+	.await
+	.expect("Failed to load grant versions.")
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn admin_grants_put_and_revoke_write_audit_versions() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping admin_grants_put_and_revoke_write_audit_versions; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!("Skipping admin_grants_put_and_revoke_write_audit_versions; set ELF_QDRANT_URL.");
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_admin_grants");
+	let docs_collection = test_db.collection_name("elf_admin_grants_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let created = service.grants_put(put_request()).await.expect("grants_put failed.");
+
+	assert!(created.changed);
+	assert_eq!(created.grant.granted_by_agent_id, "admin-grants");
+
+	let repeated = service.grants_put(put_request()).await.expect("grants_put failed.");
+
+	assert!(!repeated.changed);
+	assert_eq!(repeated.grant.grant_id, created.grant.grant_id);
+	assert!(repeated.version_id.is_none());
+
+	let mut self_grant = put_request();
+
+	self_grant.grantee_agent_id = Some("owner-grants".to_string());
+
+	let err = service.grants_put(self_grant).await.expect_err("Expected a self-grant error.");
+
+	assert!(matches!(err, Error::InvalidRequest { .. }), "Unexpected error: {err:?}");
+
+	let listed = service
+		.grants_list(AdminGrantsListRequest {
+			tenant_id: "tenant-grants".to_string(),
+			space_owner_agent_id: Some("owner-grants".to_string()),
+			..Default::default()
+		})
+		.await
+		.expect("grants_list failed.");
+
+	assert_eq!(listed.grants.len(), 1);
+
+	let revoked = service
+		.grants_revoke(AdminGrantRevokeRequest {
+			tenant_id: "tenant-grants".to_string(),
+			actor_agent_id: "admin-grants".to_string(),
+			grant_id: created.grant.grant_id,
+			reason: None,
+		})
+		.await
+		.expect("grants_revoke failed.");
+
+	assert!(revoked.changed);
+	assert_eq!(revoked.grant.revoked_by_agent_id.as_deref(), Some("admin-grants"));
+
+	let active = service
+		.grants_list(AdminGrantsListRequest {
+			tenant_id: "tenant-grants".to_string(),
+			..Default::default()
+		})
+		.await
+		.expect("grants_list failed.");
+
+	assert!(active.grants.is_empty());
+
+	let other_tenant = service
+		.grants_revoke(AdminGrantRevokeRequest {
+			tenant_id: "tenant-other".to_string(),
+			actor_agent_id: "admin-grants".to_string(),
+			grant_id: created.grant.grant_id,
+			reason: None,
+		})
+		.await
+		.expect_err("Expected a missing grant.");
+
+	assert!(matches!(other_tenant, Error::NotFound { .. }), "Unexpected error: {other_tenant:?}");
+
+	assert_eq!(
+		grant_versions(&service).await,
+		vec![
+			("grant".to_string(), "onboarding".to_string(), "admin-grants".to_string(), true),
+			("revoke".to_string(), "admin_revoke".to_string(), "admin-grants".to_string(), false),
+		]
+	);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn space_grant_upsert_and_revoke_write_audit_versions() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping space_grant_upsert_and_revoke_write_audit_versions; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping space_grant_upsert_and_revoke_write_audit_versions; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_space_grants");
+	let docs_collection = test_db.collection_name("elf_space_grants_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	service.space_grant_upsert(space_grant_request()).await.expect("space_grant_upsert failed.");
+	service.space_grant_upsert(space_grant_request()).await.expect("space_grant_upsert failed.");
+	service
+		.space_grant_revoke(SpaceGrantRevokeRequest {
+			tenant_id: "tenant-grants".to_string(),
+			project_id: "project-grants".to_string(),
+			agent_id: "owner-grants".to_string(),
+			scope: ShareScope::ProjectShared,
+			grantee_kind: GranteeKind::Agent,
+			grantee_agent_id: Some("reader-grants".to_string()),
+		})
+		.await
+		.expect("space_grant_revoke failed.");
+
+	assert_eq!(
+		grant_versions(&service).await,
+		vec![
+			("grant".to_string(), "space_grant".to_string(), "owner-grants".to_string(), true),
+			("grant".to_string(), "space_grant".to_string(), "owner-grants".to_string(), false),
+			("revoke".to_string(), "space_revoke".to_string(), "owner-grants".to_string(), false),
+		]
+	);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -0,0 +1,132 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use uuid::Uuid;
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, AuditLogListRequest, AuditOutcome, AuditTransport, NoteOp,
+	PinNoteRequest, Providers,
+};
+
+const TENANT_ID: &str = "tenant-audit";
+const PROJECT_ID: &str = "project-audit";
+const AGENT_ID: &str = "agent-audit";
+
+fn note_request() -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: AGENT_ID.to_string(),
+		role: None,
+		scope: "agent_private".to_string(),
+		notes: vec![AddNoteInput {
+			r#type: "fact".to_string(),
+			key: Some("release_train".to_string()),
+			text: "Fact: The release train leaves every Thursday.".to_string(),
+			structured: None,
+			importance: 0.5,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({ "schema": "acceptance/audit_log" }),
+			write_policy: None,
+		}],
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn service_writes_record_typed_audit_entries() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping service_writes_record_typed_audit_entries; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!("Skipping service_writes_record_typed_audit_entries; set ELF_QDRANT_URL.");
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_audit_log");
+	let docs_collection = test_db.collection_name("elf_audit_log_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let added = service.add_note(note_request()).await.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected note_id.");
+
+	assert_eq!(added.results[0].op, NoteOp::Add);
+
+	let missing = Uuid::new_v4();
+	let transport = AuditTransport {
+		token_id: Some("key-audit".to_string()),
+		method: Some("POST".to_string()),
+		path: Some("/v2/notes/{note_id}/pin".to_string()),
+		..AuditTransport::default()
+	};
+	let pinned = elf_service::with_audit_transport(
+		transport,
+		service.pin_note(PinNoteRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			role: None,
+			note_id: missing,
+		}),
+	)
+	.await;
+
+	assert!(pinned.is_err());
+
+	let log = service
+		.audit_log_list(AuditLogListRequest {
+			tenant_id: TENANT_ID.to_string(),
+			operation: None,
+			token_id: None,
+			actor: None,
+			before: None,
+			limit: None,
+		})
+		.await
+		.expect("Failed to list audit log.");
+
+	assert_eq!(log.entries.len(), 2);
+	assert_eq!(log.chain_intact, Some(true));
+
+	let pin = &log.entries[0];
+
+	assert_eq!(pin.operation, "pin");
+	assert_eq!(pin.outcome, AuditOutcome::Failed);
+	assert_eq!(pin.target_ids, vec![missing]);
+	assert_eq!(pin.reject_reasons, vec!["NOT_FOUND".to_string()]);
+	assert_eq!(pin.token_id.as_deref(), Some("key-audit"));
+	assert_eq!(pin.path.as_deref(), Some("/v2/notes/{note_id}/pin"));
+
+	let add = &log.entries[1];
+
+	assert_eq!(add.operation, "add_note");
+	assert_eq!(add.outcome, AuditOutcome::Succeeded);
+	assert_eq!(add.project_id, PROJECT_ID);
+	assert_eq!(add.actor, AGENT_ID);
+	assert_eq!(add.target_ids, vec![note_id]);
+	assert_eq!(add.method, None);
+	assert!(log.entries.iter().all(|entry| entry.hash_valid));
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -87,6 +87,7 @@ async fn load_filter_impact_from_trace(context: &TestContext, trace_id: Uuid) ->
 			tenant_id: "t".to_string(),
 			project_id: "p".to_string(),
 			agent_id: "a".to_string(),
+			token_id: None,
 			trace_id,
 		})
 		.await
@@ -152,8 +153,15 @@ async fn search_filter_affects_candidate_set_and_records_filter_impact() {
 				"schema": "search_filter_expr/v1",
 				"expr": { "op": "gte", "field": "importance", "value": 0.5 },
 			})),
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.expect("Search failed.");
@@ -185,3 +193,173 @@ async fn search_filter_affects_candidate_set_and_records_filter_impact() {
 
 	context.test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
+
+async fn insert_structured_field(
+	context: &TestContext,
+	note_id: Uuid,
+	field_kind: &str,
+	text: &str,
+) {
+	sqlx::query(
+		"\
+INSERT INTO memory_note_fields (field_id, note_id, field_kind, item_index, text)
+VALUES ($1, $2, $3, 0, $4)",
+	)
+	.bind(Uuid::new_v4())
+	.bind(note_id)
+	.bind(field_kind)
+	.bind(text)
+	.execute(&context.service.db.pool)
+	.await
+	.expect("Failed to insert structured field.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run this test."]
+async fn search_filter_on_structured_fields_records_field_predicates() {
+	let provider = tests_helpers::build_providers(StubRerank);
+	let bob_note_text = "alpha release owned by bob";
+	let alice_note_text = "alpha release owned by alice";
+	let bob_note_id = Uuid::new_v4();
+	let alice_note_id = Uuid::new_v4();
+	let mut context = match tests_helpers::setup_context(
+		"search_filter_on_structured_fields_records_field_predicates",
+		provider,
+	)
+	.await
+	{
+		Some(context) => context,
+		None => return,
+	};
+
+	context.service.cfg.search.explain.write_mode = "inline".to_string();
+
+	seed_filter_impact_notes(
+		&context,
+		bob_note_id,
+		alice_note_id,
+		Uuid::new_v4(),
+		Uuid::new_v4(),
+		bob_note_text,
+		alice_note_text,
+	)
+	.await;
+	insert_structured_field(&context, bob_note_id, "owner", "bob").await;
+	insert_structured_field(&context, bob_note_id, "deadline", "2024-11-30").await;
+	insert_structured_field(&context, alice_note_id, "owner", "alice").await;
+	insert_structured_field(&context, alice_note_id, "deadline", "2024-12-15").await;
+
+	let response = context
+		.service
+		.search_raw(SearchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			read_profile: "private_only".to_string(),
+			payload_level: Default::default(),
+			query: "alpha".to_string(),
+			top_k: Some(2),
+			candidate_k: Some(10),
+			filter: Some(serde_json::json!({
+				"schema": "search_filter_expr/v1",
+				"expr": {
+					"op": "and",
+					"args": [
+						{ "op": "eq", "field": "fields.owner", "value": "alice" },
+						{ "op": "lt", "field": "fields.deadline", "value": "2025-01-01" },
+					],
+				},
+			})),
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
+			record_hits: Some(false),
+			ranking: None,
+			session_id: None,
+			session_mode: None,
+		})
+		.await
+		.expect("Search failed.");
+
+	assert_eq!(response.items.len(), 1);
+	assert_eq!(response.items[0].note_id, alice_note_id);
+
+	let filter_impact = load_filter_impact_from_trace(&context, response.trace_id).await;
+
+	assert_eq!(
+		filter_impact.get("field_predicates"),
+		Some(&serde_json::json!([
+			{ "op": "eq", "field": "fields.owner", "value": "alice" },
+			{ "op": "lt", "field": "fields.deadline", "value": "2025-01-01" },
+		]))
+	);
+	assert_eq!(filter_impact.get("dropped_total"), Some(&Value::from(1_u64)));
+
+	context.test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run this test."]
+async fn typed_search_filters_narrow_candidates_before_ranking() {
+	let provider = tests_helpers::build_providers(StubRerank);
+	let low_note_text = "alpha low importance note";
+	let high_note_text = "alpha high importance note";
+	let low_note_id = Uuid::new_v4();
+	let high_note_id = Uuid::new_v4();
+	let mut context = match tests_helpers::setup_context(
+		"typed_search_filters_narrow_candidates_before_ranking",
+		provider,
+	)
+	.await
+	{
+		Some(context) => context,
+		None => return,
+	};
+
+	context.service.cfg.search.explain.write_mode = "inline".to_string();
+
+	seed_filter_impact_notes(
+		&context,
+		low_note_id,
+		high_note_id,
+		Uuid::new_v4(),
+		Uuid::new_v4(),
+		low_note_text,
+		high_note_text,
+	)
+	.await;
+
+	let response = context
+		.service
+		.search_raw(SearchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			read_profile: "private_only".to_string(),
+			payload_level: Default::default(),
+			query: "alpha".to_string(),
+			top_k: Some(2),
+			candidate_k: Some(10),
+			filter: None,
+			types: Vec::new(),
+			updated_after: Some("2000-01-01T00:00:00Z".to_string()),
+			updated_before: None,
+			min_importance: Some(0.5),
+			max_result_tokens: None,
+			record_hits: Some(false),
+			ranking: None,
+			session_id: None,
+			session_mode: None,
+		})
+		.await
+		.expect("Search failed.");
+
+	assert_eq!(response.items.len(), 1);
+	assert_eq!(response.items[0].note_id, high_note_id);
+
+	context.test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -19,8 +19,15 @@ fn build_payload_shape_search_request(payload_level: PayloadLevel) -> SearchRequ
 		top_k: Some(5),
 		candidate_k: Some(10),
 		filter: None,
+		types: Vec::new(),
+		updated_after: None,
+		updated_before: None,
+		min_importance: None,
+		max_result_tokens: None,
 		record_hits: Some(false),
 		ranking: None,
+		session_id: None,
+		session_mode: None,
 	}
 }
 
@@ -81,6 +88,7 @@ async fn fetch_search_detail_note_for_level(
 			payload_level,
 			note_ids: vec![note_id],
 			record_hits: Some(false),
+			session_id: None,
 		})
 		.await
 		.expect("Search details failed.");
@@ -244,8 +252,15 @@ async fn search_details_payload_level_shapes_text_and_fields() {
 			top_k: Some(5),
 			candidate_k: Some(10),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.expect("Search index failed.");
@@ -33,8 +33,15 @@ async fn search_raw_quick_includes_relation_context_and_respects_fact_bounds() {
 			top_k: Some(5),
 			candidate_k: Some(10),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.expect("Search failed.");
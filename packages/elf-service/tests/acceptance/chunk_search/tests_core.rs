@@ -1,3 +1,8 @@
 mod basic_search;
 mod dedupe;
+mod degraded;
+mod pinned;
 mod progressive;
+mod search_batch;
+mod search_hooks;
+mod search_v2;
@@ -57,8 +57,15 @@ async fn search_returns_chunk_items() {
 			top_k: Some(5),
 			candidate_k: Some(10),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.expect("Search failed.");
@@ -133,8 +140,15 @@ async fn search_stitches_adjacent_chunks() {
 			top_k: Some(5),
 			candidate_k: Some(10),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.expect("Search failed.");
@@ -192,8 +206,15 @@ async fn search_skips_missing_chunk_metadata() {
 			top_k: Some(5),
 			candidate_k: Some(10),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.expect("Search failed.");
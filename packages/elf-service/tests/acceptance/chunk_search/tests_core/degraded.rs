@@ -0,0 +1,109 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use uuid::Uuid;
+
+use crate::acceptance::{
+	SpyExtractor,
+	chunk_search::tests_helpers::{self, KeywordRerank},
+};
+use elf_config::{EmbeddingProviderConfig, SearchDegraded};
+use elf_service::{
+	BoxFuture, EmbeddingProvider, Error, Providers, Result, SearchRequest, SearchWarning,
+};
+
+struct FailingEmbedding;
+impl EmbeddingProvider for FailingEmbedding {
+	fn embed<'a>(
+		&'a self,
+		_cfg: &'a EmbeddingProviderConfig,
+		_texts: &'a [String],
+	) -> BoxFuture<'a, Result<Vec<Vec<f32>>>> {
+		Box::pin(async move {
+			Err(Error::Provider { message: "Embedding provider is unavailable.".to_string() })
+		})
+	}
+}
+
+fn search_request() -> SearchRequest {
+	SearchRequest {
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		token_id: None,
+		read_profile: "private_only".to_string(),
+		payload_level: Default::default(),
+		query: "alpha".to_string(),
+		top_k: Some(5),
+		candidate_k: Some(10),
+		filter: None,
+		types: Vec::new(),
+		updated_after: None,
+		updated_before: None,
+		min_importance: None,
+		max_result_tokens: None,
+		record_hits: Some(false),
+		ranking: None,
+		session_id: None,
+		session_mode: None,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn search_falls_back_to_bm25_when_embedding_fails() {
+	let providers = Providers::new(
+		Arc::new(FailingEmbedding),
+		Arc::new(KeywordRerank { keyword: "alpha" }),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let Some(mut context) =
+		tests_helpers::setup_context("search_falls_back_to_bm25_when_embedding_fails", providers)
+			.await
+	else {
+		return;
+	};
+	let note_id = Uuid::new_v4();
+	let chunk_id = Uuid::new_v4();
+	let text = "alpha rollout checklist.";
+
+	tests_helpers::insert_note(&context.service.db.pool, note_id, text, &context.embedding_version)
+		.await;
+	tests_helpers::insert_chunk(
+		&context.service.db.pool,
+		chunk_id,
+		note_id,
+		0,
+		0,
+		text.len() as i32,
+		text,
+		&context.embedding_version,
+	)
+	.await;
+	tests_helpers::upsert_point(&context.service, chunk_id, note_id, 0, 0, text.len() as i32, text)
+		.await;
+
+	let err = context
+		.service
+		.search_raw(search_request())
+		.await
+		.expect_err("Expected search to fail without the fallback.");
+
+	assert!(matches!(err, Error::Provider { .. }), "Unexpected error: {err:?}");
+
+	context.service.cfg.search.degraded = Some(SearchDegraded { bm25_fallback: true });
+
+	let response =
+		context.service.search_raw(search_request()).await.expect("Degraded search failed.");
+	let item = response.items.first().expect("Expected search result.");
+
+	assert_eq!(item.note_id, note_id);
+	assert!(response.warnings.iter().any(|warning| matches!(
+		warning,
+		SearchWarning::DegradedMode { mode, .. } if mode == "bm25_only"
+	)));
+
+	context.test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -0,0 +1,103 @@
+use uuid::Uuid;
+
+use crate::acceptance::chunk_search::tests_helpers::{self, KeywordRerank};
+use elf_service::{NoteOp, PinNoteRequest, SearchRequest};
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn search_force_includes_pinned_notes() {
+	let providers = tests_helpers::build_providers(KeywordRerank { keyword: "preferred" });
+	let Some(context) =
+		tests_helpers::setup_context("search_force_includes_pinned_notes", providers).await
+	else {
+		return;
+	};
+	let matched_id = Uuid::new_v4();
+	let matched_chunk_id = Uuid::new_v4();
+	let matched_text = "preferred alpha.";
+	let pinned_id = Uuid::new_v4();
+	let pinned_chunk_id = Uuid::new_v4();
+	let pinned_text = "Deploy freezes start on Fridays.";
+
+	for (note_id, chunk_id, text) in
+		[(matched_id, matched_chunk_id, matched_text), (pinned_id, pinned_chunk_id, pinned_text)]
+	{
+		tests_helpers::insert_note(
+			&context.service.db.pool,
+			note_id,
+			text,
+			&context.embedding_version,
+		)
+		.await;
+		tests_helpers::insert_chunk(
+			&context.service.db.pool,
+			chunk_id,
+			note_id,
+			0,
+			0,
+			text.len() as i32,
+			text,
+			&context.embedding_version,
+		)
+		.await;
+	}
+
+	// Only the matching note is indexed, so retrieval alone never returns the pinned note.
+	tests_helpers::upsert_point(
+		&context.service,
+		matched_chunk_id,
+		matched_id,
+		0,
+		0,
+		matched_text.len() as i32,
+		matched_text,
+	)
+	.await;
+
+	let pinned = context
+		.service
+		.pin_note(PinNoteRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			role: None,
+			note_id: pinned_id,
+		})
+		.await
+		.expect("Failed to pin note.");
+
+	assert_eq!(pinned.op, NoteOp::Update);
+
+	let response = context
+		.service
+		.search_raw(SearchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			read_profile: "private_only".to_string(),
+			payload_level: Default::default(),
+			query: "alpha".to_string(),
+			top_k: Some(1),
+			candidate_k: Some(10),
+			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
+			record_hits: Some(false),
+			ranking: None,
+			session_id: None,
+			session_mode: None,
+		})
+		.await
+		.expect("Search failed.");
+	let item = response.items.first().expect("Expected search result.");
+
+	assert_eq!(response.items.len(), 1);
+	assert_eq!(item.note_id, pinned_id);
+	assert!(item.explain.ranking.terms.iter().any(|term| term.name == "pin_boost"));
+
+	context.test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -61,8 +61,15 @@ async fn progressive_search_returns_index_timeline_and_details() {
 			top_k: Some(5),
 			candidate_k: Some(10),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.expect("Search index failed.");
@@ -78,6 +85,7 @@ async fn progressive_search_returns_index_timeline_and_details() {
 			search_session_id: index.search_session_id,
 			payload_level: Default::default(),
 			group_by: None,
+			group_limit: None,
 		})
 		.await
 		.expect("Search timeline failed.");
@@ -94,6 +102,7 @@ async fn progressive_search_returns_index_timeline_and_details() {
 			payload_level: Default::default(),
 			note_ids: vec![note_id],
 			record_hits: Some(false),
+			session_id: None,
 		})
 		.await
 		.expect("Search details failed.");
@@ -0,0 +1,112 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicUsize, Ordering},
+};
+
+use uuid::Uuid;
+
+use crate::acceptance::{SpyEmbedding, SpyExtractor, StubRerank, chunk_search::tests_helpers};
+use elf_service::{Providers, SearchBatchRequest, SearchRequest, SearchV2Mode};
+
+fn search_request(query: &str) -> SearchRequest {
+	SearchRequest {
+		tenant_id: "t".to_string(),
+		project_id: "p".to_string(),
+		agent_id: "a".to_string(),
+		token_id: None,
+		read_profile: "private_only".to_string(),
+		payload_level: Default::default(),
+		query: query.to_string(),
+		top_k: Some(5),
+		candidate_k: Some(10),
+		filter: None,
+		types: Vec::new(),
+		updated_after: None,
+		updated_before: None,
+		min_importance: None,
+		max_result_tokens: None,
+		record_hits: Some(false),
+		ranking: None,
+		session_id: None,
+		session_mode: None,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn search_batch_shares_one_embedding_call() {
+	let embed_calls = Arc::new(AtomicUsize::new(0));
+	let providers = Providers::new(
+		Arc::new(SpyEmbedding { vector_dim: 4_096, calls: embed_calls.clone() }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let Some(context) =
+		tests_helpers::setup_context("search_batch_shares_one_embedding_call", providers).await
+	else {
+		return;
+	};
+	let note_id = Uuid::new_v4();
+	let chunk_id = Uuid::new_v4();
+	let note_text = "Batch search shares embedding calls across related queries.";
+
+	tests_helpers::insert_note(
+		&context.service.db.pool,
+		note_id,
+		note_text,
+		&context.embedding_version,
+	)
+	.await;
+	tests_helpers::insert_chunk(
+		&context.service.db.pool,
+		chunk_id,
+		note_id,
+		0,
+		0,
+		note_text.len() as i32,
+		note_text,
+		&context.embedding_version,
+	)
+	.await;
+	tests_helpers::upsert_point(
+		&context.service,
+		chunk_id,
+		note_id,
+		0,
+		0,
+		note_text.len() as i32,
+		note_text,
+	)
+	.await;
+
+	let response = context
+		.service
+		.search_batch(SearchBatchRequest {
+			searches: vec![
+				search_request("Batch search"),
+				search_request("embedding calls"),
+				search_request("Batch search"),
+			],
+			mode: SearchV2Mode::QuickFind,
+		})
+		.await
+		.expect("Batch search failed.");
+
+	assert_eq!(response.results.len(), 3);
+	assert_eq!(embed_calls.load(Ordering::SeqCst), 1);
+	assert!(response.results.iter().all(|result| !result.items.is_empty()));
+	assert_ne!(response.results[0].search_session_id, response.results[2].search_session_id);
+
+	let err = context
+		.service
+		.search_batch(SearchBatchRequest { searches: Vec::new(), mode: SearchV2Mode::QuickFind })
+		.await
+		.expect_err("Expected an empty batch to be rejected.");
+
+	assert!(err.to_string().contains("at least one search"), "Unexpected error: {err}");
+
+	context.test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -0,0 +1,138 @@
+use std::sync::{Arc, Mutex};
+
+use uuid::Uuid;
+
+use crate::acceptance::{StubRerank, chunk_search::tests_helpers};
+use elf_service::{
+	BoxFuture, Error, Result, SearchHookOutput, SearchHookStage, SearchRequest, SearchStageContext,
+	SearchStageHook, SearchWarning,
+};
+
+struct RecordingHook {
+	seen: Arc<Mutex<Vec<(SearchHookStage, usize)>>>,
+}
+impl SearchStageHook for RecordingHook {
+	fn name(&self) -> &str {
+		"recording"
+	}
+
+	fn on_stage<'a>(
+		&'a self,
+		ctx: &'a SearchStageContext<'a>,
+	) -> BoxFuture<'a, Result<SearchHookOutput>> {
+		self.seen.lock().expect("Hook lock poisoned.").push((ctx.stage, ctx.candidates.len()));
+
+		let mut output = SearchHookOutput::default();
+
+		if ctx.stage == SearchHookStage::PreSelection {
+			output.warnings.push("Policy check observed the final candidates.".to_string());
+		}
+
+		Box::pin(async move { Ok(output) })
+	}
+}
+
+struct FailingHook;
+impl SearchStageHook for FailingHook {
+	fn name(&self) -> &str {
+		"failing"
+	}
+
+	fn stages(&self) -> &[SearchHookStage] {
+		&[SearchHookStage::PostRetrieval]
+	}
+
+	fn on_stage<'a>(
+		&'a self,
+		_ctx: &'a SearchStageContext<'a>,
+	) -> BoxFuture<'a, Result<SearchHookOutput>> {
+		Box::pin(async move { Err(Error::Provider { message: "policy backend down".to_string() }) })
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn search_hooks_run_at_every_stage_without_failing_search() {
+	let providers = tests_helpers::build_providers(StubRerank);
+	let Some(context) = tests_helpers::setup_context(
+		"search_hooks_run_at_every_stage_without_failing_search",
+		providers,
+	)
+	.await
+	else {
+		return;
+	};
+	let seen = Arc::new(Mutex::new(Vec::new()));
+	let service = context
+		.service
+		.with_search_hook(Arc::new(RecordingHook { seen: seen.clone() }))
+		.with_search_hook(Arc::new(FailingHook));
+	let note_id = Uuid::new_v4();
+	let chunk_id = Uuid::new_v4();
+	let note_text = "Stage hooks observe the search pipeline.";
+
+	tests_helpers::insert_note(&service.db.pool, note_id, note_text, &context.embedding_version)
+		.await;
+	tests_helpers::insert_chunk(
+		&service.db.pool,
+		chunk_id,
+		note_id,
+		0,
+		0,
+		note_text.len() as i32,
+		note_text,
+		&context.embedding_version,
+	)
+	.await;
+	tests_helpers::upsert_point(
+		&service,
+		chunk_id,
+		note_id,
+		0,
+		0,
+		note_text.len() as i32,
+		note_text,
+	)
+	.await;
+
+	let response = service
+		.search_raw_quick(SearchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			read_profile: "private_only".to_string(),
+			payload_level: Default::default(),
+			query: "Stage hooks".to_string(),
+			top_k: Some(5),
+			candidate_k: Some(10),
+			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
+			record_hits: Some(false),
+			ranking: None,
+			session_id: None,
+			session_mode: None,
+		})
+		.await
+		.expect("Search failed.");
+	let stages: Vec<SearchHookStage> =
+		seen.lock().expect("Hook lock poisoned.").iter().map(|(stage, _)| *stage).collect();
+
+	assert_eq!(stages, SearchHookStage::ALL.to_vec());
+	assert_eq!(response.items.first().map(|item| item.note_id), Some(note_id));
+	assert!(response.warnings.iter().any(|warning| matches!(
+		warning,
+		SearchWarning::SearchHook { hook, stage, .. } if hook == "recording" && stage == "pre_selection"
+	)));
+	assert!(response.warnings.iter().any(|warning| matches!(
+		warning,
+		SearchWarning::SearchHook { hook, message, .. }
+			if hook == "failing" && message.contains("policy backend down")
+	)));
+
+	context.test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -0,0 +1,108 @@
+use uuid::Uuid;
+
+use crate::acceptance::{StubRerank, chunk_search::tests_helpers};
+use elf_service::{SearchRequest, SearchV2Delivery, SearchV2Mode, SearchV2Request};
+
+fn search_v2_request(mode: SearchV2Mode, delivery: SearchV2Delivery) -> SearchV2Request {
+	SearchV2Request {
+		search: SearchRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			read_profile: "private_only".to_string(),
+			payload_level: Default::default(),
+			query: "Unified retrieval".to_string(),
+			top_k: Some(5),
+			candidate_k: Some(10),
+			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
+			record_hits: Some(false),
+			ranking: None,
+			session_id: None,
+			session_mode: None,
+		},
+		mode,
+		delivery,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn search_v2_raw_and_session_deliveries_rank_the_same_notes() {
+	let providers = tests_helpers::build_providers(StubRerank);
+	let Some(context) = tests_helpers::setup_context(
+		"search_v2_raw_and_session_deliveries_rank_the_same_notes",
+		providers,
+	)
+	.await
+	else {
+		return;
+	};
+	let note_id = Uuid::new_v4();
+	let chunk_id = Uuid::new_v4();
+	let note_text = "Unified retrieval keeps every search surface on one ranking path.";
+
+	tests_helpers::insert_note(
+		&context.service.db.pool,
+		note_id,
+		note_text,
+		&context.embedding_version,
+	)
+	.await;
+	tests_helpers::insert_chunk(
+		&context.service.db.pool,
+		chunk_id,
+		note_id,
+		0,
+		0,
+		note_text.len() as i32,
+		note_text,
+		&context.embedding_version,
+	)
+	.await;
+	tests_helpers::upsert_point(
+		&context.service,
+		chunk_id,
+		note_id,
+		0,
+		0,
+		note_text.len() as i32,
+		note_text,
+	)
+	.await;
+
+	let raw = context
+		.service
+		.search_v2(search_v2_request(SearchV2Mode::QuickFind, SearchV2Delivery::Raw))
+		.await
+		.expect("Raw search failed.");
+
+	assert!(raw.session.is_none());
+	assert!(raw.query_plan.is_none());
+	assert_eq!(raw.items.first().map(|item| item.note_id), Some(note_id));
+
+	let session = context
+		.service
+		.search_v2(search_v2_request(SearchV2Mode::QuickFind, SearchV2Delivery::Session))
+		.await
+		.expect("Session search failed.");
+	let stored = session.session.expect("Expected a stored search session.");
+
+	assert!(session.items.is_empty());
+	assert_eq!(stored.items.first().map(|item| item.note_id), Some(note_id));
+
+	let planned = context
+		.service
+		.search_v2(search_v2_request(SearchV2Mode::PlannedSearch, SearchV2Delivery::Raw))
+		.await
+		.expect("Planned search failed.");
+
+	assert!(planned.query_plan.is_some());
+
+	context.test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
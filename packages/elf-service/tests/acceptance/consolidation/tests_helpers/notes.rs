@@ -13,6 +13,7 @@ pub(in crate::acceptance::consolidation) async fn insert_source_note(
 			tenant_id: TENANT_ID.to_string(),
 			project_id: PROJECT_ID.to_string(),
 			agent_id: AGENT_ID.to_string(),
+			role: None,
 			scope: "agent_private".to_string(),
 			notes: vec![AddNoteInput {
 				r#type: "fact".to_string(),
@@ -23,11 +23,22 @@ pub(in crate::acceptance::consolidation) async fn process_consolidation_worker(
 		)
 		.expect("Failed to build docs Qdrant store."),
 		embedding,
+		embedding_scopes: Default::default(),
+		embedding_types: Default::default(),
 		chunking: ChunkingConfig {
 			max_tokens: service.cfg.chunking.max_tokens,
 			overlap_tokens: service.cfg.chunking.overlap_tokens,
 		},
+		chunking_per_type: Default::default(),
 		tokenizer,
+		url_snapshots: None,
+		egress: None,
+		qdrant_maintenance: None,
+		trash_retention_days: None,
+		note_consolidation: None,
+		lifecycle: None,
+		importance_rescore: None,
+		indexing_concurrency: 1,
 	};
 
 	worker::process_once(&worker_state).await.expect("consolidation worker should process once");
@@ -36,9 +36,24 @@ pub(crate) async fn spawn_doc_worker(service: &ElfService) -> (JoinHandle<()>, S
 			dimensions: 4_096,
 			timeout_ms: 1_000,
 			default_headers: Map::new(),
+			query_input: None,
+			model_dir: None,
+			resilience: None,
+			cache: None,
 		},
+		embedding_scopes: Default::default(),
+		embedding_types: Default::default(),
 		chunking: ChunkingConfig { max_tokens: 64, overlap_tokens: 8 },
+		chunking_per_type: Default::default(),
 		tokenizer: build_test_tokenizer(),
+		url_snapshots: None,
+		egress: None,
+		qdrant_maintenance: None,
+		trash_retention_days: None,
+		note_consolidation: None,
+		lifecycle: None,
+		importance_rescore: None,
+		indexing_concurrency: 1,
 	};
 	let handle = tokio::spawn(async move {
 		let _ = worker::run_worker(worker_state).await;
@@ -3,8 +3,8 @@ use uuid::Uuid;
 
 use crate::acceptance::docs_extension_v1::{self, DocsContext};
 use elf_service::{
-	AddNoteInput, AddNoteRequest, DocsExcerptsGetRequest, DocsSearchL0Request, ElfService,
-	PayloadLevel, SearchRequest,
+	AddNoteInput, AddNoteRequest, DocsExcerptsGetRequest, DocsSearchL0Request, DocsSearchRequest,
+	ElfService, PayloadLevel, SearchRequest,
 };
 
 #[tokio::test]
@@ -83,6 +83,79 @@ async fn docs_search_l0_returns_pointer_and_explain_trajectory() {
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
 
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL (or ELF_QDRANT_GRPC_URL) to run."]
+async fn docs_search_returns_bounded_excerpts_with_doc_metadata() {
+	let Some(ctx) = docs_extension_v1::setup_docs_context().await else { return };
+	let DocsContext { test_db, service } = ctx;
+	let doc = docs_extension_v1::put_test_doc(&service).await;
+	let (handle, shutdown) = docs_extension_v1::spawn_doc_worker(&service).await;
+
+	assert!(
+		docs_extension_v1::wait_for_doc_outbox_done(
+			&service.db.pool,
+			doc.doc_id,
+			std::time::Duration::from_secs(15)
+		)
+		.await,
+		"Expected doc outbox to reach DONE."
+	);
+
+	let results = service
+		.docs_search(DocsSearchRequest {
+			search: DocsSearchL0Request {
+				tenant_id: "t".to_string(),
+				project_id: "p".to_string(),
+				caller_agent_id: "reader".to_string(),
+				scope: None,
+				status: None,
+				doc_type: None,
+				sparse_mode: None,
+				domain: None,
+				repo: None,
+				agent_id: None,
+				thread_id: None,
+				updated_after: None,
+				updated_before: None,
+				ts_gte: None,
+				ts_lte: None,
+				read_profile: "private_plus_project".to_string(),
+				query: "peregrine".to_string(),
+				top_k: Some(5),
+				candidate_k: Some(20),
+				explain: None,
+			},
+			level: Some("L0".to_string()),
+		})
+		.await
+		.expect("Failed to search docs.");
+	let item = results.items.first().expect("Expected a docs_search hit.");
+
+	assert_eq!(item.hit.doc_id, doc.doc_id);
+	assert_eq!(item.title.as_deref(), Some("Docs v1"));
+	assert_eq!(item.excerpt.level, "L0");
+	assert!(item.excerpt.text.len() <= 256);
+	assert!(item.excerpt.text.contains("peregrine"));
+	assert_eq!(
+		docs_extension_v1::TEST_CONTENT.get(item.excerpt.start_offset..item.excerpt.end_offset),
+		Some(item.excerpt.text.as_str())
+	);
+	assert_eq!(
+		item.excerpt.excerpt_hash,
+		blake3::hash(item.excerpt.text.as_bytes()).to_hex().to_string()
+	);
+
+	let _ = shutdown.send(());
+
+	handle.abort();
+
+	let _ = handle.await;
+
+	drop(service);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
 #[tokio::test]
 #[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL (or ELF_QDRANT_GRPC_URL) to run."]
 async fn docs_search_l0_note_pointer_roundtrip_hydrates_doc() {
@@ -127,8 +200,15 @@ async fn docs_search_l0_note_pointer_roundtrip_hydrates_doc() {
 			top_k: Some(5),
 			candidate_k: Some(20),
 			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
 			record_hits: Some(false),
 			ranking: None,
+			session_id: None,
+			session_mode: None,
 		})
 		.await
 		.expect("Failed to search note with doc pointer source_ref.");
@@ -211,6 +291,7 @@ async fn add_note_with_pointer_source_ref(service: &ElfService, source_ref: Valu
 			tenant_id: "t".to_string(),
 			project_id: "p".to_string(),
 			agent_id: "agent".to_string(),
+			role: None,
 			scope: "agent_private".to_string(),
 			notes: vec![AddNoteInput {
 				r#type: "fact".to_string(),
@@ -11,9 +11,11 @@ async fn rejects_non_english_in_add_event() {
 		tenant_id: "t".to_string(),
 		project_id: "p".to_string(),
 		agent_id: "a".to_string(),
+		role: None,
 		scope: Some("agent_private".to_string()),
 		dry_run: Some(true),
 		ingestion_profile: None,
+		locale: None,
 		messages: vec![EventMessage {
 			role: "user".to_string(),
 			content: "こんにちは".to_string(),
@@ -44,9 +46,11 @@ async fn rejects_cyrillic_in_add_event() {
 		tenant_id: "t".to_string(),
 		project_id: "p".to_string(),
 		agent_id: "a".to_string(),
+		role: None,
 		scope: Some("agent_private".to_string()),
 		dry_run: Some(true),
 		ingestion_profile: None,
+		locale: None,
 		messages: vec![EventMessage {
 			role: "user".to_string(),
 			content: "Это не английский текст.".to_string(),
@@ -11,6 +11,7 @@ async fn rejects_non_english_in_add_note() {
 		tenant_id: "t".to_string(),
 		project_id: "p".to_string(),
 		agent_id: "a".to_string(),
+		role: None,
 		scope: "agent_private".to_string(),
 		notes: vec![AddNoteInput {
 			r#type: "fact".to_string(),
@@ -46,6 +47,7 @@ async fn rejects_cyrillic_in_add_note() {
 		tenant_id: "t".to_string(),
 		project_id: "p".to_string(),
 		agent_id: "a".to_string(),
+		role: None,
 		scope: "agent_private".to_string(),
 		notes: vec![AddNoteInput {
 			r#type: "fact".to_string(),
@@ -18,8 +18,15 @@ async fn rejects_non_english_in_search() {
 		top_k: Some(5),
 		candidate_k: Some(10),
 		filter: None,
+		types: Vec::new(),
+		updated_after: None,
+		updated_before: None,
+		min_importance: None,
+		max_result_tokens: None,
 		record_hits: Some(false),
 		ranking: None,
+		session_id: None,
+		session_mode: None,
 	};
 	let result = fixture.service.search(request).await;
 
@@ -50,8 +57,15 @@ async fn rejects_cyrillic_in_search() {
 		top_k: Some(5),
 		candidate_k: Some(10),
 		filter: None,
+		types: Vec::new(),
+		updated_after: None,
+		updated_before: None,
+		min_importance: None,
+		max_result_tokens: None,
 		record_hits: Some(false),
 		ranking: None,
+		session_id: None,
+		session_mode: None,
 	};
 	let result = fixture.service.search(request).await;
 
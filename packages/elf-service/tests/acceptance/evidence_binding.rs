@@ -1,7 +1,10 @@
 use std::sync::{Arc, atomic::AtomicUsize};
 
 use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
-use elf_domain::memory_policy::MemoryPolicyDecision;
+use elf_domain::{
+	memory_policy::MemoryPolicyDecision,
+	writegate::{RejectDetail, RejectFix},
+};
 use elf_service::{
 	AddEventRequest, EventMessage, NoteOp, Providers, REJECT_EVIDENCE_MISMATCH,
 	REJECT_WRITE_POLICY_MISMATCH,
@@ -62,9 +65,11 @@ async fn rejects_invalid_evidence_quote() {
 		tenant_id: "t".to_string(),
 		project_id: "p".to_string(),
 		agent_id: "a".to_string(),
+		role: None,
 		scope: Some("agent_private".to_string()),
 		dry_run: Some(false),
 		ingestion_profile: None,
+		locale: None,
 		messages: vec![EventMessage {
 			role: "user".to_string(),
 			content: "This is a message without the expected quote.".to_string(),
@@ -81,6 +86,14 @@ async fn rejects_invalid_evidence_quote() {
 	assert_eq!(result.reason_code.as_deref(), Some(REJECT_EVIDENCE_MISMATCH));
 	assert_eq!(result.policy_decision, MemoryPolicyDecision::Reject);
 
+	let feedback = result.reject_feedback.as_ref().expect("Expected reject feedback.");
+
+	assert_eq!(feedback.suggested_fix, RejectFix::QuoteVerbatim);
+	assert_eq!(
+		feedback.detail,
+		RejectDetail::EvidenceQuoteNotFound { quote_index: 0, message_index: 0 }
+	);
+
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
 
@@ -143,9 +156,11 @@ async fn rejects_transformed_quote_mismatch_with_write_policy() {
 		tenant_id: "t".to_string(),
 		project_id: "p".to_string(),
 		agent_id: "a".to_string(),
+		role: None,
 		scope: Some("agent_private".to_string()),
 		dry_run: Some(false),
 		ingestion_profile: None,
+		locale: None,
 		messages: vec![EventMessage {
 			role: "user".to_string(),
 			content: "Alice mentors Bob.".to_string(),
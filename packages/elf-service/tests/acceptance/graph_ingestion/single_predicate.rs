@@ -6,7 +6,8 @@ use crate::acceptance::graph_ingestion::tests_helpers::{
 	self, GRAPH_REL_SUBJECT, TEST_PROJECT, TEST_SCOPE, TEST_TENANT,
 };
 use elf_service::{
-	GraphQueryEntityRef, GraphQueryPredicateRef, GraphQueryRequest, RelationTemporalStatus,
+	GraphFactsAsOfRequest, GraphQueryEntityRef, GraphQueryPredicateRef, GraphQueryRequest,
+	RelationTemporalStatus,
 };
 
 #[derive(Debug, FromRow)]
@@ -33,6 +34,24 @@ fn works_at_graph_query_request(as_of: OffsetDateTime) -> GraphQueryRequest {
 	}
 }
 
+fn alice_facts_as_of_request(
+	as_of: OffsetDateTime,
+	include_superseded: Option<bool>,
+) -> GraphFactsAsOfRequest {
+	GraphFactsAsOfRequest {
+		tenant_id: TEST_TENANT.to_string(),
+		project_id: TEST_PROJECT.to_string(),
+		agent_id: "a".to_string(),
+		read_profile: "private_only".to_string(),
+		entity: GraphQueryEntityRef::Surface { surface: "Alice".to_string() },
+		as_of,
+		predicate: Some(GraphQueryPredicateRef::Surface { surface: "works at".to_string() }),
+		scopes: Some(vec![TEST_SCOPE.to_string()]),
+		include_superseded,
+		limit: Some(10),
+	}
+}
+
 async fn graph_fact_row(pool: &PgPool, predicate: &str, object_value: &str) -> GraphFactRow {
 	sqlx::query_as::<_, GraphFactRow>(
 		"\
@@ -240,3 +259,79 @@ async fn add_note_single_predicate_supersedes_conflicting_fact() {
 
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn graph_facts_as_of_returns_superseded_facts_with_replacements() {
+	let Some(test_db) = tests_helpers::build_test_db(
+		"graph_facts_as_of_returns_superseded_facts_with_replacements",
+	)
+	.await
+	else {
+		return;
+	};
+	let service = tests_helpers::build_stub_service(&test_db).await;
+
+	tests_helpers::reset_service_db(&service).await;
+
+	let old_note_id = tests_helpers::add_fact_note(
+		&service,
+		"employment-a",
+		"Alice works at Initech.",
+		"works at",
+		"Initech",
+	)
+	.await;
+	let fact_a = graph_fact_row(&service.db.pool, "works at", "Initech").await;
+
+	activate_single_predicate(
+		&service.db.pool,
+		fact_a.predicate_id.expect("Expected predicate_id."),
+	)
+	.await;
+
+	tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+
+	tests_helpers::add_fact_note(
+		&service,
+		"employment-b",
+		"Alice works at Globex.",
+		"works at",
+		"Globex",
+	)
+	.await;
+
+	let fact_b = graph_fact_row(&service.db.pool, "works at", "Globex").await;
+	let t_before = fact_b.valid_from - time::Duration::microseconds(1);
+	let past = service
+		.graph_facts_as_of(alice_facts_as_of_request(t_before, None))
+		.await
+		.expect("as-of graph read failed.");
+
+	assert!(past.include_superseded);
+	assert_eq!(past.facts.len(), 1);
+	assert_eq!(past.facts[0].fact_id, fact_a.fact_id);
+	assert_eq!(past.facts[0].object.value.as_deref(), Some("Initech"));
+	assert_eq!(past.facts[0].temporal_status, RelationTemporalStatus::Historical);
+	assert_eq!(past.facts[0].superseded_at, Some(fact_b.valid_from));
+	assert_eq!(past.facts[0].superseded_by_fact_ids, vec![fact_b.fact_id]);
+	assert_eq!(past.facts[0].evidence_note_ids, vec![old_note_id]);
+
+	let without_superseded = service
+		.graph_facts_as_of(alice_facts_as_of_request(t_before, Some(false)))
+		.await
+		.expect("as-of graph read without superseded facts failed.");
+
+	assert!(without_superseded.facts.is_empty());
+
+	let current = service
+		.graph_facts_as_of(alice_facts_as_of_request(OffsetDateTime::now_utc(), None))
+		.await
+		.expect("current as-of graph read failed.");
+
+	assert_eq!(current.facts.len(), 1);
+	assert_eq!(current.facts[0].fact_id, fact_b.fact_id);
+	assert!(current.facts[0].superseded_by_fact_ids.is_empty());
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
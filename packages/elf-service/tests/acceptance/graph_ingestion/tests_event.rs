@@ -38,9 +38,11 @@ async fn add_event_persists_graph_relations() {
 			tenant_id: "t".to_string(),
 			project_id: "p".to_string(),
 			agent_id: "a".to_string(),
+			role: None,
 			scope: Some("agent_private".to_string()),
 			dry_run: Some(false),
 			ingestion_profile: None,
+			locale: None,
 			messages: vec![EventMessage {
 				role: "user".to_string(),
 				content: "Alice mentors Bob.".to_string(),
@@ -39,6 +39,7 @@ pub(in crate::acceptance::graph_ingestion) fn duplicate_fact_attaches_multiple_e
 		tenant_id: "t".to_string(),
 		project_id: "p".to_string(),
 		agent_id: "a".to_string(),
+		role: None,
 		scope: "agent_private".to_string(),
 		notes: vec![
 			AddNoteInput {
@@ -101,6 +102,7 @@ pub(in crate::acceptance::graph_ingestion) async fn add_fact_note(
 			tenant_id: TEST_TENANT.to_string(),
 			project_id: TEST_PROJECT.to_string(),
 			agent_id: "a".to_string(),
+			role: None,
 			scope: TEST_SCOPE.to_string(),
 			notes: vec![fact_note(key, text, predicate, object_value)],
 		})
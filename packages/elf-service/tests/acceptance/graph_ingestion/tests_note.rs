@@ -73,6 +73,7 @@ async fn add_note_invalid_relation_rejected_has_field_path() {
 			tenant_id: "t".to_string(),
 			project_id: "p".to_string(),
 			agent_id: "a".to_string(),
+			role: None,
 			scope: "agent_private".to_string(),
 			notes: vec![AddNoteInput {
 				r#type: "fact".to_string(),
@@ -126,6 +127,7 @@ async fn add_note_persists_graph_relations() {
 			tenant_id: "t".to_string(),
 			project_id: "p".to_string(),
 			agent_id: "a".to_string(),
+			role: None,
 			scope: "agent_private".to_string(),
 			notes: vec![AddNoteInput {
 				r#type: "fact".to_string(),
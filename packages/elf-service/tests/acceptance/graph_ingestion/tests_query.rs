@@ -2,7 +2,9 @@ use crate::acceptance::graph_ingestion::tests_helpers::{
 	self, TEST_PROJECT, TEST_SCOPE, TEST_TENANT,
 };
 use elf_service::{
-	DeleteRequest, GraphQueryEntityRef, GraphQueryPredicateRef, GraphQueryRequest, NoteOp,
+	AddNoteInput, AddNoteRequest, AdminGraphEntitiesMergeRequest, AdminGraphEntitySplitRequest,
+	DeleteRequest, ElfService, GraphNeighborhoodRequest, GraphQueryEntityRef,
+	GraphQueryPredicateRef, GraphQueryRequest, NoteOp, StructuredFields,
 };
 
 #[tokio::test]
@@ -49,6 +51,7 @@ async fn graph_query_suppresses_deleted_evidence_notes() {
 			tenant_id: TEST_TENANT.to_string(),
 			project_id: TEST_PROJECT.to_string(),
 			agent_id: "a".to_string(),
+			role: None,
 			note_id,
 		})
 		.await
@@ -79,3 +82,253 @@ async fn graph_query_suppresses_deleted_evidence_notes() {
 
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
+
+async fn add_entity_relation_note(
+	service: &ElfService,
+	key: &str,
+	text: &str,
+	subject: &str,
+	predicate: &str,
+	object: &str,
+) -> uuid::Uuid {
+	let structured = serde_json::from_value::<StructuredFields>(serde_json::json!({
+		"relations": [{
+			"subject": { "canonical": subject },
+			"predicate": predicate,
+			"object": { "entity": { "canonical": object } }
+		}]
+	}))
+	.expect("Failed to build structured fields.");
+	let response = service
+		.add_note(AddNoteRequest {
+			tenant_id: TEST_TENANT.to_string(),
+			project_id: TEST_PROJECT.to_string(),
+			agent_id: "a".to_string(),
+			role: None,
+			scope: TEST_SCOPE.to_string(),
+			notes: vec![AddNoteInput {
+				r#type: "fact".to_string(),
+				key: Some(key.to_string()),
+				text: text.to_string(),
+				structured: Some(structured),
+				importance: 0.8,
+				confidence: 0.9,
+				ttl_days: None,
+				source_ref: serde_json::json!({}),
+				write_policy: None,
+			}],
+		})
+		.await
+		.expect("add_note failed.");
+
+	assert_eq!(response.results[0].op, NoteOp::Add);
+
+	response.results[0].note_id.expect("Expected note_id.")
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn graph_neighborhood_walks_hops_with_evidence() {
+	let Some(test_db) =
+		tests_helpers::build_test_db("graph_neighborhood_walks_hops_with_evidence").await
+	else {
+		return;
+	};
+	let service = tests_helpers::build_stub_service(&test_db).await;
+
+	tests_helpers::reset_service_db(&service).await;
+
+	let mentors_note = add_entity_relation_note(
+		&service,
+		"mentorship",
+		"Alice mentors Bob.",
+		"Alice",
+		"mentors",
+		"Bob",
+	)
+	.await;
+	let manages_note = add_entity_relation_note(
+		&service,
+		"management",
+		"Bob manages Carol.",
+		"Bob",
+		"manages",
+		"Carol",
+	)
+	.await;
+	let request =
+		|depth: u32, predicates: Option<Vec<GraphQueryPredicateRef>>| GraphNeighborhoodRequest {
+			tenant_id: TEST_TENANT.to_string(),
+			project_id: TEST_PROJECT.to_string(),
+			agent_id: "a".to_string(),
+			read_profile: "private_only".to_string(),
+			entity: GraphQueryEntityRef::Surface { surface: "Alice".to_string() },
+			depth: Some(depth),
+			predicates,
+			scopes: None,
+			as_of: None,
+			limit: None,
+		};
+	let one_hop =
+		service.graph_neighborhood(request(1, None)).await.expect("one-hop neighborhood failed");
+
+	assert_eq!(one_hop.triples.len(), 1);
+	assert_eq!(one_hop.triples[0].predicate, "mentors");
+	assert_eq!(one_hop.triples[0].evidence_note_ids, vec![mentors_note]);
+
+	let two_hops =
+		service.graph_neighborhood(request(2, None)).await.expect("two-hop neighborhood failed");
+	let hops = two_hops
+		.triples
+		.iter()
+		.map(|triple| (triple.hop, triple.subject.canonical.as_str(), triple.predicate.as_str()))
+		.collect::<Vec<_>>();
+
+	assert_eq!(hops, vec![(1, "Alice", "mentors"), (2, "Bob", "manages")]);
+	assert_eq!(two_hops.triples[1].evidence_note_ids, vec![manages_note]);
+	assert_eq!(two_hops.entities.len(), 3);
+	assert!(!two_hops.truncated);
+
+	let filtered = service
+		.graph_neighborhood(request(
+			2,
+			Some(vec![GraphQueryPredicateRef::Surface { surface: "mentors".to_string() }]),
+		))
+		.await
+		.expect("filtered neighborhood failed");
+
+	assert_eq!(filtered.triples.len(), 1);
+	assert_eq!(filtered.predicates.len(), 1);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+async fn entity_id_by_canonical(service: &ElfService, canonical_norm: &str) -> uuid::Uuid {
+	sqlx::query_scalar(
+		"SELECT entity_id FROM graph_entities WHERE tenant_id = $1 AND project_id = $2 AND canonical_norm = $3",
+	)
+	.bind(TEST_TENANT)
+	.bind(TEST_PROJECT)
+	.bind(canonical_norm)
+	.fetch_one(&service.db.pool)
+	.await
+	.expect("Failed to load graph entity.")
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn admin_entity_merge_and_split_repoint_facts() {
+	let Some(test_db) =
+		tests_helpers::build_test_db("admin_entity_merge_and_split_repoint_facts").await
+	else {
+		return;
+	};
+	let service = tests_helpers::build_stub_service(&test_db).await;
+
+	tests_helpers::reset_service_db(&service).await;
+
+	let alice_postgres = add_entity_relation_note(
+		&service,
+		"alice-db",
+		"Alice uses Postgres.",
+		"Alice",
+		"uses",
+		"Postgres",
+	)
+	.await;
+	let alice_postgresql = add_entity_relation_note(
+		&service,
+		"alice-db-full",
+		"Alice uses PostgreSQL.",
+		"Alice",
+		"uses",
+		"PostgreSQL",
+	)
+	.await;
+
+	add_entity_relation_note(
+		&service,
+		"bob-db",
+		"Bob uses PostgreSQL.",
+		"Bob",
+		"uses",
+		"PostgreSQL",
+	)
+	.await;
+
+	let winner = entity_id_by_canonical(&service, "postgres").await;
+	let loser = entity_id_by_canonical(&service, "postgresql").await;
+	let merged = service
+		.admin_graph_entities_merge(AdminGraphEntitiesMergeRequest {
+			tenant_id: TEST_TENANT.to_string(),
+			project_id: TEST_PROJECT.to_string(),
+			agent_id: "a".to_string(),
+			winner_entity_id: winner,
+			loser_entity_ids: vec![loser],
+			reason: Some("Extractor drift.".to_string()),
+		})
+		.await
+		.expect("entity merge failed");
+
+	assert_eq!(merged.merged_entity_ids, vec![loser]);
+	assert_eq!(merged.entity.aliases, vec!["PostgreSQL".to_string()]);
+	assert_eq!(merged.deduplicated_facts.len(), 1);
+	assert_eq!(merged.repointed_fact_ids.len(), 1);
+
+	let query = |subject: &str| GraphQueryRequest {
+		tenant_id: TEST_TENANT.to_string(),
+		project_id: TEST_PROJECT.to_string(),
+		agent_id: "a".to_string(),
+		read_profile: "private_only".to_string(),
+		subject: GraphQueryEntityRef::Surface { surface: subject.to_string() },
+		predicate: None,
+		scopes: None,
+		as_of: None,
+		limit: None,
+		explain: None,
+	};
+	let alice = service.graph_query(query("Alice")).await.expect("graph query failed");
+
+	assert_eq!(alice.facts.len(), 1);
+	assert_eq!(alice.facts[0].object.entity.as_ref().map(|entity| entity.entity_id), Some(winner));
+	assert_eq!(alice.facts[0].evidence_note_ids, vec![alice_postgres, alice_postgresql]);
+
+	let bob_fact_id = merged.repointed_fact_ids[0];
+	let split = service
+		.admin_graph_entity_split(AdminGraphEntitySplitRequest {
+			tenant_id: TEST_TENANT.to_string(),
+			project_id: TEST_PROJECT.to_string(),
+			agent_id: "a".to_string(),
+			entity_id: winner,
+			canonical: "PostgreSQL".to_string(),
+			kind: None,
+			aliases: vec!["postgresql".to_string()],
+			fact_ids: vec![bob_fact_id],
+			reason: None,
+		})
+		.await
+		.expect("entity split failed");
+
+	assert!(split.source.aliases.is_empty());
+	assert_eq!(split.entity.aliases, vec!["PostgreSQL".to_string()]);
+	assert_eq!(split.moved_fact_ids, vec![bob_fact_id]);
+
+	let bob = service.graph_query(query("Bob")).await.expect("graph query failed");
+
+	assert_eq!(
+		bob.facts[0].object.entity.as_ref().map(|entity| entity.entity_id),
+		Some(split.entity.entity_id)
+	);
+
+	let events: Vec<String> = sqlx::query_scalar(
+		"SELECT event_type FROM graph_entity_events WHERE entity_id = $1 ORDER BY ts",
+	)
+	.bind(winner)
+	.fetch_all(&service.db.pool)
+	.await
+	.expect("Failed to load graph entity events.");
+
+	assert_eq!(events, vec!["merged".to_string(), "split".to_string()]);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -9,6 +9,7 @@ pub(super) async fn insert_source_note(service: &ElfService, key: &str, text: &s
 			tenant_id: TENANT_ID.to_string(),
 			project_id: PROJECT_ID.to_string(),
 			agent_id: AGENT_ID.to_string(),
+			role: None,
 			scope: "agent_private".to_string(),
 			notes: vec![AddNoteInput {
 				r#type: "fact".to_string(),
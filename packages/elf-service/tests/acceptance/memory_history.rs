@@ -3,10 +3,12 @@ use std::{
 	sync::{Arc, atomic::AtomicUsize},
 };
 
+use uuid::Uuid;
+
 use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
 use elf_service::{
-	AddNoteInput, AddNoteRequest, MemoryHistoryGetRequest, NoteOp, NoteProvenanceGetRequest,
-	Providers,
+	AddNoteInput, AddNoteRequest, ElfService, MemoryHistoryGetRequest, NoteHistoryRequest, NoteOp,
+	NoteProvenanceGetRequest, Providers, TextDiffKind,
 };
 
 fn history_request(text: &str, importance: f32) -> AddNoteRequest {
@@ -14,6 +16,7 @@ fn history_request(text: &str, importance: f32) -> AddNoteRequest {
 		tenant_id: "tenant-history".to_string(),
 		project_id: "project-history".to_string(),
 		agent_id: "agent-history".to_string(),
+		role: None,
 		scope: "agent_private".to_string(),
 		notes: vec![AddNoteInput {
 			r#type: "fact".to_string(),
@@ -29,6 +32,51 @@ fn history_request(text: &str, importance: f32) -> AddNoteRequest {
 	}
 }
 
+async fn assert_note_history(service: &ElfService, note_id: Uuid) {
+	let note_history = service
+		.note_history(NoteHistoryRequest {
+			tenant_id: "tenant-history".to_string(),
+			project_id: "project-history".to_string(),
+			agent_id: "agent-history".to_string(),
+			note_id,
+		})
+		.await
+		.expect("note history should be readable by the owner");
+
+	assert!(!note_history.truncated);
+	assert_eq!(
+		note_history.versions.iter().map(|version| version.op.as_str()).collect::<Vec<_>>(),
+		vec!["ADD", "UPDATE"]
+	);
+
+	let update = &note_history.versions[1];
+	let rebuilt_prev = update
+		.text_diff
+		.iter()
+		.filter(|span| span.kind != TextDiffKind::Insert)
+		.map(|span| span.text.as_str())
+		.collect::<String>();
+
+	assert_eq!(rebuilt_prev, "Fact: Memory history readback starts with original evidence.");
+	assert_eq!(
+		update.text.as_deref(),
+		Some("Fact: Memory history readback records updated evidence.")
+	);
+	assert!(update.changed_fields.iter().any(|field| field == "text"));
+	assert!(update.changed_fields.iter().any(|field| field == "importance"));
+
+	let foreign = service
+		.note_history(NoteHistoryRequest {
+			tenant_id: "tenant-history".to_string(),
+			project_id: "project-history".to_string(),
+			agent_id: "agent-other".to_string(),
+			note_id,
+		})
+		.await;
+
+	assert!(foreign.is_err(), "Private note history must not be readable by other agents.");
+}
+
 #[tokio::test]
 #[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
 async fn memory_history_links_versions_and_ignored_decisions() {
@@ -134,5 +182,93 @@ async fn memory_history_links_versions_and_ignored_decisions() {
 
 	assert_eq!(provenance.history.len(), history.events.len());
 
+	assert_note_history(&service, note_id).await;
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn memory_history_coalesces_rapid_updates_by_same_actor() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping memory_history_coalesces_rapid_updates_by_same_actor; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping memory_history_coalesces_rapid_updates_by_same_actor; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_history_coalesce");
+	let docs_collection = test_db.collection_name("elf_history_coalesce_docs");
+	let mut cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+
+	cfg.memory.version_coalesce_window_ms = Some(60_000);
+
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let first = service
+		.add_note(history_request("Fact: Coalesced history starts with the first draft.", 0.7))
+		.await
+		.expect("initial note should be added");
+	let note_id = first.results[0].note_id.expect("add should return note id");
+	let second = service
+		.add_note(history_request("Fact: Coalesced history records the second draft.", 0.7))
+		.await
+		.expect("second note should update by key");
+	let third = service
+		.add_note(history_request("Fact: Coalesced history keeps the final draft.", 0.7))
+		.await
+		.expect("third note should update by key");
+
+	assert_eq!(second.results[0].op, NoteOp::Update);
+	assert_eq!(third.results[0].op, NoteOp::Update);
+
+	let versions: Vec<(serde_json::Value, serde_json::Value, String)> = sqlx::query_as(
+		"SELECT prev_snapshot, new_snapshot, reason FROM memory_note_versions WHERE note_id = $1 AND op = 'UPDATE'",
+	)
+	.bind(note_id)
+	.fetch_all(&service.db.pool)
+	.await
+	.expect("update versions should be queryable");
+
+	assert_eq!(versions.len(), 1);
+
+	let (prev_snapshot, new_snapshot, reason) = &versions[0];
+
+	assert_eq!(prev_snapshot["text"], "Fact: Coalesced history starts with the first draft.");
+	assert_eq!(new_snapshot["text"], "Fact: Coalesced history keeps the final draft.");
+	assert_eq!(reason, "add_note;coalesced=2");
+
+	let pending_upserts: i64 = sqlx::query_scalar(
+		"SELECT count(*) FROM indexing_outbox WHERE note_id = $1 AND op = 'UPSERT' AND status = 'PENDING'",
+	)
+	.bind(note_id)
+	.fetch_one(&service.db.pool)
+	.await
+	.expect("pending outbox count should be queryable");
+
+	assert_eq!(pending_upserts, 2);
+
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
@@ -0,0 +1,338 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_config::{SecurityAuthKey, SecurityAuthRole};
+use elf_service::{AddNoteInput, AddNoteRequest, NoteFetchRequest, Providers};
+
+const TENANT_ID: &str = "tenant-access";
+const PROJECT_ID: &str = "project-access";
+const AGENT_ID: &str = "agent-access";
+const OTHER_AGENT_ID: &str = "agent-access-other";
+const SUPER_ADMIN_TOKEN_ID: &str = "access-super-admin";
+
+fn note_request(scope: &str) -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: AGENT_ID.to_string(),
+		role: None,
+		scope: scope.to_string(),
+		notes: vec![AddNoteInput {
+			r#type: "fact".to_string(),
+			key: Some("deploy_cadence".to_string()),
+			text: "Fact: Deploys ship every Tuesday afternoon.".to_string(),
+			structured: None,
+			importance: 0.6,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({ "schema": "acceptance/access_stats" }),
+			write_policy: None,
+		}],
+	}
+}
+
+async fn insert_hit(pool: &PgPool, note_id: Uuid, rank: i32, ts: OffsetDateTime) {
+	sqlx::query(
+		"\
+INSERT INTO memory_hits (hit_id, note_id, chunk_id, query_hash, rank, final_score, ts)
+VALUES ($1, $2, NULL, 'query-hash', $3, 0.5, $4)",
+	)
+	.bind(Uuid::new_v4())
+	.bind(note_id)
+	.bind(rank)
+	.bind(ts)
+	.execute(pool)
+	.await
+	.expect("Failed to insert hit.");
+}
+
+async fn insert_trace_item(pool: &PgPool, trace_id: Uuid, note_id: Uuid, rank: i32) {
+	sqlx::query(
+		"\
+INSERT INTO search_trace_items (item_id, trace_id, note_id, chunk_id, rank, final_score, explain)
+VALUES ($1, $2, $3, NULL, $4, 0.5, '{}'::jsonb)",
+	)
+	.bind(Uuid::new_v4())
+	.bind(trace_id)
+	.bind(note_id)
+	.bind(rank)
+	.execute(pool)
+	.await
+	.expect("Failed to insert trace item.");
+}
+
+async fn insert_traced_retrieval(
+	pool: &PgPool,
+	project_id: &str,
+	agent_id: &str,
+	note_id: Uuid,
+	query: &str,
+	rank: i32,
+	created_at: OffsetDateTime,
+) -> Uuid {
+	let trace_id = Uuid::new_v4();
+
+	sqlx::query(
+		"\
+INSERT INTO search_traces (
+	trace_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	read_profile,
+	query,
+	expansion_mode,
+	expanded_queries,
+	allowed_scopes,
+	candidate_count,
+	top_k,
+	config_snapshot,
+	trace_version,
+	created_at,
+	expires_at
+)
+VALUES ($1, $2, $3, $4, 'private_only', $5, 'off', $6, $7, 10, 5, '{}'::jsonb, 1, $8, $9)",
+	)
+	.bind(trace_id)
+	.bind(TENANT_ID)
+	.bind(project_id)
+	.bind(agent_id)
+	.bind(query)
+	.bind(serde_json::json!([query]))
+	.bind(serde_json::json!(["agent_private"]))
+	.bind(created_at)
+	.bind(created_at + Duration::days(7))
+	.execute(pool)
+	.await
+	.expect("Failed to insert trace.");
+	insert_trace_item(pool, trace_id, note_id, rank).await;
+
+	trace_id
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn note_access_stats_report_hits_queries_and_retrieval_window() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!(
+			"Skipping note_access_stats_report_hits_queries_and_retrieval_window; set ELF_PG_DSN."
+		);
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping note_access_stats_report_hits_queries_and_retrieval_window; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_note_access_stats");
+	let docs_collection = test_db.collection_name("elf_note_access_stats_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let added = service.add_note(note_request("agent_private")).await.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected an added note id.");
+	let first = OffsetDateTime::from_unix_timestamp(1_700_000_000).expect("timestamp");
+	let second = first + Duration::hours(1);
+	let third = first + Duration::hours(2);
+
+	for (rank, ts) in [(2, first), (1, second), (4, third)] {
+		insert_hit(&service.db.pool, note_id, rank, ts).await;
+	}
+
+	sqlx::query("UPDATE memory_notes SET hit_count = 3, last_hit_at = $2 WHERE note_id = $1")
+		.bind(note_id)
+		.bind(third)
+		.execute(&service.db.pool)
+		.await
+		.expect("Failed to update hit counters.");
+
+	let pool = &service.db.pool;
+
+	insert_traced_retrieval(pool, PROJECT_ID, AGENT_ID, note_id, "deploy cadence", 2, first).await;
+	insert_traced_retrieval(pool, PROJECT_ID, AGENT_ID, note_id, "deploy cadence", 1, second).await;
+	insert_traced_retrieval(pool, PROJECT_ID, AGENT_ID, note_id, "release owner", 4, third).await;
+	// Traces from another project never contribute queries.
+	insert_traced_retrieval(pool, "project-other", AGENT_ID, note_id, "other project", 1, third)
+		.await;
+
+	let fetched = service
+		.get_note(NoteFetchRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			token_id: None,
+			note_id,
+			include_access_stats: true,
+		})
+		.await
+		.expect("Failed to fetch note.");
+	let stats = fetched.access_stats.expect("Expected access stats.");
+
+	assert_eq!(stats.hit_count, 3);
+	assert_eq!(stats.last_hit_at, Some(third));
+	assert_eq!(stats.first_retrieved_at, Some(first));
+	assert_eq!(stats.last_retrieved_at, Some(third));
+
+	let queries = stats
+		.recent_queries
+		.iter()
+		.map(|query| {
+			(query.query.as_str(), query.retrievals, query.best_rank, query.last_retrieved_at)
+		})
+		.collect::<Vec<_>>();
+
+	assert_eq!(queries, vec![("release owner", 1, 4, third), ("deploy cadence", 2, 1, second)]);
+
+	let without_stats = service
+		.get_note(NoteFetchRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			token_id: None,
+			note_id,
+			include_access_stats: false,
+		})
+		.await
+		.expect("Failed to fetch note.");
+
+	assert!(without_stats.access_stats.is_none());
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn note_access_stats_hide_other_agents_private_traces() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping note_access_stats_hide_other_agents_private_traces; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping note_access_stats_hide_other_agents_private_traces; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_note_access_stats");
+	let docs_collection = test_db.collection_name("elf_note_access_stats_docs");
+	let mut cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+
+	// The trace admin override comes from super_admin tokens, which require static keys.
+	cfg.security.auth_mode = "static_keys".to_string();
+	cfg.security.auth_keys = vec![SecurityAuthKey {
+		token_id: SUPER_ADMIN_TOKEN_ID.to_string(),
+		token: "access-super-admin-token".to_string(),
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: Some(AGENT_ID.to_string()),
+		read_profile: "all_scopes".to_string(),
+		role: SecurityAuthRole::SuperAdmin,
+	}];
+
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let added =
+		service.add_note(note_request("project_shared")).await.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected an added note id.");
+	let pool = &service.db.pool;
+	let first = OffsetDateTime::from_unix_timestamp(1_700_000_000).expect("timestamp");
+	let second = first + Duration::hours(1);
+	let third = first + Duration::hours(2);
+
+	insert_traced_retrieval(pool, PROJECT_ID, AGENT_ID, note_id, "owner query", 1, first).await;
+	// Another agent's trace that only returned shared notes is visible to the owner.
+	insert_traced_retrieval(pool, PROJECT_ID, OTHER_AGENT_ID, note_id, "shared query", 2, second)
+		.await;
+
+	// Another agent's trace that also returned one of its private notes stays hidden.
+	let private_trace = insert_traced_retrieval(
+		pool,
+		PROJECT_ID,
+		OTHER_AGENT_ID,
+		note_id,
+		"private query",
+		1,
+		third,
+	)
+	.await;
+
+	insert_trace_item(pool, private_trace, Uuid::new_v4(), 2).await;
+
+	let fetched = service
+		.get_note(NoteFetchRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			token_id: None,
+			note_id,
+			include_access_stats: true,
+		})
+		.await
+		.expect("Failed to fetch note.");
+	let stats = fetched.access_stats.expect("Expected access stats.");
+	let queries = stats.recent_queries.iter().map(|query| query.query.as_str()).collect::<Vec<_>>();
+
+	assert_eq!(queries, vec!["shared query", "owner query"]);
+
+	let fetched = service
+		.get_note(NoteFetchRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			token_id: Some(SUPER_ADMIN_TOKEN_ID.to_string()),
+			note_id,
+			include_access_stats: true,
+		})
+		.await
+		.expect("Failed to fetch note.");
+	let stats = fetched.access_stats.expect("Expected access stats.");
+	let queries = stats.recent_queries.iter().map(|query| query.query.as_str()).collect::<Vec<_>>();
+
+	// A super_admin token sees every trace in the project, like trace reads.
+	assert_eq!(queries, vec!["private query", "shared query", "owner query"]);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
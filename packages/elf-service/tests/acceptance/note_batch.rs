@@ -0,0 +1,152 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use uuid::Uuid;
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, DeleteBatchRequest, NoteOp, Providers, UpdateBatchItem,
+	UpdateBatchRequest,
+};
+
+fn note_input(key: &str, text: &str) -> AddNoteInput {
+	AddNoteInput {
+		r#type: "fact".to_string(),
+		key: Some(key.to_string()),
+		text: text.to_string(),
+		structured: None,
+		importance: 0.6,
+		confidence: 0.9,
+		ttl_days: None,
+		source_ref: serde_json::json!({ "schema": "acceptance/batch" }),
+		write_policy: None,
+	}
+}
+
+fn update_item(note_id: Uuid, text: &str) -> UpdateBatchItem {
+	UpdateBatchItem {
+		note_id,
+		text: Some(text.to_string()),
+		importance: None,
+		confidence: None,
+		ttl_days: None,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn batch_update_and_delete_report_per_note_results() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping batch_update_and_delete_report_per_note_results; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!("Skipping batch_update_and_delete_report_per_note_results; set ELF_QDRANT_URL.");
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_note_batch");
+	let docs_collection = test_db.collection_name("elf_note_batch_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let added = service
+		.add_note(AddNoteRequest {
+			tenant_id: "tenant-batch".to_string(),
+			project_id: "project-batch".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			scope: "agent_private".to_string(),
+			notes: vec![
+				note_input("batch_first", "Fact: The first batch note is editable."),
+				note_input("batch_second", "Fact: The second batch note is editable."),
+			],
+		})
+		.await
+		.expect("Failed to add notes.");
+	let first = added.results[0].note_id.expect("Expected the first note id.");
+	let second = added.results[1].note_id.expect("Expected the second note id.");
+	let missing = Uuid::new_v4();
+	let updated = service
+		.update_batch(UpdateBatchRequest {
+			tenant_id: "tenant-batch".to_string(),
+			project_id: "project-batch".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			items: vec![
+				update_item(first, "Fact: The first batch note was edited in a batch."),
+				update_item(missing, "Fact: This note does not exist."),
+				update_item(second, "Fact: The second batch note was edited in a batch."),
+			],
+		})
+		.await
+		.expect("Failed to update notes.");
+
+	assert_eq!(updated.applied, 2);
+	assert_eq!(updated.rejected, 1);
+	assert_eq!(
+		updated.results.iter().map(|result| result.op).collect::<Vec<_>>(),
+		vec![NoteOp::Update, NoteOp::Rejected, NoteOp::Update]
+	);
+	assert_eq!(updated.results[1].note_id, missing);
+	assert_eq!(updated.results[1].reason_code.as_deref(), Some("INVALID_REQUEST"));
+
+	let deleted = service
+		.delete_batch(DeleteBatchRequest {
+			tenant_id: "tenant-batch".to_string(),
+			project_id: "project-batch".to_string(),
+			agent_id: "agent-other".to_string(),
+			role: None,
+			note_ids: vec![first],
+		})
+		.await
+		.expect("Failed to run batch delete.");
+
+	assert_eq!(deleted.results[0].op, NoteOp::Rejected);
+
+	let deleted = service
+		.delete_batch(DeleteBatchRequest {
+			tenant_id: "tenant-batch".to_string(),
+			project_id: "project-batch".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			note_ids: vec![first, second],
+		})
+		.await
+		.expect("Failed to delete notes.");
+
+	assert_eq!(deleted.applied, 2);
+
+	let (trashed, delete_jobs): (i64, i64) = sqlx::query_as(
+		"\
+SELECT
+	(SELECT count(*) FROM memory_notes WHERE note_id = ANY($1) AND status = 'trashed'),
+	(SELECT count(*) FROM indexing_outbox WHERE note_id = ANY($1) AND op = 'DELETE')",
+	)
+	.bind(vec![first, second])
+	.fetch_one(&service.db.pool)
+	.await
+	.expect("Failed to load batch state.");
+
+	assert_eq!(trashed, 2);
+	assert_eq!(delete_jobs, 2);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -0,0 +1,154 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, DeleteRequest, ElfService, NoteEvent, NoteEventsRequest,
+	Providers,
+};
+
+fn feed_request(agent_id: &str, after: Option<String>) -> NoteEventsRequest {
+	NoteEventsRequest {
+		tenant_id: "tenant-feed".to_string(),
+		project_id: "project-feed".to_string(),
+		agent_id: agent_id.to_string(),
+		read_profile: "private_plus_project".to_string(),
+		after,
+		limit: None,
+		wait_ms: Some(2_000),
+	}
+}
+
+fn note_request(text: &str) -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: "tenant-feed".to_string(),
+		project_id: "project-feed".to_string(),
+		agent_id: "agent-owner".to_string(),
+		role: None,
+		scope: "agent_private".to_string(),
+		notes: vec![AddNoteInput {
+			r#type: "fact".to_string(),
+			key: Some("change_feed_target".to_string()),
+			text: text.to_string(),
+			structured: None,
+			importance: 0.6,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({ "schema": "acceptance/feed" }),
+			write_policy: None,
+		}],
+	}
+}
+
+/// Reads the feed until `expected` events arrive or a read returns nothing new.
+async fn read_events(service: &ElfService, agent_id: &str, expected: usize) -> Vec<NoteEvent> {
+	let mut events = Vec::new();
+	let mut after = None;
+
+	while events.len() < expected {
+		let page = service
+			.note_events(feed_request(agent_id, after.clone()))
+			.await
+			.expect("Failed to read note events.");
+
+		if page.events.is_empty() {
+			break;
+		}
+
+		after = Some(page.next_cursor);
+
+		events.extend(page.events);
+	}
+
+	events
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn note_events_feed_delivers_owner_writes_in_commit_order() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!(
+			"Skipping note_events_feed_delivers_owner_writes_in_commit_order; set ELF_PG_DSN."
+		);
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping note_events_feed_delivers_owner_writes_in_commit_order; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_note_events");
+	let docs_collection = test_db.collection_name("elf_note_events_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let added = service
+		.add_note(note_request("Fact: The change feed starts with this note."))
+		.await
+		.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected an added note id.");
+
+	service
+		.add_note(note_request("Fact: The change feed then records this update."))
+		.await
+		.expect("Failed to update note.");
+	service
+		.delete(DeleteRequest {
+			tenant_id: "tenant-feed".to_string(),
+			project_id: "project-feed".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			note_id,
+		})
+		.await
+		.expect("Failed to delete note.");
+
+	let events = read_events(&service, "agent-owner", 3).await;
+	let ops = events.iter().map(|event| event.op.as_str()).collect::<Vec<_>>();
+
+	assert_eq!(ops, vec!["ADD", "UPDATE", "DELETE"]);
+	assert!(events.iter().all(|event| event.note_id == note_id));
+	assert_eq!(
+		events[1].note.as_ref().and_then(|note| note.get("text")).and_then(|text| text.as_str()),
+		Some("Fact: The change feed then records this update.")
+	);
+
+	let caught_up = service
+		.note_events(NoteEventsRequest {
+			wait_ms: None,
+			..feed_request("agent-owner", Some(events[2].cursor.clone()))
+		})
+		.await
+		.expect("Failed to read caught-up feed.");
+
+	assert!(caught_up.events.is_empty());
+	assert_eq!(caught_up.next_cursor, events[2].cursor);
+
+	let other = service
+		.note_events(NoteEventsRequest { wait_ms: None, ..feed_request("agent-other", None) })
+		.await
+		.expect("Failed to read feed as another agent.");
+
+	assert!(other.events.is_empty());
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -0,0 +1,179 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, ElfService, Error, MemoryHistoryGetRequest, NoteFetchRequest,
+	NoteMergeStrategy, NoteOp, NotesMergeRequest, Providers,
+};
+
+const TENANT_ID: &str = "tenant-merge";
+const PROJECT_ID: &str = "project-merge";
+const AGENT_ID: &str = "agent-merge";
+
+async fn add_fact(service: &ElfService, key: &str, text: &str, thread: &str) -> Uuid {
+	let response = service
+		.add_note(AddNoteRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			role: None,
+			scope: "agent_private".to_string(),
+			notes: vec![AddNoteInput {
+				r#type: "fact".to_string(),
+				key: Some(key.to_string()),
+				text: text.to_string(),
+				structured: None,
+				importance: 0.5,
+				confidence: 0.8,
+				ttl_days: None,
+				source_ref: serde_json::json!({ "schema": "acceptance/merge", "thread": thread }),
+				write_policy: None,
+			}],
+		})
+		.await
+		.expect("note should be added");
+
+	response.results[0].note_id.expect("add should return note id")
+}
+
+fn merge_request(
+	primary_note_id: Uuid,
+	secondary_note_id: Uuid,
+	strategy: NoteMergeStrategy,
+) -> NotesMergeRequest {
+	NotesMergeRequest {
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: AGENT_ID.to_string(),
+		role: None,
+		primary_note_id,
+		secondary_note_id,
+		strategy,
+	}
+}
+
+fn fetch_request(note_id: Uuid) -> NoteFetchRequest {
+	NoteFetchRequest {
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: AGENT_ID.to_string(),
+		token_id: None,
+		note_id,
+		include_access_stats: false,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn notes_merge_unions_content_and_supersedes_secondary() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping notes_merge_unions_content_and_supersedes_secondary; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping notes_merge_unions_content_and_supersedes_secondary; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({
+				"text": "Fact: Deploys run on Fridays and rollbacks need on-call approval."
+			}),
+		}),
+	);
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		test_db.collection_name("elf_merge"),
+		test_db.collection_name("elf_merge_docs"),
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let primary = add_fact(&service, "deploy_day", "Fact: Deploys run on Fridays.", "a").await;
+	let secondary =
+		add_fact(&service, "rollback_rule", "Fact: Rollbacks need on-call approval.", "b").await;
+	let same = service
+		.notes_merge(merge_request(primary, primary, NoteMergeStrategy::Concatenate))
+		.await
+		.expect_err("Expected merging a note into itself to fail.");
+
+	assert!(matches!(same, Error::InvalidRequest { .. }));
+
+	let merged = service
+		.notes_merge(merge_request(primary, secondary, NoteMergeStrategy::Concatenate))
+		.await
+		.expect("merge should succeed");
+
+	assert_eq!(merged.op, NoteOp::Update);
+
+	let note = service.get_note(fetch_request(primary)).await.expect("primary should be readable");
+
+	assert_eq!(
+		note.text,
+		"Fact: Deploys run on Fridays.\n\nFact: Rollbacks need on-call approval."
+	);
+	assert_eq!(note.source_ref["thread"], "a");
+	assert_eq!(note.source_ref["merged_source_refs"][0]["thread"], "b");
+	assert_eq!(note.source_ref["merged_note_ids"][0], secondary.to_string());
+
+	let (status, source_ref): (String, Value) =
+		sqlx::query_as("SELECT status, source_ref FROM memory_notes WHERE note_id = $1")
+			.bind(secondary)
+			.fetch_one(&service.db.pool)
+			.await
+			.expect("secondary note should be queryable");
+
+	assert_eq!(status, "deprecated");
+	assert_eq!(source_ref["merged_into"], primary.to_string());
+
+	let history = service
+		.memory_history_get(MemoryHistoryGetRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			note_id: secondary,
+		})
+		.await
+		.expect("history should be readable");
+
+	assert!(history.events.iter().any(|event| event.event_type == "superseded"));
+
+	let again = service
+		.notes_merge(merge_request(primary, secondary, NoteMergeStrategy::Concatenate))
+		.await
+		.expect_err("Expected a superseded note to be rejected as a merge input.");
+
+	assert!(matches!(again, Error::InvalidRequest { .. }));
+
+	let third = add_fact(&service, "deploy_window", "Fact: Deploys run on Fridays.", "c").await;
+	let extracted = service
+		.notes_merge(merge_request(third, primary, NoteMergeStrategy::Extractor))
+		.await
+		.expect("extractor merge should succeed");
+
+	assert_eq!(extracted.strategy, NoteMergeStrategy::Extractor);
+
+	let note =
+		service.get_note(fetch_request(third)).await.expect("merged note should be readable");
+
+	assert_eq!(note.text, "Fact: Deploys run on Fridays and rollbacks need on-call approval.");
+	assert_eq!(
+		note.source_ref["merged_note_ids"],
+		serde_json::json!([secondary.to_string(), primary.to_string()])
+	);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
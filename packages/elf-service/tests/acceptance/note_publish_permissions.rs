@@ -0,0 +1,147 @@
+use std::{
+	collections::HashMap,
+	sync::{Arc, atomic::AtomicUsize},
+};
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_config::{SecurityAuthRole, SecurityRolePermissions};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, Error, Providers, PublishNoteRequest, ShareScope,
+	UnpublishNoteRequest,
+};
+
+const TENANT_ID: &str = "tenant-publish";
+const PROJECT_ID: &str = "project-publish";
+const AGENT_ID: &str = "agent-publish";
+
+fn note_request(scope: &str, key: &str) -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: AGENT_ID.to_string(),
+		role: None,
+		scope: scope.to_string(),
+		notes: vec![AddNoteInput {
+			r#type: "fact".to_string(),
+			key: Some(key.to_string()),
+			text: format!("Fact: The {key} note checks publish permissions."),
+			structured: None,
+			importance: 0.6,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({ "schema": "acceptance/publish_permissions" }),
+			write_policy: None,
+		}],
+	}
+}
+
+fn update_only(scope: &str) -> SecurityRolePermissions {
+	SecurityRolePermissions { update: vec![scope.to_string()], ..Default::default() }
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn publish_and_unpublish_require_update_on_the_target_scope() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!(
+			"Skipping publish_and_unpublish_require_update_on_the_target_scope; set ELF_PG_DSN."
+		);
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping publish_and_unpublish_require_update_on_the_target_scope; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_note_publish_permissions");
+	let docs_collection = test_db.collection_name("elf_note_publish_permissions_docs");
+	let mut cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+
+	// Users may update their private notes but not project_shared ones; admins the reverse.
+	cfg.security.permissions = Some(HashMap::from([
+		(SecurityAuthRole::User, update_only("agent_private")),
+		(SecurityAuthRole::Admin, update_only("project_shared")),
+	]));
+
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let private = service
+		.add_note(note_request("agent_private", "private_draft"))
+		.await
+		.expect("Failed to add private note.");
+	let private_id = private.results[0].note_id.expect("Expected an added note id.");
+	let publish = service
+		.publish_note(PublishNoteRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			role: Some(SecurityAuthRole::User),
+			note_id: private_id,
+			scope: ShareScope::ProjectShared,
+		})
+		.await;
+
+	assert!(
+		matches!(
+			&publish,
+			Err(Error::ScopeDenied { message }) if message.contains("project_shared")
+		),
+		"Unexpected publish result: {publish:?}"
+	);
+
+	let shared = service
+		.add_note(note_request("project_shared", "shared_fact"))
+		.await
+		.expect("Failed to add shared note.");
+	let shared_id = shared.results[0].note_id.expect("Expected an added note id.");
+	let unpublish = service
+		.unpublish_note(UnpublishNoteRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			role: Some(SecurityAuthRole::Admin),
+			note_id: shared_id,
+		})
+		.await;
+
+	assert!(
+		matches!(
+			&unpublish,
+			Err(Error::ScopeDenied { message }) if message.contains("agent_private")
+		),
+		"Unexpected unpublish result: {unpublish:?}"
+	);
+
+	let scopes: Vec<(String,)> =
+		sqlx::query_as("SELECT scope FROM memory_notes WHERE note_id = ANY($1) ORDER BY scope")
+			.bind(vec![private_id, shared_id])
+			.fetch_all(&service.db.pool)
+			.await
+			.expect("Failed to load note scopes.");
+
+	assert_eq!(
+		scopes,
+		vec![("agent_private".to_string(),), ("project_shared".to_string(),)],
+		"Denied publish and unpublish must leave both notes in place."
+	);
+}
@@ -0,0 +1,189 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, DeleteRequest, Error, NoteOp, NoteRelationCreateRequest,
+	NoteRelationDeleteRequest, NoteRelationKind, NoteRelationsListRequest, Providers,
+};
+
+fn note_input(key: &str, text: &str) -> AddNoteInput {
+	AddNoteInput {
+		r#type: "fact".to_string(),
+		key: Some(key.to_string()),
+		text: text.to_string(),
+		structured: None,
+		importance: 0.6,
+		confidence: 0.9,
+		ttl_days: None,
+		source_ref: serde_json::json!({ "schema": "acceptance/relations" }),
+		write_policy: None,
+	}
+}
+
+fn relation_request(
+	agent_id: &str,
+	from_note_id: uuid::Uuid,
+	to_note_id: uuid::Uuid,
+	relation: NoteRelationKind,
+) -> NoteRelationCreateRequest {
+	NoteRelationCreateRequest {
+		tenant_id: "tenant-relations".to_string(),
+		project_id: "project-relations".to_string(),
+		agent_id: agent_id.to_string(),
+		role: None,
+		from_note_id,
+		to_note_id,
+		relation,
+	}
+}
+
+fn list_request(agent_id: &str, note_id: uuid::Uuid) -> NoteRelationsListRequest {
+	NoteRelationsListRequest {
+		tenant_id: "tenant-relations".to_string(),
+		project_id: "project-relations".to_string(),
+		agent_id: agent_id.to_string(),
+		note_id,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn note_relations_are_created_listed_and_removed() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping note_relations_are_created_listed_and_removed; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!("Skipping note_relations_are_created_listed_and_removed; set ELF_QDRANT_URL.");
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_note_relations");
+	let docs_collection = test_db.collection_name("elf_note_relations_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let added = service
+		.add_note(AddNoteRequest {
+			tenant_id: "tenant-relations".to_string(),
+			project_id: "project-relations".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			scope: "project_shared".to_string(),
+			notes: vec![
+				note_input("relation_old", "Fact: The deploy window is Friday."),
+				note_input("relation_new", "Fact: The deploy window moved to Thursday."),
+			],
+		})
+		.await
+		.expect("Failed to add notes.");
+	let old = added.results[0].note_id.expect("Expected the old note id.");
+	let new = added.results[1].note_id.expect("Expected the new note id.");
+	let created = service
+		.note_relation_create(relation_request(
+			"agent-owner",
+			new,
+			old,
+			NoteRelationKind::Supersedes,
+		))
+		.await
+		.expect("Failed to create relation.");
+
+	assert_eq!(created.op, NoteOp::Add);
+
+	let repeated = service
+		.note_relation_create(relation_request(
+			"agent-owner",
+			new,
+			old,
+			NoteRelationKind::Supersedes,
+		))
+		.await
+		.expect("Failed to repeat relation.");
+
+	assert_eq!(repeated.op, NoteOp::None);
+	assert_eq!(repeated.relation.relation_id, created.relation.relation_id);
+
+	let reversed = service
+		.note_relation_create(relation_request(
+			"agent-owner",
+			old,
+			new,
+			NoteRelationKind::Supersedes,
+		))
+		.await;
+
+	assert!(matches!(reversed, Err(Error::Conflict { .. })), "Unexpected result: {reversed:?}");
+
+	let foreign = service
+		.note_relation_create(relation_request(
+			"agent-other",
+			new,
+			old,
+			NoteRelationKind::RelatedTo,
+		))
+		.await;
+
+	assert!(foreign.is_err(), "Only the source owner may relate notes.");
+
+	let listed = service
+		.note_relations_list(list_request("agent-other", old))
+		.await
+		.expect("Failed to list relations.");
+
+	assert_eq!(listed.relations.len(), 1);
+	assert_eq!(listed.relations[0].from_note_id, new);
+	assert_eq!(listed.relations[0].relation, NoteRelationKind::Supersedes);
+
+	service
+		.delete(DeleteRequest {
+			tenant_id: "tenant-relations".to_string(),
+			project_id: "project-relations".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			note_id: old,
+		})
+		.await
+		.expect("Failed to delete note.");
+
+	let hidden = service
+		.note_relations_list(list_request("agent-owner", new))
+		.await
+		.expect("Failed to list relations.");
+
+	assert!(hidden.relations.is_empty(), "Relations to trashed notes should be hidden.");
+
+	let removed = service
+		.note_relation_delete(NoteRelationDeleteRequest {
+			tenant_id: "tenant-relations".to_string(),
+			project_id: "project-relations".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			from_note_id: new,
+			relation_id: created.relation.relation_id,
+		})
+		.await
+		.expect("Failed to delete relation.");
+
+	assert_eq!(removed.op, NoteOp::Delete);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
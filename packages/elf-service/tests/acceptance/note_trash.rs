@@ -0,0 +1,161 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use uuid::Uuid;
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, DeleteRequest, Error, ListTrashedRequest, NoteOp, Providers,
+	UndeleteRequest,
+};
+
+fn note_request(text: &str) -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: "tenant-trash".to_string(),
+		project_id: "project-trash".to_string(),
+		agent_id: "agent-owner".to_string(),
+		role: None,
+		scope: "agent_private".to_string(),
+		notes: vec![AddNoteInput {
+			r#type: "fact".to_string(),
+			key: Some("trash_target".to_string()),
+			text: text.to_string(),
+			structured: None,
+			importance: 0.6,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({ "schema": "acceptance/trash" }),
+			write_policy: None,
+		}],
+	}
+}
+
+fn undelete_request(note_id: Uuid) -> UndeleteRequest {
+	UndeleteRequest {
+		tenant_id: "tenant-trash".to_string(),
+		project_id: "project-trash".to_string(),
+		agent_id: "agent-owner".to_string(),
+		role: None,
+		note_id,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn deleted_notes_go_to_trash_and_can_be_restored() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping deleted_notes_go_to_trash_and_can_be_restored; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!("Skipping deleted_notes_go_to_trash_and_can_be_restored; set ELF_QDRANT_URL.");
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_note_trash");
+	let docs_collection = test_db.collection_name("elf_note_trash_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let added = service
+		.add_note(note_request("Fact: The trash keeps this note restorable."))
+		.await
+		.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected an added note id.");
+	let deleted = service
+		.delete(DeleteRequest {
+			tenant_id: "tenant-trash".to_string(),
+			project_id: "project-trash".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			note_id,
+		})
+		.await
+		.expect("Failed to delete note.");
+
+	assert_eq!(deleted.op, NoteOp::Delete);
+
+	let status: String = sqlx::query_scalar("SELECT status FROM memory_notes WHERE note_id = $1")
+		.bind(note_id)
+		.fetch_one(&service.db.pool)
+		.await
+		.expect("Failed to load note status.");
+
+	assert_eq!(status, "trashed");
+
+	let trash = service
+		.list_trashed(ListTrashedRequest {
+			tenant_id: "tenant-trash".to_string(),
+			project_id: "project-trash".to_string(),
+			agent_id: "agent-owner".to_string(),
+		})
+		.await
+		.expect("Failed to list trash.");
+
+	assert_eq!(trash.retention_days, 30);
+	assert_eq!(trash.items.len(), 1);
+	assert_eq!(trash.items[0].note_id, note_id);
+
+	let replacement = service
+		.add_note(note_request("Fact: A replacement note now holds the key."))
+		.await
+		.expect("Failed to add replacement note.");
+	let replacement_id = replacement.results[0].note_id.expect("Expected a replacement id.");
+	let err = service
+		.undelete(undelete_request(note_id))
+		.await
+		.expect_err("Expected a key conflict while the replacement is active.");
+
+	assert!(matches!(err, Error::Conflict { .. }), "Unexpected error: {err}");
+
+	service
+		.delete(DeleteRequest {
+			tenant_id: "tenant-trash".to_string(),
+			project_id: "project-trash".to_string(),
+			agent_id: "agent-owner".to_string(),
+			role: None,
+			note_id: replacement_id,
+		})
+		.await
+		.expect("Failed to delete replacement note.");
+
+	let restored =
+		service.undelete(undelete_request(note_id)).await.expect("Failed to restore note.");
+
+	assert_eq!(restored.op, NoteOp::Update);
+
+	let (status, upserts): (String, i64) = sqlx::query_as(
+		"\
+SELECT
+	n.status,
+	(SELECT count(*) FROM indexing_outbox o WHERE o.note_id = n.note_id AND o.op = 'UPSERT')
+FROM memory_notes n
+WHERE n.note_id = $1",
+	)
+	.bind(note_id)
+	.fetch_one(&service.db.pool)
+	.await
+	.expect("Failed to load restored note.");
+
+	assert_eq!(status, "active");
+	assert!(upserts >= 2, "Expected the restore to queue an UPSERT.");
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
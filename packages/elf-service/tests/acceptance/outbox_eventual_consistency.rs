@@ -149,9 +149,24 @@ async fn spawn_outbox_worker(service: &ElfService, api_base: String) -> JoinHand
 			dimensions: 4_096,
 			timeout_ms: 1_000,
 			default_headers: Map::new(),
+			query_input: None,
+			model_dir: None,
+			resilience: None,
+			cache: None,
 		},
+		embedding_scopes: Default::default(),
+		embedding_types: Default::default(),
 		chunking: ChunkingConfig { max_tokens: 64, overlap_tokens: 8 },
+		chunking_per_type: Default::default(),
 		tokenizer: build_test_tokenizer(),
+		url_snapshots: None,
+		egress: None,
+		qdrant_maintenance: None,
+		trash_retention_days: None,
+		note_consolidation: None,
+		lifecycle: None,
+		importance_rescore: None,
+		indexing_concurrency: 1,
 	};
 
 	tokio::spawn(async move {
@@ -209,6 +224,7 @@ async fn outbox_retries_to_done() {
 			tenant_id: "t".to_string(),
 			project_id: "p".to_string(),
 			agent_id: "a".to_string(),
+			role: None,
 			scope: "agent_private".to_string(),
 			notes: vec![AddNoteInput {
 				r#type: "fact".to_string(),
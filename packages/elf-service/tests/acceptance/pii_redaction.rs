@@ -0,0 +1,166 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_config::{SecurityPii, SecurityPiiAction};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, NoteOp, Providers, StructuredFields, UpdateRequest,
+};
+
+fn pii_note_request() -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: "tenant-pii".to_string(),
+		project_id: "project-pii".to_string(),
+		agent_id: "agent-pii".to_string(),
+		role: None,
+		scope: "agent_private".to_string(),
+		notes: vec![AddNoteInput {
+			r#type: "fact".to_string(),
+			key: Some("escalation_contact:ada@example.com".to_string()),
+			text: "Fact: Billing escalations go to ada@example.com on weekdays.".to_string(),
+			structured: Some(StructuredFields {
+				summary: Some("Billing escalations go to ada@example.com.".to_string()),
+				facts: Some(vec![
+					"Billing escalations go to ada@example.com on weekdays.".to_string(),
+				]),
+				..StructuredFields::default()
+			}),
+			importance: 0.6,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({
+				"schema": "acceptance/pii_redaction",
+				"evidence": [{ "quote": "Escalate billing issues to ada@example.com." }],
+			}),
+			write_policy: None,
+		}],
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn pii_redaction_covers_note_fields_and_updates() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping pii_redaction_covers_note_fields_and_updates; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!("Skipping pii_redaction_covers_note_fields_and_updates; set ELF_QDRANT_URL.");
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_pii_redaction");
+	let docs_collection = test_db.collection_name("elf_pii_redaction_docs");
+	let mut cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+
+	cfg.security.pii =
+		Some(SecurityPii { email: SecurityPiiAction::Redact, ..SecurityPii::default() });
+
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let added = service.add_note(pii_note_request()).await.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected note_id.");
+
+	assert_eq!(added.results[0].op, NoteOp::Add);
+
+	let (key, text, source_ref): (Option<String>, String, serde_json::Value) =
+		sqlx::query_as("SELECT key, text, source_ref FROM memory_notes WHERE note_id = $1")
+			.bind(note_id)
+			.fetch_one(&service.db.pool)
+			.await
+			.expect("Failed to load note.");
+
+	assert_eq!(key.as_deref(), Some("escalation_contact:[REDACTED_EMAIL]"));
+	assert_eq!(text, "Fact: Billing escalations go to [REDACTED_EMAIL] on weekdays.");
+	assert_eq!(source_ref["evidence"][0]["quote"], "Escalate billing issues to [REDACTED_EMAIL].");
+
+	let field_texts: Vec<String> = sqlx::query_scalar(
+		"SELECT text FROM memory_note_fields WHERE note_id = $1 ORDER BY field_kind, item_index",
+	)
+	.bind(note_id)
+	.fetch_all(&service.db.pool)
+	.await
+	.expect("Failed to load structured fields.");
+
+	assert!(!field_texts.is_empty());
+	assert!(field_texts.iter().all(|text| !text.contains("ada@example.com")), "{field_texts:?}");
+
+	let add_fields = latest_redaction_fields(&service.db.pool, note_id).await;
+
+	for field in [
+		"$.text",
+		"$.key",
+		"$.structured.summary",
+		"$.structured.facts[0]",
+		"$.source_ref.evidence[0].quote",
+	] {
+		assert!(add_fields.iter().any(|recorded| recorded == field), "{field} in {add_fields:?}");
+	}
+
+	let updated = service
+		.update(UpdateRequest {
+			tenant_id: "tenant-pii".to_string(),
+			project_id: "project-pii".to_string(),
+			agent_id: "agent-pii".to_string(),
+			role: None,
+			note_id,
+			text: Some(
+				"Fact: Billing escalations now go to grace@example.com on weekdays.".to_string(),
+			),
+			importance: None,
+			confidence: None,
+			ttl_days: None,
+		})
+		.await
+		.expect("Failed to update note.");
+
+	assert_eq!(updated.op, NoteOp::Update);
+
+	let text: String = sqlx::query_scalar("SELECT text FROM memory_notes WHERE note_id = $1")
+		.bind(note_id)
+		.fetch_one(&service.db.pool)
+		.await
+		.expect("Failed to load updated note.");
+
+	assert_eq!(text, "Fact: Billing escalations now go to [REDACTED_EMAIL] on weekdays.");
+	assert_eq!(latest_redaction_fields(&service.db.pool, note_id).await, vec!["$.text"]);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+async fn latest_redaction_fields(pool: &PgPool, note_id: Uuid) -> Vec<String> {
+	let redactions: Option<serde_json::Value> = sqlx::query_scalar(
+		"SELECT redactions FROM memory_note_versions WHERE note_id = $1 ORDER BY ts DESC LIMIT 1",
+	)
+	.bind(note_id)
+	.fetch_one(pool)
+	.await
+	.expect("Failed to load version redactions.");
+
+	redactions
+		.and_then(|value| value.as_array().cloned())
+		.unwrap_or_default()
+		.iter()
+		.filter_map(|redaction| redaction["field"].as_str().map(str::to_string))
+		.collect()
+}
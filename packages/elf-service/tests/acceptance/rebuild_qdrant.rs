@@ -8,7 +8,7 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use crate::acceptance::{self, SpyEmbedding, SpyExtractor, StubRerank};
-use elf_service::Providers;
+use elf_service::{Providers, RebuildQdrantRequest};
 
 fn build_zero_vector_text(dim: usize) -> String {
 	let mut buf = String::with_capacity(2 + (dim * 2));
@@ -210,11 +210,34 @@ async fn rebuild_uses_postgres_vectors_only() {
 	)
 	.await;
 
-	let report = service.rebuild_qdrant().await.expect("Rebuild failed.");
+	let report =
+		service.rebuild_qdrant(RebuildQdrantRequest::default()).await.expect("Rebuild failed.");
 
 	assert_eq!(report.missing_vector_count, 0);
 	assert!(report.rebuilt_count >= 1);
 	assert_eq!(embed_calls.load(Ordering::SeqCst), 0);
 
+	let scoped = service
+		.rebuild_qdrant(RebuildQdrantRequest {
+			tenant_id: Some("t".to_string()),
+			project_id: Some("p".to_string()),
+			note_type: Some("fact".to_string()),
+			..RebuildQdrantRequest::default()
+		})
+		.await
+		.expect("Scoped rebuild failed.");
+
+	assert_eq!(scoped.rebuilt_count, 1);
+
+	let other_tenant = service
+		.rebuild_qdrant(RebuildQdrantRequest {
+			tenant_id: Some("other".to_string()),
+			..RebuildQdrantRequest::default()
+		})
+		.await
+		.expect("Scoped rebuild failed.");
+
+	assert_eq!(other_tenant.rebuilt_count, 0);
+
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
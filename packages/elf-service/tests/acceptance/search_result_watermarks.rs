@@ -0,0 +1,221 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use sqlx::PgPool;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, GranteeKind, NoteOp, PinNoteRequest, Providers,
+	SearchFeedbackKind, SearchFeedbackRequest, ShareScope, SpaceGrantRevokeRequest,
+	SpaceGrantUpsertRequest,
+};
+
+const TENANT_ID: &str = "tenant-watermark";
+const PROJECT_ID: &str = "project-watermark";
+const AGENT_ID: &str = "agent-watermark";
+
+async fn generation(pool: &PgPool, scope: &str) -> i64 {
+	sqlx::query_scalar(
+		"\
+SELECT COALESCE(max(generation), 0)
+FROM search_result_watermarks
+WHERE tenant_id = $1 AND project_id = $2 AND scope = $3",
+	)
+	.bind(TENANT_ID)
+	.bind(PROJECT_ID)
+	.bind(scope)
+	.fetch_one(pool)
+	.await
+	.expect("Failed to load search result watermark.")
+}
+
+/// Inserts a persisted trace that returned `note_id` and returns the item's result handle.
+async fn insert_traced_result(pool: &PgPool, note_id: Uuid) -> Uuid {
+	let trace_id = Uuid::new_v4();
+	let item_id = Uuid::new_v4();
+	let now = OffsetDateTime::now_utc();
+
+	sqlx::query(
+		"\
+INSERT INTO search_traces (
+	trace_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	read_profile,
+	query,
+	expansion_mode,
+	expanded_queries,
+	allowed_scopes,
+	candidate_count,
+	top_k,
+	config_snapshot,
+	trace_version,
+	created_at,
+	expires_at
+)
+VALUES ($1, $2, $3, $4, 'private_plus_project', 'deploy day', 'off', $5, $6, 1, 5, '{}'::jsonb, 1, $7, $8)",
+	)
+	.bind(trace_id)
+	.bind(TENANT_ID)
+	.bind(PROJECT_ID)
+	.bind(AGENT_ID)
+	.bind(serde_json::json!(["deploy day"]))
+	.bind(serde_json::json!(["agent_private", "project_shared"]))
+	.bind(now)
+	.bind(now + Duration::days(7))
+	.execute(pool)
+	.await
+	.expect("Failed to insert trace.");
+	sqlx::query(
+		"\
+INSERT INTO search_trace_items (item_id, trace_id, note_id, chunk_id, rank, final_score, explain)
+VALUES ($1, $2, $3, NULL, 1, 0.5, '{}'::jsonb)",
+	)
+	.bind(item_id)
+	.bind(trace_id)
+	.bind(note_id)
+	.execute(pool)
+	.await
+	.expect("Failed to insert trace item.");
+
+	item_id
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn search_result_watermarks_advance_on_note_grant_and_feedback_writes() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!(
+			"Skipping search_result_watermarks_advance_on_note_grant_and_feedback_writes; set ELF_PG_DSN."
+		);
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping search_result_watermarks_advance_on_note_grant_and_feedback_writes; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_search_result_watermarks");
+	let docs_collection = test_db.collection_name("elf_search_result_watermarks_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	assert_eq!(generation(&service.db.pool, "project_shared").await, 0);
+
+	let added = service
+		.add_note(AddNoteRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			role: None,
+			scope: "project_shared".to_string(),
+			notes: vec![AddNoteInput {
+				r#type: "fact".to_string(),
+				key: Some("deploy_window".to_string()),
+				text: "Fact: Deploys run on Tuesdays.".to_string(),
+				structured: None,
+				importance: 0.5,
+				confidence: 0.9,
+				ttl_days: None,
+				source_ref: serde_json::json!({ "schema": "acceptance/search_result_watermarks" }),
+				write_policy: None,
+			}],
+		})
+		.await
+		.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected note_id.");
+
+	assert_eq!(added.results[0].op, NoteOp::Add);
+
+	let after_add = generation(&service.db.pool, "project_shared").await;
+
+	assert!(after_add > 0);
+	assert_eq!(generation(&service.db.pool, "agent_private").await, 0);
+
+	service
+		.pin_note(PinNoteRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			role: None,
+			note_id,
+		})
+		.await
+		.expect("Failed to pin note.");
+
+	let after_pin = generation(&service.db.pool, "project_shared").await;
+
+	assert!(after_pin > after_add);
+
+	service
+		.space_grant_upsert(SpaceGrantUpsertRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			scope: ShareScope::ProjectShared,
+			grantee_kind: GranteeKind::Agent,
+			grantee_agent_id: Some("agent-reader".to_string()),
+		})
+		.await
+		.expect("Failed to upsert grant.");
+
+	let after_grant = generation(&service.db.pool, "project_shared").await;
+
+	assert!(after_grant > after_pin);
+
+	service
+		.space_grant_revoke(SpaceGrantRevokeRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			scope: ShareScope::ProjectShared,
+			grantee_kind: GranteeKind::Agent,
+			grantee_agent_id: Some("agent-reader".to_string()),
+		})
+		.await
+		.expect("Failed to revoke grant.");
+
+	let after_revoke = generation(&service.db.pool, "project_shared").await;
+
+	assert!(after_revoke > after_grant);
+
+	let result_handle = insert_traced_result(&service.db.pool, note_id).await;
+
+	service
+		.record_feedback(SearchFeedbackRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			token_id: None,
+			result_handle,
+			feedback: SearchFeedbackKind::NotUseful,
+		})
+		.await
+		.expect("Failed to record feedback.");
+
+	assert!(generation(&service.db.pool, "project_shared").await > after_revoke);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
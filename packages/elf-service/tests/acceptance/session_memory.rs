@@ -0,0 +1,159 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicUsize, Ordering},
+};
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_service::{
+	Error, NoteOp, Providers, SessionAppendRequest, SessionGetRequest, SessionMessageInput,
+	SessionSummarizeRequest,
+};
+
+fn message(content: &str) -> SessionMessageInput {
+	SessionMessageInput {
+		role: "user".to_string(),
+		content: content.to_string(),
+		ts: None,
+		msg_id: None,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn session_messages_stay_out_of_notes_until_summarized() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping session_messages_stay_out_of_notes_until_summarized; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping session_messages_stay_out_of_notes_until_summarized; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let calls = Arc::new(AtomicUsize::new(0));
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: calls.clone(),
+			payload: serde_json::json!({
+				"notes": [
+					{
+						"type": "plan",
+						"key": "staging_deploy",
+						"text": "Plan: Deploy the staging build tonight.",
+						"importance": 0.6,
+						"confidence": 0.8,
+						"ttl_days": null,
+						"scope_suggestion": "agent_private",
+						"evidence": [
+							{ "message_index": 1, "quote": "Deploy the staging build tonight." }
+						],
+						"reason": "test"
+					}
+				]
+			}),
+		}),
+	);
+	let collection = test_db.collection_name("elf_session_memory");
+	let docs_collection = test_db.collection_name("elf_session_memory_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	for content in ["Let us review the release checklist.", "Deploy the staging build tonight."] {
+		service
+			.session_append(SessionAppendRequest {
+				tenant_id: "t".to_string(),
+				project_id: "p".to_string(),
+				agent_id: "a".to_string(),
+				role: None,
+				session_id: "run-1".to_string(),
+				ttl_seconds: None,
+				messages: vec![message(content)],
+			})
+			.await
+			.expect("session_append failed.");
+	}
+
+	let session = service
+		.session_get(SessionGetRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			session_id: "run-1".to_string(),
+		})
+		.await
+		.expect("session_get failed.");
+
+	assert_eq!(session.messages.len(), 2);
+	assert_eq!(session.messages[1].content, "Deploy the staging build tonight.");
+
+	let other_agent = service
+		.session_get(SessionGetRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "b".to_string(),
+			session_id: "run-1".to_string(),
+		})
+		.await
+		.expect_err("Expected another agent's session to be hidden.");
+
+	assert!(matches!(other_agent, Error::NotFound { .. }), "Unexpected error: {other_agent:?}");
+
+	let notes_before: i64 = sqlx::query_scalar("SELECT count(*) FROM memory_notes")
+		.fetch_one(&service.db.pool)
+		.await
+		.expect("Failed to count notes.");
+
+	assert_eq!(notes_before, 0);
+
+	let summarized = service
+		.session_summarize_to_notes(SessionSummarizeRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			role: None,
+			session_id: "run-1".to_string(),
+			scope: Some("agent_private".to_string()),
+			dry_run: Some(false),
+			locale: None,
+		})
+		.await
+		.expect("session_summarize_to_notes failed.");
+
+	assert_eq!(summarized.message_count, 2);
+	assert_eq!(summarized.extraction.results.len(), 1);
+	assert_eq!(summarized.extraction.results[0].op, NoteOp::Add);
+	assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+	sqlx::query("UPDATE memory_session_messages SET expires_at = now() - interval '1 second'")
+		.execute(&service.db.pool)
+		.await
+		.expect("Failed to expire session.");
+
+	let expired = service
+		.session_get(SessionGetRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			session_id: "run-1".to_string(),
+		})
+		.await
+		.expect_err("Expected an expired session.");
+
+	assert!(matches!(expired, Error::NotFound { .. }), "Unexpected error: {expired:?}");
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
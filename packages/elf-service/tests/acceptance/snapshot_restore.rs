@@ -0,0 +1,430 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank, chunking::ChunkingConfig};
+use elf_service::{
+	AddNoteInput, AddNoteRequest, ElfService, Providers, SearchRequest, SnapshotRestoreRequest,
+	SnapshotRestoreResponse, TenantExportRequest, tenant_export,
+};
+use elf_storage::{db::Db, qdrant::QdrantStore};
+use elf_worker::worker::{self, WorkerState};
+
+const RANKING_NOTES: &[&str] = &[
+	"Fact: The release checklist requires a staging deploy before production.",
+	"Fact: Database migrations run before the API deploy and are never rolled back automatically.",
+	"Fact: Incident reviews are written within two days and shared with the whole team.",
+	"Fact: The staging database is refreshed from a production snapshot every Monday.",
+	"Fact: On-call engineers rotate weekly and hand over open incidents in writing.",
+];
+const RANKING_QUERIES: &[&str] =
+	&["staging deploy", "database migration", "incident review", "weekly rotation"];
+
+fn build_vector_text(dim: usize) -> String {
+	let values: Vec<String> = (0..dim).map(|i| format!("{}", (i % 7) as f32 * 0.125)).collect();
+
+	format!("[{}]", values.join(","))
+}
+
+async fn insert_note(pool: &PgPool, note_id: Uuid, now: OffsetDateTime, embedding_version: &str) {
+	sqlx::query(
+		"\
+INSERT INTO memory_notes (
+	note_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	scope,
+	type,
+	key,
+	text,
+	importance,
+	confidence,
+	status,
+	created_at,
+	updated_at,
+	expires_at,
+	embedding_version,
+	source_ref,
+	hit_count,
+	last_hit_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)",
+	)
+	.bind(note_id)
+	.bind("t")
+	.bind("p")
+	.bind("a")
+	.bind("agent_private")
+	.bind("fact")
+	.bind(Some("restore_key"))
+	.bind("Fact: Snapshots restore deterministically.")
+	.bind(0.4_f32)
+	.bind(0.9_f32)
+	.bind("active")
+	.bind(now)
+	.bind(now)
+	.bind(Option::<OffsetDateTime>::None)
+	.bind(embedding_version)
+	.bind(serde_json::json!({}))
+	.bind(0_i64)
+	.bind(Option::<OffsetDateTime>::None)
+	.execute(pool)
+	.await
+	.expect("Failed to insert memory note.");
+}
+
+async fn insert_embedding(pool: &PgPool, note_id: Uuid, embedding_version: &str, vec_text: &str) {
+	sqlx::query(
+		"\
+INSERT INTO note_embeddings (note_id, embedding_version, embedding_dim, vec)
+VALUES ($1, $2, $3, $4::text::vector)",
+	)
+	.bind(note_id)
+	.bind(embedding_version)
+	.bind(4_096_i32)
+	.bind(vec_text)
+	.execute(pool)
+	.await
+	.expect("Failed to insert embedding.");
+}
+
+async fn export_lines(service: &ElfService, tenant_id: &str) -> Vec<String> {
+	let mut exporter = service
+		.tenant_export(TenantExportRequest { tenant_id: tenant_id.to_string() })
+		.await
+		.expect("Failed to start export.");
+	let mut lines = Vec::new();
+
+	while let Some(batch) = exporter.next_batch().await.expect("Failed to read export batch.") {
+		for line in batch {
+			lines.push(serde_json::to_string(&line).expect("Failed to encode export line."));
+		}
+	}
+
+	lines
+}
+
+/// Builds a worker that embeds with the service's local hash provider.
+async fn local_worker_state(service: &ElfService) -> WorkerState {
+	let tokenizer = elf_chunking::load_tokenizer(&service.cfg.chunking.tokenizer_repo)
+		.expect("worker tokenizer should load");
+
+	WorkerState {
+		db: Db::connect(&service.cfg.storage.postgres).await.expect("Failed to connect worker DB."),
+		qdrant: QdrantStore::new(&service.cfg.storage.qdrant)
+			.expect("Failed to build Qdrant store."),
+		docs_qdrant: QdrantStore::new_with_collection(
+			&service.cfg.storage.qdrant,
+			&service.cfg.storage.qdrant.docs_collection,
+		)
+		.expect("Failed to build docs Qdrant store."),
+		embedding: service.cfg.providers.embedding.clone(),
+		embedding_scopes: Default::default(),
+		embedding_types: Default::default(),
+		chunking: ChunkingConfig {
+			max_tokens: service.cfg.chunking.max_tokens,
+			overlap_tokens: service.cfg.chunking.overlap_tokens,
+		},
+		chunking_per_type: Default::default(),
+		tokenizer,
+		url_snapshots: None,
+		egress: None,
+		qdrant_maintenance: None,
+		trash_retention_days: None,
+		note_consolidation: None,
+		lifecycle: None,
+		importance_rescore: None,
+		indexing_concurrency: 1,
+	}
+}
+
+/// Runs the worker until no indexing job is left for `tenant_id`.
+async fn index_tenant(worker_state: &WorkerState, tenant_id: &str) {
+	for _ in 0..20 {
+		worker::process_once(worker_state).await.expect("worker should process once");
+
+		let pending: i64 = sqlx::query_scalar(
+			"\
+SELECT COUNT(*)
+FROM indexing_outbox o
+JOIN memory_notes n ON n.note_id = o.note_id
+WHERE n.tenant_id = $1 AND o.status <> 'DONE'",
+		)
+		.bind(tenant_id)
+		.fetch_one(&worker_state.db.pool)
+		.await
+		.expect("Failed to count pending indexing jobs.");
+
+		if pending == 0 {
+			return;
+		}
+	}
+
+	panic!("Indexing jobs for {tenant_id} did not finish.");
+}
+
+/// Returns the ranked note ids `query` finds in `tenant_id`.
+async fn ranked_note_ids(service: &ElfService, tenant_id: &str, query: &str) -> Vec<Uuid> {
+	let response = service
+		.search_raw(SearchRequest {
+			tenant_id: tenant_id.to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			token_id: None,
+			read_profile: "private_only".to_string(),
+			payload_level: Default::default(),
+			query: query.to_string(),
+			top_k: Some(5),
+			candidate_k: Some(20),
+			filter: None,
+			types: Vec::new(),
+			updated_after: None,
+			updated_before: None,
+			min_importance: None,
+			max_result_tokens: None,
+			record_hits: Some(false),
+			ranking: None,
+			session_id: None,
+			session_mode: None,
+		})
+		.await
+		.expect("Search failed.");
+
+	response.items.iter().map(|item| item.note_id).collect()
+}
+
+async fn restore_lines(
+	service: &ElfService,
+	tenant_id: &str,
+	lines: &[String],
+) -> SnapshotRestoreResponse {
+	let mut restorer = service
+		.restore_snapshot(SnapshotRestoreRequest { tenant_id: tenant_id.to_string() })
+		.await
+		.expect("Failed to start restore.");
+
+	for line in lines {
+		restorer.push_line(line).await.expect("Failed to restore line.");
+	}
+
+	restorer.finish().await.expect("Failed to finish restore.")
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn restore_rewrites_ids_and_enqueues_indexing() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!(
+			"Skipping restore_rewrites_ids_and_enqueues_indexing; set ELF_PG_DSN to run this test."
+		);
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping restore_rewrites_ids_and_enqueues_indexing; set ELF_QDRANT_URL to run this test."
+		);
+
+		return;
+	};
+	let collection = test_db.collection_name("elf_acceptance");
+	let docs_collection = test_db.collection_name("elf_acceptance_docs");
+	let cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	let note_id = Uuid::new_v4();
+	let now = OffsetDateTime::now_utc();
+	let embedding_version = format!(
+		"{}:{}:{}",
+		service.cfg.providers.embedding.provider_id,
+		service.cfg.providers.embedding.model,
+		service.cfg.storage.qdrant.vector_dim
+	);
+
+	insert_note(&service.db.pool, note_id, now, embedding_version.as_str()).await;
+	insert_embedding(
+		&service.db.pool,
+		note_id,
+		embedding_version.as_str(),
+		build_vector_text(4_096).as_str(),
+	)
+	.await;
+
+	let lines = export_lines(&service, "t").await;
+
+	assert_eq!(lines.len(), 4);
+
+	let restored = restore_lines(&service, "t2", &lines).await;
+	let restored_note_id = tenant_export::restored_id("t2", note_id);
+
+	assert_eq!(restored.source_tenant_id, "t");
+	assert_eq!(restored.restored.get("memory_notes"), Some(&1));
+	assert_eq!(restored.restored.get("note_embeddings"), Some(&1));
+	assert_eq!(restored.outbox_enqueued, 1);
+
+	let (tenant_id, text): (String, String) =
+		sqlx::query_as("SELECT tenant_id, text FROM memory_notes WHERE note_id = $1")
+			.bind(restored_note_id)
+			.fetch_one(&service.db.pool)
+			.await
+			.expect("Failed to load restored note.");
+
+	assert_eq!(tenant_id, "t2");
+	assert_eq!(text, "Fact: Snapshots restore deterministically.");
+
+	let same_vector: bool = sqlx::query_scalar(
+		"\
+SELECT s.vec = r.vec
+FROM note_embeddings s
+JOIN note_embeddings r ON r.embedding_version = s.embedding_version
+WHERE s.note_id = $1 AND r.note_id = $2",
+	)
+	.bind(note_id)
+	.bind(restored_note_id)
+	.fetch_one(&service.db.pool)
+	.await
+	.expect("Failed to compare embeddings.");
+
+	assert!(same_vector);
+
+	let outbox: i64 = sqlx::query_scalar(
+		"SELECT COUNT(*) FROM indexing_outbox WHERE note_id = $1 AND op = 'UPSERT'",
+	)
+	.bind(restored_note_id)
+	.fetch_one(&service.db.pool)
+	.await
+	.expect("Failed to count outbox entries.");
+
+	assert_eq!(outbox, 1);
+
+	let repeated = restore_lines(&service, "t2", &lines).await;
+
+	assert_eq!(repeated.skipped.get("memory_notes"), Some(&1));
+	assert_eq!(repeated.outbox_enqueued, 0);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn restore_reproduces_ranked_note_order() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!(
+			"Skipping restore_reproduces_ranked_note_order; set ELF_PG_DSN to run this test."
+		);
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping restore_reproduces_ranked_note_order; set ELF_QDRANT_URL to run this test."
+		);
+
+		return;
+	};
+	let mut cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		test_db.collection_name("elf_acceptance"),
+		test_db.collection_name("elf_acceptance_docs"),
+	);
+
+	// Local hash embeddings and rerank scores make both tenants rank from the same vectors.
+	cfg.providers.embedding.provider_id = "local".to_string();
+	cfg.providers.rerank.provider_id = "local".to_string();
+
+	let service = acceptance::build_service(cfg, Providers::default())
+		.await
+		.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+	acceptance::reset_qdrant_collection(
+		&service.qdrant.client,
+		&service.qdrant.collection,
+		service.qdrant.vector_dim,
+	)
+	.await
+	.expect("Failed to reset Qdrant collection.");
+
+	let worker_state = local_worker_state(&service).await;
+	let notes = RANKING_NOTES
+		.iter()
+		.map(|text| AddNoteInput {
+			r#type: "fact".to_string(),
+			key: None,
+			text: (*text).to_string(),
+			structured: None,
+			importance: 0.5,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({}),
+			write_policy: None,
+		})
+		.collect();
+
+	service
+		.add_note(AddNoteRequest {
+			tenant_id: "t".to_string(),
+			project_id: "p".to_string(),
+			agent_id: "a".to_string(),
+			role: None,
+			scope: "agent_private".to_string(),
+			notes,
+		})
+		.await
+		.expect("Failed to add notes.");
+	index_tenant(&worker_state, "t").await;
+
+	let mut source_rankings = Vec::new();
+
+	for query in RANKING_QUERIES {
+		let ranked = ranked_note_ids(&service, "t", query).await;
+
+		assert!(!ranked.is_empty(), "Expected source hits for {query}.");
+
+		source_rankings.push(ranked);
+	}
+
+	let lines = export_lines(&service, "t").await;
+	let restored = restore_lines(&service, "t2", &lines).await;
+
+	assert_eq!(restored.restored.get("memory_notes"), Some(&(RANKING_NOTES.len() as u64)));
+
+	index_tenant(&worker_state, "t2").await;
+
+	for (query, source_ranked) in RANKING_QUERIES.iter().zip(source_rankings) {
+		let expected = source_ranked
+			.into_iter()
+			.map(|note_id| tenant_export::restored_id("t2", note_id))
+			.collect::<Vec<_>>();
+
+		assert_eq!(
+			ranked_note_ids(&service, "t2", query).await,
+			expected,
+			"Restored ranking differs for {query}."
+		);
+	}
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
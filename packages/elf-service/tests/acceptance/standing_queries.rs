@@ -0,0 +1,227 @@
+use std::{
+	collections::HashMap,
+	future::IntoFuture,
+	sync::{Arc, Mutex, atomic::AtomicUsize},
+};
+
+use axum::{Json, Router, extract::State, routing};
+use serde_json::Value;
+use tokio::{
+	net::TcpListener,
+	sync::{oneshot, oneshot::Sender},
+};
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank, chunking::ChunkingConfig};
+use elf_config::EmbeddingProviderConfig;
+use elf_service::{
+	AddNoteInput, AddNoteRequest, ElfService, Providers, StandingQueryCreateRequest,
+	StandingQueryFilter, StandingQueryMatchesRequest,
+};
+use elf_storage::{db::Db, qdrant::QdrantStore};
+use elf_worker::worker::{self, WorkerState};
+
+const TENANT_ID: &str = "tenant-standing";
+const PROJECT_ID: &str = "project-standing";
+const AGENT_ID: &str = "agent-standing";
+const ROUTED_TYPE: &str = "decision";
+const QUERY: &str = "Which database does the team deploy?";
+
+type RecordedInputs = Arc<Mutex<Vec<String>>>;
+
+/// Starts an embedding server that records every input and returns one constant vector per input.
+async fn start_recording_embed_server(inputs: RecordedInputs) -> (String, Sender<()>) {
+	let app = Router::new()
+		.route("/embeddings", routing::post(recording_embed_handler))
+		.with_state(inputs);
+	let listener = TcpListener::bind("127.0.0.1:0").await.expect("Failed to bind embed server.");
+	let addr = listener.local_addr().expect("Failed to read embed server address.");
+	let (tx, rx) = oneshot::channel();
+	let server = axum::serve(listener, app).with_graceful_shutdown(async move {
+		let _ = rx.await;
+	});
+
+	tokio::spawn(async move {
+		let _ = server.into_future().await;
+	});
+
+	(format!("http://{addr}"), tx)
+}
+
+async fn recording_embed_handler(
+	State(inputs): State<RecordedInputs>,
+	Json(payload): Json<Value>,
+) -> Json<Value> {
+	let texts: Vec<String> = payload
+		.get("input")
+		.and_then(|value| value.as_array())
+		.map(|items| items.iter().filter_map(|item| item.as_str().map(str::to_string)).collect())
+		.unwrap_or_default();
+	let data: Vec<_> = texts
+		.iter()
+		.enumerate()
+		.map(|(index, _)| serde_json::json!({ "index": index, "embedding": vec![0.1_f32; 4_096] }))
+		.collect();
+
+	inputs.lock().expect("recorded inputs lock").extend(texts);
+
+	Json(serde_json::json!({ "data": data }))
+}
+
+/// Builds a worker with the embedding routes resolved from the service config.
+async fn routed_worker_state(service: &ElfService) -> WorkerState {
+	let tokenizer = elf_chunking::load_tokenizer(&service.cfg.chunking.tokenizer_repo)
+		.expect("worker tokenizer should load");
+
+	WorkerState {
+		db: Db::connect(&service.cfg.storage.postgres).await.expect("Failed to connect worker DB."),
+		qdrant: QdrantStore::new(&service.cfg.storage.qdrant)
+			.expect("Failed to build Qdrant store."),
+		docs_qdrant: QdrantStore::new_with_collection(
+			&service.cfg.storage.qdrant,
+			&service.cfg.storage.qdrant.docs_collection,
+		)
+		.expect("Failed to build docs Qdrant store."),
+		embedding: service.cfg.providers.embedding.clone(),
+		embedding_scopes: service.cfg.providers.embedding_scope_providers(),
+		embedding_types: service.cfg.providers.embedding_type_providers(),
+		chunking: ChunkingConfig {
+			max_tokens: service.cfg.chunking.max_tokens,
+			overlap_tokens: service.cfg.chunking.overlap_tokens,
+		},
+		chunking_per_type: Default::default(),
+		tokenizer,
+		url_snapshots: None,
+		egress: None,
+		qdrant_maintenance: None,
+		trash_retention_days: None,
+		note_consolidation: None,
+		lifecycle: None,
+		importance_rescore: None,
+		indexing_concurrency: 1,
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn standing_query_embeds_with_the_note_type_route() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!(
+			"Skipping standing_query_embeds_with_the_note_type_route; set ELF_PG_DSN to run this test."
+		);
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping standing_query_embeds_with_the_note_type_route; set ELF_QDRANT_URL to run this test."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let inputs = RecordedInputs::default();
+	let (api_base, shutdown) = start_recording_embed_server(inputs.clone()).await;
+	let mut cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		test_db.collection_name("elf_acceptance"),
+		test_db.collection_name("elf_acceptance_docs"),
+	);
+
+	// The default provider is unreachable, so only the type route can embed for the worker.
+	cfg.providers.embeddings = Some(HashMap::from([(
+		"routed".to_string(),
+		EmbeddingProviderConfig {
+			provider_id: "routed".to_string(),
+			api_base,
+			path: "/embeddings".to_string(),
+			model: "routed-model".to_string(),
+			..acceptance::dummy_embedding_provider()
+		},
+	)]));
+	cfg.providers.embedding_types =
+		Some(HashMap::from([(ROUTED_TYPE.to_string(), "routed".to_string())]));
+
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+	acceptance::reset_qdrant_collection(
+		&service.qdrant.client,
+		&service.qdrant.collection,
+		service.qdrant.vector_dim,
+	)
+	.await
+	.expect("Failed to reset Qdrant collection.");
+
+	let worker_state = routed_worker_state(&service).await;
+	let standing_query = service
+		.standing_query_create(StandingQueryCreateRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			read_profile: "private_plus_project".to_string(),
+			query: QUERY.to_string(),
+			top_k: Some(5),
+			filter: StandingQueryFilter::default(),
+			webhook_url: None,
+		})
+		.await
+		.expect("Failed to create standing query.");
+	let added = service
+		.add_note(AddNoteRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			role: None,
+			scope: "agent_private".to_string(),
+			notes: vec![AddNoteInput {
+				r#type: ROUTED_TYPE.to_string(),
+				key: Some("deploy_database".to_string()),
+				text: "Decision: The team deploys Postgres as the primary database.".to_string(),
+				structured: None,
+				importance: 0.6,
+				confidence: 0.9,
+				ttl_days: None,
+				source_ref: serde_json::json!({}),
+				write_policy: None,
+			}],
+		})
+		.await
+		.expect("Failed to add note.");
+	let note_id = added.results[0].note_id.expect("Expected note_id in add_note result.");
+
+	worker::process_once(&worker_state).await.expect("worker should process once");
+
+	let matches = service
+		.standing_query_matches(StandingQueryMatchesRequest {
+			tenant_id: TENANT_ID.to_string(),
+			project_id: PROJECT_ID.to_string(),
+			agent_id: AGENT_ID.to_string(),
+			standing_query_id: standing_query.standing_query_id,
+			after_seq: None,
+			limit: None,
+		})
+		.await
+		.expect("Failed to read standing query matches.");
+	let recorded = inputs.lock().expect("recorded inputs lock").clone();
+
+	assert!(recorded.iter().any(|text| text == QUERY));
+	assert_eq!(matches.items.len(), 1);
+	assert_eq!(matches.items[0].note_id, note_id);
+	assert!(matches.items[0].embedding_version.starts_with("routed:routed-model:"));
+
+	let _ = shutdown.send(());
+
+	drop(service);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
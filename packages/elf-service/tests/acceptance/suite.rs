@@ -1,4 +1,6 @@
 mod add_note_no_llm;
+mod admin_grants;
+mod audit_log;
 mod chunk_search;
 mod chunking;
 #[path = "suite/config.rs"] mod config;
@@ -10,14 +12,28 @@ mod graph_ingestion;
 mod idempotency;
 mod knowledge_pages;
 mod memory_history;
+mod note_access_stats;
+mod note_batch;
+mod note_events;
+mod note_merge;
+mod note_publish_permissions;
+mod note_relations;
+mod note_trash;
 mod outbox_eventual_consistency;
+mod pii_redaction;
 #[path = "suite/providers.rs"] mod providers;
 mod rebuild_qdrant;
 #[path = "suite/runtime.rs"] mod runtime;
+mod search_result_watermarks;
+mod session_memory;
+mod snapshot_restore;
 mod sot_vectors;
+mod standing_queries;
 mod structured_field_retrieval;
+mod tenant_quotas;
 mod trace_admin_observability;
 mod work_journal;
+mod write_anomaly;
 
 pub(crate) use self::{
 	config::{dummy_embedding_provider, test_config, test_qdrant_url},
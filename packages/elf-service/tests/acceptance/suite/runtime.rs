@@ -27,10 +27,15 @@ pub(crate) enum AcceptanceFailure {
 	#[error(transparent)]
 	Sqlx(#[from] sqlx::Error),
 	#[error(transparent)]
-	Qdrant(#[from] QdrantError),
+	Qdrant(#[from] Box<QdrantError>),
 	#[error("{0}")]
 	Message(String),
 }
+impl From<QdrantError> for AcceptanceFailure {
+	fn from(err: QdrantError) -> Self {
+		Self::Qdrant(Box::new(err))
+	}
+}
 
 pub(crate) async fn test_db() -> Option<TestDatabase> {
 	let base_dsn = elf_testkit::env_dsn()?;
@@ -109,20 +114,34 @@ where
 	sqlx::query(
 		"\
 TRUNCATE
+	audit_log,
 	graph_entities,
 	graph_entity_aliases,
+	graph_entity_events,
 	graph_predicates,
 	graph_predicate_aliases,
+	graph_entity_kinds,
 	graph_facts,
 	graph_fact_evidence,
 	graph_fact_supersessions,
 	memory_hits,
+	memory_session_hits,
+	memory_feedback,
 	memory_ingest_decisions,
+	memory_note_events,
+	memory_note_relations,
 	memory_note_versions,
 	memory_space_grants,
+	memory_space_grant_versions,
+	memory_session_messages,
 	note_field_embeddings,
 	memory_note_fields,
+	memory_note_evidence,
+	memory_note_values,
+	memory_write_incidents,
+	qdrant_maintenance_runs,
 	note_chunk_embeddings,
+	chunk_content_embeddings,
 	memory_note_chunks,
 	note_embeddings,
 	search_trace_items,
@@ -132,6 +151,14 @@ TRUNCATE
 	search_trace_outbox,
 	search_sessions,
 	search_trace_candidates,
+	search_shadow_comparisons,
+	search_profiles,
+	search_result_watermarks,
+	eval_continuous_runs,
+	standing_query_matches,
+	standing_query_embeddings,
+	standing_queries,
+	source_url_snapshots,
 	indexing_outbox,
 	doc_indexing_outbox,
 	doc_chunk_embeddings,
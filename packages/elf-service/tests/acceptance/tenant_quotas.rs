@@ -0,0 +1,97 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_config::SecurityQuotas;
+use elf_service::{AddNoteInput, AddNoteRequest, Error, NoteOp, Providers, QuotaUsageRequest};
+
+fn note_request(step: usize) -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: "tenant-quota".to_string(),
+		project_id: "project-quota".to_string(),
+		agent_id: "agent-quota".to_string(),
+		role: None,
+		scope: "agent_private".to_string(),
+		notes: vec![AddNoteInput {
+			r#type: "fact".to_string(),
+			key: Some(format!("quota_fact_{step}")),
+			text: format!("Fact: Quota fixture number {step} describes a distinct service."),
+			structured: None,
+			importance: 0.5,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({ "schema": "acceptance/tenant_quotas" }),
+			write_policy: None,
+		}],
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn tenant_quotas_reject_notes_past_the_daily_limit() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!("Skipping tenant_quotas_reject_notes_past_the_daily_limit; set ELF_PG_DSN.");
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!("Skipping tenant_quotas_reject_notes_past_the_daily_limit; set ELF_QDRANT_URL.");
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_tenant_quotas");
+	let docs_collection = test_db.collection_name("elf_tenant_quotas_docs");
+	let mut cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+
+	cfg.security.quotas = Some(SecurityQuotas {
+		max_notes_per_day: Some(2),
+		max_search_qps: Some(1),
+		max_total_notes: None,
+	});
+
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	for step in 1..=2 {
+		let response = service.add_note(note_request(step)).await.expect("add_note failed.");
+
+		assert_eq!(response.results[0].op, NoteOp::Add);
+	}
+
+	let err = service.add_note(note_request(3)).await.expect_err("Expected a quota error.");
+
+	assert!(matches!(err, Error::QuotaExceeded { .. }), "Unexpected error: {err:?}");
+	assert!(service.check_search_quota("tenant-quota").is_ok());
+	assert!(service.check_search_quota("tenant-quota").is_err());
+
+	let usage = service
+		.quota_usage(QuotaUsageRequest { tenant_id: "tenant-quota".to_string() })
+		.await
+		.expect("quota usage should be reported");
+	let daily = usage
+		.quotas
+		.iter()
+		.find(|item| item.quota == "max_notes_per_day")
+		.expect("Expected the daily note quota.");
+
+	assert_eq!(daily.limit, Some(2));
+	assert_eq!(daily.used, 2);
+	assert_eq!(usage.searches_throttled, 1);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -1,8 +1,9 @@
 mod helpers;
 
 pub(crate) use helpers::{
-	PROJECT_ID, TENANT_ID, TraceAdminObservabilityFixture, VisibilityTraceFixtureIds,
-	assertions::assert_trace_admin_visibility_cross_scope,
+	PROJECT_ID, SUPER_ADMIN_TOKEN_ID, TENANT_ID, TraceAdminObservabilityFixture,
+	VisibilityTraceFixtureIds,
+	assertions::assert_trace_visibility_rules,
 	inserts::{
 		insert_trace, insert_trace_candidate, insert_trace_item, insert_trace_stage,
 		insert_trace_stage_item,
@@ -14,7 +15,10 @@ pub(crate) use helpers::{
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use elf_service::{TraceBundleGetRequest, search::TraceBundleMode};
+use elf_service::{
+	Error, SearchFeedbackKind, SearchFeedbackRequest, TraceBundleGetRequest,
+	search::TraceBundleMode,
+};
 
 #[tokio::test]
 #[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
@@ -24,9 +28,10 @@ async fn trace_admin_visibility_and_recent_list_cursor() {
 	};
 	let TraceAdminObservabilityFixture { service, test_db } = fixture;
 	let now = OffsetDateTime::now_utc();
-	let VisibilityTraceFixtureIds { trace_one, trace_two, trace_three, item_two } =
+	let VisibilityTraceFixtureIds { trace_one, trace_two, trace_three, item_one, item_two } =
 		seed_visibility_and_recent_list_traces(&service, now).await;
-	let first = trace_recent_list_page(&service, None, None).await;
+	let admin = Some(SUPER_ADMIN_TOKEN_ID);
+	let first = trace_recent_list_page(&service, "admin_agent", admin, None, None).await;
 
 	assert_eq!(first.schema, "elf.recent_traces/v1");
 	assert_eq!(first.traces.len(), 2);
@@ -37,14 +42,32 @@ async fn trace_admin_visibility_and_recent_list_cursor() {
 	let Some(cursor) = first.next_cursor else {
 		panic!("Expected next_cursor to exist for second page.");
 	};
-	let second =
-		trace_recent_list_page(&service, Some(cursor.created_at), Some(cursor.trace_id)).await;
+	let second = trace_recent_list_page(
+		&service,
+		"admin_agent",
+		admin,
+		Some(cursor.created_at),
+		Some(cursor.trace_id),
+	)
+	.await;
 
 	assert_eq!(second.traces.len(), 1);
 	assert_eq!(second.traces[0].trace_id, trace_three);
 	assert!(second.next_cursor.is_none());
 
-	assert_trace_admin_visibility_cross_scope(&service, trace_two, item_two).await;
+	let unprivileged = trace_recent_list_page(&service, "different_agent", None, None, None).await;
+
+	assert_eq!(unprivileged.traces.len(), 1);
+	assert_eq!(unprivileged.traces[0].trace_id, trace_one);
+
+	let owner = trace_recent_list_page(&service, "agent_two", None, None, None).await;
+
+	assert_eq!(
+		owner.traces.iter().map(|trace| trace.trace_id).collect::<Vec<_>>(),
+		vec![trace_one, trace_two]
+	);
+
+	assert_trace_visibility_rules(&service, trace_two, item_two, trace_one, item_one).await;
 
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
@@ -99,6 +122,7 @@ async fn trace_bundle_truncation_and_candidate_limits() {
 			tenant_id: TENANT_ID.to_string(),
 			project_id: PROJECT_ID.to_string(),
 			agent_id: "admin_agent".to_string(),
+			token_id: Some(SUPER_ADMIN_TOKEN_ID.to_string()),
 			trace_id,
 			mode: TraceBundleMode::Bounded,
 			stage_items_limit: Some(1),
@@ -117,6 +141,7 @@ async fn trace_bundle_truncation_and_candidate_limits() {
 			tenant_id: TENANT_ID.to_string(),
 			project_id: PROJECT_ID.to_string(),
 			agent_id: "admin_agent".to_string(),
+			token_id: Some(SUPER_ADMIN_TOKEN_ID.to_string()),
 			trace_id,
 			mode: TraceBundleMode::Full,
 			stage_items_limit: Some(1),
@@ -141,3 +166,72 @@ async fn trace_bundle_truncation_and_candidate_limits() {
 
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn search_feedback_keeps_one_judgement_per_agent_and_result() {
+	let Some(fixture) =
+		setup_service("search_feedback_keeps_one_judgement_per_agent_and_result").await
+	else {
+		return;
+	};
+	let TraceAdminObservabilityFixture { service, test_db } = fixture;
+	let now = OffsetDateTime::now_utc();
+	let VisibilityTraceFixtureIds { trace_one, trace_two, item_one, item_two, .. } =
+		seed_visibility_and_recent_list_traces(&service, now).await;
+	let feedback_request = |agent_id: &str, result_handle, feedback| SearchFeedbackRequest {
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: agent_id.to_string(),
+		token_id: None,
+		result_handle,
+		feedback,
+	};
+	let wrong = service
+		.record_feedback(feedback_request("agent_two", item_two, SearchFeedbackKind::Wrong))
+		.await
+		.expect("Failed to record feedback.");
+
+	assert_eq!(wrong.trace_id, trace_two);
+	assert_eq!(wrong.feedback, SearchFeedbackKind::Wrong);
+
+	service
+		.record_feedback(feedback_request("agent_two", item_two, SearchFeedbackKind::Useful))
+		.await
+		.expect("Failed to replace feedback.");
+
+	let hidden = service
+		.record_feedback(feedback_request("different_agent", item_two, SearchFeedbackKind::Wrong))
+		.await
+		.expect_err("Expected another agent's private result to stay hidden.");
+
+	assert!(matches!(hidden, Error::InvalidRequest { .. }));
+
+	let shared = service
+		.record_feedback(feedback_request(
+			"different_agent",
+			item_one,
+			SearchFeedbackKind::NotUseful,
+		))
+		.await
+		.expect("Failed to record feedback on a shared result.");
+
+	assert_eq!(shared.trace_id, trace_one);
+
+	let unknown = service
+		.record_feedback(feedback_request("agent_two", Uuid::new_v4(), SearchFeedbackKind::Useful))
+		.await
+		.expect_err("Expected an unknown result handle to be rejected.");
+
+	assert!(matches!(unknown, Error::InvalidRequest { .. }));
+
+	let rows: Vec<(Uuid, String)> =
+		sqlx::query_as("SELECT result_handle, feedback FROM memory_feedback ORDER BY feedback ASC")
+			.fetch_all(&service.db.pool)
+			.await
+			.expect("Failed to load feedback rows.");
+
+	assert_eq!(rows, vec![(item_one, "not_useful".to_string()), (item_two, "useful".to_string())]);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
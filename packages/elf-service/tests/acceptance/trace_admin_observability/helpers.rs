@@ -11,6 +11,7 @@ use elf_testkit::TestDatabase;
 pub(crate) const TENANT_ID: &str = "tenant_admin_scope";
 pub(crate) const PROJECT_ID: &str = "project_admin_scope";
 pub(crate) const TRACE_VERSION: i32 = 3;
+pub(crate) const SUPER_ADMIN_TOKEN_ID: &str = "trace_super_admin";
 
 pub(crate) struct TraceAdminObservabilityFixture {
 	pub(crate) service: ElfService,
@@ -21,5 +22,6 @@ pub(crate) struct VisibilityTraceFixtureIds {
 	pub(crate) trace_one: Uuid,
 	pub(crate) trace_two: Uuid,
 	pub(crate) trace_three: Uuid,
+	pub(crate) item_one: Uuid,
 	pub(crate) item_two: Uuid,
 }
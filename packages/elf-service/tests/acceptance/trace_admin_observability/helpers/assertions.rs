@@ -1,47 +1,110 @@
 use uuid::Uuid;
 
-use crate::acceptance::trace_admin_observability::helpers::{PROJECT_ID, TENANT_ID};
-use elf_service::{ElfService, SearchExplainRequest, TraceGetRequest, TraceTrajectoryGetRequest};
+use crate::acceptance::trace_admin_observability::helpers::{
+	PROJECT_ID, SUPER_ADMIN_TOKEN_ID, TENANT_ID,
+};
+use elf_service::{
+	ElfService, Error, SearchExplainRequest, TraceGetRequest, TraceTrajectoryGetRequest,
+};
 
-pub(crate) async fn assert_trace_admin_visibility_cross_scope(
+pub(crate) async fn assert_trace_visibility_rules(
 	service: &ElfService,
-	trace_id: Uuid,
-	item_id: Uuid,
+	private_trace_id: Uuid,
+	private_item_id: Uuid,
+	shared_trace_id: Uuid,
+	shared_item_id: Uuid,
 ) {
-	let cross_agent_trace_get = service
-		.trace_get(TraceGetRequest {
-			tenant_id: TENANT_ID.to_string(),
-			project_id: PROJECT_ID.to_string(),
-			agent_id: "different_agent".to_string(),
-			trace_id,
-		})
+	let hidden_trace_get = service
+		.trace_get(trace_get_request("different_agent", None, private_trace_id))
 		.await
-		.expect("Expected cross-agent trace lookup to bypass agent ownership filtering.");
+		.expect_err("Expected another agent's private trace to stay hidden.");
 
-	assert_eq!(cross_agent_trace_get.trace.trace_id, trace_id);
-	assert_eq!(cross_agent_trace_get.trace.agent_id, "agent_two");
+	assert!(matches!(hidden_trace_get, Error::InvalidRequest { .. }));
 
-	let cross_agent_trajectory = service
+	let hidden_trajectory = service
 		.trace_trajectory_get(TraceTrajectoryGetRequest {
 			tenant_id: TENANT_ID.to_string(),
 			project_id: PROJECT_ID.to_string(),
 			agent_id: "different_agent".to_string(),
-			trace_id,
+			token_id: None,
+			trace_id: private_trace_id,
 		})
 		.await
-		.expect("Expected cross-agent trajectory lookup to bypass agent ownership filtering.");
+		.expect_err("Expected another agent's private trajectory to stay hidden.");
 
-	assert_eq!(cross_agent_trajectory.trace.trace_id, trace_id);
+	assert!(matches!(hidden_trajectory, Error::InvalidRequest { .. }));
 
-	let cross_agent_item = service
-		.search_explain(SearchExplainRequest {
-			tenant_id: TENANT_ID.to_string(),
-			project_id: PROJECT_ID.to_string(),
-			agent_id: "different_agent".to_string(),
-			result_handle: item_id,
-		})
+	let hidden_item = service
+		.search_explain(explain_request("different_agent", None, private_item_id))
+		.await
+		.expect_err("Expected another agent's private trace item to stay hidden.");
+
+	assert!(matches!(hidden_item, Error::InvalidRequest { .. }));
+
+	let owner_trace_get = service
+		.trace_get(trace_get_request("agent_two", None, private_trace_id))
+		.await
+		.expect("Expected the owner agent to read its trace.");
+
+	assert_eq!(owner_trace_get.trace.agent_id, "agent_two");
+
+	let admin_trace_get = service
+		.trace_get(trace_get_request(
+			"different_agent",
+			Some(SUPER_ADMIN_TOKEN_ID),
+			private_trace_id,
+		))
 		.await
-		.expect("Expected cross-agent trace-item lookup to bypass agent ownership filtering.");
+		.expect("Expected a super_admin token to override trace ownership.");
+
+	assert_eq!(admin_trace_get.trace.trace_id, private_trace_id);
+
+	let admin_item = service
+		.search_explain(explain_request(
+			"different_agent",
+			Some(SUPER_ADMIN_TOKEN_ID),
+			private_item_id,
+		))
+		.await
+		.expect("Expected a super_admin token to override trace-item ownership.");
+
+	assert_eq!(admin_item.item.result_handle, private_item_id);
+
+	let shared_trace_get = service
+		.trace_get(trace_get_request("different_agent", None, shared_trace_id))
+		.await
+		.expect("Expected a shared-scope trace to be readable by other agents.");
+
+	assert_eq!(shared_trace_get.trace.agent_id, "agent_one");
+
+	let shared_item = service
+		.search_explain(explain_request("different_agent", None, shared_item_id))
+		.await
+		.expect("Expected a shared-scope trace item to be readable by other agents.");
+
+	assert_eq!(shared_item.item.result_handle, shared_item_id);
+}
+
+fn trace_get_request(agent_id: &str, token_id: Option<&str>, trace_id: Uuid) -> TraceGetRequest {
+	TraceGetRequest {
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: agent_id.to_string(),
+		token_id: token_id.map(str::to_string),
+		trace_id,
+	}
+}
 
-	assert_eq!(cross_agent_item.item.result_handle, item_id);
+fn explain_request(
+	agent_id: &str,
+	token_id: Option<&str>,
+	result_handle: Uuid,
+) -> SearchExplainRequest {
+	SearchExplainRequest {
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: agent_id.to_string(),
+		token_id: token_id.map(str::to_string),
+		result_handle,
+	}
 }
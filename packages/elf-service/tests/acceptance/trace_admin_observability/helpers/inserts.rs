@@ -71,6 +71,47 @@ INSERT INTO search_traces (
 	.expect("Failed to insert trace.");
 }
 
+pub(crate) async fn insert_note(
+	executor: &PgPool,
+	note_id: Uuid,
+	agent_id: &str,
+	scope: &str,
+	created_at: OffsetDateTime,
+) {
+	sqlx::query(
+		"\
+INSERT INTO memory_notes (
+	note_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	scope,
+	type,
+	key,
+	text,
+	importance,
+	confidence,
+	status,
+	created_at,
+	updated_at,
+	expires_at,
+	embedding_version,
+	source_ref
+)
+VALUES ($1, $2, $3, $4, $5, 'fact', NULL, 'Fact: Trace visibility fixture note.', 0.5, 0.9, 'active', $6, $6, NULL, 'test:embedding:4096', $7)",
+	)
+	.bind(note_id)
+	.bind(TENANT_ID)
+	.bind(PROJECT_ID)
+	.bind(agent_id)
+	.bind(scope)
+	.bind(created_at)
+	.bind(serde_json::json!({ "schema": "trace_visibility_test/v1" }))
+	.execute(executor)
+	.await
+	.expect("Failed to insert note.");
+}
+
 pub(crate) async fn insert_trace_item(
 	executor: &PgPool,
 	item_id: Uuid,
@@ -231,6 +272,9 @@ VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)",
 			note_updated_at: created_at,
 			note_hit_count: 12,
 			note_last_hit_at: None,
+			note_evidence_coverage: None,
+			note_session_adjustment: None,
+			note_feedback_adjustment: None,
 			diversity_selected: None,
 			diversity_selected_rank: None,
 			diversity_selected_reason: None,
@@ -239,6 +283,7 @@ VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)",
 			diversity_similarity: None,
 			diversity_mmr_score: None,
 			diversity_missing_embedding: None,
+			diversity_balance_penalty: None,
 		};
 
 		serde_json::to_value(candidate_snapshot)
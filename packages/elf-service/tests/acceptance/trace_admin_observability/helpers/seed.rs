@@ -43,6 +43,9 @@ pub(crate) async fn seed_visibility_and_recent_list_traces(
 		now - Duration::seconds(20),
 	)
 	.await;
+	// Only trace_one is shared: its sole item references a project_shared note.
+	inserts::insert_note(&service.db.pool, note_one, "agent_one", "project_shared", now).await;
+	inserts::insert_note(&service.db.pool, note_two, "agent_two", "agent_private", now).await;
 	inserts::insert_trace_item(&service.db.pool, item_one, trace_one, note_one, chunk_one, 1).await;
 	inserts::insert_trace_item(&service.db.pool, item_two, trace_two, note_two, chunk_two, 1).await;
 	inserts::insert_trace_item(
@@ -55,11 +58,13 @@ pub(crate) async fn seed_visibility_and_recent_list_traces(
 	)
 	.await;
 
-	VisibilityTraceFixtureIds { trace_one, trace_two, trace_three, item_two }
+	VisibilityTraceFixtureIds { trace_one, trace_two, trace_three, item_one, item_two }
 }
 
 pub(crate) async fn trace_recent_list_page(
 	service: &ElfService,
+	agent_id: &str,
+	token_id: Option<&str>,
 	cursor_created_at: Option<OffsetDateTime>,
 	cursor_trace_id: Option<Uuid>,
 ) -> TraceRecentListResponse {
@@ -67,7 +72,8 @@ pub(crate) async fn trace_recent_list_page(
 		.trace_recent_list(TraceRecentListRequest {
 			tenant_id: TENANT_ID.to_string(),
 			project_id: PROJECT_ID.to_string(),
-			agent_id: "admin_agent".to_string(),
+			agent_id: agent_id.to_string(),
+			token_id: token_id.map(str::to_string),
 			limit: Some(2),
 			cursor_created_at,
 			cursor_trace_id,
@@ -2,8 +2,11 @@ use std::sync::{Arc, atomic::AtomicUsize};
 
 use crate::acceptance::{
 	self, SpyExtractor, StubEmbedding, StubRerank,
-	trace_admin_observability::helpers::TraceAdminObservabilityFixture,
+	trace_admin_observability::helpers::{
+		PROJECT_ID, SUPER_ADMIN_TOKEN_ID, TENANT_ID, TraceAdminObservabilityFixture,
+	},
 };
+use elf_config::{SecurityAuthKey, SecurityAuthRole};
 use elf_service::Providers;
 
 pub(crate) async fn setup_service(test_name: &str) -> Option<TraceAdminObservabilityFixture> {
@@ -19,13 +22,26 @@ pub(crate) async fn setup_service(test_name: &str) -> Option<TraceAdminObservabi
 	};
 	let collection = test_db.collection_name("elf_acceptance");
 	let docs_collection = test_db.collection_name("elf_acceptance_docs");
-	let cfg = acceptance::test_config(
+	let mut cfg = acceptance::test_config(
 		test_db.dsn().to_string(),
 		qdrant_url,
 		4_096,
 		collection,
 		docs_collection,
 	);
+
+	// Trace visibility overrides come from super_admin tokens, which require static keys.
+	cfg.security.auth_mode = "static_keys".to_string();
+	cfg.security.auth_keys = vec![SecurityAuthKey {
+		token_id: SUPER_ADMIN_TOKEN_ID.to_string(),
+		token: "trace-super-admin-token".to_string(),
+		tenant_id: TENANT_ID.to_string(),
+		project_id: PROJECT_ID.to_string(),
+		agent_id: Some("admin_agent".to_string()),
+		read_profile: "all_scopes".to_string(),
+		role: SecurityAuthRole::SuperAdmin,
+	}];
+
 	let extractor = SpyExtractor {
 		calls: Arc::new(AtomicUsize::new(0)),
 		payload: serde_json::json!({ "notes": [] }),
@@ -0,0 +1,106 @@
+use std::sync::{Arc, atomic::AtomicUsize};
+
+use crate::acceptance::{self, SpyExtractor, StubEmbedding, StubRerank};
+use elf_config::MemoryWriteAnomaly;
+use elf_service::{
+	AddNoteInput, AddNoteRequest, AdminWriteIncidentsListRequest, NoteOp, Providers, RATE_ANOMALY,
+};
+
+fn loop_request(step: usize) -> AddNoteRequest {
+	AddNoteRequest {
+		tenant_id: "tenant-anomaly".to_string(),
+		project_id: "project-anomaly".to_string(),
+		agent_id: "agent-loop".to_string(),
+		role: None,
+		scope: "agent_private".to_string(),
+		notes: vec![AddNoteInput {
+			r#type: "fact".to_string(),
+			key: Some(format!("deploy_retry_{step}")),
+			text: format!("Fact: Retrying deploy step {step} for service api."),
+			structured: None,
+			importance: 0.5,
+			confidence: 0.9,
+			ttl_days: None,
+			source_ref: serde_json::json!({ "schema": "acceptance/write_anomaly" }),
+			write_policy: None,
+		}],
+	}
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres and Qdrant. Set ELF_PG_DSN and ELF_QDRANT_URL to run."]
+async fn write_anomaly_rejects_similar_write_loops_and_reports_incident() {
+	let Some(test_db) = acceptance::test_db().await else {
+		eprintln!(
+			"Skipping write_anomaly_rejects_similar_write_loops_and_reports_incident; set ELF_PG_DSN."
+		);
+
+		return;
+	};
+	let Some(qdrant_url) = acceptance::test_qdrant_url() else {
+		eprintln!(
+			"Skipping write_anomaly_rejects_similar_write_loops_and_reports_incident; set ELF_QDRANT_URL."
+		);
+
+		return;
+	};
+	let providers = Providers::new(
+		Arc::new(StubEmbedding { vector_dim: 4_096 }),
+		Arc::new(StubRerank),
+		Arc::new(SpyExtractor {
+			calls: Arc::new(AtomicUsize::new(0)),
+			payload: serde_json::json!({ "notes": [] }),
+		}),
+	);
+	let collection = test_db.collection_name("elf_write_anomaly");
+	let docs_collection = test_db.collection_name("elf_write_anomaly_docs");
+	let mut cfg = acceptance::test_config(
+		test_db.dsn().to_string(),
+		qdrant_url,
+		4_096,
+		collection,
+		docs_collection,
+	);
+
+	cfg.memory.write_anomaly = Some(MemoryWriteAnomaly {
+		window_seconds: 300,
+		max_similar_writes: 3,
+		similarity_threshold: 0.7,
+		action: "reject".to_string(),
+	});
+
+	let service =
+		acceptance::build_service(cfg, providers).await.expect("Failed to build service.");
+
+	acceptance::reset_db(&service.db.pool).await.expect("Failed to reset test database.");
+
+	for step in 1..=3 {
+		let response = service.add_note(loop_request(step)).await.expect("add_note failed.");
+
+		assert_eq!(response.results[0].op, NoteOp::Add);
+	}
+
+	for step in 4..=5 {
+		let response = service.add_note(loop_request(step)).await.expect("add_note failed.");
+
+		assert_eq!(response.results[0].op, NoteOp::Rejected);
+		assert_eq!(response.results[0].reason_code.as_deref(), Some(RATE_ANOMALY));
+	}
+
+	let incidents = service
+		.admin_write_incidents_list(AdminWriteIncidentsListRequest {
+			tenant_id: "tenant-anomaly".to_string(),
+			project_id: "project-anomaly".to_string(),
+			agent_id: Some("agent-loop".to_string()),
+			limit: None,
+		})
+		.await
+		.expect("incidents should be listable");
+
+	assert_eq!(incidents.incidents.len(), 1);
+	assert_eq!(incidents.incidents[0].flagged_writes, 2);
+	assert_eq!(incidents.incidents[0].action, "reject");
+	assert_eq!(incidents.incidents[0].peak_similar_writes, 3);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -36,3 +36,28 @@ fn qdrant_init_script_creates_docs_payload_indexes() {
 		"Docs payload indexing is not gated to ELF_QDRANT_DOCS_COLLECTION."
 	);
 }
+
+#[test]
+fn qdrant_init_script_creates_notes_payload_indexes() {
+	let script_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+	let script_path = script_path.join("..").join("qdrant").join("init.sh");
+	let script = fs::read_to_string(&script_path)
+		.unwrap_or_else(|err| panic!("Failed to read {}: {err}", script_path.display()));
+	let script = script.chars().filter(|ch| !ch.is_whitespace()).collect::<String>();
+
+	for (field, field_schema) in
+		[("type", "keyword"), ("updated_at", "datetime"), ("importance", "float")]
+	{
+		let needle = format!("\"field_name\":\"{field}\",\"field_schema\":\"{field_schema}\"");
+
+		assert!(
+			script.contains(&needle),
+			"Missing payload index for notes field {field} with schema {field_schema} in qdrant/init.sh"
+		);
+	}
+
+	assert!(
+		script.contains("\"${collection}\"==\"${ELF_QDRANT_COLLECTION}\""),
+		"Notes payload indexing is not gated to ELF_QDRANT_COLLECTION."
+	);
+}
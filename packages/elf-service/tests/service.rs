@@ -3,6 +3,7 @@
 //! Integration tests for service-layer note ingestion and policy behavior.
 
 mod service {
+	mod builder;
 	mod config;
 	mod note_ingestion;
 	mod providers;
@@ -0,0 +1,60 @@
+use std::{sync::Arc, time::Duration};
+
+use sqlx::postgres::PgPoolOptions;
+use uuid::Uuid;
+
+use crate::service::{
+	config,
+	providers::{DummyEmbedding, DummyRerank, SpyExtractor},
+};
+use elf_service::{DocsGetRequest, ElfService, Error, Providers, ServiceSubsystems};
+use elf_storage::{db::Db, qdrant::QdrantStore};
+
+fn builder() -> elf_service::ElfServiceBuilder {
+	let cfg = config::test_config();
+	// Failed calls still write an audit row; fail that write fast when no database is running.
+	let pool = PgPoolOptions::new()
+		.acquire_timeout(Duration::from_millis(200))
+		.connect_lazy(&cfg.storage.postgres.dsn)
+		.expect("Failed to create lazy pool.");
+	let db = Db { pool };
+	let qdrant = QdrantStore::new(&cfg.storage.qdrant).expect("Failed to create Qdrant store.");
+	let providers = Providers::new(
+		Arc::new(DummyEmbedding),
+		Arc::new(DummyRerank),
+		Arc::new(SpyExtractor::new()),
+	);
+
+	ElfService::builder(cfg, db, qdrant).providers(providers)
+}
+
+#[tokio::test]
+async fn builder_enables_every_subsystem_by_default() {
+	let service = builder().build();
+
+	assert_eq!(service.subsystems(), ServiceSubsystems::default());
+	assert!(service.subsystems().trace_persistence);
+	assert!(service.subsystems().graph);
+	assert!(service.subsystems().docs);
+	assert!(service.subsystems().caches);
+}
+
+#[tokio::test]
+async fn disabled_docs_subsystem_rejects_docs_calls() {
+	let service = builder().docs(false).caches(false).build();
+	let result = service
+		.docs_get(DocsGetRequest {
+			tenant_id: "t1".to_string(),
+			project_id: "p1".to_string(),
+			agent_id: "a1".to_string(),
+			read_profile: "private_only".to_string(),
+			doc_id: Uuid::new_v4(),
+		})
+		.await;
+
+	assert!(!service.subsystems().caches);
+	assert!(service.subsystems().graph);
+	assert!(
+		matches!(result, Err(Error::InvalidRequest { message }) if message.contains("docs subsystem"))
+	);
+}
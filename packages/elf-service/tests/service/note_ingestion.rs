@@ -1,6 +1,6 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use sqlx::PgPool;
+use sqlx::postgres::PgPoolOptions;
 
 use crate::service::{
 	config,
@@ -11,8 +11,11 @@ use elf_storage::{db::Db, qdrant::QdrantStore};
 
 fn build_service_with_spy(spy: Arc<SpyExtractor>) -> ElfService {
 	let cfg = config::test_config();
-	let pool =
-		PgPool::connect_lazy(&cfg.storage.postgres.dsn).expect("Failed to create lazy pool.");
+	// Failed calls still write an audit row; fail that write fast when no database is running.
+	let pool = PgPoolOptions::new()
+		.acquire_timeout(Duration::from_millis(200))
+		.connect_lazy(&cfg.storage.postgres.dsn)
+		.expect("Failed to create lazy pool.");
 	let db = Db { pool };
 	let qdrant = QdrantStore::new(&cfg.storage.qdrant).expect("Failed to create Qdrant store.");
 	let providers = Providers::new(Arc::new(DummyEmbedding), Arc::new(DummyRerank), spy);
@@ -29,6 +32,7 @@ async fn add_note_does_not_call_llm() {
 		tenant_id: "t1".to_string(),
 		project_id: "p1".to_string(),
 		agent_id: "a1".to_string(),
+		role: None,
 		scope: "agent_private".to_string(),
 		notes: vec![AddNoteInput {
 			r#type: "fact".to_string(),
@@ -57,6 +61,7 @@ async fn add_note_rejects_empty_notes() {
 		tenant_id: "t1".to_string(),
 		project_id: "p1".to_string(),
 		agent_id: "a1".to_string(),
+		role: None,
 		scope: "agent_private".to_string(),
 		notes: vec![],
 	};
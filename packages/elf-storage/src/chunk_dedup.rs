@@ -0,0 +1,153 @@
+//! Content-hash keyed chunk embeddings shared across memory note chunks.
+
+use sqlx::{FromRow, PgExecutor};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::Result;
+
+/// Shared embedding stored for one chunk content hash.
+#[derive(Clone, Debug, FromRow)]
+pub struct ChunkContentEmbedding {
+	/// BLAKE3 hex digest of the chunk text.
+	pub content_hash: String,
+	/// Embedding vector rendered in pgvector text form.
+	pub vec_text: String,
+}
+
+/// Loads shared embeddings for the given content hashes under one embedding version.
+pub async fn fetch_chunk_content_embeddings<'e, E>(
+	executor: E,
+	embedding_version: &str,
+	content_hashes: &[String],
+) -> Result<Vec<ChunkContentEmbedding>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, ChunkContentEmbedding>(
+		"\
+SELECT content_hash, vec::text AS vec_text
+FROM chunk_content_embeddings
+WHERE embedding_version = $1 AND content_hash = ANY($2)",
+	)
+	.bind(embedding_version)
+	.bind(content_hashes)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Adds `refs` references to a shared embedding, inserting it when the hash is new.
+pub async fn acquire_chunk_content_embedding<'e, E>(
+	executor: E,
+	content_hash: &str,
+	embedding_version: &str,
+	embedding_dim: i32,
+	vec: &str,
+	refs: i32,
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+INSERT INTO chunk_content_embeddings (
+	content_hash,
+	embedding_version,
+	embedding_dim,
+	vec,
+	ref_count,
+	created_at,
+	updated_at
+)
+VALUES ($1, $2, $3, $4::text::vector, $5, $6, $6)
+ON CONFLICT (content_hash, embedding_version) DO UPDATE
+SET
+	ref_count = chunk_content_embeddings.ref_count + EXCLUDED.ref_count,
+	updated_at = EXCLUDED.updated_at",
+	)
+	.bind(content_hash)
+	.bind(embedding_version)
+	.bind(embedding_dim)
+	.bind(vec)
+	.bind(refs)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Drops the shared-embedding references held by a note's chunks.
+///
+/// Released chunks have their `content_hash` cleared, so calling this again for the same note is a
+/// no-op. Returns the released hashes so callers can prune unreferenced rows.
+pub async fn release_note_chunk_refs<'e, E>(
+	executor: E,
+	note_id: Uuid,
+	now: OffsetDateTime,
+) -> Result<Vec<String>>
+where
+	E: PgExecutor<'e>,
+{
+	let hashes = sqlx::query_scalar::<_, String>(
+		"\
+WITH held AS (
+	SELECT chunk_id, content_hash
+	FROM memory_note_chunks
+	WHERE note_id = $1 AND content_hash IS NOT NULL
+	FOR UPDATE
+),
+released AS (
+	UPDATE memory_note_chunks c
+	SET content_hash = NULL
+	FROM held
+	WHERE c.chunk_id = held.chunk_id
+	RETURNING held.content_hash, c.embedding_version
+),
+counts AS (
+	SELECT content_hash, embedding_version, count(*)::int AS refs
+	FROM released
+	GROUP BY content_hash, embedding_version
+)
+UPDATE chunk_content_embeddings e
+SET
+	ref_count = e.ref_count - counts.refs,
+	updated_at = $2
+FROM counts
+WHERE e.content_hash = counts.content_hash AND e.embedding_version = counts.embedding_version
+RETURNING e.content_hash",
+	)
+	.bind(note_id)
+	.bind(now)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(hashes)
+}
+
+/// Deletes shared embeddings among `content_hashes` that no chunk references anymore.
+pub async fn prune_chunk_content_embeddings<'e, E>(
+	executor: E,
+	content_hashes: &[String],
+) -> Result<u64>
+where
+	E: PgExecutor<'e>,
+{
+	if content_hashes.is_empty() {
+		return Ok(0);
+	}
+
+	let result = sqlx::query(
+		"\
+DELETE FROM chunk_content_embeddings
+WHERE content_hash = ANY($1) AND ref_count <= 0",
+	)
+	.bind(content_hashes)
+	.execute(executor)
+	.await?;
+
+	Ok(result.rows_affected())
+}
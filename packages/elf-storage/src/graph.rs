@@ -1,18 +1,23 @@
 //! Graph entity, predicate, and fact storage helpers.
 
 mod entity;
+mod entity_kind;
 mod fact;
 mod predicate;
 
 pub use self::{
 	entity::{resolve_entity_by_surface, upsert_entity, upsert_entity_alias},
+	entity_kind::{
+		get_entity_kind_by_id, list_entity_kinds_by_scope_keys, promote_entity_kind_to_tenant,
+		resolve_or_register_entity_kind,
+	},
 	fact::{
 		fetch_active_facts_for_subject, insert_fact_with_evidence,
 		supersede_conflicting_active_facts, upsert_fact_with_evidence,
 	},
 	predicate::{
 		add_predicate_alias, get_predicate_by_id, list_predicate_aliases,
-		list_predicates_by_scope_keys, resolve_or_register_predicate,
+		list_predicates_by_scope_keys, promote_predicate_to_tenant, resolve_or_register_predicate,
 		resolve_predicate_no_register, update_predicate, update_predicate_guarded,
 	},
 };
@@ -28,6 +33,7 @@ use crate::{
 
 const GRAPH_PREDICATE_SCOPE_GLOBAL: &str = "__global__";
 const GRAPH_PREDICATE_SCOPE_PROJECT_PREFIX: &str = "__project__:";
+const GRAPH_PREDICATE_SCOPE_TENANT_PREFIX: &str = "__tenant__:";
 
 /// Normalizes graph entity surfaces for uniqueness and lookup.
 pub fn normalize_entity_name(input: &str) -> String {
@@ -46,3 +52,7 @@ fn predicate_scope_key_tenant_project(tenant_id: &str, project_id: &str) -> Stri
 fn predicate_scope_key_project(project_id: &str) -> String {
 	format!("{GRAPH_PREDICATE_SCOPE_PROJECT_PREFIX}{project_id}")
 }
+
+fn predicate_scope_key_tenant(tenant_id: &str) -> String {
+	format!("{GRAPH_PREDICATE_SCOPE_TENANT_PREFIX}{tenant_id}")
+}
@@ -0,0 +1,210 @@
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{Error, Result, graph, models::GraphEntityKind};
+
+/// Resolves an entity kind against the project and tenant vocabularies, registering a
+/// project-scoped kind when neither has it.
+///
+/// Project kinds take precedence over tenant kinds, so a project can keep its own casing for a
+/// kind the tenant also defines.
+pub async fn resolve_or_register_entity_kind(
+	executor: &mut PgConnection,
+	tenant_id: &str,
+	project_id: &str,
+	kind: &str,
+) -> Result<GraphEntityKind> {
+	let kind = kind.trim();
+	let kind_norm = graph::normalize_entity_name(kind);
+
+	if kind_norm.is_empty() {
+		return Err(Error::InvalidArgument(
+			"graph entity kind is required; kind must not be empty".to_string(),
+		));
+	}
+
+	let tenant_project_scope = graph::predicate_scope_key_tenant_project(tenant_id, project_id);
+	let tenant_scope = graph::predicate_scope_key_tenant(tenant_id);
+
+	for scope_key in [&tenant_project_scope, &tenant_scope] {
+		if let Some(row) = sqlx::query_as::<_, GraphEntityKind>(
+			"\
+SELECT
+	kind_id,
+	scope_key,
+	tenant_id,
+	project_id,
+	kind,
+	kind_norm,
+	created_at,
+	updated_at
+FROM graph_entity_kinds
+WHERE scope_key = $1 AND kind_norm = $2",
+		)
+		.bind(scope_key)
+		.bind(&kind_norm)
+		.fetch_optional(&mut *executor)
+		.await?
+		{
+			return Ok(row);
+		}
+	}
+
+	let row = sqlx::query_as::<_, GraphEntityKind>(
+		"\
+INSERT INTO graph_entity_kinds (
+	kind_id,
+	scope_key,
+	tenant_id,
+	project_id,
+	kind,
+	kind_norm,
+	created_at,
+	updated_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, now(), now())
+ON CONFLICT (scope_key, kind_norm)
+DO UPDATE
+SET kind = graph_entity_kinds.kind
+RETURNING
+	kind_id,
+	scope_key,
+	tenant_id,
+	project_id,
+	kind,
+	kind_norm,
+	created_at,
+	updated_at",
+	)
+	.bind(Uuid::new_v4())
+	.bind(&tenant_project_scope)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(kind)
+	.bind(&kind_norm)
+	.fetch_one(&mut *executor)
+	.await?;
+
+	Ok(row)
+}
+
+/// Lists entity kinds visible within the provided scope keys.
+pub async fn list_entity_kinds_by_scope_keys(
+	executor: &mut PgConnection,
+	scope_keys: &[String],
+) -> Result<Vec<GraphEntityKind>> {
+	if scope_keys.is_empty() {
+		return Ok(vec![]);
+	}
+
+	let rows = sqlx::query_as::<_, GraphEntityKind>(
+		"\
+SELECT
+	kind_id,
+	scope_key,
+	tenant_id,
+	project_id,
+	kind,
+	kind_norm,
+	created_at,
+	updated_at
+FROM graph_entity_kinds
+WHERE scope_key = ANY($1::text[])
+ORDER BY scope_key, kind_norm",
+	)
+	.bind(scope_keys)
+	.fetch_all(&mut *executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Fetches one entity kind by identifier.
+pub async fn get_entity_kind_by_id(
+	executor: &mut PgConnection,
+	kind_id: Uuid,
+) -> Result<Option<GraphEntityKind>> {
+	let row = sqlx::query_as::<_, GraphEntityKind>(
+		"\
+SELECT
+	kind_id,
+	scope_key,
+	tenant_id,
+	project_id,
+	kind,
+	kind_norm,
+	created_at,
+	updated_at
+FROM graph_entity_kinds
+WHERE kind_id = $1",
+	)
+	.bind(kind_id)
+	.fetch_optional(&mut *executor)
+	.await?;
+
+	Ok(row)
+}
+
+/// Moves a project-scoped entity kind to the tenant scope.
+pub async fn promote_entity_kind_to_tenant(
+	executor: &mut PgConnection,
+	kind_id: Uuid,
+) -> Result<GraphEntityKind> {
+	let kind = get_entity_kind_by_id(&mut *executor, kind_id).await?.ok_or_else(|| {
+		Error::NotFound(format!("graph entity kind not found; kind_id={kind_id}"))
+	})?;
+	let Some(project_id) = kind.project_id.as_deref() else {
+		return Err(Error::InvalidArgument(format!(
+			"graph entity kind is not project-scoped; scope_key={}",
+			kind.scope_key
+		)));
+	};
+
+	if kind.scope_key != graph::predicate_scope_key_tenant_project(&kind.tenant_id, project_id) {
+		return Err(Error::InvalidArgument(format!(
+			"graph entity kind is not project-scoped; scope_key={}",
+			kind.scope_key
+		)));
+	}
+
+	let tenant_scope = graph::predicate_scope_key_tenant(&kind.tenant_id);
+	let taken: Option<(Uuid,)> = sqlx::query_as(
+		"\
+SELECT kind_id
+FROM graph_entity_kinds
+WHERE scope_key = $1 AND kind_norm = $2",
+	)
+	.bind(&tenant_scope)
+	.bind(&kind.kind_norm)
+	.fetch_optional(&mut *executor)
+	.await?;
+
+	if let Some((existing_id,)) = taken {
+		return Err(Error::Conflict(format!(
+			"graph entity kind already exists at tenant scope; kind_id={existing_id} kind_norm={}",
+			kind.kind_norm
+		)));
+	}
+
+	let promoted = sqlx::query_as::<_, GraphEntityKind>(
+		"\
+UPDATE graph_entity_kinds
+SET scope_key = $2, project_id = NULL, updated_at = now()
+WHERE kind_id = $1
+RETURNING
+	kind_id,
+	scope_key,
+	tenant_id,
+	project_id,
+	kind,
+	kind_norm,
+	created_at,
+	updated_at",
+	)
+	.bind(kind_id)
+	.bind(&tenant_scope)
+	.fetch_one(&mut *executor)
+	.await?;
+
+	Ok(promoted)
+}
@@ -0,0 +1,101 @@
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{Error, Result, graph, models::GraphPredicate};
+
+/// Moves a tenant-project predicate and its aliases to the tenant scope.
+///
+/// The predicate keeps its identifier, so facts already bound to it stay valid. Other projects in
+/// the tenant resolve it unless they registered the same surface in their own scope.
+pub async fn promote_predicate_to_tenant(
+	executor: &mut PgConnection,
+	predicate_id: Uuid,
+) -> Result<GraphPredicate> {
+	let predicate =
+		graph::get_predicate_by_id(&mut *executor, predicate_id).await?.ok_or_else(|| {
+			Error::NotFound(format!("graph predicate not found; predicate_id={predicate_id}"))
+		})?;
+	let (Some(tenant_id), Some(project_id)) =
+		(predicate.tenant_id.as_deref(), predicate.project_id.as_deref())
+	else {
+		return Err(Error::InvalidArgument(format!(
+			"graph predicate is not project-scoped; scope_key={}",
+			predicate.scope_key
+		)));
+	};
+
+	if predicate.scope_key != graph::predicate_scope_key_tenant_project(tenant_id, project_id) {
+		return Err(Error::InvalidArgument(format!(
+			"graph predicate is not project-scoped; scope_key={}",
+			predicate.scope_key
+		)));
+	}
+
+	let tenant_scope = graph::predicate_scope_key_tenant(tenant_id);
+	let canonical_taken: Option<(Uuid,)> = sqlx::query_as(
+		"\
+SELECT predicate_id
+FROM graph_predicates
+WHERE scope_key = $1 AND canonical_norm = $2",
+	)
+	.bind(&tenant_scope)
+	.bind(&predicate.canonical_norm)
+	.fetch_optional(&mut *executor)
+	.await?;
+
+	if let Some((existing_id,)) = canonical_taken {
+		return Err(Error::Conflict(format!(
+			"graph predicate already exists at tenant scope; predicate_id={existing_id} canonical_norm={}",
+			predicate.canonical_norm
+		)));
+	}
+
+	let alias_taken: Option<(String,)> = sqlx::query_as(
+		"\
+SELECT tenant_alias.alias_norm
+FROM graph_predicate_aliases tenant_alias
+JOIN graph_predicate_aliases own_alias ON own_alias.alias_norm = tenant_alias.alias_norm
+WHERE tenant_alias.scope_key = $1 AND own_alias.predicate_id = $2
+LIMIT 1",
+	)
+	.bind(&tenant_scope)
+	.bind(predicate_id)
+	.fetch_optional(&mut *executor)
+	.await?;
+
+	if let Some((alias_norm,)) = alias_taken {
+		return Err(Error::Conflict(format!(
+			"graph predicate alias already bound at tenant scope; alias_norm={alias_norm}"
+		)));
+	}
+
+	let promoted = sqlx::query_as::<_, GraphPredicate>(
+		"\
+UPDATE graph_predicates
+SET scope_key = $2, project_id = NULL, updated_at = now()
+WHERE predicate_id = $1
+RETURNING
+	predicate_id,
+	scope_key,
+	tenant_id,
+	project_id,
+	canonical,
+	canonical_norm,
+	cardinality,
+	status,
+	created_at,
+	updated_at",
+	)
+	.bind(predicate_id)
+	.bind(&tenant_scope)
+	.fetch_one(&mut *executor)
+	.await?;
+
+	sqlx::query("UPDATE graph_predicate_aliases SET scope_key = $2 WHERE predicate_id = $1")
+		.bind(predicate_id)
+		.bind(&tenant_scope)
+		.execute(&mut *executor)
+		.await?;
+
+	Ok(promoted)
+}
@@ -25,9 +25,10 @@ pub async fn resolve_or_register_predicate(
 	let alias_norm = graph::normalize_predicate_name(predicate_surface);
 	let tenant_project_scope = graph::predicate_scope_key_tenant_project(tenant_id, project_id);
 	let project_scope = graph::predicate_scope_key_project(project_id);
+	let tenant_scope = graph::predicate_scope_key_tenant(tenant_id);
 	let global_scope = GRAPH_PREDICATE_SCOPE_GLOBAL.to_string();
 
-	for scope_key in [&tenant_project_scope, &project_scope, &global_scope] {
+	for scope_key in [&tenant_project_scope, &project_scope, &tenant_scope, &global_scope] {
 		if let Some(row) = sqlx::query_as::<_, GraphPredicate>(
 			"\
 SELECT
@@ -138,9 +139,10 @@ pub async fn resolve_predicate_no_register(
 	let alias_norm = graph::normalize_predicate_name(predicate_surface);
 	let tenant_project_scope = graph::predicate_scope_key_tenant_project(tenant_id, project_id);
 	let project_scope = graph::predicate_scope_key_project(project_id);
+	let tenant_scope = graph::predicate_scope_key_tenant(tenant_id);
 	let global_scope = GRAPH_PREDICATE_SCOPE_GLOBAL.to_string();
 
-	for scope_key in [&tenant_project_scope, &project_scope, &global_scope] {
+	for scope_key in [&tenant_project_scope, &project_scope, &tenant_scope, &global_scope] {
 		if let Some(row) = sqlx::query_as::<_, GraphPredicate>(
 			"\
 SELECT
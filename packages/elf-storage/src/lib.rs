@@ -2,6 +2,7 @@
 
 //! Storage adapters and row models for ELF persistence backends.
 
+pub mod chunk_dedup;
 pub mod consolidation;
 pub mod db;
 pub mod doc_outbox;
@@ -11,8 +12,15 @@ pub mod knowledge;
 pub mod models;
 pub mod outbox;
 pub mod qdrant;
+pub mod qdrant_maintenance;
 pub mod queries;
+pub mod reembed;
 pub mod schema;
+pub mod search_profiles;
+pub mod search_watermarks;
+pub mod sessions;
+pub mod standing_queries;
+pub mod url_snapshots;
 pub mod work_journal;
 
 mod error;
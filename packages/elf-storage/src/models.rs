@@ -6,6 +6,12 @@ mod graph;
 mod knowledge;
 mod notes;
 mod outbox;
+mod qdrant_maintenance;
+mod reembed;
+mod search_profiles;
+mod sessions;
+mod standing_queries;
+mod url_snapshots;
 mod work_journal;
 
 pub use self::{
@@ -15,13 +21,19 @@ pub use self::{
 	},
 	docs::{DocChunk, DocChunkEmbedding, DocDocument, DocIndexingOutboxEntry},
 	graph::{
-		GraphEntity, GraphEntityAlias, GraphFact, GraphFactEvidence, GraphFactSupersession,
-		GraphPredicate, GraphPredicateAlias,
+		GraphEntity, GraphEntityAlias, GraphEntityKind, GraphFact, GraphFactEvidence,
+		GraphFactSupersession, GraphPredicate, GraphPredicateAlias,
 	},
 	knowledge::{
 		KnowledgePage, KnowledgePageLintFinding, KnowledgePageSection, KnowledgePageSourceRef,
 	},
 	notes::{MemoryNote, MemoryNoteChunk, NoteChunkEmbedding, NoteEmbedding},
 	outbox::{IndexingOutboxEntry, TraceOutboxJob},
+	qdrant_maintenance::QdrantMaintenanceRun,
+	reembed::EmbeddingReembedRun,
+	search_profiles::SearchProfile,
+	sessions::SessionMessage,
+	standing_queries::{StandingQuery, StandingQueryMatch, StandingQueryNotification},
+	url_snapshots::SourceUrlSnapshot,
 	work_journal::WorkJournalEntry,
 };
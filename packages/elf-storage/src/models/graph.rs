@@ -84,6 +84,27 @@ pub struct GraphFactEvidence {
 	pub created_at: OffsetDateTime,
 }
 
+/// Persisted graph entity kind vocabulary row.
+#[derive(Debug, FromRow)]
+pub struct GraphEntityKind {
+	/// Entity kind identifier.
+	pub kind_id: Uuid,
+	/// Scope key where the kind is visible.
+	pub scope_key: String,
+	/// Tenant that owns the kind.
+	pub tenant_id: String,
+	/// Project scope, when project-specific.
+	pub project_id: Option<String>,
+	/// Canonical kind surface.
+	pub kind: String,
+	/// Normalized kind surface.
+	pub kind_norm: String,
+	/// Creation timestamp.
+	pub created_at: OffsetDateTime,
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
+
 /// Persisted graph predicate row.
 #[derive(Debug, FromRow)]
 pub struct GraphPredicate {
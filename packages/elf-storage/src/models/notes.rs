@@ -63,6 +63,8 @@ pub struct MemoryNoteChunk {
 	pub embedding_version: String,
 	/// Creation timestamp.
 	pub created_at: OffsetDateTime,
+	/// BLAKE3 hex digest of the chunk text while it holds a shared-embedding reference.
+	pub content_hash: Option<String>,
 }
 
 /// Persisted embedding row for one note chunk.
@@ -0,0 +1,29 @@
+use serde_json::Value;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// One recorded Qdrant maintenance run for a single collection.
+#[derive(Clone, Debug, FromRow)]
+pub struct QdrantMaintenanceRun {
+	/// Run identifier.
+	pub run_id: Uuid,
+	/// Operation: `optimize`, `snapshot`, `restore_snapshot`, or `verify_counts`.
+	pub operation: String,
+	/// Collection kind: `notes` or `docs`.
+	pub collection: String,
+	/// Run trigger: `scheduled` or `manual`.
+	pub trigger: String,
+	/// Run status: `succeeded` or `failed`.
+	pub status: String,
+	/// Operation-specific result details.
+	pub detail: Value,
+	/// Failure message for failed runs.
+	pub error: Option<String>,
+	/// Alert delivery status: `none`, `sent`, `failed`, or `not_configured`.
+	pub alert_status: String,
+	/// Run start time.
+	pub started_at: OffsetDateTime,
+	/// Run end time.
+	pub finished_at: OffsetDateTime,
+}
@@ -0,0 +1,36 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// One admin re-embed run toward a target embedding version.
+#[derive(Clone, Debug, FromRow)]
+pub struct EmbeddingReembedRun {
+	/// Run identifier.
+	pub run_id: Uuid,
+	/// Embedding version the run writes.
+	pub target_version: String,
+	/// Qdrant collection that receives the re-embedded points.
+	pub target_collection: String,
+	/// Vector dimension of the target embedding version.
+	pub target_dim: i32,
+	/// Run status: `running`, `completed`, or `activated`.
+	pub status: String,
+	/// Last note identifier the run finished; the next batch starts after it.
+	pub cursor_note_id: Option<Uuid>,
+	/// Notes re-embedded so far.
+	pub notes_processed: i64,
+	/// Chunk vectors written so far.
+	pub chunks_embedded: i64,
+	/// Structured field vectors written so far.
+	pub fields_embedded: i64,
+	/// Failure message of the most recent failed batch.
+	pub last_error: Option<String>,
+	/// Run start time.
+	pub started_at: OffsetDateTime,
+	/// Time of the last recorded progress.
+	pub updated_at: OffsetDateTime,
+	/// Time the run found no more notes to re-embed.
+	pub completed_at: Option<OffsetDateTime>,
+	/// Time the target version became the active version.
+	pub activated_at: Option<OffsetDateTime>,
+}
@@ -0,0 +1,28 @@
+use serde_json::Value;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+/// Persisted named search template shared by the agents of one project.
+#[derive(Clone, Debug, FromRow)]
+pub struct SearchProfile {
+	/// Tenant that owns the profile.
+	pub tenant_id: String,
+	/// Project that owns the profile.
+	pub project_id: String,
+	/// Profile name, unique within the project.
+	pub name: String,
+	/// Optional human-readable description.
+	pub description: Option<String>,
+	/// Stored search template.
+	pub template: Value,
+	/// Revision counter, incremented on every update.
+	pub version: i32,
+	/// Agent that created the profile.
+	pub created_by: String,
+	/// Agent that last updated the profile.
+	pub updated_by: String,
+	/// Creation timestamp.
+	pub created_at: OffsetDateTime,
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
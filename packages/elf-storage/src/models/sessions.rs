@@ -0,0 +1,29 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+/// Persisted short-term message in an agent working-memory session.
+#[derive(Clone, Debug, FromRow)]
+pub struct SessionMessage {
+	/// Append order within the whole table; increases within a session.
+	pub message_seq: i64,
+	/// Tenant that owns the session.
+	pub tenant_id: String,
+	/// Project that owns the session.
+	pub project_id: String,
+	/// Agent that owns the session.
+	pub agent_id: String,
+	/// Caller-chosen session identifier.
+	pub session_id: String,
+	/// Speaker or message role.
+	pub role: String,
+	/// Message body content.
+	pub content: String,
+	/// Optional source timestamp string.
+	pub ts: Option<String>,
+	/// Optional message identifier from the upstream source.
+	pub msg_id: Option<String>,
+	/// Append timestamp.
+	pub created_at: OffsetDateTime,
+	/// Time after which the session's messages are purged.
+	pub expires_at: OffsetDateTime,
+}
@@ -0,0 +1,88 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Persisted standing query evaluated against newly indexed notes.
+#[derive(Clone, Debug, FromRow)]
+pub struct StandingQuery {
+	/// Standing query identifier.
+	pub standing_query_id: Uuid,
+	/// Tenant that owns the standing query.
+	pub tenant_id: String,
+	/// Project that owns the standing query.
+	pub project_id: String,
+	/// Agent that registered the standing query.
+	pub agent_id: String,
+	/// Query text matched against note embeddings.
+	pub query: String,
+	/// Scopes resolved from the registering read profile.
+	pub allowed_scopes: Vec<String>,
+	/// Note types to match; empty matches every type.
+	pub note_types: Vec<String>,
+	/// Optional minimum note importance.
+	pub min_importance: Option<f32>,
+	/// Optional minimum note confidence.
+	pub min_confidence: Option<f32>,
+	/// Rank cutoff a new note must reach to record a match.
+	pub top_k: i32,
+	/// Optional webhook notified for each new match.
+	pub webhook_url: Option<String>,
+	/// Lifecycle status for the standing query.
+	pub status: String,
+	/// Creation timestamp.
+	pub created_at: OffsetDateTime,
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
+
+/// Persisted match between a standing query and a newly indexed note.
+#[derive(Clone, Debug, FromRow)]
+pub struct StandingQueryMatch {
+	/// Match identifier.
+	pub match_id: Uuid,
+	/// Monotonic change-feed position.
+	pub seq: i64,
+	/// Standing query that matched.
+	pub standing_query_id: Uuid,
+	/// Matched note identifier.
+	pub note_id: Uuid,
+	/// Embedding version used to rank the note.
+	pub embedding_version: String,
+	/// 1-based rank of the note for the standing query when it was indexed.
+	pub rank: i32,
+	/// Cosine similarity between the query and the note.
+	pub score: f32,
+	/// Webhook delivery status.
+	pub notify_status: String,
+	/// Number of webhook delivery attempts.
+	pub notify_attempts: i32,
+	/// Next webhook delivery attempt time.
+	pub notify_next_at: Option<OffsetDateTime>,
+	/// Last webhook delivery error.
+	pub notify_error: Option<String>,
+	/// Successful webhook delivery timestamp.
+	pub notified_at: Option<OffsetDateTime>,
+	/// Match timestamp.
+	pub created_at: OffsetDateTime,
+}
+
+/// Pending webhook delivery claimed by the worker.
+#[derive(Clone, Debug, FromRow)]
+pub struct StandingQueryNotification {
+	/// Match identifier.
+	pub match_id: Uuid,
+	/// Standing query that matched.
+	pub standing_query_id: Uuid,
+	/// Matched note identifier.
+	pub note_id: Uuid,
+	/// 1-based rank of the note when it was indexed.
+	pub rank: i32,
+	/// Cosine similarity between the query and the note.
+	pub score: f32,
+	/// Delivery attempts made before this claim.
+	pub notify_attempts: i32,
+	/// Match timestamp.
+	pub created_at: OffsetDateTime,
+	/// Webhook destination.
+	pub webhook_url: String,
+}
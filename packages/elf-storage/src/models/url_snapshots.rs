@@ -0,0 +1,44 @@
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Queued snapshot of an external URL referenced by a note `source_ref`.
+#[derive(Clone, Debug, FromRow)]
+pub struct SourceUrlSnapshot {
+	/// Snapshot job identifier.
+	pub snapshot_id: Uuid,
+	/// Note whose `source_ref` points at the URL.
+	pub note_id: Uuid,
+	/// Tenant that owns the note.
+	pub tenant_id: String,
+	/// Project that owns the note.
+	pub project_id: String,
+	/// Agent that wrote the note.
+	pub agent_id: String,
+	/// Scope assigned to the note and to the stored snapshot document.
+	pub scope: String,
+	/// External URL to fetch.
+	pub url: String,
+	/// Embedding version used when indexing the snapshot document.
+	pub embedding_version: String,
+	/// Snapshot lifecycle status: `pending`, `done`, `blocked`, or `failed`.
+	pub status: String,
+	/// Number of fetch attempts made so far.
+	pub attempts: i32,
+	/// Earliest time the next attempt may run.
+	pub next_attempt_at: Option<OffsetDateTime>,
+	/// Last fetch error, when any.
+	pub last_error: Option<String>,
+	/// HTTP status of the last page fetch.
+	pub http_status: Option<i32>,
+	/// Stored snapshot document, once captured.
+	pub doc_id: Option<Uuid>,
+	/// BLAKE3 hash of the stored snapshot text.
+	pub content_hash: Option<String>,
+	/// Time the snapshot was captured.
+	pub fetched_at: Option<OffsetDateTime>,
+	/// Creation timestamp.
+	pub created_at: OffsetDateTime,
+	/// Last update timestamp.
+	pub updated_at: OffsetDateTime,
+}
@@ -8,9 +8,12 @@ use crate::{
 	Result,
 	db::Db,
 	models::{IndexingOutboxEntry, TraceOutboxJob},
+	search_watermarks,
 };
 
 /// Enqueues one note for downstream indexing work.
+///
+/// The job records the note's tenant so it stays attributable after the note row is purged.
 pub async fn enqueue_outbox<'e, E>(
 	executor: E,
 	note_id: Uuid,
@@ -21,8 +24,9 @@ where
 	E: PgExecutor<'e>,
 {
 	sqlx::query(
-		"INSERT INTO indexing_outbox (outbox_id, note_id, op, embedding_version, status) \
-VALUES ($1,$2,$3,$4,'PENDING')",
+		"\
+INSERT INTO indexing_outbox (outbox_id, note_id, tenant_id, op, embedding_version, status)
+VALUES ($1,$2,(SELECT tenant_id FROM memory_notes WHERE note_id = $2),$3,$4,'PENDING')",
 	)
 	.bind(Uuid::new_v4())
 	.bind(note_id)
@@ -35,6 +39,9 @@ VALUES ($1,$2,$3,$4,'PENDING')",
 }
 
 /// Claims the next due note-indexing outbox job and leases it until `lease_seconds`.
+///
+/// Only the oldest open job of each note is claimable, so concurrent claimers never run two jobs
+/// of one note at once and each note's jobs complete in enqueue order.
 pub async fn claim_next_indexing_outbox_job(
 	db: &Db,
 	now: OffsetDateTime,
@@ -56,6 +63,13 @@ SELECT
 	updated_at
 FROM indexing_outbox
 WHERE status IN ('PENDING','FAILED') AND available_at <= $1
+	AND NOT EXISTS (
+		SELECT 1
+		FROM indexing_outbox earlier
+		WHERE earlier.note_id = indexing_outbox.note_id
+			AND earlier.status IN ('PENDING','FAILED')
+			AND (earlier.created_at, earlier.outbox_id) < (indexing_outbox.created_at, indexing_outbox.outbox_id)
+	)
 ORDER BY available_at ASC
 LIMIT 1
 FOR UPDATE SKIP LOCKED",
@@ -88,17 +102,27 @@ FOR UPDATE SKIP LOCKED",
 	Ok(job)
 }
 
-/// Marks a note-indexing outbox job as completed.
+/// Marks a note-indexing outbox job as completed and advances the search result watermark of
+/// the note's space, since the index now serves the new content.
 pub async fn mark_indexing_outbox_done(
 	db: &Db,
 	outbox_id: Uuid,
 	now: OffsetDateTime,
 ) -> Result<()> {
-	sqlx::query("UPDATE indexing_outbox SET status = 'DONE', updated_at = $1 WHERE outbox_id = $2")
-		.bind(now)
-		.bind(outbox_id)
-		.execute(&db.pool)
-		.await?;
+	let mut tx = db.pool.begin().await?;
+	let note_id: Option<Uuid> = sqlx::query_scalar(
+		"UPDATE indexing_outbox SET status = 'DONE', updated_at = $1 WHERE outbox_id = $2 RETURNING note_id",
+	)
+	.bind(now)
+	.bind(outbox_id)
+	.fetch_optional(&mut *tx)
+	.await?;
+
+	if let Some(note_id) = note_id {
+		search_watermarks::bump_for_notes(&mut *tx, &[note_id], now).await?;
+	}
+
+	tx.commit().await?;
 
 	Ok(())
 }
@@ -133,6 +157,111 @@ WHERE outbox_id = $5",
 	Ok(())
 }
 
+/// Moves a note-indexing outbox job that exhausted its retries to `DEAD_LETTER`.
+///
+/// Dead-lettered jobs keep their last error and are never claimed again until replayed.
+pub async fn mark_indexing_outbox_dead_letter(
+	db: &Db,
+	outbox_id: Uuid,
+	attempts: i32,
+	error_text: &str,
+	now: OffsetDateTime,
+) -> Result<()> {
+	sqlx::query(
+		"\
+UPDATE indexing_outbox
+SET status = 'DEAD_LETTER',
+	attempts = $1,
+	last_error = $2,
+	updated_at = $3
+WHERE outbox_id = $4",
+	)
+	.bind(attempts)
+	.bind(error_text)
+	.bind(now)
+	.bind(outbox_id)
+	.execute(&db.pool)
+	.await?;
+
+	Ok(())
+}
+
+/// Lists a tenant's dead-lettered note-indexing outbox jobs, most recently dead-lettered first.
+///
+/// Jobs belong to the tenant recorded when they were enqueued, falling back to the tenant of their
+/// note for jobs enqueued before the tenant was recorded, so jobs of purged notes stay listed.
+pub async fn list_indexing_outbox_dead_letters<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	limit: i64,
+) -> Result<Vec<IndexingOutboxEntry>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, IndexingOutboxEntry>(
+		"\
+SELECT
+	o.outbox_id,
+	o.note_id,
+	o.op,
+	o.embedding_version,
+	o.status,
+	o.attempts,
+	o.last_error,
+	o.available_at,
+	o.created_at,
+	o.updated_at
+FROM indexing_outbox o
+LEFT JOIN memory_notes n ON n.note_id = o.note_id
+WHERE o.status = 'DEAD_LETTER' AND COALESCE(o.tenant_id, n.tenant_id) = $1
+ORDER BY o.updated_at DESC, o.outbox_id ASC
+LIMIT $2",
+	)
+	.bind(tenant_id)
+	.bind(limit)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Requeues a tenant's dead-lettered note-indexing outbox jobs with a fresh retry budget.
+///
+/// Returns the identifiers that were requeued; ids that are not dead-lettered jobs of the tenant
+/// are left alone. Tenancy is resolved as in [`list_indexing_outbox_dead_letters`].
+pub async fn replay_indexing_outbox_dead_letters<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	outbox_ids: &[Uuid],
+	now: OffsetDateTime,
+) -> Result<Vec<Uuid>>
+where
+	E: PgExecutor<'e>,
+{
+	let replayed = sqlx::query_scalar::<_, Uuid>(
+		"\
+UPDATE indexing_outbox o
+SET status = 'PENDING',
+	attempts = 0,
+	available_at = $1,
+	updated_at = $1
+WHERE COALESCE(
+		o.tenant_id,
+		(SELECT n.tenant_id FROM memory_notes n WHERE n.note_id = o.note_id)
+	) = $2
+	AND o.status = 'DEAD_LETTER'
+	AND o.outbox_id = ANY($3)
+RETURNING o.outbox_id",
+	)
+	.bind(now)
+	.bind(tenant_id)
+	.bind(outbox_ids)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(replayed)
+}
+
 /// Claims the next due trace outbox job and leases it until `lease_seconds`.
 pub async fn claim_next_trace_outbox_job(
 	db: &Db,
@@ -19,6 +19,12 @@ pub const DENSE_VECTOR_NAME: &str = "dense";
 pub const BM25_VECTOR_NAME: &str = "bm25";
 /// Sparse model identifier used for BM25 search.
 pub const BM25_MODEL: &str = "qdrant/bm25";
+/// Required payload indexes for the typed note-search filters.
+pub const NOTES_SEARCH_FILTER_INDEXES: [(&str, PayloadSchemaType, FieldType); 3] = [
+	("type", PayloadSchemaType::Keyword, FieldType::Keyword),
+	("updated_at", PayloadSchemaType::Datetime, FieldType::Datetime),
+	("importance", PayloadSchemaType::Float, FieldType::Float),
+];
 /// Required payload indexes for the document-search collection.
 pub const DOCS_SEARCH_FILTER_INDEXES: [(&str, PayloadSchemaType, FieldType); 9] = [
 	("scope", PayloadSchemaType::Keyword, FieldType::Keyword),
@@ -0,0 +1,344 @@
+//! Qdrant maintenance operations and their run history.
+
+use qdrant_client::qdrant::{
+	CollectionStatus, CountPointsBuilder, DeleteSnapshotRequestBuilder,
+	OptimizersConfigDiffBuilder, SnapshotDescription, UpdateCollectionBuilder,
+};
+use serde_json::Value;
+use sqlx::{PgExecutor, PgPool};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{Error, Result, models::QdrantMaintenanceRun, qdrant::QdrantStore};
+
+/// Collection kind label for the note collection.
+pub const COLLECTION_NOTES: &str = "notes";
+/// Collection kind label for the document-chunk collection.
+pub const COLLECTION_DOCS: &str = "docs";
+
+/// One Qdrant maintenance operation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QdrantMaintenanceOperation {
+	/// Nudges the collection optimizers and reports segment state.
+	Optimize,
+	/// Creates a collection snapshot and prunes snapshots beyond the retention count.
+	Snapshot,
+	/// Recovers a collection from one of its snapshots.
+	RestoreSnapshot,
+	/// Compares the Qdrant point count with the Postgres chunks that should be indexed.
+	VerifyCounts,
+}
+impl QdrantMaintenanceOperation {
+	/// Operations that may run on a schedule.
+	pub const SCHEDULED: [Self; 3] = [Self::Optimize, Self::Snapshot, Self::VerifyCounts];
+
+	/// Stable storage label.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Optimize => "optimize",
+			Self::Snapshot => "snapshot",
+			Self::RestoreSnapshot => "restore_snapshot",
+			Self::VerifyCounts => "verify_counts",
+		}
+	}
+
+	/// Parses a storage label.
+	pub fn parse(value: &str) -> Option<Self> {
+		match value {
+			"optimize" => Some(Self::Optimize),
+			"snapshot" => Some(Self::Snapshot),
+			"restore_snapshot" => Some(Self::RestoreSnapshot),
+			"verify_counts" => Some(Self::VerifyCounts),
+			_ => None,
+		}
+	}
+}
+
+/// New maintenance run history row.
+#[derive(Clone, Debug)]
+pub struct QdrantMaintenanceRunInsert<'a> {
+	/// Operation label.
+	pub operation: QdrantMaintenanceOperation,
+	/// Collection kind: `notes` or `docs`.
+	pub collection: &'a str,
+	/// Run trigger: `scheduled` or `manual`.
+	pub trigger: &'a str,
+	/// Operation-specific result details.
+	pub detail: &'a Value,
+	/// Failure message; `None` marks the run as succeeded.
+	pub error: Option<&'a str>,
+	/// Alert delivery status: `none`, `sent`, `failed`, or `not_configured`.
+	pub alert_status: &'a str,
+	/// Run start time.
+	pub started_at: OffsetDateTime,
+	/// Run end time.
+	pub finished_at: OffsetDateTime,
+}
+
+/// Records one finished maintenance run.
+pub async fn insert_qdrant_maintenance_run<'e, E>(
+	executor: E,
+	run: &QdrantMaintenanceRunInsert<'_>,
+) -> Result<QdrantMaintenanceRun>
+where
+	E: PgExecutor<'e>,
+{
+	let row = sqlx::query_as::<_, QdrantMaintenanceRun>(
+		"\
+INSERT INTO qdrant_maintenance_runs (
+	run_id,
+	operation,
+	collection,
+	trigger,
+	status,
+	detail,
+	error,
+	alert_status,
+	started_at,
+	finished_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+RETURNING *",
+	)
+	.bind(Uuid::new_v4())
+	.bind(run.operation.as_str())
+	.bind(run.collection)
+	.bind(run.trigger)
+	.bind(if run.error.is_some() { "failed" } else { "succeeded" })
+	.bind(run.detail)
+	.bind(run.error)
+	.bind(run.alert_status)
+	.bind(run.started_at)
+	.bind(run.finished_at)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(row)
+}
+
+/// Returns the start time of the most recent scheduled run of `operation`.
+pub async fn latest_scheduled_run_started_at<'e, E>(
+	executor: E,
+	operation: QdrantMaintenanceOperation,
+) -> Result<Option<OffsetDateTime>>
+where
+	E: PgExecutor<'e>,
+{
+	let started_at = sqlx::query_scalar::<_, OffsetDateTime>(
+		"\
+SELECT started_at
+FROM qdrant_maintenance_runs
+WHERE operation = $1 AND trigger = 'scheduled'
+ORDER BY started_at DESC
+LIMIT 1",
+	)
+	.bind(operation.as_str())
+	.fetch_optional(executor)
+	.await?;
+
+	Ok(started_at)
+}
+
+/// Lists maintenance runs, newest first, optionally filtered by operation.
+pub async fn list_qdrant_maintenance_runs<'e, E>(
+	executor: E,
+	operation: Option<QdrantMaintenanceOperation>,
+	limit: i64,
+) -> Result<Vec<QdrantMaintenanceRun>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, QdrantMaintenanceRun>(
+		"\
+SELECT *
+FROM qdrant_maintenance_runs
+WHERE $1::text IS NULL OR operation = $1
+ORDER BY started_at DESC
+LIMIT $2",
+	)
+	.bind(operation.map(QdrantMaintenanceOperation::as_str))
+	.bind(limit)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Runs one schedulable operation on a collection and returns its detail and failure message.
+///
+/// Failed verifications keep their count detail so the history shows the observed drift.
+pub async fn run_qdrant_maintenance(
+	pool: &PgPool,
+	store: &QdrantStore,
+	collection: &str,
+	operation: QdrantMaintenanceOperation,
+	cfg: &elf_config::QdrantMaintenance,
+	now: OffsetDateTime,
+) -> (Value, Option<String>) {
+	let result = match operation {
+		QdrantMaintenanceOperation::Optimize => optimize_collection(store).await,
+		QdrantMaintenanceOperation::Snapshot =>
+			snapshot_collection(store, cfg.snapshot_retention).await,
+		QdrantMaintenanceOperation::VerifyCounts =>
+			verify_collection_counts(pool, store, collection, cfg.max_count_drift, now).await,
+		QdrantMaintenanceOperation::RestoreSnapshot =>
+			Err(Error::InvalidArgument("restore_snapshot cannot run on a schedule.".to_string())),
+	};
+
+	match result {
+		Ok(detail) if detail.get("passed").and_then(Value::as_bool) == Some(false) => {
+			let message = format!(
+				"Point count drift {} exceeds {}.",
+				detail.get("drift").unwrap_or(&Value::Null),
+				cfg.max_count_drift
+			);
+
+			(detail, Some(message))
+		},
+		Ok(detail) => (detail, None),
+		Err(err) => (serde_json::json!({}), Some(err.to_string())),
+	}
+}
+
+/// Triggers an optimizer pass on the collection and reports its segment state.
+///
+/// Qdrant has no explicit optimize call; an empty optimizer config update makes it re-evaluate
+/// segments against the current thresholds.
+pub async fn optimize_collection(store: &QdrantStore) -> Result<Value> {
+	store
+		.client
+		.update_collection(
+			UpdateCollectionBuilder::new(store.collection.as_str())
+				.optimizers_config(OptimizersConfigDiffBuilder::default()),
+		)
+		.await?;
+
+	let info = store.client.collection_info(store.collection.as_str()).await?.result;
+	let Some(info) = info else {
+		return Err(Error::NotFound(format!("Qdrant collection {}.", store.collection)));
+	};
+	if let Some(optimizer) = info.optimizer_status.as_ref().filter(|status| !status.ok) {
+		return Err(Error::Conflict(format!("Qdrant optimizer error: {}", optimizer.error)));
+	}
+
+	Ok(serde_json::json!({
+		"collection_status": CollectionStatus::try_from(info.status)
+			.map(|status| status.as_str_name())
+			.unwrap_or("UnknownCollectionStatus"),
+		"segments_count": info.segments_count,
+		"points_count": info.points_count,
+		"indexed_vectors_count": info.indexed_vectors_count,
+	}))
+}
+
+/// Creates a collection snapshot and deletes the oldest snapshots beyond `retention`.
+pub async fn snapshot_collection(store: &QdrantStore, retention: u32) -> Result<Value> {
+	let created = store
+		.client
+		.create_snapshot(store.collection.as_str())
+		.await?
+		.snapshot_description
+		.ok_or_else(|| Error::NotFound("Qdrant returned no snapshot description.".to_string()))?;
+	let snapshots = list_collection_snapshots(store).await?;
+	let mut deleted = Vec::new();
+
+	for snapshot in snapshots.iter().skip(retention as usize) {
+		store
+			.client
+			.delete_snapshot(DeleteSnapshotRequestBuilder::new(
+				store.collection.as_str(),
+				snapshot.name.as_str(),
+			))
+			.await?;
+		deleted.push(snapshot.name.clone());
+	}
+
+	Ok(serde_json::json!({
+		"snapshot_name": created.name,
+		"size_bytes": created.size,
+		"checksum": created.checksum,
+		"retained": snapshots.len().min(retention as usize),
+		"deleted": deleted,
+	}))
+}
+
+/// Lists collection snapshots, newest first.
+pub async fn list_collection_snapshots(store: &QdrantStore) -> Result<Vec<SnapshotDescription>> {
+	let mut snapshots =
+		store.client.list_snapshots(store.collection.as_str()).await?.snapshot_descriptions;
+
+	snapshots.sort_by(|left, right| {
+		let key = |snapshot: &SnapshotDescription| {
+			snapshot.creation_time.map(|time| (time.seconds, time.nanos))
+		};
+
+		key(right).cmp(&key(left)).then_with(|| right.name.cmp(&left.name))
+	});
+
+	Ok(snapshots)
+}
+
+/// Compares the exact Qdrant point count with the Postgres chunks expected in the collection.
+///
+/// The returned detail sets `passed` to false when the counts differ by more than `max_drift`.
+pub async fn verify_collection_counts(
+	pool: &PgPool,
+	store: &QdrantStore,
+	collection: &str,
+	max_drift: u64,
+	now: OffsetDateTime,
+) -> Result<Value> {
+	let qdrant_points = store
+		.client
+		.count(CountPointsBuilder::new(store.collection.as_str()).exact(true))
+		.await?
+		.result
+		.map(|result| result.count)
+		.unwrap_or_default();
+	let postgres_chunks = match collection {
+		COLLECTION_NOTES => count_indexable_note_chunks(pool, now).await?,
+		COLLECTION_DOCS => count_indexable_doc_chunks(pool).await?,
+		other => return Err(Error::InvalidArgument(format!("Unknown collection kind {other}."))),
+	};
+	let drift = qdrant_points.abs_diff(postgres_chunks);
+
+	Ok(serde_json::json!({
+		"qdrant_points": qdrant_points,
+		"postgres_chunks": postgres_chunks,
+		"drift": drift,
+		"max_drift": max_drift,
+		"passed": drift <= max_drift,
+	}))
+}
+
+async fn count_indexable_note_chunks(pool: &PgPool, now: OffsetDateTime) -> Result<u64> {
+	let count: i64 = sqlx::query_scalar(
+		"\
+SELECT count(*)
+FROM memory_note_chunks c
+JOIN memory_notes n ON n.note_id = c.note_id
+JOIN note_chunk_embeddings e
+	ON e.chunk_id = c.chunk_id AND e.embedding_version = c.embedding_version
+WHERE n.status = 'active' AND (n.expires_at IS NULL OR n.expires_at > $1)",
+	)
+	.bind(now)
+	.fetch_one(pool)
+	.await?;
+
+	Ok(count.max(0) as u64)
+}
+
+async fn count_indexable_doc_chunks(pool: &PgPool) -> Result<u64> {
+	let count: i64 = sqlx::query_scalar(
+		"\
+SELECT count(DISTINCT c.chunk_id)
+FROM doc_chunks c
+JOIN doc_documents d ON d.doc_id = c.doc_id
+JOIN doc_chunk_embeddings e ON e.chunk_id = c.chunk_id
+WHERE d.status = 'active'",
+	)
+	.fetch_one(pool)
+	.await?;
+
+	Ok(count.max(0) as u64)
+}
@@ -1,5 +1,6 @@
 //! Memory note persistence queries.
 
+use serde_json::Value;
 use sqlx::PgExecutor;
 use uuid::Uuid;
 
@@ -122,6 +123,8 @@ where
 
 #[allow(clippy::too_many_arguments)]
 /// Upserts one chunk row for a memory note.
+///
+/// `chunking` records the resolved chunking settings that produced the row.
 pub async fn insert_note_chunk<'e, E>(
 	executor: E,
 	chunk_id: Uuid,
@@ -130,7 +133,9 @@ pub async fn insert_note_chunk<'e, E>(
 	start_offset: i32,
 	end_offset: i32,
 	text: &str,
+	content_hash: &str,
 	embedding_version: &str,
+	chunking: &Value,
 ) -> Result<()>
 where
 	E: PgExecutor<'e>,
@@ -144,14 +149,18 @@ INSERT INTO memory_note_chunks (
 	start_offset,
 	end_offset,
 	text,
-	embedding_version
+	content_hash,
+	embedding_version,
+	chunking
 )
-VALUES ($1, $2, $3, $4, $5, $6, $7)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
 ON CONFLICT (chunk_id) DO UPDATE
 SET
 	text = EXCLUDED.text,
+	content_hash = EXCLUDED.content_hash,
 	start_offset = EXCLUDED.start_offset,
-	end_offset = EXCLUDED.end_offset",
+	end_offset = EXCLUDED.end_offset,
+	chunking = EXCLUDED.chunking",
 	)
 	.bind(chunk_id)
 	.bind(note_id)
@@ -159,7 +168,9 @@ SET
 	.bind(start_offset)
 	.bind(end_offset)
 	.bind(text)
+	.bind(content_hash)
 	.bind(embedding_version)
+	.bind(chunking)
 	.execute(executor)
 	.await?;
 
@@ -196,3 +207,65 @@ created_at = now()",
 
 	Ok(())
 }
+
+/// Upserts the pooled embedding vector of a note.
+pub async fn insert_note_embedding<'e, E>(
+	executor: E,
+	note_id: Uuid,
+	embedding_version: &str,
+	embedding_dim: i32,
+	vec: &str,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+INSERT INTO note_embeddings (note_id, embedding_version, embedding_dim, vec)
+VALUES ($1, $2, $3, $4::text::vector)
+ON CONFLICT (note_id, embedding_version) DO UPDATE
+SET
+	embedding_dim = EXCLUDED.embedding_dim,
+	vec = EXCLUDED.vec,
+	created_at = now()",
+	)
+	.bind(note_id)
+	.bind(embedding_version)
+	.bind(embedding_dim)
+	.bind(vec)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Upserts one embedding vector for a structured note field.
+pub async fn insert_note_field_embedding<'e, E>(
+	executor: E,
+	field_id: Uuid,
+	embedding_version: &str,
+	embedding_dim: i32,
+	vec: &str,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+INSERT INTO note_field_embeddings (field_id, embedding_version, embedding_dim, vec)
+VALUES ($1, $2, $3, $4::text::vector)
+ON CONFLICT (field_id, embedding_version) DO UPDATE
+SET
+	embedding_dim = EXCLUDED.embedding_dim,
+	vec = EXCLUDED.vec,
+	created_at = now()",
+	)
+	.bind(field_id)
+	.bind(embedding_version)
+	.bind(embedding_dim)
+	.bind(vec)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
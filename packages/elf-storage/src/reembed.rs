@@ -0,0 +1,469 @@
+//! Admin re-embed runs that migrate notes to a new embedding version.
+//!
+//! A run writes target-version vectors next to the active ones and only changes what readers see
+//! when it is activated, which flips every re-embedded chunk and note in one transaction.
+
+use sqlx::{FromRow, PgConnection, PgExecutor};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	Result,
+	models::{EmbeddingReembedRun, MemoryNote, MemoryNoteChunk},
+};
+
+/// Structured field text re-embedded alongside its note's chunks.
+#[derive(Clone, Debug, FromRow)]
+pub struct ReembedField {
+	/// Field identifier.
+	pub field_id: Uuid,
+	/// Parent note identifier.
+	pub note_id: Uuid,
+	/// Field text.
+	pub text: String,
+}
+
+/// Counts of rows moved to the target version by an activation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReembedActivation {
+	/// Chunks whose embedding version was flipped.
+	pub chunks: u64,
+	/// Notes whose embedding version was flipped.
+	pub notes: u64,
+}
+
+/// Returns the unfinished run toward `target_version`, if any.
+pub async fn find_running_reembed_run<'e, E>(
+	executor: E,
+	target_version: &str,
+) -> Result<Option<EmbeddingReembedRun>>
+where
+	E: PgExecutor<'e>,
+{
+	let run = sqlx::query_as::<_, EmbeddingReembedRun>(
+		"\
+SELECT *
+FROM embedding_reembed_runs
+WHERE target_version = $1 AND status = 'running'",
+	)
+	.bind(target_version)
+	.fetch_optional(executor)
+	.await?;
+
+	Ok(run)
+}
+
+/// Starts a new run toward `target_version`.
+pub async fn insert_reembed_run<'e, E>(
+	executor: E,
+	target_version: &str,
+	target_collection: &str,
+	target_dim: i32,
+	now: OffsetDateTime,
+) -> Result<EmbeddingReembedRun>
+where
+	E: PgExecutor<'e>,
+{
+	let run = sqlx::query_as::<_, EmbeddingReembedRun>(
+		"\
+INSERT INTO embedding_reembed_runs (
+	run_id,
+	target_version,
+	target_collection,
+	target_dim,
+	status,
+	started_at,
+	updated_at
+)
+VALUES ($1, $2, $3, $4, 'running', $5, $5)
+RETURNING *",
+	)
+	.bind(Uuid::new_v4())
+	.bind(target_version)
+	.bind(target_collection)
+	.bind(target_dim)
+	.bind(now)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(run)
+}
+
+/// Loads a run and locks it for the rest of the transaction.
+pub async fn lock_reembed_run(
+	conn: &mut PgConnection,
+	run_id: Uuid,
+) -> Result<Option<EmbeddingReembedRun>> {
+	let run = sqlx::query_as::<_, EmbeddingReembedRun>(
+		"SELECT * FROM embedding_reembed_runs WHERE run_id = $1 FOR UPDATE",
+	)
+	.bind(run_id)
+	.fetch_optional(conn)
+	.await?;
+
+	Ok(run)
+}
+
+/// Lists runs, newest first.
+pub async fn list_reembed_runs<'e, E>(executor: E, limit: i64) -> Result<Vec<EmbeddingReembedRun>>
+where
+	E: PgExecutor<'e>,
+{
+	let runs = sqlx::query_as::<_, EmbeddingReembedRun>(
+		"\
+SELECT *
+FROM embedding_reembed_runs
+ORDER BY started_at DESC
+LIMIT $1",
+	)
+	.bind(limit)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(runs)
+}
+
+/// Records one finished batch and moves the run cursor past it.
+pub async fn record_reembed_batch<'e, E>(
+	executor: E,
+	run_id: Uuid,
+	cursor_note_id: Uuid,
+	notes: i64,
+	chunks: i64,
+	fields: i64,
+	now: OffsetDateTime,
+) -> Result<EmbeddingReembedRun>
+where
+	E: PgExecutor<'e>,
+{
+	let run = sqlx::query_as::<_, EmbeddingReembedRun>(
+		"\
+UPDATE embedding_reembed_runs
+SET
+	cursor_note_id = $2,
+	notes_processed = notes_processed + $3,
+	chunks_embedded = chunks_embedded + $4,
+	fields_embedded = fields_embedded + $5,
+	last_error = NULL,
+	updated_at = $6
+WHERE run_id = $1
+RETURNING *",
+	)
+	.bind(run_id)
+	.bind(cursor_note_id)
+	.bind(notes)
+	.bind(chunks)
+	.bind(fields)
+	.bind(now)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(run)
+}
+
+/// Records a failed batch; the cursor stays put so the next call retries it.
+pub async fn record_reembed_error<'e, E>(
+	executor: E,
+	run_id: Uuid,
+	error: &str,
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"UPDATE embedding_reembed_runs SET last_error = $2, updated_at = $3 WHERE run_id = $1",
+	)
+	.bind(run_id)
+	.bind(error)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Marks a run `completed` once no notes are left after its cursor.
+pub async fn complete_reembed_run<'e, E>(
+	executor: E,
+	run_id: Uuid,
+	now: OffsetDateTime,
+) -> Result<EmbeddingReembedRun>
+where
+	E: PgExecutor<'e>,
+{
+	let run = sqlx::query_as::<_, EmbeddingReembedRun>(
+		"\
+UPDATE embedding_reembed_runs
+SET status = 'completed', completed_at = $2, updated_at = $2
+WHERE run_id = $1
+RETURNING *",
+	)
+	.bind(run_id)
+	.bind(now)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(run)
+}
+
+/// Marks a run `activated`.
+pub async fn mark_reembed_run_activated(
+	conn: &mut PgConnection,
+	run_id: Uuid,
+	now: OffsetDateTime,
+) -> Result<EmbeddingReembedRun> {
+	let run = sqlx::query_as::<_, EmbeddingReembedRun>(
+		"\
+UPDATE embedding_reembed_runs
+SET status = 'activated', activated_at = $2, updated_at = $2
+WHERE run_id = $1
+RETURNING *",
+	)
+	.bind(run_id)
+	.bind(now)
+	.fetch_one(conn)
+	.await?;
+
+	Ok(run)
+}
+
+/// Returns the next active notes after `cursor` that still have a chunk without a
+/// `target_version` vector, in note-id order.
+pub async fn fetch_pending_reembed_notes<'e, E>(
+	executor: E,
+	target_version: &str,
+	cursor: Option<Uuid>,
+	limit: i64,
+	now: OffsetDateTime,
+) -> Result<Vec<MemoryNote>>
+where
+	E: PgExecutor<'e>,
+{
+	let notes = sqlx::query_as::<_, MemoryNote>(
+		"\
+SELECT n.*
+FROM memory_notes n
+WHERE n.status = 'active'
+	AND (n.expires_at IS NULL OR n.expires_at > $3)
+	AND ($2::uuid IS NULL OR n.note_id > $2)
+	AND EXISTS (
+		SELECT 1
+		FROM memory_note_chunks c
+		WHERE c.note_id = n.note_id
+			AND NOT EXISTS (
+				SELECT 1
+				FROM note_chunk_embeddings e
+				WHERE e.chunk_id = c.chunk_id AND e.embedding_version = $1
+			)
+	)
+ORDER BY n.note_id ASC
+LIMIT $4",
+	)
+	.bind(target_version)
+	.bind(cursor)
+	.bind(now)
+	.bind(limit)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(notes)
+}
+
+/// Counts active notes that still have a chunk without a `target_version` vector.
+pub async fn count_pending_reembed_notes<'e, E>(
+	executor: E,
+	target_version: &str,
+	now: OffsetDateTime,
+) -> Result<i64>
+where
+	E: PgExecutor<'e>,
+{
+	let count = sqlx::query_scalar::<_, i64>(
+		"\
+SELECT count(*)
+FROM memory_notes n
+WHERE n.status = 'active'
+	AND (n.expires_at IS NULL OR n.expires_at > $2)
+	AND EXISTS (
+		SELECT 1
+		FROM memory_note_chunks c
+		WHERE c.note_id = n.note_id
+			AND NOT EXISTS (
+				SELECT 1
+				FROM note_chunk_embeddings e
+				WHERE e.chunk_id = c.chunk_id AND e.embedding_version = $1
+			)
+	)",
+	)
+	.bind(target_version)
+	.bind(now)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(count)
+}
+
+/// Loads the chunks of `note_ids`, grouped by note in chunk order.
+pub async fn fetch_reembed_chunks<'e, E>(
+	executor: E,
+	note_ids: &[Uuid],
+) -> Result<Vec<MemoryNoteChunk>>
+where
+	E: PgExecutor<'e>,
+{
+	let chunks = sqlx::query_as::<_, MemoryNoteChunk>(
+		"\
+SELECT
+	chunk_id,
+	note_id,
+	chunk_index,
+	start_offset,
+	end_offset,
+	text,
+	embedding_version,
+	created_at,
+	content_hash
+FROM memory_note_chunks
+WHERE note_id = ANY($1)
+ORDER BY note_id ASC, chunk_index ASC",
+	)
+	.bind(note_ids)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(chunks)
+}
+
+/// Loads the embedded structured fields of `note_ids`.
+///
+/// `answer` fields are never embedded, matching the worker.
+pub async fn fetch_reembed_fields<'e, E>(
+	executor: E,
+	note_ids: &[Uuid],
+) -> Result<Vec<ReembedField>>
+where
+	E: PgExecutor<'e>,
+{
+	let fields = sqlx::query_as::<_, ReembedField>(
+		"\
+SELECT field_id, note_id, text
+FROM memory_note_fields
+WHERE note_id = ANY($1) AND field_kind <> 'answer'
+ORDER BY note_id ASC, field_kind ASC, item_index ASC",
+	)
+	.bind(note_ids)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(fields)
+}
+
+/// Makes `target_version` the active embedding version of every re-embedded chunk and note.
+///
+/// Chunks move only when a `target_version` vector exists for them, and notes only when a pooled
+/// `target_version` vector exists. Shared chunk-content references move with their chunks so
+/// `chunk_content_embeddings.ref_count` stays exact for both versions.
+pub async fn activate_embedding_version(
+	conn: &mut PgConnection,
+	target_version: &str,
+	now: OffsetDateTime,
+) -> Result<ReembedActivation> {
+	let chunks = sqlx::query_scalar::<_, i64>(
+		"\
+WITH moved AS (
+	SELECT c.chunk_id, c.content_hash, c.embedding_version AS previous_version
+	FROM memory_note_chunks c
+	WHERE c.embedding_version <> $1
+		AND EXISTS (
+			SELECT 1
+			FROM note_chunk_embeddings e
+			WHERE e.chunk_id = c.chunk_id AND e.embedding_version = $1
+		)
+	FOR UPDATE
+),
+flipped AS (
+	UPDATE memory_note_chunks c
+	SET embedding_version = $1
+	FROM moved
+	WHERE c.chunk_id = moved.chunk_id
+	RETURNING moved.content_hash, moved.previous_version
+),
+released AS (
+	SELECT content_hash, previous_version, count(*)::int AS refs
+	FROM flipped
+	WHERE content_hash IS NOT NULL
+	GROUP BY content_hash, previous_version
+),
+updated AS (
+	UPDATE chunk_content_embeddings s
+	SET ref_count = s.ref_count - released.refs, updated_at = $2
+	FROM released
+	WHERE s.content_hash = released.content_hash
+		AND s.embedding_version = released.previous_version
+)
+SELECT count(*) FROM flipped",
+	)
+	.bind(target_version)
+	.bind(now)
+	.fetch_one(&mut *conn)
+	.await?;
+
+	sqlx::query(
+		"DELETE FROM chunk_content_embeddings WHERE embedding_version <> $1 AND ref_count <= 0",
+	)
+	.bind(target_version)
+	.execute(&mut *conn)
+	.await?;
+	sqlx::query(
+		"\
+INSERT INTO chunk_content_embeddings (
+	content_hash,
+	embedding_version,
+	embedding_dim,
+	vec,
+	ref_count,
+	created_at,
+	updated_at
+)
+SELECT DISTINCT ON (c.content_hash)
+	c.content_hash,
+	$1,
+	e.embedding_dim,
+	e.vec,
+	count(*) OVER (PARTITION BY c.content_hash),
+	$2,
+	$2
+FROM memory_note_chunks c
+JOIN note_chunk_embeddings e
+	ON e.chunk_id = c.chunk_id AND e.embedding_version = c.embedding_version
+WHERE c.embedding_version = $1 AND c.content_hash IS NOT NULL
+ORDER BY c.content_hash, c.chunk_id
+ON CONFLICT (content_hash, embedding_version) DO UPDATE
+SET
+	ref_count = EXCLUDED.ref_count,
+	updated_at = EXCLUDED.updated_at",
+	)
+	.bind(target_version)
+	.bind(now)
+	.execute(&mut *conn)
+	.await?;
+
+	let notes = sqlx::query(
+		"\
+UPDATE memory_notes n
+SET embedding_version = $1
+WHERE n.embedding_version <> $1
+	AND EXISTS (
+		SELECT 1
+		FROM note_embeddings e
+		WHERE e.note_id = n.note_id AND e.embedding_version = $1
+	)",
+	)
+	.bind(target_version)
+	.execute(&mut *conn)
+	.await?
+	.rows_affected();
+
+	Ok(ReembedActivation { chunks: chunks as u64, notes })
+}
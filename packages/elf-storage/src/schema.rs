@@ -8,111 +8,93 @@ pub fn render_schema(vector_dim: u32) -> String {
 	expanded.replace("<VECTOR_DIM>", &vector_dim.to_string())
 }
 
+macro_rules! include_entry {
+	($path:literal) => {
+		($path, include_str!(concat!("../../../sql/", $path)))
+	};
+}
+
+/// SQL files referenced by `\ir` lines in `sql/init.sql`, keyed by their include path.
+const INCLUDES: &[(&str, &str)] = &[
+	include_entry!("00_extensions.sql"),
+	include_entry!("tables/001_memory_notes.sql"),
+	include_entry!("tables/016_graph_entities.sql"),
+	include_entry!("tables/017_graph_entity_aliases.sql"),
+	include_entry!("tables/020_graph_predicates.sql"),
+	include_entry!("tables/021_graph_predicate_aliases.sql"),
+	include_entry!("tables/018_graph_facts.sql"),
+	include_entry!("tables/019_graph_fact_evidence.sql"),
+	include_entry!("tables/022_graph_fact_supersessions.sql"),
+	include_entry!("tables/013_memory_note_fields.sql"),
+	include_entry!("tables/009_memory_note_chunks.sql"),
+	include_entry!("tables/010_note_chunk_embeddings.sql"),
+	include_entry!("tables/014_note_field_embeddings.sql"),
+	include_entry!("tables/002_note_embeddings.sql"),
+	include_entry!("tables/003_memory_note_versions.sql"),
+	include_entry!("tables/004_memory_hits.sql"),
+	include_entry!("tables/005_indexing_outbox.sql"),
+	include_entry!("tables/006_search_traces.sql"),
+	include_entry!("tables/012_search_trace_candidates.sql"),
+	include_entry!("tables/015_search_trace_stages.sql"),
+	include_entry!("tables/007_search_trace_outbox.sql"),
+	include_entry!("tables/008_llm_cache.sql"),
+	include_entry!("tables/011_search_sessions.sql"),
+	include_entry!("tables/025_doc_documents.sql"),
+	include_entry!("tables/026_doc_chunks.sql"),
+	include_entry!("tables/027_doc_chunk_embeddings.sql"),
+	include_entry!("tables/028_doc_indexing_outbox.sql"),
+	include_entry!("tables/029_memory_ingestion_profiles.sql"),
+	include_entry!("tables/030_memory_ingestion_profile_defaults.sql"),
+	include_entry!("tables/031_consolidation_runs.sql"),
+	include_entry!("tables/032_consolidation_proposals.sql"),
+	include_entry!("tables/033_consolidation_proposal_reviews.sql"),
+	include_entry!("tables/034_consolidation_run_jobs.sql"),
+	include_entry!("tables/035_knowledge_pages.sql"),
+	include_entry!("tables/036_knowledge_page_sections.sql"),
+	include_entry!("tables/037_knowledge_page_source_refs.sql"),
+	include_entry!("tables/038_knowledge_page_lint_findings.sql"),
+	include_entry!("tables/039_core_memory_blocks.sql"),
+	include_entry!("tables/040_core_memory_block_attachments.sql"),
+	include_entry!("tables/041_core_memory_block_events.sql"),
+	include_entry!("tables/042_work_journal_entries.sql"),
+	include_entry!("tables/043_search_shadow_comparisons.sql"),
+	include_entry!("tables/044_standing_queries.sql"),
+	include_entry!("tables/045_standing_query_embeddings.sql"),
+	include_entry!("tables/046_standing_query_matches.sql"),
+	include_entry!("tables/047_source_url_snapshots.sql"),
+	include_entry!("tables/048_chunk_content_embeddings.sql"),
+	include_entry!("tables/049_eval_continuous_runs.sql"),
+	include_entry!("tables/050_memory_note_evidence.sql"),
+	include_entry!("tables/051_graph_entity_kinds.sql"),
+	include_entry!("tables/052_memory_note_values.sql"),
+	include_entry!("tables/053_memory_write_incidents.sql"),
+	include_entry!("tables/054_qdrant_maintenance_runs.sql"),
+	include_entry!("tables/055_memory_session_hits.sql"),
+	include_entry!("tables/056_memory_note_events.sql"),
+	include_entry!("tables/057_embedding_reembed_runs.sql"),
+	include_entry!("tables/058_memory_space_grant_versions.sql"),
+	include_entry!("tables/059_memory_session_messages.sql"),
+	include_entry!("tables/060_graph_entity_events.sql"),
+	include_entry!("tables/061_memory_feedback.sql"),
+	include_entry!("tables/062_search_profiles.sql"),
+	include_entry!("tables/063_audit_log.sql"),
+	include_entry!("tables/064_memory_note_relations.sql"),
+	include_entry!("tables/065_search_result_watermarks.sql"),
+	include_entry!("tables/023_memory_ingest_decisions.sql"),
+	include_entry!("tables/024_memory_space_grants.sql"),
+];
+
 fn expand_includes(sql: &str) -> String {
 	let mut out = String::new();
 
 	for line in sql.lines() {
-		let trimmed = line.trim();
+		let include = line.trim().strip_prefix("\\ir ").and_then(|path| {
+			let path = path.trim();
 
-		if let Some(path) = trimmed.strip_prefix("\\ir ") {
-			match path.trim() {
-				"00_extensions.sql" => out.push_str(include_str!("../../../sql/00_extensions.sql")),
-				"tables/001_memory_notes.sql" =>
-					out.push_str(include_str!("../../../sql/tables/001_memory_notes.sql")),
-				"tables/016_graph_entities.sql" =>
-					out.push_str(include_str!("../../../sql/tables/016_graph_entities.sql")),
-				"tables/017_graph_entity_aliases.sql" =>
-					out.push_str(include_str!("../../../sql/tables/017_graph_entity_aliases.sql")),
-				"tables/020_graph_predicates.sql" =>
-					out.push_str(include_str!("../../../sql/tables/020_graph_predicates.sql")),
-				"tables/021_graph_predicate_aliases.sql" => out
-					.push_str(include_str!("../../../sql/tables/021_graph_predicate_aliases.sql")),
-				"tables/018_graph_facts.sql" =>
-					out.push_str(include_str!("../../../sql/tables/018_graph_facts.sql")),
-				"tables/019_graph_fact_evidence.sql" =>
-					out.push_str(include_str!("../../../sql/tables/019_graph_fact_evidence.sql")),
-				"tables/022_graph_fact_supersessions.sql" => out
-					.push_str(include_str!("../../../sql/tables/022_graph_fact_supersessions.sql")),
-				"tables/013_memory_note_fields.sql" =>
-					out.push_str(include_str!("../../../sql/tables/013_memory_note_fields.sql")),
-				"tables/009_memory_note_chunks.sql" =>
-					out.push_str(include_str!("../../../sql/tables/009_memory_note_chunks.sql")),
-				"tables/010_note_chunk_embeddings.sql" =>
-					out.push_str(include_str!("../../../sql/tables/010_note_chunk_embeddings.sql")),
-				"tables/014_note_field_embeddings.sql" =>
-					out.push_str(include_str!("../../../sql/tables/014_note_field_embeddings.sql")),
-				"tables/002_note_embeddings.sql" =>
-					out.push_str(include_str!("../../../sql/tables/002_note_embeddings.sql")),
-				"tables/003_memory_note_versions.sql" =>
-					out.push_str(include_str!("../../../sql/tables/003_memory_note_versions.sql")),
-				"tables/004_memory_hits.sql" =>
-					out.push_str(include_str!("../../../sql/tables/004_memory_hits.sql")),
-				"tables/005_indexing_outbox.sql" =>
-					out.push_str(include_str!("../../../sql/tables/005_indexing_outbox.sql")),
-				"tables/006_search_traces.sql" =>
-					out.push_str(include_str!("../../../sql/tables/006_search_traces.sql")),
-				"tables/012_search_trace_candidates.sql" => out
-					.push_str(include_str!("../../../sql/tables/012_search_trace_candidates.sql")),
-				"tables/015_search_trace_stages.sql" =>
-					out.push_str(include_str!("../../../sql/tables/015_search_trace_stages.sql")),
-				"tables/007_search_trace_outbox.sql" =>
-					out.push_str(include_str!("../../../sql/tables/007_search_trace_outbox.sql")),
-				"tables/008_llm_cache.sql" =>
-					out.push_str(include_str!("../../../sql/tables/008_llm_cache.sql")),
-				"tables/011_search_sessions.sql" =>
-					out.push_str(include_str!("../../../sql/tables/011_search_sessions.sql")),
-				"tables/025_doc_documents.sql" =>
-					out.push_str(include_str!("../../../sql/tables/025_doc_documents.sql")),
-				"tables/026_doc_chunks.sql" =>
-					out.push_str(include_str!("../../../sql/tables/026_doc_chunks.sql")),
-				"tables/027_doc_chunk_embeddings.sql" =>
-					out.push_str(include_str!("../../../sql/tables/027_doc_chunk_embeddings.sql")),
-				"tables/028_doc_indexing_outbox.sql" =>
-					out.push_str(include_str!("../../../sql/tables/028_doc_indexing_outbox.sql")),
-				"tables/029_memory_ingestion_profiles.sql" => out.push_str(include_str!(
-					"../../../sql/tables/029_memory_ingestion_profiles.sql"
-				)),
-				"tables/030_memory_ingestion_profile_defaults.sql" => out.push_str(include_str!(
-					"../../../sql/tables/030_memory_ingestion_profile_defaults.sql"
-				)),
-				"tables/031_consolidation_runs.sql" =>
-					out.push_str(include_str!("../../../sql/tables/031_consolidation_runs.sql")),
-				"tables/032_consolidation_proposals.sql" => out
-					.push_str(include_str!("../../../sql/tables/032_consolidation_proposals.sql")),
-				"tables/033_consolidation_proposal_reviews.sql" => out.push_str(include_str!(
-					"../../../sql/tables/033_consolidation_proposal_reviews.sql"
-				)),
-				"tables/034_consolidation_run_jobs.sql" =>
-					out.push_str(include_str!("../../../sql/tables/034_consolidation_run_jobs.sql")),
-				"tables/035_knowledge_pages.sql" =>
-					out.push_str(include_str!("../../../sql/tables/035_knowledge_pages.sql")),
-				"tables/036_knowledge_page_sections.sql" => out
-					.push_str(include_str!("../../../sql/tables/036_knowledge_page_sections.sql")),
-				"tables/037_knowledge_page_source_refs.sql" => out.push_str(include_str!(
-					"../../../sql/tables/037_knowledge_page_source_refs.sql"
-				)),
-				"tables/038_knowledge_page_lint_findings.sql" => out.push_str(include_str!(
-					"../../../sql/tables/038_knowledge_page_lint_findings.sql"
-				)),
-				"tables/039_core_memory_blocks.sql" =>
-					out.push_str(include_str!("../../../sql/tables/039_core_memory_blocks.sql")),
-				"tables/040_core_memory_block_attachments.sql" => out.push_str(include_str!(
-					"../../../sql/tables/040_core_memory_block_attachments.sql"
-				)),
-				"tables/041_core_memory_block_events.sql" => out
-					.push_str(include_str!("../../../sql/tables/041_core_memory_block_events.sql")),
-				"tables/042_work_journal_entries.sql" =>
-					out.push_str(include_str!("../../../sql/tables/042_work_journal_entries.sql")),
-				"tables/023_memory_ingest_decisions.sql" => out
-					.push_str(include_str!("../../../sql/tables/023_memory_ingest_decisions.sql")),
-				"tables/024_memory_space_grants.sql" =>
-					out.push_str(include_str!("../../../sql/tables/024_memory_space_grants.sql")),
-				_ => out.push_str(line),
-			}
-		} else {
-			out.push_str(line);
-		}
+			INCLUDES.iter().find(|(name, _)| *name == path).map(|(_, contents)| *contents)
+		});
 
+		out.push_str(include.unwrap_or(line));
 		out.push('\n');
 	}
 
@@ -139,5 +121,23 @@ mod tests {
 		assert!(schema.contains("CREATE TABLE IF NOT EXISTS core_memory_block_attachments"));
 		assert!(schema.contains("CREATE TABLE IF NOT EXISTS core_memory_block_events"));
 		assert!(schema.contains("CREATE TABLE IF NOT EXISTS work_journal_entries"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS search_shadow_comparisons"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS standing_queries"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS standing_query_embeddings"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS standing_query_matches"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS source_url_snapshots"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS memory_note_evidence"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS memory_note_values"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS memory_write_incidents"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS qdrant_maintenance_runs"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS memory_session_hits"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS embedding_reembed_runs"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS memory_space_grant_versions"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS memory_session_messages"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS graph_entity_events"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS memory_feedback"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS search_profiles"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS audit_log"));
+		assert!(schema.contains("CREATE TABLE IF NOT EXISTS memory_note_relations"));
 	}
 }
@@ -0,0 +1,176 @@
+//! Named search profile persistence.
+
+use sqlx::PgExecutor;
+
+use crate::{Result, models::SearchProfile};
+
+/// Inserts a profile or replaces the template of an existing one, returning the stored row.
+///
+/// Replacing a profile keeps `created_by` and `created_at` and increments `version`.
+pub async fn upsert_search_profile<'e, E>(
+	executor: E,
+	profile: &SearchProfile,
+) -> Result<SearchProfile>
+where
+	E: PgExecutor<'e>,
+{
+	let row = sqlx::query_as::<_, SearchProfile>(
+		"\
+INSERT INTO search_profiles (
+	tenant_id,
+	project_id,
+	name,
+	description,
+	template,
+	version,
+	created_by,
+	updated_by,
+	created_at,
+	updated_at
+)
+VALUES ($1, $2, $3, $4, $5, 1, $6, $6, $7, $7)
+ON CONFLICT (tenant_id, project_id, name) DO UPDATE
+SET
+	description = EXCLUDED.description,
+	template = EXCLUDED.template,
+	version = search_profiles.version + 1,
+	updated_by = EXCLUDED.updated_by,
+	updated_at = EXCLUDED.updated_at
+RETURNING
+	tenant_id,
+	project_id,
+	name,
+	description,
+	template,
+	version,
+	created_by,
+	updated_by,
+	created_at,
+	updated_at",
+	)
+	.bind(profile.tenant_id.as_str())
+	.bind(profile.project_id.as_str())
+	.bind(profile.name.as_str())
+	.bind(profile.description.as_deref())
+	.bind(&profile.template)
+	.bind(profile.updated_by.as_str())
+	.bind(profile.updated_at)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(row)
+}
+
+/// Fetches one profile by name.
+pub async fn get_search_profile<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	name: &str,
+) -> Result<Option<SearchProfile>>
+where
+	E: PgExecutor<'e>,
+{
+	let row = sqlx::query_as::<_, SearchProfile>(
+		"\
+SELECT
+	tenant_id,
+	project_id,
+	name,
+	description,
+	template,
+	version,
+	created_by,
+	updated_by,
+	created_at,
+	updated_at
+FROM search_profiles
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND name = $3",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(name)
+	.fetch_optional(executor)
+	.await?;
+
+	Ok(row)
+}
+
+/// Lists the profiles of one project in name order.
+pub async fn list_search_profiles<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+) -> Result<Vec<SearchProfile>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, SearchProfile>(
+		"\
+SELECT
+	tenant_id,
+	project_id,
+	name,
+	description,
+	template,
+	version,
+	created_by,
+	updated_by,
+	created_at,
+	updated_at
+FROM search_profiles
+WHERE tenant_id = $1
+	AND project_id = $2
+ORDER BY name",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Counts the profiles of one project.
+pub async fn count_search_profiles<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+) -> Result<i64>
+where
+	E: PgExecutor<'e>,
+{
+	let count = sqlx::query_scalar::<_, i64>(
+		"SELECT count(*) FROM search_profiles WHERE tenant_id = $1 AND project_id = $2",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(count)
+}
+
+/// Deletes one profile, returning whether it existed.
+pub async fn delete_search_profile<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	name: &str,
+) -> Result<bool>
+where
+	E: PgExecutor<'e>,
+{
+	let result = sqlx::query(
+		"DELETE FROM search_profiles WHERE tenant_id = $1 AND project_id = $2 AND name = $3",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(name)
+	.execute(executor)
+	.await?;
+
+	Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,111 @@
+//! Per-space generation counters that invalidate cached search results.
+//!
+//! A space is one `(tenant_id, project_id, scope)` triple. Every write that can change what a
+//! search over that space returns advances its generation inside the writing transaction, so a
+//! reader derives a fresh cache key from a handful of rows instead of scanning the corpus.
+
+use serde_json::Value;
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::Result;
+
+/// Advances the generation of every space named by the note snapshots.
+///
+/// Snapshots without a `tenant_id` are ignored. A snapshot pair that moves a note between
+/// spaces advances both.
+pub async fn bump_for_snapshots<'e, E>(
+	executor: E,
+	snapshots: &[&Value],
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	let snapshots = snapshots.iter().map(|snapshot| (*snapshot).clone()).collect::<Vec<_>>();
+
+	// Rows are locked in key order so two writers touching the same spaces cannot deadlock.
+	sqlx::query(
+		"\
+INSERT INTO search_result_watermarks (tenant_id, project_id, scope, generation, updated_at)
+SELECT DISTINCT
+	snapshot->>'tenant_id',
+	snapshot->>'project_id',
+	snapshot->>'scope',
+	1::bigint,
+	$2::timestamptz
+FROM unnest($1::jsonb[]) AS snapshot
+WHERE snapshot ? 'tenant_id'
+ORDER BY 1, 2, 3
+ON CONFLICT (tenant_id, project_id, scope) DO UPDATE
+SET
+	generation = search_result_watermarks.generation + 1,
+	updated_at = EXCLUDED.updated_at",
+	)
+	.bind(snapshots)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Advances the generation of the spaces that hold the given notes.
+pub async fn bump_for_notes<'e, E>(
+	executor: E,
+	note_ids: &[Uuid],
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+INSERT INTO search_result_watermarks (tenant_id, project_id, scope, generation, updated_at)
+SELECT DISTINCT tenant_id, project_id, scope, 1::bigint, $2::timestamptz
+FROM memory_notes
+WHERE note_id = ANY($1)
+ORDER BY 1, 2, 3
+ON CONFLICT (tenant_id, project_id, scope) DO UPDATE
+SET
+	generation = search_result_watermarks.generation + 1,
+	updated_at = EXCLUDED.updated_at",
+	)
+	.bind(note_ids)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Advances the generation of one space, for example after its sharing grants change.
+pub async fn bump_space<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	scope: &str,
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+INSERT INTO search_result_watermarks (tenant_id, project_id, scope, generation, updated_at)
+VALUES ($1, $2, $3, 1, $4)
+ON CONFLICT (tenant_id, project_id, scope) DO UPDATE
+SET
+	generation = search_result_watermarks.generation + 1,
+	updated_at = EXCLUDED.updated_at",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(scope)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
@@ -0,0 +1,181 @@
+//! Short-term session message persistence for agent working memory.
+
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+
+use crate::{Result, models::SessionMessage};
+
+/// Agent session that owns a set of working-memory messages.
+#[derive(Clone, Copy, Debug)]
+pub struct SessionKey<'a> {
+	/// Tenant that owns the session.
+	pub tenant_id: &'a str,
+	/// Project that owns the session.
+	pub project_id: &'a str,
+	/// Agent that owns the session.
+	pub agent_id: &'a str,
+	/// Caller-chosen session identifier.
+	pub session_id: &'a str,
+}
+
+/// New message appended to a session.
+#[derive(Clone, Copy, Debug)]
+pub struct SessionMessageInsert<'a> {
+	/// Speaker or message role.
+	pub role: &'a str,
+	/// Message body content.
+	pub content: &'a str,
+	/// Optional source timestamp string.
+	pub ts: Option<&'a str>,
+	/// Optional message identifier from the upstream source.
+	pub msg_id: Option<&'a str>,
+}
+
+/// Counts the unexpired messages in one session.
+pub async fn count_session_messages<'e, E>(
+	executor: E,
+	key: SessionKey<'_>,
+	now: OffsetDateTime,
+) -> Result<i64>
+where
+	E: PgExecutor<'e>,
+{
+	let count = sqlx::query_scalar::<_, i64>(
+		"\
+SELECT count(*)
+FROM memory_session_messages
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND agent_id = $3
+	AND session_id = $4
+	AND expires_at > $5",
+	)
+	.bind(key.tenant_id)
+	.bind(key.project_id)
+	.bind(key.agent_id)
+	.bind(key.session_id)
+	.bind(now)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(count)
+}
+
+/// Inserts one session message row.
+pub async fn insert_session_message<'e, E>(
+	executor: E,
+	key: SessionKey<'_>,
+	message: SessionMessageInsert<'_>,
+	now: OffsetDateTime,
+	expires_at: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+INSERT INTO memory_session_messages (
+	tenant_id,
+	project_id,
+	agent_id,
+	session_id,
+	role,
+	content,
+	ts,
+	msg_id,
+	created_at,
+	expires_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+	)
+	.bind(key.tenant_id)
+	.bind(key.project_id)
+	.bind(key.agent_id)
+	.bind(key.session_id)
+	.bind(message.role)
+	.bind(message.content)
+	.bind(message.ts)
+	.bind(message.msg_id)
+	.bind(now)
+	.bind(expires_at)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Moves the expiry of every unexpired message in one session to `expires_at`.
+///
+/// Keeps a session's messages expiring together, so each append slides the whole session TTL.
+pub async fn extend_session_expiry<'e, E>(
+	executor: E,
+	key: SessionKey<'_>,
+	now: OffsetDateTime,
+	expires_at: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+UPDATE memory_session_messages
+SET expires_at = $6
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND agent_id = $3
+	AND session_id = $4
+	AND expires_at > $5",
+	)
+	.bind(key.tenant_id)
+	.bind(key.project_id)
+	.bind(key.agent_id)
+	.bind(key.session_id)
+	.bind(now)
+	.bind(expires_at)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Lists the unexpired messages in one session, oldest first.
+pub async fn list_session_messages<'e, E>(
+	executor: E,
+	key: SessionKey<'_>,
+	now: OffsetDateTime,
+) -> Result<Vec<SessionMessage>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, SessionMessage>(
+		"\
+SELECT
+	message_seq,
+	tenant_id,
+	project_id,
+	agent_id,
+	session_id,
+	role,
+	content,
+	ts,
+	msg_id,
+	created_at,
+	expires_at
+FROM memory_session_messages
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND agent_id = $3
+	AND session_id = $4
+	AND expires_at > $5
+ORDER BY message_seq",
+	)
+	.bind(key.tenant_id)
+	.bind(key.project_id)
+	.bind(key.agent_id)
+	.bind(key.session_id)
+	.bind(now)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
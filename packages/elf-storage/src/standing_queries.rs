@@ -0,0 +1,633 @@
+//! Standing query persistence, incremental ranking, and notification queries.
+
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+	Error, Result,
+	models::{StandingQuery, StandingQueryMatch, StandingQueryNotification},
+};
+
+/// Rank of one note against a standing query.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StandingQueryRank {
+	/// 1-based rank among notes the standing query can read.
+	pub rank: i32,
+	/// Cosine similarity between the query and the note.
+	pub score: f32,
+}
+
+/// New match row recorded by the worker.
+#[derive(Clone, Debug)]
+pub struct StandingQueryMatchInsert<'a> {
+	/// Standing query that matched.
+	pub standing_query_id: Uuid,
+	/// Matched note identifier.
+	pub note_id: Uuid,
+	/// Embedding version used to rank the note.
+	pub embedding_version: &'a str,
+	/// Rank and score for the note.
+	pub rank: StandingQueryRank,
+	/// Whether a webhook delivery should be queued.
+	pub notify: bool,
+	/// Match timestamp.
+	pub now: OffsetDateTime,
+}
+
+/// Inserts one standing query row.
+pub async fn insert_standing_query<'e, E>(executor: E, query: &StandingQuery) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	let result = sqlx::query(
+		"\
+INSERT INTO standing_queries (
+	standing_query_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	query,
+	allowed_scopes,
+	note_types,
+	min_importance,
+	min_confidence,
+	top_k,
+	webhook_url,
+	status,
+	created_at,
+	updated_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+ON CONFLICT (standing_query_id) DO NOTHING",
+	)
+	.bind(query.standing_query_id)
+	.bind(query.tenant_id.as_str())
+	.bind(query.project_id.as_str())
+	.bind(query.agent_id.as_str())
+	.bind(query.query.as_str())
+	.bind(&query.allowed_scopes)
+	.bind(&query.note_types)
+	.bind(query.min_importance)
+	.bind(query.min_confidence)
+	.bind(query.top_k)
+	.bind(query.webhook_url.as_deref())
+	.bind(query.status.as_str())
+	.bind(query.created_at)
+	.bind(query.updated_at)
+	.execute(executor)
+	.await?;
+
+	if result.rows_affected() == 0 {
+		return Err(Error::Conflict("standing_query_id already exists".to_string()));
+	}
+
+	Ok(())
+}
+
+/// Fetches one active standing query owned by an agent.
+pub async fn get_standing_query<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	agent_id: &str,
+	standing_query_id: Uuid,
+) -> Result<Option<StandingQuery>>
+where
+	E: PgExecutor<'e>,
+{
+	let row = sqlx::query_as::<_, StandingQuery>(
+		"\
+SELECT
+	standing_query_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	query,
+	allowed_scopes,
+	note_types,
+	min_importance,
+	min_confidence,
+	top_k,
+	webhook_url,
+	status,
+	created_at,
+	updated_at
+FROM standing_queries
+WHERE standing_query_id = $1
+	AND tenant_id = $2
+	AND project_id = $3
+	AND agent_id = $4
+	AND status = 'active'",
+	)
+	.bind(standing_query_id)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(agent_id)
+	.fetch_optional(executor)
+	.await?;
+
+	Ok(row)
+}
+
+/// Lists active standing queries owned by an agent in newest-first order.
+pub async fn list_standing_queries<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	agent_id: &str,
+) -> Result<Vec<StandingQuery>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, StandingQuery>(
+		"\
+SELECT
+	standing_query_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	query,
+	allowed_scopes,
+	note_types,
+	min_importance,
+	min_confidence,
+	top_k,
+	webhook_url,
+	status,
+	created_at,
+	updated_at
+FROM standing_queries
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND agent_id = $3
+	AND status = 'active'
+ORDER BY created_at DESC, standing_query_id DESC",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(agent_id)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Lists active standing queries that can read notes in `scope` for a note's project.
+///
+/// Notes in the org project are visible to standing queries from every project in the tenant.
+pub async fn list_active_standing_queries_for_note<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	org_project_id: &str,
+	scope: &str,
+) -> Result<Vec<StandingQuery>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, StandingQuery>(
+		"\
+SELECT
+	standing_query_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	query,
+	allowed_scopes,
+	note_types,
+	min_importance,
+	min_confidence,
+	top_k,
+	webhook_url,
+	status,
+	created_at,
+	updated_at
+FROM standing_queries
+WHERE tenant_id = $1
+	AND ($2 = $3 OR project_id = $2)
+	AND $4 = ANY(allowed_scopes)
+	AND status = 'active'
+ORDER BY created_at ASC, standing_query_id ASC",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(org_project_id)
+	.bind(scope)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Counts active standing queries owned by an agent.
+pub async fn count_active_standing_queries<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	agent_id: &str,
+) -> Result<i64>
+where
+	E: PgExecutor<'e>,
+{
+	let count = sqlx::query_scalar::<_, i64>(
+		"\
+SELECT count(*)
+FROM standing_queries
+WHERE tenant_id = $1
+	AND project_id = $2
+	AND agent_id = $3
+	AND status = 'active'",
+	)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(agent_id)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(count)
+}
+
+/// Marks one standing query deleted and cancels its pending notifications.
+///
+/// Returns false when no active standing query matched.
+pub async fn delete_standing_query<'e, E>(
+	executor: E,
+	tenant_id: &str,
+	project_id: &str,
+	agent_id: &str,
+	standing_query_id: Uuid,
+	now: OffsetDateTime,
+) -> Result<bool>
+where
+	E: PgExecutor<'e>,
+{
+	let deleted = sqlx::query_scalar::<_, bool>(
+		"\
+WITH deleted AS (
+	UPDATE standing_queries
+	SET status = 'deleted', updated_at = $5
+	WHERE standing_query_id = $1
+		AND tenant_id = $2
+		AND project_id = $3
+		AND agent_id = $4
+		AND status = 'active'
+	RETURNING standing_query_id
+),
+cancelled AS (
+	UPDATE standing_query_matches m
+	SET notify_status = 'failed', notify_next_at = NULL, notify_error = 'Standing query deleted.'
+	FROM deleted d
+	WHERE m.standing_query_id = d.standing_query_id
+		AND m.notify_status = 'pending'
+)
+SELECT EXISTS (SELECT 1 FROM deleted)",
+	)
+	.bind(standing_query_id)
+	.bind(tenant_id)
+	.bind(project_id)
+	.bind(agent_id)
+	.bind(now)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(deleted)
+}
+
+/// Lists matches for one standing query after a change-feed position, oldest first.
+pub async fn list_standing_query_matches<'e, E>(
+	executor: E,
+	standing_query_id: Uuid,
+	after_seq: i64,
+	limit: i64,
+) -> Result<Vec<StandingQueryMatch>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, StandingQueryMatch>(
+		"\
+SELECT
+	match_id,
+	seq,
+	standing_query_id,
+	note_id,
+	embedding_version,
+	rank,
+	score,
+	notify_status,
+	notify_attempts,
+	notify_next_at,
+	notify_error,
+	notified_at,
+	created_at
+FROM standing_query_matches
+WHERE standing_query_id = $1
+	AND seq > $2
+ORDER BY seq ASC
+LIMIT $3",
+	)
+	.bind(standing_query_id)
+	.bind(after_seq)
+	.bind(limit)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Returns whether a query embedding is cached for an embedding version.
+pub async fn has_standing_query_embedding<'e, E>(
+	executor: E,
+	standing_query_id: Uuid,
+	embedding_version: &str,
+) -> Result<bool>
+where
+	E: PgExecutor<'e>,
+{
+	let exists = sqlx::query_scalar::<_, bool>(
+		"\
+SELECT EXISTS (
+	SELECT 1
+	FROM standing_query_embeddings
+	WHERE standing_query_id = $1 AND embedding_version = $2
+)",
+	)
+	.bind(standing_query_id)
+	.bind(embedding_version)
+	.fetch_one(executor)
+	.await?;
+
+	Ok(exists)
+}
+
+/// Caches a query embedding for one embedding version.
+pub async fn upsert_standing_query_embedding<'e, E>(
+	executor: E,
+	standing_query_id: Uuid,
+	embedding_version: &str,
+	embedding_dim: i32,
+	vec_text: &str,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+INSERT INTO standing_query_embeddings (
+	standing_query_id,
+	embedding_version,
+	embedding_dim,
+	vec
+)
+VALUES ($1, $2, $3, $4::text::vector)
+ON CONFLICT (standing_query_id, embedding_version) DO UPDATE
+SET
+	embedding_dim = EXCLUDED.embedding_dim,
+	vec = EXCLUDED.vec,
+	created_at = now()",
+	)
+	.bind(standing_query_id)
+	.bind(embedding_version)
+	.bind(embedding_dim)
+	.bind(vec_text)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Ranks one note against a standing query using pooled note embeddings.
+///
+/// Only notes the standing query owner can read, and that pass its type and threshold
+/// filters, are ranked. Returns `None` when the note itself is not eligible or no query
+/// embedding is cached for `embedding_version`.
+pub async fn rank_note_for_standing_query<'e, E>(
+	executor: E,
+	query: &StandingQuery,
+	note_id: Uuid,
+	embedding_version: &str,
+	org_project_id: &str,
+	now: OffsetDateTime,
+) -> Result<Option<StandingQueryRank>>
+where
+	E: PgExecutor<'e>,
+{
+	let row = sqlx::query_as::<_, (f64, i64)>(
+		"\
+WITH query_vec AS (
+	SELECT vec
+	FROM standing_query_embeddings
+	WHERE standing_query_id = $1 AND embedding_version = $2
+),
+candidates AS (
+	SELECT n.note_id, ne.vec <=> q.vec AS distance
+	FROM memory_notes n
+	JOIN note_embeddings ne ON ne.note_id = n.note_id AND ne.embedding_version = $2
+	CROSS JOIN query_vec q
+	WHERE n.tenant_id = $3
+		AND (n.project_id = $4 OR (n.scope = 'org_shared' AND n.project_id = $5))
+		AND n.status = 'active'
+		AND (n.expires_at IS NULL OR n.expires_at > $6)
+		AND n.scope = ANY($7)
+		AND (cardinality($8::text[]) = 0 OR n.type = ANY($8))
+		AND ($9::real IS NULL OR n.importance >= $9)
+		AND ($10::real IS NULL OR n.confidence >= $10)
+		AND (
+			n.agent_id = $11
+			OR (
+				n.scope IN ('project_shared', 'org_shared')
+				AND EXISTS (
+					SELECT 1
+					FROM memory_space_grants g
+					WHERE g.tenant_id = n.tenant_id
+						AND g.project_id = n.project_id
+						AND g.scope = n.scope
+						AND g.space_owner_agent_id = n.agent_id
+						AND g.revoked_at IS NULL
+						AND (g.grantee_kind = 'project' OR g.grantee_agent_id = $11)
+				)
+			)
+		)
+)
+SELECT
+	c.distance,
+	(
+		SELECT count(*)
+		FROM (
+			SELECT 1
+			FROM candidates o
+			WHERE o.note_id <> c.note_id AND o.distance < c.distance
+			LIMIT $12
+		) closer
+	) AS closer
+FROM candidates c
+WHERE c.note_id = $13",
+	)
+	.bind(query.standing_query_id)
+	.bind(embedding_version)
+	.bind(query.tenant_id.as_str())
+	.bind(query.project_id.as_str())
+	.bind(org_project_id)
+	.bind(now)
+	.bind(&query.allowed_scopes)
+	.bind(&query.note_types)
+	.bind(query.min_importance)
+	.bind(query.min_confidence)
+	.bind(query.agent_id.as_str())
+	.bind(i64::from(query.top_k))
+	.bind(note_id)
+	.fetch_optional(executor)
+	.await?;
+
+	Ok(row.map(|(distance, closer)| StandingQueryRank {
+		rank: i32::try_from(closer).unwrap_or(i32::MAX).saturating_add(1),
+		score: (1.0 - distance) as f32,
+	}))
+}
+
+/// Records one match, ignoring notes already matched by the standing query.
+///
+/// Returns false when the match already existed.
+pub async fn insert_standing_query_match<'e, E>(
+	executor: E,
+	args: &StandingQueryMatchInsert<'_>,
+) -> Result<bool>
+where
+	E: PgExecutor<'e>,
+{
+	let (notify_status, notify_next_at) =
+		if args.notify { ("pending", Some(args.now)) } else { ("none", None) };
+	let result = sqlx::query(
+		"\
+INSERT INTO standing_query_matches (
+	match_id,
+	standing_query_id,
+	note_id,
+	embedding_version,
+	rank,
+	score,
+	notify_status,
+	notify_next_at,
+	created_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (standing_query_id, note_id) DO NOTHING",
+	)
+	.bind(Uuid::new_v4())
+	.bind(args.standing_query_id)
+	.bind(args.note_id)
+	.bind(args.embedding_version)
+	.bind(args.rank.rank)
+	.bind(args.rank.score)
+	.bind(notify_status)
+	.bind(notify_next_at)
+	.bind(args.now)
+	.execute(executor)
+	.await?;
+
+	Ok(result.rows_affected() > 0)
+}
+
+/// Claims due webhook deliveries and leases them until `lease_until`.
+pub async fn claim_standing_query_notifications<'e, E>(
+	executor: E,
+	now: OffsetDateTime,
+	lease_until: OffsetDateTime,
+	limit: i64,
+) -> Result<Vec<StandingQueryNotification>>
+where
+	E: PgExecutor<'e>,
+{
+	let rows = sqlx::query_as::<_, StandingQueryNotification>(
+		"\
+WITH due AS (
+	SELECT m.match_id
+	FROM standing_query_matches m
+	WHERE m.notify_status = 'pending' AND m.notify_next_at <= $1
+	ORDER BY m.notify_next_at ASC
+	LIMIT $3
+	FOR UPDATE SKIP LOCKED
+)
+UPDATE standing_query_matches m
+SET notify_next_at = $2
+FROM due, standing_queries q
+WHERE m.match_id = due.match_id
+	AND q.standing_query_id = m.standing_query_id
+	AND q.webhook_url IS NOT NULL
+RETURNING
+	m.match_id,
+	m.standing_query_id,
+	m.note_id,
+	m.rank,
+	m.score,
+	m.notify_attempts,
+	m.created_at,
+	q.webhook_url",
+	)
+	.bind(now)
+	.bind(lease_until)
+	.bind(limit)
+	.fetch_all(executor)
+	.await?;
+
+	Ok(rows)
+}
+
+/// Marks one webhook delivery as delivered.
+pub async fn mark_standing_query_notification_delivered<'e, E>(
+	executor: E,
+	match_id: Uuid,
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+UPDATE standing_query_matches
+SET
+	notify_status = 'delivered',
+	notify_attempts = notify_attempts + 1,
+	notify_next_at = NULL,
+	notify_error = NULL,
+	notified_at = $2
+WHERE match_id = $1",
+	)
+	.bind(match_id)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Records a failed webhook delivery.
+///
+/// A `retry_at` of `None` marks the delivery as permanently failed.
+pub async fn mark_standing_query_notification_failed<'e, E>(
+	executor: E,
+	match_id: Uuid,
+	error: &str,
+	retry_at: Option<OffsetDateTime>,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+UPDATE standing_query_matches
+SET
+	notify_status = CASE WHEN $3::timestamptz IS NULL THEN 'failed' ELSE 'pending' END,
+	notify_attempts = notify_attempts + 1,
+	notify_next_at = $3,
+	notify_error = $2
+WHERE match_id = $1",
+	)
+	.bind(match_id)
+	.bind(error)
+	.bind(retry_at)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
@@ -0,0 +1,249 @@
+//! External URL snapshot queue and note evidence linking queries.
+
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{Result, models::SourceUrlSnapshot};
+
+/// New snapshot job queued when a note references an external URL.
+#[derive(Clone, Debug)]
+pub struct SourceUrlSnapshotInsert<'a> {
+	/// Note whose `source_ref` points at the URL.
+	pub note_id: Uuid,
+	/// Tenant that owns the note.
+	pub tenant_id: &'a str,
+	/// Project that owns the note.
+	pub project_id: &'a str,
+	/// Agent that wrote the note.
+	pub agent_id: &'a str,
+	/// Scope assigned to the note.
+	pub scope: &'a str,
+	/// External URL to fetch.
+	pub url: &'a str,
+	/// Embedding version used when indexing the snapshot document.
+	pub embedding_version: &'a str,
+	/// Enqueue timestamp; the job is due immediately.
+	pub now: OffsetDateTime,
+}
+
+/// Queues a snapshot of `url` for a note.
+///
+/// A note that already has a job for the same URL keeps the existing job.
+pub async fn enqueue_source_url_snapshot<'e, E>(
+	executor: E,
+	snapshot: &SourceUrlSnapshotInsert<'_>,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+INSERT INTO source_url_snapshots (
+	snapshot_id,
+	note_id,
+	tenant_id,
+	project_id,
+	agent_id,
+	scope,
+	url,
+	embedding_version,
+	status,
+	next_attempt_at,
+	created_at,
+	updated_at
+)
+VALUES ($1,$2,$3,$4,$5,$6,$7,$8,'pending',$9,$9,$9)
+ON CONFLICT (note_id, url) DO NOTHING",
+	)
+	.bind(Uuid::new_v4())
+	.bind(snapshot.note_id)
+	.bind(snapshot.tenant_id)
+	.bind(snapshot.project_id)
+	.bind(snapshot.agent_id)
+	.bind(snapshot.scope)
+	.bind(snapshot.url)
+	.bind(snapshot.embedding_version)
+	.bind(snapshot.now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Claims the next due snapshot job and leases it until `lease_until`.
+pub async fn claim_next_source_url_snapshot<'e, E>(
+	executor: E,
+	now: OffsetDateTime,
+	lease_until: OffsetDateTime,
+) -> Result<Option<SourceUrlSnapshot>>
+where
+	E: PgExecutor<'e>,
+{
+	let row = sqlx::query_as::<_, SourceUrlSnapshot>(
+		"\
+WITH due AS (
+	SELECT snapshot_id
+	FROM source_url_snapshots
+	WHERE status = 'pending' AND next_attempt_at <= $1
+	ORDER BY next_attempt_at ASC
+	LIMIT 1
+	FOR UPDATE SKIP LOCKED
+)
+UPDATE source_url_snapshots s
+SET next_attempt_at = $2, updated_at = $1
+FROM due
+WHERE s.snapshot_id = due.snapshot_id
+RETURNING s.*",
+	)
+	.bind(now)
+	.bind(lease_until)
+	.fetch_optional(executor)
+	.await?;
+
+	Ok(row)
+}
+
+/// Marks a snapshot job as captured into `doc_id`.
+pub async fn mark_source_url_snapshot_done<'e, E>(
+	executor: E,
+	snapshot_id: Uuid,
+	doc_id: Uuid,
+	content_hash: &str,
+	http_status: i32,
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+UPDATE source_url_snapshots
+SET
+	status = 'done',
+	attempts = attempts + 1,
+	next_attempt_at = NULL,
+	last_error = NULL,
+	http_status = $4,
+	doc_id = $2,
+	content_hash = $3,
+	fetched_at = $5,
+	updated_at = $5
+WHERE snapshot_id = $1",
+	)
+	.bind(snapshot_id)
+	.bind(doc_id)
+	.bind(content_hash)
+	.bind(http_status)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Marks a snapshot job as blocked by the site's robots.txt. Blocked jobs are never retried.
+pub async fn mark_source_url_snapshot_blocked<'e, E>(
+	executor: E,
+	snapshot_id: Uuid,
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+UPDATE source_url_snapshots
+SET
+	status = 'blocked',
+	attempts = attempts + 1,
+	next_attempt_at = NULL,
+	last_error = 'Disallowed by robots.txt.',
+	updated_at = $2
+WHERE snapshot_id = $1",
+	)
+	.bind(snapshot_id)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Records a failed snapshot attempt.
+///
+/// A `retry_at` of `None` marks the job as permanently failed.
+pub async fn mark_source_url_snapshot_failed<'e, E>(
+	executor: E,
+	snapshot_id: Uuid,
+	error: &str,
+	http_status: Option<i32>,
+	retry_at: Option<OffsetDateTime>,
+	now: OffsetDateTime,
+) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+UPDATE source_url_snapshots
+SET
+	status = CASE WHEN $4::timestamptz IS NULL THEN 'failed' ELSE 'pending' END,
+	attempts = attempts + 1,
+	next_attempt_at = $4,
+	last_error = $2,
+	http_status = COALESCE($3, http_status),
+	updated_at = $5
+WHERE snapshot_id = $1",
+	)
+	.bind(snapshot_id)
+	.bind(error)
+	.bind(http_status)
+	.bind(retry_at)
+	.bind(now)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
+
+/// Records the captured snapshot for `url` under `source_ref.state.snapshot` of a note.
+///
+/// Nothing changes unless a `done` snapshot exists for the note and URL and the note still points
+/// at that URL. The note text and version history are untouched.
+pub async fn attach_source_url_snapshot<'e, E>(executor: E, note_id: Uuid, url: &str) -> Result<()>
+where
+	E: PgExecutor<'e>,
+{
+	sqlx::query(
+		"\
+UPDATE memory_notes n
+SET source_ref = n.source_ref || jsonb_build_object(
+	'state',
+	CASE
+		WHEN jsonb_typeof(n.source_ref->'state') = 'object' THEN n.source_ref->'state'
+		ELSE '{}'::jsonb
+	END || jsonb_build_object(
+		'snapshot',
+		jsonb_build_object(
+			'doc_id', s.doc_id,
+			'content_hash', s.content_hash,
+			'http_status', s.http_status,
+			'fetched_at', s.fetched_at
+		)
+	)
+)
+FROM source_url_snapshots s
+WHERE s.note_id = $1
+	AND s.url = $2
+	AND s.status = 'done'
+	AND n.note_id = s.note_id
+	AND n.source_ref->'ref'->>'url' = s.url",
+	)
+	.bind(note_id)
+	.bind(url)
+	.execute(executor)
+	.await?;
+
+	Ok(())
+}
@@ -40,3 +40,49 @@ async fn graph_entity_upsert_is_idempotent_by_normalized_canonical() {
 
 	assert!(test_db.cleanup().await.is_ok(), "Failed to cleanup test database.");
 }
+
+#[tokio::test]
+#[ignore = "Requires external Postgres. Set ELF_PG_DSN to run."]
+async fn graph_entity_kind_resolves_project_before_tenant_vocabulary() {
+	let Some(base_dsn) = elf_testkit::env_dsn() else {
+		eprintln!(
+			"Skipping graph_entity_kind_resolves_project_before_tenant_vocabulary; set ELF_PG_DSN to run."
+		);
+
+		return;
+	};
+	let test_db = TestDatabase::new(&base_dsn).await.expect("Failed to create test database.");
+	let cfg = Postgres { dsn: test_db.dsn().to_string(), pool_max_conns: 1 };
+	let db = Db::connect(&cfg).await.expect("Failed to connect to Postgres.");
+
+	db.ensure_schema(4_096).await.expect("Failed to ensure schema.");
+
+	let mut tx = db.pool.begin().await.expect("Failed to open transaction.");
+	let kind = graph::resolve_or_register_entity_kind(&mut tx, "tenant-a", "project-a", "Person")
+		.await
+		.expect("Failed to register entity kind.");
+	let promoted = graph::promote_entity_kind_to_tenant(&mut tx, kind.kind_id)
+		.await
+		.expect("Failed to promote entity kind.");
+
+	assert_eq!(promoted.scope_key, "__tenant__:tenant-a");
+
+	let inherited =
+		graph::resolve_or_register_entity_kind(&mut tx, "tenant-a", "project-b", " person ")
+			.await
+			.expect("Failed to resolve entity kind.");
+
+	assert_eq!(inherited.kind_id, kind.kind_id);
+	assert_eq!(inherited.kind, "Person");
+
+	let other_tenant =
+		graph::resolve_or_register_entity_kind(&mut tx, "tenant-b", "project-b", "person")
+			.await
+			.expect("Failed to register entity kind.");
+
+	assert_ne!(other_tenant.kind_id, kind.kind_id);
+	assert_eq!(other_tenant.kind, "person");
+
+	tx.rollback().await.expect("Failed to rollback transaction.");
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -71,3 +71,67 @@ async fn graph_predicate_guarded_update_conflicts_after_deprecate() {
 	tx.rollback().await.expect("Failed to rollback transaction.");
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
+
+#[tokio::test]
+#[ignore = "Requires external Postgres. Set ELF_PG_DSN to run."]
+async fn graph_predicate_promoted_to_tenant_resolves_in_sibling_projects() {
+	let Some(base_dsn) = elf_testkit::env_dsn() else {
+		eprintln!(
+			"Skipping graph_predicate_promoted_to_tenant_resolves_in_sibling_projects; set ELF_PG_DSN to run."
+		);
+
+		return;
+	};
+	let test_db = TestDatabase::new(&base_dsn).await.expect("Failed to create test database.");
+	let cfg = Postgres { dsn: test_db.dsn().to_string(), pool_max_conns: 1 };
+	let db = Db::connect(&cfg).await.expect("Failed to connect to Postgres.");
+
+	db.ensure_schema(4_096).await.expect("Failed to ensure schema.");
+
+	let mut tx = db.pool.begin().await.expect("Failed to open transaction.");
+	let local = graph::resolve_or_register_predicate(&mut tx, "tenant-a", "project-b", "Mentors")
+		.await
+		.expect("Failed to register project-b predicate.");
+	let predicate =
+		graph::resolve_or_register_predicate(&mut tx, "tenant-a", "project-a", "mentors")
+			.await
+			.expect("Failed to register project-a predicate.");
+	let promoted = graph::promote_predicate_to_tenant(&mut tx, predicate.predicate_id)
+		.await
+		.expect("Failed to promote predicate.");
+
+	assert_eq!(promoted.predicate_id, predicate.predicate_id);
+	assert_eq!(promoted.scope_key, "__tenant__:tenant-a");
+	assert_eq!(promoted.project_id, None);
+
+	let sibling = graph::resolve_predicate_no_register(&mut tx, "tenant-a", "project-c", "MENTORS")
+		.await
+		.expect("Failed to resolve predicate.")
+		.expect("Expected tenant predicate to resolve.");
+
+	assert_eq!(sibling.predicate_id, predicate.predicate_id);
+
+	let overridden =
+		graph::resolve_predicate_no_register(&mut tx, "tenant-a", "project-b", "mentors")
+			.await
+			.expect("Failed to resolve predicate.")
+			.expect("Expected project predicate to resolve.");
+
+	assert_eq!(overridden.predicate_id, local.predicate_id);
+
+	let other_tenant =
+		graph::resolve_predicate_no_register(&mut tx, "tenant-b", "project-a", "mentors")
+			.await
+			.expect("Failed to resolve predicate.");
+
+	assert!(other_tenant.is_none());
+
+	let err = graph::promote_predicate_to_tenant(&mut tx, local.predicate_id)
+		.await
+		.expect_err("Expected promotion to conflict with the tenant predicate.");
+
+	assert!(matches!(err, elf_storage::Error::Conflict(_)));
+
+	tx.rollback().await.expect("Failed to rollback transaction.");
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
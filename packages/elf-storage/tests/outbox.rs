@@ -2,12 +2,58 @@
 
 //! Integration tests for storage outbox helpers.
 
+use time::{Duration, OffsetDateTime};
 use uuid::Uuid;
 
 use elf_config::Postgres;
-use elf_storage::{db::Db, outbox};
+use elf_storage::{db::Db, models::MemoryNote, outbox, queries};
 use elf_testkit::TestDatabase;
 
+async fn insert_note(db: &Db, tenant_id: &str) -> Uuid {
+	let now = OffsetDateTime::now_utc();
+	let note = MemoryNote {
+		note_id: Uuid::new_v4(),
+		tenant_id: tenant_id.to_string(),
+		project_id: "project-a".to_string(),
+		agent_id: "agent-a".to_string(),
+		scope: "agent_private".to_string(),
+		r#type: "fact".to_string(),
+		key: None,
+		text: "Outbox test note.".to_string(),
+		importance: 0.5,
+		confidence: 0.5,
+		status: "active".to_string(),
+		created_at: now,
+		updated_at: now,
+		expires_at: None,
+		embedding_version: "test:vector:1".to_string(),
+		source_ref: serde_json::json!({}),
+		hit_count: 0,
+		last_hit_at: None,
+	};
+
+	queries::insert_note(&db.pool, &note).await.expect("Failed to insert note.");
+
+	note.note_id
+}
+
+async fn dead_letter_job(db: &Db, note_id: Uuid, op: &str, now: OffsetDateTime) -> Uuid {
+	outbox::enqueue_outbox(&db.pool, note_id, op, "test:vector:1")
+		.await
+		.expect("Failed to enqueue outbox.");
+
+	let job = outbox::claim_next_indexing_outbox_job(db, now, 30)
+		.await
+		.expect("Failed to claim outbox job.")
+		.expect("Expected a claimable job.");
+
+	outbox::mark_indexing_outbox_dead_letter(db, job.outbox_id, 10, "provider down", now)
+		.await
+		.expect("Failed to dead-letter outbox job.");
+
+	job.outbox_id
+}
+
 #[tokio::test]
 #[ignore = "Requires external Postgres. Set ELF_PG_DSN to run."]
 async fn enqueues_outbox_job() {
@@ -28,3 +74,180 @@ async fn enqueues_outbox_job() {
 
 	test_db.cleanup().await.expect("Failed to cleanup test database.");
 }
+
+#[tokio::test]
+#[ignore = "Requires external Postgres. Set ELF_PG_DSN to run."]
+async fn claims_one_open_job_per_note_in_enqueue_order() {
+	let Some(base_dsn) = elf_testkit::env_dsn() else {
+		eprintln!(
+			"Skipping claims_one_open_job_per_note_in_enqueue_order; set ELF_PG_DSN to run this test."
+		);
+
+		return;
+	};
+	let test_db = TestDatabase::new(&base_dsn).await.expect("Failed to create test database.");
+	let cfg = Postgres { dsn: test_db.dsn().to_string(), pool_max_conns: 1 };
+	let db = Db::connect(&cfg).await.expect("Failed to connect to Postgres.");
+
+	db.ensure_schema(4_096).await.expect("Failed to ensure schema.");
+
+	let note_a = Uuid::new_v4();
+	let note_b = Uuid::new_v4();
+
+	for (note_id, op) in [(note_a, "UPSERT"), (note_a, "DELETE"), (note_b, "UPSERT")] {
+		outbox::enqueue_outbox(&db.pool, note_id, op, "test:vector:1")
+			.await
+			.expect("Failed to enqueue outbox.");
+	}
+
+	let now = OffsetDateTime::now_utc() + Duration::seconds(1);
+	let first = outbox::claim_next_indexing_outbox_job(&db, now, 30)
+		.await
+		.expect("Failed to claim outbox job.")
+		.expect("Expected a claimable job.");
+	let second = outbox::claim_next_indexing_outbox_job(&db, now, 30)
+		.await
+		.expect("Failed to claim outbox job.")
+		.expect("Expected a claimable job.");
+
+	assert_eq!((first.note_id, first.op.as_str()), (note_a, "UPSERT"));
+	assert_eq!(second.note_id, note_b);
+	assert!(
+		outbox::claim_next_indexing_outbox_job(&db, now, 30)
+			.await
+			.expect("Failed to claim outbox job.")
+			.is_none(),
+		"The later job of a note must wait for the earlier one."
+	);
+
+	outbox::mark_indexing_outbox_done(&db, first.outbox_id, now)
+		.await
+		.expect("Failed to mark outbox job done.");
+
+	let third = outbox::claim_next_indexing_outbox_job(&db, now, 30)
+		.await
+		.expect("Failed to claim outbox job.")
+		.expect("Expected the later job once the earlier one is done.");
+
+	assert_eq!((third.note_id, third.op.as_str()), (note_a, "DELETE"));
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres. Set ELF_PG_DSN to run."]
+async fn dead_lettered_jobs_are_listed_and_replayed_per_tenant() {
+	let Some(base_dsn) = elf_testkit::env_dsn() else {
+		eprintln!(
+			"Skipping dead_lettered_jobs_are_listed_and_replayed_per_tenant; set ELF_PG_DSN to run this test."
+		);
+
+		return;
+	};
+	let test_db = TestDatabase::new(&base_dsn).await.expect("Failed to create test database.");
+	let cfg = Postgres { dsn: test_db.dsn().to_string(), pool_max_conns: 1 };
+	let db = Db::connect(&cfg).await.expect("Failed to connect to Postgres.");
+
+	db.ensure_schema(4_096).await.expect("Failed to ensure schema.");
+
+	let now = OffsetDateTime::now_utc() + Duration::seconds(1);
+	let own_note = insert_note(&db, "tenant-a").await;
+	let other_note = insert_note(&db, "tenant-b").await;
+	let own_job = dead_letter_job(&db, own_note, "UPSERT", now).await;
+	let other_job = dead_letter_job(&db, other_note, "UPSERT", now).await;
+	let dead_letters = outbox::list_indexing_outbox_dead_letters(&db.pool, "tenant-a", 10)
+		.await
+		.expect("Failed to list dead letters.");
+
+	assert_eq!(dead_letters.len(), 1);
+	assert_eq!(dead_letters[0].outbox_id, own_job);
+	assert_eq!(dead_letters[0].last_error.as_deref(), Some("provider down"));
+
+	let replayed = outbox::replay_indexing_outbox_dead_letters(
+		&db.pool,
+		"tenant-a",
+		&[own_job, other_job, Uuid::new_v4()],
+		now,
+	)
+	.await
+	.expect("Failed to replay dead letters.");
+
+	assert_eq!(replayed, vec![own_job]);
+
+	let requeued = outbox::claim_next_indexing_outbox_job(&db, now, 30)
+		.await
+		.expect("Failed to claim outbox job.")
+		.expect("Expected the replayed job to be claimable.");
+
+	assert_eq!((requeued.outbox_id, requeued.attempts), (own_job, 0));
+
+	let other_dead_letters = outbox::list_indexing_outbox_dead_letters(&db.pool, "tenant-b", 10)
+		.await
+		.expect("Failed to list dead letters.");
+
+	assert_eq!(other_dead_letters.len(), 1);
+	assert_eq!(other_dead_letters[0].outbox_id, other_job);
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
+
+#[tokio::test]
+#[ignore = "Requires external Postgres. Set ELF_PG_DSN to run."]
+async fn dead_lettered_delete_jobs_of_purged_notes_stay_listed_and_replayable() {
+	let Some(base_dsn) = elf_testkit::env_dsn() else {
+		eprintln!(
+			"Skipping dead_lettered_delete_jobs_of_purged_notes_stay_listed_and_replayable; set ELF_PG_DSN to run this test."
+		);
+
+		return;
+	};
+	let test_db = TestDatabase::new(&base_dsn).await.expect("Failed to create test database.");
+	let cfg = Postgres { dsn: test_db.dsn().to_string(), pool_max_conns: 1 };
+	let db = Db::connect(&cfg).await.expect("Failed to connect to Postgres.");
+
+	db.ensure_schema(4_096).await.expect("Failed to ensure schema.");
+
+	let now = OffsetDateTime::now_utc() + Duration::seconds(1);
+	let note_id = insert_note(&db, "tenant-a").await;
+	let job = dead_letter_job(&db, note_id, "DELETE", now).await;
+
+	sqlx::query("DELETE FROM memory_notes WHERE note_id = $1")
+		.bind(note_id)
+		.execute(&db.pool)
+		.await
+		.expect("Failed to purge note.");
+
+	let dead_letters = outbox::list_indexing_outbox_dead_letters(&db.pool, "tenant-a", 10)
+		.await
+		.expect("Failed to list dead letters.");
+
+	assert_eq!(dead_letters.len(), 1);
+	assert_eq!((dead_letters[0].outbox_id, dead_letters[0].op.as_str()), (job, "DELETE"));
+	assert!(
+		outbox::list_indexing_outbox_dead_letters(&db.pool, "tenant-b", 10)
+			.await
+			.expect("Failed to list dead letters.")
+			.is_empty()
+	);
+
+	let foreign = outbox::replay_indexing_outbox_dead_letters(&db.pool, "tenant-b", &[job], now)
+		.await
+		.expect("Failed to replay dead letters.");
+
+	assert!(foreign.is_empty());
+
+	let replayed = outbox::replay_indexing_outbox_dead_letters(&db.pool, "tenant-a", &[job], now)
+		.await
+		.expect("Failed to replay dead letters.");
+
+	assert_eq!(replayed, vec![job]);
+
+	let requeued = outbox::claim_next_indexing_outbox_job(&db, now, 30)
+		.await
+		.expect("Failed to claim outbox job.")
+		.expect("Expected the replayed job to be claimable.");
+
+	assert_eq!((requeued.outbox_id, requeued.note_id), (job, note_id));
+
+	test_db.cleanup().await.expect("Failed to cleanup test database.");
+}
@@ -73,6 +73,12 @@ for collection in "${collections[@]}"; do
 JSON
   fi
 
+  if [[ "${collection}" == "${ELF_QDRANT_COLLECTION}" ]]; then
+    create_payload_index "$collection" '{"field_name":"type","field_schema":"keyword"}'
+    create_payload_index "$collection" '{"field_name":"updated_at","field_schema":"datetime"}'
+    create_payload_index "$collection" '{"field_name":"importance","field_schema":"float"}'
+  fi
+
   if [[ -n "${ELF_QDRANT_DOCS_COLLECTION:-}" && "${collection}" == "${ELF_QDRANT_DOCS_COLLECTION}" ]]; then
     create_payload_index "$collection" '{"field_name":"scope","field_schema":"keyword"}'
     create_payload_index "$collection" '{"field_name":"status","field_schema":"keyword"}'
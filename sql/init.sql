@@ -10,6 +10,7 @@
 \ir tables/013_memory_note_fields.sql
 \ir tables/009_memory_note_chunks.sql
 \ir tables/010_note_chunk_embeddings.sql
+\ir tables/048_chunk_content_embeddings.sql
 \ir tables/014_note_field_embeddings.sql
 \ir tables/002_note_embeddings.sql
 \ir tables/003_memory_note_versions.sql
@@ -41,3 +42,25 @@
 \ir tables/040_core_memory_block_attachments.sql
 \ir tables/041_core_memory_block_events.sql
 \ir tables/042_work_journal_entries.sql
+\ir tables/043_search_shadow_comparisons.sql
+\ir tables/044_standing_queries.sql
+\ir tables/045_standing_query_embeddings.sql
+\ir tables/046_standing_query_matches.sql
+\ir tables/047_source_url_snapshots.sql
+\ir tables/049_eval_continuous_runs.sql
+\ir tables/050_memory_note_evidence.sql
+\ir tables/051_graph_entity_kinds.sql
+\ir tables/052_memory_note_values.sql
+\ir tables/053_memory_write_incidents.sql
+\ir tables/054_qdrant_maintenance_runs.sql
+\ir tables/055_memory_session_hits.sql
+\ir tables/056_memory_note_events.sql
+\ir tables/057_embedding_reembed_runs.sql
+\ir tables/058_memory_space_grant_versions.sql
+\ir tables/059_memory_session_messages.sql
+\ir tables/060_graph_entity_events.sql
+\ir tables/061_memory_feedback.sql
+\ir tables/062_search_profiles.sql
+\ir tables/063_audit_log.sql
+\ir tables/064_memory_note_relations.sql
+\ir tables/065_search_result_watermarks.sql
@@ -19,6 +19,9 @@ CREATE TABLE IF NOT EXISTS memory_notes (
 	last_hit_at timestamptz NULL
 );
 
+ALTER TABLE memory_notes
+	ADD COLUMN IF NOT EXISTS pinned boolean NOT NULL DEFAULT false;
+
 CREATE INDEX IF NOT EXISTS idx_notes_scope_status
 	ON memory_notes (tenant_id, project_id, scope, status);
 CREATE INDEX IF NOT EXISTS idx_notes_key
@@ -26,3 +29,10 @@ CREATE INDEX IF NOT EXISTS idx_notes_key
 	WHERE key IS NOT NULL;
 CREATE INDEX IF NOT EXISTS idx_notes_expires
 	ON memory_notes (expires_at);
+CREATE INDEX IF NOT EXISTS idx_notes_agent_created
+	ON memory_notes (tenant_id, project_id, agent_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_notes_tenant_created
+	ON memory_notes (tenant_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_notes_pinned
+	ON memory_notes (tenant_id, project_id)
+	WHERE pinned;
@@ -8,3 +8,6 @@ CREATE TABLE IF NOT EXISTS memory_note_versions (
 	actor text NOT NULL,
 	ts timestamptz NOT NULL DEFAULT now()
 );
+
+ALTER TABLE memory_note_versions
+	ADD COLUMN IF NOT EXISTS redactions jsonb NULL;
@@ -15,3 +15,6 @@ CREATE INDEX IF NOT EXISTS idx_outbox_status_available
 	ON indexing_outbox (status, available_at);
 CREATE INDEX IF NOT EXISTS idx_outbox_note_op_status
 	ON indexing_outbox (note_id, op, status);
+
+ALTER TABLE indexing_outbox
+	ADD COLUMN IF NOT EXISTS tenant_id text;
@@ -13,3 +13,13 @@ CREATE INDEX IF NOT EXISTS idx_note_chunks_note
 	ON memory_note_chunks (note_id);
 CREATE INDEX IF NOT EXISTS idx_note_chunks_note_index
 	ON memory_note_chunks (note_id, chunk_index);
+
+ALTER TABLE memory_note_chunks
+	ADD COLUMN IF NOT EXISTS content_hash text;
+
+CREATE INDEX IF NOT EXISTS idx_note_chunks_content_hash
+	ON memory_note_chunks (content_hash)
+	WHERE content_hash IS NOT NULL;
+
+ALTER TABLE memory_note_chunks
+	ADD COLUMN IF NOT EXISTS chunking jsonb;
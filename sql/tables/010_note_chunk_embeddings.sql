@@ -2,7 +2,11 @@ CREATE TABLE IF NOT EXISTS note_chunk_embeddings (
 	chunk_id uuid NOT NULL REFERENCES memory_note_chunks(chunk_id) ON DELETE CASCADE,
 	embedding_version text NOT NULL,
 	embedding_dim int NOT NULL,
-	vec vector(<VECTOR_DIM>) NOT NULL,
+	vec vector NOT NULL,
 	created_at timestamptz NOT NULL DEFAULT now(),
 	PRIMARY KEY (chunk_id, embedding_version)
 );
+
+-- Rows of different embedding versions may have different dimensions during a re-embed migration.
+ALTER TABLE note_chunk_embeddings
+	ALTER COLUMN vec TYPE vector;
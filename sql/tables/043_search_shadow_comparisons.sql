@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS search_shadow_comparisons (
+	comparison_id uuid PRIMARY KEY,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	agent_id text NOT NULL,
+	mode text NOT NULL,
+	query text NOT NULL,
+	primary_trace_id uuid NULL,
+	shadow_trace_id uuid NULL,
+	primary_note_ids uuid[] NOT NULL,
+	shadow_note_ids uuid[] NOT NULL,
+	overlap_at_k real NULL,
+	jaccard real NULL,
+	top1_match boolean NULL,
+	primary_latency_ms int NOT NULL,
+	shadow_latency_ms int NULL,
+	shadow_status int NULL,
+	error text NULL,
+	created_at timestamptz NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_search_shadow_comparisons_context
+	ON search_shadow_comparisons (tenant_id, project_id, created_at DESC);
@@ -0,0 +1,34 @@
+CREATE TABLE IF NOT EXISTS standing_queries (
+	standing_query_id uuid PRIMARY KEY,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	agent_id text NOT NULL,
+	query text NOT NULL,
+	allowed_scopes text[] NOT NULL,
+	note_types text[] NOT NULL DEFAULT '{}',
+	min_importance real NULL,
+	min_confidence real NULL,
+	top_k int NOT NULL,
+	webhook_url text NULL,
+	status text NOT NULL,
+	created_at timestamptz NOT NULL,
+	updated_at timestamptz NOT NULL
+);
+
+ALTER TABLE standing_queries
+	DROP CONSTRAINT IF EXISTS ck_standing_queries_status;
+ALTER TABLE standing_queries
+	ADD CONSTRAINT ck_standing_queries_status
+		CHECK (status IN ('active', 'deleted'));
+
+ALTER TABLE standing_queries
+	DROP CONSTRAINT IF EXISTS ck_standing_queries_top_k;
+ALTER TABLE standing_queries
+	ADD CONSTRAINT ck_standing_queries_top_k
+		CHECK (top_k > 0);
+
+CREATE INDEX IF NOT EXISTS idx_standing_queries_owner
+	ON standing_queries (tenant_id, project_id, agent_id, status, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_standing_queries_active
+	ON standing_queries (tenant_id, project_id)
+	WHERE status = 'active';
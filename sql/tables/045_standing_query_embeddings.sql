@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS standing_query_embeddings (
+	standing_query_id uuid NOT NULL REFERENCES standing_queries(standing_query_id) ON DELETE CASCADE,
+	embedding_version text NOT NULL,
+	embedding_dim int NOT NULL,
+	vec vector(<VECTOR_DIM>) NOT NULL,
+	created_at timestamptz NOT NULL DEFAULT now(),
+	PRIMARY KEY (standing_query_id, embedding_version)
+);
@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS standing_query_matches (
+	match_id uuid PRIMARY KEY,
+	seq bigserial NOT NULL,
+	standing_query_id uuid NOT NULL REFERENCES standing_queries(standing_query_id) ON DELETE CASCADE,
+	note_id uuid NOT NULL,
+	embedding_version text NOT NULL,
+	rank int NOT NULL,
+	score real NOT NULL,
+	notify_status text NOT NULL,
+	notify_attempts int NOT NULL DEFAULT 0,
+	notify_next_at timestamptz NULL,
+	notify_error text NULL,
+	notified_at timestamptz NULL,
+	created_at timestamptz NOT NULL
+);
+
+ALTER TABLE standing_query_matches
+	DROP CONSTRAINT IF EXISTS ck_standing_query_matches_notify_status;
+ALTER TABLE standing_query_matches
+	ADD CONSTRAINT ck_standing_query_matches_notify_status
+		CHECK (notify_status IN ('none', 'pending', 'delivered', 'failed'));
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_standing_query_matches_note
+	ON standing_query_matches (standing_query_id, note_id);
+CREATE INDEX IF NOT EXISTS idx_standing_query_matches_feed
+	ON standing_query_matches (standing_query_id, seq);
+CREATE INDEX IF NOT EXISTS idx_standing_query_matches_notify
+	ON standing_query_matches (notify_next_at)
+	WHERE notify_status = 'pending';
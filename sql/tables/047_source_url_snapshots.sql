@@ -0,0 +1,32 @@
+CREATE TABLE IF NOT EXISTS source_url_snapshots (
+	snapshot_id uuid PRIMARY KEY,
+	note_id uuid NOT NULL REFERENCES memory_notes(note_id) ON DELETE CASCADE,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	agent_id text NOT NULL,
+	scope text NOT NULL,
+	url text NOT NULL,
+	embedding_version text NOT NULL,
+	status text NOT NULL,
+	attempts int NOT NULL DEFAULT 0,
+	next_attempt_at timestamptz NULL,
+	last_error text NULL,
+	http_status int NULL,
+	doc_id uuid NULL,
+	content_hash text NULL,
+	fetched_at timestamptz NULL,
+	created_at timestamptz NOT NULL,
+	updated_at timestamptz NOT NULL
+);
+
+ALTER TABLE source_url_snapshots
+	DROP CONSTRAINT IF EXISTS ck_source_url_snapshots_status;
+ALTER TABLE source_url_snapshots
+	ADD CONSTRAINT ck_source_url_snapshots_status
+		CHECK (status IN ('pending', 'done', 'blocked', 'failed'));
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_source_url_snapshots_note_url
+	ON source_url_snapshots (note_id, url);
+CREATE INDEX IF NOT EXISTS idx_source_url_snapshots_due
+	ON source_url_snapshots (next_attempt_at)
+	WHERE status = 'pending';
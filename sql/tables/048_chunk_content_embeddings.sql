@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS chunk_content_embeddings (
+	content_hash text NOT NULL,
+	embedding_version text NOT NULL,
+	embedding_dim int NOT NULL,
+	vec vector NOT NULL,
+	ref_count int NOT NULL DEFAULT 0,
+	created_at timestamptz NOT NULL DEFAULT now(),
+	updated_at timestamptz NOT NULL DEFAULT now(),
+	PRIMARY KEY (content_hash, embedding_version)
+);
+
+ALTER TABLE chunk_content_embeddings
+	ALTER COLUMN vec TYPE vector;
@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS eval_continuous_runs (
+	run_id uuid PRIMARY KEY,
+	config_path text NOT NULL,
+	window_start timestamptz NOT NULL,
+	window_end timestamptz NOT NULL,
+	sampled_traces int NOT NULL,
+	labeled_traces int NOT NULL,
+	metrics jsonb NOT NULL,
+	breaches jsonb NOT NULL,
+	alert_status text NOT NULL,
+	created_at timestamptz NOT NULL
+);
+
+ALTER TABLE eval_continuous_runs
+	DROP CONSTRAINT IF EXISTS ck_eval_continuous_runs_alert_status;
+ALTER TABLE eval_continuous_runs
+	ADD CONSTRAINT ck_eval_continuous_runs_alert_status
+		CHECK (alert_status IN ('none', 'sent', 'failed', 'not_configured'));
+
+CREATE INDEX IF NOT EXISTS idx_eval_continuous_runs_created
+	ON eval_continuous_runs (created_at DESC);
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS memory_note_evidence (
+	note_id uuid PRIMARY KEY REFERENCES memory_notes(note_id) ON DELETE CASCADE,
+	claim_count int NOT NULL,
+	backed_claim_count int NOT NULL,
+	coverage real NOT NULL,
+	quote_count int NOT NULL,
+	computed_at timestamptz NOT NULL
+);
+
+ALTER TABLE memory_note_evidence
+	DROP CONSTRAINT IF EXISTS ck_memory_note_evidence_coverage;
+ALTER TABLE memory_note_evidence
+	ADD CONSTRAINT ck_memory_note_evidence_coverage
+		CHECK (coverage >= 0.0 AND coverage <= 1.0);
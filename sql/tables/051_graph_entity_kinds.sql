@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS graph_entity_kinds (
+	kind_id uuid PRIMARY KEY,
+	scope_key text NOT NULL,
+	tenant_id text NOT NULL,
+	project_id text NULL,
+	kind text NOT NULL,
+	kind_norm text NOT NULL,
+	created_at timestamptz NOT NULL DEFAULT now(),
+	updated_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_graph_entity_kinds_scope_kind_norm
+	ON graph_entity_kinds (scope_key, kind_norm);
+
+CREATE INDEX IF NOT EXISTS idx_graph_entity_kinds_tenant_project
+	ON graph_entity_kinds (tenant_id, project_id);
@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS memory_note_values (
+	value_id uuid PRIMARY KEY,
+	note_id uuid NOT NULL REFERENCES memory_notes(note_id) ON DELETE CASCADE,
+	field_kind text NOT NULL,
+	item_index int NOT NULL,
+	value_kind text NOT NULL,
+	raw text NOT NULL,
+	normalized text NOT NULL,
+	numeric_value double precision NULL,
+	unit text NULL,
+	date_value date NULL,
+	locale text NULL,
+	created_at timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_note_values_note
+	ON memory_note_values (note_id);
+CREATE INDEX IF NOT EXISTS idx_note_values_unit_numeric
+	ON memory_note_values (unit, numeric_value)
+	WHERE numeric_value IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_note_values_date
+	ON memory_note_values (date_value)
+	WHERE date_value IS NOT NULL;
+
+ALTER TABLE memory_note_values
+	DROP CONSTRAINT IF EXISTS ck_memory_note_values_kind;
+ALTER TABLE memory_note_values
+	ADD CONSTRAINT ck_memory_note_values_kind
+		CHECK (value_kind IN ('date', 'quantity', 'currency'));
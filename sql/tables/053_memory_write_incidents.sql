@@ -0,0 +1,26 @@
+CREATE TABLE IF NOT EXISTS memory_write_incidents (
+	incident_id uuid PRIMARY KEY,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	agent_id text NOT NULL,
+	pipeline text NOT NULL,
+	action text NOT NULL,
+	reason_code text NOT NULL,
+	window_seconds bigint NOT NULL,
+	similarity_threshold real NOT NULL,
+	max_similar_writes int NOT NULL,
+	peak_similar_writes int NOT NULL,
+	flagged_writes bigint NOT NULL DEFAULT 1,
+	sample_text text NOT NULL,
+	first_seen_at timestamptz NOT NULL,
+	last_seen_at timestamptz NOT NULL,
+	CONSTRAINT ck_memory_write_incidents_pipeline
+		CHECK (pipeline IN ('add_note', 'add_event')),
+	CONSTRAINT ck_memory_write_incidents_action
+		CHECK (action IN ('reject', 'report'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_memory_write_incidents_agent
+	ON memory_write_incidents (tenant_id, project_id, agent_id, last_seen_at DESC);
+CREATE INDEX IF NOT EXISTS idx_memory_write_incidents_recent
+	ON memory_write_incidents (tenant_id, project_id, last_seen_at DESC);
@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS qdrant_maintenance_runs (
+	run_id uuid PRIMARY KEY,
+	operation text NOT NULL,
+	collection text NOT NULL,
+	trigger text NOT NULL,
+	status text NOT NULL,
+	detail jsonb NOT NULL,
+	error text NULL,
+	alert_status text NOT NULL,
+	started_at timestamptz NOT NULL,
+	finished_at timestamptz NOT NULL,
+	CONSTRAINT ck_qdrant_maintenance_runs_operation
+		CHECK (operation IN ('optimize', 'snapshot', 'restore_snapshot', 'verify_counts')),
+	CONSTRAINT ck_qdrant_maintenance_runs_trigger
+		CHECK (trigger IN ('scheduled', 'manual')),
+	CONSTRAINT ck_qdrant_maintenance_runs_status
+		CHECK (status IN ('succeeded', 'failed')),
+	CONSTRAINT ck_qdrant_maintenance_runs_alert_status
+		CHECK (alert_status IN ('none', 'sent', 'failed', 'not_configured'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_qdrant_maintenance_runs_recent
+	ON qdrant_maintenance_runs (started_at DESC);
+CREATE INDEX IF NOT EXISTS idx_qdrant_maintenance_runs_operation
+	ON qdrant_maintenance_runs (operation, trigger, started_at DESC);
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS memory_session_hits (
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	agent_id text NOT NULL,
+	session_id text NOT NULL,
+	note_id uuid NOT NULL REFERENCES memory_notes(note_id) ON DELETE CASCADE,
+	hit_count int NOT NULL DEFAULT 1,
+	first_hit_at timestamptz NOT NULL,
+	last_hit_at timestamptz NOT NULL,
+	expires_at timestamptz NOT NULL,
+	PRIMARY KEY (tenant_id, project_id, agent_id, session_id, note_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_memory_session_hits_expires
+	ON memory_session_hits (expires_at);
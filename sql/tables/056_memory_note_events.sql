@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS memory_note_events (
+	event_seq bigserial PRIMARY KEY,
+	tx_id xid8 NOT NULL DEFAULT pg_current_xact_id(),
+	version_id uuid NOT NULL,
+	note_id uuid NOT NULL,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	agent_id text NOT NULL,
+	scope text NOT NULL,
+	op text NOT NULL,
+	ts timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_memory_note_events_tenant_cursor
+	ON memory_note_events (tenant_id, tx_id, event_seq);
@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS embedding_reembed_runs (
+	run_id uuid PRIMARY KEY,
+	target_version text NOT NULL,
+	target_collection text NOT NULL,
+	target_dim int NOT NULL,
+	status text NOT NULL,
+	cursor_note_id uuid NULL,
+	notes_processed bigint NOT NULL DEFAULT 0,
+	chunks_embedded bigint NOT NULL DEFAULT 0,
+	fields_embedded bigint NOT NULL DEFAULT 0,
+	last_error text NULL,
+	started_at timestamptz NOT NULL,
+	updated_at timestamptz NOT NULL,
+	completed_at timestamptz NULL,
+	activated_at timestamptz NULL,
+	CONSTRAINT ck_embedding_reembed_runs_status
+		CHECK (status IN ('running', 'completed', 'activated'))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_embedding_reembed_runs_running
+	ON embedding_reembed_runs (target_version)
+	WHERE status = 'running';
+CREATE INDEX IF NOT EXISTS idx_embedding_reembed_runs_recent
+	ON embedding_reembed_runs (started_at DESC);
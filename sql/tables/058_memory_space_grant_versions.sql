@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS memory_space_grant_versions (
+	version_id uuid PRIMARY KEY,
+	grant_id uuid NOT NULL,
+	tenant_id text NOT NULL,
+	op text NOT NULL,
+	prev_snapshot jsonb NULL,
+	new_snapshot jsonb NULL,
+	reason text NOT NULL,
+	actor text NOT NULL,
+	ts timestamptz NOT NULL DEFAULT now(),
+	CONSTRAINT ck_memory_space_grant_versions_op
+		CHECK (op IN ('grant', 'revoke'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_memory_space_grant_versions_grant
+	ON memory_space_grant_versions (grant_id, ts);
+CREATE INDEX IF NOT EXISTS idx_memory_space_grant_versions_tenant_recent
+	ON memory_space_grant_versions (tenant_id, ts DESC);
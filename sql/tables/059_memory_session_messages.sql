@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS memory_session_messages (
+	message_seq bigserial PRIMARY KEY,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	agent_id text NOT NULL,
+	session_id text NOT NULL,
+	role text NOT NULL,
+	content text NOT NULL,
+	ts text NULL,
+	msg_id text NULL,
+	created_at timestamptz NOT NULL,
+	expires_at timestamptz NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_memory_session_messages_session
+	ON memory_session_messages (tenant_id, project_id, agent_id, session_id, message_seq);
+CREATE INDEX IF NOT EXISTS idx_memory_session_messages_expires
+	ON memory_session_messages (expires_at);
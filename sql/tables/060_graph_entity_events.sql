@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS graph_entity_events (
+	event_id uuid PRIMARY KEY,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	actor_agent_id text NOT NULL,
+	event_type text NOT NULL,
+	entity_id uuid NOT NULL,
+	related_entity_ids uuid[] NOT NULL,
+	detail jsonb NOT NULL,
+	reason text NULL,
+	ts timestamptz NOT NULL DEFAULT now(),
+	CONSTRAINT ck_graph_entity_events_event_type
+		CHECK (event_type IN ('merged', 'split'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_graph_entity_events_tenant_project_entity_ts
+	ON graph_entity_events (tenant_id, project_id, entity_id, ts);
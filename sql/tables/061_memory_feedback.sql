@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS memory_feedback (
+	feedback_id uuid PRIMARY KEY,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	agent_id text NOT NULL,
+	note_id uuid NOT NULL REFERENCES memory_notes(note_id) ON DELETE CASCADE,
+	trace_id uuid NOT NULL,
+	result_handle uuid NOT NULL,
+	feedback text NOT NULL,
+	created_at timestamptz NOT NULL,
+	updated_at timestamptz NOT NULL,
+	CONSTRAINT ck_memory_feedback_feedback
+		CHECK (feedback IN ('useful', 'not_useful', 'wrong')),
+	CONSTRAINT uq_memory_feedback_result_handle_agent
+		UNIQUE (result_handle, agent_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_memory_feedback_note
+	ON memory_feedback (note_id);
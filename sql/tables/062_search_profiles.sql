@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS search_profiles (
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	name text NOT NULL,
+	description text NULL,
+	template jsonb NOT NULL,
+	version int NOT NULL,
+	created_by text NOT NULL,
+	updated_by text NOT NULL,
+	created_at timestamptz NOT NULL,
+	updated_at timestamptz NOT NULL,
+	PRIMARY KEY (tenant_id, project_id, name),
+	CONSTRAINT ck_search_profiles_version
+		CHECK (version > 0)
+);
@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS audit_log (
+	seq bigserial PRIMARY KEY,
+	audit_id uuid NOT NULL UNIQUE,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	token_id text NULL,
+	actor text NOT NULL,
+	operation text NOT NULL,
+	method text NULL,
+	path text NULL,
+	outcome text NOT NULL,
+	target_ids uuid[] NOT NULL DEFAULT '{}',
+	reject_reasons text[] NOT NULL DEFAULT '{}',
+	ts timestamptz NOT NULL,
+	prev_hash text NOT NULL,
+	entry_hash text NOT NULL,
+	CONSTRAINT ck_audit_log_outcome
+		CHECK (outcome IN ('succeeded', 'rejected', 'failed'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_tenant_seq
+	ON audit_log (tenant_id, seq DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_log_tenant_operation
+	ON audit_log (tenant_id, operation, seq DESC);
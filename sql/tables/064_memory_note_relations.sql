@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS memory_note_relations (
+	relation_id uuid PRIMARY KEY,
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	from_note_id uuid NOT NULL REFERENCES memory_notes(note_id) ON DELETE CASCADE,
+	to_note_id uuid NOT NULL REFERENCES memory_notes(note_id) ON DELETE CASCADE,
+	relation text NOT NULL,
+	agent_id text NOT NULL,
+	created_at timestamptz NOT NULL,
+	CONSTRAINT ck_memory_note_relations_relation
+		CHECK (relation IN ('supersedes', 'derived_from', 'related_to')),
+	CONSTRAINT ck_memory_note_relations_distinct
+		CHECK (from_note_id <> to_note_id),
+	CONSTRAINT uq_memory_note_relations_edge
+		UNIQUE (from_note_id, to_note_id, relation)
+);
+
+CREATE INDEX IF NOT EXISTS idx_memory_note_relations_to
+	ON memory_note_relations (to_note_id);
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS search_result_watermarks (
+	tenant_id text NOT NULL,
+	project_id text NOT NULL,
+	scope text NOT NULL,
+	generation bigint NOT NULL,
+	updated_at timestamptz NOT NULL,
+	PRIMARY KEY (tenant_id, project_id, scope)
+);